@@ -27,4 +27,12 @@ created_at: string,
 /**
  * ISO8601 timestamp of last update
  */
-updated_at: string, };
+updated_at: string, 
+/**
+ * Ruleset edition ("2014" or "2024"), NULL means both
+ */
+edition: string | null, 
+/**
+ * Whether AI searches and exports are limited to SRD content (SQLite stores as integer)
+ */
+srd_only: number, };
@@ -48,4 +48,28 @@ created_at: string,
 /**
  * ISO8601 timestamp of last update
  */
-updated_at: string, };
+updated_at: string, 
+/**
+ * ISO8601 timestamp when moved to the trash (None if not trashed)
+ */
+deleted_at: string | null, 
+/**
+ * Subrace name (e.g., "High" for a High Elf)
+ */
+subrace_name: string | null, 
+/**
+ * Subrace source (e.g., "PHB")
+ */
+subrace_source: string | null, 
+/**
+ * Walking speed in feet
+ */
+speed: number, 
+/**
+ * Darkvision range in feet (None if the character has none)
+ */
+darkvision: number | null, 
+/**
+ * JSON array of damage types the character resists
+ */
+resistances: string, };
@@ -40,4 +40,8 @@ created_at: string,
 /**
  * ISO8601 timestamp of last update
  */
-updated_at: string, };
+updated_at: string, 
+/**
+ * ISO8601 timestamp when moved to the trash (None if not trashed)
+ */
+deleted_at: string | null, };
@@ -31,4 +31,9 @@ created_at: string,
 /**
  * ISO8601 timestamp of last update
  */
-updated_at: string, };
+updated_at: string, 
+/**
+ * Template the module was created from (e.g., "dungeon"), which its
+ * prep checklist is derived from
+ */
+module_type: string, };
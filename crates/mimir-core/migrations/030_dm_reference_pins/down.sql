@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_dm_reference_pins_campaign;
+DROP TABLE IF EXISTS dm_reference_pins;
@@ -0,0 +1,19 @@
+-- DM reference window pins
+-- Content panels (stat blocks, document sections, NPC cards, rules excerpts)
+-- pinned to the DM screen, persisted per campaign
+
+CREATE TABLE dm_reference_pins (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    panel_type TEXT NOT NULL,  -- monster, document_section, npc, rules
+    title TEXT NOT NULL,
+    ref_id TEXT,  -- document/character ID, or catalog entry name
+    ref_source TEXT,  -- catalog source code for monster/rules panels
+    section TEXT,  -- document heading, or catalog kind for rules panels
+    content TEXT,  -- free-form excerpt text
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_dm_reference_pins_campaign ON dm_reference_pins(campaign_id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_game_sessions_campaign;
+DROP TABLE IF EXISTS game_sessions;
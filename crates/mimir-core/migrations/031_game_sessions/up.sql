@@ -0,0 +1,23 @@
+-- Game sessions
+-- One row per session played at the table, with pacing totals
+-- recorded from the session clock, break timer, and combat turn timer
+
+CREATE TABLE game_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    module_id TEXT REFERENCES modules(id) ON DELETE SET NULL,
+    session_number INTEGER NOT NULL,
+    title TEXT,
+    started_at TEXT NOT NULL DEFAULT (datetime('now')),
+    ended_at TEXT,  -- NULL while the session is in progress
+    play_seconds INTEGER NOT NULL DEFAULT 0,
+    break_seconds INTEGER NOT NULL DEFAULT 0,
+    combat_turns INTEGER NOT NULL DEFAULT 0,
+    combat_turn_seconds INTEGER NOT NULL DEFAULT 0,
+    longest_turn_seconds INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, session_number)
+);
+
+CREATE INDEX idx_game_sessions_campaign ON game_sessions(campaign_id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_quick_actions_campaign;
+DROP TABLE IF EXISTS quick_actions;
@@ -0,0 +1,16 @@
+-- Quick actions
+-- Named, per-campaign bundles of scene changes (fog reveals, lights, handouts,
+-- ambience, timers) triggered together from the DM soundboard
+
+CREATE TABLE quick_actions (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    steps TEXT NOT NULL DEFAULT '[]',  -- JSON array of steps, run in order
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_quick_actions_campaign ON quick_actions(campaign_id);
@@ -0,0 +1,10 @@
+DROP TRIGGER IF EXISTS entity_tags_asset_delete;
+DROP TRIGGER IF EXISTS entity_tags_map_delete;
+DROP TRIGGER IF EXISTS entity_tags_homebrew_monster_delete;
+DROP TRIGGER IF EXISTS entity_tags_monster_delete;
+DROP TRIGGER IF EXISTS entity_tags_npc_delete;
+DROP TRIGGER IF EXISTS entity_tags_document_delete;
+DROP INDEX IF EXISTS idx_entity_tags_entity;
+DROP TABLE IF EXISTS entity_tags;
+DROP INDEX IF EXISTS idx_tags_campaign;
+DROP TABLE IF EXISTS tags;
@@ -0,0 +1,50 @@
+-- Campaign-wide tags
+-- Tags organize content by arc, location, or theme across entity types.
+-- entity_tags is polymorphic: entity_type names the tagged table.
+
+CREATE TABLE tags (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    name TEXT NOT NULL COLLATE NOCASE,
+    color TEXT,  -- optional hex color for chips, e.g. '#8b5cf6'
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, name)
+);
+
+CREATE INDEX idx_tags_campaign ON tags(campaign_id);
+
+CREATE TABLE entity_tags (
+    tag_id TEXT NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    entity_type TEXT NOT NULL,  -- document, npc, monster, homebrew_monster, map, asset
+    entity_id TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (tag_id, entity_type, entity_id)
+);
+
+CREATE INDEX idx_entity_tags_entity ON entity_tags(entity_type, entity_id);
+
+-- Triggers to drop tag links when a tagged entity is deleted
+
+CREATE TRIGGER entity_tags_document_delete AFTER DELETE ON documents BEGIN
+    DELETE FROM entity_tags WHERE entity_type = 'document' AND entity_id = old.id;
+END;
+
+CREATE TRIGGER entity_tags_npc_delete AFTER DELETE ON characters BEGIN
+    DELETE FROM entity_tags WHERE entity_type = 'npc' AND entity_id = old.id;
+END;
+
+CREATE TRIGGER entity_tags_monster_delete AFTER DELETE ON module_monsters BEGIN
+    DELETE FROM entity_tags WHERE entity_type = 'monster' AND entity_id = old.id;
+END;
+
+CREATE TRIGGER entity_tags_homebrew_monster_delete AFTER DELETE ON campaign_homebrew_monsters BEGIN
+    DELETE FROM entity_tags WHERE entity_type = 'homebrew_monster' AND entity_id = old.id;
+END;
+
+CREATE TRIGGER entity_tags_map_delete AFTER DELETE ON maps BEGIN
+    DELETE FROM entity_tags WHERE entity_type = 'map' AND entity_id = old.id;
+END;
+
+CREATE TRIGGER entity_tags_asset_delete AFTER DELETE ON campaign_assets BEGIN
+    DELETE FROM entity_tags WHERE entity_type = 'asset' AND entity_id = old.id;
+END;
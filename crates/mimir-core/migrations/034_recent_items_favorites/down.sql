@@ -0,0 +1,7 @@
+DROP TRIGGER IF EXISTS recent_items_map_delete;
+DROP TRIGGER IF EXISTS recent_items_document_delete;
+DROP INDEX IF EXISTS idx_favorites_ref;
+DROP TABLE IF EXISTS favorites;
+DROP INDEX IF EXISTS idx_recent_items_viewed;
+DROP INDEX IF EXISTS idx_recent_items_ref;
+DROP TABLE IF EXISTS recent_items;
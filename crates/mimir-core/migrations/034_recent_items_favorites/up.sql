@@ -0,0 +1,43 @@
+-- Recently viewed items and favorites
+-- Catalog entries, documents, and maps the DM has opened or pinned, for the
+-- "jump back in" panel. Catalog entries are referenced by name and source;
+-- documents and maps by ID within their campaign.
+
+CREATE TABLE recent_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL,  -- document, map, or a catalog kind (monster, spell, ...)
+    ref_id TEXT NOT NULL,  -- document/map ID, or catalog entry name
+    ref_source TEXT,  -- catalog source code
+    campaign_id TEXT REFERENCES campaigns(id) ON DELETE CASCADE,  -- set for documents and maps
+    title TEXT NOT NULL,
+    view_count INTEGER NOT NULL DEFAULT 1,
+    last_viewed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_recent_items_ref ON recent_items(kind, ref_id);
+CREATE INDEX idx_recent_items_viewed ON recent_items(last_viewed_at);
+
+CREATE TABLE favorites (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL,
+    ref_id TEXT NOT NULL,
+    ref_source TEXT,
+    campaign_id TEXT REFERENCES campaigns(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_favorites_ref ON favorites(kind, ref_id);
+
+-- Triggers to drop history and favorites for deleted documents and maps
+
+CREATE TRIGGER recent_items_document_delete AFTER DELETE ON documents BEGIN
+    DELETE FROM recent_items WHERE kind = 'document' AND ref_id = old.id;
+    DELETE FROM favorites WHERE kind = 'document' AND ref_id = old.id;
+END;
+
+CREATE TRIGGER recent_items_map_delete AFTER DELETE ON maps BEGIN
+    DELETE FROM recent_items WHERE kind = 'map' AND ref_id = old.id;
+    DELETE FROM favorites WHERE kind = 'map' AND ref_id = old.id;
+END;
@@ -0,0 +1,16 @@
+-- Rollback soft-delete support
+
+DROP INDEX IF EXISTS idx_maps_deleted;
+DROP INDEX IF EXISTS idx_characters_deleted;
+DROP INDEX IF EXISTS idx_documents_deleted;
+
+-- Permanently remove anything still in the trash so it does not reappear
+DELETE FROM maps WHERE deleted_at IS NOT NULL;
+DELETE FROM characters WHERE deleted_at IS NOT NULL;
+DELETE FROM documents WHERE deleted_at IS NOT NULL;
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the columns
+-- (they're ignored once the rows above are gone)
+-- ALTER TABLE documents DROP COLUMN deleted_at;
+-- ALTER TABLE characters DROP COLUMN deleted_at;
+-- ALTER TABLE maps DROP COLUMN deleted_at;
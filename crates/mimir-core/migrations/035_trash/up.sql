@@ -0,0 +1,11 @@
+-- Soft-delete support for documents, characters, and maps.
+-- Trashed rows keep their data (and child rows) until purged; list queries
+-- skip any row with deleted_at set.
+
+ALTER TABLE documents ADD COLUMN deleted_at TEXT;
+ALTER TABLE characters ADD COLUMN deleted_at TEXT;
+ALTER TABLE maps ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX idx_documents_deleted ON documents(deleted_at);
+CREATE INDEX idx_characters_deleted ON characters(deleted_at);
+CREATE INDEX idx_maps_deleted ON maps(deleted_at);
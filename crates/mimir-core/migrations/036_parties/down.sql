@@ -0,0 +1,35 @@
+-- Rollback party support
+
+DROP INDEX IF EXISTS idx_party_timeline_events_party;
+DROP TABLE IF EXISTS party_timeline_events;
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we recreate the table
+DROP INDEX IF EXISTS idx_game_sessions_party;
+CREATE TABLE game_sessions_backup AS SELECT id, campaign_id, module_id, session_number, title, started_at, ended_at, play_seconds, break_seconds, combat_turns, combat_turn_seconds, longest_turn_seconds, created_at, updated_at FROM game_sessions;
+DROP TABLE game_sessions;
+CREATE TABLE game_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    module_id TEXT REFERENCES modules(id) ON DELETE SET NULL,
+    session_number INTEGER NOT NULL,
+    title TEXT,
+    started_at TEXT NOT NULL DEFAULT (datetime('now')),
+    ended_at TEXT,
+    play_seconds INTEGER NOT NULL DEFAULT 0,
+    break_seconds INTEGER NOT NULL DEFAULT 0,
+    combat_turns INTEGER NOT NULL DEFAULT 0,
+    combat_turn_seconds INTEGER NOT NULL DEFAULT 0,
+    longest_turn_seconds INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, session_number)
+);
+INSERT INTO game_sessions SELECT * FROM game_sessions_backup;
+DROP TABLE game_sessions_backup;
+CREATE INDEX idx_game_sessions_campaign ON game_sessions(campaign_id);
+
+DROP INDEX IF EXISTS idx_party_members_character;
+DROP TABLE IF EXISTS party_members;
+
+DROP INDEX IF EXISTS idx_parties_campaign;
+DROP TABLE IF EXISTS parties;
@@ -0,0 +1,46 @@
+-- Parties
+-- Multi-table and West Marches campaigns run several groups in one world.
+-- Characters can belong to more than one party; sessions, XP, and the
+-- in-world calendar are tracked per party.
+
+CREATE TABLE parties (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    name TEXT NOT NULL COLLATE NOCASE,
+    description TEXT,
+    color TEXT,  -- optional hex color for chips, e.g. '#8b5cf6'
+    current_day INTEGER NOT NULL DEFAULT 1,  -- in-world day on the party's calendar
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, name)
+);
+
+CREATE INDEX idx_parties_campaign ON parties(campaign_id);
+
+CREATE TABLE party_members (
+    party_id TEXT NOT NULL REFERENCES parties(id) ON DELETE CASCADE,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    joined_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (party_id, character_id)
+);
+
+CREATE INDEX idx_party_members_character ON party_members(character_id);
+
+-- Sessions are played by one party; xp_awarded is the XP each member earned
+ALTER TABLE game_sessions ADD COLUMN party_id TEXT REFERENCES parties(id) ON DELETE SET NULL;
+ALTER TABLE game_sessions ADD COLUMN xp_awarded INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_game_sessions_party ON game_sessions(party_id);
+
+-- Per-party calendar timeline
+CREATE TABLE party_timeline_events (
+    id TEXT PRIMARY KEY NOT NULL,
+    party_id TEXT NOT NULL REFERENCES parties(id) ON DELETE CASCADE,
+    session_id TEXT REFERENCES game_sessions(id) ON DELETE SET NULL,
+    day INTEGER NOT NULL,  -- in-world day the event happened on
+    title TEXT NOT NULL,
+    description TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_party_timeline_events_party ON party_timeline_events(party_id, day);
@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS idx_player_claims_player;
+DROP INDEX IF EXISTS idx_player_claims_campaign;
+DROP TABLE IF EXISTS player_claims;
+
+DROP INDEX IF EXISTS idx_player_characters_player;
+DROP TABLE IF EXISTS player_characters;
+
+DROP INDEX IF EXISTS idx_players_campaign;
+DROP TABLE IF EXISTS players;
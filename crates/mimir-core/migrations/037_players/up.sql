@@ -0,0 +1,44 @@
+-- Player roster
+-- The people at the table, with contact details. Characters are linked to
+-- the player who owns them; attendance is derived from game sessions.
+
+CREATE TABLE players (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    name TEXT NOT NULL COLLATE NOCASE,
+    email TEXT,
+    discord TEXT,
+    phone TEXT,
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, name)
+);
+
+CREATE INDEX idx_players_campaign ON players(campaign_id);
+
+-- A character has at most one owning player
+CREATE TABLE player_characters (
+    character_id TEXT PRIMARY KEY NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    player_id TEXT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    linked_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_player_characters_player ON player_characters(player_id);
+
+-- Claim codes shared with a player. Redeeming one either hands over an
+-- existing character (character_id) or creates one from submitted JSON.
+CREATE TABLE player_claims (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    player_id TEXT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    code TEXT NOT NULL UNIQUE,  -- normalized: uppercase, no separators
+    character_id TEXT REFERENCES characters(id) ON DELETE CASCADE,
+    expires_at TEXT,  -- NULL never expires
+    redeemed_at TEXT,
+    redeemed_character_id TEXT REFERENCES characters(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_player_claims_campaign ON player_claims(campaign_id);
+CREATE INDEX idx_player_claims_player ON player_claims(player_id);
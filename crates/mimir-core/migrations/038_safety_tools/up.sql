@@ -0,0 +1,15 @@
+-- Safety Tools
+-- One row per campaign holding the table's agreed lines, veils, and safety
+-- mechanics. Lines and veils are JSON arrays of strings.
+
+CREATE TABLE campaign_safety_tools (
+    campaign_id TEXT PRIMARY KEY NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    lines TEXT NOT NULL DEFAULT '[]',   -- hard limits: never appear in the game
+    veils TEXT NOT NULL DEFAULT '[]',   -- may be referenced, but fade to black
+    x_card_enabled BOOLEAN NOT NULL DEFAULT 1,
+    x_card_signal TEXT,                 -- how to invoke the X-card, e.g. 'tap the card'
+    open_door BOOLEAN NOT NULL DEFAULT 1,
+    check_ins BOOLEAN NOT NULL DEFAULT 1,
+    notes TEXT,                         -- DM-only notes (e.g. private requests from players)
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
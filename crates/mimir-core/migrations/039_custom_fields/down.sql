@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS character_custom_fields;
+DROP TABLE IF EXISTS campaign_custom_fields;
@@ -0,0 +1,29 @@
+-- Custom character sheet fields
+-- House-ruled stats (sanity, faction renown, inspiration dice) defined per
+-- campaign, with values stored per character by field key.
+
+CREATE TABLE campaign_custom_fields (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    field_key TEXT NOT NULL,  -- snake_case, immutable once created
+    label TEXT NOT NULL,
+    field_type TEXT NOT NULL CHECK(field_type IN ('number', 'text', 'boolean', 'dice')),
+    default_value TEXT,
+    min_value INTEGER,  -- number fields only
+    max_value INTEGER,  -- number fields only
+    description TEXT,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, field_key)
+);
+
+CREATE INDEX idx_campaign_custom_fields_campaign ON campaign_custom_fields(campaign_id);
+
+CREATE TABLE character_custom_fields (
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    field_key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (character_id, field_key)
+);
@@ -0,0 +1,18 @@
+-- Class resources
+-- Limited-use class features (rage, ki, channel divinity) tracked per
+-- character, derived from class levels and restored by rests.
+
+CREATE TABLE character_resources (
+    id TEXT PRIMARY KEY NOT NULL,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    resource_name TEXT NOT NULL,
+    source_class TEXT NOT NULL,
+    max_uses INTEGER NOT NULL,
+    current_uses INTEGER NOT NULL,
+    recharge TEXT NOT NULL CHECK(recharge IN ('short_rest', 'long_rest')),
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(character_id, resource_name, source_class)
+);
+
+CREATE INDEX idx_character_resources_character ON character_resources(character_id);
@@ -0,0 +1,9 @@
+-- Rollback racial traits on characters
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the columns
+-- (they keep their defaults and are ignored by older code)
+-- ALTER TABLE characters DROP COLUMN resistances;
+-- ALTER TABLE characters DROP COLUMN darkvision;
+-- ALTER TABLE characters DROP COLUMN speed;
+-- ALTER TABLE characters DROP COLUMN subrace_source;
+-- ALTER TABLE characters DROP COLUMN subrace_name;
@@ -0,0 +1,9 @@
+-- Racial traits on characters
+-- Subrace plus the walking speed, darkvision, and damage resistances a
+-- character's race grants, filled in from the catalog at creation.
+
+ALTER TABLE characters ADD COLUMN subrace_name TEXT;
+ALTER TABLE characters ADD COLUMN subrace_source TEXT;
+ALTER TABLE characters ADD COLUMN speed INTEGER NOT NULL DEFAULT 30;
+ALTER TABLE characters ADD COLUMN darkvision INTEGER;
+ALTER TABLE characters ADD COLUMN resistances TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1,25 @@
+-- Character companions
+-- Beast companions, familiars, and sidekicks (Tasha's) that travel with a
+-- character. The stat block comes from a catalog or homebrew monster;
+-- hit points and sidekick levels are tracked here alongside the owner.
+
+CREATE TABLE character_companions (
+    id TEXT PRIMARY KEY NOT NULL,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    companion_type TEXT NOT NULL CHECK(companion_type IN ('beast', 'familiar', 'sidekick', 'other')),
+    -- Stat block: a catalog monster or a campaign homebrew monster
+    monster_name TEXT,
+    monster_source TEXT,
+    homebrew_monster_id TEXT REFERENCES campaign_homebrew_monsters(id) ON DELETE SET NULL,
+    -- Sidekick class and level: 'expert', 'spellcaster', 'warrior'
+    sidekick_class TEXT CHECK(sidekick_class IN ('expert', 'spellcaster', 'warrior')),
+    level INTEGER,
+    max_hp INTEGER NOT NULL,
+    current_hp INTEGER NOT NULL,
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_character_companions_character ON character_companions(character_id);
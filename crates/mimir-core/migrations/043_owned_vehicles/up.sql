@@ -0,0 +1,25 @@
+-- Owned vehicles and mounts
+-- Mounts (catalog items of type MNT) and vehicles (vehicle catalog entries)
+-- owned by a single character or by a whole party. Capacity comes from the
+-- catalog entry; the owner and a display name are tracked here.
+
+CREATE TABLE owned_vehicles (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    -- Owner: exactly one of a character or a party
+    character_id TEXT REFERENCES characters(id) ON DELETE CASCADE,
+    party_id TEXT REFERENCES parties(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    vehicle_kind TEXT NOT NULL CHECK(vehicle_kind IN ('vehicle', 'mount')),
+    -- Catalog entry: a vehicle for 'vehicle', an item for 'mount'
+    catalog_name TEXT NOT NULL,
+    catalog_source TEXT NOT NULL,
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    CHECK((character_id IS NULL) <> (party_id IS NULL))
+);
+
+CREATE INDEX idx_owned_vehicles_campaign ON owned_vehicles(campaign_id);
+CREATE INDEX idx_owned_vehicles_character ON owned_vehicles(character_id);
+CREATE INDEX idx_owned_vehicles_party ON owned_vehicles(party_id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS campaign_stash_ledger;
+DROP TABLE IF EXISTS campaign_stash_items;
+DROP TABLE IF EXISTS campaign_stash_currency;
@@ -0,0 +1,52 @@
+-- Campaign shared stash
+-- Coins and items the party holds in common, plus a ledger of every
+-- deposit, withdrawal, and hand-out so "who took the bag of holding" has
+-- an answer.
+
+-- One row per campaign; coins can't go negative
+CREATE TABLE campaign_stash_currency (
+    campaign_id TEXT PRIMARY KEY NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    cp INTEGER NOT NULL DEFAULT 0 CHECK(cp >= 0),
+    sp INTEGER NOT NULL DEFAULT 0 CHECK(sp >= 0),
+    ep INTEGER NOT NULL DEFAULT 0 CHECK(ep >= 0),
+    gp INTEGER NOT NULL DEFAULT 0 CHECK(gp >= 0),
+    pp INTEGER NOT NULL DEFAULT 0 CHECK(pp >= 0),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- Stacked items: one row per catalog item ('HB' source for homebrew)
+CREATE TABLE campaign_stash_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    item_name TEXT NOT NULL,
+    item_source TEXT NOT NULL,
+    quantity INTEGER NOT NULL CHECK(quantity > 0),
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, item_name, item_source)
+);
+
+-- Ledger: 'deposit' into the stash, 'withdraw' out of play, 'take' into a
+-- character's inventory or purse. Amounts are always positive.
+CREATE TABLE campaign_stash_ledger (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    action TEXT NOT NULL CHECK(action IN ('deposit', 'withdraw', 'take')),
+    -- Character involved; the name is kept in case the character is deleted
+    character_id TEXT REFERENCES characters(id) ON DELETE SET NULL,
+    character_name TEXT,
+    item_name TEXT,
+    item_source TEXT,
+    quantity INTEGER NOT NULL DEFAULT 0,
+    cp INTEGER NOT NULL DEFAULT 0,
+    sp INTEGER NOT NULL DEFAULT 0,
+    ep INTEGER NOT NULL DEFAULT 0,
+    gp INTEGER NOT NULL DEFAULT 0,
+    pp INTEGER NOT NULL DEFAULT 0,
+    note TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_campaign_stash_items_campaign ON campaign_stash_items(campaign_id);
+CREATE INDEX idx_campaign_stash_ledger_campaign ON campaign_stash_ledger(campaign_id, created_at);
@@ -0,0 +1,31 @@
+-- Crafting projects
+-- Items a character is crafting during downtime. Cost, time, tool, and
+-- exotic ingredient are derived from the catalog item when the project
+-- starts (XGE/DMG crafting rules); downtime days are logged against it
+-- until it completes and the item goes into the crafter's inventory.
+
+CREATE TABLE crafting_projects (
+    id TEXT PRIMARY KEY NOT NULL,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    -- Item being crafted: a catalog item, or 'HB' for campaign homebrew
+    item_name TEXT NOT NULL,
+    item_source TEXT NOT NULL,
+    quantity INTEGER NOT NULL DEFAULT 1 CHECK(quantity > 0),
+    -- Rarity for magic items; NULL for mundane items
+    rarity TEXT,
+    -- Gold spent on materials, in copper pieces
+    cost_cp INTEGER NOT NULL DEFAULT 0 CHECK(cost_cp >= 0),
+    days_required INTEGER NOT NULL CHECK(days_required > 0),
+    days_spent INTEGER NOT NULL DEFAULT 0 CHECK(days_spent >= 0),
+    tool TEXT,
+    -- Exotic material a magic item needs, and whether it's in hand
+    ingredient TEXT,
+    ingredient_acquired INTEGER NOT NULL DEFAULT 0,
+    status TEXT NOT NULL DEFAULT 'in_progress' CHECK(status IN ('in_progress', 'completed', 'abandoned')),
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    completed_at TEXT
+);
+
+CREATE INDEX idx_crafting_projects_character ON crafting_projects(character_id);
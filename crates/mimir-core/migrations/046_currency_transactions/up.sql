@@ -0,0 +1,18 @@
+-- Currency transactions
+-- Every change to a character's coins, with the reason and the amount per
+-- denomination (positive for gains, negative for spending), so purse
+-- changes can be audited after the fact.
+
+CREATE TABLE currency_transactions (
+    id TEXT PRIMARY KEY NOT NULL,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL,
+    cp INTEGER NOT NULL DEFAULT 0,
+    sp INTEGER NOT NULL DEFAULT 0,
+    ep INTEGER NOT NULL DEFAULT 0,
+    gp INTEGER NOT NULL DEFAULT 0,
+    pp INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_currency_transactions_character ON currency_transactions(character_id, created_at);
@@ -0,0 +1,7 @@
+-- Rollback character deaths
+
+DROP TABLE IF EXISTS character_deaths;
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the column
+-- (it keeps its default and is ignored by older code)
+-- ALTER TABLE characters DROP COLUMN is_dead;
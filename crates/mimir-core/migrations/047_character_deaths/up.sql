@@ -0,0 +1,22 @@
+-- Character deaths
+-- Characters can die and be brought back. is_dead marks the current state;
+-- character_deaths keeps each death with its cause and in-world day, and the
+-- resurrection (method and cost) that ended it, for the campaign memorial.
+
+ALTER TABLE characters ADD COLUMN is_dead BOOLEAN NOT NULL DEFAULT 0;
+
+CREATE TABLE character_deaths (
+    id TEXT PRIMARY KEY NOT NULL,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    cause TEXT NOT NULL,
+    party_id TEXT REFERENCES parties(id) ON DELETE SET NULL,  -- party whose calendar dates the death
+    day INTEGER,  -- in-world day on the party's calendar
+    notes TEXT,
+    died_at TEXT NOT NULL DEFAULT (datetime('now')),
+    resurrection_method TEXT,  -- e.g. 'Raise Dead'; NULL while still dead
+    resurrection_cost_cp INTEGER,
+    resurrection_day INTEGER,
+    resurrected_at TEXT
+);
+
+CREATE INDEX idx_character_deaths_character ON character_deaths(character_id, died_at);
@@ -0,0 +1,7 @@
+-- Rollback character portraits and tokens
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the columns
+-- (they default to NULL and are ignored by older code)
+-- ALTER TABLE characters DROP COLUMN portrait_path;
+-- ALTER TABLE characters DROP COLUMN token_path;
+-- ALTER TABLE token_placements DROP COLUMN character_id;
@@ -0,0 +1,11 @@
+-- Character portraits and tokens
+-- Images live under the assets directory at characters/{id}/, named by a hash
+-- of their content so a replaced image never collides with a cached one. The
+-- token is a square, circle-cropped render of the portrait (or an upload).
+
+ALTER TABLE characters ADD COLUMN portrait_path TEXT;  -- relative to the assets directory
+ALTER TABLE characters ADD COLUMN token_path TEXT;     -- relative to the assets directory
+
+-- PC tokens can point at the character they stand for, so the map can show
+-- the character's token image
+ALTER TABLE token_placements ADD COLUMN character_id TEXT REFERENCES characters(id) ON DELETE SET NULL;
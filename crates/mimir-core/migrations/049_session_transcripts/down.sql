@@ -0,0 +1,5 @@
+-- Rollback session transcripts
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the column
+-- (it defaults to NULL and is ignored by older code)
+-- ALTER TABLE game_sessions DROP COLUMN transcript;
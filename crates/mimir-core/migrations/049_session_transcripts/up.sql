@@ -0,0 +1,5 @@
+-- Session transcripts
+-- Plain text of what was said at the table, imported from a speech-to-text
+-- tool (e.g. whisper.cpp) after the session.
+
+ALTER TABLE game_sessions ADD COLUMN transcript TEXT;
@@ -0,0 +1,16 @@
+-- Campaign memories
+-- Named facts the AI assistant saves while helping run a campaign ("the party
+-- insulted Duke Avery"), so it can recall them in later conversations. Names
+-- are unique per campaign; remembering an existing name replaces the fact.
+
+CREATE TABLE campaign_memories (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    content TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (campaign_id, name)
+);
+
+CREATE INDEX idx_campaign_memories_campaign ON campaign_memories(campaign_id, updated_at);
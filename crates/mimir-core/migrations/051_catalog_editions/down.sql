@@ -0,0 +1,6 @@
+-- Rollback catalog editions
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the columns
+-- (they have defaults and are ignored by older code)
+-- ALTER TABLE catalog_sources DROP COLUMN edition;
+-- ALTER TABLE campaigns DROP COLUMN edition;
@@ -0,0 +1,11 @@
+-- Catalog editions
+-- Tag each catalog source with the ruleset it was written for (2014 or the
+-- 2024 revision), and let a campaign pick one so searches leave out the
+-- other edition's superseded content.
+
+ALTER TABLE catalog_sources ADD COLUMN edition TEXT NOT NULL DEFAULT '2014';
+
+UPDATE catalog_sources SET edition = '2024' WHERE code IN ('XPHB', 'XDMG', 'XMM');
+
+-- NULL means the campaign uses content from both editions
+ALTER TABLE campaigns ADD COLUMN edition TEXT;
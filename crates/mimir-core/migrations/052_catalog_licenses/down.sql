@@ -0,0 +1,7 @@
+-- Rollback catalog licenses
+
+DROP TABLE IF EXISTS catalog_licenses;
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the column
+-- (it defaults to 0 and is ignored by older code)
+-- ALTER TABLE campaigns DROP COLUMN srd_only;
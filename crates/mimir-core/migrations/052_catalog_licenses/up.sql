@@ -0,0 +1,15 @@
+-- Catalog licenses
+-- Records which catalog entries come from an openly licensed System Reference
+-- Document, so a campaign can be limited to content that may be shared. Entries
+-- without a row are book content.
+
+CREATE TABLE catalog_licenses (
+    entity_type TEXT NOT NULL,
+    name TEXT NOT NULL,
+    source TEXT NOT NULL REFERENCES catalog_sources(code),
+    license TEXT NOT NULL,
+    PRIMARY KEY (entity_type, name, source)
+);
+
+-- 1 limits AI searches and exports to SRD content
+ALTER TABLE campaigns ADD COLUMN srd_only INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,20 @@
+-- Module links
+-- How a campaign's modules connect: a module can follow from an earlier one,
+-- or foreshadow a later one with a hook (the note) that is marked resolved
+-- once it pays off. Links are directed, from_module -> to_module.
+
+CREATE TABLE module_links (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    from_module_id TEXT NOT NULL REFERENCES modules(id) ON DELETE CASCADE,
+    to_module_id TEXT NOT NULL REFERENCES modules(id) ON DELETE CASCADE,
+    link_type TEXT NOT NULL CHECK (link_type IN ('follows_from', 'foreshadows')),
+    note TEXT,
+    resolved INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    CHECK (from_module_id <> to_module_id),
+    UNIQUE (from_module_id, to_module_id, link_type)
+);
+
+CREATE INDEX idx_module_links_from ON module_links(from_module_id);
+CREATE INDEX idx_module_links_to ON module_links(to_module_id);
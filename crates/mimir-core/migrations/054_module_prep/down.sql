@@ -0,0 +1,7 @@
+-- Rollback module prep checklists
+
+DROP TABLE IF EXISTS module_prep_checks;
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the column
+-- (it defaults to 'general' and is ignored by older code)
+-- ALTER TABLE modules DROP COLUMN module_type;
@@ -0,0 +1,15 @@
+-- Module prep checklists
+-- A module's prep checklist is derived from its type's template, so the type
+-- picked at creation is now kept. Existing modules were all created from
+-- whichever template was picked, which wasn't stored; they count as general.
+ALTER TABLE modules ADD COLUMN module_type TEXT NOT NULL DEFAULT 'general';
+
+-- Prep tasks the DM ticked off by hand, keyed by the task's key in the
+-- derived checklist. Most tasks tick themselves once the work shows up in
+-- the module; these cover work done outside Mimir.
+CREATE TABLE module_prep_checks (
+    module_id TEXT NOT NULL REFERENCES modules(id) ON DELETE CASCADE,
+    task_key TEXT NOT NULL,
+    checked_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (module_id, task_key)
+);
@@ -0,0 +1,3 @@
+-- Rollback map regions
+
+DROP TABLE IF EXISTS map_regions;
@@ -0,0 +1,21 @@
+-- Map regions
+-- Named encounter areas on a map, e.g. "area-3". Documents link to one with
+-- {{map-region: Map Name | area-3}}. Bounds are in grid units like fog
+-- revealed areas. A polygon region also keeps its points (a JSON array of
+-- [x, y] pairs); x/y/width/height then hold the polygon's bounding box.
+
+CREATE TABLE map_regions (
+    id TEXT PRIMARY KEY NOT NULL,
+    map_id TEXT NOT NULL REFERENCES maps(id) ON DELETE CASCADE,
+    name TEXT NOT NULL COLLATE NOCASE,
+    x REAL NOT NULL,
+    y REAL NOT NULL,
+    width REAL NOT NULL,
+    height REAL NOT NULL,
+    points TEXT,  -- polygon vertices, NULL for a plain rectangle
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(map_id, name)
+);
+
+CREATE INDEX idx_map_regions_map_id ON map_regions(map_id);
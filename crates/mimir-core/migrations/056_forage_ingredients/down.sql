@@ -0,0 +1,3 @@
+-- Rollback forage ingredients
+
+DROP TABLE IF EXISTS forage_ingredients;
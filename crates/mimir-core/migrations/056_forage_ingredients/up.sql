@@ -0,0 +1,22 @@
+-- Forage ingredients
+-- Per-campaign gathering tables: the ingredients that can be foraged in each
+-- environment (biome), the check DC to find each one, and how many are found
+-- as a dice expression. Items are catalog items or campaign homebrew ("HB"),
+-- and foraged finds go into the campaign stash. Weight sets how often an
+-- ingredient comes up among those the check beats.
+
+CREATE TABLE forage_ingredients (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    environment TEXT NOT NULL COLLATE NOCASE,
+    item_name TEXT NOT NULL,
+    item_source TEXT NOT NULL,
+    dc INTEGER NOT NULL DEFAULT 10,
+    quantity TEXT NOT NULL DEFAULT '1',  -- e.g. '1d4' or '2'
+    weight INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(campaign_id, environment, item_name, item_source)
+);
+
+CREATE INDEX idx_forage_ingredients_campaign_id ON forage_ingredients(campaign_id);
@@ -0,0 +1,3 @@
+-- Rollback encounter effects
+
+DROP TABLE IF EXISTS encounter_effects;
@@ -0,0 +1,18 @@
+-- Encounter effects
+-- Environmental effects that act during a module's combat: lair actions on
+-- an initiative count, hazards at the start of every round, and one-off
+-- events such as a ceiling collapsing at the start of a given round. The
+-- initiative tracker schedules them when the module is run.
+
+CREATE TABLE encounter_effects (
+    id TEXT PRIMARY KEY NOT NULL,
+    module_id TEXT NOT NULL REFERENCES modules(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    timing TEXT NOT NULL DEFAULT 'initiative',  -- 'initiative', 'each_round', or 'round'
+    timing_value INTEGER,  -- initiative count, or round number
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_encounter_effects_module_id ON encounter_effects(module_id);
@@ -0,0 +1,5 @@
+-- Rollback mob tokens
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the column
+-- (it defaults to NULL and is ignored by older code)
+-- ALTER TABLE token_placements DROP COLUMN mob_size;
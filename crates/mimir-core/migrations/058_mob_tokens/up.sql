@@ -0,0 +1,5 @@
+-- Mob tokens
+-- A single monster token can stand for a mob of identical creatures (DMG mob
+-- rules), so a 30-goblin fight doesn't need 30 tokens. The size shrinks as
+-- the mob takes casualties; NULL is an ordinary token.
+ALTER TABLE token_placements ADD COLUMN mob_size INTEGER;
@@ -0,0 +1,5 @@
+-- Rollback spellbook scribing
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the column
+-- (it defaults to 0 and is ignored by older code)
+-- ALTER TABLE character_spells DROP COLUMN scribed;
@@ -0,0 +1,5 @@
+-- Spellbook scribing
+-- Wizards copy spells into their spellbook from scrolls and other books, on
+-- top of the ones learned on level up. Scribed spells are tracked apart from
+-- prepared ones so the grimoire export can show what scribing them cost.
+ALTER TABLE character_spells ADD COLUMN scribed INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,6 @@
+-- Rollback map trap catalog reference
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the columns
+-- (they default to NULL and are ignored by older code)
+-- ALTER TABLE map_traps DROP COLUMN catalog_name;
+-- ALTER TABLE map_traps DROP COLUMN catalog_source;
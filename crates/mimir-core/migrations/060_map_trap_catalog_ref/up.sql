@@ -0,0 +1,7 @@
+-- Map trap catalog reference
+-- Traps placed from the catalog copy their trigger, effect, and DC onto the
+-- map trap and remember which catalog entry they came from, so they can be
+-- refreshed later and exports can print the full catalog text. NULL is a
+-- custom trap.
+ALTER TABLE map_traps ADD COLUMN catalog_name TEXT;
+ALTER TABLE map_traps ADD COLUMN catalog_source TEXT;
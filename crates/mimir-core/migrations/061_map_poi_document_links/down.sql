@@ -0,0 +1,6 @@
+-- Rollback map POI document links
+
+-- SQLite doesn't support DROP COLUMN before 3.35.0, so we leave the columns
+-- (they default to NULL and are ignored by older code)
+-- ALTER TABLE map_pois DROP COLUMN document_id;
+-- ALTER TABLE map_pois DROP COLUMN document_section;
@@ -0,0 +1,6 @@
+-- Map POI document links
+-- A POI can point at a document, and optionally at a heading within it, so
+-- a keyed map links each numbered location to its description. NULL is an
+-- unlinked POI.
+ALTER TABLE map_pois ADD COLUMN document_id TEXT;
+ALTER TABLE map_pois ADD COLUMN document_section TEXT;
@@ -0,0 +1,4 @@
+-- Rollback rumors
+
+DROP TABLE IF EXISTS rumor_hearings;
+DROP TABLE IF EXISTS rumors;
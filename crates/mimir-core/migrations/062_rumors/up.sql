@@ -0,0 +1,28 @@
+-- Rumors
+-- Rumors and adventure hooks the DM can drop into play: what is said, whether
+-- it is true, the NPC it comes from, and the module it leads into. Hearings
+-- record which players have heard each rumor, and when.
+
+CREATE TABLE rumors (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    text TEXT NOT NULL,
+    is_true INTEGER NOT NULL DEFAULT 1,
+    source_npc_id TEXT REFERENCES characters(id) ON DELETE SET NULL,
+    module_id TEXT REFERENCES modules(id) ON DELETE SET NULL,  -- quest the rumor leads into
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_rumors_campaign_id ON rumors(campaign_id);
+
+CREATE TABLE rumor_hearings (
+    rumor_id TEXT NOT NULL REFERENCES rumors(id) ON DELETE CASCADE,
+    player_id TEXT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    session_id TEXT REFERENCES game_sessions(id) ON DELETE SET NULL,
+    heard_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (rumor_id, player_id)
+);
+
+CREATE INDEX idx_rumor_hearings_player_id ON rumor_hearings(player_id);
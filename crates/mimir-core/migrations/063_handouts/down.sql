@@ -0,0 +1,4 @@
+-- Rollback handouts
+
+DROP TABLE IF EXISTS handout_reveals;
+DROP TABLE IF EXISTS handouts;
@@ -0,0 +1,30 @@
+-- Handouts
+-- Images and documents staged during prep and revealed to the player display
+-- one at a time. A handout leaves the queue once revealed; reveals record
+-- what players have seen, and in which session.
+
+CREATE TABLE handouts (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    -- Exactly one source: a catalog image, an uploaded image, or a document
+    image_path TEXT,  -- relative to the catalog images directory
+    asset_id TEXT REFERENCES campaign_assets(id) ON DELETE CASCADE,
+    document_id TEXT REFERENCES documents(id) ON DELETE CASCADE,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    revealed_at TEXT,  -- NULL while queued
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_handouts_campaign_id ON handouts(campaign_id);
+
+CREATE TABLE handout_reveals (
+    id TEXT PRIMARY KEY NOT NULL,
+    handout_id TEXT NOT NULL REFERENCES handouts(id) ON DELETE CASCADE,
+    session_id TEXT REFERENCES game_sessions(id) ON DELETE SET NULL,
+    revealed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_handout_reveals_handout_id ON handout_reveals(handout_id);
+CREATE INDEX idx_handout_reveals_session_id ON handout_reveals(session_id);
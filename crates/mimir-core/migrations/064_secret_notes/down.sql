@@ -0,0 +1,3 @@
+-- Rollback secret notes
+
+DROP TABLE IF EXISTS secret_notes;
@@ -0,0 +1,20 @@
+-- Secret Notes
+-- Notes meant for one player's eyes only, queued during prep and handed over
+-- at the table (usually as a printed slip). A note is outstanding until it
+-- is marked delivered, recorded against the session it was handed over in.
+
+CREATE TABLE secret_notes (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    player_id TEXT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    module_id TEXT REFERENCES modules(id) ON DELETE SET NULL,
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    delivered_at TEXT,  -- NULL while outstanding
+    session_id TEXT REFERENCES game_sessions(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_secret_notes_campaign_id ON secret_notes(campaign_id);
+CREATE INDEX idx_secret_notes_player_id ON secret_notes(player_id);
@@ -0,0 +1,5 @@
+-- Rollback NPC reactions
+
+DROP TABLE IF EXISTS npc_interactions;
+DROP TABLE IF EXISTS party_reputations;
+DROP TABLE IF EXISTS dispositions;
@@ -0,0 +1,56 @@
+-- NPC Reactions
+-- What a reaction check needs and what it leaves behind. A disposition is how
+-- an NPC, or everyone in a faction, regards outsiders, from -3 (hostile) to
+-- +3 (devoted); an NPC's own disposition wins over their faction's. A party's
+-- reputation with a faction (-3 to +3) adds to every check against its
+-- members. Each check is kept as an interaction in the NPC's relationship
+-- history.
+
+CREATE TABLE dispositions (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    character_id TEXT REFERENCES characters(id) ON DELETE CASCADE,
+    faction TEXT,
+    disposition INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    -- Either an NPC's or a faction's, never both
+    CHECK ((character_id IS NULL) <> (faction IS NULL))
+);
+
+CREATE INDEX idx_dispositions_campaign_id ON dispositions(campaign_id);
+CREATE UNIQUE INDEX idx_dispositions_character_id ON dispositions(character_id)
+    WHERE character_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_dispositions_faction ON dispositions(campaign_id, faction)
+    WHERE faction IS NOT NULL;
+
+CREATE TABLE party_reputations (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    party_id TEXT NOT NULL REFERENCES parties(id) ON DELETE CASCADE,
+    faction TEXT NOT NULL,
+    reputation INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (party_id, faction)
+);
+
+CREATE INDEX idx_party_reputations_campaign_id ON party_reputations(campaign_id);
+
+CREATE TABLE npc_interactions (
+    id TEXT PRIMARY KEY NOT NULL,
+    campaign_id TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    character_id TEXT NOT NULL REFERENCES characters(id) ON DELETE CASCADE,
+    party_id TEXT REFERENCES parties(id) ON DELETE SET NULL,
+    session_id TEXT REFERENCES game_sessions(id) ON DELETE SET NULL,
+    dice TEXT NOT NULL,       -- '2d6' or 'd20'
+    roll INTEGER NOT NULL,
+    modifier INTEGER NOT NULL,
+    total INTEGER NOT NULL,
+    attitude TEXT NOT NULL,   -- 'hostile', 'unfriendly', 'indifferent', 'friendly', 'helpful'
+    notes TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX idx_npc_interactions_campaign_id ON npc_interactions(campaign_id);
+CREATE INDEX idx_npc_interactions_character_id ON npc_interactions(character_id);
@@ -0,0 +1,76 @@
+//! CampaignMemory Data Access Layer
+//!
+//! Database operations for the facts remembered for each campaign.
+
+use crate::models::campaign::{CampaignMemory, NewCampaignMemory, UpdateCampaignMemory};
+use crate::schema::campaign_memories;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new campaign memory.
+pub fn insert_campaign_memory(
+    conn: &mut SqliteConnection,
+    memory: &NewCampaignMemory,
+) -> QueryResult<String> {
+    diesel::insert_into(campaign_memories::table)
+        .values(memory)
+        .execute(conn)?;
+
+    Ok(memory.id.to_string())
+}
+
+/// Get a campaign memory by ID.
+pub fn get_campaign_memory(conn: &mut SqliteConnection, id: &str) -> QueryResult<CampaignMemory> {
+    campaign_memories::table.find(id).first(conn)
+}
+
+/// Get a campaign memory by ID, returning None if not found.
+pub fn get_campaign_memory_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<CampaignMemory>> {
+    campaign_memories::table.find(id).first(conn).optional()
+}
+
+/// Find a campaign's memory by name.
+pub fn find_campaign_memory_by_name(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    name: &str,
+) -> QueryResult<Option<CampaignMemory>> {
+    campaign_memories::table
+        .filter(campaign_memories::campaign_id.eq(campaign_id))
+        .filter(campaign_memories::name.eq(name))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's memories, most recently updated first.
+pub fn list_campaign_memories(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<CampaignMemory>> {
+    campaign_memories::table
+        .filter(campaign_memories::campaign_id.eq(campaign_id))
+        .order((
+            campaign_memories::updated_at.desc(),
+            campaign_memories::name.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a campaign memory.
+pub fn update_campaign_memory(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCampaignMemory,
+) -> QueryResult<usize> {
+    diesel::update(campaign_memories::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a campaign memory by ID.
+pub fn delete_campaign_memory(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(campaign_memories::table.find(id)).execute(conn)
+}
@@ -0,0 +1,190 @@
+//! Campaign Stash Data Access Layer
+//!
+//! Database operations for each campaign's shared stash and its ledger.
+
+use crate::models::campaign::{
+    CampaignStashCurrency, CampaignStashItem, NewCampaignStashCurrency, NewCampaignStashItem,
+    NewStashLedgerEntry, StashLedgerEntry, UpdateCampaignStashItem,
+};
+use crate::schema::{campaign_stash_currency, campaign_stash_items, campaign_stash_ledger};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Currency
+// =============================================================================
+
+/// Get a campaign's stash coins, if anything has been deposited.
+pub fn get_campaign_stash_currency(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Option<CampaignStashCurrency>> {
+    campaign_stash_currency::table
+        .find(campaign_id)
+        .first(conn)
+        .optional()
+}
+
+/// Insert or replace a campaign's stash coins.
+pub fn upsert_campaign_stash_currency(
+    conn: &mut SqliteConnection,
+    currency: &NewCampaignStashCurrency,
+) -> QueryResult<usize> {
+    diesel::replace_into(campaign_stash_currency::table)
+        .values(currency)
+        .execute(conn)
+}
+
+// =============================================================================
+// Items
+// =============================================================================
+
+/// Insert a new stash item stack.
+pub fn insert_campaign_stash_item(
+    conn: &mut SqliteConnection,
+    item: &NewCampaignStashItem,
+) -> QueryResult<String> {
+    diesel::insert_into(campaign_stash_items::table)
+        .values(item)
+        .execute(conn)?;
+
+    Ok(item.id.to_string())
+}
+
+/// Get a stash item by ID.
+pub fn get_campaign_stash_item(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<CampaignStashItem> {
+    campaign_stash_items::table.find(id).first(conn)
+}
+
+/// Get a stash item by ID, returning None if not found.
+pub fn get_campaign_stash_item_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<CampaignStashItem>> {
+    campaign_stash_items::table.find(id).first(conn).optional()
+}
+
+/// Find the stack for an item in a campaign's stash.
+pub fn find_campaign_stash_item(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    item_name: &str,
+    item_source: &str,
+) -> QueryResult<Option<CampaignStashItem>> {
+    campaign_stash_items::table
+        .filter(campaign_stash_items::campaign_id.eq(campaign_id))
+        .filter(campaign_stash_items::item_name.eq(item_name))
+        .filter(campaign_stash_items::item_source.eq(item_source))
+        .first(conn)
+        .optional()
+}
+
+/// List the items in a campaign's stash, alphabetically.
+pub fn list_campaign_stash_items(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<CampaignStashItem>> {
+    campaign_stash_items::table
+        .filter(campaign_stash_items::campaign_id.eq(campaign_id))
+        .order(campaign_stash_items::item_name.asc())
+        .load(conn)
+}
+
+/// Update a stash item.
+pub fn update_campaign_stash_item(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCampaignStashItem,
+) -> QueryResult<usize> {
+    diesel::update(campaign_stash_items::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a stash item.
+pub fn delete_campaign_stash_item(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(campaign_stash_items::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Ledger
+// =============================================================================
+
+/// Record a ledger entry.
+pub fn insert_stash_ledger_entry(
+    conn: &mut SqliteConnection,
+    entry: &NewStashLedgerEntry,
+) -> QueryResult<String> {
+    diesel::insert_into(campaign_stash_ledger::table)
+        .values(entry)
+        .execute(conn)?;
+
+    Ok(entry.id.to_string())
+}
+
+/// List a campaign's ledger, newest first.
+pub fn list_stash_ledger(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<StashLedgerEntry>> {
+    campaign_stash_ledger::table
+        .filter(campaign_stash_ledger::campaign_id.eq(campaign_id))
+        .order((
+            campaign_stash_ledger::created_at.desc(),
+            campaign_stash_ledger::id.desc(),
+        ))
+        .load(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_character, insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{LedgerAction, NewCampaign, NewCharacter};
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_item_stacks_are_unique() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let rope = NewCampaignStashItem::new("item-1", "camp-1", "Rope, Hempen", "PHB", 2);
+        insert_campaign_stash_item(&mut conn, &rope).unwrap();
+        let duplicate = NewCampaignStashItem::new("item-2", "camp-1", "Rope, Hempen", "PHB", 1);
+        assert!(insert_campaign_stash_item(&mut conn, &duplicate).is_err());
+
+        let found = find_campaign_stash_item(&mut conn, "camp-1", "Rope, Hempen", "PHB")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.quantity, 2);
+    }
+
+    #[test]
+    fn test_ledger_keeps_name_after_character_deleted() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let entry = NewStashLedgerEntry::new("led-1", "camp-1", LedgerAction::Take)
+            .with_character("char-1", "Ada")
+            .with_item("Bag of Holding", "DMG", 1);
+        insert_stash_ledger_entry(&mut conn, &entry).unwrap();
+        delete_character(&mut conn, "char-1").unwrap();
+
+        let ledger = list_stash_ledger(&mut conn, "camp-1").unwrap();
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger[0].character_id, None);
+        assert_eq!(ledger[0].character_name.as_deref(), Some("Ada"));
+    }
+}
@@ -35,6 +35,7 @@ pub fn list_campaign_characters(
     campaign_id: &str,
 ) -> QueryResult<Vec<Character>> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .order(characters::name.asc())
         .load(conn)
@@ -43,6 +44,7 @@ pub fn list_campaign_characters(
 /// List all player characters for a campaign.
 pub fn list_pcs(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Character>> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .filter(characters::is_npc.eq(0))
         .order(characters::name.asc())
@@ -52,6 +54,7 @@ pub fn list_pcs(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<V
 /// List all unassigned player characters (no campaign).
 pub fn list_unassigned_pcs(conn: &mut SqliteConnection) -> QueryResult<Vec<Character>> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.is_null())
         .filter(characters::is_npc.eq(0))
         .order(characters::name.asc())
@@ -61,6 +64,7 @@ pub fn list_unassigned_pcs(conn: &mut SqliteConnection) -> QueryResult<Vec<Chara
 /// List all NPCs for a campaign.
 pub fn list_npcs(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Character>> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .filter(characters::is_npc.eq(1))
         .order(characters::name.asc())
@@ -74,6 +78,7 @@ pub fn list_npcs_by_location(
     location: &str,
 ) -> QueryResult<Vec<Character>> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .filter(characters::is_npc.eq(1))
         .filter(characters::location.eq(location))
@@ -88,6 +93,7 @@ pub fn list_npcs_by_faction(
     faction: &str,
 ) -> QueryResult<Vec<Character>> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .filter(characters::is_npc.eq(1))
         .filter(characters::faction.eq(faction))
@@ -111,6 +117,36 @@ pub fn delete_character(conn: &mut SqliteConnection, id: &str) -> QueryResult<us
     diesel::delete(characters::table.find(id)).execute(conn)
 }
 
+/// Move a character to the trash.
+pub fn trash_character(conn: &mut SqliteConnection, id: &str, deleted_at: &str) -> QueryResult<usize> {
+    diesel::update(characters::table.find(id).filter(characters::deleted_at.is_null()))
+        .set(characters::deleted_at.eq(Some(deleted_at)))
+        .execute(conn)
+}
+
+/// Restore a trashed character.
+pub fn restore_character(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::update(characters::table.find(id).filter(characters::deleted_at.is_not_null()))
+        .set(characters::deleted_at.eq(None::<String>))
+        .execute(conn)
+}
+
+/// List trashed characters, most recently trashed first.
+pub fn list_trashed_characters(
+    conn: &mut SqliteConnection,
+    campaign_id: Option<&str>,
+) -> QueryResult<Vec<Character>> {
+    let mut query = characters::table
+        .filter(characters::deleted_at.is_not_null())
+        .into_boxed();
+
+    if let Some(campaign_id) = campaign_id {
+        query = query.filter(characters::campaign_id.eq(campaign_id));
+    }
+
+    query.order(characters::deleted_at.desc()).load(conn)
+}
+
 /// Assign a character to a campaign.
 pub fn assign_character_to_campaign(
     conn: &mut SqliteConnection,
@@ -133,6 +169,7 @@ pub fn character_exists(conn: &mut SqliteConnection, id: &str) -> QueryResult<bo
 /// Count all characters for a campaign.
 pub fn count_campaign_characters(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<i64> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .count()
         .get_result(conn)
@@ -141,6 +178,7 @@ pub fn count_campaign_characters(conn: &mut SqliteConnection, campaign_id: &str)
 /// Count PCs for a campaign.
 pub fn count_pcs(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<i64> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .filter(characters::is_npc.eq(0))
         .count()
@@ -150,6 +188,7 @@ pub fn count_pcs(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<
 /// Count NPCs for a campaign.
 pub fn count_npcs(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<i64> {
     characters::table
+        .filter(characters::deleted_at.is_null())
         .filter(characters::campaign_id.eq(campaign_id))
         .filter(characters::is_npc.eq(1))
         .count()
@@ -0,0 +1,135 @@
+//! CharacterCompanion Data Access Layer
+//!
+//! Database operations for characters' companion creatures.
+
+use crate::models::campaign::{
+    CharacterCompanion, NewCharacterCompanion, UpdateCharacterCompanion,
+};
+use crate::schema::character_companions;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new companion.
+pub fn insert_character_companion(
+    conn: &mut SqliteConnection,
+    companion: &NewCharacterCompanion,
+) -> QueryResult<String> {
+    diesel::insert_into(character_companions::table)
+        .values(companion)
+        .execute(conn)?;
+
+    Ok(companion.id.to_string())
+}
+
+/// Get a companion by ID.
+pub fn get_character_companion(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<CharacterCompanion> {
+    character_companions::table.find(id).first(conn)
+}
+
+/// Get a companion by ID, returning None if not found.
+pub fn get_character_companion_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<CharacterCompanion>> {
+    character_companions::table.find(id).first(conn).optional()
+}
+
+/// List a character's companions, alphabetically.
+pub fn list_character_companions(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<CharacterCompanion>> {
+    character_companions::table
+        .filter(character_companions::character_id.eq(character_id))
+        .order(character_companions::name.asc())
+        .load(conn)
+}
+
+/// List the companions of several characters (e.g., a party's members).
+pub fn list_companions_for_characters(
+    conn: &mut SqliteConnection,
+    character_ids: &[&str],
+) -> QueryResult<Vec<CharacterCompanion>> {
+    character_companions::table
+        .filter(character_companions::character_id.eq_any(character_ids))
+        .order((
+            character_companions::character_id.asc(),
+            character_companions::name.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a companion.
+pub fn update_character_companion(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCharacterCompanion,
+) -> QueryResult<usize> {
+    diesel::update(character_companions::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a companion.
+pub fn delete_character_companion(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(character_companions::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_character, insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{CompanionType, NewCampaign, NewCharacter};
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        for (id, name) in [("char-1", "Ada"), ("char-2", "Brom")] {
+            insert_character(
+                conn,
+                &NewCharacter::new_pc(id, Some("camp-1"), name, "Alice"),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_list_companions_for_characters() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        for (id, owner, name) in [
+            ("comp-1", "char-1", "Whiskers"),
+            ("comp-2", "char-1", "Ash"),
+            ("comp-3", "char-2", "Grip"),
+        ] {
+            let companion = NewCharacterCompanion::new(id, owner, name, CompanionType::Familiar, 1);
+            insert_character_companion(&mut conn, &companion).unwrap();
+        }
+
+        let own = list_character_companions(&mut conn, "char-1").unwrap();
+        let names: Vec<&str> = own.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Ash", "Whiskers"]);
+
+        let all = list_companions_for_characters(&mut conn, &["char-1", "char-2"]).unwrap();
+        assert_eq!(all.len(), 3);
+    }
+
+    #[test]
+    fn test_companions_removed_with_character() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let companion =
+            NewCharacterCompanion::new("comp-1", "char-1", "Fang", CompanionType::Beast, 13);
+        insert_character_companion(&mut conn, &companion).unwrap();
+        delete_character(&mut conn, "char-1").unwrap();
+
+        assert!(get_character_companion_optional(&mut conn, "comp-1")
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! CharacterDeath Data Access Layer
+//!
+//! Database operations for character deaths and resurrections.
+
+use crate::models::campaign::{Character, CharacterDeath, NewCharacterDeath, UpdateCharacterDeath};
+use crate::schema::{character_deaths, characters};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Record a character death.
+pub fn insert_character_death(
+    conn: &mut SqliteConnection,
+    death: &NewCharacterDeath,
+) -> QueryResult<String> {
+    diesel::insert_into(character_deaths::table)
+        .values(death)
+        .execute(conn)?;
+
+    Ok(death.id.to_string())
+}
+
+/// Get a death by ID.
+pub fn get_character_death(conn: &mut SqliteConnection, id: &str) -> QueryResult<CharacterDeath> {
+    character_deaths::table.find(id).first(conn)
+}
+
+/// List a character's deaths, oldest first.
+pub fn list_character_deaths(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<CharacterDeath>> {
+    character_deaths::table
+        .filter(character_deaths::character_id.eq(character_id))
+        .order((character_deaths::died_at.asc(), character_deaths::id.asc()))
+        .load(conn)
+}
+
+/// Get the death a character hasn't been resurrected from, if any.
+pub fn get_open_character_death(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Option<CharacterDeath>> {
+    character_deaths::table
+        .filter(character_deaths::character_id.eq(character_id))
+        .filter(character_deaths::resurrected_at.is_null())
+        .order(character_deaths::died_at.desc())
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's dead characters (excluding trashed ones), alphabetically.
+pub fn list_campaign_dead_characters(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<Character>> {
+    characters::table
+        .filter(characters::campaign_id.eq(campaign_id))
+        .filter(characters::is_dead.eq(true))
+        .filter(characters::deleted_at.is_null())
+        .order(characters::name.asc())
+        .select(Character::as_select())
+        .load(conn)
+}
+
+/// Update a death (record a resurrection).
+pub fn update_character_death(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCharacterDeath,
+) -> QueryResult<usize> {
+    diesel::update(character_deaths::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, update_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter, UpdateCharacter};
+
+    #[test]
+    fn test_open_death_and_resurrection() {
+        let mut conn = test_connection();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            &mut conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+
+        let death = NewCharacterDeath::new("d-1", "char-1", "Poisoned");
+        insert_character_death(&mut conn, &death).unwrap();
+        let update = UpdateCharacter {
+            is_dead: Some(true),
+            ..Default::default()
+        };
+        update_character(&mut conn, "char-1", &update).unwrap();
+
+        let open = get_open_character_death(&mut conn, "char-1").unwrap();
+        assert_eq!(open.map(|d| d.id), Some("d-1".to_string()));
+        let dead = list_campaign_dead_characters(&mut conn, "camp-1").unwrap();
+        assert_eq!(dead.len(), 1);
+
+        let update = UpdateCharacterDeath {
+            resurrection_method: Some(Some("Revivify")),
+            resurrected_at: Some(Some("2024-01-01T00:00:00Z")),
+            ..Default::default()
+        };
+        update_character_death(&mut conn, "d-1", &update).unwrap();
+        assert!(get_open_character_death(&mut conn, "char-1")
+            .unwrap()
+            .is_none());
+        assert_eq!(list_character_deaths(&mut conn, "char-1").unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,173 @@
+//! CharacterResource Data Access Layer
+//!
+//! Database operations for characters' limited-use class resources.
+
+use crate::models::campaign::{CharacterResource, NewCharacterResource, UpdateCharacterResource};
+use crate::schema::character_resources;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new character resource.
+pub fn insert_character_resource(
+    conn: &mut SqliteConnection,
+    resource: &NewCharacterResource,
+) -> QueryResult<String> {
+    diesel::insert_into(character_resources::table)
+        .values(resource)
+        .execute(conn)?;
+
+    Ok(resource.id.to_string())
+}
+
+/// Get a character resource by ID.
+pub fn get_character_resource(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<CharacterResource> {
+    character_resources::table.find(id).first(conn)
+}
+
+/// Get a character resource by ID, returning None if not found.
+pub fn get_character_resource_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<CharacterResource>> {
+    character_resources::table.find(id).first(conn).optional()
+}
+
+/// List a character's resources, grouped by class.
+pub fn list_character_resources(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<CharacterResource>> {
+    character_resources::table
+        .filter(character_resources::character_id.eq(character_id))
+        .order((
+            character_resources::source_class.asc(),
+            character_resources::resource_name.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a character resource.
+pub fn update_character_resource(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCharacterResource,
+) -> QueryResult<usize> {
+    diesel::update(character_resources::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Restore every use of a character's resources with the given recharge types.
+pub fn restore_character_resources(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+    recharges: &[&str],
+    updated_at: &str,
+) -> QueryResult<usize> {
+    diesel::update(
+        character_resources::table
+            .filter(character_resources::character_id.eq(character_id))
+            .filter(character_resources::recharge.eq_any(recharges))
+            .filter(character_resources::current_uses.lt(character_resources::max_uses)),
+    )
+    .set((
+        character_resources::current_uses.eq(character_resources::max_uses),
+        character_resources::updated_at.eq(updated_at),
+    ))
+    .execute(conn)
+}
+
+/// Delete a character resource.
+pub fn delete_character_resource(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(character_resources::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_character, insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter, RechargeType};
+
+    const NOW: &str = "2024-01-01T00:00:00Z";
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_restore_by_recharge() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let ki = NewCharacterResource::new(
+            "res-1",
+            "char-1",
+            "Ki Points",
+            "Monk",
+            5,
+            RechargeType::ShortRest,
+        )
+        .with_current_uses(1);
+        insert_character_resource(&mut conn, &ki).unwrap();
+        let indomitable = NewCharacterResource::new(
+            "res-2",
+            "char-1",
+            "Indomitable",
+            "Fighter",
+            1,
+            RechargeType::LongRest,
+        )
+        .with_current_uses(0);
+        insert_character_resource(&mut conn, &indomitable).unwrap();
+
+        let restored =
+            restore_character_resources(&mut conn, "char-1", &["short_rest"], NOW).unwrap();
+        assert_eq!(restored, 1);
+        assert_eq!(
+            get_character_resource(&mut conn, "res-1")
+                .unwrap()
+                .current_uses,
+            5
+        );
+        assert_eq!(
+            get_character_resource(&mut conn, "res-2")
+                .unwrap()
+                .current_uses,
+            0
+        );
+
+        let resources = list_character_resources(&mut conn, "char-1").unwrap();
+        let names: Vec<&str> = resources.iter().map(|r| r.resource_name.as_str()).collect();
+        assert_eq!(names, vec!["Indomitable", "Ki Points"]);
+    }
+
+    #[test]
+    fn test_resources_removed_with_character() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let rage = NewCharacterResource::new(
+            "res-1",
+            "char-1",
+            "Rage",
+            "Barbarian",
+            2,
+            RechargeType::LongRest,
+        );
+        insert_character_resource(&mut conn, &rage).unwrap();
+        delete_character(&mut conn, "char-1").unwrap();
+
+        assert!(get_character_resource_optional(&mut conn, "res-1")
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! CraftingProject Data Access Layer
+//!
+//! Database operations for characters' downtime crafting projects.
+
+use crate::models::campaign::{CraftingProject, NewCraftingProject, UpdateCraftingProject};
+use crate::schema::crafting_projects;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new crafting project.
+pub fn insert_crafting_project(
+    conn: &mut SqliteConnection,
+    project: &NewCraftingProject,
+) -> QueryResult<String> {
+    diesel::insert_into(crafting_projects::table)
+        .values(project)
+        .execute(conn)?;
+
+    Ok(project.id.to_string())
+}
+
+/// Get a crafting project by ID.
+pub fn get_crafting_project(conn: &mut SqliteConnection, id: &str) -> QueryResult<CraftingProject> {
+    crafting_projects::table.find(id).first(conn)
+}
+
+/// Get a crafting project by ID, returning None if not found.
+pub fn get_crafting_project_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<CraftingProject>> {
+    crafting_projects::table.find(id).first(conn).optional()
+}
+
+/// List a character's crafting projects, oldest first.
+pub fn list_character_crafting_projects(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<CraftingProject>> {
+    crafting_projects::table
+        .filter(crafting_projects::character_id.eq(character_id))
+        .order((
+            crafting_projects::created_at.asc(),
+            crafting_projects::id.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a crafting project.
+pub fn update_crafting_project(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCraftingProject,
+) -> QueryResult<usize> {
+    diesel::update(crafting_projects::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a crafting project.
+pub fn delete_crafting_project(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(crafting_projects::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_character, insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_crafting_project_crud() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let project = NewCraftingProject::new("p-1", "char-1", "Longsword", "PHB", 750, 2)
+            .with_tool("Smith's Tools");
+        insert_crafting_project(&mut conn, &project).unwrap();
+
+        let update = UpdateCraftingProject {
+            days_spent: Some(1),
+            ..Default::default()
+        };
+        update_crafting_project(&mut conn, "p-1", &update).unwrap();
+
+        let fetched = get_crafting_project(&mut conn, "p-1").unwrap();
+        assert_eq!(fetched.days_spent, 1);
+        assert_eq!(fetched.days_remaining(), 1);
+        assert_eq!(fetched.tool.as_deref(), Some("Smith's Tools"));
+
+        delete_crafting_project(&mut conn, "p-1").unwrap();
+        assert!(get_crafting_project_optional(&mut conn, "p-1")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_projects_deleted_with_character() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let project = NewCraftingProject::new("p-1", "char-1", "Longsword", "PHB", 750, 2);
+        insert_crafting_project(&mut conn, &project).unwrap();
+        delete_character(&mut conn, "char-1").unwrap();
+
+        assert!(list_character_crafting_projects(&mut conn, "char-1")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+//! CurrencyTransaction Data Access Layer
+//!
+//! Database operations for the ledger of characters' coin changes.
+
+use crate::models::campaign::{CurrencyTransaction, NewCurrencyTransaction};
+use crate::schema::currency_transactions;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Record a currency transaction.
+pub fn insert_currency_transaction(
+    conn: &mut SqliteConnection,
+    transaction: &NewCurrencyTransaction,
+) -> QueryResult<String> {
+    diesel::insert_into(currency_transactions::table)
+        .values(transaction)
+        .execute(conn)?;
+
+    Ok(transaction.id.to_string())
+}
+
+/// Get a currency transaction by ID.
+pub fn get_currency_transaction(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<CurrencyTransaction> {
+    currency_transactions::table.find(id).first(conn)
+}
+
+/// List a character's currency transactions, newest first.
+pub fn list_character_currency_transactions(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<CurrencyTransaction>> {
+    currency_transactions::table
+        .filter(currency_transactions::character_id.eq(character_id))
+        .order((
+            currency_transactions::created_at.desc(),
+            currency_transactions::id.desc(),
+        ))
+        .load(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+
+    #[test]
+    fn test_list_newest_first() {
+        let mut conn = test_connection();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            &mut conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+
+        let reward =
+            NewCurrencyTransaction::new("tx-1", "char-1", "Quest reward", [0, 0, 0, 50, 0])
+                .with_created_at("2024-01-01T00:00:00Z");
+        insert_currency_transaction(&mut conn, &reward).unwrap();
+        let inn = NewCurrencyTransaction::new("tx-2", "char-1", "Inn", [0, -5, 0, 0, 0])
+            .with_created_at("2024-01-02T00:00:00Z");
+        insert_currency_transaction(&mut conn, &inn).unwrap();
+
+        let history = list_character_currency_transactions(&mut conn, "char-1").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].reason, "Inn");
+        assert_eq!(history[0].amounts(), [0, -5, 0, 0, 0]);
+        assert_eq!(history[1].gp, 50);
+    }
+}
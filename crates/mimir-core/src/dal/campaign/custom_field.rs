@@ -0,0 +1,265 @@
+//! Custom Field Data Access Layer
+//!
+//! Database operations for campaign custom field definitions and the values
+//! characters hold for them.
+
+use crate::models::campaign::{
+    CampaignCustomField, CharacterCustomField, NewCampaignCustomField, NewCharacterCustomField,
+    UpdateCampaignCustomField,
+};
+use crate::schema::{campaign_custom_fields, character_custom_fields, characters};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Field Definitions
+// =============================================================================
+
+/// Insert a new custom field definition.
+pub fn insert_campaign_custom_field(
+    conn: &mut SqliteConnection,
+    field: &NewCampaignCustomField,
+) -> QueryResult<String> {
+    diesel::insert_into(campaign_custom_fields::table)
+        .values(field)
+        .execute(conn)?;
+
+    Ok(field.id.to_string())
+}
+
+/// Get a custom field definition by ID.
+pub fn get_campaign_custom_field(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<CampaignCustomField> {
+    campaign_custom_fields::table.find(id).first(conn)
+}
+
+/// Get a campaign's custom field definition by key.
+pub fn get_campaign_custom_field_by_key(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    field_key: &str,
+) -> QueryResult<Option<CampaignCustomField>> {
+    campaign_custom_fields::table
+        .filter(campaign_custom_fields::campaign_id.eq(campaign_id))
+        .filter(campaign_custom_fields::field_key.eq(field_key))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's custom field definitions in sheet order.
+pub fn list_campaign_custom_fields(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<CampaignCustomField>> {
+    campaign_custom_fields::table
+        .filter(campaign_custom_fields::campaign_id.eq(campaign_id))
+        .order((
+            campaign_custom_fields::sort_order.asc(),
+            campaign_custom_fields::created_at.asc(),
+        ))
+        .load(conn)
+}
+
+/// Get the next available sort_order for a campaign's custom fields.
+pub fn next_campaign_custom_field_sort_order(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<i32> {
+    let max: Option<i32> = campaign_custom_fields::table
+        .filter(campaign_custom_fields::campaign_id.eq(campaign_id))
+        .select(diesel::dsl::max(campaign_custom_fields::sort_order))
+        .first(conn)?;
+
+    Ok(max.unwrap_or(0) + 1)
+}
+
+/// Update a custom field definition.
+pub fn update_campaign_custom_field(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateCampaignCustomField,
+) -> QueryResult<usize> {
+    diesel::update(campaign_custom_fields::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a custom field definition. Character values are left to the caller.
+pub fn delete_campaign_custom_field(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(campaign_custom_fields::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Character Values
+// =============================================================================
+
+/// Set a character's value for a custom field, replacing any existing value.
+pub fn upsert_character_custom_field(
+    conn: &mut SqliteConnection,
+    value: &NewCharacterCustomField,
+) -> QueryResult<usize> {
+    diesel::replace_into(character_custom_fields::table)
+        .values(value)
+        .execute(conn)
+}
+
+/// List a character's custom field values.
+pub fn list_character_custom_fields(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<CharacterCustomField>> {
+    character_custom_fields::table
+        .filter(character_custom_fields::character_id.eq(character_id))
+        .order(character_custom_fields::field_key.asc())
+        .load(conn)
+}
+
+/// Remove a character's value for a custom field.
+pub fn delete_character_custom_field(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+    field_key: &str,
+) -> QueryResult<usize> {
+    diesel::delete(character_custom_fields::table.find((character_id, field_key))).execute(conn)
+}
+
+/// Remove every value stored under a key for a campaign's characters.
+pub fn delete_campaign_custom_field_values(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    field_key: &str,
+) -> QueryResult<usize> {
+    let campaign_characters = characters::table
+        .filter(characters::campaign_id.eq(campaign_id))
+        .select(characters::id);
+
+    diesel::delete(
+        character_custom_fields::table
+            .filter(character_custom_fields::field_key.eq(field_key))
+            .filter(character_custom_fields::character_id.eq_any(campaign_characters)),
+    )
+    .execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_character, insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{CustomFieldType, NewCampaign, NewCharacter};
+
+    const NOW: &str = "2024-01-01T00:00:00Z";
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_field_definitions_in_sort_order() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let renown = NewCampaignCustomField::new(
+            "field-2",
+            "camp-1",
+            "renown",
+            "Renown",
+            CustomFieldType::Number,
+        )
+        .with_sort_order(2);
+        insert_campaign_custom_field(&mut conn, &renown).unwrap();
+        let sanity = NewCampaignCustomField::new(
+            "field-1",
+            "camp-1",
+            "sanity",
+            "Sanity",
+            CustomFieldType::Number,
+        )
+        .with_sort_order(1);
+        insert_campaign_custom_field(&mut conn, &sanity).unwrap();
+
+        let fields = list_campaign_custom_fields(&mut conn, "camp-1").unwrap();
+        let keys: Vec<&str> = fields.iter().map(|f| f.field_key.as_str()).collect();
+        assert_eq!(keys, vec!["sanity", "renown"]);
+        assert_eq!(
+            next_campaign_custom_field_sort_order(&mut conn, "camp-1").unwrap(),
+            3
+        );
+
+        let found = get_campaign_custom_field_by_key(&mut conn, "camp-1", "renown").unwrap();
+        assert_eq!(found.unwrap().id, "field-2");
+
+        // Keys are unique per campaign
+        let duplicate = NewCampaignCustomField::new(
+            "field-3",
+            "camp-1",
+            "sanity",
+            "Sanity",
+            CustomFieldType::Text,
+        );
+        assert!(insert_campaign_custom_field(&mut conn, &duplicate).is_err());
+    }
+
+    #[test]
+    fn test_character_values() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        upsert_character_custom_field(
+            &mut conn,
+            &NewCharacterCustomField::new("char-1", "sanity", "50", NOW),
+        )
+        .unwrap();
+        upsert_character_custom_field(
+            &mut conn,
+            &NewCharacterCustomField::new("char-1", "sanity", "45", NOW),
+        )
+        .unwrap();
+        upsert_character_custom_field(
+            &mut conn,
+            &NewCharacterCustomField::new("char-1", "renown", "3", NOW),
+        )
+        .unwrap();
+
+        let values = list_character_custom_fields(&mut conn, "char-1").unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1].field_key, "sanity");
+        assert_eq!(values[1].value, "45");
+
+        assert_eq!(
+            delete_campaign_custom_field_values(&mut conn, "camp-1", "renown").unwrap(),
+            1
+        );
+        assert_eq!(
+            delete_character_custom_field(&mut conn, "char-1", "sanity").unwrap(),
+            1
+        );
+        assert!(list_character_custom_fields(&mut conn, "char-1")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_values_removed_with_character() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        upsert_character_custom_field(
+            &mut conn,
+            &NewCharacterCustomField::new("char-1", "sanity", "50", NOW),
+        )
+        .unwrap();
+        delete_character(&mut conn, "char-1").unwrap();
+
+        assert!(list_character_custom_fields(&mut conn, "char-1")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+//! DmReferencePin Data Access Layer
+//!
+//! Database operations for panels pinned to the DM reference window.
+
+use crate::models::campaign::{DmReferencePin, NewDmReferencePin, UpdateDmReferencePin};
+use crate::schema::dm_reference_pins;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new pin.
+pub fn insert_dm_reference_pin(
+    conn: &mut SqliteConnection,
+    pin: &NewDmReferencePin,
+) -> QueryResult<String> {
+    diesel::insert_into(dm_reference_pins::table)
+        .values(pin)
+        .execute(conn)?;
+
+    Ok(pin.id.to_string())
+}
+
+/// Get a pin by ID.
+pub fn get_dm_reference_pin(conn: &mut SqliteConnection, id: &str) -> QueryResult<DmReferencePin> {
+    dm_reference_pins::table.find(id).first(conn)
+}
+
+/// List all pins for a campaign in display order.
+pub fn list_dm_reference_pins(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<DmReferencePin>> {
+    dm_reference_pins::table
+        .filter(dm_reference_pins::campaign_id.eq(campaign_id))
+        .order((
+            dm_reference_pins::sort_order.asc(),
+            dm_reference_pins::created_at.asc(),
+        ))
+        .load(conn)
+}
+
+/// Get the next available sort_order for a campaign's pins.
+pub fn next_dm_reference_pin_sort_order(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<i32> {
+    let max: Option<i32> = dm_reference_pins::table
+        .filter(dm_reference_pins::campaign_id.eq(campaign_id))
+        .select(diesel::dsl::max(dm_reference_pins::sort_order))
+        .first(conn)?;
+
+    Ok(max.unwrap_or(0) + 1)
+}
+
+/// Update a pin.
+pub fn update_dm_reference_pin(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateDmReferencePin,
+) -> QueryResult<usize> {
+    diesel::update(dm_reference_pins::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a pin by ID.
+pub fn delete_dm_reference_pin(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(dm_reference_pins::table.find(id)).execute(conn)
+}
+
+/// Delete all pins for a campaign.
+pub fn delete_all_dm_reference_pins(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<usize> {
+    diesel::delete(
+        dm_reference_pins::table.filter(dm_reference_pins::campaign_id.eq(campaign_id)),
+    )
+    .execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, PanelType};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+    }
+
+    #[test]
+    fn test_insert_and_get_pin() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let pin = NewDmReferencePin::new("pin-1", "camp-1", PanelType::Rules, "Grappling")
+            .with_ref("Grappled", Some("PHB"))
+            .with_section("condition");
+        insert_dm_reference_pin(&mut conn, &pin).expect("Failed to insert");
+
+        let retrieved = get_dm_reference_pin(&mut conn, "pin-1").expect("Failed to get");
+        assert_eq!(retrieved.title, "Grappling");
+        assert_eq!(retrieved.panel_type, "rules");
+        assert_eq!(retrieved.section, Some("condition".to_string()));
+    }
+
+    #[test]
+    fn test_list_in_sort_order() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        assert_eq!(next_dm_reference_pin_sort_order(&mut conn, "camp-1").unwrap(), 1);
+
+        let second = NewDmReferencePin::new("pin-2", "camp-1", PanelType::Npc, "Innkeeper")
+            .with_sort_order(2);
+        let first = NewDmReferencePin::new("pin-1", "camp-1", PanelType::Monster, "Goblin")
+            .with_sort_order(1);
+        insert_dm_reference_pin(&mut conn, &second).expect("Failed to insert");
+        insert_dm_reference_pin(&mut conn, &first).expect("Failed to insert");
+
+        let pins = list_dm_reference_pins(&mut conn, "camp-1").expect("Failed to list");
+        assert_eq!(pins.len(), 2);
+        assert_eq!(pins[0].id, "pin-1");
+        assert_eq!(pins[1].id, "pin-2");
+
+        assert_eq!(next_dm_reference_pin_sort_order(&mut conn, "camp-1").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_update_and_delete_pin() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let pin = NewDmReferencePin::new("pin-1", "camp-1", PanelType::Rules, "Cover");
+        insert_dm_reference_pin(&mut conn, &pin).expect("Failed to insert");
+
+        let update = UpdateDmReferencePin {
+            content: Some(Some("Half cover: +2 AC")),
+            updated_at: Some("2024-01-20T12:00:00Z"),
+            ..Default::default()
+        };
+        update_dm_reference_pin(&mut conn, "pin-1", &update).expect("Failed to update");
+        let retrieved = get_dm_reference_pin(&mut conn, "pin-1").expect("Failed to get");
+        assert_eq!(retrieved.content, Some("Half cover: +2 AC".to_string()));
+
+        assert_eq!(delete_dm_reference_pin(&mut conn, "pin-1").unwrap(), 1);
+        assert!(list_dm_reference_pins(&mut conn, "camp-1").unwrap().is_empty());
+    }
+}
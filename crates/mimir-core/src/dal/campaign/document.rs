@@ -38,6 +38,7 @@ pub fn list_campaign_documents(
     campaign_id: &str,
 ) -> QueryResult<Vec<Document>> {
     documents::table
+        .filter(documents::deleted_at.is_null())
         .filter(documents::campaign_id.eq(campaign_id))
         .order((documents::sort_order.asc(), documents::title.asc()))
         .load(conn)
@@ -49,6 +50,7 @@ pub fn list_campaign_level_documents(
     campaign_id: &str,
 ) -> QueryResult<Vec<Document>> {
     documents::table
+        .filter(documents::deleted_at.is_null())
         .filter(documents::campaign_id.eq(campaign_id))
         .filter(documents::module_id.is_null())
         .order((documents::sort_order.asc(), documents::title.asc()))
@@ -61,6 +63,7 @@ pub fn list_module_documents(
     module_id: &str,
 ) -> QueryResult<Vec<Document>> {
     documents::table
+        .filter(documents::deleted_at.is_null())
         .filter(documents::module_id.eq(module_id))
         .order((documents::sort_order.asc(), documents::title.asc()))
         .load(conn)
@@ -73,6 +76,7 @@ pub fn list_documents_by_type(
     doc_type: &str,
 ) -> QueryResult<Vec<Document>> {
     documents::table
+        .filter(documents::deleted_at.is_null())
         .filter(documents::campaign_id.eq(campaign_id))
         .filter(documents::doc_type.eq(doc_type))
         .order((documents::sort_order.asc(), documents::title.asc()))
@@ -90,11 +94,52 @@ pub fn update_document(
         .execute(conn)
 }
 
+/// Set a document's sort_order.
+pub fn set_document_sort_order(
+    conn: &mut SqliteConnection,
+    id: &str,
+    sort_order: i32,
+) -> QueryResult<usize> {
+    diesel::update(documents::table.find(id))
+        .set(documents::sort_order.eq(sort_order))
+        .execute(conn)
+}
+
 /// Delete a document by ID.
 pub fn delete_document(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
     diesel::delete(documents::table.find(id)).execute(conn)
 }
 
+/// Move a document to the trash.
+pub fn trash_document(conn: &mut SqliteConnection, id: &str, deleted_at: &str) -> QueryResult<usize> {
+    diesel::update(documents::table.find(id).filter(documents::deleted_at.is_null()))
+        .set(documents::deleted_at.eq(Some(deleted_at)))
+        .execute(conn)
+}
+
+/// Restore a trashed document.
+pub fn restore_document(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::update(documents::table.find(id).filter(documents::deleted_at.is_not_null()))
+        .set(documents::deleted_at.eq(None::<String>))
+        .execute(conn)
+}
+
+/// List trashed documents, most recently trashed first.
+pub fn list_trashed_documents(
+    conn: &mut SqliteConnection,
+    campaign_id: Option<&str>,
+) -> QueryResult<Vec<Document>> {
+    let mut query = documents::table
+        .filter(documents::deleted_at.is_not_null())
+        .into_boxed();
+
+    if let Some(campaign_id) = campaign_id {
+        query = query.filter(documents::campaign_id.eq(campaign_id));
+    }
+
+    query.order(documents::deleted_at.desc()).load(conn)
+}
+
 /// Check if a document exists.
 pub fn document_exists(conn: &mut SqliteConnection, id: &str) -> QueryResult<bool> {
     use diesel::dsl::exists;
@@ -106,6 +151,7 @@ pub fn document_exists(conn: &mut SqliteConnection, id: &str) -> QueryResult<boo
 /// Count documents for a campaign.
 pub fn count_campaign_documents(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<i64> {
     documents::table
+        .filter(documents::deleted_at.is_null())
         .filter(documents::campaign_id.eq(campaign_id))
         .count()
         .get_result(conn)
@@ -114,6 +160,7 @@ pub fn count_campaign_documents(conn: &mut SqliteConnection, campaign_id: &str)
 /// Count documents for a module.
 pub fn count_module_documents(conn: &mut SqliteConnection, module_id: &str) -> QueryResult<i64> {
     documents::table
+        .filter(documents::deleted_at.is_null())
         .filter(documents::module_id.eq(module_id))
         .count()
         .get_result(conn)
@@ -213,6 +260,7 @@ pub fn search_documents(
         FROM documents d
         JOIN documents_fts fts ON d.rowid = fts.rowid
         WHERE d.campaign_id = ?
+          AND d.deleted_at IS NULL
           AND documents_fts MATCH ?
         ORDER BY bm25(documents_fts) ASC
         LIMIT 50
@@ -235,6 +283,7 @@ pub fn search_module_documents(
         FROM documents d
         JOIN documents_fts fts ON d.rowid = fts.rowid
         WHERE d.module_id = ?
+          AND d.deleted_at IS NULL
           AND documents_fts MATCH ?
         ORDER BY bm25(documents_fts) ASC
         LIMIT 50
@@ -393,6 +442,30 @@ mod tests {
         assert!(!document_exists(&mut conn, "doc-1").expect("Failed to check"));
     }
 
+    #[test]
+    fn test_trash_and_restore_document() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let doc = NewDocument::for_campaign("doc-1", "camp-1", "Notes", "note");
+        insert_document(&mut conn, &doc).expect("Failed to insert");
+
+        assert_eq!(trash_document(&mut conn, "doc-1", "2024-01-20T12:00:00Z").unwrap(), 1);
+        // Trashing twice is a no-op
+        assert_eq!(trash_document(&mut conn, "doc-1", "2024-01-21T12:00:00Z").unwrap(), 0);
+
+        assert!(list_campaign_documents(&mut conn, "camp-1").unwrap().is_empty());
+        assert_eq!(count_campaign_documents(&mut conn, "camp-1").unwrap(), 0);
+        let trashed = list_trashed_documents(&mut conn, Some("camp-1")).unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].deleted_at.as_deref(), Some("2024-01-20T12:00:00Z"));
+
+        assert_eq!(restore_document(&mut conn, "doc-1").unwrap(), 1);
+        assert_eq!(restore_document(&mut conn, "doc-1").unwrap(), 0);
+        assert_eq!(list_campaign_documents(&mut conn, "camp-1").unwrap().len(), 1);
+        assert!(list_trashed_documents(&mut conn, None).unwrap().is_empty());
+    }
+
     #[test]
     fn test_count_documents() {
         let mut conn = test_connection();
@@ -0,0 +1,129 @@
+//! EncounterEffect Data Access Layer
+//!
+//! Database operations for the environmental effects in module encounters.
+
+use crate::models::campaign::{EncounterEffect, NewEncounterEffect, UpdateEncounterEffect};
+use crate::schema::encounter_effects;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new encounter effect.
+pub fn insert_encounter_effect(
+    conn: &mut SqliteConnection,
+    effect: &NewEncounterEffect,
+) -> QueryResult<String> {
+    diesel::insert_into(encounter_effects::table)
+        .values(effect)
+        .execute(conn)?;
+
+    Ok(effect.id.to_string())
+}
+
+/// Get an encounter effect by ID.
+pub fn get_encounter_effect(conn: &mut SqliteConnection, id: &str) -> QueryResult<EncounterEffect> {
+    encounter_effects::table.find(id).first(conn)
+}
+
+/// Get an encounter effect by ID, returning None if not found.
+pub fn get_encounter_effect_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<EncounterEffect>> {
+    encounter_effects::table.find(id).first(conn).optional()
+}
+
+/// List a module's encounter effects in the order they were added.
+pub fn list_encounter_effects(
+    conn: &mut SqliteConnection,
+    module_id: &str,
+) -> QueryResult<Vec<EncounterEffect>> {
+    encounter_effects::table
+        .filter(encounter_effects::module_id.eq(module_id))
+        .order((
+            encounter_effects::created_at.asc(),
+            encounter_effects::id.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update an encounter effect.
+pub fn update_encounter_effect(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateEncounterEffect,
+) -> QueryResult<usize> {
+    diesel::update(encounter_effects::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete an encounter effect by ID.
+pub fn delete_encounter_effect(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(encounter_effects::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_module, insert_campaign, insert_module};
+    use crate::db::test_connection;
+    use crate::models::campaign::{EffectTiming, NewCampaign, NewModule};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        let module = NewModule::new("mod-1", "camp-1", "Dragon's Lair", 1);
+        insert_module(conn, &module).expect("Failed to create module");
+    }
+
+    #[test]
+    fn test_insert_update_and_list() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let lair = NewEncounterEffect::new(
+            "fx-1",
+            "mod-1",
+            "Lair Action",
+            EffectTiming::Initiative,
+            Some(20),
+        );
+        insert_encounter_effect(&mut conn, &lair).expect("Failed to insert");
+        let collapse =
+            NewEncounterEffect::new("fx-2", "mod-1", "Collapse", EffectTiming::Round, Some(3));
+        insert_encounter_effect(&mut conn, &collapse).expect("Failed to insert");
+
+        let update = UpdateEncounterEffect {
+            timing_value: Some(Some(4)),
+            description: Some("The ceiling gives way."),
+            ..Default::default()
+        };
+        update_encounter_effect(&mut conn, "fx-2", &update).expect("Failed to update");
+
+        let effects = list_encounter_effects(&mut conn, "mod-1").expect("Failed to list");
+        assert_eq!(effects.len(), 2);
+        let collapse = get_encounter_effect(&mut conn, "fx-2").expect("Failed to get");
+        assert_eq!(collapse.timing_value, Some(4));
+        assert_eq!(collapse.description, "The ceiling gives way.");
+
+        assert_eq!(delete_encounter_effect(&mut conn, "fx-1").unwrap(), 1);
+        assert!(get_encounter_effect_optional(&mut conn, "fx-1")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_deleted_with_module() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let hazard =
+            NewEncounterEffect::new("fx-1", "mod-1", "Smoke", EffectTiming::EachRound, None);
+        insert_encounter_effect(&mut conn, &hazard).expect("Failed to insert");
+
+        delete_module(&mut conn, "mod-1").expect("Failed to delete module");
+        assert!(list_encounter_effects(&mut conn, "mod-1")
+            .unwrap()
+            .is_empty());
+    }
+}
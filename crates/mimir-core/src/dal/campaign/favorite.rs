@@ -0,0 +1,115 @@
+//! Favorite Data Access Layer
+//!
+//! Database operations for pinned catalog entries, documents, and maps.
+
+use crate::models::campaign::{Favorite, ItemKind, NewFavorite};
+use crate::schema::favorites;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new favorite.
+pub fn insert_favorite(conn: &mut SqliteConnection, favorite: &NewFavorite) -> QueryResult<String> {
+    diesel::insert_into(favorites::table)
+        .values(favorite)
+        .execute(conn)?;
+
+    Ok(favorite.id.to_string())
+}
+
+/// Get a favorite by ID.
+pub fn get_favorite(conn: &mut SqliteConnection, id: &str) -> QueryResult<Favorite> {
+    favorites::table.find(id).first(conn)
+}
+
+/// Find the favorite for an item, if it is pinned.
+pub fn find_favorite(
+    conn: &mut SqliteConnection,
+    kind: ItemKind,
+    ref_id: &str,
+    ref_source: Option<&str>,
+) -> QueryResult<Option<Favorite>> {
+    let mut query = favorites::table
+        .filter(favorites::kind.eq(kind.as_str()))
+        .filter(favorites::ref_id.eq(ref_id))
+        .into_boxed();
+
+    query = match ref_source {
+        Some(source) => query.filter(favorites::ref_source.eq(source)),
+        None => query.filter(favorites::ref_source.is_null()),
+    };
+
+    query.first(conn).optional()
+}
+
+/// List favorites in pinned order.
+///
+/// With a campaign, catalog entries are included along with that campaign's
+/// documents and maps; other campaigns' content is left out.
+pub fn list_favorites(
+    conn: &mut SqliteConnection,
+    kind: Option<ItemKind>,
+    campaign_id: Option<&str>,
+) -> QueryResult<Vec<Favorite>> {
+    let mut query = favorites::table.into_boxed();
+
+    if let Some(kind) = kind {
+        query = query.filter(favorites::kind.eq(kind.as_str()));
+    }
+    if let Some(campaign_id) = campaign_id {
+        query = query.filter(
+            favorites::campaign_id
+                .is_null()
+                .or(favorites::campaign_id.eq(campaign_id)),
+        );
+    }
+
+    query
+        .order((favorites::sort_order.asc(), favorites::created_at.asc()))
+        .load(conn)
+}
+
+/// Get the next available sort_order for favorites.
+pub fn next_favorite_sort_order(conn: &mut SqliteConnection) -> QueryResult<i32> {
+    let max: Option<i32> = favorites::table
+        .select(diesel::dsl::max(favorites::sort_order))
+        .first(conn)?;
+
+    Ok(max.unwrap_or(0) + 1)
+}
+
+/// Delete a favorite by ID.
+pub fn delete_favorite(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(favorites::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_connection;
+
+    #[test]
+    fn test_insert_find_and_delete() {
+        let mut conn = test_connection();
+
+        assert_eq!(next_favorite_sort_order(&mut conn).unwrap(), 1);
+        let favorite = NewFavorite::new("fav-1", ItemKind::Condition, "Grappled", "Grappled")
+            .with_source("PHB")
+            .with_sort_order(1);
+        insert_favorite(&mut conn, &favorite).expect("Failed to insert");
+
+        let found = find_favorite(&mut conn, ItemKind::Condition, "Grappled", Some("PHB"))
+            .unwrap()
+            .expect("Favorite should be found");
+        assert_eq!(found.id, "fav-1");
+        assert_eq!(next_favorite_sort_order(&mut conn).unwrap(), 2);
+
+        let listed = list_favorites(&mut conn, Some(ItemKind::Condition), None).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(list_favorites(&mut conn, Some(ItemKind::Spell), None)
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(delete_favorite(&mut conn, "fav-1").unwrap(), 1);
+        assert!(get_favorite(&mut conn, "fav-1").is_err());
+    }
+}
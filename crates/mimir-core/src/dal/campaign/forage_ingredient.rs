@@ -0,0 +1,163 @@
+//! ForageIngredient Data Access Layer
+//!
+//! Database operations for each campaign's gathering tables.
+
+use crate::models::campaign::{ForageIngredient, NewForageIngredient, UpdateForageIngredient};
+use crate::schema::forage_ingredients;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new forage ingredient.
+pub fn insert_forage_ingredient(
+    conn: &mut SqliteConnection,
+    ingredient: &NewForageIngredient,
+) -> QueryResult<String> {
+    diesel::insert_into(forage_ingredients::table)
+        .values(ingredient)
+        .execute(conn)?;
+
+    Ok(ingredient.id.to_string())
+}
+
+/// Get a forage ingredient by ID.
+pub fn get_forage_ingredient(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<ForageIngredient> {
+    forage_ingredients::table.find(id).first(conn)
+}
+
+/// Get a forage ingredient by ID, returning None if not found.
+pub fn get_forage_ingredient_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<ForageIngredient>> {
+    forage_ingredients::table.find(id).first(conn).optional()
+}
+
+/// Find an item in one of a campaign's environments (case-insensitive).
+pub fn find_forage_ingredient(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    environment: &str,
+    item_name: &str,
+    item_source: &str,
+) -> QueryResult<Option<ForageIngredient>> {
+    forage_ingredients::table
+        .filter(forage_ingredients::campaign_id.eq(campaign_id))
+        .filter(forage_ingredients::environment.eq(environment))
+        .filter(forage_ingredients::item_name.eq(item_name))
+        .filter(forage_ingredients::item_source.eq(item_source))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's forage ingredients by environment, then DC and name.
+pub fn list_forage_ingredients(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<ForageIngredient>> {
+    forage_ingredients::table
+        .filter(forage_ingredients::campaign_id.eq(campaign_id))
+        .order((
+            forage_ingredients::environment.asc(),
+            forage_ingredients::dc.asc(),
+            forage_ingredients::item_name.asc(),
+        ))
+        .load(conn)
+}
+
+/// List the ingredients in one of a campaign's environments
+/// (case-insensitive), by DC and name.
+pub fn list_forage_ingredients_for_environment(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    environment: &str,
+) -> QueryResult<Vec<ForageIngredient>> {
+    forage_ingredients::table
+        .filter(forage_ingredients::campaign_id.eq(campaign_id))
+        .filter(forage_ingredients::environment.eq(environment))
+        .order((
+            forage_ingredients::dc.asc(),
+            forage_ingredients::item_name.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a forage ingredient.
+pub fn update_forage_ingredient(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateForageIngredient,
+) -> QueryResult<usize> {
+    diesel::update(forage_ingredients::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a forage ingredient by ID.
+pub fn delete_forage_ingredient(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(forage_ingredients::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::db::test_connection;
+    use crate::models::campaign::NewCampaign;
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+    }
+
+    #[test]
+    fn test_environment_is_case_insensitive() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let herb = NewForageIngredient::new("fi-1", "camp-1", "Forest", "Wildroot", "HB");
+        insert_forage_ingredient(&mut conn, &herb).expect("Failed to insert");
+        let moss = NewForageIngredient::new("fi-2", "camp-1", "swamp", "Bog Moss", "HB");
+        insert_forage_ingredient(&mut conn, &moss).expect("Failed to insert");
+
+        let forest = list_forage_ingredients_for_environment(&mut conn, "camp-1", "FOREST")
+            .expect("Failed to list");
+        assert_eq!(forest.len(), 1);
+        assert_eq!(forest[0].item_name, "Wildroot");
+
+        assert!(
+            find_forage_ingredient(&mut conn, "camp-1", "forest", "Wildroot", "HB")
+                .expect("Failed to query")
+                .is_some()
+        );
+
+        let duplicate = NewForageIngredient::new("fi-3", "camp-1", "forest", "Wildroot", "HB");
+        assert!(insert_forage_ingredient(&mut conn, &duplicate).is_err());
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let herb = NewForageIngredient::new("fi-1", "camp-1", "forest", "Wildroot", "HB");
+        insert_forage_ingredient(&mut conn, &herb).expect("Failed to insert");
+
+        let update = UpdateForageIngredient {
+            dc: Some(15),
+            quantity: Some("2d4"),
+            ..Default::default()
+        };
+        update_forage_ingredient(&mut conn, "fi-1", &update).expect("Failed to update");
+        let updated = get_forage_ingredient(&mut conn, "fi-1").expect("Failed to get");
+        assert_eq!(updated.dc, 15);
+        assert_eq!(updated.quantity, "2d4");
+
+        assert_eq!(delete_forage_ingredient(&mut conn, "fi-1").unwrap(), 1);
+        assert!(get_forage_ingredient_optional(&mut conn, "fi-1")
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,171 @@
+//! GameSession Data Access Layer
+//!
+//! Database operations for game sessions.
+
+use crate::models::campaign::{GameSession, NewGameSession, UpdateGameSession};
+use crate::schema::game_sessions;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new game session.
+pub fn insert_game_session(
+    conn: &mut SqliteConnection,
+    session: &NewGameSession,
+) -> QueryResult<String> {
+    diesel::insert_into(game_sessions::table)
+        .values(session)
+        .execute(conn)?;
+
+    Ok(session.id.to_string())
+}
+
+/// Get a game session by ID.
+pub fn get_game_session(conn: &mut SqliteConnection, id: &str) -> QueryResult<GameSession> {
+    game_sessions::table.find(id).first(conn)
+}
+
+/// Get a game session by ID, returning None if not found.
+pub fn get_game_session_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<GameSession>> {
+    game_sessions::table.find(id).first(conn).optional()
+}
+
+/// List all sessions for a campaign, most recent first.
+pub fn list_game_sessions(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<GameSession>> {
+    game_sessions::table
+        .filter(game_sessions::campaign_id.eq(campaign_id))
+        .order(game_sessions::session_number.desc())
+        .load(conn)
+}
+
+/// List all sessions played by a party, most recent first.
+pub fn list_party_game_sessions(
+    conn: &mut SqliteConnection,
+    party_id: &str,
+) -> QueryResult<Vec<GameSession>> {
+    game_sessions::table
+        .filter(game_sessions::party_id.eq(party_id))
+        .order(game_sessions::session_number.desc())
+        .load(conn)
+}
+
+/// Get the in-progress session for a campaign, if any.
+pub fn get_active_game_session(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Option<GameSession>> {
+    game_sessions::table
+        .filter(game_sessions::campaign_id.eq(campaign_id))
+        .filter(game_sessions::ended_at.is_null())
+        .order(game_sessions::session_number.desc())
+        .first(conn)
+        .optional()
+}
+
+/// List in-progress sessions across all campaigns, most recently started first.
+pub fn list_active_game_sessions(conn: &mut SqliteConnection) -> QueryResult<Vec<GameSession>> {
+    game_sessions::table
+        .filter(game_sessions::ended_at.is_null())
+        .order(game_sessions::started_at.desc())
+        .load(conn)
+}
+
+/// Get the next session number for a campaign.
+pub fn next_game_session_number(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<i32> {
+    let max: Option<i32> = game_sessions::table
+        .filter(game_sessions::campaign_id.eq(campaign_id))
+        .select(diesel::dsl::max(game_sessions::session_number))
+        .first(conn)?;
+
+    Ok(max.unwrap_or(0) + 1)
+}
+
+/// Update a game session.
+pub fn update_game_session(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateGameSession,
+) -> QueryResult<usize> {
+    diesel::update(game_sessions::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a game session by ID.
+pub fn delete_game_session(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(game_sessions::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::db::test_connection;
+    use crate::models::campaign::NewCampaign;
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+    }
+
+    #[test]
+    fn test_insert_and_get_session() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let session = NewGameSession::new("sess-1", "camp-1", 1).with_title("Session Zero");
+        insert_game_session(&mut conn, &session).expect("Failed to insert");
+
+        let retrieved = get_game_session(&mut conn, "sess-1").expect("Failed to get");
+        assert_eq!(retrieved.session_number, 1);
+        assert_eq!(retrieved.title, Some("Session Zero".to_string()));
+        assert!(retrieved.is_active());
+        assert_eq!(retrieved.play_seconds, 0);
+    }
+
+    #[test]
+    fn test_session_numbering_and_active() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        assert_eq!(next_game_session_number(&mut conn, "camp-1").unwrap(), 1);
+
+        insert_game_session(&mut conn, &NewGameSession::new("sess-1", "camp-1", 1))
+            .expect("Failed to insert");
+        let update = UpdateGameSession {
+            ended_at: Some(Some("2024-01-20T22:00:00Z")),
+            ..Default::default()
+        };
+        update_game_session(&mut conn, "sess-1", &update).expect("Failed to update");
+
+        assert!(get_active_game_session(&mut conn, "camp-1").unwrap().is_none());
+        assert_eq!(next_game_session_number(&mut conn, "camp-1").unwrap(), 2);
+
+        insert_game_session(&mut conn, &NewGameSession::new("sess-2", "camp-1", 2))
+            .expect("Failed to insert");
+        let active = get_active_game_session(&mut conn, "camp-1").unwrap().unwrap();
+        assert_eq!(active.id, "sess-2");
+
+        let sessions = list_game_sessions(&mut conn, "camp-1").expect("Failed to list");
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].id, "sess-2");
+    }
+
+    #[test]
+    fn test_duplicate_session_number_rejected() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        insert_game_session(&mut conn, &NewGameSession::new("sess-1", "camp-1", 1))
+            .expect("Failed to insert");
+        assert!(insert_game_session(&mut conn, &NewGameSession::new("sess-2", "camp-1", 1)).is_err());
+    }
+}
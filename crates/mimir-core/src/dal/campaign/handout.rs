@@ -0,0 +1,190 @@
+//! Handout Data Access Layer
+//!
+//! Database operations for the handout queue and the record of reveals.
+
+use crate::models::campaign::{
+    Handout, HandoutReveal, NewHandout, NewHandoutReveal, UpdateHandout,
+};
+use crate::schema::{handout_reveals, handouts};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Handouts
+// =============================================================================
+
+/// Insert a new handout.
+pub fn insert_handout(conn: &mut SqliteConnection, handout: &NewHandout) -> QueryResult<String> {
+    diesel::insert_into(handouts::table)
+        .values(handout)
+        .execute(conn)?;
+
+    Ok(handout.id.to_string())
+}
+
+/// Get a handout by ID.
+pub fn get_handout(conn: &mut SqliteConnection, id: &str) -> QueryResult<Handout> {
+    handouts::table.find(id).first(conn)
+}
+
+/// Get a handout by ID, returning None if not found.
+pub fn get_handout_optional(conn: &mut SqliteConnection, id: &str) -> QueryResult<Option<Handout>> {
+    handouts::table.find(id).first(conn).optional()
+}
+
+/// List a campaign's handouts in queue order.
+pub fn list_handouts(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Handout>> {
+    handouts::table
+        .filter(handouts::campaign_id.eq(campaign_id))
+        .order((handouts::sort_order.asc(), handouts::created_at.asc()))
+        .load(conn)
+}
+
+/// Get the first handout still waiting in a campaign's queue.
+pub fn next_queued_handout(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Option<Handout>> {
+    handouts::table
+        .filter(handouts::campaign_id.eq(campaign_id))
+        .filter(handouts::revealed_at.is_null())
+        .order((handouts::sort_order.asc(), handouts::created_at.asc()))
+        .first(conn)
+        .optional()
+}
+
+/// Get the next available sort_order for a campaign's handouts.
+pub fn next_handout_sort_order(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<i32> {
+    let max: Option<i32> = handouts::table
+        .filter(handouts::campaign_id.eq(campaign_id))
+        .select(diesel::dsl::max(handouts::sort_order))
+        .first(conn)?;
+
+    Ok(max.unwrap_or(0) + 1)
+}
+
+/// Update a handout.
+pub fn update_handout(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateHandout,
+) -> QueryResult<usize> {
+    diesel::update(handouts::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a handout by ID. Its reveals are deleted with it.
+pub fn delete_handout(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(handouts::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Handout Reveals
+// =============================================================================
+
+/// Record that a handout was shown.
+pub fn insert_handout_reveal(
+    conn: &mut SqliteConnection,
+    reveal: &NewHandoutReveal,
+) -> QueryResult<usize> {
+    diesel::insert_into(handout_reveals::table)
+        .values(reveal)
+        .execute(conn)
+}
+
+/// List the reveals of a campaign's handouts, earliest first, optionally
+/// only those in one session.
+pub fn list_handout_reveals(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    session_id: Option<&str>,
+) -> QueryResult<Vec<HandoutReveal>> {
+    let mut query = handout_reveals::table
+        .inner_join(handouts::table)
+        .filter(handouts::campaign_id.eq(campaign_id))
+        .select(HandoutReveal::as_select())
+        .order((
+            handout_reveals::revealed_at.asc(),
+            handout_reveals::id.asc(),
+        ))
+        .into_boxed();
+    if let Some(session_id) = session_id {
+        query = query.filter(handout_reveals::session_id.eq(session_id));
+    }
+    query.load(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::db::test_connection;
+    use crate::models::campaign::NewCampaign;
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+    }
+
+    #[test]
+    fn test_queue_order() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        assert_eq!(next_handout_sort_order(&mut conn, "camp-1").unwrap(), 1);
+
+        let map = NewHandout::new("handout-2", "camp-1", "Treasure Map")
+            .with_image_path("book/DMG/map.webp")
+            .with_sort_order(2);
+        insert_handout(&mut conn, &map).expect("Failed to insert");
+        let letter = NewHandout::new("handout-1", "camp-1", "Letter")
+            .with_image_path("book/DMG/letter.webp")
+            .with_sort_order(1);
+        insert_handout(&mut conn, &letter).expect("Failed to insert");
+
+        let handouts = list_handouts(&mut conn, "camp-1").unwrap();
+        assert_eq!(handouts[0].id, "handout-1");
+        assert_eq!(next_handout_sort_order(&mut conn, "camp-1").unwrap(), 3);
+
+        let update = UpdateHandout {
+            revealed_at: Some(Some("2026-01-01T00:00:00Z")),
+            ..Default::default()
+        };
+        update_handout(&mut conn, "handout-1", &update).unwrap();
+        let next = next_queued_handout(&mut conn, "camp-1").unwrap().unwrap();
+        assert_eq!(next.id, "handout-2");
+        assert!(!get_handout(&mut conn, "handout-1").unwrap().is_queued());
+
+        assert_eq!(delete_handout(&mut conn, "handout-2").unwrap(), 1);
+        assert!(next_queued_handout(&mut conn, "camp-1").unwrap().is_none());
+        assert!(get_handout_optional(&mut conn, "handout-2")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_reveals() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        let letter = NewHandout::new("handout-1", "camp-1", "Letter")
+            .with_image_path("book/DMG/letter.webp");
+        insert_handout(&mut conn, &letter).unwrap();
+
+        let first = NewHandoutReveal::new("reveal-1", "handout-1", "2026-01-01T00:00:00Z");
+        insert_handout_reveal(&mut conn, &first).expect("Failed to insert");
+        let again = NewHandoutReveal::new("reveal-2", "handout-1", "2026-01-08T00:00:00Z");
+        insert_handout_reveal(&mut conn, &again).unwrap();
+
+        let reveals = list_handout_reveals(&mut conn, "camp-1", None).unwrap();
+        assert_eq!(reveals.len(), 2);
+        assert_eq!(reveals[0].id, "reveal-1");
+        assert!(list_handout_reveals(&mut conn, "camp-1", Some("session-1"))
+            .unwrap()
+            .is_empty());
+
+        delete_handout(&mut conn, "handout-1").unwrap();
+        assert!(list_handout_reveals(&mut conn, "camp-1", None)
+            .unwrap()
+            .is_empty());
+    }
+}
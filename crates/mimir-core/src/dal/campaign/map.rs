@@ -29,6 +29,7 @@ pub fn get_map_optional(conn: &mut SqliteConnection, id: &str) -> QueryResult<Op
 /// List all maps for a campaign (including module maps).
 pub fn list_campaign_maps(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Map>> {
     maps::table
+        .filter(maps::deleted_at.is_null())
         .filter(maps::campaign_id.eq(campaign_id))
         .order(maps::sort_order.asc())
         .then_order_by(maps::name.asc())
@@ -41,6 +42,7 @@ pub fn list_campaign_level_maps(
     campaign_id: &str,
 ) -> QueryResult<Vec<Map>> {
     maps::table
+        .filter(maps::deleted_at.is_null())
         .filter(maps::campaign_id.eq(campaign_id))
         .filter(maps::module_id.is_null())
         .order(maps::sort_order.asc())
@@ -51,6 +53,7 @@ pub fn list_campaign_level_maps(
 /// List all maps for a module.
 pub fn list_module_maps(conn: &mut SqliteConnection, module_id: &str) -> QueryResult<Vec<Map>> {
     maps::table
+        .filter(maps::deleted_at.is_null())
         .filter(maps::module_id.eq(module_id))
         .order(maps::sort_order.asc())
         .then_order_by(maps::name.asc())
@@ -69,6 +72,36 @@ pub fn delete_map(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
     diesel::delete(maps::table.find(id)).execute(conn)
 }
 
+/// Move a map to the trash.
+pub fn trash_map(conn: &mut SqliteConnection, id: &str, deleted_at: &str) -> QueryResult<usize> {
+    diesel::update(maps::table.find(id).filter(maps::deleted_at.is_null()))
+        .set(maps::deleted_at.eq(Some(deleted_at)))
+        .execute(conn)
+}
+
+/// Restore a trashed map.
+pub fn restore_map(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::update(maps::table.find(id).filter(maps::deleted_at.is_not_null()))
+        .set(maps::deleted_at.eq(None::<String>))
+        .execute(conn)
+}
+
+/// List trashed maps, most recently trashed first.
+pub fn list_trashed_maps(
+    conn: &mut SqliteConnection,
+    campaign_id: Option<&str>,
+) -> QueryResult<Vec<Map>> {
+    let mut query = maps::table
+        .filter(maps::deleted_at.is_not_null())
+        .into_boxed();
+
+    if let Some(campaign_id) = campaign_id {
+        query = query.filter(maps::campaign_id.eq(campaign_id));
+    }
+
+    query.order(maps::deleted_at.desc()).load(conn)
+}
+
 /// Check if a map exists.
 pub fn map_exists(conn: &mut SqliteConnection, id: &str) -> QueryResult<bool> {
     use diesel::dsl::exists;
@@ -80,6 +113,7 @@ pub fn map_exists(conn: &mut SqliteConnection, id: &str) -> QueryResult<bool> {
 /// Count maps for a campaign.
 pub fn count_campaign_maps(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<i64> {
     maps::table
+        .filter(maps::deleted_at.is_null())
         .filter(maps::campaign_id.eq(campaign_id))
         .count()
         .get_result(conn)
@@ -88,6 +122,7 @@ pub fn count_campaign_maps(conn: &mut SqliteConnection, campaign_id: &str) -> Qu
 /// Count maps for a module.
 pub fn count_module_maps(conn: &mut SqliteConnection, module_id: &str) -> QueryResult<i64> {
     maps::table
+        .filter(maps::deleted_at.is_null())
         .filter(maps::module_id.eq(module_id))
         .count()
         .get_result(conn)
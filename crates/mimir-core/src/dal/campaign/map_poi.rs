@@ -137,6 +137,31 @@ mod tests {
         assert!(retrieved.is_visible());
     }
 
+    #[test]
+    fn test_update_document_link() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let poi = NewMapPoi::new("poi-1", "map-1", "Guard Room", 2, 3);
+        insert_map_poi(&mut conn, &poi).expect("Failed to insert");
+        let unlinked = get_map_poi(&mut conn, "poi-1").unwrap();
+        assert!(unlinked.document_link().is_none());
+
+        let update = UpdateMapPoi::set_document_link(Some("doc-1"), Some("1. Guard Room"), "now");
+        update_map_poi(&mut conn, "poi-1", &update).expect("Failed to link");
+        let linked = get_map_poi(&mut conn, "poi-1").unwrap();
+        assert_eq!(
+            linked.document_link(),
+            Some(("doc-1", Some("1. Guard Room")))
+        );
+
+        let update = UpdateMapPoi::set_document_link(None, None, "now");
+        update_map_poi(&mut conn, "poi-1", &update).expect("Failed to unlink");
+        let unlinked = get_map_poi(&mut conn, "poi-1").unwrap();
+        assert!(unlinked.document_link().is_none());
+        assert!(unlinked.document_section.is_none());
+    }
+
     #[test]
     fn test_list_map_pois() {
         let mut conn = test_connection();
@@ -0,0 +1,145 @@
+//! MapRegion Data Access Layer
+//!
+//! Database operations for named encounter regions on maps.
+
+use crate::models::campaign::{MapRegion, NewMapRegion, UpdateMapRegion};
+use crate::schema::map_regions;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new map region.
+pub fn insert_map_region(
+    conn: &mut SqliteConnection,
+    region: &NewMapRegion,
+) -> QueryResult<String> {
+    diesel::insert_into(map_regions::table)
+        .values(region)
+        .execute(conn)?;
+
+    Ok(region.id.to_string())
+}
+
+/// Get a map region by ID.
+pub fn get_map_region(conn: &mut SqliteConnection, id: &str) -> QueryResult<MapRegion> {
+    map_regions::table.find(id).first(conn)
+}
+
+/// Get a map region by ID, returning None if not found.
+pub fn get_map_region_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<MapRegion>> {
+    map_regions::table.find(id).first(conn).optional()
+}
+
+/// Find a map's region by name (case-insensitive).
+pub fn get_map_region_by_name(
+    conn: &mut SqliteConnection,
+    map_id: &str,
+    name: &str,
+) -> QueryResult<Option<MapRegion>> {
+    map_regions::table
+        .filter(map_regions::map_id.eq(map_id))
+        .filter(map_regions::name.eq(name))
+        .first(conn)
+        .optional()
+}
+
+/// List all regions for a map, alphabetically.
+pub fn list_map_regions(conn: &mut SqliteConnection, map_id: &str) -> QueryResult<Vec<MapRegion>> {
+    map_regions::table
+        .filter(map_regions::map_id.eq(map_id))
+        .order(map_regions::name.asc())
+        .load(conn)
+}
+
+/// Update a map region.
+pub fn update_map_region(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateMapRegion,
+) -> QueryResult<usize> {
+    diesel::update(map_regions::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a map region by ID.
+pub fn delete_map_region(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(map_regions::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_campaign_asset, insert_map};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCampaignAsset, NewMap};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+
+        let asset = NewCampaignAsset::for_campaign(
+            "asset-1",
+            "camp-1",
+            "crypt.uvtt",
+            "application/octet-stream",
+            "/blobs/crypt.uvtt",
+        );
+        insert_campaign_asset(conn, &asset).expect("Failed to create asset");
+
+        let map = NewMap::for_campaign("map-1", "camp-1", "Crypt Level 1", "asset-1");
+        insert_map(conn, &map).expect("Failed to create map");
+    }
+
+    #[test]
+    fn test_insert_and_get_by_name() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let region = NewMapRegion::rect("region-1", "map-1", "Area-3", 2.0, 3.0, 4.0, 5.0);
+        insert_map_region(&mut conn, &region).expect("Failed to insert");
+
+        let found = get_map_region_by_name(&mut conn, "map-1", "area-3")
+            .expect("Failed to query")
+            .expect("Region not found");
+        assert_eq!(found.id, "region-1");
+        assert_eq!(found.width, 4.0);
+
+        assert!(get_map_region_by_name(&mut conn, "map-1", "area-4")
+            .expect("Failed to query")
+            .is_none());
+    }
+
+    #[test]
+    fn test_names_unique_per_map() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let first = NewMapRegion::rect("region-1", "map-1", "Area-3", 0.0, 0.0, 1.0, 1.0);
+        insert_map_region(&mut conn, &first).expect("Failed to insert");
+
+        let duplicate = NewMapRegion::rect("region-2", "map-1", "area-3", 5.0, 5.0, 1.0, 1.0);
+        assert!(insert_map_region(&mut conn, &duplicate).is_err());
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let region = NewMapRegion::rect("region-1", "map-1", "area-3", 0.0, 0.0, 1.0, 1.0);
+        insert_map_region(&mut conn, &region).expect("Failed to insert");
+
+        let update = UpdateMapRegion::set_name("Throne Room", "2024-01-20T12:00:00Z");
+        update_map_region(&mut conn, "region-1", &update).expect("Failed to update");
+        assert_eq!(
+            get_map_region(&mut conn, "region-1").unwrap().name,
+            "Throne Room"
+        );
+
+        delete_map_region(&mut conn, "region-1").expect("Failed to delete");
+        assert!(list_map_regions(&mut conn, "map-1").unwrap().is_empty());
+    }
+}
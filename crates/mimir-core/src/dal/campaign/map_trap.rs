@@ -149,6 +149,22 @@ mod tests {
         assert_eq!(retrieved.dc, Some(15));
     }
 
+    #[test]
+    fn test_insert_with_catalog_ref() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let trap = NewMapTrap::new("trap-1", "map-1", "Pits", 0, 0).with_catalog_ref("Pits", "DMG");
+        insert_map_trap(&mut conn, &trap).expect("Failed to insert");
+
+        let retrieved = get_map_trap(&mut conn, "trap-1").expect("Failed to get");
+        assert_eq!(retrieved.catalog_ref(), Some(("Pits", "DMG")));
+
+        let custom = NewMapTrap::new("trap-2", "map-1", "Net", 1, 1);
+        insert_map_trap(&mut conn, &custom).expect("Failed to insert");
+        assert_eq!(get_map_trap(&mut conn, "trap-2").unwrap().catalog_ref(), None);
+    }
+
     #[test]
     fn test_list_map_traps() {
         let mut conn = test_connection();
@@ -1,6 +1,6 @@
 //! Campaign Data Access Layer
 //!
-//! Database operations for campaigns, modules, campaign sources, assets, documents, characters, maps, module entities, and map overlays.
+//! Database operations for campaigns, modules, campaign sources, assets, documents, characters, maps, module entities, map overlays, parties, players, safety tools, custom character fields, class resources, companions, owned vehicles, the shared stash, crafting projects, currency transactions, character deaths, campaign memories, encounter effects, forage tables, module links, and module prep checks.
 
 mod campaign;
 mod campaign_asset;
@@ -8,24 +8,51 @@ mod homebrew_crud;
 mod campaign_homebrew_item;
 mod campaign_homebrew_monster;
 mod campaign_homebrew_spell;
+mod campaign_memory;
 mod campaign_source;
+mod campaign_stash;
 mod character;
 mod character_class;
+mod character_companion;
+mod character_death;
 mod character_feat;
 mod character_feature;
 mod character_inventory;
 mod character_proficiency;
+mod character_resource;
 mod character_source;
 mod character_spell;
+mod crafting_project;
+mod currency_transaction;
+mod custom_field;
+mod dm_reference_pin;
 mod document;
+mod encounter_effect;
+mod favorite;
 mod fog;
+mod forage_ingredient;
+mod game_session;
+mod handout;
 mod light_source;
 mod map;
 mod map_poi;
+mod map_region;
 mod map_trap;
 mod module;
+mod module_link;
 mod module_monster;
 mod module_npc;
+mod module_prep_check;
+mod owned_vehicle;
+mod party;
+mod player;
+mod quick_action;
+mod recent_item;
+mod relationship;
+mod rumor;
+mod safety_tools;
+mod secret_note;
+mod tag;
 mod token_placement;
 
 pub use campaign::*;
@@ -33,22 +60,49 @@ pub use campaign_asset::*;
 pub use campaign_homebrew_item::*;
 pub use campaign_homebrew_monster::*;
 pub use campaign_homebrew_spell::*;
+pub use campaign_memory::*;
 pub use campaign_source::*;
+pub use campaign_stash::*;
 pub use character::*;
 pub use character_class::*;
+pub use character_companion::*;
+pub use character_death::*;
 pub use character_feat::*;
 pub use character_feature::*;
 pub use character_inventory::*;
 pub use character_proficiency::*;
+pub use character_resource::*;
 pub use character_source::*;
 pub use character_spell::*;
+pub use crafting_project::*;
+pub use currency_transaction::*;
+pub use custom_field::*;
+pub use dm_reference_pin::*;
 pub use document::*;
+pub use encounter_effect::*;
+pub use favorite::*;
 pub use fog::*;
+pub use forage_ingredient::*;
+pub use game_session::*;
+pub use handout::*;
 pub use light_source::*;
 pub use map::*;
 pub use map_poi::*;
+pub use map_region::*;
 pub use map_trap::*;
 pub use module::*;
+pub use module_link::*;
 pub use module_monster::*;
 pub use module_npc::*;
+pub use module_prep_check::*;
+pub use owned_vehicle::*;
+pub use party::*;
+pub use player::*;
+pub use quick_action::*;
+pub use recent_item::*;
+pub use relationship::*;
+pub use rumor::*;
+pub use safety_tools::*;
+pub use secret_note::*;
+pub use tag::*;
 pub use token_placement::*;
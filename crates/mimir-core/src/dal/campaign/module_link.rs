@@ -0,0 +1,90 @@
+//! ModuleLink Data Access Layer
+//!
+//! Database operations for the links between a campaign's modules.
+
+use crate::models::campaign::{ModuleLink, NewModuleLink, UpdateModuleLink};
+use crate::schema::module_links;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new module link.
+pub fn insert_module_link(
+    conn: &mut SqliteConnection,
+    link: &NewModuleLink,
+) -> QueryResult<String> {
+    diesel::insert_into(module_links::table)
+        .values(link)
+        .execute(conn)?;
+
+    Ok(link.id.to_string())
+}
+
+/// Get a module link by ID.
+pub fn get_module_link(conn: &mut SqliteConnection, id: &str) -> QueryResult<ModuleLink> {
+    module_links::table.find(id).first(conn)
+}
+
+/// Get a module link by ID, returning None if not found.
+pub fn get_module_link_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<ModuleLink>> {
+    module_links::table.find(id).first(conn).optional()
+}
+
+/// Find the link of a type between two modules.
+pub fn find_module_link(
+    conn: &mut SqliteConnection,
+    from_module_id: &str,
+    to_module_id: &str,
+    link_type: &str,
+) -> QueryResult<Option<ModuleLink>> {
+    module_links::table
+        .filter(module_links::from_module_id.eq(from_module_id))
+        .filter(module_links::to_module_id.eq(to_module_id))
+        .filter(module_links::link_type.eq(link_type))
+        .first(conn)
+        .optional()
+}
+
+/// List all module links in a campaign, oldest first.
+pub fn list_module_links(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<ModuleLink>> {
+    module_links::table
+        .filter(module_links::campaign_id.eq(campaign_id))
+        .order(module_links::created_at.asc())
+        .load(conn)
+}
+
+/// List the links starting or ending at a module, oldest first.
+pub fn list_links_for_module(
+    conn: &mut SqliteConnection,
+    module_id: &str,
+) -> QueryResult<Vec<ModuleLink>> {
+    module_links::table
+        .filter(
+            module_links::from_module_id
+                .eq(module_id)
+                .or(module_links::to_module_id.eq(module_id)),
+        )
+        .order(module_links::created_at.asc())
+        .load(conn)
+}
+
+/// Update a module link.
+pub fn update_module_link(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateModuleLink,
+) -> QueryResult<usize> {
+    diesel::update(module_links::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a module link by ID.
+pub fn delete_module_link(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(module_links::table.find(id)).execute(conn)
+}
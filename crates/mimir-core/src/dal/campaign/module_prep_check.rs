@@ -0,0 +1,38 @@
+//! ModulePrepCheck Data Access Layer
+//!
+//! Database operations for prep tasks ticked off by hand.
+
+use crate::models::campaign::{ModulePrepCheck, NewModulePrepCheck};
+use crate::schema::module_prep_checks;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Tick off a prep task. Ticking a task twice keeps the first tick.
+pub fn insert_module_prep_check(
+    conn: &mut SqliteConnection,
+    check: &NewModulePrepCheck,
+) -> QueryResult<usize> {
+    diesel::insert_or_ignore_into(module_prep_checks::table)
+        .values(check)
+        .execute(conn)
+}
+
+/// List a module's ticked prep tasks.
+pub fn list_module_prep_checks(
+    conn: &mut SqliteConnection,
+    module_id: &str,
+) -> QueryResult<Vec<ModulePrepCheck>> {
+    module_prep_checks::table
+        .filter(module_prep_checks::module_id.eq(module_id))
+        .order(module_prep_checks::task_key.asc())
+        .load(conn)
+}
+
+/// Untick a prep task.
+pub fn delete_module_prep_check(
+    conn: &mut SqliteConnection,
+    module_id: &str,
+    task_key: &str,
+) -> QueryResult<usize> {
+    diesel::delete(module_prep_checks::table.find((module_id, task_key))).execute(conn)
+}
@@ -0,0 +1,177 @@
+//! OwnedVehicle Data Access Layer
+//!
+//! Database operations for mounts and vehicles owned by characters and
+//! parties.
+
+use crate::models::campaign::{NewOwnedVehicle, OwnedVehicle, UpdateOwnedVehicle};
+use crate::schema::owned_vehicles;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new owned vehicle.
+pub fn insert_owned_vehicle(
+    conn: &mut SqliteConnection,
+    vehicle: &NewOwnedVehicle,
+) -> QueryResult<String> {
+    diesel::insert_into(owned_vehicles::table)
+        .values(vehicle)
+        .execute(conn)?;
+
+    Ok(vehicle.id.to_string())
+}
+
+/// Get an owned vehicle by ID.
+pub fn get_owned_vehicle(conn: &mut SqliteConnection, id: &str) -> QueryResult<OwnedVehicle> {
+    owned_vehicles::table.find(id).first(conn)
+}
+
+/// Get an owned vehicle by ID, returning None if not found.
+pub fn get_owned_vehicle_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<OwnedVehicle>> {
+    owned_vehicles::table.find(id).first(conn).optional()
+}
+
+/// List every owned vehicle in a campaign, alphabetically.
+pub fn list_campaign_owned_vehicles(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<OwnedVehicle>> {
+    owned_vehicles::table
+        .filter(owned_vehicles::campaign_id.eq(campaign_id))
+        .order(owned_vehicles::name.asc())
+        .load(conn)
+}
+
+/// List a character's own mounts and vehicles, alphabetically.
+pub fn list_character_owned_vehicles(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<OwnedVehicle>> {
+    owned_vehicles::table
+        .filter(owned_vehicles::character_id.eq(character_id))
+        .order(owned_vehicles::name.asc())
+        .load(conn)
+}
+
+/// List the mounts and vehicles a party has access to: those the party owns
+/// plus those owned by the given members.
+pub fn list_party_owned_vehicles(
+    conn: &mut SqliteConnection,
+    party_id: &str,
+    member_ids: &[&str],
+) -> QueryResult<Vec<OwnedVehicle>> {
+    owned_vehicles::table
+        .filter(
+            owned_vehicles::party_id
+                .eq(party_id)
+                .or(owned_vehicles::character_id.eq_any(member_ids)),
+        )
+        .order(owned_vehicles::name.asc())
+        .load(conn)
+}
+
+/// Update an owned vehicle.
+pub fn update_owned_vehicle(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateOwnedVehicle,
+) -> QueryResult<usize> {
+    diesel::update(owned_vehicles::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete an owned vehicle.
+pub fn delete_owned_vehicle(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(owned_vehicles::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, insert_party};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewParty, VehicleKind};
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        for (id, name) in [("char-1", "Ada"), ("char-2", "Brom")] {
+            insert_character(
+                conn,
+                &NewCharacter::new_pc(id, Some("camp-1"), name, "Alice"),
+            )
+            .unwrap();
+        }
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Blue Team")).unwrap();
+    }
+
+    #[test]
+    fn test_list_party_owned_vehicles() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let keelboat = NewOwnedVehicle::for_party(
+            "veh-1",
+            "camp-1",
+            "party-1",
+            "Salty Gull",
+            VehicleKind::Vehicle,
+            "Keelboat",
+            "GoS",
+        );
+        let horse = NewOwnedVehicle::for_character(
+            "veh-2",
+            "camp-1",
+            "char-1",
+            "Clover",
+            VehicleKind::Mount,
+            "Riding Horse",
+            "PHB",
+        );
+        let pony = NewOwnedVehicle::for_character(
+            "veh-3",
+            "camp-1",
+            "char-2",
+            "Pip",
+            VehicleKind::Mount,
+            "Pony",
+            "PHB",
+        );
+        for vehicle in [&keelboat, &horse, &pony] {
+            insert_owned_vehicle(&mut conn, vehicle).unwrap();
+        }
+
+        let party = list_party_owned_vehicles(&mut conn, "party-1", &["char-1"]).unwrap();
+        let names: Vec<&str> = party.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["Clover", "Salty Gull"]);
+
+        let own = list_character_owned_vehicles(&mut conn, "char-2").unwrap();
+        assert_eq!(own.len(), 1);
+        assert_eq!(
+            list_campaign_owned_vehicles(&mut conn, "camp-1")
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_owner_is_required() {
+        let mut conn = test_connection();
+        setup(&mut conn);
+
+        let mut ownerless = NewOwnedVehicle::for_party(
+            "veh-1",
+            "camp-1",
+            "party-1",
+            "Cart",
+            VehicleKind::Vehicle,
+            "Cart",
+            "PHB",
+        );
+        ownerless.party_id = None;
+        assert!(insert_owned_vehicle(&mut conn, &ownerless).is_err());
+    }
+}
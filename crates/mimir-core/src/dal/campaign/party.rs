@@ -0,0 +1,267 @@
+//! Party Data Access Layer
+//!
+//! Database operations for parties, party membership, and party timelines.
+
+use crate::models::campaign::{
+    Character, NewParty, NewPartyMember, NewPartyTimelineEvent, Party, PartyMember,
+    PartyTimelineEvent, UpdateParty,
+};
+use crate::schema::{characters, parties, party_members, party_timeline_events};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Parties
+// =============================================================================
+
+/// Insert a new party.
+pub fn insert_party(conn: &mut SqliteConnection, party: &NewParty) -> QueryResult<String> {
+    diesel::insert_into(parties::table)
+        .values(party)
+        .execute(conn)?;
+    Ok(party.id.to_string())
+}
+
+/// Get a party by ID.
+pub fn get_party(conn: &mut SqliteConnection, id: &str) -> QueryResult<Party> {
+    parties::table.find(id).first(conn)
+}
+
+/// Get a party by ID, returning None if not found.
+pub fn get_party_optional(conn: &mut SqliteConnection, id: &str) -> QueryResult<Option<Party>> {
+    parties::table.find(id).first(conn).optional()
+}
+
+/// Find a campaign's party by name (case-insensitive).
+pub fn get_party_by_name(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    name: &str,
+) -> QueryResult<Option<Party>> {
+    parties::table
+        .filter(parties::campaign_id.eq(campaign_id))
+        .filter(parties::name.eq(name))
+        .first(conn)
+        .optional()
+}
+
+/// List all parties for a campaign, alphabetically.
+pub fn list_parties(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Party>> {
+    parties::table
+        .filter(parties::campaign_id.eq(campaign_id))
+        .order(parties::name.asc())
+        .load(conn)
+}
+
+/// Update a party.
+pub fn update_party(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateParty,
+) -> QueryResult<usize> {
+    diesel::update(parties::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a party. Memberships and timeline events are removed by cascade;
+/// its sessions are kept with no party.
+pub fn delete_party(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(parties::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Party Members
+// =============================================================================
+
+/// Add a character to a party. Adding a member twice is a no-op.
+pub fn insert_party_member(
+    conn: &mut SqliteConnection,
+    member: &NewPartyMember,
+) -> QueryResult<usize> {
+    diesel::insert_or_ignore_into(party_members::table)
+        .values(member)
+        .execute(conn)
+}
+
+/// Remove a character from a party.
+pub fn delete_party_member(
+    conn: &mut SqliteConnection,
+    party_id: &str,
+    character_id: &str,
+) -> QueryResult<usize> {
+    diesel::delete(
+        party_members::table
+            .filter(party_members::party_id.eq(party_id))
+            .filter(party_members::character_id.eq(character_id)),
+    )
+    .execute(conn)
+}
+
+/// List a party's memberships, earliest joined first.
+pub fn list_party_members(
+    conn: &mut SqliteConnection,
+    party_id: &str,
+) -> QueryResult<Vec<PartyMember>> {
+    party_members::table
+        .filter(party_members::party_id.eq(party_id))
+        .order(party_members::joined_at.asc())
+        .load(conn)
+}
+
+/// List the player characters in a party, alphabetically.
+pub fn list_party_pcs(conn: &mut SqliteConnection, party_id: &str) -> QueryResult<Vec<Character>> {
+    characters::table
+        .inner_join(party_members::table)
+        .filter(party_members::party_id.eq(party_id))
+        .filter(characters::deleted_at.is_null())
+        .filter(characters::is_npc.eq(0))
+        .order(characters::name.asc())
+        .select(Character::as_select())
+        .load(conn)
+}
+
+/// List the parties a character belongs to, alphabetically.
+pub fn list_parties_for_character(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<Party>> {
+    parties::table
+        .inner_join(party_members::table)
+        .filter(party_members::character_id.eq(character_id))
+        .order(parties::name.asc())
+        .select(Party::as_select())
+        .load(conn)
+}
+
+// =============================================================================
+// Timeline Events
+// =============================================================================
+
+/// Insert a new timeline event.
+pub fn insert_party_timeline_event(
+    conn: &mut SqliteConnection,
+    event: &NewPartyTimelineEvent,
+) -> QueryResult<String> {
+    diesel::insert_into(party_timeline_events::table)
+        .values(event)
+        .execute(conn)?;
+    Ok(event.id.to_string())
+}
+
+/// Get a timeline event by ID.
+pub fn get_party_timeline_event(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<PartyTimelineEvent> {
+    party_timeline_events::table.find(id).first(conn)
+}
+
+/// List a party's timeline in calendar order.
+pub fn list_party_timeline_events(
+    conn: &mut SqliteConnection,
+    party_id: &str,
+) -> QueryResult<Vec<PartyTimelineEvent>> {
+    party_timeline_events::table
+        .filter(party_timeline_events::party_id.eq(party_id))
+        .order((
+            party_timeline_events::day.asc(),
+            party_timeline_events::created_at.asc(),
+        ))
+        .load(conn)
+}
+
+/// Delete a timeline event by ID.
+pub fn delete_party_timeline_event(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(party_timeline_events::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, trash_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Tuesday Group"))
+            .expect("Failed to create party");
+    }
+
+    #[test]
+    fn test_insert_and_find_party() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let party = get_party(&mut conn, "party-1").expect("Failed to get");
+        assert_eq!(party.current_day, 1);
+
+        let found = get_party_by_name(&mut conn, "camp-1", "tuesday group").unwrap();
+        assert_eq!(found.map(|p| p.id), Some("party-1".to_string()));
+
+        // Names are unique per campaign
+        let dup = NewParty::new("party-2", "camp-1", "TUESDAY GROUP");
+        assert!(insert_party(&mut conn, &dup).is_err());
+    }
+
+    #[test]
+    fn test_party_members() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let pc = NewCharacter::new_pc("pc-1", Some("camp-1"), "Aria", "Alice");
+        insert_character(&mut conn, &pc).expect("Failed to create PC");
+        let npc = NewCharacter::new_npc("npc-1", Some("camp-1"), "Guide");
+        insert_character(&mut conn, &npc).expect("Failed to create NPC");
+
+        insert_party_member(&mut conn, &NewPartyMember::new("party-1", "pc-1")).unwrap();
+        insert_party_member(&mut conn, &NewPartyMember::new("party-1", "npc-1")).unwrap();
+        // Adding twice is ignored
+        assert_eq!(
+            insert_party_member(&mut conn, &NewPartyMember::new("party-1", "pc-1")).unwrap(),
+            0
+        );
+
+        assert_eq!(list_party_members(&mut conn, "party-1").unwrap().len(), 2);
+        let pcs = list_party_pcs(&mut conn, "party-1").expect("Failed to list");
+        assert_eq!(pcs.len(), 1);
+        assert_eq!(pcs[0].id, "pc-1");
+
+        // Trashed characters drop out of the party list
+        trash_character(&mut conn, "pc-1", "2024-01-01T00:00:00Z").expect("Failed to trash");
+        assert!(list_party_pcs(&mut conn, "party-1").unwrap().is_empty());
+
+        let parties = list_parties_for_character(&mut conn, "npc-1").unwrap();
+        assert_eq!(parties.len(), 1);
+
+        assert_eq!(
+            delete_party_member(&mut conn, "party-1", "npc-1").unwrap(),
+            1
+        );
+        assert!(list_parties_for_character(&mut conn, "npc-1")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_timeline_order_and_cascade() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let late = NewPartyTimelineEvent::new("evt-1", "party-1", 9, "Reached the keep");
+        let early = NewPartyTimelineEvent::new("evt-2", "party-1", 3, "Left town");
+        insert_party_timeline_event(&mut conn, &late).unwrap();
+        insert_party_timeline_event(&mut conn, &early).unwrap();
+
+        let events = list_party_timeline_events(&mut conn, "party-1").unwrap();
+        assert_eq!(events[0].id, "evt-2");
+        assert_eq!(events[1].id, "evt-1");
+
+        delete_party(&mut conn, "party-1").expect("Failed to delete");
+        assert!(list_party_timeline_events(&mut conn, "party-1")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Player Data Access Layer
+//!
+//! Database operations for the player roster, character ownership, and
+//! player claim codes.
+
+use crate::models::campaign::{
+    Character, NewPlayer, NewPlayerCharacter, NewPlayerClaim, Player, PlayerClaim, UpdatePlayer,
+};
+use crate::schema::{characters, player_characters, player_claims, players};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Players
+// =============================================================================
+
+/// Insert a new player.
+pub fn insert_player(conn: &mut SqliteConnection, player: &NewPlayer) -> QueryResult<String> {
+    diesel::insert_into(players::table)
+        .values(player)
+        .execute(conn)?;
+    Ok(player.id.to_string())
+}
+
+/// Get a player by ID.
+pub fn get_player(conn: &mut SqliteConnection, id: &str) -> QueryResult<Player> {
+    players::table.find(id).first(conn)
+}
+
+/// Get a player by ID, returning None if not found.
+pub fn get_player_optional(conn: &mut SqliteConnection, id: &str) -> QueryResult<Option<Player>> {
+    players::table.find(id).first(conn).optional()
+}
+
+/// Find a campaign's player by name (case-insensitive).
+pub fn get_player_by_name(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    name: &str,
+) -> QueryResult<Option<Player>> {
+    players::table
+        .filter(players::campaign_id.eq(campaign_id))
+        .filter(players::name.eq(name))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's players, alphabetically.
+pub fn list_players(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Player>> {
+    players::table
+        .filter(players::campaign_id.eq(campaign_id))
+        .order(players::name.asc())
+        .load(conn)
+}
+
+/// Update a player.
+pub fn update_player(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdatePlayer,
+) -> QueryResult<usize> {
+    diesel::update(players::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a player. Character links and claims are removed by cascade.
+pub fn delete_player(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(players::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Player Characters
+// =============================================================================
+
+/// Link a character to a player, replacing any previous owner.
+pub fn link_player_character(
+    conn: &mut SqliteConnection,
+    link: &NewPlayerCharacter,
+) -> QueryResult<usize> {
+    diesel::replace_into(player_characters::table)
+        .values(link)
+        .execute(conn)
+}
+
+/// Remove a character's link to its player.
+pub fn unlink_player_character(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<usize> {
+    diesel::delete(player_characters::table.find(character_id)).execute(conn)
+}
+
+/// Get the player who owns a character, if any.
+pub fn get_character_player(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Option<Player>> {
+    players::table
+        .inner_join(player_characters::table)
+        .filter(player_characters::character_id.eq(character_id))
+        .select(Player::as_select())
+        .first(conn)
+        .optional()
+}
+
+/// List a player's characters, alphabetically. Trashed characters are skipped.
+pub fn list_player_characters(
+    conn: &mut SqliteConnection,
+    player_id: &str,
+) -> QueryResult<Vec<Character>> {
+    characters::table
+        .inner_join(player_characters::table)
+        .filter(player_characters::player_id.eq(player_id))
+        .filter(characters::deleted_at.is_null())
+        .order(characters::name.asc())
+        .select(Character::as_select())
+        .load(conn)
+}
+
+// =============================================================================
+// Player Claims
+// =============================================================================
+
+/// Insert a new claim.
+pub fn insert_player_claim(
+    conn: &mut SqliteConnection,
+    claim: &NewPlayerClaim,
+) -> QueryResult<String> {
+    diesel::insert_into(player_claims::table)
+        .values(claim)
+        .execute(conn)?;
+    Ok(claim.id.to_string())
+}
+
+/// Get a claim by ID.
+pub fn get_player_claim(conn: &mut SqliteConnection, id: &str) -> QueryResult<PlayerClaim> {
+    player_claims::table.find(id).first(conn)
+}
+
+/// Find a claim by its normalized code.
+pub fn get_player_claim_by_code(
+    conn: &mut SqliteConnection,
+    code: &str,
+) -> QueryResult<Option<PlayerClaim>> {
+    player_claims::table
+        .filter(player_claims::code.eq(code))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's claims, newest first.
+pub fn list_player_claims(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<PlayerClaim>> {
+    player_claims::table
+        .filter(player_claims::campaign_id.eq(campaign_id))
+        .order(player_claims::created_at.desc())
+        .load(conn)
+}
+
+/// Mark a claim redeemed. Returns 0 if it was already redeemed.
+pub fn mark_player_claim_redeemed(
+    conn: &mut SqliteConnection,
+    id: &str,
+    redeemed_at: &str,
+    character_id: &str,
+) -> QueryResult<usize> {
+    diesel::update(
+        player_claims::table
+            .find(id)
+            .filter(player_claims::redeemed_at.is_null()),
+    )
+    .set((
+        player_claims::redeemed_at.eq(Some(redeemed_at)),
+        player_claims::redeemed_character_id.eq(Some(character_id)),
+    ))
+    .execute(conn)
+}
+
+/// Delete a claim by ID.
+pub fn delete_player_claim(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(player_claims::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        insert_player(conn, &NewPlayer::new("player-1", "camp-1", "Alice")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-2", "camp-1", "Bob")).unwrap();
+        let pc = NewCharacter::new_pc("pc-1", Some("camp-1"), "Aria", "Alice");
+        insert_character(conn, &pc).expect("Failed to create PC");
+    }
+
+    #[test]
+    fn test_player_names_unique_per_campaign() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let found = get_player_by_name(&mut conn, "camp-1", "ALICE").unwrap();
+        assert_eq!(found.map(|p| p.id), Some("player-1".to_string()));
+        assert!(insert_player(&mut conn, &NewPlayer::new("player-3", "camp-1", "alice")).is_err());
+        assert_eq!(list_players(&mut conn, "camp-1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_link_replaces_owner() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        link_player_character(&mut conn, &NewPlayerCharacter::new("pc-1", "player-1")).unwrap();
+        assert_eq!(
+            list_player_characters(&mut conn, "player-1").unwrap().len(),
+            1
+        );
+
+        link_player_character(&mut conn, &NewPlayerCharacter::new("pc-1", "player-2")).unwrap();
+        assert!(list_player_characters(&mut conn, "player-1")
+            .unwrap()
+            .is_empty());
+        let owner = get_character_player(&mut conn, "pc-1").unwrap();
+        assert_eq!(owner.map(|p| p.id), Some("player-2".to_string()));
+
+        assert_eq!(unlink_player_character(&mut conn, "pc-1").unwrap(), 1);
+        assert!(get_character_player(&mut conn, "pc-1").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_claim_redeemed_once() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let claim = NewPlayerClaim::new("claim-1", "camp-1", "player-1", "ABCD2345");
+        insert_player_claim(&mut conn, &claim).unwrap();
+
+        let found = get_player_claim_by_code(&mut conn, "ABCD2345").unwrap();
+        assert!(found.is_some());
+
+        let now = "2024-01-01T00:00:00Z";
+        assert_eq!(
+            mark_player_claim_redeemed(&mut conn, "claim-1", now, "pc-1").unwrap(),
+            1
+        );
+        assert_eq!(
+            mark_player_claim_redeemed(&mut conn, "claim-1", now, "pc-1").unwrap(),
+            0
+        );
+
+        let claim = get_player_claim(&mut conn, "claim-1").unwrap();
+        assert!(claim.is_redeemed());
+        assert_eq!(claim.redeemed_character_id, Some("pc-1".to_string()));
+
+        // Deleting the player removes its claims
+        delete_player(&mut conn, "player-1").unwrap();
+        assert!(list_player_claims(&mut conn, "camp-1").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+//! QuickAction Data Access Layer
+//!
+//! Database operations for campaign quick actions.
+
+use crate::models::campaign::{NewQuickAction, QuickAction, UpdateQuickAction};
+use crate::schema::quick_actions;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new quick action.
+pub fn insert_quick_action(
+    conn: &mut SqliteConnection,
+    action: &NewQuickAction,
+) -> QueryResult<String> {
+    diesel::insert_into(quick_actions::table)
+        .values(action)
+        .execute(conn)?;
+
+    Ok(action.id.to_string())
+}
+
+/// Get a quick action by ID.
+pub fn get_quick_action(conn: &mut SqliteConnection, id: &str) -> QueryResult<QuickAction> {
+    quick_actions::table.find(id).first(conn)
+}
+
+/// Get a quick action by ID, returning None if not found.
+pub fn get_quick_action_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<QuickAction>> {
+    quick_actions::table.find(id).first(conn).optional()
+}
+
+/// List all quick actions for a campaign in soundboard order.
+pub fn list_quick_actions(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<QuickAction>> {
+    quick_actions::table
+        .filter(quick_actions::campaign_id.eq(campaign_id))
+        .order((quick_actions::sort_order.asc(), quick_actions::created_at.asc()))
+        .load(conn)
+}
+
+/// Get the next available sort_order for a campaign's quick actions.
+pub fn next_quick_action_sort_order(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<i32> {
+    let max: Option<i32> = quick_actions::table
+        .filter(quick_actions::campaign_id.eq(campaign_id))
+        .select(diesel::dsl::max(quick_actions::sort_order))
+        .first(conn)?;
+
+    Ok(max.unwrap_or(0) + 1)
+}
+
+/// Update a quick action.
+pub fn update_quick_action(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateQuickAction,
+) -> QueryResult<usize> {
+    diesel::update(quick_actions::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a quick action by ID.
+pub fn delete_quick_action(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(quick_actions::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::db::test_connection;
+    use crate::models::campaign::NewCampaign;
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+    }
+
+    #[test]
+    fn test_insert_and_get_quick_action() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let action = NewQuickAction::new("qa-1", "camp-1", "Open the vault", "[]")
+            .with_description("Reveal the vault and light the braziers");
+        insert_quick_action(&mut conn, &action).expect("Failed to insert");
+
+        let retrieved = get_quick_action(&mut conn, "qa-1").expect("Failed to get");
+        assert_eq!(retrieved.name, "Open the vault");
+        assert_eq!(retrieved.steps, "[]");
+        assert!(get_quick_action_optional(&mut conn, "missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_list_in_sort_order() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        assert_eq!(next_quick_action_sort_order(&mut conn, "camp-1").unwrap(), 1);
+
+        let second = NewQuickAction::new("qa-2", "camp-1", "Ambush", "[]").with_sort_order(2);
+        let first = NewQuickAction::new("qa-1", "camp-1", "Lights out", "[]").with_sort_order(1);
+        insert_quick_action(&mut conn, &second).expect("Failed to insert");
+        insert_quick_action(&mut conn, &first).expect("Failed to insert");
+
+        let actions = list_quick_actions(&mut conn, "camp-1").expect("Failed to list");
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0].id, "qa-1");
+        assert_eq!(actions[1].id, "qa-2");
+
+        assert_eq!(next_quick_action_sort_order(&mut conn, "camp-1").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_update_and_delete_quick_action() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let action = NewQuickAction::new("qa-1", "camp-1", "Lights out", "[]");
+        insert_quick_action(&mut conn, &action).expect("Failed to insert");
+
+        let update = UpdateQuickAction {
+            name: Some("Darkness falls"),
+            updated_at: Some("2024-01-20T12:00:00Z"),
+            ..Default::default()
+        };
+        update_quick_action(&mut conn, "qa-1", &update).expect("Failed to update");
+        let retrieved = get_quick_action(&mut conn, "qa-1").expect("Failed to get");
+        assert_eq!(retrieved.name, "Darkness falls");
+
+        assert_eq!(delete_quick_action(&mut conn, "qa-1").unwrap(), 1);
+        assert!(list_quick_actions(&mut conn, "camp-1").unwrap().is_empty());
+    }
+}
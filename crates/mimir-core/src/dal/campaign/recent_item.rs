@@ -0,0 +1,196 @@
+//! RecentItem Data Access Layer
+//!
+//! Database operations for recently viewed catalog entries, documents, and maps.
+
+use crate::models::campaign::{ItemKind, NewRecentItem, RecentItem};
+use crate::schema::recent_items;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new recent item.
+pub fn insert_recent_item(
+    conn: &mut SqliteConnection,
+    item: &NewRecentItem,
+) -> QueryResult<String> {
+    diesel::insert_into(recent_items::table)
+        .values(item)
+        .execute(conn)?;
+
+    Ok(item.id.to_string())
+}
+
+/// Find the history entry for an item, if it has been viewed before.
+pub fn find_recent_item(
+    conn: &mut SqliteConnection,
+    kind: ItemKind,
+    ref_id: &str,
+    ref_source: Option<&str>,
+) -> QueryResult<Option<RecentItem>> {
+    let mut query = recent_items::table
+        .filter(recent_items::kind.eq(kind.as_str()))
+        .filter(recent_items::ref_id.eq(ref_id))
+        .into_boxed();
+
+    query = match ref_source {
+        Some(source) => query.filter(recent_items::ref_source.eq(source)),
+        None => query.filter(recent_items::ref_source.is_null()),
+    };
+
+    query.first(conn).optional()
+}
+
+/// Record another view of an existing item.
+pub fn touch_recent_item(
+    conn: &mut SqliteConnection,
+    id: &str,
+    title: &str,
+    viewed_at: &str,
+) -> QueryResult<usize> {
+    diesel::update(recent_items::table.find(id))
+        .set((
+            recent_items::view_count.eq(recent_items::view_count + 1),
+            recent_items::title.eq(title),
+            recent_items::last_viewed_at.eq(viewed_at),
+        ))
+        .execute(conn)
+}
+
+/// List recently viewed items, most recent first.
+///
+/// With a campaign, catalog entries are included along with that campaign's
+/// documents and maps; other campaigns' content is left out.
+pub fn list_recent_items(
+    conn: &mut SqliteConnection,
+    kind: Option<ItemKind>,
+    campaign_id: Option<&str>,
+    limit: i64,
+) -> QueryResult<Vec<RecentItem>> {
+    let mut query = recent_items::table.into_boxed();
+
+    if let Some(kind) = kind {
+        query = query.filter(recent_items::kind.eq(kind.as_str()));
+    }
+    if let Some(campaign_id) = campaign_id {
+        query = query.filter(
+            recent_items::campaign_id
+                .is_null()
+                .or(recent_items::campaign_id.eq(campaign_id)),
+        );
+    }
+
+    query
+        .order(recent_items::last_viewed_at.desc())
+        .limit(limit)
+        .load(conn)
+}
+
+/// Delete a recent item by ID.
+pub fn delete_recent_item(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(recent_items::table.find(id)).execute(conn)
+}
+
+/// Delete all recent history.
+pub fn delete_all_recent_items(conn: &mut SqliteConnection) -> QueryResult<usize> {
+    diesel::delete(recent_items::table).execute(conn)
+}
+
+/// Keep only the `keep` most recently viewed items.
+pub fn prune_recent_items(conn: &mut SqliteConnection, keep: i64) -> QueryResult<usize> {
+    let kept: Vec<String> = recent_items::table
+        .select(recent_items::id)
+        .order(recent_items::last_viewed_at.desc())
+        .limit(keep)
+        .load(conn)?;
+
+    diesel::delete(recent_items::table.filter(recent_items::id.ne_all(kept))).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::db::test_connection;
+    use crate::models::campaign::NewCampaign;
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_campaign(conn, &NewCampaign::new("camp-2", "Other Campaign")).unwrap();
+    }
+
+    #[test]
+    fn test_find_and_touch() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let item = NewRecentItem::new(
+            "r-1",
+            ItemKind::Monster,
+            "Goblin",
+            "Goblin",
+            "2024-01-20T12:00:00Z",
+        )
+        .with_source("MM");
+        insert_recent_item(&mut conn, &item).expect("Failed to insert");
+
+        assert!(find_recent_item(&mut conn, ItemKind::Monster, "Goblin", None)
+            .unwrap()
+            .is_none());
+        let found = find_recent_item(&mut conn, ItemKind::Monster, "Goblin", Some("MM"))
+            .unwrap()
+            .expect("Item should be found");
+
+        touch_recent_item(&mut conn, &found.id, "Goblin", "2024-01-20T13:00:00Z").unwrap();
+        let touched = find_recent_item(&mut conn, ItemKind::Monster, "Goblin", Some("MM"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(touched.view_count, 2);
+        assert_eq!(touched.last_viewed_at, "2024-01-20T13:00:00Z");
+    }
+
+    #[test]
+    fn test_list_filters_by_campaign_and_kind() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let items = [
+            NewRecentItem::new("r-1", ItemKind::Spell, "Fireball", "Fireball", "2024-01-20T12:00:00Z")
+                .with_source("PHB"),
+            NewRecentItem::new("r-2", ItemKind::Document, "doc-1", "Heist", "2024-01-20T12:01:00Z")
+                .with_campaign("camp-1"),
+            NewRecentItem::new("r-3", ItemKind::Document, "doc-2", "Other", "2024-01-20T12:02:00Z")
+                .with_campaign("camp-2"),
+        ];
+        for item in &items {
+            insert_recent_item(&mut conn, item).unwrap();
+        }
+
+        let all = list_recent_items(&mut conn, None, None, 10).unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].id, "r-3");
+
+        let camp1 = list_recent_items(&mut conn, None, Some("camp-1"), 10).unwrap();
+        let ids: Vec<&str> = camp1.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["r-2", "r-1"]);
+
+        let docs = list_recent_items(&mut conn, Some(ItemKind::Document), Some("camp-1"), 10).unwrap();
+        assert_eq!(docs.len(), 1);
+    }
+
+    #[test]
+    fn test_prune_keeps_most_recent() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        for (i, name) in ["A", "B", "C"].iter().enumerate() {
+            let id = format!("r-{}", i);
+            let at = format!("2024-01-20T12:0{}:00Z", i);
+            let item = NewRecentItem::new(&id, ItemKind::Condition, name, name, &at).with_source("PHB");
+            insert_recent_item(&mut conn, &item).unwrap();
+        }
+
+        assert_eq!(prune_recent_items(&mut conn, 2).unwrap(), 1);
+        let remaining = list_recent_items(&mut conn, None, None, 10).unwrap();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.iter().all(|i| i.id != "r-0"));
+    }
+}
@@ -0,0 +1,283 @@
+//! Relationship Data Access Layer
+//!
+//! Database operations for dispositions, party reputations, and NPC
+//! interaction history.
+
+use crate::models::campaign::{
+    Disposition, NewDisposition, NewNpcInteraction, NewPartyReputation, NpcInteraction,
+    PartyReputation, UpdateDisposition, UpdatePartyReputation,
+};
+use crate::schema::{dispositions, npc_interactions, party_reputations};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Dispositions
+// =============================================================================
+
+/// Insert a new disposition.
+pub fn insert_disposition(
+    conn: &mut SqliteConnection,
+    disposition: &NewDisposition,
+) -> QueryResult<String> {
+    diesel::insert_into(dispositions::table)
+        .values(disposition)
+        .execute(conn)?;
+
+    Ok(disposition.id.to_string())
+}
+
+/// Get a disposition by ID.
+pub fn get_disposition(conn: &mut SqliteConnection, id: &str) -> QueryResult<Disposition> {
+    dispositions::table.find(id).first(conn)
+}
+
+/// Get an NPC's own disposition, if one has been set.
+pub fn get_npc_disposition(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Option<Disposition>> {
+    dispositions::table
+        .filter(dispositions::character_id.eq(character_id))
+        .first(conn)
+        .optional()
+}
+
+/// Get a faction's disposition, if one has been set.
+pub fn get_faction_disposition(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    faction: &str,
+) -> QueryResult<Option<Disposition>> {
+    dispositions::table
+        .filter(dispositions::campaign_id.eq(campaign_id))
+        .filter(dispositions::faction.eq(faction))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's dispositions, faction ones first, then NPC ones.
+pub fn list_dispositions(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<Disposition>> {
+    dispositions::table
+        .filter(dispositions::campaign_id.eq(campaign_id))
+        .order((
+            dispositions::character_id.is_not_null(),
+            dispositions::faction.asc(),
+            dispositions::created_at.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a disposition.
+pub fn update_disposition(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateDisposition,
+) -> QueryResult<usize> {
+    diesel::update(dispositions::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a disposition by ID.
+pub fn delete_disposition(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(dispositions::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Party Reputations
+// =============================================================================
+
+/// Insert a new party reputation.
+pub fn insert_party_reputation(
+    conn: &mut SqliteConnection,
+    reputation: &NewPartyReputation,
+) -> QueryResult<String> {
+    diesel::insert_into(party_reputations::table)
+        .values(reputation)
+        .execute(conn)?;
+
+    Ok(reputation.id.to_string())
+}
+
+/// Get a party's reputation with a faction, if one has been set.
+pub fn get_party_reputation(
+    conn: &mut SqliteConnection,
+    party_id: &str,
+    faction: &str,
+) -> QueryResult<Option<PartyReputation>> {
+    party_reputations::table
+        .filter(party_reputations::party_id.eq(party_id))
+        .filter(party_reputations::faction.eq(faction))
+        .first(conn)
+        .optional()
+}
+
+/// List a campaign's party reputations by party, then faction.
+pub fn list_party_reputations(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<PartyReputation>> {
+    party_reputations::table
+        .filter(party_reputations::campaign_id.eq(campaign_id))
+        .order((
+            party_reputations::party_id.asc(),
+            party_reputations::faction.asc(),
+        ))
+        .load(conn)
+}
+
+/// Update a party reputation.
+pub fn update_party_reputation(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdatePartyReputation,
+) -> QueryResult<usize> {
+    diesel::update(party_reputations::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a party reputation by ID.
+pub fn delete_party_reputation(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(party_reputations::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// NPC Interactions
+// =============================================================================
+
+/// Insert a new NPC interaction.
+pub fn insert_npc_interaction(
+    conn: &mut SqliteConnection,
+    interaction: &NewNpcInteraction,
+) -> QueryResult<String> {
+    diesel::insert_into(npc_interactions::table)
+        .values(interaction)
+        .execute(conn)?;
+
+    Ok(interaction.id.to_string())
+}
+
+/// Get an NPC interaction by ID.
+pub fn get_npc_interaction(conn: &mut SqliteConnection, id: &str) -> QueryResult<NpcInteraction> {
+    npc_interactions::table.find(id).first(conn)
+}
+
+/// List an NPC's interactions, most recent first.
+pub fn list_npc_interactions(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> QueryResult<Vec<NpcInteraction>> {
+    npc_interactions::table
+        .filter(npc_interactions::character_id.eq(character_id))
+        .order((
+            npc_interactions::created_at.desc(),
+            npc_interactions::id.desc(),
+        ))
+        .load(conn)
+}
+
+/// Delete an NPC interaction by ID.
+pub fn delete_npc_interaction(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(npc_interactions::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, insert_party};
+    use crate::db::test_connection;
+    use crate::models::campaign::{Attitude, NewCampaign, NewCharacter, NewParty, ReactionDice};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_npc("npc-1", Some("camp-1"), "Toblen"),
+        )
+        .unwrap();
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Heroes")).unwrap();
+    }
+
+    #[test]
+    fn test_dispositions() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        let npc = NewDisposition::for_npc("disp-1", "camp-1", "npc-1", 2);
+        insert_disposition(&mut conn, &npc).expect("Failed to insert");
+        let faction = NewDisposition::for_faction("disp-2", "camp-1", "Redbrands", -3);
+        insert_disposition(&mut conn, &faction).unwrap();
+
+        assert_eq!(
+            get_npc_disposition(&mut conn, "npc-1")
+                .unwrap()
+                .unwrap()
+                .disposition,
+            2
+        );
+        assert!(get_faction_disposition(&mut conn, "camp-1", "Zhentarim")
+            .unwrap()
+            .is_none());
+        let all = list_dispositions(&mut conn, "camp-1").unwrap();
+        assert_eq!(all[0].faction.as_deref(), Some("Redbrands"));
+
+        // One disposition per faction
+        let duplicate = NewDisposition::for_faction("disp-3", "camp-1", "Redbrands", 1);
+        assert!(insert_disposition(&mut conn, &duplicate).is_err());
+
+        let update = UpdateDisposition {
+            disposition: Some(-1),
+            ..Default::default()
+        };
+        update_disposition(&mut conn, "disp-2", &update).unwrap();
+        assert_eq!(
+            get_faction_disposition(&mut conn, "camp-1", "Redbrands")
+                .unwrap()
+                .unwrap()
+                .disposition,
+            -1
+        );
+    }
+
+    #[test]
+    fn test_reputations_and_interactions() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        let reputation = NewPartyReputation::new("rep-1", "camp-1", "party-1", "Redbrands", -2);
+        insert_party_reputation(&mut conn, &reputation).unwrap();
+        assert_eq!(
+            get_party_reputation(&mut conn, "party-1", "Redbrands")
+                .unwrap()
+                .unwrap()
+                .reputation,
+            -2
+        );
+        assert_eq!(
+            list_party_reputations(&mut conn, "camp-1").unwrap().len(),
+            1
+        );
+
+        for (id, roll) in [("int-1", 4), ("int-2", 10)] {
+            let interaction = NewNpcInteraction::new(
+                id,
+                "camp-1",
+                "npc-1",
+                ReactionDice::TwoD6,
+                roll,
+                0,
+                Attitude::Indifferent,
+            )
+            .with_party("party-1");
+            insert_npc_interaction(&mut conn, &interaction).unwrap();
+        }
+        let history = list_npc_interactions(&mut conn, "npc-1").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].id, "int-2");
+        assert_eq!(delete_npc_interaction(&mut conn, "int-1").unwrap(), 1);
+        assert_eq!(list_npc_interactions(&mut conn, "npc-1").unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,185 @@
+//! Rumor Data Access Layer
+//!
+//! Database operations for rumors and the record of which players have
+//! heard them.
+
+use crate::models::campaign::{NewRumor, NewRumorHearing, Rumor, RumorHearing, UpdateRumor};
+use crate::schema::{rumor_hearings, rumors};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Rumors
+// =============================================================================
+
+/// Insert a new rumor.
+pub fn insert_rumor(conn: &mut SqliteConnection, rumor: &NewRumor) -> QueryResult<String> {
+    diesel::insert_into(rumors::table)
+        .values(rumor)
+        .execute(conn)?;
+
+    Ok(rumor.id.to_string())
+}
+
+/// Get a rumor by ID.
+pub fn get_rumor(conn: &mut SqliteConnection, id: &str) -> QueryResult<Rumor> {
+    rumors::table.find(id).first(conn)
+}
+
+/// Get a rumor by ID, returning None if not found.
+pub fn get_rumor_optional(conn: &mut SqliteConnection, id: &str) -> QueryResult<Option<Rumor>> {
+    rumors::table.find(id).first(conn).optional()
+}
+
+/// List a campaign's rumors in the order they were added.
+pub fn list_rumors(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Rumor>> {
+    rumors::table
+        .filter(rumors::campaign_id.eq(campaign_id))
+        .order((rumors::created_at.asc(), rumors::id.asc()))
+        .load(conn)
+}
+
+/// Update a rumor.
+pub fn update_rumor(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateRumor,
+) -> QueryResult<usize> {
+    diesel::update(rumors::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a rumor by ID. Its hearings are deleted with it.
+pub fn delete_rumor(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(rumors::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Rumor Hearings
+// =============================================================================
+
+/// Record that a player heard a rumor, replacing any earlier record.
+pub fn insert_rumor_hearing(
+    conn: &mut SqliteConnection,
+    hearing: &NewRumorHearing,
+) -> QueryResult<usize> {
+    diesel::replace_into(rumor_hearings::table)
+        .values(hearing)
+        .execute(conn)
+}
+
+/// Remove the record of a player hearing a rumor.
+pub fn delete_rumor_hearing(
+    conn: &mut SqliteConnection,
+    rumor_id: &str,
+    player_id: &str,
+) -> QueryResult<usize> {
+    diesel::delete(rumor_hearings::table.find((rumor_id, player_id))).execute(conn)
+}
+
+/// List who has heard a rumor, earliest first.
+pub fn list_rumor_hearings(
+    conn: &mut SqliteConnection,
+    rumor_id: &str,
+) -> QueryResult<Vec<RumorHearing>> {
+    rumor_hearings::table
+        .filter(rumor_hearings::rumor_id.eq(rumor_id))
+        .order((
+            rumor_hearings::heard_at.asc(),
+            rumor_hearings::player_id.asc(),
+        ))
+        .load(conn)
+}
+
+/// List the hearings of all of a campaign's rumors.
+pub fn list_campaign_rumor_hearings(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<RumorHearing>> {
+    rumor_hearings::table
+        .inner_join(rumors::table)
+        .filter(rumors::campaign_id.eq(campaign_id))
+        .order((
+            rumor_hearings::heard_at.asc(),
+            rumor_hearings::player_id.asc(),
+        ))
+        .select(RumorHearing::as_select())
+        .load(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_player, insert_campaign, insert_player};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewPlayer};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        insert_player(conn, &NewPlayer::new("player-1", "camp-1", "Alice")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-2", "camp-1", "Bob")).unwrap();
+    }
+
+    #[test]
+    fn test_insert_update_and_list() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let wolves = NewRumor::new("rumor-1", "camp-1", "Wolves walk on two legs in the hills.");
+        insert_rumor(&mut conn, &wolves).expect("Failed to insert");
+        let gold =
+            NewRumor::new("rumor-2", "camp-1", "The old mine is full of gold.").with_truth(false);
+        insert_rumor(&mut conn, &gold).expect("Failed to insert");
+
+        let update = UpdateRumor {
+            notes: Some(Some("Spread by the mine's owner.")),
+            ..Default::default()
+        };
+        update_rumor(&mut conn, "rumor-2", &update).expect("Failed to update");
+
+        let rumors = list_rumors(&mut conn, "camp-1").expect("Failed to list");
+        assert_eq!(rumors.len(), 2);
+        let gold = get_rumor(&mut conn, "rumor-2").expect("Failed to get");
+        assert!(!gold.is_true());
+        assert_eq!(gold.notes.as_deref(), Some("Spread by the mine's owner."));
+
+        assert_eq!(delete_rumor(&mut conn, "rumor-1").unwrap(), 1);
+        assert!(get_rumor_optional(&mut conn, "rumor-1").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_hearings() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        insert_rumor(&mut conn, &NewRumor::new("rumor-1", "camp-1", "Wolves")).unwrap();
+
+        let alice = NewRumorHearing::new("rumor-1", "player-1", "2026-01-01T00:00:00Z");
+        insert_rumor_hearing(&mut conn, &alice).expect("Failed to insert");
+        // Hearing it again replaces the record rather than failing
+        let again = NewRumorHearing::new("rumor-1", "player-1", "2026-01-02T00:00:00Z");
+        insert_rumor_hearing(&mut conn, &again).expect("Failed to replace");
+        let bob = NewRumorHearing::new("rumor-1", "player-2", "2026-01-03T00:00:00Z");
+        insert_rumor_hearing(&mut conn, &bob).unwrap();
+
+        let hearings = list_rumor_hearings(&mut conn, "rumor-1").unwrap();
+        assert_eq!(hearings.len(), 2);
+        assert_eq!(hearings[0].heard_at, "2026-01-02T00:00:00Z");
+        assert_eq!(
+            list_campaign_rumor_hearings(&mut conn, "camp-1")
+                .unwrap()
+                .len(),
+            2
+        );
+
+        delete_player(&mut conn, "player-2").unwrap();
+        assert_eq!(
+            delete_rumor_hearing(&mut conn, "rumor-1", "player-1").unwrap(),
+            1
+        );
+        assert!(list_rumor_hearings(&mut conn, "rumor-1")
+            .unwrap()
+            .is_empty());
+    }
+}
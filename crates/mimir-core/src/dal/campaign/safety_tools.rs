@@ -0,0 +1,64 @@
+//! Campaign Safety Tools Data Access Layer
+//!
+//! Database operations for each campaign's safety tools configuration.
+
+use crate::models::campaign::{CampaignSafetyTools, NewCampaignSafetyTools};
+use crate::schema::campaign_safety_tools;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Get a campaign's safety tools, if they have been configured.
+pub fn get_campaign_safety_tools(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Option<CampaignSafetyTools>> {
+    campaign_safety_tools::table
+        .find(campaign_id)
+        .first(conn)
+        .optional()
+}
+
+/// Insert or replace a campaign's safety tools.
+pub fn upsert_campaign_safety_tools(
+    conn: &mut SqliteConnection,
+    tools: &NewCampaignSafetyTools,
+) -> QueryResult<usize> {
+    diesel::replace_into(campaign_safety_tools::table)
+        .values(tools)
+        .execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_campaign, insert_campaign};
+    use crate::db::test_connection;
+    use crate::models::campaign::NewCampaign;
+
+    #[test]
+    fn test_upsert_replaces_configuration() {
+        let mut conn = test_connection();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        assert!(get_campaign_safety_tools(&mut conn, "camp-1")
+            .unwrap()
+            .is_none());
+
+        let now = "2024-01-01T00:00:00Z";
+        let tools = NewCampaignSafetyTools::new("camp-1", now).with_limits(r#"["Spiders"]"#, "[]");
+        upsert_campaign_safety_tools(&mut conn, &tools).unwrap();
+
+        let tools = NewCampaignSafetyTools::new("camp-1", now).with_x_card(false, None);
+        upsert_campaign_safety_tools(&mut conn, &tools).unwrap();
+
+        let stored = get_campaign_safety_tools(&mut conn, "camp-1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.lines, "[]");
+        assert!(!stored.x_card_enabled);
+
+        delete_campaign(&mut conn, "camp-1").unwrap();
+        assert!(get_campaign_safety_tools(&mut conn, "camp-1")
+            .unwrap()
+            .is_none());
+    }
+}
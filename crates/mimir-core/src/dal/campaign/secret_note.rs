@@ -0,0 +1,135 @@
+//! Secret Note Data Access Layer
+//!
+//! Database operations for per-player secret notes.
+
+use crate::models::campaign::{NewSecretNote, SecretNote, UpdateSecretNote};
+use crate::schema::secret_notes;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Insert a new secret note.
+pub fn insert_secret_note(
+    conn: &mut SqliteConnection,
+    note: &NewSecretNote,
+) -> QueryResult<String> {
+    diesel::insert_into(secret_notes::table)
+        .values(note)
+        .execute(conn)?;
+
+    Ok(note.id.to_string())
+}
+
+/// Get a secret note by ID.
+pub fn get_secret_note(conn: &mut SqliteConnection, id: &str) -> QueryResult<SecretNote> {
+    secret_notes::table.find(id).first(conn)
+}
+
+/// Get a secret note by ID, returning None if not found.
+pub fn get_secret_note_optional(
+    conn: &mut SqliteConnection,
+    id: &str,
+) -> QueryResult<Option<SecretNote>> {
+    secret_notes::table.find(id).first(conn).optional()
+}
+
+/// List a campaign's secret notes in the order they were added.
+pub fn list_secret_notes(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+) -> QueryResult<Vec<SecretNote>> {
+    secret_notes::table
+        .filter(secret_notes::campaign_id.eq(campaign_id))
+        .order((secret_notes::created_at.asc(), secret_notes::id.asc()))
+        .load(conn)
+}
+
+/// List a campaign's notes that have not been delivered yet, optionally
+/// only those for one player.
+pub fn list_outstanding_secret_notes(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    player_id: Option<&str>,
+) -> QueryResult<Vec<SecretNote>> {
+    let mut query = secret_notes::table
+        .filter(secret_notes::campaign_id.eq(campaign_id))
+        .filter(secret_notes::delivered_at.is_null())
+        .order((secret_notes::created_at.asc(), secret_notes::id.asc()))
+        .into_boxed();
+    if let Some(player_id) = player_id {
+        query = query.filter(secret_notes::player_id.eq(player_id));
+    }
+    query.load(conn)
+}
+
+/// Update a secret note.
+pub fn update_secret_note(
+    conn: &mut SqliteConnection,
+    id: &str,
+    update: &UpdateSecretNote,
+) -> QueryResult<usize> {
+    diesel::update(secret_notes::table.find(id))
+        .set(update)
+        .execute(conn)
+}
+
+/// Delete a secret note by ID.
+pub fn delete_secret_note(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(secret_notes::table.find(id)).execute(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_player, insert_campaign, insert_player};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewPlayer};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        insert_player(conn, &NewPlayer::new("player-1", "camp-1", "Alice")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-2", "camp-1", "Bob")).unwrap();
+    }
+
+    #[test]
+    fn test_outstanding_notes() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        let dream = NewSecretNote::new("note-1", "camp-1", "player-1", "Dream", "A voice.");
+        insert_secret_note(&mut conn, &dream).expect("Failed to insert");
+        let letter = NewSecretNote::new("note-2", "camp-1", "player-2", "Letter", "Burn this.");
+        insert_secret_note(&mut conn, &letter).unwrap();
+
+        let outstanding = list_outstanding_secret_notes(&mut conn, "camp-1", None).unwrap();
+        assert_eq!(outstanding.len(), 2);
+        let alice = list_outstanding_secret_notes(&mut conn, "camp-1", Some("player-1")).unwrap();
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice[0].id, "note-1");
+
+        let update = UpdateSecretNote {
+            delivered_at: Some(Some("2026-01-01T00:00:00Z")),
+            ..Default::default()
+        };
+        update_secret_note(&mut conn, "note-1", &update).unwrap();
+        assert!(!get_secret_note(&mut conn, "note-1")
+            .unwrap()
+            .is_outstanding());
+        let outstanding = list_outstanding_secret_notes(&mut conn, "camp-1", None).unwrap();
+        assert_eq!(outstanding.len(), 1);
+        assert_eq!(list_secret_notes(&mut conn, "camp-1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_deleted_with_player() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+        let dream = NewSecretNote::new("note-1", "camp-1", "player-1", "Dream", "A voice.");
+        insert_secret_note(&mut conn, &dream).unwrap();
+
+        delete_player(&mut conn, "player-1").unwrap();
+        assert!(get_secret_note_optional(&mut conn, "note-1")
+            .unwrap()
+            .is_none());
+        assert_eq!(delete_secret_note(&mut conn, "note-1").unwrap(), 0);
+    }
+}
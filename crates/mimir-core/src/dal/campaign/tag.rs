@@ -0,0 +1,210 @@
+//! Tag Data Access Layer
+//!
+//! Database operations for campaign tags and the links attaching them to entities.
+
+use crate::models::campaign::{EntityTag, NewEntityTag, NewTag, Tag, TaggableType, UpdateTag};
+use crate::schema::{entity_tags, tags};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+// =============================================================================
+// Tags
+// =============================================================================
+
+/// Insert a new tag.
+pub fn insert_tag(conn: &mut SqliteConnection, tag: &NewTag) -> QueryResult<String> {
+    diesel::insert_into(tags::table).values(tag).execute(conn)?;
+    Ok(tag.id.to_string())
+}
+
+/// Get a tag by ID.
+pub fn get_tag(conn: &mut SqliteConnection, id: &str) -> QueryResult<Tag> {
+    tags::table.find(id).first(conn)
+}
+
+/// Get a tag by ID, returning None if not found.
+pub fn get_tag_optional(conn: &mut SqliteConnection, id: &str) -> QueryResult<Option<Tag>> {
+    tags::table.find(id).first(conn).optional()
+}
+
+/// Find a campaign's tag by name (case-insensitive).
+pub fn get_tag_by_name(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    name: &str,
+) -> QueryResult<Option<Tag>> {
+    tags::table
+        .filter(tags::campaign_id.eq(campaign_id))
+        .filter(tags::name.eq(name))
+        .first(conn)
+        .optional()
+}
+
+/// List all tags for a campaign, alphabetically.
+pub fn list_tags(conn: &mut SqliteConnection, campaign_id: &str) -> QueryResult<Vec<Tag>> {
+    tags::table
+        .filter(tags::campaign_id.eq(campaign_id))
+        .order(tags::name.asc())
+        .load(conn)
+}
+
+/// Update a tag.
+pub fn update_tag(conn: &mut SqliteConnection, id: &str, update: &UpdateTag) -> QueryResult<usize> {
+    diesel::update(tags::table.find(id)).set(update).execute(conn)
+}
+
+/// Delete a tag (its entity links are removed by cascade).
+pub fn delete_tag(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(tags::table.find(id)).execute(conn)
+}
+
+// =============================================================================
+// Entity Tags
+// =============================================================================
+
+/// Attach a tag to an entity. Attaching a tag twice is a no-op.
+pub fn insert_entity_tag(conn: &mut SqliteConnection, link: &NewEntityTag) -> QueryResult<usize> {
+    diesel::insert_or_ignore_into(entity_tags::table)
+        .values(link)
+        .execute(conn)
+}
+
+/// Detach a tag from an entity.
+pub fn delete_entity_tag(
+    conn: &mut SqliteConnection,
+    tag_id: &str,
+    entity_type: TaggableType,
+    entity_id: &str,
+) -> QueryResult<usize> {
+    diesel::delete(
+        entity_tags::table
+            .filter(entity_tags::tag_id.eq(tag_id))
+            .filter(entity_tags::entity_type.eq(entity_type.as_str()))
+            .filter(entity_tags::entity_id.eq(entity_id)),
+    )
+    .execute(conn)
+}
+
+/// List the tags attached to an entity, alphabetically.
+pub fn list_tags_for_entity(
+    conn: &mut SqliteConnection,
+    entity_type: TaggableType,
+    entity_id: &str,
+) -> QueryResult<Vec<Tag>> {
+    tags::table
+        .inner_join(entity_tags::table)
+        .filter(entity_tags::entity_type.eq(entity_type.as_str()))
+        .filter(entity_tags::entity_id.eq(entity_id))
+        .order(tags::name.asc())
+        .select(Tag::as_select())
+        .load(conn)
+}
+
+/// List every entity carrying a tag, oldest link first.
+pub fn list_entity_tags_for_tag(
+    conn: &mut SqliteConnection,
+    tag_id: &str,
+) -> QueryResult<Vec<EntityTag>> {
+    entity_tags::table
+        .filter(entity_tags::tag_id.eq(tag_id))
+        .order(entity_tags::created_at.asc())
+        .load(conn)
+}
+
+/// List the IDs of entities of one type carrying a tag.
+pub fn list_tagged_entity_ids(
+    conn: &mut SqliteConnection,
+    tag_id: &str,
+    entity_type: TaggableType,
+) -> QueryResult<Vec<String>> {
+    entity_tags::table
+        .filter(entity_tags::tag_id.eq(tag_id))
+        .filter(entity_tags::entity_type.eq(entity_type.as_str()))
+        .select(entity_tags::entity_id)
+        .load(conn)
+}
+
+/// Count the entities carrying a tag.
+pub fn count_entity_tags(conn: &mut SqliteConnection, tag_id: &str) -> QueryResult<i64> {
+    entity_tags::table
+        .filter(entity_tags::tag_id.eq(tag_id))
+        .count()
+        .get_result(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{delete_document, insert_campaign, insert_document};
+    use crate::db::test_connection;
+    use crate::models::campaign::{NewCampaign, NewDocument};
+
+    fn setup_test_data(conn: &mut SqliteConnection) {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+
+        let doc = NewDocument::for_campaign("doc-1", "camp-1", "Heist Plan", "notes");
+        insert_document(conn, &doc).expect("Failed to create document");
+    }
+
+    #[test]
+    fn test_insert_and_find_tag_by_name() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        let tag = NewTag::new("tag-1", "camp-1", "Act 2").with_color("#8b5cf6");
+        insert_tag(&mut conn, &tag).expect("Failed to insert");
+
+        let found = get_tag_by_name(&mut conn, "camp-1", "act 2")
+            .expect("Failed to query")
+            .expect("Tag should match case-insensitively");
+        assert_eq!(found.id, "tag-1");
+        assert_eq!(found.color, Some("#8b5cf6".to_string()));
+
+        // Names are unique per campaign regardless of case
+        let duplicate = NewTag::new("tag-2", "camp-1", "ACT 2");
+        assert!(insert_tag(&mut conn, &duplicate).is_err());
+    }
+
+    #[test]
+    fn test_tag_and_untag_entity() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        insert_tag(&mut conn, &NewTag::new("tag-1", "camp-1", "Act 2")).unwrap();
+        let link = NewEntityTag::new("tag-1", TaggableType::Document, "doc-1");
+        assert_eq!(insert_entity_tag(&mut conn, &link).unwrap(), 1);
+        assert_eq!(insert_entity_tag(&mut conn, &link).unwrap(), 0);
+
+        let tags = list_tags_for_entity(&mut conn, TaggableType::Document, "doc-1").unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(
+            list_tagged_entity_ids(&mut conn, "tag-1", TaggableType::Document).unwrap(),
+            vec!["doc-1".to_string()]
+        );
+        assert!(list_tagged_entity_ids(&mut conn, "tag-1", TaggableType::Map)
+            .unwrap()
+            .is_empty());
+
+        delete_entity_tag(&mut conn, "tag-1", TaggableType::Document, "doc-1").unwrap();
+        assert_eq!(count_entity_tags(&mut conn, "tag-1").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_links_removed_with_entity_and_tag() {
+        let mut conn = test_connection();
+        setup_test_data(&mut conn);
+
+        insert_tag(&mut conn, &NewTag::new("tag-1", "camp-1", "Act 2")).unwrap();
+        let link = NewEntityTag::new("tag-1", TaggableType::Document, "doc-1");
+        insert_entity_tag(&mut conn, &link).unwrap();
+
+        delete_document(&mut conn, "doc-1").unwrap();
+        assert_eq!(count_entity_tags(&mut conn, "tag-1").unwrap(), 0);
+
+        let link = NewEntityTag::new("tag-1", TaggableType::Map, "map-1");
+        insert_entity_tag(&mut conn, &link).unwrap();
+        delete_tag(&mut conn, "tag-1").unwrap();
+        assert!(list_entity_tags_for_tag(&mut conn, "tag-1").unwrap().is_empty());
+    }
+}
@@ -84,6 +84,23 @@ pub fn update_token_placement(
         .execute(conn)
 }
 
+/// Set the size of every mob token standing for a module monster.
+///
+/// Tokens for single creatures are left alone.
+pub fn resize_mob_tokens(
+    conn: &mut SqliteConnection,
+    module_monster_id: &str,
+    mob_size: i32,
+) -> QueryResult<usize> {
+    diesel::update(
+        token_placements::table
+            .filter(token_placements::module_monster_id.eq(module_monster_id))
+            .filter(token_placements::mob_size.is_not_null()),
+    )
+    .set(token_placements::mob_size.eq(mob_size))
+    .execute(conn)
+}
+
 /// Delete a token placement by ID.
 pub fn delete_token_placement(conn: &mut SqliteConnection, id: &str) -> QueryResult<usize> {
     diesel::delete(token_placements::table.find(id)).execute(conn)
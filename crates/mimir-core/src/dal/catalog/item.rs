@@ -59,6 +59,24 @@ pub fn get_item_by_name(
         .optional()
 }
 
+/// Get items matching any of the given names (case-insensitive), from
+/// every source.
+///
+/// Batches lookups for exports; callers pick the source they need.
+pub fn list_items_by_names(
+    conn: &mut SqliteConnection,
+    names: &[String],
+) -> QueryResult<Vec<Item>> {
+    let mut found = Vec::new();
+    for chunk in super::lowercase_names(names).chunks(super::NAME_BATCH_SIZE) {
+        let batch: Vec<Item> = items::table
+            .filter(lower(items::name).eq_any(chunk))
+            .load(conn)?;
+        found.extend(batch);
+    }
+    Ok(found)
+}
+
 /// List all items, ordered by name.
 pub fn list_items(conn: &mut SqliteConnection) -> QueryResult<Vec<Item>> {
     items::table.order(items::name.asc()).load(conn)
@@ -349,4 +367,17 @@ mod tests {
             2
         );
     }
+
+    #[test]
+    fn test_list_items_by_names() {
+        let mut conn = setup_test_db_with_sources();
+
+        insert_item(&mut conn, &NewItem::new("Longsword", "PHB", r#"{}"#)).expect("Failed to insert");
+        insert_item(&mut conn, &NewItem::new("Shield", "PHB", r#"{}"#)).expect("Failed to insert");
+
+        let names = vec!["longsword".to_string()];
+        let found = list_items_by_names(&mut conn, &names).expect("Failed to query");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].source, "PHB");
+    }
 }
@@ -0,0 +1,68 @@
+//! Catalog License Data Access Layer
+//!
+//! Database operations for catalog entry licenses.
+
+use crate::models::catalog::NewCatalogLicense;
+use crate::schema::catalog_licenses;
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+
+/// Record a catalog entry's license, replacing any existing record.
+pub fn insert_catalog_license(
+    conn: &mut SqliteConnection,
+    license: &NewCatalogLicense,
+) -> QueryResult<usize> {
+    diesel::replace_into(catalog_licenses::table)
+        .values(license)
+        .execute(conn)
+}
+
+/// Get the license of a catalog entry, or None if it is book content.
+pub fn get_catalog_license(
+    conn: &mut SqliteConnection,
+    entity_type: &str,
+    name: &str,
+    source: &str,
+) -> QueryResult<Option<String>> {
+    catalog_licenses::table
+        .find((entity_type, name, source))
+        .select(catalog_licenses::license)
+        .first(conn)
+        .optional()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::catalog::{delete_source_cascade, insert_source};
+    use crate::db::test_connection;
+    use crate::models::catalog::{NewCatalogSource, LICENSE_SRD_5_1};
+
+    #[test]
+    fn test_catalog_licenses() {
+        let mut conn = test_connection();
+        let source = NewCatalogSource::new("MM", "Monster Manual", true, "2024-01-20T12:00:00Z");
+        insert_source(&mut conn, &source).expect("Failed to insert source");
+
+        let license = NewCatalogLicense::new("monster", "Goblin", "MM", LICENSE_SRD_5_1);
+        insert_catalog_license(&mut conn, &license).expect("Failed to insert");
+        // Re-importing the same entry replaces the record
+        insert_catalog_license(&mut conn, &license).expect("Failed to insert");
+
+        assert_eq!(
+            get_catalog_license(&mut conn, "monster", "Goblin", "MM").unwrap(),
+            Some(LICENSE_SRD_5_1.to_string())
+        );
+        assert_eq!(
+            get_catalog_license(&mut conn, "monster", "Beholder", "MM").unwrap(),
+            None
+        );
+
+        // Deleting the source removes its license records
+        delete_source_cascade(&mut conn, "MM").expect("Failed to delete source");
+        assert_eq!(
+            get_catalog_license(&mut conn, "monster", "Goblin", "MM").unwrap(),
+            None
+        );
+    }
+}
@@ -18,6 +18,7 @@ mod hazard;
 mod item;
 mod item_attunement;
 mod language;
+mod license;
 mod monster;
 mod object;
 mod optional_feature;
@@ -50,6 +51,7 @@ pub use hazard::*;
 pub use item::*;
 pub use item_attunement::*;
 pub use language::*;
+pub use license::*;
 pub use monster::*;
 pub use object::*;
 pub use optional_feature::*;
@@ -67,3 +69,15 @@ pub use table::*;
 pub use trap::*;
 pub use variant_rule::*;
 pub use vehicle::*;
+
+/// Maximum names bound in one `IN (...)` query, well under SQLite's
+/// host parameter limit.
+const NAME_BATCH_SIZE: usize = 500;
+
+/// Lowercase and deduplicate names for a case-insensitive batch lookup.
+fn lowercase_names(names: &[String]) -> Vec<String> {
+    let mut lowered: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    lowered.sort();
+    lowered.dedup();
+    lowered
+}
@@ -59,6 +59,24 @@ pub fn get_monster_by_name(
         .optional()
 }
 
+/// Get monsters matching any of the given names (case-insensitive), from
+/// every source.
+///
+/// Batches lookups for exports; callers pick the source they need.
+pub fn list_monsters_by_names(
+    conn: &mut SqliteConnection,
+    names: &[String],
+) -> QueryResult<Vec<Monster>> {
+    let mut found = Vec::new();
+    for chunk in super::lowercase_names(names).chunks(super::NAME_BATCH_SIZE) {
+        let batch: Vec<Monster> = monsters::table
+            .filter(lower(monsters::name).eq_any(chunk))
+            .load(conn)?;
+        found.extend(batch);
+    }
+    Ok(found)
+}
+
 /// List all monsters, ordered by name.
 pub fn list_monsters(conn: &mut SqliteConnection) -> QueryResult<Vec<Monster>> {
     monsters::table.order(monsters::name.asc()).load(conn)
@@ -378,4 +396,24 @@ mod tests {
             2
         );
     }
+
+    #[test]
+    fn test_list_monsters_by_names() {
+        let mut conn = setup_test_db_with_sources();
+
+        let monsters = vec![
+            NewMonster::new("Goblin", "MM", r#"{"name":"Goblin"}"#),
+            NewMonster::new("Orc", "MM", r#"{"name":"Orc"}"#),
+            NewMonster::new("Kobold", "MM", r#"{"name":"Kobold"}"#),
+        ];
+        insert_monsters(&mut conn, &monsters).expect("Failed to insert");
+
+        let names = vec!["goblin".to_string(), "ORC".to_string(), "Dragon".to_string()];
+        let mut found = list_monsters_by_names(&mut conn, &names).expect("Failed to query");
+        found.sort_by(|a, b| a.name.cmp(&b.name));
+        let found: Vec<&str> = found.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(found, vec!["Goblin", "Orc"]);
+
+        assert!(list_monsters_by_names(&mut conn, &[]).expect("Failed to query").is_empty());
+    }
 }
@@ -60,6 +60,34 @@ pub fn get_race_by_name(
         .optional()
 }
 
+/// Get a subrace of a race by name and source (case-insensitive name matching).
+///
+/// Subraces are stored alongside races under their own name (e.g., "High"),
+/// with the parent race named in their data's `raceName`.
+pub fn get_subrace_by_name(
+    conn: &mut SqliteConnection,
+    race_name: &str,
+    name: &str,
+    source: &str,
+) -> QueryResult<Option<Race>> {
+    let name_lower = name.to_lowercase();
+    let candidates: Vec<Race> = races::table
+        .filter(lower(races::name).eq(&name_lower))
+        .filter(races::source.eq(source))
+        .load(conn)?;
+
+    Ok(candidates.into_iter().find(|race| {
+        serde_json::from_str::<serde_json::Value>(&race.data)
+            .ok()
+            .and_then(|data| {
+                data.get("raceName")
+                    .and_then(|v| v.as_str())
+                    .map(|parent| parent.eq_ignore_ascii_case(race_name))
+            })
+            .unwrap_or(false)
+    }))
+}
+
 /// List all races, ordered by name.
 pub fn list_races(conn: &mut SqliteConnection) -> QueryResult<Vec<Race>> {
     races::table.order(races::name.asc()).load(conn)
@@ -191,6 +219,27 @@ mod tests {
         assert_eq!(count_races(&mut conn).expect("Failed to count"), 0);
     }
 
+    #[test]
+    fn test_get_subrace_by_name() {
+        let mut conn = setup_test_db_with_sources();
+
+        let races = vec![
+            NewRace::new("Elf", "PHB", r#"{"name":"Elf"}"#),
+            NewRace::new("High", "PHB", r#"{"name":"High","raceName":"Elf"}"#),
+        ];
+        insert_races(&mut conn, &races).expect("Failed to insert");
+
+        let subrace = get_subrace_by_name(&mut conn, "Elf", "high", "PHB")
+            .expect("Failed to query")
+            .expect("Subrace not found");
+        assert_eq!(subrace.name, "High");
+
+        // A subrace only matches under its own parent race
+        assert!(get_subrace_by_name(&mut conn, "Dwarf", "High", "PHB")
+            .expect("Failed to query")
+            .is_none());
+    }
+
     #[test]
     fn test_search_races() {
         let mut conn = setup_test_db_with_sources();
@@ -64,6 +64,26 @@ pub fn set_enabled(conn: &mut SqliteConnection, code: &str, enabled: bool) -> Qu
         .execute(conn)
 }
 
+/// Set the edition a catalog source is tagged with.
+pub fn set_edition(conn: &mut SqliteConnection, code: &str, edition: &str) -> QueryResult<usize> {
+    let update = UpdateCatalogSource::set_edition(edition);
+    diesel::update(catalog_sources::table.find(code))
+        .set(&update)
+        .execute(conn)
+}
+
+/// List the codes of all sources tagged with an edition.
+pub fn list_source_codes_by_edition(
+    conn: &mut SqliteConnection,
+    edition: &str,
+) -> QueryResult<Vec<String>> {
+    catalog_sources::table
+        .filter(catalog_sources::edition.eq(edition))
+        .select(catalog_sources::code)
+        .order(catalog_sources::code.asc())
+        .load(conn)
+}
+
 /// Delete a catalog source by its code.
 ///
 /// Note: This will fail if there are entities referencing this source.
@@ -78,7 +98,7 @@ pub fn delete_source(conn: &mut SqliteConnection, code: &str) -> QueryResult<usi
 /// then deletes the source record.
 pub fn delete_source_cascade(conn: &mut SqliteConnection, code: &str) -> QueryResult<usize> {
     use crate::schema::{
-        actions, backgrounds, books, catalog_tables, classes, conditions, cults, deities, diseases,
+        actions, backgrounds, books, catalog_licenses, catalog_tables, classes, conditions, cults, deities, diseases,
         feats, hazards, item_attunement_classes, items, languages, monsters, objects,
         optional_features, psionics, races, rewards, senses, skills, spell_classes,
         spell_subclasses, spells, subclasses, traps, variant_rules, vehicles,
@@ -125,6 +145,8 @@ pub fn delete_source_cascade(conn: &mut SqliteConnection, code: &str) -> QueryRe
     diesel::delete(spell_classes::table.filter(spell_classes::source.eq(code))).execute(conn)?;
     diesel::delete(spell_subclasses::table.filter(spell_subclasses::source.eq(code))).execute(conn)?;
 
+    diesel::delete(catalog_licenses::table.filter(catalog_licenses::source.eq(code))).execute(conn)?;
+
     // Delete from all entity tables
     diesel::delete(actions::table.filter(actions::source.eq(code))).execute(conn)?;
     diesel::delete(backgrounds::table.filter(backgrounds::source.eq(code))).execute(conn)?;
@@ -254,6 +276,26 @@ mod tests {
         assert_eq!(retrieved.enabled, 1);
     }
 
+    #[test]
+    fn test_editions() {
+        let mut conn = test_connection();
+
+        let sources = [
+            NewCatalogSource::new("PHB", "Player's Handbook", true, "2024-01-20T12:00:00Z"),
+            NewCatalogSource::new("XPHB", "Player's Handbook (2024)", true, "2024-01-20T12:00:00Z"),
+            NewCatalogSource::new("XGE", "Xanathar's Guide to Everything", true, "2024-01-20T12:00:00Z"),
+        ];
+        insert_sources(&mut conn, &sources).expect("Failed to insert");
+
+        let codes = list_source_codes_by_edition(&mut conn, "2024").expect("Failed to list");
+        assert_eq!(codes, vec!["XPHB"]);
+
+        set_edition(&mut conn, "XGE", "2024").expect("Failed to set edition");
+        let codes = list_source_codes_by_edition(&mut conn, "2024").expect("Failed to list");
+        assert_eq!(codes, vec!["XGE", "XPHB"]);
+        assert_eq!(get_source(&mut conn, "XGE").unwrap().edition, "2024");
+    }
+
     #[test]
     fn test_get_source_optional() {
         let mut conn = test_connection();
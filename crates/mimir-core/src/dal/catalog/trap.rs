@@ -59,6 +59,24 @@ pub fn get_trap_by_name(
         .optional()
 }
 
+/// Get traps matching any of the given names (case-insensitive), from
+/// every source.
+///
+/// Batches lookups for exports; callers pick the source they need.
+pub fn list_traps_by_names(
+    conn: &mut SqliteConnection,
+    names: &[String],
+) -> QueryResult<Vec<Trap>> {
+    let mut found = Vec::new();
+    for chunk in super::lowercase_names(names).chunks(super::NAME_BATCH_SIZE) {
+        let batch: Vec<Trap> = traps::table
+            .filter(lower(traps::name).eq_any(chunk))
+            .load(conn)?;
+        found.extend(batch);
+    }
+    Ok(found)
+}
+
 /// List all traps, ordered by name.
 pub fn list_traps(conn: &mut SqliteConnection) -> QueryResult<Vec<Trap>> {
     traps::table.order(traps::name.asc()).load(conn)
@@ -239,4 +257,20 @@ mod tests {
         let complex = list_complex_traps(&mut conn).expect("Failed to list");
         assert_eq!(complex.len(), 1);
     }
+
+    #[test]
+    fn test_list_traps_by_names() {
+        let mut conn = setup_test_db_with_sources();
+
+        let traps = vec![
+            NewTrap::new("Pit Trap", "DMG", r#"{}"#),
+            NewTrap::new("Collapsing Roof", "DMG", r#"{}"#),
+        ];
+        insert_traps(&mut conn, &traps).expect("Failed to insert");
+
+        let names = vec!["pit trap".to_string(), "Pit Trap".to_string()];
+        let found = list_traps_by_names(&mut conn, &names).expect("Failed to query");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "Pit Trap");
+    }
 }
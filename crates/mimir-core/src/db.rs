@@ -8,6 +8,8 @@
 use diesel::prelude::*;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 
+use crate::encryption;
+
 /// Embed all migrations at compile time.
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
@@ -49,6 +51,9 @@ pub fn run_migrations(conn: &mut SqliteConnection) -> Result<Vec<String>, Box<dy
 pub fn init_database(db_url: &str) -> Result<SqliteConnection, Box<dyn std::error::Error + Send + Sync>> {
     let mut conn = SqliteConnection::establish(db_url)?;
 
+    // Unlock an encrypted database before touching it
+    encryption::unlock(&mut conn, db_url)?;
+
     // Configure pragmas (including WAL mode)
     configure_connection(&mut conn);
 
@@ -61,9 +66,11 @@ pub fn init_database(db_url: &str) -> Result<SqliteConnection, Box<dyn std::erro
 /// Create a new database connection with pragmas configured.
 ///
 /// Use this for on-demand connections after the database has been initialized.
-/// Each connection is configured with WAL mode, foreign keys, and optimal settings.
+/// Each connection is unlocked if the database is encrypted, then configured
+/// with WAL mode, foreign keys, and optimal settings.
 pub fn create_connection(db_url: &str) -> Result<SqliteConnection, Box<dyn std::error::Error + Send + Sync>> {
     let mut conn = SqliteConnection::establish(db_url)?;
+    encryption::unlock(&mut conn, db_url)?;
     configure_connection(&mut conn);
     Ok(conn)
 }
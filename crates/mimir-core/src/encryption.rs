@@ -0,0 +1,336 @@
+//! Database Encryption
+//!
+//! Optional encryption at rest for the Mimir database using SQLCipher, for
+//! DMs keeping campaigns on shared or synced machines.
+//!
+//! The key is a random 256-bit key stored in the OS keychain (Keychain on
+//! macOS, Credential Manager on Windows, Secret Service on Linux), never on
+//! disk. Connections made through [`crate::db`] unlock an encrypted database
+//! automatically, so the app, the MCP server, and the folder watchers all keep
+//! working once the key is in the keychain.
+//!
+//! Enabling or disabling encryption rewrites the database file with
+//! `sqlcipher_export`, so nothing else should have the database open while it
+//! runs (quit the MCP server first).
+//!
+//! Only the database is encrypted. Features that copy campaign content to
+//! other files (markdown export and watched folders, campaign history,
+//! backups, and sync) would leave it on disk in plain text, so they refuse to
+//! run while the database is encrypted; see [`ensure_unencrypted`]. Copies
+//! made before encryption was turned on are left where they are.
+
+use diesel::connection::SimpleConnection;
+use diesel::prelude::*;
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::info;
+
+use crate::services::{ServiceError, ServiceResult};
+
+/// Keychain service name Mimir secrets are stored under.
+pub(crate) const KEYCHAIN_SERVICE: &str = "mimir";
+
+/// First 16 bytes of every unencrypted SQLite database.
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// Keys already read from the keychain, by database path, so opening a
+/// connection doesn't hit the keychain every time.
+static KEY_CACHE: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Whether a database is encrypted and can be unlocked.
+#[derive(Debug, Clone, Serialize)]
+pub struct EncryptionStatus {
+    /// The database file is encrypted
+    pub encrypted: bool,
+    /// The key for the database is in the OS keychain
+    pub key_available: bool,
+}
+
+/// Check whether a database file is encrypted.
+///
+/// A missing or empty file is not encrypted; SQLite creates it in plain text.
+pub fn is_encrypted(db_path: &Path) -> bool {
+    let mut header = [0u8; 16];
+    match File::open(db_path).and_then(|mut f| f.read_exact(&mut header)) {
+        Ok(()) => &header != SQLITE_HEADER,
+        Err(_) => false,
+    }
+}
+
+/// Report whether a database is encrypted and whether its key is available.
+pub fn encryption_status(db_path: &Path) -> ServiceResult<EncryptionStatus> {
+    let encrypted = is_encrypted(db_path);
+    let key_available = encrypted && database_key(db_path)?.is_some();
+    Ok(EncryptionStatus {
+        encrypted,
+        key_available,
+    })
+}
+
+/// Check whether the database a connection has open is encrypted.
+///
+/// In-memory and temporary databases are never encrypted.
+pub fn connection_is_encrypted(conn: &mut SqliteConnection) -> ServiceResult<bool> {
+    #[derive(QueryableByName)]
+    struct DatabaseFile {
+        #[diesel(sql_type = diesel::sql_types::Text)]
+        file: String,
+    }
+
+    let main = diesel::sql_query("SELECT file FROM pragma_database_list WHERE name = 'main'")
+        .get_result::<DatabaseFile>(conn)
+        .optional()?;
+    Ok(match main {
+        Some(main) if !main.file.is_empty() => is_encrypted(Path::new(&main.file)),
+        _ => false,
+    })
+}
+
+/// Fail if the connection's database is encrypted.
+///
+/// Used by features that write campaign content outside the database, which
+/// would otherwise leave a plain-text copy next to the encrypted database.
+/// `feature` names the feature in the error message.
+pub fn ensure_unencrypted(conn: &mut SqliteConnection, feature: &str) -> ServiceResult<()> {
+    if connection_is_encrypted(conn)? {
+        return Err(ServiceError::validation(format!(
+            "{} writes campaign content to disk unencrypted, so it is unavailable while \
+             the database is encrypted",
+            feature
+        )));
+    }
+    Ok(())
+}
+
+/// Unlock a freshly opened connection if its database is encrypted.
+///
+/// Must run before any other statement on the connection.
+pub fn unlock(conn: &mut SqliteConnection, db_url: &str) -> ServiceResult<()> {
+    let db_path = Path::new(db_url);
+    if !is_encrypted(db_path) {
+        return Ok(());
+    }
+
+    let key = database_key(db_path)?.ok_or_else(|| {
+        ServiceError::validation("The database is encrypted but its key is not in the OS keychain")
+    })?;
+    apply_key(conn, &key)
+}
+
+/// Encrypt a database with a new key and store the key in the OS keychain.
+pub fn enable_encryption(db_path: &Path) -> ServiceResult<()> {
+    if is_encrypted(db_path) {
+        return Err(ServiceError::validation(
+            "The database is already encrypted",
+        ));
+    }
+
+    let key = generate_key();
+    let temp_path = working_copy_path(db_path);
+    remove_if_exists(&temp_path)?;
+
+    {
+        let mut conn = open(db_path)?;
+        conn.batch_execute("PRAGMA wal_checkpoint(TRUNCATE);")?;
+        conn.batch_execute(&format!(
+            "ATTACH DATABASE '{}' AS encrypted KEY \"x'{}'\";
+             SELECT sqlcipher_export('encrypted');
+             DETACH DATABASE encrypted;",
+            sql_path(&temp_path),
+            key
+        ))?;
+    }
+
+    // Keep the key before replacing the database, so a keychain failure
+    // leaves the plain database in place
+    if let Err(e) = store_key(db_path, &key) {
+        remove_if_exists(&temp_path)?;
+        return Err(e);
+    }
+    replace_database(&temp_path, db_path)?;
+
+    info!(path = %db_path.display(), "Database encrypted");
+    Ok(())
+}
+
+/// Decrypt a database and remove its key from the OS keychain.
+pub fn disable_encryption(db_path: &Path) -> ServiceResult<()> {
+    if !is_encrypted(db_path) {
+        return Err(ServiceError::validation("The database is not encrypted"));
+    }
+    let key = database_key(db_path)?.ok_or_else(|| {
+        ServiceError::validation("The database is encrypted but its key is not in the OS keychain")
+    })?;
+
+    let temp_path = working_copy_path(db_path);
+    remove_if_exists(&temp_path)?;
+
+    {
+        let mut conn = open(db_path)?;
+        apply_key(&mut conn, &key)?;
+        conn.batch_execute("PRAGMA wal_checkpoint(TRUNCATE);")?;
+        conn.batch_execute(&format!(
+            "ATTACH DATABASE '{}' AS plaintext KEY '';
+             SELECT sqlcipher_export('plaintext');
+             DETACH DATABASE plaintext;",
+            sql_path(&temp_path)
+        ))?;
+    }
+
+    replace_database(&temp_path, db_path)?;
+    delete_key(db_path)?;
+
+    info!(path = %db_path.display(), "Database decrypted");
+    Ok(())
+}
+
+fn open(db_path: &Path) -> ServiceResult<SqliteConnection> {
+    SqliteConnection::establish(&db_path.to_string_lossy()).map_err(|e| {
+        ServiceError::validation(format!("Failed to open {}: {}", db_path.display(), e))
+    })
+}
+
+/// Give SQLCipher the key, then read the schema to check the key is right.
+fn apply_key(conn: &mut SqliteConnection, key: &str) -> ServiceResult<()> {
+    conn.batch_execute(&format!("PRAGMA key = \"x'{}'\";", key))?;
+    conn.batch_execute("SELECT count(*) FROM sqlite_master;")
+        .map_err(|_| ServiceError::validation("The database key in the OS keychain is wrong"))
+}
+
+/// A random 256-bit key, hex encoded.
+fn generate_key() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn keychain_entry(db_path: &Path) -> ServiceResult<keyring::Entry> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, &keychain_account(db_path)).map_err(keychain_error)
+}
+
+/// Keychain account for a database: its path, so dev and production
+/// databases get separate keys.
+fn keychain_account(db_path: &Path) -> String {
+    format!("database:{}", db_path.display())
+}
+
+fn database_key(db_path: &Path) -> ServiceResult<Option<String>> {
+    let account = keychain_account(db_path);
+    if let Some(key) = KEY_CACHE.lock().unwrap().get(&account) {
+        return Ok(Some(key.clone()));
+    }
+
+    match keychain_entry(db_path)?.get_password() {
+        Ok(key) => {
+            KEY_CACHE.lock().unwrap().insert(account, key.clone());
+            Ok(Some(key))
+        }
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(keychain_error(e)),
+    }
+}
+
+fn store_key(db_path: &Path, key: &str) -> ServiceResult<()> {
+    keychain_entry(db_path)?
+        .set_password(key)
+        .map_err(keychain_error)?;
+    KEY_CACHE
+        .lock()
+        .unwrap()
+        .insert(keychain_account(db_path), key.to_string());
+    Ok(())
+}
+
+fn delete_key(db_path: &Path) -> ServiceResult<()> {
+    KEY_CACHE.lock().unwrap().remove(&keychain_account(db_path));
+    match keychain_entry(db_path)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(keychain_error(e)),
+    }
+}
+
+pub(crate) fn keychain_error(e: keyring::Error) -> ServiceError {
+    ServiceError::Io(std::io::Error::other(format!("OS keychain error: {}", e)))
+}
+
+/// Where the rewritten database is built before it replaces the original.
+fn working_copy_path(db_path: &Path) -> PathBuf {
+    let mut name = db_path.as_os_str().to_owned();
+    name.push(".rewrite");
+    PathBuf::from(name)
+}
+
+/// A path quoted for use inside a single-quoted SQL string.
+fn sql_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\'', "''")
+}
+
+/// Move the rewritten database over the original and drop the original's
+/// (already checkpointed) WAL files.
+fn replace_database(new_path: &Path, db_path: &Path) -> ServiceResult<()> {
+    for suffix in ["-wal", "-shm"] {
+        let mut name = db_path.as_os_str().to_owned();
+        name.push(suffix);
+        remove_if_exists(Path::new(&name))?;
+    }
+    fs::rename(new_path, db_path)?;
+    Ok(())
+}
+
+fn remove_if_exists(path: &Path) -> ServiceResult<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(ServiceError::Io(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_encrypted() {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("mimir.db");
+
+        // Missing and plain databases are not encrypted
+        assert!(!is_encrypted(&db_path));
+        let mut conn = SqliteConnection::establish(&db_path.to_string_lossy()).unwrap();
+        conn.batch_execute("CREATE TABLE t (id INTEGER);").unwrap();
+        drop(conn);
+        assert!(!is_encrypted(&db_path));
+
+        // Anything without the SQLite header is
+        fs::write(&db_path, [0x5au8; 64]).unwrap();
+        assert!(is_encrypted(&db_path));
+    }
+
+    #[test]
+    fn test_ensure_unencrypted_allows_plain_databases() {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("mimir.db");
+        let mut conn = SqliteConnection::establish(&db_path.to_string_lossy()).unwrap();
+        conn.batch_execute("CREATE TABLE t (id INTEGER);").unwrap();
+        assert!(!connection_is_encrypted(&mut conn).unwrap());
+        assert!(ensure_unencrypted(&mut conn, "Markdown export").is_ok());
+
+        let mut memory = SqliteConnection::establish(":memory:").unwrap();
+        assert!(!connection_is_encrypted(&mut memory).unwrap());
+    }
+
+    #[test]
+    fn test_generate_key() {
+        let key = generate_key();
+        assert_eq!(key.len(), 64);
+        assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(key, generate_key());
+    }
+}
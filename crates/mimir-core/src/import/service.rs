@@ -5,7 +5,7 @@
 
 use crate::dal::catalog::{self, insert_source};
 use crate::fts::{flatten_entries, index_entity, ContentType};
-use crate::import::{collect_source_entities, copy_images, discover_available_sources, get_token_path, CollectedEntities};
+use crate::import::{carries_srd_flag, collect_source_entities, copy_images, discover_available_sources, get_token_path, is_srd, CollectedEntities};
 use crate::models::catalog::*;
 use crate::utils::now_rfc3339;
 use anyhow::{Context, Result};
@@ -93,6 +93,8 @@ pub struct CatalogImportService<'a> {
     images_copied: usize,
     /// Groups to include (None = all groups, Some = only specified groups).
     allowed_groups: Option<Vec<String>>,
+    /// Only import SRD and Basic Rules content.
+    srd_only: bool,
 }
 
 impl<'a> CatalogImportService<'a> {
@@ -112,6 +114,7 @@ impl<'a> CatalogImportService<'a> {
                     .map(|s| s.to_string())
                     .collect(),
             ),
+            srd_only: false,
         }
     }
 
@@ -129,6 +132,15 @@ impl<'a> CatalogImportService<'a> {
         self
     }
 
+    /// Only import content that is part of the SRD or Basic Rules.
+    ///
+    /// Book text and expanded magic item variants are skipped, since they
+    /// aren't marked as SRD content.
+    pub fn with_srd_only(mut self) -> Self {
+        self.srd_only = true;
+        self
+    }
+
     /// Configure image copying from source to destination.
     ///
     /// All images from the source directory will be copied to the destination,
@@ -231,7 +243,12 @@ impl<'a> CatalogImportService<'a> {
         }
 
         // Global magic variant expansion for disk-based import
-        match self.expand_magic_variants_from_disk(repo_path) {
+        let expanded = if self.srd_only {
+            Ok(0)
+        } else {
+            self.expand_magic_variants_from_disk(repo_path)
+        };
+        match expanded {
             Ok(count) => {
                 if count > 0 {
                     *result.entity_counts.entry("item (expanded variant)".to_string()).or_insert(0) += count;
@@ -374,7 +391,12 @@ impl<'a> CatalogImportService<'a> {
 
         // Global magic variant expansion: expand all variants against all base items
         // regardless of source (e.g., DMG variants + PHB base items)
-        match self.expand_magic_variants_from_memory(&json_files) {
+        let expanded = if self.srd_only {
+            Ok(0)
+        } else {
+            self.expand_magic_variants_from_memory(&json_files)
+        };
+        match expanded {
             Ok(count) => {
                 if count > 0 {
                     *result.entity_counts.entry("item (expanded variant)".to_string()).or_insert(0) += count;
@@ -505,7 +527,7 @@ impl<'a> CatalogImportService<'a> {
         }
 
         // Import book content if available
-        if collected.has_book_content() {
+        if collected.has_book_content() && !self.srd_only {
             match self.import_book(&collected, source_code, source_name) {
                 Ok(_) => {
                     info!("Imported book content for {}", source_code);
@@ -586,7 +608,7 @@ impl<'a> CatalogImportService<'a> {
         }
 
         // Import book content if available
-        if collected.has_book_content() {
+        if collected.has_book_content() && !self.srd_only {
             match self.import_book(&collected, source_code, source_name) {
                 Ok(_) => {
                     info!("Imported book content for {}", source_code);
@@ -610,8 +632,12 @@ impl<'a> CatalogImportService<'a> {
         collected: &CollectedEntities,
     ) -> Result<usize> {
         let mut count = 0;
+        let check_srd = self.srd_only && carries_srd_flag(entity_type);
 
         for entity in entities {
+            if check_srd && !is_srd(entity) {
+                continue;
+            }
             match self.import_single_entity(entity_type, entity, source, collected) {
                 Ok(id) => {
                     count += 1;
@@ -619,6 +645,9 @@ impl<'a> CatalogImportService<'a> {
                     if let Err(e) = self.index_entity_fts(entity_type, id, entity) {
                         warn!("Failed to index entity in FTS: {}", e);
                     }
+                    if let Err(e) = self.record_license(entity_type, entity, source) {
+                        warn!("Failed to record entity license: {}", e);
+                    }
                 }
                 Err(e) => {
                     let name = entity
@@ -633,6 +662,27 @@ impl<'a> CatalogImportService<'a> {
         Ok(count)
     }
 
+    /// Record the SRD license of an entity, if it has one.
+    fn record_license(&mut self, entity_type: &str, entity: &Value, source: &str) -> Result<()> {
+        let Some(license) = license_for_entity(entity) else {
+            return Ok(());
+        };
+        let name = entity
+            .get("name")
+            .and_then(|v| v.as_str())
+            .context("Entity missing name")?;
+        // Base items are searched as items
+        let entity_type = if entity_type == "baseitem" {
+            "item"
+        } else {
+            entity_type
+        };
+
+        let record = NewCatalogLicense::new(entity_type, name, source, license);
+        catalog::insert_catalog_license(self.conn, &record)?;
+        Ok(())
+    }
+
     /// Import a single entity based on its type.
     fn import_single_entity(
         &mut self,
@@ -90,6 +90,12 @@ const SRD_ENTITY_KEYS: &[&str] = &[
     "boon",
 ];
 
+/// Whether entities of this type are marked as SRD content or not, so
+/// unmarked ones can be left out of an SRD-only import.
+pub fn carries_srd_flag(entity_type: &str) -> bool {
+    entity_type == "baseitem" || SRD_ENTITY_KEYS.contains(&entity_type)
+}
+
 /// Check the SRD status of an item.
 ///
 /// Checks in order:
@@ -342,6 +348,13 @@ mod tests {
         assert!(!is_srd(&json!({"name": "Test"})));
     }
 
+    #[test]
+    fn test_carries_srd_flag() {
+        assert!(carries_srd_flag("monster"));
+        assert!(carries_srd_flag("baseitem"));
+        assert!(!carries_srd_flag("classFeature"));
+    }
+
     #[test]
     fn test_check_srd_status() {
         assert_eq!(
@@ -5,12 +5,14 @@
 pub mod catalog;
 pub mod dal;
 pub mod db;
+pub mod encryption;
 pub mod fts;
 pub mod import;
 pub mod models;
 mod schema;
 pub mod seed;
 pub mod services;
+pub mod sync;
 pub mod templates;
 pub mod tokens;
 pub mod utils;
@@ -27,6 +27,12 @@ pub struct Campaign {
     pub created_at: String,
     /// ISO8601 timestamp of last update
     pub updated_at: String,
+    /// Ruleset edition ("2014" or "2024"), NULL means both
+    #[serde(default)]
+    pub edition: Option<String>,
+    /// Whether AI searches and exports are limited to SRD content (SQLite stores as integer)
+    #[serde(default)]
+    pub srd_only: i32,
 }
 
 impl Campaign {
@@ -34,6 +40,11 @@ impl Campaign {
     pub fn is_archived(&self) -> bool {
         self.archived_at.is_some()
     }
+
+    /// Check if the campaign is limited to SRD content.
+    pub fn is_srd_only(&self) -> bool {
+        self.srd_only != 0
+    }
 }
 
 /// Data for inserting a new campaign.
@@ -43,6 +54,8 @@ pub struct NewCampaign<'a> {
     pub id: &'a str,
     pub name: &'a str,
     pub description: Option<&'a str>,
+    pub edition: Option<&'a str>,
+    pub srd_only: i32,
 }
 
 impl<'a> NewCampaign<'a> {
@@ -52,6 +65,8 @@ impl<'a> NewCampaign<'a> {
             id,
             name,
             description: None,
+            edition: None,
+            srd_only: 0,
         }
     }
 
@@ -60,6 +75,18 @@ impl<'a> NewCampaign<'a> {
         self.description = Some(description);
         self
     }
+
+    /// Set the ruleset edition.
+    pub fn with_edition(mut self, edition: &'a str) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+
+    /// Limit the campaign to SRD content.
+    pub fn with_srd_only(mut self, srd_only: bool) -> Self {
+        self.srd_only = if srd_only { 1 } else { 0 };
+        self
+    }
 }
 
 /// Data for updating a campaign.
@@ -70,6 +97,8 @@ pub struct UpdateCampaign<'a> {
     pub description: Option<Option<&'a str>>,
     pub archived_at: Option<Option<&'a str>>,
     pub updated_at: Option<&'a str>,
+    pub edition: Option<Option<&'a str>>,
+    pub srd_only: Option<i32>,
 }
 
 impl<'a> UpdateCampaign<'a> {
@@ -91,6 +120,15 @@ impl<'a> UpdateCampaign<'a> {
         }
     }
 
+    /// Create an update to change the edition (None means both editions).
+    pub fn set_edition(edition: Option<&'a str>, updated_at: &'a str) -> Self {
+        Self {
+            edition: Some(edition),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+
     /// Create an update to archive the campaign.
     pub fn archive(archived_at: &'a str) -> Self {
         Self {
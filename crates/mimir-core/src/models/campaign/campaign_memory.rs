@@ -0,0 +1,58 @@
+//! CampaignMemory Model
+//!
+//! Named facts the AI assistant remembers about a campaign between
+//! conversations, e.g. "the party insulted Duke Avery".
+
+use crate::schema::campaign_memories;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A fact remembered for a campaign.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = campaign_memories)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CampaignMemory {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this memory belongs to
+    pub campaign_id: String,
+    /// Short name, unique within the campaign (e.g., "Duke Avery insulted")
+    pub name: String,
+    /// The fact itself
+    pub content: String,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new campaign memory.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = campaign_memories)]
+pub struct NewCampaignMemory<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub name: &'a str,
+    pub content: &'a str,
+}
+
+impl<'a> NewCampaignMemory<'a> {
+    /// Create a new memory.
+    pub fn new(id: &'a str, campaign_id: &'a str, name: &'a str, content: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            name,
+            content,
+        }
+    }
+}
+
+/// Data for updating a campaign memory.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = campaign_memories)]
+pub struct UpdateCampaignMemory<'a> {
+    pub name: Option<&'a str>,
+    pub content: Option<&'a str>,
+    pub updated_at: Option<&'a str>,
+}
@@ -0,0 +1,317 @@
+//! Campaign Stash Models
+//!
+//! The party's shared stash: coins and stacked items held in common, and a
+//! ledger recording every deposit, withdrawal, and hand-out.
+
+use crate::schema::{campaign_stash_currency, campaign_stash_items, campaign_stash_ledger};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Coins in a campaign's stash. Each campaign has at most one row.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = campaign_stash_currency)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CampaignStashCurrency {
+    /// Campaign this stash belongs to
+    pub campaign_id: String,
+    pub cp: i32,
+    pub sp: i32,
+    pub ep: i32,
+    pub gp: i32,
+    pub pp: i32,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl CampaignStashCurrency {
+    /// Coins as [CP, SP, EP, GP, PP].
+    pub fn as_array(&self) -> [i32; 5] {
+        [self.cp, self.sp, self.ep, self.gp, self.pp]
+    }
+}
+
+/// Data for inserting or replacing a campaign's stash coins.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = campaign_stash_currency)]
+pub struct NewCampaignStashCurrency<'a> {
+    pub campaign_id: &'a str,
+    pub cp: i32,
+    pub sp: i32,
+    pub ep: i32,
+    pub gp: i32,
+    pub pp: i32,
+    pub updated_at: &'a str,
+}
+
+impl<'a> NewCampaignStashCurrency<'a> {
+    /// Create from coins as [CP, SP, EP, GP, PP].
+    pub fn new(campaign_id: &'a str, coins: [i32; 5], updated_at: &'a str) -> Self {
+        Self {
+            campaign_id,
+            cp: coins[0],
+            sp: coins[1],
+            ep: coins[2],
+            gp: coins[3],
+            pp: coins[4],
+            updated_at,
+        }
+    }
+}
+
+/// A stack of items in a campaign's stash.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = campaign_stash_items)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CampaignStashItem {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this stash belongs to
+    pub campaign_id: String,
+    /// Item name
+    pub item_name: String,
+    /// Item source (e.g., "PHB", or "HB" for campaign homebrew)
+    pub item_source: String,
+    /// Number of items in the stack
+    pub quantity: i32,
+    /// Free-form notes
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new stash item.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = campaign_stash_items)]
+pub struct NewCampaignStashItem<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub item_name: &'a str,
+    pub item_source: &'a str,
+    pub quantity: i32,
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> NewCampaignStashItem<'a> {
+    /// Create a new stash item stack.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        item_name: &'a str,
+        item_source: &'a str,
+        quantity: i32,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            item_name,
+            item_source,
+            quantity,
+            notes: None,
+        }
+    }
+
+    /// Set notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+/// Data for updating a stash item.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = campaign_stash_items)]
+pub struct UpdateCampaignStashItem<'a> {
+    pub quantity: Option<i32>,
+    pub notes: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// What happened in a stash ledger entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAction {
+    /// Coins or items added to the stash
+    Deposit,
+    /// Coins or items removed from play (spent, sold, lost)
+    Withdraw,
+    /// Coins or items handed to a character
+    Take,
+}
+
+impl LedgerAction {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerAction::Deposit => "deposit",
+            LedgerAction::Withdraw => "withdraw",
+            LedgerAction::Take => "take",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "deposit" => Some(LedgerAction::Deposit),
+            "withdraw" => Some(LedgerAction::Withdraw),
+            "take" => Some(LedgerAction::Take),
+            _ => None,
+        }
+    }
+}
+
+/// One movement of coins or items in or out of the stash.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = campaign_stash_ledger)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct StashLedgerEntry {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this stash belongs to
+    pub campaign_id: String,
+    /// 'deposit', 'withdraw', or 'take'
+    pub action: String,
+    /// Character involved (None once the character is deleted)
+    pub character_id: Option<String>,
+    /// Character's name when the entry was recorded
+    pub character_name: Option<String>,
+    /// Item moved, if any
+    pub item_name: Option<String>,
+    /// Item source, if any
+    pub item_source: Option<String>,
+    /// Number of items moved
+    pub quantity: i32,
+    pub cp: i32,
+    pub sp: i32,
+    pub ep: i32,
+    pub gp: i32,
+    pub pp: i32,
+    /// Free-form note (e.g., "Sold to the fence")
+    pub note: Option<String>,
+    /// ISO8601 timestamp of the movement
+    pub created_at: String,
+}
+
+impl StashLedgerEntry {
+    /// Get the action as an enum.
+    pub fn action_enum(&self) -> Option<LedgerAction> {
+        LedgerAction::from_str(&self.action)
+    }
+
+    /// Coins moved as [CP, SP, EP, GP, PP].
+    pub fn currency(&self) -> [i32; 5] {
+        [self.cp, self.sp, self.ep, self.gp, self.pp]
+    }
+}
+
+/// Data for inserting a ledger entry.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = campaign_stash_ledger)]
+pub struct NewStashLedgerEntry<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub action: &'a str,
+    pub character_id: Option<&'a str>,
+    pub character_name: Option<&'a str>,
+    pub item_name: Option<&'a str>,
+    pub item_source: Option<&'a str>,
+    pub quantity: i32,
+    pub cp: i32,
+    pub sp: i32,
+    pub ep: i32,
+    pub gp: i32,
+    pub pp: i32,
+    pub note: Option<&'a str>,
+    /// Defaults to now; set when importing history
+    pub created_at: Option<&'a str>,
+}
+
+impl<'a> NewStashLedgerEntry<'a> {
+    /// Create an empty entry for an action.
+    pub fn new(id: &'a str, campaign_id: &'a str, action: LedgerAction) -> Self {
+        Self {
+            id,
+            campaign_id,
+            action: action.as_str(),
+            character_id: None,
+            character_name: None,
+            item_name: None,
+            item_source: None,
+            quantity: 0,
+            cp: 0,
+            sp: 0,
+            ep: 0,
+            gp: 0,
+            pp: 0,
+            note: None,
+            created_at: None,
+        }
+    }
+
+    /// Record the character involved.
+    pub fn with_character(mut self, character_id: &'a str, name: &'a str) -> Self {
+        self.character_id = Some(character_id);
+        self.character_name = Some(name);
+        self
+    }
+
+    /// Record an item movement.
+    pub fn with_item(mut self, name: &'a str, source: &'a str, quantity: i32) -> Self {
+        self.item_name = Some(name);
+        self.item_source = Some(source);
+        self.quantity = quantity;
+        self
+    }
+
+    /// Record coins moved as [CP, SP, EP, GP, PP].
+    pub fn with_currency(mut self, coins: [i32; 5]) -> Self {
+        self.cp = coins[0];
+        self.sp = coins[1];
+        self.ep = coins[2];
+        self.gp = coins[3];
+        self.pp = coins[4];
+        self
+    }
+
+    /// Set a note.
+    pub fn with_note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Keep the original timestamp (for imports).
+    pub fn with_created_at(mut self, created_at: &'a str) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger_action_round_trip() {
+        for action in [
+            LedgerAction::Deposit,
+            LedgerAction::Withdraw,
+            LedgerAction::Take,
+        ] {
+            assert_eq!(LedgerAction::from_str(action.as_str()), Some(action));
+        }
+        assert_eq!(LedgerAction::from_str("steal"), None);
+    }
+
+    #[test]
+    fn test_new_ledger_entry() {
+        let entry = NewStashLedgerEntry::new("led-1", "camp-1", LedgerAction::Take)
+            .with_character("char-1", "Ada")
+            .with_item("Bag of Holding", "DMG", 1)
+            .with_currency([0, 0, 0, 25, 0]);
+        assert_eq!(entry.action, "take");
+        assert_eq!(entry.character_name, Some("Ada"));
+        assert_eq!(entry.quantity, 1);
+        assert_eq!(entry.gp, 25);
+    }
+}
@@ -69,9 +69,51 @@ pub struct Character {
     pub created_at: String,
     /// ISO8601 timestamp of last update
     pub updated_at: String,
+    /// ISO8601 timestamp when moved to the trash (None if not trashed)
+    #[serde(default)]
+    pub deleted_at: Option<String>,
+
+    // Racial traits (filled in from the catalog at creation)
+    /// Subrace name (e.g., "High" for a High Elf)
+    #[serde(default)]
+    pub subrace_name: Option<String>,
+    /// Subrace source (e.g., "PHB")
+    #[serde(default)]
+    pub subrace_source: Option<String>,
+    /// Walking speed in feet
+    #[serde(default = "default_speed")]
+    pub speed: i32,
+    /// Darkvision range in feet (None if the character has none)
+    #[serde(default)]
+    pub darkvision: Option<i32>,
+    /// JSON array of damage types the character resists
+    #[serde(default = "default_resistances")]
+    pub resistances: String,
+    /// Whether the character is dead (see character_deaths for the details)
+    #[serde(default)]
+    pub is_dead: bool,
+    /// Portrait image, relative to the assets directory
+    #[serde(default)]
+    pub portrait_path: Option<String>,
+    /// Circular token image, relative to the assets directory
+    #[serde(default)]
+    pub token_path: Option<String>,
+}
+
+fn default_speed() -> i32 {
+    30
+}
+
+fn default_resistances() -> String {
+    "[]".to_string()
 }
 
 impl Character {
+    /// Check if this character is in the trash.
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     /// Check if this character is an NPC.
     pub fn is_npc(&self) -> bool {
         self.is_npc != 0
@@ -118,6 +160,11 @@ impl Character {
         Self::ability_modifier(self.charisma)
     }
 
+    /// Parse the stored damage resistances.
+    pub fn parse_resistances(&self) -> Result<Vec<String>, serde_json::Error> {
+        serde_json::from_str(&self.resistances)
+    }
+
     /// Calculate total gold value of all currency.
     pub fn total_gold_value(&self) -> f64 {
         (self.cp as f64 / 100.0)
@@ -159,6 +206,12 @@ pub struct NewCharacter<'a> {
     pub role: Option<&'a str>,
     pub location: Option<&'a str>,
     pub faction: Option<&'a str>,
+    pub subrace_name: Option<&'a str>,
+    pub subrace_source: Option<&'a str>,
+    pub speed: i32,
+    pub darkvision: Option<i32>,
+    pub resistances: &'a str,
+    pub is_dead: bool,
 }
 
 impl<'a> NewCharacter<'a> {
@@ -192,6 +245,12 @@ impl<'a> NewCharacter<'a> {
             role: None,
             location: None,
             faction: None,
+            subrace_name: None,
+            subrace_source: None,
+            speed: 30,
+            darkvision: None,
+            resistances: "[]",
+            is_dead: false,
         }
     }
 
@@ -225,6 +284,12 @@ impl<'a> NewCharacter<'a> {
             role: None,
             location: None,
             faction: None,
+            subrace_name: None,
+            subrace_source: None,
+            speed: 30,
+            darkvision: None,
+            resistances: "[]",
+            is_dead: false,
         }
     }
 
@@ -235,6 +300,27 @@ impl<'a> NewCharacter<'a> {
         self
     }
 
+    /// Set the subrace.
+    pub fn with_subrace(mut self, name: &'a str, source: &'a str) -> Self {
+        self.subrace_name = Some(name);
+        self.subrace_source = Some(source);
+        self
+    }
+
+    /// Set racial traits: walking speed, darkvision range, and a JSON array
+    /// of damage resistances.
+    pub fn with_race_traits(
+        mut self,
+        speed: i32,
+        darkvision: Option<i32>,
+        resistances: &'a str,
+    ) -> Self {
+        self.speed = speed;
+        self.darkvision = darkvision;
+        self.resistances = resistances;
+        self
+    }
+
     /// Set the background.
     pub fn with_background(mut self, name: &'a str, source: &'a str) -> Self {
         self.background_name = Some(name);
@@ -328,6 +414,14 @@ pub struct UpdateCharacter<'a> {
     pub role: Option<Option<&'a str>>,
     pub location: Option<Option<&'a str>>,
     pub faction: Option<Option<&'a str>>,
+    pub subrace_name: Option<Option<&'a str>>,
+    pub subrace_source: Option<Option<&'a str>>,
+    pub speed: Option<i32>,
+    pub darkvision: Option<Option<i32>>,
+    pub resistances: Option<&'a str>,
+    pub is_dead: Option<bool>,
+    pub portrait_path: Option<Option<&'a str>>,
+    pub token_path: Option<Option<&'a str>>,
     pub updated_at: Option<&'a str>,
 }
 
@@ -480,6 +574,21 @@ mod tests {
         assert_eq!(pc.pp, 1);
     }
 
+    #[test]
+    fn test_with_race_traits() {
+        let pc = NewCharacter::new_pc("char-1", Some("camp-1"), "Ranger", "Jane");
+        assert_eq!(pc.speed, 30);
+        assert_eq!(pc.resistances, "[]");
+
+        let pc = pc
+            .with_subrace("Hill", "PHB")
+            .with_race_traits(25, Some(60), r#"["poison"]"#);
+        assert_eq!(pc.subrace_name, Some("Hill"));
+        assert_eq!(pc.speed, 25);
+        assert_eq!(pc.darkvision, Some(60));
+        assert_eq!(pc.resistances, r#"["poison"]"#);
+    }
+
     #[test]
     fn test_with_npc_info() {
         let npc = NewCharacter::new_npc("char-1", Some("camp-1"), "Guard Captain")
@@ -562,6 +671,18 @@ pub struct CharacterResponse {
     pub faction: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    pub subrace_name: Option<String>,
+    pub subrace_source: Option<String>,
+    pub speed: i32,
+    pub darkvision: Option<i32>,
+    /// Damage types resisted (parsed from the character's JSON array)
+    pub resistances: Vec<String>,
+    /// Whether the character is dead
+    pub is_dead: bool,
+    /// Portrait image, relative to the assets directory
+    pub portrait_path: Option<String>,
+    /// Circular token image, relative to the assets directory
+    pub token_path: Option<String>,
     /// Character classes (populated from character_classes table)
     pub classes: Vec<CharacterClass>,
     /// Character proficiencies (populated from character_proficiencies table)
@@ -575,6 +696,7 @@ impl CharacterResponse {
         classes: Vec<CharacterClass>,
         proficiencies: Vec<CharacterProficiency>,
     ) -> Self {
+        let resistances = character.parse_resistances().unwrap_or_default();
         Self {
             id: character.id,
             campaign_id: character.campaign_id,
@@ -605,6 +727,14 @@ impl CharacterResponse {
             faction: character.faction,
             created_at: character.created_at,
             updated_at: character.updated_at,
+            subrace_name: character.subrace_name,
+            subrace_source: character.subrace_source,
+            speed: character.speed,
+            darkvision: character.darkvision,
+            resistances,
+            is_dead: character.is_dead,
+            portrait_path: character.portrait_path,
+            token_path: character.token_path,
             classes,
             proficiencies,
         }
@@ -0,0 +1,258 @@
+//! CharacterCompanion Model
+//!
+//! Creatures that travel with a character: beast companions, familiars, and
+//! sidekicks (Tasha's Cauldron of Everything) with their own class levels.
+
+use crate::schema::character_companions;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A companion creature linked to a character.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = character_companions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CharacterCompanion {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Character who owns this companion
+    pub character_id: String,
+    /// Companion's name (e.g., "Whiskers")
+    pub name: String,
+    /// Kind of companion: 'beast', 'familiar', 'sidekick', 'other'
+    pub companion_type: String,
+    /// Catalog monster name for the stat block
+    pub monster_name: Option<String>,
+    /// Catalog monster source for the stat block
+    pub monster_source: Option<String>,
+    /// Campaign homebrew monster for the stat block
+    pub homebrew_monster_id: Option<String>,
+    /// Sidekick class: 'expert', 'spellcaster', 'warrior'
+    pub sidekick_class: Option<String>,
+    /// Sidekick level (None for companions without levels)
+    pub level: Option<i32>,
+    /// Hit point maximum
+    pub max_hp: i32,
+    /// Current hit points
+    pub current_hp: i32,
+    /// Free-form notes
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl CharacterCompanion {
+    /// Get the companion type as an enum.
+    pub fn companion_type_enum(&self) -> Option<CompanionType> {
+        CompanionType::from_str(&self.companion_type)
+    }
+
+    /// Get the sidekick class as an enum.
+    pub fn sidekick_class_enum(&self) -> Option<SidekickClass> {
+        self.sidekick_class
+            .as_deref()
+            .and_then(SidekickClass::from_str)
+    }
+
+    /// Check if this companion is a sidekick with class levels.
+    pub fn is_sidekick(&self) -> bool {
+        self.companion_type == "sidekick"
+    }
+
+    /// Check if this companion's stat block is a campaign homebrew monster.
+    pub fn is_homebrew(&self) -> bool {
+        self.homebrew_monster_id.is_some()
+    }
+}
+
+/// Kind of companion creature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompanionType {
+    /// Beast companion (e.g., Ranger's Companion, Primal Companion)
+    Beast,
+    /// Familiar from Find Familiar or Pact of the Chain
+    Familiar,
+    /// Sidekick with class levels
+    Sidekick,
+    /// Any other creature (hireling, summoned ally)
+    Other,
+}
+
+impl CompanionType {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompanionType::Beast => "beast",
+            CompanionType::Familiar => "familiar",
+            CompanionType::Sidekick => "sidekick",
+            CompanionType::Other => "other",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "beast" => Some(CompanionType::Beast),
+            "familiar" => Some(CompanionType::Familiar),
+            "sidekick" => Some(CompanionType::Sidekick),
+            "other" => Some(CompanionType::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Sidekick class from Tasha's Cauldron of Everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidekickClass {
+    Expert,
+    Spellcaster,
+    Warrior,
+}
+
+impl SidekickClass {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SidekickClass::Expert => "expert",
+            SidekickClass::Spellcaster => "spellcaster",
+            SidekickClass::Warrior => "warrior",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "expert" => Some(SidekickClass::Expert),
+            "spellcaster" => Some(SidekickClass::Spellcaster),
+            "warrior" => Some(SidekickClass::Warrior),
+            _ => None,
+        }
+    }
+}
+
+/// Data for inserting a new companion.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = character_companions)]
+pub struct NewCharacterCompanion<'a> {
+    pub id: &'a str,
+    pub character_id: &'a str,
+    pub name: &'a str,
+    pub companion_type: &'a str,
+    pub monster_name: Option<&'a str>,
+    pub monster_source: Option<&'a str>,
+    pub homebrew_monster_id: Option<&'a str>,
+    pub sidekick_class: Option<&'a str>,
+    pub level: Option<i32>,
+    pub max_hp: i32,
+    pub current_hp: i32,
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> NewCharacterCompanion<'a> {
+    /// Create a new companion at full hit points.
+    pub fn new(
+        id: &'a str,
+        character_id: &'a str,
+        name: &'a str,
+        companion_type: CompanionType,
+        max_hp: i32,
+    ) -> Self {
+        Self {
+            id,
+            character_id,
+            name,
+            companion_type: companion_type.as_str(),
+            monster_name: None,
+            monster_source: None,
+            homebrew_monster_id: None,
+            sidekick_class: None,
+            level: None,
+            max_hp,
+            current_hp: max_hp,
+            notes: None,
+        }
+    }
+
+    /// Use a catalog monster's stat block.
+    pub fn with_monster(mut self, name: &'a str, source: &'a str) -> Self {
+        self.monster_name = Some(name);
+        self.monster_source = Some(source);
+        self
+    }
+
+    /// Use a campaign homebrew monster's stat block.
+    pub fn with_homebrew_monster(mut self, homebrew_monster_id: &'a str) -> Self {
+        self.homebrew_monster_id = Some(homebrew_monster_id);
+        self
+    }
+
+    /// Set the sidekick class and level.
+    pub fn with_sidekick_class(mut self, class: SidekickClass, level: i32) -> Self {
+        self.sidekick_class = Some(class.as_str());
+        self.level = Some(level);
+        self
+    }
+
+    /// Set notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+/// Data for updating a companion.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = character_companions)]
+pub struct UpdateCharacterCompanion<'a> {
+    pub name: Option<&'a str>,
+    pub sidekick_class: Option<Option<&'a str>>,
+    pub level: Option<Option<i32>>,
+    pub max_hp: Option<i32>,
+    pub current_hp: Option<i32>,
+    pub notes: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+impl<'a> UpdateCharacterCompanion<'a> {
+    /// Set current hit points.
+    pub fn set_current_hp(current_hp: i32, updated_at: &'a str) -> Self {
+        Self {
+            current_hp: Some(current_hp),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_companion_type_round_trip() {
+        for kind in [
+            CompanionType::Beast,
+            CompanionType::Familiar,
+            CompanionType::Sidekick,
+            CompanionType::Other,
+        ] {
+            assert_eq!(CompanionType::from_str(kind.as_str()), Some(kind));
+        }
+        assert_eq!(CompanionType::from_str("mount"), None);
+    }
+
+    #[test]
+    fn test_new_sidekick() {
+        let companion =
+            NewCharacterCompanion::new("comp-1", "char-1", "Grip", CompanionType::Sidekick, 11)
+                .with_monster("Bandit", "MM")
+                .with_sidekick_class(SidekickClass::Warrior, 1);
+        assert_eq!(companion.current_hp, 11);
+        assert_eq!(companion.companion_type, "sidekick");
+        assert_eq!(companion.sidekick_class, Some("warrior"));
+        assert_eq!(companion.level, Some(1));
+    }
+}
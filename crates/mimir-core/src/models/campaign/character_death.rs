@@ -0,0 +1,117 @@
+//! CharacterDeath Model
+//!
+//! A character's death: the cause and when it happened on the party
+//! calendar, plus the resurrection that ended it, if any. A character can
+//! die more than once; only the latest death can still be open.
+
+use crate::schema::character_deaths;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A death of a character.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = character_deaths)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CharacterDeath {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Character who died
+    pub character_id: String,
+    /// How the character died (e.g., "Slain by the dragon Vermithrax")
+    pub cause: String,
+    /// Party whose calendar dates the death
+    pub party_id: Option<String>,
+    /// In-world day of the death on the party's calendar
+    pub day: Option<i32>,
+    /// Free-form notes (last words, where the body lies)
+    pub notes: Option<String>,
+    /// ISO8601 timestamp the death was recorded
+    pub died_at: String,
+    /// How the character came back (None while still dead)
+    pub resurrection_method: Option<String>,
+    /// What the resurrection cost, in copper pieces
+    pub resurrection_cost_cp: Option<i32>,
+    /// In-world day of the resurrection on the party's calendar
+    pub resurrection_day: Option<i32>,
+    /// ISO8601 timestamp the resurrection was recorded
+    pub resurrected_at: Option<String>,
+}
+
+impl CharacterDeath {
+    /// Check if the character hasn't been brought back from this death.
+    pub fn is_open(&self) -> bool {
+        self.resurrected_at.is_none()
+    }
+}
+
+/// Data for inserting a new death.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = character_deaths)]
+pub struct NewCharacterDeath<'a> {
+    pub id: &'a str,
+    pub character_id: &'a str,
+    pub cause: &'a str,
+    pub party_id: Option<&'a str>,
+    pub day: Option<i32>,
+    pub notes: Option<&'a str>,
+    pub died_at: Option<&'a str>,
+}
+
+impl<'a> NewCharacterDeath<'a> {
+    /// Create a new death.
+    pub fn new(id: &'a str, character_id: &'a str, cause: &'a str) -> Self {
+        Self {
+            id,
+            character_id,
+            cause,
+            party_id: None,
+            day: None,
+            notes: None,
+            died_at: None,
+        }
+    }
+
+    /// Date the death on a party's calendar.
+    pub fn on_day(mut self, party_id: &'a str, day: i32) -> Self {
+        self.party_id = Some(party_id);
+        self.day = Some(day);
+        self
+    }
+
+    /// Set notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    /// Keep an existing timestamp (e.g., when importing an archive).
+    pub fn with_died_at(mut self, died_at: &'a str) -> Self {
+        self.died_at = Some(died_at);
+        self
+    }
+}
+
+/// Data for recording a resurrection against a death.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = character_deaths)]
+pub struct UpdateCharacterDeath<'a> {
+    pub resurrection_method: Option<Option<&'a str>>,
+    pub resurrection_cost_cp: Option<Option<i32>>,
+    pub resurrection_day: Option<Option<i32>>,
+    pub resurrected_at: Option<Option<&'a str>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_character_death() {
+        let death = NewCharacterDeath::new("d-1", "char-1", "Fell into lava")
+            .on_day("party-1", 42)
+            .with_notes("Nothing left to bury");
+        assert_eq!(death.party_id, Some("party-1"));
+        assert_eq!(death.day, Some(42));
+        assert!(death.died_at.is_none());
+    }
+}
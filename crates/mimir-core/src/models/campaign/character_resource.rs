@@ -0,0 +1,208 @@
+//! CharacterResource Model
+//!
+//! Tracks limited-use class features (Rage, Ki, Channel Divinity) and how many
+//! uses a character has left before their next rest.
+
+use crate::schema::character_resources;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A limited-use class resource held by a character.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = character_resources)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CharacterResource {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Character who has this resource
+    pub character_id: String,
+    /// Resource name (e.g., "Rage", "Ki Points")
+    pub resource_name: String,
+    /// Which class grants this resource
+    pub source_class: String,
+    /// Uses available after a rest
+    pub max_uses: i32,
+    /// Uses remaining
+    pub current_uses: i32,
+    /// Rest that restores it: 'short_rest', 'long_rest'
+    pub recharge: String,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl CharacterResource {
+    /// Get the recharge type as an enum.
+    pub fn recharge_enum(&self) -> Option<RechargeType> {
+        RechargeType::from_str(&self.recharge)
+    }
+
+    /// Check if every use has been spent.
+    pub fn is_expended(&self) -> bool {
+        self.current_uses <= 0
+    }
+}
+
+/// Rest that restores a resource's expended uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RechargeType {
+    /// Restored by a short or long rest
+    ShortRest,
+    /// Restored only by a long rest
+    LongRest,
+}
+
+impl RechargeType {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RechargeType::ShortRest => "short_rest",
+            RechargeType::LongRest => "long_rest",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "short_rest" => Some(RechargeType::ShortRest),
+            "long_rest" => Some(RechargeType::LongRest),
+            _ => None,
+        }
+    }
+
+    /// Check if a rest of the given kind restores this resource.
+    pub fn restored_by(&self, rest: RestType) -> bool {
+        match rest {
+            RestType::Short => *self == RechargeType::ShortRest,
+            RestType::Long => true,
+        }
+    }
+}
+
+/// Kind of rest a character takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestType {
+    /// One hour of downtime
+    Short,
+    /// Eight hours of sleep or light activity
+    Long,
+}
+
+impl RestType {
+    /// Convert to string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RestType::Short => "short",
+            RestType::Long => "long",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "short" => Some(RestType::Short),
+            "long" => Some(RestType::Long),
+            _ => None,
+        }
+    }
+}
+
+/// Data for inserting a new character resource.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = character_resources)]
+pub struct NewCharacterResource<'a> {
+    pub id: &'a str,
+    pub character_id: &'a str,
+    pub resource_name: &'a str,
+    pub source_class: &'a str,
+    pub max_uses: i32,
+    pub current_uses: i32,
+    pub recharge: &'a str,
+}
+
+impl<'a> NewCharacterResource<'a> {
+    /// Create a new resource with every use available.
+    pub fn new(
+        id: &'a str,
+        character_id: &'a str,
+        resource_name: &'a str,
+        source_class: &'a str,
+        max_uses: i32,
+        recharge: RechargeType,
+    ) -> Self {
+        Self {
+            id,
+            character_id,
+            resource_name,
+            source_class,
+            max_uses,
+            current_uses: max_uses,
+            recharge: recharge.as_str(),
+        }
+    }
+
+    /// Set the remaining uses.
+    pub fn with_current_uses(mut self, current_uses: i32) -> Self {
+        self.current_uses = current_uses;
+        self
+    }
+}
+
+/// Data for updating a character resource.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = character_resources)]
+pub struct UpdateCharacterResource<'a> {
+    pub max_uses: Option<i32>,
+    pub current_uses: Option<i32>,
+    pub recharge: Option<&'a str>,
+    pub updated_at: Option<&'a str>,
+}
+
+impl<'a> UpdateCharacterResource<'a> {
+    /// Set the remaining uses.
+    pub fn set_current_uses(current_uses: i32, updated_at: &'a str) -> Self {
+        Self {
+            current_uses: Some(current_uses),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recharge_type_round_trip() {
+        for recharge in [RechargeType::ShortRest, RechargeType::LongRest] {
+            assert_eq!(RechargeType::from_str(recharge.as_str()), Some(recharge));
+        }
+        assert_eq!(RechargeType::from_str("dawn"), None);
+    }
+
+    #[test]
+    fn test_restored_by() {
+        assert!(RechargeType::ShortRest.restored_by(RestType::Short));
+        assert!(RechargeType::ShortRest.restored_by(RestType::Long));
+        assert!(!RechargeType::LongRest.restored_by(RestType::Short));
+        assert!(RechargeType::LongRest.restored_by(RestType::Long));
+    }
+
+    #[test]
+    fn test_new_resource_starts_full() {
+        let resource = NewCharacterResource::new(
+            "res-1",
+            "char-1",
+            "Ki Points",
+            "Monk",
+            5,
+            RechargeType::ShortRest,
+        );
+        assert_eq!(resource.current_uses, 5);
+        assert_eq!(resource.recharge, "short_rest");
+    }
+}
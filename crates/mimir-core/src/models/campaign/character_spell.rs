@@ -1,6 +1,6 @@
 //! CharacterSpell Model
 //!
-//! Tracks spells known or prepared by characters.
+//! Tracks spells known, prepared, or scribed into a spellbook by characters.
 
 use crate::schema::character_spells;
 use diesel::prelude::*;
@@ -22,6 +22,10 @@ pub struct CharacterSpell {
     pub source_class: String,
     /// Whether the spell is prepared
     pub prepared: i32,
+    /// Whether the spell was copied into the spellbook from a scroll or
+    /// another book, rather than learned on level up
+    #[serde(default)]
+    pub scribed: i32,
 }
 
 impl CharacterSpell {
@@ -29,6 +33,11 @@ impl CharacterSpell {
     pub fn is_prepared(&self) -> bool {
         self.prepared != 0
     }
+
+    /// Check if this spell was scribed into the spellbook.
+    pub fn is_scribed(&self) -> bool {
+        self.scribed != 0
+    }
 }
 
 /// Data for inserting a new character spell.
@@ -41,6 +50,7 @@ pub struct NewCharacterSpell<'a> {
     pub spell_source: &'a str,
     pub source_class: &'a str,
     pub prepared: i32,
+    pub scribed: i32,
 }
 
 impl<'a> NewCharacterSpell<'a> {
@@ -59,6 +69,7 @@ impl<'a> NewCharacterSpell<'a> {
             spell_source,
             source_class,
             prepared: 0,
+            scribed: 0,
         }
     }
 
@@ -67,6 +78,12 @@ impl<'a> NewCharacterSpell<'a> {
         self.prepared = 1;
         self
     }
+
+    /// Mark as scribed into the spellbook.
+    pub fn scribed(mut self) -> Self {
+        self.scribed = 1;
+        self
+    }
 }
 
 /// Data for updating a character spell.
@@ -74,6 +91,7 @@ impl<'a> NewCharacterSpell<'a> {
 #[diesel(table_name = character_spells)]
 pub struct UpdateCharacterSpell {
     pub prepared: Option<i32>,
+    pub scribed: Option<i32>,
 }
 
 impl UpdateCharacterSpell {
@@ -81,6 +99,15 @@ impl UpdateCharacterSpell {
     pub fn set_prepared(is_prepared: bool) -> Self {
         Self {
             prepared: Some(if is_prepared { 1 } else { 0 }),
+            ..Default::default()
+        }
+    }
+
+    /// Set scribed status.
+    pub fn set_scribed(is_scribed: bool) -> Self {
+        Self {
+            scribed: Some(if is_scribed { 1 } else { 0 }),
+            ..Default::default()
         }
     }
 }
@@ -96,6 +123,15 @@ mod tests {
         assert_eq!(spell.spell_source, "PHB");
         assert_eq!(spell.source_class, "Wizard");
         assert_eq!(spell.prepared, 0);
+        assert_eq!(spell.scribed, 0);
+    }
+
+    #[test]
+    fn test_scribed_spell() {
+        let spell =
+            NewCharacterSpell::new("spell-1", "char-1", "Fireball", "PHB", "Wizard").scribed();
+        assert_eq!(spell.scribed, 1);
+        assert_eq!(spell.prepared, 0);
     }
 
     #[test]
@@ -112,5 +148,10 @@ mod tests {
 
         let unprepare = UpdateCharacterSpell::set_prepared(false);
         assert_eq!(unprepare.prepared, Some(0));
+        assert_eq!(unprepare.scribed, None);
+
+        let scribe = UpdateCharacterSpell::set_scribed(true);
+        assert_eq!(scribe.scribed, Some(1));
+        assert_eq!(scribe.prepared, None);
     }
 }
@@ -0,0 +1,221 @@
+//! CraftingProject Model
+//!
+//! Items a character crafts during downtime. The recipe (materials cost,
+//! time, tool, and exotic ingredient) is fixed when the project starts, and
+//! downtime days are logged against it until the item is finished.
+
+use crate::schema::crafting_projects;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An item a character is crafting.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = crafting_projects)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CraftingProject {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Character doing the crafting
+    pub character_id: String,
+    /// Item name
+    pub item_name: String,
+    /// Item source (e.g., "DMG", or "HB" for campaign homebrew)
+    pub item_source: String,
+    /// Number of items produced
+    pub quantity: i32,
+    /// Rarity for magic items (None for mundane items)
+    pub rarity: Option<String>,
+    /// Materials cost in copper pieces
+    pub cost_cp: i32,
+    /// Downtime days needed
+    pub days_required: i32,
+    /// Downtime days logged so far
+    pub days_spent: i32,
+    /// Tool proficiency the work needs (e.g., "Smith's Tools")
+    pub tool: Option<String>,
+    /// Exotic material a magic item needs
+    pub ingredient: Option<String>,
+    /// Whether the exotic material is in hand
+    pub ingredient_acquired: bool,
+    /// 'in_progress', 'completed', or 'abandoned'
+    pub status: String,
+    /// Free-form notes
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+    /// ISO8601 timestamp the item was finished
+    pub completed_at: Option<String>,
+}
+
+impl CraftingProject {
+    /// Get the status as an enum.
+    pub fn status_enum(&self) -> Option<CraftingStatus> {
+        CraftingStatus::from_str(&self.status)
+    }
+
+    /// Check if work can still be logged.
+    pub fn is_in_progress(&self) -> bool {
+        self.status == "in_progress"
+    }
+
+    /// Downtime days still needed.
+    pub fn days_remaining(&self) -> i32 {
+        (self.days_required - self.days_spent).max(0)
+    }
+
+    /// Check if the exotic material is still missing.
+    pub fn needs_ingredient(&self) -> bool {
+        self.ingredient.is_some() && !self.ingredient_acquired
+    }
+}
+
+/// Status of a crafting project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CraftingStatus {
+    InProgress,
+    Completed,
+    Abandoned,
+}
+
+impl CraftingStatus {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CraftingStatus::InProgress => "in_progress",
+            CraftingStatus::Completed => "completed",
+            CraftingStatus::Abandoned => "abandoned",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "in_progress" => Some(CraftingStatus::InProgress),
+            "completed" => Some(CraftingStatus::Completed),
+            "abandoned" => Some(CraftingStatus::Abandoned),
+            _ => None,
+        }
+    }
+}
+
+/// Data for inserting a new crafting project.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = crafting_projects)]
+pub struct NewCraftingProject<'a> {
+    pub id: &'a str,
+    pub character_id: &'a str,
+    pub item_name: &'a str,
+    pub item_source: &'a str,
+    pub quantity: i32,
+    pub rarity: Option<&'a str>,
+    pub cost_cp: i32,
+    pub days_required: i32,
+    pub days_spent: i32,
+    pub tool: Option<&'a str>,
+    pub ingredient: Option<&'a str>,
+    pub ingredient_acquired: bool,
+    pub status: &'a str,
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> NewCraftingProject<'a> {
+    /// Create a new in-progress project.
+    pub fn new(
+        id: &'a str,
+        character_id: &'a str,
+        item_name: &'a str,
+        item_source: &'a str,
+        cost_cp: i32,
+        days_required: i32,
+    ) -> Self {
+        Self {
+            id,
+            character_id,
+            item_name,
+            item_source,
+            quantity: 1,
+            rarity: None,
+            cost_cp,
+            days_required,
+            days_spent: 0,
+            tool: None,
+            ingredient: None,
+            ingredient_acquired: false,
+            status: CraftingStatus::InProgress.as_str(),
+            notes: None,
+        }
+    }
+
+    /// Set the number of items produced.
+    pub fn with_quantity(mut self, quantity: i32) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// Set the magic item rarity.
+    pub fn with_rarity(mut self, rarity: &'a str) -> Self {
+        self.rarity = Some(rarity);
+        self
+    }
+
+    /// Set the tool proficiency the work needs.
+    pub fn with_tool(mut self, tool: &'a str) -> Self {
+        self.tool = Some(tool);
+        self
+    }
+
+    /// Set the exotic material needed.
+    pub fn with_ingredient(mut self, ingredient: &'a str) -> Self {
+        self.ingredient = Some(ingredient);
+        self
+    }
+
+    /// Set notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+/// Data for updating a crafting project.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = crafting_projects)]
+pub struct UpdateCraftingProject<'a> {
+    pub days_spent: Option<i32>,
+    pub ingredient_acquired: Option<bool>,
+    pub status: Option<&'a str>,
+    pub notes: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+    pub completed_at: Option<Option<&'a str>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crafting_status_round_trip() {
+        for status in [
+            CraftingStatus::InProgress,
+            CraftingStatus::Completed,
+            CraftingStatus::Abandoned,
+        ] {
+            assert_eq!(CraftingStatus::from_str(status.as_str()), Some(status));
+        }
+        assert_eq!(CraftingStatus::from_str("paused"), None);
+    }
+
+    #[test]
+    fn test_new_crafting_project() {
+        let project = NewCraftingProject::new("p-1", "char-1", "Bag of Holding", "DMG", 10000, 10)
+            .with_rarity("uncommon")
+            .with_ingredient("Exotic material from a CR 4-8 creature");
+        assert_eq!(project.status, "in_progress");
+        assert_eq!(project.quantity, 1);
+        assert_eq!(project.days_spent, 0);
+        assert!(!project.ingredient_acquired);
+    }
+}
@@ -0,0 +1,79 @@
+//! CurrencyTransaction Model
+//!
+//! The ledger of changes to a character's coins: what changed, per
+//! denomination, and why.
+
+use crate::schema::currency_transactions;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// One change to a character's coins.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = currency_transactions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CurrencyTransaction {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Character whose purse changed
+    pub character_id: String,
+    /// Why the coins changed (e.g., "Sold the ruby")
+    pub reason: String,
+    /// Change in copper (negative when spent)
+    pub cp: i32,
+    /// Change in silver
+    pub sp: i32,
+    /// Change in electrum
+    pub ep: i32,
+    /// Change in gold
+    pub gp: i32,
+    /// Change in platinum
+    pub pp: i32,
+    /// ISO8601 timestamp of the change
+    pub created_at: String,
+}
+
+impl CurrencyTransaction {
+    /// Change as [CP, SP, EP, GP, PP].
+    pub fn amounts(&self) -> [i32; 5] {
+        [self.cp, self.sp, self.ep, self.gp, self.pp]
+    }
+}
+
+/// Data for inserting a currency transaction.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = currency_transactions)]
+pub struct NewCurrencyTransaction<'a> {
+    pub id: &'a str,
+    pub character_id: &'a str,
+    pub reason: &'a str,
+    pub cp: i32,
+    pub sp: i32,
+    pub ep: i32,
+    pub gp: i32,
+    pub pp: i32,
+    /// Defaults to now; set when importing history
+    pub created_at: Option<&'a str>,
+}
+
+impl<'a> NewCurrencyTransaction<'a> {
+    /// Create a transaction from a change as [CP, SP, EP, GP, PP].
+    pub fn new(id: &'a str, character_id: &'a str, reason: &'a str, amounts: [i32; 5]) -> Self {
+        Self {
+            id,
+            character_id,
+            reason,
+            cp: amounts[0],
+            sp: amounts[1],
+            ep: amounts[2],
+            gp: amounts[3],
+            pp: amounts[4],
+            created_at: None,
+        }
+    }
+
+    /// Keep the original timestamp (for imports).
+    pub fn with_created_at(mut self, created_at: &'a str) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+}
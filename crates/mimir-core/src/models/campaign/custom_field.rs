@@ -0,0 +1,240 @@
+//! Custom Field Models
+//!
+//! House-ruled character sheet fields (sanity, faction renown, inspiration
+//! dice) defined per campaign, and the values characters hold for them.
+
+use crate::schema::{campaign_custom_fields, character_custom_fields};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A custom character sheet field defined for a campaign.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = campaign_custom_fields)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CampaignCustomField {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this field is defined for
+    pub campaign_id: String,
+    /// Key character values are stored under, unique per campaign
+    pub field_key: String,
+    /// Label shown on the character sheet
+    pub label: String,
+    /// Value type: 'number', 'text', 'boolean', 'dice'
+    pub field_type: String,
+    /// Value for characters that have not set one
+    pub default_value: Option<String>,
+    /// Lowest allowed value (number fields)
+    pub min_value: Option<i32>,
+    /// Highest allowed value (number fields)
+    pub max_value: Option<i32>,
+    /// What the field tracks
+    pub description: Option<String>,
+    /// Position on the character sheet
+    pub sort_order: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl CampaignCustomField {
+    /// Get the field type as an enum.
+    pub fn field_type_enum(&self) -> Option<CustomFieldType> {
+        CustomFieldType::from_str(&self.field_type)
+    }
+}
+
+/// Type of value a custom field holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    /// Whole number, e.g. a sanity score
+    Number,
+    /// Free text, e.g. a faction rank title
+    Text,
+    /// Yes/no, e.g. a sworn oath
+    Boolean,
+    /// Dice expression, e.g. an inspiration pool of "2d6"
+    Dice,
+}
+
+impl CustomFieldType {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomFieldType::Number => "number",
+            CustomFieldType::Text => "text",
+            CustomFieldType::Boolean => "boolean",
+            CustomFieldType::Dice => "dice",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "number" => Some(CustomFieldType::Number),
+            "text" => Some(CustomFieldType::Text),
+            "boolean" => Some(CustomFieldType::Boolean),
+            "dice" => Some(CustomFieldType::Dice),
+            _ => None,
+        }
+    }
+}
+
+/// Data for inserting a new custom field definition.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = campaign_custom_fields)]
+pub struct NewCampaignCustomField<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub field_key: &'a str,
+    pub label: &'a str,
+    pub field_type: &'a str,
+    pub default_value: Option<&'a str>,
+    pub min_value: Option<i32>,
+    pub max_value: Option<i32>,
+    pub description: Option<&'a str>,
+    pub sort_order: i32,
+}
+
+impl<'a> NewCampaignCustomField<'a> {
+    /// Create a new field definition with required fields.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        field_key: &'a str,
+        label: &'a str,
+        field_type: CustomFieldType,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            field_key,
+            label,
+            field_type: field_type.as_str(),
+            default_value: None,
+            min_value: None,
+            max_value: None,
+            description: None,
+            sort_order: 0,
+        }
+    }
+
+    /// Set the default value.
+    pub fn with_default(mut self, default_value: &'a str) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+
+    /// Set the allowed range for a number field.
+    pub fn with_range(mut self, min_value: Option<i32>, max_value: Option<i32>) -> Self {
+        self.min_value = min_value;
+        self.max_value = max_value;
+        self
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn with_sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+}
+
+/// Data for updating a custom field definition. The key and type are fixed.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = campaign_custom_fields)]
+pub struct UpdateCampaignCustomField<'a> {
+    pub label: Option<&'a str>,
+    pub default_value: Option<Option<&'a str>>,
+    pub min_value: Option<Option<i32>>,
+    pub max_value: Option<Option<i32>>,
+    pub description: Option<Option<&'a str>>,
+    pub sort_order: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// A character's value for a custom field.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = character_custom_fields)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CharacterCustomField {
+    /// Character ID
+    pub character_id: String,
+    /// Key of the campaign's field definition
+    pub field_key: String,
+    /// Value, stored as text whatever the field type
+    pub value: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for setting a character's custom field value.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = character_custom_fields)]
+pub struct NewCharacterCustomField<'a> {
+    pub character_id: &'a str,
+    pub field_key: &'a str,
+    pub value: &'a str,
+    pub updated_at: &'a str,
+}
+
+impl<'a> NewCharacterCustomField<'a> {
+    /// Create a new value.
+    pub fn new(
+        character_id: &'a str,
+        field_key: &'a str,
+        value: &'a str,
+        updated_at: &'a str,
+    ) -> Self {
+        Self {
+            character_id,
+            field_key,
+            value,
+            updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_type_round_trip() {
+        for field_type in [
+            CustomFieldType::Number,
+            CustomFieldType::Text,
+            CustomFieldType::Boolean,
+            CustomFieldType::Dice,
+        ] {
+            assert_eq!(
+                CustomFieldType::from_str(field_type.as_str()),
+                Some(field_type)
+            );
+        }
+        assert_eq!(CustomFieldType::from_str("pool"), None);
+    }
+
+    #[test]
+    fn test_new_custom_field() {
+        let field = NewCampaignCustomField::new(
+            "field-1",
+            "camp-1",
+            "sanity",
+            "Sanity",
+            CustomFieldType::Number,
+        )
+        .with_default("50")
+        .with_range(Some(0), Some(99));
+        assert_eq!(field.field_type, "number");
+        assert_eq!(field.default_value, Some("50"));
+        assert_eq!(field.max_value, Some(99));
+    }
+}
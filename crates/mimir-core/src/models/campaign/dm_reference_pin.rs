@@ -0,0 +1,181 @@
+//! DmReferencePin Model
+//!
+//! Content panels pinned to the DM reference window (the rules side of the DM screen).
+
+use crate::schema::dm_reference_pins;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A content panel pinned to the DM reference window.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = dm_reference_pins)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DmReferencePin {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this pin belongs to
+    pub campaign_id: String,
+    /// Panel type: 'monster', 'document_section', 'npc', 'rules'
+    pub panel_type: String,
+    /// Panel heading shown in the DM reference window
+    pub title: String,
+    /// Document/character ID, or catalog entry name for monster and rules panels
+    pub ref_id: Option<String>,
+    /// Catalog source code for monster and rules panels
+    pub ref_source: Option<String>,
+    /// Document heading for section panels, or catalog kind for rules panels
+    pub section: Option<String>,
+    /// Free-form excerpt text
+    pub content: Option<String>,
+    /// Position in the panel list
+    pub sort_order: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Type of content shown in a pinned panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PanelType {
+    /// Catalog or homebrew monster stat block
+    Monster,
+    /// A section of a campaign document
+    DocumentSection,
+    /// NPC card for a campaign character
+    Npc,
+    /// Rules excerpt (catalog condition, action, variant rule, or free text)
+    Rules,
+}
+
+impl PanelType {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PanelType::Monster => "monster",
+            PanelType::DocumentSection => "document_section",
+            PanelType::Npc => "npc",
+            PanelType::Rules => "rules",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "monster" => Some(PanelType::Monster),
+            "document_section" => Some(PanelType::DocumentSection),
+            "npc" => Some(PanelType::Npc),
+            "rules" => Some(PanelType::Rules),
+            _ => None,
+        }
+    }
+}
+
+/// Data for inserting a new pin.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = dm_reference_pins)]
+pub struct NewDmReferencePin<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub panel_type: &'a str,
+    pub title: &'a str,
+    pub ref_id: Option<&'a str>,
+    pub ref_source: Option<&'a str>,
+    pub section: Option<&'a str>,
+    pub content: Option<&'a str>,
+    pub sort_order: i32,
+}
+
+impl<'a> NewDmReferencePin<'a> {
+    /// Create a new pin with required fields.
+    pub fn new(id: &'a str, campaign_id: &'a str, panel_type: PanelType, title: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            panel_type: panel_type.as_str(),
+            title,
+            ref_id: None,
+            ref_source: None,
+            section: None,
+            content: None,
+            sort_order: 0,
+        }
+    }
+
+    /// Set the referenced entity ID or catalog name.
+    pub fn with_ref(mut self, ref_id: &'a str, ref_source: Option<&'a str>) -> Self {
+        self.ref_id = Some(ref_id);
+        self.ref_source = ref_source;
+        self
+    }
+
+    /// Set the document section or rules kind.
+    pub fn with_section(mut self, section: &'a str) -> Self {
+        self.section = Some(section);
+        self
+    }
+
+    /// Set free-form excerpt content.
+    pub fn with_content(mut self, content: &'a str) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn with_sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+}
+
+/// Data for updating a pin.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = dm_reference_pins)]
+pub struct UpdateDmReferencePin<'a> {
+    pub title: Option<&'a str>,
+    pub section: Option<Option<&'a str>>,
+    pub content: Option<Option<&'a str>>,
+    pub sort_order: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+impl<'a> UpdateDmReferencePin<'a> {
+    /// Update the sort order.
+    pub fn set_sort_order(sort_order: i32, updated_at: &'a str) -> Self {
+        Self {
+            sort_order: Some(sort_order),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panel_type_round_trip() {
+        for panel_type in [
+            PanelType::Monster,
+            PanelType::DocumentSection,
+            PanelType::Npc,
+            PanelType::Rules,
+        ] {
+            assert_eq!(PanelType::from_str(panel_type.as_str()), Some(panel_type));
+        }
+        assert_eq!(PanelType::from_str("spell"), None);
+    }
+
+    #[test]
+    fn test_new_pin() {
+        let pin = NewDmReferencePin::new("pin-1", "camp-1", PanelType::Monster, "Goblin")
+            .with_ref("Goblin", Some("MM"))
+            .with_sort_order(3);
+        assert_eq!(pin.panel_type, "monster");
+        assert_eq!(pin.ref_id, Some("Goblin"));
+        assert_eq!(pin.ref_source, Some("MM"));
+        assert_eq!(pin.sort_order, 3);
+        assert!(pin.content.is_none());
+    }
+}
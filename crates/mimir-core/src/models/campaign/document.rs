@@ -35,9 +35,17 @@ pub struct Document {
     pub created_at: String,
     /// ISO8601 timestamp of last update
     pub updated_at: String,
+    /// ISO8601 timestamp when moved to the trash (None if not trashed)
+    #[serde(default)]
+    pub deleted_at: Option<String>,
 }
 
 impl Document {
+    /// Check if this document is in the trash.
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     /// Check if this document belongs to a module.
     pub fn is_module_document(&self) -> bool {
         self.module_id.is_some()
@@ -0,0 +1,152 @@
+//! EncounterEffect Model
+//!
+//! Environmental effects that act during a module's combat: lair actions,
+//! recurring hazards, and events scheduled for a given round.
+
+use crate::schema::encounter_effects;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// When an encounter effect acts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectTiming {
+    /// On an initiative count every round, losing ties (e.g., lair actions on 20)
+    Initiative,
+    /// At the start of every round (e.g., a spreading fire)
+    EachRound,
+    /// Once, at the start of a given round (e.g., the ceiling collapses on round 3)
+    Round,
+}
+
+impl EffectTiming {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EffectTiming::Initiative => "initiative",
+            EffectTiming::EachRound => "each_round",
+            EffectTiming::Round => "round",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "initiative" => Some(EffectTiming::Initiative),
+            "each_round" => Some(EffectTiming::EachRound),
+            "round" => Some(EffectTiming::Round),
+            _ => None,
+        }
+    }
+}
+
+/// An environmental effect in a module's encounter.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = encounter_effects)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct EncounterEffect {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Module whose encounter this effect belongs to
+    pub module_id: String,
+    /// Name shown in the turn order (e.g., "Lair Action")
+    pub name: String,
+    /// What happens when the effect acts
+    pub description: String,
+    /// 'initiative', 'each_round', or 'round'
+    pub timing: String,
+    /// Initiative count for 'initiative', round number for 'round'
+    pub timing_value: Option<i32>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl EncounterEffect {
+    /// Get the timing as an enum.
+    pub fn timing_enum(&self) -> Option<EffectTiming> {
+        EffectTiming::from_str(&self.timing)
+    }
+}
+
+/// Data for inserting a new encounter effect.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = encounter_effects)]
+pub struct NewEncounterEffect<'a> {
+    pub id: &'a str,
+    pub module_id: &'a str,
+    pub name: &'a str,
+    pub description: &'a str,
+    pub timing: &'a str,
+    pub timing_value: Option<i32>,
+}
+
+impl<'a> NewEncounterEffect<'a> {
+    /// Create a new effect with no description.
+    pub fn new(
+        id: &'a str,
+        module_id: &'a str,
+        name: &'a str,
+        timing: EffectTiming,
+        timing_value: Option<i32>,
+    ) -> Self {
+        Self {
+            id,
+            module_id,
+            name,
+            description: "",
+            timing: timing.as_str(),
+            timing_value,
+        }
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = description;
+        self
+    }
+}
+
+/// Data for updating an encounter effect.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = encounter_effects)]
+pub struct UpdateEncounterEffect<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub timing: Option<&'a str>,
+    pub timing_value: Option<Option<i32>>,
+    pub updated_at: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_round_trip() {
+        for timing in [
+            EffectTiming::Initiative,
+            EffectTiming::EachRound,
+            EffectTiming::Round,
+        ] {
+            assert_eq!(EffectTiming::from_str(timing.as_str()), Some(timing));
+        }
+        assert_eq!(EffectTiming::from_str("turn"), None);
+    }
+
+    #[test]
+    fn test_new_effect() {
+        let effect = NewEncounterEffect::new(
+            "fx-1",
+            "mod-1",
+            "Lair Action",
+            EffectTiming::Initiative,
+            Some(20),
+        )
+        .with_description("Magma erupts from a point the dragon can see.");
+        assert_eq!(effect.timing, "initiative");
+        assert_eq!(effect.timing_value, Some(20));
+        assert!(effect.description.starts_with("Magma"));
+    }
+}
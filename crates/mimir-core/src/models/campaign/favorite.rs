@@ -0,0 +1,77 @@
+//! Favorite Model
+//!
+//! Catalog entries, documents, and maps the DM has pinned for quick access.
+
+use crate::models::campaign::ItemKind;
+use crate::schema::favorites;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A pinned favorite.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = favorites)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Favorite {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Item kind: 'document', 'map', or a catalog kind such as 'monster'
+    pub kind: String,
+    /// Document/map ID, or catalog entry name
+    pub ref_id: String,
+    /// Catalog source code
+    pub ref_source: Option<String>,
+    /// Campaign the document or map belongs to
+    pub campaign_id: Option<String>,
+    /// Display title
+    pub title: String,
+    /// Position in the favorites list
+    pub sort_order: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+}
+
+/// Data for inserting a new favorite.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = favorites)]
+pub struct NewFavorite<'a> {
+    pub id: &'a str,
+    pub kind: &'a str,
+    pub ref_id: &'a str,
+    pub ref_source: Option<&'a str>,
+    pub campaign_id: Option<&'a str>,
+    pub title: &'a str,
+    pub sort_order: i32,
+}
+
+impl<'a> NewFavorite<'a> {
+    /// Create a new favorite.
+    pub fn new(id: &'a str, kind: ItemKind, ref_id: &'a str, title: &'a str) -> Self {
+        Self {
+            id,
+            kind: kind.as_str(),
+            ref_id,
+            ref_source: None,
+            campaign_id: None,
+            title,
+            sort_order: 0,
+        }
+    }
+
+    /// Set the catalog source.
+    pub fn with_source(mut self, source: &'a str) -> Self {
+        self.ref_source = Some(source);
+        self
+    }
+
+    /// Set the owning campaign.
+    pub fn with_campaign(mut self, campaign_id: &'a str) -> Self {
+        self.campaign_id = Some(campaign_id);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn with_sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+}
@@ -0,0 +1,124 @@
+//! ForageIngredient Model
+//!
+//! Entries in a campaign's gathering tables: an ingredient that can be
+//! foraged in an environment, the check DC to find it, and how many are found.
+
+use crate::schema::forage_ingredients;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An ingredient that can be foraged in an environment.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = forage_ingredients)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ForageIngredient {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign whose gathering tables this belongs to
+    pub campaign_id: String,
+    /// Environment or biome, matched case-insensitively (e.g., "forest")
+    pub environment: String,
+    /// Item name from the catalog (or campaign homebrew)
+    pub item_name: String,
+    /// Item source (e.g., "DMG", or "HB" for campaign homebrew)
+    pub item_source: String,
+    /// Lowest check total that finds this ingredient
+    pub dc: i32,
+    /// How many are found, as a number or dice expression (e.g., "1d4")
+    pub quantity: String,
+    /// Relative chance of coming up among the ingredients a check finds
+    pub weight: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new forage ingredient.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = forage_ingredients)]
+pub struct NewForageIngredient<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub environment: &'a str,
+    pub item_name: &'a str,
+    pub item_source: &'a str,
+    pub dc: i32,
+    pub quantity: &'a str,
+    pub weight: i32,
+}
+
+impl<'a> NewForageIngredient<'a> {
+    /// Create a new ingredient found on DC 10, one at a time.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        environment: &'a str,
+        item_name: &'a str,
+        item_source: &'a str,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            environment,
+            item_name,
+            item_source,
+            dc: 10,
+            quantity: "1",
+            weight: 1,
+        }
+    }
+
+    /// Set the check DC.
+    pub fn with_dc(mut self, dc: i32) -> Self {
+        self.dc = dc;
+        self
+    }
+
+    /// Set the quantity found.
+    pub fn with_quantity(mut self, quantity: &'a str) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// Set the roll weight.
+    pub fn with_weight(mut self, weight: i32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// Data for updating a forage ingredient.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = forage_ingredients)]
+pub struct UpdateForageIngredient<'a> {
+    pub dc: Option<i32>,
+    pub quantity: Option<&'a str>,
+    pub weight: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_ingredient_defaults() {
+        let ingredient = NewForageIngredient::new("fi-1", "camp-1", "forest", "Wildroot", "HB");
+        assert_eq!(ingredient.environment, "forest");
+        assert_eq!(ingredient.dc, 10);
+        assert_eq!(ingredient.quantity, "1");
+        assert_eq!(ingredient.weight, 1);
+    }
+
+    #[test]
+    fn test_new_ingredient_with_options() {
+        let ingredient = NewForageIngredient::new("fi-1", "camp-1", "swamp", "Bloodgrass", "HB")
+            .with_dc(15)
+            .with_quantity("1d4")
+            .with_weight(3);
+        assert_eq!(ingredient.dc, 15);
+        assert_eq!(ingredient.quantity, "1d4");
+        assert_eq!(ingredient.weight, 3);
+    }
+}
@@ -0,0 +1,151 @@
+//! GameSession Model
+//!
+//! A session played at the table, with pacing totals from the table timers.
+
+use crate::schema::game_sessions;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A session played at the table.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = game_sessions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct GameSession {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this session belongs to
+    pub campaign_id: String,
+    /// Module being run, if any
+    pub module_id: Option<String>,
+    /// Sequential session number within the campaign (1-based)
+    pub session_number: i32,
+    /// Optional session title
+    pub title: Option<String>,
+    /// ISO8601 timestamp when the session started
+    pub started_at: String,
+    /// ISO8601 timestamp when the session ended, NULL while in progress
+    pub ended_at: Option<String>,
+    /// Time on the session clock, in seconds
+    pub play_seconds: i32,
+    /// Time spent on breaks, in seconds
+    pub break_seconds: i32,
+    /// Number of timed combat turns
+    pub combat_turns: i32,
+    /// Total time spent on combat turns, in seconds
+    pub combat_turn_seconds: i32,
+    /// Longest single combat turn, in seconds
+    pub longest_turn_seconds: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+    /// Party that played this session, if the campaign tracks parties
+    pub party_id: Option<String>,
+    /// XP awarded to each party member for this session
+    pub xp_awarded: i32,
+    /// What was said at the table, imported from a speech-to-text tool
+    pub transcript: Option<String>,
+}
+
+impl GameSession {
+    /// Check if the session is still in progress.
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Average combat turn length in seconds, if any turns were timed.
+    pub fn average_turn_seconds(&self) -> Option<f64> {
+        if self.combat_turns > 0 {
+            Some(self.combat_turn_seconds as f64 / self.combat_turns as f64)
+        } else {
+            None
+        }
+    }
+}
+
+/// Data for inserting a new game session.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = game_sessions)]
+pub struct NewGameSession<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub module_id: Option<&'a str>,
+    pub session_number: i32,
+    pub title: Option<&'a str>,
+    pub party_id: Option<&'a str>,
+    /// Defaults to now; set when importing history
+    pub started_at: Option<&'a str>,
+}
+
+impl<'a> NewGameSession<'a> {
+    /// Create a new session.
+    pub fn new(id: &'a str, campaign_id: &'a str, session_number: i32) -> Self {
+        Self {
+            id,
+            campaign_id,
+            module_id: None,
+            session_number,
+            title: None,
+            party_id: None,
+            started_at: None,
+        }
+    }
+
+    /// Set the module being run.
+    pub fn with_module(mut self, module_id: &'a str) -> Self {
+        self.module_id = Some(module_id);
+        self
+    }
+
+    /// Set the session title.
+    pub fn with_title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set the party playing the session.
+    pub fn with_party(mut self, party_id: &'a str) -> Self {
+        self.party_id = Some(party_id);
+        self
+    }
+
+    /// Keep the original start time (for imports).
+    pub fn with_started_at(mut self, started_at: &'a str) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+}
+
+/// Data for updating a game session.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = game_sessions)]
+pub struct UpdateGameSession<'a> {
+    pub title: Option<Option<&'a str>>,
+    pub ended_at: Option<Option<&'a str>>,
+    pub play_seconds: Option<i32>,
+    pub break_seconds: Option<i32>,
+    pub combat_turns: Option<i32>,
+    pub combat_turn_seconds: Option<i32>,
+    pub longest_turn_seconds: Option<i32>,
+    pub party_id: Option<Option<&'a str>>,
+    pub xp_awarded: Option<i32>,
+    pub transcript: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_game_session() {
+        let session = NewGameSession::new("sess-1", "camp-1", 3)
+            .with_module("mod-1")
+            .with_title("Into the Caves")
+            .with_party("party-1");
+        assert_eq!(session.session_number, 3);
+        assert_eq!(session.party_id, Some("party-1"));
+        assert_eq!(session.module_id, Some("mod-1"));
+        assert_eq!(session.title, Some("Into the Caves"));
+    }
+}
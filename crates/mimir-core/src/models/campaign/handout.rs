@@ -0,0 +1,172 @@
+//! Handout Models
+//!
+//! Images and documents staged during prep to be revealed to the player
+//! display one at a time, and the record of when players saw them.
+
+use crate::schema::{handout_reveals, handouts};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A handout in a campaign's reveal queue.
+///
+/// Exactly one of `image_path`, `asset_id`, and `document_id` is set.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = handouts)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Handout {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the handout belongs to
+    pub campaign_id: String,
+    /// Title shown with the handout
+    pub title: String,
+    /// Catalog image, relative to the catalog images directory
+    pub image_path: Option<String>,
+    /// Uploaded campaign image
+    pub asset_id: Option<String>,
+    /// Document shown as text
+    pub document_id: Option<String>,
+    /// Position in the queue
+    pub sort_order: i32,
+    /// ISO8601 timestamp of the last reveal; `None` while queued
+    pub revealed_at: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl Handout {
+    /// Check if the handout is still waiting to be revealed.
+    pub fn is_queued(&self) -> bool {
+        self.revealed_at.is_none()
+    }
+}
+
+/// Data for inserting a new handout.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = handouts)]
+pub struct NewHandout<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub title: &'a str,
+    pub image_path: Option<&'a str>,
+    pub asset_id: Option<&'a str>,
+    pub document_id: Option<&'a str>,
+    pub sort_order: i32,
+}
+
+impl<'a> NewHandout<'a> {
+    /// Create a new handout. Set its source with one of the `with_*` methods.
+    pub fn new(id: &'a str, campaign_id: &'a str, title: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            title,
+            image_path: None,
+            asset_id: None,
+            document_id: None,
+            sort_order: 0,
+        }
+    }
+
+    /// Show a catalog image.
+    pub fn with_image_path(mut self, image_path: &'a str) -> Self {
+        self.image_path = Some(image_path);
+        self
+    }
+
+    /// Show an uploaded campaign image.
+    pub fn with_asset(mut self, asset_id: &'a str) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    /// Show a document.
+    pub fn with_document(mut self, document_id: &'a str) -> Self {
+        self.document_id = Some(document_id);
+        self
+    }
+
+    /// Set the position in the queue.
+    pub fn with_sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+}
+
+/// Data for updating a handout.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = handouts)]
+pub struct UpdateHandout<'a> {
+    pub title: Option<&'a str>,
+    pub sort_order: Option<i32>,
+    pub revealed_at: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// A record of players being shown a handout.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = handout_reveals)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct HandoutReveal {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Handout that was shown
+    pub handout_id: String,
+    /// Session it was shown in
+    pub session_id: Option<String>,
+    /// ISO8601 timestamp of when it was shown
+    pub revealed_at: String,
+}
+
+/// Data for recording that a handout was shown.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = handout_reveals)]
+pub struct NewHandoutReveal<'a> {
+    pub id: &'a str,
+    pub handout_id: &'a str,
+    pub session_id: Option<&'a str>,
+    pub revealed_at: &'a str,
+}
+
+impl<'a> NewHandoutReveal<'a> {
+    /// Create a new reveal.
+    pub fn new(id: &'a str, handout_id: &'a str, revealed_at: &'a str) -> Self {
+        Self {
+            id,
+            handout_id,
+            session_id: None,
+            revealed_at,
+        }
+    }
+
+    /// Set the session the handout was shown in.
+    pub fn with_session(mut self, session_id: &'a str) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_handout() {
+        let handout = NewHandout::new("handout-1", "camp-1", "The Baron's Letter")
+            .with_document("doc-1")
+            .with_sort_order(3);
+        assert_eq!(handout.document_id, Some("doc-1"));
+        assert!(handout.image_path.is_none());
+        assert!(handout.asset_id.is_none());
+        assert_eq!(handout.sort_order, 3);
+    }
+
+    #[test]
+    fn test_new_reveal() {
+        let reveal = NewHandoutReveal::new("reveal-1", "handout-1", "2026-01-01T00:00:00Z")
+            .with_session("session-1");
+        assert_eq!(reveal.session_id, Some("session-1"));
+    }
+}
@@ -38,9 +38,17 @@ pub struct Map {
     pub created_at: String,
     /// ISO8601 timestamp of last update
     pub updated_at: String,
+    /// ISO8601 timestamp when moved to the trash (None if not trashed)
+    #[serde(default)]
+    pub deleted_at: Option<String>,
 }
 
 impl Map {
+    /// Check if this map is in the trash.
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     /// Check if this is a module-level map.
     pub fn is_module_map(&self) -> bool {
         self.module_id.is_some()
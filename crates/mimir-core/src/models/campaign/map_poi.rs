@@ -22,6 +22,12 @@ pub struct MapPoi {
     pub visible: i32,
     pub created_at: String,
     pub updated_at: String,
+    /// Document describing this location (None if unlinked)
+    #[serde(default)]
+    pub document_id: Option<String>,
+    /// Heading within the document, e.g. "3. Guard Room"
+    #[serde(default)]
+    pub document_section: Option<String>,
 }
 
 impl MapPoi {
@@ -29,6 +35,13 @@ impl MapPoi {
     pub fn is_visible(&self) -> bool {
         self.visible == 1
     }
+
+    /// Linked document ID and section heading, if the POI is linked.
+    pub fn document_link(&self) -> Option<(&str, Option<&str>)> {
+        self.document_id
+            .as_deref()
+            .map(|id| (id, self.document_section.as_deref()))
+    }
 }
 
 /// Data for creating a new POI.
@@ -44,6 +57,8 @@ pub struct NewMapPoi<'a> {
     pub icon: &'a str,
     pub color: Option<&'a str>,
     pub visible: i32,
+    pub document_id: Option<&'a str>,
+    pub document_section: Option<&'a str>,
 }
 
 impl<'a> NewMapPoi<'a> {
@@ -59,6 +74,8 @@ impl<'a> NewMapPoi<'a> {
             icon: "pin",
             color: None,
             visible: 0,
+            document_id: None,
+            document_section: None,
         }
     }
 
@@ -85,6 +102,13 @@ impl<'a> NewMapPoi<'a> {
         self.visible = 1;
         self
     }
+
+    /// Link to a document, optionally at a section heading.
+    pub fn with_document_link(mut self, document_id: &'a str, section: Option<&'a str>) -> Self {
+        self.document_id = Some(document_id);
+        self.document_section = section;
+        self
+    }
 }
 
 /// Data for updating an existing POI.
@@ -99,6 +123,8 @@ pub struct UpdateMapPoi<'a> {
     pub color: Option<Option<&'a str>>,
     pub visible: Option<i32>,
     pub updated_at: Option<&'a str>,
+    pub document_id: Option<Option<&'a str>>,
+    pub document_section: Option<Option<&'a str>>,
 }
 
 impl<'a> UpdateMapPoi<'a> {
@@ -120,6 +146,22 @@ impl<'a> UpdateMapPoi<'a> {
             ..Default::default()
         }
     }
+
+    /// Link to a document section, or unlink with `None`.
+    ///
+    /// Unlinking also clears the section.
+    pub fn set_document_link(
+        document_id: Option<&'a str>,
+        section: Option<&'a str>,
+        updated_at: &'a str,
+    ) -> Self {
+        Self {
+            document_id: Some(document_id),
+            document_section: Some(document_id.and(section)),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -167,4 +209,16 @@ mod tests {
         let update = UpdateMapPoi::set_visible(false, "2024-01-20T12:00:00Z");
         assert_eq!(update.visible, Some(0));
     }
+
+    #[test]
+    fn test_document_link() {
+        let poi = NewMapPoi::new("poi-1", "map-1", "Guard Room", 3, 4)
+            .with_document_link("doc-1", Some("1. Guard Room"));
+        assert_eq!(poi.document_id, Some("doc-1"));
+        assert_eq!(poi.document_section, Some("1. Guard Room"));
+
+        let update = UpdateMapPoi::set_document_link(None, Some("1. Guard Room"), "now");
+        assert_eq!(update.document_id, Some(None));
+        assert_eq!(update.document_section, Some(None));
+    }
 }
@@ -0,0 +1,212 @@
+//! MapRegion Model
+//!
+//! Named encounter regions on maps. Documents reference a region with
+//! `{{map-region: Map Name | region}}` to jump the DM map to it.
+
+use crate::schema::map_regions;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A named rectangle or polygon on a map, in grid units.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = map_regions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct MapRegion {
+    pub id: String,
+    pub map_id: String,
+    pub name: String,
+    /// Left edge of the bounding box (grid units)
+    pub x: f64,
+    /// Top edge of the bounding box (grid units)
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    /// Polygon vertices as a JSON array of `[x, y]` pairs, if not a rectangle
+    pub points: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl MapRegion {
+    /// Parse the polygon vertices, if this region is a polygon.
+    pub fn polygon(&self) -> Option<Vec<[f64; 2]>> {
+        self.points
+            .as_deref()
+            .and_then(|p| serde_json::from_str(p).ok())
+    }
+
+    /// Whether a point (grid units) lies inside the region.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let in_bounds =
+            x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height;
+        if !in_bounds {
+            return false;
+        }
+
+        match self.polygon() {
+            Some(points) => polygon_contains(&points, x, y),
+            None => true,
+        }
+    }
+}
+
+/// Even-odd ray casting test.
+fn polygon_contains(points: &[[f64; 2]], x: f64, y: f64) -> bool {
+    let mut inside = false;
+    let mut j = points.len().wrapping_sub(1);
+    for i in 0..points.len() {
+        let [xi, yi] = points[i];
+        let [xj, yj] = points[j];
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Data for creating a new map region.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = map_regions)]
+pub struct NewMapRegion<'a> {
+    pub id: &'a str,
+    pub map_id: &'a str,
+    pub name: &'a str,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub points: Option<&'a str>,
+}
+
+impl<'a> NewMapRegion<'a> {
+    /// Create a rectangular region.
+    pub fn rect(
+        id: &'a str,
+        map_id: &'a str,
+        name: &'a str,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) -> Self {
+        Self {
+            id,
+            map_id,
+            name,
+            x,
+            y,
+            width,
+            height,
+            points: None,
+        }
+    }
+
+    /// Set polygon vertices (JSON array of `[x, y]` pairs).
+    ///
+    /// The rectangle should be the polygon's bounding box.
+    pub fn with_points(mut self, points: &'a str) -> Self {
+        self.points = Some(points);
+        self
+    }
+}
+
+/// Data for updating an existing map region.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = map_regions)]
+pub struct UpdateMapRegion<'a> {
+    pub name: Option<&'a str>,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub points: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+impl<'a> UpdateMapRegion<'a> {
+    /// Rename the region.
+    pub fn set_name(name: &'a str, updated_at: &'a str) -> Self {
+        Self {
+            name: Some(name),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+
+    /// Replace the region's shape.
+    pub fn set_bounds(
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        points: Option<&'a str>,
+        updated_at: &'a str,
+    ) -> Self {
+        Self {
+            x: Some(x),
+            y: Some(y),
+            width: Some(width),
+            height: Some(height),
+            points: Some(points),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(points: Option<&str>) -> MapRegion {
+        MapRegion {
+            id: "region-1".to_string(),
+            map_id: "map-1".to_string(),
+            name: "area-3".to_string(),
+            x: 0.0,
+            y: 0.0,
+            width: 4.0,
+            height: 4.0,
+            points: points.map(String::from),
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_new_region() {
+        let region = NewMapRegion::rect("region-1", "map-1", "area-3", 1.0, 2.0, 3.0, 4.0);
+        assert_eq!(region.name, "area-3");
+        assert_eq!(region.width, 3.0);
+        assert!(region.points.is_none());
+
+        let region = region.with_points("[[1,2],[4,2],[1,6]]");
+        assert_eq!(region.points, Some("[[1,2],[4,2],[1,6]]"));
+    }
+
+    #[test]
+    fn test_rect_contains() {
+        let region = region(None);
+        assert!(region.contains(0.5, 3.5));
+        assert!(!region.contains(4.5, 1.0));
+        assert!(region.polygon().is_none());
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        // Lower-left triangle of the 4x4 box
+        let region = region(Some("[[0,0],[0,4],[4,4]]"));
+        assert_eq!(region.polygon().unwrap().len(), 3);
+        assert!(region.contains(0.5, 3.5));
+        assert!(!region.contains(3.5, 0.5));
+    }
+
+    #[test]
+    fn test_update_bounds() {
+        let update = UpdateMapRegion::set_bounds(1.0, 1.0, 2.0, 2.0, None, "2024-01-20T12:00:00Z");
+        assert_eq!(update.width, Some(2.0));
+        assert_eq!(update.points, Some(None));
+        assert!(update.name.is_none());
+    }
+}
@@ -36,6 +36,12 @@ pub struct MapTrap {
     pub created_at: String,
     /// ISO8601 timestamp of last update
     pub updated_at: String,
+    /// Catalog trap this was placed from (None for custom traps)
+    #[serde(default)]
+    pub catalog_name: Option<String>,
+    /// Source of the catalog trap, e.g. "DMG"
+    #[serde(default)]
+    pub catalog_source: Option<String>,
 }
 
 impl MapTrap {
@@ -53,6 +59,14 @@ impl MapTrap {
     pub fn is_armed(&self) -> bool {
         self.triggered == 0
     }
+
+    /// Catalog name and source, if this trap was placed from the catalog.
+    pub fn catalog_ref(&self) -> Option<(&str, &str)> {
+        match (&self.catalog_name, &self.catalog_source) {
+            (Some(name), Some(source)) => Some((name, source)),
+            _ => None,
+        }
+    }
 }
 
 /// Data for inserting a new map trap.
@@ -70,6 +84,8 @@ pub struct NewMapTrap<'a> {
     pub dc: Option<i32>,
     pub triggered: i32,
     pub visible: i32,
+    pub catalog_name: Option<&'a str>,
+    pub catalog_source: Option<&'a str>,
 }
 
 impl<'a> NewMapTrap<'a> {
@@ -87,6 +103,8 @@ impl<'a> NewMapTrap<'a> {
             dc: None,
             triggered: 0,
             visible: 0,
+            catalog_name: None,
+            catalog_source: None,
         }
     }
 
@@ -119,6 +137,13 @@ impl<'a> NewMapTrap<'a> {
         self.visible = 1;
         self
     }
+
+    /// Remember the catalog trap this was placed from.
+    pub fn with_catalog_ref(mut self, name: &'a str, source: &'a str) -> Self {
+        self.catalog_name = Some(name);
+        self.catalog_source = Some(source);
+        self
+    }
 }
 
 /// Data for updating a map trap.
@@ -135,6 +160,8 @@ pub struct UpdateMapTrap<'a> {
     pub triggered: Option<i32>,
     pub visible: Option<i32>,
     pub updated_at: Option<&'a str>,
+    pub catalog_name: Option<Option<&'a str>>,
+    pub catalog_source: Option<Option<&'a str>>,
 }
 
 impl<'a> UpdateMapTrap<'a> {
@@ -222,6 +249,16 @@ mod tests {
         assert_eq!(trap.visible, 1);
     }
 
+    #[test]
+    fn test_with_catalog_ref() {
+        let trap = NewMapTrap::new("trap-1", "map-1", "Pits", 0, 0);
+        assert_eq!(trap.catalog_name, None);
+
+        let trap = trap.with_catalog_ref("Pits", "DMG");
+        assert_eq!(trap.catalog_name, Some("Pits"));
+        assert_eq!(trap.catalog_source, Some("DMG"));
+    }
+
     #[test]
     fn test_update_trigger() {
         let update = UpdateMapTrap::trigger("2024-01-20T12:00:00Z");
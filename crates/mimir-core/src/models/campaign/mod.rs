@@ -1,30 +1,57 @@
 //! Campaign Models
 //!
-//! Models for campaign management including campaigns, modules, sources, assets, documents, characters, maps, module entities, and map overlays.
+//! Models for campaign management including campaigns, modules, sources, assets, documents, characters, maps, module entities, map overlays, parties, players, safety tools, custom character fields, class resources, companions, owned vehicles, the shared stash, crafting projects, currency transactions, character deaths, campaign memories, encounter effects, forage tables, module links, and module prep checks.
 
 mod campaign;
 mod campaign_asset;
 mod campaign_homebrew_item;
 mod campaign_homebrew_monster;
 mod campaign_homebrew_spell;
+mod campaign_memory;
 mod campaign_source;
+mod campaign_stash;
 mod character;
 mod character_class;
+mod character_companion;
+mod character_death;
 mod character_feat;
 mod character_feature;
 mod character_inventory;
 mod character_proficiency;
+mod character_resource;
 mod character_source;
 mod character_spell;
+mod crafting_project;
+mod currency_transaction;
+mod custom_field;
+mod dm_reference_pin;
 mod document;
+mod encounter_effect;
+mod favorite;
 mod fog;
+mod forage_ingredient;
+mod game_session;
+mod handout;
 mod light_source;
 mod map;
 mod map_poi;
+mod map_region;
 mod map_trap;
 mod module;
+mod module_link;
 mod module_monster;
 mod module_npc;
+mod module_prep_check;
+mod owned_vehicle;
+mod party;
+mod player;
+mod quick_action;
+mod recent_item;
+mod relationship;
+mod rumor;
+mod safety_tools;
+mod secret_note;
+mod tag;
 mod token_placement;
 
 pub use campaign::{Campaign, NewCampaign, UpdateCampaign};
@@ -41,24 +68,78 @@ pub use campaign_asset::{
     extension_for_mime_type, is_allowed_mime_type, CampaignAsset, NewCampaignAsset,
     ALLOWED_MIME_TYPES,
 };
+pub use campaign_memory::{CampaignMemory, NewCampaignMemory, UpdateCampaignMemory};
 pub use campaign_source::{CampaignSource, NewCampaignSource};
+pub use campaign_stash::{
+    CampaignStashCurrency, CampaignStashItem, LedgerAction, NewCampaignStashCurrency,
+    NewCampaignStashItem, NewStashLedgerEntry, StashLedgerEntry, UpdateCampaignStashItem,
+};
 pub use character::{Character, CharacterResponse, NewCharacter, UpdateCharacter};
 pub use character_class::{CharacterClass, NewCharacterClass, UpdateCharacterClass};
+pub use character_companion::{
+    CharacterCompanion, CompanionType, NewCharacterCompanion, SidekickClass,
+    UpdateCharacterCompanion,
+};
+pub use character_death::{CharacterDeath, NewCharacterDeath, UpdateCharacterDeath};
 pub use character_feat::{CharacterFeat, FeatSourceType, NewCharacterFeat};
 pub use character_feature::{CharacterFeature, FeatureType, NewCharacterFeature};
 pub use character_inventory::{CharacterInventory, NewCharacterInventory, UpdateCharacterInventory};
 pub use character_proficiency::{
     CharacterProficiency, NewCharacterProficiency, ProficiencyType, UpdateCharacterProficiency,
 };
+pub use character_resource::{
+    CharacterResource, NewCharacterResource, RechargeType, RestType, UpdateCharacterResource,
+};
 pub use character_source::{CharacterSource, NewCharacterSource};
 pub use character_spell::{CharacterSpell, NewCharacterSpell, UpdateCharacterSpell};
+pub use crafting_project::{
+    CraftingProject, CraftingStatus, NewCraftingProject, UpdateCraftingProject,
+};
+pub use currency_transaction::{CurrencyTransaction, NewCurrencyTransaction};
+pub use custom_field::{
+    CampaignCustomField, CharacterCustomField, CustomFieldType, NewCampaignCustomField,
+    NewCharacterCustomField, UpdateCampaignCustomField,
+};
+pub use dm_reference_pin::{DmReferencePin, NewDmReferencePin, PanelType, UpdateDmReferencePin};
 pub use document::{Document, NewDocument, UpdateDocument};
+pub use encounter_effect::{
+    EffectTiming, EncounterEffect, NewEncounterEffect, UpdateEncounterEffect,
+};
+pub use favorite::{Favorite, NewFavorite};
 pub use fog::{FogRevealedArea, FogState, NewFogRevealedArea};
+pub use forage_ingredient::{ForageIngredient, NewForageIngredient, UpdateForageIngredient};
+pub use game_session::{GameSession, NewGameSession, UpdateGameSession};
+pub use handout::{Handout, HandoutReveal, NewHandout, NewHandoutReveal, UpdateHandout};
 pub use light_source::{presets as light_presets, LightSource, NewLightSource, UpdateLightSource};
 pub use map::{LightingMode, Map, NewMap, UpdateMap};
 pub use map_trap::{MapTrap, NewMapTrap, UpdateMapTrap};
 pub use map_poi::{MapPoi, NewMapPoi, UpdateMapPoi};
+pub use map_region::{MapRegion, NewMapRegion, UpdateMapRegion};
 pub use module::{Module, NewModule, UpdateModule};
+pub use module_link::{ModuleLink, ModuleLinkType, NewModuleLink, UpdateModuleLink};
 pub use module_monster::{ModuleMonster, NewModuleMonster, UpdateModuleMonster};
 pub use module_npc::{ModuleNpc, NewModuleNpc, UpdateModuleNpc};
+pub use module_prep_check::{ModulePrepCheck, NewModulePrepCheck};
+pub use owned_vehicle::{NewOwnedVehicle, OwnedVehicle, UpdateOwnedVehicle, VehicleKind};
+pub use party::{
+    NewParty, NewPartyMember, NewPartyTimelineEvent, Party, PartyMember, PartyTimelineEvent,
+    UpdateParty,
+};
+pub use player::{
+    NewPlayer, NewPlayerCharacter, NewPlayerClaim, Player, PlayerCharacter, PlayerClaim,
+    UpdatePlayer,
+};
+pub use quick_action::{
+    NewQuickAction, QuickAction, QuickActionResponse, QuickActionStep, UpdateQuickAction,
+};
+pub use recent_item::{ItemKind, NewRecentItem, RecentItem};
+pub use relationship::{
+    Attitude, Disposition, NewDisposition, NewNpcInteraction, NewPartyReputation, NpcInteraction,
+    PartyReputation, ReactionDice, UpdateDisposition, UpdatePartyReputation, MAX_STANDING,
+    MIN_STANDING,
+};
+pub use rumor::{NewRumor, NewRumorHearing, Rumor, RumorHearing, UpdateRumor};
+pub use safety_tools::{CampaignSafetyTools, NewCampaignSafetyTools};
+pub use secret_note::{NewSecretNote, SecretNote, UpdateSecretNote};
+pub use tag::{EntityTag, NewEntityTag, NewTag, Tag, TaggableType, UpdateTag};
 pub use token_placement::{NewTokenPlacement, TokenPlacement, UpdateTokenPlacement};
@@ -29,6 +29,15 @@ pub struct Module {
     pub created_at: String,
     /// ISO8601 timestamp of last update
     pub updated_at: String,
+    /// Template the module was created from (e.g., "dungeon"), which its
+    /// prep checklist is derived from
+    #[serde(default = "default_module_type")]
+    pub module_type: String,
+}
+
+/// Modules exported before the type was kept were general-purpose.
+fn default_module_type() -> String {
+    "general".to_string()
 }
 
 /// Data for inserting a new module.
@@ -40,6 +49,7 @@ pub struct NewModule<'a> {
     pub name: &'a str,
     pub description: Option<&'a str>,
     pub module_number: i32,
+    pub module_type: &'a str,
 }
 
 impl<'a> NewModule<'a> {
@@ -51,6 +61,7 @@ impl<'a> NewModule<'a> {
             name,
             description: None,
             module_number,
+            module_type: "general",
         }
     }
 
@@ -59,6 +70,12 @@ impl<'a> NewModule<'a> {
         self.description = Some(description);
         self
     }
+
+    /// Set the module type.
+    pub fn with_type(mut self, module_type: &'a str) -> Self {
+        self.module_type = module_type;
+        self
+    }
 }
 
 /// Data for updating a module.
@@ -111,6 +128,7 @@ mod tests {
         assert_eq!(module.campaign_id, "campaign-id");
         assert_eq!(module.name, "Chapter 1");
         assert_eq!(module.module_number, 1);
+        assert_eq!(module.module_type, "general");
         assert!(module.description.is_none());
     }
 
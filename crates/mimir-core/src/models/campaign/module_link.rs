@@ -0,0 +1,134 @@
+//! ModuleLink Model
+//!
+//! Directed links between a campaign's modules: one module following from an
+//! earlier one, or foreshadowing a later one with a hook that pays off there.
+
+use crate::schema::module_links;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How two modules are connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleLinkType {
+    /// The `from` module picks up where the `to` module left off
+    FollowsFrom,
+    /// The `from` module sets up a hook that pays off in the `to` module
+    Foreshadows,
+}
+
+impl ModuleLinkType {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModuleLinkType::FollowsFrom => "follows_from",
+            ModuleLinkType::Foreshadows => "foreshadows",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "follows_from" => Some(ModuleLinkType::FollowsFrom),
+            "foreshadows" => Some(ModuleLinkType::Foreshadows),
+            _ => None,
+        }
+    }
+}
+
+/// A link from one module to another.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = module_links)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ModuleLink {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign both modules belong to
+    pub campaign_id: String,
+    /// Module the link starts from
+    pub from_module_id: String,
+    /// Module the link points to
+    pub to_module_id: String,
+    /// 'follows_from' or 'foreshadows'
+    pub link_type: String,
+    /// What carries over, e.g. the hook being foreshadowed
+    pub note: Option<String>,
+    /// Whether a foreshadowed hook has paid off (0 or 1)
+    pub resolved: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+}
+
+impl ModuleLink {
+    /// Get the link type as an enum.
+    pub fn link_type_enum(&self) -> Option<ModuleLinkType> {
+        ModuleLinkType::from_str(&self.link_type)
+    }
+
+    /// Whether a foreshadowed hook has paid off.
+    pub fn is_resolved(&self) -> bool {
+        self.resolved != 0
+    }
+}
+
+/// Data for inserting a new module link.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = module_links)]
+pub struct NewModuleLink<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub from_module_id: &'a str,
+    pub to_module_id: &'a str,
+    pub link_type: &'a str,
+    pub note: Option<&'a str>,
+}
+
+impl<'a> NewModuleLink<'a> {
+    /// Create a new link.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        from_module_id: &'a str,
+        to_module_id: &'a str,
+        link_type: ModuleLinkType,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            from_module_id,
+            to_module_id,
+            link_type: link_type.as_str(),
+            note: None,
+        }
+    }
+
+    /// Set the note.
+    pub fn with_note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+}
+
+/// Data for updating a module link.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = module_links)]
+pub struct UpdateModuleLink<'a> {
+    pub note: Option<Option<&'a str>>,
+    pub resolved: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_type_round_trip() {
+        for link_type in [ModuleLinkType::FollowsFrom, ModuleLinkType::Foreshadows] {
+            assert_eq!(
+                ModuleLinkType::from_str(link_type.as_str()),
+                Some(link_type)
+            );
+        }
+        assert_eq!(ModuleLinkType::from_str("sequel"), None);
+    }
+}
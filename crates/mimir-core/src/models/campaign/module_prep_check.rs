@@ -0,0 +1,39 @@
+//! ModulePrepCheck Model
+//!
+//! Prep checklist tasks a DM ticked off by hand. The checklist itself is
+//! derived from the module's template; only manual ticks are stored.
+
+use crate::schema::module_prep_checks;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A prep task ticked off by hand.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = module_prep_checks)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ModulePrepCheck {
+    /// Module the task belongs to
+    pub module_id: String,
+    /// Key of the task in the module's prep checklist
+    pub task_key: String,
+    /// ISO8601 timestamp of when it was ticked
+    pub checked_at: String,
+}
+
+/// Data for ticking off a prep task.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = module_prep_checks)]
+pub struct NewModulePrepCheck<'a> {
+    pub module_id: &'a str,
+    pub task_key: &'a str,
+}
+
+impl<'a> NewModulePrepCheck<'a> {
+    /// Create a new check.
+    pub fn new(module_id: &'a str, task_key: &'a str) -> Self {
+        Self {
+            module_id,
+            task_key,
+        }
+    }
+}
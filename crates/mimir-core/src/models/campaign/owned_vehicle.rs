@@ -0,0 +1,196 @@
+//! OwnedVehicle Model
+//!
+//! Mounts and vehicles owned by a character or by a whole party. The stat
+//! block and capacity come from the catalog: mounts are items of type MNT,
+//! vehicles are vehicle catalog entries.
+
+use crate::schema::owned_vehicles;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A mount or vehicle owned by a character or a party.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = owned_vehicles)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct OwnedVehicle {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the owner belongs to
+    pub campaign_id: String,
+    /// Character who owns it (None when a party owns it)
+    pub character_id: Option<String>,
+    /// Party that owns it (None when a character owns it)
+    pub party_id: Option<String>,
+    /// Display name (e.g., "The Salty Gull")
+    pub name: String,
+    /// Kind of conveyance: 'vehicle', 'mount'
+    pub vehicle_kind: String,
+    /// Catalog name (vehicle or mount item)
+    pub catalog_name: String,
+    /// Catalog source
+    pub catalog_source: String,
+    /// Free-form notes
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl OwnedVehicle {
+    /// Get the vehicle kind as an enum.
+    pub fn vehicle_kind_enum(&self) -> Option<VehicleKind> {
+        VehicleKind::from_str(&self.vehicle_kind)
+    }
+
+    /// Check if this is a mount rather than a vehicle.
+    pub fn is_mount(&self) -> bool {
+        self.vehicle_kind == "mount"
+    }
+
+    /// Check if a party (rather than a single character) owns this.
+    pub fn is_party_owned(&self) -> bool {
+        self.party_id.is_some()
+    }
+}
+
+/// Kind of owned conveyance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VehicleKind {
+    /// Ship, cart, or other entry from the vehicle catalog
+    Vehicle,
+    /// Riding or draft animal from the item catalog (type MNT)
+    Mount,
+}
+
+impl VehicleKind {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VehicleKind::Vehicle => "vehicle",
+            VehicleKind::Mount => "mount",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "vehicle" => Some(VehicleKind::Vehicle),
+            "mount" => Some(VehicleKind::Mount),
+            _ => None,
+        }
+    }
+}
+
+/// Data for inserting a new owned vehicle.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = owned_vehicles)]
+pub struct NewOwnedVehicle<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub character_id: Option<&'a str>,
+    pub party_id: Option<&'a str>,
+    pub name: &'a str,
+    pub vehicle_kind: &'a str,
+    pub catalog_name: &'a str,
+    pub catalog_source: &'a str,
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> NewOwnedVehicle<'a> {
+    /// Create a vehicle owned by a character.
+    pub fn for_character(
+        id: &'a str,
+        campaign_id: &'a str,
+        character_id: &'a str,
+        name: &'a str,
+        kind: VehicleKind,
+        catalog_name: &'a str,
+        catalog_source: &'a str,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            character_id: Some(character_id),
+            party_id: None,
+            name,
+            vehicle_kind: kind.as_str(),
+            catalog_name,
+            catalog_source,
+            notes: None,
+        }
+    }
+
+    /// Create a vehicle owned by a party.
+    pub fn for_party(
+        id: &'a str,
+        campaign_id: &'a str,
+        party_id: &'a str,
+        name: &'a str,
+        kind: VehicleKind,
+        catalog_name: &'a str,
+        catalog_source: &'a str,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            character_id: None,
+            party_id: Some(party_id),
+            name,
+            vehicle_kind: kind.as_str(),
+            catalog_name,
+            catalog_source,
+            notes: None,
+        }
+    }
+
+    /// Set notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+/// Data for updating an owned vehicle.
+///
+/// Moving a vehicle between owners sets one owner column and clears the
+/// other.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = owned_vehicles)]
+pub struct UpdateOwnedVehicle<'a> {
+    pub character_id: Option<Option<&'a str>>,
+    pub party_id: Option<Option<&'a str>>,
+    pub name: Option<&'a str>,
+    pub notes: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vehicle_kind_round_trip() {
+        for kind in [VehicleKind::Vehicle, VehicleKind::Mount] {
+            assert_eq!(VehicleKind::from_str(kind.as_str()), Some(kind));
+        }
+        assert_eq!(VehicleKind::from_str("ship"), None);
+    }
+
+    #[test]
+    fn test_new_party_vehicle() {
+        let vehicle = NewOwnedVehicle::for_party(
+            "veh-1",
+            "camp-1",
+            "party-1",
+            "The Salty Gull",
+            VehicleKind::Vehicle,
+            "Keelboat",
+            "GoS",
+        );
+        assert_eq!(vehicle.character_id, None);
+        assert_eq!(vehicle.party_id, Some("party-1"));
+        assert_eq!(vehicle.vehicle_kind, "vehicle");
+    }
+}
@@ -0,0 +1,193 @@
+//! Party Models
+//!
+//! Parties within a campaign, their members, and each party's calendar
+//! timeline. Multi-table and West Marches campaigns run several parties
+//! through the same world.
+
+use crate::schema::{parties, party_members, party_timeline_events};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A group of player characters adventuring together.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = parties)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Party {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this party belongs to
+    pub campaign_id: String,
+    /// Party name, unique per campaign (case-insensitive)
+    pub name: String,
+    /// Optional description or table notes
+    pub description: Option<String>,
+    /// Optional hex color for display
+    pub color: Option<String>,
+    /// Current in-world day on the party's calendar (1-based)
+    pub current_day: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new party.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = parties)]
+pub struct NewParty<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub color: Option<&'a str>,
+}
+
+impl<'a> NewParty<'a> {
+    /// Create a new party.
+    pub fn new(id: &'a str, campaign_id: &'a str, name: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            name,
+            description: None,
+            color: None,
+        }
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Set the display color.
+    pub fn with_color(mut self, color: &'a str) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Data for updating a party.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = parties)]
+pub struct UpdateParty<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<Option<&'a str>>,
+    pub color: Option<Option<&'a str>>,
+    pub current_day: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// A character's membership in a party.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = party_members)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PartyMember {
+    /// Party ID
+    pub party_id: String,
+    /// Member character ID
+    pub character_id: String,
+    /// ISO8601 timestamp of when the character joined
+    pub joined_at: String,
+}
+
+/// Data for adding a character to a party.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = party_members)]
+pub struct NewPartyMember<'a> {
+    pub party_id: &'a str,
+    pub character_id: &'a str,
+}
+
+impl<'a> NewPartyMember<'a> {
+    /// Create a new membership.
+    pub fn new(party_id: &'a str, character_id: &'a str) -> Self {
+        Self {
+            party_id,
+            character_id,
+        }
+    }
+}
+
+/// An event on a party's calendar timeline.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = party_timeline_events)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PartyTimelineEvent {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Party whose timeline this is on
+    pub party_id: String,
+    /// Session the event happened in, if any
+    pub session_id: Option<String>,
+    /// In-world day the event happened on
+    pub day: i32,
+    /// Short event title
+    pub title: String,
+    /// Optional details
+    pub description: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+}
+
+/// Data for inserting a new timeline event.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = party_timeline_events)]
+pub struct NewPartyTimelineEvent<'a> {
+    pub id: &'a str,
+    pub party_id: &'a str,
+    pub session_id: Option<&'a str>,
+    pub day: i32,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+}
+
+impl<'a> NewPartyTimelineEvent<'a> {
+    /// Create a new timeline event.
+    pub fn new(id: &'a str, party_id: &'a str, day: i32, title: &'a str) -> Self {
+        Self {
+            id,
+            party_id,
+            session_id: None,
+            day,
+            title,
+            description: None,
+        }
+    }
+
+    /// Link the event to the session it happened in.
+    pub fn with_session(mut self, session_id: &'a str) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    /// Set the details.
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_party() {
+        let party = NewParty::new("party-1", "camp-1", "Tuesday Group")
+            .with_description("Online table")
+            .with_color("#8b5cf6");
+        assert_eq!(party.name, "Tuesday Group");
+        assert_eq!(party.description, Some("Online table"));
+        assert_eq!(party.color, Some("#8b5cf6"));
+    }
+
+    #[test]
+    fn test_new_timeline_event() {
+        let event = NewPartyTimelineEvent::new("evt-1", "party-1", 12, "Cleared the barrow")
+            .with_session("sess-1");
+        assert_eq!(event.day, 12);
+        assert_eq!(event.session_id, Some("sess-1"));
+        assert!(event.description.is_none());
+    }
+}
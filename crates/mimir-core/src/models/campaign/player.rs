@@ -0,0 +1,236 @@
+//! Player Models
+//!
+//! The people playing in a campaign, the characters they own, and the claim
+//! codes used to hand characters over to them.
+
+use crate::schema::{player_characters, player_claims, players};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A player on a campaign's roster.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = players)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Player {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this player is on the roster for
+    pub campaign_id: String,
+    /// Player name, unique per campaign (case-insensitive)
+    pub name: String,
+    /// Email address
+    pub email: Option<String>,
+    /// Discord handle
+    pub discord: Option<String>,
+    /// Phone number
+    pub phone: Option<String>,
+    /// DM notes (availability, preferences, safety tools)
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new player.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = players)]
+pub struct NewPlayer<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub name: &'a str,
+    pub email: Option<&'a str>,
+    pub discord: Option<&'a str>,
+    pub phone: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> NewPlayer<'a> {
+    /// Create a new player.
+    pub fn new(id: &'a str, campaign_id: &'a str, name: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            name,
+            email: None,
+            discord: None,
+            phone: None,
+            notes: None,
+        }
+    }
+
+    /// Set the email address.
+    pub fn with_email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Set the Discord handle.
+    pub fn with_discord(mut self, discord: &'a str) -> Self {
+        self.discord = Some(discord);
+        self
+    }
+
+    /// Set the phone number.
+    pub fn with_phone(mut self, phone: &'a str) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    /// Set the DM notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+/// Data for updating a player.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = players)]
+pub struct UpdatePlayer<'a> {
+    pub name: Option<&'a str>,
+    pub email: Option<Option<&'a str>>,
+    pub discord: Option<Option<&'a str>>,
+    pub phone: Option<Option<&'a str>>,
+    pub notes: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// Link between a character and the player who owns it.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = player_characters)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PlayerCharacter {
+    /// Character ID
+    pub character_id: String,
+    /// Owning player ID
+    pub player_id: String,
+    /// ISO8601 timestamp of when the character was linked
+    pub linked_at: String,
+}
+
+/// Data for linking a character to a player.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = player_characters)]
+pub struct NewPlayerCharacter<'a> {
+    pub character_id: &'a str,
+    pub player_id: &'a str,
+}
+
+impl<'a> NewPlayerCharacter<'a> {
+    /// Create a new link.
+    pub fn new(character_id: &'a str, player_id: &'a str) -> Self {
+        Self {
+            character_id,
+            player_id,
+        }
+    }
+}
+
+/// A shareable claim code issued to a player.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = player_claims)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PlayerClaim {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the claim is for
+    pub campaign_id: String,
+    /// Player the claim was issued to
+    pub player_id: String,
+    /// Claim code, uppercase without separators
+    pub code: String,
+    /// Existing character offered to the player, if any
+    pub character_id: Option<String>,
+    /// ISO8601 timestamp after which the code no longer works
+    pub expires_at: Option<String>,
+    /// ISO8601 timestamp of redemption
+    pub redeemed_at: Option<String>,
+    /// Character claimed or created on redemption
+    pub redeemed_character_id: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+}
+
+impl PlayerClaim {
+    /// Check if the claim has been redeemed.
+    pub fn is_redeemed(&self) -> bool {
+        self.redeemed_at.is_some()
+    }
+
+    /// Claim code formatted for sharing, e.g. "ABCD-2345".
+    pub fn display_code(&self) -> String {
+        let (head, tail) = self.code.split_at(self.code.len() / 2);
+        format!("{}-{}", head, tail)
+    }
+}
+
+/// Data for inserting a new claim.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = player_claims)]
+pub struct NewPlayerClaim<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub player_id: &'a str,
+    pub code: &'a str,
+    pub character_id: Option<&'a str>,
+    pub expires_at: Option<&'a str>,
+}
+
+impl<'a> NewPlayerClaim<'a> {
+    /// Create a new claim.
+    pub fn new(id: &'a str, campaign_id: &'a str, player_id: &'a str, code: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            player_id,
+            code,
+            character_id: None,
+            expires_at: None,
+        }
+    }
+
+    /// Offer an existing character.
+    pub fn with_character(mut self, character_id: &'a str) -> Self {
+        self.character_id = Some(character_id);
+        self
+    }
+
+    /// Set when the code expires.
+    pub fn with_expiry(mut self, expires_at: &'a str) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_player() {
+        let player = NewPlayer::new("player-1", "camp-1", "Alice")
+            .with_email("alice@example.com")
+            .with_discord("alice#1234");
+        assert_eq!(player.email, Some("alice@example.com"));
+        assert_eq!(player.discord, Some("alice#1234"));
+        assert!(player.phone.is_none());
+    }
+
+    #[test]
+    fn test_claim_display_code() {
+        let claim = PlayerClaim {
+            id: "claim-1".to_string(),
+            campaign_id: "camp-1".to_string(),
+            player_id: "player-1".to_string(),
+            code: "ABCD2345".to_string(),
+            character_id: None,
+            expires_at: None,
+            redeemed_at: None,
+            redeemed_character_id: None,
+            created_at: String::new(),
+        };
+        assert_eq!(claim.display_code(), "ABCD-2345");
+        assert!(!claim.is_redeemed());
+    }
+}
@@ -0,0 +1,250 @@
+//! QuickAction Model
+//!
+//! Named bundles of scene changes that the DM triggers with a single click,
+//! e.g. "Open the vault": reveal the room, light the braziers, show the
+//! handout, and start the ambience track.
+
+use crate::schema::quick_actions;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A quick action stored for a campaign.
+///
+/// This is the database model - use `QuickActionResponse` for API responses
+/// with parsed steps.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = quick_actions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct QuickAction {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this action belongs to
+    pub campaign_id: String,
+    /// Button label
+    pub name: String,
+    /// Optional note on what the action does
+    pub description: Option<String>,
+    /// JSON array of `QuickActionStep`, run in order
+    pub steps: String,
+    /// Position on the soundboard
+    pub sort_order: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl QuickAction {
+    /// Parse the stored steps.
+    pub fn parse_steps(&self) -> Result<Vec<QuickActionStep>, serde_json::Error> {
+        serde_json::from_str(&self.steps)
+    }
+}
+
+/// A single step of a quick action.
+///
+/// Map steps change campaign data and are applied together in one
+/// transaction. Display steps (handouts, blackout, ambience, timers) are
+/// carried out by the application after the map steps commit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuickActionStep {
+    /// Reveal a rectangular fog area
+    RevealRect {
+        map_id: String,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// Reveal a circular fog area
+    RevealCircle {
+        map_id: String,
+        x: f64,
+        y: f64,
+        radius: f64,
+    },
+    /// Turn a light source on or off
+    SetLight { light_id: String, active: bool },
+    /// Hide or show a token placement
+    SetTokenHidden { token_id: String, hidden: bool },
+    /// Show or hide a point of interest to players
+    SetPoiVisible { poi_id: String, visible: bool },
+    /// Show or hide a trap to players
+    SetTrapVisible { trap_id: String, visible: bool },
+    /// Show an image handout on the player display
+    SendHandout {
+        kind: String,
+        image_path: String,
+        title: Option<String>,
+        screen: Option<String>,
+    },
+    /// Turn player display blackout on or off
+    Blackout {
+        enabled: bool,
+        screen: Option<String>,
+    },
+    /// Start an ambience track (saved, but not played until the app has an audio player)
+    PlayAmbience { track: String },
+    /// Start a table timer ("turn", "break", or "session")
+    StartTimer {
+        timer: String,
+        limit_secs: Option<i64>,
+        label: Option<String>,
+    },
+}
+
+impl QuickActionStep {
+    /// Whether this step changes map data (and so runs inside the transaction).
+    pub fn is_map_step(&self) -> bool {
+        matches!(
+            self,
+            QuickActionStep::RevealRect { .. }
+                | QuickActionStep::RevealCircle { .. }
+                | QuickActionStep::SetLight { .. }
+                | QuickActionStep::SetTokenHidden { .. }
+                | QuickActionStep::SetPoiVisible { .. }
+                | QuickActionStep::SetTrapVisible { .. }
+        )
+    }
+}
+
+/// Data for inserting a new quick action.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = quick_actions)]
+pub struct NewQuickAction<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub steps: &'a str,
+    pub sort_order: i32,
+}
+
+impl<'a> NewQuickAction<'a> {
+    /// Create a new quick action with required fields.
+    pub fn new(id: &'a str, campaign_id: &'a str, name: &'a str, steps: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            name,
+            description: None,
+            steps,
+            sort_order: 0,
+        }
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn with_sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+}
+
+/// Data for updating a quick action.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = quick_actions)]
+pub struct UpdateQuickAction<'a> {
+    pub name: Option<&'a str>,
+    pub description: Option<Option<&'a str>>,
+    pub steps: Option<&'a str>,
+    pub sort_order: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+impl<'a> UpdateQuickAction<'a> {
+    /// Update the sort order.
+    pub fn set_sort_order(sort_order: i32, updated_at: &'a str) -> Self {
+        Self {
+            sort_order: Some(sort_order),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+}
+
+/// A quick action with its steps parsed, for API responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickActionResponse {
+    pub id: String,
+    pub campaign_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub steps: Vec<QuickActionStep>,
+    pub sort_order: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl TryFrom<QuickAction> for QuickActionResponse {
+    type Error = serde_json::Error;
+
+    fn try_from(action: QuickAction) -> Result<Self, Self::Error> {
+        let steps = action.parse_steps()?;
+        Ok(Self {
+            id: action.id,
+            campaign_id: action.campaign_id,
+            name: action.name,
+            description: action.description,
+            steps,
+            sort_order: action.sort_order,
+            created_at: action.created_at,
+            updated_at: action.updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_json_format() {
+        let json = r#"[
+            {"type": "reveal_rect", "map_id": "map-1", "x": 0, "y": 0, "width": 140, "height": 70},
+            {"type": "set_light", "light_id": "light-1", "active": true},
+            {"type": "play_ambience", "track": "crypt-drone"}
+        ]"#;
+        let steps: Vec<QuickActionStep> = serde_json::from_str(json).expect("Failed to parse");
+        assert_eq!(steps.len(), 3);
+        assert!(steps[0].is_map_step());
+        assert!(steps[1].is_map_step());
+        assert!(!steps[2].is_map_step());
+        assert_eq!(
+            steps[1],
+            QuickActionStep::SetLight {
+                light_id: "light-1".to_string(),
+                active: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_step_rejected() {
+        let json = r#"[{"type": "summon_dragon"}]"#;
+        assert!(serde_json::from_str::<Vec<QuickActionStep>>(json).is_err());
+    }
+
+    #[test]
+    fn test_response_parses_steps() {
+        let action = QuickAction {
+            id: "qa-1".to_string(),
+            campaign_id: "camp-1".to_string(),
+            name: "Lights out".to_string(),
+            description: None,
+            steps: r#"[{"type": "blackout", "enabled": true, "screen": null}]"#.to_string(),
+            sort_order: 1,
+            created_at: "2024-01-20T12:00:00Z".to_string(),
+            updated_at: "2024-01-20T12:00:00Z".to_string(),
+        };
+        let response = QuickActionResponse::try_from(action).expect("Failed to convert");
+        assert_eq!(response.steps.len(), 1);
+        assert!(!response.steps[0].is_map_step());
+    }
+}
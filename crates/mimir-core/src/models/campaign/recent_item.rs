@@ -0,0 +1,204 @@
+//! RecentItem Model
+//!
+//! Catalog entries, documents, and maps the DM has recently opened.
+
+use crate::schema::recent_items;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Kind of item tracked in recent history and favorites.
+///
+/// Documents and maps are campaign content referenced by ID; every other
+/// kind is a catalog entry referenced by name and source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKind {
+    Document,
+    Map,
+    Monster,
+    Spell,
+    Item,
+    Race,
+    Background,
+    Class,
+    Subclass,
+    Feat,
+    OptionalFeature,
+    Table,
+    VariantRule,
+    Vehicle,
+    Cult,
+    Psionic,
+    Reward,
+    Object,
+    Condition,
+    Language,
+    Trap,
+    Hazard,
+    Action,
+    Deity,
+}
+
+impl ItemKind {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ItemKind::Document => "document",
+            ItemKind::Map => "map",
+            ItemKind::Monster => "monster",
+            ItemKind::Spell => "spell",
+            ItemKind::Item => "item",
+            ItemKind::Race => "race",
+            ItemKind::Background => "background",
+            ItemKind::Class => "class",
+            ItemKind::Subclass => "subclass",
+            ItemKind::Feat => "feat",
+            ItemKind::OptionalFeature => "optional_feature",
+            ItemKind::Table => "table",
+            ItemKind::VariantRule => "variant_rule",
+            ItemKind::Vehicle => "vehicle",
+            ItemKind::Cult => "cult",
+            ItemKind::Psionic => "psionic",
+            ItemKind::Reward => "reward",
+            ItemKind::Object => "object",
+            ItemKind::Condition => "condition",
+            ItemKind::Language => "language",
+            ItemKind::Trap => "trap",
+            ItemKind::Hazard => "hazard",
+            ItemKind::Action => "action",
+            ItemKind::Deity => "deity",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "document" => Some(ItemKind::Document),
+            "map" => Some(ItemKind::Map),
+            "monster" => Some(ItemKind::Monster),
+            "spell" => Some(ItemKind::Spell),
+            "item" => Some(ItemKind::Item),
+            "race" => Some(ItemKind::Race),
+            "background" => Some(ItemKind::Background),
+            "class" => Some(ItemKind::Class),
+            "subclass" => Some(ItemKind::Subclass),
+            "feat" => Some(ItemKind::Feat),
+            "optional_feature" => Some(ItemKind::OptionalFeature),
+            "table" => Some(ItemKind::Table),
+            "variant_rule" => Some(ItemKind::VariantRule),
+            "vehicle" => Some(ItemKind::Vehicle),
+            "cult" => Some(ItemKind::Cult),
+            "psionic" => Some(ItemKind::Psionic),
+            "reward" => Some(ItemKind::Reward),
+            "object" => Some(ItemKind::Object),
+            "condition" => Some(ItemKind::Condition),
+            "language" => Some(ItemKind::Language),
+            "trap" => Some(ItemKind::Trap),
+            "hazard" => Some(ItemKind::Hazard),
+            "action" => Some(ItemKind::Action),
+            "deity" => Some(ItemKind::Deity),
+            _ => None,
+        }
+    }
+
+    /// Whether this kind is a catalog entry (referenced by name and source).
+    pub fn is_catalog(&self) -> bool {
+        !matches!(self, ItemKind::Document | ItemKind::Map)
+    }
+}
+
+/// A recently viewed item.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = recent_items)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct RecentItem {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Item kind: 'document', 'map', or a catalog kind such as 'monster'
+    pub kind: String,
+    /// Document/map ID, or catalog entry name
+    pub ref_id: String,
+    /// Catalog source code
+    pub ref_source: Option<String>,
+    /// Campaign the document or map belongs to
+    pub campaign_id: Option<String>,
+    /// Display title
+    pub title: String,
+    /// Number of times the item was opened
+    pub view_count: i32,
+    /// ISO8601 timestamp of the latest view
+    pub last_viewed_at: String,
+}
+
+/// Data for inserting a new recent item.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = recent_items)]
+pub struct NewRecentItem<'a> {
+    pub id: &'a str,
+    pub kind: &'a str,
+    pub ref_id: &'a str,
+    pub ref_source: Option<&'a str>,
+    pub campaign_id: Option<&'a str>,
+    pub title: &'a str,
+    pub last_viewed_at: &'a str,
+}
+
+impl<'a> NewRecentItem<'a> {
+    /// Create a new recent item viewed at `viewed_at`.
+    pub fn new(
+        id: &'a str,
+        kind: ItemKind,
+        ref_id: &'a str,
+        title: &'a str,
+        viewed_at: &'a str,
+    ) -> Self {
+        Self {
+            id,
+            kind: kind.as_str(),
+            ref_id,
+            ref_source: None,
+            campaign_id: None,
+            title,
+            last_viewed_at: viewed_at,
+        }
+    }
+
+    /// Set the catalog source.
+    pub fn with_source(mut self, source: &'a str) -> Self {
+        self.ref_source = Some(source);
+        self
+    }
+
+    /// Set the owning campaign.
+    pub fn with_campaign(mut self, campaign_id: &'a str) -> Self {
+        self.campaign_id = Some(campaign_id);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_kind_round_trip() {
+        for kind in [
+            ItemKind::Document,
+            ItemKind::Map,
+            ItemKind::Monster,
+            ItemKind::OptionalFeature,
+            ItemKind::VariantRule,
+            ItemKind::Deity,
+        ] {
+            assert_eq!(ItemKind::from_str(kind.as_str()), Some(kind));
+        }
+        assert_eq!(ItemKind::from_str("npc"), None);
+    }
+
+    #[test]
+    fn test_is_catalog() {
+        assert!(!ItemKind::Document.is_catalog());
+        assert!(!ItemKind::Map.is_catalog());
+        assert!(ItemKind::Spell.is_catalog());
+    }
+}
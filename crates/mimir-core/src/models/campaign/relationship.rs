@@ -0,0 +1,375 @@
+//! Relationship Models
+//!
+//! How NPCs and factions regard the party, and the history of reaction
+//! checks made against each NPC.
+
+use crate::schema::{dispositions, npc_interactions, party_reputations};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Lowest disposition or reputation value
+pub const MIN_STANDING: i32 = -3;
+
+/// Highest disposition or reputation value
+pub const MAX_STANDING: i32 = 3;
+
+/// Dice a reaction check is rolled with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReactionDice {
+    /// Classic 2d6 reaction roll
+    #[default]
+    #[serde(rename = "2d6")]
+    TwoD6,
+    /// A single d20
+    #[serde(rename = "d20")]
+    D20,
+}
+
+impl ReactionDice {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactionDice::TwoD6 => "2d6",
+            ReactionDice::D20 => "d20",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "2d6" => Some(ReactionDice::TwoD6),
+            "d20" => Some(ReactionDice::D20),
+            _ => None,
+        }
+    }
+}
+
+/// An NPC's attitude toward the party after a reaction check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Attitude {
+    /// Attacks, threatens, or flees
+    Hostile,
+    /// Wary and uncooperative
+    Unfriendly,
+    /// Uncertain; can be talked round
+    Indifferent,
+    /// Willing to talk and trade
+    Friendly,
+    /// Eager to help, even at some risk
+    Helpful,
+}
+
+impl Attitude {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Attitude::Hostile => "hostile",
+            Attitude::Unfriendly => "unfriendly",
+            Attitude::Indifferent => "indifferent",
+            Attitude::Friendly => "friendly",
+            Attitude::Helpful => "helpful",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "hostile" => Some(Attitude::Hostile),
+            "unfriendly" => Some(Attitude::Unfriendly),
+            "indifferent" => Some(Attitude::Indifferent),
+            "friendly" => Some(Attitude::Friendly),
+            "helpful" => Some(Attitude::Helpful),
+            _ => None,
+        }
+    }
+}
+
+/// How an NPC, or everyone in a faction, regards outsiders.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = dispositions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Disposition {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the disposition belongs to
+    pub campaign_id: String,
+    /// NPC, for an NPC's own disposition
+    pub character_id: Option<String>,
+    /// Faction name, for a faction's disposition
+    pub faction: Option<String>,
+    /// -3 (hostile) to +3 (devoted)
+    pub disposition: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new disposition.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = dispositions)]
+pub struct NewDisposition<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub character_id: Option<&'a str>,
+    pub faction: Option<&'a str>,
+    pub disposition: i32,
+}
+
+impl<'a> NewDisposition<'a> {
+    /// Create an NPC's disposition.
+    pub fn for_npc(
+        id: &'a str,
+        campaign_id: &'a str,
+        character_id: &'a str,
+        disposition: i32,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            character_id: Some(character_id),
+            faction: None,
+            disposition,
+        }
+    }
+
+    /// Create a faction's disposition.
+    pub fn for_faction(
+        id: &'a str,
+        campaign_id: &'a str,
+        faction: &'a str,
+        disposition: i32,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            character_id: None,
+            faction: Some(faction),
+            disposition,
+        }
+    }
+}
+
+/// Data for updating a disposition.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = dispositions)]
+pub struct UpdateDisposition<'a> {
+    pub disposition: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// A party's standing with a faction.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = party_reputations)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PartyReputation {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the party belongs to
+    pub campaign_id: String,
+    /// Party the reputation is for
+    pub party_id: String,
+    /// Faction name
+    pub faction: String,
+    /// -3 (reviled) to +3 (honored)
+    pub reputation: i32,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+/// Data for inserting a new party reputation.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = party_reputations)]
+pub struct NewPartyReputation<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub party_id: &'a str,
+    pub faction: &'a str,
+    pub reputation: i32,
+}
+
+impl<'a> NewPartyReputation<'a> {
+    /// Create a new party reputation.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        party_id: &'a str,
+        faction: &'a str,
+        reputation: i32,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            party_id,
+            faction,
+            reputation,
+        }
+    }
+}
+
+/// Data for updating a party reputation.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = party_reputations)]
+pub struct UpdatePartyReputation<'a> {
+    pub reputation: Option<i32>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// A reaction check made against an NPC, kept as their relationship history.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = npc_interactions)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct NpcInteraction {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the NPC belongs to
+    pub campaign_id: String,
+    /// NPC the check was made against
+    pub character_id: String,
+    /// Party the NPC reacted to
+    pub party_id: Option<String>,
+    /// Session the check was made in
+    pub session_id: Option<String>,
+    /// '2d6' or 'd20'
+    pub dice: String,
+    /// The dice result
+    pub roll: i32,
+    /// Disposition, reputation, and situational modifiers combined
+    pub modifier: i32,
+    /// Roll plus modifier
+    pub total: i32,
+    /// 'hostile', 'unfriendly', 'indifferent', 'friendly', or 'helpful'
+    pub attitude: String,
+    /// What happened
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of the check
+    pub created_at: String,
+}
+
+impl NpcInteraction {
+    /// Get the dice as an enum.
+    pub fn dice_enum(&self) -> Option<ReactionDice> {
+        ReactionDice::from_str(&self.dice)
+    }
+
+    /// Get the attitude as an enum.
+    pub fn attitude_enum(&self) -> Option<Attitude> {
+        Attitude::from_str(&self.attitude)
+    }
+}
+
+/// Data for inserting a new NPC interaction.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = npc_interactions)]
+pub struct NewNpcInteraction<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub character_id: &'a str,
+    pub party_id: Option<&'a str>,
+    pub session_id: Option<&'a str>,
+    pub dice: &'a str,
+    pub roll: i32,
+    pub modifier: i32,
+    pub total: i32,
+    pub attitude: &'a str,
+    pub notes: Option<&'a str>,
+    /// Defaults to now; set when importing history
+    pub created_at: Option<&'a str>,
+}
+
+impl<'a> NewNpcInteraction<'a> {
+    /// Create a new interaction with no party, session, or notes.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        character_id: &'a str,
+        dice: ReactionDice,
+        roll: i32,
+        modifier: i32,
+        attitude: Attitude,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            character_id,
+            party_id: None,
+            session_id: None,
+            dice: dice.as_str(),
+            roll,
+            modifier,
+            total: roll + modifier,
+            attitude: attitude.as_str(),
+            notes: None,
+            created_at: None,
+        }
+    }
+
+    /// Set the party the NPC reacted to.
+    pub fn with_party(mut self, party_id: &'a str) -> Self {
+        self.party_id = Some(party_id);
+        self
+    }
+
+    /// Set the session the check was made in.
+    pub fn with_session(mut self, session_id: &'a str) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    /// Set the notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    /// Keep the original timestamp (for imports).
+    pub fn with_created_at(mut self, created_at: &'a str) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enums_round_trip() {
+        for dice in [ReactionDice::TwoD6, ReactionDice::D20] {
+            assert_eq!(ReactionDice::from_str(dice.as_str()), Some(dice));
+        }
+        for attitude in [
+            Attitude::Hostile,
+            Attitude::Unfriendly,
+            Attitude::Indifferent,
+            Attitude::Friendly,
+            Attitude::Helpful,
+        ] {
+            assert_eq!(Attitude::from_str(attitude.as_str()), Some(attitude));
+        }
+        assert_eq!(Attitude::from_str("smitten"), None);
+    }
+
+    #[test]
+    fn test_new_interaction() {
+        let interaction = NewNpcInteraction::new(
+            "int-1",
+            "camp-1",
+            "npc-1",
+            ReactionDice::TwoD6,
+            7,
+            2,
+            Attitude::Friendly,
+        )
+        .with_party("party-1")
+        .with_notes("Bought the first round");
+        assert_eq!(interaction.total, 9);
+        assert_eq!(interaction.dice, "2d6");
+        assert_eq!(interaction.attitude, "friendly");
+        assert_eq!(interaction.party_id, Some("party-1"));
+        assert!(interaction.session_id.is_none());
+    }
+}
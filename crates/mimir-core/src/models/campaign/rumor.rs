@@ -0,0 +1,173 @@
+//! Rumor Models
+//!
+//! Rumors and adventure hooks the DM can drop into play, and the record of
+//! which players have heard each one.
+
+use crate::schema::{rumor_hearings, rumors};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A rumor or adventure hook in a campaign.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = rumors)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Rumor {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the rumor belongs to
+    pub campaign_id: String,
+    /// What is said
+    pub text: String,
+    /// Whether the rumor is true (0 = false, 1 = true)
+    pub is_true: i32,
+    /// NPC the rumor comes from
+    pub source_npc_id: Option<String>,
+    /// Module (quest) the rumor leads into
+    pub module_id: Option<String>,
+    /// DM notes
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl Rumor {
+    /// Check if the rumor is true.
+    pub fn is_true(&self) -> bool {
+        self.is_true != 0
+    }
+}
+
+/// Data for inserting a new rumor.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = rumors)]
+pub struct NewRumor<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub text: &'a str,
+    pub is_true: i32,
+    pub source_npc_id: Option<&'a str>,
+    pub module_id: Option<&'a str>,
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> NewRumor<'a> {
+    /// Create a new true rumor.
+    pub fn new(id: &'a str, campaign_id: &'a str, text: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            text,
+            is_true: 1,
+            source_npc_id: None,
+            module_id: None,
+            notes: None,
+        }
+    }
+
+    /// Set whether the rumor is true.
+    pub fn with_truth(mut self, is_true: bool) -> Self {
+        self.is_true = is_true as i32;
+        self
+    }
+
+    /// Set the NPC the rumor comes from.
+    pub fn with_source_npc(mut self, npc_id: &'a str) -> Self {
+        self.source_npc_id = Some(npc_id);
+        self
+    }
+
+    /// Set the module the rumor leads into.
+    pub fn with_module(mut self, module_id: &'a str) -> Self {
+        self.module_id = Some(module_id);
+        self
+    }
+
+    /// Set the DM notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+/// Data for updating a rumor.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = rumors)]
+pub struct UpdateRumor<'a> {
+    pub text: Option<&'a str>,
+    pub is_true: Option<i32>,
+    pub source_npc_id: Option<Option<&'a str>>,
+    pub module_id: Option<Option<&'a str>>,
+    pub notes: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+/// A record of a player having heard a rumor.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = rumor_hearings)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct RumorHearing {
+    /// Rumor ID
+    pub rumor_id: String,
+    /// Player who heard it
+    pub player_id: String,
+    /// Session it was heard in
+    pub session_id: Option<String>,
+    /// ISO8601 timestamp of when it was heard
+    pub heard_at: String,
+}
+
+/// Data for recording that a player heard a rumor.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = rumor_hearings)]
+pub struct NewRumorHearing<'a> {
+    pub rumor_id: &'a str,
+    pub player_id: &'a str,
+    pub session_id: Option<&'a str>,
+    pub heard_at: &'a str,
+}
+
+impl<'a> NewRumorHearing<'a> {
+    /// Create a new hearing.
+    pub fn new(rumor_id: &'a str, player_id: &'a str, heard_at: &'a str) -> Self {
+        Self {
+            rumor_id,
+            player_id,
+            session_id: None,
+            heard_at,
+        }
+    }
+
+    /// Set the session the rumor was heard in.
+    pub fn with_session(mut self, session_id: &'a str) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_rumor() {
+        let rumor = NewRumor::new("rumor-1", "camp-1", "The miller's son never came back.")
+            .with_truth(false)
+            .with_source_npc("npc-1")
+            .with_module("mod-1");
+        assert_eq!(rumor.is_true, 0);
+        assert_eq!(rumor.source_npc_id, Some("npc-1"));
+        assert_eq!(rumor.module_id, Some("mod-1"));
+        assert!(rumor.notes.is_none());
+
+        assert_eq!(NewRumor::new("rumor-2", "camp-1", "Wolves").is_true, 1);
+    }
+
+    #[test]
+    fn test_new_hearing() {
+        let hearing = NewRumorHearing::new("rumor-1", "player-1", "2026-01-01T00:00:00Z")
+            .with_session("session-1");
+        assert_eq!(hearing.session_id, Some("session-1"));
+    }
+}
@@ -0,0 +1,131 @@
+//! Campaign Safety Tools Model
+//!
+//! The lines, veils, and safety mechanics a table agreed on at session zero.
+//! Each campaign has at most one row.
+
+use crate::schema::campaign_safety_tools;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A campaign's safety tools configuration.
+///
+/// This is the database model - lines and veils are stored as JSON arrays.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = campaign_safety_tools)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct CampaignSafetyTools {
+    /// Campaign these tools apply to
+    pub campaign_id: String,
+    /// JSON array of hard limits that never appear in the game
+    pub lines: String,
+    /// JSON array of topics that may be referenced but fade to black
+    pub veils: String,
+    /// Whether the table uses an X-card
+    pub x_card_enabled: bool,
+    /// How to invoke the X-card, e.g. "tap the card in the middle of the table"
+    pub x_card_signal: Option<String>,
+    /// Whether anyone may step away from the table at any time
+    pub open_door: bool,
+    /// Whether the DM checks in on comfort levels at the start of sessions
+    pub check_ins: bool,
+    /// DM-only notes
+    pub notes: Option<String>,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl CampaignSafetyTools {
+    /// Parse the stored lines.
+    pub fn parse_lines(&self) -> Result<Vec<String>, serde_json::Error> {
+        serde_json::from_str(&self.lines)
+    }
+
+    /// Parse the stored veils.
+    pub fn parse_veils(&self) -> Result<Vec<String>, serde_json::Error> {
+        serde_json::from_str(&self.veils)
+    }
+}
+
+/// Data for inserting or replacing a campaign's safety tools.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = campaign_safety_tools)]
+pub struct NewCampaignSafetyTools<'a> {
+    pub campaign_id: &'a str,
+    pub lines: &'a str,
+    pub veils: &'a str,
+    pub x_card_enabled: bool,
+    pub x_card_signal: Option<&'a str>,
+    pub open_door: bool,
+    pub check_ins: bool,
+    pub notes: Option<&'a str>,
+    pub updated_at: &'a str,
+}
+
+impl<'a> NewCampaignSafetyTools<'a> {
+    /// Create a configuration with the default mechanics enabled and no
+    /// lines or veils.
+    pub fn new(campaign_id: &'a str, updated_at: &'a str) -> Self {
+        Self {
+            campaign_id,
+            lines: "[]",
+            veils: "[]",
+            x_card_enabled: true,
+            x_card_signal: None,
+            open_door: true,
+            check_ins: true,
+            notes: None,
+            updated_at,
+        }
+    }
+
+    /// Set the lines and veils, as JSON arrays.
+    pub fn with_limits(mut self, lines: &'a str, veils: &'a str) -> Self {
+        self.lines = lines;
+        self.veils = veils;
+        self
+    }
+
+    /// Set the X-card options.
+    pub fn with_x_card(mut self, enabled: bool, signal: Option<&'a str>) -> Self {
+        self.x_card_enabled = enabled;
+        self.x_card_signal = signal;
+        self
+    }
+
+    /// Set DM-only notes.
+    pub fn with_notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_safety_tools_defaults() {
+        let tools = NewCampaignSafetyTools::new("camp-1", "2024-01-01T00:00:00Z");
+        assert_eq!(tools.lines, "[]");
+        assert!(tools.x_card_enabled);
+        assert!(tools.open_door);
+        assert!(tools.check_ins);
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        let tools = CampaignSafetyTools {
+            campaign_id: "camp-1".to_string(),
+            lines: r#"["Harm to children"]"#.to_string(),
+            veils: "[]".to_string(),
+            x_card_enabled: true,
+            x_card_signal: None,
+            open_door: true,
+            check_ins: true,
+            notes: None,
+            updated_at: String::new(),
+        };
+        assert_eq!(tools.parse_lines().unwrap(), vec!["Harm to children"]);
+        assert!(tools.parse_veils().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+//! Secret Note Models
+//!
+//! Notes meant for one player's eyes only, queued for delivery at the table
+//! and marked delivered once handed over.
+
+use crate::schema::secret_notes;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A secret note for one player.
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = secret_notes)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct SecretNote {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign the note belongs to
+    pub campaign_id: String,
+    /// Player the note is for
+    pub player_id: String,
+    /// Module the note relates to
+    pub module_id: Option<String>,
+    /// Short title printed on the slip
+    pub title: String,
+    /// The secret itself
+    pub content: String,
+    /// ISO8601 timestamp of delivery; `None` while outstanding
+    pub delivered_at: Option<String>,
+    /// Session the note was delivered in
+    pub session_id: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+    /// ISO8601 timestamp of last update
+    pub updated_at: String,
+}
+
+impl SecretNote {
+    /// Check if the note is still waiting to be delivered.
+    pub fn is_outstanding(&self) -> bool {
+        self.delivered_at.is_none()
+    }
+}
+
+/// Data for inserting a new secret note.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = secret_notes)]
+pub struct NewSecretNote<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub player_id: &'a str,
+    pub module_id: Option<&'a str>,
+    pub title: &'a str,
+    pub content: &'a str,
+}
+
+impl<'a> NewSecretNote<'a> {
+    /// Create a new outstanding note.
+    pub fn new(
+        id: &'a str,
+        campaign_id: &'a str,
+        player_id: &'a str,
+        title: &'a str,
+        content: &'a str,
+    ) -> Self {
+        Self {
+            id,
+            campaign_id,
+            player_id,
+            module_id: None,
+            title,
+            content,
+        }
+    }
+
+    /// Set the module the note relates to.
+    pub fn with_module(mut self, module_id: &'a str) -> Self {
+        self.module_id = Some(module_id);
+        self
+    }
+}
+
+/// Data for updating a secret note.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = secret_notes)]
+pub struct UpdateSecretNote<'a> {
+    pub player_id: Option<&'a str>,
+    pub module_id: Option<Option<&'a str>>,
+    pub title: Option<&'a str>,
+    pub content: Option<&'a str>,
+    pub delivered_at: Option<Option<&'a str>>,
+    pub session_id: Option<Option<&'a str>>,
+    pub updated_at: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_secret_note() {
+        let note = NewSecretNote::new(
+            "note-1",
+            "camp-1",
+            "player-1",
+            "Your Dreams",
+            "The voice calls you by your true name.",
+        )
+        .with_module("mod-1");
+        assert_eq!(note.player_id, "player-1");
+        assert_eq!(note.module_id, Some("mod-1"));
+    }
+}
@@ -0,0 +1,168 @@
+//! Tag Models
+//!
+//! Campaign-wide tags and the polymorphic links that attach them to
+//! documents, NPCs, monsters, maps, and assets.
+
+use crate::schema::{entity_tags, tags};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A tag defined for a campaign (e.g. "Act 2", "Underdark", "Heist").
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct Tag {
+    /// Unique ID (UUID)
+    pub id: String,
+    /// Campaign this tag belongs to
+    pub campaign_id: String,
+    /// Tag name, unique per campaign (case-insensitive)
+    pub name: String,
+    /// Optional hex color for display
+    pub color: Option<String>,
+    /// ISO8601 timestamp of creation
+    pub created_at: String,
+}
+
+/// Data for inserting a new tag.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = tags)]
+pub struct NewTag<'a> {
+    pub id: &'a str,
+    pub campaign_id: &'a str,
+    pub name: &'a str,
+    pub color: Option<&'a str>,
+}
+
+impl<'a> NewTag<'a> {
+    /// Create a new tag.
+    pub fn new(id: &'a str, campaign_id: &'a str, name: &'a str) -> Self {
+        Self {
+            id,
+            campaign_id,
+            name,
+            color: None,
+        }
+    }
+
+    /// Set the display color.
+    pub fn with_color(mut self, color: &'a str) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// Data for updating a tag.
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = tags)]
+pub struct UpdateTag<'a> {
+    pub name: Option<&'a str>,
+    pub color: Option<Option<&'a str>>,
+}
+
+/// Kind of entity a tag can be attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaggableType {
+    /// Campaign or module document
+    Document,
+    /// NPC character
+    Npc,
+    /// Monster entry in a module
+    Monster,
+    /// Campaign homebrew monster
+    HomebrewMonster,
+    /// Campaign or module map
+    Map,
+    /// Uploaded campaign asset
+    Asset,
+}
+
+impl TaggableType {
+    /// Convert to string for database storage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaggableType::Document => "document",
+            TaggableType::Npc => "npc",
+            TaggableType::Monster => "monster",
+            TaggableType::HomebrewMonster => "homebrew_monster",
+            TaggableType::Map => "map",
+            TaggableType::Asset => "asset",
+        }
+    }
+
+    /// Parse from string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "document" => Some(TaggableType::Document),
+            "npc" => Some(TaggableType::Npc),
+            "monster" => Some(TaggableType::Monster),
+            "homebrew_monster" => Some(TaggableType::HomebrewMonster),
+            "map" => Some(TaggableType::Map),
+            "asset" => Some(TaggableType::Asset),
+            _ => None,
+        }
+    }
+}
+
+/// A tag attached to an entity.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = entity_tags)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct EntityTag {
+    /// Tag ID
+    pub tag_id: String,
+    /// Entity type: 'document', 'npc', 'monster', 'homebrew_monster', 'map', 'asset'
+    pub entity_type: String,
+    /// ID of the tagged entity
+    pub entity_id: String,
+    /// ISO8601 timestamp of when the tag was attached
+    pub created_at: String,
+}
+
+/// Data for attaching a tag to an entity.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = entity_tags)]
+pub struct NewEntityTag<'a> {
+    pub tag_id: &'a str,
+    pub entity_type: &'a str,
+    pub entity_id: &'a str,
+}
+
+impl<'a> NewEntityTag<'a> {
+    /// Create a new tag link.
+    pub fn new(tag_id: &'a str, entity_type: TaggableType, entity_id: &'a str) -> Self {
+        Self {
+            tag_id,
+            entity_type: entity_type.as_str(),
+            entity_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_taggable_type_round_trip() {
+        for entity_type in [
+            TaggableType::Document,
+            TaggableType::Npc,
+            TaggableType::Monster,
+            TaggableType::HomebrewMonster,
+            TaggableType::Map,
+            TaggableType::Asset,
+        ] {
+            assert_eq!(TaggableType::from_str(entity_type.as_str()), Some(entity_type));
+        }
+        assert_eq!(TaggableType::from_str("spell"), None);
+    }
+
+    #[test]
+    fn test_new_entity_tag() {
+        let link = NewEntityTag::new("tag-1", TaggableType::HomebrewMonster, "hb-1");
+        assert_eq!(link.entity_type, "homebrew_monster");
+        assert_eq!(link.entity_id, "hb-1");
+    }
+}
@@ -1,13 +1,13 @@
 //! TokenPlacement Model
 //!
-//! Token placements for monsters and NPCs on maps.
-//! PCs are placed at runtime via frontend state, not persisted here.
+//! Token placements for monsters, NPCs, and PCs on maps.
+//! PC tokens carry a label and, optionally, the character they stand for.
 
 use crate::schema::token_placements;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// A token placement on a map, representing either a monster or NPC.
+/// A token placement on a map, representing a monster, NPC, or PC.
 #[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
 #[diesel(table_name = token_placements)]
 pub struct TokenPlacement {
@@ -39,6 +39,11 @@ pub struct TokenPlacement {
     pub light_radius_ft: i32,
     /// ISO8601 timestamp of creation
     pub created_at: String,
+    /// Character a PC token stands for (for its token image)
+    pub character_id: Option<String>,
+    /// Number of creatures in the mob a monster token stands for; None for a
+    /// single creature
+    pub mob_size: Option<i32>,
 }
 
 impl TokenPlacement {
@@ -75,6 +80,8 @@ pub struct NewTokenPlacement<'a> {
     pub vision_dim_ft: Option<i32>,
     pub vision_dark_ft: i32,
     pub light_radius_ft: i32,
+    pub character_id: Option<&'a str>,
+    pub mob_size: Option<i32>,
 }
 
 impl<'a> NewTokenPlacement<'a> {
@@ -100,6 +107,8 @@ impl<'a> NewTokenPlacement<'a> {
             vision_dim_ft: None,
             vision_dark_ft: 0,
             light_radius_ft: 0,
+            character_id: None,
+            mob_size: None,
         }
     }
 
@@ -125,6 +134,8 @@ impl<'a> NewTokenPlacement<'a> {
             vision_dim_ft: None,
             vision_dark_ft: 0,
             light_radius_ft: 0,
+            character_id: None,
+            mob_size: None,
         }
     }
 
@@ -145,6 +156,18 @@ impl<'a> NewTokenPlacement<'a> {
         self.hidden = 1;
         self
     }
+
+    /// Link a PC token to its character.
+    pub fn with_character(mut self, character_id: &'a str) -> Self {
+        self.character_id = Some(character_id);
+        self
+    }
+
+    /// Make a monster token stand for a mob of `size` creatures.
+    pub fn as_mob(mut self, size: i32) -> Self {
+        self.mob_size = Some(size);
+        self
+    }
 }
 
 /// Data for updating a token placement.
@@ -160,6 +183,7 @@ pub struct UpdateTokenPlacement<'a> {
     pub vision_dim_ft: Option<Option<i32>>,
     pub vision_dark_ft: Option<i32>,
     pub light_radius_ft: Option<i32>,
+    pub mob_size: Option<Option<i32>>,
 }
 
 impl<'a> UpdateTokenPlacement<'a> {
@@ -211,6 +235,14 @@ impl<'a> UpdateTokenPlacement<'a> {
             ..Default::default()
         }
     }
+
+    /// Update mob size (None makes it a single creature's token).
+    pub fn set_mob_size(mob_size: Option<i32>) -> Self {
+        Self {
+            mob_size: Some(mob_size),
+            ..Default::default()
+        }
+    }
 }
 
 #[cfg(test)]
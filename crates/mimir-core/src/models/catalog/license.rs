@@ -0,0 +1,130 @@
+//! Catalog License Model
+//!
+//! Records which catalog entries come from an openly licensed System Reference
+//! Document. Entries without a license record are book content.
+
+use crate::schema::catalog_licenses;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// System Reference Document 5.1 (2014 rules), CC-BY-4.0.
+pub const LICENSE_SRD_5_1: &str = "SRD 5.1";
+/// System Reference Document 5.2 (2024 rules), CC-BY-4.0.
+pub const LICENSE_SRD_5_2: &str = "SRD 5.2";
+
+/// The SRD license a 5etools entity is published under, if any.
+///
+/// 5etools marks SRD entries with `srd` / `srd52`, set to `true` or to the
+/// name the entry has in the SRD when that differs.
+pub fn license_for_entity(entity: &Value) -> Option<&'static str> {
+    let flagged = |key: &str| match entity.get(key) {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::String(s)) => !s.is_empty(),
+        _ => false,
+    };
+
+    if flagged("srd52") {
+        Some(LICENSE_SRD_5_2)
+    } else if flagged("srd") {
+        Some(LICENSE_SRD_5_1)
+    } else {
+        None
+    }
+}
+
+/// The attribution notice required when sharing content under these licenses.
+///
+/// Returns `None` if no licenses are given.
+pub fn license_notice(licenses: &[&str]) -> Option<String> {
+    let mut paragraphs = Vec::new();
+    if licenses.contains(&LICENSE_SRD_5_1) {
+        paragraphs.push(
+            "This work includes material taken from the System Reference Document 5.1 \
+             (\"SRD 5.1\") by Wizards of the Coast LLC and available at \
+             https://dnd.wizards.com/resources/systems-reference-document. The SRD 5.1 is \
+             licensed under the Creative Commons Attribution 4.0 International License \
+             available at https://creativecommons.org/licenses/by/4.0/legalcode.",
+        );
+    }
+    if licenses.contains(&LICENSE_SRD_5_2) {
+        paragraphs.push(
+            "This work includes material from the System Reference Document 5.2 \
+             (\"SRD 5.2\") by Wizards of the Coast LLC, available at \
+             https://www.dndbeyond.com/srd. The SRD 5.2 is licensed under the Creative \
+             Commons Attribution 4.0 International License, available at \
+             https://creativecommons.org/licenses/by/4.0/legalcode.",
+        );
+    }
+
+    if paragraphs.is_empty() {
+        None
+    } else {
+        Some(format!("# License\n\n{}\n", paragraphs.join("\n\n")))
+    }
+}
+
+/// The license of a catalog entry.
+#[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = catalog_licenses)]
+pub struct CatalogLicense {
+    /// Catalog entity type (e.g., "monster", "spell")
+    pub entity_type: String,
+    pub name: String,
+    pub source: String,
+    /// License name (e.g., "SRD 5.1")
+    pub license: String,
+}
+
+/// Data for recording a catalog entry's license.
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = catalog_licenses)]
+pub struct NewCatalogLicense<'a> {
+    pub entity_type: &'a str,
+    pub name: &'a str,
+    pub source: &'a str,
+    pub license: &'a str,
+}
+
+impl<'a> NewCatalogLicense<'a> {
+    pub fn new(entity_type: &'a str, name: &'a str, source: &'a str, license: &'a str) -> Self {
+        Self {
+            entity_type,
+            name,
+            source,
+            license,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_license_for_entity() {
+        assert_eq!(
+            license_for_entity(&json!({"name": "Goblin", "srd": true})),
+            Some(LICENSE_SRD_5_1)
+        );
+        assert_eq!(
+            license_for_entity(&json!({"name": "Goblin Warrior", "srd52": "Goblin"})),
+            Some(LICENSE_SRD_5_2)
+        );
+        assert_eq!(license_for_entity(&json!({"name": "Beholder"})), None);
+        assert_eq!(
+            license_for_entity(&json!({"name": "Beholder", "srd": false})),
+            None
+        );
+    }
+
+    #[test]
+    fn test_license_notice() {
+        assert!(license_notice(&[]).is_none());
+
+        let notice = license_notice(&[LICENSE_SRD_5_1]).unwrap();
+        assert!(notice.contains("SRD 5.1"));
+        assert!(!notice.contains("SRD 5.2"));
+    }
+}
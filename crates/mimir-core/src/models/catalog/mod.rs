@@ -16,6 +16,7 @@ mod hazard;
 mod item;
 mod item_attunement;
 mod language;
+mod license;
 mod monster;
 mod object;
 mod optional_feature;
@@ -48,6 +49,7 @@ pub use hazard::*;
 pub use item::*;
 pub use item_attunement::*;
 pub use language::*;
+pub use license::*;
 pub use monster::*;
 pub use object::*;
 pub use optional_feature::*;
@@ -6,6 +6,31 @@ use crate::schema::catalog_sources;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// The 2014 ruleset (5e).
+pub const EDITION_2014: &str = "2014";
+/// The 2024 revision of the ruleset (5.5e).
+pub const EDITION_2024: &str = "2024";
+
+/// Source codes of the books written for the 2024 ruleset.
+///
+/// Sources not listed here are tagged 2014 on import; a DM can retag any
+/// source afterwards.
+pub const EDITION_2024_SOURCES: &[&str] = &["XPHB", "XDMG", "XMM"];
+
+/// The edition a source is tagged with when first imported.
+pub fn edition_for_source(code: &str) -> &'static str {
+    if EDITION_2024_SOURCES.contains(&code) {
+        EDITION_2024
+    } else {
+        EDITION_2014
+    }
+}
+
+/// Check that an edition is one the catalog knows about.
+pub fn is_valid_edition(edition: &str) -> bool {
+    edition == EDITION_2014 || edition == EDITION_2024
+}
+
 /// A catalog source (book) that has been imported.
 #[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
 #[diesel(table_name = catalog_sources)]
@@ -19,6 +44,8 @@ pub struct CatalogSource {
     pub enabled: i32,
     /// ISO 8601 timestamp of when this source was imported
     pub imported_at: String,
+    /// Ruleset edition the source was written for ("2014" or "2024")
+    pub edition: String,
 }
 
 impl CatalogSource {
@@ -36,11 +63,14 @@ pub struct NewCatalogSource<'a> {
     pub name: &'a str,
     pub enabled: i32,
     pub imported_at: &'a str,
+    pub edition: &'a str,
 }
 
 impl<'a> NewCatalogSource<'a> {
     /// Create a new catalog source entry.
     ///
+    /// The edition is taken from [`edition_for_source`].
+    ///
     /// # Arguments
     /// * `code` - Source code (e.g., "PHB")
     /// * `name` - Display name (e.g., "Player's Handbook")
@@ -52,22 +82,39 @@ impl<'a> NewCatalogSource<'a> {
             name,
             enabled: if enabled { 1 } else { 0 },
             imported_at,
+            edition: edition_for_source(code),
         }
     }
+
+    /// Override the edition.
+    pub fn with_edition(mut self, edition: &'a str) -> Self {
+        self.edition = edition;
+        self
+    }
 }
 
-/// Data for updating a catalog source's enabled status.
-#[derive(Debug, Clone, AsChangeset)]
+/// Data for updating a catalog source.
+#[derive(Debug, Clone, Default, AsChangeset)]
 #[diesel(table_name = catalog_sources)]
-pub struct UpdateCatalogSource {
+pub struct UpdateCatalogSource<'a> {
     pub enabled: Option<i32>,
+    pub edition: Option<&'a str>,
 }
 
-impl UpdateCatalogSource {
+impl<'a> UpdateCatalogSource<'a> {
     /// Create an update to change the enabled status.
     pub fn set_enabled(enabled: bool) -> Self {
         Self {
             enabled: Some(if enabled { 1 } else { 0 }),
+            ..Default::default()
+        }
+    }
+
+    /// Create an update to change the edition.
+    pub fn set_edition(edition: &'a str) -> Self {
+        Self {
+            edition: Some(edition),
+            ..Default::default()
         }
     }
 }
@@ -82,6 +129,22 @@ mod tests {
         assert_eq!(source.code, "PHB");
         assert_eq!(source.name, "Player's Handbook");
         assert_eq!(source.enabled, 1);
+        assert_eq!(source.edition, EDITION_2014);
+    }
+
+    #[test]
+    fn test_new_catalog_source_edition() {
+        let source = NewCatalogSource::new(
+            "XPHB",
+            "Player's Handbook (2024)",
+            true,
+            "2024-01-20T12:00:00Z",
+        );
+        assert_eq!(source.edition, EDITION_2024);
+
+        let source = NewCatalogSource::new("HB", "Homebrew", true, "2024-01-20T12:00:00Z")
+            .with_edition(EDITION_2024);
+        assert_eq!(source.edition, EDITION_2024);
     }
 
     #[test]
@@ -2,9 +2,20 @@
 //!
 //! Represents a trap.
 
+use crate::fts::strip_5etools_tags;
 use crate::schema::traps;
 use diesel::prelude::*;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// `{@dc 15}` tags, kept as "DC 15" when tags are stripped
+static DC_TAG_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{@dc (\d+)[^}]*\}").expect("valid DC tag regex"));
+
+/// A DC in plain text, e.g. "DC 15 Wisdom (Perception) check"
+static DC_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bDC (\d+)").expect("valid DC regex"));
 
 /// A trap from the catalog.
 #[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
@@ -33,6 +44,102 @@ impl Trap {
     pub fn is_complex(&self) -> bool {
         self.trap_tier.as_ref().map_or(false, |t| t == "complex")
     }
+
+    /// Plain-text trigger, effect, description, and DC for placing on a map.
+    pub fn placement_text(&self) -> Result<TrapPlacementText, serde_json::Error> {
+        Ok(TrapPlacementText::from_data(&self.parse_data()?))
+    }
+}
+
+/// Trap text copied onto a map trap when it is placed from the catalog.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TrapPlacementText {
+    /// Flavor text followed by countermeasures
+    pub description: Option<String>,
+    /// How the trap is triggered
+    pub trigger: Option<String>,
+    /// What the trap does, including a complex trap's elements
+    pub effect: Option<String>,
+    /// First DC in the trigger, effect, or countermeasures
+    pub dc: Option<i32>,
+}
+
+impl TrapPlacementText {
+    /// Extract placement text from 5etools trap data.
+    pub fn from_data(data: &Value) -> Self {
+        let section = |key: &str| {
+            data.get(key)
+                .map(entries_text)
+                .filter(|text| !text.is_empty())
+        };
+
+        let trigger = section("trigger");
+
+        let effects: Vec<String> = [
+            ("effect", None),
+            ("eActive", Some("Active Elements")),
+            ("eDynamic", Some("Dynamic Elements")),
+            ("eConstant", Some("Constant Elements")),
+        ]
+        .into_iter()
+        .filter_map(|(key, label)| {
+            section(key).map(|text| match label {
+                Some(label) => format!("{}. {}", label, text),
+                None => text,
+            })
+        })
+        .collect();
+        let effect = (!effects.is_empty()).then(|| effects.join("\n\n"));
+
+        let countermeasures = section("countermeasures");
+        let mut description: Vec<String> = section("entries").into_iter().collect();
+        if let Some(ref text) = countermeasures {
+            description.push(format!("Countermeasures. {}", text));
+        }
+        let description = (!description.is_empty()).then(|| description.join("\n\n"));
+
+        let dc = [&trigger, &effect, &countermeasures]
+            .into_iter()
+            .flatten()
+            .find_map(|text| {
+                DC_REGEX
+                    .captures(text)
+                    .and_then(|caps| caps[1].parse().ok())
+            });
+
+        Self {
+            description,
+            trigger,
+            effect,
+            dc,
+        }
+    }
+}
+
+/// Plain text for a 5etools entry or entry list, one paragraph per entry.
+fn entries_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => strip_5etools_tags(&DC_TAG_REGEX.replace_all(s, "DC $1")),
+        Value::Array(entries) => entries
+            .iter()
+            .map(entries_text)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        Value::Object(obj) => {
+            let body = obj
+                .get("entries")
+                .or_else(|| obj.get("items"))
+                .map(entries_text)
+                .unwrap_or_default();
+            match obj.get("name").and_then(|n| n.as_str()) {
+                Some(name) if !body.is_empty() => format!("{}. {}", name, body),
+                Some(name) => name.to_string(),
+                None => body,
+            }
+        }
+        _ => String::new(),
+    }
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -119,4 +226,48 @@ mod tests {
         assert_eq!(trap.name, "Pit Trap");
         assert_eq!(trap.trap_tier, Some("simple"));
     }
+
+    #[test]
+    fn test_placement_text_simple_trap() {
+        let data = serde_json::json!({
+            "name": "Poison Darts",
+            "trapHazType": "MECH",
+            "entries": ["Hidden tubes in the walls shoot darts."],
+            "trigger": ["A creature steps on a hidden pressure plate."],
+            "effect": ["Each creature makes a {@dc 15} Dexterity saving throw, taking {@damage 2d10} poison damage on a failure."],
+            "countermeasures": ["A DC 15 Wisdom ({@skill Perception}) check spots the plate."]
+        });
+        let text = TrapPlacementText::from_data(&data);
+
+        assert_eq!(
+            text.trigger.as_deref(),
+            Some("A creature steps on a hidden pressure plate.")
+        );
+        assert_eq!(
+            text.effect.as_deref(),
+            Some("Each creature makes a DC 15 Dexterity saving throw, taking 2d10 poison damage on a failure.")
+        );
+        assert_eq!(
+            text.description.as_deref(),
+            Some("Hidden tubes in the walls shoot darts.\n\nCountermeasures. A DC 15 Wisdom (Perception) check spots the plate.")
+        );
+        assert_eq!(text.dc, Some(15));
+    }
+
+    #[test]
+    fn test_placement_text_complex_trap() {
+        let data = serde_json::json!({
+            "name": "Sphere of Crushing Doom",
+            "trigger": ["Opening the door releases the sphere."],
+            "eActive": [{"type": "entries", "name": "Roll", "entries": ["The sphere rolls 60 feet."]}],
+            "eConstant": ["Creatures in the sphere's path make a DC 13 Strength saving throw."]
+        });
+        let text = TrapPlacementText::from_data(&data);
+
+        let effect = text.effect.unwrap();
+        assert!(effect.starts_with("Active Elements. Roll. The sphere rolls 60 feet."));
+        assert!(effect.contains("\n\nConstant Elements. Creatures"));
+        assert_eq!(text.description, None);
+        assert_eq!(text.dc, Some(13));
+    }
 }
@@ -33,6 +33,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    campaign_custom_fields (id) {
+        id -> Text,
+        campaign_id -> Text,
+        field_key -> Text,
+        label -> Text,
+        field_type -> Text,
+        default_value -> Nullable<Text>,
+        min_value -> Nullable<Integer>,
+        max_value -> Nullable<Integer>,
+        description -> Nullable<Text>,
+        sort_order -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     campaign_homebrew_items (id) {
         id -> Text,
@@ -79,6 +96,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    campaign_memories (id) {
+        id -> Text,
+        campaign_id -> Text,
+        name -> Text,
+        content -> Text,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    campaign_safety_tools (campaign_id) {
+        campaign_id -> Text,
+        lines -> Text,
+        veils -> Text,
+        x_card_enabled -> Bool,
+        x_card_signal -> Nullable<Text>,
+        open_door -> Bool,
+        check_ins -> Bool,
+        notes -> Nullable<Text>,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     campaign_sources (id) {
         id -> Text,
@@ -87,6 +129,51 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    campaign_stash_currency (campaign_id) {
+        campaign_id -> Text,
+        cp -> Integer,
+        sp -> Integer,
+        ep -> Integer,
+        gp -> Integer,
+        pp -> Integer,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    campaign_stash_items (id) {
+        id -> Text,
+        campaign_id -> Text,
+        item_name -> Text,
+        item_source -> Text,
+        quantity -> Integer,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    campaign_stash_ledger (id) {
+        id -> Text,
+        campaign_id -> Text,
+        action -> Text,
+        character_id -> Nullable<Text>,
+        character_name -> Nullable<Text>,
+        item_name -> Nullable<Text>,
+        item_source -> Nullable<Text>,
+        quantity -> Integer,
+        cp -> Integer,
+        sp -> Integer,
+        ep -> Integer,
+        gp -> Integer,
+        pp -> Integer,
+        note -> Nullable<Text>,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     campaigns (id) {
         id -> Text,
@@ -95,6 +182,17 @@ diesel::table! {
         archived_at -> Nullable<Text>,
         created_at -> Text,
         updated_at -> Text,
+        edition -> Nullable<Text>,
+        srd_only -> Integer,
+    }
+}
+
+diesel::table! {
+    catalog_licenses (entity_type, name, source) {
+        entity_type -> Text,
+        name -> Text,
+        source -> Text,
+        license -> Text,
     }
 }
 
@@ -104,6 +202,7 @@ diesel::table! {
         name -> Text,
         enabled -> Integer,
         imported_at -> Text,
+        edition -> Text,
     }
 }
 
@@ -127,6 +226,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    character_companions (id) {
+        id -> Text,
+        character_id -> Text,
+        name -> Text,
+        companion_type -> Text,
+        monster_name -> Nullable<Text>,
+        monster_source -> Nullable<Text>,
+        homebrew_monster_id -> Nullable<Text>,
+        sidekick_class -> Nullable<Text>,
+        level -> Nullable<Integer>,
+        max_hp -> Integer,
+        current_hp -> Integer,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     character_classes (id) {
         id -> Text,
@@ -140,6 +258,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    character_custom_fields (character_id, field_key) {
+        character_id -> Text,
+        field_key -> Text,
+        value -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    character_deaths (id) {
+        id -> Text,
+        character_id -> Text,
+        cause -> Text,
+        party_id -> Nullable<Text>,
+        day -> Nullable<Integer>,
+        notes -> Nullable<Text>,
+        died_at -> Text,
+        resurrection_method -> Nullable<Text>,
+        resurrection_cost_cp -> Nullable<Integer>,
+        resurrection_day -> Nullable<Integer>,
+        resurrected_at -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     character_feats (id) {
         id -> Text,
@@ -184,6 +327,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    character_resources (id) {
+        id -> Text,
+        character_id -> Text,
+        resource_name -> Text,
+        source_class -> Text,
+        max_uses -> Integer,
+        current_uses -> Integer,
+        recharge -> Text,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     character_sources (id) {
         id -> Text,
@@ -200,6 +357,7 @@ diesel::table! {
         spell_source -> Text,
         source_class -> Text,
         prepared -> Integer,
+        scribed -> Integer,
     }
 }
 
@@ -234,6 +392,15 @@ diesel::table! {
         faction -> Nullable<Text>,
         created_at -> Text,
         updated_at -> Text,
+        deleted_at -> Nullable<Text>,
+        subrace_name -> Nullable<Text>,
+        subrace_source -> Nullable<Text>,
+        speed -> Integer,
+        darkvision -> Nullable<Integer>,
+        resistances -> Text,
+        is_dead -> Bool,
+        portrait_path -> Nullable<Text>,
+        token_path -> Nullable<Text>,
     }
 }
 
@@ -283,6 +450,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    crafting_projects (id) {
+        id -> Text,
+        character_id -> Text,
+        item_name -> Text,
+        item_source -> Text,
+        quantity -> Integer,
+        rarity -> Nullable<Text>,
+        cost_cp -> Integer,
+        days_required -> Integer,
+        days_spent -> Integer,
+        tool -> Nullable<Text>,
+        ingredient -> Nullable<Text>,
+        ingredient_acquired -> Bool,
+        status -> Text,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+        completed_at -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     cults (id) {
         id -> Nullable<Integer>,
@@ -292,6 +481,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    currency_transactions (id) {
+        id -> Text,
+        character_id -> Text,
+        reason -> Text,
+        cp -> Integer,
+        sp -> Integer,
+        ep -> Integer,
+        gp -> Integer,
+        pp -> Integer,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     deities (id) {
         id -> Nullable<Integer>,
@@ -312,6 +515,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    dispositions (id) {
+        id -> Text,
+        campaign_id -> Text,
+        character_id -> Nullable<Text>,
+        faction -> Nullable<Text>,
+        disposition -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    dm_reference_pins (id) {
+        id -> Text,
+        campaign_id -> Text,
+        panel_type -> Text,
+        title -> Text,
+        ref_id -> Nullable<Text>,
+        ref_source -> Nullable<Text>,
+        section -> Nullable<Text>,
+        content -> Nullable<Text>,
+        sort_order -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     documents (id) {
         id -> Text,
@@ -323,6 +554,42 @@ diesel::table! {
         sort_order -> Integer,
         created_at -> Text,
         updated_at -> Text,
+        deleted_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    encounter_effects (id) {
+        id -> Text,
+        module_id -> Text,
+        name -> Text,
+        description -> Text,
+        timing -> Text,
+        timing_value -> Nullable<Integer>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    entity_tags (tag_id, entity_type, entity_id) {
+        tag_id -> Text,
+        entity_type -> Text,
+        entity_id -> Text,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    favorites (id) {
+        id -> Text,
+        kind -> Text,
+        ref_id -> Text,
+        ref_source -> Nullable<Text>,
+        campaign_id -> Nullable<Text>,
+        title -> Text,
+        sort_order -> Integer,
+        created_at -> Text,
     }
 }
 
@@ -336,6 +603,67 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    forage_ingredients (id) {
+        id -> Text,
+        campaign_id -> Text,
+        environment -> Text,
+        item_name -> Text,
+        item_source -> Text,
+        dc -> Integer,
+        quantity -> Text,
+        weight -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    game_sessions (id) {
+        id -> Text,
+        campaign_id -> Text,
+        module_id -> Nullable<Text>,
+        session_number -> Integer,
+        title -> Nullable<Text>,
+        started_at -> Text,
+        ended_at -> Nullable<Text>,
+        play_seconds -> Integer,
+        break_seconds -> Integer,
+        combat_turns -> Integer,
+        combat_turn_seconds -> Integer,
+        longest_turn_seconds -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+        party_id -> Nullable<Text>,
+        xp_awarded -> Integer,
+        transcript -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    handout_reveals (id) {
+        id -> Text,
+        handout_id -> Text,
+        session_id -> Nullable<Text>,
+        revealed_at -> Text,
+    }
+}
+
+diesel::table! {
+    handouts (id) {
+        id -> Text,
+        campaign_id -> Text,
+        title -> Text,
+        image_path -> Nullable<Text>,
+        asset_id -> Nullable<Text>,
+        document_id -> Nullable<Text>,
+        sort_order -> Integer,
+        revealed_at -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     hazards (id) {
         id -> Nullable<Integer>,
@@ -408,6 +736,8 @@ diesel::table! {
         visible -> Integer,
         created_at -> Text,
         updated_at -> Text,
+        catalog_name -> Nullable<Text>,
+        catalog_source -> Nullable<Text>,
     }
 }
 
@@ -424,6 +754,23 @@ diesel::table! {
         visible -> Integer,
         created_at -> Text,
         updated_at -> Text,
+        document_id -> Nullable<Text>,
+        document_section -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    map_regions (id) {
+        id -> Text,
+        map_id -> Text,
+        name -> Text,
+        x -> Double,
+        y -> Double,
+        width -> Double,
+        height -> Double,
+        points -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
     }
 }
 
@@ -440,6 +787,7 @@ diesel::table! {
         fog_enabled -> Integer,
         created_at -> Text,
         updated_at -> Text,
+        deleted_at -> Nullable<Text>,
     }
 }
 
@@ -455,6 +803,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    module_links (id) {
+        id -> Text,
+        campaign_id -> Text,
+        from_module_id -> Text,
+        to_module_id -> Text,
+        link_type -> Text,
+        note -> Nullable<Text>,
+        resolved -> Integer,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     module_monsters (id) {
         id -> Text,
@@ -488,6 +849,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    module_prep_checks (module_id, task_key) {
+        module_id -> Text,
+        task_key -> Text,
+        checked_at -> Text,
+    }
+}
+
 diesel::table! {
     modules (id) {
         id -> Text,
@@ -497,6 +866,7 @@ diesel::table! {
         module_number -> Integer,
         created_at -> Text,
         updated_at -> Text,
+        module_type -> Text,
     }
 }
 
@@ -514,6 +884,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    npc_interactions (id) {
+        id -> Text,
+        campaign_id -> Text,
+        character_id -> Text,
+        party_id -> Nullable<Text>,
+        session_id -> Nullable<Text>,
+        dice -> Text,
+        roll -> Integer,
+        modifier -> Integer,
+        total -> Integer,
+        attitude -> Text,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     objects (id) {
         id -> Nullable<Integer>,
@@ -535,6 +922,103 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    owned_vehicles (id) {
+        id -> Text,
+        campaign_id -> Text,
+        character_id -> Nullable<Text>,
+        party_id -> Nullable<Text>,
+        name -> Text,
+        vehicle_kind -> Text,
+        catalog_name -> Text,
+        catalog_source -> Text,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    parties (id) {
+        id -> Text,
+        campaign_id -> Text,
+        name -> Text,
+        description -> Nullable<Text>,
+        color -> Nullable<Text>,
+        current_day -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    party_members (party_id, character_id) {
+        party_id -> Text,
+        character_id -> Text,
+        joined_at -> Text,
+    }
+}
+
+diesel::table! {
+    party_reputations (id) {
+        id -> Text,
+        campaign_id -> Text,
+        party_id -> Text,
+        faction -> Text,
+        reputation -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    party_timeline_events (id) {
+        id -> Text,
+        party_id -> Text,
+        session_id -> Nullable<Text>,
+        day -> Integer,
+        title -> Text,
+        description -> Nullable<Text>,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    player_characters (character_id) {
+        character_id -> Text,
+        player_id -> Text,
+        linked_at -> Text,
+    }
+}
+
+diesel::table! {
+    player_claims (id) {
+        id -> Text,
+        campaign_id -> Text,
+        player_id -> Text,
+        code -> Text,
+        character_id -> Nullable<Text>,
+        expires_at -> Nullable<Text>,
+        redeemed_at -> Nullable<Text>,
+        redeemed_character_id -> Nullable<Text>,
+        created_at -> Text,
+    }
+}
+
+diesel::table! {
+    players (id) {
+        id -> Text,
+        campaign_id -> Text,
+        name -> Text,
+        email -> Nullable<Text>,
+        discord -> Nullable<Text>,
+        phone -> Nullable<Text>,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     psionics (id) {
         id -> Nullable<Integer>,
@@ -546,6 +1030,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    quick_actions (id) {
+        id -> Text,
+        campaign_id -> Text,
+        name -> Text,
+        description -> Nullable<Text>,
+        steps -> Text,
+        sort_order -> Integer,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    recent_items (id) {
+        id -> Text,
+        kind -> Text,
+        ref_id -> Text,
+        ref_source -> Nullable<Text>,
+        campaign_id -> Nullable<Text>,
+        title -> Text,
+        view_count -> Integer,
+        last_viewed_at -> Text,
+    }
+}
+
 diesel::table! {
     races (id) {
         id -> Nullable<Integer>,
@@ -566,6 +1076,44 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rumor_hearings (rumor_id, player_id) {
+        rumor_id -> Text,
+        player_id -> Text,
+        session_id -> Nullable<Text>,
+        heard_at -> Text,
+    }
+}
+
+diesel::table! {
+    rumors (id) {
+        id -> Text,
+        campaign_id -> Text,
+        text -> Text,
+        is_true -> Integer,
+        source_npc_id -> Nullable<Text>,
+        module_id -> Nullable<Text>,
+        notes -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
+diesel::table! {
+    secret_notes (id) {
+        id -> Text,
+        campaign_id -> Text,
+        player_id -> Text,
+        module_id -> Nullable<Text>,
+        title -> Text,
+        content -> Text,
+        delivered_at -> Nullable<Text>,
+        session_id -> Nullable<Text>,
+        created_at -> Text,
+        updated_at -> Text,
+    }
+}
+
 diesel::table! {
     senses (id) {
         id -> Nullable<Integer>,
@@ -629,6 +1177,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tags (id) {
+        id -> Text,
+        campaign_id -> Text,
+        name -> Text,
+        color -> Nullable<Text>,
+        created_at -> Text,
+    }
+}
+
 diesel::table! {
     token_placements (id) {
         id -> Text,
@@ -645,6 +1203,8 @@ diesel::table! {
         vision_dark_ft -> Integer,
         light_radius_ft -> Integer,
         created_at -> Text,
+        character_id -> Nullable<Text>,
+        mob_size -> Nullable<Integer>,
     }
 }
 
@@ -683,32 +1243,63 @@ diesel::table! {
 diesel::joinable!(actions -> catalog_sources (source));
 diesel::joinable!(backgrounds -> catalog_sources (source));
 diesel::joinable!(campaign_assets -> campaigns (campaign_id));
+diesel::joinable!(campaign_custom_fields -> campaigns (campaign_id));
 diesel::joinable!(campaign_homebrew_items -> campaigns (campaign_id));
 diesel::joinable!(campaign_homebrew_monsters -> campaigns (campaign_id));
 diesel::joinable!(campaign_homebrew_spells -> campaigns (campaign_id));
 diesel::joinable!(campaign_assets -> modules (module_id));
+diesel::joinable!(campaign_memories -> campaigns (campaign_id));
+diesel::joinable!(campaign_safety_tools -> campaigns (campaign_id));
 diesel::joinable!(campaign_sources -> campaigns (campaign_id));
 diesel::joinable!(campaign_sources -> catalog_sources (source_code));
+diesel::joinable!(campaign_stash_currency -> campaigns (campaign_id));
+diesel::joinable!(campaign_stash_items -> campaigns (campaign_id));
+diesel::joinable!(campaign_stash_ledger -> campaigns (campaign_id));
+diesel::joinable!(campaign_stash_ledger -> characters (character_id));
+diesel::joinable!(catalog_licenses -> catalog_sources (source));
 diesel::joinable!(catalog_tables -> catalog_sources (source));
 diesel::joinable!(books -> catalog_sources (source));
 diesel::joinable!(character_classes -> characters (character_id));
+diesel::joinable!(character_companions -> campaign_homebrew_monsters (homebrew_monster_id));
+diesel::joinable!(character_companions -> characters (character_id));
+diesel::joinable!(character_custom_fields -> characters (character_id));
+diesel::joinable!(character_deaths -> characters (character_id));
+diesel::joinable!(character_deaths -> parties (party_id));
 diesel::joinable!(character_feats -> characters (character_id));
 diesel::joinable!(character_features -> characters (character_id));
 diesel::joinable!(character_inventory -> characters (character_id));
 diesel::joinable!(character_proficiencies -> characters (character_id));
+diesel::joinable!(character_resources -> characters (character_id));
 diesel::joinable!(character_sources -> characters (character_id));
 diesel::joinable!(character_sources -> catalog_sources (source_code));
 diesel::joinable!(character_spells -> characters (character_id));
 diesel::joinable!(characters -> campaigns (campaign_id));
 diesel::joinable!(classes -> catalog_sources (source));
 diesel::joinable!(conditions -> catalog_sources (source));
+diesel::joinable!(crafting_projects -> characters (character_id));
 diesel::joinable!(cults -> catalog_sources (source));
+diesel::joinable!(currency_transactions -> characters (character_id));
 diesel::joinable!(deities -> catalog_sources (source));
 diesel::joinable!(diseases -> catalog_sources (source));
+diesel::joinable!(dispositions -> campaigns (campaign_id));
+diesel::joinable!(dispositions -> characters (character_id));
+diesel::joinable!(dm_reference_pins -> campaigns (campaign_id));
 diesel::joinable!(documents -> campaigns (campaign_id));
 diesel::joinable!(documents -> modules (module_id));
+diesel::joinable!(encounter_effects -> modules (module_id));
+diesel::joinable!(entity_tags -> tags (tag_id));
+diesel::joinable!(favorites -> campaigns (campaign_id));
 diesel::joinable!(feats -> catalog_sources (source));
 diesel::joinable!(fog_revealed_areas -> maps (map_id));
+diesel::joinable!(forage_ingredients -> campaigns (campaign_id));
+diesel::joinable!(game_sessions -> campaigns (campaign_id));
+diesel::joinable!(game_sessions -> modules (module_id));
+diesel::joinable!(game_sessions -> parties (party_id));
+diesel::joinable!(handout_reveals -> game_sessions (session_id));
+diesel::joinable!(handout_reveals -> handouts (handout_id));
+diesel::joinable!(handouts -> campaign_assets (asset_id));
+diesel::joinable!(handouts -> campaigns (campaign_id));
+diesel::joinable!(handouts -> documents (document_id));
 diesel::joinable!(hazards -> catalog_sources (source));
 diesel::joinable!(item_attunement_classes -> items (item_id));
 diesel::joinable!(items -> catalog_sources (source));
@@ -716,20 +1307,54 @@ diesel::joinable!(languages -> catalog_sources (source));
 diesel::joinable!(light_sources -> maps (map_id));
 diesel::joinable!(map_traps -> maps (map_id));
 diesel::joinable!(map_pois -> maps (map_id));
+diesel::joinable!(map_regions -> maps (map_id));
 diesel::joinable!(maps -> campaign_assets (uvtt_asset_id));
 diesel::joinable!(maps -> campaigns (campaign_id));
 diesel::joinable!(maps -> modules (module_id));
+diesel::joinable!(module_links -> campaigns (campaign_id));
 diesel::joinable!(module_monsters -> campaign_homebrew_monsters (homebrew_monster_id));
 diesel::joinable!(module_monsters -> modules (module_id));
 diesel::joinable!(module_npcs -> campaign_assets (token_asset_id));
 diesel::joinable!(module_npcs -> modules (module_id));
+diesel::joinable!(module_prep_checks -> modules (module_id));
 diesel::joinable!(modules -> campaigns (campaign_id));
 diesel::joinable!(monsters -> catalog_sources (source));
+diesel::joinable!(npc_interactions -> campaigns (campaign_id));
+diesel::joinable!(npc_interactions -> characters (character_id));
+diesel::joinable!(npc_interactions -> game_sessions (session_id));
+diesel::joinable!(npc_interactions -> parties (party_id));
 diesel::joinable!(objects -> catalog_sources (source));
 diesel::joinable!(optional_features -> catalog_sources (source));
+diesel::joinable!(owned_vehicles -> campaigns (campaign_id));
+diesel::joinable!(owned_vehicles -> characters (character_id));
+diesel::joinable!(owned_vehicles -> parties (party_id));
+diesel::joinable!(parties -> campaigns (campaign_id));
+diesel::joinable!(party_members -> characters (character_id));
+diesel::joinable!(party_members -> parties (party_id));
+diesel::joinable!(party_reputations -> campaigns (campaign_id));
+diesel::joinable!(party_reputations -> parties (party_id));
+diesel::joinable!(party_timeline_events -> game_sessions (session_id));
+diesel::joinable!(party_timeline_events -> parties (party_id));
+diesel::joinable!(player_characters -> characters (character_id));
+diesel::joinable!(player_characters -> players (player_id));
+diesel::joinable!(player_claims -> campaigns (campaign_id));
+diesel::joinable!(player_claims -> players (player_id));
+diesel::joinable!(players -> campaigns (campaign_id));
 diesel::joinable!(psionics -> catalog_sources (source));
+diesel::joinable!(quick_actions -> campaigns (campaign_id));
+diesel::joinable!(recent_items -> campaigns (campaign_id));
 diesel::joinable!(races -> catalog_sources (source));
 diesel::joinable!(rewards -> catalog_sources (source));
+diesel::joinable!(rumor_hearings -> game_sessions (session_id));
+diesel::joinable!(rumor_hearings -> players (player_id));
+diesel::joinable!(rumor_hearings -> rumors (rumor_id));
+diesel::joinable!(rumors -> campaigns (campaign_id));
+diesel::joinable!(rumors -> characters (source_npc_id));
+diesel::joinable!(rumors -> modules (module_id));
+diesel::joinable!(secret_notes -> campaigns (campaign_id));
+diesel::joinable!(secret_notes -> game_sessions (session_id));
+diesel::joinable!(secret_notes -> modules (module_id));
+diesel::joinable!(secret_notes -> players (player_id));
 diesel::joinable!(senses -> catalog_sources (source));
 diesel::joinable!(skills -> catalog_sources (source));
 diesel::joinable!(spell_classes -> catalog_sources (source));
@@ -738,6 +1363,8 @@ diesel::joinable!(spell_subclasses -> catalog_sources (source));
 diesel::joinable!(spell_subclasses -> spells (spell_id));
 diesel::joinable!(spells -> catalog_sources (source));
 diesel::joinable!(subclasses -> catalog_sources (source));
+diesel::joinable!(tags -> campaigns (campaign_id));
+diesel::joinable!(token_placements -> characters (character_id));
 diesel::joinable!(token_placements -> maps (map_id));
 diesel::joinable!(token_placements -> module_monsters (module_monster_id));
 diesel::joinable!(token_placements -> module_npcs (module_npc_id));
@@ -750,29 +1377,51 @@ diesel::allow_tables_to_appear_in_same_query!(
     backgrounds,
     books,
     campaign_assets,
+    campaign_custom_fields,
     campaign_homebrew_items,
     campaign_homebrew_monsters,
     campaign_homebrew_spells,
+    campaign_memories,
+    campaign_safety_tools,
     campaign_sources,
+    campaign_stash_currency,
+    campaign_stash_items,
+    campaign_stash_ledger,
     campaigns,
+    catalog_licenses,
     catalog_sources,
     catalog_tables,
     character_classes,
+    character_companions,
+    character_custom_fields,
+    character_deaths,
     character_feats,
     character_features,
     character_inventory,
     character_proficiencies,
+    character_resources,
     character_sources,
     character_spells,
     characters,
     classes,
     conditions,
+    crafting_projects,
     cults,
+    currency_transactions,
     deities,
     diseases,
+    dispositions,
+    dm_reference_pins,
     documents,
+    encounter_effects,
+    entity_tags,
+    favorites,
     feats,
     fog_revealed_areas,
+    forage_ingredients,
+    game_sessions,
+    handout_reveals,
+    handouts,
     hazards,
     item_attunement_classes,
     items,
@@ -780,22 +1429,40 @@ diesel::allow_tables_to_appear_in_same_query!(
     light_sources,
     map_traps,
     map_pois,
+    map_regions,
     maps,
+    module_links,
     module_monsters,
     module_npcs,
+    module_prep_checks,
     modules,
     monsters,
+    npc_interactions,
     objects,
     optional_features,
+    owned_vehicles,
+    parties,
+    party_members,
+    party_reputations,
+    party_timeline_events,
+    player_characters,
+    player_claims,
+    players,
     psionics,
+    quick_actions,
+    recent_items,
     races,
     rewards,
+    rumor_hearings,
+    rumors,
+    secret_notes,
     senses,
     skills,
     spell_classes,
     spell_subclasses,
     spells,
     subclasses,
+    tags,
     token_placements,
     traps,
     variant_rules,
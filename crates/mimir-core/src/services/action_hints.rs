@@ -0,0 +1,565 @@
+//! Action Hints
+//!
+//! Reads what a monster action or spell does out of its 5etools data: the
+//! attack bonus, damage dice and types, the saving throw and its DC, and the
+//! conditions it inflicts. The combat view turns these into one-click rolls,
+//! so the DM doesn't have to re-read stat blocks every turn.
+//!
+//! Monster text is tagged (`{@hit 5}`, `{@damage 2d8 + 3}`, `{@dc 13}`,
+//! `{@condition poisoned}`); spells also list `savingThrow`, `spellAttack`,
+//! `damageInflict` and `conditionInflict`. Spell DCs and attack bonuses
+//! belong to the caster, so they are passed in.
+
+use once_cell::sync::Lazy;
+use rand::Rng;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::fts::{flatten_entries, strip_5etools_tags};
+
+/// Monster data keys holding action lists, with the kind of each.
+const ACTION_LISTS: &[(&str, HintKind)] = &[
+    ("trait", HintKind::Trait),
+    ("action", HintKind::Action),
+    ("bonus", HintKind::Bonus),
+    ("reaction", HintKind::Reaction),
+    ("legendary", HintKind::Legendary),
+];
+
+/// `{@hit 5}` or `{@hit +5}`
+static HIT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{@hit ([+-]?\d+)\}").expect("Failed to compile hit regex"));
+
+/// `{@damage 2d8 + 3}` and the damage type written after it
+static DAMAGE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{@damage ([^}|]+)[^}]*\}\)?(?:\s+([a-z]+)\s+damage)?")
+        .expect("Failed to compile damage regex")
+});
+
+/// `{@dc 13} Constitution saving throw` or `DC 13 Constitution saving throw`
+static SAVE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?:\{@dc (\d+)\}|DC (\d+))\s+(Strength|Dexterity|Constitution|Intelligence|Wisdom|Charisma)\s+saving throw",
+    )
+    .expect("Failed to compile save regex")
+});
+
+/// 2024 saves: `{@actSave con} {@dc 13}`
+static ACT_SAVE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{@actSave (\w+)\}\s*\{@dc (\d+)\}").expect("Failed to compile actSave regex")
+});
+
+/// `{@condition poisoned}` or `{@condition poisoned|XPHB}`
+static CONDITION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{@condition ([^}|]+)[^}]*\}").expect("Failed to compile condition regex")
+});
+
+/// `{@recharge}` (6) or `{@recharge 5}` (5-6)
+static RECHARGE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\s*\{@recharge(?: (\d))?\}").expect("Failed to compile recharge regex")
+});
+
+/// Where an action comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HintKind {
+    Trait,
+    Action,
+    Bonus,
+    Reaction,
+    Legendary,
+    Spell,
+}
+
+/// Damage an action deals.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DamageHint {
+    /// Dice expression (e.g., "2d8 + 3")
+    pub dice: String,
+    /// Damage type (e.g., "piercing"), when the text gives one
+    pub damage_type: Option<String>,
+}
+
+/// A saving throw an action calls for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveHint {
+    /// Ability, e.g. "Constitution"
+    pub ability: String,
+    /// Save DC; None for a spell whose caster DC wasn't given
+    pub dc: Option<i32>,
+}
+
+/// What one monster action or spell does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionHint {
+    /// Action name, without tags
+    pub name: String,
+    pub kind: HintKind,
+    /// Attack roll bonus, for attacks
+    pub attack_bonus: Option<i32>,
+    /// Damage, in the order written
+    pub damage: Vec<DamageHint>,
+    pub save: Option<SaveHint>,
+    /// Conditions inflicted, lowercase
+    pub conditions: Vec<String>,
+    /// Recharge range (e.g., "5-6"), for recharge actions
+    pub recharge: Option<String>,
+}
+
+impl ActionHint {
+    /// Whether there's anything to roll or apply.
+    pub fn is_actionable(&self) -> bool {
+        self.attack_bonus.is_some()
+            || !self.damage.is_empty()
+            || self.save.is_some()
+            || !self.conditions.is_empty()
+    }
+}
+
+/// A caster's spell save DC and spell attack bonus.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpellCaster {
+    pub save_dc: Option<i32>,
+    pub attack_bonus: Option<i32>,
+}
+
+/// A rolled attack.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttackRoll {
+    /// The d20
+    pub d20: i32,
+    pub bonus: i32,
+    pub total: i32,
+    /// Natural 20
+    pub critical: bool,
+    /// Natural 1
+    pub fumble: bool,
+}
+
+/// Rolled damage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DamageRoll {
+    /// Expression rolled (dice doubled on a critical hit)
+    pub dice: String,
+    pub damage_type: Option<String>,
+    /// Each die rolled
+    pub rolls: Vec<i32>,
+    pub total: i32,
+}
+
+/// The result of rolling an action hint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HintRoll {
+    pub name: String,
+    pub attack: Option<AttackRoll>,
+    pub damage: Vec<DamageRoll>,
+    /// Save the targets make
+    pub save: Option<SaveHint>,
+    /// Conditions to apply on a hit or failed save
+    pub conditions: Vec<String>,
+    /// One-line summary for the combat log
+    pub summary: String,
+}
+
+/// Read the actionable traits and actions out of 5etools monster data.
+pub fn monster_action_hints(monster: &Value) -> Vec<ActionHint> {
+    let mut hints = Vec::new();
+    for (key, kind) in ACTION_LISTS {
+        let Some(actions) = monster.get(*key).and_then(|v| v.as_array()) else {
+            continue;
+        };
+        for action in actions {
+            let Some(name) = action.get("name").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let entries = action
+                .get("entries")
+                .and_then(|v| v.as_array())
+                .map(|e| flatten_entries(e))
+                .unwrap_or_default();
+            let hint = hint_from_text(name, *kind, &entries);
+            if hint.is_actionable() {
+                hints.push(hint);
+            }
+        }
+    }
+    hints
+}
+
+/// Read what a spell does out of its 5etools data, using the caster's save
+/// DC and attack bonus.
+pub fn spell_action_hint(spell: &Value, caster: SpellCaster) -> ActionHint {
+    let name = spell.get("name").and_then(|v| v.as_str()).unwrap_or("");
+    let entries = spell
+        .get("entries")
+        .and_then(|v| v.as_array())
+        .map(|e| flatten_entries(e))
+        .unwrap_or_default();
+    let mut hint = hint_from_text(name, HintKind::Spell, &entries);
+    let strings = |key: &str| -> Vec<String> {
+        spell
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|a| {
+                a.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(str::to_lowercase)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    if !strings("spellAttack").is_empty() {
+        hint.attack_bonus = caster.attack_bonus;
+    }
+    if let Some(ability) = strings("savingThrow").first() {
+        hint.save = Some(SaveHint {
+            ability: capitalize(ability),
+            dc: caster.save_dc,
+        });
+    }
+    // Not every spell names the type right after the dice; fill it in when
+    // the spell only deals one type.
+    if let [damage_type] = strings("damageInflict").as_slice() {
+        for damage in hint.damage.iter_mut().filter(|d| d.damage_type.is_none()) {
+            damage.damage_type = Some(damage_type.clone());
+        }
+    }
+    for condition in strings("conditionInflict") {
+        if !hint.conditions.contains(&condition) {
+            hint.conditions.push(condition);
+        }
+    }
+    hint
+}
+
+/// Roll an action hint: the attack (if any) and its damage, doubling damage
+/// dice on a natural 20.
+pub fn roll_action_hint(hint: &ActionHint) -> HintRoll {
+    let mut rng = rand::thread_rng();
+    let attack = hint.attack_bonus.map(|bonus| {
+        let d20 = rng.gen_range(1..=20);
+        AttackRoll {
+            d20,
+            bonus,
+            total: d20 + bonus,
+            critical: d20 == 20,
+            fumble: d20 == 1,
+        }
+    });
+    let critical = attack.as_ref().is_some_and(|a| a.critical);
+    let damage: Vec<DamageRoll> = hint
+        .damage
+        .iter()
+        .filter_map(|d| {
+            roll_dice(&d.dice, critical, &mut rng).map(|(dice, rolls, total)| DamageRoll {
+                dice,
+                damage_type: d.damage_type.clone(),
+                rolls,
+                total,
+            })
+        })
+        .collect();
+
+    let summary = summarize(&hint.name, attack.as_ref(), &damage, hint.save.as_ref());
+    HintRoll {
+        name: hint.name.clone(),
+        attack,
+        damage,
+        save: hint.save.clone(),
+        conditions: hint.conditions.clone(),
+        summary,
+    }
+}
+
+/// Read a hint from an action's name and flattened (still tagged) text.
+fn hint_from_text(name: &str, kind: HintKind, text: &str) -> ActionHint {
+    let recharge = RECHARGE_REGEX.captures(name).map(|c| match c.get(1) {
+        Some(low) if low.as_str() != "6" => format!("{}-6", low.as_str()),
+        _ => "6".to_string(),
+    });
+    let name = strip_5etools_tags(&RECHARGE_REGEX.replace_all(name, ""))
+        .trim()
+        .to_string();
+
+    let attack_bonus = HIT_REGEX
+        .captures(text)
+        .and_then(|c| c[1].trim_start_matches('+').parse().ok());
+    let damage = DAMAGE_REGEX
+        .captures_iter(text)
+        .map(|c| DamageHint {
+            dice: c[1].trim().to_string(),
+            damage_type: c.get(2).map(|m| m.as_str().to_string()),
+        })
+        .collect();
+    let save = SAVE_REGEX
+        .captures(text)
+        .map(|c| SaveHint {
+            ability: c[3].to_string(),
+            dc: c.get(1).or(c.get(2)).and_then(|m| m.as_str().parse().ok()),
+        })
+        .or_else(|| {
+            ACT_SAVE_REGEX.captures(text).map(|c| SaveHint {
+                ability: ability_name(&c[1]),
+                dc: c[2].parse().ok(),
+            })
+        });
+    let mut conditions: Vec<String> = Vec::new();
+    for c in CONDITION_REGEX.captures_iter(text) {
+        let condition = c[1].trim().to_lowercase();
+        if !conditions.contains(&condition) {
+            conditions.push(condition);
+        }
+    }
+
+    ActionHint {
+        name,
+        kind,
+        attack_bonus,
+        damage,
+        save,
+        conditions,
+        recharge,
+    }
+}
+
+/// Roll a dice expression such as "2d8 + 3" or "1d6 + 1d4", returning the
+/// expression rolled, each die, and the total. A critical hit doubles the
+/// dice. Not a dice expression is None.
+fn roll_dice(
+    expression: &str,
+    critical: bool,
+    rng: &mut impl Rng,
+) -> Option<(String, Vec<i32>, i32)> {
+    let compact: String = expression
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    if compact.is_empty() {
+        return None;
+    }
+
+    let mut terms = Vec::new();
+    let mut rolls = Vec::new();
+    let mut total = 0;
+    let mut rest = compact.as_str();
+    while !rest.is_empty() {
+        let sign = if rest.starts_with('-') { -1 } else { 1 };
+        rest = rest.trim_start_matches(['+', '-']);
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let (term, tail) = rest.split_at(end);
+        rest = tail;
+
+        match term.split_once('d') {
+            Some((count, sides)) => {
+                let count: i32 = if count.is_empty() {
+                    1
+                } else {
+                    count.parse().ok()?
+                };
+                let sides: i32 = sides.parse().ok()?;
+                if count < 1 || sides < 1 {
+                    return None;
+                }
+                let count = if critical { count * 2 } else { count };
+                for _ in 0..count {
+                    let roll = rng.gen_range(1..=sides);
+                    rolls.push(roll);
+                    total += sign * roll;
+                }
+                terms.push((sign, format!("{}d{}", count, sides)));
+            }
+            None => {
+                let value: i32 = term.parse().ok()?;
+                total += sign * value;
+                terms.push((sign, value.to_string()));
+            }
+        }
+    }
+
+    let mut rolled = String::new();
+    for (i, (sign, term)) in terms.iter().enumerate() {
+        match (i, sign) {
+            (0, -1) => rolled.push('-'),
+            (0, _) => {}
+            (_, -1) => rolled.push_str(" - "),
+            _ => rolled.push_str(" + "),
+        }
+        rolled.push_str(term);
+    }
+    Some((rolled, rolls, total.max(0)))
+}
+
+fn summarize(
+    name: &str,
+    attack: Option<&AttackRoll>,
+    damage: &[DamageRoll],
+    save: Option<&SaveHint>,
+) -> String {
+    let mut parts = Vec::new();
+    if let Some(attack) = attack {
+        let note = if attack.critical {
+            " (critical!)"
+        } else if attack.fumble {
+            " (natural 1)"
+        } else {
+            ""
+        };
+        parts.push(format!("{} to hit{}", attack.total, note));
+    }
+    if let Some(save) = save {
+        match save.dc {
+            Some(dc) => parts.push(format!("DC {} {} save", dc, save.ability)),
+            None => parts.push(format!("{} save", save.ability)),
+        }
+    }
+    if !damage.is_empty() {
+        let dealt: Vec<String> = damage
+            .iter()
+            .map(|d| match d.damage_type {
+                Some(ref t) => format!("{} {}", d.total, t),
+                None => d.total.to_string(),
+            })
+            .collect();
+        parts.push(format!("{} damage", dealt.join(" + ")));
+    }
+    if parts.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}: {}", name, parts.join(", "))
+    }
+}
+
+/// Full ability name for an abbreviation like "con".
+fn ability_name(abbreviation: &str) -> String {
+    match abbreviation.to_lowercase().as_str() {
+        "str" => "Strength",
+        "dex" => "Dexterity",
+        "con" => "Constitution",
+        "int" => "Intelligence",
+        "wis" => "Wisdom",
+        "cha" => "Charisma",
+        other => return capitalize(other),
+    }
+    .to_string()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn owlbear_wyvern() -> Value {
+        json!({
+            "name": "Test Beast",
+            "trait": [
+                {"name": "Keen Sight", "entries": ["The beast has advantage on sight checks."]}
+            ],
+            "action": [
+                {
+                    "name": "Stinger",
+                    "entries": ["{@atk mw} {@hit 7} to hit, reach 10 ft., one creature. {@h}11 ({@damage 2d6 + 4}) piercing damage. The target must make a {@dc 15} Constitution saving throw, taking 24 ({@damage 7d6}) poison damage on a failed save, or half as much on a successful one."]
+                },
+                {
+                    "name": "Web {@recharge 5}",
+                    "entries": ["{@actSave dex} {@dc 12}, each creature in a 20-foot cube. {@actSaveFail} The target has the {@condition restrained|XPHB} condition."]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_monster_action_hints() {
+        let hints = monster_action_hints(&owlbear_wyvern());
+        assert_eq!(hints.len(), 2);
+
+        let stinger = &hints[0];
+        assert_eq!(stinger.kind, HintKind::Action);
+        assert_eq!(stinger.attack_bonus, Some(7));
+        assert_eq!(stinger.damage.len(), 2);
+        assert_eq!(stinger.damage[0].dice, "2d6 + 4");
+        assert_eq!(stinger.damage[1].damage_type.as_deref(), Some("poison"));
+        assert_eq!(
+            stinger.save,
+            Some(SaveHint {
+                ability: "Constitution".to_string(),
+                dc: Some(15),
+            })
+        );
+
+        let web = &hints[1];
+        assert_eq!(web.name, "Web");
+        assert_eq!(web.recharge.as_deref(), Some("5-6"));
+        assert_eq!(web.save.as_ref().unwrap().ability, "Dexterity");
+        assert_eq!(web.save.as_ref().unwrap().dc, Some(12));
+        assert_eq!(web.conditions, vec!["restrained"]);
+    }
+
+    #[test]
+    fn test_spell_action_hint_uses_caster() {
+        let fireball = json!({
+            "name": "Fireball",
+            "entries": ["Each creature in a 20-foot-radius sphere must make a Dexterity saving throw. A target takes {@damage 8d6} fire damage on a failed save."],
+            "savingThrow": ["dexterity"],
+            "damageInflict": ["fire"]
+        });
+        let caster = SpellCaster {
+            save_dc: Some(15),
+            attack_bonus: Some(7),
+        };
+        let hint = spell_action_hint(&fireball, caster);
+        assert_eq!(hint.attack_bonus, None);
+        assert_eq!(hint.save.as_ref().unwrap().dc, Some(15));
+        assert_eq!(hint.damage[0].dice, "8d6");
+        assert_eq!(hint.damage[0].damage_type.as_deref(), Some("fire"));
+
+        let ray = json!({
+            "name": "Ray of Sickness",
+            "entries": ["Make a ranged spell attack. On a hit, the target takes {@damage 2d8} damage."],
+            "spellAttack": ["R"],
+            "damageInflict": ["poison"],
+            "conditionInflict": ["poisoned"]
+        });
+        let hint = spell_action_hint(&ray, caster);
+        assert_eq!(hint.attack_bonus, Some(7));
+        assert_eq!(hint.damage[0].damage_type.as_deref(), Some("poison"));
+        assert_eq!(hint.conditions, vec!["poisoned"]);
+    }
+
+    #[test]
+    fn test_roll_action_hint() {
+        let hints = monster_action_hints(&owlbear_wyvern());
+        let roll = roll_action_hint(&hints[0]);
+        let attack = roll.attack.as_ref().unwrap();
+        assert_eq!(attack.total, attack.d20 + 7);
+        let dice = if attack.critical { 4 } else { 2 };
+        assert_eq!(roll.damage[0].rolls.len(), dice);
+        assert!(roll.summary.starts_with("Stinger: "));
+        assert!(roll.summary.contains("DC 15 Constitution save"));
+    }
+
+    #[test]
+    fn test_roll_dice() {
+        let mut rng = rand::thread_rng();
+        let (dice, rolls, total) = roll_dice("2d6 + 1d4 - 1", false, &mut rng).unwrap();
+        assert_eq!(dice, "2d6 + 1d4 - 1");
+        assert_eq!(rolls.len(), 3);
+        assert_eq!(total, (rolls.iter().sum::<i32>() - 1).max(0));
+
+        let (dice, rolls, _) = roll_dice("1d8+3", true, &mut rng).unwrap();
+        assert_eq!(dice, "2d8 + 3");
+        assert_eq!(rolls.len(), 2);
+
+        assert!(roll_dice("a handful", false, &mut rng).is_none());
+    }
+}
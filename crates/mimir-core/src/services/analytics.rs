@@ -0,0 +1,334 @@
+//! Campaign Analytics Service
+//!
+//! Local statistics about how a campaign has been run: sessions played,
+//! prep documents written per week, the monsters and spells that come up
+//! most, and combat pacing from the table timers. Everything is computed
+//! from the campaign database on demand; nothing is recorded or sent
+//! anywhere else.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate};
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::dal::campaign as dal;
+use crate::services::{ServiceError, ServiceResult};
+
+/// Number of entries kept in the most-used monster and spell lists.
+pub const TOP_USAGE_COUNT: usize = 10;
+
+/// Statistics for a campaign.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CampaignAnalytics {
+    pub campaign_id: String,
+    pub campaign_name: String,
+    pub sessions: SessionStats,
+    pub combat: CombatStats,
+    /// Prep documents created per week, oldest first, with empty weeks included
+    pub prep_per_week: Vec<WeeklyCount>,
+    /// Monsters added to the campaign's modules, most used first
+    pub top_monsters: Vec<UsageCount>,
+    /// Spells known by the campaign's characters, most common first
+    pub top_spells: Vec<UsageCount>,
+}
+
+/// Totals over completed sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionStats {
+    /// Sessions that have ended
+    pub sessions_run: usize,
+    /// Time on the session clock across all sessions, in seconds
+    pub total_play_seconds: i64,
+    /// Average time on the session clock per session, in seconds
+    pub average_play_seconds: i64,
+    /// XP awarded to each party member across all sessions
+    pub total_xp_awarded: i64,
+    /// When the first session started
+    pub first_session_at: Option<String>,
+    /// When the most recent session started
+    pub last_session_at: Option<String>,
+}
+
+/// Combat pacing from the turn timer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CombatStats {
+    /// Sessions with at least one timed combat turn
+    pub sessions_with_combat: usize,
+    /// Timed combat turns across all sessions
+    pub combat_turns: i64,
+    /// Average time spent in combat per session that had combat, in seconds
+    pub average_combat_seconds: i64,
+    /// Average length of a combat turn, in seconds
+    pub average_turn_seconds: i64,
+    /// Longest single combat turn, in seconds
+    pub longest_turn_seconds: i64,
+}
+
+/// A count for the week starting on a Monday.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeeklyCount {
+    /// Monday the week starts on (YYYY-MM-DD)
+    pub week_start: String,
+    pub count: usize,
+}
+
+/// How often a monster or spell is used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageCount {
+    pub name: String,
+    /// Catalog source, or None for homebrew
+    pub source: Option<String>,
+    pub count: i64,
+}
+
+/// Service for campaign statistics.
+pub struct AnalyticsService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> AnalyticsService<'a> {
+    /// Create a new analytics service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Compute statistics for a campaign.
+    pub fn campaign_analytics(&mut self, campaign_id: &str) -> ServiceResult<CampaignAnalytics> {
+        let campaign = dal::get_campaign_optional(self.conn, campaign_id)?
+            .ok_or_else(|| ServiceError::not_found("Campaign", campaign_id))?;
+
+        let (sessions, combat) = self.session_stats(campaign_id)?;
+        let documents = dal::list_campaign_documents(self.conn, campaign_id)?;
+        let prep_per_week = weekly_counts(documents.iter().map(|d| d.created_at.as_str()));
+
+        Ok(CampaignAnalytics {
+            campaign_id: campaign.id,
+            campaign_name: campaign.name,
+            sessions,
+            combat,
+            prep_per_week,
+            top_monsters: self.top_monsters(campaign_id)?,
+            top_spells: self.top_spells(campaign_id)?,
+        })
+    }
+
+    fn session_stats(&mut self, campaign_id: &str) -> ServiceResult<(SessionStats, CombatStats)> {
+        let mut sessions: Vec<_> = dal::list_game_sessions(self.conn, campaign_id)?
+            .into_iter()
+            .filter(|s| !s.is_active())
+            .collect();
+        sessions.sort_by_key(|s| s.session_number);
+
+        let run = sessions.len();
+        let total_play_seconds: i64 = sessions.iter().map(|s| s.play_seconds as i64).sum();
+        let session_stats = SessionStats {
+            sessions_run: run,
+            total_play_seconds,
+            average_play_seconds: average(total_play_seconds, run),
+            total_xp_awarded: sessions.iter().map(|s| s.xp_awarded as i64).sum(),
+            first_session_at: sessions.first().map(|s| s.started_at.clone()),
+            last_session_at: sessions.last().map(|s| s.started_at.clone()),
+        };
+
+        let with_combat: Vec<_> = sessions.iter().filter(|s| s.combat_turns > 0).collect();
+        let combat_turns: i64 = with_combat.iter().map(|s| s.combat_turns as i64).sum();
+        let combat_seconds: i64 = with_combat
+            .iter()
+            .map(|s| s.combat_turn_seconds as i64)
+            .sum();
+        let combat_stats = CombatStats {
+            sessions_with_combat: with_combat.len(),
+            combat_turns,
+            average_combat_seconds: average(combat_seconds, with_combat.len()),
+            average_turn_seconds: average(combat_seconds, combat_turns as usize),
+            longest_turn_seconds: with_combat
+                .iter()
+                .map(|s| s.longest_turn_seconds as i64)
+                .max()
+                .unwrap_or(0),
+        };
+
+        Ok((session_stats, combat_stats))
+    }
+
+    fn top_monsters(&mut self, campaign_id: &str) -> ServiceResult<Vec<UsageCount>> {
+        let homebrew_names: HashMap<String, String> =
+            dal::list_campaign_homebrew_monsters(self.conn, campaign_id)?
+                .into_iter()
+                .map(|m| (m.id, m.name))
+                .collect();
+
+        let mut counts: BTreeMap<(String, Option<String>), i64> = BTreeMap::new();
+        for module in dal::list_modules(self.conn, campaign_id)? {
+            for monster in dal::list_module_monsters(self.conn, &module.id)? {
+                let name = monster
+                    .monster_name
+                    .or_else(|| {
+                        monster
+                            .homebrew_monster_id
+                            .and_then(|id| homebrew_names.get(&id).cloned())
+                    })
+                    .or(monster.display_name);
+                if let Some(name) = name {
+                    *counts.entry((name, monster.monster_source)).or_insert(0) +=
+                        monster.quantity.max(1) as i64;
+                }
+            }
+        }
+        Ok(top_usage(counts))
+    }
+
+    fn top_spells(&mut self, campaign_id: &str) -> ServiceResult<Vec<UsageCount>> {
+        let mut counts: BTreeMap<(String, Option<String>), i64> = BTreeMap::new();
+        for character in dal::list_campaign_characters(self.conn, campaign_id)? {
+            for spell in dal::list_character_spells(self.conn, &character.id)? {
+                *counts
+                    .entry((spell.spell_name, Some(spell.spell_source)))
+                    .or_insert(0) += 1;
+            }
+        }
+        Ok(top_usage(counts))
+    }
+}
+
+/// Integer average, or 0 when there is nothing to average.
+fn average(total: i64, count: usize) -> i64 {
+    if count == 0 {
+        0
+    } else {
+        total / count as i64
+    }
+}
+
+/// The most used entries, highest count first and then by name.
+fn top_usage(counts: BTreeMap<(String, Option<String>), i64>) -> Vec<UsageCount> {
+    let mut usage: Vec<UsageCount> = counts
+        .into_iter()
+        .map(|((name, source), count)| UsageCount {
+            name,
+            source,
+            count,
+        })
+        .collect();
+    // Stable sort keeps the BTreeMap's name order among equal counts
+    usage.sort_by(|a, b| b.count.cmp(&a.count));
+    usage.truncate(TOP_USAGE_COUNT);
+    usage
+}
+
+/// Count RFC 3339 timestamps by the Monday of their week, filling empty
+/// weeks between the first and last.
+fn weekly_counts<'t>(timestamps: impl Iterator<Item = &'t str>) -> Vec<WeeklyCount> {
+    let mut counts: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    for timestamp in timestamps {
+        if let Ok(time) = DateTime::parse_from_rfc3339(timestamp) {
+            let date = time.date_naive();
+            let monday = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+            *counts.entry(monday).or_insert(0) += 1;
+        }
+    }
+
+    let (Some(&first), Some(&last)) = (counts.keys().next(), counts.keys().next_back()) else {
+        return Vec::new();
+    };
+    let mut weeks = Vec::new();
+    let mut week = first;
+    while week <= last {
+        weeks.push(WeeklyCount {
+            week_start: week.format("%Y-%m-%d").to_string(),
+            count: counts.get(&week).copied().unwrap_or(0),
+        });
+        week += Duration::weeks(1);
+    }
+    weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::models::campaign::NewCampaign;
+    use crate::services::{GameSessionService, SessionTotals, StartSessionInput};
+    use crate::test_utils::setup_test_db;
+
+    #[test]
+    fn test_weekly_counts_fill_gaps() {
+        let weeks = weekly_counts(
+            [
+                "2024-03-04T10:00:00Z", // Monday
+                "2024-03-10T23:00:00Z", // Sunday of the same week
+                "2024-03-20T09:00:00Z", // two weeks later
+                "not a date",
+            ]
+            .into_iter(),
+        );
+        let summary: Vec<_> = weeks
+            .iter()
+            .map(|w| (w.week_start.as_str(), w.count))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("2024-03-04", 2), ("2024-03-11", 0), ("2024-03-18", 1)]
+        );
+        assert!(weekly_counts(std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_campaign_analytics_sessions_and_combat() {
+        let mut conn = setup_test_db();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Stats Campaign"))
+            .expect("Failed to create campaign");
+
+        let mut sessions = GameSessionService::new(&mut conn);
+        for totals in [
+            SessionTotals {
+                play_seconds: 3600,
+                combat_turns: 10,
+                combat_turn_seconds: 600,
+                longest_turn_seconds: 120,
+                ..Default::default()
+            },
+            SessionTotals {
+                play_seconds: 7200,
+                ..Default::default()
+            },
+        ] {
+            let session = sessions
+                .start(StartSessionInput::new("camp-1"))
+                .expect("Failed to start session");
+            sessions
+                .end(&session.id, &totals)
+                .expect("Failed to end session");
+        }
+        // A session still in progress isn't counted
+        sessions
+            .start(StartSessionInput::new("camp-1"))
+            .expect("Failed to start session");
+
+        let analytics = AnalyticsService::new(&mut conn)
+            .campaign_analytics("camp-1")
+            .expect("Failed to compute analytics");
+        assert_eq!(analytics.campaign_name, "Stats Campaign");
+        assert_eq!(analytics.sessions.sessions_run, 2);
+        assert_eq!(analytics.sessions.average_play_seconds, 5400);
+        assert_eq!(analytics.combat.sessions_with_combat, 1);
+        assert_eq!(analytics.combat.average_combat_seconds, 600);
+        assert_eq!(analytics.combat.average_turn_seconds, 60);
+        assert_eq!(analytics.combat.longest_turn_seconds, 120);
+        assert!(analytics.top_monsters.is_empty());
+
+        let result = AnalyticsService::new(&mut conn).campaign_analytics("missing");
+        assert!(matches!(result, Err(ServiceError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_top_usage_orders_by_count() {
+        let mut counts = BTreeMap::new();
+        counts.insert(("Goblin".to_string(), Some("MM".to_string())), 6);
+        counts.insert(("Bugbear".to_string(), Some("MM".to_string())), 1);
+        counts.insert(("Ogre".to_string(), None), 6);
+        let usage = top_usage(counts);
+        let names: Vec<_> = usage.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, vec!["Goblin", "Ogre", "Bugbear"]);
+    }
+}
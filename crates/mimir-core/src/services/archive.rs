@@ -5,16 +5,26 @@
 
 use crate::dal::campaign as dal;
 use crate::models::campaign::{
-    Campaign, CampaignAsset, CampaignHomebrewItem, CampaignHomebrewMonster,
-    CampaignHomebrewSpell, Character,
-    CharacterClass, CharacterFeat, CharacterFeature, CharacterInventory, CharacterProficiency,
-    CharacterSpell, Document, FogRevealedArea, LightSource, Map, MapPoi, MapTrap, Module,
-    ModuleMonster, ModuleNpc, NewCampaign, NewCampaignAsset, NewCampaignHomebrewItem,
-    NewCampaignHomebrewMonster, NewCampaignHomebrewSpell, NewCharacter, NewCharacterClass,
+    Campaign, CampaignAsset, CampaignCustomField, CampaignHomebrewItem, CampaignHomebrewMonster,
+    CampaignHomebrewSpell, CampaignMemory, CampaignSafetyTools, CampaignStashCurrency,
+    CampaignStashItem,
+    Character,
+    CharacterClass, CharacterCompanion, CharacterCustomField, CharacterDeath, CharacterFeat, CharacterFeature, CharacterInventory, CharacterProficiency,
+    CharacterResource, CharacterSpell, CraftingProject, CurrencyTransaction, Disposition, DmReferencePin, Document, EncounterEffect, EntityTag, FogRevealedArea, ForageIngredient, GameSession, Handout, HandoutReveal, LightSource, Map, MapPoi, MapRegion, MapTrap, Module,
+    ModuleLink, ModuleMonster, ModuleNpc, ModulePrepCheck, NewCampaign, NewCampaignAsset, NewCampaignCustomField,
+    NewCampaignHomebrewItem,
+    NewCampaignHomebrewMonster, NewCampaignHomebrewSpell, NewCampaignMemory,
+    NewCampaignSafetyTools,
+    NewCampaignStashCurrency, NewCampaignStashItem,
+    NewCharacter, NewCharacterClass, NewCharacterCompanion, NewCharacterCustomField,
+    NewCharacterDeath,
     NewCharacterFeat,
-    NewCharacterFeature, NewCharacterInventory, NewCharacterProficiency, NewCharacterSpell,
-    NewDocument, NewFogRevealedArea, NewLightSource, NewMap, NewMapPoi, NewMapTrap, NewModule,
-    NewModuleMonster, NewModuleNpc, NewTokenPlacement, TokenPlacement,
+    NewCharacterFeature, NewCharacterInventory, NewCharacterProficiency, NewCharacterResource,
+    NewCharacterSpell, NewCraftingProject, NewCurrencyTransaction, NewDisposition, NewDmReferencePin,
+    NewDocument, NewEncounterEffect, NewEntityTag, NewFogRevealedArea, NewForageIngredient, NewGameSession, NewHandout, NewHandoutReveal, NewLightSource, NewMap, NewMapPoi, NewMapRegion, NewMapTrap, NewModule,
+    NewModuleLink, NewModuleMonster, NewModuleNpc, NewModulePrepCheck, NewNpcInteraction, NewOwnedVehicle, NewParty, NewPartyMember, NewPartyReputation, NewPartyTimelineEvent, NewPlayer, NewQuickAction, NewRumor, NewSecretNote, NewStashLedgerEntry, NewTag, NewTokenPlacement,
+    NpcInteraction, OwnedVehicle, PanelType, Party, PartyMember, PartyReputation, PartyTimelineEvent, QuickAction, QuickActionStep, Rumor, SecretNote, StashLedgerEntry, Tag, TaggableType, TokenPlacement, UpdateCharacterDeath, UpdateGameSession, UpdateHandout, UpdateModuleLink, UpdateParty, UpdateSecretNote,
+    VehicleKind,
 };
 use crate::services::{ServiceError, ServiceResult};
 use chrono::{DateTime, Utc};
@@ -22,9 +32,10 @@ use diesel::SqliteConnection;
 use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
-use std::io::Read;
+use std::io::{BufWriter, Read};
 use std::path::{Path, PathBuf};
 use tar::{Archive, Builder};
 use tracing::{info, warn};
@@ -35,6 +46,8 @@ const ARCHIVE_VERSION: &str = "2.0";
 const ARCHIVE_FORMAT: &str = "mimir-campaign";
 /// File extension for campaign archives
 pub const ARCHIVE_EXTENSION: &str = ".mimir-campaign.tar.gz";
+/// Checksum manifest, written as the last entry of the archive
+const CHECKSUMS_FILE: &str = "checksums.json";
 
 // =============================================================================
 // Archive Data Structures
@@ -80,6 +93,56 @@ pub struct CatalogReference {
     pub source: String,
 }
 
+/// SHA-256 checksums of every other file in an archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveChecksums {
+    pub algorithm: String,
+    pub files: Vec<ArchiveChecksum>,
+}
+
+/// Checksum of a single archive entry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveChecksum {
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Export progress, reported after each file is written to the archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveProgress {
+    pub files_written: usize,
+    pub total_files: usize,
+    pub bytes_written: u64,
+    pub total_bytes: u64,
+    /// Archive path of the file just written
+    pub current_file: String,
+}
+
+/// Result of verifying an archive against its checksum manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveVerification {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// An asset file to stream into the archive
+#[derive(Debug)]
+struct AssetFile {
+    archive_path: String,
+    source: PathBuf,
+    size: u64,
+}
+
+/// Which characters to copy when cloning a campaign
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CloneCampaignOptions {
+    /// Copy player characters (default: false)
+    pub include_player_characters: Option<bool>,
+    /// Copy NPCs (default: true)
+    pub include_npcs: Option<bool>,
+}
+
 /// Preview information about an archive without importing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchivePreview {
@@ -111,6 +174,12 @@ struct IdMaps {
     module_monsters: HashMap<String, String>,
     module_npcs: HashMap<String, String>,
     homebrew_monsters: HashMap<String, String>,
+    pois: HashMap<String, String>,
+    traps: HashMap<String, String>,
+    light_sources: HashMap<String, String>,
+    tokens: HashMap<String, String>,
+    parties: HashMap<String, String>,
+    game_sessions: HashMap<String, String>,
 }
 
 /// Complete archive data - uses existing models directly
@@ -132,6 +201,59 @@ pub struct ArchiveData {
     pub homebrew_monsters: Vec<CampaignHomebrewMonster>,
     #[serde(default)]
     pub homebrew_spells: Vec<CampaignHomebrewSpell>,
+    #[serde(default)]
+    pub safety_tools: Option<CampaignSafetyTools>,
+    #[serde(default)]
+    pub custom_fields: Vec<CampaignCustomField>,
+    #[serde(default)]
+    pub stash_currency: Option<CampaignStashCurrency>,
+    #[serde(default)]
+    pub stash_items: Vec<CampaignStashItem>,
+    #[serde(default)]
+    pub stash_ledger: Vec<StashLedgerEntry>,
+    #[serde(default)]
+    pub memories: Vec<CampaignMemory>,
+    #[serde(default)]
+    pub module_links: Vec<ModuleLink>,
+    #[serde(default)]
+    pub forage_ingredients: Vec<ForageIngredient>,
+    #[serde(default)]
+    pub encounter_effects: Vec<EncounterEffect>,
+    #[serde(default)]
+    pub rumors: Vec<Rumor>,
+    #[serde(default)]
+    pub handouts: Vec<Handout>,
+    #[serde(default)]
+    pub handout_reveals: Vec<HandoutReveal>,
+    #[serde(default)]
+    pub module_prep_checks: Vec<ModulePrepCheck>,
+    #[serde(default)]
+    pub secret_notes: Vec<SecretNoteWithPlayer>,
+    #[serde(default)]
+    pub dispositions: Vec<Disposition>,
+    #[serde(default)]
+    pub party_reputations: Vec<PartyReputation>,
+    #[serde(default)]
+    pub npc_interactions: Vec<NpcInteraction>,
+    #[serde(default)]
+    pub parties: Vec<Party>,
+    #[serde(default)]
+    pub party_members: Vec<PartyMember>,
+    #[serde(default)]
+    pub party_timeline: Vec<PartyTimelineEvent>,
+    /// Mounts and vehicles owned by a party rather than a character
+    #[serde(default)]
+    pub party_vehicles: Vec<OwnedVehicle>,
+    #[serde(default)]
+    pub game_sessions: Vec<GameSession>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub entity_tags: Vec<EntityTag>,
+    #[serde(default)]
+    pub quick_actions: Vec<QuickAction>,
+    #[serde(default)]
+    pub dm_reference_pins: Vec<DmReferencePin>,
 }
 
 /// Character with all related data aggregated
@@ -145,6 +267,20 @@ pub struct CharacterWithRelated {
     pub proficiencies: Vec<CharacterProficiency>,
     pub features: Vec<CharacterFeature>,
     pub feats: Vec<CharacterFeat>,
+    #[serde(default)]
+    pub custom_fields: Vec<CharacterCustomField>,
+    #[serde(default)]
+    pub resources: Vec<CharacterResource>,
+    #[serde(default)]
+    pub companions: Vec<CharacterCompanion>,
+    #[serde(default)]
+    pub vehicles: Vec<OwnedVehicle>,
+    #[serde(default)]
+    pub crafting_projects: Vec<CraftingProject>,
+    #[serde(default)]
+    pub currency_transactions: Vec<CurrencyTransaction>,
+    #[serde(default)]
+    pub deaths: Vec<CharacterDeath>,
 }
 
 /// Map with all related data aggregated
@@ -156,6 +292,17 @@ pub struct MapWithRelated {
     pub traps: Vec<MapTrap>,
     pub light_sources: Vec<LightSource>,
     pub fog_areas: Vec<FogRevealedArea>,
+    #[serde(default)]
+    pub regions: Vec<MapRegion>,
+}
+
+/// Secret note with its recipient's name. The player roster isn't archived,
+/// so recipients are recreated by name without their contact details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretNoteWithPlayer {
+    #[serde(flatten)]
+    pub note: SecretNote,
+    pub player_name: String,
 }
 
 // =============================================================================
@@ -178,6 +325,23 @@ impl<'a> ArchiveService<'a> {
         campaign_id: &str,
         output_dir: &Path,
         assets_dir: &Path,
+    ) -> ServiceResult<PathBuf> {
+        self.export_campaign_with_progress(campaign_id, output_dir, assets_dir, |_| {})
+    }
+
+    /// Export a campaign to a tar.gz archive, reporting progress as files are
+    /// written.
+    ///
+    /// Asset files are streamed from disk into the archive, so memory use
+    /// doesn't grow with asset size. The archive is written next to its final
+    /// path and only moved into place once it verifies against its checksum
+    /// manifest.
+    pub fn export_campaign_with_progress<F: FnMut(&ArchiveProgress)>(
+        &mut self,
+        campaign_id: &str,
+        output_dir: &Path,
+        assets_dir: &Path,
+        on_progress: F,
     ) -> ServiceResult<PathBuf> {
         let campaign = dal::get_campaign(self.conn, campaign_id).map_err(|e| match e {
             diesel::result::Error::NotFound => ServiceError::not_found("Campaign", campaign_id),
@@ -215,10 +379,31 @@ impl<'a> ArchiveService<'a> {
         let slug = Self::slugify(&archive_data.campaign.name);
         let archive_name = format!("{}{}", slug, ARCHIVE_EXTENSION);
         let archive_path = output_dir.join(&archive_name);
+        let partial_path = output_dir.join(format!("{}.partial", archive_name));
+
+        let written = Self::create_archive(
+            &partial_path,
+            &manifest,
+            &archive_data,
+            &asset_files,
+            on_progress,
+        )
+        .and_then(|_| Self::verify_archive(&partial_path));
+        let verification = match written {
+            Ok(verification) => verification,
+            Err(e) => {
+                let _ = fs::remove_file(&partial_path);
+                return Err(e);
+            }
+        };
+        fs::rename(&partial_path, &archive_path).map_err(ServiceError::Io)?;
 
-        self.create_archive(&archive_path, &manifest, &archive_data, &asset_files)?;
-
-        info!(path = %archive_path.display(), "Campaign archive created");
+        info!(
+            path = %archive_path.display(),
+            files = verification.files,
+            bytes = verification.bytes,
+            "Campaign archive created"
+        );
         Ok(archive_path)
     }
 
@@ -244,6 +429,23 @@ impl<'a> ArchiveService<'a> {
         let data: ArchiveData = serde_json::from_str(&data_content)
             .map_err(|e| ServiceError::validation(format!("Invalid data.json: {}", e)))?;
 
+        self.import_data(
+            &data,
+            &|asset: &CampaignAsset| archived_asset_path(temp_dir.path(), asset),
+            assets_dir,
+            campaign_name_override,
+        )
+    }
+
+    /// Create a new campaign from archive data, copying each asset's file
+    /// from the path `asset_source` gives for it.
+    pub(super) fn import_data(
+        &mut self,
+        data: &ArchiveData,
+        asset_source: &dyn Fn(&CampaignAsset) -> PathBuf,
+        assets_dir: &Path,
+        campaign_name_override: Option<&str>,
+    ) -> ServiceResult<ImportResult> {
         // Determine campaign name
         let campaign_name = campaign_name_override
             .map(|s| s.to_string())
@@ -256,7 +458,14 @@ impl<'a> ArchiveService<'a> {
         let new_campaign_id = uuid::Uuid::new_v4().to_string();
         id_maps.campaign.insert(data.campaign.id.clone(), new_campaign_id.clone());
 
-        let new_campaign = NewCampaign::new(&new_campaign_id, &campaign_name);
+        let mut new_campaign = NewCampaign::new(&new_campaign_id, &campaign_name);
+        if let Some(ref desc) = data.campaign.description {
+            new_campaign = new_campaign.with_description(desc);
+        }
+        if let Some(ref edition) = data.campaign.edition {
+            new_campaign = new_campaign.with_edition(edition);
+        }
+        new_campaign = new_campaign.with_srd_only(data.campaign.is_srd_only());
         dal::insert_campaign(self.conn, &new_campaign)?;
 
         // Add campaign sources
@@ -271,34 +480,95 @@ impl<'a> ArchiveService<'a> {
         }
 
         // 2. Import assets first (needed for map references)
-        self.import_assets(&data, &mut id_maps, &new_campaign_id, assets_dir, temp_dir.path())?;
+        self.import_assets(
+            data,
+            &mut id_maps,
+            &new_campaign_id,
+            assets_dir,
+            asset_source,
+        )?;
 
         // 3. Import modules
-        self.import_modules(&data, &mut id_maps, &new_campaign_id)?;
+        self.import_modules(data, &mut id_maps, &new_campaign_id)?;
 
         // 4. Import characters
-        self.import_characters(&data, &mut id_maps, &new_campaign_id)?;
+        self.import_characters(data, &mut id_maps, &new_campaign_id)?;
 
         // 5. Import documents
-        self.import_documents(&data, &mut id_maps, &new_campaign_id)?;
+        self.import_documents(data, &mut id_maps, &new_campaign_id)?;
 
         // 6. Import maps
-        self.import_maps(&data, &mut id_maps, &new_campaign_id)?;
+        self.import_maps(data, &mut id_maps, &new_campaign_id)?;
+
+        // Parties and their members, play history, and party calendars
+        self.import_parties(data, &mut id_maps, &new_campaign_id)?;
+        self.import_game_sessions(data, &mut id_maps, &new_campaign_id)?;
+        self.import_party_timeline(data, &id_maps)?;
 
         // 7. Import homebrew items
-        self.import_homebrew_items(&data, &new_campaign_id)?;
+        self.import_homebrew_items(data, &new_campaign_id)?;
 
         // 8. Import homebrew monsters (before module entities, since module monsters may reference them)
-        self.import_homebrew_monsters(&data, &new_campaign_id, &mut id_maps)?;
+        self.import_homebrew_monsters(data, &new_campaign_id, &mut id_maps)?;
+
+        // Companions may use homebrew monster stat blocks
+        self.import_companions(data, &id_maps)?;
+        self.import_owned_vehicles(data, &id_maps, &new_campaign_id)?;
+        self.import_crafting_projects(data, &id_maps)?;
+        self.import_currency_transactions(data, &id_maps)?;
+        self.import_character_deaths(data, &id_maps)?;
 
         // 9. Import homebrew spells
-        self.import_homebrew_spells(&data, &new_campaign_id)?;
+        self.import_homebrew_spells(data, &new_campaign_id)?;
+
+        // 10. Import safety tools
+        self.import_safety_tools(data, &new_campaign_id)?;
+
+        // 11. Import custom field definitions
+        self.import_custom_fields(data, &new_campaign_id)?;
+
+        // Shared stash (ledger entries refer to characters)
+        self.import_stash(data, &id_maps, &new_campaign_id)?;
 
-        // 10. Import module monsters and NPCs
-        self.import_module_entities(&data, &mut id_maps)?;
+        // Facts the AI assistant remembered for the campaign
+        self.import_memories(data, &new_campaign_id)?;
 
-        // 11. Import tokens (need map and module_monster/module_npc IDs)
-        self.import_tokens(&data, &id_maps)?;
+        // Follows-from and foreshadows links between modules
+        self.import_module_links(data, &id_maps, &new_campaign_id)?;
+
+        // Gathering tables
+        self.import_forage_ingredients(data, &new_campaign_id)?;
+
+        // Lair actions and other environmental effects
+        self.import_encounter_effects(data, &id_maps)?;
+
+        // Prep checklist tasks ticked off by hand
+        self.import_module_prep_checks(data, &id_maps)?;
+
+        // Rumors (who heard them isn't kept; players aren't archived)
+        self.import_rumors(data, &id_maps, &new_campaign_id)?;
+
+        // Secret notes (recipients come back as players without contact details)
+        self.import_secret_notes(data, &id_maps, &new_campaign_id)?;
+
+        // Dispositions, reputations, and reaction history
+        self.import_relationships(data, &id_maps, &new_campaign_id)?;
+
+        // Handout queue and reveal history
+        self.import_handouts(data, &id_maps, &new_campaign_id)?;
+
+        // 12. Import module monsters and NPCs
+        self.import_module_entities(data, &mut id_maps)?;
+
+        // 13. Import tokens (need map and module_monster/module_npc IDs)
+        self.import_tokens(data, &mut id_maps)?;
+
+        // 14. Import tags (need the IDs of everything they attach to)
+        self.import_tags(data, &id_maps, &new_campaign_id)?;
+
+        // 15. Import quick actions and reference pins (need map, token, and character IDs)
+        self.import_quick_actions(data, &id_maps, &new_campaign_id)?;
+        self.import_dm_reference_pins(data, &id_maps, &new_campaign_id)?;
 
         let counts = ArchiveCounts {
             modules: data.modules.len(),
@@ -327,6 +597,48 @@ impl<'a> ArchiveService<'a> {
         })
     }
 
+    /// Deep-copy a campaign into a new campaign named `new_name`.
+    ///
+    /// Modules, documents, maps, homebrew, and asset files are copied with
+    /// fresh IDs, and assets go into the new campaign's own directory.
+    /// Characters are copied according to `options`.
+    pub fn clone_campaign(
+        &mut self,
+        campaign_id: &str,
+        new_name: &str,
+        options: &CloneCampaignOptions,
+        assets_dir: &Path,
+    ) -> ServiceResult<ImportResult> {
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err(ServiceError::validation("Campaign name cannot be empty"));
+        }
+        let campaign = dal::get_campaign(self.conn, campaign_id).map_err(|e| match e {
+            diesel::result::Error::NotFound => ServiceError::not_found("Campaign", campaign_id),
+            other => ServiceError::Database(other),
+        })?;
+
+        info!(campaign_id = %campaign_id, new_name = %new_name, "Cloning campaign");
+
+        let mut data = self.collect_campaign_data(campaign_id, campaign)?;
+        let include_pcs = options.include_player_characters.unwrap_or(false);
+        let include_npcs = options.include_npcs.unwrap_or(true);
+        data.characters.retain(|c| {
+            if c.character.is_npc() {
+                include_npcs
+            } else {
+                include_pcs
+            }
+        });
+
+        self.import_data(
+            &data,
+            &|asset: &CampaignAsset| assets_dir.join(&asset.blob_path),
+            assets_dir,
+            Some(new_name),
+        )
+    }
+
     /// Preview an archive without importing.
     pub fn preview_archive(archive_path: &Path) -> ServiceResult<ArchivePreview> {
         let file = File::open(archive_path).map_err(ServiceError::Io)?;
@@ -365,7 +677,75 @@ impl<'a> ArchiveService<'a> {
         Err(ServiceError::validation("Archive missing manifest.json"))
     }
 
-    fn collect_campaign_data(
+    /// Check every file in an archive against its checksum manifest.
+    ///
+    /// Fails if the archive has no checksum manifest, or if any file is
+    /// missing, unexpected, or has a different size or SHA-256 hash.
+    pub fn verify_archive(archive_path: &Path) -> ServiceResult<ArchiveVerification> {
+        let file = File::open(archive_path).map_err(ServiceError::Io)?;
+        let mut archive = Archive::new(GzDecoder::new(file));
+
+        let mut actual: HashMap<String, ArchiveChecksum> = HashMap::new();
+        let mut checksums: Option<ArchiveChecksums> = None;
+        for entry in archive.entries().map_err(ServiceError::Io)? {
+            let mut entry = entry.map_err(ServiceError::Io)?;
+            let path = entry
+                .path()
+                .map_err(ServiceError::Io)?
+                .to_string_lossy()
+                .into_owned();
+
+            if path == CHECKSUMS_FILE {
+                let mut content = String::new();
+                entry
+                    .read_to_string(&mut content)
+                    .map_err(ServiceError::Io)?;
+                checksums = Some(serde_json::from_str(&content).map_err(|e| {
+                    ServiceError::validation(format!("Invalid {}: {}", CHECKSUMS_FILE, e))
+                })?);
+            } else {
+                let mut reader = HashingReader::new(&mut entry);
+                std::io::copy(&mut reader, &mut std::io::sink()).map_err(ServiceError::Io)?;
+                actual.insert(path.clone(), reader.finish(path));
+            }
+        }
+
+        let checksums = checksums.ok_or_else(|| {
+            ServiceError::validation(format!("Archive missing {}", CHECKSUMS_FILE))
+        })?;
+
+        let mut bytes = 0;
+        for expected in &checksums.files {
+            match actual.remove(&expected.path) {
+                Some(found) if found == *expected => bytes += found.size,
+                Some(_) => {
+                    return Err(ServiceError::validation(format!(
+                        "Checksum mismatch for '{}'",
+                        expected.path
+                    )))
+                }
+                None => {
+                    return Err(ServiceError::validation(format!(
+                        "Archive is missing '{}'",
+                        expected.path
+                    )))
+                }
+            }
+        }
+        if let Some(path) = actual.keys().next() {
+            return Err(ServiceError::validation(format!(
+                "Archive contains '{}', which has no checksum",
+                path
+            )));
+        }
+
+        Ok(ArchiveVerification {
+            files: checksums.files.len(),
+            bytes,
+        })
+    }
+
+    pub(super) fn collect_campaign_data(
         &mut self,
         campaign_id: &str,
         campaign: Campaign,
@@ -385,6 +765,13 @@ impl<'a> ArchiveService<'a> {
                 proficiencies: dal::list_character_proficiencies(self.conn, &c.id)?,
                 features: dal::list_character_features(self.conn, &c.id)?,
                 feats: dal::list_character_feats(self.conn, &c.id)?,
+                custom_fields: dal::list_character_custom_fields(self.conn, &c.id)?,
+                resources: dal::list_character_resources(self.conn, &c.id)?,
+                companions: dal::list_character_companions(self.conn, &c.id)?,
+                vehicles: dal::list_character_owned_vehicles(self.conn, &c.id)?,
+                crafting_projects: dal::list_character_crafting_projects(self.conn, &c.id)?,
+                currency_transactions: dal::list_character_currency_transactions(self.conn, &c.id)?,
+                deaths: dal::list_character_deaths(self.conn, &c.id)?,
                 character: c,
             });
         }
@@ -401,6 +788,7 @@ impl<'a> ArchiveService<'a> {
                 traps: dal::list_map_traps(self.conn, &m.id)?,
                 light_sources: dal::list_light_sources(self.conn, &m.id)?,
                 fog_areas: dal::list_fog_revealed_areas(self.conn, &m.id)?,
+                regions: dal::list_map_regions(self.conn, &m.id)?,
                 map: m,
             });
         }
@@ -408,9 +796,13 @@ impl<'a> ArchiveService<'a> {
         // Module entities
         let mut module_monsters = Vec::new();
         let mut module_npcs = Vec::new();
+        let mut encounter_effects = Vec::new();
+        let mut module_prep_checks = Vec::new();
         for module in &modules {
             module_monsters.extend(dal::list_module_monsters(self.conn, &module.id)?);
             module_npcs.extend(dal::list_module_npcs(self.conn, &module.id)?);
+            encounter_effects.extend(dal::list_encounter_effects(self.conn, &module.id)?);
+            module_prep_checks.extend(dal::list_module_prep_checks(self.conn, &module.id)?);
         }
 
         // All campaign assets (maps, tokens, images, etc.)
@@ -425,6 +817,81 @@ impl<'a> ArchiveService<'a> {
         // Homebrew spells
         let homebrew_spells = dal::list_campaign_homebrew_spells(self.conn, campaign_id)?;
 
+        // Safety tools (DM-only, kept so restores and clones keep the table's limits)
+        let safety_tools = dal::get_campaign_safety_tools(self.conn, campaign_id)?;
+
+        // Custom character sheet field definitions
+        let custom_fields = dal::list_campaign_custom_fields(self.conn, campaign_id)?;
+
+        // Shared stash and its ledger
+        let stash_currency = dal::get_campaign_stash_currency(self.conn, campaign_id)?;
+        let stash_items = dal::list_campaign_stash_items(self.conn, campaign_id)?;
+        let stash_ledger = dal::list_stash_ledger(self.conn, campaign_id)?;
+
+        // Facts the AI assistant remembered for the campaign
+        let memories = dal::list_campaign_memories(self.conn, campaign_id)?;
+
+        // Follows-from and foreshadows links between modules
+        let module_links = dal::list_module_links(self.conn, campaign_id)?;
+
+        // Gathering tables
+        let forage_ingredients = dal::list_forage_ingredients(self.conn, campaign_id)?;
+
+        // Rumors and adventure hooks
+        let rumors = dal::list_rumors(self.conn, campaign_id)?;
+
+        // Handout queue and when players saw each one
+        let handouts = dal::list_handouts(self.conn, campaign_id)?;
+        let handout_reveals = dal::list_handout_reveals(self.conn, campaign_id, None)?;
+
+        // Per-player secret notes, with who each one is for
+        let player_names: HashMap<String, String> = dal::list_players(self.conn, campaign_id)?
+            .into_iter()
+            .map(|p| (p.id, p.name))
+            .collect();
+        let secret_notes = dal::list_secret_notes(self.conn, campaign_id)?
+            .into_iter()
+            .filter_map(|note| {
+                let player_name = player_names.get(&note.player_id)?.clone();
+                Some(SecretNoteWithPlayer { note, player_name })
+            })
+            .collect();
+
+        // Dispositions, party reputations, and NPC reaction history
+        let dispositions = dal::list_dispositions(self.conn, campaign_id)?;
+        let party_reputations = dal::list_party_reputations(self.conn, campaign_id)?;
+        let mut npc_interactions = Vec::new();
+        for c in &characters {
+            npc_interactions.extend(dal::list_npc_interactions(self.conn, &c.character.id)?);
+        }
+
+        // Parties with their members, calendars, and shared vehicles
+        let parties = dal::list_parties(self.conn, campaign_id)?;
+        let mut party_members = Vec::new();
+        let mut party_timeline = Vec::new();
+        for party in &parties {
+            party_members.extend(dal::list_party_members(self.conn, &party.id)?);
+            party_timeline.extend(dal::list_party_timeline_events(self.conn, &party.id)?);
+        }
+        let party_vehicles = dal::list_campaign_owned_vehicles(self.conn, campaign_id)?
+            .into_iter()
+            .filter(|v| v.is_party_owned())
+            .collect();
+
+        // Play history
+        let game_sessions = dal::list_game_sessions(self.conn, campaign_id)?;
+
+        // Tags and what they're attached to
+        let tags = dal::list_tags(self.conn, campaign_id)?;
+        let mut entity_tags = Vec::new();
+        for tag in &tags {
+            entity_tags.extend(dal::list_entity_tags_for_tag(self.conn, &tag.id)?);
+        }
+
+        // Scene changes and the DM reference window
+        let quick_actions = dal::list_quick_actions(self.conn, campaign_id)?;
+        let dm_reference_pins = dal::list_dm_reference_pins(self.conn, campaign_id)?;
+
         Ok(ArchiveData {
             campaign,
             sources,
@@ -439,6 +906,32 @@ impl<'a> ArchiveService<'a> {
             homebrew_items,
             homebrew_monsters,
             homebrew_spells,
+            safety_tools,
+            custom_fields,
+            stash_currency,
+            stash_items,
+            stash_ledger,
+            memories,
+            module_links,
+            forage_ingredients,
+            encounter_effects,
+            rumors,
+            handouts,
+            handout_reveals,
+            module_prep_checks,
+            secret_notes,
+            dispositions,
+            party_reputations,
+            npc_interactions,
+            parties,
+            party_members,
+            party_timeline,
+            party_vehicles,
+            game_sessions,
+            tags,
+            entity_tags,
+            quick_actions,
+            dm_reference_pins,
         })
     }
 
@@ -446,34 +939,36 @@ impl<'a> ArchiveService<'a> {
         &self,
         data: &ArchiveData,
         assets_dir: &Path,
-    ) -> ServiceResult<HashMap<String, Vec<u8>>> {
-        let mut files = HashMap::new();
+    ) -> ServiceResult<Vec<AssetFile>> {
+        let mut files = Vec::new();
 
         // Include all campaign assets - these are the actual binary files (maps, tokens, images)
         for asset in &data.assets {
             let file_path = assets_dir.join(&asset.blob_path);
-            if file_path.exists() {
-                match fs::read(&file_path) {
-                    Ok(bytes) => {
-                        // Store with asset ID as key to preserve references
-                        let archive_path = format!("{}/{}", asset.id, asset.filename);
-                        files.insert(archive_path, bytes);
-                    }
-                    Err(e) => {
-                        warn!(
-                            asset_id = %asset.id,
-                            path = %file_path.display(),
-                            error = %e,
-                            "Failed to read asset file"
-                        );
-                    }
+            match fs::metadata(&file_path) {
+                Ok(metadata) if metadata.is_file() => {
+                    // Store with asset ID as key to preserve references
+                    files.push(AssetFile {
+                        archive_path: format!("assets/{}/{}", asset.id, asset.filename),
+                        source: file_path,
+                        size: metadata.len(),
+                    });
+                }
+                Ok(_) => {
+                    warn!(
+                        asset_id = %asset.id,
+                        path = %file_path.display(),
+                        "Asset path is not a file"
+                    );
+                }
+                Err(e) => {
+                    warn!(
+                        asset_id = %asset.id,
+                        path = %file_path.display(),
+                        error = %e,
+                        "Asset file not found"
+                    );
                 }
-            } else {
-                warn!(
-                    asset_id = %asset.id,
-                    path = %file_path.display(),
-                    "Asset file not found"
-                );
             }
         }
 
@@ -503,43 +998,97 @@ impl<'a> ArchiveService<'a> {
         refs.into_iter().collect()
     }
 
-    fn create_archive(
-        &self,
+    fn create_archive<F: FnMut(&ArchiveProgress)>(
         archive_path: &Path,
         manifest: &ArchiveManifest,
         data: &ArchiveData,
-        asset_files: &HashMap<String, Vec<u8>>,
+        asset_files: &[AssetFile],
+        mut on_progress: F,
     ) -> ServiceResult<()> {
         let file = File::create(archive_path).map_err(ServiceError::Io)?;
-        let gz = GzEncoder::new(file, Compression::default());
+        let gz = GzEncoder::new(BufWriter::new(file), Compression::default());
         let mut archive = Builder::new(gz);
 
-        let manifest_json = serde_json::to_string_pretty(manifest)
-            .map_err(|e| ServiceError::validation(format!("Failed to serialize manifest: {}", e)))?;
-        Self::add_file_to_archive(&mut archive, "manifest.json", manifest_json.as_bytes())?;
-
-        let data_json = serde_json::to_string_pretty(data)
+        let manifest_json = serde_json::to_vec_pretty(manifest).map_err(|e| {
+            ServiceError::validation(format!("Failed to serialize manifest: {}", e))
+        })?;
+        let data_json = serde_json::to_vec_pretty(data)
             .map_err(|e| ServiceError::validation(format!("Failed to serialize data: {}", e)))?;
-        Self::add_file_to_archive(&mut archive, "data.json", data_json.as_bytes())?;
 
-        for (path, bytes) in asset_files {
-            Self::add_file_to_archive(&mut archive, &format!("assets/{}", path), bytes)?;
+        let mut progress = ArchiveProgress {
+            files_written: 0,
+            total_files: asset_files.len() + 2,
+            bytes_written: 0,
+            total_bytes: (manifest_json.len() + data_json.len()) as u64
+                + asset_files.iter().map(|f| f.size).sum::<u64>(),
+            current_file: String::new(),
+        };
+        let mut checksums = Vec::with_capacity(progress.total_files);
+        let mut record = |checksum: ArchiveChecksum| {
+            progress.files_written += 1;
+            progress.bytes_written += checksum.size;
+            progress.current_file = checksum.path.clone();
+            on_progress(&progress);
+            checksums.push(checksum);
+        };
+
+        record(Self::append_entry(
+            &mut archive,
+            "manifest.json",
+            manifest_json.len() as u64,
+            &manifest_json[..],
+        )?);
+        record(Self::append_entry(
+            &mut archive,
+            "data.json",
+            data_json.len() as u64,
+            &data_json[..],
+        )?);
+        drop(data_json);
+
+        for asset in asset_files {
+            let file = File::open(&asset.source).map_err(ServiceError::Io)?;
+            record(Self::append_entry(
+                &mut archive,
+                &asset.archive_path,
+                asset.size,
+                file,
+            )?);
         }
 
+        let checksums_json = serde_json::to_vec_pretty(&ArchiveChecksums {
+            algorithm: "sha256".to_string(),
+            files: checksums,
+        })
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize checksums: {}", e)))?;
+        Self::append_entry(
+            &mut archive,
+            CHECKSUMS_FILE,
+            checksums_json.len() as u64,
+            &checksums_json[..],
+        )?;
+
         archive
             .into_inner()
             .map_err(|e| ServiceError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?
             .finish()
+            .map_err(ServiceError::Io)?
+            .into_inner()
+            .map_err(|e| ServiceError::Io(e.into_error()))?
+            .sync_all()
             .map_err(ServiceError::Io)?;
 
         Ok(())
     }
 
-    fn add_file_to_archive<W: std::io::Write>(
+    /// Stream `size` bytes from `reader` into the archive, returning the
+    /// entry's checksum.
+    pub(super) fn append_entry<W: std::io::Write, R: Read>(
         archive: &mut Builder<W>,
         path: &str,
-        data: &[u8],
-    ) -> ServiceResult<()> {
+        size: u64,
+        reader: R,
+    ) -> ServiceResult<ArchiveChecksum> {
         let mut header = tar::Header::new_gnu();
         header.set_path(path).map_err(|e| {
             ServiceError::Io(std::io::Error::new(
@@ -547,16 +1096,29 @@ impl<'a> ArchiveService<'a> {
                 format!("Invalid archive path '{}': {}", path, e),
             ))
         })?;
-        header.set_size(data.len() as u64);
+        header.set_size(size);
         header.set_mode(0o644);
         header.set_cksum();
-        archive.append(&header, data).map_err(|e| {
+
+        let mut reader = HashingReader::new(reader.take(size));
+        archive.append(&header, &mut reader).map_err(|e| {
             ServiceError::Io(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("Failed to add '{}' to archive: {}", path, e),
             ))
         })?;
-        Ok(())
+
+        let checksum = reader.finish(path.to_string());
+        if checksum.size != size {
+            return Err(ServiceError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!(
+                    "'{}' changed while exporting: expected {} bytes, read {}",
+                    path, size, checksum.size
+                ),
+            )));
+        }
+        Ok(checksum)
     }
 
     // =========================================================================
@@ -595,14 +1157,14 @@ impl<'a> ArchiveService<'a> {
         id_maps: &mut IdMaps,
         campaign_id: &str,
         assets_dir: &Path,
-        temp_dir: &Path,
+        asset_source: &dyn Fn(&CampaignAsset) -> PathBuf,
     ) -> ServiceResult<()> {
         for asset in &data.assets {
             let new_id = uuid::Uuid::new_v4().to_string();
             id_maps.assets.insert(asset.id.clone(), new_id.clone());
 
             // Copy asset file
-            let src_path = temp_dir.join("assets").join(&asset.id).join(&asset.filename);
+            let src_path = asset_source(asset);
             let blob_path = format!("campaigns/{}/{}", campaign_id, asset.filename);
             let dest_path = assets_dir.join(&blob_path);
 
@@ -635,7 +1197,8 @@ impl<'a> ArchiveService<'a> {
             let new_id = uuid::Uuid::new_v4().to_string();
             id_maps.modules.insert(module.id.clone(), new_id.clone());
 
-            let mut new_module = NewModule::new(&new_id, campaign_id, &module.name, module.module_number);
+            let mut new_module = NewModule::new(&new_id, campaign_id, &module.name, module.module_number)
+                .with_type(&module.module_type);
             if let Some(ref desc) = module.description {
                 new_module = new_module.with_description(desc);
             }
@@ -686,6 +1249,12 @@ impl<'a> ArchiveService<'a> {
             new_char.role = c.role.as_deref();
             new_char.location = c.location.as_deref();
             new_char.faction = c.faction.as_deref();
+            new_char.subrace_name = c.subrace_name.as_deref();
+            new_char.subrace_source = c.subrace_source.as_deref();
+            new_char.speed = c.speed;
+            new_char.darkvision = c.darkvision;
+            new_char.resistances = &c.resistances;
+            new_char.is_dead = c.is_dead;
 
             dal::insert_character(self.conn, &new_char)?;
 
@@ -746,6 +1315,9 @@ impl<'a> ArchiveService<'a> {
                 if spell.prepared != 0 {
                     new_spell = new_spell.prepared();
                 }
+                if spell.scribed != 0 {
+                    new_spell = new_spell.scribed();
+                }
                 dal::insert_character_spell(self.conn, &new_spell)?;
             }
 
@@ -789,6 +1361,34 @@ impl<'a> ArchiveService<'a> {
                 };
                 dal::insert_character_feat(self.conn, &new_feat)?;
             }
+
+            // Import custom field values (keyed by field, so no ID mapping)
+            for value in &char_data.custom_fields {
+                let new_value = NewCharacterCustomField::new(
+                    &new_id,
+                    &value.field_key,
+                    &value.value,
+                    &value.updated_at,
+                );
+                dal::upsert_character_custom_field(self.conn, &new_value)?;
+            }
+
+            // Import class resources, keeping uses spent before the export
+            for resource in &char_data.resources {
+                let resource_id = uuid::Uuid::new_v4().to_string();
+                let new_resource = NewCharacterResource::new(
+                    &resource_id,
+                    &new_id,
+                    &resource.resource_name,
+                    &resource.source_class,
+                    resource.max_uses,
+                    resource
+                        .recharge_enum()
+                        .unwrap_or(crate::models::campaign::RechargeType::LongRest),
+                )
+                .with_current_uses(resource.current_uses);
+                dal::insert_character_resource(self.conn, &new_resource)?;
+            }
         }
         Ok(())
     }
@@ -844,6 +1444,7 @@ impl<'a> ArchiveService<'a> {
             // Import POIs
             for poi in &map_data.pois {
                 let poi_id = uuid::Uuid::new_v4().to_string();
+                id_maps.pois.insert(poi.id.clone(), poi_id.clone());
                 let mut new_poi = NewMapPoi::new(&poi_id, &new_id, &poi.name, poi.grid_x, poi.grid_y);
                 if let Some(ref desc) = poi.description {
                     new_poi = new_poi.with_description(desc);
@@ -855,12 +1456,20 @@ impl<'a> ArchiveService<'a> {
                 if poi.visible != 0 {
                     new_poi = new_poi.visible();
                 }
+                let document_id = poi
+                    .document_id
+                    .as_ref()
+                    .and_then(|old_id| id_maps.documents.get(old_id));
+                if let Some(document_id) = document_id {
+                    new_poi = new_poi.with_document_link(document_id, poi.document_section.as_deref());
+                }
                 dal::insert_map_poi(self.conn, &new_poi)?;
             }
 
             // Import traps
             for trap in &map_data.traps {
                 let trap_id = uuid::Uuid::new_v4().to_string();
+                id_maps.traps.insert(trap.id.clone(), trap_id.clone());
                 let mut new_trap = NewMapTrap::new(
                     &trap_id,
                     &new_id,
@@ -883,6 +1492,9 @@ impl<'a> ArchiveService<'a> {
                 if trap.visible != 0 {
                     new_trap = new_trap.visible();
                 }
+                if let Some((name, source)) = trap.catalog_ref() {
+                    new_trap = new_trap.with_catalog_ref(name, source);
+                }
                 // Note: triggered state is preserved in new_trap.triggered field
                 dal::insert_map_trap(self.conn, &new_trap)?;
             }
@@ -890,6 +1502,9 @@ impl<'a> ArchiveService<'a> {
             // Import light sources
             for light in &map_data.light_sources {
                 let light_id = uuid::Uuid::new_v4().to_string();
+                id_maps
+                    .light_sources
+                    .insert(light.id.clone(), light_id.clone());
                 let mut new_light = NewLightSource::new(
                     &light_id,
                     &new_id,
@@ -923,6 +1538,24 @@ impl<'a> ArchiveService<'a> {
                 );
                 dal::insert_fog_revealed_area(self.conn, &new_fog)?;
             }
+
+            // Import regions
+            for region in &map_data.regions {
+                let region_id = uuid::Uuid::new_v4().to_string();
+                let mut new_region = NewMapRegion::rect(
+                    &region_id,
+                    &new_id,
+                    &region.name,
+                    region.x,
+                    region.y,
+                    region.width,
+                    region.height,
+                );
+                if let Some(ref points) = region.points {
+                    new_region = new_region.with_points(points);
+                }
+                dal::insert_map_region(self.conn, &new_region)?;
+            }
         }
         Ok(())
     }
@@ -984,7 +1617,7 @@ impl<'a> ArchiveService<'a> {
         Ok(())
     }
 
-    fn import_tokens(&mut self, data: &ArchiveData, id_maps: &IdMaps) -> ServiceResult<()> {
+    fn import_tokens(&mut self, data: &ArchiveData, id_maps: &mut IdMaps) -> ServiceResult<()> {
         for token in &data.tokens {
             let new_id = uuid::Uuid::new_v4().to_string();
 
@@ -1006,7 +1639,12 @@ impl<'a> ArchiveService<'a> {
             }
 
             let new_token = if let Some(monster_id) = module_monster_id {
-                NewTokenPlacement::for_monster(&new_id, map_id, monster_id, token.grid_x, token.grid_y)
+                let mut placement =
+                    NewTokenPlacement::for_monster(&new_id, map_id, monster_id, token.grid_x, token.grid_y);
+                if let Some(size) = token.mob_size {
+                    placement = placement.as_mob(size);
+                }
+                placement
             } else if let Some(npc_id) = module_npc_id {
                 NewTokenPlacement::for_npc(&new_id, map_id, npc_id, token.grid_x, token.grid_y)
             } else {
@@ -1014,6 +1652,129 @@ impl<'a> ArchiveService<'a> {
             };
 
             dal::insert_token_placement(self.conn, &new_token)?;
+            id_maps.tokens.insert(token.id.clone(), new_id);
+        }
+        Ok(())
+    }
+
+    /// Import tags and their links. Links to anything that wasn't brought
+    /// along (e.g. player characters left out of a clone) are dropped.
+    fn import_tags(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        let mut tag_ids = HashMap::new();
+        for tag in &data.tags {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_tag = NewTag::new(&new_id, campaign_id, &tag.name);
+            if let Some(color) = tag.color.as_deref() {
+                new_tag = new_tag.with_color(color);
+            }
+            dal::insert_tag(self.conn, &new_tag)?;
+            tag_ids.insert(tag.id.clone(), new_id);
+        }
+
+        for link in &data.entity_tags {
+            let (Some(tag_id), Some(entity_type)) = (
+                tag_ids.get(&link.tag_id),
+                TaggableType::from_str(&link.entity_type),
+            ) else {
+                continue;
+            };
+            let entity_ids = match entity_type {
+                TaggableType::Document => &id_maps.documents,
+                TaggableType::Npc => &id_maps.characters,
+                TaggableType::Monster => &id_maps.module_monsters,
+                TaggableType::HomebrewMonster => &id_maps.homebrew_monsters,
+                TaggableType::Map => &id_maps.maps,
+                TaggableType::Asset => &id_maps.assets,
+            };
+            let Some(entity_id) = entity_ids.get(&link.entity_id) else {
+                continue;
+            };
+            dal::insert_entity_tag(
+                self.conn,
+                &NewEntityTag::new(tag_id, entity_type, entity_id),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Import quick actions, pointing their steps at the new maps, lights,
+    /// tokens, POIs, and traps. Steps whose target wasn't brought along are
+    /// dropped, and so is an action left with no steps.
+    fn import_quick_actions(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for action in &data.quick_actions {
+            let Ok(steps) = action.parse_steps() else {
+                warn!(quick_action_id = %action.id, "Skipping quick action with unreadable steps");
+                continue;
+            };
+            let steps: Vec<QuickActionStep> = steps
+                .into_iter()
+                .filter_map(|step| remap_quick_action_step(step, id_maps))
+                .collect();
+            if steps.is_empty() {
+                continue;
+            }
+            let steps = serde_json::to_string(&steps).map_err(|e| {
+                ServiceError::validation(format!("Failed to serialize steps: {}", e))
+            })?;
+
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_action = NewQuickAction::new(&new_id, campaign_id, &action.name, &steps)
+                .with_sort_order(action.sort_order);
+            if let Some(description) = action.description.as_deref() {
+                new_action = new_action.with_description(description);
+            }
+            dal::insert_quick_action(self.conn, &new_action)?;
+        }
+        Ok(())
+    }
+
+    /// Import reference pins. NPC and document pins whose character or
+    /// document wasn't brought along are dropped.
+    fn import_dm_reference_pins(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for pin in &data.dm_reference_pins {
+            let Some(panel_type) = PanelType::from_str(&pin.panel_type) else {
+                continue;
+            };
+            let ref_id = match (panel_type, pin.ref_id.as_ref()) {
+                (PanelType::Npc, Some(id)) => match id_maps.characters.get(id) {
+                    Some(new_id) => Some(new_id.as_str()),
+                    None => continue,
+                },
+                (PanelType::DocumentSection, Some(id)) => match id_maps.documents.get(id) {
+                    Some(new_id) => Some(new_id.as_str()),
+                    None => continue,
+                },
+                (_, ref_id) => ref_id.map(|s| s.as_str()),
+            };
+
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_pin = NewDmReferencePin::new(&new_id, campaign_id, panel_type, &pin.title)
+                .with_sort_order(pin.sort_order);
+            if let Some(ref_id) = ref_id {
+                new_pin = new_pin.with_ref(ref_id, pin.ref_source.as_deref());
+            }
+            if let Some(section) = pin.section.as_deref() {
+                new_pin = new_pin.with_section(section);
+            }
+            if let Some(content) = pin.content.as_deref() {
+                new_pin = new_pin.with_content(content);
+            }
+            dal::insert_dm_reference_pin(self.conn, &new_pin)?;
         }
         Ok(())
     }
@@ -1054,47 +1815,831 @@ impl<'a> ArchiveService<'a> {
         Ok(())
     }
 
-    fn import_homebrew_monsters(
+    fn import_safety_tools(&mut self, data: &ArchiveData, campaign_id: &str) -> ServiceResult<()> {
+        if let Some(ref tools) = data.safety_tools {
+            let mut new_tools = NewCampaignSafetyTools::new(campaign_id, &tools.updated_at)
+                .with_limits(&tools.lines, &tools.veils)
+                .with_x_card(tools.x_card_enabled, tools.x_card_signal.as_deref());
+            new_tools.open_door = tools.open_door;
+            new_tools.check_ins = tools.check_ins;
+            new_tools.notes = tools.notes.as_deref();
+            dal::upsert_campaign_safety_tools(self.conn, &new_tools)?;
+        }
+        Ok(())
+    }
+
+    fn import_memories(&mut self, data: &ArchiveData, campaign_id: &str) -> ServiceResult<()> {
+        for memory in &data.memories {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let new_memory =
+                NewCampaignMemory::new(&new_id, campaign_id, &memory.name, &memory.content);
+            dal::insert_campaign_memory(self.conn, &new_memory)?;
+        }
+        Ok(())
+    }
+
+    fn import_forage_ingredients(
         &mut self,
         data: &ArchiveData,
         campaign_id: &str,
-        id_maps: &mut IdMaps,
     ) -> ServiceResult<()> {
-        for monster in &data.homebrew_monsters {
+        for ingredient in &data.forage_ingredients {
             let new_id = uuid::Uuid::new_v4().to_string();
-            id_maps.homebrew_monsters.insert(monster.id.clone(), new_id.clone());
-            let mut new_monster =
-                NewCampaignHomebrewMonster::new(&new_id, campaign_id, &monster.name, &monster.data);
-            new_monster.cr = monster.cr.as_deref();
-            new_monster.creature_type = monster.creature_type.as_deref();
-            new_monster.size = monster.size.as_deref();
-            new_monster.cloned_from_name = monster.cloned_from_name.as_deref();
-            new_monster.cloned_from_source = monster.cloned_from_source.as_deref();
-            dal::insert_campaign_homebrew_monster(self.conn, &new_monster)?;
+            let new_ingredient = NewForageIngredient::new(
+                &new_id,
+                campaign_id,
+                &ingredient.environment,
+                &ingredient.item_name,
+                &ingredient.item_source,
+            )
+            .with_dc(ingredient.dc)
+            .with_quantity(&ingredient.quantity)
+            .with_weight(ingredient.weight);
+            dal::insert_forage_ingredient(self.conn, &new_ingredient)?;
         }
         Ok(())
     }
 
-    fn slugify(name: &str) -> String {
-        name.to_lowercase()
-            .chars()
-            .map(|c| if c.is_alphanumeric() { c } else { '-' })
-            .collect::<String>()
-            .split('-')
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-            .join("-")
-    }
-}
-
+    fn import_encounter_effects(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+    ) -> ServiceResult<()> {
+        for effect in &data.encounter_effects {
+            let (Some(module_id), Some(timing)) =
+                (id_maps.modules.get(&effect.module_id), effect.timing_enum())
+            else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let new_effect = NewEncounterEffect::new(
+                &new_id,
+                module_id,
+                &effect.name,
+                timing,
+                effect.timing_value,
+            )
+            .with_description(&effect.description);
+            dal::insert_encounter_effect(self.conn, &new_effect)?;
+        }
+        Ok(())
+    }
+
+    fn import_module_prep_checks(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+    ) -> ServiceResult<()> {
+        for check in &data.module_prep_checks {
+            let Some(module_id) = id_maps.modules.get(&check.module_id) else {
+                continue;
+            };
+            dal::insert_module_prep_check(
+                self.conn,
+                &NewModulePrepCheck::new(module_id, &check.task_key),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn import_rumors(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for rumor in &data.rumors {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_rumor =
+                NewRumor::new(&new_id, campaign_id, &rumor.text).with_truth(rumor.is_true());
+            if let Some(npc_id) = rumor
+                .source_npc_id
+                .as_ref()
+                .and_then(|id| id_maps.characters.get(id))
+            {
+                new_rumor = new_rumor.with_source_npc(npc_id);
+            }
+            if let Some(module_id) = rumor
+                .module_id
+                .as_ref()
+                .and_then(|id| id_maps.modules.get(id))
+            {
+                new_rumor = new_rumor.with_module(module_id);
+            }
+            if let Some(notes) = rumor.notes.as_deref() {
+                new_rumor = new_rumor.with_notes(notes);
+            }
+            dal::insert_rumor(self.conn, &new_rumor)?;
+        }
+        Ok(())
+    }
+
+    fn import_secret_notes(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for archived in &data.secret_notes {
+            let note = &archived.note;
+            let player_id =
+                match dal::get_player_by_name(self.conn, campaign_id, &archived.player_name)? {
+                    Some(player) => player.id,
+                    None => {
+                        let new_id = uuid::Uuid::new_v4().to_string();
+                        let player = NewPlayer::new(&new_id, campaign_id, &archived.player_name);
+                        dal::insert_player(self.conn, &player)?
+                    }
+                };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_note =
+                NewSecretNote::new(&new_id, campaign_id, &player_id, &note.title, &note.content);
+            if let Some(module_id) = note
+                .module_id
+                .as_ref()
+                .and_then(|id| id_maps.modules.get(id))
+            {
+                new_note = new_note.with_module(module_id);
+            }
+            dal::insert_secret_note(self.conn, &new_note)?;
+            if let Some(delivered_at) = note.delivered_at.as_deref() {
+                let update = UpdateSecretNote {
+                    delivered_at: Some(Some(delivered_at)),
+                    ..Default::default()
+                };
+                dal::update_secret_note(self.conn, &new_id, &update)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Import parties and their members. Members whose characters weren't
+    /// brought along are dropped.
+    fn import_parties(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &mut IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for party in &data.parties {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_party = NewParty::new(&new_id, campaign_id, &party.name);
+            if let Some(description) = party.description.as_deref() {
+                new_party = new_party.with_description(description);
+            }
+            if let Some(color) = party.color.as_deref() {
+                new_party = new_party.with_color(color);
+            }
+            dal::insert_party(self.conn, &new_party)?;
+
+            let update = UpdateParty {
+                current_day: Some(party.current_day),
+                ..Default::default()
+            };
+            dal::update_party(self.conn, &new_id, &update)?;
+            id_maps.parties.insert(party.id.clone(), new_id);
+        }
+
+        for member in &data.party_members {
+            let (Some(party_id), Some(character_id)) = (
+                id_maps.parties.get(&member.party_id),
+                id_maps.characters.get(&member.character_id),
+            ) else {
+                continue;
+            };
+            dal::insert_party_member(self.conn, &NewPartyMember::new(party_id, character_id))?;
+        }
+        Ok(())
+    }
+
+    /// Import game sessions. A session still running when the archive was
+    /// made is ended at its last update, so the copy doesn't keep a clock going.
+    fn import_game_sessions(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &mut IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for session in &data.game_sessions {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_session = NewGameSession::new(&new_id, campaign_id, session.session_number)
+                .with_started_at(&session.started_at);
+            if let Some(module_id) = session
+                .module_id
+                .as_ref()
+                .and_then(|id| id_maps.modules.get(id))
+            {
+                new_session = new_session.with_module(module_id);
+            }
+            if let Some(title) = session.title.as_deref() {
+                new_session = new_session.with_title(title);
+            }
+            if let Some(party_id) = session
+                .party_id
+                .as_ref()
+                .and_then(|id| id_maps.parties.get(id))
+            {
+                new_session = new_session.with_party(party_id);
+            }
+            dal::insert_game_session(self.conn, &new_session)?;
+
+            let ended_at = session.ended_at.as_deref().unwrap_or(&session.updated_at);
+            let update = UpdateGameSession {
+                ended_at: Some(Some(ended_at)),
+                play_seconds: Some(session.play_seconds),
+                break_seconds: Some(session.break_seconds),
+                combat_turns: Some(session.combat_turns),
+                combat_turn_seconds: Some(session.combat_turn_seconds),
+                longest_turn_seconds: Some(session.longest_turn_seconds),
+                xp_awarded: Some(session.xp_awarded),
+                transcript: Some(session.transcript.as_deref()),
+                ..Default::default()
+            };
+            dal::update_game_session(self.conn, &new_id, &update)?;
+            id_maps.game_sessions.insert(session.id.clone(), new_id);
+        }
+        Ok(())
+    }
+
+    fn import_party_timeline(&mut self, data: &ArchiveData, id_maps: &IdMaps) -> ServiceResult<()> {
+        for event in &data.party_timeline {
+            let Some(party_id) = id_maps.parties.get(&event.party_id) else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_event =
+                NewPartyTimelineEvent::new(&new_id, party_id, event.day, &event.title);
+            if let Some(session_id) = event
+                .session_id
+                .as_ref()
+                .and_then(|id| id_maps.game_sessions.get(id))
+            {
+                new_event = new_event.with_session(session_id);
+            }
+            if let Some(description) = event.description.as_deref() {
+                new_event = new_event.with_description(description);
+            }
+            dal::insert_party_timeline_event(self.conn, &new_event)?;
+        }
+        Ok(())
+    }
+
+    fn import_relationships(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for disposition in &data.dispositions {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let new_disposition = match (&disposition.character_id, &disposition.faction) {
+                (Some(character_id), _) => {
+                    let Some(character_id) = id_maps.characters.get(character_id) else {
+                        continue;
+                    };
+                    NewDisposition::for_npc(
+                        &new_id,
+                        campaign_id,
+                        character_id,
+                        disposition.disposition,
+                    )
+                }
+                (None, Some(faction)) => NewDisposition::for_faction(
+                    &new_id,
+                    campaign_id,
+                    faction,
+                    disposition.disposition,
+                ),
+                (None, None) => continue,
+            };
+            dal::insert_disposition(self.conn, &new_disposition)?;
+        }
+
+        for reputation in &data.party_reputations {
+            let Some(party_id) = id_maps.parties.get(&reputation.party_id) else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let new_reputation = NewPartyReputation::new(
+                &new_id,
+                campaign_id,
+                party_id,
+                &reputation.faction,
+                reputation.reputation,
+            );
+            dal::insert_party_reputation(self.conn, &new_reputation)?;
+        }
+
+        for interaction in &data.npc_interactions {
+            let (Some(character_id), Some(dice), Some(attitude)) = (
+                id_maps.characters.get(&interaction.character_id),
+                interaction.dice_enum(),
+                interaction.attitude_enum(),
+            ) else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_interaction = NewNpcInteraction::new(
+                &new_id,
+                campaign_id,
+                character_id,
+                dice,
+                interaction.roll,
+                interaction.modifier,
+                attitude,
+            )
+            .with_created_at(&interaction.created_at);
+            if let Some(party_id) = interaction
+                .party_id
+                .as_ref()
+                .and_then(|id| id_maps.parties.get(id))
+            {
+                new_interaction = new_interaction.with_party(party_id);
+            }
+            if let Some(session_id) = interaction
+                .session_id
+                .as_ref()
+                .and_then(|id| id_maps.game_sessions.get(id))
+            {
+                new_interaction = new_interaction.with_session(session_id);
+            }
+            if let Some(notes) = interaction.notes.as_deref() {
+                new_interaction = new_interaction.with_notes(notes);
+            }
+            dal::insert_npc_interaction(self.conn, &new_interaction)?;
+        }
+        Ok(())
+    }
+
+    fn import_handouts(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        let mut handout_ids = HashMap::new();
+        for handout in &data.handouts {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_handout = NewHandout::new(&new_id, campaign_id, &handout.title)
+                .with_sort_order(handout.sort_order);
+            if let Some(image_path) = handout.image_path.as_deref() {
+                new_handout = new_handout.with_image_path(image_path);
+            } else if let Some(asset_id) = handout.asset_id.as_ref() {
+                let Some(asset_id) = id_maps.assets.get(asset_id) else {
+                    continue;
+                };
+                new_handout = new_handout.with_asset(asset_id);
+            } else if let Some(document_id) = handout.document_id.as_ref() {
+                let Some(document_id) = id_maps.documents.get(document_id) else {
+                    continue;
+                };
+                new_handout = new_handout.with_document(document_id);
+            }
+            dal::insert_handout(self.conn, &new_handout)?;
+
+            if let Some(revealed_at) = handout.revealed_at.as_deref() {
+                let update = UpdateHandout {
+                    revealed_at: Some(Some(revealed_at)),
+                    ..Default::default()
+                };
+                dal::update_handout(self.conn, &new_id, &update)?;
+            }
+            handout_ids.insert(handout.id.clone(), new_id);
+        }
+
+        for reveal in &data.handout_reveals {
+            let Some(handout_id) = handout_ids.get(&reveal.handout_id) else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_reveal = NewHandoutReveal::new(&new_id, handout_id, &reveal.revealed_at);
+            if let Some(session_id) = reveal
+                .session_id
+                .as_ref()
+                .and_then(|id| id_maps.game_sessions.get(id))
+            {
+                new_reveal = new_reveal.with_session(session_id);
+            }
+            dal::insert_handout_reveal(self.conn, &new_reveal)?;
+        }
+        Ok(())
+    }
+
+    fn import_module_links(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for link in &data.module_links {
+            let (Some(from_id), Some(to_id), Some(link_type)) = (
+                id_maps.modules.get(&link.from_module_id),
+                id_maps.modules.get(&link.to_module_id),
+                link.link_type_enum(),
+            ) else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_link = NewModuleLink::new(&new_id, campaign_id, from_id, to_id, link_type);
+            if let Some(note) = link.note.as_deref() {
+                new_link = new_link.with_note(note);
+            }
+            dal::insert_module_link(self.conn, &new_link)?;
+            if link.is_resolved() {
+                let update = UpdateModuleLink {
+                    resolved: Some(1),
+                    ..Default::default()
+                };
+                dal::update_module_link(self.conn, &new_id, &update)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn import_custom_fields(&mut self, data: &ArchiveData, campaign_id: &str) -> ServiceResult<()> {
+        for field in &data.custom_fields {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let field_type = field.field_type_enum().ok_or_else(|| {
+                ServiceError::validation(format!(
+                    "Unknown custom field type '{}'",
+                    field.field_type
+                ))
+            })?;
+            let mut new_field = NewCampaignCustomField::new(
+                &new_id,
+                campaign_id,
+                &field.field_key,
+                &field.label,
+                field_type,
+            )
+            .with_range(field.min_value, field.max_value)
+            .with_sort_order(field.sort_order);
+            new_field.default_value = field.default_value.as_deref();
+            new_field.description = field.description.as_deref();
+            dal::insert_campaign_custom_field(self.conn, &new_field)?;
+        }
+        Ok(())
+    }
+
+    fn import_stash(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        if let Some(ref currency) = data.stash_currency {
+            let new_currency = NewCampaignStashCurrency::new(
+                campaign_id,
+                currency.as_array(),
+                &currency.updated_at,
+            );
+            dal::upsert_campaign_stash_currency(self.conn, &new_currency)?;
+        }
+        for item in &data.stash_items {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_item = NewCampaignStashItem::new(
+                &new_id,
+                campaign_id,
+                &item.item_name,
+                &item.item_source,
+                item.quantity,
+            );
+            new_item.notes = item.notes.as_deref();
+            dal::insert_campaign_stash_item(self.conn, &new_item)?;
+        }
+        for entry in &data.stash_ledger {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let action = entry.action_enum().ok_or_else(|| {
+                ServiceError::validation(format!("Unknown stash ledger action '{}'", entry.action))
+            })?;
+            let mut new_entry = NewStashLedgerEntry::new(&new_id, campaign_id, action)
+                .with_currency(entry.currency())
+                .with_created_at(&entry.created_at);
+            new_entry.character_id = entry
+                .character_id
+                .as_ref()
+                .and_then(|id| id_maps.characters.get(id))
+                .map(String::as_str);
+            new_entry.character_name = entry.character_name.as_deref();
+            new_entry.item_name = entry.item_name.as_deref();
+            new_entry.item_source = entry.item_source.as_deref();
+            new_entry.quantity = entry.quantity;
+            new_entry.note = entry.note.as_deref();
+            dal::insert_stash_ledger_entry(self.conn, &new_entry)?;
+        }
+        Ok(())
+    }
+
+    fn import_homebrew_monsters(
+        &mut self,
+        data: &ArchiveData,
+        campaign_id: &str,
+        id_maps: &mut IdMaps,
+    ) -> ServiceResult<()> {
+        for monster in &data.homebrew_monsters {
+            let new_id = uuid::Uuid::new_v4().to_string();
+            id_maps.homebrew_monsters.insert(monster.id.clone(), new_id.clone());
+            let mut new_monster =
+                NewCampaignHomebrewMonster::new(&new_id, campaign_id, &monster.name, &monster.data);
+            new_monster.cr = monster.cr.as_deref();
+            new_monster.creature_type = monster.creature_type.as_deref();
+            new_monster.size = monster.size.as_deref();
+            new_monster.cloned_from_name = monster.cloned_from_name.as_deref();
+            new_monster.cloned_from_source = monster.cloned_from_source.as_deref();
+            dal::insert_campaign_homebrew_monster(self.conn, &new_monster)?;
+        }
+        Ok(())
+    }
+
+    fn import_companions(&mut self, data: &ArchiveData, id_maps: &IdMaps) -> ServiceResult<()> {
+        for char_data in &data.characters {
+            let Some(character_id) = id_maps.characters.get(&char_data.character.id) else {
+                continue;
+            };
+            for companion in &char_data.companions {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                let mut new_companion = NewCharacterCompanion::new(
+                    &new_id,
+                    character_id,
+                    &companion.name,
+                    companion
+                        .companion_type_enum()
+                        .unwrap_or(crate::models::campaign::CompanionType::Other),
+                    companion.max_hp,
+                );
+                new_companion.monster_name = companion.monster_name.as_deref();
+                new_companion.monster_source = companion.monster_source.as_deref();
+                new_companion.homebrew_monster_id = companion
+                    .homebrew_monster_id
+                    .as_ref()
+                    .and_then(|id| id_maps.homebrew_monsters.get(id))
+                    .map(String::as_str);
+                new_companion.sidekick_class = companion.sidekick_class.as_deref();
+                new_companion.level = companion.level;
+                new_companion.current_hp = companion.current_hp;
+                new_companion.notes = companion.notes.as_deref();
+                dal::insert_character_companion(self.conn, &new_companion)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn import_owned_vehicles(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+        campaign_id: &str,
+    ) -> ServiceResult<()> {
+        for char_data in &data.characters {
+            let Some(character_id) = id_maps.characters.get(&char_data.character.id) else {
+                continue;
+            };
+            for vehicle in &char_data.vehicles {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                let mut new_vehicle = NewOwnedVehicle::for_character(
+                    &new_id,
+                    campaign_id,
+                    character_id,
+                    &vehicle.name,
+                    vehicle.vehicle_kind_enum().unwrap_or(VehicleKind::Vehicle),
+                    &vehicle.catalog_name,
+                    &vehicle.catalog_source,
+                );
+                new_vehicle.notes = vehicle.notes.as_deref();
+                dal::insert_owned_vehicle(self.conn, &new_vehicle)?;
+            }
+        }
+        for vehicle in &data.party_vehicles {
+            let Some(party_id) = vehicle
+                .party_id
+                .as_ref()
+                .and_then(|id| id_maps.parties.get(id))
+            else {
+                continue;
+            };
+            let new_id = uuid::Uuid::new_v4().to_string();
+            let mut new_vehicle = NewOwnedVehicle::for_party(
+                &new_id,
+                campaign_id,
+                party_id,
+                &vehicle.name,
+                vehicle.vehicle_kind_enum().unwrap_or(VehicleKind::Vehicle),
+                &vehicle.catalog_name,
+                &vehicle.catalog_source,
+            );
+            new_vehicle.notes = vehicle.notes.as_deref();
+            dal::insert_owned_vehicle(self.conn, &new_vehicle)?;
+        }
+        Ok(())
+    }
+
+    fn import_crafting_projects(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+    ) -> ServiceResult<()> {
+        for char_data in &data.characters {
+            let Some(character_id) = id_maps.characters.get(&char_data.character.id) else {
+                continue;
+            };
+            for project in &char_data.crafting_projects {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                let mut new_project = NewCraftingProject::new(
+                    &new_id,
+                    character_id,
+                    &project.item_name,
+                    &project.item_source,
+                    project.cost_cp,
+                    project.days_required,
+                )
+                .with_quantity(project.quantity);
+                new_project.rarity = project.rarity.as_deref();
+                new_project.days_spent = project.days_spent;
+                new_project.tool = project.tool.as_deref();
+                new_project.ingredient = project.ingredient.as_deref();
+                new_project.ingredient_acquired = project.ingredient_acquired;
+                new_project.status = &project.status;
+                new_project.notes = project.notes.as_deref();
+                dal::insert_crafting_project(self.conn, &new_project)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn import_currency_transactions(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+    ) -> ServiceResult<()> {
+        for char_data in &data.characters {
+            let Some(character_id) = id_maps.characters.get(&char_data.character.id) else {
+                continue;
+            };
+            for transaction in &char_data.currency_transactions {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                let new_transaction = NewCurrencyTransaction::new(
+                    &new_id,
+                    character_id,
+                    &transaction.reason,
+                    transaction.amounts(),
+                )
+                .with_created_at(&transaction.created_at);
+                dal::insert_currency_transaction(self.conn, &new_transaction)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn import_character_deaths(
+        &mut self,
+        data: &ArchiveData,
+        id_maps: &IdMaps,
+    ) -> ServiceResult<()> {
+        for char_data in &data.characters {
+            let Some(character_id) = id_maps.characters.get(&char_data.character.id) else {
+                continue;
+            };
+            for death in &char_data.deaths {
+                let new_id = uuid::Uuid::new_v4().to_string();
+                let mut new_death = NewCharacterDeath::new(&new_id, character_id, &death.cause)
+                    .with_died_at(&death.died_at);
+                new_death.day = death.day;
+                new_death.party_id = death
+                    .party_id
+                    .as_ref()
+                    .and_then(|id| id_maps.parties.get(id))
+                    .map(|s| s.as_str());
+                new_death.notes = death.notes.as_deref();
+                dal::insert_character_death(self.conn, &new_death)?;
+
+                if death.resurrected_at.is_some() {
+                    let update = UpdateCharacterDeath {
+                        resurrection_method: Some(death.resurrection_method.as_deref()),
+                        resurrection_cost_cp: Some(death.resurrection_cost_cp),
+                        resurrection_day: Some(death.resurrection_day),
+                        resurrected_at: Some(death.resurrected_at.as_deref()),
+                    };
+                    dal::update_character_death(self.conn, &new_id, &update)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn slugify(name: &str) -> String {
+        name.to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+/// Point a quick action step at the imported map, light, token, POI, or
+/// trap, or `None` if its target wasn't imported.
+fn remap_quick_action_step(step: QuickActionStep, id_maps: &IdMaps) -> Option<QuickActionStep> {
+    let remap = |ids: &HashMap<String, String>, old_id: &str| ids.get(old_id).cloned();
+    Some(match step {
+        QuickActionStep::RevealRect {
+            map_id,
+            x,
+            y,
+            width,
+            height,
+        } => QuickActionStep::RevealRect {
+            map_id: remap(&id_maps.maps, &map_id)?,
+            x,
+            y,
+            width,
+            height,
+        },
+        QuickActionStep::RevealCircle {
+            map_id,
+            x,
+            y,
+            radius,
+        } => QuickActionStep::RevealCircle {
+            map_id: remap(&id_maps.maps, &map_id)?,
+            x,
+            y,
+            radius,
+        },
+        QuickActionStep::SetLight { light_id, active } => QuickActionStep::SetLight {
+            light_id: remap(&id_maps.light_sources, &light_id)?,
+            active,
+        },
+        QuickActionStep::SetTokenHidden { token_id, hidden } => QuickActionStep::SetTokenHidden {
+            token_id: remap(&id_maps.tokens, &token_id)?,
+            hidden,
+        },
+        QuickActionStep::SetPoiVisible { poi_id, visible } => QuickActionStep::SetPoiVisible {
+            poi_id: remap(&id_maps.pois, &poi_id)?,
+            visible,
+        },
+        QuickActionStep::SetTrapVisible { trap_id, visible } => QuickActionStep::SetTrapVisible {
+            trap_id: remap(&id_maps.traps, &trap_id)?,
+            visible,
+        },
+        other => other,
+    })
+}
+
+/// Where an archive extracted to `files_dir` keeps an asset's file.
+pub(super) fn archived_asset_path(files_dir: &Path, asset: &CampaignAsset) -> PathBuf {
+    files_dir.join("assets").join(&asset.id).join(&asset.filename)
+}
+
+/// Reader that hashes and counts the bytes read through it.
+pub(super) struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl<R: Read> HashingReader<R> {
+    pub(super) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    pub(super) fn finish(self, path: String) -> ArchiveChecksum {
+        let sha256 = self
+            .hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        ArchiveChecksum {
+            path,
+            size: self.size,
+            sha256,
+        }
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.size += n as u64;
+        Ok(n)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::dal::campaign::{insert_campaign, insert_module};
     use crate::models::campaign::{
-        NewCampaign, NewCampaignHomebrewItem, NewCampaignHomebrewMonster,
+        Attitude, NewCampaign, NewCampaignHomebrewItem, NewCampaignHomebrewMonster,
         NewCampaignHomebrewSpell, NewCharacterClass, NewCharacterInventory,
-        NewCharacterProficiency, NewModule, NewModuleMonster, NewModuleNpc,
+        NewCharacterProficiency, NewModule, NewModuleMonster, NewModuleNpc, ReactionDice,
     };
     use crate::services::document::{CreateDocumentInput, DocumentService};
     use crate::services::character::CharacterService;
@@ -1139,12 +2684,19 @@ mod tests {
                 player_name: None,
                 race_name: Some("Human".to_string()),
                 race_source: None,
+                subrace_name: None,
+                subrace_source: None,
+                racial_ability_choices: None,
                 background_name: None,
                 background_source: None,
                 ability_scores: None,
                 class_name: None,
                 class_source: None,
                 selected_skills: None,
+                traits: None,
+                ideals: None,
+                bonds: None,
+                flaws: None,
             };
             char_svc.create(input).expect("Failed to create character");
         }
@@ -1513,12 +3065,19 @@ mod tests {
                 player_name: None,
                 race_name: Some("Dwarf".to_string()),
                 race_source: Some("PHB".to_string()),
+                subrace_name: None,
+                subrace_source: None,
+                racial_ability_choices: None,
                 background_name: Some("Soldier".to_string()),
                 background_source: Some("PHB".to_string()),
                 ability_scores: Some([16, 10, 14, 8, 12, 13]),
                 class_name: Some("Fighter".to_string()),
                 class_source: Some("PHB".to_string()),
                 selected_skills: None,
+                traits: None,
+                ideals: None,
+                bonds: None,
+                flaws: None,
             };
             char_svc.create(input).expect("Failed to create character")
         };
@@ -1634,6 +3193,332 @@ mod tests {
         assert_eq!(spells[0].school.as_deref(), Some("V"));
     }
 
+    #[test]
+    fn test_safety_tools_round_trip() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let tools = NewCampaignSafetyTools::new(&campaign_id, "2024-01-01T00:00:00Z")
+            .with_limits(r#"["Spiders"]"#, r#"["Torture"]"#)
+            .with_x_card(true, Some("Tap the card"))
+            .with_notes("Check in with Sam before the drowning scene");
+        dal::upsert_campaign_safety_tools(&mut conn, &tools).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Safety Import"))
+                .unwrap()
+        };
+
+        let imported = dal::get_campaign_safety_tools(&mut conn, &import_result.campaign_id)
+            .unwrap()
+            .expect("safety tools should be imported");
+        assert_eq!(imported.parse_lines().unwrap(), vec!["Spiders"]);
+        assert_eq!(imported.parse_veils().unwrap(), vec!["Torture"]);
+        assert_eq!(imported.x_card_signal.as_deref(), Some("Tap the card"));
+        assert!(imported.notes.is_some());
+    }
+
+    #[test]
+    fn test_custom_fields_round_trip() {
+        use crate::models::campaign::CustomFieldType;
+
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let field = NewCampaignCustomField::new(
+            "field-1",
+            &campaign_id,
+            "sanity",
+            "Sanity",
+            CustomFieldType::Number,
+        )
+        .with_default("50")
+        .with_range(Some(0), Some(99));
+        dal::insert_campaign_custom_field(&mut conn, &field).unwrap();
+        let pc = NewCharacter::new_pc("char-1", Some(&campaign_id), "Ada", "Alice");
+        dal::insert_character(&mut conn, &pc).unwrap();
+        let value = NewCharacterCustomField::new("char-1", "sanity", "42", "2024-01-01T00:00:00Z");
+        dal::upsert_character_custom_field(&mut conn, &value).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Fields Import"))
+                .unwrap()
+        };
+
+        let fields =
+            dal::list_campaign_custom_fields(&mut conn, &import_result.campaign_id).unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].default_value.as_deref(), Some("50"));
+        assert_eq!(fields[0].max_value, Some(99));
+
+        let characters =
+            dal::list_campaign_characters(&mut conn, &import_result.campaign_id).unwrap();
+        let values = dal::list_character_custom_fields(&mut conn, &characters[0].id).unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].value, "42");
+    }
+
+    #[test]
+    fn test_stash_round_trip() {
+        use crate::models::campaign::LedgerAction;
+
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let pc = NewCharacter::new_pc("char-1", Some(&campaign_id), "Ada", "Alice");
+        dal::insert_character(&mut conn, &pc).unwrap();
+        let currency =
+            NewCampaignStashCurrency::new(&campaign_id, [0, 0, 0, 75, 2], "2024-01-01T00:00:00Z");
+        dal::upsert_campaign_stash_currency(&mut conn, &currency).unwrap();
+        let rope = NewCampaignStashItem::new("item-1", &campaign_id, "Rope, Hempen", "PHB", 2);
+        dal::insert_campaign_stash_item(&mut conn, &rope).unwrap();
+        let entry = NewStashLedgerEntry::new("led-1", &campaign_id, LedgerAction::Take)
+            .with_character("char-1", "Ada")
+            .with_item("Bag of Holding", "DMG", 1)
+            .with_created_at("2024-01-02T00:00:00Z");
+        dal::insert_stash_ledger_entry(&mut conn, &entry).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Stash Import"))
+                .unwrap()
+        };
+        let new_campaign_id = import_result.campaign_id;
+
+        let currency = dal::get_campaign_stash_currency(&mut conn, &new_campaign_id)
+            .unwrap()
+            .expect("stash coins should be imported");
+        assert_eq!(currency.as_array(), [0, 0, 0, 75, 2]);
+        let items = dal::list_campaign_stash_items(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].quantity, 2);
+
+        let characters = dal::list_campaign_characters(&mut conn, &new_campaign_id).unwrap();
+        let ledger = dal::list_stash_ledger(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(
+            ledger[0].character_id.as_deref(),
+            Some(characters[0].id.as_str())
+        );
+        assert_eq!(ledger[0].created_at, "2024-01-02T00:00:00Z");
+    }
+
+    #[test]
+    fn test_handouts_round_trip() {
+        let mut conn = setup_test_db();
+        let (campaign_id, _) = seed_campaign(&mut conn);
+        let documents = dal::list_campaign_documents(&mut conn, &campaign_id).unwrap();
+        let letter = NewHandout::new("handout-1", &campaign_id, "The Baron's Letter")
+            .with_document(&documents[0].id);
+        dal::insert_handout(&mut conn, &letter).unwrap();
+        let map = NewHandout::new("handout-2", &campaign_id, "Treasure Map")
+            .with_image_path("items/DMG/Treasure Map.webp")
+            .with_sort_order(1);
+        dal::insert_handout(&mut conn, &map).unwrap();
+        let update = UpdateHandout {
+            revealed_at: Some(Some("2024-01-02T00:00:00Z")),
+            ..Default::default()
+        };
+        dal::update_handout(&mut conn, "handout-2", &update).unwrap();
+        let reveal = NewHandoutReveal::new("reveal-1", "handout-2", "2024-01-02T00:00:00Z");
+        dal::insert_handout_reveal(&mut conn, &reveal).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Handout Import"))
+                .unwrap()
+        };
+        let new_campaign_id = import_result.campaign_id;
+
+        let handouts = dal::list_handouts(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(handouts.len(), 2);
+        let new_documents = dal::list_campaign_documents(&mut conn, &new_campaign_id).unwrap();
+        let letter = handouts.iter().find(|h| h.document_id.is_some()).unwrap();
+        assert_eq!(
+            letter.document_id.as_deref(),
+            Some(new_documents[0].id.as_str())
+        );
+        assert!(letter.is_queued());
+        let map = handouts.iter().find(|h| h.image_path.is_some()).unwrap();
+        assert_eq!(map.revealed_at.as_deref(), Some("2024-01-02T00:00:00Z"));
+
+        let reveals = dal::list_handout_reveals(&mut conn, &new_campaign_id, None).unwrap();
+        assert_eq!(reveals.len(), 1);
+        assert_eq!(reveals[0].handout_id, map.id);
+    }
+
+    #[test]
+    fn test_module_prep_checks_round_trip() {
+        let mut conn = setup_test_db();
+        let (campaign_id, module_id) = seed_campaign(&mut conn);
+        let check = NewModulePrepCheck::new(&module_id, "review-npcs");
+        dal::insert_module_prep_check(&mut conn, &check).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Prep Import"))
+                .unwrap()
+        };
+
+        let modules = dal::list_modules(&mut conn, &import_result.campaign_id).unwrap();
+        let checks = dal::list_module_prep_checks(&mut conn, &modules[0].id).unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].task_key, "review-npcs");
+    }
+
+    #[test]
+    fn test_secret_notes_round_trip() {
+        let mut conn = setup_test_db();
+        let (campaign_id, module_id) = seed_campaign(&mut conn);
+        let player = NewPlayer::new("player-1", &campaign_id, "Sam").with_email("sam@example.com");
+        dal::insert_player(&mut conn, &player).unwrap();
+        let note = NewSecretNote::new(
+            "note-1",
+            &campaign_id,
+            "player-1",
+            "Your Dreams",
+            "The voice calls you by your true name.",
+        )
+        .with_module(&module_id);
+        dal::insert_secret_note(&mut conn, &note).unwrap();
+        let update = UpdateSecretNote {
+            delivered_at: Some(Some("2024-01-02T00:00:00Z")),
+            ..Default::default()
+        };
+        dal::update_secret_note(&mut conn, "note-1", &update).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Notes Import"))
+                .unwrap()
+        };
+        let new_campaign_id = import_result.campaign_id;
+
+        let notes = dal::list_secret_notes(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(
+            notes[0].delivered_at.as_deref(),
+            Some("2024-01-02T00:00:00Z")
+        );
+        let modules = dal::list_modules(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(notes[0].module_id.as_deref(), Some(modules[0].id.as_str()));
+
+        // The recipient comes back by name, without contact details
+        let players = dal::list_players(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].id, notes[0].player_id);
+        assert_eq!(players[0].name, "Sam");
+        assert!(players[0].email.is_none());
+    }
+
+    #[test]
+    fn test_relationships_round_trip() {
+        let mut conn = setup_test_db();
+        let (campaign_id, _) = seed_campaign(&mut conn);
+        let npc = NewCharacter::new_npc("npc-1", Some(campaign_id.as_str()), "Toblen");
+        dal::insert_character(&mut conn, &npc).unwrap();
+        dal::insert_party(&mut conn, &NewParty::new("party-1", &campaign_id, "Heroes")).unwrap();
+        let npc_disposition = NewDisposition::for_npc("disp-1", &campaign_id, "npc-1", 2);
+        dal::insert_disposition(&mut conn, &npc_disposition).unwrap();
+        let faction = NewDisposition::for_faction("disp-2", &campaign_id, "Redbrands", -3);
+        dal::insert_disposition(&mut conn, &faction).unwrap();
+        let reputation = NewPartyReputation::new("rep-1", &campaign_id, "party-1", "Redbrands", -2);
+        dal::insert_party_reputation(&mut conn, &reputation).unwrap();
+        let interaction = NewNpcInteraction::new(
+            "int-1",
+            &campaign_id,
+            "npc-1",
+            ReactionDice::TwoD6,
+            9,
+            2,
+            Attitude::Friendly,
+        )
+        .with_party("party-1")
+        .with_notes("Offered a room at the inn")
+        .with_created_at("2024-01-02T00:00:00Z");
+        dal::insert_npc_interaction(&mut conn, &interaction).unwrap();
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let archive_path = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.export_campaign(&campaign_id, output_dir.path(), assets_dir.path())
+                .unwrap()
+        };
+        let import_result = {
+            let mut svc = ArchiveService::new(&mut conn);
+            svc.import_campaign(&archive_path, assets_dir.path(), Some("Reaction Import"))
+                .unwrap()
+        };
+        let new_campaign_id = import_result.campaign_id;
+
+        let characters = dal::list_campaign_characters(&mut conn, &new_campaign_id).unwrap();
+        let new_npc = characters.iter().find(|c| c.name == "Toblen").unwrap();
+        let new_party = dal::get_party_by_name(&mut conn, &new_campaign_id, "Heroes")
+            .unwrap()
+            .unwrap();
+
+        let dispositions = dal::list_dispositions(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(dispositions.len(), 2);
+        assert_eq!(dispositions[0].faction.as_deref(), Some("Redbrands"));
+        assert_eq!(
+            dispositions[1].character_id.as_deref(),
+            Some(new_npc.id.as_str())
+        );
+
+        let reputations = dal::list_party_reputations(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(reputations.len(), 1);
+        assert_eq!(reputations[0].party_id, new_party.id);
+        assert_eq!(reputations[0].reputation, -2);
+
+        let history = dal::list_npc_interactions(&mut conn, &new_npc.id).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].party_id.as_deref(), Some(new_party.id.as_str()));
+        assert_eq!(history[0].total, 11);
+        assert_eq!(history[0].created_at, "2024-01-02T00:00:00Z");
+    }
+
     #[test]
     fn test_character_details_round_trip() {
         let mut conn = setup_test_db();
@@ -1775,4 +3660,296 @@ mod tests {
         assert_eq!(import_result.counts.documents, 0);
         assert_eq!(import_result.counts.characters, 0);
     }
+
+    #[test]
+    fn test_export_streams_assets_with_progress_and_checksums() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let output_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+
+        let blob_path = format!("campaigns/{}/map.png", campaign_id);
+        fs::create_dir_all(assets_dir.path().join(format!("campaigns/{}", campaign_id))).unwrap();
+        fs::write(assets_dir.path().join(&blob_path), vec![7u8; 64 * 1024]).unwrap();
+        let asset_id = uuid::Uuid::new_v4().to_string();
+        dal::insert_campaign_asset(
+            &mut conn,
+            &NewCampaignAsset::for_campaign(
+                &asset_id,
+                &campaign_id,
+                "map.png",
+                "image/png",
+                &blob_path,
+            ),
+        )
+        .unwrap();
+
+        let mut progress = Vec::new();
+        let path = ArchiveService::new(&mut conn)
+            .export_campaign_with_progress(
+                &campaign_id,
+                output_dir.path(),
+                assets_dir.path(),
+                |p| progress.push(p.clone()),
+            )
+            .unwrap();
+
+        assert_eq!(progress.len(), 3);
+        let last = progress.last().unwrap();
+        assert_eq!(last.files_written, last.total_files);
+        assert_eq!(last.bytes_written, last.total_bytes);
+        assert_eq!(last.current_file, format!("assets/{}/map.png", asset_id));
+
+        let verification = ArchiveService::verify_archive(&path).unwrap();
+        assert_eq!(verification.files, 3);
+        assert_eq!(verification.bytes, last.total_bytes);
+
+        // Only the finished archive is left in the output directory
+        let entries: Vec<_> = fs::read_dir(output_dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_verify_detects_checksum_mismatch() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("tampered.tar.gz");
+
+        let checksums = ArchiveChecksums {
+            algorithm: "sha256".to_string(),
+            files: vec![ArchiveChecksum {
+                path: "data.json".to_string(),
+                size: 2,
+                sha256: "0".repeat(64),
+            }],
+        };
+        let checksums_json = serde_json::to_vec(&checksums).unwrap();
+
+        let gz = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+        let mut archive = Builder::new(gz);
+        ArchiveService::append_entry(&mut archive, "data.json", 2, &b"{}"[..]).unwrap();
+        ArchiveService::append_entry(
+            &mut archive,
+            CHECKSUMS_FILE,
+            checksums_json.len() as u64,
+            &checksums_json[..],
+        )
+        .unwrap();
+        archive.into_inner().unwrap().finish().unwrap();
+
+        let err = ArchiveService::verify_archive(&path).unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"));
+    }
+
+    #[test]
+    fn test_verify_requires_checksum_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("legacy.tar.gz");
+
+        let gz = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+        let mut archive = Builder::new(gz);
+        ArchiveService::append_entry(&mut archive, "manifest.json", 2, &b"{}"[..]).unwrap();
+        archive.into_inner().unwrap().finish().unwrap();
+
+        assert!(ArchiveService::verify_archive(&path).is_err());
+    }
+
+    #[test]
+    fn test_clone_campaign_copies_content_without_players() {
+        let mut conn = setup_test_db();
+        let (campaign_id, _) = seed_campaign(&mut conn);
+        let assets_dir = TempDir::new().unwrap();
+
+        let blob_path = format!("campaigns/{}/map.png", campaign_id);
+        fs::create_dir_all(assets_dir.path().join(format!("campaigns/{}", campaign_id))).unwrap();
+        fs::write(assets_dir.path().join(&blob_path), b"map").unwrap();
+        let asset_id = uuid::Uuid::new_v4().to_string();
+        dal::insert_campaign_asset(
+            &mut conn,
+            &NewCampaignAsset::for_campaign(
+                &asset_id,
+                &campaign_id,
+                "map.png",
+                "image/png",
+                &blob_path,
+            ),
+        )
+        .unwrap();
+
+        let result = ArchiveService::new(&mut conn)
+            .clone_campaign(
+                &campaign_id,
+                "Second Table",
+                &CloneCampaignOptions::default(),
+                assets_dir.path(),
+            )
+            .unwrap();
+
+        assert_ne!(result.campaign_id, campaign_id);
+        assert_eq!(result.campaign_name, "Second Table");
+        let modules = dal::list_modules(&mut conn, &result.campaign_id).unwrap();
+        assert_eq!(modules.len(), 1);
+        let docs = dal::list_campaign_documents(&mut conn, &result.campaign_id).unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].module_id, Some(modules[0].id.clone()));
+        let characters = dal::list_campaign_characters(&mut conn, &result.campaign_id).unwrap();
+        assert!(characters.is_empty());
+
+        // Assets are copied into the clone's own directory
+        let assets = dal::list_campaign_assets(&mut conn, &result.campaign_id).unwrap();
+        assert_eq!(assets.len(), 1);
+        assert!(assets[0].blob_path.contains(&result.campaign_id));
+        assert!(assets_dir.path().join(&assets[0].blob_path).exists());
+        assert!(assets_dir.path().join(&blob_path).exists());
+    }
+
+    #[test]
+    fn test_clone_campaign_with_player_characters() {
+        let mut conn = setup_test_db();
+        let (campaign_id, _) = seed_campaign(&mut conn);
+        let assets_dir = TempDir::new().unwrap();
+
+        let options = CloneCampaignOptions {
+            include_player_characters: Some(true),
+            include_npcs: None,
+        };
+        let result = ArchiveService::new(&mut conn)
+            .clone_campaign(&campaign_id, "Second Table", &options, assets_dir.path())
+            .unwrap();
+
+        let original = dal::list_campaign_characters(&mut conn, &campaign_id).unwrap();
+        let cloned = dal::list_campaign_characters(&mut conn, &result.campaign_id).unwrap();
+        assert_eq!(cloned.len(), 1);
+        assert_eq!(cloned[0].name, "Test Hero");
+        assert_ne!(cloned[0].id, original[0].id);
+    }
+
+    #[test]
+    fn test_remap_quick_action_step_drops_unresolved_targets() {
+        let mut id_maps = IdMaps::default();
+        id_maps
+            .maps
+            .insert("map-1".to_string(), "map-2".to_string());
+
+        let reveal = QuickActionStep::RevealCircle {
+            map_id: "map-1".to_string(),
+            x: 1.0,
+            y: 2.0,
+            radius: 3.0,
+        };
+        assert_eq!(
+            remap_quick_action_step(reveal, &id_maps),
+            Some(QuickActionStep::RevealCircle {
+                map_id: "map-2".to_string(),
+                x: 1.0,
+                y: 2.0,
+                radius: 3.0,
+            })
+        );
+
+        let light = QuickActionStep::SetLight {
+            light_id: "light-1".to_string(),
+            active: true,
+        };
+        assert_eq!(remap_quick_action_step(light, &id_maps), None);
+
+        let blackout = QuickActionStep::Blackout {
+            enabled: true,
+            screen: None,
+        };
+        assert_eq!(
+            remap_quick_action_step(blackout.clone(), &id_maps),
+            Some(blackout)
+        );
+    }
+
+    #[test]
+    fn test_clone_campaign_keeps_tags_parties_and_sessions() {
+        let mut conn = setup_test_db();
+        let (campaign_id, _) = seed_campaign(&mut conn);
+        let assets_dir = TempDir::new().unwrap();
+        let hero_id = dal::list_campaign_characters(&mut conn, &campaign_id).unwrap()[0]
+            .id
+            .clone();
+        let doc_id = dal::list_campaign_documents(&mut conn, &campaign_id).unwrap()[0]
+            .id
+            .clone();
+
+        dal::insert_tag(&mut conn, &NewTag::new("tag-1", &campaign_id, "Act 1")).unwrap();
+        dal::insert_entity_tag(
+            &mut conn,
+            &NewEntityTag::new("tag-1", TaggableType::Document, &doc_id),
+        )
+        .unwrap();
+
+        dal::insert_party(&mut conn, &NewParty::new("party-1", &campaign_id, "Heroes")).unwrap();
+        dal::insert_party_member(&mut conn, &NewPartyMember::new("party-1", &hero_id)).unwrap();
+
+        let session = NewGameSession::new("session-1", &campaign_id, 1)
+            .with_title("Into the Caves")
+            .with_party("party-1");
+        dal::insert_game_session(&mut conn, &session).unwrap();
+        let update = UpdateGameSession {
+            xp_awarded: Some(300),
+            ..Default::default()
+        };
+        dal::update_game_session(&mut conn, "session-1", &update).unwrap();
+        let event = NewPartyTimelineEvent::new("event-1", "party-1", 3, "Reached the caves")
+            .with_session("session-1");
+        dal::insert_party_timeline_event(&mut conn, &event).unwrap();
+
+        let options = CloneCampaignOptions {
+            include_player_characters: Some(true),
+            include_npcs: None,
+        };
+        let result = ArchiveService::new(&mut conn)
+            .clone_campaign(&campaign_id, "Second Table", &options, assets_dir.path())
+            .unwrap();
+        let new_campaign_id = result.campaign_id;
+
+        let tags = dal::list_tags(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name, "Act 1");
+        let new_doc_id = dal::list_campaign_documents(&mut conn, &new_campaign_id).unwrap()[0]
+            .id
+            .clone();
+        let tagged =
+            dal::list_tagged_entity_ids(&mut conn, &tags[0].id, TaggableType::Document).unwrap();
+        assert_eq!(tagged, vec![new_doc_id]);
+
+        let parties = dal::list_parties(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(parties.len(), 1);
+        let new_party_id = &parties[0].id;
+        assert_ne!(new_party_id, "party-1");
+        let members = dal::list_party_pcs(&mut conn, new_party_id).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].name, "Test Hero");
+        assert_ne!(members[0].id, hero_id);
+
+        let sessions = dal::list_game_sessions(&mut conn, &new_campaign_id).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].title.as_deref(), Some("Into the Caves"));
+        assert_eq!(sessions[0].party_id.as_ref(), Some(new_party_id));
+        assert_eq!(sessions[0].xp_awarded, 300);
+        // The source session was still running; the copy isn't
+        assert!(!sessions[0].is_active());
+
+        let timeline = dal::list_party_timeline_events(&mut conn, new_party_id).unwrap();
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(timeline[0].session_id.as_ref(), Some(&sessions[0].id));
+    }
+
+    #[test]
+    fn test_clone_campaign_requires_name() {
+        let mut conn = setup_test_db();
+        let (campaign_id, _) = seed_campaign(&mut conn);
+        let assets_dir = TempDir::new().unwrap();
+
+        let result = ArchiveService::new(&mut conn).clone_campaign(
+            &campaign_id,
+            "  ",
+            &CloneCampaignOptions::default(),
+            assets_dir.path(),
+        );
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+    }
 }
@@ -0,0 +1,388 @@
+//! Background Options
+//!
+//! Reads what a background offers a new character from its catalog JSON:
+//! the background feature, the proficiencies it grants (and the ones the
+//! player picks), and the suggested characteristics tables for personality
+//! traits, ideals, bonds, and flaws.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::character::extract_keyed_proficiencies;
+use crate::dal::catalog as catalog_dal;
+use crate::fts::{flatten_entries, flatten_entry, strip_5etools_tags};
+use crate::models::campaign::ProficiencyType;
+use crate::services::{ServiceError, ServiceResult};
+
+/// A background's feature (e.g., Shelter of the Faithful).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackgroundFeature {
+    /// Feature name, without the "Feature:" prefix
+    pub name: String,
+    /// Feature rules text
+    pub text: String,
+}
+
+/// Characteristics rolled from a background's tables.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RolledCharacteristics {
+    /// Two different personality traits
+    pub personality_traits: Vec<String>,
+    pub ideal: Option<String>,
+    pub bond: Option<String>,
+    pub flaw: Option<String>,
+}
+
+/// What a background offers a character at creation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackgroundOptions {
+    pub name: String,
+    pub source: String,
+    /// Background feature, if the data marks one
+    pub feature: Option<BackgroundFeature>,
+    /// Skill proficiencies granted outright
+    pub skill_proficiencies: Vec<String>,
+    /// Tool proficiencies granted outright
+    pub tool_proficiencies: Vec<String>,
+    /// Languages granted outright
+    pub language_proficiencies: Vec<String>,
+    /// Proficiency picks left to the player (e.g., "Choose 2 languages")
+    pub proficiency_choices: Vec<String>,
+    /// Suggested personality traits table
+    pub personality_traits: Vec<String>,
+    /// Suggested ideals table
+    pub ideals: Vec<String>,
+    /// Suggested bonds table
+    pub bonds: Vec<String>,
+    /// Suggested flaws table
+    pub flaws: Vec<String>,
+    /// Characteristics rolled from the tables, when requested
+    pub rolled: Option<RolledCharacteristics>,
+}
+
+impl BackgroundOptions {
+    /// Parse options from a background's catalog JSON.
+    pub fn from_data(name: &str, source: &str, data: &Value) -> Self {
+        let mut options = Self {
+            name: name.to_string(),
+            source: source.to_string(),
+            ..Default::default()
+        };
+
+        let keyed = [
+            (
+                "skillProficiencies",
+                ProficiencyType::Skill,
+                "skill",
+                "skills",
+            ),
+            ("toolProficiencies", ProficiencyType::Tool, "tool", "tools"),
+            (
+                "languageProficiencies",
+                ProficiencyType::Language,
+                "language",
+                "languages",
+            ),
+        ];
+        for (field, prof_type, singular, plural) in keyed {
+            let items = match data.get(field).and_then(|v| v.as_array()) {
+                Some(items) => items,
+                None => continue,
+            };
+            let names = extract_keyed_proficiencies(items, prof_type)
+                .into_iter()
+                .map(|p| p.name);
+            match prof_type {
+                ProficiencyType::Skill => options.skill_proficiencies.extend(names),
+                ProficiencyType::Tool => options.tool_proficiencies.extend(names),
+                _ => options.language_proficiencies.extend(names),
+            }
+            options
+                .proficiency_choices
+                .extend(choice_notes(items, singular, plural));
+        }
+
+        if let Some(entries) = data.get("entries").and_then(|v| v.as_array()) {
+            options.feature = find_feature(entries);
+            for entry in entries {
+                collect_characteristics(entry, &mut options);
+            }
+        }
+
+        options
+    }
+
+    /// Roll two different personality traits and one ideal, bond, and flaw.
+    pub fn roll_characteristics(&self) -> RolledCharacteristics {
+        let mut personality_traits = Vec::new();
+        if let Some(first) = pick(&self.personality_traits) {
+            personality_traits.push(first.clone());
+            let rest: Vec<String> = self
+                .personality_traits
+                .iter()
+                .filter(|t| *t != first)
+                .cloned()
+                .collect();
+            if let Some(second) = pick(&rest) {
+                personality_traits.push(second.clone());
+            }
+        }
+
+        RolledCharacteristics {
+            personality_traits,
+            ideal: pick(&self.ideals).cloned(),
+            bond: pick(&self.bonds).cloned(),
+            flaw: pick(&self.flaws).cloned(),
+        }
+    }
+}
+
+/// Describe the picks a proficiency list leaves to the player.
+fn choice_notes(items: &[Value], singular: &str, plural: &str) -> Vec<String> {
+    let label = |count: u64| if count == 1 { singular } else { plural };
+    let mut notes = Vec::new();
+    for obj in items.iter().filter_map(|v| v.as_object()) {
+        for (key, value) in obj {
+            if key == "choose" {
+                let count = value.get("count").and_then(|v| v.as_u64()).unwrap_or(1);
+                let from: Vec<String> = value
+                    .get("from")
+                    .and_then(|v| v.as_array())
+                    .map(|a| {
+                        extract_keyed_proficiencies(a, ProficiencyType::Skill)
+                            .into_iter()
+                            .map(|p| p.name)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                notes.push(format!(
+                    "Choose {} {} from: {}",
+                    count,
+                    label(count),
+                    from.join(", ")
+                ));
+            } else if key.starts_with("any") {
+                let count = value.as_u64().unwrap_or(1);
+                notes.push(format!("Choose {} {}", count, label(count)));
+            }
+        }
+    }
+    notes
+}
+
+/// Find the background feature: an entry flagged `isFeature`, or one named
+/// "Feature: ...".
+fn find_feature(entries: &[Value]) -> Option<BackgroundFeature> {
+    for entry in entries {
+        let obj = match entry.as_object() {
+            Some(obj) => obj,
+            None => continue,
+        };
+        let name = obj.get("name").and_then(|v| v.as_str()).unwrap_or("");
+        let flagged = obj
+            .get("data")
+            .and_then(|d| d.get("isFeature"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if flagged || name.starts_with("Feature:") {
+            let text = obj
+                .get("entries")
+                .and_then(|v| v.as_array())
+                .map(|e| strip_5etools_tags(&flatten_entries(e)))
+                .unwrap_or_default();
+            return Some(BackgroundFeature {
+                name: name.trim_start_matches("Feature:").trim().to_string(),
+                text,
+            });
+        }
+        if let Some(children) = obj.get("entries").and_then(|v| v.as_array()) {
+            if let Some(feature) = find_feature(children) {
+                return Some(feature);
+            }
+        }
+    }
+    None
+}
+
+/// Collect the suggested characteristics tables, found anywhere in the
+/// entries and told apart by their last column label.
+fn collect_characteristics(entry: &Value, options: &mut BackgroundOptions) {
+    let obj = match entry.as_object() {
+        Some(obj) => obj,
+        None => return,
+    };
+
+    if obj.get("type").and_then(|v| v.as_str()) == Some("table") {
+        let label = obj
+            .get("colLabels")
+            .and_then(|v| v.as_array())
+            .and_then(|labels| labels.last())
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_lowercase();
+        let table = if label.contains("personality trait") {
+            &mut options.personality_traits
+        } else if label.contains("ideal") {
+            &mut options.ideals
+        } else if label.contains("bond") {
+            &mut options.bonds
+        } else if label.contains("flaw") {
+            &mut options.flaws
+        } else {
+            return;
+        };
+
+        if let Some(rows) = obj.get("rows").and_then(|v| v.as_array()) {
+            for row in rows {
+                if let Some(cell) = row.as_array().and_then(|cells| cells.last()) {
+                    let text = strip_5etools_tags(&flatten_entry(cell));
+                    if !text.is_empty() {
+                        table.push(text);
+                    }
+                }
+            }
+        }
+        return;
+    }
+
+    if let Some(entries) = obj.get("entries").and_then(|v| v.as_array()) {
+        for child in entries {
+            collect_characteristics(child, options);
+        }
+    }
+}
+
+/// Pick a random entry from a table.
+fn pick(table: &[String]) -> Option<&String> {
+    if table.is_empty() {
+        return None;
+    }
+    let bytes = *Uuid::new_v4().as_bytes();
+    let roll = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+    table.get(roll % table.len())
+}
+
+/// Load a background's options from the catalog.
+pub fn get_background_options(
+    conn: &mut SqliteConnection,
+    name: &str,
+    source: &str,
+) -> ServiceResult<BackgroundOptions> {
+    let background = catalog_dal::get_background_by_name(conn, name, source)?
+        .ok_or_else(|| ServiceError::not_found("Background", format!("{} ({})", name, source)))?;
+    let data: Value = serde_json::from_str(&background.data).unwrap_or_default();
+    Ok(BackgroundOptions::from_data(
+        &background.name,
+        &background.source,
+        &data,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::catalog::insert_background;
+    use crate::models::catalog::NewBackground;
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    fn acolyte() -> Value {
+        json!({
+            "name": "Acolyte",
+            "skillProficiencies": [{"insight": true, "religion": true}],
+            "languageProficiencies": [{"anyStandard": 2}],
+            "entries": [
+                {
+                    "type": "entries",
+                    "name": "Feature: Shelter of the Faithful",
+                    "data": {"isFeature": true},
+                    "entries": ["You command the respect of those who share your {@deity faith}."]
+                },
+                {
+                    "type": "entries",
+                    "name": "Suggested Characteristics",
+                    "entries": [
+                        {
+                            "type": "table",
+                            "colLabels": ["{@dice d8}", "Personality Trait"],
+                            "rows": [["1", "I idolize a hero."], ["2", "I quote sacred texts."]]
+                        },
+                        {
+                            "type": "table",
+                            "colLabels": ["{@dice d6}", "Ideal"],
+                            "rows": [["1", "Tradition. Ancient traditions must be kept. (Lawful)"]]
+                        },
+                        {
+                            "type": "table",
+                            "colLabels": ["{@dice d6}", "Bond"],
+                            "rows": [["1", "I would die to recover a relic."]]
+                        },
+                        {
+                            "type": "table",
+                            "colLabels": ["{@dice d6}", "Flaw"],
+                            "rows": [["1", "I judge others harshly."]]
+                        }
+                    ]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_parse_background_options() {
+        let options = BackgroundOptions::from_data("Acolyte", "PHB", &acolyte());
+
+        let feature = options.feature.unwrap();
+        assert_eq!(feature.name, "Shelter of the Faithful");
+        assert_eq!(
+            feature.text,
+            "You command the respect of those who share your faith."
+        );
+        assert_eq!(options.skill_proficiencies, vec!["Insight", "Religion"]);
+        assert!(options.language_proficiencies.is_empty());
+        assert_eq!(options.proficiency_choices, vec!["Choose 2 languages"]);
+        assert_eq!(options.personality_traits.len(), 2);
+        assert_eq!(options.ideals.len(), 1);
+        assert_eq!(options.bonds.len(), 1);
+        assert_eq!(options.flaws, vec!["I judge others harshly."]);
+    }
+
+    #[test]
+    fn test_roll_characteristics() {
+        let options = BackgroundOptions::from_data("Acolyte", "PHB", &acolyte());
+        let rolled = options.roll_characteristics();
+
+        // Only two traits in the table, so both are picked
+        assert_eq!(rolled.personality_traits.len(), 2);
+        assert_ne!(rolled.personality_traits[0], rolled.personality_traits[1]);
+        assert_eq!(
+            rolled.ideal.as_deref(),
+            Some("Tradition. Ancient traditions must be kept. (Lawful)")
+        );
+        assert!(rolled.bond.is_some());
+        assert!(rolled.flaw.is_some());
+
+        let empty = BackgroundOptions::default().roll_characteristics();
+        assert!(empty.personality_traits.is_empty());
+        assert!(empty.ideal.is_none());
+    }
+
+    #[test]
+    fn test_get_background_options() {
+        let mut conn = setup_test_db_with_sources();
+        insert_background(
+            &mut conn,
+            &NewBackground::new("Acolyte", "PHB", &acolyte().to_string()),
+        )
+        .unwrap();
+
+        let options = get_background_options(&mut conn, "acolyte", "PHB").unwrap();
+        assert_eq!(options.name, "Acolyte");
+        assert!(options.feature.is_some());
+
+        let missing = get_background_options(&mut conn, "Sage", "PHB");
+        assert!(matches!(missing, Err(ServiceError::NotFound { .. })));
+    }
+}
@@ -0,0 +1,699 @@
+//! Campaign Backup Service
+//!
+//! Full and differential campaign backups. A full backup stores every entity
+//! and asset file of a campaign; a differential backup stores only the
+//! entities and files that changed since the previous backup. Each campaign's
+//! backups live under `<app data>/backups/<campaign id>/` beside a
+//! `chain.json` manifest recording their order, checksums, and the hashes
+//! used to detect changes, so a campaign can be restored from its last full
+//! backup plus the differential backups after it.
+
+use chrono::{DateTime, Utc};
+use diesel::SqliteConnection;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Read};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tar::{Archive, Builder};
+use tracing::{info, warn};
+
+use crate::dal::campaign as dal;
+use crate::encryption;
+use crate::models::campaign::CampaignAsset;
+use crate::services::archive::{archived_asset_path, HashingReader};
+use crate::services::{ArchiveData, ArchiveService, ImportResult, ServiceError, ServiceResult};
+
+/// Directory under the app data directory holding one backup chain per campaign.
+pub const BACKUP_DIR: &str = "backups";
+/// File extension for backup files
+pub const BACKUP_EXTENSION: &str = ".mimir-backup.tar.gz";
+
+/// Chain manifest in each campaign's backup directory
+const CHAIN_FILE: &str = "chain.json";
+/// Entity changes, the first entry of each backup file
+const CONTENTS_FILE: &str = "backup.json";
+
+/// `ArchiveData` fields holding lists of entities. Every other field holds a
+/// single entity.
+const ENTITY_LISTS: [&str; 12] = [
+    "sources",
+    "modules",
+    "documents",
+    "characters",
+    "maps",
+    "tokens",
+    "module_monsters",
+    "module_npcs",
+    "assets",
+    "homebrew_items",
+    "homebrew_monsters",
+    "homebrew_spells",
+];
+
+/// Whether a backup stands alone or builds on the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    Full,
+    Differential,
+}
+
+/// A backup in a campaign's chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupEntry {
+    pub id: String,
+    pub kind: BackupKind,
+    /// Backup this one builds on; `None` for full backups
+    pub parent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// File name within the campaign's backup directory
+    pub file: String,
+    pub size_bytes: u64,
+    pub sha256: String,
+    pub changed_entities: usize,
+    pub removed_entities: usize,
+    pub changed_assets: usize,
+    pub removed_assets: usize,
+}
+
+/// Chain manifest of a campaign's backups.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupChain {
+    pub campaign_id: String,
+    pub campaign_name: String,
+    /// Backups, oldest first
+    pub backups: Vec<BackupEntry>,
+    /// Entity hashes as of the latest backup
+    #[serde(default)]
+    entity_hashes: BTreeMap<String, String>,
+    /// Asset files as of the latest backup, by asset ID
+    #[serde(default)]
+    assets: BTreeMap<String, AssetState>,
+}
+
+/// An asset file as last backed up.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct AssetState {
+    filename: String,
+    size: u64,
+    /// Modification time in milliseconds since the epoch, when known
+    modified_ms: Option<u64>,
+    sha256: String,
+}
+
+/// Entity changes stored in a backup file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BackupContents {
+    /// Changed or added entities by key
+    changed: BTreeMap<String, Value>,
+    /// Keys of removed entities
+    removed: Vec<String>,
+    /// IDs of assets whose files are in this backup
+    changed_assets: Vec<String>,
+    /// IDs of assets removed since the previous backup
+    removed_assets: Vec<String>,
+}
+
+/// Service for full and differential campaign backups.
+pub struct BackupService<'a> {
+    conn: &'a mut SqliteConnection,
+    backup_dir: PathBuf,
+}
+
+impl<'a> BackupService<'a> {
+    /// Create a new backup service.
+    pub fn new(conn: &'a mut SqliteConnection, app_data_dir: impl AsRef<Path>) -> Self {
+        Self {
+            conn,
+            backup_dir: app_data_dir.as_ref().join(BACKUP_DIR),
+        }
+    }
+
+    /// List a campaign's backups, oldest first.
+    pub fn list(&self, campaign_id: &str) -> ServiceResult<Vec<BackupEntry>> {
+        Ok(self.read_chain(campaign_id)?.backups)
+    }
+
+    /// Back up a campaign.
+    ///
+    /// A differential backup stores only what changed since the campaign's
+    /// previous backup; when there is no previous backup a full backup is
+    /// made instead.
+    pub fn create(
+        &mut self,
+        campaign_id: &str,
+        kind: BackupKind,
+        assets_dir: &Path,
+    ) -> ServiceResult<BackupEntry> {
+        encryption::ensure_unencrypted(self.conn, "Campaign backup")?;
+        let campaign = dal::get_campaign_optional(self.conn, campaign_id)?
+            .ok_or_else(|| ServiceError::not_found("Campaign", campaign_id))?;
+        let mut chain = self.read_chain(campaign_id)?;
+        let kind = if chain.backups.is_empty() {
+            BackupKind::Full
+        } else {
+            kind
+        };
+
+        chain.campaign_id = campaign_id.to_string();
+        chain.campaign_name = campaign.name.clone();
+        let data = ArchiveService::new(self.conn).collect_campaign_data(campaign_id, campaign)?;
+
+        let (previous_hashes, previous_assets) = match kind {
+            BackupKind::Full => (BTreeMap::new(), BTreeMap::new()),
+            BackupKind::Differential => (
+                std::mem::take(&mut chain.entity_hashes),
+                std::mem::take(&mut chain.assets),
+            ),
+        };
+
+        // Entities
+        let entities = flatten(&data)?;
+        let mut contents = BackupContents::default();
+        let mut entity_hashes = BTreeMap::new();
+        for (key, value) in entities {
+            let hash = hash_value(&value)?;
+            if previous_hashes.get(&key) != Some(&hash) {
+                contents.changed.insert(key.clone(), value);
+            }
+            entity_hashes.insert(key, hash);
+        }
+        contents.removed = previous_hashes
+            .keys()
+            .filter(|key| !entity_hashes.contains_key(*key))
+            .cloned()
+            .collect();
+
+        // Asset files
+        let mut assets = BTreeMap::new();
+        let mut asset_files = Vec::new();
+        for asset in &data.assets {
+            let path = assets_dir.join(&asset.blob_path);
+            let state = match asset_state(&path, &asset.filename, previous_assets.get(&asset.id)) {
+                Ok(state) => state,
+                Err(e) => {
+                    warn!(
+                        asset_id = %asset.id,
+                        path = %path.display(),
+                        error = %e,
+                        "Skipping asset file"
+                    );
+                    continue;
+                }
+            };
+            if previous_assets.get(&asset.id).map(|prev| &prev.sha256) != Some(&state.sha256) {
+                contents.changed_assets.push(asset.id.clone());
+                asset_files.push((
+                    format!("assets/{}/{}", asset.id, asset.filename),
+                    path,
+                    state.size,
+                ));
+            }
+            assets.insert(asset.id.clone(), state);
+        }
+        contents.removed_assets = previous_assets
+            .keys()
+            .filter(|id| !assets.contains_key(*id))
+            .cloned()
+            .collect();
+
+        // Write the backup file, then record it in the chain
+        let created_at = Utc::now();
+        let id = format!(
+            "{:04}-{}",
+            chain.backups.len() + 1,
+            created_at.format("%Y%m%dT%H%M%SZ")
+        );
+        let suffix = match kind {
+            BackupKind::Full => "full",
+            BackupKind::Differential => "diff",
+        };
+        let file = format!("{}-{}{}", id, suffix, BACKUP_EXTENSION);
+        let dir = self.campaign_dir(campaign_id);
+        fs::create_dir_all(&dir)?;
+
+        let partial_path = dir.join(format!("{}.partial", file));
+        if let Err(e) = write_backup(&partial_path, &contents, &asset_files) {
+            let _ = fs::remove_file(&partial_path);
+            return Err(e);
+        }
+        let (size_bytes, sha256) = file_checksum(&partial_path)?;
+        fs::rename(&partial_path, dir.join(&file))?;
+
+        let entry = BackupEntry {
+            id,
+            kind,
+            parent: match kind {
+                BackupKind::Full => None,
+                BackupKind::Differential => chain.backups.last().map(|b| b.id.clone()),
+            },
+            created_at,
+            file,
+            size_bytes,
+            sha256,
+            changed_entities: contents.changed.len(),
+            removed_entities: contents.removed.len(),
+            changed_assets: contents.changed_assets.len(),
+            removed_assets: contents.removed_assets.len(),
+        };
+        chain.backups.push(entry.clone());
+        chain.entity_hashes = entity_hashes;
+        chain.assets = assets;
+        self.write_chain(campaign_id, &chain)?;
+
+        info!(
+            campaign_id = %campaign_id,
+            backup = %entry.file,
+            changed = entry.changed_entities,
+            removed = entry.removed_entities,
+            assets = entry.changed_assets,
+            "Campaign backup created"
+        );
+        Ok(entry)
+    }
+
+    /// Restore a backup as a new campaign.
+    ///
+    /// Replays the last full backup at or before `backup_id` and every
+    /// differential backup after it up to `backup_id`, checking each file
+    /// against the checksum in the chain manifest.
+    pub fn restore(
+        &mut self,
+        campaign_id: &str,
+        backup_id: &str,
+        assets_dir: &Path,
+        campaign_name_override: Option<&str>,
+    ) -> ServiceResult<ImportResult> {
+        let chain = self.read_chain(campaign_id)?;
+        let target = chain
+            .backups
+            .iter()
+            .position(|b| b.id == backup_id)
+            .ok_or_else(|| ServiceError::not_found("Backup", backup_id))?;
+        let base = chain.backups[..=target]
+            .iter()
+            .rposition(|b| b.kind == BackupKind::Full)
+            .ok_or_else(|| ServiceError::validation("Backup chain has no full backup"))?;
+        let steps = &chain.backups[base..=target];
+        for pair in steps.windows(2) {
+            if pair[1].parent.as_deref() != Some(pair[0].id.as_str()) {
+                return Err(ServiceError::validation(format!(
+                    "Backup chain is broken at {}",
+                    pair[1].id
+                )));
+            }
+        }
+
+        let temp_dir = tempfile::tempdir()?;
+        let dir = self.campaign_dir(campaign_id);
+        let mut entities = BTreeMap::new();
+        for step in steps {
+            let path = dir.join(&step.file);
+            let (_, sha256) = file_checksum(&path)?;
+            if sha256 != step.sha256 {
+                return Err(ServiceError::validation(format!(
+                    "Backup {} does not match its checksum",
+                    step.file
+                )));
+            }
+
+            let contents = read_backup(&path, temp_dir.path())?;
+            for key in &contents.removed {
+                entities.remove(key);
+            }
+            entities.extend(contents.changed);
+            for asset_id in &contents.removed_assets {
+                let asset_dir = temp_dir.path().join("assets").join(asset_id);
+                if asset_dir.exists() {
+                    fs::remove_dir_all(asset_dir)?;
+                }
+            }
+        }
+
+        let data = unflatten(entities)?;
+        info!(
+            campaign_id = %campaign_id,
+            backup_id = %backup_id,
+            backups = steps.len(),
+            "Restoring campaign backup"
+        );
+        ArchiveService::new(self.conn).import_data(
+            &data,
+            &|asset: &CampaignAsset| archived_asset_path(temp_dir.path(), asset),
+            assets_dir,
+            campaign_name_override,
+        )
+    }
+
+    fn campaign_dir(&self, campaign_id: &str) -> PathBuf {
+        self.backup_dir.join(campaign_id)
+    }
+
+    fn read_chain(&self, campaign_id: &str) -> ServiceResult<BackupChain> {
+        let path = self.campaign_dir(campaign_id).join(CHAIN_FILE);
+        if !path.exists() {
+            return Ok(BackupChain::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .map_err(|e| ServiceError::validation(format!("Invalid {}: {}", CHAIN_FILE, e)))
+    }
+
+    fn write_chain(&self, campaign_id: &str, chain: &BackupChain) -> ServiceResult<()> {
+        let dir = self.campaign_dir(campaign_id);
+        let json = serde_json::to_string_pretty(chain)
+            .map_err(|e| ServiceError::validation(format!("Failed to serialize chain: {}", e)))?;
+        // Write beside the manifest and rename, so a crash never leaves a
+        // half-written chain behind
+        let temp_path = dir.join(format!("{}.tmp", CHAIN_FILE));
+        fs::write(&temp_path, json)?;
+        fs::rename(&temp_path, dir.join(CHAIN_FILE))?;
+        Ok(())
+    }
+}
+
+/// Split archive data into entities keyed by `<list>/<id>`, or by field name
+/// for single entities such as the campaign itself.
+fn flatten(data: &ArchiveData) -> ServiceResult<BTreeMap<String, Value>> {
+    let value = serde_json::to_value(data)
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize data: {}", e)))?;
+    let Value::Object(fields) = value else {
+        return Err(ServiceError::validation("Archive data is not an object"));
+    };
+
+    let mut entities = BTreeMap::new();
+    for (field, value) in fields {
+        match value {
+            Value::Array(items) => {
+                for item in items {
+                    let id = match (&item, item.get("id")) {
+                        (_, Some(Value::String(id))) => id.clone(),
+                        (Value::String(s), _) => s.clone(),
+                        _ => hash_value(&item)?,
+                    };
+                    entities.insert(format!("{}/{}", field, id), item);
+                }
+            }
+            other => {
+                entities.insert(field, other);
+            }
+        }
+    }
+    Ok(entities)
+}
+
+/// Rebuild archive data from flattened entities.
+fn unflatten(entities: BTreeMap<String, Value>) -> ServiceResult<ArchiveData> {
+    let mut fields = serde_json::Map::new();
+    for list in ENTITY_LISTS {
+        fields.insert(list.to_string(), Value::Array(Vec::new()));
+    }
+    for (key, value) in entities {
+        match key.split_once('/') {
+            Some((list, _)) => {
+                if let Value::Array(items) = fields
+                    .entry(list)
+                    .or_insert_with(|| Value::Array(Vec::new()))
+                {
+                    items.push(value);
+                }
+            }
+            None => {
+                fields.insert(key, value);
+            }
+        }
+    }
+    serde_json::from_value(Value::Object(fields))
+        .map_err(|e| ServiceError::validation(format!("Invalid backup data: {}", e)))
+}
+
+fn hash_value(value: &Value) -> ServiceResult<String> {
+    let bytes = serde_json::to_vec(value)
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize entity: {}", e)))?;
+    Ok(Sha256::digest(&bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// Current state of an asset file. The previous hash is reused when the
+/// file's size and modification time are unchanged, so unchanged files are
+/// not read again.
+fn asset_state(
+    path: &Path,
+    filename: &str,
+    previous: Option<&AssetState>,
+) -> std::io::Result<AssetState> {
+    let metadata = fs::metadata(path)?;
+    let modified_ms = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64);
+
+    let sha256 = match previous {
+        Some(prev)
+            if prev.size == metadata.len()
+                && prev.modified_ms.is_some()
+                && prev.modified_ms == modified_ms =>
+        {
+            prev.sha256.clone()
+        }
+        _ => file_checksum(path)?.1,
+    };
+    Ok(AssetState {
+        filename: filename.to_string(),
+        size: metadata.len(),
+        modified_ms,
+        sha256,
+    })
+}
+
+/// Size and SHA-256 hash of a file, read in a stream.
+fn file_checksum(path: &Path) -> std::io::Result<(u64, String)> {
+    let mut reader = HashingReader::new(File::open(path)?);
+    std::io::copy(&mut reader, &mut std::io::sink())?;
+    let checksum = reader.finish(String::new());
+    Ok((checksum.size, checksum.sha256))
+}
+
+fn write_backup(
+    path: &Path,
+    contents: &BackupContents,
+    asset_files: &[(String, PathBuf, u64)],
+) -> ServiceResult<()> {
+    let file = File::create(path)?;
+    let gz = GzEncoder::new(BufWriter::new(file), Compression::default());
+    let mut archive = Builder::new(gz);
+
+    let json = serde_json::to_vec(contents)
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize backup: {}", e)))?;
+    ArchiveService::append_entry(&mut archive, CONTENTS_FILE, json.len() as u64, &json[..])?;
+    for (archive_path, source, size) in asset_files {
+        ArchiveService::append_entry(&mut archive, archive_path, *size, File::open(source)?)?;
+    }
+
+    archive
+        .into_inner()?
+        .finish()?
+        .into_inner()
+        .map_err(|e| ServiceError::Io(e.into_error()))?
+        .sync_all()?;
+    Ok(())
+}
+
+/// Read a backup's entity changes, unpacking its asset files into `dest`.
+fn read_backup(path: &Path, dest: &Path) -> ServiceResult<BackupContents> {
+    let mut archive = Archive::new(GzDecoder::new(File::open(path)?));
+    let mut contents = None;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.as_os_str() == CONTENTS_FILE {
+            let mut json = String::new();
+            entry.read_to_string(&mut json)?;
+            contents = Some(serde_json::from_str(&json).map_err(|e| {
+                ServiceError::validation(format!("Invalid {}: {}", CONTENTS_FILE, e))
+            })?);
+        } else {
+            entry.unpack_in(dest)?;
+        }
+    }
+    contents.ok_or_else(|| {
+        ServiceError::validation(format!("{} is missing {}", path.display(), CONTENTS_FILE))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_campaign_asset, insert_module};
+    use crate::models::campaign::{NewCampaign, NewCampaignAsset, NewModule};
+    use crate::services::{CreateDocumentInput, DocumentService, UpdateDocumentInput};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    fn seed(conn: &mut SqliteConnection, assets_dir: &Path) -> String {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Curse of Strahd")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "Death House", 1)).unwrap();
+        DocumentService::new(conn)
+            .create(
+                CreateDocumentInput::for_campaign("camp-1", "House Rules")
+                    .with_content("No flanking."),
+            )
+            .unwrap();
+
+        fs::create_dir_all(assets_dir.join("campaigns/camp-1")).unwrap();
+        fs::write(assets_dir.join("campaigns/camp-1/map.png"), b"map v1").unwrap();
+        insert_campaign_asset(
+            conn,
+            &NewCampaignAsset::for_campaign(
+                "asset-1",
+                "camp-1",
+                "map.png",
+                "image/png",
+                "campaigns/camp-1/map.png",
+            ),
+        )
+        .unwrap();
+        "camp-1".to_string()
+    }
+
+    #[test]
+    fn test_flatten_round_trip() {
+        let mut conn = setup_test_db();
+        let assets_dir = TempDir::new().unwrap();
+        let campaign_id = seed(&mut conn, assets_dir.path());
+        let campaign = dal::get_campaign(&mut conn, &campaign_id).unwrap();
+        let data = ArchiveService::new(&mut conn)
+            .collect_campaign_data(&campaign_id, campaign)
+            .unwrap();
+
+        let entities = flatten(&data).unwrap();
+        for key in entities.keys() {
+            match key.split_once('/') {
+                Some((list, _)) => assert!(ENTITY_LISTS.contains(&list), "unknown list {}", list),
+                None => assert_eq!(key, "campaign"),
+            }
+        }
+
+        let rebuilt = unflatten(entities).unwrap();
+        assert_eq!(rebuilt.campaign.name, "Curse of Strahd");
+        assert_eq!(rebuilt.modules.len(), 1);
+        assert_eq!(rebuilt.documents.len(), 1);
+        assert_eq!(rebuilt.assets.len(), 1);
+    }
+
+    #[test]
+    fn test_differential_backup_stores_only_changes() {
+        let mut conn = setup_test_db();
+        let app_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+        let campaign_id = seed(&mut conn, assets_dir.path());
+
+        let mut service = BackupService::new(&mut conn, app_dir.path());
+        // The first backup is full even when a differential one is asked for
+        let base = service
+            .create(&campaign_id, BackupKind::Differential, assets_dir.path())
+            .unwrap();
+        assert_eq!(base.kind, BackupKind::Full);
+        assert_eq!(base.changed_assets, 1);
+
+        let unchanged = service
+            .create(&campaign_id, BackupKind::Differential, assets_dir.path())
+            .unwrap();
+        assert_eq!(unchanged.kind, BackupKind::Differential);
+        assert_eq!(unchanged.parent.as_deref(), Some(base.id.as_str()));
+        assert_eq!(unchanged.changed_entities, 0);
+        assert_eq!(unchanged.changed_assets, 0);
+
+        let docs = dal::list_campaign_documents(service.conn, &campaign_id).unwrap();
+        DocumentService::new(service.conn)
+            .update(
+                &docs[0].id,
+                UpdateDocumentInput::set_content("Flanking is fine."),
+            )
+            .unwrap();
+        let changed = service
+            .create(&campaign_id, BackupKind::Differential, assets_dir.path())
+            .unwrap();
+        assert_eq!(changed.changed_entities, 1);
+        assert_eq!(changed.changed_assets, 0);
+
+        let backups = service.list(&campaign_id).unwrap();
+        assert_eq!(backups.len(), 3);
+    }
+
+    #[test]
+    fn test_restore_replays_chain() {
+        let mut conn = setup_test_db();
+        let app_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+        let campaign_id = seed(&mut conn, assets_dir.path());
+
+        let mut service = BackupService::new(&mut conn, app_dir.path());
+        let base = service
+            .create(&campaign_id, BackupKind::Full, assets_dir.path())
+            .unwrap();
+
+        let docs = dal::list_campaign_documents(service.conn, &campaign_id).unwrap();
+        DocumentService::new(service.conn)
+            .update(
+                &docs[0].id,
+                UpdateDocumentInput::set_content("Flanking is fine."),
+            )
+            .unwrap();
+        fs::write(
+            assets_dir.path().join("campaigns/camp-1/map.png"),
+            b"map v2 redrawn",
+        )
+        .unwrap();
+        let diff = service
+            .create(&campaign_id, BackupKind::Differential, assets_dir.path())
+            .unwrap();
+        assert_eq!(diff.changed_assets, 1);
+
+        let restored = service
+            .restore(&campaign_id, &diff.id, assets_dir.path(), Some("Restored"))
+            .unwrap();
+        let docs = dal::list_campaign_documents(service.conn, &restored.campaign_id).unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].content, "Flanking is fine.");
+        let assets = dal::list_campaign_assets(service.conn, &restored.campaign_id).unwrap();
+        let bytes = fs::read(assets_dir.path().join(&assets[0].blob_path)).unwrap();
+        assert_eq!(bytes, b"map v2 redrawn");
+
+        // Restoring the base backup ignores later differentials
+        let original = service
+            .restore(&campaign_id, &base.id, assets_dir.path(), Some("Original"))
+            .unwrap();
+        let docs = dal::list_campaign_documents(service.conn, &original.campaign_id).unwrap();
+        assert_eq!(docs[0].content, "No flanking.");
+    }
+
+    #[test]
+    fn test_restore_rejects_corrupt_backup() {
+        let mut conn = setup_test_db();
+        let app_dir = TempDir::new().unwrap();
+        let assets_dir = TempDir::new().unwrap();
+        let campaign_id = seed(&mut conn, assets_dir.path());
+
+        let mut service = BackupService::new(&mut conn, app_dir.path());
+        let base = service
+            .create(&campaign_id, BackupKind::Full, assets_dir.path())
+            .unwrap();
+        let path = app_dir
+            .path()
+            .join(BACKUP_DIR)
+            .join(&campaign_id)
+            .join(&base.file);
+        fs::write(&path, b"corrupted").unwrap();
+
+        let result = service.restore(&campaign_id, &base.id, assets_dir.path(), None);
+        assert!(result.is_err());
+    }
+}
@@ -6,7 +6,9 @@ use diesel::SqliteConnection;
 use uuid::Uuid;
 
 use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
 use crate::models::campaign::{Campaign, NewCampaign, NewDocument, UpdateCampaign};
+use crate::models::catalog::is_valid_edition;
 use crate::services::{ServiceError, ServiceResult};
 use crate::templates;
 use crate::utils::now_rfc3339;
@@ -43,6 +45,10 @@ pub struct UpdateCampaignInput {
     pub name: Option<String>,
     /// New description (if changing). Use Some(None) to clear.
     pub description: Option<Option<String>>,
+    /// New ruleset edition (if changing). Use Some(None) to allow both.
+    pub edition: Option<Option<String>>,
+    /// Whether to limit the campaign to SRD content (if changing)
+    pub srd_only: Option<bool>,
 }
 
 impl UpdateCampaignInput {
@@ -50,15 +56,31 @@ impl UpdateCampaignInput {
     pub fn set_name(name: impl Into<String>) -> Self {
         Self {
             name: Some(name.into()),
-            description: None,
+            ..Default::default()
         }
     }
 
     /// Create an update to change the description.
     pub fn set_description(description: Option<String>) -> Self {
         Self {
-            name: None,
             description: Some(description),
+            ..Default::default()
+        }
+    }
+
+    /// Create an update to change the ruleset edition.
+    pub fn set_edition(edition: Option<String>) -> Self {
+        Self {
+            edition: Some(edition),
+            ..Default::default()
+        }
+    }
+
+    /// Create an update to limit the campaign to SRD content, or lift the limit.
+    pub fn set_srd_only(srd_only: bool) -> Self {
+        Self {
+            srd_only: Some(srd_only),
+            ..Default::default()
         }
     }
 }
@@ -130,17 +152,29 @@ impl<'a> CampaignService<'a> {
     ///
     /// Returns the updated campaign, or an error if not found.
     pub fn update(&mut self, id: &str, input: UpdateCampaignInput) -> ServiceResult<Campaign> {
+        if let Some(Some(ref edition)) = input.edition {
+            if !is_valid_edition(edition) {
+                return Err(ServiceError::validation(format!(
+                    "Unknown edition '{}'. Must be 2014 or 2024",
+                    edition
+                )));
+            }
+        }
+
         let now = now_rfc3339();
 
         // Build the update changeset
         let name_ref = input.name.as_deref();
         let desc_ref = input.description.as_ref().map(|d| d.as_deref());
+        let edition_ref = input.edition.as_ref().map(|e| e.as_deref());
 
         let update = UpdateCampaign {
             name: name_ref,
             description: desc_ref,
             archived_at: None,
             updated_at: Some(&now),
+            edition: edition_ref,
+            srd_only: input.srd_only.map(|s| if s { 1 } else { 0 }),
         };
 
         let rows = dal::update_campaign(self.conn, id, &update)?;
@@ -151,6 +185,36 @@ impl<'a> CampaignService<'a> {
         dal::get_campaign(self.conn, id).map_err(ServiceError::from)
     }
 
+    /// The catalog sources a campaign's searches should be limited to.
+    ///
+    /// Starts from the campaign's allowed books (or every imported source if
+    /// none are set) and drops sources from the other edition when the
+    /// campaign has picked one. Returns `None` when searches are unrestricted.
+    pub fn search_sources(&mut self, id: &str) -> ServiceResult<Option<Vec<String>>> {
+        let campaign = dal::get_campaign_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Campaign", id))?;
+        let allowed = dal::list_campaign_source_codes(self.conn, id)?;
+
+        let Some(edition) = campaign.edition else {
+            return Ok(if allowed.is_empty() {
+                None
+            } else {
+                Some(allowed)
+            });
+        };
+
+        let edition_sources = catalog_dal::list_source_codes_by_edition(self.conn, &edition)?;
+        if allowed.is_empty() {
+            return Ok(Some(edition_sources));
+        }
+        Ok(Some(
+            allowed
+                .into_iter()
+                .filter(|code| edition_sources.contains(code))
+                .collect(),
+        ))
+    }
+
     /// Archive a campaign (soft delete).
     ///
     /// Archived campaigns are hidden from default lists but can be restored.
@@ -345,6 +409,88 @@ mod tests {
         assert!(matches!(result, Err(ServiceError::NotFound { .. })));
     }
 
+    #[test]
+    fn test_set_srd_only() {
+        let mut conn = setup_test_db();
+        let mut service = CampaignService::new(&mut conn);
+        let campaign = service
+            .create(CreateCampaignInput::new("Test Campaign"))
+            .expect("Failed to create");
+        assert!(!campaign.is_srd_only());
+
+        let updated = service
+            .update(&campaign.id, UpdateCampaignInput::set_srd_only(true))
+            .expect("Failed to update");
+        assert!(updated.is_srd_only());
+        assert_eq!(updated.name, "Test Campaign");
+    }
+
+    #[test]
+    fn test_search_sources_by_edition() {
+        use crate::dal::campaign::insert_campaign_source;
+        use crate::models::campaign::NewCampaignSource;
+        use crate::models::catalog::NewCatalogSource;
+        use crate::test_utils::setup_test_db_with_sources;
+
+        let mut conn = setup_test_db_with_sources();
+        catalog_dal::insert_source(
+            &mut conn,
+            &NewCatalogSource::new(
+                "XPHB",
+                "Player's Handbook (2024)",
+                true,
+                "2024-01-20T12:00:00Z",
+            ),
+        )
+        .expect("Failed to insert source");
+        let mut service = CampaignService::new(&mut conn);
+        let campaign = service
+            .create(CreateCampaignInput::new("Test Campaign"))
+            .expect("Failed to create");
+
+        // No allowed books and no edition: unrestricted
+        assert_eq!(service.search_sources(&campaign.id).unwrap(), None);
+
+        let updated = service
+            .update(
+                &campaign.id,
+                UpdateCampaignInput::set_edition(Some("2024".into())),
+            )
+            .expect("Failed to update");
+        assert_eq!(updated.edition.as_deref(), Some("2024"));
+        assert_eq!(
+            service.search_sources(&campaign.id).unwrap(),
+            Some(vec!["XPHB".to_string()])
+        );
+
+        // Allowed books are narrowed to the campaign's edition
+        for code in ["PHB", "XPHB"] {
+            let id = Uuid::new_v4().to_string();
+            insert_campaign_source(
+                service.conn,
+                &NewCampaignSource::new(&id, &campaign.id, code),
+            )
+            .expect("Failed to add source");
+        }
+        service
+            .update(
+                &campaign.id,
+                UpdateCampaignInput::set_edition(Some("2014".into())),
+            )
+            .expect("Failed to update");
+        assert_eq!(
+            service.search_sources(&campaign.id).unwrap(),
+            Some(vec!["PHB".to_string()])
+        );
+
+        assert!(service
+            .update(
+                &campaign.id,
+                UpdateCampaignInput::set_edition(Some("4e".into()))
+            )
+            .is_err());
+    }
+
     #[test]
     fn test_archive_and_unarchive() {
         let mut conn = setup_test_db();
@@ -4,20 +4,29 @@
 
 use diesel::SqliteConnection;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 use crate::dal::campaign as dal;
 use crate::dal::catalog as catalog_dal;
 use crate::models::campaign::{
-    Character, CharacterClass, CharacterInventory, CharacterResponse, FeatSourceType,
-    NewCharacter, NewCharacterClass, NewCharacterFeat, NewCharacterFeature, NewCharacterInventory,
-    NewCharacterProficiency, NewCharacterSpell, ProficiencyType, UpdateCharacter,
-    UpdateCharacterClass, UpdateCharacterInventory, UpdateCharacterProficiency,
+    Character, CharacterClass, CharacterCompanion, CharacterFeat, CharacterInventory,
+    CharacterResponse, FeatSourceType, NewCharacter, NewCharacterClass, NewCharacterFeat,
+    NewCharacterFeature, NewCharacterInventory, NewCharacterProficiency, NewCharacterSpell,
+    ProficiencyType, UpdateCharacter, UpdateCharacterClass, UpdateCharacterInventory,
+    UpdateCharacterProficiency,
 };
 use crate::services::catalog::CatalogEntityService;
+use crate::services::companion::advance_sidekicks;
+use crate::services::currency::set_purse;
+use crate::services::feat_effects::{apply_feat_effects, revert_feat_effects, FeatApplication};
+use crate::services::race_traits::load_race_traits;
 use crate::services::{ClassService, ServiceError, ServiceResult};
 use crate::utils::now_rfc3339;
 
+/// Ledger reason for coins set directly on the character sheet.
+const CURRENCY_EDIT_REASON: &str = "Edited on character sheet";
+
 /// Input for creating a new character.
 #[derive(Debug, Clone)]
 pub struct CreateCharacterInput {
@@ -33,11 +42,17 @@ pub struct CreateCharacterInput {
     pub race_name: Option<String>,
     /// Race source (e.g., "PHB")
     pub race_source: Option<String>,
+    /// Subrace name (e.g., "High" for a High Elf)
+    pub subrace_name: Option<String>,
+    /// Subrace source (e.g., "PHB")
+    pub subrace_source: Option<String>,
+    /// Abilities picked for racial increases that offer a choice
+    pub racial_ability_choices: Option<Vec<String>>,
     /// Background name (e.g., "Acolyte")
     pub background_name: Option<String>,
     /// Background source (e.g., "PHB")
     pub background_source: Option<String>,
-    /// Ability scores [STR, DEX, CON, INT, WIS, CHA]
+    /// Base ability scores [STR, DEX, CON, INT, WIS, CHA], before racial increases
     pub ability_scores: Option<[i32; 6]>,
     /// Starting class name (e.g., "Fighter")
     pub class_name: Option<String>,
@@ -45,6 +60,14 @@ pub struct CreateCharacterInput {
     pub class_source: Option<String>,
     /// Selected skill proficiencies from class (user choices)
     pub selected_skills: Option<Vec<String>>,
+    /// Personality traits, picked or rolled from the background's tables
+    pub traits: Option<String>,
+    /// Ideals
+    pub ideals: Option<String>,
+    /// Bonds
+    pub bonds: Option<String>,
+    /// Flaws
+    pub flaws: Option<String>,
 }
 
 impl CreateCharacterInput {
@@ -61,12 +84,19 @@ impl CreateCharacterInput {
             player_name: Some(player_name.into()),
             race_name: None,
             race_source: None,
+            subrace_name: None,
+            subrace_source: None,
+            racial_ability_choices: None,
             background_name: None,
             background_source: None,
             ability_scores: None,
             class_name: None,
             class_source: None,
             selected_skills: None,
+            traits: None,
+            ideals: None,
+            bonds: None,
+            flaws: None,
         }
     }
 
@@ -79,12 +109,19 @@ impl CreateCharacterInput {
             player_name: None,
             race_name: None,
             race_source: None,
+            subrace_name: None,
+            subrace_source: None,
+            racial_ability_choices: None,
             background_name: None,
             background_source: None,
             ability_scores: None,
             class_name: None,
             class_source: None,
             selected_skills: None,
+            traits: None,
+            ideals: None,
+            bonds: None,
+            flaws: None,
         }
     }
 
@@ -95,6 +132,19 @@ impl CreateCharacterInput {
         self
     }
 
+    /// Set the subrace.
+    pub fn with_subrace(mut self, name: impl Into<String>, source: impl Into<String>) -> Self {
+        self.subrace_name = Some(name.into());
+        self.subrace_source = Some(source.into());
+        self
+    }
+
+    /// Set the abilities picked for racial increases that offer a choice.
+    pub fn with_racial_ability_choices(mut self, abilities: Vec<String>) -> Self {
+        self.racial_ability_choices = Some(abilities);
+        self
+    }
+
     /// Set the background.
     pub fn with_background(mut self, name: impl Into<String>, source: impl Into<String>) -> Self {
         self.background_name = Some(name.into());
@@ -102,7 +152,8 @@ impl CreateCharacterInput {
         self
     }
 
-    /// Set ability scores [STR, DEX, CON, INT, WIS, CHA].
+    /// Set base ability scores [STR, DEX, CON, INT, WIS, CHA]; racial
+    /// increases are added on creation.
     pub fn with_ability_scores(mut self, scores: [i32; 6]) -> Self {
         self.ability_scores = Some(scores);
         self
@@ -120,6 +171,21 @@ impl CreateCharacterInput {
         self.selected_skills = Some(skills);
         self
     }
+
+    /// Set personality traits, ideals, bonds, and flaws.
+    pub fn with_roleplay(
+        mut self,
+        traits: Option<String>,
+        ideals: Option<String>,
+        bonds: Option<String>,
+        flaws: Option<String>,
+    ) -> Self {
+        self.traits = traits;
+        self.ideals = ideals;
+        self.bonds = bonds;
+        self.flaws = flaws;
+        self
+    }
 }
 
 /// Input for updating a character.
@@ -133,6 +199,8 @@ pub struct UpdateCharacterInput {
     pub background_source: Option<Option<String>>,
     pub ability_scores: Option<[i32; 6]>,
     pub currency: Option<[i32; 5]>,
+    /// Why the currency changed, for the transaction history
+    pub currency_reason: Option<String>,
     pub traits: Option<Option<String>>,
     pub ideals: Option<Option<String>>,
     pub bonds: Option<Option<String>>,
@@ -397,6 +465,9 @@ pub enum AsiOrFeat {
         name: String,
         /// Feat source (e.g., "PHB")
         source: String,
+        /// Ability to increase when the feat offers a choice (e.g., Resilient)
+        #[serde(default)]
+        ability: Option<String>,
     },
 }
 
@@ -422,6 +493,60 @@ pub struct LevelUpResult {
     pub new_total_level: i32,
     /// Whether this was a multiclass (new class added)
     pub is_multiclass: bool,
+    /// Effects applied from a feat taken this level
+    pub feat: Option<FeatApplication>,
+    /// Sidekicks who gained a level alongside the character
+    pub sidekicks: Vec<CharacterCompanion>,
+}
+
+// =============================================================================
+// Respec Types
+// =============================================================================
+
+/// A class in a rebuilt character.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RespecClass {
+    /// Class name (e.g., "Fighter")
+    pub class_name: String,
+    /// Source book for the class (e.g., "PHB")
+    pub class_source: String,
+    /// Levels in this class
+    pub level: i32,
+    /// Subclass, if the class has reached its subclass level
+    pub subclass: Option<SubclassChoice>,
+}
+
+/// Request for rebuilding a character at its current level with different choices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RespecRequest {
+    /// Classes in the order they were taken; the first is the starting class.
+    /// Levels must add up to the character's current total level.
+    pub classes: Vec<RespecClass>,
+    /// Ability scores before improvements and feats. Required because the
+    /// current scores already include the old build's improvements.
+    pub ability_scores: [i32; 6],
+    /// One ability score improvement or feat per improvement the classes grant
+    #[serde(default)]
+    pub asi_or_feats: Vec<AsiOrFeat>,
+    /// Why the character is being rebuilt, recorded with the new version
+    pub reason: Option<String>,
+}
+
+/// Response from a respec.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RespecResult {
+    /// Rebuilt character data
+    pub character: Character,
+    /// Class entries after the respec
+    pub classes: Vec<CharacterClass>,
+    /// Class entries before the respec
+    pub previous_classes: Vec<CharacterClass>,
+    /// Feats taken in place of ability score improvements before the respec
+    pub previous_feats: Vec<CharacterFeat>,
+    /// Effects applied from the feats in the new build
+    pub feats: Vec<FeatApplication>,
+    /// Why the character was rebuilt
+    pub reason: Option<String>,
 }
 
 // =============================================================================
@@ -501,6 +626,76 @@ fn check_multiclass_prerequisites(
     Ok(())
 }
 
+/// Class levels that grant an ability score improvement.
+fn asi_levels(class_name: &str) -> &'static [i32] {
+    match class_name.to_lowercase().as_str() {
+        "fighter" => &[4, 6, 8, 12, 14, 16, 19],
+        "rogue" => &[4, 8, 10, 12, 16, 19],
+        _ => &[4, 8, 12, 16, 19],
+    }
+}
+
+/// Number of ability score improvements a class grants by the given level.
+fn asi_count(class_name: &str, level: i32) -> usize {
+    asi_levels(class_name)
+        .iter()
+        .filter(|asi_level| **asi_level <= level)
+        .count()
+}
+
+/// Check a respec build against the character's current level.
+fn validate_respec(request: &RespecRequest, total_level: i32) -> Result<(), ServiceError> {
+    if total_level == 0 {
+        return Err(ServiceError::validation(
+            "Character has no class levels to rebuild",
+        ));
+    }
+    if request.classes.is_empty() {
+        return Err(ServiceError::validation(
+            "A respec needs at least one class",
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for class in &request.classes {
+        if class.level < 1 {
+            return Err(ServiceError::validation(format!(
+                "{} must have at least one level, got {}",
+                class.class_name, class.level
+            )));
+        }
+        if !seen.insert(class.class_name.to_lowercase()) {
+            return Err(ServiceError::validation(format!(
+                "{} is listed more than once",
+                class.class_name
+            )));
+        }
+    }
+
+    let new_level: i32 = request.classes.iter().map(|c| c.level).sum();
+    if new_level != total_level {
+        return Err(ServiceError::validation(format!(
+            "Rebuilt class levels add up to {} but the character is level {}",
+            new_level, total_level
+        )));
+    }
+
+    let slots: usize = request
+        .classes
+        .iter()
+        .map(|c| asi_count(&c.class_name, c.level))
+        .sum();
+    if request.asi_or_feats.len() > slots {
+        return Err(ServiceError::validation(format!(
+            "Build takes {} ability score improvements or feats but its classes grant {}",
+            request.asi_or_feats.len(),
+            slots
+        )));
+    }
+
+    Ok(())
+}
+
 /// Get an ability score by name.
 fn get_ability_score(character: &Character, ability: &str) -> i32 {
     match ability.to_lowercase().as_str() {
@@ -536,6 +731,40 @@ fn set_ability_score(character: &Character, ability: &str, new_value: i32) -> [i
     scores
 }
 
+/// Apply an ability score improvement (total increase must be 2, each score
+/// capped at 20).
+fn apply_ability_score_improvement(
+    character: &mut Character,
+    ability1: &str,
+    increase1: i32,
+    ability2: Option<&str>,
+    increase2: Option<i32>,
+) -> Result<(), ServiceError> {
+    let total_increase = increase1 + increase2.unwrap_or(0);
+    if total_increase != 2 {
+        return Err(ServiceError::validation(format!(
+            "ASI total increase must be exactly 2, got {}",
+            total_increase
+        )));
+    }
+
+    let mut increases = vec![(ability1, increase1)];
+    if let (Some(ability2), Some(increase2)) = (ability2, increase2) {
+        increases.push((ability2, increase2));
+    }
+    for (ability, increase) in increases {
+        let new_value = (get_ability_score(character, ability) + increase).min(20);
+        let scores = set_ability_score(character, ability, new_value);
+        character.strength = scores[0];
+        character.dexterity = scores[1];
+        character.constitution = scores[2];
+        character.intelligence = scores[3];
+        character.wisdom = scores[4];
+        character.charisma = scores[5];
+    }
+    Ok(())
+}
+
 /// Calculate HP gain for a level up.
 fn calculate_hp_gain(method: &HpGainMethod, hit_die: i32, con_mod: i32) -> i32 {
     let base = match method {
@@ -548,7 +777,7 @@ fn calculate_hp_gain(method: &HpGainMethod, hit_die: i32, con_mod: i32) -> i32 {
 }
 
 /// Get hit die value for a class from catalog, returns d8 as default.
-fn get_class_hit_die(conn: &mut SqliteConnection, class_name: &str, class_source: &str) -> i32 {
+pub(super) fn get_class_hit_die(conn: &mut SqliteConnection, class_name: &str, class_source: &str) -> i32 {
     // Try to get from catalog
     if let Ok(Some(class)) = ClassService::new(conn).get_by_name_and_source(class_name, class_source) {
         // Parse hit die from JSON data
@@ -574,16 +803,17 @@ fn get_class_hit_die(conn: &mut SqliteConnection, class_name: &str, class_source
 // =============================================================================
 
 /// A proficiency to be inserted during character creation.
-struct ProficiencyEntry {
-    prof_type: ProficiencyType,
-    name: String,
+#[derive(Debug, Clone)]
+pub(super) struct ProficiencyEntry {
+    pub(super) prof_type: ProficiencyType,
+    pub(super) name: String,
 }
 
 /// Extract deterministic (non-choice) proficiencies from a keyed JSON object.
 ///
 /// Handles the common 5etools format: `{ "perception": true, "stealth": true }`
 /// Ignores choice keys like "choose", "any", "anyStandard", etc.
-fn extract_keyed_proficiencies(
+pub(super) fn extract_keyed_proficiencies(
     items: &[serde_json::Value],
     prof_type: ProficiencyType,
 ) -> Vec<ProficiencyEntry> {
@@ -616,6 +846,14 @@ fn extract_keyed_proficiencies(
     result
 }
 
+/// Capitalize each word of a name (e.g., "hellish rebuke" -> "Hellish Rebuke").
+fn capitalize_words(s: &str) -> String {
+    s.split(' ')
+        .map(capitalize_proficiency)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Capitalize a proficiency name for display.
 fn capitalize_proficiency(s: &str) -> String {
     let mut chars = s.chars();
@@ -708,7 +946,7 @@ fn extract_background_proficiencies(bg_data: &serde_json::Value) -> Vec<Proficie
 }
 
 /// Extract proficiencies from race catalog data.
-fn extract_race_proficiencies(race_data: &serde_json::Value) -> Vec<ProficiencyEntry> {
+pub(super) fn extract_race_proficiencies(race_data: &serde_json::Value) -> Vec<ProficiencyEntry> {
     let mut profs = Vec::new();
 
     // Skill proficiencies
@@ -761,7 +999,7 @@ fn extract_multiclass_proficiencies(class_data: &serde_json::Value) -> Vec<Profi
 }
 
 /// Insert proficiency entries for a character, skipping duplicates.
-fn insert_proficiencies(
+pub(super) fn insert_proficiencies(
     conn: &mut SqliteConnection,
     character_id: &str,
     entries: &[ProficiencyEntry],
@@ -823,20 +1061,50 @@ impl<'a> CharacterService<'a> {
             )
         };
 
+        // Look up racial traits from the catalog
+        let subrace = input
+            .subrace_name
+            .as_deref()
+            .zip(input.subrace_source.as_deref());
+        let race_traits = match (race_name, race_source) {
+            (Some(name), Some(source)) => load_race_traits(self.conn, name, source, subrace)?,
+            _ => None,
+        };
+        let resistances = race_traits
+            .as_ref()
+            .map(|t| t.resistances_json())
+            .unwrap_or_else(|| "[]".to_string());
+
         // Set race if provided
         if let (Some(name), Some(source)) = (race_name, race_source) {
             new_char = new_char.with_race(name, source);
+            if let Some((name, source)) = subrace {
+                new_char = new_char.with_subrace(name, source);
+            }
+        }
+        if let Some(traits) = &race_traits {
+            new_char = new_char.with_race_traits(traits.speed, traits.darkvision, &resistances);
         }
 
         // Set background if provided
         if let (Some(name), Some(source)) = (background_name, background_source) {
             new_char = new_char.with_background(name, source);
         }
+        new_char = new_char.with_roleplay(
+            input.traits.as_deref(),
+            input.ideals.as_deref(),
+            input.bonds.as_deref(),
+            input.flaws.as_deref(),
+        );
 
-        // Set ability scores if provided
-        if let Some([str, dex, con, int, wis, cha]) = input.ability_scores {
-            new_char = new_char.with_ability_scores(str, dex, con, int, wis, cha);
+        // Set ability scores, adding racial increases to the base scores
+        let mut scores = input.ability_scores.unwrap_or([10; 6]);
+        if let Some(traits) = &race_traits {
+            let choices = input.racial_ability_choices.as_deref().unwrap_or(&[]);
+            traits.apply_ability_increases(&mut scores, choices);
         }
+        let [str, dex, con, int, wis, cha] = scores;
+        new_char = new_char.with_ability_scores(str, dex, con, int, wis, cha);
 
         dal::insert_character(self.conn, &new_char)?;
 
@@ -870,15 +1138,22 @@ impl<'a> CharacterService<'a> {
             }
         }
 
-        // Race proficiencies (skills, weapons, armor, tools, languages)
-        if let (Some(r_name), Some(r_source)) = (race_name, race_source) {
-            if let Ok(Some(race)) =
-                catalog_dal::get_race_by_name(self.conn, r_name, r_source)
-            {
-                if let Ok(race_data) = serde_json::from_str::<serde_json::Value>(&race.data) {
-                    let race_profs = extract_race_proficiencies(&race_data);
-                    insert_proficiencies(self.conn, &char_id, &race_profs)?;
-                }
+        if let (Some(traits), Some(r_name)) = (&race_traits, race_name) {
+            // Race proficiencies (skills, weapons, armor, tools, languages)
+            insert_proficiencies(self.conn, &char_id, &traits.proficiencies)?;
+
+            // Innate spells known from 1st level
+            for (spell_name, spell_source) in &traits.innate_spells {
+                let spell_name =
+                    match catalog_dal::get_spell_by_name(self.conn, spell_name, spell_source)? {
+                        Some(spell) => spell.name,
+                        None => capitalize_words(spell_name),
+                    };
+                let spell_id = Uuid::new_v4().to_string();
+                let new_spell =
+                    NewCharacterSpell::new(&spell_id, &char_id, &spell_name, spell_source, r_name)
+                        .prepared();
+                dal::insert_character_spell(self.conn, &new_spell)?;
             }
         }
 
@@ -997,11 +1272,6 @@ impl<'a> CharacterService<'a> {
             intelligence: input.ability_scores.map(|s| s[3]),
             wisdom: input.ability_scores.map(|s| s[4]),
             charisma: input.ability_scores.map(|s| s[5]),
-            cp: input.currency.map(|c| c[0]),
-            sp: input.currency.map(|c| c[1]),
-            ep: input.currency.map(|c| c[2]),
-            gp: input.currency.map(|c| c[3]),
-            pp: input.currency.map(|c| c[4]),
             traits: traits_ref,
             ideals: ideals_ref,
             bonds: bonds_ref,
@@ -1010,6 +1280,7 @@ impl<'a> CharacterService<'a> {
             location: location_ref,
             faction: faction_ref,
             updated_at: Some(&now),
+            ..Default::default()
         };
 
         let rows = dal::update_character(self.conn, id, &update)?;
@@ -1017,6 +1288,18 @@ impl<'a> CharacterService<'a> {
             return Err(ServiceError::not_found("Character", id));
         }
 
+        // Coin changes go through the currency ledger
+        if let Some(currency) = input.currency {
+            let character = dal::get_character(self.conn, id)?;
+            let reason = input
+                .currency_reason
+                .as_deref()
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .unwrap_or(CURRENCY_EDIT_REASON);
+            set_purse(self.conn, &character, currency, reason)?;
+        }
+
         dal::get_character(self.conn, id).map_err(ServiceError::from)
     }
 
@@ -1107,6 +1390,7 @@ impl<'a> CharacterService<'a> {
 
         // 7. Handle ASI or Feat if provided
         let mut updated_character = character.clone();
+        let mut feat_application = None;
         if let Some(ref asi_or_feat) = request.asi_or_feat {
             match asi_or_feat {
                 AsiOrFeat::AbilityScoreImprovement {
@@ -1115,38 +1399,13 @@ impl<'a> CharacterService<'a> {
                     ability2,
                     increase2,
                 } => {
-                    // Validate total increase is exactly 2
-                    let total_increase = increase1 + increase2.unwrap_or(0);
-                    if total_increase != 2 {
-                        return Err(ServiceError::validation(format!(
-                            "ASI total increase must be exactly 2, got {}",
-                            total_increase
-                        )));
-                    }
-
-                    // Apply first ability increase (cap at 20)
-                    let current1 = get_ability_score(&updated_character, ability1);
-                    let new1 = (current1 + increase1).min(20);
-                    let scores = set_ability_score(&updated_character, ability1, new1);
-                    updated_character.strength = scores[0];
-                    updated_character.dexterity = scores[1];
-                    updated_character.constitution = scores[2];
-                    updated_character.intelligence = scores[3];
-                    updated_character.wisdom = scores[4];
-                    updated_character.charisma = scores[5];
-
-                    // Apply second ability increase if provided (cap at 20)
-                    if let (Some(ability2), Some(increase2)) = (ability2, increase2) {
-                        let current2 = get_ability_score(&updated_character, ability2);
-                        let new2 = (current2 + increase2).min(20);
-                        let scores = set_ability_score(&updated_character, ability2, new2);
-                        updated_character.strength = scores[0];
-                        updated_character.dexterity = scores[1];
-                        updated_character.constitution = scores[2];
-                        updated_character.intelligence = scores[3];
-                        updated_character.wisdom = scores[4];
-                        updated_character.charisma = scores[5];
-                    }
+                    apply_ability_score_improvement(
+                        &mut updated_character,
+                        ability1,
+                        *increase1,
+                        ability2.as_deref(),
+                        *increase2,
+                    )?;
 
                     // Update character ability scores in database
                     let now = now_rfc3339();
@@ -1162,12 +1421,25 @@ impl<'a> CharacterService<'a> {
                     };
                     dal::update_character(self.conn, character_id, &update)?;
                 }
-                AsiOrFeat::Feat { name, source } => {
+                AsiOrFeat::Feat {
+                    name,
+                    source,
+                    ability,
+                } => {
                     // Add feat to character
                     let feat_id = Uuid::new_v4().to_string();
                     let new_feat =
                         NewCharacterFeat::new(&feat_id, character_id, name, source, FeatSourceType::Asi);
                     dal::insert_character_feat(self.conn, &new_feat)?;
+
+                    // Apply the feat's ability increases and proficiencies
+                    feat_application = Some(apply_feat_effects(
+                        self.conn,
+                        &character,
+                        name,
+                        source,
+                        ability.as_deref(),
+                    )?);
                 }
             }
         }
@@ -1496,7 +1768,10 @@ impl<'a> CharacterService<'a> {
         // 11. Calculate new total level
         let new_total_level = dal::get_total_level(self.conn, character_id)? as i32;
 
-        // 12. Refresh character data
+        // 12. Sidekicks level up with their owner
+        let sidekicks = advance_sidekicks(self.conn, character_id)?;
+
+        // 13. Refresh character data
         let final_character = dal::get_character(self.conn, character_id)?;
 
         Ok(LevelUpResult {
@@ -1505,6 +1780,192 @@ impl<'a> CharacterService<'a> {
             hp_gained,
             new_total_level,
             is_multiclass,
+            feat: feat_application,
+            sidekicks,
+        })
+    }
+
+    // --- Respec ---
+
+    /// Rebuild a character at its current level with different class,
+    /// subclass, and feat choices.
+    ///
+    /// Identity, race, background, inventory, and roleplay fields are kept.
+    /// Ability scores are rebuilt from the requested base scores, and the
+    /// proficiencies old feats granted are removed before the new feats are
+    /// applied. Spells and features from classes the new build drops are
+    /// removed, and saving throw proficiencies follow the new starting class.
+    /// The build is validated the way level-up validates each step, and all
+    /// updates occur in a single transaction.
+    pub fn respec(
+        &mut self,
+        character_id: &str,
+        request: RespecRequest,
+    ) -> ServiceResult<RespecResult> {
+        use diesel::Connection;
+
+        let character = dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+        let previous_classes = dal::list_character_classes(self.conn, character_id)?;
+        let total_level: i32 = previous_classes.iter().map(|c| c.level).sum();
+        validate_respec(&request, total_level)?;
+
+        // Base ability scores plus the build's improvements
+        let mut rebuilt = character.clone();
+        let [str, dex, con, int, wis, cha] = request.ability_scores;
+        rebuilt.strength = str;
+        rebuilt.dexterity = dex;
+        rebuilt.constitution = con;
+        rebuilt.intelligence = int;
+        rebuilt.wisdom = wis;
+        rebuilt.charisma = cha;
+        for choice in &request.asi_or_feats {
+            if let AsiOrFeat::AbilityScoreImprovement {
+                ability1,
+                increase1,
+                ability2,
+                increase2,
+            } = choice
+            {
+                apply_ability_score_improvement(
+                    &mut rebuilt,
+                    ability1,
+                    *increase1,
+                    ability2.as_deref(),
+                    *increase2,
+                )?;
+            }
+        }
+
+        // A multiclass build must meet the prerequisites of every class in it
+        if request.classes.len() > 1 {
+            for class in &request.classes {
+                check_multiclass_prerequisites(&rebuilt, &class.class_name)?;
+            }
+        }
+
+        self.conn.transaction(|conn| {
+            let now = now_rfc3339();
+            let update = UpdateCharacter {
+                strength: Some(rebuilt.strength),
+                dexterity: Some(rebuilt.dexterity),
+                constitution: Some(rebuilt.constitution),
+                intelligence: Some(rebuilt.intelligence),
+                wisdom: Some(rebuilt.wisdom),
+                charisma: Some(rebuilt.charisma),
+                updated_at: Some(&now),
+                ..Default::default()
+            };
+            dal::update_character(conn, character_id, &update)?;
+
+            // Replace class levels
+            dal::delete_character_classes(conn, character_id)?;
+            for (index, class) in request.classes.iter().enumerate() {
+                let class_id = Uuid::new_v4().to_string();
+                let mut new_class = if index == 0 {
+                    NewCharacterClass::starting(
+                        &class_id,
+                        character_id,
+                        &class.class_name,
+                        &class.class_source,
+                    )
+                } else {
+                    NewCharacterClass::multiclass(
+                        &class_id,
+                        character_id,
+                        &class.class_name,
+                        &class.class_source,
+                    )
+                }
+                .with_level(class.level);
+                if let Some(ref subclass) = class.subclass {
+                    new_class = new_class.with_subclass(&subclass.name, &subclass.source);
+                }
+                dal::insert_character_class(conn, &new_class)?;
+            }
+
+            // Drop spells and features from classes the build no longer has
+            for previous in &previous_classes {
+                let kept = request
+                    .classes
+                    .iter()
+                    .any(|c| c.class_name.eq_ignore_ascii_case(&previous.class_name));
+                if kept {
+                    continue;
+                }
+                for spell in dal::list_spells_by_class(conn, character_id, &previous.class_name)? {
+                    dal::delete_character_spell(conn, &spell.id)?;
+                }
+                for feature in
+                    dal::list_features_by_class(conn, character_id, &previous.class_name)?
+                {
+                    dal::delete_character_feature(conn, &feature.id)?;
+                }
+            }
+
+            // Saving throws come from the starting class
+            let starting = &request.classes[0];
+            if let Some(class_row) =
+                catalog_dal::get_class_by_name(conn, &starting.class_name, &starting.class_source)?
+            {
+                if let Ok(class_data) = serde_json::from_str::<serde_json::Value>(&class_row.data) {
+                    for save in dal::list_save_proficiencies(conn, character_id)? {
+                        dal::delete_character_proficiency(conn, &save.id)?;
+                    }
+                    let saves: Vec<ProficiencyEntry> =
+                        extract_class_proficiencies(&class_data, &[])
+                            .into_iter()
+                            .filter(|p| p.prof_type == ProficiencyType::Save)
+                            .collect();
+                    insert_proficiencies(conn, character_id, &saves)?;
+                }
+            }
+
+            // Replace feats taken in place of ability score improvements,
+            // undoing what they granted before the new feats apply
+            let previous_feats =
+                dal::list_feats_by_source_type(conn, character_id, FeatSourceType::Asi.as_str())?;
+            for feat in &previous_feats {
+                revert_feat_effects(conn, character_id, &feat.feat_name, &feat.feat_source)?;
+                dal::delete_character_feat(conn, &feat.id)?;
+            }
+            let mut feats = Vec::new();
+            for choice in &request.asi_or_feats {
+                if let AsiOrFeat::Feat {
+                    name,
+                    source,
+                    ability,
+                } = choice
+                {
+                    let feat_id = Uuid::new_v4().to_string();
+                    let new_feat = NewCharacterFeat::new(
+                        &feat_id,
+                        character_id,
+                        name,
+                        source,
+                        FeatSourceType::Asi,
+                    );
+                    dal::insert_character_feat(conn, &new_feat)?;
+
+                    let current = dal::get_character(conn, character_id)?;
+                    feats.push(apply_feat_effects(
+                        conn,
+                        &current,
+                        name,
+                        source,
+                        ability.as_deref(),
+                    )?);
+                }
+            }
+
+            Ok(RespecResult {
+                character: dal::get_character(conn, character_id)?,
+                classes: dal::list_character_classes(conn, character_id)?,
+                previous_classes,
+                previous_feats,
+                feats,
+                reason: request.reason,
+            })
         })
     }
 
@@ -1607,8 +2068,10 @@ impl<'a> CharacterService<'a> {
 mod tests {
     use super::*;
     use crate::dal::campaign::insert_campaign;
+    use crate::dal::catalog::insert_feat;
     use crate::models::campaign::NewCampaign;
-    use crate::test_utils::setup_test_db;
+    use crate::models::catalog::NewFeat;
+    use crate::test_utils::{setup_test_db, setup_test_db_with_sources};
 
     fn create_test_campaign(conn: &mut SqliteConnection) -> String {
         let campaign_id = Uuid::new_v4().to_string();
@@ -1665,6 +2128,28 @@ mod tests {
         assert_eq!(character.background_source, Some("PHB".to_string()));
     }
 
+    #[test]
+    fn test_create_with_roleplay() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+
+        let mut service = CharacterService::new(&mut conn);
+
+        let input = CreateCharacterInput::new_pc(Some(&campaign_id), "Brother Aldric", "Jane")
+            .with_roleplay(
+                Some("I quote sacred texts.".to_string()),
+                Some("Tradition.".to_string()),
+                None,
+                Some("I judge others harshly.".to_string()),
+            );
+        let character = service.create(input).expect("Failed to create character");
+
+        assert_eq!(character.traits.as_deref(), Some("I quote sacred texts."));
+        assert_eq!(character.ideals.as_deref(), Some("Tradition."));
+        assert!(character.bonds.is_none());
+        assert_eq!(character.flaws.as_deref(), Some("I judge others harshly."));
+    }
+
     #[test]
     fn test_create_with_ability_scores() {
         let mut conn = setup_test_db();
@@ -1684,6 +2169,58 @@ mod tests {
         assert_eq!(character.charisma, 12);
     }
 
+    #[test]
+    fn test_create_applies_racial_traits() {
+        let mut conn = crate::test_utils::setup_test_db_with_sources();
+        let campaign_id = create_test_campaign(&mut conn);
+
+        let dwarf = serde_json::json!({
+            "name": "Dwarf",
+            "speed": 25,
+            "ability": [{"con": 2}],
+            "darkvision": 60,
+            "resist": ["poison"],
+            "languageProficiencies": [{"common": true, "dwarvish": true}],
+            "additionalSpells": [{"known": {"1": ["thaumaturgy#c"]}}]
+        });
+        let hill =
+            serde_json::json!({"name": "Hill", "raceName": "Dwarf", "ability": [{"wis": 1}]});
+        catalog_dal::insert_race(
+            &mut conn,
+            &crate::models::catalog::NewRace::new("Dwarf", "PHB", &dwarf.to_string()),
+        )
+        .unwrap();
+        catalog_dal::insert_race(
+            &mut conn,
+            &crate::models::catalog::NewRace::new("Hill", "PHB", &hill.to_string()),
+        )
+        .unwrap();
+
+        let input = CreateCharacterInput::new_pc(Some(&campaign_id), "Bruenor", "John")
+            .with_race("Dwarf", "PHB")
+            .with_subrace("Hill", "PHB")
+            .with_ability_scores([15, 10, 14, 8, 12, 10]);
+        let character = CharacterService::new(&mut conn)
+            .create(input)
+            .expect("Failed to create character");
+
+        assert_eq!(character.subrace_name, Some("Hill".to_string()));
+        assert_eq!(character.constitution, 16);
+        assert_eq!(character.wisdom, 13);
+        assert_eq!(character.speed, 25);
+        assert_eq!(character.darkvision, Some(60));
+        assert_eq!(character.parse_resistances().unwrap(), vec!["poison"]);
+        assert!(
+            dal::character_has_proficiency(&mut conn, &character.id, "language", "Dwarvish")
+                .unwrap()
+        );
+
+        let spells = dal::list_character_spells(&mut conn, &character.id).unwrap();
+        assert_eq!(spells.len(), 1);
+        assert_eq!(spells[0].spell_name, "Thaumaturgy");
+        assert_eq!(spells[0].source_class, "Dwarf");
+    }
+
     #[test]
     fn test_list_for_campaign() {
         let mut conn = setup_test_db();
@@ -1777,6 +2314,28 @@ mod tests {
         assert_eq!(updated.race_source, Some("PHB".to_string()));
     }
 
+    #[test]
+    fn test_update_currency_records_transaction() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+
+        let mut service = CharacterService::new(&mut conn);
+
+        let input = CreateCharacterInput::new_pc(Some(&campaign_id), "Hero", "John");
+        let created = service.create(input).expect("Failed to create character");
+
+        let update = UpdateCharacterInput::set_currency([0, 0, 0, 25, 0]);
+        let updated = service
+            .update(&created.id, update)
+            .expect("Failed to update");
+        assert_eq!(updated.gp, 25);
+
+        let history = dal::list_character_currency_transactions(&mut conn, &created.id).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reason, CURRENCY_EDIT_REASON);
+        assert_eq!(history[0].amounts(), [0, 0, 0, 25, 0]);
+    }
+
     #[test]
     fn test_update_character_not_found() {
         let mut conn = setup_test_db();
@@ -1853,6 +2412,239 @@ mod tests {
         );
     }
 
+    fn level_up_fighter(service: &mut CharacterService, character_id: &str) {
+        let request = LevelUpRequest {
+            class_name: "Fighter".to_string(),
+            class_source: "PHB".to_string(),
+            hit_points_method: HpGainMethod::Average,
+            subclass: None,
+            asi_or_feat: None,
+            spell_changes: None,
+            feature_choices: None,
+        };
+        service
+            .level_up(character_id, request)
+            .expect("Failed to level up");
+    }
+
+    #[test]
+    fn test_respec_character() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+
+        let mut service = CharacterService::new(&mut conn);
+        let input = CreateCharacterInput::new_pc(Some(&campaign_id), "Vex", "Jane")
+            .with_ability_scores([15, 14, 13, 12, 10, 8]);
+        let character = service.create(input).expect("Failed to create character");
+        for _ in 0..4 {
+            level_up_fighter(&mut service, &character.id);
+        }
+        service
+            .add_to_inventory(&character.id, AddInventoryInput::new("Longsword", "PHB"))
+            .expect("Failed to add item");
+
+        let feature_id = Uuid::new_v4().to_string();
+        let style = NewCharacterFeature::fighting_style(
+            &feature_id,
+            &character.id,
+            "Defense",
+            "PHB",
+            "Fighter",
+        );
+        dal::insert_character_feature(service.conn, &style).unwrap();
+
+        let request = RespecRequest {
+            classes: vec![RespecClass {
+                class_name: "Rogue".to_string(),
+                class_source: "PHB".to_string(),
+                level: 4,
+                subclass: Some(SubclassChoice {
+                    name: "Thief".to_string(),
+                    source: "PHB".to_string(),
+                }),
+            }],
+            ability_scores: [15, 14, 13, 12, 10, 8],
+            asi_or_feats: vec![AsiOrFeat::AbilityScoreImprovement {
+                ability1: "dex".to_string(),
+                increase1: 2,
+                ability2: None,
+                increase2: None,
+            }],
+            reason: Some("Retrained as a rogue".to_string()),
+        };
+        let result = service
+            .respec(&character.id, request)
+            .expect("Failed to respec");
+
+        assert_eq!(result.character.name, "Vex");
+        assert_eq!(result.character.dexterity, 16);
+        assert_eq!(result.previous_classes.len(), 1);
+        assert_eq!(result.previous_classes[0].class_name, "Fighter");
+        assert_eq!(result.classes.len(), 1);
+        assert_eq!(result.classes[0].class_name, "Rogue");
+        assert_eq!(result.classes[0].level, 4);
+        assert_eq!(result.classes[0].subclass_name.as_deref(), Some("Thief"));
+        assert_eq!(result.reason.as_deref(), Some("Retrained as a rogue"));
+
+        assert!(dal::list_character_features(service.conn, &character.id)
+            .unwrap()
+            .is_empty());
+        assert_eq!(service.get_inventory(&character.id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_respec_replaces_old_improvements_and_feats() {
+        let mut conn = setup_test_db_with_sources();
+        let campaign_id = create_test_campaign(&mut conn);
+        let data =
+            serde_json::json!({"ability": [{"str": 1}], "armorProficiencies": [{"heavy": true}]});
+        insert_feat(
+            &mut conn,
+            &NewFeat::new("Heavily Armored", "PHB", &data.to_string()),
+        )
+        .unwrap();
+
+        let mut service = CharacterService::new(&mut conn);
+        let input = CreateCharacterInput::new_pc(Some(&campaign_id), "Vex", "Jane")
+            .with_ability_scores([15, 14, 13, 12, 10, 8]);
+        let character = service.create(input).expect("Failed to create character");
+        let choices = [
+            None,
+            None,
+            Some(AsiOrFeat::AbilityScoreImprovement {
+                ability1: "str".to_string(),
+                increase1: 2,
+                ability2: None,
+                increase2: None,
+            }),
+            None,
+            Some(AsiOrFeat::Feat {
+                name: "Heavily Armored".to_string(),
+                source: "PHB".to_string(),
+                ability: None,
+            }),
+        ];
+        for asi_or_feat in choices {
+            let request = LevelUpRequest {
+                class_name: "Fighter".to_string(),
+                class_source: "PHB".to_string(),
+                hit_points_method: HpGainMethod::Average,
+                subclass: None,
+                asi_or_feat,
+                spell_changes: None,
+                feature_choices: None,
+            };
+            service
+                .level_up(&character.id, request)
+                .expect("Failed to level up");
+        }
+        let leveled = dal::get_character(service.conn, &character.id).unwrap();
+        assert_eq!(leveled.strength, 18);
+        assert!(
+            dal::character_has_proficiency(service.conn, &character.id, "armor", "Heavy").unwrap()
+        );
+
+        let request = RespecRequest {
+            classes: vec![RespecClass {
+                class_name: "Fighter".to_string(),
+                class_source: "PHB".to_string(),
+                level: 6,
+                subclass: None,
+            }],
+            ability_scores: [15, 14, 13, 12, 10, 8],
+            asi_or_feats: vec![
+                AsiOrFeat::AbilityScoreImprovement {
+                    ability1: "dex".to_string(),
+                    increase1: 2,
+                    ability2: None,
+                    increase2: None,
+                },
+                AsiOrFeat::AbilityScoreImprovement {
+                    ability1: "con".to_string(),
+                    increase1: 1,
+                    ability2: Some("wis".to_string()),
+                    increase2: Some(1),
+                },
+            ],
+            reason: None,
+        };
+        let result = service
+            .respec(&character.id, request)
+            .expect("Failed to respec");
+
+        let c = &result.character;
+        assert_eq!(
+            [
+                c.strength,
+                c.dexterity,
+                c.constitution,
+                c.intelligence,
+                c.wisdom,
+                c.charisma
+            ],
+            [15, 16, 14, 12, 11, 8]
+        );
+        assert_eq!(result.previous_feats.len(), 1);
+        assert!(dal::list_character_feats(service.conn, &character.id)
+            .unwrap()
+            .is_empty());
+        assert!(
+            !dal::character_has_proficiency(service.conn, &character.id, "armor", "Heavy").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_respec_validates_build() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+
+        let mut service = CharacterService::new(&mut conn);
+        let input = CreateCharacterInput::new_pc(Some(&campaign_id), "Vex", "Jane")
+            .with_ability_scores([15, 14, 13, 12, 10, 8]);
+        let character = service.create(input).expect("Failed to create character");
+        level_up_fighter(&mut service, &character.id);
+        level_up_fighter(&mut service, &character.id);
+
+        let class = |name: &str, level: i32| RespecClass {
+            class_name: name.to_string(),
+            class_source: "PHB".to_string(),
+            level,
+            subclass: None,
+        };
+        let respec = |classes: Vec<RespecClass>, asi_or_feats: Vec<AsiOrFeat>| RespecRequest {
+            classes,
+            ability_scores: [15, 14, 13, 12, 10, 8],
+            asi_or_feats,
+            reason: None,
+        };
+
+        // Levels must add up to the current level
+        let result = service.respec(&character.id, respec(vec![class("Rogue", 3)], vec![]));
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+
+        // Level 2 grants no ability score improvements
+        let feat = AsiOrFeat::Feat {
+            name: "Alert".to_string(),
+            source: "PHB".to_string(),
+            ability: None,
+        };
+        let result = service.respec(&character.id, respec(vec![class("Rogue", 2)], vec![feat]));
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+
+        // Multiclassing into Wizard needs Intelligence 13
+        let result = service.respec(
+            &character.id,
+            respec(vec![class("Fighter", 1), class("Wizard", 1)], vec![]),
+        );
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+
+        // Failed respecs leave the character untouched
+        let classes = dal::list_character_classes(service.conn, &character.id).unwrap();
+        assert_eq!(classes.len(), 1);
+        assert_eq!(classes[0].class_name, "Fighter");
+        assert_eq!(classes[0].level, 2);
+    }
+
     #[test]
     fn test_add_to_inventory() {
         let mut conn = setup_test_db();
@@ -0,0 +1,379 @@
+//! Character Image Service
+//!
+//! Portraits and map tokens for characters. Images are stored under the
+//! assets directory at `characters/{character_id}/`, named by a hash of
+//! their content so a replaced image never reuses a cached file name.
+//!
+//! A token is generated from the portrait whenever one is uploaded: a square
+//! center crop masked to a circle, ringed in the owning player's color.
+
+use diesel::SqliteConnection;
+use image::{imageops::FilterType, DynamicImage, ImageFormat, Rgba, RgbaImage};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Component, Path, PathBuf};
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{Character, UpdateCharacter};
+use crate::services::{ServiceError, ServiceResult};
+
+/// Width and height of generated tokens, in pixels.
+pub const TOKEN_SIZE_PX: u32 = 256;
+
+/// Width of the colored ring around generated tokens, in pixels.
+const TOKEN_BORDER_PX: f32 = 10.0;
+
+/// Border colors for player-owned characters' tokens. A player always gets
+/// the same color, picked from their ID.
+pub const TOKEN_BORDER_COLORS: &[&str] = &[
+    "#E53935", "#1E88E5", "#43A047", "#FB8C00", "#8E24AA", "#00ACC1", "#FDD835", "#D81B60",
+];
+
+/// Border color for tokens of characters no player owns (NPCs, unclaimed PCs).
+pub const UNOWNED_TOKEN_BORDER_COLOR: &str = "#757575";
+
+/// Hex digits of the content hash kept in image file names.
+const HASH_LEN: usize = 16;
+
+/// Service for character portraits and tokens.
+pub struct CharacterImageService<'a> {
+    conn: &'a mut SqliteConnection,
+    assets_dir: PathBuf,
+}
+
+impl<'a> CharacterImageService<'a> {
+    /// Create a new character image service.
+    pub fn new(conn: &'a mut SqliteConnection, assets_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            conn,
+            assets_dir: assets_dir.into(),
+        }
+    }
+
+    /// Set a character's portrait and generate its token from it.
+    ///
+    /// Accepts PNG and JPEG images. Replaces any existing portrait and token.
+    pub fn upload_portrait(&mut self, character_id: &str, data: &[u8]) -> ServiceResult<Character> {
+        let character = self.character(character_id)?;
+        let (img, ext) = decode_image(data)?;
+
+        let portrait_path = self.write_image(character_id, "portrait", ext, data)?;
+        let token_path = self.write_token(&character, &img)?;
+        self.set_paths(
+            &character,
+            Some(Some(&portrait_path)),
+            Some(Some(&token_path)),
+        )
+    }
+
+    /// Set a character's token from an image of its own, rather than the
+    /// portrait. The image is used as uploaded.
+    pub fn upload_token(&mut self, character_id: &str, data: &[u8]) -> ServiceResult<Character> {
+        let character = self.character(character_id)?;
+        let (_, ext) = decode_image(data)?;
+
+        let token_path = self.write_image(character_id, "token", ext, data)?;
+        self.set_paths(&character, None, Some(Some(&token_path)))
+    }
+
+    /// Generate a character's token again from its portrait (e.g., after the
+    /// character changed hands and the border color should follow).
+    pub fn regenerate_token(&mut self, character_id: &str) -> ServiceResult<Character> {
+        let character = self.character(character_id)?;
+        let data = self.read_portrait(&character).ok_or_else(|| {
+            ServiceError::validation(format!("{} has no portrait", character.name))
+        })?;
+        let (img, _) = decode_image(&data)?;
+
+        let token_path = self.write_token(&character, &img)?;
+        self.set_paths(&character, None, Some(Some(&token_path)))
+    }
+
+    /// Remove a character's portrait. The token is kept.
+    pub fn remove_portrait(&mut self, character_id: &str) -> ServiceResult<Character> {
+        let character = self.character(character_id)?;
+        self.set_paths(&character, Some(None), None)
+    }
+
+    /// Remove a character's token.
+    pub fn remove_token(&mut self, character_id: &str) -> ServiceResult<Character> {
+        let character = self.character(character_id)?;
+        self.set_paths(&character, None, Some(None))
+    }
+
+    /// Read a character's portrait, if it has one on disk.
+    pub fn read_portrait(&self, character: &Character) -> Option<Vec<u8>> {
+        read_character_image(&self.assets_dir, character.portrait_path.as_deref()?)
+    }
+
+    /// Read a character's token, if it has one on disk.
+    pub fn read_token(&self, character: &Character) -> Option<Vec<u8>> {
+        read_character_image(&self.assets_dir, character.token_path.as_deref()?)
+    }
+
+    /// Border color for a character's token: its player's color, or gray.
+    pub fn border_color(&mut self, character_id: &str) -> ServiceResult<&'static str> {
+        let player = dal::get_character_player(self.conn, character_id)?;
+        Ok(token_border_color(player.as_ref().map(|p| p.id.as_str())))
+    }
+
+    // ── Private helpers ────────────────────────────────────────────────────
+
+    fn character(&mut self, character_id: &str) -> ServiceResult<Character> {
+        dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))
+    }
+
+    /// Render and store a circular token for a character.
+    fn write_token(&mut self, character: &Character, img: &DynamicImage) -> ServiceResult<String> {
+        let border = self.border_color(&character.id)?;
+        let token = render_token(img, border);
+
+        let mut png = Vec::new();
+        token
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .map_err(|e| ServiceError::validation(format!("Failed to encode token: {}", e)))?;
+        self.write_image(&character.id, "token", "png", &png)
+    }
+
+    /// Write image bytes to `characters/{id}/{kind}-{hash}.{ext}` and return
+    /// that path, relative to the assets directory.
+    fn write_image(
+        &self,
+        character_id: &str,
+        kind: &str,
+        ext: &str,
+        data: &[u8],
+    ) -> ServiceResult<String> {
+        let relative_path = format!(
+            "characters/{}/{}-{}.{}",
+            character_id,
+            kind,
+            content_hash(data),
+            ext
+        );
+        let full_path = self.assets_dir.join(&relative_path);
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&full_path, data)?;
+        Ok(relative_path)
+    }
+
+    /// Point the character at new images and delete the files it no longer uses.
+    fn set_paths(
+        &mut self,
+        character: &Character,
+        portrait_path: Option<Option<&str>>,
+        token_path: Option<Option<&str>>,
+    ) -> ServiceResult<Character> {
+        let update = UpdateCharacter {
+            portrait_path,
+            token_path,
+            ..Default::default()
+        };
+        dal::update_character(self.conn, &character.id, &update)?;
+
+        let replaced = [
+            (portrait_path, character.portrait_path.as_deref()),
+            (token_path, character.token_path.as_deref()),
+        ];
+        for (new, old) in replaced {
+            if let (Some(new), Some(old)) = (new, old) {
+                if new != Some(old) && is_relative_path(old) {
+                    let _ = fs::remove_file(self.assets_dir.join(old));
+                }
+            }
+        }
+
+        self.character(&character.id)
+    }
+}
+
+/// Token border color for a player, or the unowned color without one.
+pub fn token_border_color(player_id: Option<&str>) -> &'static str {
+    match player_id {
+        Some(id) => {
+            let index = Sha256::digest(id.as_bytes())[0] as usize % TOKEN_BORDER_COLORS.len();
+            TOKEN_BORDER_COLORS[index]
+        }
+        None => UNOWNED_TOKEN_BORDER_COLOR,
+    }
+}
+
+/// Render a square token: the image center-cropped to a square, masked to a
+/// circle, and ringed with `border_color` (hex, e.g. "#E53935").
+pub fn render_token(img: &DynamicImage, border_color: &str) -> RgbaImage {
+    let size = TOKEN_SIZE_PX;
+    let art = img
+        .resize_to_fill(size, size, FilterType::Lanczos3)
+        .to_rgba8();
+    let [r, g, b] = parse_hex_color(border_color).unwrap_or([0x75, 0x75, 0x75]);
+
+    let center = size as f32 / 2.0;
+    let radius = center;
+    RgbaImage::from_fn(size, size, |x, y| {
+        let dx = x as f32 + 0.5 - center;
+        let dy = y as f32 + 0.5 - center;
+        let distance = (dx * dx + dy * dy).sqrt();
+
+        // Anti-aliased edges: coverage fades over the pixel straddling each edge
+        let outer = (radius - distance + 0.5).clamp(0.0, 1.0);
+        let inner = (radius - TOKEN_BORDER_PX - distance + 0.5).clamp(0.0, 1.0);
+
+        let pixel = art.get_pixel(x, y).0;
+        let blend = |inside: u8, ring: u8| {
+            (inside as f32 * inner + ring as f32 * (1.0 - inner)).round() as u8
+        };
+        let art_alpha = pixel[3] as f32 / 255.0;
+        let alpha = outer * (inner * art_alpha + (1.0 - inner));
+        Rgba([
+            blend(pixel[0], r),
+            blend(pixel[1], g),
+            blend(pixel[2], b),
+            (alpha * 255.0).round() as u8,
+        ])
+    })
+}
+
+/// Read a character image under the assets directory.
+///
+/// Returns `None` if the path escapes the directory or the file can't be read.
+pub fn read_character_image(assets_dir: &Path, relative_path: &str) -> Option<Vec<u8>> {
+    if !is_relative_path(relative_path) {
+        return None;
+    }
+    fs::read(assets_dir.join(relative_path)).ok()
+}
+
+/// Decode an uploaded image, returning it with the file extension to store it under.
+fn decode_image(data: &[u8]) -> ServiceResult<(DynamicImage, &'static str)> {
+    let ext = match image::guess_format(data) {
+        Ok(ImageFormat::Png) => "png",
+        Ok(ImageFormat::Jpeg) => "jpg",
+        _ => {
+            return Err(ServiceError::validation(
+                "Character images must be PNG or JPEG",
+            ))
+        }
+    };
+    let img = image::load_from_memory(data)
+        .map_err(|e| ServiceError::validation(format!("Invalid image: {}", e)))?;
+    Ok((img, ext))
+}
+
+/// Short content hash used in image file names.
+fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .take(HASH_LEN / 2)
+        .collect()
+}
+
+/// Check that a stored path stays inside the assets directory.
+fn is_relative_path(path: &str) -> bool {
+    Path::new(path)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+}
+
+/// Parse a "#RRGGBB" color.
+fn parse_hex_color(color: &str) -> Option<[u8; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_player, link_player_character,
+    };
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewPlayer, NewPlayerCharacter};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let img = RgbaImage::from_pixel(width, height, Rgba([10, 200, 30, 255]));
+        let mut bytes = Vec::new();
+        img.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    fn setup() -> (SqliteConnection, TempDir) {
+        let mut conn = setup_test_db();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        let pc = NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice");
+        insert_character(&mut conn, &pc).unwrap();
+        (conn, TempDir::new().unwrap())
+    }
+
+    #[test]
+    fn test_upload_portrait_generates_token() {
+        let (mut conn, dir) = setup();
+        let mut service = CharacterImageService::new(&mut conn, dir.path());
+
+        let character = service.upload_portrait("char-1", &png(300, 200)).unwrap();
+        let portrait = character.portrait_path.clone().unwrap();
+        let token = character.token_path.clone().unwrap();
+        assert!(portrait.starts_with("characters/char-1/portrait-"));
+        assert!(token.starts_with("characters/char-1/token-"));
+
+        let token_img = image::load_from_memory(&service.read_token(&character).unwrap())
+            .unwrap()
+            .to_rgba8();
+        assert_eq!(token_img.dimensions(), (TOKEN_SIZE_PX, TOKEN_SIZE_PX));
+        // Corners are cut away, the center keeps the portrait
+        assert_eq!(token_img.get_pixel(0, 0)[3], 0);
+        let center = token_img.get_pixel(128, 128);
+        assert!(center[1] > 190 && center[3] == 255);
+        // No player, so the ring is gray
+        assert_eq!(token_img.get_pixel(128, 3).0, [0x75, 0x75, 0x75, 255]);
+
+        // Replacing the portrait removes the old files
+        let character = service.upload_portrait("char-1", &png(64, 64)).unwrap();
+        assert_ne!(character.portrait_path.as_deref(), Some(portrait.as_str()));
+        assert!(!dir.path().join(&portrait).exists());
+        assert!(!dir.path().join(&token).exists());
+    }
+
+    #[test]
+    fn test_token_border_follows_player() {
+        let (mut conn, dir) = setup();
+        insert_player(&mut conn, &NewPlayer::new("player-1", "camp-1", "Alice")).unwrap();
+        link_player_character(&mut conn, &NewPlayerCharacter::new("char-1", "player-1")).unwrap();
+
+        let mut service = CharacterImageService::new(&mut conn, dir.path());
+        assert_eq!(
+            service.border_color("char-1").unwrap(),
+            token_border_color(Some("player-1"))
+        );
+        assert_ne!(
+            token_border_color(Some("player-1")),
+            UNOWNED_TOKEN_BORDER_COLOR
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_images() {
+        let (mut conn, dir) = setup();
+        let mut service = CharacterImageService::new(&mut conn, dir.path());
+        let result = service.upload_portrait("char-1", b"not an image");
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+        assert!(service.regenerate_token("char-1").is_err());
+    }
+
+    #[test]
+    fn test_read_character_image_rejects_traversal() {
+        let dir = TempDir::new().unwrap();
+        assert!(read_character_image(dir.path(), "../secret.png").is_none());
+        assert!(read_character_image(dir.path(), "/etc/passwd").is_none());
+    }
+}
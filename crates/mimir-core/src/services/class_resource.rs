@@ -0,0 +1,607 @@
+//! Class Resource Service
+//!
+//! Business logic for limited-use class features: Rage, Ki, Channel Divinity,
+//! and the like. A character's resources are derived from their class levels,
+//! with the recharge rule read from the catalog's class feature text where the
+//! feature is imported. Spending and restoring uses, and taking rests, keep
+//! the remaining uses in the database instead of on scratch paper.
+
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::fts::flatten_entries;
+use crate::models::campaign::{
+    Character, CharacterResource, NewCharacterResource, RechargeType, RestType,
+    UpdateCharacterResource,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// A resource granted by a character's levels in one class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DerivedResource {
+    /// Resource name shown on the sheet
+    name: &'static str,
+    /// Class that grants it
+    source_class: String,
+    /// Uses available after a rest
+    max_uses: i32,
+    /// Recharge rule when the catalog has no text for the feature
+    recharge: RechargeType,
+    /// Catalog class feature whose text states the recharge rule
+    catalog_feature: Option<&'static str>,
+}
+
+impl DerivedResource {
+    fn new(
+        name: &'static str,
+        source_class: &str,
+        max_uses: i32,
+        recharge: RechargeType,
+        catalog_feature: Option<&'static str>,
+    ) -> Self {
+        Self {
+            name,
+            source_class: source_class.to_string(),
+            max_uses,
+            recharge,
+            catalog_feature,
+        }
+    }
+}
+
+/// Limited-use features granted by levels in a class.
+///
+/// Features that become unlimited (Rage and Wild Shape at 20th level) are
+/// dropped rather than tracked.
+fn class_resources(class_name: &str, level: i32, cha_mod: i32) -> Vec<DerivedResource> {
+    use RechargeType::{LongRest, ShortRest};
+
+    let mut resources = Vec::new();
+    match class_name.to_lowercase().as_str() {
+        "barbarian" if level < 20 => {
+            let rages = match level {
+                1..=2 => 2,
+                3..=5 => 3,
+                6..=11 => 4,
+                12..=16 => 5,
+                _ => 6,
+            };
+            resources.push(DerivedResource::new(
+                "Rage",
+                class_name,
+                rages,
+                LongRest,
+                Some("Rage"),
+            ));
+        }
+        "bard" => {
+            // Font of Inspiration moves the recharge to short rests at 5th level
+            let (recharge, feature) = if level >= 5 {
+                (ShortRest, "Font of Inspiration")
+            } else {
+                (LongRest, "Bardic Inspiration")
+            };
+            resources.push(DerivedResource::new(
+                "Bardic Inspiration",
+                class_name,
+                cha_mod.max(1),
+                recharge,
+                Some(feature),
+            ));
+        }
+        "cleric" if level >= 2 => {
+            let uses = match level {
+                2..=5 => 1,
+                6..=17 => 2,
+                _ => 3,
+            };
+            resources.push(DerivedResource::new(
+                "Channel Divinity",
+                class_name,
+                uses,
+                ShortRest,
+                Some("Channel Divinity"),
+            ));
+        }
+        "druid" if (2..20).contains(&level) => {
+            resources.push(DerivedResource::new(
+                "Wild Shape",
+                class_name,
+                2,
+                ShortRest,
+                Some("Wild Shape"),
+            ));
+        }
+        "fighter" => {
+            resources.push(DerivedResource::new(
+                "Second Wind",
+                class_name,
+                1,
+                ShortRest,
+                Some("Second Wind"),
+            ));
+            if level >= 2 {
+                let surges = if level >= 17 { 2 } else { 1 };
+                resources.push(DerivedResource::new(
+                    "Action Surge",
+                    class_name,
+                    surges,
+                    ShortRest,
+                    Some("Action Surge"),
+                ));
+            }
+            if level >= 9 {
+                let uses = match level {
+                    9..=12 => 1,
+                    13..=16 => 2,
+                    _ => 3,
+                };
+                resources.push(DerivedResource::new(
+                    "Indomitable",
+                    class_name,
+                    uses,
+                    LongRest,
+                    Some("Indomitable"),
+                ));
+            }
+        }
+        "monk" if level >= 2 => {
+            resources.push(DerivedResource::new(
+                "Ki Points",
+                class_name,
+                level,
+                ShortRest,
+                Some("Ki"),
+            ));
+        }
+        "paladin" => {
+            resources.push(DerivedResource::new(
+                "Divine Sense",
+                class_name,
+                (1 + cha_mod).max(1),
+                LongRest,
+                Some("Divine Sense"),
+            ));
+            resources.push(DerivedResource::new(
+                "Lay on Hands (HP)",
+                class_name,
+                level * 5,
+                LongRest,
+                Some("Lay on Hands"),
+            ));
+            if level >= 3 {
+                resources.push(DerivedResource::new(
+                    "Channel Divinity",
+                    class_name,
+                    1,
+                    ShortRest,
+                    Some("Channel Divinity"),
+                ));
+            }
+        }
+        "sorcerer" if level >= 2 => {
+            resources.push(DerivedResource::new(
+                "Sorcery Points",
+                class_name,
+                level,
+                LongRest,
+                Some("Font of Magic"),
+            ));
+        }
+        "wizard" => {
+            // "Once per day when you finish a short rest" reads as a short
+            // rest recharge, so the catalog text is not consulted
+            resources.push(DerivedResource::new(
+                "Arcane Recovery",
+                class_name,
+                1,
+                LongRest,
+                None,
+            ));
+        }
+        _ => {}
+    }
+
+    resources
+}
+
+/// Read the recharge rule from a class feature's text.
+fn recharge_from_text(text: &str) -> Option<RechargeType> {
+    let text = text.to_lowercase();
+    if text.contains("short or long rest") {
+        Some(RechargeType::ShortRest)
+    } else if text.contains("long rest") {
+        Some(RechargeType::LongRest)
+    } else if text.contains("short rest") {
+        Some(RechargeType::ShortRest)
+    } else {
+        None
+    }
+}
+
+/// Outcome of a character taking a rest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestResult {
+    /// Kind of rest taken
+    pub rest_type: RestType,
+    /// Names of the resources that regained uses
+    pub restored: Vec<String>,
+    /// All of the character's resources after the rest
+    pub resources: Vec<CharacterResource>,
+}
+
+/// Service for class resource operations.
+pub struct ClassResourceService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> ClassResourceService<'a> {
+    /// Create a new class resource service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a character's class resources.
+    ///
+    /// Resources are re-derived from the character's current class levels
+    /// first, so level-ups and multiclassing are picked up.
+    pub fn list_resources(&mut self, character_id: &str) -> ServiceResult<Vec<CharacterResource>> {
+        let character = dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+
+        self.sync(&character)?;
+        Ok(dal::list_character_resources(self.conn, character_id)?)
+    }
+
+    /// Spend uses of a resource.
+    pub fn use_resource(&mut self, id: &str, amount: i32) -> ServiceResult<CharacterResource> {
+        if amount < 1 {
+            return Err(ServiceError::validation("Must use at least one"));
+        }
+        let resource = self.get_resource(id)?;
+        if resource.current_uses < amount {
+            return Err(ServiceError::validation(format!(
+                "{} has {} of {} uses remaining",
+                resource.resource_name, resource.current_uses, resource.max_uses
+            )));
+        }
+
+        let now = now_rfc3339();
+        let update =
+            UpdateCharacterResource::set_current_uses(resource.current_uses - amount, &now);
+        dal::update_character_resource(self.conn, id, &update)?;
+        self.get_resource(id)
+    }
+
+    /// Restore uses of a resource outside of a rest, e.g. from a potion or a
+    /// feature like Wizard's Arcane Recovery. Without an amount every use is
+    /// restored.
+    pub fn restore_resource(
+        &mut self,
+        id: &str,
+        amount: Option<i32>,
+    ) -> ServiceResult<CharacterResource> {
+        let resource = self.get_resource(id)?;
+        let current_uses = match amount {
+            Some(amount) if amount < 1 => {
+                return Err(ServiceError::validation("Must restore at least one"));
+            }
+            Some(amount) => (resource.current_uses + amount).min(resource.max_uses),
+            None => resource.max_uses,
+        };
+
+        let now = now_rfc3339();
+        let update = UpdateCharacterResource::set_current_uses(current_uses, &now);
+        dal::update_character_resource(self.conn, id, &update)?;
+        self.get_resource(id)
+    }
+
+    /// Take a short or long rest, restoring every resource that recharges on
+    /// that rest.
+    pub fn take_rest(
+        &mut self,
+        character_id: &str,
+        rest_type: RestType,
+    ) -> ServiceResult<RestResult> {
+        let before = self.list_resources(character_id)?;
+        let recharges: Vec<&str> = [RechargeType::ShortRest, RechargeType::LongRest]
+            .iter()
+            .filter(|r| r.restored_by(rest_type))
+            .map(|r| r.as_str())
+            .collect();
+
+        let now = now_rfc3339();
+        dal::restore_character_resources(self.conn, character_id, &recharges, &now)?;
+
+        let restored = before
+            .iter()
+            .filter(|r| r.current_uses < r.max_uses && recharges.contains(&r.recharge.as_str()))
+            .map(|r| r.resource_name.clone())
+            .collect();
+        let resources = dal::list_character_resources(self.conn, character_id)?;
+
+        Ok(RestResult {
+            rest_type,
+            restored,
+            resources,
+        })
+    }
+
+    fn get_resource(&mut self, id: &str) -> ServiceResult<CharacterResource> {
+        dal::get_character_resource_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("CharacterResource", id))
+    }
+
+    /// Resources the character's class levels grant, with recharge rules
+    /// taken from the catalog where available.
+    fn derive(&mut self, character: &Character) -> ServiceResult<Vec<DerivedResource>> {
+        let classes = dal::list_character_classes(self.conn, &character.id)?;
+        let mut derived = Vec::new();
+
+        for class in &classes {
+            for mut resource in class_resources(&class.class_name, class.level, character.cha_mod())
+            {
+                if let Some(feature) = resource.catalog_feature {
+                    if let Some(recharge) = self.catalog_recharge(feature, &class.class_name) {
+                        resource.recharge = recharge;
+                    }
+                }
+                derived.push(resource);
+            }
+        }
+
+        Ok(derived)
+    }
+
+    /// Recharge rule stated in a catalog class feature's text.
+    fn catalog_recharge(&mut self, feature: &str, class_name: &str) -> Option<RechargeType> {
+        let row = catalog_dal::get_class_feature_by_name_and_class(self.conn, feature, class_name)
+            .ok()
+            .flatten()?;
+        let data = row.parse_data().ok()?;
+        let entries = data.get("entries")?.as_array()?;
+        recharge_from_text(&flatten_entries(entries))
+    }
+
+    /// Bring the stored resources in line with the derived ones: add new
+    /// resources at full uses, adjust changed maximums, and drop resources
+    /// the character no longer has.
+    fn sync(&mut self, character: &Character) -> ServiceResult<()> {
+        let derived = self.derive(character)?;
+        let character_id = character.id.as_str();
+
+        self.conn.transaction(|conn| {
+            let existing = dal::list_character_resources(conn, character_id)?;
+            let now = now_rfc3339();
+
+            for resource in &derived {
+                let stored = existing.iter().find(|r| {
+                    r.resource_name == resource.name && r.source_class == resource.source_class
+                });
+                match stored {
+                    Some(stored) => {
+                        let recharge = resource.recharge.as_str();
+                        if stored.max_uses == resource.max_uses && stored.recharge == recharge {
+                            continue;
+                        }
+                        // Extra uses from a higher maximum are available at once
+                        let gained = (resource.max_uses - stored.max_uses).max(0);
+                        let current_uses = (stored.current_uses + gained).min(resource.max_uses);
+                        let update = UpdateCharacterResource {
+                            max_uses: Some(resource.max_uses),
+                            current_uses: Some(current_uses),
+                            recharge: Some(recharge),
+                            updated_at: Some(&now),
+                        };
+                        dal::update_character_resource(conn, &stored.id, &update)?;
+                    }
+                    None => {
+                        let id = Uuid::new_v4().to_string();
+                        let new_resource = NewCharacterResource::new(
+                            &id,
+                            character_id,
+                            resource.name,
+                            &resource.source_class,
+                            resource.max_uses,
+                            resource.recharge,
+                        );
+                        dal::insert_character_resource(conn, &new_resource)?;
+                    }
+                }
+            }
+
+            for stored in &existing {
+                let still_granted = derived.iter().any(|r| {
+                    r.name == stored.resource_name && r.source_class == stored.source_class
+                });
+                if !still_granted {
+                    dal::delete_character_resource(conn, &stored.id)?;
+                }
+            }
+
+            Ok::<_, ServiceError>(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::catalog::insert_class_feature;
+    use crate::models::campaign::{
+        NewCampaign, NewCharacter, NewCharacterClass, UpdateCharacterClass,
+    };
+    use crate::models::catalog::NewClassFeature;
+    use crate::test_utils::{setup_test_db, setup_test_db_with_sources};
+
+    fn setup(conn: &mut SqliteConnection, class_name: &str, level: i32) {
+        dal::insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        dal::insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice")
+                .with_ability_scores(10, 10, 10, 10, 10, 16),
+        )
+        .unwrap();
+        dal::insert_character_class(
+            conn,
+            &NewCharacterClass::starting("class-1", "char-1", class_name, "PHB").with_level(level),
+        )
+        .unwrap();
+    }
+
+    fn find<'r>(resources: &'r [CharacterResource], name: &str) -> &'r CharacterResource {
+        resources
+            .iter()
+            .find(|r| r.resource_name == name)
+            .unwrap_or_else(|| panic!("missing resource {}", name))
+    }
+
+    #[test]
+    fn test_recharge_from_text() {
+        assert_eq!(
+            recharge_from_text(
+                "You regain all expended ki points when you finish a short or long rest."
+            ),
+            Some(RechargeType::ShortRest)
+        );
+        assert_eq!(
+            recharge_from_text("you must finish a long rest before you can rage again"),
+            Some(RechargeType::LongRest)
+        );
+        assert_eq!(recharge_from_text("You can rage as a bonus action."), None);
+    }
+
+    #[test]
+    fn test_resources_derived_from_class_levels() {
+        let mut conn = setup_test_db();
+        setup(&mut conn, "Fighter", 9);
+        let mut service = ClassResourceService::new(&mut conn);
+
+        let resources = service.list_resources("char-1").unwrap();
+        let names: Vec<&str> = resources.iter().map(|r| r.resource_name.as_str()).collect();
+        assert_eq!(names, vec!["Action Surge", "Indomitable", "Second Wind"]);
+        assert_eq!(find(&resources, "Indomitable").recharge, "long_rest");
+        assert_eq!(find(&resources, "Second Wind").current_uses, 1);
+    }
+
+    #[test]
+    fn test_sync_follows_level_changes() {
+        let mut conn = setup_test_db();
+        setup(&mut conn, "Monk", 2);
+
+        let ki = ClassResourceService::new(&mut conn)
+            .list_resources("char-1")
+            .unwrap()
+            .remove(0);
+        ClassResourceService::new(&mut conn)
+            .use_resource(&ki.id, 2)
+            .unwrap();
+
+        // A higher maximum grants the extra uses immediately
+        dal::update_character_class(&mut conn, "class-1", &UpdateCharacterClass::set_level(3))
+            .unwrap();
+        let resources = ClassResourceService::new(&mut conn)
+            .list_resources("char-1")
+            .unwrap();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].id, ki.id);
+        assert_eq!(resources[0].max_uses, 3);
+        assert_eq!(resources[0].current_uses, 1);
+
+        // Losing the feature drops the resource
+        dal::update_character_class(&mut conn, "class-1", &UpdateCharacterClass::set_level(1))
+            .unwrap();
+        assert!(ClassResourceService::new(&mut conn)
+            .list_resources("char-1")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_use_and_restore_resource() {
+        let mut conn = setup_test_db();
+        setup(&mut conn, "Barbarian", 3);
+        let mut service = ClassResourceService::new(&mut conn);
+
+        let rage = service.list_resources("char-1").unwrap().remove(0);
+        assert_eq!(rage.max_uses, 3);
+
+        let rage = service.use_resource(&rage.id, 3).unwrap();
+        assert!(rage.is_expended());
+        assert!(matches!(
+            service.use_resource(&rage.id, 1),
+            Err(ServiceError::Validation(_))
+        ));
+
+        let rage = service.restore_resource(&rage.id, Some(5)).unwrap();
+        assert_eq!(rage.current_uses, 3);
+        assert!(matches!(
+            service.restore_resource(&rage.id, Some(0)),
+            Err(ServiceError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_take_rest_restores_by_recharge() {
+        let mut conn = setup_test_db();
+        setup(&mut conn, "Paladin", 3);
+        let mut service = ClassResourceService::new(&mut conn);
+
+        let resources = service.list_resources("char-1").unwrap();
+        for resource in &resources {
+            service.use_resource(&resource.id, 1).unwrap();
+        }
+
+        let short = service.take_rest("char-1", RestType::Short).unwrap();
+        assert_eq!(short.restored, vec!["Channel Divinity".to_string()]);
+        assert_eq!(find(&short.resources, "Channel Divinity").current_uses, 1);
+        // Divine Sense is 1 + CHA modifier
+        assert_eq!(find(&short.resources, "Divine Sense").current_uses, 3);
+        assert_eq!(find(&short.resources, "Lay on Hands (HP)").current_uses, 14);
+
+        let long = service.take_rest("char-1", RestType::Long).unwrap();
+        assert_eq!(long.restored.len(), 2);
+        assert!(long.resources.iter().all(|r| r.current_uses == r.max_uses));
+    }
+
+    #[test]
+    fn test_recharge_read_from_catalog() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn, "Wizard", 1);
+        setup_catalog_feature(&mut conn);
+
+        let resources = ClassResourceService::new(&mut conn)
+            .list_resources("char-1")
+            .unwrap();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].recharge, "long_rest");
+
+        dal::insert_character_class(
+            &mut conn,
+            &NewCharacterClass::multiclass("class-2", "char-1", "Barbarian", "PHB"),
+        )
+        .unwrap();
+        let resources = ClassResourceService::new(&mut conn)
+            .list_resources("char-1")
+            .unwrap();
+        assert_eq!(find(&resources, "Rage").recharge, "short_rest");
+    }
+
+    /// A house-ruled Rage that recharges on a short rest.
+    fn setup_catalog_feature(conn: &mut SqliteConnection) {
+        let data = serde_json::json!({
+            "entries": ["You can rage again once you finish a short or long rest."]
+        })
+        .to_string();
+        insert_class_feature(
+            conn,
+            &NewClassFeature::new("Rage", "PHB", "Barbarian", "PHB", 1, &data),
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,512 @@
+//! Companion Service
+//!
+//! Business logic for companion creatures linked to characters: beast
+//! companions, familiars, and sidekicks. Stat blocks come from catalog or
+//! campaign homebrew monsters; hit points and sidekick levels are tracked on
+//! the companion and sidekicks gain a level whenever their owner does.
+
+use diesel::{OptionalExtension, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    Character, CharacterCompanion, CompanionType, NewCharacterCompanion, SidekickClass,
+    UpdateCharacterCompanion,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Highest level a sidekick can reach.
+const MAX_SIDEKICK_LEVEL: i32 = 20;
+
+/// Input for adding a companion to a character.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddCompanionInput {
+    /// Companion's name
+    pub name: String,
+    /// Kind of companion
+    pub companion_type: CompanionType,
+    /// Catalog monster name for the stat block
+    pub monster_name: Option<String>,
+    /// Catalog monster source for the stat block
+    pub monster_source: Option<String>,
+    /// Campaign homebrew monster for the stat block
+    pub homebrew_monster_id: Option<String>,
+    /// Sidekick class (sidekicks only)
+    pub sidekick_class: Option<SidekickClass>,
+    /// Starting sidekick level (defaults to 1)
+    pub level: Option<i32>,
+    /// Hit point maximum; defaults to the stat block's average
+    pub max_hp: Option<i32>,
+    /// Free-form notes
+    pub notes: Option<String>,
+}
+
+/// Input for updating a companion.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateCompanionInput {
+    /// New name
+    pub name: Option<String>,
+    /// New hit point maximum; current HP is capped to it
+    pub max_hp: Option<i32>,
+    /// New notes (Some(None) clears them)
+    pub notes: Option<Option<String>>,
+}
+
+/// Service for companion management.
+pub struct CompanionService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> CompanionService<'a> {
+    /// Create a new companion service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a character's companions.
+    pub fn list(&mut self, character_id: &str) -> ServiceResult<Vec<CharacterCompanion>> {
+        self.get_character(character_id)?;
+        dal::list_character_companions(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    /// Get a companion by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<CharacterCompanion> {
+        dal::get_character_companion_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("CharacterCompanion", id))
+    }
+
+    /// Link a new companion to a character.
+    ///
+    /// The stat block is a catalog monster or a homebrew monster from the
+    /// character's campaign. Without an explicit hit point maximum the stat
+    /// block's average is used.
+    pub fn add(
+        &mut self,
+        character_id: &str,
+        input: AddCompanionInput,
+    ) -> ServiceResult<CharacterCompanion> {
+        let character = self.get_character(character_id)?;
+        let name = input.name.trim();
+        if name.is_empty() {
+            return Err(ServiceError::validation("Companion name cannot be empty"));
+        }
+
+        let is_sidekick = input.companion_type == CompanionType::Sidekick;
+        if is_sidekick && input.sidekick_class.is_none() {
+            return Err(ServiceError::validation(
+                "Sidekicks need a sidekick class (expert, spellcaster, or warrior)",
+            ));
+        }
+        if !is_sidekick && (input.sidekick_class.is_some() || input.level.is_some()) {
+            return Err(ServiceError::validation(
+                "Only sidekicks have a sidekick class and level",
+            ));
+        }
+        let level = input.level.unwrap_or(1);
+        if !(1..=MAX_SIDEKICK_LEVEL).contains(&level) {
+            return Err(ServiceError::validation(format!(
+                "Sidekick level must be between 1 and {}, got {}",
+                MAX_SIDEKICK_LEVEL, level
+            )));
+        }
+
+        let statblock = match (
+            &input.monster_name,
+            &input.monster_source,
+            &input.homebrew_monster_id,
+        ) {
+            (Some(monster), Some(source), None) => {
+                let monster = catalog_dal::get_monster_by_name(self.conn, monster, source)?
+                    .ok_or_else(|| {
+                        ServiceError::not_found("Monster", format!("{} ({})", monster, source))
+                    })?;
+                Some(parse_statblock(&monster.data)?)
+            }
+            (None, None, Some(homebrew_id)) => {
+                let monster = dal::get_campaign_homebrew_monster(self.conn, homebrew_id)
+                    .optional()?
+                    .ok_or_else(|| {
+                        ServiceError::not_found("CampaignHomebrewMonster", homebrew_id.as_str())
+                    })?;
+                if character.campaign_id.as_deref() != Some(monster.campaign_id.as_str()) {
+                    return Err(ServiceError::validation(
+                        "Homebrew monster belongs to a different campaign",
+                    ));
+                }
+                Some(parse_statblock(&monster.data)?)
+            }
+            (None, None, None) => None,
+            (_, _, Some(_)) => {
+                return Err(ServiceError::validation(
+                    "A companion uses either a catalog or a homebrew stat block, not both",
+                ));
+            }
+            _ => {
+                return Err(ServiceError::validation(
+                    "Catalog stat blocks need both a monster name and source",
+                ));
+            }
+        };
+
+        let max_hp = match input.max_hp {
+            Some(hp) => hp,
+            None => statblock
+                .as_ref()
+                .and_then(|s| s["hp"]["average"].as_i64())
+                .map(|hp| hp as i32)
+                .ok_or_else(|| {
+                    ServiceError::validation(
+                        "Hit point maximum is required when the stat block has no average HP",
+                    )
+                })?,
+        };
+        if max_hp < 1 {
+            return Err(ServiceError::validation(format!(
+                "Hit point maximum must be at least 1, got {}",
+                max_hp
+            )));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let mut companion =
+            NewCharacterCompanion::new(&id, character_id, name, input.companion_type, max_hp);
+        if let (Some(monster), Some(source)) = (&input.monster_name, &input.monster_source) {
+            companion = companion.with_monster(monster, source);
+        }
+        if let Some(ref homebrew_id) = input.homebrew_monster_id {
+            companion = companion.with_homebrew_monster(homebrew_id);
+        }
+        if let Some(class) = input.sidekick_class {
+            companion = companion.with_sidekick_class(class, level);
+        }
+        if let Some(ref notes) = input.notes {
+            companion = companion.with_notes(notes);
+        }
+
+        dal::insert_character_companion(self.conn, &companion)?;
+        self.get(&id)
+    }
+
+    /// Update a companion's name, hit point maximum, or notes.
+    pub fn update(
+        &mut self,
+        id: &str,
+        input: UpdateCompanionInput,
+    ) -> ServiceResult<CharacterCompanion> {
+        let companion = self.get(id)?;
+        if let Some(ref name) = input.name {
+            if name.trim().is_empty() {
+                return Err(ServiceError::validation("Companion name cannot be empty"));
+            }
+        }
+        if let Some(max_hp) = input.max_hp {
+            if max_hp < 1 {
+                return Err(ServiceError::validation(format!(
+                    "Hit point maximum must be at least 1, got {}",
+                    max_hp
+                )));
+            }
+        }
+
+        let now = now_rfc3339();
+        let update = UpdateCharacterCompanion {
+            name: input.name.as_deref().map(str::trim),
+            max_hp: input.max_hp,
+            current_hp: input.max_hp.map(|max| companion.current_hp.min(max)),
+            notes: input.notes.as_ref().map(|n| n.as_deref()),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_character_companion(self.conn, id, &update)?;
+        self.get(id)
+    }
+
+    /// Apply damage (negative) or healing (positive) to a companion.
+    ///
+    /// Hit points stay between 0 and the companion's maximum.
+    pub fn adjust_hp(&mut self, id: &str, delta: i32) -> ServiceResult<CharacterCompanion> {
+        let companion = self.get(id)?;
+        let current_hp = (companion.current_hp + delta).clamp(0, companion.max_hp);
+
+        let now = now_rfc3339();
+        let update = UpdateCharacterCompanion::set_current_hp(current_hp, &now);
+        dal::update_character_companion(self.conn, id, &update)?;
+        self.get(id)
+    }
+
+    /// Give a sidekick one level, adding the average roll of its hit die
+    /// plus its Constitution modifier to its hit points.
+    pub fn level_up(&mut self, id: &str) -> ServiceResult<CharacterCompanion> {
+        let companion = self.get(id)?;
+        if !companion.is_sidekick() {
+            return Err(ServiceError::validation(format!(
+                "{} is not a sidekick and has no levels",
+                companion.name
+            )));
+        }
+        advance_sidekick(self.conn, &companion)?;
+        self.get(id)
+    }
+
+    /// Remove a companion from its owner.
+    pub fn remove(&mut self, id: &str) -> ServiceResult<()> {
+        self.get(id)?;
+        dal::delete_character_companion(self.conn, id)?;
+        Ok(())
+    }
+
+    /// The companion's stat block in 5etools monster JSON, named for the
+    /// companion and carrying its tracked hit points.
+    pub fn statblock(&mut self, id: &str) -> ServiceResult<Value> {
+        let companion = self.get(id)?;
+        companion_statblock(self.conn, &companion)
+    }
+
+    fn get_character(&mut self, character_id: &str) -> ServiceResult<Character> {
+        dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))
+    }
+}
+
+/// Build a companion's stat block, or a minimal one when it has none.
+pub fn companion_statblock(
+    conn: &mut SqliteConnection,
+    companion: &CharacterCompanion,
+) -> ServiceResult<Value> {
+    let mut statblock =
+        base_statblock(conn, companion)?.unwrap_or_else(|| Value::Object(Default::default()));
+    let formula = statblock["hp"]["formula"].clone();
+
+    if let Some(obj) = statblock.as_object_mut() {
+        obj.insert("name".to_string(), Value::String(companion.name.clone()));
+        obj.entry("source".to_string())
+            .or_insert_with(|| Value::String("Homebrew".to_string()));
+        let mut hp = serde_json::json!({
+            "average": companion.max_hp,
+            "current": companion.current_hp,
+        });
+        if !formula.is_null() && companion.level.unwrap_or(1) <= 1 {
+            hp["formula"] = formula;
+        }
+        obj.insert("hp".to_string(), hp);
+        obj.insert(
+            "companionType".to_string(),
+            Value::String(companion.companion_type.clone()),
+        );
+        if let (Some(class), Some(level)) = (&companion.sidekick_class, companion.level) {
+            obj.insert("sidekickClass".to_string(), Value::String(class.clone()));
+            obj.insert("sidekickLevel".to_string(), Value::from(level));
+        }
+    }
+
+    Ok(statblock)
+}
+
+/// Level up every sidekick a character owns, returning the updated sidekicks.
+///
+/// Called when the owner gains a level; sidekicks at the level cap are left
+/// alone.
+pub(super) fn advance_sidekicks(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> ServiceResult<Vec<CharacterCompanion>> {
+    let mut advanced = Vec::new();
+    for companion in dal::list_character_companions(conn, character_id)? {
+        if !companion.is_sidekick() || companion.level.unwrap_or(1) >= MAX_SIDEKICK_LEVEL {
+            continue;
+        }
+        advance_sidekick(conn, &companion)?;
+        advanced.push(dal::get_character_companion(conn, &companion.id)?);
+    }
+    Ok(advanced)
+}
+
+/// Add one level and the average hit point gain to a sidekick.
+fn advance_sidekick(
+    conn: &mut SqliteConnection,
+    companion: &CharacterCompanion,
+) -> ServiceResult<()> {
+    let level = companion.level.unwrap_or(1);
+    if level >= MAX_SIDEKICK_LEVEL {
+        return Err(ServiceError::validation(format!(
+            "{} is already level {}",
+            companion.name, MAX_SIDEKICK_LEVEL
+        )));
+    }
+
+    let statblock = base_statblock(conn, companion)?;
+    let hp_gain = statblock.as_ref().map(hp_per_level).unwrap_or(5);
+
+    let now = now_rfc3339();
+    let update = UpdateCharacterCompanion {
+        level: Some(Some(level + 1)),
+        max_hp: Some(companion.max_hp + hp_gain),
+        current_hp: Some(companion.current_hp + hp_gain),
+        updated_at: Some(&now),
+        ..Default::default()
+    };
+    dal::update_character_companion(conn, &companion.id, &update)?;
+    Ok(())
+}
+
+/// The catalog or homebrew stat block a companion is built on.
+fn base_statblock(
+    conn: &mut SqliteConnection,
+    companion: &CharacterCompanion,
+) -> ServiceResult<Option<Value>> {
+    if let Some(ref homebrew_id) = companion.homebrew_monster_id {
+        let monster = dal::get_campaign_homebrew_monster(conn, homebrew_id)?;
+        let mut data = parse_statblock(&monster.data)?;
+        if let Some(obj) = data.as_object_mut() {
+            obj.insert("source".to_string(), Value::String("Homebrew".to_string()));
+        }
+        return Ok(Some(data));
+    }
+    if let (Some(name), Some(source)) = (&companion.monster_name, &companion.monster_source) {
+        if let Some(monster) = catalog_dal::get_monster_by_name(conn, name, source)? {
+            return parse_statblock(&monster.data).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// Parse stat block JSON.
+fn parse_statblock(data: &str) -> ServiceResult<Value> {
+    serde_json::from_str(data)
+        .map_err(|e| ServiceError::validation(format!("Invalid monster data: {}", e)))
+}
+
+/// Average hit points per level from a stat block: the hit die in its HP
+/// formula (e.g., "2d8 + 2" is a d8) plus its Constitution modifier.
+fn hp_per_level(statblock: &Value) -> i32 {
+    let die = statblock["hp"]["formula"]
+        .as_str()
+        .and_then(|f| f.split('d').nth(1))
+        .and_then(|rest| {
+            rest.trim()
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .and_then(|faces| faces.parse::<i32>().ok())
+        })
+        .unwrap_or(8);
+    let con = statblock["con"].as_i64().unwrap_or(10) as i32;
+    let con_mod = Character::ability_modifier(con);
+    (die / 2 + 1 + con_mod).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::dal::catalog::insert_monster;
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+    use crate::models::catalog::NewMonster;
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+        let bandit = json!({
+            "name": "Bandit",
+            "source": "MM",
+            "hp": {"average": 11, "formula": "2d8 + 2"},
+            "con": 12
+        });
+        insert_monster(conn, &NewMonster::new("Bandit", "MM", &bandit.to_string())).unwrap();
+    }
+
+    fn sidekick_input() -> AddCompanionInput {
+        AddCompanionInput {
+            name: "Grip".to_string(),
+            companion_type: CompanionType::Sidekick,
+            monster_name: Some("Bandit".to_string()),
+            monster_source: Some("MM".to_string()),
+            homebrew_monster_id: None,
+            sidekick_class: Some(SidekickClass::Warrior),
+            level: None,
+            max_hp: None,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_add_sidekick_uses_statblock_hp() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = CompanionService::new(&mut conn);
+
+        let grip = service.add("char-1", sidekick_input()).unwrap();
+        assert_eq!(grip.max_hp, 11);
+        assert_eq!(grip.current_hp, 11);
+        assert_eq!(grip.level, Some(1));
+        assert_eq!(grip.sidekick_class_enum(), Some(SidekickClass::Warrior));
+
+        let statblock = service.statblock(&grip.id).unwrap();
+        assert_eq!(statblock["name"], "Grip");
+        assert_eq!(statblock["hp"]["average"], 11);
+        assert_eq!(statblock["sidekickLevel"], 1);
+    }
+
+    #[test]
+    fn test_add_validates_companion() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = CompanionService::new(&mut conn);
+
+        let mut input = sidekick_input();
+        input.sidekick_class = None;
+        assert!(service.add("char-1", input).is_err());
+
+        let mut input = sidekick_input();
+        input.companion_type = CompanionType::Familiar;
+        assert!(service.add("char-1", input).is_err());
+
+        let mut input = sidekick_input();
+        input.monster_name = None;
+        input.monster_source = None;
+        assert!(service.add("char-1", input).is_err());
+    }
+
+    #[test]
+    fn test_adjust_hp_clamps() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = CompanionService::new(&mut conn);
+        let grip = service.add("char-1", sidekick_input()).unwrap();
+
+        assert_eq!(service.adjust_hp(&grip.id, -20).unwrap().current_hp, 0);
+        assert_eq!(service.adjust_hp(&grip.id, 50).unwrap().current_hp, 11);
+    }
+
+    #[test]
+    fn test_sidekicks_advance_with_owner() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = CompanionService::new(&mut conn);
+        let grip = service.add("char-1", sidekick_input()).unwrap();
+        let mut familiar = sidekick_input();
+        familiar.name = "Whiskers".to_string();
+        familiar.companion_type = CompanionType::Familiar;
+        familiar.sidekick_class = None;
+        let whiskers = service.add("char-1", familiar).unwrap();
+
+        let advanced = advance_sidekicks(&mut conn, "char-1").unwrap();
+        assert_eq!(advanced.len(), 1);
+        // d8 average (5) plus Con +1
+        assert_eq!(advanced[0].id, grip.id);
+        assert_eq!(advanced[0].level, Some(2));
+        assert_eq!(advanced[0].max_hp, 17);
+
+        let mut service = CompanionService::new(&mut conn);
+        assert!(service.level_up(&whiskers.id).is_err());
+    }
+}
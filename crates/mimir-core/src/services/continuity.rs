@@ -0,0 +1,625 @@
+//! Continuity Service
+//!
+//! Business logic for links between modules and the continuity checklist
+//! built from them. A module can follow from an earlier one or foreshadow a
+//! later one; before wrapping a module up, the checklist lists the hooks
+//! still waiting to pay off, the NPCs introduced but never used, and the
+//! items handed out while it was played.
+//!
+//! NPCs count as introduced by a module when they are one of its module
+//! NPCs, or an NPC character sharing a tag with one of its documents or
+//! maps. They count as used once a document of the module, or of a module
+//! that follows from or is foreshadowed by it, mentions them by name.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use diesel::SqliteConnection;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    LedgerAction, Module, ModuleLink, ModuleLinkType, NewModuleLink, TaggableType, UpdateModuleLink,
+};
+use crate::services::{ServiceError, ServiceResult};
+
+/// Input for linking two modules.
+#[derive(Debug, Clone)]
+pub struct CreateModuleLinkInput {
+    pub from_module_id: String,
+    pub to_module_id: String,
+    pub link_type: ModuleLinkType,
+    pub note: Option<String>,
+}
+
+impl CreateModuleLinkInput {
+    /// Create input for a link with no note.
+    pub fn new(
+        from_module_id: impl Into<String>,
+        to_module_id: impl Into<String>,
+        link_type: ModuleLinkType,
+    ) -> Self {
+        Self {
+            from_module_id: from_module_id.into(),
+            to_module_id: to_module_id.into(),
+            link_type,
+            note: None,
+        }
+    }
+
+    /// Set the note.
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+}
+
+/// Input for updating a module link.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateModuleLinkInput {
+    /// New note (`Some(None)` clears it)
+    pub note: Option<Option<String>>,
+    /// Whether a foreshadowed hook has paid off
+    pub resolved: Option<bool>,
+}
+
+/// A foreshadowed hook that hasn't paid off yet.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContinuityHook {
+    pub link_id: String,
+    pub from_module_id: String,
+    pub from_module_name: String,
+    pub to_module_id: String,
+    pub to_module_name: String,
+    pub note: Option<String>,
+    /// True if the hook was planted in the checked module, false if it is
+    /// meant to pay off there
+    pub planted_here: bool,
+}
+
+/// An NPC the checked module introduced but no document mentions.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedNpc {
+    /// Module NPC or NPC character ID
+    pub id: String,
+    pub name: String,
+    /// False for module NPCs, true for NPC characters brought in by a tag
+    pub is_character: bool,
+}
+
+/// An item handed to a character from the stash while the module was played.
+#[derive(Debug, Clone, Serialize)]
+pub struct HandedOutItem {
+    pub ledger_entry_id: String,
+    pub item_name: String,
+    pub item_source: Option<String>,
+    pub quantity: i32,
+    pub character_id: Option<String>,
+    pub character_name: Option<String>,
+    pub session_number: i32,
+    pub handed_out_at: String,
+}
+
+/// Continuity checklist for a module.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleCompletion {
+    pub module_id: String,
+    pub module_name: String,
+    /// Every link to or from the module
+    pub links: Vec<ModuleLink>,
+    pub unresolved_hooks: Vec<ContinuityHook>,
+    pub unused_npcs: Vec<UnusedNpc>,
+    pub items_handed_out: Vec<HandedOutItem>,
+    /// No hooks left open and no NPCs left unused
+    pub is_complete: bool,
+}
+
+/// Service for module links and continuity checks.
+pub struct ContinuityService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> ContinuityService<'a> {
+    /// Create a new continuity service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Link two modules of the same campaign.
+    ///
+    /// A module can't be linked to itself, the same link can't be made
+    /// twice, and follows-from links can't form a loop.
+    pub fn link(&mut self, input: CreateModuleLinkInput) -> ServiceResult<ModuleLink> {
+        if input.from_module_id == input.to_module_id {
+            return Err(ServiceError::validation(
+                "A module can't be linked to itself",
+            ));
+        }
+        let from = self.get_module(&input.from_module_id)?;
+        let to = self.get_module(&input.to_module_id)?;
+        if from.campaign_id != to.campaign_id {
+            return Err(ServiceError::validation(
+                "Linked modules must belong to the same campaign",
+            ));
+        }
+
+        let link_type = input.link_type.as_str();
+        if dal::find_module_link(self.conn, &from.id, &to.id, link_type)?.is_some() {
+            return Err(ServiceError::validation(format!(
+                "'{}' already {} '{}'",
+                from.name,
+                link_type.replace('_', " "),
+                to.name
+            )));
+        }
+
+        if input.link_type == ModuleLinkType::FollowsFrom {
+            let links = dal::list_module_links(self.conn, &from.campaign_id)?;
+            if follows_from_ancestors(&links, &to.id).contains(from.id.as_str()) {
+                return Err(ServiceError::validation(format!(
+                    "'{}' already comes before '{}'",
+                    from.name, to.name
+                )));
+            }
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let mut new_link =
+            NewModuleLink::new(&id, &from.campaign_id, &from.id, &to.id, input.link_type);
+        if let Some(note) = input.note.as_deref() {
+            new_link = new_link.with_note(note);
+        }
+        dal::insert_module_link(self.conn, &new_link)?;
+        dal::get_module_link(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Change a link's note or mark its hook resolved.
+    pub fn update_link(
+        &mut self,
+        id: &str,
+        input: UpdateModuleLinkInput,
+    ) -> ServiceResult<ModuleLink> {
+        if dal::get_module_link_optional(self.conn, id)?.is_none() {
+            return Err(ServiceError::not_found("Module link", id));
+        }
+
+        let update = UpdateModuleLink {
+            note: input.note.as_ref().map(|n| n.as_deref()),
+            resolved: input.resolved.map(i32::from),
+        };
+        dal::update_module_link(self.conn, id, &update)?;
+        dal::get_module_link(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Remove a link.
+    pub fn unlink(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_module_link(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("Module link", id));
+        }
+        Ok(())
+    }
+
+    /// List a campaign's module links.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<ModuleLink>> {
+        dal::list_module_links(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// List the links to or from a module.
+    pub fn links_for_module(&mut self, module_id: &str) -> ServiceResult<Vec<ModuleLink>> {
+        dal::list_links_for_module(self.conn, module_id).map_err(ServiceError::from)
+    }
+
+    /// Build the continuity checklist for a module.
+    pub fn check_module_completion(&mut self, module_id: &str) -> ServiceResult<ModuleCompletion> {
+        let module = self.get_module(module_id)?;
+        let links = dal::list_links_for_module(self.conn, module_id)?;
+        let names: HashMap<String, String> = dal::list_modules(self.conn, &module.campaign_id)?
+            .into_iter()
+            .map(|m| (m.id, m.name))
+            .collect();
+        let name_of = |id: &str| names.get(id).cloned().unwrap_or_default();
+
+        let unresolved_hooks = links
+            .iter()
+            .filter(|l| l.link_type_enum() == Some(ModuleLinkType::Foreshadows))
+            .filter(|l| !l.is_resolved())
+            .map(|l| ContinuityHook {
+                link_id: l.id.clone(),
+                from_module_id: l.from_module_id.clone(),
+                from_module_name: name_of(&l.from_module_id),
+                to_module_id: l.to_module_id.clone(),
+                to_module_name: name_of(&l.to_module_id),
+                note: l.note.clone(),
+                planted_here: l.from_module_id == module.id,
+            })
+            .collect::<Vec<_>>();
+
+        let unused_npcs = self.unused_npcs(&module, &links)?;
+        let items_handed_out = self.items_handed_out(&module)?;
+        let is_complete = unresolved_hooks.is_empty() && unused_npcs.is_empty();
+
+        Ok(ModuleCompletion {
+            module_id: module.id,
+            module_name: module.name,
+            links,
+            unresolved_hooks,
+            unused_npcs,
+            items_handed_out,
+            is_complete,
+        })
+    }
+
+    fn get_module(&mut self, id: &str) -> ServiceResult<Module> {
+        dal::get_module_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Module", id))
+    }
+
+    /// NPCs the module introduced that no document of it, or of a module
+    /// carrying on from it, mentions by name.
+    fn unused_npcs(
+        &mut self,
+        module: &Module,
+        links: &[ModuleLink],
+    ) -> ServiceResult<Vec<UnusedNpc>> {
+        let mut introduced: Vec<UnusedNpc> = dal::list_module_npcs(self.conn, &module.id)?
+            .into_iter()
+            .map(|npc| UnusedNpc {
+                id: npc.id,
+                name: npc.name,
+                is_character: false,
+            })
+            .collect();
+
+        let documents = dal::list_module_documents(self.conn, &module.id)?;
+        let mut tagged: Vec<(TaggableType, String)> = documents
+            .iter()
+            .map(|d| (TaggableType::Document, d.id.clone()))
+            .collect();
+        for map in dal::list_module_maps(self.conn, &module.id)? {
+            tagged.push((TaggableType::Map, map.id));
+        }
+        let mut tag_ids = HashSet::new();
+        for (entity_type, entity_id) in &tagged {
+            for tag in dal::list_tags_for_entity(self.conn, *entity_type, entity_id)? {
+                tag_ids.insert(tag.id);
+            }
+        }
+        let mut character_ids = HashSet::new();
+        for tag_id in &tag_ids {
+            character_ids.extend(dal::list_tagged_entity_ids(
+                self.conn,
+                tag_id,
+                TaggableType::Npc,
+            )?);
+        }
+        for character_id in character_ids {
+            if let Some(character) = dal::get_character_optional(self.conn, &character_id)? {
+                introduced.push(UnusedNpc {
+                    id: character.id,
+                    name: character.name,
+                    is_character: true,
+                });
+            }
+        }
+
+        // Text of the module's documents and of every module carrying on
+        // from it, lowercased for matching names.
+        let mut text: Vec<String> = documents
+            .into_iter()
+            .map(|d| format!("{}\n{}", d.title, d.content).to_lowercase())
+            .collect();
+        for successor in successors(links, &module.id) {
+            for d in dal::list_module_documents(self.conn, successor)? {
+                text.push(format!("{}\n{}", d.title, d.content).to_lowercase());
+            }
+        }
+
+        let mut unused: Vec<UnusedNpc> = introduced
+            .into_iter()
+            .filter(|npc| {
+                let name = npc.name.to_lowercase();
+                !text.iter().any(|t| t.contains(&name))
+            })
+            .collect();
+        unused.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(unused)
+    }
+
+    /// Items taken from the stash during the module's game sessions.
+    fn items_handed_out(&mut self, module: &Module) -> ServiceResult<Vec<HandedOutItem>> {
+        let sessions: Vec<_> = dal::list_game_sessions(self.conn, &module.campaign_id)?
+            .into_iter()
+            .filter(|s| s.module_id.as_deref() == Some(module.id.as_str()))
+            .filter_map(|s| {
+                let start = parse_timestamp(&s.started_at)?;
+                let end = match s.ended_at.as_deref() {
+                    Some(ended_at) => parse_timestamp(ended_at)?,
+                    None => Utc::now().naive_utc(),
+                };
+                Some((s.session_number, start, end))
+            })
+            .collect();
+        if sessions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut items: Vec<HandedOutItem> = dal::list_stash_ledger(self.conn, &module.campaign_id)?
+            .into_iter()
+            .filter(|e| e.action_enum() == Some(LedgerAction::Take))
+            .filter_map(|e| {
+                let at = parse_timestamp(&e.created_at)?;
+                let (session_number, _, _) = sessions
+                    .iter()
+                    .find(|(_, start, end)| *start <= at && at <= *end)?;
+                Some(HandedOutItem {
+                    ledger_entry_id: e.id,
+                    item_name: e.item_name?,
+                    item_source: e.item_source,
+                    quantity: e.quantity,
+                    character_id: e.character_id,
+                    character_name: e.character_name,
+                    session_number: *session_number,
+                    handed_out_at: e.created_at,
+                })
+            })
+            .collect();
+        // The ledger lists newest first; the checklist reads in play order.
+        items.reverse();
+        Ok(items)
+    }
+}
+
+/// Every module `module_id` follows from, directly or through others.
+fn follows_from_ancestors<'l>(links: &'l [ModuleLink], module_id: &str) -> HashSet<&'l str> {
+    let mut seen = HashSet::new();
+    let mut pending = vec![module_id];
+    while let Some(current) = pending.pop() {
+        for link in links {
+            if link.from_module_id == current
+                && link.link_type_enum() == Some(ModuleLinkType::FollowsFrom)
+                && seen.insert(link.to_module_id.as_str())
+            {
+                pending.push(&link.to_module_id);
+            }
+        }
+    }
+    seen
+}
+
+/// Modules that follow from, or are foreshadowed by, `module_id`.
+fn successors<'l>(links: &'l [ModuleLink], module_id: &str) -> Vec<&'l str> {
+    links
+        .iter()
+        .filter_map(|l| match l.link_type_enum()? {
+            ModuleLinkType::FollowsFrom if l.to_module_id == module_id => {
+                Some(l.from_module_id.as_str())
+            }
+            ModuleLinkType::Foreshadows if l.from_module_id == module_id => {
+                Some(l.to_module_id.as_str())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parse a timestamp written either as RFC 3339 or by SQLite's
+/// `datetime('now')`, as UTC.
+fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_game_session, insert_module, insert_module_npc,
+        insert_stash_ledger_entry,
+    };
+    use crate::models::campaign::{
+        NewCampaign, NewCharacter, NewGameSession, NewModule, NewModuleNpc, NewStashLedgerEntry,
+    };
+    use crate::services::{CreateDocumentInput, DocumentService};
+    use crate::test_utils::setup_test_db;
+
+    fn create_test_campaign(conn: &mut SqliteConnection) -> String {
+        let id = Uuid::new_v4().to_string();
+        insert_campaign(conn, &NewCampaign::new(&id, "Test Campaign")).unwrap();
+        id
+    }
+
+    fn create_module(
+        conn: &mut SqliteConnection,
+        campaign_id: &str,
+        number: i32,
+        name: &str,
+    ) -> String {
+        let id = Uuid::new_v4().to_string();
+        insert_module(conn, &NewModule::new(&id, campaign_id, name, number)).unwrap();
+        id
+    }
+
+    #[test]
+    fn test_link_validation() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let first = create_module(&mut conn, &campaign_id, 1, "Goblin Caves");
+        let second = create_module(&mut conn, &campaign_id, 2, "Bandit Fort");
+        let third = create_module(&mut conn, &campaign_id, 3, "Dragon Peak");
+        let other_campaign = create_test_campaign(&mut conn);
+        let elsewhere = create_module(&mut conn, &other_campaign, 1, "Elsewhere");
+        let mut service = ContinuityService::new(&mut conn);
+
+        service
+            .link(CreateModuleLinkInput::new(
+                &second,
+                &first,
+                ModuleLinkType::FollowsFrom,
+            ))
+            .unwrap();
+        service
+            .link(CreateModuleLinkInput::new(
+                &third,
+                &second,
+                ModuleLinkType::FollowsFrom,
+            ))
+            .unwrap();
+
+        for input in [
+            CreateModuleLinkInput::new(&first, &first, ModuleLinkType::Foreshadows),
+            CreateModuleLinkInput::new(&first, &elsewhere, ModuleLinkType::Foreshadows),
+            CreateModuleLinkInput::new(&second, &first, ModuleLinkType::FollowsFrom),
+            // Third comes after first through second
+            CreateModuleLinkInput::new(&first, &third, ModuleLinkType::FollowsFrom),
+        ] {
+            assert!(matches!(
+                service.link(input),
+                Err(ServiceError::Validation(_))
+            ));
+        }
+
+        // Foreshadowing isn't held to the follows-from order
+        assert!(service
+            .link(CreateModuleLinkInput::new(
+                &first,
+                &third,
+                ModuleLinkType::Foreshadows
+            ))
+            .is_ok());
+        assert_eq!(service.links_for_module(&first).unwrap().len(), 2);
+        assert_eq!(service.list_for_campaign(&campaign_id).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_update_and_unlink() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let first = create_module(&mut conn, &campaign_id, 1, "Goblin Caves");
+        let second = create_module(&mut conn, &campaign_id, 2, "Bandit Fort");
+        let mut service = ContinuityService::new(&mut conn);
+
+        let link = service
+            .link(
+                CreateModuleLinkInput::new(&first, &second, ModuleLinkType::Foreshadows)
+                    .with_note("Map to the fort"),
+            )
+            .unwrap();
+        assert!(!link.is_resolved());
+
+        let link = service
+            .update_link(
+                &link.id,
+                UpdateModuleLinkInput {
+                    resolved: Some(true),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(link.is_resolved());
+        assert_eq!(link.note.as_deref(), Some("Map to the fort"));
+
+        service.unlink(&link.id).unwrap();
+        assert!(matches!(
+            service.unlink(&link.id),
+            Err(ServiceError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_module_completion() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let first = create_module(&mut conn, &campaign_id, 1, "Goblin Caves");
+        let second = create_module(&mut conn, &campaign_id, 2, "Bandit Fort");
+
+        insert_module_npc(&mut conn, &NewModuleNpc::new("npc-1", &first, "Sildar")).unwrap();
+        insert_module_npc(&mut conn, &NewModuleNpc::new("npc-2", &first, "Klarg")).unwrap();
+        DocumentService::new(&mut conn)
+            .create(
+                CreateDocumentInput::for_module(&campaign_id, &first, "Cave Entrance")
+                    .with_content("Klarg guards the cave."),
+            )
+            .unwrap();
+
+        let character_id = Uuid::new_v4().to_string();
+        insert_character(
+            &mut conn,
+            &NewCharacter::new_pc(&character_id, Some(&campaign_id), "Vex", "Alice"),
+        )
+        .unwrap();
+        insert_game_session(
+            &mut conn,
+            &NewGameSession::new("s-1", &campaign_id, 1).with_module(&first),
+        )
+        .unwrap();
+        insert_stash_ledger_entry(
+            &mut conn,
+            &NewStashLedgerEntry::new("l-1", &campaign_id, LedgerAction::Take)
+                .with_character(&character_id, "Vex")
+                .with_item("Potion of Healing", "PHB", 2),
+        )
+        .unwrap();
+
+        let mut service = ContinuityService::new(&mut conn);
+        let hook = service
+            .link(
+                CreateModuleLinkInput::new(&first, &second, ModuleLinkType::Foreshadows)
+                    .with_note("Sildar mentions the fort"),
+            )
+            .unwrap();
+
+        let completion = service.check_module_completion(&first).unwrap();
+        assert!(!completion.is_complete);
+        assert_eq!(completion.unresolved_hooks.len(), 1);
+        assert!(completion.unresolved_hooks[0].planted_here);
+        assert_eq!(completion.unresolved_hooks[0].to_module_name, "Bandit Fort");
+        let unused: Vec<_> = completion
+            .unused_npcs
+            .iter()
+            .map(|n| n.name.as_str())
+            .collect();
+        assert_eq!(unused, vec!["Sildar"]);
+        assert_eq!(completion.items_handed_out.len(), 1);
+        assert_eq!(
+            completion.items_handed_out[0].item_name,
+            "Potion of Healing"
+        );
+        assert_eq!(completion.items_handed_out[0].session_number, 1);
+
+        // Sildar shows up in the module the first one foreshadows
+        DocumentService::new(&mut conn)
+            .create(
+                CreateDocumentInput::for_module(&campaign_id, &second, "Fort Gate")
+                    .with_content("Sildar waits at the gate."),
+            )
+            .unwrap();
+        let mut service = ContinuityService::new(&mut conn);
+        service
+            .update_link(
+                &hook.id,
+                UpdateModuleLinkInput {
+                    resolved: Some(true),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let completion = service.check_module_completion(&first).unwrap();
+        assert!(completion.is_complete);
+        assert!(completion.unused_npcs.is_empty());
+
+        // The hook pays off in the second module, so it shows up there until resolved
+        let completion = service.check_module_completion(&second).unwrap();
+        assert!(completion.unresolved_hooks.is_empty());
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        let sqlite = parse_timestamp("2024-05-01 12:30:00").unwrap();
+        let rfc3339 = parse_timestamp("2024-05-01T12:30:00+00:00").unwrap();
+        assert_eq!(sqlite, rfc3339);
+        assert!(parse_timestamp("yesterday").is_none());
+    }
+}
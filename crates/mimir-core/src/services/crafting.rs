@@ -0,0 +1,639 @@
+//! Crafting Service
+//!
+//! Downtime crafting per Xanathar's Guide to Everything. Mundane items take
+//! one workweek for every 50 gp of market value and cost half that value in
+//! raw materials. Magic items take the time and gold listed for their rarity,
+//! need an exotic material from a creature of matching challenge rating, and
+//! have a minimum crafter level; potions and scrolls take half the time and
+//! gold. Days are logged against a project until it's done, then the item
+//! goes into the crafter's inventory.
+
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    Character, CharacterInventory, CraftingProject, CraftingStatus, NewCharacterInventory,
+    NewCraftingProject, UpdateCraftingProject,
+};
+use crate::services::currency::{make_change, set_purse};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Item source used for campaign homebrew items.
+const HOMEBREW_SOURCE: &str = "HB";
+
+/// Downtime days in a workweek.
+const DAYS_PER_WORKWEEK: i32 = 5;
+
+/// Market value of mundane crafting progress per downtime day, in copper
+/// (50 gp per workweek).
+const MUNDANE_PROGRESS_CP_PER_DAY: i64 = 1000;
+
+/// Magic item crafting by rarity (XGE): workweeks, cost in gp, minimum
+/// crafter level, and the challenge rating range of the exotic material.
+const MAGIC_ITEM_RULES: [(&str, i32, i64, i32, &str); 5] = [
+    ("common", 1, 50, 3, "0-3"),
+    ("uncommon", 2, 200, 3, "4-8"),
+    ("rare", 10, 2_000, 6, "9-12"),
+    ("very rare", 25, 20_000, 11, "13-18"),
+    ("legendary", 50, 100_000, 17, "19+"),
+];
+
+/// What it takes to craft an item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CraftingRecipe {
+    /// Item name as it appears in the catalog
+    pub item_name: String,
+    /// Item source
+    pub item_source: String,
+    /// Number of items
+    pub quantity: i32,
+    /// Rarity for magic items (None for mundane items)
+    pub rarity: Option<String>,
+    /// Potions and scrolls take half the time and gold
+    pub consumable: bool,
+    /// Materials cost in copper pieces
+    pub cost_cp: i32,
+    /// Downtime days needed
+    pub days: i32,
+    /// Minimum crafter level (magic items only)
+    pub min_level: Option<i32>,
+    /// Tool proficiency the work needs
+    pub tool: Option<String>,
+    /// Exotic material a magic item needs
+    pub ingredient: Option<String>,
+}
+
+/// Input for starting a crafting project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartCraftingInput {
+    /// Character doing the crafting
+    pub character_id: String,
+    /// Item name from the catalog (or campaign homebrew)
+    pub item_name: String,
+    /// Item source (e.g., "PHB", or "HB" for campaign homebrew)
+    pub item_source: String,
+    /// Number of items (default 1)
+    pub quantity: Option<i32>,
+    /// Pay the materials cost from the character's purse now (default true)
+    #[serde(default = "default_true")]
+    pub pay_from_purse: bool,
+    /// Skip the level and tool proficiency checks (DM's call)
+    #[serde(default)]
+    pub ignore_requirements: bool,
+    /// Free-form notes
+    pub notes: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Input for logging downtime against a crafting project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdvanceCraftingInput {
+    /// Downtime days spent crafting
+    #[serde(default)]
+    pub days: i32,
+    /// Mark the exotic material as found (or lost)
+    pub ingredient_acquired: Option<bool>,
+}
+
+/// A crafting project after logging downtime.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CraftingProgress {
+    /// The project as it stands
+    pub project: CraftingProject,
+    /// Inventory entry for the finished item, when the project completed
+    pub item: Option<CharacterInventory>,
+}
+
+/// Service for downtime crafting.
+pub struct CraftingService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> CraftingService<'a> {
+    /// Create a new crafting service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Get a crafting project by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<CraftingProject> {
+        dal::get_crafting_project_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("CraftingProject", id))
+    }
+
+    /// List a character's crafting projects, oldest first.
+    pub fn list_for_character(
+        &mut self,
+        character_id: &str,
+    ) -> ServiceResult<Vec<CraftingProject>> {
+        require_character(self.conn, character_id)?;
+        dal::list_character_crafting_projects(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    /// Work out what crafting an item would take for a character.
+    pub fn recipe(
+        &mut self,
+        character_id: &str,
+        item_name: &str,
+        item_source: &str,
+        quantity: Option<i32>,
+    ) -> ServiceResult<CraftingRecipe> {
+        let character = require_character(self.conn, character_id)?;
+        let quantity = positive_quantity(quantity.unwrap_or(1))?;
+        let (name, data) = load_item(
+            self.conn,
+            character.campaign_id.as_deref(),
+            item_name,
+            item_source,
+        )?;
+        crafting_recipe(&name, item_source, &data, quantity)
+    }
+
+    /// Start crafting an item, paying for materials from the character's
+    /// purse unless told otherwise.
+    pub fn start(&mut self, input: StartCraftingInput) -> ServiceResult<CraftingProject> {
+        let recipe = self.recipe(
+            &input.character_id,
+            &input.item_name,
+            &input.item_source,
+            input.quantity,
+        )?;
+
+        self.conn.transaction(|conn| {
+            let character = require_character(conn, &input.character_id)?;
+            if !input.ignore_requirements {
+                check_requirements(conn, &character, &recipe)?;
+            }
+            if input.pay_from_purse && recipe.cost_cp > 0 {
+                let purse = [
+                    character.cp,
+                    character.sp,
+                    character.ep,
+                    character.gp,
+                    character.pp,
+                ];
+                let paid = make_change(purse, recipe.cost_cp as i64).ok_or_else(|| {
+                    ServiceError::validation(format!(
+                        "{} can't afford the {} gp of materials",
+                        character.name,
+                        format_gp(recipe.cost_cp)
+                    ))
+                })?;
+                let reason = format!("Materials for {}", recipe.item_name);
+                set_purse(conn, &character, paid, &reason)?;
+            }
+
+            let id = Uuid::new_v4().to_string();
+            let mut project = NewCraftingProject::new(
+                &id,
+                &input.character_id,
+                &recipe.item_name,
+                &recipe.item_source,
+                recipe.cost_cp,
+                recipe.days,
+            )
+            .with_quantity(recipe.quantity);
+            if let Some(ref rarity) = recipe.rarity {
+                project = project.with_rarity(rarity);
+            }
+            if let Some(ref tool) = recipe.tool {
+                project = project.with_tool(tool);
+            }
+            if let Some(ref ingredient) = recipe.ingredient {
+                project = project.with_ingredient(ingredient);
+            }
+            if let Some(ref notes) = input.notes {
+                project = project.with_notes(notes);
+            }
+            dal::insert_crafting_project(conn, &project)?;
+
+            dal::get_crafting_project(conn, &id).map_err(ServiceError::from)
+        })
+    }
+
+    /// Log downtime days against a project. Once every day is logged and
+    /// the exotic material is in hand, the project completes and the item
+    /// goes into the crafter's inventory.
+    pub fn advance(
+        &mut self,
+        id: &str,
+        input: AdvanceCraftingInput,
+    ) -> ServiceResult<CraftingProgress> {
+        if input.days < 0 {
+            return Err(ServiceError::validation("Days cannot be negative"));
+        }
+        if input.days == 0 && input.ingredient_acquired.is_none() {
+            return Err(ServiceError::validation("No downtime days given"));
+        }
+
+        self.conn.transaction(|conn| {
+            let project = dal::get_crafting_project_optional(conn, id)?
+                .ok_or_else(|| ServiceError::not_found("CraftingProject", id))?;
+            if !project.is_in_progress() {
+                return Err(ServiceError::validation(format!(
+                    "Crafting {} is already {}",
+                    project.item_name,
+                    project.status.replace('_', " ")
+                )));
+            }
+
+            let days_spent = (project.days_spent + input.days).min(project.days_required);
+            let ingredient_acquired = input
+                .ingredient_acquired
+                .unwrap_or(project.ingredient_acquired);
+            let finished = days_spent == project.days_required
+                && (project.ingredient.is_none() || ingredient_acquired);
+
+            let now = now_rfc3339();
+            let update = UpdateCraftingProject {
+                days_spent: Some(days_spent),
+                ingredient_acquired: Some(ingredient_acquired),
+                status: finished.then_some(CraftingStatus::Completed.as_str()),
+                completed_at: finished.then_some(Some(now.as_str())),
+                updated_at: Some(&now),
+                ..Default::default()
+            };
+            dal::update_crafting_project(conn, id, &update)?;
+
+            let item = if finished {
+                let inventory_id = Uuid::new_v4().to_string();
+                let item = NewCharacterInventory::new(
+                    &inventory_id,
+                    &project.character_id,
+                    &project.item_name,
+                    &project.item_source,
+                )
+                .with_quantity(project.quantity);
+                dal::insert_character_inventory(conn, &item)?;
+                Some(dal::get_character_inventory(conn, &inventory_id)?)
+            } else {
+                None
+            };
+
+            Ok(CraftingProgress {
+                project: dal::get_crafting_project(conn, id)?,
+                item,
+            })
+        })
+    }
+
+    /// Abandon a project. Materials already bought are not refunded.
+    pub fn abandon(&mut self, id: &str) -> ServiceResult<CraftingProject> {
+        let project = self.get(id)?;
+        if !project.is_in_progress() {
+            return Err(ServiceError::validation(format!(
+                "Crafting {} is already {}",
+                project.item_name,
+                project.status.replace('_', " ")
+            )));
+        }
+        let now = now_rfc3339();
+        let update = UpdateCraftingProject {
+            status: Some(CraftingStatus::Abandoned.as_str()),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_crafting_project(self.conn, id, &update)?;
+        self.get(id)
+    }
+}
+
+/// Work out the crafting recipe for an item from its 5etools JSON.
+pub fn crafting_recipe(
+    item_name: &str,
+    item_source: &str,
+    data: &Value,
+    quantity: i32,
+) -> ServiceResult<CraftingRecipe> {
+    let item_type = data["type"]
+        .as_str()
+        .map(|t| t.split('|').next().unwrap_or(t));
+    let consumable = matches!(item_type, Some("P") | Some("SC"));
+    let rarity = data["rarity"]
+        .as_str()
+        .map(str::to_lowercase)
+        .filter(|r| r != "none");
+
+    let (cost_cp, days, min_level, ingredient) = match rarity {
+        None => {
+            let value = data["value"].as_f64().filter(|v| *v > 0.0).ok_or_else(|| {
+                ServiceError::validation(format!("{} has no market value to craft from", item_name))
+            })?;
+            let value = value as i64 * quantity as i64;
+            let days = (value + MUNDANE_PROGRESS_CP_PER_DAY - 1) / MUNDANE_PROGRESS_CP_PER_DAY;
+            (value / 2, days.max(1), None, None)
+        }
+        Some(ref rarity) => {
+            let (_, workweeks, gp, level, cr_range) = MAGIC_ITEM_RULES
+                .iter()
+                .find(|(r, ..)| *r == rarity.as_str())
+                .copied()
+                .ok_or_else(|| {
+                    ServiceError::validation(format!(
+                        "{} ({}) can't be crafted with the downtime rules",
+                        item_name, rarity
+                    ))
+                })?;
+            let mut cost = gp * 100 * quantity as i64;
+            let mut days = (workweeks * DAYS_PER_WORKWEEK) as i64 * quantity as i64;
+            if consumable {
+                cost /= 2;
+                days = (days + 1) / 2;
+            }
+            let ingredient = format!("Exotic material from a CR {} creature", cr_range);
+            (cost, days, Some(level), Some(ingredient))
+        }
+    };
+
+    let cost_cp = i32::try_from(cost_cp)
+        .map_err(|_| ServiceError::validation(format!("Crafting {} costs too much", item_name)))?;
+    let days = i32::try_from(days)
+        .map_err(|_| ServiceError::validation(format!("Crafting {} takes too long", item_name)))?;
+
+    Ok(CraftingRecipe {
+        item_name: item_name.to_string(),
+        item_source: item_source.to_string(),
+        quantity,
+        rarity,
+        consumable,
+        cost_cp,
+        days,
+        min_level,
+        tool: item_type.and_then(crafting_tool).map(str::to_string),
+        ingredient,
+    })
+}
+
+/// Tool proficiency for crafting an item type (XGE tool descriptions).
+fn crafting_tool(item_type: &str) -> Option<&'static str> {
+    match item_type {
+        "M" | "MA" | "HA" | "S" => Some("Smith's Tools"),
+        "R" | "A" => Some("Woodcarver's Tools"),
+        "LA" => Some("Leatherworker's Tools"),
+        "P" => Some("Herbalism Kit"),
+        "SC" => Some("Calligrapher's Supplies"),
+        _ => None,
+    }
+}
+
+/// Check the crafter's level and tool proficiency.
+fn check_requirements(
+    conn: &mut SqliteConnection,
+    character: &Character,
+    recipe: &CraftingRecipe,
+) -> ServiceResult<()> {
+    if let Some(min_level) = recipe.min_level {
+        let level = dal::get_total_level(conn, &character.id)? as i32;
+        if level < min_level {
+            return Err(ServiceError::validation(format!(
+                "{} must be level {} to craft {} items (currently level {})",
+                character.name,
+                min_level,
+                recipe.rarity.as_deref().unwrap_or("magic"),
+                level
+            )));
+        }
+    }
+    if let Some(ref tool) = recipe.tool {
+        let proficient = dal::list_character_proficiencies(conn, &character.id)?
+            .iter()
+            .any(|p| p.name.eq_ignore_ascii_case(tool));
+        if !proficient {
+            return Err(ServiceError::validation(format!(
+                "{} needs proficiency with {} to craft {}",
+                character.name, tool, recipe.item_name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Format copper as gold without a trailing ".00".
+fn format_gp(cp: i32) -> String {
+    if cp % 100 == 0 {
+        format!("{}", cp / 100)
+    } else {
+        format!("{:.2}", cp as f64 / 100.0)
+    }
+}
+
+/// Load an item's JSON from the catalog, or campaign homebrew for "HB",
+/// returning its canonical name.
+fn load_item(
+    conn: &mut SqliteConnection,
+    campaign_id: Option<&str>,
+    name: &str,
+    source: &str,
+) -> ServiceResult<(String, Value)> {
+    let not_found = || ServiceError::not_found("Item", format!("{} ({})", name, source));
+    let (name, data, rarity) = if source == HOMEBREW_SOURCE {
+        let campaign_id = campaign_id.ok_or_else(not_found)?;
+        let item = dal::get_campaign_homebrew_item_by_name(conn, campaign_id, name)?
+            .ok_or_else(not_found)?;
+        (item.name, item.data, item.rarity)
+    } else {
+        let item = catalog_dal::get_item_by_name(conn, name, source)?.ok_or_else(not_found)?;
+        (item.name, item.data, item.rarity)
+    };
+    let mut data: Value = serde_json::from_str(&data)
+        .map_err(|e| ServiceError::validation(format!("Invalid item data for {}: {}", name, e)))?;
+    if data["rarity"].is_null() {
+        if let (Some(rarity), Some(obj)) = (rarity, data.as_object_mut()) {
+            obj.insert("rarity".to_string(), Value::String(rarity));
+        }
+    }
+    Ok((name, data))
+}
+
+fn require_character(conn: &mut SqliteConnection, character_id: &str) -> ServiceResult<Character> {
+    dal::get_character_optional(conn, character_id)?
+        .ok_or_else(|| ServiceError::not_found("Character", character_id))
+}
+
+fn positive_quantity(quantity: i32) -> ServiceResult<i32> {
+    if quantity < 1 {
+        return Err(ServiceError::validation(format!(
+            "Quantity must be at least 1, got {}",
+            quantity
+        )));
+    }
+    Ok(quantity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_character_proficiency, list_character_inventory,
+    };
+    use crate::dal::catalog::insert_item;
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewCharacterProficiency};
+    use crate::models::catalog::NewItem;
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    #[test]
+    fn test_mundane_recipe() {
+        // Plate armor: 1,500 gp takes 30 workweeks and 750 gp of materials
+        let data = json!({"type": "HA", "value": 150000});
+        let recipe = crafting_recipe("Plate Armor", "PHB", &data, 1).unwrap();
+        assert_eq!(recipe.cost_cp, 75000);
+        assert_eq!(recipe.days, 150);
+        assert_eq!(recipe.tool.as_deref(), Some("Smith's Tools"));
+        assert!(recipe.ingredient.is_none());
+    }
+
+    #[test]
+    fn test_magic_recipe_halves_consumables() {
+        let data = json!({"type": "P", "rarity": "rare"});
+        let recipe = crafting_recipe("Potion of Heroism", "DMG", &data, 1).unwrap();
+        assert_eq!(recipe.cost_cp, 100_000);
+        assert_eq!(recipe.days, 25);
+        assert_eq!(recipe.min_level, Some(6));
+        assert_eq!(
+            recipe.ingredient.as_deref(),
+            Some("Exotic material from a CR 9-12 creature")
+        );
+
+        let artifact = json!({"rarity": "artifact"});
+        assert!(crafting_recipe("Eye of Vecna", "DMG", &artifact, 1).is_err());
+    }
+
+    #[test]
+    fn test_craft_longsword_into_inventory() {
+        let mut conn = setup_test_db_with_sources();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            &mut conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Brom", "Alice")
+                .with_currency(0, 0, 0, 20, 0),
+        )
+        .unwrap();
+        insert_character_proficiency(
+            &mut conn,
+            &NewCharacterProficiency::tool("prof-1", "char-1", "Smith's Tools"),
+        )
+        .unwrap();
+        let longsword = r#"{"name": "Longsword", "source": "PHB", "type": "M", "value": 1500}"#;
+        insert_item(&mut conn, &NewItem::new("Longsword", "PHB", longsword)).unwrap();
+
+        let mut service = CraftingService::new(&mut conn);
+        let project = service
+            .start(StartCraftingInput {
+                character_id: "char-1".to_string(),
+                item_name: "longsword".to_string(),
+                item_source: "PHB".to_string(),
+                quantity: None,
+                pay_from_purse: true,
+                ignore_requirements: false,
+                notes: None,
+            })
+            .unwrap();
+        assert_eq!(project.item_name, "Longsword");
+        assert_eq!(project.cost_cp, 750);
+        assert_eq!(project.days_required, 2);
+
+        let progress = service
+            .advance(
+                &project.id,
+                AdvanceCraftingInput {
+                    days: 1,
+                    ingredient_acquired: None,
+                },
+            )
+            .unwrap();
+        assert!(progress.item.is_none());
+        let progress = service
+            .advance(
+                &project.id,
+                AdvanceCraftingInput {
+                    days: 5,
+                    ingredient_acquired: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(progress.project.status, "completed");
+        assert_eq!(progress.project.days_spent, 2);
+        assert!(progress.item.is_some());
+
+        let character = dal::get_character(&mut conn, "char-1").unwrap();
+        assert_eq!(
+            [
+                character.cp,
+                character.sp,
+                character.ep,
+                character.gp,
+                character.pp
+            ],
+            [0, 5, 0, 12, 0]
+        );
+        let ledger = dal::list_character_currency_transactions(&mut conn, "char-1").unwrap();
+        assert_eq!(ledger[0].reason, "Materials for Longsword");
+        let inventory = list_character_inventory(&mut conn, "char-1").unwrap();
+        assert!(inventory.iter().any(|i| i.item_name == "Longsword"));
+    }
+
+    #[test]
+    fn test_magic_item_waits_for_ingredient() {
+        let mut conn = setup_test_db_with_sources();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            &mut conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+        let bag = r#"{"name": "Bag of Holding", "source": "DMG", "rarity": "uncommon"}"#;
+        insert_item(&mut conn, &NewItem::new("Bag of Holding", "DMG", bag)).unwrap();
+
+        let mut service = CraftingService::new(&mut conn);
+        let input = StartCraftingInput {
+            character_id: "char-1".to_string(),
+            item_name: "Bag of Holding".to_string(),
+            item_source: "DMG".to_string(),
+            quantity: None,
+            pay_from_purse: false,
+            ignore_requirements: false,
+            notes: None,
+        };
+        // A character with no class levels is below the minimum level
+        assert!(service.start(input.clone()).is_err());
+
+        let project = service
+            .start(StartCraftingInput {
+                ignore_requirements: true,
+                ..input
+            })
+            .unwrap();
+        let progress = service
+            .advance(
+                &project.id,
+                AdvanceCraftingInput {
+                    days: 10,
+                    ingredient_acquired: None,
+                },
+            )
+            .unwrap();
+        assert!(progress.item.is_none());
+        assert!(progress.project.needs_ingredient());
+
+        let progress = service
+            .advance(
+                &project.id,
+                AdvanceCraftingInput {
+                    days: 0,
+                    ingredient_acquired: Some(true),
+                },
+            )
+            .unwrap();
+        assert_eq!(progress.project.status, "completed");
+        assert!(progress.item.is_some());
+    }
+}
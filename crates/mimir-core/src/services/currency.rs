@@ -0,0 +1,324 @@
+//! Currency Service
+//!
+//! Characters' coins and the ledger of every change to them. Gains and
+//! spending go through here with a reason, so a purse can be audited later.
+//! Also provides denomination helpers: totals in copper, making change when
+//! paying a cost, and consolidating loose coins into gold.
+
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    Character, CurrencyTransaction, NewCurrencyTransaction, UpdateCharacter,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Coin values in copper, as [CP, SP, EP, GP, PP].
+pub const COIN_VALUES_CP: [i64; 5] = [1, 10, 50, 100, 1000];
+
+const DENOMINATIONS: [&str; 5] = ["cp", "sp", "ep", "gp", "pp"];
+
+/// Total value of coins ([CP, SP, EP, GP, PP]) in copper.
+pub fn total_cp(coins: [i32; 5]) -> i64 {
+    coins
+        .iter()
+        .zip(COIN_VALUES_CP)
+        .map(|(&count, value)| count as i64 * value)
+        .sum()
+}
+
+/// Pay an amount in copper from a purse ([CP, SP, EP, GP, PP]), spending the
+/// smallest coins first and breaking one larger coin for change when needed.
+/// Returns the purse afterwards, or None if it can't cover the cost.
+pub fn make_change(purse: [i32; 5], cost_cp: i64) -> Option<[i32; 5]> {
+    let mut coins = purse;
+    let mut owed = cost_cp;
+    for (count, value) in coins.iter_mut().zip(COIN_VALUES_CP) {
+        let used = (*count as i64).min(owed / value);
+        *count -= used as i32;
+        owed -= used * value;
+    }
+    if owed > 0 {
+        // Any coin left is worth more than what's owed; break the smallest.
+        let i = coins.iter().position(|&c| c > 0)?;
+        coins[i] -= 1;
+        let change = COIN_VALUES_CP[i] - owed;
+        coins = add_coins(coins, coins_for(change));
+    }
+    Some(coins)
+}
+
+/// Convert every coin to gold, keeping what doesn't make a whole gold piece
+/// as silver and copper.
+pub fn consolidate_to_gold(coins: [i32; 5]) -> [i32; 5] {
+    let total = total_cp(coins);
+    let gp = total / 100;
+    let sp = (total % 100) / 10;
+    let cp = total % 10;
+    [cp as i32, sp as i32, 0, gp as i32, 0]
+}
+
+/// Express an amount of copper in platinum, gold, silver, and copper (no
+/// electrum).
+fn coins_for(mut amount_cp: i64) -> [i32; 5] {
+    let mut coins = [0; 5];
+    for i in [4, 3, 1, 0] {
+        coins[i] = (amount_cp / COIN_VALUES_CP[i]) as i32;
+        amount_cp %= COIN_VALUES_CP[i];
+    }
+    coins
+}
+
+fn add_coins(a: [i32; 5], b: [i32; 5]) -> [i32; 5] {
+    std::array::from_fn(|i| a[i] + b[i])
+}
+
+fn purse(character: &Character) -> [i32; 5] {
+    [
+        character.cp,
+        character.sp,
+        character.ep,
+        character.gp,
+        character.pp,
+    ]
+}
+
+/// Set a character's coins and record the difference in the ledger.
+/// Returns the transaction, or None when nothing changed.
+pub(crate) fn set_purse(
+    conn: &mut SqliteConnection,
+    character: &Character,
+    coins: [i32; 5],
+    reason: &str,
+) -> ServiceResult<Option<CurrencyTransaction>> {
+    let before = purse(character);
+    let delta: [i32; 5] = std::array::from_fn(|i| coins[i] - before[i]);
+    if delta.iter().all(|&d| d == 0) {
+        return Ok(None);
+    }
+
+    let now = now_rfc3339();
+    let update =
+        UpdateCharacter::set_currency(coins[0], coins[1], coins[2], coins[3], coins[4], &now);
+    dal::update_character(conn, &character.id, &update)?;
+
+    let id = Uuid::new_v4().to_string();
+    let transaction = NewCurrencyTransaction::new(&id, &character.id, reason, delta);
+    dal::insert_currency_transaction(conn, &transaction)?;
+    Ok(Some(dal::get_currency_transaction(conn, &id)?))
+}
+
+/// Input for changing a character's coins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyAdjustment {
+    /// Change as [CP, SP, EP, GP, PP]; negative amounts are spent
+    pub amounts: [i32; 5],
+    /// Why the coins changed
+    pub reason: String,
+    /// Pay amounts the purse lacks in a denomination from other coins,
+    /// making change (e.g., spend 5 sp from a purse of gold)
+    #[serde(default)]
+    pub make_change: bool,
+}
+
+/// A character's coins after a change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyResult {
+    /// Coins as [CP, SP, EP, GP, PP]
+    pub balance: [i32; 5],
+    /// Ledger entry for the change (None when nothing changed)
+    pub transaction: Option<CurrencyTransaction>,
+}
+
+/// Service for characters' coins.
+pub struct CurrencyService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> CurrencyService<'a> {
+    /// Create a new currency service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// A character's currency transactions, newest first.
+    pub fn history(&mut self, character_id: &str) -> ServiceResult<Vec<CurrencyTransaction>> {
+        require_character(self.conn, character_id)?;
+        dal::list_character_currency_transactions(self.conn, character_id)
+            .map_err(ServiceError::from)
+    }
+
+    /// Add or remove coins. Without `make_change`, no denomination may go
+    /// below zero.
+    pub fn adjust(
+        &mut self,
+        character_id: &str,
+        adjustment: CurrencyAdjustment,
+    ) -> ServiceResult<CurrencyResult> {
+        let reason = require_reason(&adjustment.reason)?;
+        self.conn.transaction(|conn| {
+            let character = require_character(conn, character_id)?;
+            let current = purse(&character);
+            let mut coins = add_coins(current, adjustment.amounts);
+
+            if let Some(i) = coins.iter().position(|&c| c < 0) {
+                if !adjustment.make_change {
+                    return Err(ServiceError::validation(format!(
+                        "{} only has {} {}",
+                        character.name, current[i], DENOMINATIONS[i]
+                    )));
+                }
+                // Gains first, then pay what's spent from whatever coins there are
+                let gains = adjustment.amounts.map(|a| a.max(0));
+                let owed = -total_cp(adjustment.amounts.map(|a| a.min(0)));
+                coins = make_change(add_coins(current, gains), owed)
+                    .ok_or_else(|| cannot_afford(&character, owed))?;
+            }
+
+            let transaction = set_purse(conn, &character, coins, reason)?;
+            Ok(CurrencyResult {
+                balance: coins,
+                transaction,
+            })
+        })
+    }
+
+    /// Spend an amount given in copper, making change from the purse.
+    pub fn spend(
+        &mut self,
+        character_id: &str,
+        cost_cp: i64,
+        reason: &str,
+    ) -> ServiceResult<CurrencyResult> {
+        let reason = require_reason(reason)?;
+        if cost_cp <= 0 {
+            return Err(ServiceError::validation("Cost must be more than zero"));
+        }
+        self.conn.transaction(|conn| {
+            let character = require_character(conn, character_id)?;
+            let coins = make_change(purse(&character), cost_cp)
+                .ok_or_else(|| cannot_afford(&character, cost_cp))?;
+            let transaction = set_purse(conn, &character, coins, reason)?;
+            Ok(CurrencyResult {
+                balance: coins,
+                transaction,
+            })
+        })
+    }
+
+    /// Convert a character's coins to gold, keeping leftover silver and
+    /// copper.
+    pub fn consolidate(&mut self, character_id: &str) -> ServiceResult<CurrencyResult> {
+        self.conn.transaction(|conn| {
+            let character = require_character(conn, character_id)?;
+            let coins = consolidate_to_gold(purse(&character));
+            let transaction = set_purse(conn, &character, coins, "Consolidated to gold")?;
+            Ok(CurrencyResult {
+                balance: coins,
+                transaction,
+            })
+        })
+    }
+}
+
+fn require_character(conn: &mut SqliteConnection, character_id: &str) -> ServiceResult<Character> {
+    dal::get_character_optional(conn, character_id)?
+        .ok_or_else(|| ServiceError::not_found("Character", character_id))
+}
+
+fn require_reason(reason: &str) -> ServiceResult<&str> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return Err(ServiceError::validation("A reason is required"));
+    }
+    Ok(reason)
+}
+
+fn cannot_afford(character: &Character, cost_cp: i64) -> ServiceError {
+    ServiceError::validation(format!(
+        "{} can't afford {} gp",
+        character.name,
+        cost_cp as f64 / 100.0
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice")
+                .with_currency(0, 0, 0, 10, 0),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_make_change() {
+        assert_eq!(make_change([0, 0, 0, 2, 0], 150), Some([0, 5, 0, 0, 0]));
+        assert_eq!(make_change([5, 3, 0, 10, 0], 35), Some([0, 0, 0, 10, 0]));
+        assert_eq!(make_change([0, 0, 0, 0, 1], 250), Some([0, 5, 0, 7, 0]));
+        assert_eq!(make_change([0, 0, 0, 1, 0], 150), None);
+    }
+
+    #[test]
+    fn test_consolidate_to_gold() {
+        assert_eq!(consolidate_to_gold([123, 45, 3, 7, 2]), [3, 7, 0, 30, 0]);
+        assert_eq!(total_cp([123, 45, 3, 7, 2]), 3073);
+    }
+
+    #[test]
+    fn test_adjust_records_transaction() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CurrencyService::new(&mut conn);
+
+        let spend_silver = CurrencyAdjustment {
+            amounts: [0, -5, 0, 0, 0],
+            reason: "Inn".to_string(),
+            make_change: false,
+        };
+        assert!(service.adjust("char-1", spend_silver.clone()).is_err());
+
+        let result = service
+            .adjust(
+                "char-1",
+                CurrencyAdjustment {
+                    make_change: true,
+                    ..spend_silver
+                },
+            )
+            .unwrap();
+        assert_eq!(result.balance, [0, 5, 0, 9, 0]);
+        assert_eq!(result.transaction.unwrap().amounts(), [0, 5, 0, -1, 0]);
+
+        let history = service.history("char-1").unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reason, "Inn");
+    }
+
+    #[test]
+    fn test_spend_and_consolidate() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CurrencyService::new(&mut conn);
+
+        assert!(service.spend("char-1", 2000, "Plate armor").is_err());
+        let result = service.spend("char-1", 25, "Rations").unwrap();
+        assert_eq!(result.balance, [5, 7, 0, 9, 0]);
+
+        let result = service.consolidate("char-1").unwrap();
+        assert_eq!(result.balance, [5, 7, 0, 9, 0]);
+        assert!(result.transaction.is_none());
+        assert_eq!(service.history("char-1").unwrap().len(), 1);
+    }
+}
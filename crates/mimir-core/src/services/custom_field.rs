@@ -0,0 +1,626 @@
+//! Custom Field Service
+//!
+//! Business logic for custom character sheet fields. A campaign defines the
+//! fields its house rules need (sanity, faction renown, an inspiration dice
+//! pool); characters in the campaign hold a value for each, falling back to
+//! the field's default. Values are validated against the field's type when
+//! set, so the sheet never shows "banana" as a sanity score.
+
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    CampaignCustomField, CharacterCustomField, CustomFieldType, NewCampaignCustomField,
+    NewCharacterCustomField, UpdateCampaignCustomField,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Longest allowed field key.
+const MAX_KEY_LENGTH: usize = 40;
+
+/// Input for defining a custom field.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateCustomFieldInput {
+    /// Label shown on the character sheet
+    pub label: String,
+    /// Value type
+    pub field_type: CustomFieldType,
+    /// Storage key; derived from the label when omitted
+    #[serde(default)]
+    pub field_key: Option<String>,
+    /// Value for characters that have not set one
+    #[serde(default)]
+    pub default_value: Option<String>,
+    /// Lowest allowed value (number fields)
+    #[serde(default)]
+    pub min_value: Option<i32>,
+    /// Highest allowed value (number fields)
+    #[serde(default)]
+    pub max_value: Option<i32>,
+    /// What the field tracks
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl CreateCustomFieldInput {
+    /// Create input for a field with the given label and type.
+    pub fn new(label: impl Into<String>, field_type: CustomFieldType) -> Self {
+        Self {
+            label: label.into(),
+            field_type,
+            field_key: None,
+            default_value: None,
+            min_value: None,
+            max_value: None,
+            description: None,
+        }
+    }
+
+    /// Set the default value.
+    pub fn with_default(mut self, default_value: impl Into<String>) -> Self {
+        self.default_value = Some(default_value.into());
+        self
+    }
+
+    /// Set the allowed range for a number field.
+    pub fn with_range(mut self, min_value: Option<i32>, max_value: Option<i32>) -> Self {
+        self.min_value = min_value;
+        self.max_value = max_value;
+        self
+    }
+}
+
+/// Input for updating a custom field. The key and type cannot change, since
+/// characters' stored values depend on them.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateCustomFieldInput {
+    /// New label
+    pub label: Option<String>,
+    /// New default value (Some(None) clears it)
+    pub default_value: Option<Option<String>>,
+    /// New lowest value (Some(None) clears it)
+    pub min_value: Option<Option<i32>>,
+    /// New highest value (Some(None) clears it)
+    pub max_value: Option<Option<i32>>,
+    /// New description (Some(None) clears it)
+    pub description: Option<Option<String>>,
+}
+
+/// A character's value for one of their campaign's custom fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomFieldValue {
+    /// Field key
+    pub field_key: String,
+    /// Label shown on the character sheet
+    pub label: String,
+    /// Value type
+    pub field_type: CustomFieldType,
+    /// The character's value, or the field default when unset
+    pub value: Option<String>,
+    /// Whether `value` is the field default rather than the character's own
+    pub is_default: bool,
+}
+
+impl CustomFieldValue {
+    /// The value as shown on a character sheet, e.g. "Yes" for a true
+    /// boolean. `None` when the field has no value or default.
+    pub fn display_value(&self) -> Option<String> {
+        let value = self.value.as_deref()?;
+        Some(match (self.field_type, value) {
+            (CustomFieldType::Boolean, "true") => "Yes".to_string(),
+            (CustomFieldType::Boolean, "false") => "No".to_string(),
+            _ => value.to_string(),
+        })
+    }
+}
+
+/// Pair a campaign's field definitions with a character's stored values, in
+/// sheet order. Values for fields the campaign no longer defines are ignored.
+pub fn resolve_custom_field_values(
+    fields: &[CampaignCustomField],
+    stored: &[CharacterCustomField],
+) -> Vec<CustomFieldValue> {
+    fields
+        .iter()
+        .map(|field| {
+            let own = stored.iter().find(|v| v.field_key == field.field_key);
+            CustomFieldValue {
+                field_key: field.field_key.clone(),
+                label: field.label.clone(),
+                field_type: field.field_type_enum().unwrap_or(CustomFieldType::Text),
+                value: own
+                    .map(|v| v.value.clone())
+                    .or_else(|| field.default_value.clone()),
+                is_default: own.is_none(),
+            }
+        })
+        .collect()
+}
+
+/// Service for custom field definitions and character values.
+pub struct CustomFieldService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> CustomFieldService<'a> {
+    /// Create a new custom field service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a campaign's custom fields in sheet order.
+    pub fn list_fields(&mut self, campaign_id: &str) -> ServiceResult<Vec<CampaignCustomField>> {
+        Ok(dal::list_campaign_custom_fields(self.conn, campaign_id)?)
+    }
+
+    /// Define a custom field at the end of a campaign's sheet.
+    pub fn create_field(
+        &mut self,
+        campaign_id: &str,
+        input: CreateCustomFieldInput,
+    ) -> ServiceResult<CampaignCustomField> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+
+        let label = input.label.trim();
+        if label.is_empty() {
+            return Err(ServiceError::validation("Field label cannot be empty"));
+        }
+        let field_key = match input.field_key.as_deref().map(str::trim) {
+            Some(key) if !key.is_empty() => key.to_string(),
+            _ => field_key_from_label(label),
+        };
+        validate_key(&field_key)?;
+        if dal::get_campaign_custom_field_by_key(self.conn, campaign_id, &field_key)?.is_some() {
+            return Err(ServiceError::validation(format!(
+                "A field with key '{}' already exists",
+                field_key
+            )));
+        }
+
+        let (min_value, max_value) =
+            validate_range(input.field_type, input.min_value, input.max_value)?;
+        let default_value = input
+            .default_value
+            .as_deref()
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| normalize_value(input.field_type, min_value, max_value, v))
+            .transpose()?;
+        let description = input
+            .description
+            .as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty());
+
+        let id = Uuid::new_v4().to_string();
+        let sort_order = dal::next_campaign_custom_field_sort_order(self.conn, campaign_id)?;
+        let mut new_field =
+            NewCampaignCustomField::new(&id, campaign_id, &field_key, label, input.field_type)
+                .with_range(min_value, max_value)
+                .with_sort_order(sort_order);
+        if let Some(ref default_value) = default_value {
+            new_field = new_field.with_default(default_value);
+        }
+        if let Some(description) = description {
+            new_field = new_field.with_description(description);
+        }
+
+        dal::insert_campaign_custom_field(self.conn, &new_field)?;
+        Ok(dal::get_campaign_custom_field(self.conn, &id)?)
+    }
+
+    /// Update a custom field's label, default, range, or description.
+    pub fn update_field(
+        &mut self,
+        id: &str,
+        input: UpdateCustomFieldInput,
+    ) -> ServiceResult<CampaignCustomField> {
+        let field = self.get_field(id)?;
+        let field_type = field_type(&field)?;
+
+        let label = input.label.as_deref().map(str::trim);
+        if label == Some("") {
+            return Err(ServiceError::validation("Field label cannot be empty"));
+        }
+        let (min_value, max_value) = validate_range(
+            field_type,
+            input.min_value.unwrap_or(field.min_value),
+            input.max_value.unwrap_or(field.max_value),
+        )?;
+        let default_value = match input.default_value {
+            Some(Some(ref v)) if !v.trim().is_empty() => {
+                Some(Some(normalize_value(field_type, min_value, max_value, v)?))
+            }
+            Some(_) => Some(None),
+            None => None,
+        };
+
+        let now = now_rfc3339();
+        let update = UpdateCampaignCustomField {
+            label,
+            default_value: default_value.as_ref().map(|d| d.as_deref()),
+            min_value: input.min_value.map(|_| min_value),
+            max_value: input.max_value.map(|_| max_value),
+            description: input
+                .description
+                .as_ref()
+                .map(|d| d.as_deref().map(str::trim).filter(|d| !d.is_empty())),
+            sort_order: None,
+            updated_at: Some(&now),
+        };
+        dal::update_campaign_custom_field(self.conn, id, &update)?;
+        self.get_field(id)
+    }
+
+    /// Reorder a campaign's custom fields.
+    ///
+    /// `field_ids` lists the campaign's fields in their new order.
+    pub fn reorder_fields(
+        &mut self,
+        campaign_id: &str,
+        field_ids: &[String],
+    ) -> ServiceResult<Vec<CampaignCustomField>> {
+        self.conn.transaction(|conn| {
+            let existing = dal::list_campaign_custom_fields(conn, campaign_id)?;
+            let now = now_rfc3339();
+
+            for (index, field_id) in field_ids.iter().enumerate() {
+                if !existing.iter().any(|f| &f.id == field_id) {
+                    return Err(ServiceError::validation(format!(
+                        "Custom field {} is not in this campaign",
+                        field_id
+                    )));
+                }
+                let update = UpdateCampaignCustomField {
+                    sort_order: Some(index as i32 + 1),
+                    updated_at: Some(&now),
+                    ..Default::default()
+                };
+                dal::update_campaign_custom_field(conn, field_id, &update)?;
+            }
+            Ok(())
+        })?;
+
+        self.list_fields(campaign_id)
+    }
+
+    /// Delete a custom field and every character's value for it.
+    pub fn delete_field(&mut self, id: &str) -> ServiceResult<()> {
+        let field = self.get_field(id)?;
+
+        self.conn.transaction(|conn| {
+            dal::delete_campaign_custom_field_values(conn, &field.campaign_id, &field.field_key)?;
+            dal::delete_campaign_custom_field(conn, id)?;
+            Ok(())
+        })
+    }
+
+    /// A character's values for each of their campaign's fields, in sheet
+    /// order. Characters outside a campaign have none.
+    pub fn character_values(&mut self, character_id: &str) -> ServiceResult<Vec<CustomFieldValue>> {
+        let character = dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+        let Some(campaign_id) = character.campaign_id else {
+            return Ok(Vec::new());
+        };
+
+        let fields = dal::list_campaign_custom_fields(self.conn, &campaign_id)?;
+        let stored = dal::list_character_custom_fields(self.conn, character_id)?;
+        Ok(resolve_custom_field_values(&fields, &stored))
+    }
+
+    /// Set a character's value for a custom field. An empty value resets it
+    /// to the field default.
+    pub fn set_value(
+        &mut self,
+        character_id: &str,
+        field_key: &str,
+        value: &str,
+    ) -> ServiceResult<Vec<CustomFieldValue>> {
+        let field = self.get_character_field(character_id, field_key)?;
+
+        if value.trim().is_empty() {
+            dal::delete_character_custom_field(self.conn, character_id, field_key)?;
+        } else {
+            let value =
+                normalize_value(field_type(&field)?, field.min_value, field.max_value, value)?;
+            let now = now_rfc3339();
+            dal::upsert_character_custom_field(
+                self.conn,
+                &NewCharacterCustomField::new(character_id, field_key, &value, &now),
+            )?;
+        }
+
+        self.character_values(character_id)
+    }
+
+    /// Reset a character's value for a custom field to the field default.
+    pub fn clear_value(
+        &mut self,
+        character_id: &str,
+        field_key: &str,
+    ) -> ServiceResult<Vec<CustomFieldValue>> {
+        self.set_value(character_id, field_key, "")
+    }
+
+    fn get_field(&mut self, id: &str) -> ServiceResult<CampaignCustomField> {
+        dal::get_campaign_custom_field(self.conn, id).map_err(|e| match e {
+            diesel::result::Error::NotFound => ServiceError::not_found("CustomField", id),
+            e => e.into(),
+        })
+    }
+
+    /// Look up a field by key in the character's campaign.
+    fn get_character_field(
+        &mut self,
+        character_id: &str,
+        field_key: &str,
+    ) -> ServiceResult<CampaignCustomField> {
+        let character = dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+        let campaign_id = character.campaign_id.ok_or_else(|| {
+            ServiceError::validation("Character is not in a campaign, so has no custom fields")
+        })?;
+
+        dal::get_campaign_custom_field_by_key(self.conn, &campaign_id, field_key)?
+            .ok_or_else(|| ServiceError::not_found("CustomField", field_key))
+    }
+}
+
+fn field_type(field: &CampaignCustomField) -> ServiceResult<CustomFieldType> {
+    field.field_type_enum().ok_or_else(|| {
+        ServiceError::validation(format!("Unknown custom field type '{}'", field.field_type))
+    })
+}
+
+/// Derive a storage key from a label: "Faction Renown" -> "faction_renown".
+fn field_key_from_label(label: &str) -> String {
+    let mut key = String::new();
+    for c in label.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            key.push(c);
+        } else if !key.is_empty() && !key.ends_with('_') {
+            key.push('_');
+        }
+    }
+    key.trim_end_matches('_').to_string()
+}
+
+fn validate_key(key: &str) -> ServiceResult<()> {
+    let valid = key.len() <= MAX_KEY_LENGTH
+        && key.starts_with(|c: char| c.is_ascii_lowercase())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(ServiceError::validation(format!(
+            "Field key '{}' must be lowercase letters, digits, and underscores, \
+             starting with a letter (at most {} characters)",
+            key, MAX_KEY_LENGTH
+        )))
+    }
+}
+
+/// Check a number field's range. Other field types cannot have one.
+fn validate_range(
+    field_type: CustomFieldType,
+    min_value: Option<i32>,
+    max_value: Option<i32>,
+) -> ServiceResult<(Option<i32>, Option<i32>)> {
+    if field_type != CustomFieldType::Number {
+        if min_value.is_some() || max_value.is_some() {
+            return Err(ServiceError::validation(
+                "Only number fields can have a minimum or maximum",
+            ));
+        }
+        return Ok((None, None));
+    }
+    if let (Some(min), Some(max)) = (min_value, max_value) {
+        if min > max {
+            return Err(ServiceError::validation(format!(
+                "Minimum {} is greater than maximum {}",
+                min, max
+            )));
+        }
+    }
+    Ok((min_value, max_value))
+}
+
+/// Validate a value against a field type, returning its stored form.
+fn normalize_value(
+    field_type: CustomFieldType,
+    min_value: Option<i32>,
+    max_value: Option<i32>,
+    value: &str,
+) -> ServiceResult<String> {
+    let value = value.trim();
+    match field_type {
+        CustomFieldType::Text => Ok(value.to_string()),
+        CustomFieldType::Number => {
+            let n: i32 = value
+                .parse()
+                .map_err(|_| ServiceError::validation(format!("'{}' is not a number", value)))?;
+            if min_value.is_some_and(|min| n < min) || max_value.is_some_and(|max| n > max) {
+                return Err(ServiceError::validation(format!(
+                    "{} is outside the allowed range {}..{}",
+                    n,
+                    min_value.map(|v| v.to_string()).unwrap_or_default(),
+                    max_value.map(|v| v.to_string()).unwrap_or_default()
+                )));
+            }
+            Ok(n.to_string())
+        }
+        CustomFieldType::Boolean => match value.to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok("true".to_string()),
+            "false" | "no" | "0" => Ok("false".to_string()),
+            _ => Err(ServiceError::validation(format!(
+                "'{}' is not true or false",
+                value
+            ))),
+        },
+        CustomFieldType::Dice => normalize_dice(value).ok_or_else(|| {
+            ServiceError::validation(format!(
+                "'{}' is not a dice expression like 2d6 or 1d8+1",
+                value
+            ))
+        }),
+    }
+}
+
+/// Normalize a dice expression such as "2D6 + 1" to "2d6+1".
+fn normalize_dice(value: &str) -> Option<String> {
+    let compact: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    let (count, rest) = compact.split_once('d')?;
+    let count: u32 = if count.is_empty() {
+        1
+    } else {
+        count.parse().ok()?
+    };
+    let (sides, modifier) = match rest.find(['+', '-']) {
+        Some(i) => (&rest[..i], Some(&rest[i..])),
+        None => (rest, None),
+    };
+    let sides: u32 = sides.parse().ok()?;
+    if count == 0 || sides == 0 {
+        return None;
+    }
+
+    let mut out = format!("{}d{}", count, sides);
+    if let Some(modifier) = modifier {
+        let n: i32 = modifier.parse().ok()?;
+        if n != 0 {
+            out.push_str(&format!("{:+}", n));
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("char-1", Some("camp-1"), "Ada", "Alice"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_create_field_derives_key() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CustomFieldService::new(&mut conn);
+
+        let field = service
+            .create_field(
+                "camp-1",
+                CreateCustomFieldInput::new("Faction Renown", CustomFieldType::Number),
+            )
+            .unwrap();
+        assert_eq!(field.field_key, "faction_renown");
+        assert_eq!(field.sort_order, 1);
+
+        let duplicate = service.create_field(
+            "camp-1",
+            CreateCustomFieldInput::new("Faction renown", CustomFieldType::Text),
+        );
+        assert!(matches!(duplicate, Err(ServiceError::Validation(_))));
+
+        let ranged_text = service.create_field(
+            "camp-1",
+            CreateCustomFieldInput::new("Oath", CustomFieldType::Text).with_range(Some(0), None),
+        );
+        assert!(matches!(ranged_text, Err(ServiceError::Validation(_))));
+    }
+
+    #[test]
+    fn test_character_values_fall_back_to_default() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CustomFieldService::new(&mut conn);
+
+        service
+            .create_field(
+                "camp-1",
+                CreateCustomFieldInput::new("Sanity", CustomFieldType::Number)
+                    .with_default("50")
+                    .with_range(Some(0), Some(99)),
+            )
+            .unwrap();
+        service
+            .create_field(
+                "camp-1",
+                CreateCustomFieldInput::new("Inspiration Dice", CustomFieldType::Dice),
+            )
+            .unwrap();
+
+        let values = service.character_values("char-1").unwrap();
+        assert_eq!(values[0].value.as_deref(), Some("50"));
+        assert!(values[0].is_default);
+        assert_eq!(values[1].value, None);
+
+        let values = service
+            .set_value("char-1", "inspiration_dice", "2D6 + 1")
+            .unwrap();
+        assert_eq!(values[1].value.as_deref(), Some("2d6+1"));
+        assert!(!values[1].is_default);
+
+        assert!(service.set_value("char-1", "sanity", "120").is_err());
+        assert!(service.set_value("char-1", "sanity", "lots").is_err());
+        let values = service.set_value("char-1", "sanity", "42").unwrap();
+        assert_eq!(values[0].value.as_deref(), Some("42"));
+
+        let values = service.clear_value("char-1", "sanity").unwrap();
+        assert_eq!(values[0].value.as_deref(), Some("50"));
+        assert!(values[0].is_default);
+    }
+
+    #[test]
+    fn test_delete_field_removes_values() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CustomFieldService::new(&mut conn);
+
+        let field = service
+            .create_field(
+                "camp-1",
+                CreateCustomFieldInput::new("Sworn Oath", CustomFieldType::Boolean),
+            )
+            .unwrap();
+        let values = service.set_value("char-1", "sworn_oath", "yes").unwrap();
+        assert_eq!(values[0].value.as_deref(), Some("true"));
+        assert_eq!(values[0].display_value().as_deref(), Some("Yes"));
+
+        service.delete_field(&field.id).unwrap();
+        assert!(service.character_values("char-1").unwrap().is_empty());
+        assert!(dal::list_character_custom_fields(&mut conn, "char-1")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_normalize_dice() {
+        assert_eq!(normalize_dice("d8").as_deref(), Some("1d8"));
+        assert_eq!(normalize_dice("3d6 - 2").as_deref(), Some("3d6-2"));
+        assert_eq!(normalize_dice("2d6+0").as_deref(), Some("2d6"));
+        assert_eq!(normalize_dice("0d6"), None);
+        assert_eq!(normalize_dice("2x6"), None);
+    }
+}
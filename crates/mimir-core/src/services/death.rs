@@ -0,0 +1,452 @@
+//! Death Service
+//!
+//! Character death and resurrection. A death records its cause and the
+//! in-world day on the party calendar (adding a timeline event); a
+//! resurrection closes it with the method and what it cost, optionally paid
+//! from a character's purse. Dead characters are listed on the campaign
+//! memorial and left out of party XP summaries and encounter budgets.
+
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    Character, CharacterDeath, NewCharacterDeath, Party, UpdateCharacter, UpdateCharacterDeath,
+};
+use crate::services::currency::{make_change, set_purse};
+use crate::services::party::{PartyService, TimelineEventInput};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Material component costs (in copper) of the PHB spells that return the
+/// dead to life, used when a resurrection doesn't give its own cost.
+const RESURRECTION_COSTS_CP: [(&str, i32); 5] = [
+    ("revivify", 30_000),
+    ("raise dead", 50_000),
+    ("reincarnate", 100_000),
+    ("resurrection", 100_000),
+    ("true resurrection", 2_500_000),
+];
+
+/// Input for marking a character dead.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarkDeadInput {
+    /// How the character died
+    pub cause: String,
+    /// Party whose calendar dates the death; defaults to the character's
+    /// party when they belong to exactly one
+    pub party_id: Option<String>,
+    /// In-world day; defaults to the party's current day
+    pub day: Option<i32>,
+    /// Free-form notes
+    pub notes: Option<String>,
+}
+
+/// Input for bringing a dead character back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResurrectInput {
+    /// How the character came back (e.g., "Raise Dead")
+    pub method: String,
+    /// Cost in copper; defaults to the spell's material component cost
+    pub cost_cp: Option<i32>,
+    /// Character who pays the cost from their purse
+    pub paid_by: Option<String>,
+    /// Party whose calendar dates the resurrection; defaults to the party
+    /// that dated the death
+    pub party_id: Option<String>,
+    /// In-world day; defaults to the party's current day
+    pub day: Option<i32>,
+}
+
+/// A fallen character on the campaign memorial.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemorialEntry {
+    /// Character ID
+    pub character_id: String,
+    /// Character name
+    pub name: String,
+    /// Player name (PCs only)
+    pub player_name: Option<String>,
+    /// Whether the character was an NPC
+    pub is_npc: bool,
+    /// Total character level at death
+    pub level: i32,
+    /// The death (None if marked dead without a record, e.g. by an old archive)
+    pub death: Option<CharacterDeath>,
+}
+
+/// Service for character death and resurrection.
+pub struct DeathService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> DeathService<'a> {
+    /// Create a new death service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// A character's deaths, oldest first.
+    pub fn history(&mut self, character_id: &str) -> ServiceResult<Vec<CharacterDeath>> {
+        require_character(self.conn, character_id)?;
+        dal::list_character_deaths(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    /// The campaign's dead characters, alphabetically.
+    pub fn memorial(&mut self, campaign_id: &str) -> ServiceResult<Vec<MemorialEntry>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+
+        let mut entries = Vec::new();
+        for character in dal::list_campaign_dead_characters(self.conn, campaign_id)? {
+            let level = dal::get_total_level(self.conn, &character.id)? as i32;
+            let death = dal::get_open_character_death(self.conn, &character.id)?;
+            entries.push(MemorialEntry {
+                is_npc: character.is_npc(),
+                character_id: character.id,
+                name: character.name,
+                player_name: character.player_name,
+                level,
+                death,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Mark a character dead.
+    pub fn mark_dead(
+        &mut self,
+        character_id: &str,
+        input: MarkDeadInput,
+    ) -> ServiceResult<CharacterDeath> {
+        let cause = input.cause.trim();
+        if cause.is_empty() {
+            return Err(ServiceError::validation("A cause of death is required"));
+        }
+
+        self.conn.transaction(|conn| {
+            let character = require_character(conn, character_id)?;
+            if character.is_dead {
+                return Err(ServiceError::validation(format!(
+                    "{} is already dead",
+                    character.name
+                )));
+            }
+            let party = calendar_party(conn, &character, input.party_id.as_deref())?;
+            let day = calendar_day(party.as_ref(), input.day)?;
+
+            let id = Uuid::new_v4().to_string();
+            let mut death = NewCharacterDeath::new(&id, character_id, cause);
+            if let (Some(party), Some(day)) = (&party, day) {
+                death = death.on_day(&party.id, day);
+            }
+            if let Some(ref notes) = input.notes {
+                death = death.with_notes(notes);
+            }
+            dal::insert_character_death(conn, &death)?;
+            set_dead(conn, character_id, true)?;
+
+            if let Some(party) = party {
+                PartyService::new(conn).add_timeline_event(
+                    &party.id,
+                    TimelineEventInput {
+                        day,
+                        title: format!("{} died", character.name),
+                        description: Some(cause.to_string()),
+                        session_id: None,
+                    },
+                )?;
+            }
+
+            dal::get_character_death(conn, &id).map_err(ServiceError::from)
+        })
+    }
+
+    /// Bring a dead character back to life, recording the method and cost.
+    pub fn resurrect(
+        &mut self,
+        character_id: &str,
+        input: ResurrectInput,
+    ) -> ServiceResult<CharacterDeath> {
+        let method = input.method.trim();
+        if method.is_empty() {
+            return Err(ServiceError::validation(
+                "A resurrection method is required",
+            ));
+        }
+        let cost_cp = input.cost_cp.or_else(|| resurrection_cost_cp(method));
+        if cost_cp.is_some_and(|c| c < 0) {
+            return Err(ServiceError::validation("Cost cannot be negative"));
+        }
+
+        self.conn.transaction(|conn| {
+            let character = require_character(conn, character_id)?;
+            if !character.is_dead {
+                return Err(ServiceError::validation(format!(
+                    "{} is not dead",
+                    character.name
+                )));
+            }
+            let open_death = dal::get_open_character_death(conn, character_id)?;
+            let party_id = input
+                .party_id
+                .clone()
+                .or_else(|| open_death.as_ref().and_then(|d| d.party_id.clone()));
+            let party = calendar_party(conn, &character, party_id.as_deref())?;
+            let day = calendar_day(party.as_ref(), input.day)?;
+
+            if let (Some(payer_id), Some(cost)) = (&input.paid_by, cost_cp.filter(|&c| c > 0)) {
+                let payer = require_character(conn, payer_id)?;
+                if payer.campaign_id != character.campaign_id {
+                    return Err(ServiceError::validation(format!(
+                        "{} is not in this campaign",
+                        payer.name
+                    )));
+                }
+                let purse = [payer.cp, payer.sp, payer.ep, payer.gp, payer.pp];
+                let paid = make_change(purse, cost as i64).ok_or_else(|| {
+                    ServiceError::validation(format!(
+                        "{} can't afford the {} gp",
+                        payer.name,
+                        cost as f64 / 100.0
+                    ))
+                })?;
+                let reason = format!("{} for {}", method, character.name);
+                set_purse(conn, &payer, paid, &reason)?;
+            }
+
+            // A character marked dead without a record (e.g., by an old
+            // archive) gets one so the resurrection has somewhere to go.
+            let death = match open_death {
+                Some(death) => death,
+                None => {
+                    let id = Uuid::new_v4().to_string();
+                    let new_death = NewCharacterDeath::new(&id, character_id, "Unknown");
+                    dal::insert_character_death(conn, &new_death)?;
+                    dal::get_character_death(conn, &id)?
+                }
+            };
+
+            let now = now_rfc3339();
+            let update = UpdateCharacterDeath {
+                resurrection_method: Some(Some(method)),
+                resurrection_cost_cp: Some(cost_cp),
+                resurrection_day: Some(day),
+                resurrected_at: Some(Some(&now)),
+            };
+            dal::update_character_death(conn, &death.id, &update)?;
+            set_dead(conn, character_id, false)?;
+
+            if let Some(party) = party {
+                PartyService::new(conn).add_timeline_event(
+                    &party.id,
+                    TimelineEventInput {
+                        day,
+                        title: format!("{} returned to life", character.name),
+                        description: Some(method.to_string()),
+                        session_id: None,
+                    },
+                )?;
+            }
+
+            dal::get_character_death(conn, &death.id).map_err(ServiceError::from)
+        })
+    }
+}
+
+/// Material component cost of a resurrection spell, by name.
+pub fn resurrection_cost_cp(method: &str) -> Option<i32> {
+    let method = method.trim().to_lowercase();
+    RESURRECTION_COSTS_CP
+        .iter()
+        .find(|(name, _)| *name == method)
+        .map(|(_, cost)| *cost)
+}
+
+fn set_dead(conn: &mut SqliteConnection, character_id: &str, is_dead: bool) -> ServiceResult<()> {
+    let now = now_rfc3339();
+    let update = UpdateCharacter {
+        is_dead: Some(is_dead),
+        updated_at: Some(&now),
+        ..Default::default()
+    };
+    dal::update_character(conn, character_id, &update)?;
+    Ok(())
+}
+
+/// The party whose calendar dates an event: the one given (which must be in
+/// the character's campaign), or the character's only party.
+fn calendar_party(
+    conn: &mut SqliteConnection,
+    character: &Character,
+    party_id: Option<&str>,
+) -> ServiceResult<Option<Party>> {
+    match party_id {
+        Some(party_id) => {
+            let party = dal::get_party_optional(conn, party_id)?
+                .filter(|p| character.campaign_id.as_deref() == Some(p.campaign_id.as_str()))
+                .ok_or_else(|| ServiceError::not_found("Party", party_id))?;
+            Ok(Some(party))
+        }
+        None => {
+            let mut parties = dal::list_parties_for_character(conn, &character.id)?;
+            Ok(if parties.len() == 1 {
+                parties.pop()
+            } else {
+                None
+            })
+        }
+    }
+}
+
+fn calendar_day(party: Option<&Party>, day: Option<i32>) -> ServiceResult<Option<i32>> {
+    match (party, day) {
+        (None, Some(_)) => Err(ServiceError::validation(
+            "A calendar day needs a party to date it",
+        )),
+        (None, None) => Ok(None),
+        (Some(party), day) => Ok(Some(day.unwrap_or(party.current_day))),
+    }
+}
+
+fn require_character(conn: &mut SqliteConnection, character_id: &str) -> ServiceResult<Character> {
+    dal::get_character_optional(conn, character_id)?
+        .ok_or_else(|| ServiceError::not_found("Character", character_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, insert_character_class};
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewCharacterClass};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) -> String {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        for (id, name) in [("char-1", "Ada"), ("char-2", "Bram")] {
+            insert_character(
+                conn,
+                &NewCharacter::new_pc(id, Some("camp-1"), name, "Alice")
+                    .with_currency(0, 0, 0, 600, 0),
+            )
+            .unwrap();
+            let class_id = format!("class-{}", id);
+            insert_character_class(
+                conn,
+                &NewCharacterClass::starting(&class_id, id, "Fighter", "PHB").with_level(5),
+            )
+            .unwrap();
+        }
+
+        let mut parties = PartyService::new(conn);
+        let party = parties
+            .create("camp-1", "Tuesday Group", None, None)
+            .unwrap();
+        parties.add_member(&party.id, "char-1").unwrap();
+        parties.add_member(&party.id, "char-2").unwrap();
+        parties.set_current_day(&party.id, 12).unwrap();
+        party.id
+    }
+
+    #[test]
+    fn test_death_dates_on_party_calendar() {
+        let mut conn = setup_test_db();
+        let party_id = setup(&mut conn);
+
+        let mut service = DeathService::new(&mut conn);
+        let death = service
+            .mark_dead(
+                "char-1",
+                MarkDeadInput {
+                    cause: "Slain by an owlbear".to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(death.party_id.as_deref(), Some(party_id.as_str()));
+        assert_eq!(death.day, Some(12));
+        assert!(service
+            .mark_dead(
+                "char-1",
+                MarkDeadInput {
+                    cause: "Again".to_string(),
+                    ..Default::default()
+                },
+            )
+            .is_err());
+
+        let memorial = service.memorial("camp-1").unwrap();
+        assert_eq!(memorial.len(), 1);
+        assert_eq!(memorial[0].level, 5);
+
+        let timeline = PartyService::new(&mut conn).timeline(&party_id).unwrap();
+        assert_eq!(timeline[0].title, "Ada died");
+    }
+
+    #[test]
+    fn test_dead_excluded_from_party_budget() {
+        let mut conn = setup_test_db();
+        let party_id = setup(&mut conn);
+
+        DeathService::new(&mut conn)
+            .mark_dead(
+                "char-1",
+                MarkDeadInput {
+                    cause: "Disintegrated".to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let mut parties = PartyService::new(&mut conn);
+        let living = parties.xp_summary(&party_id, false).unwrap();
+        assert_eq!(living.members.len(), 1);
+        let everyone = parties.xp_summary(&party_id, true).unwrap();
+        assert_eq!(everyone.members.len(), 2);
+        assert!(
+            parties.encounter_budget(&party_id, false).unwrap().deadly
+                < parties.encounter_budget(&party_id, true).unwrap().deadly
+        );
+    }
+
+    #[test]
+    fn test_resurrection_paid_from_purse() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let mut service = DeathService::new(&mut conn);
+        service
+            .mark_dead(
+                "char-1",
+                MarkDeadInput {
+                    cause: "Drowned".to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let death = service
+            .resurrect(
+                "char-1",
+                ResurrectInput {
+                    method: "Raise Dead".to_string(),
+                    paid_by: Some("char-2".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(death.resurrection_cost_cp, Some(50_000));
+        assert_eq!(death.resurrection_day, Some(12));
+        assert!(!death.is_open());
+
+        let ada = dal::get_character(&mut conn, "char-1").unwrap();
+        assert!(!ada.is_dead);
+        let bram = dal::get_character(&mut conn, "char-2").unwrap();
+        assert_eq!(bram.gp, 100);
+        assert!(DeathService::new(&mut conn)
+            .memorial("camp-1")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -2,13 +2,17 @@
 //!
 //! Business logic for document management (campaign and module markdown content).
 
-use diesel::SqliteConnection;
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use uuid::Uuid;
 
 use crate::dal::campaign as dal;
-use crate::models::campaign::{Document, NewDocument, UpdateDocument as DalUpdateDocument};
-use crate::services::{ServiceError, ServiceResult};
-use crate::utils::now_rfc3339;
+use crate::models::campaign::{
+    Document, NewDocument, TaggableType, UpdateDocument as DalUpdateDocument,
+};
+use crate::services::{ServiceError, ServiceResult, TagService};
+use crate::utils::{merge3, now_rfc3339, MergeResult};
 
 /// Input for creating a blank document.
 #[derive(Debug, Clone)]
@@ -105,6 +109,120 @@ impl UpdateDocumentInput {
     }
 }
 
+/// Metadata changes applied to every document in a bulk update.
+#[derive(Debug, Clone, Default)]
+pub struct BulkDocumentMetadata {
+    /// New document type
+    pub doc_type: Option<String>,
+    /// Tag names to add (created if missing)
+    pub add_tags: Vec<String>,
+    /// Tag names to remove
+    pub remove_tags: Vec<String>,
+}
+
+impl BulkDocumentMetadata {
+    /// Check if no changes were requested.
+    pub fn is_empty(&self) -> bool {
+        self.doc_type.is_none() && self.add_tags.is_empty() && self.remove_tags.is_empty()
+    }
+}
+
+/// Outcome for one document in a bulk operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkItemResult {
+    pub id: String,
+    pub success: bool,
+    /// Why the item failed (None on success)
+    pub error: Option<String>,
+}
+
+/// Result of a bulk document operation.
+///
+/// Bulk operations are all-or-nothing: when any item fails, `applied` is
+/// false and no document is changed. `results` then shows which items failed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkResult {
+    pub applied: bool,
+    pub results: Vec<BulkItemResult>,
+}
+
+/// Whether a document changed since an editor loaded it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalChangeStatus {
+    /// True when the stored document is newer than the editor's copy
+    pub changed: bool,
+    /// The document as currently stored
+    pub document: Document,
+}
+
+/// Outcome of saving a document against the version the editor started from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SaveOutcome {
+    /// The save was written. `merged` is true when changes made elsewhere
+    /// were combined with the editor's changes.
+    Saved { document: Document, merged: bool },
+    /// The editor's changes overlap changes made elsewhere. Nothing was
+    /// written; `merged_content` holds both versions between conflict
+    /// markers for the user to resolve and save again.
+    Conflict {
+        document: Document,
+        merged_content: String,
+    },
+}
+
+/// Run `op` for each document in a single transaction, rolling back every
+/// change if any item fails. Duplicate IDs are processed once.
+fn run_bulk<F>(conn: &mut SqliteConnection, ids: &[String], mut op: F) -> ServiceResult<BulkResult>
+where
+    F: FnMut(&mut SqliteConnection, &str) -> ServiceResult<()>,
+{
+    let mut seen = HashSet::new();
+    let ids: Vec<&str> = ids
+        .iter()
+        .map(String::as_str)
+        .filter(|id| seen.insert(*id))
+        .collect();
+    if ids.is_empty() {
+        return Err(ServiceError::validation("No documents selected"));
+    }
+
+    let mut results = Vec::with_capacity(ids.len());
+    let outcome = conn.transaction(|conn| {
+        for id in &ids {
+            let error = op(conn, id).err().map(|e| e.to_string());
+            results.push(BulkItemResult {
+                id: id.to_string(),
+                success: error.is_none(),
+                error,
+            });
+        }
+        if results.iter().any(|r| !r.success) {
+            return Err(ServiceError::validation("Bulk operation rolled back"));
+        }
+        Ok(())
+    });
+
+    match outcome {
+        Ok(()) => Ok(BulkResult {
+            applied: true,
+            results,
+        }),
+        Err(_) if results.iter().any(|r| !r.success) => Ok(BulkResult {
+            applied: false,
+            results,
+        }),
+        Err(e) => Err(e),
+    }
+}
+
+/// Get a document that is not in the trash.
+fn get_active_document(conn: &mut SqliteConnection, id: &str) -> ServiceResult<Document> {
+    dal::get_document_optional(conn, id)?
+        .filter(|d| !d.is_trashed())
+        .ok_or_else(|| ServiceError::not_found("Document", id))
+}
+
 /// Service for document management.
 ///
 /// Handles document CRUD operations for campaign and module markdown content.
@@ -201,6 +319,64 @@ impl<'a> DocumentService<'a> {
         dal::get_document(self.conn, id).map_err(ServiceError::from)
     }
 
+    /// Check whether a document was changed elsewhere (another window, the
+    /// MCP server) since the editor loaded the version stamped `base_updated_at`.
+    pub fn check_external_changes(
+        &mut self,
+        id: &str,
+        base_updated_at: &str,
+    ) -> ServiceResult<ExternalChangeStatus> {
+        let document = dal::get_document_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Document", id))?;
+        Ok(ExternalChangeStatus {
+            changed: document.updated_at != base_updated_at,
+            document,
+        })
+    }
+
+    /// Save a document without overwriting changes made elsewhere.
+    ///
+    /// `base_content` is the content the editor started from. If the stored
+    /// content still matches it, this is a plain update. Otherwise the
+    /// editor's content is three-way merged with the stored content; a clean
+    /// merge is saved, and overlapping edits are returned as a conflict
+    /// without writing anything.
+    ///
+    /// The read, merge, and write run in one immediate transaction, so a
+    /// save from another connection can't land between them and be lost.
+    pub fn save_merging(
+        &mut self,
+        id: &str,
+        base_content: &str,
+        mut input: UpdateDocumentInput,
+    ) -> ServiceResult<SaveOutcome> {
+        self.conn.immediate_transaction(|conn| {
+            let current = dal::get_document_optional(conn, id)?
+                .ok_or_else(|| ServiceError::not_found("Document", id))?;
+
+            let merged = match input.content.as_deref() {
+                Some(ours) if current.content != base_content => {
+                    match merge3(base_content, ours, &current.content) {
+                        MergeResult::Clean(content) => {
+                            input.content = Some(content);
+                            true
+                        }
+                        MergeResult::Conflict(merged_content) => {
+                            return Ok(SaveOutcome::Conflict {
+                                document: current,
+                                merged_content,
+                            });
+                        }
+                    }
+                }
+                _ => false,
+            };
+
+            let document = DocumentService::new(conn).update(id, input)?;
+            Ok(SaveOutcome::Saved { document, merged })
+        })
+    }
+
     /// Delete a document permanently.
     pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
         let rows = dal::delete_document(self.conn, id)?;
@@ -293,6 +469,99 @@ impl<'a> DocumentService<'a> {
     ) -> ServiceResult<Vec<dal::DocumentSearchResult>> {
         dal::search_module_documents(self.conn, module_id, query).map_err(ServiceError::from)
     }
+
+    // --- Bulk Operations ---
+
+    /// Move documents into a module (`Some`) or out to campaign level (`None`).
+    ///
+    /// Every document must belong to the module's campaign. Moved documents
+    /// are appended to the end of their new scope.
+    pub fn bulk_move(
+        &mut self,
+        ids: &[String],
+        module_id: Option<&str>,
+    ) -> ServiceResult<BulkResult> {
+        let module = match module_id {
+            Some(id) => Some(
+                dal::get_module_optional(self.conn, id)?
+                    .ok_or_else(|| ServiceError::not_found("Module", id))?,
+            ),
+            None => None,
+        };
+
+        run_bulk(self.conn, ids, |conn, id| {
+            let doc = get_active_document(conn, id)?;
+            if module.as_ref().is_some_and(|m| m.campaign_id != doc.campaign_id) {
+                return Err(ServiceError::validation(
+                    "Document belongs to a different campaign than the module",
+                ));
+            }
+            if doc.module_id.as_deref() == module_id {
+                return Ok(());
+            }
+
+            let now = now_rfc3339();
+            let (update, sort_order) = match module_id {
+                Some(module_id) => (
+                    DalUpdateDocument::move_to_module(module_id, &now),
+                    dal::next_module_document_sort_order(conn, module_id)?,
+                ),
+                None => (
+                    DalUpdateDocument::move_to_campaign(&now),
+                    dal::next_campaign_document_sort_order(conn, &doc.campaign_id)?,
+                ),
+            };
+            dal::update_document(conn, id, &update)?;
+            dal::set_document_sort_order(conn, id, sort_order)?;
+            Ok(())
+        })
+    }
+
+    /// Change the type and tags of several documents at once.
+    pub fn bulk_update_metadata(
+        &mut self,
+        ids: &[String],
+        metadata: &BulkDocumentMetadata,
+    ) -> ServiceResult<BulkResult> {
+        if metadata.is_empty() {
+            return Err(ServiceError::validation("No metadata changes given"));
+        }
+        if metadata.doc_type.as_deref().is_some_and(|t| t.trim().is_empty()) {
+            return Err(ServiceError::validation("Document type cannot be empty"));
+        }
+
+        run_bulk(self.conn, ids, |conn, id| {
+            let doc = get_active_document(conn, id)?;
+
+            if let Some(ref doc_type) = metadata.doc_type {
+                let now = now_rfc3339();
+                dal::update_document(conn, id, &DalUpdateDocument::set_doc_type(doc_type, &now))?;
+            }
+
+            let mut tags = TagService::new(conn);
+            for name in &metadata.add_tags {
+                tags.tag_entity(&doc.campaign_id, TaggableType::Document, id, name)?;
+            }
+
+            for name in &metadata.remove_tags {
+                if let Some(tag) = dal::get_tag_by_name(conn, &doc.campaign_id, name.trim())? {
+                    dal::delete_entity_tag(conn, &tag.id, TaggableType::Document, id)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Move several documents to the trash.
+    pub fn bulk_trash(&mut self, ids: &[String]) -> ServiceResult<BulkResult> {
+        let now = now_rfc3339();
+        run_bulk(self.conn, ids, |conn, id| {
+            if dal::trash_document(conn, id, &now)? == 0 {
+                return Err(ServiceError::not_found("Document", id));
+            }
+            Ok(())
+        })
+    }
 }
 
 #[cfg(test)]
@@ -609,4 +878,142 @@ mod tests {
             .expect("Failed to list by type");
         assert_eq!(npcs.len(), 1);
     }
+
+    #[test]
+    fn test_bulk_move_is_all_or_nothing() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let module_id = create_test_module(&mut conn, &campaign_id);
+
+        let mut service = DocumentService::new(&mut conn);
+        let a = service
+            .create(CreateDocumentInput::for_campaign(&campaign_id, "A"))
+            .unwrap();
+        let b = service
+            .create(CreateDocumentInput::for_campaign(&campaign_id, "B"))
+            .unwrap();
+
+        let ids = vec![a.id.clone(), "missing".to_string(), b.id.clone()];
+        let result = service.bulk_move(&ids, Some(&module_id)).unwrap();
+        assert!(!result.applied);
+        assert!(result.results[0].success);
+        assert!(!result.results[1].success);
+        assert_eq!(service.list_for_module(&module_id).unwrap().len(), 0);
+
+        let ids = vec![a.id.clone(), b.id.clone(), a.id.clone()];
+        let result = service.bulk_move(&ids, Some(&module_id)).unwrap();
+        assert!(result.applied);
+        assert_eq!(result.results.len(), 2);
+        let moved = service.list_for_module(&module_id).unwrap();
+        assert_eq!(moved.len(), 2);
+        assert_ne!(moved[0].sort_order, moved[1].sort_order);
+    }
+
+    #[test]
+    fn test_bulk_update_metadata_and_trash() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+
+        let mut service = DocumentService::new(&mut conn);
+        let a = service
+            .create(CreateDocumentInput::for_campaign(&campaign_id, "A"))
+            .unwrap();
+        let b = service
+            .create(CreateDocumentInput::for_campaign(&campaign_id, "B"))
+            .unwrap();
+        let ids = vec![a.id.clone(), b.id.clone()];
+
+        let metadata = BulkDocumentMetadata {
+            doc_type: Some("session".to_string()),
+            add_tags: vec!["Act 1".to_string()],
+            ..Default::default()
+        };
+        let result = service.bulk_update_metadata(&ids, &metadata).unwrap();
+        assert!(result.applied);
+        assert_eq!(service.list_by_type(&campaign_id, "session").unwrap().len(), 2);
+
+        let result = service.bulk_trash(&ids).unwrap();
+        assert!(result.applied);
+        assert!(service.list_for_campaign(&campaign_id).unwrap().is_empty());
+        assert!(!service.bulk_trash(&ids).unwrap().applied);
+
+        drop(service);
+        let tags = TagService::new(&mut conn)
+            .tags_for_entity(TaggableType::Document, &a.id)
+            .unwrap();
+        assert_eq!(tags.len(), 1);
+    }
+
+    #[test]
+    fn test_check_external_changes() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = DocumentService::new(&mut conn);
+
+        let doc = service
+            .create(CreateDocumentInput::for_campaign(&campaign_id, "Notes"))
+            .unwrap();
+        let status = service
+            .check_external_changes(&doc.id, &doc.updated_at)
+            .unwrap();
+        assert!(!status.changed);
+
+        let status = service
+            .check_external_changes(&doc.id, "2000-01-01T00:00:00+00:00")
+            .unwrap();
+        assert!(status.changed);
+    }
+
+    #[test]
+    fn test_save_merging_combines_or_reports_conflicts() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = DocumentService::new(&mut conn);
+
+        let base = "Intro\nMiddle\nEnd\n";
+        let doc = service
+            .create(CreateDocumentInput::for_campaign(&campaign_id, "Notes").with_content(base))
+            .unwrap();
+
+        // Someone else edits the last line while the editor changes the first.
+        service
+            .update(
+                &doc.id,
+                UpdateDocumentInput::set_content("Intro\nMiddle\nFinale\n"),
+            )
+            .unwrap();
+        let outcome = service
+            .save_merging(
+                &doc.id,
+                base,
+                UpdateDocumentInput::set_content("Prologue\nMiddle\nEnd\n"),
+            )
+            .unwrap();
+        match outcome {
+            SaveOutcome::Saved { document, merged } => {
+                assert!(merged);
+                assert_eq!(document.content, "Prologue\nMiddle\nFinale\n");
+            }
+            other => panic!("Expected a clean merge, got {:?}", other),
+        }
+
+        // Both sides change the middle line: nothing is written.
+        let base = "Prologue\nMiddle\nFinale\n";
+        service
+            .update(
+                &doc.id,
+                UpdateDocumentInput::set_content("Prologue\nTheirs\nFinale\n"),
+            )
+            .unwrap();
+        let outcome = service
+            .save_merging(
+                &doc.id,
+                base,
+                UpdateDocumentInput::set_content("Prologue\nMine\nFinale\n"),
+            )
+            .unwrap();
+        assert!(matches!(outcome, SaveOutcome::Conflict { .. }));
+        let stored = service.get(&doc.id).unwrap().unwrap();
+        assert_eq!(stored.content, "Prologue\nTheirs\nFinale\n");
+    }
 }
@@ -0,0 +1,457 @@
+//! Dungeon layout generation
+//!
+//! Places rectangular rooms on a grid, joins them with corridors along a
+//! minimum spanning tree plus a few extra loops, and puts a door wherever a
+//! corridor enters a room. Walls run along every edge between two different
+//! areas that is not a door, so the layout converts directly to UVTT walls.
+
+use std::collections::BTreeSet;
+
+use rand::Rng;
+use serde::Serialize;
+
+/// Smallest room side, in squares.
+pub const MIN_ROOM_SIZE: i32 = 3;
+
+/// Largest room side, in squares.
+pub const MAX_ROOM_SIZE: i32 = 8;
+
+/// Squares kept clear between rooms and along the map edge.
+const ROOM_MARGIN: i32 = 2;
+
+/// Placement attempts per requested room before giving up on it.
+const PLACEMENT_ATTEMPTS: usize = 40;
+
+/// A grid square and a point on the grid, in squares.
+pub type GridPoint = (i32, i32);
+
+/// What a grid square holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    /// Solid rock
+    Rock,
+    /// Floor of the room at this index
+    Room(usize),
+    /// Corridor floor
+    Corridor,
+}
+
+impl Cell {
+    /// Whether creatures can stand here.
+    pub fn is_floor(self) -> bool {
+        !matches!(self, Cell::Rock)
+    }
+}
+
+/// A rectangular room, in squares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Room {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Room {
+    /// The square at the middle of the room.
+    pub fn center(&self) -> GridPoint {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+
+    /// Whether a square is inside the room.
+    pub fn contains(&self, (x, y): GridPoint) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+
+    /// Every square of the room, row by row.
+    pub fn cells(&self) -> Vec<GridPoint> {
+        (self.y..self.y + self.height)
+            .flat_map(|y| (self.x..self.x + self.width).map(move |x| (x, y)))
+            .collect()
+    }
+
+    /// Whether two rooms come closer than `margin` squares.
+    fn overlaps(&self, other: &Room, margin: i32) -> bool {
+        self.x - margin < other.x + other.width
+            && other.x - margin < self.x + self.width
+            && self.y - margin < other.y + other.height
+            && other.y - margin < self.y + self.height
+    }
+}
+
+/// A door on the edge between two neighbouring squares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Door {
+    /// The upper or left square
+    pub a: GridPoint,
+    /// The lower or right square
+    pub b: GridPoint,
+}
+
+impl Door {
+    fn new(a: GridPoint, b: GridPoint) -> Self {
+        if a <= b {
+            Self { a, b }
+        } else {
+            Self { a: b, b: a }
+        }
+    }
+
+    /// The edge the door fills, as two grid points.
+    pub fn edge(&self) -> (GridPoint, GridPoint) {
+        shared_edge(self.a, self.b)
+    }
+}
+
+/// The edge between two neighbouring squares, `a` above or left of `b`.
+fn shared_edge(a: GridPoint, b: GridPoint) -> (GridPoint, GridPoint) {
+    if a.0 != b.0 {
+        ((b.0, a.1), (b.0, a.1 + 1))
+    } else {
+        ((a.0, b.1), (a.0 + 1, b.1))
+    }
+}
+
+/// A generated dungeon layout.
+#[derive(Debug, Clone)]
+pub struct DungeonLayout {
+    pub width: i32,
+    pub height: i32,
+    /// Rooms in reading order, top to bottom and left to right
+    pub rooms: Vec<Room>,
+    /// Doors between rooms and corridors
+    pub doors: Vec<Door>,
+    cells: Vec<Cell>,
+}
+
+impl DungeonLayout {
+    /// Generate a layout with up to `room_count` rooms.
+    ///
+    /// Fewer rooms are placed when the grid has no space left for them.
+    pub fn generate(width: i32, height: i32, room_count: usize, rng: &mut impl Rng) -> Self {
+        let mut rooms: Vec<Room> = Vec::new();
+        for _ in 0..room_count * PLACEMENT_ATTEMPTS {
+            if rooms.len() >= room_count {
+                break;
+            }
+            let room_width = rng.gen_range(MIN_ROOM_SIZE..=MAX_ROOM_SIZE);
+            let room_height = rng.gen_range(MIN_ROOM_SIZE..=MAX_ROOM_SIZE);
+            if room_width + 2 * ROOM_MARGIN > width || room_height + 2 * ROOM_MARGIN > height {
+                continue;
+            }
+            let room = Room {
+                x: rng.gen_range(ROOM_MARGIN..=width - ROOM_MARGIN - room_width),
+                y: rng.gen_range(ROOM_MARGIN..=height - ROOM_MARGIN - room_height),
+                width: room_width,
+                height: room_height,
+            };
+            if rooms.iter().all(|r| !r.overlaps(&room, ROOM_MARGIN)) {
+                rooms.push(room);
+            }
+        }
+        rooms.sort_by_key(|r| (r.center().1, r.center().0));
+
+        let mut layout = Self {
+            width,
+            height,
+            rooms,
+            doors: Vec::new(),
+            cells: vec![Cell::Rock; (width.max(0) * height.max(0)) as usize],
+        };
+        for (i, room) in layout.rooms.clone().iter().enumerate() {
+            for cell in room.cells() {
+                layout.set(cell, Cell::Room(i));
+            }
+        }
+
+        let mut doors = BTreeSet::new();
+        for (from, to) in layout.connections(rng) {
+            let start = layout.rooms[from].center();
+            let end = layout.rooms[to].center();
+            let mut previous = start;
+            for cell in corridor_path(start, end, rng.gen_bool(0.5))
+                .into_iter()
+                .skip(1)
+            {
+                if layout.cell(cell) == Cell::Rock {
+                    layout.set(cell, Cell::Corridor);
+                }
+                let entering = matches!(
+                    (layout.cell(previous), layout.cell(cell)),
+                    (Cell::Room(_), Cell::Corridor) | (Cell::Corridor, Cell::Room(_))
+                );
+                if entering {
+                    doors.insert(Door::new(previous, cell));
+                }
+                previous = cell;
+            }
+        }
+        layout.doors = doors.into_iter().collect();
+        layout
+    }
+
+    /// What a square holds; squares off the grid are rock.
+    pub fn cell(&self, (x, y): GridPoint) -> Cell {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return Cell::Rock;
+        }
+        self.cells[(y * self.width + x) as usize]
+    }
+
+    fn set(&mut self, (x, y): GridPoint, cell: Cell) {
+        if x >= 0 && y >= 0 && x < self.width && y < self.height {
+            self.cells[(y * self.width + x) as usize] = cell;
+        }
+    }
+
+    /// Corridor squares, row by row.
+    pub fn corridor_cells(&self) -> Vec<GridPoint> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&cell| self.cell(cell) == Cell::Corridor)
+            .collect()
+    }
+
+    /// Doors of one room, with the room's square first.
+    pub fn room_doors(&self, room: usize) -> Vec<(GridPoint, GridPoint)> {
+        self.doors
+            .iter()
+            .filter_map(|door| match (self.cell(door.a), self.cell(door.b)) {
+                (Cell::Room(i), _) if i == room => Some((door.a, door.b)),
+                (_, Cell::Room(i)) if i == room => Some((door.b, door.a)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Wall segments, with runs along the same line merged into one.
+    pub fn walls(&self) -> Vec<(GridPoint, GridPoint)> {
+        let doors: BTreeSet<Door> = self.doors.iter().copied().collect();
+        let is_wall = |a: GridPoint, b: GridPoint| {
+            let (first, second) = (self.cell(a), self.cell(b));
+            first != second
+                && (first.is_floor() || second.is_floor())
+                && !doors.contains(&Door::new(a, b))
+        };
+
+        let mut walls = Vec::new();
+        // Horizontal edges, along the top of row y
+        for y in 0..=self.height {
+            let mut run: Option<i32> = None;
+            for x in 0..=self.width {
+                let wall = x < self.width && is_wall((x, y - 1), (x, y));
+                match (wall, run) {
+                    (true, None) => run = Some(x),
+                    (false, Some(start)) => {
+                        walls.push(((start, y), (x, y)));
+                        run = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        // Vertical edges, along the left of column x
+        for x in 0..=self.width {
+            let mut run: Option<i32> = None;
+            for y in 0..=self.height {
+                let wall = y < self.height && is_wall((x - 1, y), (x, y));
+                match (wall, run) {
+                    (true, None) => run = Some(y),
+                    (false, Some(start)) => {
+                        walls.push(((x, start), (x, y)));
+                        run = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        walls
+    }
+
+    /// Pairs of rooms to join: a minimum spanning tree over room centers,
+    /// plus one extra corridor for every four rooms so the dungeon has loops.
+    fn connections(&self, rng: &mut impl Rng) -> Vec<(usize, usize)> {
+        let count = self.rooms.len();
+        if count < 2 {
+            return Vec::new();
+        }
+        let distance = |i: usize, j: usize| {
+            let (a, b) = (self.rooms[i].center(), self.rooms[j].center());
+            (a.0 - b.0).pow(2) + (a.1 - b.1).pow(2)
+        };
+
+        let mut connected = vec![false; count];
+        connected[0] = true;
+        let mut edges = Vec::with_capacity(count);
+        for _ in 1..count {
+            let next = (0..count)
+                .filter(|&i| connected[i])
+                .flat_map(|i| (0..count).filter(|&j| !connected[j]).map(move |j| (i, j)))
+                .min_by_key(|&(i, j)| distance(i, j));
+            if let Some((i, j)) = next {
+                connected[j] = true;
+                edges.push((i, j));
+            }
+        }
+
+        for _ in 0..count / 4 {
+            let (i, j) = (rng.gen_range(0..count), rng.gen_range(0..count));
+            if i != j && !edges.contains(&(i, j)) && !edges.contains(&(j, i)) {
+                edges.push((i, j));
+            }
+        }
+        edges
+    }
+}
+
+/// Squares along an L-shaped path, horizontal leg first or vertical first.
+fn corridor_path(start: GridPoint, end: GridPoint, horizontal_first: bool) -> Vec<GridPoint> {
+    let corner = if horizontal_first {
+        (end.0, start.1)
+    } else {
+        (start.0, end.1)
+    };
+    let mut path = vec![start];
+    for target in [corner, end] {
+        let mut current = *path.last().unwrap_or(&start);
+        while current != target {
+            current.0 += (target.0 - current.0).signum();
+            current.1 += (target.1 - current.1).signum();
+            path.push(current);
+        }
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn layout(seed: u64) -> DungeonLayout {
+        DungeonLayout::generate(40, 30, 8, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Squares reachable from `start` without crossing a wall.
+    fn reachable(layout: &DungeonLayout, start: GridPoint) -> BTreeSet<GridPoint> {
+        let walls: BTreeSet<(GridPoint, GridPoint)> = layout
+            .walls()
+            .into_iter()
+            .flat_map(|((x0, y0), (x1, y1))| {
+                // Split merged runs back into unit edges
+                let steps = (x1 - x0).max(y1 - y0);
+                let (dx, dy) = ((x1 - x0).signum(), (y1 - y0).signum());
+                (0..steps).map(move |i| {
+                    let from = (x0 + dx * i, y0 + dy * i);
+                    (from, (from.0 + dx, from.1 + dy))
+                })
+            })
+            .collect();
+
+        let mut seen = BTreeSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(cell) = stack.pop() {
+            for next in [
+                (cell.0 + 1, cell.1),
+                (cell.0 - 1, cell.1),
+                (cell.0, cell.1 + 1),
+                (cell.0, cell.1 - 1),
+            ] {
+                let (a, b) = if cell <= next {
+                    (cell, next)
+                } else {
+                    (next, cell)
+                };
+                if layout.cell(next).is_floor()
+                    && !walls.contains(&shared_edge(a, b))
+                    && seen.insert(next)
+                {
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn test_same_seed_same_layout() {
+        let (first, second) = (layout(7), layout(7));
+        assert_eq!(first.rooms, second.rooms);
+        assert_eq!(first.doors, second.doors);
+        assert_ne!(first.rooms, layout(8).rooms);
+    }
+
+    #[test]
+    fn test_rooms_fit_and_keep_apart() {
+        let layout = layout(42);
+        assert!(layout.rooms.len() >= 4);
+        for (i, room) in layout.rooms.iter().enumerate() {
+            assert!(room.x >= ROOM_MARGIN && room.x + room.width <= 40 - ROOM_MARGIN);
+            assert!(room.y >= ROOM_MARGIN && room.y + room.height <= 30 - ROOM_MARGIN);
+            for other in &layout.rooms[i + 1..] {
+                assert!(!room.overlaps(other, ROOM_MARGIN));
+            }
+        }
+        let centers: Vec<_> = layout
+            .rooms
+            .iter()
+            .map(|r| (r.center().1, r.center().0))
+            .collect();
+        assert!(centers.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_every_room_is_reachable() {
+        for seed in 0..10 {
+            let layout = layout(seed);
+            let seen = reachable(&layout, layout.rooms[0].center());
+            for room in &layout.rooms {
+                assert!(
+                    seen.contains(&room.center()),
+                    "seed {} left a room cut off",
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_doors_join_rooms_to_corridors() {
+        let layout = layout(3);
+        assert!(!layout.doors.is_empty());
+        for door in &layout.doors {
+            let cells = [layout.cell(door.a), layout.cell(door.b)];
+            assert!(cells.contains(&Cell::Corridor));
+            assert!(cells.iter().any(|c| matches!(c, Cell::Room(_))));
+        }
+        for (i, _) in layout.rooms.iter().enumerate() {
+            for (inside, outside) in layout.room_doors(i) {
+                assert_eq!(layout.cell(inside), Cell::Room(i));
+                assert_eq!(layout.cell(outside), Cell::Corridor);
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_room_has_no_corridors() {
+        let layout = DungeonLayout::generate(12, 12, 1, &mut StdRng::seed_from_u64(1));
+        assert_eq!(layout.rooms.len(), 1);
+        assert!(layout.doors.is_empty());
+        assert!(layout.corridor_cells().is_empty());
+        // A closed box: four walls
+        assert_eq!(layout.walls().len(), 4);
+    }
+
+    #[test]
+    fn test_corridor_path() {
+        assert_eq!(
+            corridor_path((0, 0), (2, 1), true),
+            vec![(0, 0), (1, 0), (2, 0), (2, 1)]
+        );
+        assert_eq!(
+            corridor_path((0, 0), (2, 1), false),
+            vec![(0, 0), (0, 1), (1, 1), (2, 1)]
+        );
+    }
+}
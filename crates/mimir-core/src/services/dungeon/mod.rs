@@ -0,0 +1,593 @@
+//! Dungeon Generator
+//!
+//! Generates a random dungeon as a ready-to-run module map: rooms and
+//! corridors with walls and doors, monsters from the bestiary chosen by
+//! environment and challenge rating, traps from the catalog, a point of
+//! interest per room, and a location key describing every room.
+
+mod layout;
+mod uvtt;
+
+pub use layout::{Door, DungeonLayout, Room, MAX_ROOM_SIZE, MIN_ROOM_SIZE};
+pub use uvtt::{layout_to_uvtt, PIXELS_PER_GRID};
+
+use std::path::PathBuf;
+
+use diesel::SqliteConnection;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    LightingMode, Map, MapTrap, ModuleMonster, NewMapPoi, NewMapTrap, NewModuleMonster,
+};
+use crate::models::catalog::{Monster, MonsterFilter, Trap, TrapFilter};
+use crate::services::{
+    CampaignService, CreateMapInput, CreateTokenInput, LocationKey, LocationKeyService, MapService,
+    ServiceError, ServiceResult, TokenService,
+};
+
+/// Smallest dungeon side, in squares.
+pub const MIN_DUNGEON_SIZE: i32 = 12;
+
+/// Largest dungeon side, in squares.
+pub const MAX_DUNGEON_SIZE: i32 = 80;
+
+/// Most rooms a dungeon can have.
+pub const MAX_ROOMS: usize = 30;
+
+/// Chance that a room past the entrance has inhabitants.
+const INHABITED_CHANCE: f64 = 0.65;
+
+/// Names given to rooms, shuffled for each dungeon.
+const ROOM_NAMES: [&str; 20] = [
+    "Guard Post",
+    "Barracks",
+    "Shrine",
+    "Storeroom",
+    "Crypt",
+    "Cistern",
+    "Armory",
+    "Library",
+    "Kennels",
+    "Larder",
+    "Throne Room",
+    "Workshop",
+    "Prison Cells",
+    "Treasury",
+    "Ossuary",
+    "Well Room",
+    "Hall of Pillars",
+    "Chapel",
+    "Den",
+    "Forge",
+];
+
+/// Input for generating a dungeon.
+#[derive(Debug, Clone)]
+pub struct GenerateDungeonInput {
+    /// Module the dungeon map is added to
+    pub module_id: String,
+    /// Map name
+    pub name: String,
+    /// Width in squares
+    pub width: i32,
+    /// Height in squares
+    pub height: i32,
+    /// Rooms to place; fewer fit on a crowded grid
+    pub rooms: usize,
+    /// Only monsters found in this environment (e.g., "underdark")
+    pub environment: Option<String>,
+    /// Lowest challenge rating, e.g. "1/4"
+    pub min_cr: Option<String>,
+    /// Highest challenge rating, e.g. "3"
+    pub max_cr: Option<String>,
+    /// Chance that a room has a trap in its doorway, from 0 to 1
+    pub trap_chance: f64,
+    /// Seed for a repeatable dungeon; random when not set
+    pub seed: Option<u64>,
+}
+
+impl GenerateDungeonInput {
+    /// A 40 × 30 dungeon with eight rooms, CR 0 to 2, and traps in a
+    /// quarter of the rooms.
+    pub fn new(module_id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            module_id: module_id.into(),
+            name: name.into(),
+            width: 40,
+            height: 30,
+            rooms: 8,
+            environment: None,
+            min_cr: None,
+            max_cr: Some("2".to_string()),
+            trap_chance: 0.25,
+            seed: None,
+        }
+    }
+}
+
+/// A generated dungeon.
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneratedDungeon {
+    /// The new map
+    pub map: Map,
+    /// The key document and the room POIs it describes
+    pub key: LocationKey,
+    /// Module monsters added for the room inhabitants
+    pub monsters: Vec<ModuleMonster>,
+    /// Traps placed on the map
+    pub traps: Vec<MapTrap>,
+    /// Seed that reproduces this layout
+    pub seed: u64,
+}
+
+/// What was put in one room.
+struct RoomContents {
+    name: String,
+    inhabitants: Option<(Monster, i32)>,
+    trap: Option<(Trap, usize)>,
+}
+
+/// Numeric value of a challenge rating ("1/4" is 0.25).
+pub fn cr_value(cr: &str) -> Option<f64> {
+    let cr = cr.trim();
+    match cr.split_once('/') {
+        Some((num, den)) => {
+            let (num, den) = (num.parse::<f64>().ok()?, den.parse::<f64>().ok()?);
+            (den != 0.0).then_some(num / den)
+        }
+        None => cr.parse().ok(),
+    }
+}
+
+/// A monster's challenge rating, from the indexed column or its data.
+fn monster_cr(monster: &Monster, data: &serde_json::Value) -> Option<f64> {
+    if let Some(cr) = monster.cr.as_deref() {
+        return cr_value(cr);
+    }
+    match &data["cr"] {
+        serde_json::Value::String(cr) => cr_value(cr),
+        serde_json::Value::Object(obj) => obj.get("cr").and_then(|v| v.as_str()).and_then(cr_value),
+        _ => None,
+    }
+}
+
+/// Whether a monster's 5etools `environment` list includes `environment`.
+fn lives_in(data: &serde_json::Value, environment: &str) -> bool {
+    data["environment"].as_array().is_some_and(|envs| {
+        envs.iter()
+            .filter_map(|e| e.as_str())
+            .any(|e| e.eq_ignore_ascii_case(environment))
+    })
+}
+
+/// How many of a monster share a room: more of the weak ones.
+fn group_size(cr: f64, rng: &mut impl Rng) -> i32 {
+    if cr <= 0.25 {
+        rng.gen_range(2..=5)
+    } else if cr <= 1.0 {
+        rng.gen_range(1..=3)
+    } else {
+        rng.gen_range(1..=2)
+    }
+}
+
+/// Service for generating dungeons.
+pub struct DungeonService<'a> {
+    conn: &'a mut SqliteConnection,
+    app_data_dir: PathBuf,
+}
+
+impl<'a> DungeonService<'a> {
+    /// Create a new dungeon service.
+    pub fn new(conn: &'a mut SqliteConnection, app_data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            conn,
+            app_data_dir: app_data_dir.into(),
+        }
+    }
+
+    /// Generate a dungeon map in a module.
+    ///
+    /// The first room in reading order is the entrance and is left empty.
+    /// Every other room may get a group of one monster, with hidden tokens,
+    /// and a trap in one of its doorways. Monsters and traps come from the
+    /// sources the campaign can use.
+    pub fn generate(&mut self, input: GenerateDungeonInput) -> ServiceResult<GeneratedDungeon> {
+        let name = input.name.trim();
+        if name.is_empty() {
+            return Err(ServiceError::validation("Dungeon needs a name"));
+        }
+        for (label, side) in [("Width", input.width), ("Height", input.height)] {
+            if !(MIN_DUNGEON_SIZE..=MAX_DUNGEON_SIZE).contains(&side) {
+                return Err(ServiceError::validation(format!(
+                    "{} must be between {} and {} squares",
+                    label, MIN_DUNGEON_SIZE, MAX_DUNGEON_SIZE
+                )));
+            }
+        }
+        if !(1..=MAX_ROOMS).contains(&input.rooms) {
+            return Err(ServiceError::validation(format!(
+                "Rooms must be between 1 and {}",
+                MAX_ROOMS
+            )));
+        }
+        if !(0.0..=1.0).contains(&input.trap_chance) {
+            return Err(ServiceError::validation(
+                "Trap chance must be between 0 and 1",
+            ));
+        }
+        let min_cr = parse_cr_bound(input.min_cr.as_deref())?.unwrap_or(0.0);
+        let max_cr = parse_cr_bound(input.max_cr.as_deref())?.unwrap_or(f64::MAX);
+        if min_cr > max_cr {
+            return Err(ServiceError::validation(
+                "Lowest CR is above the highest CR",
+            ));
+        }
+
+        let module = dal::get_module_optional(self.conn, &input.module_id)?
+            .ok_or_else(|| ServiceError::not_found("Module", &input.module_id))?;
+
+        let sources = self.usable_sources(&module.campaign_id)?;
+        let environment = input
+            .environment
+            .as_deref()
+            .map(str::trim)
+            .filter(|e| !e.is_empty());
+        let monsters = self.monster_pool(&sources, environment, min_cr, max_cr)?;
+        if monsters.is_empty() {
+            if let Some(environment) = environment {
+                return Err(ServiceError::validation(format!(
+                    "No monsters from the campaign's sources live in {} within that CR range",
+                    environment
+                )));
+            }
+        }
+        let traps = catalog_dal::search_traps(
+            self.conn,
+            &TrapFilter::new().with_sources(sources).with_tier("simple"),
+        )?;
+
+        // Random seeds stay small enough to type back in
+        let seed = input
+            .seed
+            .unwrap_or_else(|| rand::thread_rng().gen::<u32>() as u64);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let layout = DungeonLayout::generate(input.width, input.height, input.rooms, &mut rng);
+
+        let mut names: Vec<&str> = ROOM_NAMES.to_vec();
+        names.shuffle(&mut rng);
+        let contents: Vec<RoomContents> = (0..layout.rooms.len())
+            .map(|i| {
+                if i == 0 {
+                    return RoomContents {
+                        name: "Entrance".to_string(),
+                        inhabitants: None,
+                        trap: None,
+                    };
+                }
+                let room_name = match names.get(i - 1) {
+                    Some(room_name) => room_name.to_string(),
+                    None => format!("Chamber {}", i + 1),
+                };
+                let inhabitants = monsters
+                    .choose(&mut rng)
+                    .filter(|_| rng.gen_bool(INHABITED_CHANCE))
+                    .map(|(monster, cr)| (monster.clone(), group_size(*cr, &mut rng)));
+                let doors = layout.room_doors(i);
+                let trap = traps
+                    .choose(&mut rng)
+                    .filter(|_| !doors.is_empty() && rng.gen_bool(input.trap_chance))
+                    .map(|trap| (trap.clone(), rng.gen_range(0..doors.len())));
+                RoomContents {
+                    name: room_name,
+                    inhabitants,
+                    trap,
+                }
+            })
+            .collect();
+
+        let uvtt = layout_to_uvtt(&layout)?;
+        let map_input = CreateMapInput::for_module(
+            &module.campaign_id,
+            &module.id,
+            name,
+            format!("{}.uvtt", name),
+            uvtt,
+        )
+        .with_description(format!("Generated dungeon (seed {})", seed))
+        .with_lighting_mode(LightingMode::Dark);
+        let map = MapService::new(self.conn, &self.app_data_dir).create(map_input)?;
+
+        let mut added_monsters = Vec::new();
+        let mut placed_traps = Vec::new();
+        for (i, (room, contents)) in layout.rooms.iter().zip(&contents).enumerate() {
+            if let Some((monster, count)) = &contents.inhabitants {
+                let id = Uuid::new_v4().to_string();
+                let notes = format!("{}: {}", name, contents.name);
+                let new_monster =
+                    NewModuleMonster::new(&id, &module.id, &monster.name, &monster.source)
+                        .with_quantity(*count)
+                        .with_notes(&notes);
+                dal::insert_module_monster(self.conn, &new_monster)?;
+
+                let mut cells = room.cells();
+                cells.shuffle(&mut rng);
+                let mut tokens = TokenService::new(self.conn, &self.app_data_dir);
+                for &(x, y) in cells.iter().take(*count as usize) {
+                    tokens.create(CreateTokenInput::for_monster(&map.id, &id, x, y).hidden())?;
+                }
+                added_monsters.push(dal::get_module_monster(self.conn, &id)?);
+            }
+
+            if let Some((trap, door)) = &contents.trap {
+                let (x, y) = layout.room_doors(i)[*door].0;
+                placed_traps.push(self.place_trap(&map.id, trap, x, y)?);
+            }
+
+            let id = Uuid::new_v4().to_string();
+            let description = room_description(room, contents, placed_traps.last());
+            let icon = match (i, &contents.inhabitants) {
+                (0, _) => "door",
+                (_, Some(_)) => "skull",
+                _ => "pin",
+            };
+            let (x, y) = room.center();
+            let poi = NewMapPoi::new(&id, &map.id, &contents.name, x, y)
+                .with_description(&description)
+                .with_icon(icon);
+            dal::insert_map_poi(self.conn, &poi)?;
+        }
+
+        let key = LocationKeyService::new(self.conn).generate(&map.id)?;
+
+        Ok(GeneratedDungeon {
+            map,
+            key,
+            monsters: added_monsters,
+            traps: placed_traps,
+            seed,
+        })
+    }
+
+    /// Catalog sources that are enabled and allowed in the campaign.
+    fn usable_sources(&mut self, campaign_id: &str) -> ServiceResult<Vec<String>> {
+        let allowed = CampaignService::new(self.conn).search_sources(campaign_id)?;
+        Ok(catalog_dal::list_sources(self.conn)?
+            .into_iter()
+            .filter(|s| s.is_enabled())
+            .map(|s| s.code)
+            .filter(|code| allowed.as_ref().map_or(true, |codes| codes.contains(code)))
+            .collect())
+    }
+
+    /// Monsters in the CR range, and environment if given, with their CR.
+    fn monster_pool(
+        &mut self,
+        sources: &[String],
+        environment: Option<&str>,
+        min_cr: f64,
+        max_cr: f64,
+    ) -> ServiceResult<Vec<(Monster, f64)>> {
+        let filter = MonsterFilter::new().with_sources(sources.to_vec());
+        Ok(catalog_dal::search_monsters(self.conn, &filter)?
+            .into_iter()
+            .filter_map(|monster| {
+                let data = monster.parse_data().ok()?;
+                let cr = monster_cr(&monster, &data)?;
+                let fits = cr >= min_cr
+                    && cr <= max_cr
+                    && environment.map_or(true, |env| lives_in(&data, env));
+                fits.then_some((monster, cr))
+            })
+            .collect())
+    }
+
+    /// Place a catalog trap with its text copied onto the map.
+    fn place_trap(&mut self, map_id: &str, trap: &Trap, x: i32, y: i32) -> ServiceResult<MapTrap> {
+        let text = trap.placement_text().map_err(|e| {
+            ServiceError::validation(format!("Invalid data for trap {}: {}", trap.name, e))
+        })?;
+        let id = Uuid::new_v4().to_string();
+        let mut new_trap = NewMapTrap::new(&id, map_id, &trap.name, x, y)
+            .with_catalog_ref(&trap.name, &trap.source);
+        if let Some(description) = &text.description {
+            new_trap = new_trap.with_description(description);
+        }
+        if let Some(trigger) = &text.trigger {
+            new_trap = new_trap.with_trigger(trigger);
+        }
+        if let Some(effect) = &text.effect {
+            new_trap = new_trap.with_effect(effect);
+        }
+        if let Some(dc) = text.dc {
+            new_trap = new_trap.with_dc(dc);
+        }
+        dal::insert_map_trap(self.conn, &new_trap)?;
+        Ok(dal::get_map_trap(self.conn, &id)?)
+    }
+}
+
+/// Parse an optional CR bound, rejecting text that is not a CR.
+fn parse_cr_bound(cr: Option<&str>) -> ServiceResult<Option<f64>> {
+    match cr.map(str::trim).filter(|cr| !cr.is_empty()) {
+        None => Ok(None),
+        Some(cr) => cr_value(cr)
+            .map(Some)
+            .ok_or_else(|| ServiceError::validation(format!("Invalid challenge rating: {}", cr))),
+    }
+}
+
+/// Key text for a room: its size, who lives there, and its trap.
+fn room_description(room: &Room, contents: &RoomContents, trap: Option<&MapTrap>) -> String {
+    let mut text = format!("A {} × {} ft room.", room.width * 5, room.height * 5);
+    if contents.name == "Entrance" {
+        text = format!("The way in. {}", text);
+    }
+    if let Some((monster, count)) = &contents.inhabitants {
+        let cr = monster.cr.as_deref().unwrap_or("?");
+        text.push_str(&format!(
+            "\n\n**Inhabitants:** {} × {} (CR {})\n\n{{{{monster: {}|{}}}}}",
+            count, monster.name, cr, monster.name, monster.source
+        ));
+    }
+    if let (Some(_), Some(trap)) = (&contents.trap, trap) {
+        text.push_str(&format!("\n\n**Trap:** {} in the doorway", trap.name));
+        if let Some(dc) = trap.dc {
+            text.push_str(&format!(" (DC {})", dc));
+        }
+        text.push('.');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_module, list_map_pois, list_map_traps};
+    use crate::dal::catalog::{insert_monster, insert_trap};
+    use crate::models::campaign::{NewCampaign, NewModule};
+    use crate::models::catalog::{NewMonster, NewTrap};
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "Delve", 1)).unwrap();
+
+        for (name, cr, environment) in [
+            ("Goblin", "1/4", "forest"),
+            ("Drow", "1/4", "underdark"),
+            ("Hook Horror", "3", "underdark"),
+        ] {
+            let data =
+                json!({ "name": name, "source": "MM", "cr": cr, "environment": [environment] });
+            let data = data.to_string();
+            insert_monster(conn, &NewMonster::new(name, "MM", &data).with_cr(cr)).unwrap();
+        }
+        let trap = json!({
+            "name": "Pit Trap",
+            "source": "DMG",
+            "trigger": ["A creature steps on the lid."],
+            "effect": ["A {@dc 15} Dexterity saving throw avoids the fall."],
+        });
+        insert_trap(
+            conn,
+            &NewTrap::new("Pit Trap", "DMG", &trap.to_string()).with_tier("simple"),
+        )
+        .unwrap();
+    }
+
+    fn input() -> GenerateDungeonInput {
+        let mut input = GenerateDungeonInput::new("mod-1", "Underkeep");
+        input.environment = Some("Underdark".to_string());
+        input.max_cr = Some("1".to_string());
+        input.trap_chance = 1.0;
+        input.seed = Some(12);
+        input
+    }
+
+    #[test]
+    fn test_generate_dungeon() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let dir = TempDir::new().unwrap();
+
+        let dungeon = DungeonService::new(&mut conn, dir.path())
+            .generate(input())
+            .unwrap();
+        assert_eq!(dungeon.seed, 12);
+        assert_eq!(dungeon.map.module_id.as_deref(), Some("mod-1"));
+
+        // Only underdark monsters within the CR range
+        assert!(!dungeon.monsters.is_empty());
+        for monster in &dungeon.monsters {
+            assert_eq!(monster.monster_name.as_deref(), Some("Drow"));
+        }
+        let tokens = TokenService::new(&mut conn, dir.path())
+            .list(&dungeon.map.id)
+            .unwrap();
+        let expected: i32 = dungeon.monsters.iter().map(|m| m.quantity).sum();
+        assert_eq!(tokens.len() as i32, expected);
+
+        // Every room past the entrance has a trap
+        let pois = list_map_pois(&mut conn, &dungeon.map.id).unwrap();
+        assert_eq!(dungeon.traps.len(), pois.len() - 1);
+        let traps = list_map_traps(&mut conn, &dungeon.map.id).unwrap();
+        assert!(traps
+            .iter()
+            .all(|t| t.catalog_ref() == Some(("Pit Trap", "DMG"))));
+        assert!(traps.iter().all(|t| t.dc == Some(15)));
+
+        // One keyed section per room
+        let content = &dungeon.key.document.content;
+        assert_eq!(dungeon.key.pois.len(), pois.len());
+        assert!(content.contains("## 1. Entrance"));
+        assert!(content.contains("{{monster: Drow|MM}}"));
+        assert!(content.contains("**Trap:** Pit Trap in the doorway (DC 15)."));
+    }
+
+    #[test]
+    fn test_same_seed_same_dungeon() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let dir = TempDir::new().unwrap();
+
+        let mut service = DungeonService::new(&mut conn, dir.path());
+        let first = service.generate(input()).unwrap();
+        let second = service.generate(input()).unwrap();
+        assert_ne!(first.map.id, second.map.id);
+        assert_eq!(first.key.document.content, second.key.document.content);
+        assert_eq!(first.traps.len(), second.traps.len());
+    }
+
+    #[test]
+    fn test_generate_validates_input() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let dir = TempDir::new().unwrap();
+        let mut service = DungeonService::new(&mut conn, dir.path());
+
+        let mut small = input();
+        small.width = 4;
+        assert!(matches!(
+            service.generate(small),
+            Err(ServiceError::Validation(_))
+        ));
+
+        let mut nowhere = input();
+        nowhere.environment = Some("astral plane".to_string());
+        assert!(matches!(
+            service.generate(nowhere),
+            Err(ServiceError::Validation(_))
+        ));
+
+        let mut backwards = input();
+        backwards.min_cr = Some("5".to_string());
+        assert!(matches!(
+            service.generate(backwards),
+            Err(ServiceError::Validation(_))
+        ));
+
+        let mut missing = input();
+        missing.module_id = "mod-missing".to_string();
+        assert!(matches!(
+            service.generate(missing),
+            Err(ServiceError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cr_value() {
+        assert_eq!(cr_value("1/4"), Some(0.25));
+        assert_eq!(cr_value("3"), Some(3.0));
+        assert_eq!(cr_value("1/0"), None);
+        assert_eq!(cr_value("dragon"), None);
+    }
+}
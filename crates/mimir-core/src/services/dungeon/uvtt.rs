@@ -0,0 +1,190 @@
+//! UVTT output for generated dungeons
+//!
+//! Draws a layout as a plain top-down floor plan and wraps it in a UVTT
+//! file with the layout's walls as line of sight and its doors as portals.
+
+use std::io::Cursor;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::{ImageFormat, Rgb, RgbImage};
+use serde_json::{json, Value};
+
+use super::layout::{Cell, DungeonLayout, GridPoint};
+use crate::services::{ServiceError, ServiceResult};
+
+/// Pixels per grid square in the rendered image.
+pub const PIXELS_PER_GRID: u32 = 70;
+
+const ROCK: Rgb<u8> = Rgb([43, 38, 34]);
+const ROOM_FLOOR: Rgb<u8> = Rgb([217, 207, 191]);
+const CORRIDOR_FLOOR: Rgb<u8> = Rgb([196, 185, 166]);
+const GRID_LINE: Rgb<u8> = Rgb([176, 165, 148]);
+const WALL: Rgb<u8> = Rgb([31, 26, 23]);
+const DOOR: Rgb<u8> = Rgb([139, 90, 43]);
+
+/// Wall thickness in pixels.
+const WALL_WIDTH: u32 = 6;
+
+/// Build the UVTT file for a layout.
+pub fn layout_to_uvtt(layout: &DungeonLayout) -> ServiceResult<Vec<u8>> {
+    let point = |(x, y): GridPoint| json!({ "x": x as f64, "y": y as f64 });
+
+    let line_of_sight: Vec<Value> = layout
+        .walls()
+        .into_iter()
+        .map(|(a, b)| json!([point(a), point(b)]))
+        .collect();
+
+    let portals: Vec<Value> = layout
+        .doors
+        .iter()
+        .map(|door| {
+            let (a, b) = door.edge();
+            let rotation = if a.0 == b.0 {
+                std::f64::consts::FRAC_PI_2
+            } else {
+                0.0
+            };
+            json!({
+                "position": {
+                    "x": (a.0 + b.0) as f64 / 2.0,
+                    "y": (a.1 + b.1) as f64 / 2.0,
+                },
+                "bounds": [point(a), point(b)],
+                "rotation": rotation,
+                "closed": true,
+                "freestanding": false,
+            })
+        })
+        .collect();
+
+    let uvtt = json!({
+        "format": 0.3,
+        "resolution": {
+            "map_origin": { "x": 0, "y": 0 },
+            "map_size": { "x": layout.width, "y": layout.height },
+            "pixels_per_grid": PIXELS_PER_GRID,
+        },
+        "line_of_sight": line_of_sight,
+        "objects_line_of_sight": [],
+        "portals": portals,
+        "environment": {
+            "baked_lighting": false,
+            "ambient_light": "ffffffff",
+        },
+        "lights": [],
+        "image": STANDARD.encode(render_png(layout)?),
+    });
+
+    serde_json::to_vec(&uvtt)
+        .map_err(|e| ServiceError::validation(format!("Failed to write UVTT: {}", e)))
+}
+
+/// Draw the layout: rock, floors with grid lines, walls, and doors.
+fn render_png(layout: &DungeonLayout) -> ServiceResult<Vec<u8>> {
+    let size = PIXELS_PER_GRID;
+    let mut img = RgbImage::from_pixel(
+        layout.width.max(1) as u32 * size,
+        layout.height.max(1) as u32 * size,
+        ROCK,
+    );
+
+    for y in 0..layout.height {
+        for x in 0..layout.width {
+            let color = match layout.cell((x, y)) {
+                Cell::Rock => continue,
+                Cell::Room(_) => ROOM_FLOOR,
+                Cell::Corridor => CORRIDOR_FLOOR,
+            };
+            let (px, py) = (x as u32 * size, y as u32 * size);
+            fill_rect(&mut img, px, py, size, size, color);
+            fill_rect(&mut img, px, py, size, 1, GRID_LINE);
+            fill_rect(&mut img, px, py, 1, size, GRID_LINE);
+        }
+    }
+
+    for (a, b) in layout.walls() {
+        draw_edge(&mut img, a, b, WALL_WIDTH, WALL);
+    }
+    for door in &layout.doors {
+        let (a, b) = door.edge();
+        draw_edge(&mut img, a, b, WALL_WIDTH + 6, WALL);
+        draw_edge(&mut img, a, b, WALL_WIDTH + 2, DOOR);
+    }
+
+    let mut png = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| ServiceError::validation(format!("Failed to encode map image: {}", e)))?;
+    Ok(png)
+}
+
+/// Draw a horizontal or vertical edge between two grid points.
+fn draw_edge(img: &mut RgbImage, a: GridPoint, b: GridPoint, thickness: u32, color: Rgb<u8>) {
+    let size = PIXELS_PER_GRID as i64;
+    let half = thickness as i64 / 2;
+    let (x0, x1) = (a.0.min(b.0) as i64 * size, a.0.max(b.0) as i64 * size);
+    let (y0, y1) = (a.1.min(b.1) as i64 * size, a.1.max(b.1) as i64 * size);
+    let (left, top) = ((x0 - half).max(0), (y0 - half).max(0));
+    let (right, bottom) = (x1 + half, y1 + half);
+    fill_rect(
+        img,
+        left as u32,
+        top as u32,
+        (right - left) as u32,
+        (bottom - top) as u32,
+        color,
+    );
+}
+
+/// Fill a rectangle, clipped to the image.
+fn fill_rect(img: &mut RgbImage, x: u32, y: u32, width: u32, height: u32, color: Rgb<u8>) {
+    let right = (x + width).min(img.width());
+    let bottom = (y + height).min(img.height());
+    for py in y..bottom {
+        for px in x..right {
+            img.put_pixel(px, py, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_uvtt_has_walls_portals_and_image() {
+        let layout = DungeonLayout::generate(30, 20, 5, &mut StdRng::seed_from_u64(11));
+        let uvtt: Value = serde_json::from_slice(&layout_to_uvtt(&layout).unwrap()).unwrap();
+
+        assert_eq!(uvtt["resolution"]["map_size"]["x"], 30);
+        assert_eq!(uvtt["resolution"]["pixels_per_grid"], PIXELS_PER_GRID);
+        assert_eq!(
+            uvtt["line_of_sight"].as_array().unwrap().len(),
+            layout.walls().len()
+        );
+        assert_eq!(
+            uvtt["portals"].as_array().unwrap().len(),
+            layout.doors.len()
+        );
+
+        let png = STANDARD.decode(uvtt["image"].as_str().unwrap()).unwrap();
+        let img = image::load_from_memory(&png).unwrap();
+        assert_eq!(img.width(), 30 * PIXELS_PER_GRID);
+        assert_eq!(img.height(), 20 * PIXELS_PER_GRID);
+    }
+
+    #[test]
+    fn test_portal_bounds_span_one_square() {
+        let layout = DungeonLayout::generate(30, 20, 5, &mut StdRng::seed_from_u64(5));
+        let uvtt: Value = serde_json::from_slice(&layout_to_uvtt(&layout).unwrap()).unwrap();
+        for portal in uvtt["portals"].as_array().unwrap() {
+            let bounds = portal["bounds"].as_array().unwrap();
+            let dx = bounds[1]["x"].as_f64().unwrap() - bounds[0]["x"].as_f64().unwrap();
+            let dy = bounds[1]["y"].as_f64().unwrap() - bounds[0]["y"].as_f64().unwrap();
+            assert_eq!(dx.abs() + dy.abs(), 1.0);
+            assert_eq!(portal["closed"], true);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+//! Encounter Difficulty
+//!
+//! Encounter building math from the DMG: XP thresholds by character level,
+//! XP by challenge rating, and the multiplier for fighting several monsters
+//! at once. Budgets are built from a party's member levels.
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::{ServiceError, ServiceResult};
+
+/// Easy, medium, hard, and deadly XP thresholds for levels 1-20.
+const XP_THRESHOLDS: [[i32; 4]; 20] = [
+    [25, 50, 75, 100],
+    [50, 100, 150, 200],
+    [75, 150, 225, 400],
+    [125, 250, 375, 500],
+    [250, 500, 750, 1100],
+    [300, 600, 900, 1400],
+    [350, 750, 1100, 1700],
+    [450, 900, 1400, 2100],
+    [550, 1100, 1600, 2400],
+    [600, 1200, 1900, 2800],
+    [800, 1600, 2400, 3600],
+    [1000, 2000, 3000, 4500],
+    [1100, 2200, 3400, 5100],
+    [1250, 2500, 3800, 5700],
+    [1400, 2800, 4300, 6400],
+    [1600, 3200, 4800, 7200],
+    [2000, 3900, 5900, 8800],
+    [2100, 4200, 6300, 9500],
+    [2400, 4900, 7300, 10900],
+    [2800, 5700, 8500, 12700],
+];
+
+/// XP for challenge ratings 1-30.
+const CR_XP: [i32; 30] = [
+    200, 450, 700, 1100, 1800, 2300, 2900, 3900, 5000, 5900, 7200, 8400, 10000, 11500, 13000,
+    15000, 18000, 20000, 22000, 25000, 33000, 41000, 50000, 62000, 75000, 90000, 105000, 120000,
+    135000, 155000,
+];
+
+/// Encounter multipliers, from a lone monster against a large party up to a
+/// horde against a small one.
+const MULTIPLIERS: [f64; 8] = [0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0];
+
+/// Difficulty rating of an encounter for a party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncounterDifficulty {
+    /// Below the easy threshold
+    Trivial,
+    Easy,
+    Medium,
+    Hard,
+    Deadly,
+}
+
+/// A monster entry in an encounter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterMonster {
+    /// Challenge rating ("0", "1/8", "1/4", "1/2", "1" through "30")
+    pub cr: String,
+    /// How many of this monster (defaults to 1)
+    #[serde(default = "default_quantity")]
+    pub quantity: i32,
+}
+
+fn default_quantity() -> i32 {
+    1
+}
+
+/// XP thresholds for a party.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncounterBudget {
+    /// Number of characters in the party
+    pub party_size: usize,
+    /// Level of each character, clamped to 1-20
+    pub levels: Vec<i32>,
+    /// Adjusted XP at which an encounter becomes easy
+    pub easy: i32,
+    /// Adjusted XP at which an encounter becomes medium
+    pub medium: i32,
+    /// Adjusted XP at which an encounter becomes hard
+    pub hard: i32,
+    /// Adjusted XP at which an encounter becomes deadly
+    pub deadly: i32,
+}
+
+impl EncounterBudget {
+    /// Sum the thresholds for each character level.
+    pub fn for_levels(levels: &[i32]) -> Self {
+        let levels: Vec<i32> = levels.iter().map(|l| (*l).clamp(1, 20)).collect();
+        let mut totals = [0; 4];
+        for level in &levels {
+            let row = XP_THRESHOLDS[(*level - 1) as usize];
+            for (total, xp) in totals.iter_mut().zip(row) {
+                *total += xp;
+            }
+        }
+
+        Self {
+            party_size: levels.len(),
+            levels,
+            easy: totals[0],
+            medium: totals[1],
+            hard: totals[2],
+            deadly: totals[3],
+        }
+    }
+
+    /// Rate an adjusted XP total against these thresholds.
+    pub fn rate(&self, adjusted_xp: i32) -> EncounterDifficulty {
+        if adjusted_xp >= self.deadly {
+            EncounterDifficulty::Deadly
+        } else if adjusted_xp >= self.hard {
+            EncounterDifficulty::Hard
+        } else if adjusted_xp >= self.medium {
+            EncounterDifficulty::Medium
+        } else if adjusted_xp >= self.easy {
+            EncounterDifficulty::Easy
+        } else {
+            EncounterDifficulty::Trivial
+        }
+    }
+}
+
+/// An encounter rated against a party.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterEvaluation {
+    /// Thresholds the encounter was rated against
+    pub budget: EncounterBudget,
+    /// Total number of monsters
+    pub monster_count: i32,
+    /// XP awarded for defeating every monster
+    pub base_xp: i32,
+    /// Multiplier for the number of monsters and party size
+    pub multiplier: f64,
+    /// Base XP times the multiplier, used to rate difficulty
+    pub adjusted_xp: i32,
+    /// Difficulty rating
+    pub difficulty: EncounterDifficulty,
+}
+
+/// XP for a challenge rating, or None if the CR isn't recognized.
+pub fn cr_xp(cr: &str) -> Option<i32> {
+    match cr.trim() {
+        "0" => Some(10),
+        "1/8" => Some(25),
+        "1/4" => Some(50),
+        "1/2" => Some(100),
+        other => {
+            let cr: usize = other.parse().ok()?;
+            CR_XP.get(cr.checked_sub(1)?).copied()
+        }
+    }
+}
+
+/// Encounter multiplier for a number of monsters against a party.
+///
+/// Parties of fewer than three step up to the next multiplier; parties of
+/// six or more step down.
+pub fn encounter_multiplier(monster_count: i32, party_size: usize) -> f64 {
+    if monster_count <= 0 {
+        return 0.0;
+    }
+    let index: usize = match monster_count {
+        1 => 1,
+        2 => 2,
+        3..=6 => 3,
+        7..=10 => 4,
+        11..=14 => 5,
+        _ => 6,
+    };
+    let index = match party_size {
+        0..=2 => index + 1,
+        3..=5 => index,
+        _ => index - 1,
+    };
+    MULTIPLIERS[index]
+}
+
+/// Rate a group of monsters against a budget.
+pub fn evaluate_encounter(
+    budget: EncounterBudget,
+    monsters: &[EncounterMonster],
+) -> ServiceResult<EncounterEvaluation> {
+    let mut monster_count = 0;
+    let mut base_xp = 0;
+    for monster in monsters {
+        if monster.quantity < 1 {
+            return Err(ServiceError::validation(format!(
+                "Monster quantity must be at least 1, got {}",
+                monster.quantity
+            )));
+        }
+        let xp = cr_xp(&monster.cr).ok_or_else(|| {
+            ServiceError::validation(format!("Unknown challenge rating '{}'", monster.cr))
+        })?;
+        monster_count += monster.quantity;
+        base_xp += xp * monster.quantity;
+    }
+
+    let multiplier = encounter_multiplier(monster_count, budget.party_size);
+    let adjusted_xp = (base_xp as f64 * multiplier).round() as i32;
+    let difficulty = budget.rate(adjusted_xp);
+
+    Ok(EncounterEvaluation {
+        budget,
+        monster_count,
+        base_xp,
+        multiplier,
+        adjusted_xp,
+        difficulty,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monster(cr: &str, quantity: i32) -> EncounterMonster {
+        EncounterMonster {
+            cr: cr.to_string(),
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_cr_xp() {
+        assert_eq!(cr_xp("0"), Some(10));
+        assert_eq!(cr_xp("1/4"), Some(50));
+        assert_eq!(cr_xp("5"), Some(1800));
+        assert_eq!(cr_xp("30"), Some(155000));
+        assert_eq!(cr_xp("31"), None);
+        assert_eq!(cr_xp("dragon"), None);
+    }
+
+    #[test]
+    fn test_budget_sums_levels() {
+        // DMG example: three 3rd-level and one 2nd-level character
+        let budget = EncounterBudget::for_levels(&[3, 3, 3, 2]);
+        assert_eq!(budget.party_size, 4);
+        assert_eq!(budget.easy, 275);
+        assert_eq!(budget.medium, 550);
+        assert_eq!(budget.hard, 825);
+        assert_eq!(budget.deadly, 1400);
+
+        // Out-of-range levels are clamped
+        let budget = EncounterBudget::for_levels(&[0, 25]);
+        assert_eq!(budget.levels, vec![1, 20]);
+    }
+
+    #[test]
+    fn test_multiplier_adjusts_for_party_size() {
+        assert_eq!(encounter_multiplier(1, 4), 1.0);
+        assert_eq!(encounter_multiplier(4, 4), 2.0);
+        assert_eq!(encounter_multiplier(1, 2), 1.5);
+        assert_eq!(encounter_multiplier(20, 1), 5.0);
+        assert_eq!(encounter_multiplier(1, 6), 0.5);
+    }
+
+    #[test]
+    fn test_evaluate_encounter() {
+        let budget = EncounterBudget::for_levels(&[3, 3, 3, 3]);
+        let eval = evaluate_encounter(budget, &[monster("1/4", 4), monster("1", 1)])
+            .expect("Failed to evaluate");
+        assert_eq!(eval.monster_count, 5);
+        assert_eq!(eval.base_xp, 400);
+        assert_eq!(eval.adjusted_xp, 800);
+        assert_eq!(eval.difficulty, EncounterDifficulty::Medium);
+
+        let budget = EncounterBudget::for_levels(&[3]);
+        assert!(evaluate_encounter(budget.clone(), &[monster("CR 2", 1)]).is_err());
+        assert!(evaluate_encounter(budget, &[monster("2", 0)]).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+//! Encounter Effect Service
+//!
+//! Business logic for the environmental effects in a module's encounter.
+//! Effects act on an initiative count every round (lair actions, losing
+//! initiative ties), at the start of every round (hazards), or once at the
+//! start of a given round (a collapsing ceiling). The initiative tracker
+//! schedules them when the module is run.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    EffectTiming, EncounterEffect, NewEncounterEffect, UpdateEncounterEffect,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Initiative count lair actions act on by default.
+pub const LAIR_INITIATIVE: i32 = 20;
+
+/// Highest initiative count an effect can act on.
+const MAX_INITIATIVE: i32 = 30;
+
+/// Input for adding or replacing an encounter effect.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterEffectInput {
+    /// Name shown in the turn order (e.g., "Lair Action")
+    pub name: String,
+    /// What happens when the effect acts
+    #[serde(default)]
+    pub description: Option<String>,
+    /// When the effect acts
+    pub timing: EffectTiming,
+    /// Initiative count (default 20) or round number; ignored for each-round effects
+    #[serde(default)]
+    pub timing_value: Option<i32>,
+}
+
+/// Service for module encounter effects.
+pub struct EncounterEffectService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> EncounterEffectService<'a> {
+    /// Create a new encounter effect service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a module's encounter effects.
+    pub fn list(&mut self, module_id: &str) -> ServiceResult<Vec<EncounterEffect>> {
+        if !dal::module_exists(self.conn, module_id)? {
+            return Err(ServiceError::not_found("Module", module_id));
+        }
+        dal::list_encounter_effects(self.conn, module_id).map_err(ServiceError::from)
+    }
+
+    /// Add an effect to a module's encounter.
+    pub fn add(
+        &mut self,
+        module_id: &str,
+        input: EncounterEffectInput,
+    ) -> ServiceResult<EncounterEffect> {
+        if !dal::module_exists(self.conn, module_id)? {
+            return Err(ServiceError::not_found("Module", module_id));
+        }
+        let (name, timing_value) = validate(&input)?;
+
+        let id = Uuid::new_v4().to_string();
+        let effect = NewEncounterEffect::new(&id, module_id, name, input.timing, timing_value)
+            .with_description(input.description.as_deref().unwrap_or("").trim());
+        dal::insert_encounter_effect(self.conn, &effect)?;
+        dal::get_encounter_effect(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Replace an effect's name, description, and timing.
+    pub fn update(
+        &mut self,
+        id: &str,
+        input: EncounterEffectInput,
+    ) -> ServiceResult<EncounterEffect> {
+        dal::get_encounter_effect_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Encounter effect", id))?;
+        let (name, timing_value) = validate(&input)?;
+
+        let now = now_rfc3339();
+        let update = UpdateEncounterEffect {
+            name: Some(name),
+            description: Some(input.description.as_deref().unwrap_or("").trim()),
+            timing: Some(input.timing.as_str()),
+            timing_value: Some(timing_value),
+            updated_at: Some(&now),
+        };
+        dal::update_encounter_effect(self.conn, id, &update)?;
+        dal::get_encounter_effect(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Remove an effect from its module's encounter.
+    pub fn remove(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_encounter_effect(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("Encounter effect", id));
+        }
+        Ok(())
+    }
+}
+
+/// Check an effect's input, returning its trimmed name and the timing value
+/// to store.
+fn validate(input: &EncounterEffectInput) -> ServiceResult<(&str, Option<i32>)> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::validation("Effect name is required"));
+    }
+
+    let timing_value = match input.timing {
+        EffectTiming::Initiative => {
+            let count = input.timing_value.unwrap_or(LAIR_INITIATIVE);
+            if !(1..=MAX_INITIATIVE).contains(&count) {
+                return Err(ServiceError::validation(format!(
+                    "Initiative count must be between 1 and {}",
+                    MAX_INITIATIVE
+                )));
+            }
+            Some(count)
+        }
+        EffectTiming::EachRound => None,
+        EffectTiming::Round => match input.timing_value {
+            Some(round) if round >= 1 => Some(round),
+            _ => {
+                return Err(ServiceError::validation(
+                    "A round effect needs the round it happens on (1 or later)",
+                ))
+            }
+        },
+    };
+    Ok((name, timing_value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_module};
+    use crate::models::campaign::{NewCampaign, NewModule};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "Dragon's Lair", 1)).unwrap();
+    }
+
+    fn input(name: &str, timing: EffectTiming, value: Option<i32>) -> EncounterEffectInput {
+        EncounterEffectInput {
+            name: name.to_string(),
+            description: None,
+            timing,
+            timing_value: value,
+        }
+    }
+
+    #[test]
+    fn test_add_defaults_and_validates() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = EncounterEffectService::new(&mut conn);
+
+        let lair = service
+            .add(
+                "mod-1",
+                input(" Lair Action ", EffectTiming::Initiative, None),
+            )
+            .unwrap();
+        assert_eq!(lair.name, "Lair Action");
+        assert_eq!(lair.timing_value, Some(LAIR_INITIATIVE));
+
+        let smoke = service
+            .add("mod-1", input("Smoke", EffectTiming::EachRound, Some(4)))
+            .unwrap();
+        assert_eq!(smoke.timing_enum(), Some(EffectTiming::EachRound));
+        assert_eq!(smoke.timing_value, None);
+
+        assert!(service
+            .add("mod-1", input("Collapse", EffectTiming::Round, None))
+            .is_err());
+        assert!(service
+            .add("mod-1", input("Quake", EffectTiming::Initiative, Some(40)))
+            .is_err());
+        assert!(service
+            .add("mod-1", input("  ", EffectTiming::EachRound, None))
+            .is_err());
+        assert!(service
+            .add("mod-9", input("Smoke", EffectTiming::EachRound, None))
+            .is_err());
+
+        assert_eq!(service.list("mod-1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_update_and_remove() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = EncounterEffectService::new(&mut conn);
+
+        let effect = service
+            .add("mod-1", input("Collapse", EffectTiming::Round, Some(3)))
+            .unwrap();
+        let mut change = input("Ceiling Collapse", EffectTiming::Round, Some(4));
+        change.description = Some("Everyone in the hall makes a DC 15 Dexterity save.".into());
+        let updated = service.update(&effect.id, change).unwrap();
+        assert_eq!(updated.name, "Ceiling Collapse");
+        assert_eq!(updated.timing_value, Some(4));
+        assert!(updated.description.starts_with("Everyone"));
+
+        service.remove(&effect.id).unwrap();
+        assert!(service.remove(&effect.id).is_err());
+        assert!(service
+            .update(&effect.id, input("Collapse", EffectTiming::Round, Some(3)))
+            .is_err());
+    }
+}
@@ -0,0 +1,94 @@
+//! Favorite Service
+//!
+//! Pin and unpin catalog entries, documents, and maps for quick access.
+
+use diesel::SqliteConnection;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{Favorite, ItemKind, NewFavorite};
+use crate::services::{ItemRef, ServiceError, ServiceResult};
+
+/// Service for favorites.
+pub struct FavoriteService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> FavoriteService<'a> {
+    /// Create a new favorite service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Pin an item. Pinning an item that is already a favorite returns the
+    /// existing favorite.
+    pub fn pin(&mut self, item: &ItemRef) -> ServiceResult<Favorite> {
+        item.validate(self.conn)?;
+
+        if let Some(existing) =
+            dal::find_favorite(self.conn, item.kind, &item.ref_id, item.ref_source.as_deref())?
+        {
+            return Ok(existing);
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let sort_order = dal::next_favorite_sort_order(self.conn)?;
+        let mut favorite = NewFavorite::new(&id, item.kind, &item.ref_id, &item.title)
+            .with_sort_order(sort_order);
+        if let Some(ref source) = item.ref_source {
+            favorite = favorite.with_source(source);
+        }
+        if let Some(ref campaign_id) = item.campaign_id {
+            favorite = favorite.with_campaign(campaign_id);
+        }
+
+        dal::insert_favorite(self.conn, &favorite)?;
+        dal::get_favorite(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Unpin a favorite.
+    pub fn unpin(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_favorite(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("Favorite", id));
+        }
+        Ok(())
+    }
+
+    /// List favorites, optionally of one kind and limited to a campaign's
+    /// content plus the catalog.
+    pub fn list(
+        &mut self,
+        kind: Option<ItemKind>,
+        campaign_id: Option<&str>,
+    ) -> ServiceResult<Vec<Favorite>> {
+        dal::list_favorites(self.conn, kind, campaign_id).map_err(ServiceError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db;
+
+    #[test]
+    fn test_pin_is_idempotent() {
+        let mut conn = setup_test_db();
+        let mut service = FavoriteService::new(&mut conn);
+
+        let grappled = ItemRef::catalog(ItemKind::Condition, "Grappled", "PHB");
+        let first = service.pin(&grappled).expect("Failed to pin");
+        let second = service.pin(&grappled).expect("Failed to pin");
+        assert_eq!(first.id, second.id);
+
+        let prone = service
+            .pin(&ItemRef::catalog(ItemKind::Condition, "Prone", "PHB"))
+            .expect("Failed to pin");
+        assert_eq!(prone.sort_order, first.sort_order + 1);
+
+        service.unpin(&first.id).expect("Failed to unpin");
+        let remaining = service.list(None, None).expect("Failed to list");
+        assert_eq!(remaining.len(), 1);
+        assert!(service.unpin(&first.id).is_err());
+    }
+}
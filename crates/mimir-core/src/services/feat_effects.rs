@@ -0,0 +1,492 @@
+//! Feat Effects
+//!
+//! Reads the common mechanical effects of a feat from its catalog JSON
+//! (ability score increases, skill/tool/armor proficiencies, speed changes)
+//! so level-up can apply them instead of recording only the feat's name.
+//! Anything that needs a player's choice or can't be read from the data is
+//! reported back as a manual adjustment.
+
+use diesel::SqliteConnection;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::character::{extract_keyed_proficiencies, insert_proficiencies, ProficiencyEntry};
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::fts::flatten_entries;
+use crate::models::campaign::{Character, ProficiencyType, UpdateCharacter};
+use crate::services::ServiceResult;
+use crate::utils::now_rfc3339;
+
+/// Highest score an ability increase from a feat can reach.
+const ABILITY_SCORE_MAX: i32 = 20;
+
+/// Full ability name for a 5etools ability abbreviation.
+pub(super) fn ability_name(abbreviation: &str) -> Option<&'static str> {
+    match abbreviation {
+        "str" => Some("Strength"),
+        "dex" => Some("Dexterity"),
+        "con" => Some("Constitution"),
+        "int" => Some("Intelligence"),
+        "wis" => Some("Wisdom"),
+        "cha" => Some("Charisma"),
+        _ => None,
+    }
+}
+
+/// An ability increase the player picks from a list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbilityChoice {
+    /// Abilities to choose from (full names)
+    pub from: Vec<String>,
+    /// Amount the chosen ability increases by
+    pub amount: i32,
+}
+
+/// Mechanical effects read from a feat's catalog data.
+#[derive(Debug, Clone, Default)]
+pub struct FeatEffects {
+    /// Fixed ability increases as (ability, amount)
+    pub ability_increases: Vec<(String, i32)>,
+    /// Ability increase the player chooses
+    pub ability_choice: Option<AbilityChoice>,
+    /// Saving throw proficiency in whichever ability is chosen (e.g., Resilient)
+    pub save_follows_choice: bool,
+    /// Proficiencies granted outright
+    pub(super) proficiencies: Vec<ProficiencyEntry>,
+    /// Walking speed increase in feet
+    pub speed_bonus: i32,
+    /// Effects that must be applied by hand
+    pub manual_adjustments: Vec<String>,
+}
+
+impl FeatEffects {
+    /// Parse effects from a feat's catalog JSON.
+    pub fn from_data(feat_name: &str, data: &serde_json::Value) -> Self {
+        let mut effects = Self::default();
+
+        if let Some(ability) = data
+            .get("ability")
+            .and_then(|v| v.as_array())
+            .and_then(|a| a.first())
+            .and_then(|v| v.as_object())
+        {
+            for (key, value) in ability {
+                if key == "choose" {
+                    let from: Vec<String> = value
+                        .get("from")
+                        .and_then(|v| v.as_array())
+                        .map(|a| {
+                            a.iter()
+                                .filter_map(|v| v.as_str().and_then(ability_name))
+                                .map(String::from)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let amount = value.get("amount").and_then(|v| v.as_i64()).unwrap_or(1) as i32;
+                    if from.is_empty() {
+                        effects
+                            .manual_adjustments
+                            .push("Apply the ability score increase by hand".to_string());
+                    } else {
+                        effects.ability_choice = Some(AbilityChoice { from, amount });
+                    }
+                } else if let (Some(name), Some(amount)) = (ability_name(key), value.as_i64()) {
+                    effects
+                        .ability_increases
+                        .push((name.to_string(), amount as i32));
+                }
+            }
+        }
+
+        let keyed = [
+            (
+                "skillProficiencies",
+                ProficiencyType::Skill,
+                "skill proficiencies",
+            ),
+            (
+                "toolProficiencies",
+                ProficiencyType::Tool,
+                "tool proficiencies",
+            ),
+            (
+                "weaponProficiencies",
+                ProficiencyType::Weapon,
+                "weapon proficiencies",
+            ),
+            (
+                "armorProficiencies",
+                ProficiencyType::Armor,
+                "armor proficiencies",
+            ),
+            (
+                "languageProficiencies",
+                ProficiencyType::Language,
+                "languages",
+            ),
+        ];
+        for (field, prof_type, label) in keyed {
+            if let Some(items) = data.get(field).and_then(|v| v.as_array()) {
+                effects
+                    .proficiencies
+                    .extend(extract_keyed_proficiencies(items, prof_type));
+                if has_choice(items) {
+                    effects
+                        .manual_adjustments
+                        .push(format!("Choose the {} {} grants", label, feat_name));
+                }
+            }
+        }
+
+        if let Some(saves) = data
+            .get("savingThrowProficiencies")
+            .and_then(|v| v.as_array())
+        {
+            for save in saves.iter().filter_map(|v| v.as_object()) {
+                for (key, value) in save {
+                    if key == "choose" {
+                        effects.save_follows_choice = true;
+                    } else if let (Some(name), Some(true)) = (ability_name(key), value.as_bool()) {
+                        effects.proficiencies.push(ProficiencyEntry {
+                            prof_type: ProficiencyType::Save,
+                            name: name.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if data.get("expertise").is_some() {
+            effects
+                .manual_adjustments
+                .push(format!("Choose the expertise {} grants", feat_name));
+        }
+
+        if let Some(entries) = data.get("entries").and_then(|v| v.as_array()) {
+            effects.speed_bonus = speed_bonus_from_text(&flatten_entries(entries));
+        }
+
+        if !effects.has_effects() && effects.manual_adjustments.is_empty() {
+            effects.manual_adjustments.push(format!(
+                "No mechanical effects could be read from {}; apply its benefits by hand",
+                feat_name
+            ));
+        }
+
+        effects
+    }
+
+    /// Whether any effect was read from the data.
+    pub fn has_effects(&self) -> bool {
+        !self.ability_increases.is_empty()
+            || self.ability_choice.is_some()
+            || !self.proficiencies.is_empty()
+            || self.speed_bonus != 0
+    }
+}
+
+/// Whether a proficiency list leaves a choice to the player.
+fn has_choice(items: &[serde_json::Value]) -> bool {
+    items
+        .iter()
+        .filter_map(|v| v.as_object())
+        .any(|obj| obj.keys().any(|k| k == "choose" || k.starts_with("any")))
+}
+
+/// Regex to match a walking speed increase like "Your speed increases by 10 feet"
+static SPEED_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(?:your (?:walking )?speed increases by|increase your (?:walking )?speed by) (\d+) (?:feet|ft)",
+    )
+    .expect("Failed to compile speed regex")
+});
+
+/// Read a walking speed increase from a feat's text.
+fn speed_bonus_from_text(text: &str) -> i32 {
+    SPEED_REGEX
+        .captures(text)
+        .and_then(|c| c.get(1))
+        .and_then(|m| m.as_str().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Load and parse a feat's effects from the catalog.
+///
+/// Returns `None` when the feat isn't in the catalog.
+pub fn load_feat_effects(
+    conn: &mut SqliteConnection,
+    name: &str,
+    source: &str,
+) -> ServiceResult<Option<FeatEffects>> {
+    let feat = match catalog_dal::get_feat_by_name(conn, name, source)? {
+        Some(feat) => feat,
+        None => return Ok(None),
+    };
+    let data: serde_json::Value = serde_json::from_str(&feat.data).unwrap_or_default();
+    Ok(Some(FeatEffects::from_data(&feat.name, &data)))
+}
+
+/// Total walking speed increase from a character's feats.
+pub fn feat_speed_bonus(conn: &mut SqliteConnection, character_id: &str) -> ServiceResult<i32> {
+    let mut bonus = 0;
+    for feat in dal::list_character_feats(conn, character_id)? {
+        if let Some(effects) = load_feat_effects(conn, &feat.feat_name, &feat.feat_source)? {
+            bonus += effects.speed_bonus;
+        }
+    }
+    Ok(bonus)
+}
+
+/// What applying a feat's effects changed, and what is left to do by hand.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeatApplication {
+    /// Feat name
+    pub feat_name: String,
+    /// Effects applied to the character (e.g., "Strength +1")
+    pub applied: Vec<String>,
+    /// Effects that could not be applied automatically
+    pub manual_adjustments: Vec<String>,
+}
+
+/// Apply a feat's effects to a character.
+///
+/// `chosen_ability` picks the ability for feats that offer a choice. Speed
+/// increases are not stored; sheets derive them from the character's feats.
+pub(super) fn apply_feat_effects(
+    conn: &mut SqliteConnection,
+    character: &Character,
+    feat_name: &str,
+    feat_source: &str,
+    chosen_ability: Option<&str>,
+) -> ServiceResult<FeatApplication> {
+    let mut application = FeatApplication {
+        feat_name: feat_name.to_string(),
+        ..Default::default()
+    };
+
+    let effects = match load_feat_effects(conn, feat_name, feat_source)? {
+        Some(effects) => effects,
+        None => {
+            application.manual_adjustments.push(format!(
+                "{} ({}) is not in the catalog; apply its effects by hand",
+                feat_name, feat_source
+            ));
+            return Ok(application);
+        }
+    };
+    application.manual_adjustments = effects.manual_adjustments.clone();
+
+    let mut increases = effects.ability_increases.clone();
+    let mut proficiencies = effects.proficiencies.clone();
+
+    if let Some(choice) = &effects.ability_choice {
+        let chosen = chosen_ability.and_then(|a| {
+            choice
+                .from
+                .iter()
+                .find(|name| name.eq_ignore_ascii_case(a) || name[..3].eq_ignore_ascii_case(a))
+        });
+        match chosen {
+            Some(name) => {
+                increases.push((name.clone(), choice.amount));
+                if effects.save_follows_choice {
+                    proficiencies.push(ProficiencyEntry {
+                        prof_type: ProficiencyType::Save,
+                        name: name.clone(),
+                    });
+                }
+            }
+            None => {
+                application.manual_adjustments.push(format!(
+                    "Increase one of {} by {}",
+                    choice.from.join(", "),
+                    choice.amount
+                ));
+                if effects.save_follows_choice {
+                    application
+                        .manual_adjustments
+                        .push("Add proficiency in the chosen ability's saving throws".to_string());
+                }
+            }
+        }
+    }
+
+    if !increases.is_empty() {
+        let mut scores = [
+            character.strength,
+            character.dexterity,
+            character.constitution,
+            character.intelligence,
+            character.wisdom,
+            character.charisma,
+        ];
+        for (ability, amount) in &increases {
+            let index = match ability.as_str() {
+                "Strength" => 0,
+                "Dexterity" => 1,
+                "Constitution" => 2,
+                "Intelligence" => 3,
+                "Wisdom" => 4,
+                _ => 5,
+            };
+            scores[index] = (scores[index] + amount).min(ABILITY_SCORE_MAX);
+            application.applied.push(format!("{} +{}", ability, amount));
+        }
+
+        let now = now_rfc3339();
+        let update = UpdateCharacter {
+            strength: Some(scores[0]),
+            dexterity: Some(scores[1]),
+            constitution: Some(scores[2]),
+            intelligence: Some(scores[3]),
+            wisdom: Some(scores[4]),
+            charisma: Some(scores[5]),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_character(conn, &character.id, &update)?;
+    }
+
+    insert_proficiencies(conn, &character.id, &proficiencies)?;
+    for prof in &proficiencies {
+        application.applied.push(format!(
+            "{} proficiency: {}",
+            prof.prof_type.as_str(),
+            prof.name
+        ));
+    }
+
+    if effects.speed_bonus != 0 {
+        application
+            .applied
+            .push(format!("Speed +{} ft", effects.speed_bonus));
+    }
+
+    Ok(application)
+}
+
+/// Undo the effects `apply_feat_effects` stored for a feat that is being
+/// removed: the proficiencies it grants outright are deleted.
+///
+/// Ability increases are not reverted here; callers that remove feats reset
+/// ability scores themselves. Returns the proficiencies removed.
+pub(super) fn revert_feat_effects(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+    feat_name: &str,
+    feat_source: &str,
+) -> ServiceResult<Vec<String>> {
+    let Some(effects) = load_feat_effects(conn, feat_name, feat_source)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut removed = Vec::new();
+    for entry in &effects.proficiencies {
+        let type_str = entry.prof_type.as_str();
+        for prof in dal::list_proficiencies_by_type(conn, character_id, type_str)? {
+            if prof.name.eq_ignore_ascii_case(&entry.name) {
+                dal::delete_character_proficiency(conn, &prof.id)?;
+                removed.push(format!("{} proficiency: {}", type_str, prof.name));
+            }
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::catalog::insert_feat;
+    use crate::models::campaign::NewCampaign;
+    use crate::models::catalog::NewFeat;
+    use crate::services::{CharacterService, CreateCharacterInput};
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_fixed_increase_and_proficiencies() {
+        let data = json!({
+            "ability": [{"str": 1}],
+            "armorProficiencies": [{"heavy": true}],
+            "entries": ["You have trained to master the use of heavy armor."]
+        });
+        let effects = FeatEffects::from_data("Heavily Armored", &data);
+        assert_eq!(effects.ability_increases, vec![("Strength".to_string(), 1)]);
+        assert_eq!(effects.proficiencies.len(), 1);
+        assert_eq!(effects.proficiencies[0].name, "Heavy");
+        assert!(effects.manual_adjustments.is_empty());
+    }
+
+    #[test]
+    fn test_parse_choices_and_speed() {
+        let data = json!({
+            "ability": [{"choose": {"from": ["str", "dex"], "amount": 1}}],
+            "skillProficiencies": [{"any": 1}],
+            "entries": ["Your speed increases by 10 feet."]
+        });
+        let effects = FeatEffects::from_data("Mobile", &data);
+        let choice = effects.ability_choice.unwrap();
+        assert_eq!(choice.from, vec!["Strength", "Dexterity"]);
+        assert_eq!(effects.speed_bonus, 10);
+        assert_eq!(effects.manual_adjustments.len(), 1);
+    }
+
+    #[test]
+    fn test_unparseable_feat_reported() {
+        let data = json!({"entries": ["You gain a +5 bonus to initiative."]});
+        let effects = FeatEffects::from_data("Alert", &data);
+        assert!(!effects.has_effects());
+        assert_eq!(effects.manual_adjustments.len(), 1);
+    }
+
+    #[test]
+    fn test_speed_bonus_from_text() {
+        assert_eq!(
+            speed_bonus_from_text("Increase your walking speed by 5 feet."),
+            5
+        );
+        assert_eq!(speed_bonus_from_text("You can move at half speed."), 0);
+    }
+
+    #[test]
+    fn test_apply_resilient_with_choice() {
+        let mut conn = setup_test_db_with_sources();
+        crate::dal::campaign::insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test"))
+            .unwrap();
+        let data = json!({
+            "ability": [{"choose": {"from": ["str", "dex", "con", "int", "wis", "cha"]}}],
+            "savingThrowProficiencies": [{"choose": {"from": ["str", "dex", "con", "int", "wis", "cha"]}}]
+        })
+        .to_string();
+        insert_feat(&mut conn, &NewFeat::new("Resilient", "PHB", &data)).unwrap();
+        let character = CharacterService::new(&mut conn)
+            .create(
+                CreateCharacterInput::new_pc(Some("camp-1"), "Ada", "Alice")
+                    .with_ability_scores([10, 10, 13, 10, 10, 10]),
+            )
+            .unwrap();
+
+        let application =
+            apply_feat_effects(&mut conn, &character, "Resilient", "PHB", Some("con")).unwrap();
+        assert_eq!(
+            application.applied,
+            vec!["Constitution +1", "save proficiency: Constitution"]
+        );
+        assert!(application.manual_adjustments.is_empty());
+
+        let updated = dal::get_character(&mut conn, &character.id).unwrap();
+        assert_eq!(updated.constitution, 14);
+        assert!(
+            dal::character_has_proficiency(&mut conn, &character.id, "save", "Constitution")
+                .unwrap()
+        );
+
+        // Without a choice the increase is left to the player
+        let application =
+            apply_feat_effects(&mut conn, &character, "Resilient", "PHB", None).unwrap();
+        assert!(application.applied.is_empty());
+        assert_eq!(application.manual_adjustments.len(), 2);
+    }
+}
@@ -0,0 +1,651 @@
+//! Forage Service
+//!
+//! Business logic for gathering ingredients. Each campaign keeps gathering
+//! tables by environment: catalog or homebrew items, the check DC that finds
+//! them, how many are found, and a weight for how often they come up. A
+//! forage check rolls d20 plus the forager's modifier, picks one of the
+//! ingredients the total beats, and adds what was found to the party stash.
+//!
+//! Tables can be filled by hand or imported from a catalog table whose rows
+//! reference items (e.g., "01-50 | {@item Potion of Healing}").
+
+use diesel::SqliteConnection;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    CampaignStashItem, ForageIngredient, NewForageIngredient, UpdateForageIngredient,
+};
+use crate::services::stash::{resolve_item, StashItemInput, StashService};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Highest check DC an ingredient can have.
+const MAX_DC: i32 = 30;
+
+/// Source assumed for `{@item}` tags that don't name one.
+const DEFAULT_ITEM_SOURCE: &str = "DMG";
+
+/// Input for adding an ingredient to a gathering table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddForageIngredientInput {
+    /// Environment or biome (e.g., "forest")
+    pub environment: String,
+    /// Item name from the catalog (or campaign homebrew)
+    pub item_name: String,
+    /// Item source (e.g., "DMG", or "HB" for campaign homebrew)
+    pub item_source: String,
+    /// Check DC to find it (default 10)
+    pub dc: Option<i32>,
+    /// How many are found, as a number or dice expression (default "1")
+    pub quantity: Option<String>,
+    /// Relative chance among the ingredients a check finds (default 1)
+    pub weight: Option<i32>,
+}
+
+/// Input for updating an ingredient.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateForageIngredientInput {
+    /// New check DC
+    pub dc: Option<i32>,
+    /// New quantity expression
+    pub quantity: Option<String>,
+    /// New weight
+    pub weight: Option<i32>,
+}
+
+/// Result of importing a catalog table into a gathering table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForageImportResult {
+    /// Ingredients added
+    pub added: Vec<ForageIngredient>,
+    /// Rows skipped, with why (no item, unknown item, already in the table)
+    pub skipped: Vec<String>,
+}
+
+/// Result of a forage check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForageResult {
+    /// Environment foraged
+    pub environment: String,
+    /// The d20 roll
+    pub roll: i32,
+    /// Modifier added to the roll
+    pub modifier: i32,
+    /// Check total
+    pub total: i32,
+    /// Lowest DC in the environment's table
+    pub lowest_dc: i32,
+    /// Ingredient found, if the total beat any DC
+    pub ingredient: Option<ForageIngredient>,
+    /// How many were found
+    pub quantity: i32,
+    /// The stash stack they were added to
+    pub stash_item: Option<CampaignStashItem>,
+}
+
+/// Service for gathering tables and forage checks.
+pub struct ForageService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> ForageService<'a> {
+    /// Create a new forage service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a campaign's ingredients, optionally for one environment.
+    pub fn list(
+        &mut self,
+        campaign_id: &str,
+        environment: Option<&str>,
+    ) -> ServiceResult<Vec<ForageIngredient>> {
+        require_campaign(self.conn, campaign_id)?;
+        match environment {
+            Some(environment) => {
+                let environment = validate_environment(environment)?;
+                dal::list_forage_ingredients_for_environment(self.conn, campaign_id, environment)
+            }
+            None => dal::list_forage_ingredients(self.conn, campaign_id),
+        }
+        .map_err(ServiceError::from)
+    }
+
+    /// Add an ingredient to an environment's gathering table.
+    pub fn add_ingredient(
+        &mut self,
+        campaign_id: &str,
+        input: AddForageIngredientInput,
+    ) -> ServiceResult<ForageIngredient> {
+        require_campaign(self.conn, campaign_id)?;
+        let environment = validate_environment(&input.environment)?;
+        let dc = validate_dc(input.dc.unwrap_or(10))?;
+        let quantity = match input.quantity {
+            Some(ref quantity) => validate_quantity(quantity)?,
+            None => "1".to_string(),
+        };
+        let weight = validate_weight(input.weight.unwrap_or(1))?;
+        let item_name = resolve_item(self.conn, campaign_id, &input.item_name, &input.item_source)?;
+
+        if dal::find_forage_ingredient(
+            self.conn,
+            campaign_id,
+            environment,
+            &item_name,
+            &input.item_source,
+        )?
+        .is_some()
+        {
+            return Err(ServiceError::validation(format!(
+                "{} is already in the {} table",
+                item_name, environment
+            )));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let ingredient = NewForageIngredient::new(
+            &id,
+            campaign_id,
+            environment,
+            &item_name,
+            &input.item_source,
+        )
+        .with_dc(dc)
+        .with_quantity(&quantity)
+        .with_weight(weight);
+        dal::insert_forage_ingredient(self.conn, &ingredient)?;
+        dal::get_forage_ingredient(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Change an ingredient's DC, quantity or weight.
+    pub fn update_ingredient(
+        &mut self,
+        id: &str,
+        input: UpdateForageIngredientInput,
+    ) -> ServiceResult<ForageIngredient> {
+        dal::get_forage_ingredient_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Forage ingredient", id))?;
+        let dc = input.dc.map(validate_dc).transpose()?;
+        let quantity = input
+            .quantity
+            .as_deref()
+            .map(validate_quantity)
+            .transpose()?;
+        let weight = input.weight.map(validate_weight).transpose()?;
+
+        let now = now_rfc3339();
+        let update = UpdateForageIngredient {
+            dc,
+            quantity: quantity.as_deref(),
+            weight,
+            updated_at: Some(&now),
+        };
+        dal::update_forage_ingredient(self.conn, id, &update)?;
+        dal::get_forage_ingredient(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Remove an ingredient from its gathering table.
+    pub fn remove_ingredient(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_forage_ingredient(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("Forage ingredient", id));
+        }
+        Ok(())
+    }
+
+    /// Add the items in a catalog table to an environment's gathering table,
+    /// all at one DC. A row's roll range becomes its weight, so a "01-50"
+    /// row comes up five times as often as a "91-95" row.
+    pub fn import_catalog_table(
+        &mut self,
+        campaign_id: &str,
+        environment: &str,
+        table_name: &str,
+        table_source: &str,
+        dc: Option<i32>,
+    ) -> ServiceResult<ForageImportResult> {
+        require_campaign(self.conn, campaign_id)?;
+        let environment = validate_environment(environment)?;
+        let dc = validate_dc(dc.unwrap_or(10))?;
+        let table = catalog_dal::get_catalog_table_by_name(self.conn, table_name, table_source)?
+            .ok_or_else(|| {
+                ServiceError::not_found("Table", format!("{} ({})", table_name, table_source))
+            })?;
+        let data = table
+            .parse_data()
+            .map_err(|e| ServiceError::validation(format!("Invalid table data: {}", e)))?;
+
+        let mut result = ForageImportResult {
+            added: Vec::new(),
+            skipped: Vec::new(),
+        };
+        for row in table_item_rows(&data) {
+            let (name, source, weight) = match row {
+                Ok(row) => row,
+                Err(text) => {
+                    result.skipped.push(format!("{}: no item", text));
+                    continue;
+                }
+            };
+            let input = AddForageIngredientInput {
+                environment: environment.to_string(),
+                item_name: name.clone(),
+                item_source: source.clone(),
+                dc: Some(dc),
+                quantity: None,
+                weight: Some(weight),
+            };
+            match self.add_ingredient(campaign_id, input) {
+                Ok(ingredient) => result.added.push(ingredient),
+                Err(ServiceError::NotFound { .. }) => result
+                    .skipped
+                    .push(format!("{} ({}): not in the catalog", name, source)),
+                Err(ServiceError::Validation(_)) => result
+                    .skipped
+                    .push(format!("{} ({}): already in the table", name, source)),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Make a forage check in an environment and add what's found to the
+    /// party stash. `roll` uses a physical d20 result instead of rolling.
+    pub fn forage(
+        &mut self,
+        campaign_id: &str,
+        environment: &str,
+        modifier: i32,
+        roll: Option<i32>,
+    ) -> ServiceResult<ForageResult> {
+        require_campaign(self.conn, campaign_id)?;
+        let environment = validate_environment(environment)?;
+        let roll = match roll {
+            Some(roll) if !(1..=20).contains(&roll) => {
+                return Err(ServiceError::validation(format!(
+                    "A d20 roll must be 1-20, got {}",
+                    roll
+                )));
+            }
+            Some(roll) => roll,
+            None => rand::thread_rng().gen_range(1..=20),
+        };
+
+        let table =
+            dal::list_forage_ingredients_for_environment(self.conn, campaign_id, environment)?;
+        let Some(lowest_dc) = table.iter().map(|i| i.dc).min() else {
+            return Err(self.unknown_environment(campaign_id, environment));
+        };
+
+        let total = roll + modifier;
+        let found: Vec<&ForageIngredient> = table.iter().filter(|i| i.dc <= total).collect();
+        let mut result = ForageResult {
+            environment: environment.to_string(),
+            roll,
+            modifier,
+            total,
+            lowest_dc,
+            ingredient: None,
+            quantity: 0,
+            stash_item: None,
+        };
+        let Some(ingredient) = pick_weighted(&found) else {
+            return Ok(result);
+        };
+
+        let quantity = roll_quantity(&ingredient.quantity);
+        let stash_item = StashService::new(self.conn).add_item(
+            campaign_id,
+            StashItemInput {
+                item_name: ingredient.item_name.clone(),
+                item_source: ingredient.item_source.clone(),
+                quantity: Some(quantity),
+                note: Some(format!("Foraged ({})", ingredient.environment)),
+            },
+        )?;
+        result.ingredient = Some(ingredient.clone());
+        result.quantity = quantity;
+        result.stash_item = Some(stash_item);
+        Ok(result)
+    }
+
+    fn unknown_environment(&mut self, campaign_id: &str, environment: &str) -> ServiceError {
+        let mut environments: Vec<String> = dal::list_forage_ingredients(self.conn, campaign_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| i.environment.to_lowercase())
+            .collect();
+        environments.dedup();
+        if environments.is_empty() {
+            return ServiceError::validation(format!(
+                "No gathering table for {}; this campaign has no forage ingredients yet",
+                environment
+            ));
+        }
+        ServiceError::validation(format!(
+            "No gathering table for {}; available: {}",
+            environment,
+            environments.join(", ")
+        ))
+    }
+}
+
+/// Pick an ingredient, each weighted by its `weight`.
+fn pick_weighted<'i>(found: &[&'i ForageIngredient]) -> Option<&'i ForageIngredient> {
+    let total: i32 = found.iter().map(|i| i.weight).sum();
+    if total < 1 {
+        return None;
+    }
+    let mut roll = rand::thread_rng().gen_range(0..total);
+    for ingredient in found {
+        if roll < ingredient.weight {
+            return Some(ingredient);
+        }
+        roll -= ingredient.weight;
+    }
+    None
+}
+
+/// Parse a quantity such as "3", "1d4" or "2d6+1" into (count, sides, bonus).
+/// A plain number has no dice.
+fn parse_quantity(quantity: &str) -> Option<(u32, u32, i32)> {
+    let compact: String = quantity
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    let Some((count, rest)) = compact.split_once('d') else {
+        return compact.parse().ok().map(|n| (0, 0, n));
+    };
+    let count: u32 = if count.is_empty() {
+        1
+    } else {
+        count.parse().ok()?
+    };
+    let (sides, bonus) = match rest.find(['+', '-']) {
+        Some(i) => (&rest[..i], rest[i..].parse().ok()?),
+        None => (rest, 0),
+    };
+    let sides: u32 = sides.parse().ok()?;
+    if count == 0 || sides == 0 {
+        return None;
+    }
+    Some((count, sides, bonus))
+}
+
+/// Roll a validated quantity, finding at least one.
+fn roll_quantity(quantity: &str) -> i32 {
+    let Some((count, sides, bonus)) = parse_quantity(quantity) else {
+        return 1;
+    };
+    let mut rng = rand::thread_rng();
+    let dice: i32 = (0..count).map(|_| rng.gen_range(1..=sides) as i32).sum();
+    (dice + bonus).max(1)
+}
+
+/// The item rows of a 5etools table as (name, source, weight), or the row's
+/// text when it doesn't reference an item.
+fn table_item_rows(data: &Value) -> Vec<Result<(String, String, i32), String>> {
+    let Some(rows) = data.get("rows").and_then(|r| r.as_array()) else {
+        return Vec::new();
+    };
+    rows.iter()
+        .filter_map(|row| row.as_array())
+        .map(|cells| {
+            let weight = cells.first().and_then(roll_span).unwrap_or(1);
+            let text: Vec<&str> = cells.iter().filter_map(|c| c.as_str()).collect();
+            text.iter()
+                .find_map(|t| item_tag(t))
+                .map(|(name, source)| (name, source, weight))
+                .ok_or_else(|| text.join(" | "))
+        })
+        .collect()
+}
+
+/// How many die faces a roll cell covers: "01-50" is 50, "7" is 1, and
+/// `{"roll": {"min": 1, "max": 5}}` is 5. Not a roll cell is None.
+fn roll_span(cell: &Value) -> Option<i32> {
+    if let Some(roll) = cell.get("roll") {
+        let min = roll.get("min").and_then(|v| v.as_i64());
+        let max = roll.get("max").and_then(|v| v.as_i64());
+        let exact = roll.get("exact").and_then(|v| v.as_i64());
+        return match (min, max, exact) {
+            (Some(min), Some(max), _) if max >= min => Some((max - min + 1) as i32),
+            (_, _, Some(_)) => Some(1),
+            _ => None,
+        };
+    }
+    let text = cell.as_str()?.trim();
+    let face = |s: &str| -> Option<i32> {
+        // 5etools writes the d100 face 100 as "00".
+        match s.trim() {
+            "00" => Some(100),
+            s => s.parse().ok(),
+        }
+    };
+    match text.split_once(['-', '\u{2013}']) {
+        Some((min, max)) => {
+            let (min, max) = (face(min)?, face(max)?);
+            (max >= min).then_some(max - min + 1)
+        }
+        None => face(text).map(|_| 1),
+    }
+}
+
+/// The first `{@item Name|Source}` tag in some text.
+fn item_tag(text: &str) -> Option<(String, String)> {
+    let start = text.find("{@item ")? + "{@item ".len();
+    let end = start + text[start..].find('}')?;
+    let mut parts = text[start..end].split('|');
+    let name = parts.next()?.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let source = parts
+        .next()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or(DEFAULT_ITEM_SOURCE);
+    Some((name.to_string(), source.to_string()))
+}
+
+fn validate_environment(environment: &str) -> ServiceResult<&str> {
+    let environment = environment.trim();
+    if environment.is_empty() {
+        return Err(ServiceError::validation("Environment cannot be empty"));
+    }
+    Ok(environment)
+}
+
+fn validate_dc(dc: i32) -> ServiceResult<i32> {
+    if !(1..=MAX_DC).contains(&dc) {
+        return Err(ServiceError::validation(format!(
+            "DC must be 1-{}, got {}",
+            MAX_DC, dc
+        )));
+    }
+    Ok(dc)
+}
+
+fn validate_quantity(quantity: &str) -> ServiceResult<String> {
+    match parse_quantity(quantity) {
+        Some((0, _, n)) if n < 1 => Err(ServiceError::validation(format!(
+            "Quantity must be at least 1, got {}",
+            n
+        ))),
+        Some(_) => Ok(quantity
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase()),
+        None => Err(ServiceError::validation(format!(
+            "'{}' is not a number or dice expression like 1d4",
+            quantity
+        ))),
+    }
+}
+
+fn validate_weight(weight: i32) -> ServiceResult<i32> {
+    if weight < 1 {
+        return Err(ServiceError::validation(format!(
+            "Weight must be at least 1, got {}",
+            weight
+        )));
+    }
+    Ok(weight)
+}
+
+fn require_campaign(conn: &mut SqliteConnection, campaign_id: &str) -> ServiceResult<()> {
+    dal::get_campaign_optional(conn, campaign_id)?
+        .map(|_| ())
+        .ok_or_else(|| ServiceError::not_found("Campaign", campaign_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::dal::catalog::{insert_catalog_table, insert_item};
+    use crate::models::campaign::NewCampaign;
+    use crate::models::catalog::{NewCatalogTable, NewItem};
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        for name in ["Potion of Healing", "Antitoxin"] {
+            let data = json!({ "name": name, "source": "DMG" }).to_string();
+            insert_item(conn, &NewItem::new(name, "DMG", &data)).unwrap();
+        }
+    }
+
+    fn ingredient(name: &str, dc: i32, quantity: &str) -> AddForageIngredientInput {
+        AddForageIngredientInput {
+            environment: "Forest".to_string(),
+            item_name: name.to_string(),
+            item_source: "DMG".to_string(),
+            dc: Some(dc),
+            quantity: Some(quantity.to_string()),
+            weight: None,
+        }
+    }
+
+    #[test]
+    fn test_add_ingredient_validates() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = ForageService::new(&mut conn);
+
+        let added = service
+            .add_ingredient("camp-1", ingredient("potion of healing", 12, "1D4"))
+            .unwrap();
+        assert_eq!(added.item_name, "Potion of Healing");
+        assert_eq!(added.quantity, "1d4");
+
+        assert!(service
+            .add_ingredient("camp-1", ingredient("Potion of Healing", 12, "1"))
+            .is_err());
+        assert!(service
+            .add_ingredient("camp-1", ingredient("Antitoxin", 31, "1"))
+            .is_err());
+        assert!(service
+            .add_ingredient("camp-1", ingredient("Antitoxin", 10, "a handful"))
+            .is_err());
+        assert!(service
+            .add_ingredient("camp-1", ingredient("Moonpetal", 10, "1"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_forage_adds_to_stash() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = ForageService::new(&mut conn);
+        service
+            .add_ingredient("camp-1", ingredient("Antitoxin", 10, "2"))
+            .unwrap();
+        service
+            .add_ingredient("camp-1", ingredient("Potion of Healing", 25, "1"))
+            .unwrap();
+
+        let miss = service.forage("camp-1", "forest", 2, Some(5)).unwrap();
+        assert_eq!(miss.total, 7);
+        assert_eq!(miss.lowest_dc, 10);
+        assert!(miss.ingredient.is_none());
+
+        let hit = service.forage("camp-1", "FOREST", 2, Some(10)).unwrap();
+        assert_eq!(hit.ingredient.unwrap().item_name, "Antitoxin");
+        assert_eq!(hit.quantity, 2);
+        assert_eq!(hit.stash_item.unwrap().quantity, 2);
+
+        let stash = StashService::new(&mut conn).get("camp-1").unwrap();
+        assert_eq!(stash.items.len(), 1);
+        assert_eq!(stash.items[0].item_name, "Antitoxin");
+    }
+
+    #[test]
+    fn test_forage_unknown_environment_lists_tables() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = ForageService::new(&mut conn);
+        service
+            .add_ingredient("camp-1", ingredient("Antitoxin", 10, "1"))
+            .unwrap();
+
+        let err = service.forage("camp-1", "desert", 0, None).unwrap_err();
+        assert!(err.to_string().contains("available: forest"));
+        assert!(service.forage("camp-1", "forest", 0, Some(21)).is_err());
+    }
+
+    #[test]
+    fn test_import_catalog_table() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let data = json!({
+            "name": "Forest Finds",
+            "colLabels": ["d100", "Find"],
+            "rows": [
+                ["01-75", "{@item Potion of Healing}"],
+                ["76-95", "{@item Antitoxin|DMG}"],
+                ["96-00", "{@item Moonpetal|HB}"],
+                ["00", "Nothing"]
+            ]
+        })
+        .to_string();
+        insert_catalog_table(
+            &mut conn,
+            &NewCatalogTable {
+                name: "Forest Finds",
+                source: "DMG",
+                data: &data,
+            },
+        )
+        .unwrap();
+        let mut service = ForageService::new(&mut conn);
+
+        let result = service
+            .import_catalog_table("camp-1", "forest", "Forest Finds", "DMG", Some(13))
+            .unwrap();
+        assert_eq!(result.added.len(), 2);
+        assert_eq!(result.added[0].weight, 75);
+        assert_eq!(result.added[1].weight, 20);
+        assert!(result.added.iter().all(|i| i.dc == 13));
+        assert_eq!(result.skipped.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_quantity_and_roll_span() {
+        assert_eq!(parse_quantity("3"), Some((0, 0, 3)));
+        assert_eq!(parse_quantity("2d6 + 1"), Some((2, 6, 1)));
+        assert_eq!(parse_quantity("d4"), Some((1, 4, 0)));
+        assert_eq!(parse_quantity("0d4"), None);
+        assert_eq!(roll_span(&json!("01-50")), Some(50));
+        assert_eq!(roll_span(&json!("91\u{2013}00")), Some(10));
+        assert_eq!(roll_span(&json!({"roll": {"min": 2, "max": 4}})), Some(3));
+        assert_eq!(roll_span(&json!("Nothing")), None);
+    }
+}
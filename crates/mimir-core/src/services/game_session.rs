@@ -0,0 +1,511 @@
+//! Game Session Service
+//!
+//! Business logic for recording sessions played at the table and their pacing totals.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{GameSession, NewGameSession, UpdateGameSession};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Input for starting a new game session.
+#[derive(Debug, Clone)]
+pub struct StartSessionInput {
+    /// Campaign being played
+    pub campaign_id: String,
+    /// Module being run (optional)
+    pub module_id: Option<String>,
+    /// Session title (optional)
+    pub title: Option<String>,
+    /// Party playing the session (optional)
+    pub party_id: Option<String>,
+}
+
+impl StartSessionInput {
+    /// Create input for a campaign session.
+    pub fn new(campaign_id: impl Into<String>) -> Self {
+        Self {
+            campaign_id: campaign_id.into(),
+            module_id: None,
+            title: None,
+            party_id: None,
+        }
+    }
+
+    /// Set the module being run.
+    pub fn with_module(mut self, module_id: impl Into<String>) -> Self {
+        self.module_id = Some(module_id.into());
+        self
+    }
+
+    /// Set the session title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the party playing the session.
+    pub fn with_party(mut self, party_id: impl Into<String>) -> Self {
+        self.party_id = Some(party_id.into());
+        self
+    }
+}
+
+/// Pacing totals recorded from the table timers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionTotals {
+    /// Time on the session clock, in seconds
+    pub play_seconds: i32,
+    /// Time spent on breaks, in seconds
+    pub break_seconds: i32,
+    /// Number of timed combat turns
+    pub combat_turns: i32,
+    /// Total time spent on combat turns, in seconds
+    pub combat_turn_seconds: i32,
+    /// Longest single combat turn, in seconds
+    pub longest_turn_seconds: i32,
+}
+
+impl SessionTotals {
+    /// Totals last recorded on a session.
+    pub fn recorded(session: &GameSession) -> Self {
+        Self {
+            play_seconds: session.play_seconds,
+            break_seconds: session.break_seconds,
+            combat_turns: session.combat_turns,
+            combat_turn_seconds: session.combat_turn_seconds,
+            longest_turn_seconds: session.longest_turn_seconds,
+        }
+    }
+}
+
+/// Service for game session management.
+pub struct GameSessionService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> GameSessionService<'a> {
+    /// Create a new game session service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Start a new session.
+    ///
+    /// Fails if the campaign already has a session in progress.
+    pub fn start(&mut self, input: StartSessionInput) -> ServiceResult<GameSession> {
+        if !dal::campaign_exists(self.conn, &input.campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", &input.campaign_id));
+        }
+        if let Some(ref module_id) = input.module_id {
+            if !dal::module_exists(self.conn, module_id)? {
+                return Err(ServiceError::not_found("Module", module_id));
+            }
+        }
+        if let Some(ref party_id) = input.party_id {
+            let in_campaign = dal::get_party_optional(self.conn, party_id)?
+                .is_some_and(|p| p.campaign_id == input.campaign_id);
+            if !in_campaign {
+                return Err(ServiceError::not_found("Party", party_id));
+            }
+        }
+        if let Some(active) = dal::get_active_game_session(self.conn, &input.campaign_id)? {
+            return Err(ServiceError::validation(format!(
+                "Session {} is still in progress for this campaign",
+                active.session_number
+            )));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let number = dal::next_game_session_number(self.conn, &input.campaign_id)?;
+
+        let mut new_session = NewGameSession::new(&id, &input.campaign_id, number);
+        if let Some(ref module_id) = input.module_id {
+            new_session = new_session.with_module(module_id);
+        }
+        if let Some(ref title) = input.title {
+            new_session = new_session.with_title(title);
+        }
+        if let Some(ref party_id) = input.party_id {
+            new_session = new_session.with_party(party_id);
+        }
+
+        dal::insert_game_session(self.conn, &new_session)?;
+        dal::get_game_session(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Get a session by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<Option<GameSession>> {
+        dal::get_game_session_optional(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// List all sessions for a campaign, most recent first.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<GameSession>> {
+        dal::list_game_sessions(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// List all sessions played by a party, most recent first.
+    pub fn list_for_party(&mut self, party_id: &str) -> ServiceResult<Vec<GameSession>> {
+        dal::list_party_game_sessions(self.conn, party_id).map_err(ServiceError::from)
+    }
+
+    /// Get the in-progress session for a campaign, if any.
+    pub fn get_active(&mut self, campaign_id: &str) -> ServiceResult<Option<GameSession>> {
+        dal::get_active_game_session(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// List in-progress sessions across all campaigns, most recently started first.
+    pub fn list_active(&mut self) -> ServiceResult<Vec<GameSession>> {
+        dal::list_active_game_sessions(self.conn).map_err(ServiceError::from)
+    }
+
+    /// Record pacing totals for a session without ending it.
+    pub fn record_totals(&mut self, id: &str, totals: &SessionTotals) -> ServiceResult<GameSession> {
+        self.write_totals(id, totals, None)
+    }
+
+    /// End a session, recording its final pacing totals.
+    pub fn end(&mut self, id: &str, totals: &SessionTotals) -> ServiceResult<GameSession> {
+        let session = dal::get_game_session_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("GameSession", id))?;
+        if !session.is_active() {
+            return Err(ServiceError::validation(format!(
+                "Session {} has already ended",
+                session.session_number
+            )));
+        }
+
+        let now = now_rfc3339();
+        self.write_totals(id, totals, Some(&now))
+    }
+
+    /// End a session that is no longer being timed, keeping the totals last
+    /// recorded on it.
+    pub fn abandon(&mut self, id: &str) -> ServiceResult<GameSession> {
+        let session = dal::get_game_session_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("GameSession", id))?;
+        self.end(id, &SessionTotals::recorded(&session))
+    }
+
+    /// Set the XP each party member earned in a session.
+    pub fn award_xp(&mut self, id: &str, xp: i32) -> ServiceResult<GameSession> {
+        if xp < 0 {
+            return Err(ServiceError::validation("Awarded XP cannot be negative"));
+        }
+
+        let now = now_rfc3339();
+        let update = UpdateGameSession {
+            xp_awarded: Some(xp),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        let rows = dal::update_game_session(self.conn, id, &update)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("GameSession", id));
+        }
+
+        dal::get_game_session(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Attach a transcript to a session, replacing any earlier one.
+    ///
+    /// Accepts plain text or the subtitle formats speech-to-text tools write
+    /// (WebVTT, SRT, whisper.cpp console output); cue numbers and timestamps
+    /// are stripped. A blank transcript clears it.
+    pub fn set_transcript(&mut self, id: &str, transcript: &str) -> ServiceResult<GameSession> {
+        let text = transcript_text(transcript);
+        let now = now_rfc3339();
+        let update = UpdateGameSession {
+            transcript: Some(Some(text.as_str()).filter(|t| !t.is_empty())),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        let rows = dal::update_game_session(self.conn, id, &update)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("GameSession", id));
+        }
+
+        dal::get_game_session(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Delete a session.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_game_session(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("GameSession", id));
+        }
+        Ok(())
+    }
+
+    fn write_totals(
+        &mut self,
+        id: &str,
+        totals: &SessionTotals,
+        ended_at: Option<&str>,
+    ) -> ServiceResult<GameSession> {
+        let now = now_rfc3339();
+        let update = UpdateGameSession {
+            ended_at: ended_at.map(Some),
+            play_seconds: Some(totals.play_seconds),
+            break_seconds: Some(totals.break_seconds),
+            combat_turns: Some(totals.combat_turns),
+            combat_turn_seconds: Some(totals.combat_turn_seconds),
+            longest_turn_seconds: Some(totals.longest_turn_seconds),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+
+        let rows = dal::update_game_session(self.conn, id, &update)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("GameSession", id));
+        }
+
+        dal::get_game_session(self.conn, id).map_err(ServiceError::from)
+    }
+}
+
+/// Reduce a transcript to its spoken text, one cue per line.
+pub fn transcript_text(raw: &str) -> String {
+    let lines: Vec<&str> = raw.lines().map(str::trim).collect();
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &line)| {
+            if i == 0 && line.starts_with("WEBVTT") {
+                return None;
+            }
+            // whisper.cpp console output: "[00:00:00.000 --> 00:00:04.000]  text"
+            if line.starts_with('[') && line.contains("-->") {
+                return line.split_once(']').map(|(_, text)| text.trim());
+            }
+            if line.contains("-->") || is_srt_cue_number(line, lines.get(i + 1)) {
+                return None;
+            }
+            Some(line)
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check for an SRT cue number, which sits right above its cue's timing line.
+///
+/// Spoken lines that are only digits, like a called-out die roll, are kept.
+fn is_srt_cue_number(line: &str, next: Option<&&str>) -> bool {
+    !line.is_empty()
+        && line.chars().all(|c| c.is_ascii_digit())
+        && next.is_some_and(|next| !next.starts_with('[') && next.contains("-->"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::models::campaign::{NewCampaign, NewParty};
+    use crate::test_utils::setup_test_db;
+
+    fn create_test_campaign(conn: &mut SqliteConnection) -> String {
+        let campaign_id = Uuid::new_v4().to_string();
+        let campaign = NewCampaign::new(&campaign_id, "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        campaign_id
+    }
+
+    #[test]
+    fn test_start_numbers_sessions() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = GameSessionService::new(&mut conn);
+
+        let first = service
+            .start(StartSessionInput::new(&campaign_id).with_title("Session Zero"))
+            .expect("Failed to start session");
+        assert_eq!(first.session_number, 1);
+        assert!(first.is_active());
+
+        service
+            .end(&first.id, &SessionTotals::default())
+            .expect("Failed to end session");
+
+        let second = service
+            .start(StartSessionInput::new(&campaign_id))
+            .expect("Failed to start session");
+        assert_eq!(second.session_number, 2);
+    }
+
+    #[test]
+    fn test_start_rejects_concurrent_session() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = GameSessionService::new(&mut conn);
+
+        service
+            .start(StartSessionInput::new(&campaign_id))
+            .expect("Failed to start session");
+        let result = service.start(StartSessionInput::new(&campaign_id));
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+    }
+
+    #[test]
+    fn test_start_with_party_and_award_xp() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let party = NewParty::new("party-1", &campaign_id, "Tuesday Group");
+        dal::insert_party(&mut conn, &party).expect("Failed to create party");
+        let mut service = GameSessionService::new(&mut conn);
+
+        let result = service.start(StartSessionInput::new(&campaign_id).with_party("missing"));
+        assert!(matches!(result, Err(ServiceError::NotFound { .. })));
+
+        let session = service
+            .start(StartSessionInput::new(&campaign_id).with_party("party-1"))
+            .expect("Failed to start session");
+        assert_eq!(session.party_id.as_deref(), Some("party-1"));
+
+        let session = service
+            .award_xp(&session.id, 450)
+            .expect("Failed to award XP");
+        assert_eq!(session.xp_awarded, 450);
+        assert!(service.award_xp(&session.id, -1).is_err());
+
+        let sessions = service.list_for_party("party-1").expect("Failed to list");
+        assert_eq!(sessions.len(), 1);
+    }
+
+    #[test]
+    fn test_start_unknown_campaign() {
+        let mut conn = setup_test_db();
+        let mut service = GameSessionService::new(&mut conn);
+
+        let result = service.start(StartSessionInput::new("missing"));
+        assert!(matches!(result, Err(ServiceError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_end_records_totals() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = GameSessionService::new(&mut conn);
+
+        let session = service
+            .start(StartSessionInput::new(&campaign_id))
+            .expect("Failed to start session");
+
+        let totals = SessionTotals {
+            play_seconds: 3 * 3600,
+            break_seconds: 900,
+            combat_turns: 24,
+            combat_turn_seconds: 1440,
+            longest_turn_seconds: 180,
+        };
+        let ended = service.end(&session.id, &totals).expect("Failed to end session");
+
+        assert!(!ended.is_active());
+        assert_eq!(ended.play_seconds, 10800);
+        assert_eq!(ended.average_turn_seconds(), Some(60.0));
+        assert!(service.get_active(&campaign_id).unwrap().is_none());
+
+        // Ending twice is rejected
+        assert!(service.end(&session.id, &totals).is_err());
+    }
+
+    #[test]
+    fn test_record_totals_keeps_session_active() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = GameSessionService::new(&mut conn);
+
+        let session = service
+            .start(StartSessionInput::new(&campaign_id))
+            .expect("Failed to start session");
+        let totals = SessionTotals {
+            play_seconds: 600,
+            ..Default::default()
+        };
+        let updated = service
+            .record_totals(&session.id, &totals)
+            .expect("Failed to record totals");
+
+        assert!(updated.is_active());
+        assert_eq!(updated.play_seconds, 600);
+    }
+
+    #[test]
+    fn test_abandon_keeps_recorded_totals() {
+        let mut conn = setup_test_db();
+        let first = create_test_campaign(&mut conn);
+        let second = create_test_campaign(&mut conn);
+        let mut service = GameSessionService::new(&mut conn);
+
+        let session = service
+            .start(StartSessionInput::new(&first))
+            .expect("Failed to start session");
+        service
+            .start(StartSessionInput::new(&second))
+            .expect("Failed to start session");
+        assert_eq!(service.list_active().unwrap().len(), 2);
+
+        let totals = SessionTotals {
+            play_seconds: 1200,
+            ..Default::default()
+        };
+        service.record_totals(&session.id, &totals).unwrap();
+        let ended = service.abandon(&session.id).expect("Failed to abandon");
+        assert!(!ended.is_active());
+        assert_eq!(ended.play_seconds, 1200);
+        assert_eq!(service.list_active().unwrap().len(), 1);
+
+        // The campaign can start a new session again
+        service
+            .start(StartSessionInput::new(&first))
+            .expect("Failed to start session");
+    }
+
+    #[test]
+    fn test_set_transcript_strips_subtitle_timing() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = GameSessionService::new(&mut conn);
+
+        let session = service
+            .start(StartSessionInput::new(&campaign_id))
+            .expect("Failed to start session");
+        let vtt = "WEBVTT\n\n00:00:00.000 --> 00:00:03.000\nYou enter the tavern.\n\n00:00:03.000 --> 00:00:05.500\nI order an ale.\n";
+        let updated = service
+            .set_transcript(&session.id, vtt)
+            .expect("Failed to set transcript");
+        assert_eq!(
+            updated.transcript.as_deref(),
+            Some("You enter the tavern.\nI order an ale.")
+        );
+
+        let cleared = service
+            .set_transcript(&session.id, "  \n")
+            .expect("Failed to clear transcript");
+        assert!(cleared.transcript.is_none());
+
+        assert!(service.set_transcript("missing", "text").is_err());
+    }
+
+    #[test]
+    fn test_transcript_text_formats() {
+        let srt = "1\n00:00:00,000 --> 00:00:02,000\nRoll initiative.\n\n2\n00:00:02,000 --> 00:00:04,000\nSeventeen!\n";
+        assert_eq!(transcript_text(srt), "Roll initiative.\nSeventeen!");
+
+        let console = "[00:00:00.000 --> 00:00:04.000]   The door creaks open.\n";
+        assert_eq!(transcript_text(console), "The door creaks open.");
+
+        assert_eq!(transcript_text("Plain notes.\n"), "Plain notes.");
+    }
+
+    #[test]
+    fn test_transcript_text_keeps_spoken_numbers() {
+        let srt = "1\n00:00:00,000 --> 00:00:02,000\nWhat did you roll?\n20\n\n2\n00:00:02,000 --> 00:00:04,000\n15\n";
+        assert_eq!(transcript_text(srt), "What did you roll?\n20\n15");
+
+        assert_eq!(transcript_text("Roll it.\n7\n"), "Roll it.\n7");
+    }
+}
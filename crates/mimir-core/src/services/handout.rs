@@ -0,0 +1,378 @@
+//! Handout Service
+//!
+//! Business logic for a campaign's handout queue. Handouts (catalog images,
+//! uploaded images, or documents) are staged during prep and revealed to
+//! the player display one at a time. A revealed handout leaves the queue,
+//! and each reveal is recorded against the campaign's session in progress,
+//! so the DM can see what players were shown and when.
+
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    Handout, HandoutReveal, NewHandout, NewHandoutReveal, UpdateHandout,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Input for staging a handout. Exactly one source must be given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandoutInput {
+    /// Title shown with the handout
+    pub title: String,
+    /// Catalog image, relative to the catalog images directory
+    #[serde(default)]
+    pub image_path: Option<String>,
+    /// Uploaded campaign image
+    #[serde(default)]
+    pub asset_id: Option<String>,
+    /// Document shown as text
+    #[serde(default)]
+    pub document_id: Option<String>,
+}
+
+/// A reveal with the title of the handout shown.
+#[derive(Debug, Clone, Serialize)]
+pub struct RevealedHandout {
+    #[serde(flatten)]
+    pub reveal: HandoutReveal,
+    pub title: String,
+    /// Number of the session it was shown in
+    pub session_number: Option<i32>,
+}
+
+/// Service for the handout queue.
+pub struct HandoutService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> HandoutService<'a> {
+    /// Create a new handout service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a campaign's handouts in queue order, revealed ones included.
+    pub fn list(&mut self, campaign_id: &str) -> ServiceResult<Vec<Handout>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        dal::list_handouts(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Stage a handout at the end of a campaign's queue.
+    pub fn create(&mut self, campaign_id: &str, input: HandoutInput) -> ServiceResult<Handout> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let title = input.title.trim();
+        if title.is_empty() {
+            return Err(ServiceError::validation("Handout title is required"));
+        }
+        self.validate_source(campaign_id, &input)?;
+
+        let id = Uuid::new_v4().to_string();
+        let sort_order = dal::next_handout_sort_order(self.conn, campaign_id)?;
+        let mut handout = NewHandout::new(&id, campaign_id, title).with_sort_order(sort_order);
+        if let Some(image_path) = input.image_path.as_deref() {
+            handout = handout.with_image_path(image_path);
+        }
+        if let Some(asset_id) = input.asset_id.as_deref() {
+            handout = handout.with_asset(asset_id);
+        }
+        if let Some(document_id) = input.document_id.as_deref() {
+            handout = handout.with_document(document_id);
+        }
+        dal::insert_handout(self.conn, &handout)?;
+        dal::get_handout(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Delete a handout and the record of its reveals.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_handout(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("Handout", id));
+        }
+        Ok(())
+    }
+
+    /// Reorder a campaign's handouts.
+    ///
+    /// `handout_ids` lists the campaign's handouts in their new order.
+    pub fn reorder(
+        &mut self,
+        campaign_id: &str,
+        handout_ids: &[String],
+    ) -> ServiceResult<Vec<Handout>> {
+        self.conn.transaction(|conn| {
+            let existing = dal::list_handouts(conn, campaign_id)?;
+            let now = now_rfc3339();
+
+            for (index, handout_id) in handout_ids.iter().enumerate() {
+                if !existing.iter().any(|h| &h.id == handout_id) {
+                    return Err(ServiceError::validation(format!(
+                        "Handout {} is not in this campaign",
+                        handout_id
+                    )));
+                }
+                let update = UpdateHandout {
+                    sort_order: Some(index as i32 + 1),
+                    updated_at: Some(&now),
+                    ..Default::default()
+                };
+                dal::update_handout(conn, handout_id, &update)?;
+            }
+            Ok(())
+        })?;
+
+        self.list(campaign_id)
+    }
+
+    /// Put a revealed handout back in the queue. Its reveals stay recorded.
+    pub fn requeue(&mut self, id: &str) -> ServiceResult<Handout> {
+        let now = now_rfc3339();
+        let update = UpdateHandout {
+            revealed_at: Some(None),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        if dal::update_handout(self.conn, id, &update)? == 0 {
+            return Err(ServiceError::not_found("Handout", id));
+        }
+        dal::get_handout(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Get a handout by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<Handout> {
+        dal::get_handout_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Handout", id))
+    }
+
+    /// Get the next handout waiting in a campaign's queue, if any.
+    pub fn next_queued(&mut self, campaign_id: &str) -> ServiceResult<Option<Handout>> {
+        dal::next_queued_handout(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Record that players were shown a handout, taking it off the queue.
+    ///
+    /// The reveal is recorded against the campaign's session in progress, if
+    /// there is one.
+    pub fn record_reveal(&mut self, id: &str) -> ServiceResult<Handout> {
+        let handout = self.get(id)?;
+        let session = dal::get_active_game_session(self.conn, &handout.campaign_id)?;
+
+        self.conn.transaction(|conn| {
+            let now = now_rfc3339();
+            let reveal_id = Uuid::new_v4().to_string();
+            let mut reveal = NewHandoutReveal::new(&reveal_id, id, &now);
+            if let Some(session) = session.as_ref() {
+                reveal = reveal.with_session(&session.id);
+            }
+            dal::insert_handout_reveal(conn, &reveal)?;
+
+            let update = UpdateHandout {
+                revealed_at: Some(Some(&now)),
+                updated_at: Some(&now),
+                ..Default::default()
+            };
+            dal::update_handout(conn, id, &update)?;
+            Ok::<_, ServiceError>(())
+        })?;
+
+        self.get(id)
+    }
+
+    /// What players have been shown in a campaign, earliest first,
+    /// optionally only in one session.
+    pub fn history(
+        &mut self,
+        campaign_id: &str,
+        session_id: Option<&str>,
+    ) -> ServiceResult<Vec<RevealedHandout>> {
+        let handouts = self.list(campaign_id)?;
+        let sessions = dal::list_game_sessions(self.conn, campaign_id)?;
+        let reveals = dal::list_handout_reveals(self.conn, campaign_id, session_id)?;
+
+        Ok(reveals
+            .into_iter()
+            .map(|reveal| RevealedHandout {
+                title: handouts
+                    .iter()
+                    .find(|h| h.id == reveal.handout_id)
+                    .map(|h| h.title.clone())
+                    .unwrap_or_default(),
+                session_number: sessions
+                    .iter()
+                    .find(|s| Some(&s.id) == reveal.session_id.as_ref())
+                    .map(|s| s.session_number),
+                reveal,
+            })
+            .collect())
+    }
+
+    // ── Private helpers ────────────────────────────────────────────────────
+
+    /// Check that the input names exactly one source, and that it belongs to
+    /// the campaign.
+    fn validate_source(&mut self, campaign_id: &str, input: &HandoutInput) -> ServiceResult<()> {
+        let sources = [
+            input.image_path.is_some(),
+            input.asset_id.is_some(),
+            input.document_id.is_some(),
+        ];
+        if sources.iter().filter(|&&set| set).count() != 1 {
+            return Err(ServiceError::validation(
+                "A handout needs exactly one of an image path, an asset, or a document",
+            ));
+        }
+
+        if let Some(image_path) = input.image_path.as_deref() {
+            if image_path.trim().is_empty() {
+                return Err(ServiceError::validation("Image path is required"));
+            }
+        }
+        if let Some(asset_id) = input.asset_id.as_deref() {
+            let asset = dal::get_campaign_asset_optional(self.conn, asset_id)?
+                .ok_or_else(|| ServiceError::not_found("Asset", asset_id))?;
+            if !asset.is_image() {
+                return Err(ServiceError::validation(format!(
+                    "{} is not an image",
+                    asset.filename
+                )));
+            }
+            let asset_campaign = match asset.module_id.as_deref() {
+                Some(module_id) => Some(dal::get_module(self.conn, module_id)?.campaign_id),
+                None => asset.campaign_id.clone(),
+            };
+            if asset_campaign.as_deref() != Some(campaign_id) {
+                return Err(ServiceError::validation(format!(
+                    "{} belongs to another campaign",
+                    asset.filename
+                )));
+            }
+        }
+        if let Some(document_id) = input.document_id.as_deref() {
+            let document = dal::get_document_optional(self.conn, document_id)?
+                .filter(|d| !d.is_trashed())
+                .ok_or_else(|| ServiceError::not_found("Document", document_id))?;
+            if document.campaign_id != campaign_id {
+                return Err(ServiceError::validation(format!(
+                    "{} belongs to another campaign",
+                    document.title
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_document, insert_game_session};
+    use crate::models::campaign::{NewCampaign, NewDocument, NewGameSession};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_campaign(conn, &NewCampaign::new("camp-2", "Other Campaign")).unwrap();
+        let letter = NewDocument::for_campaign("doc-1", "camp-1", "Letter", "handout")
+            .with_content("My dearest Elara,\n\nThe vault opens at midnight.");
+        insert_document(conn, &letter).unwrap();
+        let other = NewDocument::for_campaign("doc-2", "camp-2", "Other", "handout");
+        insert_document(conn, &other).unwrap();
+    }
+
+    fn image(title: &str) -> HandoutInput {
+        HandoutInput {
+            title: title.to_string(),
+            image_path: Some(format!("book/DMG/{}.webp", title)),
+            asset_id: None,
+            document_id: None,
+        }
+    }
+
+    #[test]
+    fn test_create_validates_source() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = HandoutService::new(&mut conn);
+
+        let mut both = image("Map");
+        both.document_id = Some("doc-1".into());
+        assert!(service.create("camp-1", both).is_err());
+
+        let mut neither = image("Map");
+        neither.image_path = None;
+        assert!(service.create("camp-1", neither).is_err());
+
+        let mut other = image("Other");
+        other.image_path = None;
+        other.document_id = Some("doc-2".into());
+        assert!(service.create("camp-1", other).is_err());
+
+        assert!(service.create("camp-1", image("  ")).is_err());
+
+        let mut letter = image("Letter");
+        letter.image_path = None;
+        letter.document_id = Some("doc-1".into());
+        let letter = service.create("camp-1", letter).unwrap();
+        assert!(letter.is_queued());
+        assert_eq!(letter.sort_order, 1);
+    }
+
+    #[test]
+    fn test_reveal_in_queue_order() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = HandoutService::new(&mut conn);
+
+        let map = service.create("camp-1", image("Map")).unwrap();
+        let seal = service.create("camp-1", image("Seal")).unwrap();
+        service
+            .reorder("camp-1", &[seal.id.clone(), map.id.clone()])
+            .unwrap();
+
+        let next = service.next_queued("camp-1").unwrap().unwrap();
+        assert_eq!(next.id, seal.id);
+        assert!(!service.record_reveal(&seal.id).unwrap().is_queued());
+        assert_eq!(service.next_queued("camp-1").unwrap().unwrap().id, map.id);
+
+        service.record_reveal(&map.id).unwrap();
+        assert!(service.next_queued("camp-1").unwrap().is_none());
+
+        // Requeued handouts come back in their place
+        service.requeue(&seal.id).unwrap();
+        assert_eq!(service.next_queued("camp-1").unwrap().unwrap().id, seal.id);
+        assert!(service.reorder("camp-2", &[map.id.clone()]).is_err());
+    }
+
+    #[test]
+    fn test_history_by_session() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let map = HandoutService::new(&mut conn)
+            .create("camp-1", image("Map"))
+            .unwrap();
+        // Shown before any session started
+        HandoutService::new(&mut conn)
+            .record_reveal(&map.id)
+            .unwrap();
+
+        insert_game_session(&mut conn, &NewGameSession::new("session-1", "camp-1", 1)).unwrap();
+        let mut service = HandoutService::new(&mut conn);
+        service.record_reveal(&map.id).unwrap();
+
+        let history = service.history("camp-1", None).unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(history[0].reveal.session_id.is_none());
+        assert_eq!(history[0].title, "Map");
+
+        let session = service.history("camp-1", Some("session-1")).unwrap();
+        assert_eq!(session.len(), 1);
+        assert_eq!(session[0].reveal.session_id.as_deref(), Some("session-1"));
+        assert_eq!(session[0].session_number, Some(1));
+    }
+}
@@ -0,0 +1,427 @@
+//! Campaign History Service
+//!
+//! Optional git-backed history for a campaign. Once enabled, every save
+//! re-exports the campaign as markdown into a git repository under the app
+//! data directory and commits the result, giving the DM a browsable history
+//! and a way to bring back an older version of a document without setting
+//! up git themselves.
+
+use chrono::DateTime;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use crate::dal::campaign as dal;
+use crate::encryption;
+use crate::models::campaign::Document;
+use crate::services::markdown_export::parse_document_manifest;
+use crate::services::{
+    DocumentService, MarkdownExportService, MarkdownRenderer, ServiceError, ServiceResult,
+    UpdateDocumentInput, DOCUMENT_MANIFEST_FILE,
+};
+
+/// Directory under the app data directory holding one repository per campaign.
+pub const HISTORY_DIR: &str = "history";
+
+/// Author and committer recorded on history commits.
+const HISTORY_AUTHOR_NAME: &str = "Mimir";
+const HISTORY_AUTHOR_EMAIL: &str = "mimir@localhost";
+
+/// A commit in a campaign's history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryCommit {
+    /// Full commit hash
+    pub id: String,
+    /// Abbreviated commit hash for display
+    pub short_id: String,
+    /// Commit message
+    pub message: String,
+    /// ISO8601 commit time
+    pub timestamp: String,
+}
+
+/// Service for git-backed campaign history.
+pub struct CampaignHistoryService<'a> {
+    conn: &'a mut SqliteConnection,
+    history_dir: PathBuf,
+}
+
+impl<'a> CampaignHistoryService<'a> {
+    /// Create a new history service.
+    pub fn new(conn: &'a mut SqliteConnection, app_data_dir: impl AsRef<Path>) -> Self {
+        Self {
+            conn,
+            history_dir: app_data_dir.as_ref().join(HISTORY_DIR),
+        }
+    }
+
+    /// Whether history is enabled for a campaign.
+    pub fn is_enabled(&self, campaign_id: &str) -> bool {
+        self.repo_dir(campaign_id).join(".git").is_dir()
+    }
+
+    /// Turn on history for a campaign and record the current state as the
+    /// first commit. Does nothing if history is already enabled.
+    ///
+    /// Refused while the database is encrypted, since the repository keeps
+    /// the campaign in plain text.
+    pub fn enable(&mut self, campaign_id: &str) -> ServiceResult<()> {
+        if self.is_enabled(campaign_id) {
+            return Ok(());
+        }
+        encryption::ensure_unencrypted(self.conn, "Campaign history")?;
+        dal::get_campaign_optional(self.conn, campaign_id)?
+            .ok_or_else(|| ServiceError::not_found("Campaign", campaign_id))?;
+
+        let dir = self.repo_dir(campaign_id);
+        fs::create_dir_all(&dir)?;
+        gix::init(&dir).map_err(git_error)?;
+        info!(campaign_id = %campaign_id, path = %dir.display(), "Enabled campaign history");
+
+        self.record(campaign_id, "Start campaign history")?;
+        Ok(())
+    }
+
+    /// Turn off history for a campaign, deleting its repository.
+    pub fn disable(&mut self, campaign_id: &str) -> ServiceResult<()> {
+        let dir = self.repo_dir(campaign_id);
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        Ok(())
+    }
+
+    /// Snapshot the campaign and commit it with `message`.
+    ///
+    /// Returns `None` when history is not enabled for the campaign, the
+    /// database has been encrypted since it was enabled, or nothing changed
+    /// since the last commit.
+    pub fn record(
+        &mut self,
+        campaign_id: &str,
+        message: &str,
+    ) -> ServiceResult<Option<HistoryCommit>> {
+        if !self.is_enabled(campaign_id) || encryption::connection_is_encrypted(self.conn)? {
+            return Ok(None);
+        }
+        let dir = self.repo_dir(campaign_id);
+
+        MarkdownExportService::new(self.conn).export_campaign_into(campaign_id, &dir)?;
+
+        let repo = gix::open(&dir).map_err(git_error)?;
+        let tree = write_tree(&repo, &dir)?;
+        let parent = repo.head_id().ok().map(|id| id.detach());
+        if let Some(parent) = parent {
+            let parent_tree = repo
+                .find_object(parent)
+                .map_err(git_error)?
+                .try_into_commit()
+                .map_err(git_error)?
+                .tree_id()
+                .map_err(git_error)?
+                .detach();
+            if parent_tree == tree {
+                return Ok(None);
+            }
+        }
+
+        let signature = gix::actor::Signature {
+            name: HISTORY_AUTHOR_NAME.into(),
+            email: HISTORY_AUTHOR_EMAIL.into(),
+            time: gix::date::Time::now_local_or_utc(),
+        };
+        let id = repo
+            .commit_as(
+                signature.to_ref(),
+                signature.to_ref(),
+                "HEAD",
+                message,
+                tree,
+                parent,
+            )
+            .map_err(git_error)?;
+
+        Ok(Some(HistoryCommit {
+            id: id.to_string(),
+            short_id: id.to_hex_with_len(7).to_string(),
+            message: message.to_string(),
+            timestamp: format_time(signature.time.seconds),
+        }))
+    }
+
+    /// Record a save that has already succeeded, if history is enabled.
+    ///
+    /// Failures are logged rather than returned, so a history problem never
+    /// fails the save. The app and the MCP server both call this after writes.
+    pub fn record_after_save(&mut self, campaign_id: &str, message: &str) {
+        if let Err(e) = self.record(campaign_id, message) {
+            warn!(campaign_id = %campaign_id, error = %e, "Failed to record campaign history");
+        }
+    }
+
+    /// List a campaign's commits, newest first.
+    pub fn history(&self, campaign_id: &str, limit: usize) -> ServiceResult<Vec<HistoryCommit>> {
+        if !self.is_enabled(campaign_id) {
+            return Ok(Vec::new());
+        }
+        let repo = gix::open(self.repo_dir(campaign_id)).map_err(git_error)?;
+        let Ok(head) = repo.head_id() else {
+            return Ok(Vec::new());
+        };
+
+        let mut commits = Vec::new();
+        for info in head.ancestors().all().map_err(git_error)?.take(limit) {
+            let info = info.map_err(git_error)?;
+            let commit = info.object().map_err(git_error)?;
+            let time = commit.time().map_err(git_error)?;
+            commits.push(HistoryCommit {
+                id: info.id.to_string(),
+                short_id: info.id.to_hex_with_len(7).to_string(),
+                message: commit.message_raw_sloppy().to_string().trim().to_string(),
+                timestamp: format_time(time.seconds),
+            });
+        }
+        Ok(commits)
+    }
+
+    /// Read a file as it was at `commit`.
+    ///
+    /// `commit` may be a full or abbreviated hash; `path` is relative to the
+    /// campaign folder, as listed in its `index.md`.
+    pub fn read_file(&self, campaign_id: &str, commit: &str, path: &str) -> ServiceResult<String> {
+        if !self.is_enabled(campaign_id) {
+            return Err(ServiceError::validation(
+                "History is not enabled for this campaign",
+            ));
+        }
+        let repo = gix::open(self.repo_dir(campaign_id)).map_err(git_error)?;
+        let tree = repo
+            .rev_parse_single(commit)
+            .map_err(|_| ServiceError::not_found("Commit", commit))?
+            .object()
+            .map_err(git_error)?
+            .peel_to_tree()
+            .map_err(git_error)?;
+        let entry = tree
+            .lookup_entry_by_path(path)
+            .map_err(git_error)?
+            .ok_or_else(|| ServiceError::not_found("File", path))?;
+        let data = entry.object().map_err(git_error)?.data.clone();
+
+        String::from_utf8(data)
+            .map_err(|_| ServiceError::validation(format!("'{}' is not a text file", path)))
+    }
+
+    /// Restore a document to its content at `commit`.
+    ///
+    /// Only document files can be restored; character sheets, NPC notes, and
+    /// session logs are read-only snapshots. The restore itself is recorded as
+    /// a new commit.
+    pub fn restore_file(
+        &mut self,
+        campaign_id: &str,
+        commit: &str,
+        path: &str,
+    ) -> ServiceResult<Document> {
+        let manifest = self.read_file(campaign_id, commit, DOCUMENT_MANIFEST_FILE)?;
+        let documents = parse_document_manifest(&manifest)?;
+        let entry = documents.iter().find(|d| d.path == path).ok_or_else(|| {
+            ServiceError::validation(format!(
+                "'{}' is not a document and cannot be restored",
+                path
+            ))
+        })?;
+
+        let document = dal::get_document_optional(self.conn, &entry.document_id)?
+            .ok_or_else(|| ServiceError::not_found("Document", &entry.document_id))?;
+        if document.is_trashed() {
+            return Err(ServiceError::validation(format!(
+                "'{}' is in the trash; restore it from the trash first",
+                document.title
+            )));
+        }
+
+        let rendered = self.read_file(campaign_id, commit, path)?;
+        let content = MarkdownRenderer::document_content(&rendered, entry.title_added);
+        let restored = DocumentService::new(self.conn).update(
+            &document.id,
+            UpdateDocumentInput {
+                content: Some(content),
+                ..Default::default()
+            },
+        )?;
+
+        let short_id: String = commit.chars().take(7).collect();
+        self.record(
+            campaign_id,
+            &format!("Restore document '{}' from {}", restored.title, short_id),
+        )?;
+        Ok(restored)
+    }
+
+    fn repo_dir(&self, campaign_id: &str) -> PathBuf {
+        self.history_dir.join(campaign_id)
+    }
+}
+
+/// Write the files under `dir` (excluding `.git`) as a git tree.
+fn write_tree(repo: &gix::Repository, dir: &Path) -> ServiceResult<gix::ObjectId> {
+    let entries = tree_entries(repo, dir)?;
+    let id = repo
+        .write_object(&gix::objs::Tree { entries })
+        .map_err(git_error)?;
+    Ok(id.detach())
+}
+
+fn tree_entries(repo: &gix::Repository, dir: &Path) -> ServiceResult<Vec<gix::objs::tree::Entry>> {
+    use gix::objs::tree::{Entry, EntryKind};
+
+    let mut entries = Vec::new();
+    for item in fs::read_dir(dir)? {
+        let item = item?;
+        let name = item.file_name().to_string_lossy().into_owned();
+        if name == ".git" {
+            continue;
+        }
+
+        let file_type = item.file_type()?;
+        if file_type.is_dir() {
+            let children = tree_entries(repo, &item.path())?;
+            if children.is_empty() {
+                continue;
+            }
+            let oid = repo
+                .write_object(&gix::objs::Tree { entries: children })
+                .map_err(git_error)?
+                .detach();
+            entries.push(Entry {
+                mode: EntryKind::Tree.into(),
+                filename: name.into(),
+                oid,
+            });
+        } else if file_type.is_file() {
+            let oid = repo
+                .write_blob(fs::read(item.path())?)
+                .map_err(git_error)?
+                .detach();
+            entries.push(Entry {
+                mode: EntryKind::Blob.into(),
+                filename: name.into(),
+                oid,
+            });
+        }
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+fn format_time(seconds: i64) -> String {
+    DateTime::from_timestamp(seconds, 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default()
+}
+
+fn git_error(e: impl std::fmt::Display) -> ServiceError {
+    ServiceError::Io(std::io::Error::other(format!("Git error: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_document};
+    use crate::models::campaign::{NewCampaign, NewDocument};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Curse of Strahd")).unwrap();
+        insert_document(
+            conn,
+            &NewDocument::for_campaign("doc-1", "camp-1", "House Rules", "note")
+                .with_content("No flanking."),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_record_is_noop_until_enabled() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let app_dir = TempDir::new().unwrap();
+        let mut service = CampaignHistoryService::new(&mut conn, app_dir.path());
+
+        assert!(!service.is_enabled("camp-1"));
+        assert!(service.record("camp-1", "Update").unwrap().is_none());
+        assert!(service.history("camp-1", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_history_records_only_changes() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let app_dir = TempDir::new().unwrap();
+
+        CampaignHistoryService::new(&mut conn, app_dir.path())
+            .enable("camp-1")
+            .unwrap();
+        assert!(CampaignHistoryService::new(&mut conn, app_dir.path())
+            .record("camp-1", "Nothing changed")
+            .unwrap()
+            .is_none());
+
+        DocumentService::new(&mut conn)
+            .update(
+                "doc-1",
+                UpdateDocumentInput::set_content("Flanking gives advantage."),
+            )
+            .unwrap();
+        let commit = CampaignHistoryService::new(&mut conn, app_dir.path())
+            .record("camp-1", "Update document 'House Rules'")
+            .unwrap()
+            .expect("Expected a commit");
+
+        let history = CampaignHistoryService::new(&mut conn, app_dir.path())
+            .history("camp-1", 10)
+            .unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].id, commit.id);
+        assert_eq!(history[0].message, "Update document 'House Rules'");
+        assert_eq!(history[1].message, "Start campaign history");
+    }
+
+    #[test]
+    fn test_restore_document_from_commit() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let app_dir = TempDir::new().unwrap();
+
+        CampaignHistoryService::new(&mut conn, app_dir.path())
+            .enable("camp-1")
+            .unwrap();
+        let first = CampaignHistoryService::new(&mut conn, app_dir.path())
+            .history("camp-1", 1)
+            .unwrap()
+            .remove(0);
+
+        DocumentService::new(&mut conn)
+            .update(
+                "doc-1",
+                UpdateDocumentInput::set_content("Flanking gives advantage."),
+            )
+            .unwrap();
+
+        let mut service = CampaignHistoryService::new(&mut conn, app_dir.path());
+        let restored = service
+            .restore_file("camp-1", &first.short_id, "documents/house-rules.md")
+            .unwrap();
+        assert_eq!(restored.content, "No flanking.");
+        assert_eq!(service.history("camp-1", 10).unwrap().len(), 2);
+
+        let err = service
+            .restore_file("camp-1", &first.id, "index.md")
+            .unwrap_err();
+        assert!(matches!(err, ServiceError::Validation(_)));
+    }
+}
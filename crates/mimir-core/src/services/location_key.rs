@@ -0,0 +1,289 @@
+//! Location Key Service
+//!
+//! Generates a map's location key: a document with one numbered section per
+//! point of interest, in reading order, with each POI linked back to its
+//! section. This is the classic keyed dungeon map with numbered descriptions.
+
+use std::collections::{HashMap, HashSet};
+
+use diesel::SqliteConnection;
+use serde::Serialize;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{Document, MapPoi, UpdateMapPoi};
+use crate::services::{
+    CreateDocumentInput, DocumentService, ServiceError, ServiceResult, UpdateDocumentInput,
+};
+use crate::utils::now_rfc3339;
+
+/// Document type of a generated location key.
+pub const LOCATION_KEY_DOC_TYPE: &str = "location_key";
+
+/// Body of a location with no description yet.
+const PLACEHOLDER_BODY: &str = "*No description yet.*";
+
+/// A generated location key and the POIs it describes.
+#[derive(Debug, Clone, Serialize)]
+pub struct LocationKey {
+    /// The key document
+    pub document: Document,
+    /// POIs in key order, each linked to its section
+    pub pois: Vec<MapPoi>,
+}
+
+/// Sort POIs in reading order: top to bottom, then left to right.
+pub fn reading_order(pois: &mut [MapPoi]) {
+    pois.sort_by(|a, b| (a.grid_y, a.grid_x, &a.name).cmp(&(b.grid_y, b.grid_x, &b.name)));
+}
+
+/// Section heading for the location numbered `number`, e.g. "3. Guard Room".
+pub fn location_heading(number: usize, name: &str) -> String {
+    format!("{}. {}", number, name.trim())
+}
+
+/// Service for generating map location keys.
+pub struct LocationKeyService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> LocationKeyService<'a> {
+    /// Create a new location key service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Write the location key for a map and link each POI to its section.
+    ///
+    /// POIs are numbered in reading order. Each section holds the POI's
+    /// description. A POI without one keeps the text of the section it was
+    /// linked to in the previous key, so text written in the key document
+    /// survives regeneration. The previous key document is updated in place,
+    /// otherwise a new one is created next to the map.
+    pub fn generate(&mut self, map_id: &str) -> ServiceResult<LocationKey> {
+        let map = dal::get_map_optional(self.conn, map_id)?
+            .ok_or_else(|| ServiceError::not_found("Map", map_id))?;
+        let mut pois = dal::list_map_pois(self.conn, map_id)?;
+        if pois.is_empty() {
+            return Err(ServiceError::validation(format!(
+                "{} has no points of interest to key",
+                map.name
+            )));
+        }
+        reading_order(&mut pois);
+
+        let mut documents = DocumentService::new(self.conn);
+        let mut existing = None;
+        let mut checked = HashSet::new();
+        for id in pois.iter().filter_map(|p| p.document_id.as_deref()) {
+            if !checked.insert(id) {
+                continue;
+            }
+            if let Some(doc) = documents.get(id)? {
+                if doc.doc_type == LOCATION_KEY_DOC_TYPE && !doc.is_trashed() {
+                    existing = Some(doc);
+                    break;
+                }
+            }
+        }
+
+        let previous = existing
+            .as_ref()
+            .map(|doc| section_bodies(&doc.content))
+            .unwrap_or_default();
+        let headings: Vec<String> = pois
+            .iter()
+            .enumerate()
+            .map(|(i, poi)| location_heading(i + 1, &poi.name))
+            .collect();
+
+        let mut content = format!(
+            "Numbered locations on **{}**, top to bottom and left to right.\n\n",
+            map.name
+        );
+        for (poi, heading) in pois.iter().zip(&headings) {
+            let description = poi
+                .description
+                .as_deref()
+                .map(str::trim)
+                .filter(|d| !d.is_empty());
+            let kept = match (&existing, poi.document_link()) {
+                (Some(doc), Some((id, Some(section)))) if id == doc.id => {
+                    previous.get(section).map(String::as_str)
+                }
+                _ => None,
+            };
+            let body = description.or(kept).unwrap_or(PLACEHOLDER_BODY);
+            content.push_str(&format!("## {}\n\n{}\n\n", heading, body));
+        }
+
+        let title = format!("{} Key", map.name);
+        let document = match existing {
+            Some(doc) => documents.update(
+                &doc.id,
+                UpdateDocumentInput::set_title_and_content(title, content),
+            )?,
+            None => {
+                let input = match map.module_id {
+                    Some(ref module_id) => {
+                        CreateDocumentInput::for_module(&map.campaign_id, module_id, title)
+                    }
+                    None => CreateDocumentInput::for_campaign(&map.campaign_id, title),
+                };
+                documents.create(input.with_type(LOCATION_KEY_DOC_TYPE).with_content(content))?
+            }
+        };
+
+        let now = now_rfc3339();
+        let mut linked = Vec::with_capacity(pois.len());
+        for (poi, heading) in pois.iter().zip(&headings) {
+            let update = UpdateMapPoi::set_document_link(
+                Some(document.id.as_str()),
+                Some(heading.as_str()),
+                &now,
+            );
+            dal::update_map_poi(self.conn, &poi.id, &update)?;
+            linked.push(dal::get_map_poi(self.conn, &poi.id)?);
+        }
+
+        Ok(LocationKey {
+            document,
+            pois: linked,
+        })
+    }
+}
+
+/// Body text under each level-2 heading of a markdown document.
+fn section_bodies(content: &str) -> HashMap<String, String> {
+    let mut sections = HashMap::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for line in content.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            if let Some((heading, body)) = current.take() {
+                sections.insert(heading, body.join("\n").trim().to_string());
+            }
+            current = Some((heading.trim().to_string(), Vec::new()));
+        } else if let Some((_, ref mut body)) = current {
+            body.push(line);
+        }
+    }
+    if let Some((heading, body)) = current {
+        sections.insert(heading, body.join("\n").trim().to_string());
+    }
+
+    sections.retain(|_, body| !body.is_empty());
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_campaign_asset, insert_map, insert_map_poi,
+    };
+    use crate::models::campaign::{NewCampaign, NewCampaignAsset, NewMap, NewMapPoi};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        let asset = NewCampaignAsset::for_campaign(
+            "asset-1",
+            "camp-1",
+            "crypt.uvtt",
+            "application/octet-stream",
+            "/blobs/crypt.uvtt",
+        );
+        insert_campaign_asset(conn, &asset).unwrap();
+        insert_map(
+            conn,
+            &NewMap::for_campaign("map-1", "camp-1", "Crypt Level 1", "asset-1"),
+        )
+        .unwrap();
+
+        for (id, name, grid_x, grid_y) in [
+            ("poi-altar", "Altar", 6, 8),
+            ("poi-gate", "Gate", 9, 1),
+            ("poi-guard", "Guard Room", 2, 1),
+        ] {
+            insert_map_poi(conn, &NewMapPoi::new(id, "map-1", name, grid_x, grid_y)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_generate_numbers_in_reading_order() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let key = LocationKeyService::new(&mut conn)
+            .generate("map-1")
+            .unwrap();
+        assert_eq!(key.document.title, "Crypt Level 1 Key");
+        assert_eq!(key.document.doc_type, LOCATION_KEY_DOC_TYPE);
+
+        let content = &key.document.content;
+        let guard = content.find("## 1. Guard Room").unwrap();
+        let gate = content.find("## 2. Gate").unwrap();
+        let altar = content.find("## 3. Altar").unwrap();
+        assert!(guard < gate && gate < altar);
+
+        let links: Vec<_> = key.pois.iter().map(|p| p.document_link()).collect();
+        assert_eq!(
+            links[0],
+            Some((key.document.id.as_str(), Some("1. Guard Room")))
+        );
+        assert_eq!(links[2], Some((key.document.id.as_str(), Some("3. Altar"))));
+    }
+
+    #[test]
+    fn test_regenerate_updates_key_and_keeps_text() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let first = LocationKeyService::new(&mut conn)
+            .generate("map-1")
+            .unwrap();
+        let edited = first.document.content.replace(
+            "## 3. Altar\n\n*No description yet.*",
+            "## 3. Altar\n\nA cracked basalt altar.",
+        );
+        DocumentService::new(&mut conn)
+            .update(&first.document.id, UpdateDocumentInput::set_content(edited))
+            .unwrap();
+        let poi = NewMapPoi::new("poi-well", "map-1", "Well", 0, 0).with_description("Dry.");
+        insert_map_poi(&mut conn, &poi).unwrap();
+
+        let second = LocationKeyService::new(&mut conn)
+            .generate("map-1")
+            .unwrap();
+        assert_eq!(second.document.id, first.document.id);
+        assert!(second.document.content.contains("## 1. Well\n\nDry."));
+        assert!(second
+            .document
+            .content
+            .contains("## 4. Altar\n\nA cracked basalt altar."));
+        assert_eq!(second.pois.len(), 4);
+    }
+
+    #[test]
+    fn test_generate_requires_pois() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        dal::delete_all_map_pois(&mut conn, "map-1").unwrap();
+
+        let err = LocationKeyService::new(&mut conn).generate("map-1");
+        assert!(matches!(err, Err(ServiceError::Validation(_))));
+        assert!(LocationKeyService::new(&mut conn)
+            .generate("missing")
+            .is_err());
+    }
+
+    #[test]
+    fn test_section_bodies() {
+        let sections = section_bodies("Intro\n\n## 1. Gate\n\nIron.\n\nRusted.\n\n## 2. Hall\n\n");
+        assert_eq!(
+            sections.get("1. Gate").map(String::as_str),
+            Some("Iron.\n\nRusted.")
+        );
+        assert!(!sections.contains_key("2. Hall"));
+    }
+}
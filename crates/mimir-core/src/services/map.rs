@@ -184,6 +184,17 @@ fn blob_path_to_meta(blob_path: &str) -> String {
     }
 }
 
+/// All files stored for a map's UVTT asset, relative to the app data directory:
+/// the UVTT file, the extracted image, the legacy PNG, and the metadata sidecar.
+pub(crate) fn map_asset_files(blob_path: &str) -> [String; 4] {
+    [
+        blob_path.to_string(),
+        blob_path_to_extracted(blob_path),
+        blob_path_to_png(blob_path),
+        blob_path_to_meta(blob_path),
+    ]
+}
+
 /// Cached resolution metadata written alongside the extracted image.
 /// Avoids re-parsing the full UVTT JSON (which contains the huge base64 image)
 /// on every command that needs grid dimensions.
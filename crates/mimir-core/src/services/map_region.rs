@@ -0,0 +1,425 @@
+//! Map Region Service
+//!
+//! Named encounter regions on maps. Documents link to a region with
+//! `{{map-region: Crypt Level 1 | area-3}}`; resolving the reference finds
+//! the map by name within the campaign, the region by name within the map,
+//! and the tokens standing inside it so the DM map can frame the area and
+//! filter to its occupants.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{MapRegion, NewMapRegion, UpdateMapRegion};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Shape of a region, in grid units.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RegionShape {
+    /// Axis-aligned rectangle
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// Polygon with at least three vertices
+    Polygon { points: Vec<[f64; 2]> },
+}
+
+impl RegionShape {
+    /// Bounding box as (x, y, width, height), and the polygon points as JSON.
+    fn bounds(&self) -> ServiceResult<(f64, f64, f64, f64, Option<String>)> {
+        match self {
+            RegionShape::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                if !(*width > 0.0 && *height > 0.0) {
+                    return Err(ServiceError::validation(
+                        "Region width and height must be positive",
+                    ));
+                }
+                Ok((*x, *y, *width, *height, None))
+            }
+            RegionShape::Polygon { points } => {
+                if points.len() < 3 {
+                    return Err(ServiceError::validation(
+                        "A polygon region needs at least three points",
+                    ));
+                }
+                let min_x = points.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min);
+                let min_y = points.iter().map(|p| p[1]).fold(f64::INFINITY, f64::min);
+                let max_x = points
+                    .iter()
+                    .map(|p| p[0])
+                    .fold(f64::NEG_INFINITY, f64::max);
+                let max_y = points
+                    .iter()
+                    .map(|p| p[1])
+                    .fold(f64::NEG_INFINITY, f64::max);
+                if !(max_x > min_x && max_y > min_y) {
+                    return Err(ServiceError::validation("Polygon region has no area"));
+                }
+                let json = serde_json::to_string(points).map_err(|e| {
+                    ServiceError::validation(format!("Invalid polygon points: {}", e))
+                })?;
+                Ok((min_x, min_y, max_x - min_x, max_y - min_y, Some(json)))
+            }
+        }
+    }
+}
+
+/// A `{{map-region: ...}}` reference resolved to a map, region, and tokens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedMapRegion {
+    pub map_id: String,
+    pub map_name: String,
+    /// Module the map belongs to, if it is a module map
+    pub module_id: Option<String>,
+    pub region: MapRegion,
+    /// Tokens whose cell center lies inside the region
+    pub token_ids: Vec<String>,
+    /// Traps whose cell center lies inside the region
+    pub trap_ids: Vec<String>,
+}
+
+/// Service for map regions.
+pub struct MapRegionService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> MapRegionService<'a> {
+    /// Create a new map region service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Create a named region on a map.
+    pub fn create(
+        &mut self,
+        map_id: &str,
+        name: &str,
+        shape: &RegionShape,
+    ) -> ServiceResult<MapRegion> {
+        let name = validate_name(name)?;
+        if dal::get_map_optional(self.conn, map_id)?.is_none() {
+            return Err(ServiceError::not_found("Map", map_id));
+        }
+        if dal::get_map_region_by_name(self.conn, map_id, name)?.is_some() {
+            return Err(ServiceError::validation(format!(
+                "A region named '{}' already exists on this map",
+                name
+            )));
+        }
+
+        let (x, y, width, height, points) = shape.bounds()?;
+        let id = Uuid::new_v4().to_string();
+        let mut region = NewMapRegion::rect(&id, map_id, name, x, y, width, height);
+        if let Some(points) = points.as_deref() {
+            region = region.with_points(points);
+        }
+        dal::insert_map_region(self.conn, &region)?;
+        dal::get_map_region(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// List a map's regions alphabetically.
+    pub fn list_for_map(&mut self, map_id: &str) -> ServiceResult<Vec<MapRegion>> {
+        dal::list_map_regions(self.conn, map_id).map_err(ServiceError::from)
+    }
+
+    /// Rename a region and/or replace its shape.
+    pub fn update(
+        &mut self,
+        id: &str,
+        name: Option<&str>,
+        shape: Option<&RegionShape>,
+    ) -> ServiceResult<MapRegion> {
+        let region = dal::get_map_region_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Map region", id))?;
+
+        let name = match name {
+            Some(name) => {
+                let name = validate_name(name)?;
+                if let Some(existing) =
+                    dal::get_map_region_by_name(self.conn, &region.map_id, name)?
+                {
+                    if existing.id != region.id {
+                        return Err(ServiceError::validation(format!(
+                            "A region named '{}' already exists on this map",
+                            name
+                        )));
+                    }
+                }
+                Some(name)
+            }
+            None => None,
+        };
+
+        let now = now_rfc3339();
+        let bounds = shape.map(RegionShape::bounds).transpose()?;
+        let mut update = match &bounds {
+            Some((x, y, width, height, points)) => {
+                UpdateMapRegion::set_bounds(*x, *y, *width, *height, points.as_deref(), &now)
+            }
+            None => UpdateMapRegion {
+                updated_at: Some(&now),
+                ..Default::default()
+            },
+        };
+        update.name = name;
+        dal::update_map_region(self.conn, id, &update)?;
+        dal::get_map_region(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Delete a region.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_map_region(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("Map region", id));
+        }
+        Ok(())
+    }
+
+    /// Resolve a document reference by map and region name (case-insensitive).
+    ///
+    /// When a campaign has several maps with the same name, the first in map
+    /// order wins.
+    pub fn resolve(
+        &mut self,
+        campaign_id: &str,
+        map_name: &str,
+        region_name: &str,
+    ) -> ServiceResult<ResolvedMapRegion> {
+        let map_name = map_name.trim();
+        let region_name = region_name.trim();
+
+        let map = dal::list_campaign_maps(self.conn, campaign_id)?
+            .into_iter()
+            .find(|m| m.name.eq_ignore_ascii_case(map_name))
+            .ok_or_else(|| ServiceError::not_found("Map", map_name))?;
+        let region =
+            dal::get_map_region_by_name(self.conn, &map.id, region_name)?.ok_or_else(|| {
+                ServiceError::not_found("Map region", format!("{} | {}", map.name, region_name))
+            })?;
+
+        let token_ids = dal::list_token_placements(self.conn, &map.id)?
+            .into_iter()
+            .filter(|t| region.contains(t.grid_x as f64 + 0.5, t.grid_y as f64 + 0.5))
+            .map(|t| t.id)
+            .collect();
+        let trap_ids = dal::list_map_traps(self.conn, &map.id)?
+            .into_iter()
+            .filter(|t| region.contains(t.grid_x as f64 + 0.5, t.grid_y as f64 + 0.5))
+            .map(|t| t.id)
+            .collect();
+
+        Ok(ResolvedMapRegion {
+            map_id: map.id,
+            map_name: map.name,
+            module_id: map.module_id,
+            region,
+            token_ids,
+            trap_ids,
+        })
+    }
+}
+
+fn validate_name(name: &str) -> ServiceResult<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::validation("Region name cannot be empty"));
+    }
+    // `|` and braces would break the {{map-region: Map | region}} syntax
+    if name.contains(['|', '{', '}']) {
+        return Err(ServiceError::validation(
+            "Region name cannot contain '|', '{' or '}'",
+        ));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_campaign_asset, insert_map, insert_map_trap, insert_token_placement,
+    };
+    use crate::models::campaign::{
+        NewCampaign, NewCampaignAsset, NewMap, NewMapTrap, NewTokenPlacement,
+    };
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        let asset = NewCampaignAsset::for_campaign(
+            "asset-1",
+            "camp-1",
+            "crypt.uvtt",
+            "application/octet-stream",
+            "/blobs/crypt.uvtt",
+        );
+        insert_campaign_asset(conn, &asset).unwrap();
+        insert_map(
+            conn,
+            &NewMap::for_campaign("map-1", "camp-1", "Crypt Level 1", "asset-1"),
+        )
+        .unwrap();
+    }
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> RegionShape {
+        RegionShape::Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_create_polygon_stores_bounding_box() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let shape = RegionShape::Polygon {
+            points: vec![[2.0, 1.0], [6.0, 1.0], [4.0, 5.0]],
+        };
+        let region = MapRegionService::new(&mut conn)
+            .create("map-1", " area-3 ", &shape)
+            .unwrap();
+        assert_eq!(region.name, "area-3");
+        assert_eq!((region.x, region.y), (2.0, 1.0));
+        assert_eq!((region.width, region.height), (4.0, 4.0));
+        assert_eq!(region.polygon().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_create_validates() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = MapRegionService::new(&mut conn);
+
+        let square = rect(0.0, 0.0, 1.0, 1.0);
+        assert!(service.create("map-1", "", &square).is_err());
+        assert!(service.create("map-1", "a|b", &square).is_err());
+        let flat = rect(0.0, 0.0, 0.0, 1.0);
+        assert!(service.create("map-1", "area-1", &flat).is_err());
+        let line = RegionShape::Polygon {
+            points: vec![[0.0, 0.0], [1.0, 1.0]],
+        };
+        assert!(service.create("map-1", "area-1", &line).is_err());
+        assert!(matches!(
+            service.create("missing", "area-1", &square),
+            Err(ServiceError::NotFound { .. })
+        ));
+
+        service.create("map-1", "Area-1", &square).unwrap();
+        assert!(service.create("map-1", "area-1", &square).is_err());
+    }
+
+    #[test]
+    fn test_update_shape() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = MapRegionService::new(&mut conn);
+
+        let region = service
+            .create(
+                "map-1",
+                "area-3",
+                &RegionShape::Polygon {
+                    points: vec![[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]],
+                },
+            )
+            .unwrap();
+        let updated = service
+            .update(
+                &region.id,
+                Some("Throne Room"),
+                Some(&rect(1.0, 1.0, 2.0, 3.0)),
+            )
+            .unwrap();
+        assert_eq!(updated.name, "Throne Room");
+        assert_eq!(updated.height, 3.0);
+        assert!(updated.points.is_none());
+    }
+
+    #[test]
+    fn test_resolve_finds_tokens_inside() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        for (id, grid_x, grid_y) in [("inside", 3, 3), ("outside", 9, 9)] {
+            let token = NewTokenPlacement {
+                id,
+                map_id: "map-1",
+                module_monster_id: None,
+                module_npc_id: None,
+                grid_x,
+                grid_y,
+                label: Some(id),
+                faction_color: None,
+                hidden: 0,
+                vision_bright_ft: None,
+                vision_dim_ft: None,
+                vision_dark_ft: 0,
+                light_radius_ft: 0,
+                character_id: None,
+                mob_size: None,
+            };
+            insert_token_placement(&mut conn, &token).unwrap();
+        }
+        MapRegionService::new(&mut conn)
+            .create("map-1", "area-3", &rect(2.0, 2.0, 4.0, 4.0))
+            .unwrap();
+
+        let resolved = MapRegionService::new(&mut conn)
+            .resolve("camp-1", "crypt level 1", "AREA-3")
+            .unwrap();
+        assert_eq!(resolved.map_id, "map-1");
+        assert_eq!(resolved.region.name, "area-3");
+        assert_eq!(resolved.token_ids, vec!["inside".to_string()]);
+        assert!(resolved.trap_ids.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_finds_traps_inside() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        for (id, grid_x, grid_y) in [("trap-in", 2, 5), ("trap-out", 0, 0)] {
+            insert_map_trap(
+                &mut conn,
+                &NewMapTrap::new(id, "map-1", "Pits", grid_x, grid_y),
+            )
+            .unwrap();
+        }
+        MapRegionService::new(&mut conn)
+            .create("map-1", "area-3", &rect(2.0, 2.0, 4.0, 4.0))
+            .unwrap();
+
+        let resolved = MapRegionService::new(&mut conn)
+            .resolve("camp-1", "Crypt Level 1", "area-3")
+            .unwrap();
+        assert_eq!(resolved.trap_ids, vec!["trap-in".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_missing_region() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = MapRegionService::new(&mut conn);
+
+        assert!(matches!(
+            service.resolve("camp-1", "Crypt Level 1", "area-9"),
+            Err(ServiceError::NotFound { .. })
+        ));
+        assert!(matches!(
+            service.resolve("camp-1", "Crypt Level 2", "area-3"),
+            Err(ServiceError::NotFound { .. })
+        ));
+    }
+}
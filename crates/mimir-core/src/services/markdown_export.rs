@@ -0,0 +1,971 @@
+//! Markdown Export Service
+//!
+//! Exports a campaign as a folder of plain markdown files - documents,
+//! character sheets, NPC notes, and session logs - with an index, so the
+//! campaign can be read outside the app or kept under version control.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::dal::campaign as dal;
+use crate::encryption;
+use crate::models::campaign::{
+    Campaign, CampaignCustomField, Character, Document, GameSession, Module, ModuleNpc,
+};
+use crate::services::{
+    resolve_custom_field_values, ArchiveService, CharacterWithRelated, ServiceError, ServiceResult,
+};
+
+/// Name of the index file written at the root of an export.
+pub const MARKDOWN_INDEX_FILE: &str = "index.md";
+
+/// Hidden file in an export mapping document files back to document IDs.
+pub const DOCUMENT_MANIFEST_FILE: &str = ".mimir-documents.json";
+
+/// Hidden file in an export listing every file the export wrote, so that
+/// exporting again only removes files Mimir created.
+pub const EXPORT_FILES_MANIFEST: &str = ".mimir-files.json";
+
+/// Result of a markdown export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownExportResult {
+    /// Root folder of the export
+    pub root: PathBuf,
+    /// Number of markdown files written, including the index
+    pub files_written: usize,
+    /// Number of markdown files a previous export wrote that were removed
+    pub files_removed: usize,
+    /// Where each document was written
+    pub documents: Vec<ExportedDocument>,
+}
+
+/// A document written by a markdown export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedDocument {
+    /// Path relative to the export root
+    pub path: String,
+    /// Document ID
+    pub document_id: String,
+    /// Whether a title heading was added in front of the document's content
+    pub title_added: bool,
+    /// Hash of the document content the file was last written or synced
+    /// with. Older manifests don't have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_hash: Option<String>,
+}
+
+impl ExportedDocument {
+    fn new(path: String, doc: &Document) -> Self {
+        Self {
+            path,
+            document_id: doc.id.clone(),
+            title_added: MarkdownRenderer::adds_document_title(doc),
+            base_hash: Some(content_hash(&doc.content)),
+        }
+    }
+}
+
+/// Hex SHA-256 of document content, used to tell whether an exported file
+/// or its document changed since the export.
+pub(crate) fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Read the document manifest of an export. A missing manifest is empty.
+pub(crate) fn read_document_manifest(root: &Path) -> ServiceResult<Vec<ExportedDocument>> {
+    let path = root.join(DOCUMENT_MANIFEST_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    parse_document_manifest(&fs::read_to_string(path)?)
+}
+
+/// Parse a document manifest.
+pub(crate) fn parse_document_manifest(json: &str) -> ServiceResult<Vec<ExportedDocument>> {
+    serde_json::from_str(json)
+        .map_err(|e| ServiceError::validation(format!("Invalid document manifest: {}", e)))
+}
+
+/// Write the document manifest of an export.
+pub(crate) fn write_document_manifest(
+    root: &Path,
+    documents: &[ExportedDocument],
+) -> ServiceResult<()> {
+    let json = serde_json::to_string_pretty(documents)
+        .map_err(|e| ServiceError::validation(format!("Failed to write manifest: {}", e)))?;
+    fs::write(root.join(DOCUMENT_MANIFEST_FILE), json)?;
+    Ok(())
+}
+
+/// Paths written by the previous export into `root`: the files manifest,
+/// plus any document paths (documents created from new files are only in the
+/// document manifest). A folder Mimir never exported into has none.
+fn previous_export_files(root: &Path) -> ServiceResult<HashSet<String>> {
+    let mut files: HashSet<String> = HashSet::new();
+    let path = root.join(EXPORT_FILES_MANIFEST);
+    if path.exists() {
+        let listed: Vec<String> = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| ServiceError::validation(format!("Invalid files manifest: {}", e)))?;
+        files.extend(listed);
+    }
+    files.extend(read_document_manifest(root)?.into_iter().map(|d| d.path));
+    Ok(files)
+}
+
+/// Folder of a module within an export, relative to the export root.
+pub(crate) fn module_dir(module: &Module) -> String {
+    format!(
+        "modules/{:02}-{}",
+        module.module_number,
+        ArchiveService::slugify(&module.name)
+    )
+}
+
+// =============================================================================
+// Markdown Renderer
+// =============================================================================
+
+/// Renders campaign entities as markdown.
+pub struct MarkdownRenderer;
+
+impl MarkdownRenderer {
+    /// Render a document. Content is kept as written; a title heading is
+    /// added when the document has neither frontmatter nor its own heading.
+    pub fn document(doc: &Document) -> String {
+        let content = doc.content.trim_end();
+        if !Self::adds_document_title(doc) {
+            format!("{}\n", content)
+        } else if content.trim().is_empty() {
+            format!("# {}\n", doc.title)
+        } else {
+            format!("# {}\n\n{}\n", doc.title, content)
+        }
+    }
+
+    /// Recover a document's content from its rendered markdown, dropping the
+    /// title heading `document` added in front of it.
+    pub fn document_content(rendered: &str, title_added: bool) -> String {
+        if !title_added {
+            return rendered.trim_end().to_string();
+        }
+        let body = rendered
+            .split_once('\n')
+            .map(|(_, rest)| rest)
+            .unwrap_or("");
+        body.strip_prefix('\n')
+            .unwrap_or(body)
+            .trim_end()
+            .to_string()
+    }
+
+    /// Whether `document` prepends a title heading to this document.
+    pub fn adds_document_title(doc: &Document) -> bool {
+        !(doc.content.starts_with("---") || doc.content.trim_start().starts_with("# "))
+    }
+
+    /// Render a character sheet, including the campaign's custom fields.
+    pub fn character(data: &CharacterWithRelated, custom_fields: &[CampaignCustomField]) -> String {
+        let c = &data.character;
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}\n", c.name);
+
+        let summary = Self::character_summary(data);
+        if !summary.is_empty() {
+            let _ = writeln!(out, "*{}*\n", summary);
+        }
+
+        let details = [
+            ("Player", &c.player_name),
+            ("Role", &c.role),
+            ("Location", &c.location),
+            ("Faction", &c.faction),
+        ];
+        let mut has_details = false;
+        for (label, value) in details {
+            if let Some(value) = value.as_deref().filter(|v| !v.trim().is_empty()) {
+                let _ = writeln!(out, "- **{}:** {}", label, value);
+                has_details = true;
+            }
+        }
+        if has_details {
+            out.push('\n');
+        }
+
+        // Ability scores
+        let scores = [
+            c.strength,
+            c.dexterity,
+            c.constitution,
+            c.intelligence,
+            c.wisdom,
+            c.charisma,
+        ];
+        let cells: Vec<String> = scores
+            .iter()
+            .map(|&s| format!("{} ({})", s, signed(Character::ability_modifier(s))))
+            .collect();
+        out.push_str("| STR | DEX | CON | INT | WIS | CHA |\n");
+        out.push_str("|:---:|:---:|:---:|:---:|:---:|:---:|\n");
+        let _ = writeln!(out, "| {} |\n", cells.join(" | "));
+
+        let custom_values: Vec<(String, String)> =
+            resolve_custom_field_values(custom_fields, &data.custom_fields)
+                .into_iter()
+                .filter_map(|v| v.display_value().map(|value| (v.label, value)))
+                .collect();
+        if !custom_values.is_empty() {
+            out.push_str("## Custom Fields\n\n");
+            for (label, value) in custom_values {
+                let _ = writeln!(out, "- **{}:** {}", label, value);
+            }
+            out.push('\n');
+        }
+
+        if !data.proficiencies.is_empty() {
+            out.push_str("## Proficiencies\n\n");
+            let mut types: Vec<&str> = Vec::new();
+            for p in &data.proficiencies {
+                if !types.contains(&p.proficiency_type.as_str()) {
+                    types.push(&p.proficiency_type);
+                }
+            }
+            for kind in types {
+                let names: Vec<String> = data
+                    .proficiencies
+                    .iter()
+                    .filter(|p| p.proficiency_type == kind)
+                    .map(|p| {
+                        if p.expertise != 0 {
+                            format!("{} (expertise)", p.name)
+                        } else {
+                            p.name.clone()
+                        }
+                    })
+                    .collect();
+                let _ = writeln!(out, "- **{}:** {}", capitalize(kind), names.join(", "));
+            }
+            out.push('\n');
+        }
+
+        if !data.features.is_empty() {
+            out.push_str("## Features\n\n");
+            for f in &data.features {
+                if f.source_class.is_empty() {
+                    let _ = writeln!(out, "- {}", f.feature_name);
+                } else {
+                    let _ = writeln!(out, "- {} *({})*", f.feature_name, f.source_class);
+                }
+            }
+            out.push('\n');
+        }
+
+        if !data.feats.is_empty() {
+            out.push_str("## Feats\n\n");
+            for f in &data.feats {
+                let _ = writeln!(out, "- {}", f.feat_name);
+            }
+            out.push('\n');
+        }
+
+        if !data.spells.is_empty() {
+            out.push_str("## Spells\n\n");
+            let mut classes: Vec<&str> = Vec::new();
+            for s in &data.spells {
+                if !classes.contains(&s.source_class.as_str()) {
+                    classes.push(&s.source_class);
+                }
+            }
+            for class in classes {
+                if !class.is_empty() {
+                    let _ = writeln!(out, "### {}\n", class);
+                }
+                for s in data.spells.iter().filter(|s| s.source_class == class) {
+                    if s.prepared != 0 {
+                        let _ = writeln!(out, "- {} *(prepared)*", s.spell_name);
+                    } else {
+                        let _ = writeln!(out, "- {}", s.spell_name);
+                    }
+                }
+                out.push('\n');
+            }
+        }
+
+        let coins: Vec<String> = [
+            (c.pp, "pp"),
+            (c.gp, "gp"),
+            (c.ep, "ep"),
+            (c.sp, "sp"),
+            (c.cp, "cp"),
+        ]
+        .iter()
+        .filter(|(amount, _)| *amount != 0)
+        .map(|(amount, unit)| format!("{} {}", amount, unit))
+        .collect();
+        if !data.inventory.is_empty() || !coins.is_empty() {
+            out.push_str("## Inventory\n\n");
+            if !coins.is_empty() {
+                let _ = writeln!(out, "**Coins:** {}\n", coins.join(", "));
+            }
+            if !data.inventory.is_empty() {
+                out.push_str("| Item | Qty | Status | Notes |\n");
+                out.push_str("|------|----:|--------|-------|\n");
+                for item in &data.inventory {
+                    let mut status = Vec::new();
+                    if item.equipped != 0 {
+                        status.push("equipped");
+                    }
+                    if item.attuned != 0 {
+                        status.push("attuned");
+                    }
+                    let _ = writeln!(
+                        out,
+                        "| {} | {} | {} | {} |",
+                        table_cell(&item.item_name),
+                        item.quantity,
+                        status.join(", "),
+                        table_cell(item.notes.as_deref().unwrap_or(""))
+                    );
+                }
+                out.push('\n');
+            }
+        }
+
+        let personality = [
+            ("Traits", &c.traits),
+            ("Ideals", &c.ideals),
+            ("Bonds", &c.bonds),
+            ("Flaws", &c.flaws),
+        ];
+        if personality.iter().any(|(_, v)| has_text(v)) {
+            out.push_str("## Personality\n\n");
+            for (label, value) in personality {
+                if let Some(value) = value.as_deref().filter(|v| !v.trim().is_empty()) {
+                    let _ = writeln!(out, "### {}\n\n{}\n", label, value.trim());
+                }
+            }
+        }
+
+        finish(out)
+    }
+
+    /// Render a module NPC's notes.
+    pub fn module_npc(npc: &ModuleNpc) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}\n", npc.name);
+        if let Some(role) = npc.role.as_deref().filter(|r| !r.trim().is_empty()) {
+            let _ = writeln!(out, "*{}*\n", role.trim());
+        }
+
+        let sections = [
+            ("Description", &npc.description),
+            ("Appearance", &npc.appearance),
+            ("Personality", &npc.personality),
+            ("Motivation", &npc.motivation),
+            ("Secrets", &npc.secrets),
+            ("Stat Block", &npc.stat_block),
+        ];
+        for (label, value) in sections {
+            if let Some(value) = value.as_deref().filter(|v| !v.trim().is_empty()) {
+                let _ = writeln!(out, "## {}\n\n{}\n", label, value.trim());
+            }
+        }
+
+        finish(out)
+    }
+
+    /// Render a game session log.
+    pub fn session(session: &GameSession, module_name: Option<&str>) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}\n", session_title(session));
+
+        if let Some(module_name) = module_name {
+            let _ = writeln!(out, "- **Module:** {}", module_name);
+        }
+        let _ = writeln!(out, "- **Started:** {}", session.started_at);
+        let _ = writeln!(
+            out,
+            "- **Ended:** {}",
+            session.ended_at.as_deref().unwrap_or("In progress")
+        );
+        let _ = writeln!(out, "- **Play time:** {}", duration(session.play_seconds));
+        if session.break_seconds > 0 {
+            let _ = writeln!(out, "- **Breaks:** {}", duration(session.break_seconds));
+        }
+        if session.combat_turns > 0 {
+            let average = session.combat_turn_seconds / session.combat_turns;
+            let _ = writeln!(
+                out,
+                "- **Combat turns:** {} (average {}, longest {})",
+                session.combat_turns,
+                duration(average),
+                duration(session.longest_turn_seconds)
+            );
+        }
+
+        out
+    }
+
+    /// One-line summary: level and classes, race, background.
+    fn character_summary(data: &CharacterWithRelated) -> String {
+        let c = &data.character;
+        let mut parts = Vec::new();
+        if !data.classes.is_empty() {
+            let level: i32 = data.classes.iter().map(|k| k.level).sum();
+            let classes: Vec<String> = data
+                .classes
+                .iter()
+                .map(|k| match k.subclass_name {
+                    Some(ref sub) => format!("{} ({}) {}", k.class_name, sub, k.level),
+                    None => format!("{} {}", k.class_name, k.level),
+                })
+                .collect();
+            parts.push(format!("Level {} {}", level, classes.join(" / ")));
+        }
+        if let Some(ref race) = c.race_name {
+            parts.push(race.clone());
+        }
+        if let Some(ref background) = c.background_name {
+            parts.push(background.clone());
+        }
+        parts.join(" · ")
+    }
+}
+
+fn session_title(session: &GameSession) -> String {
+    match session.title.as_deref().filter(|t| !t.trim().is_empty()) {
+        Some(title) => format!("Session {}: {}", session.session_number, title.trim()),
+        None => format!("Session {}", session.session_number),
+    }
+}
+
+fn signed(n: i32) -> String {
+    if n >= 0 {
+        format!("+{}", n)
+    } else {
+        n.to_string()
+    }
+}
+
+/// Format seconds as "1h 05m", "12m 30s", or "45s".
+fn duration(seconds: i32) -> String {
+    let seconds = seconds.max(0);
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if h > 0 {
+        format!("{}h {:02}m", h, m)
+    } else if m > 0 {
+        format!("{}m {:02}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn has_text(value: &Option<String>) -> bool {
+    value.as_deref().is_some_and(|v| !v.trim().is_empty())
+}
+
+/// Escape a value for a markdown table cell.
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Trim trailing blank lines down to a single newline.
+fn finish(mut out: String) -> String {
+    let len = out.trim_end().len();
+    out.truncate(len);
+    out.push('\n');
+    out
+}
+
+// =============================================================================
+// Export Writer
+// =============================================================================
+
+/// Writes export files under a root folder, keeping file names unique and
+/// remembering what was written so stale files can be cleaned up.
+struct ExportWriter {
+    root: PathBuf,
+    written: HashSet<String>,
+}
+
+impl ExportWriter {
+    fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            written: HashSet::new(),
+        }
+    }
+
+    /// Write `content` to `<dir>/<slug of name>.md`, returning the relative path.
+    fn write(&mut self, dir: &str, name: &str, content: &str) -> ServiceResult<String> {
+        let mut slug = ArchiveService::slugify(name);
+        if slug.is_empty() {
+            slug = "untitled".to_string();
+        }
+
+        let mut relative = format!("{}/{}.md", dir, slug);
+        let mut n = 2;
+        while self.written.contains(&relative) {
+            relative = format!("{}/{}-{}.md", dir, slug, n);
+            n += 1;
+        }
+
+        self.write_file(&relative, content)?;
+        Ok(relative)
+    }
+
+    fn write_file(&mut self, relative: &str, content: &str) -> ServiceResult<()> {
+        let path = self.root.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        self.written.insert(relative.to_string());
+        Ok(())
+    }
+
+    /// Remove markdown files the previous export wrote that were not written
+    /// this time. Files Mimir didn't write, such as the user's own notes, are
+    /// never touched.
+    fn remove_stale(&self, previous: &HashSet<String>) -> ServiceResult<usize> {
+        let mut removed = 0;
+        for relative in previous {
+            if self.written.contains(relative) || !is_export_markdown_path(relative) {
+                continue;
+            }
+            let path = self.root.join(relative);
+            if path.is_file() {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Record every file written, sorted so unchanged exports are identical.
+    fn write_files_manifest(&self) -> ServiceResult<()> {
+        let mut files: Vec<&String> = self.written.iter().collect();
+        files.sort();
+        let json = serde_json::to_string_pretty(&files)
+            .map_err(|e| ServiceError::validation(format!("Failed to write manifest: {}", e)))?;
+        fs::write(self.root.join(EXPORT_FILES_MANIFEST), json)?;
+        Ok(())
+    }
+}
+
+/// Whether a manifest path is a markdown file inside the export, outside any
+/// hidden folder (such as `.git`).
+fn is_export_markdown_path(relative: &str) -> bool {
+    relative.ends_with(".md")
+        && !Path::new(relative).is_absolute()
+        && relative
+            .split('/')
+            .all(|part| !part.is_empty() && part != ".." && !part.starts_with('.'))
+}
+
+// =============================================================================
+// Markdown Export Service
+// =============================================================================
+
+/// Service for exporting a campaign as markdown files.
+pub struct MarkdownExportService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> MarkdownExportService<'a> {
+    /// Create a new markdown export service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Export a campaign to `<dest>/<campaign-slug>/`.
+    ///
+    /// Exporting again into the same folder updates it in place: files are
+    /// rewritten and markdown files the last export wrote for since-deleted
+    /// content are removed. Other files in the folder are left alone. The
+    /// output contains no export timestamps, so unchanged content produces
+    /// identical files.
+    pub fn export_campaign(
+        &mut self,
+        campaign_id: &str,
+        dest: &Path,
+    ) -> ServiceResult<MarkdownExportResult> {
+        let campaign = self.get_campaign(campaign_id)?;
+        let mut folder = ArchiveService::slugify(&campaign.name);
+        if folder.is_empty() {
+            folder = "campaign".to_string();
+        }
+        self.export_campaign_into(campaign_id, &dest.join(folder))
+    }
+
+    /// Export a campaign directly into `root`, with the same in-place update
+    /// behaviour as [`export_campaign`](Self::export_campaign).
+    pub fn export_campaign_into(
+        &mut self,
+        campaign_id: &str,
+        root: &Path,
+    ) -> ServiceResult<MarkdownExportResult> {
+        encryption::ensure_unencrypted(self.conn, "Markdown export")?;
+        let campaign = self.get_campaign(campaign_id)?;
+        let root = root.to_path_buf();
+        info!(campaign_id = %campaign_id, path = %root.display(), "Exporting campaign as markdown");
+        let previous = previous_export_files(&root)?;
+
+        let modules = dal::list_modules(self.conn, campaign_id)?;
+        let documents = dal::list_campaign_documents(self.conn, campaign_id)?;
+        let mut characters = Vec::new();
+        for c in dal::list_campaign_characters(self.conn, campaign_id)? {
+            characters.push(CharacterWithRelated {
+                classes: dal::list_character_classes(self.conn, &c.id)?,
+                inventory: dal::list_character_inventory(self.conn, &c.id)?,
+                spells: dal::list_character_spells(self.conn, &c.id)?,
+                proficiencies: dal::list_character_proficiencies(self.conn, &c.id)?,
+                features: dal::list_character_features(self.conn, &c.id)?,
+                feats: dal::list_character_feats(self.conn, &c.id)?,
+                custom_fields: dal::list_character_custom_fields(self.conn, &c.id)?,
+                resources: dal::list_character_resources(self.conn, &c.id)?,
+                companions: dal::list_character_companions(self.conn, &c.id)?,
+                vehicles: dal::list_character_owned_vehicles(self.conn, &c.id)?,
+                crafting_projects: dal::list_character_crafting_projects(self.conn, &c.id)?,
+                currency_transactions: dal::list_character_currency_transactions(self.conn, &c.id)?,
+                deaths: dal::list_character_deaths(self.conn, &c.id)?,
+                character: c,
+            });
+        }
+        let custom_fields = dal::list_campaign_custom_fields(self.conn, campaign_id)?;
+        let mut sessions = dal::list_game_sessions(self.conn, campaign_id)?;
+        sessions.reverse();
+
+        let mut writer = ExportWriter::new(root.clone());
+        let mut exported = Vec::new();
+        let mut index = String::new();
+        let _ = writeln!(index, "# {}\n", campaign.name);
+        if let Some(description) = campaign
+            .description
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+        {
+            let _ = writeln!(index, "{}\n", description.trim());
+        }
+
+        // Campaign-level documents
+        let campaign_docs: Vec<&Document> =
+            documents.iter().filter(|d| d.module_id.is_none()).collect();
+        if !campaign_docs.is_empty() {
+            index.push_str("## Documents\n\n");
+            for doc in campaign_docs {
+                let path =
+                    writer.write("documents", &doc.title, &MarkdownRenderer::document(doc))?;
+                let _ = writeln!(index, "- [{}]({})", doc.title, path);
+                exported.push(ExportedDocument::new(path, doc));
+            }
+            index.push('\n');
+        }
+
+        // Modules with their documents and NPC notes
+        if !modules.is_empty() {
+            index.push_str("## Modules\n\n");
+            for module in &modules {
+                let dir = module_dir(module);
+                let _ = writeln!(index, "### {}. {}\n", module.module_number, module.name);
+
+                let mut listed = false;
+                for doc in documents
+                    .iter()
+                    .filter(|d| d.module_id.as_deref() == Some(&module.id))
+                {
+                    let path = writer.write(&dir, &doc.title, &MarkdownRenderer::document(doc))?;
+                    let _ = writeln!(index, "- [{}]({})", doc.title, path);
+                    exported.push(ExportedDocument::new(path, doc));
+                    listed = true;
+                }
+                for npc in dal::list_module_npcs(self.conn, &module.id)? {
+                    let path = writer.write(
+                        &format!("{}/npcs", dir),
+                        &npc.name,
+                        &MarkdownRenderer::module_npc(&npc),
+                    )?;
+                    let _ = writeln!(index, "- NPC: [{}]({})", npc.name, path);
+                    listed = true;
+                }
+                if !listed {
+                    index.push_str("*No documents.*\n");
+                }
+                index.push('\n');
+            }
+        }
+
+        // Characters
+        for (heading, dir, npcs) in [
+            ("Player Characters", "characters", false),
+            ("NPCs", "npcs", true),
+        ] {
+            let group: Vec<&CharacterWithRelated> = characters
+                .iter()
+                .filter(|c| c.character.is_npc() == npcs)
+                .collect();
+            if group.is_empty() {
+                continue;
+            }
+            let _ = writeln!(index, "## {}\n", heading);
+            for data in group {
+                let path = writer.write(
+                    dir,
+                    &data.character.name,
+                    &MarkdownRenderer::character(data, &custom_fields),
+                )?;
+                let summary = if npcs {
+                    data.character.role.clone().unwrap_or_default()
+                } else {
+                    MarkdownRenderer::character_summary(data)
+                };
+                if summary.is_empty() {
+                    let _ = writeln!(index, "- [{}]({})", data.character.name, path);
+                } else {
+                    let _ = writeln!(index, "- [{}]({}) - {}", data.character.name, path, summary);
+                }
+            }
+            index.push('\n');
+        }
+
+        // Session logs
+        if !sessions.is_empty() {
+            index.push_str("## Sessions\n\n");
+            for session in &sessions {
+                let module_name = session
+                    .module_id
+                    .as_deref()
+                    .and_then(|id| modules.iter().find(|m| m.id == id))
+                    .map(|m| m.name.as_str());
+                let name = format!("session-{:03}", session.session_number);
+                let path = writer.write(
+                    "sessions",
+                    &name,
+                    &MarkdownRenderer::session(session, module_name),
+                )?;
+                let _ = writeln!(index, "- [{}]({})", session_title(session), path);
+            }
+            index.push('\n');
+        }
+
+        writer.write_file(MARKDOWN_INDEX_FILE, &finish(index))?;
+        write_document_manifest(&root, &exported)?;
+        writer.write_files_manifest()?;
+        let files_removed = writer.remove_stale(&previous)?;
+
+        Ok(MarkdownExportResult {
+            root,
+            files_written: writer.written.len(),
+            files_removed,
+            documents: exported,
+        })
+    }
+
+    fn get_campaign(&mut self, campaign_id: &str) -> ServiceResult<Campaign> {
+        dal::get_campaign(self.conn, campaign_id).map_err(|e| match e {
+            diesel::result::Error::NotFound => ServiceError::not_found("Campaign", campaign_id),
+            other => ServiceError::Database(other),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, insert_document, insert_module};
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewDocument, NewModule};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Curse of Strahd")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "Death House", 1)).unwrap();
+        let docs = [
+            NewDocument::for_campaign("doc-1", "camp-1", "House Rules", "note")
+                .with_content("No flanking."),
+            NewDocument::for_module("doc-2", "camp-1", "mod-1", "Basement", "note"),
+        ];
+        for doc in &docs {
+            insert_document(conn, doc).unwrap();
+        }
+        let pc = NewCharacter::new_pc("pc-1", Some("camp-1"), "Ireena", "Sam");
+        insert_character(conn, &pc).unwrap();
+        let npc = NewCharacter::new_npc("npc-1", Some("camp-1"), "Ismark").with_npc_info(
+            Some("burgomaster's son"),
+            Some("Barovia"),
+            None,
+        );
+        insert_character(conn, &npc).unwrap();
+    }
+
+    #[test]
+    fn test_export_writes_folder_tree_and_index() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let dest = TempDir::new().unwrap();
+
+        let result = MarkdownExportService::new(&mut conn)
+            .export_campaign("camp-1", dest.path())
+            .expect("Failed to export");
+        let root = dest.path().join("curse-of-strahd");
+        assert_eq!(result.root, root);
+        assert_eq!(result.files_written, 5);
+
+        let rules = fs::read_to_string(root.join("documents/house-rules.md")).unwrap();
+        assert_eq!(rules, "# House Rules\n\nNo flanking.\n");
+        assert!(root.join("modules/01-death-house/basement.md").exists());
+        assert!(root.join("characters/ireena.md").exists());
+
+        let npc = fs::read_to_string(root.join("npcs/ismark.md")).unwrap();
+        assert!(npc.contains("- **Location:** Barovia"));
+
+        let index = fs::read_to_string(root.join(MARKDOWN_INDEX_FILE)).unwrap();
+        assert!(index.starts_with("# Curse of Strahd\n"));
+        assert!(index.contains("- [House Rules](documents/house-rules.md)"));
+        assert!(index.contains("- [Ismark](npcs/ismark.md) - burgomaster's son"));
+    }
+
+    #[test]
+    fn test_reexport_removes_stale_files_but_not_hidden_folders() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let dest = TempDir::new().unwrap();
+        let root = dest.path().join("curse-of-strahd");
+
+        MarkdownExportService::new(&mut conn)
+            .export_campaign("camp-1", dest.path())
+            .unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join(".git/notes.md"), "keep").unwrap();
+
+        dal::delete_document(&mut conn, "doc-1").unwrap();
+        let result = MarkdownExportService::new(&mut conn)
+            .export_campaign("camp-1", dest.path())
+            .unwrap();
+
+        assert_eq!(result.files_removed, 1);
+        assert!(!root.join("documents/house-rules.md").exists());
+        assert!(root.join(".git/notes.md").exists());
+    }
+
+    #[test]
+    fn test_reexport_keeps_files_mimir_did_not_write() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let dest = TempDir::new().unwrap();
+        let root = dest.path().join("curse-of-strahd");
+
+        // The user's own notes, already in the folder before the first export
+        fs::create_dir_all(root.join("documents")).unwrap();
+        fs::write(root.join("my-notes.md"), "mine").unwrap();
+        fs::write(root.join("documents/ideas.md"), "also mine").unwrap();
+
+        let first = MarkdownExportService::new(&mut conn)
+            .export_campaign("camp-1", dest.path())
+            .unwrap();
+        assert_eq!(first.files_removed, 0);
+
+        dal::delete_document(&mut conn, "doc-1").unwrap();
+        let second = MarkdownExportService::new(&mut conn)
+            .export_campaign("camp-1", dest.path())
+            .unwrap();
+
+        assert_eq!(second.files_removed, 1);
+        assert!(!root.join("documents/house-rules.md").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("my-notes.md")).unwrap(),
+            "mine"
+        );
+        assert!(root.join("documents/ideas.md").exists());
+    }
+
+    #[test]
+    fn test_export_markdown_path() {
+        assert!(is_export_markdown_path(
+            "modules/01-death-house/basement.md"
+        ));
+        assert!(!is_export_markdown_path("../outside.md"));
+        assert!(!is_export_markdown_path("/etc/notes.md"));
+        assert!(!is_export_markdown_path(".git/notes.md"));
+        assert!(!is_export_markdown_path("documents/map.png"));
+    }
+
+    #[test]
+    fn test_character_sheet_includes_custom_fields() {
+        use crate::models::campaign::{
+            CustomFieldType, NewCampaignCustomField, NewCharacterCustomField,
+        };
+
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let fields = [
+            NewCampaignCustomField::new(
+                "f-1",
+                "camp-1",
+                "sanity",
+                "Sanity",
+                CustomFieldType::Number,
+            )
+            .with_default("50"),
+            NewCampaignCustomField::new(
+                "f-2",
+                "camp-1",
+                "oath",
+                "Oath Sworn",
+                CustomFieldType::Boolean,
+            )
+            .with_sort_order(1),
+            NewCampaignCustomField::new("f-3", "camp-1", "notes", "Omens", CustomFieldType::Text)
+                .with_sort_order(2),
+        ];
+        for field in &fields {
+            dal::insert_campaign_custom_field(&mut conn, field).unwrap();
+        }
+        let value = NewCharacterCustomField::new("pc-1", "oath", "true", "2024-01-01T00:00:00Z");
+        dal::upsert_character_custom_field(&mut conn, &value).unwrap();
+        let dest = TempDir::new().unwrap();
+
+        MarkdownExportService::new(&mut conn)
+            .export_campaign("camp-1", dest.path())
+            .unwrap();
+
+        let sheet =
+            fs::read_to_string(dest.path().join("curse-of-strahd/characters/ireena.md")).unwrap();
+        assert!(sheet.contains("## Custom Fields\n\n- **Sanity:** 50\n- **Oath Sworn:** Yes\n\n"));
+        assert!(!sheet.contains("Omens"));
+    }
+
+    #[test]
+    fn test_document_content_round_trips() {
+        let rendered = "# Notes\n\nHello\n";
+        assert_eq!(MarkdownRenderer::document_content(rendered, true), "Hello");
+        assert_eq!(MarkdownRenderer::document_content("# Notes\n", true), "");
+        assert_eq!(
+            MarkdownRenderer::document_content(rendered, false),
+            "# Notes\n\nHello"
+        );
+    }
+
+    #[test]
+    fn test_duration_format() {
+        assert_eq!(duration(45), "45s");
+        assert_eq!(duration(750), "12m 30s");
+        assert_eq!(duration(3900), "1h 05m");
+    }
+}
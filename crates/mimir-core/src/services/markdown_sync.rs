@@ -0,0 +1,328 @@
+//! Markdown Sync Service
+//!
+//! Applies edits made to an exported campaign folder back to the database.
+//! The folder is produced by [`MarkdownExportService`]; its document manifest
+//! maps each document file to its document ID and a hash of the content it
+//! was written with. Editing a document file updates the document, a new
+//! markdown file under `documents/` or a module folder creates one, and
+//! deleting a document file moves it to the trash.
+//!
+//! A file is only applied when its content differs from that hash, so an
+//! editor re-saving a stale copy never overwrites newer edits made in the
+//! app. When both sides changed, the change is reported as a conflict and
+//! the document is left alone.
+//!
+//! Character sheets, NPC notes, session logs, and the index are generated
+//! output and are never read back.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::dal::campaign as dal;
+use crate::services::markdown_export::{
+    content_hash, module_dir, read_document_manifest, write_document_manifest,
+};
+use crate::services::{
+    CreateDocumentInput, DocumentService, ExportedDocument, MarkdownRenderer, ServiceResult,
+    UpdateDocumentInput, MARKDOWN_INDEX_FILE,
+};
+use crate::utils::now_rfc3339;
+
+/// What a sync did to a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncKind {
+    Created,
+    Updated,
+    Deleted,
+    /// Both the file and the document changed since the export. Nothing was
+    /// written; the file is left for the DM to reconcile.
+    Conflicted,
+}
+
+/// A document change applied from the exported folder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncedChange {
+    pub kind: SyncKind,
+    pub campaign_id: String,
+    pub document_id: String,
+    /// File path relative to the export root
+    pub path: String,
+}
+
+/// Service for syncing an exported markdown folder back into the database.
+pub struct MarkdownSyncService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> MarkdownSyncService<'a> {
+    /// Create a new markdown sync service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Apply the current state of `path` (a file inside the export `root`)
+    /// to the campaign's documents.
+    ///
+    /// Returns `None` when the file is not a document file or nothing needed
+    /// to change, which includes files the app itself just exported.
+    pub fn sync_file(
+        &mut self,
+        campaign_id: &str,
+        root: &Path,
+        path: &Path,
+    ) -> ServiceResult<Option<SyncedChange>> {
+        let Ok(relative) = path.strip_prefix(root) else {
+            return Ok(None);
+        };
+        let relative: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if relative.iter().any(|part| part.starts_with('.')) {
+            return Ok(None);
+        }
+        let relative = relative.join("/");
+        if !relative.ends_with(".md") || relative == MARKDOWN_INDEX_FILE {
+            return Ok(None);
+        }
+
+        let mut manifest = read_document_manifest(root)?;
+        let position = manifest.iter().position(|d| d.path == relative);
+        let file = root.join(&relative);
+
+        let (kind, document_id) = match (file.is_file(), position) {
+            (true, Some(i)) => {
+                let entry = &manifest[i];
+                let rendered = fs::read_to_string(&file)?;
+                let content = MarkdownRenderer::document_content(&rendered, entry.title_added);
+                let file_hash = content_hash(&content);
+                // The file still holds what was exported; saving it again
+                // must not undo edits made in the app since.
+                if entry.base_hash.as_deref() == Some(file_hash.as_str()) {
+                    return Ok(None);
+                }
+                let document = match dal::get_document_optional(self.conn, &entry.document_id)? {
+                    Some(document) if !document.is_trashed() => document,
+                    _ => return Ok(None),
+                };
+                if document.content == content {
+                    manifest[i].base_hash = Some(file_hash);
+                    write_document_manifest(root, &manifest)?;
+                    return Ok(None);
+                }
+                let document_changed = entry
+                    .base_hash
+                    .as_deref()
+                    .is_some_and(|base| base != content_hash(&document.content));
+                if document_changed {
+                    (SyncKind::Conflicted, document.id)
+                } else {
+                    DocumentService::new(self.conn)
+                        .update(&document.id, UpdateDocumentInput::set_content(content))?;
+                    manifest[i].base_hash = Some(file_hash);
+                    write_document_manifest(root, &manifest)?;
+                    (SyncKind::Updated, document.id)
+                }
+            }
+            (true, None) => {
+                let Some(module_id) = self.folder_module(campaign_id, &relative)? else {
+                    return Ok(None);
+                };
+                let content = fs::read_to_string(&file)?;
+                let title = title_from(&content, &file);
+                let input = match module_id {
+                    Some(module_id) => {
+                        CreateDocumentInput::for_module(campaign_id, module_id, title)
+                    }
+                    None => CreateDocumentInput::for_campaign(campaign_id, title),
+                };
+                let document =
+                    DocumentService::new(self.conn).create(input.with_content(content))?;
+
+                manifest.push(ExportedDocument {
+                    path: relative.clone(),
+                    document_id: document.id.clone(),
+                    title_added: false,
+                    base_hash: Some(content_hash(&document.content)),
+                });
+                write_document_manifest(root, &manifest)?;
+                (SyncKind::Created, document.id)
+            }
+            (false, Some(i)) => {
+                let entry = manifest.remove(i);
+                write_document_manifest(root, &manifest)?;
+                if dal::trash_document(self.conn, &entry.document_id, &now_rfc3339())? == 0 {
+                    return Ok(None);
+                }
+                (SyncKind::Deleted, entry.document_id)
+            }
+            (false, None) => return Ok(None),
+        };
+
+        Ok(Some(SyncedChange {
+            kind,
+            campaign_id: campaign_id.to_string(),
+            document_id,
+            path: relative,
+        }))
+    }
+
+    /// Where a new file at `relative` belongs: `Some(None)` for campaign-level
+    /// documents, `Some(Some(module_id))` for a module's documents, and `None`
+    /// for folders that don't hold documents.
+    fn folder_module(
+        &mut self,
+        campaign_id: &str,
+        relative: &str,
+    ) -> ServiceResult<Option<Option<String>>> {
+        let parts: Vec<&str> = relative.split('/').collect();
+        match parts.as_slice() {
+            ["documents", _] => Ok(Some(None)),
+            ["modules", dir, _] => {
+                let modules = dal::list_modules(self.conn, campaign_id)?;
+                Ok(modules
+                    .into_iter()
+                    .find(|m| module_dir(m) == format!("modules/{}", dir))
+                    .map(|m| Some(m.id)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Title for a new document: its first `# ` heading, or else the file name.
+fn title_from(content: &str, file: &Path) -> String {
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            file.file_stem()
+                .map(|stem| stem.to_string_lossy().replace(['-', '_'], " "))
+                .unwrap_or_else(|| "Untitled".to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_document, insert_module};
+    use crate::models::campaign::{NewCampaign, NewDocument, NewModule};
+    use crate::services::MarkdownExportService;
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    fn export(conn: &mut SqliteConnection, dir: &TempDir) -> std::path::PathBuf {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Curse of Strahd")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "Death House", 1)).unwrap();
+        insert_document(
+            conn,
+            &NewDocument::for_campaign("doc-1", "camp-1", "House Rules", "note")
+                .with_content("No flanking."),
+        )
+        .unwrap();
+        MarkdownExportService::new(conn)
+            .export_campaign("camp-1", dir.path())
+            .unwrap()
+            .root
+    }
+
+    #[test]
+    fn test_unchanged_export_is_a_noop() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let root = export(&mut conn, &dir);
+
+        let mut service = MarkdownSyncService::new(&mut conn);
+        let file = root.join("documents/house-rules.md");
+        assert!(service.sync_file("camp-1", &root, &file).unwrap().is_none());
+        let index = root.join(MARKDOWN_INDEX_FILE);
+        assert!(service
+            .sync_file("camp-1", &root, &index)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_edit_create_and_delete_files() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let root = export(&mut conn, &dir);
+        let mut service = MarkdownSyncService::new(&mut conn);
+
+        // Edit an exported document
+        let rules = root.join("documents/house-rules.md");
+        fs::write(&rules, "# House Rules\n\nFlanking gives advantage.\n").unwrap();
+        let change = service.sync_file("camp-1", &root, &rules).unwrap().unwrap();
+        assert_eq!(change.kind, SyncKind::Updated);
+        let doc = dal::get_document(service.conn, "doc-1").unwrap();
+        assert_eq!(doc.content, "Flanking gives advantage.");
+
+        // Add a note to a module folder
+        let note = root.join("modules/01-death-house/cellar.md");
+        fs::write(&note, "# The Cellar\n\nDark and damp.\n").unwrap();
+        let change = service.sync_file("camp-1", &root, &note).unwrap().unwrap();
+        assert_eq!(change.kind, SyncKind::Created);
+        let created = dal::get_document(service.conn, &change.document_id).unwrap();
+        assert_eq!(created.title, "The Cellar");
+        assert_eq!(created.module_id.as_deref(), Some("mod-1"));
+
+        // Editing the new file again updates rather than duplicates it
+        fs::write(&note, "# The Cellar\n\nDark, damp, and haunted.\n").unwrap();
+        let change = service.sync_file("camp-1", &root, &note).unwrap().unwrap();
+        assert_eq!(change.kind, SyncKind::Updated);
+
+        // Delete an exported document
+        fs::remove_file(&rules).unwrap();
+        let change = service.sync_file("camp-1", &root, &rules).unwrap().unwrap();
+        assert_eq!(change.kind, SyncKind::Deleted);
+        assert!(dal::get_document(service.conn, "doc-1")
+            .unwrap()
+            .is_trashed());
+    }
+
+    #[test]
+    fn test_stale_file_does_not_overwrite_app_edits() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let root = export(&mut conn, &dir);
+        let mut service = MarkdownSyncService::new(&mut conn);
+
+        // Edited in the app after the export
+        DocumentService::new(service.conn)
+            .update(
+                "doc-1",
+                UpdateDocumentInput::set_content("Flanking is allowed."),
+            )
+            .unwrap();
+
+        // An external editor saves its untouched copy of the old export
+        let rules = root.join("documents/house-rules.md");
+        let stale = fs::read_to_string(&rules).unwrap();
+        fs::write(&rules, &stale).unwrap();
+        assert!(service
+            .sync_file("camp-1", &root, &rules)
+            .unwrap()
+            .is_none());
+        let doc = dal::get_document(service.conn, "doc-1").unwrap();
+        assert_eq!(doc.content, "Flanking is allowed.");
+
+        // A real edit to the stale copy conflicts instead of overwriting
+        fs::write(&rules, "# House Rules\n\nNo flanking, ever.\n").unwrap();
+        let change = service.sync_file("camp-1", &root, &rules).unwrap().unwrap();
+        assert_eq!(change.kind, SyncKind::Conflicted);
+        let doc = dal::get_document(service.conn, "doc-1").unwrap();
+        assert_eq!(doc.content, "Flanking is allowed.");
+    }
+
+    #[test]
+    fn test_title_from() {
+        let file = Path::new("notes/session-prep.md");
+        assert_eq!(title_from("Intro\n# Prep\n", file), "Prep");
+        assert_eq!(title_from("No heading", file), "session prep");
+    }
+}
@@ -0,0 +1,286 @@
+//! Memory Service
+//!
+//! Business logic for campaign memories: named facts the AI assistant saves
+//! while helping run a campaign and recalls in later conversations.
+//!
+//! Recall ranks memories by how many of the query's words appear in their
+//! name and content, so "what did the party do to the duke" finds
+//! "Duke Avery insulted" without needing an embedding model.
+
+use diesel::SqliteConnection;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{CampaignMemory, NewCampaignMemory, UpdateCampaignMemory};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Words too common to say anything about which memory is meant.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "did", "do", "for", "from", "had", "has", "have", "in", "is", "it",
+    "of", "on", "or", "that", "the", "their", "they", "this", "to", "was", "what", "when", "where",
+    "who", "with",
+];
+
+/// Service for campaign memories.
+pub struct MemoryService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> MemoryService<'a> {
+    /// Create a new memory service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Remember a fact for a campaign.
+    ///
+    /// Remembering under a name the campaign already uses replaces that fact.
+    pub fn remember(
+        &mut self,
+        campaign_id: &str,
+        name: &str,
+        content: &str,
+    ) -> ServiceResult<CampaignMemory> {
+        let name = validate_text("Memory name", name)?;
+        let content = validate_text("Memory content", content)?;
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+
+        if let Some(existing) = dal::find_campaign_memory_by_name(self.conn, campaign_id, name)? {
+            let now = now_rfc3339();
+            let update = UpdateCampaignMemory {
+                content: Some(content),
+                updated_at: Some(&now),
+                ..Default::default()
+            };
+            dal::update_campaign_memory(self.conn, &existing.id, &update)?;
+            return dal::get_campaign_memory(self.conn, &existing.id).map_err(ServiceError::from);
+        }
+
+        let id = Uuid::new_v4().to_string();
+        dal::insert_campaign_memory(
+            self.conn,
+            &NewCampaignMemory::new(&id, campaign_id, name, content),
+        )?;
+        dal::get_campaign_memory(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Recall the memories that best match a query, best match first.
+    ///
+    /// Memories sharing no words with the query are left out.
+    pub fn recall(
+        &mut self,
+        campaign_id: &str,
+        query: &str,
+        limit: usize,
+    ) -> ServiceResult<Vec<CampaignMemory>> {
+        let terms = terms(query);
+        if terms.is_empty() {
+            return Err(ServiceError::validation(
+                "Recall query needs at least one meaningful word",
+            ));
+        }
+
+        // Listed most recent first, and the sort is stable, so ties go to
+        // the newer memory.
+        let mut scored: Vec<(usize, CampaignMemory)> =
+            dal::list_campaign_memories(self.conn, campaign_id)?
+                .into_iter()
+                .map(|memory| (score(&terms, &memory), memory))
+                .filter(|(score, _)| *score > 0)
+                .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+
+        Ok(scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, memory)| memory)
+            .collect())
+    }
+
+    /// Get a memory by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<Option<CampaignMemory>> {
+        dal::get_campaign_memory_optional(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// List a campaign's memories, most recently updated first.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<CampaignMemory>> {
+        dal::list_campaign_memories(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Rename a memory or change what it says.
+    pub fn update(
+        &mut self,
+        id: &str,
+        name: Option<&str>,
+        content: Option<&str>,
+    ) -> ServiceResult<CampaignMemory> {
+        let memory = dal::get_campaign_memory_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("CampaignMemory", id))?;
+        let name = name.map(|n| validate_text("Memory name", n)).transpose()?;
+        let content = content
+            .map(|c| validate_text("Memory content", c))
+            .transpose()?;
+
+        if let Some(name) = name.filter(|n| *n != memory.name) {
+            if dal::find_campaign_memory_by_name(self.conn, &memory.campaign_id, name)?.is_some() {
+                return Err(ServiceError::validation(format!(
+                    "A memory named '{}' already exists in this campaign",
+                    name
+                )));
+            }
+        }
+
+        let now = now_rfc3339();
+        let update = UpdateCampaignMemory {
+            name,
+            content,
+            updated_at: Some(&now),
+        };
+        dal::update_campaign_memory(self.conn, id, &update)?;
+        dal::get_campaign_memory(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Forget a memory.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_campaign_memory(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("CampaignMemory", id));
+        }
+        Ok(())
+    }
+}
+
+fn validate_text<'s>(field: &str, value: &'s str) -> ServiceResult<&'s str> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ServiceError::validation(format!(
+            "{} cannot be empty",
+            field
+        )));
+    }
+    Ok(value)
+}
+
+/// The distinct meaningful words of a text, lowercased.
+fn terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| !word.is_empty() && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Query words found in the memory, counting a match in the name twice.
+fn score(query_terms: &HashSet<String>, memory: &CampaignMemory) -> usize {
+    let name_terms = terms(&memory.name);
+    let content_terms = terms(&memory.content);
+    query_terms
+        .iter()
+        .map(|term| 2 * name_terms.contains(term) as usize + content_terms.contains(term) as usize)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::models::campaign::NewCampaign;
+    use crate::test_utils::setup_test_db;
+
+    fn create_test_campaign(conn: &mut SqliteConnection) -> String {
+        let campaign_id = Uuid::new_v4().to_string();
+        let campaign = NewCampaign::new(&campaign_id, "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        campaign_id
+    }
+
+    #[test]
+    fn test_remember_replaces_by_name() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = MemoryService::new(&mut conn);
+
+        let first = service
+            .remember(&campaign_id, "Duke Avery", "The party insulted Duke Avery")
+            .expect("Failed to remember");
+        let second = service
+            .remember(
+                &campaign_id,
+                " Duke Avery ",
+                "The party apologized to Duke Avery",
+            )
+            .expect("Failed to remember");
+
+        assert_eq!(first.id, second.id);
+        assert_eq!(second.content, "The party apologized to Duke Avery");
+        assert_eq!(service.list_for_campaign(&campaign_id).unwrap().len(), 1);
+
+        assert!(service.remember(&campaign_id, "", "text").is_err());
+        assert!(service.remember("missing", "Name", "text").is_err());
+    }
+
+    #[test]
+    fn test_recall_ranks_by_matching_words() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = MemoryService::new(&mut conn);
+
+        service
+            .remember(
+                &campaign_id,
+                "Duke Avery",
+                "The party insulted the duke at dinner",
+            )
+            .unwrap();
+        service
+            .remember(
+                &campaign_id,
+                "Dinner debt",
+                "The party owes the innkeeper for dinner",
+            )
+            .unwrap();
+        service
+            .remember(
+                &campaign_id,
+                "Dragon sighting",
+                "A green dragon was seen near Thundertree",
+            )
+            .unwrap();
+
+        let results = service
+            .recall(&campaign_id, "What did the party do to the duke?", 5)
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "Duke Avery");
+
+        assert!(service.recall(&campaign_id, "the of", 5).is_err());
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = MemoryService::new(&mut conn);
+
+        let memory = service
+            .remember(&campaign_id, "Rival", "Glasstaff is the party's rival")
+            .unwrap();
+        service.remember(&campaign_id, "Patron", "Sildar").unwrap();
+
+        let renamed = service
+            .update(&memory.id, Some("Villain"), None)
+            .expect("Failed to update");
+        assert_eq!(renamed.name, "Villain");
+        assert_eq!(renamed.content, "Glasstaff is the party's rival");
+
+        // Names stay unique within the campaign
+        assert!(service.update(&memory.id, Some("Patron"), None).is_err());
+
+        service.delete(&memory.id).expect("Failed to delete");
+        assert!(service.get(&memory.id).unwrap().is_none());
+        assert!(service.delete(&memory.id).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! Mob Rules
+//!
+//! The DMG's shortcut for running many identical monsters at once. Instead
+//! of rolling every attack, a mob lands one hit for every few attackers,
+//! depending on the d20 roll they need. Hit points are pooled, so the
+//! number of casualties follows from the damage the mob has taken.
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::{ServiceError, ServiceResult};
+
+/// Highest d20 roll needed in each band, and the attackers needed for one
+/// of them to hit (DMG "Mob Attacks").
+const MOB_ATTACKS: [(i32, i32); 7] = [
+    (5, 1),
+    (12, 2),
+    (14, 3),
+    (16, 4),
+    (18, 5),
+    (19, 10),
+    (20, 20),
+];
+
+/// Result of a mob attacking one target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MobAttack {
+    /// Creatures attacking the target
+    pub attackers: i32,
+    /// d20 roll an attacker needs to hit
+    pub roll_needed: i32,
+    /// Attackers needed for one of them to hit
+    pub attackers_per_hit: i32,
+    /// Attacks that hit
+    pub hits: i32,
+}
+
+/// A mob of identical creatures sharing one pool of hit points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MobPool {
+    /// Creatures in the mob when it formed
+    pub size: i32,
+    /// Hit points of each creature
+    pub hp_each: i32,
+    /// Damage the mob has taken in total
+    pub damage: i32,
+}
+
+impl MobPool {
+    /// Form a mob of `size` creatures with `hp_each` hit points apiece.
+    pub fn new(size: i32, hp_each: i32) -> ServiceResult<Self> {
+        if size < 1 {
+            return Err(ServiceError::validation(
+                "A mob must have at least one creature",
+            ));
+        }
+        if hp_each < 1 {
+            return Err(ServiceError::validation(
+                "Mob creatures need at least 1 hit point",
+            ));
+        }
+        Ok(Self {
+            size,
+            hp_each,
+            damage: 0,
+        })
+    }
+
+    /// Hit points left in the pool.
+    pub fn remaining_hp(&self) -> i32 {
+        (self.size * self.hp_each - self.damage).max(0)
+    }
+
+    /// Creatures still standing. Damage fells creatures one at a time, so
+    /// only the last creature can be wounded.
+    pub fn survivors(&self) -> i32 {
+        (self.remaining_hp() + self.hp_each - 1) / self.hp_each
+    }
+
+    /// Deal damage to the mob, returning how many creatures it fells.
+    pub fn apply_damage(&mut self, amount: i32) -> i32 {
+        let before = self.survivors();
+        self.damage = (self.damage + amount.max(0)).min(self.size * self.hp_each);
+        before - self.survivors()
+    }
+}
+
+/// Attackers needed for one hit when each needs `roll_needed` on the d20.
+///
+/// A natural 20 always hits, so even an impossible roll lands one hit in
+/// twenty.
+pub fn attackers_per_hit(roll_needed: i32) -> i32 {
+    MOB_ATTACKS
+        .iter()
+        .find(|(max_roll, _)| roll_needed <= *max_roll)
+        .map_or(20, |(_, attackers)| *attackers)
+}
+
+/// Resolve a mob's attacks against one target as a batch.
+pub fn mob_attack(attackers: i32, attack_bonus: i32, target_ac: i32) -> MobAttack {
+    let attackers = attackers.max(0);
+    let roll_needed = (target_ac - attack_bonus).clamp(1, 20);
+    let attackers_per_hit = attackers_per_hit(roll_needed);
+    MobAttack {
+        attackers,
+        roll_needed,
+        attackers_per_hit,
+        hits: attackers / attackers_per_hit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attackers_per_hit_follows_dmg_table() {
+        assert_eq!(attackers_per_hit(1), 1);
+        assert_eq!(attackers_per_hit(5), 1);
+        assert_eq!(attackers_per_hit(6), 2);
+        assert_eq!(attackers_per_hit(13), 3);
+        assert_eq!(attackers_per_hit(16), 4);
+        assert_eq!(attackers_per_hit(18), 5);
+        assert_eq!(attackers_per_hit(19), 10);
+        assert_eq!(attackers_per_hit(20), 20);
+        assert_eq!(attackers_per_hit(25), 20);
+    }
+
+    #[test]
+    fn test_mob_attack() {
+        // Goblins (+4) against AC 16 need a 12: one hit per two attackers
+        let attack = mob_attack(9, 4, 16);
+        assert_eq!(attack.roll_needed, 12);
+        assert_eq!(attack.attackers_per_hit, 2);
+        assert_eq!(attack.hits, 4);
+
+        // Against AC 25 only natural 20s land
+        assert_eq!(mob_attack(30, 4, 25).hits, 1);
+        assert_eq!(mob_attack(30, 10, 8).hits, 30);
+    }
+
+    #[test]
+    fn test_pool_counts_casualties() {
+        let mut mob = MobPool::new(30, 7).unwrap();
+        assert_eq!(mob.remaining_hp(), 210);
+
+        assert_eq!(mob.apply_damage(6), 0);
+        assert_eq!(mob.apply_damage(1), 1);
+        assert_eq!(mob.apply_damage(20), 2);
+        assert_eq!(mob.survivors(), 27);
+        assert_eq!(mob.remaining_hp(), 183);
+
+        assert_eq!(mob.apply_damage(500), 27);
+        assert_eq!(mob.survivors(), 0);
+
+        assert!(MobPool::new(0, 7).is_err());
+        assert!(MobPool::new(5, 0).is_err());
+    }
+}
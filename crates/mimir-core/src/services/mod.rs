@@ -3,38 +3,218 @@
 //! Business logic services that sit between consumers (MCP, Tauri) and the DAL layer.
 //! Services encapsulate validation, transactions, and orchestration of database operations.
 
+mod action_hints;
+mod analytics;
 mod archive;
+mod background_options;
 mod asset;
+mod backup;
 mod campaign;
 pub mod catalog;
 mod character;
+mod character_image;
+mod class_resource;
+mod companion;
+mod continuity;
+mod crafting;
+mod currency;
+mod custom_field;
+mod death;
 mod document;
+mod dungeon;
+mod encounter;
+mod encounter_effect;
+mod favorite;
+mod feat_effects;
+mod forage;
+mod game_session;
+mod handout;
+mod history;
 mod homebrew;
+mod location_key;
 mod map;
+mod map_region;
+mod markdown_export;
+mod markdown_sync;
+mod memory;
+mod mob;
 mod module;
+mod module_prep;
+mod monster_embed;
+mod onboarding;
+mod owned_vehicle;
+mod party;
+mod player;
+mod quick_action;
+mod race_traits;
+mod reaction;
+mod recap;
+mod recent_item;
+mod rumor;
+mod safety_tools;
+mod secret_note;
+mod settings;
+mod settlement;
+mod stash;
+mod statblock;
+mod tag;
+mod timeline;
 mod token;
+mod trash;
+mod travel;
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+pub use action_hints::{
+    monster_action_hints, roll_action_hint, spell_action_hint, ActionHint, AttackRoll, DamageHint,
+    DamageRoll, HintKind, HintRoll, SaveHint, SpellCaster,
+};
+pub use analytics::{
+    AnalyticsService, CampaignAnalytics, CombatStats, SessionStats, UsageCount, WeeklyCount,
+    TOP_USAGE_COUNT,
+};
 pub use archive::{
-    ArchiveCounts, ArchiveData, ArchiveManifest, ArchivePreview, ArchiveService,
-    CatalogReference, CharacterWithRelated, ImportResult, MapWithRelated, ARCHIVE_EXTENSION,
+    ArchiveChecksum, ArchiveChecksums, ArchiveCounts, ArchiveData, ArchiveManifest,
+    ArchivePreview, ArchiveProgress, ArchiveService, ArchiveVerification, CatalogReference,
+    CharacterWithRelated, CloneCampaignOptions, ImportResult, MapWithRelated, ARCHIVE_EXTENSION,
 };
 pub use asset::{AssetService, UploadAssetInput};
+pub use background_options::{
+    get_background_options, BackgroundFeature, BackgroundOptions, RolledCharacteristics,
+};
+pub use backup::{
+    BackupChain, BackupEntry, BackupKind, BackupService, BACKUP_DIR, BACKUP_EXTENSION,
+};
 pub use campaign::{CampaignService, CreateCampaignInput, UpdateCampaignInput};
 pub use character::{
     AddInventoryInput, AsiOrFeat, CharacterService, CreateCharacterInput, FeatureChoices,
     FeatureReference, HpGainMethod, InvocationChoices, LevelUpRequest, LevelUpResult,
-    ManeuverChoices, SpellChanges, SpellReference, SubclassChoice, UpdateCharacterInput,
+    ManeuverChoices, RespecClass, RespecRequest, RespecResult, SpellChanges, SpellReference,
+    SubclassChoice, UpdateCharacterInput,
+};
+pub use character_image::{
+    read_character_image, render_token, token_border_color, CharacterImageService,
+    TOKEN_BORDER_COLORS, TOKEN_SIZE_PX, UNOWNED_TOKEN_BORDER_COLOR,
+};
+pub use class_resource::{ClassResourceService, RestResult};
+pub use companion::{
+    companion_statblock, AddCompanionInput, CompanionService, UpdateCompanionInput,
+};
+pub use continuity::{
+    ContinuityHook, ContinuityService, CreateModuleLinkInput, HandedOutItem, ModuleCompletion,
+    UnusedNpc, UpdateModuleLinkInput,
+};
+pub use crafting::{
+    crafting_recipe, AdvanceCraftingInput, CraftingProgress, CraftingRecipe, CraftingService,
+    StartCraftingInput,
+};
+pub use currency::{
+    consolidate_to_gold, make_change, total_cp, CurrencyAdjustment, CurrencyResult,
+    CurrencyService, COIN_VALUES_CP,
+};
+pub use custom_field::{
+    resolve_custom_field_values, CreateCustomFieldInput, CustomFieldService, CustomFieldValue,
+    UpdateCustomFieldInput,
+};
+pub use death::{
+    resurrection_cost_cp, DeathService, MarkDeadInput, MemorialEntry, ResurrectInput,
 };
-pub use document::{CreateDocumentInput, DocumentService, UpdateDocumentInput};
+pub use document::{
+    BulkDocumentMetadata, BulkItemResult, BulkResult, CreateDocumentInput, DocumentService,
+    ExternalChangeStatus, SaveOutcome, UpdateDocumentInput,
+};
+pub use dungeon::{cr_value, DungeonService, GenerateDungeonInput, GeneratedDungeon};
+pub use encounter::{
+    cr_xp, encounter_multiplier, EncounterBudget, EncounterDifficulty, EncounterEvaluation,
+    EncounterMonster,
+};
+pub use encounter_effect::{EncounterEffectInput, EncounterEffectService, LAIR_INITIATIVE};
+pub use favorite::FavoriteService;
+pub use feat_effects::{
+    feat_speed_bonus, load_feat_effects, AbilityChoice, FeatApplication, FeatEffects,
+};
+pub use forage::{
+    AddForageIngredientInput, ForageImportResult, ForageResult, ForageService,
+    UpdateForageIngredientInput,
+};
+pub use game_session::{transcript_text, GameSessionService, SessionTotals, StartSessionInput};
+pub use handout::{HandoutInput, HandoutService, RevealedHandout};
+pub use history::{CampaignHistoryService, HistoryCommit, HISTORY_DIR};
 pub use homebrew::{
     CreateHomebrewItemInput, CreateHomebrewMonsterInput, CreateHomebrewSpellInput,
     HomebrewService, UpdateHomebrewItemInput, UpdateHomebrewMonsterInput, UpdateHomebrewSpellInput,
 };
+pub use location_key::{
+    location_heading, reading_order, LocationKey, LocationKeyService, LOCATION_KEY_DOC_TYPE,
+};
 pub use map::{CreateMapInput, MapService, UpdateMapInput};
+pub use map_region::{MapRegionService, RegionShape, ResolvedMapRegion};
+pub use markdown_export::{
+    ExportedDocument, MarkdownExportResult, MarkdownExportService, MarkdownRenderer,
+    DOCUMENT_MANIFEST_FILE, EXPORT_FILES_MANIFEST, MARKDOWN_INDEX_FILE,
+};
+pub use markdown_sync::{MarkdownSyncService, SyncKind, SyncedChange};
+pub use memory::MemoryService;
+pub use mob::{attackers_per_hit, mob_attack, MobAttack, MobPool};
 pub use module::{CreateModuleInput, ModuleService, ModuleType, UpdateModuleInput};
+pub use module_prep::{ModulePrep, ModulePrepService, ModuleWithPrep, PrepTask, PrepTaskKind};
+pub use monster_embed::{
+    MonsterEmbedService, MonsterEmbedStatus, ResolvedMonsterEmbed, HOMEBREW_SOURCE,
+};
+pub use onboarding::{
+    download_library_archive, LibraryImportSummary, OnboardingProgress, OnboardingService,
+    OnboardingStatus, OnboardingStep, SAMPLE_CAMPAIGN_NAME,
+};
+pub use owned_vehicle::{
+    vehicle_statblock, AssignVehicleInput, OwnedVehicleService, PartyCarryingCapacity,
+    UpdateOwnedVehicleInput, VehicleCapacity, VehicleOwner,
+};
+pub use party::{MemberCompanion, MemberXp, PartyService, PartyXpSummary, TimelineEventInput};
+pub use player::{
+    AttendedSession, CharacterSubmission, PlayerAttendance, PlayerContact, PlayerService,
+    UpdatePlayerInput,
+};
+pub use quick_action::{
+    CreateQuickActionInput, QuickActionService, UpdateQuickActionInput,
+};
+pub use race_traits::{load_race_traits, RaceTraits, RacialAbilityChoice};
+pub use reaction::{
+    attitude_for, DispositionSource, ReactionCheck, ReactionCheckInput, ReactionService,
+    RelationshipStandings,
+};
+pub use recap::{Recap, RecapScope, RecapService, PLAYER_RECAP_DOC_TYPE, RECAP_DOC_TYPE};
+pub use recent_item::{ItemRef, RecentItemService, RECENT_ITEMS_KEPT};
+pub use rumor::{
+    RumorInput, RumorService, RumorWithHearings, TavernRumor, TavernRumorTable, TAVERN_TABLE_SIZE,
+};
+pub use safety_tools::{SafetyTools, SafetyToolsService, SAFETY_TOOLS_DOC_TYPE};
+pub use secret_note::{SecretNoteInput, SecretNoteService, SecretSlip};
+pub use settings::{
+    AppSettings, DiagnosticsSettings, DisplayProfile, DisplaySettings, PrintSettings,
+    SettingsService, CONFIG_DIR, DEFAULT_LAN_DISPLAY_PORT, DEFAULT_SLOW_COMMAND_MS, SETTINGS_FILE,
+    SETTINGS_VERSION,
+};
+pub use settlement::{
+    GenerateSettlementInput, Settlement, SettlementCulture, SettlementPin, SettlementRumor,
+    SettlementService, SettlementShop, SettlementSize, SETTLEMENT_DOC_TYPE,
+};
+pub use stash::{
+    CampaignStash, LootAssignment, LootShare, SplitLootInput, SplitLootResult, StashItemInput,
+    StashService,
+};
+pub use statblock::{convert_character_to_statblock, StatblockService, STATBLOCK_CHARACTER_KEY};
+pub use tag::TagService;
+pub use timeline::{
+    CampaignTimelineEvent, CampaignTimelineLane, CampaignTimelineService, TimelineGrouping,
+    NO_FACTION_LANE, NO_PLOTLINE_LANE,
+};
 pub use token::{CreateTokenInput, TokenResponse, TokenService, UpdateTokenInput};
+pub use trash::{TrashKind, TrashService, TrashedItem, TRASH_DIR, TRASH_RETENTION_DAYS};
+pub use travel::{
+    plan_travel, EncounterCheck, Terrain, TravelPace, TravelPhase, TravelPlan, TravelRequest,
+    Weather,
+};
 pub use catalog::{
     ActionService, BackgroundService, CatalogEntityService, CatalogTableService,
     ClassFeatureService, ClassService, ConditionService, CultService, DeityService, FeatService,
@@ -76,6 +256,39 @@ pub enum ServiceError {
 /// Result type for service operations.
 pub type ServiceResult<T> = Result<T, ServiceError>;
 
+/// What kind of failure an error represents.
+///
+/// Shared by the app and MCP server so clients can branch on the kind of
+/// failure rather than parsing error messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// The requested entity doesn't exist.
+    NotFound,
+    /// The input was invalid or broke a business rule.
+    Validation,
+    /// The operation isn't allowed in the current configuration.
+    PermissionDenied,
+    /// The database failed or couldn't be opened.
+    Database,
+    /// A file couldn't be read or written.
+    Io,
+    /// PDF generation failed.
+    Print,
+    /// An unclassified failure; only the message is meaningful.
+    Other,
+}
+
+impl ErrorKind {
+    /// Whether the user can usually fix the problem and try again.
+    pub fn is_recoverable(self) -> bool {
+        matches!(
+            self,
+            Self::NotFound | Self::Validation | Self::PermissionDenied | Self::Io
+        )
+    }
+}
+
 impl ServiceError {
     /// Create a NotFound error.
     pub fn not_found(entity_type: impl Into<String>, id: impl Into<String>) -> Self {
@@ -89,6 +302,16 @@ impl ServiceError {
     pub fn validation(message: impl Into<String>) -> Self {
         Self::Validation(message.into())
     }
+
+    /// The kind of failure this is.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::Validation(_) => ErrorKind::Validation,
+            Self::Database(_) => ErrorKind::Database,
+            Self::Io(_) => ErrorKind::Io,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +330,20 @@ mod tests {
         assert_eq!(err.to_string(), "Validation error: Name cannot be empty");
     }
 
+    #[test]
+    fn test_error_kind() {
+        assert_eq!(
+            ServiceError::not_found("Campaign", "123").kind(),
+            ErrorKind::NotFound
+        );
+        assert!(ErrorKind::Validation.is_recoverable());
+        assert!(!ErrorKind::Database.is_recoverable());
+        assert_eq!(
+            serde_json::to_string(&ErrorKind::NotFound).unwrap(),
+            "\"not_found\""
+        );
+    }
+
     #[test]
     fn test_database_error_conversion() {
         let diesel_err = diesel::result::Error::NotFound;
@@ -167,7 +167,8 @@ impl<'a> ModuleService<'a> {
 
             // Create the module
             let mut new_module =
-                NewModule::new(&module_id, &input.campaign_id, &input.name, module_number);
+                NewModule::new(&module_id, &input.campaign_id, &input.name, module_number)
+                    .with_type(input.module_type.template_key());
             if let Some(ref desc) = input.description {
                 new_module = new_module.with_description(desc);
             }
@@ -0,0 +1,517 @@
+//! Module Prep Service
+//!
+//! Builds a module's prep checklist from the template its type was created
+//! from: one task per numbered section of the overview template, plus the
+//! maps to prepare and the monster stat blocks to select.
+//!
+//! Tasks tick themselves as the work shows up in the module. A section is
+//! written once the module's overview document no longer has any of the
+//! template's `[placeholder]` text in it; maps and stat blocks count the
+//! module's maps and monsters. Work done outside Mimir can be ticked off by
+//! hand.
+
+use diesel::SqliteConnection;
+use serde::Serialize;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{Module, NewModulePrepCheck};
+use crate::services::{ServiceError, ServiceResult};
+use crate::templates::{self, ModuleTemplateInfo};
+
+/// Sections filled in after the module is played, not during prep.
+const AFTER_PLAY_SECTIONS: &[&str] = &["post-module notes"];
+
+/// Rough minutes of prep per placeholder in a template section.
+const MINUTES_PER_PLACEHOLDER: u32 = 3;
+
+/// Least time a section with anything to fill in is estimated at.
+const MIN_SECTION_MINUTES: u32 = 10;
+
+/// Rough minutes to prepare one map.
+const MINUTES_PER_MAP: u32 = 30;
+
+/// Rough minutes to pick and review one stat block.
+const MINUTES_PER_STAT_BLOCK: u32 = 5;
+
+/// What a prep task asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrepTaskKind {
+    /// Fill in a section of the module overview
+    Document,
+    /// Prepare the module's maps
+    Map,
+    /// Select monster stat blocks for the module
+    StatBlock,
+}
+
+/// One item of a module's prep checklist.
+#[derive(Debug, Clone, Serialize)]
+pub struct PrepTask {
+    /// Stable key, e.g. `document:dungeon-background` or `maps`
+    pub key: String,
+    pub kind: PrepTaskKind,
+    pub title: String,
+    /// Units of work done: placeholders filled in, maps added, or stat
+    /// blocks selected
+    pub done: u32,
+    /// Units of work the task needs
+    pub total: u32,
+    /// Ticked off by hand
+    pub checked: bool,
+    pub complete: bool,
+    pub estimated_minutes: u32,
+    pub remaining_minutes: u32,
+}
+
+/// A module's prep checklist and how far along it is.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModulePrep {
+    pub module_type: String,
+    pub tasks: Vec<PrepTask>,
+    /// Share of the estimated prep time already done, 0-100
+    pub completion_percent: u32,
+    pub estimated_minutes_remaining: u32,
+}
+
+/// A module with its prep checklist.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleWithPrep {
+    #[serde(flatten)]
+    pub module: Module,
+    pub prep: ModulePrep,
+}
+
+/// Service for module prep checklists.
+pub struct ModulePrepService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> ModulePrepService<'a> {
+    /// Create a new module prep service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Get a module with its prep checklist.
+    ///
+    /// Returns `None` if the module doesn't exist.
+    pub fn get_module_with_prep(
+        &mut self,
+        module_id: &str,
+    ) -> ServiceResult<Option<ModuleWithPrep>> {
+        let Some(module) = dal::get_module_optional(self.conn, module_id)? else {
+            return Ok(None);
+        };
+        let prep = self.build(&module)?;
+        Ok(Some(ModuleWithPrep { module, prep }))
+    }
+
+    /// Build a module's prep checklist.
+    pub fn checklist(&mut self, module_id: &str) -> ServiceResult<ModulePrep> {
+        let module = self.get_module(module_id)?;
+        self.build(&module)
+    }
+
+    /// Tick a prep task off by hand, or untick it.
+    ///
+    /// Unticking only clears the manual tick; a task whose work shows up in
+    /// the module stays complete.
+    pub fn set_task_checked(
+        &mut self,
+        module_id: &str,
+        task_key: &str,
+        checked: bool,
+    ) -> ServiceResult<ModulePrep> {
+        let module = self.get_module(module_id)?;
+        let info = template_info(&module.module_type);
+        if !prep_task_keys(info).iter().any(|key| key == task_key) {
+            return Err(ServiceError::validation(format!(
+                "'{}' is not a prep task of this module",
+                task_key
+            )));
+        }
+
+        if checked {
+            dal::insert_module_prep_check(
+                self.conn,
+                &NewModulePrepCheck::new(module_id, task_key),
+            )?;
+        } else {
+            dal::delete_module_prep_check(self.conn, module_id, task_key)?;
+        }
+        self.build(&module)
+    }
+
+    fn get_module(&mut self, id: &str) -> ServiceResult<Module> {
+        dal::get_module_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Module", id))
+    }
+
+    fn build(&mut self, module: &Module) -> ServiceResult<ModulePrep> {
+        let info = template_info(&module.module_type);
+        let checked: Vec<String> = dal::list_module_prep_checks(self.conn, &module.id)?
+            .into_iter()
+            .map(|c| c.task_key)
+            .collect();
+        let overview = dal::list_module_documents(self.conn, &module.id)?
+            .into_iter()
+            .find(|d| d.doc_type == "module_overview")
+            .map(|d| d.content);
+        let map_count = dal::list_module_maps(self.conn, &module.id)?.len() as u32;
+        let stat_block_count = dal::list_module_monsters(self.conn, &module.id)?.len() as u32;
+
+        let mut tasks = Vec::new();
+        let written = overview.as_deref().map(sections).unwrap_or_default();
+        for (title, body) in prep_sections(info.content) {
+            let total = count_placeholders(body);
+            let remaining = match overview {
+                // No overview document: nothing has been written yet
+                None => total,
+                // A section the DM removed has nothing left to fill in
+                Some(_) => written
+                    .iter()
+                    .find(|(t, _)| t.eq_ignore_ascii_case(title))
+                    .map_or(0, |(_, b)| count_placeholders(b).min(total)),
+            };
+            tasks.push(PrepTask {
+                key: section_key(title),
+                kind: PrepTaskKind::Document,
+                title: title.to_string(),
+                done: total - remaining,
+                total,
+                checked: false,
+                complete: false,
+                estimated_minutes: (total * MINUTES_PER_PLACEHOLDER).max(MIN_SECTION_MINUTES),
+                remaining_minutes: 0,
+            });
+        }
+        if info.maps > 0 {
+            tasks.push(PrepTask {
+                key: "maps".to_string(),
+                kind: PrepTaskKind::Map,
+                title: format!("Prepare {} map(s)", info.maps),
+                done: map_count.min(info.maps),
+                total: info.maps,
+                checked: false,
+                complete: false,
+                estimated_minutes: info.maps * MINUTES_PER_MAP,
+                remaining_minutes: 0,
+            });
+        }
+        if info.stat_blocks > 0 {
+            tasks.push(PrepTask {
+                key: "stat_blocks".to_string(),
+                kind: PrepTaskKind::StatBlock,
+                title: format!("Select {} stat block(s)", info.stat_blocks),
+                done: stat_block_count.min(info.stat_blocks),
+                total: info.stat_blocks,
+                checked: false,
+                complete: false,
+                estimated_minutes: info.stat_blocks * MINUTES_PER_STAT_BLOCK,
+                remaining_minutes: 0,
+            });
+        }
+
+        for task in &mut tasks {
+            task.checked = checked.contains(&task.key);
+            task.complete = task.checked || task.done >= task.total;
+            task.remaining_minutes = if task.complete {
+                0
+            } else {
+                task.estimated_minutes * (task.total - task.done) / task.total
+            };
+        }
+
+        let estimated: u32 = tasks.iter().map(|t| t.estimated_minutes).sum();
+        let remaining: u32 = tasks.iter().map(|t| t.remaining_minutes).sum();
+        let completion_percent = if estimated == 0 {
+            100
+        } else {
+            (estimated - remaining) * 100 / estimated
+        };
+
+        Ok(ModulePrep {
+            module_type: info.module_type.to_string(),
+            tasks,
+            completion_percent,
+            estimated_minutes_remaining: remaining,
+        })
+    }
+}
+
+/// The template a module type was created from; unknown types fall back to
+/// the general template, as module creation does.
+fn template_info(module_type: &str) -> &'static ModuleTemplateInfo {
+    templates::get_module_template_info(module_type)
+        .or_else(|| templates::get_module_template_info("general"))
+        .expect("General template must exist")
+}
+
+/// Keys of every task in a checklist built from `info`.
+fn prep_task_keys(info: &ModuleTemplateInfo) -> Vec<String> {
+    let mut keys: Vec<String> = prep_sections(info.content)
+        .into_iter()
+        .map(|(title, _)| section_key(title))
+        .collect();
+    if info.maps > 0 {
+        keys.push("maps".to_string());
+    }
+    if info.stat_blocks > 0 {
+        keys.push("stat_blocks".to_string());
+    }
+    keys
+}
+
+/// Template sections with something to fill in before play.
+fn prep_sections(template: &str) -> Vec<(&str, &str)> {
+    sections(template)
+        .into_iter()
+        .filter(|(title, _)| !AFTER_PLAY_SECTIONS.contains(&title.to_lowercase().as_str()))
+        .filter(|(_, body)| count_placeholders(body) > 0)
+        .collect()
+}
+
+/// Split markdown into its numbered `## N. Title` sections, as
+/// (title, body) pairs. Each body runs to the next level-two heading.
+fn sections(markdown: &str) -> Vec<(&str, &str)> {
+    let mut found = Vec::new();
+    let mut current: Option<(&str, usize)> = None;
+    let mut offset = 0;
+    for line in markdown.split_inclusive('\n') {
+        if let Some(heading) = line.strip_prefix("## ") {
+            if let Some((title, start)) = current.take() {
+                found.push((title, &markdown[start..offset]));
+            }
+            current = numbered_title(heading.trim()).map(|title| (title, offset + line.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((title, start)) = current {
+        found.push((title, &markdown[start..]));
+    }
+    found
+}
+
+/// The title of a `N. Title` heading, or `None` if it isn't numbered.
+fn numbered_title(heading: &str) -> Option<&str> {
+    let (number, title) = heading.split_once(". ")?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(title.trim())
+}
+
+fn section_key(title: &str) -> String {
+    let slug: Vec<String> = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_ascii_lowercase())
+        .collect();
+    format!("document:{}", slug.join("-"))
+}
+
+/// Count `[placeholder]` spans, leaving out markdown links, `[[wiki]]`
+/// links, and `[ ]` / `[x]` checkboxes.
+fn count_placeholders(text: &str) -> u32 {
+    let mut count = 0;
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find(']') else {
+            break;
+        };
+        let inner = &after[..end];
+        if inner.contains('[') {
+            rest = after;
+            continue;
+        }
+        let tail = &after[end + 1..];
+        let is_placeholder = !inner.trim().is_empty()
+            && !inner.contains('\n')
+            && !matches!(inner, "x" | "X")
+            && !tail.starts_with('(')
+            && !tail.starts_with(']');
+        if is_placeholder {
+            count += 1;
+        }
+        rest = tail;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_module_monster, list_module_documents};
+    use crate::models::campaign::{NewCampaign, NewModuleMonster};
+    use crate::services::{
+        CreateModuleInput, DocumentService, ModuleService, ModuleType, UpdateDocumentInput,
+    };
+    use crate::test_utils::setup_test_db;
+    use uuid::Uuid;
+
+    fn create_module(conn: &mut SqliteConnection, module_type: ModuleType) -> Module {
+        let campaign_id = Uuid::new_v4().to_string();
+        insert_campaign(conn, &NewCampaign::new(&campaign_id, "Test Campaign")).unwrap();
+        ModuleService::new(conn)
+            .create(CreateModuleInput::new(&campaign_id, "Goblin Caves").with_type(module_type))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_count_placeholders() {
+        assert_eq!(count_placeholders("**Pitch:** [One sentence]"), 1);
+        assert_eq!(count_placeholders("[A] → [B] → [C]"), 3);
+        assert_eq!(count_placeholders("See [the map](maps/cave.png)"), 0);
+        assert_eq!(count_placeholders("- [ ] Clue found\n- [x] Clue placed"), 0);
+        assert_eq!(count_placeholders("Ask [[Sildar]] about it"), 0);
+        assert_eq!(count_placeholders("No placeholders here"), 0);
+    }
+
+    #[test]
+    fn test_sections() {
+        let markdown =
+            "# Title\n\n## 1. Overview\n[Pitch]\n\n## Notes\nfree text\n\n## 2. Map\n[Rooms]\n";
+        let found = sections(markdown);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], ("Overview", "[Pitch]\n\n"));
+        assert_eq!(found[1], ("Map", "[Rooms]\n"));
+        assert_eq!(
+            section_key("Dungeon Map & Key Areas"),
+            "document:dungeon-map-key-areas"
+        );
+    }
+
+    #[test]
+    fn test_new_module_checklist() {
+        let mut conn = setup_test_db();
+        let module = create_module(&mut conn, ModuleType::Dungeon);
+        assert_eq!(module.module_type, "dungeon");
+
+        let prep = ModulePrepService::new(&mut conn)
+            .checklist(&module.id)
+            .unwrap();
+        assert_eq!(prep.module_type, "dungeon");
+        assert_eq!(prep.completion_percent, 0);
+        assert!(prep.estimated_minutes_remaining > 0);
+        assert!(prep.tasks.iter().all(|t| !t.complete));
+        assert!(prep
+            .tasks
+            .iter()
+            .any(|t| t.key == "document:dungeon-background"));
+        assert!(!prep
+            .tasks
+            .iter()
+            .any(|t| t.key == "document:post-module-notes"));
+        let maps = prep
+            .tasks
+            .iter()
+            .find(|t| t.kind == PrepTaskKind::Map)
+            .unwrap();
+        assert_eq!(maps.total, 2);
+    }
+
+    #[test]
+    fn test_checklist_follows_module_contents() {
+        let mut conn = setup_test_db();
+        let module = create_module(&mut conn, ModuleType::General);
+        let before = ModulePrepService::new(&mut conn)
+            .checklist(&module.id)
+            .unwrap();
+
+        // Fill in the Overview section of the overview document
+        let overview = list_module_documents(&mut conn, &module.id)
+            .unwrap()
+            .into_iter()
+            .find(|d| d.doc_type == "module_overview")
+            .unwrap();
+        let filled: Vec<String> = sections(&overview.content)
+            .into_iter()
+            .map(|(title, body)| {
+                let body = if title == "Overview" {
+                    "Goblins raid the road.\n".to_string()
+                } else {
+                    body.to_string()
+                };
+                format!("## 1. {}\n{}", title, body)
+            })
+            .collect();
+        DocumentService::new(&mut conn)
+            .update(
+                &overview.id,
+                UpdateDocumentInput::set_content(filled.concat()),
+            )
+            .unwrap();
+        for i in 0..3 {
+            insert_module_monster(
+                &mut conn,
+                &NewModuleMonster::new(&format!("mm-{}", i), &module.id, "Goblin", "MM"),
+            )
+            .unwrap();
+        }
+
+        let prep = ModulePrepService::new(&mut conn)
+            .checklist(&module.id)
+            .unwrap();
+        let task = |key: &str| prep.tasks.iter().find(|t| t.key == key).unwrap();
+        assert!(task("document:overview").complete);
+        assert!(!task("document:locations").complete);
+        assert!(task("stat_blocks").complete);
+        assert!(!task("maps").complete);
+        assert!(prep.completion_percent > before.completion_percent);
+        assert!(prep.estimated_minutes_remaining < before.estimated_minutes_remaining);
+    }
+
+    #[test]
+    fn test_tick_task_by_hand() {
+        let mut conn = setup_test_db();
+        let module = create_module(&mut conn, ModuleType::Mystery);
+        let mut service = ModulePrepService::new(&mut conn);
+
+        let prep = service.set_task_checked(&module.id, "maps", true).unwrap();
+        let maps = prep.tasks.iter().find(|t| t.key == "maps").unwrap();
+        assert!(maps.checked && maps.complete);
+        assert_eq!(maps.remaining_minutes, 0);
+
+        let prep = service.set_task_checked(&module.id, "maps", false).unwrap();
+        assert!(
+            !prep
+                .tasks
+                .iter()
+                .find(|t| t.key == "maps")
+                .unwrap()
+                .complete
+        );
+
+        assert!(matches!(
+            service.set_task_checked(&module.id, "document:nope", true),
+            Err(ServiceError::Validation(_))
+        ));
+        assert!(matches!(
+            service.set_task_checked("missing", "maps", true),
+            Err(ServiceError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_get_module_with_prep() {
+        let mut conn = setup_test_db();
+        let module = create_module(&mut conn, ModuleType::Political);
+        let mut service = ModulePrepService::new(&mut conn);
+
+        let with_prep = service.get_module_with_prep(&module.id).unwrap().unwrap();
+        assert_eq!(with_prep.module.id, module.id);
+        // Political intrigue doesn't call for maps
+        assert!(!with_prep
+            .prep
+            .tasks
+            .iter()
+            .any(|t| t.kind == PrepTaskKind::Map));
+        assert!(service.get_module_with_prep("missing").unwrap().is_none());
+
+        let json = serde_json::to_value(&with_prep).unwrap();
+        assert_eq!(json["name"], "Goblin Caves");
+        assert!(json["prep"]["tasks"].is_array());
+    }
+}
@@ -0,0 +1,295 @@
+//! Monster Embed Service
+//!
+//! Resolves `{{monster: Goblin|MM}}` embeds in campaign documents to monster
+//! data. Embeds are resolved when a document is rendered, not when it is
+//! saved, so a stat block always reflects the current catalog and homebrew.
+//! Monsters from a source the campaign cannot use resolve to a fallback
+//! instead of an error, so one disabled book never breaks an export.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::CampaignHomebrewMonster;
+use crate::models::catalog::Monster;
+use crate::services::{CampaignService, ServiceError, ServiceResult};
+
+/// Source label given to campaign homebrew monsters.
+pub const HOMEBREW_SOURCE: &str = "Homebrew";
+
+/// How an embed resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonsterEmbedStatus {
+    /// Monster data was found.
+    Resolved,
+    /// The monster exists, but only in a source that is disabled or not
+    /// allowed in this campaign.
+    SourceDisabled,
+    /// No monster by that name exists in the catalog or the campaign's homebrew.
+    NotFound,
+}
+
+/// A monster embed resolved against the catalog and campaign homebrew.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedMonsterEmbed {
+    /// Monster name, as found or as written in the embed
+    pub name: String,
+    /// Source the monster came from, or the one the embed asked for
+    pub source: Option<String>,
+    pub status: MonsterEmbedStatus,
+    /// 5etools monster JSON, present when resolved
+    pub data: Option<Value>,
+}
+
+impl ResolvedMonsterEmbed {
+    fn resolved(name: String, source: String, data: Value) -> Self {
+        Self {
+            name,
+            source: Some(source),
+            status: MonsterEmbedStatus::Resolved,
+            data: Some(data),
+        }
+    }
+
+    fn unresolved(name: &str, source: Option<&str>, status: MonsterEmbedStatus) -> Self {
+        Self {
+            name: name.to_string(),
+            source: source.map(String::from),
+            status,
+            data: None,
+        }
+    }
+
+    /// Text shown in place of a stat block that could not be resolved.
+    pub fn fallback_message(&self) -> Option<String> {
+        match self.status {
+            MonsterEmbedStatus::Resolved => None,
+            MonsterEmbedStatus::SourceDisabled => Some(format!(
+                "Stat block unavailable: {} is not enabled for this campaign",
+                self.source.as_deref().unwrap_or("its source")
+            )),
+            MonsterEmbedStatus::NotFound => {
+                Some("Stat block unavailable: monster not found".to_string())
+            }
+        }
+    }
+}
+
+/// Whether an embed source refers to campaign homebrew.
+fn is_homebrew_source(source: &str) -> bool {
+    source.eq_ignore_ascii_case("HB") || source.eq_ignore_ascii_case(HOMEBREW_SOURCE)
+}
+
+/// Service for resolving monster embeds.
+pub struct MonsterEmbedService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> MonsterEmbedService<'a> {
+    /// Create a new monster embed service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Resolve one embed for a campaign.
+    ///
+    /// Without a source, campaign homebrew is checked first and then every
+    /// catalog source the campaign can use. A source of `HB` or `Homebrew`
+    /// only checks homebrew.
+    pub fn resolve(
+        &mut self,
+        campaign_id: &str,
+        name: &str,
+        source: Option<&str>,
+    ) -> ServiceResult<ResolvedMonsterEmbed> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ServiceError::validation("Monster embed needs a name"));
+        }
+        let source = source.map(str::trim).filter(|s| !s.is_empty());
+
+        let homebrew_only = source.is_some_and(is_homebrew_source);
+        if source.is_none() || homebrew_only {
+            if let Some(monster) =
+                dal::get_campaign_homebrew_monster_by_name(self.conn, campaign_id, name)?
+            {
+                return homebrew_embed(monster);
+            }
+            if homebrew_only {
+                return Ok(ResolvedMonsterEmbed::unresolved(
+                    name,
+                    Some(HOMEBREW_SOURCE),
+                    MonsterEmbedStatus::NotFound,
+                ));
+            }
+        }
+
+        let catalog_sources = catalog_dal::list_sources(self.conn)?;
+        let allowed = CampaignService::new(self.conn).search_sources(campaign_id)?;
+        let usable = |code: &str| {
+            catalog_sources
+                .iter()
+                .any(|s| s.code == code && s.is_enabled())
+                && allowed
+                    .as_ref()
+                    .map_or(true, |codes| codes.iter().any(|c| c == code))
+        };
+
+        let candidates = match source {
+            Some(source) => {
+                // Accept source codes in any case, e.g. `mm` for `MM`
+                let code = catalog_sources
+                    .iter()
+                    .find(|s| s.code.eq_ignore_ascii_case(source))
+                    .map_or(source, |s| s.code.as_str());
+                catalog_dal::get_monster_by_name(self.conn, name, code)?
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            }
+            None => catalog_dal::list_monsters_by_names(self.conn, &[name.to_string()])?,
+        };
+
+        if let Some(monster) = candidates.iter().find(|m| usable(&m.source)) {
+            return catalog_embed(monster);
+        }
+        Ok(match candidates.first() {
+            Some(monster) => ResolvedMonsterEmbed::unresolved(
+                &monster.name,
+                Some(&monster.source),
+                MonsterEmbedStatus::SourceDisabled,
+            ),
+            None => ResolvedMonsterEmbed::unresolved(name, source, MonsterEmbedStatus::NotFound),
+        })
+    }
+}
+
+fn homebrew_embed(monster: CampaignHomebrewMonster) -> ServiceResult<ResolvedMonsterEmbed> {
+    let mut data: Value = serde_json::from_str(&monster.data).map_err(|e| {
+        ServiceError::validation(format!("Invalid data for monster {}: {}", monster.name, e))
+    })?;
+    if let Some(obj) = data.as_object_mut() {
+        obj.insert("name".to_string(), Value::String(monster.name.clone()));
+        obj.insert(
+            "source".to_string(),
+            Value::String(HOMEBREW_SOURCE.to_string()),
+        );
+    }
+    Ok(ResolvedMonsterEmbed::resolved(
+        monster.name,
+        HOMEBREW_SOURCE.to_string(),
+        data,
+    ))
+}
+
+fn catalog_embed(monster: &Monster) -> ServiceResult<ResolvedMonsterEmbed> {
+    Ok(ResolvedMonsterEmbed::resolved(
+        monster.name.clone(),
+        monster.source.clone(),
+        monster.parse_data().map_err(|e| {
+            ServiceError::validation(format!("Invalid data for monster {}: {}", monster.name, e))
+        })?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_campaign_source};
+    use crate::dal::catalog::{insert_monster, set_enabled};
+    use crate::models::campaign::{NewCampaign, NewCampaignSource};
+    use crate::models::catalog::NewMonster;
+    use crate::services::{CreateHomebrewMonsterInput, HomebrewService};
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        for (name, source) in [("Goblin", "MM"), ("Goblin Boss", "VGM")] {
+            let data = json!({ "name": name, "source": source, "cr": "1/4" });
+            insert_monster(conn, &NewMonster::new(name, source, &data.to_string())).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_resolve_catalog_monster() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+
+        let embed = MonsterEmbedService::new(&mut conn)
+            .resolve("camp-1", "goblin", Some("mm"))
+            .unwrap();
+        assert_eq!(embed.status, MonsterEmbedStatus::Resolved);
+        assert_eq!(embed.name, "Goblin");
+        assert_eq!(embed.source.as_deref(), Some("MM"));
+        assert_eq!(embed.data.unwrap()["cr"], "1/4");
+    }
+
+    #[test]
+    fn test_resolve_disabled_source_falls_back() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        set_enabled(&mut conn, "MM", false).unwrap();
+
+        let embed = MonsterEmbedService::new(&mut conn)
+            .resolve("camp-1", "Goblin", Some("MM"))
+            .unwrap();
+        assert_eq!(embed.status, MonsterEmbedStatus::SourceDisabled);
+        assert!(embed.data.is_none());
+        assert!(embed.fallback_message().unwrap().contains("MM"));
+    }
+
+    #[test]
+    fn test_resolve_respects_campaign_sources() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        insert_campaign_source(&mut conn, &NewCampaignSource::new("cs-1", "camp-1", "MM")).unwrap();
+
+        let mut service = MonsterEmbedService::new(&mut conn);
+        let boss = service.resolve("camp-1", "Goblin Boss", None).unwrap();
+        assert_eq!(boss.status, MonsterEmbedStatus::SourceDisabled);
+        assert_eq!(boss.source.as_deref(), Some("VGM"));
+
+        let goblin = service.resolve("camp-1", "Goblin", None).unwrap();
+        assert_eq!(goblin.status, MonsterEmbedStatus::Resolved);
+    }
+
+    #[test]
+    fn test_resolve_prefers_homebrew() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        HomebrewService::new(&mut conn)
+            .create_monster(CreateHomebrewMonsterInput {
+                campaign_id: "camp-1".to_string(),
+                name: "Goblin".to_string(),
+                data: Some(json!({ "cr": "1" }).to_string()),
+                cr: None,
+                creature_type: None,
+                size: None,
+                cloned_from_name: None,
+                cloned_from_source: None,
+            })
+            .unwrap();
+
+        let mut service = MonsterEmbedService::new(&mut conn);
+        let embed = service.resolve("camp-1", "Goblin", None).unwrap();
+        assert_eq!(embed.source.as_deref(), Some(HOMEBREW_SOURCE));
+        assert_eq!(embed.data.unwrap()["source"], HOMEBREW_SOURCE);
+
+        let missing = service
+            .resolve("camp-1", "Goblin King", Some("HB"))
+            .unwrap();
+        assert_eq!(missing.status, MonsterEmbedStatus::NotFound);
+    }
+
+    #[test]
+    fn test_resolve_requires_name() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+
+        let result = MonsterEmbedService::new(&mut conn).resolve("camp-1", "  ", None);
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+    }
+}
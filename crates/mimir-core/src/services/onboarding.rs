@@ -0,0 +1,395 @@
+//! Onboarding Service
+//!
+//! First-run setup for a new install: tells whether the catalog library and
+//! campaign list are still empty, fills the library with SRD content from a
+//! 5etools archive (downloaded or picked from disk), and creates a sample
+//! campaign with a module, prep documents, and a ready-to-run battle map.
+//!
+//! Each step reports [`OnboardingProgress`] through a callback so the UI can
+//! show where it is.
+
+use diesel::SqliteConnection;
+use serde::Serialize;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::dal::catalog as catalog_dal;
+use crate::import::CatalogImportService;
+use crate::models::campaign::Campaign;
+use crate::services::{
+    CampaignService, CreateCampaignInput, CreateDocumentInput, CreateMapInput, CreateModuleInput,
+    DocumentService, MapService, ModuleService, ModuleType, ServiceError, ServiceResult,
+    UpdateCampaignInput,
+};
+
+/// Name of the sample campaign, used to find it again.
+pub const SAMPLE_CAMPAIGN_NAME: &str = "Sample Campaign: The Goblin Hideout";
+
+/// Battle map of the sample module, built into the app so it works without
+/// any files on disk.
+const SAMPLE_MAP_UVTT: &[u8] = include_bytes!("../seed/assets/goblin-hideout.dd2vtt");
+
+const SAMPLE_MODULE_NAME: &str = "The Goblin Hideout";
+
+/// Prep documents added to the sample module, as (title, markdown) pairs.
+const SAMPLE_MODULE_DOCUMENTS: &[(&str, &str)] = &[
+    (
+        "Adventure Overview",
+        "# The Goblin Hideout\n\n\
+         A short dungeon for four 1st-level characters. Goblins have been \
+         raiding the trail to town and dragging their loot back to a cave \
+         above a stream. The party is hired to put a stop to it.\n\n\
+         ## Hooks\n\n\
+         - A merchant's wagon was ambushed; the goblins took his strongbox.\n\
+         - The town guard offers 10 gp per goblin ear.\n\
+         - A missing scout was last seen heading up the stream.\n",
+    ),
+    (
+        "Session 1 Prep",
+        "# Session 1 Prep\n\n\
+         ## Strong Start\n\n\
+         The party finds the ambushed wagon, still smoking, with goblin \
+         tracks leading toward the hills.\n\n\
+         ## Scenes\n\n\
+         1. Follow the tracks to the cave mouth.\n\
+         2. Deal with the lookouts in the thicket.\n\
+         3. Explore the cave and confront the goblin boss.\n\n\
+         ## Secrets and Clues\n\n\
+         - The goblins answer to a bugbear who takes the best of the loot.\n\
+         - The missing scout is held captive deeper in the cave.\n\
+         - The stream can be followed into the cave, bypassing the lookouts.\n",
+    ),
+    (
+        "Using This Sample",
+        "# Using This Sample\n\n\
+         This campaign shows how Mimir fits together:\n\n\
+         - **Documents** like this one hold your prep and notes.\n\
+         - **Modules** group the prep, maps, and monsters for an adventure.\n\
+         - **Maps** open on the DM screen and can be sent to the player display.\n\n\
+         Edit or delete anything here, or archive the campaign when you're ready \
+         to start your own.\n",
+    ),
+];
+
+/// Whether this install still needs first-run setup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OnboardingStatus {
+    /// No catalog sources (books) have been imported
+    pub library_empty: bool,
+    /// Catalog sources imported so far
+    pub source_count: usize,
+    /// Campaigns, including archived ones
+    pub campaign_count: usize,
+    /// ID of the sample campaign, if it has been created
+    pub sample_campaign_id: Option<String>,
+}
+
+impl OnboardingStatus {
+    /// Whether to offer the first-run flow: nothing imported or created yet.
+    pub fn needs_onboarding(&self) -> bool {
+        self.library_empty && self.campaign_count == 0
+    }
+}
+
+/// A step of the first-run setup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    DownloadLibrary,
+    ImportLibrary,
+    CreateCampaign,
+    CreateDocuments,
+    CreateMap,
+    Done,
+}
+
+/// Progress of the first-run setup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OnboardingProgress {
+    pub step: OnboardingStep,
+    pub message: String,
+    /// Work done in this step (bytes downloaded, documents created, ...)
+    pub completed: u64,
+    /// Total work in this step, when known
+    pub total: Option<u64>,
+}
+
+impl OnboardingProgress {
+    fn new(step: OnboardingStep, message: impl Into<String>) -> Self {
+        Self {
+            step,
+            message: message.into(),
+            completed: 0,
+            total: None,
+        }
+    }
+
+    fn with_count(mut self, completed: u64, total: Option<u64>) -> Self {
+        self.completed = completed;
+        self.total = total;
+        self
+    }
+}
+
+/// What importing the SRD library added.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LibraryImportSummary {
+    pub sources_imported: usize,
+    pub sources_failed: usize,
+    pub total_entities: usize,
+}
+
+/// Service for first-run setup.
+pub struct OnboardingService<'a> {
+    conn: &'a mut SqliteConnection,
+    app_data_dir: PathBuf,
+}
+
+impl<'a> OnboardingService<'a> {
+    /// Create a new onboarding service.
+    pub fn new(conn: &'a mut SqliteConnection, app_data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            conn,
+            app_data_dir: app_data_dir.into(),
+        }
+    }
+
+    /// Whether the library and campaign list are still empty.
+    pub fn status(&mut self) -> ServiceResult<OnboardingStatus> {
+        let source_count = catalog_dal::list_sources(self.conn)?.len();
+        let campaigns = CampaignService::new(self.conn).list(true)?;
+        Ok(OnboardingStatus {
+            library_empty: source_count == 0,
+            source_count,
+            campaign_count: campaigns.len(),
+            sample_campaign_id: campaigns
+                .into_iter()
+                .find(|c| c.name == SAMPLE_CAMPAIGN_NAME)
+                .map(|c| c.id),
+        })
+    }
+
+    /// Import the SRD and Basic Rules content of a 5etools archive
+    /// (`.tar.gz`) into the catalog library.
+    pub fn import_srd_library(
+        &mut self,
+        archive_path: &Path,
+        mut progress: impl FnMut(OnboardingProgress),
+    ) -> ServiceResult<LibraryImportSummary> {
+        if !archive_path.exists() {
+            return Err(ServiceError::validation(format!(
+                "File not found: {}",
+                archive_path.display()
+            )));
+        }
+        progress(OnboardingProgress::new(
+            OnboardingStep::ImportLibrary,
+            "Importing SRD content",
+        ));
+
+        let result = CatalogImportService::new(self.conn)
+            .with_srd_only()
+            .import_from_tarball(archive_path)
+            .map_err(|e| ServiceError::validation(format!("Import failed: {}", e)))?;
+        info!("SRD library imported: {}", result.summary());
+
+        progress(
+            OnboardingProgress::new(
+                OnboardingStep::ImportLibrary,
+                format!("Imported {} entries", result.total_entities),
+            )
+            .with_count(
+                result.total_entities as u64,
+                Some(result.total_entities as u64),
+            ),
+        );
+        Ok(LibraryImportSummary {
+            sources_imported: result.sources_imported.len(),
+            sources_failed: result.sources_failed.len(),
+            total_entities: result.total_entities,
+        })
+    }
+
+    /// Create the sample campaign, or return it if it already exists.
+    ///
+    /// The campaign is limited to SRD content, and gets a module with prep
+    /// documents and a battle map alongside the usual campaign documents.
+    pub fn create_sample_campaign(
+        &mut self,
+        mut progress: impl FnMut(OnboardingProgress),
+    ) -> ServiceResult<Campaign> {
+        if let Some(id) = self.status()?.sample_campaign_id {
+            progress(OnboardingProgress::new(
+                OnboardingStep::Done,
+                "The sample campaign already exists",
+            ));
+            return CampaignService::new(self.conn)
+                .get(&id)?
+                .ok_or_else(|| ServiceError::not_found("Campaign", &id));
+        }
+
+        progress(OnboardingProgress::new(
+            OnboardingStep::CreateCampaign,
+            "Creating the sample campaign",
+        ));
+        let campaign = CampaignService::new(self.conn).create(
+            CreateCampaignInput::new(SAMPLE_CAMPAIGN_NAME)
+                .with_description("A short goblin-cave adventure to try Mimir with"),
+        )?;
+        let campaign = CampaignService::new(self.conn)
+            .update(&campaign.id, UpdateCampaignInput::set_srd_only(true))?;
+        let module = ModuleService::new(self.conn).create(
+            CreateModuleInput::new(&campaign.id, SAMPLE_MODULE_NAME)
+                .with_description("Goblins raiding the trail have a hideout in the hills")
+                .with_type(ModuleType::Dungeon),
+        )?;
+
+        let total = SAMPLE_MODULE_DOCUMENTS.len() as u64;
+        for (index, (title, content)) in SAMPLE_MODULE_DOCUMENTS.iter().enumerate() {
+            DocumentService::new(self.conn).create(
+                CreateDocumentInput::for_module(&campaign.id, &module.id, *title)
+                    .with_content(*content),
+            )?;
+            progress(
+                OnboardingProgress::new(OnboardingStep::CreateDocuments, *title)
+                    .with_count(index as u64 + 1, Some(total)),
+            );
+        }
+
+        progress(OnboardingProgress::new(
+            OnboardingStep::CreateMap,
+            "Adding the battle map",
+        ));
+        MapService::new(self.conn, &self.app_data_dir).create(
+            CreateMapInput::for_module(
+                &campaign.id,
+                &module.id,
+                SAMPLE_MODULE_NAME,
+                "goblin-hideout.dd2vtt",
+                SAMPLE_MAP_UVTT.to_vec(),
+            )
+            .with_description("The cave above the stream"),
+        )?;
+
+        info!(campaign_id = %campaign.id, "Sample campaign created");
+        progress(OnboardingProgress::new(
+            OnboardingStep::Done,
+            "The sample campaign is ready",
+        ));
+        Ok(campaign)
+    }
+}
+
+/// Download a 5etools archive from `url` to `dest`, reporting bytes received.
+pub async fn download_library_archive(
+    url: &str,
+    dest: &Path,
+    mut progress: impl FnMut(OnboardingProgress),
+) -> ServiceResult<()> {
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        return Err(ServiceError::validation(format!(
+            "Invalid download URL '{}'. Expected an http or https address",
+            url
+        )));
+    }
+    let download_error =
+        |e: reqwest::Error| ServiceError::validation(format!("Download failed: {}", e));
+
+    let mut response = reqwest::get(url)
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(download_error)?;
+    let total = response.content_length();
+
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::File::create(dest)?;
+    let mut received = 0u64;
+    progress(
+        OnboardingProgress::new(OnboardingStep::DownloadLibrary, "Downloading")
+            .with_count(0, total),
+    );
+    while let Some(chunk) = response.chunk().await.map_err(download_error)? {
+        file.write_all(&chunk)?;
+        received += chunk.len() as u64;
+        progress(
+            OnboardingProgress::new(OnboardingStep::DownloadLibrary, "Downloading")
+                .with_count(received, total),
+        );
+    }
+    file.flush()?;
+
+    info!(url = %url, bytes = received, "Library archive downloaded");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_status_of_empty_install() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let status = OnboardingService::new(&mut conn, dir.path())
+            .status()
+            .unwrap();
+        assert!(status.library_empty);
+        assert_eq!(status.campaign_count, 0);
+        assert!(status.sample_campaign_id.is_none());
+        assert!(status.needs_onboarding());
+    }
+
+    #[test]
+    fn test_create_sample_campaign() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let mut steps = Vec::new();
+
+        let campaign = OnboardingService::new(&mut conn, dir.path())
+            .create_sample_campaign(|p| steps.push(p.step))
+            .unwrap();
+        assert_eq!(campaign.name, SAMPLE_CAMPAIGN_NAME);
+        assert!(campaign.is_srd_only());
+        assert_eq!(steps.first(), Some(&OnboardingStep::CreateCampaign));
+        assert_eq!(steps.last(), Some(&OnboardingStep::Done));
+
+        let modules = ModuleService::new(&mut conn)
+            .list_for_campaign(&campaign.id)
+            .unwrap();
+        assert_eq!(modules.len(), 1);
+        let documents = DocumentService::new(&mut conn)
+            .list_for_module(&modules[0].id)
+            .unwrap();
+        for (title, _) in SAMPLE_MODULE_DOCUMENTS {
+            assert!(documents.iter().any(|d| d.title == *title));
+        }
+        let maps = MapService::new(&mut conn, dir.path())
+            .list_for_module(&modules[0].id)
+            .unwrap();
+        assert_eq!(maps.len(), 1);
+
+        // Running it again returns the same campaign
+        let mut service = OnboardingService::new(&mut conn, dir.path());
+        let again = service.create_sample_campaign(|_| {}).unwrap();
+        assert_eq!(again.id, campaign.id);
+        let status = service.status().unwrap();
+        assert_eq!(status.campaign_count, 1);
+        assert_eq!(status.sample_campaign_id, Some(campaign.id));
+        assert!(!status.needs_onboarding());
+    }
+
+    #[test]
+    fn test_import_requires_archive() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let result = OnboardingService::new(&mut conn, dir.path())
+            .import_srd_library(&dir.path().join("missing.tar.gz"), |_| {});
+        assert!(result.is_err());
+    }
+}
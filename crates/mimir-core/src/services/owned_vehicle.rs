@@ -0,0 +1,557 @@
+//! Owned Vehicle Service
+//!
+//! Business logic for mounts and vehicles owned by characters and parties.
+//! Capacity comes from the catalog entry: vehicles carry `capCargo` tons and
+//! `capCrew` + `capPassenger` people, mounts carry their `carryingCapacity`
+//! in pounds and one rider. A party's capacity covers the vehicles it owns
+//! and those of its members, checked against what the members carry.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    Character, NewOwnedVehicle, OwnedVehicle, Party, UpdateOwnedVehicle, VehicleKind,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Pounds per ton, for vehicle cargo capacity.
+const POUNDS_PER_TON: f64 = 2000.0;
+
+/// Item source used for campaign homebrew items in inventories.
+const HOMEBREW_SOURCE: &str = "HB";
+
+/// Who owns a mount or vehicle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VehicleOwner {
+    /// A single character
+    Character { character_id: String },
+    /// A whole party
+    Party { party_id: String },
+}
+
+/// Input for assigning a mount or vehicle from the catalog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignVehicleInput {
+    /// New owner
+    pub owner: VehicleOwner,
+    /// Vehicle (vehicle catalog) or mount (item catalog, type MNT)
+    pub vehicle_kind: VehicleKind,
+    /// Catalog entry name
+    pub catalog_name: String,
+    /// Catalog entry source
+    pub catalog_source: String,
+    /// Display name; defaults to the catalog name
+    pub name: Option<String>,
+    /// Free-form notes
+    pub notes: Option<String>,
+}
+
+/// Input for updating an owned vehicle.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateOwnedVehicleInput {
+    /// New display name
+    pub name: Option<String>,
+    /// New notes (Some(None) clears them)
+    pub notes: Option<Option<String>>,
+    /// Transfer to a new owner in the same campaign
+    pub owner: Option<VehicleOwner>,
+}
+
+/// Cargo and passenger capacity of one mount or vehicle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VehicleCapacity {
+    /// Owned vehicle ID
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// 'vehicle' or 'mount'
+    pub vehicle_kind: String,
+    /// Owning character's name, or None for party-owned vehicles
+    pub owner_name: Option<String>,
+    /// Cargo capacity in pounds
+    pub cargo_lb: f64,
+    /// People it carries, crew included
+    pub passengers: i32,
+}
+
+/// A party's carrying capacity against what its members carry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartyCarryingCapacity {
+    /// Party ID
+    pub party_id: String,
+    /// Party-owned and member-owned mounts and vehicles
+    pub vehicles: Vec<VehicleCapacity>,
+    /// Total cargo capacity in pounds
+    pub cargo_capacity_lb: f64,
+    /// Total people the vehicles carry
+    pub passenger_capacity: i32,
+    /// Combined weight of the members' inventories in pounds
+    pub inventory_weight_lb: f64,
+    /// Number of party members needing a seat
+    pub members: i32,
+    /// Inventory weight exceeds cargo capacity
+    pub overloaded: bool,
+    /// Members outnumber the available seats
+    pub short_of_seats: bool,
+}
+
+/// Service for mounts and vehicles owned by characters and parties.
+pub struct OwnedVehicleService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> OwnedVehicleService<'a> {
+    /// Create a new owned vehicle service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Get an owned vehicle by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<OwnedVehicle> {
+        dal::get_owned_vehicle_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("OwnedVehicle", id))
+    }
+
+    /// List every mount and vehicle owned in a campaign.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<OwnedVehicle>> {
+        dal::list_campaign_owned_vehicles(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// List a character's own mounts and vehicles.
+    pub fn list_for_character(&mut self, character_id: &str) -> ServiceResult<Vec<OwnedVehicle>> {
+        self.get_character(character_id)?;
+        dal::list_character_owned_vehicles(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    /// List the mounts and vehicles available to a party: its own plus
+    /// those of its current members.
+    pub fn list_for_party(&mut self, party_id: &str) -> ServiceResult<Vec<OwnedVehicle>> {
+        self.get_party(party_id)?;
+        let pcs = dal::list_party_pcs(self.conn, party_id)?;
+        let pc_ids: Vec<&str> = pcs.iter().map(|pc| pc.id.as_str()).collect();
+        dal::list_party_owned_vehicles(self.conn, party_id, &pc_ids).map_err(ServiceError::from)
+    }
+
+    /// Assign a catalog mount or vehicle to a character or party.
+    pub fn assign(&mut self, input: AssignVehicleInput) -> ServiceResult<OwnedVehicle> {
+        let campaign_id = self.owner_campaign(&input.owner)?;
+        let entry = catalog_entry(
+            self.conn,
+            input.vehicle_kind,
+            &input.catalog_name,
+            &input.catalog_source,
+        )?;
+        if input.vehicle_kind == VehicleKind::Mount && entry["type"].as_str() != Some("MNT") {
+            return Err(ServiceError::validation(format!(
+                "{} is not a mount",
+                input.catalog_name
+            )));
+        }
+
+        let catalog_name = entry["name"]
+            .as_str()
+            .unwrap_or(&input.catalog_name)
+            .to_string();
+        let name = match input.name.as_deref().map(str::trim) {
+            Some("") => return Err(ServiceError::validation("Vehicle name cannot be empty")),
+            Some(name) => name.to_string(),
+            None => catalog_name.clone(),
+        };
+
+        let id = Uuid::new_v4().to_string();
+        let mut vehicle = match &input.owner {
+            VehicleOwner::Character { character_id } => NewOwnedVehicle::for_character(
+                &id,
+                &campaign_id,
+                character_id,
+                &name,
+                input.vehicle_kind,
+                &catalog_name,
+                &input.catalog_source,
+            ),
+            VehicleOwner::Party { party_id } => NewOwnedVehicle::for_party(
+                &id,
+                &campaign_id,
+                party_id,
+                &name,
+                input.vehicle_kind,
+                &catalog_name,
+                &input.catalog_source,
+            ),
+        };
+        if let Some(ref notes) = input.notes {
+            vehicle = vehicle.with_notes(notes);
+        }
+
+        dal::insert_owned_vehicle(self.conn, &vehicle)?;
+        self.get(&id)
+    }
+
+    /// Rename an owned vehicle, edit its notes, or transfer it to another
+    /// owner in the same campaign.
+    pub fn update(
+        &mut self,
+        id: &str,
+        input: UpdateOwnedVehicleInput,
+    ) -> ServiceResult<OwnedVehicle> {
+        let vehicle = self.get(id)?;
+        if let Some(ref name) = input.name {
+            if name.trim().is_empty() {
+                return Err(ServiceError::validation("Vehicle name cannot be empty"));
+            }
+        }
+        if let Some(ref owner) = input.owner {
+            if self.owner_campaign(owner)? != vehicle.campaign_id {
+                return Err(ServiceError::validation(
+                    "Vehicles can only change hands within their campaign",
+                ));
+            }
+        }
+
+        let now = now_rfc3339();
+        let (character_id, party_id) = match &input.owner {
+            Some(VehicleOwner::Character { character_id }) => {
+                (Some(Some(character_id.as_str())), Some(None))
+            }
+            Some(VehicleOwner::Party { party_id }) => (Some(None), Some(Some(party_id.as_str()))),
+            None => (None, None),
+        };
+        let update = UpdateOwnedVehicle {
+            character_id,
+            party_id,
+            name: input.name.as_deref().map(str::trim),
+            notes: input.notes.as_ref().map(|n| n.as_deref()),
+            updated_at: Some(&now),
+        };
+        dal::update_owned_vehicle(self.conn, id, &update)?;
+        self.get(id)
+    }
+
+    /// Remove a mount or vehicle from its owner.
+    pub fn remove(&mut self, id: &str) -> ServiceResult<()> {
+        self.get(id)?;
+        dal::delete_owned_vehicle(self.conn, id)?;
+        Ok(())
+    }
+
+    /// The vehicle's catalog stat block, named for the owned vehicle.
+    pub fn statblock(&mut self, id: &str) -> ServiceResult<Value> {
+        let vehicle = self.get(id)?;
+        vehicle_statblock(self.conn, &vehicle)
+    }
+
+    /// Total a party's cargo and passenger capacity and compare it with the
+    /// weight of everything its members carry.
+    pub fn party_capacity(&mut self, party_id: &str) -> ServiceResult<PartyCarryingCapacity> {
+        let party = self.get_party(party_id)?;
+        let pcs = dal::list_party_pcs(self.conn, party_id)?;
+        let pc_ids: Vec<&str> = pcs.iter().map(|pc| pc.id.as_str()).collect();
+        let owned = dal::list_party_owned_vehicles(self.conn, party_id, &pc_ids)?;
+
+        let mut vehicles = Vec::with_capacity(owned.len());
+        for vehicle in &owned {
+            let statblock = vehicle_statblock(self.conn, vehicle)?;
+            let (cargo_lb, passengers) = capacity_of(vehicle, &statblock);
+            let owner_name = vehicle.character_id.as_ref().and_then(|owner| {
+                pcs.iter()
+                    .find(|pc| &pc.id == owner)
+                    .map(|pc| pc.name.clone())
+            });
+            vehicles.push(VehicleCapacity {
+                id: vehicle.id.clone(),
+                name: vehicle.name.clone(),
+                vehicle_kind: vehicle.vehicle_kind.clone(),
+                owner_name,
+                cargo_lb,
+                passengers,
+            });
+        }
+
+        let mut inventory_weight_lb = 0.0;
+        for pc in &pcs {
+            inventory_weight_lb += inventory_weight(self.conn, &party.campaign_id, &pc.id)?;
+        }
+
+        let cargo_capacity_lb: f64 = vehicles.iter().map(|v| v.cargo_lb).sum();
+        let passenger_capacity: i32 = vehicles.iter().map(|v| v.passengers).sum();
+        let members = pcs.len() as i32;
+
+        Ok(PartyCarryingCapacity {
+            party_id: party.id,
+            overloaded: !vehicles.is_empty() && inventory_weight_lb > cargo_capacity_lb,
+            short_of_seats: !vehicles.is_empty() && members > passenger_capacity,
+            vehicles,
+            cargo_capacity_lb,
+            passenger_capacity,
+            inventory_weight_lb,
+            members,
+        })
+    }
+
+    /// The campaign a new owner belongs to, checking the owner exists.
+    fn owner_campaign(&mut self, owner: &VehicleOwner) -> ServiceResult<String> {
+        match owner {
+            VehicleOwner::Character { character_id } => self
+                .get_character(character_id)?
+                .campaign_id
+                .ok_or_else(|| {
+                    ServiceError::validation("Only characters in a campaign can own vehicles")
+                }),
+            VehicleOwner::Party { party_id } => Ok(self.get_party(party_id)?.campaign_id),
+        }
+    }
+
+    fn get_character(&mut self, character_id: &str) -> ServiceResult<Character> {
+        dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))
+    }
+
+    fn get_party(&mut self, party_id: &str) -> ServiceResult<Party> {
+        dal::get_party_optional(self.conn, party_id)?
+            .ok_or_else(|| ServiceError::not_found("Party", party_id))
+    }
+}
+
+/// Build an owned vehicle's stat block from its catalog entry, named for the
+/// vehicle.
+///
+/// A catalog entry that has since been removed yields a minimal stat block
+/// with only the name, so printing and capacity still work.
+pub fn vehicle_statblock(
+    conn: &mut SqliteConnection,
+    vehicle: &OwnedVehicle,
+) -> ServiceResult<Value> {
+    let kind = vehicle.vehicle_kind_enum().unwrap_or(VehicleKind::Vehicle);
+    let mut statblock =
+        match catalog_entry(conn, kind, &vehicle.catalog_name, &vehicle.catalog_source) {
+            Ok(entry) => entry,
+            Err(ServiceError::NotFound { .. }) => Value::Object(Default::default()),
+            Err(e) => return Err(e),
+        };
+
+    if let Some(obj) = statblock.as_object_mut() {
+        if vehicle.name != vehicle.catalog_name {
+            obj.insert(
+                "catalogName".to_string(),
+                Value::String(vehicle.catalog_name.clone()),
+            );
+        }
+        obj.insert("name".to_string(), Value::String(vehicle.name.clone()));
+        obj.insert(
+            "source".to_string(),
+            Value::String(vehicle.catalog_source.clone()),
+        );
+        obj.insert(
+            "vehicleKind".to_string(),
+            Value::String(vehicle.vehicle_kind.clone()),
+        );
+    }
+
+    Ok(statblock)
+}
+
+/// Look up a mount (item catalog) or vehicle (vehicle catalog) entry.
+fn catalog_entry(
+    conn: &mut SqliteConnection,
+    kind: VehicleKind,
+    name: &str,
+    source: &str,
+) -> ServiceResult<Value> {
+    let data = match kind {
+        VehicleKind::Vehicle => catalog_dal::get_vehicle_by_name(conn, name, source)?
+            .map(|v| v.data)
+            .ok_or_else(|| ServiceError::not_found("Vehicle", format!("{} ({})", name, source)))?,
+        VehicleKind::Mount => catalog_dal::get_item_by_name(conn, name, source)?
+            .map(|i| i.data)
+            .ok_or_else(|| ServiceError::not_found("Item", format!("{} ({})", name, source)))?,
+    };
+    serde_json::from_str(&data)
+        .map_err(|e| ServiceError::validation(format!("Invalid catalog data: {}", e)))
+}
+
+/// Cargo capacity in pounds and seats for an owned vehicle's stat block.
+fn capacity_of(vehicle: &OwnedVehicle, statblock: &Value) -> (f64, i32) {
+    if vehicle.is_mount() {
+        let cargo = statblock["carryingCapacity"].as_f64().unwrap_or(0.0);
+        return (cargo, 1);
+    }
+    let cargo = statblock["capCargo"].as_f64().unwrap_or(0.0) * POUNDS_PER_TON;
+    let crew = statblock["capCrew"].as_i64().unwrap_or(0);
+    let passengers = statblock["capPassenger"].as_i64().unwrap_or(0);
+    (cargo, (crew + passengers) as i32)
+}
+
+/// Total weight in pounds of a character's inventory.
+///
+/// Items with no recorded weight (or no catalog entry) count as weightless.
+fn inventory_weight(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    character_id: &str,
+) -> ServiceResult<f64> {
+    let mut total = 0.0;
+    for item in dal::list_character_inventory(conn, character_id)? {
+        let data = if item.item_source == HOMEBREW_SOURCE {
+            dal::get_campaign_homebrew_item_by_name(conn, campaign_id, &item.item_name)?
+                .map(|hb| hb.data)
+        } else {
+            catalog_dal::get_item_by_name(conn, &item.item_name, &item.item_source)?.map(|i| i.data)
+        };
+        let weight = data
+            .and_then(|d| serde_json::from_str::<Value>(&d).ok())
+            .and_then(|v| v["weight"].as_f64())
+            .unwrap_or(0.0);
+        total += weight * item.quantity as f64;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_character_inventory, insert_party,
+        insert_party_member,
+    };
+    use crate::dal::catalog::{insert_item, insert_vehicle};
+    use crate::models::campaign::{
+        NewCampaign, NewCharacter, NewCharacterInventory, NewParty, NewPartyMember,
+    };
+    use crate::models::catalog::{NewItem, NewVehicle};
+    use crate::test_utils::setup_test_db_with_sources;
+    use serde_json::json;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Blue Team")).unwrap();
+        for (id, name) in [("char-1", "Ada"), ("char-2", "Brom")] {
+            insert_character(
+                conn,
+                &NewCharacter::new_pc(id, Some("camp-1"), name, "Alice"),
+            )
+            .unwrap();
+            insert_party_member(conn, &NewPartyMember::new("party-1", id)).unwrap();
+        }
+
+        let horse = json!({
+            "name": "Riding Horse",
+            "source": "PHB",
+            "type": "MNT",
+            "carryingCapacity": 480
+        });
+        insert_item(
+            conn,
+            &NewItem::new("Riding Horse", "PHB", &horse.to_string()).with_type("MNT"),
+        )
+        .unwrap();
+        let anvil = json!({"name": "Anvil", "source": "PHB", "weight": 300});
+        insert_item(conn, &NewItem::new("Anvil", "PHB", &anvil.to_string())).unwrap();
+        let rowboat = json!({
+            "name": "Rowboat",
+            "source": "GoS",
+            "capCrew": 1,
+            "capPassenger": 3,
+            "capCargo": 0.5
+        });
+        insert_vehicle(
+            conn,
+            &NewVehicle::new("Rowboat", "GoS", &rowboat.to_string()),
+        )
+        .unwrap();
+    }
+
+    fn horse_for(character_id: &str) -> AssignVehicleInput {
+        AssignVehicleInput {
+            owner: VehicleOwner::Character {
+                character_id: character_id.to_string(),
+            },
+            vehicle_kind: VehicleKind::Mount,
+            catalog_name: "Riding Horse".to_string(),
+            catalog_source: "PHB".to_string(),
+            name: Some("Clover".to_string()),
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_assign_validates_catalog() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = OwnedVehicleService::new(&mut conn);
+
+        let clover = service.assign(horse_for("char-1")).unwrap();
+        assert_eq!(clover.name, "Clover");
+        assert_eq!(clover.campaign_id, "camp-1");
+        assert!(clover.is_mount());
+
+        let mut anvil = horse_for("char-1");
+        anvil.catalog_name = "Anvil".to_string();
+        assert!(service.assign(anvil).is_err());
+
+        let mut pegasus = horse_for("char-1");
+        pegasus.catalog_name = "Pegasus".to_string();
+        assert!(service.assign(pegasus).is_err());
+    }
+
+    #[test]
+    fn test_transfer_to_party() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = OwnedVehicleService::new(&mut conn);
+        let clover = service.assign(horse_for("char-1")).unwrap();
+
+        let input = UpdateOwnedVehicleInput {
+            owner: Some(VehicleOwner::Party {
+                party_id: "party-1".to_string(),
+            }),
+            ..Default::default()
+        };
+        let clover = service.update(&clover.id, input).unwrap();
+        assert!(clover.is_party_owned());
+        assert_eq!(clover.character_id, None);
+        assert!(service.list_for_character("char-1").unwrap().is_empty());
+        assert_eq!(service.list_for_party("party-1").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_party_capacity_against_inventory() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let anvils = NewCharacterInventory::new("inv-1", "char-2", "Anvil", "PHB").with_quantity(2);
+        insert_character_inventory(&mut conn, &anvils).unwrap();
+
+        let mut service = OwnedVehicleService::new(&mut conn);
+        service.assign(horse_for("char-1")).unwrap();
+        let capacity = service.party_capacity("party-1").unwrap();
+        assert_eq!(capacity.cargo_capacity_lb, 480.0);
+        assert_eq!(capacity.inventory_weight_lb, 600.0);
+        assert_eq!(capacity.passenger_capacity, 1);
+        assert!(capacity.overloaded);
+        assert!(capacity.short_of_seats);
+        assert_eq!(capacity.vehicles[0].owner_name.as_deref(), Some("Ada"));
+
+        service
+            .assign(AssignVehicleInput {
+                owner: VehicleOwner::Party {
+                    party_id: "party-1".to_string(),
+                },
+                vehicle_kind: VehicleKind::Vehicle,
+                catalog_name: "Rowboat".to_string(),
+                catalog_source: "GoS".to_string(),
+                name: None,
+                notes: None,
+            })
+            .unwrap();
+        let capacity = service.party_capacity("party-1").unwrap();
+        assert_eq!(capacity.cargo_capacity_lb, 1480.0);
+        assert_eq!(capacity.passenger_capacity, 5);
+        assert!(!capacity.overloaded);
+        assert!(!capacity.short_of_seats);
+    }
+}
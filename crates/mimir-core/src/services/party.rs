@@ -0,0 +1,652 @@
+//! Party Service
+//!
+//! Business logic for parties within a campaign: membership, party-scoped
+//! XP, encounter budgets built from member levels, and each party's
+//! calendar timeline.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    Character, CharacterCompanion, NewParty, NewPartyMember, NewPartyTimelineEvent, Party,
+    PartyTimelineEvent, UpdateParty,
+};
+use crate::services::encounter::{
+    evaluate_encounter, EncounterBudget, EncounterEvaluation, EncounterMonster,
+};
+use crate::services::tag::validate_color;
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Input for adding an event to a party's timeline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimelineEventInput {
+    /// In-world day; defaults to the party's current day
+    pub day: Option<i32>,
+    /// Short event title
+    pub title: String,
+    /// Optional details
+    pub description: Option<String>,
+    /// Session the event happened in, if any
+    pub session_id: Option<String>,
+}
+
+/// XP earned by one party member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberXp {
+    /// Character ID
+    pub character_id: String,
+    /// Character name
+    pub name: String,
+    /// XP from party sessions started since the character joined
+    pub xp: i32,
+    /// Companions traveling with the character
+    #[serde(default)]
+    pub companions: Vec<MemberCompanion>,
+}
+
+/// A party member's companion, as shown in party summaries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberCompanion {
+    /// Companion ID
+    pub id: String,
+    /// Companion name
+    pub name: String,
+    /// Kind of companion ('beast', 'familiar', 'sidekick', 'other')
+    pub companion_type: String,
+    /// Sidekick level, if the companion has levels
+    pub level: Option<i32>,
+    /// Current hit points
+    pub current_hp: i32,
+    /// Hit point maximum
+    pub max_hp: i32,
+}
+
+impl From<CharacterCompanion> for MemberCompanion {
+    fn from(companion: CharacterCompanion) -> Self {
+        Self {
+            id: companion.id,
+            name: companion.name,
+            companion_type: companion.companion_type,
+            level: companion.level,
+            current_hp: companion.current_hp,
+            max_hp: companion.max_hp,
+        }
+    }
+}
+
+/// XP totals for a party.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartyXpSummary {
+    /// Party ID
+    pub party_id: String,
+    /// Number of sessions the party has played
+    pub sessions_played: usize,
+    /// XP awarded across all of the party's sessions
+    pub total_xp: i32,
+    /// XP for each current member
+    pub members: Vec<MemberXp>,
+}
+
+/// Service for party management.
+pub struct PartyService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> PartyService<'a> {
+    /// Create a new party service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Create a party for a campaign.
+    pub fn create(
+        &mut self,
+        campaign_id: &str,
+        name: &str,
+        description: Option<&str>,
+        color: Option<&str>,
+    ) -> ServiceResult<Party> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let name = validate_name(name)?;
+        if let Some(color) = color {
+            validate_color(color)?;
+        }
+        if dal::get_party_by_name(self.conn, campaign_id, name)?.is_some() {
+            return Err(ServiceError::validation(format!(
+                "A party named '{}' already exists",
+                name
+            )));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let mut new_party = NewParty::new(&id, campaign_id, name);
+        if let Some(description) = description {
+            new_party = new_party.with_description(description);
+        }
+        if let Some(color) = color {
+            new_party = new_party.with_color(color);
+        }
+
+        dal::insert_party(self.conn, &new_party)?;
+        dal::get_party(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Get a party by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<Option<Party>> {
+        dal::get_party_optional(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// List a campaign's parties alphabetically.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<Party>> {
+        dal::list_parties(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Rename or redescribe a party. `Some(None)` clears an optional field.
+    pub fn update(
+        &mut self,
+        id: &str,
+        name: Option<&str>,
+        description: Option<Option<&str>>,
+        color: Option<Option<&str>>,
+    ) -> ServiceResult<Party> {
+        let party = self.get_required(id)?;
+
+        let name = match name {
+            Some(name) => {
+                let name = validate_name(name)?;
+                if let Some(existing) = dal::get_party_by_name(self.conn, &party.campaign_id, name)?
+                {
+                    if existing.id != party.id {
+                        return Err(ServiceError::validation(format!(
+                            "A party named '{}' already exists",
+                            name
+                        )));
+                    }
+                }
+                Some(name)
+            }
+            None => None,
+        };
+        if let Some(Some(color)) = color {
+            validate_color(color)?;
+        }
+
+        let now = now_rfc3339();
+        let update = UpdateParty {
+            name,
+            description,
+            color,
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_party(self.conn, id, &update)?;
+        dal::get_party(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Delete a party. Its sessions are kept with no party.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_party(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("Party", id));
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Membership
+    // =========================================================================
+
+    /// Add a player character to a party.
+    ///
+    /// The character must be a PC in the party's campaign. Characters can
+    /// belong to several parties at once.
+    pub fn add_member(&mut self, party_id: &str, character_id: &str) -> ServiceResult<()> {
+        let party = self.get_required(party_id)?;
+        let eligible = dal::get_character_optional(self.conn, character_id)?.is_some_and(|c| {
+            !c.is_npc()
+                && !c.is_trashed()
+                && c.campaign_id.as_deref() == Some(party.campaign_id.as_str())
+        });
+        if !eligible {
+            return Err(ServiceError::not_found("Character", character_id));
+        }
+
+        dal::insert_party_member(self.conn, &NewPartyMember::new(party_id, character_id))?;
+        Ok(())
+    }
+
+    /// Remove a character from a party.
+    pub fn remove_member(&mut self, party_id: &str, character_id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_party_member(self.conn, party_id, character_id)?;
+        if rows == 0 {
+            return Err(ServiceError::validation(format!(
+                "Character {} is not in party {}",
+                character_id, party_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// List the player characters in a party, alphabetically.
+    pub fn list_pcs(&mut self, party_id: &str) -> ServiceResult<Vec<Character>> {
+        self.get_required(party_id)?;
+        dal::list_party_pcs(self.conn, party_id).map_err(ServiceError::from)
+    }
+
+    /// List the parties a character belongs to.
+    pub fn list_for_character(&mut self, character_id: &str) -> ServiceResult<Vec<Party>> {
+        dal::list_parties_for_character(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    // =========================================================================
+    // XP and Encounters
+    // =========================================================================
+
+    /// Total the XP awarded in a party's sessions.
+    ///
+    /// Each member is credited with the sessions that started after they
+    /// joined the party. Dead members are left out unless `include_dead`.
+    pub fn xp_summary(
+        &mut self,
+        party_id: &str,
+        include_dead: bool,
+    ) -> ServiceResult<PartyXpSummary> {
+        let sessions = dal::list_party_game_sessions(self.conn, party_id)?;
+        let memberships = dal::list_party_members(self.conn, party_id)?;
+        let pcs = self.active_pcs(party_id, include_dead)?;
+        let pc_ids: Vec<&str> = pcs.iter().map(|pc| pc.id.as_str()).collect();
+        let companions = dal::list_companions_for_characters(self.conn, &pc_ids)?;
+
+        let members = pcs
+            .into_iter()
+            .map(|pc| {
+                let joined_at = memberships
+                    .iter()
+                    .find(|m| m.character_id == pc.id)
+                    .map(|m| m.joined_at.as_str())
+                    .unwrap_or_default();
+                let xp = sessions
+                    .iter()
+                    .filter(|s| s.started_at.as_str() >= joined_at)
+                    .map(|s| s.xp_awarded)
+                    .sum();
+                let own = companions
+                    .iter()
+                    .filter(|c| c.character_id == pc.id)
+                    .cloned()
+                    .map(MemberCompanion::from)
+                    .collect();
+                MemberXp {
+                    character_id: pc.id,
+                    name: pc.name,
+                    xp,
+                    companions: own,
+                }
+            })
+            .collect();
+
+        Ok(PartyXpSummary {
+            party_id: party_id.to_string(),
+            sessions_played: sessions.len(),
+            total_xp: sessions.iter().map(|s| s.xp_awarded).sum(),
+            members,
+        })
+    }
+
+    /// Encounter XP thresholds for a party's current members.
+    ///
+    /// Sidekicks count as party members at their own level; companions
+    /// without levels (beasts, familiars) don't change the budget. Dead
+    /// members are left out unless `include_dead`.
+    pub fn encounter_budget(
+        &mut self,
+        party_id: &str,
+        include_dead: bool,
+    ) -> ServiceResult<EncounterBudget> {
+        let pcs = self.active_pcs(party_id, include_dead)?;
+        let mut levels = Vec::with_capacity(pcs.len());
+        for pc in &pcs {
+            let classes = dal::list_character_classes(self.conn, &pc.id)?;
+            levels.push(classes.iter().map(|c| c.level).sum());
+        }
+        let pc_ids: Vec<&str> = pcs.iter().map(|pc| pc.id.as_str()).collect();
+        levels.extend(
+            dal::list_companions_for_characters(self.conn, &pc_ids)?
+                .iter()
+                .filter(|c| c.is_sidekick())
+                .filter_map(|c| c.level),
+        );
+        Ok(EncounterBudget::for_levels(&levels))
+    }
+
+    /// Rate a group of monsters against a party.
+    pub fn evaluate_encounter(
+        &mut self,
+        party_id: &str,
+        monsters: &[EncounterMonster],
+        include_dead: bool,
+    ) -> ServiceResult<EncounterEvaluation> {
+        let budget = self.encounter_budget(party_id, include_dead)?;
+        evaluate_encounter(budget, monsters)
+    }
+
+    // =========================================================================
+    // Calendar Timeline
+    // =========================================================================
+
+    /// Set the party's current in-world day.
+    pub fn set_current_day(&mut self, party_id: &str, day: i32) -> ServiceResult<Party> {
+        validate_day(day)?;
+        self.get_required(party_id)?;
+
+        let now = now_rfc3339();
+        let update = UpdateParty {
+            current_day: Some(day),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_party(self.conn, party_id, &update)?;
+        dal::get_party(self.conn, party_id).map_err(ServiceError::from)
+    }
+
+    /// Add an event to a party's timeline.
+    pub fn add_timeline_event(
+        &mut self,
+        party_id: &str,
+        input: TimelineEventInput,
+    ) -> ServiceResult<PartyTimelineEvent> {
+        let party = self.get_required(party_id)?;
+        let title = input.title.trim();
+        if title.is_empty() {
+            return Err(ServiceError::validation("Event title cannot be empty"));
+        }
+        let day = input.day.unwrap_or(party.current_day);
+        validate_day(day)?;
+        if let Some(ref session_id) = input.session_id {
+            let in_campaign = dal::get_game_session_optional(self.conn, session_id)?
+                .is_some_and(|s| s.campaign_id == party.campaign_id);
+            if !in_campaign {
+                return Err(ServiceError::not_found("GameSession", session_id));
+            }
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let mut new_event = NewPartyTimelineEvent::new(&id, party_id, day, title);
+        if let Some(ref session_id) = input.session_id {
+            new_event = new_event.with_session(session_id);
+        }
+        if let Some(ref description) = input.description {
+            new_event = new_event.with_description(description);
+        }
+
+        dal::insert_party_timeline_event(self.conn, &new_event)?;
+        dal::get_party_timeline_event(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// A party's timeline in calendar order.
+    pub fn timeline(&mut self, party_id: &str) -> ServiceResult<Vec<PartyTimelineEvent>> {
+        self.get_required(party_id)?;
+        dal::list_party_timeline_events(self.conn, party_id).map_err(ServiceError::from)
+    }
+
+    /// Delete a timeline event.
+    pub fn delete_timeline_event(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_party_timeline_event(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("PartyTimelineEvent", id));
+        }
+        Ok(())
+    }
+
+    /// The party's PCs, leaving out the dead unless `include_dead`.
+    fn active_pcs(&mut self, party_id: &str, include_dead: bool) -> ServiceResult<Vec<Character>> {
+        let mut pcs = self.list_pcs(party_id)?;
+        if !include_dead {
+            pcs.retain(|pc| !pc.is_dead);
+        }
+        Ok(pcs)
+    }
+
+    fn get_required(&mut self, id: &str) -> ServiceResult<Party> {
+        dal::get_party_optional(self.conn, id)?.ok_or_else(|| ServiceError::not_found("Party", id))
+    }
+}
+
+fn validate_name(name: &str) -> ServiceResult<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::validation("Party name cannot be empty"));
+    }
+    if name.chars().count() > 64 {
+        return Err(ServiceError::validation(
+            "Party name cannot be longer than 64 characters",
+        ));
+    }
+    Ok(name)
+}
+
+fn validate_day(day: i32) -> ServiceResult<()> {
+    if day < 1 {
+        return Err(ServiceError::validation(format!(
+            "Calendar day must be 1 or later, got {}",
+            day
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_character_class, insert_character_companion,
+    };
+    use crate::models::campaign::{
+        CompanionType, NewCampaign, NewCharacter, NewCharacterClass, NewCharacterCompanion,
+        SidekickClass,
+    };
+    use crate::services::encounter::EncounterDifficulty;
+    use crate::services::{GameSessionService, SessionTotals, StartSessionInput};
+    use crate::test_utils::setup_test_db;
+
+    fn create_test_campaign(conn: &mut SqliteConnection) -> String {
+        let campaign_id = Uuid::new_v4().to_string();
+        let campaign = NewCampaign::new(&campaign_id, "West Marches");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        campaign_id
+    }
+
+    fn create_pc(conn: &mut SqliteConnection, campaign_id: &str, name: &str, level: i32) -> String {
+        let id = Uuid::new_v4().to_string();
+        let pc = NewCharacter::new_pc(&id, Some(campaign_id), name, "Player");
+        insert_character(conn, &pc).expect("Failed to create PC");
+        let class_id = Uuid::new_v4().to_string();
+        let class = NewCharacterClass::starting(&class_id, &id, "Fighter", "PHB").with_level(level);
+        insert_character_class(conn, &class).expect("Failed to add class");
+        id
+    }
+
+    #[test]
+    fn test_create_rejects_duplicate_name() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = PartyService::new(&mut conn);
+
+        service
+            .create(&campaign_id, "Tuesday Group", None, Some("#8b5cf6"))
+            .expect("Failed to create party");
+        let result = service.create(&campaign_id, "tuesday group", None, None);
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+        assert!(service
+            .create(&campaign_id, "Saturday", None, Some("purple"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_members_and_pc_filter() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let other_campaign = create_test_campaign(&mut conn);
+        let aria = create_pc(&mut conn, &campaign_id, "Aria", 3);
+        let bram = create_pc(&mut conn, &campaign_id, "Bram", 3);
+        let outsider = create_pc(&mut conn, &other_campaign, "Outsider", 1);
+        let mut service = PartyService::new(&mut conn);
+
+        let tuesday = service.create(&campaign_id, "Tuesday", None, None).unwrap();
+        let saturday = service
+            .create(&campaign_id, "Saturday", None, None)
+            .unwrap();
+        service.add_member(&tuesday.id, &aria).unwrap();
+        service.add_member(&saturday.id, &aria).unwrap();
+        service.add_member(&saturday.id, &bram).unwrap();
+
+        let result = service.add_member(&tuesday.id, &outsider);
+        assert!(matches!(result, Err(ServiceError::NotFound { .. })));
+
+        let pcs = service.list_pcs(&tuesday.id).unwrap();
+        assert_eq!(pcs.len(), 1);
+        assert_eq!(pcs[0].name, "Aria");
+        assert_eq!(service.list_pcs(&saturday.id).unwrap().len(), 2);
+        assert_eq!(service.list_for_character(&aria).unwrap().len(), 2);
+
+        service.remove_member(&saturday.id, &aria).unwrap();
+        assert!(service.remove_member(&saturday.id, &aria).is_err());
+        assert_eq!(service.list_pcs(&saturday.id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_xp_summary_is_party_scoped() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let aria = create_pc(&mut conn, &campaign_id, "Aria", 1);
+        let (tuesday, saturday) = {
+            let mut service = PartyService::new(&mut conn);
+            let tuesday = service.create(&campaign_id, "Tuesday", None, None).unwrap();
+            let saturday = service
+                .create(&campaign_id, "Saturday", None, None)
+                .unwrap();
+            service.add_member(&tuesday.id, &aria).unwrap();
+            (tuesday.id, saturday.id)
+        };
+
+        let mut sessions = GameSessionService::new(&mut conn);
+        for (party_id, xp) in [(&tuesday, 300), (&saturday, 500), (&tuesday, 200)] {
+            let session = sessions
+                .start(StartSessionInput::new(&campaign_id).with_party(party_id.as_str()))
+                .unwrap();
+            sessions.award_xp(&session.id, xp).unwrap();
+            sessions
+                .end(&session.id, &SessionTotals::default())
+                .unwrap();
+        }
+
+        let summary = PartyService::new(&mut conn).xp_summary(&tuesday, false).unwrap();
+        assert_eq!(summary.sessions_played, 2);
+        assert_eq!(summary.total_xp, 500);
+        assert_eq!(summary.members.len(), 1);
+        assert_eq!(summary.members[0].xp, 500);
+    }
+
+    #[test]
+    fn test_evaluate_encounter_uses_member_levels() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let pcs: Vec<String> = ["Aria", "Bram", "Cole", "Dara"]
+            .iter()
+            .map(|name| create_pc(&mut conn, &campaign_id, name, 3))
+            .collect();
+        let mut service = PartyService::new(&mut conn);
+        let party = service.create(&campaign_id, "Tuesday", None, None).unwrap();
+        for pc in &pcs {
+            service.add_member(&party.id, pc).unwrap();
+        }
+
+        let budget = service.encounter_budget(&party.id, false).unwrap();
+        assert_eq!(budget.levels, vec![3, 3, 3, 3]);
+        assert_eq!(budget.deadly, 1600);
+
+        let monsters = vec![EncounterMonster {
+            cr: "1/2".to_string(),
+            quantity: 6,
+        }];
+        let eval = service.evaluate_encounter(&party.id, &monsters, false).unwrap();
+        assert_eq!(eval.adjusted_xp, 1200);
+        assert_eq!(eval.difficulty, EncounterDifficulty::Hard);
+    }
+
+    #[test]
+    fn test_sidekicks_join_summary_and_budget() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let aria = create_pc(&mut conn, &campaign_id, "Aria", 3);
+        for (id, name, companion_type) in [
+            ("comp-1", "Grip", CompanionType::Sidekick),
+            ("comp-2", "Whiskers", CompanionType::Familiar),
+        ] {
+            let mut companion = NewCharacterCompanion::new(id, &aria, name, companion_type, 9);
+            if companion_type == CompanionType::Sidekick {
+                companion = companion.with_sidekick_class(SidekickClass::Warrior, 2);
+            }
+            insert_character_companion(&mut conn, &companion).unwrap();
+        }
+        let mut service = PartyService::new(&mut conn);
+        let party = service.create(&campaign_id, "Tuesday", None, None).unwrap();
+        service.add_member(&party.id, &aria).unwrap();
+
+        let budget = service.encounter_budget(&party.id, false).unwrap();
+        assert_eq!(budget.levels, vec![3, 2]);
+
+        let summary = service.xp_summary(&party.id, false).unwrap();
+        let names: Vec<&str> = summary.members[0]
+            .companions
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Grip", "Whiskers"]);
+    }
+
+    #[test]
+    fn test_timeline_defaults_to_current_day() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = PartyService::new(&mut conn);
+        let party = service.create(&campaign_id, "Tuesday", None, None).unwrap();
+
+        let party = service.set_current_day(&party.id, 14).unwrap();
+        assert_eq!(party.current_day, 14);
+        assert!(service.set_current_day(&party.id, 0).is_err());
+
+        let later = service
+            .add_timeline_event(
+                &party.id,
+                TimelineEventInput {
+                    title: "Reached the keep".to_string(),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(later.day, 14);
+
+        let earlier = TimelineEventInput {
+            day: Some(2),
+            title: "Left town".to_string(),
+            ..Default::default()
+        };
+        service.add_timeline_event(&party.id, earlier).unwrap();
+
+        let timeline = service.timeline(&party.id).unwrap();
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].title, "Left town");
+
+        let blank = TimelineEventInput {
+            title: "  ".to_string(),
+            ..Default::default()
+        };
+        assert!(service.add_timeline_event(&party.id, blank).is_err());
+    }
+}
@@ -0,0 +1,690 @@
+//! Player Service
+//!
+//! Business logic for a campaign's player roster: contact details, which
+//! characters each player owns, attendance derived from game sessions, and
+//! claim codes that let a player take over or submit a character.
+
+use chrono::{DateTime, Duration, Utc};
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    Character, NewPlayer, NewPlayerCharacter, NewPlayerClaim, Player, PlayerClaim, UpdateCharacter,
+    UpdatePlayer,
+};
+use crate::services::{CharacterService, CreateCharacterInput, ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Characters used in claim codes. Omits I, O, 0, and 1 so codes can be
+/// read aloud or copied by hand.
+const CLAIM_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Length of a claim code, excluding separators.
+const CLAIM_CODE_LENGTH: usize = 8;
+
+/// Contact details for a player.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerContact {
+    pub email: Option<String>,
+    pub discord: Option<String>,
+    pub phone: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Input for updating a player. `Some(None)` clears an optional field.
+#[derive(Debug, Clone, Default)]
+pub struct UpdatePlayerInput {
+    pub name: Option<String>,
+    pub email: Option<Option<String>>,
+    pub discord: Option<Option<String>>,
+    pub phone: Option<Option<String>>,
+    pub notes: Option<Option<String>>,
+}
+
+/// A session a player attended, and the character they played.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttendedSession {
+    pub session_id: String,
+    pub session_number: i32,
+    pub title: Option<String>,
+    pub started_at: String,
+    pub character_id: String,
+    pub character_name: String,
+}
+
+/// A player's attendance across a campaign's sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerAttendance {
+    /// Player ID
+    pub player_id: String,
+    /// Sessions attended, most recent first
+    pub sessions: Vec<AttendedSession>,
+    /// Number of sessions played in the campaign
+    pub campaign_sessions: usize,
+    /// Fraction of the campaign's sessions attended (0.0 - 1.0)
+    pub rate: f64,
+}
+
+/// A character submitted with a claim code, in the same shape as a new PC.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CharacterSubmission {
+    pub name: String,
+    pub race_name: Option<String>,
+    pub race_source: Option<String>,
+    pub background_name: Option<String>,
+    pub background_source: Option<String>,
+    /// Ability scores [STR, DEX, CON, INT, WIS, CHA]
+    pub ability_scores: Option<[i32; 6]>,
+    pub class_name: Option<String>,
+    pub class_source: Option<String>,
+    pub selected_skills: Option<Vec<String>>,
+}
+
+/// Service for the player roster.
+pub struct PlayerService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> PlayerService<'a> {
+    /// Create a new player service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Add a player to a campaign's roster.
+    pub fn create(
+        &mut self,
+        campaign_id: &str,
+        name: &str,
+        contact: &PlayerContact,
+    ) -> ServiceResult<Player> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let name = validate_name(name)?;
+        self.ensure_name_free(campaign_id, name, None)?;
+
+        let id = Uuid::new_v4().to_string();
+        let mut new_player = NewPlayer::new(&id, campaign_id, name);
+        if let Some(email) = non_empty(&contact.email) {
+            new_player = new_player.with_email(email);
+        }
+        if let Some(discord) = non_empty(&contact.discord) {
+            new_player = new_player.with_discord(discord);
+        }
+        if let Some(phone) = non_empty(&contact.phone) {
+            new_player = new_player.with_phone(phone);
+        }
+        if let Some(notes) = non_empty(&contact.notes) {
+            new_player = new_player.with_notes(notes);
+        }
+
+        dal::insert_player(self.conn, &new_player)?;
+        dal::get_player(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Get a player by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<Option<Player>> {
+        dal::get_player_optional(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// List a campaign's players alphabetically.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<Player>> {
+        dal::list_players(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Rename a player or change their contact details.
+    ///
+    /// A rename is copied to the `player_name` of each character they own.
+    pub fn update(&mut self, id: &str, input: UpdatePlayerInput) -> ServiceResult<Player> {
+        let player = self.get_required(id)?;
+
+        let name = match input.name.as_deref() {
+            Some(name) => {
+                let name = validate_name(name)?;
+                self.ensure_name_free(&player.campaign_id, name, Some(id))?;
+                Some(name)
+            }
+            None => None,
+        };
+
+        let now = now_rfc3339();
+        let update = UpdatePlayer {
+            name,
+            email: input.email.as_ref().map(|v| v.as_deref()),
+            discord: input.discord.as_ref().map(|v| v.as_deref()),
+            phone: input.phone.as_ref().map(|v| v.as_deref()),
+            notes: input.notes.as_ref().map(|v| v.as_deref()),
+            updated_at: Some(&now),
+        };
+
+        self.conn.transaction(|conn| {
+            dal::update_player(conn, id, &update)?;
+            if let Some(name) = name {
+                for character in dal::list_player_characters(conn, id)? {
+                    set_player_name(conn, &character.id, Some(name))?;
+                }
+            }
+            dal::get_player(conn, id).map_err(ServiceError::from)
+        })
+    }
+
+    /// Remove a player from the roster. Their characters are kept.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_player(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("Player", id));
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Characters
+    // =========================================================================
+
+    /// Give a player character to a player, replacing any previous owner.
+    ///
+    /// The character's `player_name` is set to the player's name.
+    pub fn assign_character(&mut self, player_id: &str, character_id: &str) -> ServiceResult<()> {
+        let player = self.get_required(player_id)?;
+        self.require_campaign_pc(&player.campaign_id, character_id)?;
+
+        self.conn.transaction(|conn| {
+            dal::link_player_character(conn, &NewPlayerCharacter::new(character_id, player_id))?;
+            set_player_name(conn, character_id, Some(&player.name))
+        })
+    }
+
+    /// Take a character away from its player.
+    pub fn unassign_character(&mut self, character_id: &str) -> ServiceResult<()> {
+        let rows = dal::unlink_player_character(self.conn, character_id)?;
+        if rows == 0 {
+            return Err(ServiceError::validation(format!(
+                "Character {} is not assigned to a player",
+                character_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// List a player's characters alphabetically.
+    pub fn list_characters(&mut self, player_id: &str) -> ServiceResult<Vec<Character>> {
+        self.get_required(player_id)?;
+        dal::list_player_characters(self.conn, player_id).map_err(ServiceError::from)
+    }
+
+    /// The player who owns a character, if any.
+    pub fn get_for_character(&mut self, character_id: &str) -> ServiceResult<Option<Player>> {
+        dal::get_character_player(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    // =========================================================================
+    // Attendance
+    // =========================================================================
+
+    /// Work out which of the campaign's sessions a player attended.
+    ///
+    /// A player attended a party session if one of their characters had
+    /// joined that party by the time the session started, and attended any
+    /// session without a party if they own a character at all.
+    pub fn attendance(&mut self, player_id: &str) -> ServiceResult<PlayerAttendance> {
+        let player = self.get_required(player_id)?;
+        let characters = dal::list_player_characters(self.conn, player_id)?;
+        let game_sessions = dal::list_game_sessions(self.conn, &player.campaign_id)?;
+
+        let mut sessions = Vec::new();
+        for session in &game_sessions {
+            let character = match session.party_id {
+                Some(ref party_id) => {
+                    let members = dal::list_party_members(self.conn, party_id)?;
+                    // joined_at is a SQLite datetime ("YYYY-MM-DD HH:MM:SS")
+                    characters.iter().find(|c| {
+                        members.iter().any(|m| {
+                            m.character_id == c.id
+                                && m.joined_at.replacen(' ', "T", 1) <= session.started_at
+                        })
+                    })
+                }
+                None => characters.first(),
+            };
+            if let Some(character) = character {
+                sessions.push(AttendedSession {
+                    session_id: session.id.clone(),
+                    session_number: session.session_number,
+                    title: session.title.clone(),
+                    started_at: session.started_at.clone(),
+                    character_id: character.id.clone(),
+                    character_name: character.name.clone(),
+                });
+            }
+        }
+
+        let rate = if game_sessions.is_empty() {
+            0.0
+        } else {
+            sessions.len() as f64 / game_sessions.len() as f64
+        };
+
+        Ok(PlayerAttendance {
+            player_id: player.id,
+            sessions,
+            campaign_sessions: game_sessions.len(),
+            rate,
+        })
+    }
+
+    // =========================================================================
+    // Claim Codes
+    // =========================================================================
+
+    /// Issue a claim code to a player.
+    ///
+    /// With a `character_id`, redeeming the code hands that character to the
+    /// player; without one, the player submits a new character when redeeming.
+    pub fn create_claim(
+        &mut self,
+        player_id: &str,
+        character_id: Option<&str>,
+        expires_in_days: Option<i64>,
+    ) -> ServiceResult<PlayerClaim> {
+        let player = self.get_required(player_id)?;
+        if let Some(character_id) = character_id {
+            self.require_campaign_pc(&player.campaign_id, character_id)?;
+        }
+        let expires_at = match expires_in_days {
+            Some(days) if days < 1 => {
+                return Err(ServiceError::validation(format!(
+                    "Claim expiry must be at least 1 day, got {}",
+                    days
+                )));
+            }
+            Some(days) => Some((Utc::now() + Duration::days(days)).to_rfc3339()),
+            None => None,
+        };
+
+        let code = loop {
+            let code = generate_claim_code();
+            if dal::get_player_claim_by_code(self.conn, &code)?.is_none() {
+                break code;
+            }
+        };
+
+        let id = Uuid::new_v4().to_string();
+        let mut new_claim = NewPlayerClaim::new(&id, &player.campaign_id, player_id, &code);
+        if let Some(character_id) = character_id {
+            new_claim = new_claim.with_character(character_id);
+        }
+        if let Some(ref expires_at) = expires_at {
+            new_claim = new_claim.with_expiry(expires_at);
+        }
+
+        dal::insert_player_claim(self.conn, &new_claim)?;
+        dal::get_player_claim(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// List a campaign's claim codes, newest first.
+    pub fn list_claims(&mut self, campaign_id: &str) -> ServiceResult<Vec<PlayerClaim>> {
+        dal::list_player_claims(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Revoke a claim code.
+    pub fn revoke_claim(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_player_claim(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("PlayerClaim", id));
+        }
+        Ok(())
+    }
+
+    /// Redeem a claim code and return the claimed character.
+    ///
+    /// `character_json` is a [`CharacterSubmission`]; when given, a new PC is
+    /// created for the player from it. Otherwise the claim must offer an
+    /// existing character. Codes are matched ignoring case, spaces, and dashes.
+    pub fn redeem_claim(
+        &mut self,
+        code: &str,
+        character_json: Option<&str>,
+    ) -> ServiceResult<Character> {
+        let code = normalize_claim_code(code);
+        let claim = dal::get_player_claim_by_code(self.conn, &code)?
+            .ok_or_else(|| ServiceError::not_found("PlayerClaim", &code))?;
+        if claim.is_redeemed() {
+            return Err(ServiceError::validation(format!(
+                "Claim code {} has already been redeemed",
+                claim.display_code()
+            )));
+        }
+        if let Some(ref expires_at) = claim.expires_at {
+            let expired = DateTime::parse_from_rfc3339(expires_at)
+                .map(|t| t < Utc::now())
+                .unwrap_or(true);
+            if expired {
+                return Err(ServiceError::validation(format!(
+                    "Claim code {} has expired",
+                    claim.display_code()
+                )));
+            }
+        }
+
+        let submission =
+            match character_json {
+                Some(json) => Some(serde_json::from_str::<CharacterSubmission>(json).map_err(
+                    |e| ServiceError::validation(format!("Invalid character JSON: {}", e)),
+                )?),
+                None => None,
+            };
+        let player = dal::get_player(self.conn, &claim.player_id)?;
+
+        self.conn.transaction(|conn| {
+            let character_id = match (submission, claim.character_id.as_deref()) {
+                (Some(submission), _) => {
+                    let input = submission.into_input(&claim.campaign_id, &player.name)?;
+                    CharacterService::new(conn).create(input)?.id
+                }
+                (None, Some(character_id)) => {
+                    let eligible = dal::get_character_optional(conn, character_id)?
+                        .is_some_and(|c| !c.is_trashed());
+                    if !eligible {
+                        return Err(ServiceError::not_found("Character", character_id));
+                    }
+                    character_id.to_string()
+                }
+                (None, None) => {
+                    return Err(ServiceError::validation(
+                        "This claim code needs a character submission",
+                    ));
+                }
+            };
+
+            dal::link_player_character(conn, &NewPlayerCharacter::new(&character_id, &player.id))?;
+            set_player_name(conn, &character_id, Some(&player.name))?;
+            let rows =
+                dal::mark_player_claim_redeemed(conn, &claim.id, &now_rfc3339(), &character_id)?;
+            if rows == 0 {
+                return Err(ServiceError::validation(format!(
+                    "Claim code {} has already been redeemed",
+                    claim.display_code()
+                )));
+            }
+            dal::get_character(conn, &character_id).map_err(ServiceError::from)
+        })
+    }
+
+    fn get_required(&mut self, id: &str) -> ServiceResult<Player> {
+        dal::get_player_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Player", id))
+    }
+
+    fn ensure_name_free(
+        &mut self,
+        campaign_id: &str,
+        name: &str,
+        except_id: Option<&str>,
+    ) -> ServiceResult<()> {
+        if let Some(existing) = dal::get_player_by_name(self.conn, campaign_id, name)? {
+            if Some(existing.id.as_str()) != except_id {
+                return Err(ServiceError::validation(format!(
+                    "A player named '{}' already exists",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn require_campaign_pc(&mut self, campaign_id: &str, character_id: &str) -> ServiceResult<()> {
+        let eligible = dal::get_character_optional(self.conn, character_id)?.is_some_and(|c| {
+            !c.is_npc() && !c.is_trashed() && c.campaign_id.as_deref() == Some(campaign_id)
+        });
+        if !eligible {
+            return Err(ServiceError::not_found("Character", character_id));
+        }
+        Ok(())
+    }
+}
+
+impl CharacterSubmission {
+    fn into_input(
+        self,
+        campaign_id: &str,
+        player_name: &str,
+    ) -> ServiceResult<CreateCharacterInput> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(ServiceError::validation("Character name cannot be empty"));
+        }
+
+        let mut input = CreateCharacterInput::new_pc(Some(campaign_id), name, player_name);
+        if let (Some(name), Some(source)) = (self.race_name, self.race_source) {
+            input = input.with_race(name, source);
+        }
+        if let (Some(name), Some(source)) = (self.background_name, self.background_source) {
+            input = input.with_background(name, source);
+        }
+        if let Some(scores) = self.ability_scores {
+            input = input.with_ability_scores(scores);
+        }
+        if let (Some(name), Some(source)) = (self.class_name, self.class_source) {
+            input = input.with_class(name, source);
+        }
+        if let Some(skills) = self.selected_skills {
+            input = input.with_skills(skills);
+        }
+        Ok(input)
+    }
+}
+
+fn set_player_name(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+    player_name: Option<&str>,
+) -> ServiceResult<()> {
+    let now = now_rfc3339();
+    let update = UpdateCharacter {
+        player_name: Some(player_name),
+        updated_at: Some(&now),
+        ..Default::default()
+    };
+    dal::update_character(conn, character_id, &update)?;
+    Ok(())
+}
+
+fn generate_claim_code() -> String {
+    Uuid::new_v4().as_bytes()[..CLAIM_CODE_LENGTH]
+        .iter()
+        .map(|b| CLAIM_CODE_ALPHABET[*b as usize % CLAIM_CODE_ALPHABET.len()] as char)
+        .collect()
+}
+
+fn normalize_claim_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn validate_name(name: &str) -> ServiceResult<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::validation("Player name cannot be empty"));
+    }
+    if name.chars().count() > 64 {
+        return Err(ServiceError::validation(
+            "Player name cannot be longer than 64 characters",
+        ));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::models::campaign::{NewCampaign, NewCharacter};
+    use crate::services::{GameSessionService, PartyService, SessionTotals, StartSessionInput};
+    use crate::test_utils::setup_test_db;
+
+    fn create_test_campaign(conn: &mut SqliteConnection) -> String {
+        let campaign_id = Uuid::new_v4().to_string();
+        let campaign = NewCampaign::new(&campaign_id, "West Marches");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        campaign_id
+    }
+
+    fn create_pc(conn: &mut SqliteConnection, campaign_id: &str, name: &str) -> String {
+        let id = Uuid::new_v4().to_string();
+        let pc = NewCharacter::new_pc(&id, Some(campaign_id), name, "");
+        insert_character(conn, &pc).expect("Failed to create PC");
+        id
+    }
+
+    fn play_session(conn: &mut SqliteConnection, campaign_id: &str, party_id: Option<&str>) {
+        let mut sessions = GameSessionService::new(conn);
+        let mut input = StartSessionInput::new(campaign_id);
+        if let Some(party_id) = party_id {
+            input = input.with_party(party_id);
+        }
+        let session = sessions.start(input).unwrap();
+        sessions
+            .end(&session.id, &SessionTotals::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_create_and_rename_updates_characters() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let aria = create_pc(&mut conn, &campaign_id, "Aria");
+        let mut service = PlayerService::new(&mut conn);
+
+        let contact = PlayerContact {
+            email: Some("alice@example.com".to_string()),
+            discord: Some("  ".to_string()),
+            ..Default::default()
+        };
+        let alice = service.create(&campaign_id, "Alice", &contact).unwrap();
+        assert_eq!(alice.email.as_deref(), Some("alice@example.com"));
+        assert!(alice.discord.is_none());
+        assert!(service
+            .create(&campaign_id, "alice", &PlayerContact::default())
+            .is_err());
+
+        service.assign_character(&alice.id, &aria).unwrap();
+        let input = UpdatePlayerInput {
+            name: Some("Alicia".to_string()),
+            email: Some(None),
+            ..Default::default()
+        };
+        let alicia = service.update(&alice.id, input).unwrap();
+        assert!(alicia.email.is_none());
+
+        let characters = service.list_characters(&alice.id).unwrap();
+        assert_eq!(characters[0].player_name.as_deref(), Some("Alicia"));
+    }
+
+    #[test]
+    fn test_attendance_follows_party_membership() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let aria = create_pc(&mut conn, &campaign_id, "Aria");
+        let (tuesday, saturday) = {
+            let mut parties = PartyService::new(&mut conn);
+            let tuesday = parties.create(&campaign_id, "Tuesday", None, None).unwrap();
+            let saturday = parties
+                .create(&campaign_id, "Saturday", None, None)
+                .unwrap();
+            parties.add_member(&tuesday.id, &aria).unwrap();
+            (tuesday.id, saturday.id)
+        };
+        let player_id = {
+            let mut service = PlayerService::new(&mut conn);
+            let alice = service
+                .create(&campaign_id, "Alice", &PlayerContact::default())
+                .unwrap();
+            service.assign_character(&alice.id, &aria).unwrap();
+            alice.id
+        };
+
+        play_session(&mut conn, &campaign_id, Some(&tuesday));
+        play_session(&mut conn, &campaign_id, Some(&saturday));
+        play_session(&mut conn, &campaign_id, None);
+
+        let attendance = PlayerService::new(&mut conn)
+            .attendance(&player_id)
+            .unwrap();
+        assert_eq!(attendance.campaign_sessions, 3);
+        assert_eq!(attendance.sessions.len(), 2);
+        assert_eq!(attendance.sessions[0].session_number, 3);
+        assert_eq!(attendance.sessions[1].character_name, "Aria");
+    }
+
+    #[test]
+    fn test_redeem_claim_for_existing_character() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let aria = create_pc(&mut conn, &campaign_id, "Aria");
+        let mut service = PlayerService::new(&mut conn);
+        let alice = service
+            .create(&campaign_id, "Alice", &PlayerContact::default())
+            .unwrap();
+
+        let claim = service
+            .create_claim(&alice.id, Some(&aria), Some(7))
+            .unwrap();
+        assert_eq!(claim.code.len(), CLAIM_CODE_LENGTH);
+        assert!(claim.expires_at.is_some());
+
+        let shared = claim.display_code().to_lowercase();
+        let character = service.redeem_claim(&shared, None).unwrap();
+        assert_eq!(character.id, aria);
+        assert_eq!(character.player_name.as_deref(), Some("Alice"));
+        assert_eq!(
+            service.get_for_character(&aria).unwrap().unwrap().id,
+            alice.id
+        );
+
+        let again = service.redeem_claim(&claim.code, None);
+        assert!(matches!(again, Err(ServiceError::Validation(_))));
+    }
+
+    #[test]
+    fn test_redeem_claim_with_submission() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = PlayerService::new(&mut conn);
+        let alice = service
+            .create(&campaign_id, "Alice", &PlayerContact::default())
+            .unwrap();
+        let claim = service.create_claim(&alice.id, None, None).unwrap();
+
+        assert!(service.redeem_claim(&claim.code, None).is_err());
+        assert!(service
+            .redeem_claim(&claim.code, Some("{not json"))
+            .is_err());
+
+        let json = r#"{"name": "Bram", "ability_scores": [15, 14, 13, 12, 10, 8]}"#;
+        let character = service.redeem_claim(&claim.code, Some(json)).unwrap();
+        assert_eq!(character.name, "Bram");
+        assert_eq!(character.campaign_id.as_deref(), Some(campaign_id.as_str()));
+        assert_eq!(character.strength, 15);
+        assert_eq!(service.list_characters(&alice.id).unwrap().len(), 1);
+
+        let claims = service.list_claims(&campaign_id).unwrap();
+        assert_eq!(claims[0].redeemed_character_id, Some(character.id));
+    }
+
+    #[test]
+    fn test_claim_code_normalization() {
+        assert_eq!(normalize_claim_code(" abcd-2345 "), "ABCD2345");
+        let code = generate_claim_code();
+        assert_eq!(code.len(), CLAIM_CODE_LENGTH);
+        assert!(code.bytes().all(|b| CLAIM_CODE_ALPHABET.contains(&b)));
+    }
+}
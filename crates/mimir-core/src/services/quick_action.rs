@@ -0,0 +1,483 @@
+//! Quick Action Service
+//!
+//! Business logic for campaign quick actions: named bundles of scene changes
+//! triggered together from the DM soundboard. The map steps of an action are
+//! applied in a single transaction so a scene transition either happens in
+//! full or not at all.
+
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    NewFogRevealedArea, NewQuickAction, QuickAction, QuickActionResponse, QuickActionStep,
+    UpdateLightSource, UpdateMapPoi, UpdateMapTrap, UpdateQuickAction, UpdateTokenPlacement,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Input for creating a quick action.
+#[derive(Debug, Clone)]
+pub struct CreateQuickActionInput {
+    /// Campaign the action belongs to
+    pub campaign_id: String,
+    /// Button label
+    pub name: String,
+    /// Optional note on what the action does
+    pub description: Option<String>,
+    /// Steps to run, in order
+    pub steps: Vec<QuickActionStep>,
+}
+
+impl CreateQuickActionInput {
+    /// Create input for a campaign quick action.
+    pub fn new(
+        campaign_id: impl Into<String>,
+        name: impl Into<String>,
+        steps: Vec<QuickActionStep>,
+    ) -> Self {
+        Self {
+            campaign_id: campaign_id.into(),
+            name: name.into(),
+            description: None,
+            steps,
+        }
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Input for updating a quick action.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateQuickActionInput {
+    /// New button label
+    pub name: Option<String>,
+    /// New description (Some(None) clears it)
+    pub description: Option<Option<String>>,
+    /// Replacement steps
+    pub steps: Option<Vec<QuickActionStep>>,
+}
+
+/// Service for quick action management and execution.
+pub struct QuickActionService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> QuickActionService<'a> {
+    /// Create a new quick action service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Create a quick action at the end of the campaign's soundboard.
+    pub fn create(&mut self, input: CreateQuickActionInput) -> ServiceResult<QuickActionResponse> {
+        if !dal::campaign_exists(self.conn, &input.campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", &input.campaign_id));
+        }
+        validate_name(&input.name)?;
+        validate_steps(&input.steps)?;
+
+        let id = Uuid::new_v4().to_string();
+        let steps = serialize_steps(&input.steps)?;
+        let sort_order = dal::next_quick_action_sort_order(self.conn, &input.campaign_id)?;
+
+        let mut new_action = NewQuickAction::new(&id, &input.campaign_id, &input.name, &steps)
+            .with_sort_order(sort_order);
+        if let Some(ref description) = input.description {
+            new_action = new_action.with_description(description);
+        }
+
+        dal::insert_quick_action(self.conn, &new_action)?;
+        self.get_required(&id)
+    }
+
+    /// Get a quick action by ID.
+    pub fn get(&mut self, id: &str) -> ServiceResult<Option<QuickActionResponse>> {
+        dal::get_quick_action_optional(self.conn, id)?
+            .map(to_response)
+            .transpose()
+    }
+
+    /// List a campaign's quick actions in soundboard order.
+    pub fn list_for_campaign(
+        &mut self,
+        campaign_id: &str,
+    ) -> ServiceResult<Vec<QuickActionResponse>> {
+        dal::list_quick_actions(self.conn, campaign_id)?
+            .into_iter()
+            .map(to_response)
+            .collect()
+    }
+
+    /// Update a quick action's name, description, or steps.
+    pub fn update(
+        &mut self,
+        id: &str,
+        input: UpdateQuickActionInput,
+    ) -> ServiceResult<QuickActionResponse> {
+        if let Some(ref name) = input.name {
+            validate_name(name)?;
+        }
+        let steps = match input.steps {
+            Some(ref steps) => {
+                validate_steps(steps)?;
+                Some(serialize_steps(steps)?)
+            }
+            None => None,
+        };
+
+        let now = now_rfc3339();
+        let update = UpdateQuickAction {
+            name: input.name.as_deref(),
+            description: input.description.as_ref().map(|d| d.as_deref()),
+            steps: steps.as_deref(),
+            sort_order: None,
+            updated_at: Some(&now),
+        };
+
+        let rows = dal::update_quick_action(self.conn, id, &update)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("QuickAction", id));
+        }
+        self.get_required(id)
+    }
+
+    /// Reorder a campaign's quick actions.
+    ///
+    /// `action_ids` lists the campaign's actions in their new order.
+    pub fn reorder(
+        &mut self,
+        campaign_id: &str,
+        action_ids: &[String],
+    ) -> ServiceResult<Vec<QuickActionResponse>> {
+        self.conn.transaction(|conn| {
+            let existing = dal::list_quick_actions(conn, campaign_id)?;
+            let now = now_rfc3339();
+
+            for (index, action_id) in action_ids.iter().enumerate() {
+                if !existing.iter().any(|a| &a.id == action_id) {
+                    return Err(ServiceError::validation(format!(
+                        "Quick action {} is not in this campaign",
+                        action_id
+                    )));
+                }
+                let update = UpdateQuickAction::set_sort_order(index as i32 + 1, &now);
+                dal::update_quick_action(conn, action_id, &update)?;
+            }
+            Ok(())
+        })?;
+
+        self.list_for_campaign(campaign_id)
+    }
+
+    /// Delete a quick action.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_quick_action(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("QuickAction", id));
+        }
+        Ok(())
+    }
+
+    /// Apply the map steps of a quick action in a single transaction.
+    ///
+    /// Every referenced map, light, token, POI, and trap must belong to the
+    /// action's campaign; if any step fails, none are applied. Display steps
+    /// are skipped - the caller carries them out after this returns.
+    ///
+    /// Returns the IDs of the maps that changed, in first-touched order.
+    pub fn apply_map_steps(&mut self, action: &QuickActionResponse) -> ServiceResult<Vec<String>> {
+        let campaign_id = action.campaign_id.as_str();
+
+        self.conn.transaction(|conn| {
+            let now = now_rfc3339();
+            let mut map_ids: Vec<String> = Vec::new();
+
+            for step in action.steps.iter().filter(|s| s.is_map_step()) {
+                let map_id = match step {
+                    QuickActionStep::RevealRect {
+                        map_id,
+                        x,
+                        y,
+                        width,
+                        height,
+                    } => {
+                        ensure_map_in_campaign(conn, map_id, campaign_id)?;
+                        let id = Uuid::new_v4().to_string();
+                        let area = NewFogRevealedArea::rect(&id, map_id, *x, *y, *width, *height);
+                        dal::insert_fog_revealed_area(conn, &area)?;
+                        map_id.clone()
+                    }
+                    QuickActionStep::RevealCircle {
+                        map_id,
+                        x,
+                        y,
+                        radius,
+                    } => {
+                        ensure_map_in_campaign(conn, map_id, campaign_id)?;
+                        let id = Uuid::new_v4().to_string();
+                        let area = NewFogRevealedArea::circle(&id, map_id, *x, *y, *radius);
+                        dal::insert_fog_revealed_area(conn, &area)?;
+                        map_id.clone()
+                    }
+                    QuickActionStep::SetLight { light_id, active } => {
+                        let light = dal::get_light_source_optional(conn, light_id)?
+                            .ok_or_else(|| ServiceError::not_found("LightSource", light_id))?;
+                        ensure_map_in_campaign(conn, &light.map_id, campaign_id)?;
+                        let update = if *active {
+                            UpdateLightSource::turn_on(&now)
+                        } else {
+                            UpdateLightSource::turn_off(&now)
+                        };
+                        dal::update_light_source(conn, light_id, &update)?;
+                        light.map_id
+                    }
+                    QuickActionStep::SetTokenHidden { token_id, hidden } => {
+                        let token = dal::get_token_placement_optional(conn, token_id)?
+                            .ok_or_else(|| ServiceError::not_found("TokenPlacement", token_id))?;
+                        ensure_map_in_campaign(conn, &token.map_id, campaign_id)?;
+                        dal::update_token_placement(
+                            conn,
+                            token_id,
+                            &UpdateTokenPlacement::set_hidden(*hidden),
+                        )?;
+                        token.map_id
+                    }
+                    QuickActionStep::SetPoiVisible { poi_id, visible } => {
+                        let poi = dal::get_map_poi_optional(conn, poi_id)?
+                            .ok_or_else(|| ServiceError::not_found("MapPoi", poi_id))?;
+                        ensure_map_in_campaign(conn, &poi.map_id, campaign_id)?;
+                        dal::update_map_poi(conn, poi_id, &UpdateMapPoi::set_visible(*visible, &now))?;
+                        poi.map_id
+                    }
+                    QuickActionStep::SetTrapVisible { trap_id, visible } => {
+                        let trap = dal::get_map_trap_optional(conn, trap_id)?
+                            .ok_or_else(|| ServiceError::not_found("MapTrap", trap_id))?;
+                        ensure_map_in_campaign(conn, &trap.map_id, campaign_id)?;
+                        dal::update_map_trap(
+                            conn,
+                            trap_id,
+                            &UpdateMapTrap::set_visible(*visible, &now),
+                        )?;
+                        trap.map_id
+                    }
+                    _ => continue,
+                };
+
+                if !map_ids.contains(&map_id) {
+                    map_ids.push(map_id);
+                }
+            }
+
+            Ok(map_ids)
+        })
+    }
+
+    fn get_required(&mut self, id: &str) -> ServiceResult<QuickActionResponse> {
+        self.get(id)?
+            .ok_or_else(|| ServiceError::not_found("QuickAction", id))
+    }
+}
+
+fn to_response(action: QuickAction) -> ServiceResult<QuickActionResponse> {
+    let id = action.id.clone();
+    QuickActionResponse::try_from(action).map_err(|e| {
+        ServiceError::validation(format!("Quick action {} has invalid steps: {}", id, e))
+    })
+}
+
+fn serialize_steps(steps: &[QuickActionStep]) -> ServiceResult<String> {
+    serde_json::to_string(steps)
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize steps: {}", e)))
+}
+
+fn validate_name(name: &str) -> ServiceResult<()> {
+    if name.trim().is_empty() {
+        return Err(ServiceError::validation("Quick action name cannot be empty"));
+    }
+    Ok(())
+}
+
+fn validate_steps(steps: &[QuickActionStep]) -> ServiceResult<()> {
+    if steps.is_empty() {
+        return Err(ServiceError::validation(
+            "A quick action needs at least one step",
+        ));
+    }
+
+    for step in steps {
+        match step {
+            QuickActionStep::RevealRect { width, height, .. } if *width <= 0.0 || *height <= 0.0 => {
+                return Err(ServiceError::validation(
+                    "Reveal area width and height must be positive",
+                ));
+            }
+            QuickActionStep::RevealCircle { radius, .. } if *radius <= 0.0 => {
+                return Err(ServiceError::validation("Reveal radius must be positive"));
+            }
+            QuickActionStep::PlayAmbience { track } if track.trim().is_empty() => {
+                return Err(ServiceError::validation("Ambience track cannot be empty"));
+            }
+            QuickActionStep::StartTimer { timer, .. }
+                if !matches!(timer.as_str(), "turn" | "break" | "session") =>
+            {
+                return Err(ServiceError::validation(format!(
+                    "Unknown timer '{}'. Expected turn, break, or session",
+                    timer
+                )));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn ensure_map_in_campaign(
+    conn: &mut SqliteConnection,
+    map_id: &str,
+    campaign_id: &str,
+) -> ServiceResult<()> {
+    match dal::get_map_optional(conn, map_id)? {
+        Some(map) if map.campaign_id == campaign_id => Ok(()),
+        _ => Err(ServiceError::not_found("Map", map_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_campaign_asset, insert_map};
+    use crate::models::campaign::{NewCampaign, NewCampaignAsset, NewLightSource, NewMap};
+    use crate::test_utils::setup_test_db;
+
+    fn setup_test_map(conn: &mut SqliteConnection) -> String {
+        let campaign = NewCampaign::new("camp-1", "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+
+        let asset = NewCampaignAsset::for_campaign(
+            "asset-1",
+            "camp-1",
+            "dungeon.uvtt",
+            "application/octet-stream",
+            "/blobs/dungeon.uvtt",
+        );
+        insert_campaign_asset(conn, &asset).expect("Failed to create asset");
+
+        let map = NewMap::for_campaign("map-1", "camp-1", "Dungeon", "asset-1");
+        insert_map(conn, &map).expect("Failed to create map");
+
+        let light = NewLightSource::torch("light-1", "map-1", 3, 4);
+        dal::insert_light_source(conn, &light).expect("Failed to create light");
+
+        "camp-1".to_string()
+    }
+
+    fn vault_steps() -> Vec<QuickActionStep> {
+        vec![
+            QuickActionStep::RevealRect {
+                map_id: "map-1".to_string(),
+                x: 0.0,
+                y: 0.0,
+                width: 10.0,
+                height: 5.0,
+            },
+            QuickActionStep::SetLight {
+                light_id: "light-1".to_string(),
+                active: false,
+            },
+            QuickActionStep::PlayAmbience {
+                track: "vault-hum".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_create_and_list() {
+        let mut conn = setup_test_db();
+        let campaign_id = setup_test_map(&mut conn);
+        let mut service = QuickActionService::new(&mut conn);
+
+        let first = service
+            .create(CreateQuickActionInput::new(&campaign_id, "Open the vault", vault_steps()))
+            .expect("Failed to create");
+        let second = service
+            .create(
+                CreateQuickActionInput::new(&campaign_id, "Lights out", vault_steps())
+                    .with_description("Plunge the room into darkness"),
+            )
+            .expect("Failed to create");
+
+        assert_eq!(first.sort_order, 1);
+        assert_eq!(second.sort_order, 2);
+        assert_eq!(first.steps, vault_steps());
+
+        let reordered = service
+            .reorder(&campaign_id, &[second.id.clone(), first.id.clone()])
+            .expect("Failed to reorder");
+        assert_eq!(reordered[0].id, second.id);
+    }
+
+    #[test]
+    fn test_create_validates_steps() {
+        let mut conn = setup_test_db();
+        let campaign_id = setup_test_map(&mut conn);
+        let mut service = QuickActionService::new(&mut conn);
+
+        let result = service.create(CreateQuickActionInput::new(&campaign_id, "Nothing", vec![]));
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+
+        let bad_timer = vec![QuickActionStep::StartTimer {
+            timer: "round".to_string(),
+            limit_secs: None,
+            label: None,
+        }];
+        let result = service.create(CreateQuickActionInput::new(&campaign_id, "Timer", bad_timer));
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+    }
+
+    #[test]
+    fn test_apply_map_steps() {
+        let mut conn = setup_test_db();
+        let campaign_id = setup_test_map(&mut conn);
+        let mut service = QuickActionService::new(&mut conn);
+
+        let action = service
+            .create(CreateQuickActionInput::new(&campaign_id, "Open the vault", vault_steps()))
+            .expect("Failed to create");
+        let map_ids = service.apply_map_steps(&action).expect("Failed to apply");
+        assert_eq!(map_ids, vec!["map-1".to_string()]);
+
+        assert_eq!(dal::count_fog_revealed_areas(&mut conn, "map-1").unwrap(), 1);
+        let light = dal::get_light_source(&mut conn, "light-1").unwrap();
+        assert!(!light.is_active());
+    }
+
+    #[test]
+    fn test_apply_map_steps_is_atomic() {
+        let mut conn = setup_test_db();
+        let campaign_id = setup_test_map(&mut conn);
+        let mut service = QuickActionService::new(&mut conn);
+
+        let mut steps = vault_steps();
+        steps.push(QuickActionStep::SetTokenHidden {
+            token_id: "missing-token".to_string(),
+            hidden: false,
+        });
+        let action = service
+            .create(CreateQuickActionInput::new(&campaign_id, "Broken", steps))
+            .expect("Failed to create");
+
+        let result = service.apply_map_steps(&action);
+        assert!(matches!(result, Err(ServiceError::NotFound { .. })));
+
+        // Earlier steps were rolled back
+        assert_eq!(dal::count_fog_revealed_areas(&mut conn, "map-1").unwrap(), 0);
+        let light = dal::get_light_source(&mut conn, "light-1").unwrap();
+        assert!(light.is_active());
+    }
+}
@@ -0,0 +1,455 @@
+//! Racial Traits
+//!
+//! Reads what a race and subrace grant from their catalog JSON (ability
+//! score increases, walking speed, darkvision, damage resistances,
+//! proficiencies, and innate spells) so character creation can apply them
+//! instead of expecting callers to work them into the character by hand.
+
+use diesel::SqliteConnection;
+use serde_json::Value;
+
+use super::character::{extract_race_proficiencies, ProficiencyEntry};
+use super::feat_effects::ability_name;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::ProficiencyType;
+use crate::services::ServiceResult;
+
+/// Ability abbreviations in ability score order [STR, DEX, CON, INT, WIS, CHA].
+const ABILITY_ORDER: [&str; 6] = ["str", "dex", "con", "int", "wis", "cha"];
+
+/// Walking speed when a race doesn't list one.
+const DEFAULT_SPEED: i32 = 30;
+
+/// Ability increases the player picks from a list (e.g., a Half-Elf's two +1s).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RacialAbilityChoice {
+    /// Abilities to choose from (abbreviations)
+    pub from: Vec<String>,
+    /// Increase for each pick, in order
+    pub amounts: Vec<i32>,
+}
+
+/// What a race (and optionally subrace) grants a new character.
+#[derive(Debug, Clone)]
+pub struct RaceTraits {
+    /// Fixed ability increases as (abbreviation, amount)
+    pub ability_increases: Vec<(String, i32)>,
+    /// Ability increases the player chooses
+    pub ability_choice: Option<RacialAbilityChoice>,
+    /// Walking speed in feet
+    pub speed: i32,
+    /// Darkvision range in feet
+    pub darkvision: Option<i32>,
+    /// Damage types resisted
+    pub resistances: Vec<String>,
+    /// Proficiencies granted outright
+    pub(super) proficiencies: Vec<ProficiencyEntry>,
+    /// Spells known at 1st level as (name, source)
+    pub innate_spells: Vec<(String, String)>,
+}
+
+impl RaceTraits {
+    /// Parse traits from a race's catalog JSON, with a subrace's data layered
+    /// on top. Subrace speed and darkvision replace the race's; its ability
+    /// increases, resistances, proficiencies, and spells are added unless its
+    /// `overwrite` block says to replace them.
+    pub fn from_data(race: &Value, subrace: Option<&Value>) -> Self {
+        let overwrites = |field: &str| {
+            subrace
+                .and_then(|s| s.get("overwrite"))
+                .and_then(|o| o.get(field))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+        };
+
+        let mut ability_increases = Vec::new();
+        let mut ability_choice = None;
+        if !overwrites("ability") {
+            parse_ability(race, &mut ability_increases, &mut ability_choice);
+        }
+        if let Some(subrace) = subrace {
+            parse_ability(subrace, &mut ability_increases, &mut ability_choice);
+        }
+
+        let speed = subrace
+            .and_then(parse_speed)
+            .or_else(|| parse_speed(race))
+            .unwrap_or(DEFAULT_SPEED);
+        let darkvision = subrace
+            .and_then(|s| s.get("darkvision"))
+            .or_else(|| race.get("darkvision"))
+            .and_then(|v| v.as_i64())
+            .map(|v| v as i32);
+
+        let mut resistances = parse_resistances(race);
+        let mut proficiencies = extract_race_proficiencies(race);
+        let mut innate_spells = parse_innate_spells(race);
+        if let Some(subrace) = subrace {
+            for resistance in parse_resistances(subrace) {
+                if !resistances.contains(&resistance) {
+                    resistances.push(resistance);
+                }
+            }
+            if overwrites("skillProficiencies") {
+                proficiencies.retain(|p| p.prof_type != ProficiencyType::Skill);
+            }
+            if overwrites("languageProficiencies") {
+                proficiencies.retain(|p| p.prof_type != ProficiencyType::Language);
+            }
+            proficiencies.extend(extract_race_proficiencies(subrace));
+            innate_spells.extend(parse_innate_spells(subrace));
+        }
+
+        Self {
+            ability_increases,
+            ability_choice,
+            speed,
+            darkvision,
+            resistances,
+            proficiencies,
+            innate_spells,
+        }
+    }
+
+    /// Add the racial ability increases to a set of base scores
+    /// [STR, DEX, CON, INT, WIS, CHA].
+    ///
+    /// `choices` picks abilities (abbreviations or full names) for races that
+    /// offer a choice; picks not on the list and repeated picks are ignored.
+    pub fn apply_ability_increases(&self, scores: &mut [i32; 6], choices: &[String]) {
+        for (ability, amount) in &self.ability_increases {
+            if let Some(index) = ability_index(ability) {
+                scores[index] += amount;
+            }
+        }
+
+        if let Some(choice) = &self.ability_choice {
+            let mut picked: Vec<&String> = Vec::new();
+            for c in choices {
+                let ability = choice.from.iter().find(|a| {
+                    a.eq_ignore_ascii_case(c)
+                        || ability_name(a).is_some_and(|name| name.eq_ignore_ascii_case(c))
+                });
+                if let Some(ability) = ability {
+                    if !picked.contains(&ability) {
+                        picked.push(ability);
+                    }
+                }
+            }
+            for (ability, amount) in picked.into_iter().zip(&choice.amounts) {
+                if let Some(index) = ability_index(ability) {
+                    scores[index] += amount;
+                }
+            }
+        }
+    }
+
+    /// Resistances as a JSON array for storage.
+    pub fn resistances_json(&self) -> String {
+        serde_json::to_string(&self.resistances).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+/// Position of an ability abbreviation in ability score order.
+fn ability_index(abbreviation: &str) -> Option<usize> {
+    ABILITY_ORDER.iter().position(|a| *a == abbreviation)
+}
+
+/// Read ability increases from the first `ability` entry (later entries are
+/// alternative options, which the first stands in for).
+fn parse_ability(
+    data: &Value,
+    increases: &mut Vec<(String, i32)>,
+    choice: &mut Option<RacialAbilityChoice>,
+) {
+    let ability = match data
+        .get("ability")
+        .and_then(|v| v.as_array())
+        .and_then(|a| a.first())
+        .and_then(|v| v.as_object())
+    {
+        Some(ability) => ability,
+        None => return,
+    };
+
+    for (key, value) in ability {
+        if key == "choose" {
+            // Either {"from": [...], "count": n, "amount": n} or
+            // {"weighted": {"from": [...], "weights": [2, 1]}}
+            let (spec, amounts) = match value.get("weighted") {
+                Some(weighted) => {
+                    let weights: Vec<i32> = weighted
+                        .get("weights")
+                        .and_then(|v| v.as_array())
+                        .map(|a| {
+                            a.iter()
+                                .filter_map(|w| w.as_i64())
+                                .map(|w| w as i32)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (weighted, weights)
+                }
+                None => {
+                    let count = value.get("count").and_then(|v| v.as_u64()).unwrap_or(1);
+                    let amount = value.get("amount").and_then(|v| v.as_i64()).unwrap_or(1);
+                    (value, vec![amount as i32; count as usize])
+                }
+            };
+            let from: Vec<String> = spec
+                .get("from")
+                .and_then(|v| v.as_array())
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|v| v.as_str())
+                        .filter(|a| ability_index(a).is_some())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default();
+            if !from.is_empty() && !amounts.is_empty() {
+                *choice = Some(RacialAbilityChoice { from, amounts });
+            }
+        } else if let (Some(_), Some(amount)) = (ability_index(key), value.as_i64()) {
+            increases.push((key.clone(), amount as i32));
+        }
+    }
+}
+
+/// Read the walking speed, which is either a number or `{"walk": n, ...}`.
+fn parse_speed(data: &Value) -> Option<i32> {
+    let speed = data.get("speed")?;
+    speed
+        .as_i64()
+        .or_else(|| speed.get("walk").and_then(|v| v.as_i64()))
+        .map(|v| v as i32)
+}
+
+/// Read fixed damage resistances; choices (e.g., a Dragonborn's ancestry)
+/// are left to the player.
+fn parse_resistances(data: &Value) -> Vec<String> {
+    data.get("resist")
+        .and_then(|v| v.as_array())
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read the spells a race grants at 1st level.
+///
+/// Only races with a single `additionalSpells` option are read; several
+/// options means the player picks one, as do `choose` entries.
+fn parse_innate_spells(data: &Value) -> Vec<(String, String)> {
+    let options = match data.get("additionalSpells").and_then(|v| v.as_array()) {
+        Some(options) if options.len() == 1 => options,
+        _ => return Vec::new(),
+    };
+
+    let mut spells = Vec::new();
+    for key in ["innate", "known"] {
+        if let Some(first_level) = options[0].get(key).and_then(|v| v.get("1")) {
+            collect_spell_refs(first_level, &mut spells);
+        }
+    }
+    spells
+}
+
+/// Collect spell references from a nested additionalSpells block such as
+/// `{"daily": {"1": ["hellish rebuke#2"]}}` or `["thaumaturgy#c"]`.
+fn collect_spell_refs(value: &Value, spells: &mut Vec<(String, String)>) {
+    match value {
+        Value::String(s) => {
+            // "name|source#c": drop the cast-level suffix, then split the source
+            let reference = s.split('#').next().unwrap_or(s.as_str());
+            let mut parts = reference.split('|');
+            let name = parts.next().unwrap_or(reference).trim();
+            let source = parts
+                .next()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_uppercase())
+                .unwrap_or_else(|| "PHB".to_string());
+            if !name.is_empty() && !spells.iter().any(|(n, _)| n == name) {
+                spells.push((name.to_string(), source));
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_spell_refs(item, spells);
+            }
+        }
+        Value::Object(obj) => {
+            for (key, item) in obj {
+                if key != "choose" {
+                    collect_spell_refs(item, spells);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Load a race's traits from the catalog, layering on the subrace if given.
+///
+/// Returns `None` when the race isn't in the catalog. A subrace that isn't
+/// in the catalog is skipped.
+pub fn load_race_traits(
+    conn: &mut SqliteConnection,
+    race_name: &str,
+    race_source: &str,
+    subrace: Option<(&str, &str)>,
+) -> ServiceResult<Option<RaceTraits>> {
+    let race = match catalog_dal::get_race_by_name(conn, race_name, race_source)? {
+        Some(race) => race,
+        None => return Ok(None),
+    };
+    let race_data: Value = serde_json::from_str(&race.data).unwrap_or_default();
+
+    let subrace_data: Option<Value> = match subrace {
+        Some((name, source)) => catalog_dal::get_subrace_by_name(conn, race_name, name, source)?
+            .and_then(|s| serde_json::from_str(&s.data).ok()),
+        None => None,
+    };
+
+    Ok(Some(RaceTraits::from_data(
+        &race_data,
+        subrace_data.as_ref(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn dwarf() -> Value {
+        json!({
+            "name": "Dwarf",
+            "speed": 25,
+            "ability": [{"con": 2}],
+            "darkvision": 60,
+            "resist": ["poison"],
+            "languageProficiencies": [{"common": true, "dwarvish": true}]
+        })
+    }
+
+    #[test]
+    fn test_race_traits() {
+        let traits = RaceTraits::from_data(&dwarf(), None);
+        assert_eq!(traits.ability_increases, vec![("con".to_string(), 2)]);
+        assert_eq!(traits.speed, 25);
+        assert_eq!(traits.darkvision, Some(60));
+        assert_eq!(traits.resistances, vec!["poison"]);
+        assert_eq!(traits.resistances_json(), r#"["poison"]"#);
+        assert_eq!(traits.proficiencies.len(), 2);
+    }
+
+    #[test]
+    fn test_subrace_layered_on_race() {
+        let hill = json!({
+            "name": "Hill",
+            "raceName": "Dwarf",
+            "ability": [{"wis": 1}]
+        });
+        let traits = RaceTraits::from_data(&dwarf(), Some(&hill));
+        assert_eq!(
+            traits.ability_increases,
+            vec![("con".to_string(), 2), ("wis".to_string(), 1)]
+        );
+        assert_eq!(traits.speed, 25);
+
+        let mut scores = [15, 14, 13, 12, 10, 8];
+        traits.apply_ability_increases(&mut scores, &[]);
+        assert_eq!(scores, [15, 14, 15, 12, 11, 8]);
+    }
+
+    #[test]
+    fn test_subrace_overwrites_ability() {
+        let subrace = json!({
+            "name": "Variant",
+            "raceName": "Dwarf",
+            "ability": [{"str": 2}],
+            "speed": {"walk": 30},
+            "overwrite": {"ability": true}
+        });
+        let traits = RaceTraits::from_data(&dwarf(), Some(&subrace));
+        assert_eq!(traits.ability_increases, vec![("str".to_string(), 2)]);
+        assert_eq!(traits.speed, 30);
+    }
+
+    #[test]
+    fn test_ability_choice() {
+        let half_elf = json!({
+            "name": "Half-Elf",
+            "ability": [{"cha": 2, "choose": {"from": ["str", "dex", "con", "int", "wis"], "count": 2}}]
+        });
+        let traits = RaceTraits::from_data(&half_elf, None);
+        let choice = traits.ability_choice.clone().unwrap();
+        assert_eq!(choice.amounts, vec![1, 1]);
+
+        let mut scores = [10; 6];
+        let choices = vec![
+            "dex".to_string(),
+            "Constitution".to_string(),
+            "wis".to_string(),
+        ];
+        traits.apply_ability_increases(&mut scores, &choices);
+        assert_eq!(scores, [10, 11, 11, 10, 10, 12]);
+
+        // Repeated or off-list picks are skipped
+        let mut scores = [10; 6];
+        let choices = vec![
+            "dex".to_string(),
+            "dex".to_string(),
+            "cha".to_string(),
+            "str".to_string(),
+        ];
+        traits.apply_ability_increases(&mut scores, &choices);
+        assert_eq!(scores, [11, 11, 10, 10, 10, 12]);
+    }
+
+    #[test]
+    fn test_weighted_ability_choice() {
+        let lineage = json!({
+            "name": "Custom Lineage",
+            "ability": [{"choose": {"weighted": {"from": ["str", "dex", "con", "int", "wis", "cha"], "weights": [2]}}}]
+        });
+        let traits = RaceTraits::from_data(&lineage, None);
+        assert_eq!(traits.ability_choice.unwrap().amounts, vec![2]);
+    }
+
+    #[test]
+    fn test_innate_spells() {
+        let tiefling = json!({
+            "name": "Tiefling",
+            "additionalSpells": [{
+                "innate": {
+                    "3": {"daily": {"1": ["hellish rebuke#2"]}},
+                    "5": {"daily": {"1": ["darkness"]}}
+                },
+                "known": {"1": ["thaumaturgy#c"]},
+                "ability": "cha"
+            }]
+        });
+        let traits = RaceTraits::from_data(&tiefling, None);
+        assert_eq!(
+            traits.innate_spells,
+            vec![("thaumaturgy".to_string(), "PHB".to_string())]
+        );
+
+        // Several options means the player picks one
+        let choice = json!({
+            "name": "Variant",
+            "additionalSpells": [
+                {"known": {"1": ["light#c"]}},
+                {"known": {"1": ["druidcraft#c"]}}
+            ]
+        });
+        assert!(RaceTraits::from_data(&choice, None)
+            .innate_spells
+            .is_empty());
+    }
+}
@@ -0,0 +1,565 @@
+//! Reaction Service
+//!
+//! NPC reaction checks that remember who the party has been dealing with.
+//! A check rolls 2d6 or a d20 and adds the NPC's disposition (their own, or
+//! their faction's when they have none), the party's reputation with that
+//! faction, and any situational modifier. The total suggests an attitude,
+//! and every check is kept in the NPC's relationship history.
+//!
+//! On a d20 the disposition and reputation count double, so a given
+//! standing shifts the result by about as many attitude bands as it does
+//! on 2d6.
+
+use diesel::SqliteConnection;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{
+    Attitude, Character, Disposition, NewDisposition, NewNpcInteraction, NewPartyReputation,
+    NpcInteraction, PartyReputation, ReactionDice, UpdateDisposition, UpdatePartyReputation,
+    MAX_STANDING, MIN_STANDING,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Input for a reaction check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionCheckInput {
+    /// NPC the party is approaching
+    pub character_id: String,
+    /// Party the NPC is reacting to; defaults to the party of the session in
+    /// progress, or the campaign's only party
+    #[serde(default)]
+    pub party_id: Option<String>,
+    /// Dice to roll
+    #[serde(default)]
+    pub dice: ReactionDice,
+    /// Situational modifier (a gift, a threat, the speaker's Charisma)
+    #[serde(default)]
+    pub modifier: i32,
+    /// Use a physical dice result instead of rolling
+    #[serde(default)]
+    pub roll: Option<i32>,
+    /// What happened, kept in the NPC's history
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// Where an NPC's disposition in a check came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "faction", rename_all = "snake_case")]
+pub enum DispositionSource {
+    /// The NPC's own disposition
+    Npc,
+    /// The disposition of the NPC's faction
+    Faction(String),
+    /// No disposition set; counted as 0
+    None,
+}
+
+/// Result of a reaction check.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionCheck {
+    /// The check as recorded in the NPC's history
+    pub interaction: NpcInteraction,
+    /// Disposition applied
+    pub disposition: i32,
+    pub disposition_source: DispositionSource,
+    /// Party's reputation with the NPC's faction
+    pub reputation: i32,
+    /// Situational modifier applied
+    pub situational: i32,
+    /// Suggested attitude
+    pub attitude: Attitude,
+}
+
+/// A campaign's dispositions and party reputations.
+#[derive(Debug, Clone, Serialize)]
+pub struct RelationshipStandings {
+    pub dispositions: Vec<Disposition>,
+    pub reputations: Vec<PartyReputation>,
+}
+
+/// Service for NPC reaction checks and the standings behind them.
+pub struct ReactionService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> ReactionService<'a> {
+    /// Create a new reaction service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// A campaign's dispositions and party reputations.
+    pub fn standings(&mut self, campaign_id: &str) -> ServiceResult<RelationshipStandings> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        Ok(RelationshipStandings {
+            dispositions: dal::list_dispositions(self.conn, campaign_id)?,
+            reputations: dal::list_party_reputations(self.conn, campaign_id)?,
+        })
+    }
+
+    /// Set an NPC's own disposition, or clear it with `None` so their
+    /// faction's applies.
+    pub fn set_npc_disposition(
+        &mut self,
+        character_id: &str,
+        disposition: Option<i32>,
+    ) -> ServiceResult<Option<Disposition>> {
+        let npc = self.get_npc(character_id)?;
+        let campaign_id = npc_campaign(&npc)?;
+        let existing = dal::get_npc_disposition(self.conn, character_id)?;
+        let id = Uuid::new_v4().to_string();
+        let new = disposition.map(|d| NewDisposition::for_npc(&id, campaign_id, character_id, d));
+        self.save_disposition(existing, new)
+    }
+
+    /// Set a faction's disposition, or clear it with `None`.
+    pub fn set_faction_disposition(
+        &mut self,
+        campaign_id: &str,
+        faction: &str,
+        disposition: Option<i32>,
+    ) -> ServiceResult<Option<Disposition>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let faction = validate_faction(faction)?;
+        let existing = dal::get_faction_disposition(self.conn, campaign_id, faction)?;
+        let id = Uuid::new_v4().to_string();
+        let new = disposition.map(|d| NewDisposition::for_faction(&id, campaign_id, faction, d));
+        self.save_disposition(existing, new)
+    }
+
+    /// Set a party's reputation with a faction, or clear it with `None`.
+    pub fn set_party_reputation(
+        &mut self,
+        party_id: &str,
+        faction: &str,
+        reputation: Option<i32>,
+    ) -> ServiceResult<Option<PartyReputation>> {
+        let party = dal::get_party_optional(self.conn, party_id)?
+            .ok_or_else(|| ServiceError::not_found("Party", party_id))?;
+        let faction = validate_faction(faction)?;
+        let existing = dal::get_party_reputation(self.conn, party_id, faction)?;
+
+        let Some(reputation) = reputation else {
+            if let Some(existing) = existing {
+                dal::delete_party_reputation(self.conn, &existing.id)?;
+            }
+            return Ok(None);
+        };
+        validate_standing(reputation)?;
+        match existing {
+            Some(existing) => {
+                let now = now_rfc3339();
+                let update = UpdatePartyReputation {
+                    reputation: Some(reputation),
+                    updated_at: Some(&now),
+                };
+                dal::update_party_reputation(self.conn, &existing.id, &update)?;
+            }
+            None => {
+                let id = Uuid::new_v4().to_string();
+                let new =
+                    NewPartyReputation::new(&id, &party.campaign_id, party_id, faction, reputation);
+                dal::insert_party_reputation(self.conn, &new)?;
+            }
+        }
+        Ok(dal::get_party_reputation(self.conn, party_id, faction)?)
+    }
+
+    /// Make a reaction check against an NPC and record it in their history.
+    pub fn check(&mut self, input: ReactionCheckInput) -> ServiceResult<ReactionCheck> {
+        let npc = self.get_npc(&input.character_id)?;
+        let campaign_id = npc_campaign(&npc)?.to_string();
+        let roll = match input.roll {
+            Some(roll) => validate_roll(input.dice, roll)?,
+            None => roll_dice(input.dice),
+        };
+
+        let session = dal::get_active_game_session(self.conn, &campaign_id)?;
+        let party_id = match input.party_id {
+            Some(party_id) => {
+                let party = dal::get_party_optional(self.conn, &party_id)?
+                    .ok_or_else(|| ServiceError::not_found("Party", &party_id))?;
+                if party.campaign_id != campaign_id {
+                    return Err(ServiceError::validation(format!(
+                        "{} is not in this campaign",
+                        party.name
+                    )));
+                }
+                Some(party_id)
+            }
+            None => match session.as_ref().and_then(|s| s.party_id.clone()) {
+                Some(party_id) => Some(party_id),
+                None => {
+                    let mut parties = dal::list_parties(self.conn, &campaign_id)?;
+                    (parties.len() == 1).then(|| parties.remove(0).id)
+                }
+            },
+        };
+
+        let faction = npc
+            .faction
+            .as_deref()
+            .map(str::trim)
+            .filter(|f| !f.is_empty());
+        let (disposition, disposition_source) = match dal::get_npc_disposition(self.conn, &npc.id)?
+        {
+            Some(d) => (d.disposition, DispositionSource::Npc),
+            None => match faction {
+                Some(faction) => {
+                    match dal::get_faction_disposition(self.conn, &campaign_id, faction)? {
+                        Some(d) => (
+                            d.disposition,
+                            DispositionSource::Faction(faction.to_string()),
+                        ),
+                        None => (0, DispositionSource::None),
+                    }
+                }
+                None => (0, DispositionSource::None),
+            },
+        };
+        let reputation = match (party_id.as_deref(), faction) {
+            (Some(party_id), Some(faction)) => {
+                dal::get_party_reputation(self.conn, party_id, faction)?.map_or(0, |r| r.reputation)
+            }
+            _ => 0,
+        };
+
+        let weight = match input.dice {
+            ReactionDice::TwoD6 => 1,
+            ReactionDice::D20 => 2,
+        };
+        let modifier = (disposition + reputation) * weight + input.modifier;
+        let attitude = attitude_for(input.dice, roll + modifier);
+
+        let id = Uuid::new_v4().to_string();
+        let notes = input
+            .notes
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty());
+        let mut interaction = NewNpcInteraction::new(
+            &id,
+            &campaign_id,
+            &npc.id,
+            input.dice,
+            roll,
+            modifier,
+            attitude,
+        );
+        if let Some(party_id) = party_id.as_deref() {
+            interaction = interaction.with_party(party_id);
+        }
+        if let Some(session) = session.as_ref() {
+            interaction = interaction.with_session(&session.id);
+        }
+        if let Some(notes) = notes {
+            interaction = interaction.with_notes(notes);
+        }
+        dal::insert_npc_interaction(self.conn, &interaction)?;
+
+        Ok(ReactionCheck {
+            interaction: dal::get_npc_interaction(self.conn, &id)?,
+            disposition,
+            disposition_source,
+            reputation,
+            situational: input.modifier,
+            attitude,
+        })
+    }
+
+    /// An NPC's relationship history, most recent first.
+    pub fn history(&mut self, character_id: &str) -> ServiceResult<Vec<NpcInteraction>> {
+        self.get_npc(character_id)?;
+        dal::list_npc_interactions(self.conn, character_id).map_err(ServiceError::from)
+    }
+
+    /// Remove a check from an NPC's history.
+    pub fn delete_interaction(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_npc_interaction(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("NPC interaction", id));
+        }
+        Ok(())
+    }
+
+    // ── Private helpers ────────────────────────────────────────────────────
+
+    fn get_npc(&mut self, character_id: &str) -> ServiceResult<Character> {
+        let character = dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+        if character.is_npc == 0 {
+            return Err(ServiceError::validation(format!(
+                "{} is not an NPC",
+                character.name
+            )));
+        }
+        Ok(character)
+    }
+
+    /// Replace an existing disposition's value with the new one's, insert
+    /// the new one if there is none, or delete it given `None`.
+    fn save_disposition(
+        &mut self,
+        existing: Option<Disposition>,
+        new: Option<NewDisposition>,
+    ) -> ServiceResult<Option<Disposition>> {
+        let Some(new) = new else {
+            if let Some(existing) = existing {
+                dal::delete_disposition(self.conn, &existing.id)?;
+            }
+            return Ok(None);
+        };
+        validate_standing(new.disposition)?;
+
+        let id = match existing {
+            Some(existing) => {
+                let now = now_rfc3339();
+                let update = UpdateDisposition {
+                    disposition: Some(new.disposition),
+                    updated_at: Some(&now),
+                };
+                dal::update_disposition(self.conn, &existing.id, &update)?;
+                existing.id
+            }
+            None => dal::insert_disposition(self.conn, &new)?,
+        };
+        Ok(Some(dal::get_disposition(self.conn, &id)?))
+    }
+}
+
+/// The attitude a reaction total suggests.
+///
+/// 2d6: 2 or less hostile, 3-5 unfriendly, 6-8 indifferent, 9-11 friendly,
+/// 12 or more helpful. d20: 4 or less hostile, 5-8 unfriendly,
+/// 9-12 indifferent, 13-16 friendly, 17 or more helpful.
+pub fn attitude_for(dice: ReactionDice, total: i32) -> Attitude {
+    let bands = match dice {
+        ReactionDice::TwoD6 => [2, 5, 8, 11],
+        ReactionDice::D20 => [4, 8, 12, 16],
+    };
+    match bands.iter().position(|&top| total <= top) {
+        Some(0) => Attitude::Hostile,
+        Some(1) => Attitude::Unfriendly,
+        Some(2) => Attitude::Indifferent,
+        Some(_) => Attitude::Friendly,
+        None => Attitude::Helpful,
+    }
+}
+
+fn roll_dice(dice: ReactionDice) -> i32 {
+    let mut rng = rand::thread_rng();
+    match dice {
+        ReactionDice::TwoD6 => rng.gen_range(1..=6) + rng.gen_range(1..=6),
+        ReactionDice::D20 => rng.gen_range(1..=20),
+    }
+}
+
+fn validate_roll(dice: ReactionDice, roll: i32) -> ServiceResult<i32> {
+    let range = match dice {
+        ReactionDice::TwoD6 => 2..=12,
+        ReactionDice::D20 => 1..=20,
+    };
+    if !range.contains(&roll) {
+        return Err(ServiceError::validation(format!(
+            "A {} roll must be {}-{}, got {}",
+            dice.as_str(),
+            range.start(),
+            range.end(),
+            roll
+        )));
+    }
+    Ok(roll)
+}
+
+fn validate_standing(value: i32) -> ServiceResult<()> {
+    if !(MIN_STANDING..=MAX_STANDING).contains(&value) {
+        return Err(ServiceError::validation(format!(
+            "Disposition and reputation must be {} to +{}, got {}",
+            MIN_STANDING, MAX_STANDING, value
+        )));
+    }
+    Ok(())
+}
+
+fn validate_faction(faction: &str) -> ServiceResult<&str> {
+    let faction = faction.trim();
+    if faction.is_empty() {
+        return Err(ServiceError::validation("Faction name is required"));
+    }
+    Ok(faction)
+}
+
+fn npc_campaign(npc: &Character) -> ServiceResult<&str> {
+    npc.campaign_id
+        .as_deref()
+        .ok_or_else(|| ServiceError::validation(format!("{} is not in a campaign", npc.name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_game_session, insert_party,
+    };
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewGameSession, NewParty};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Lost Mine")).unwrap();
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Heroes")).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_npc("npc-1", Some("camp-1"), "Glasstaff").with_npc_info(
+                None,
+                None,
+                Some("Redbrands"),
+            ),
+        )
+        .unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_pc("pc-1", Some("camp-1"), "Aria", "Alice"),
+        )
+        .unwrap();
+    }
+
+    fn input(dice: ReactionDice, roll: i32) -> ReactionCheckInput {
+        ReactionCheckInput {
+            character_id: "npc-1".to_string(),
+            party_id: None,
+            dice,
+            modifier: 0,
+            roll: Some(roll),
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_attitude_bands() {
+        let two_d6: Vec<_> = [2, 3, 8, 9, 12]
+            .iter()
+            .map(|&t| attitude_for(ReactionDice::TwoD6, t))
+            .collect();
+        assert_eq!(
+            two_d6,
+            vec![
+                Attitude::Hostile,
+                Attitude::Unfriendly,
+                Attitude::Indifferent,
+                Attitude::Friendly,
+                Attitude::Helpful
+            ]
+        );
+        assert_eq!(attitude_for(ReactionDice::D20, -2), Attitude::Hostile);
+        assert_eq!(attitude_for(ReactionDice::D20, 12), Attitude::Indifferent);
+        assert_eq!(attitude_for(ReactionDice::D20, 17), Attitude::Helpful);
+    }
+
+    #[test]
+    fn test_check_combines_standings() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = ReactionService::new(&mut conn);
+
+        // No standings set: the roll alone decides
+        let plain = service.check(input(ReactionDice::TwoD6, 7)).unwrap();
+        assert_eq!(plain.attitude, Attitude::Indifferent);
+        assert_eq!(plain.disposition_source, DispositionSource::None);
+        // The campaign's only party is assumed
+        assert_eq!(plain.interaction.party_id.as_deref(), Some("party-1"));
+
+        service
+            .set_faction_disposition("camp-1", " Redbrands ", Some(-2))
+            .unwrap();
+        service
+            .set_party_reputation("party-1", "Redbrands", Some(-1))
+            .unwrap();
+        let mut hostile = input(ReactionDice::TwoD6, 7);
+        hostile.modifier = 1;
+        let check = service.check(hostile).unwrap();
+        assert_eq!(
+            check.disposition_source,
+            DispositionSource::Faction("Redbrands".to_string())
+        );
+        assert_eq!(check.interaction.modifier, -2);
+        assert_eq!(check.interaction.total, 5);
+        assert_eq!(check.attitude, Attitude::Unfriendly);
+
+        // An NPC's own disposition wins, and counts double on a d20
+        service.set_npc_disposition("npc-1", Some(3)).unwrap();
+        let check = service.check(input(ReactionDice::D20, 10)).unwrap();
+        assert_eq!(check.disposition_source, DispositionSource::Npc);
+        assert_eq!(check.interaction.modifier, 4);
+        assert_eq!(check.attitude, Attitude::Friendly);
+
+        // Clearing it falls back to the faction's
+        assert!(service
+            .set_npc_disposition("npc-1", None)
+            .unwrap()
+            .is_none());
+        let check = service.check(input(ReactionDice::TwoD6, 7)).unwrap();
+        assert_eq!(check.disposition, -2);
+    }
+
+    #[test]
+    fn test_check_records_history() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        insert_game_session(&mut conn, &NewGameSession::new("session-1", "camp-1", 1)).unwrap();
+        let mut service = ReactionService::new(&mut conn);
+
+        let mut first = input(ReactionDice::TwoD6, 12);
+        first.notes = Some("  Offered a bribe ".to_string());
+        let check = service.check(first).unwrap();
+        assert_eq!(check.interaction.session_id.as_deref(), Some("session-1"));
+        assert_eq!(check.interaction.notes.as_deref(), Some("Offered a bribe"));
+        assert_eq!(check.interaction.attitude_enum(), Some(Attitude::Helpful));
+
+        let rolled = service
+            .check(ReactionCheckInput {
+                roll: None,
+                ..input(ReactionDice::D20, 0)
+            })
+            .unwrap();
+        assert!((1..=20).contains(&rolled.interaction.roll));
+
+        let history = service.history("npc-1").unwrap();
+        assert_eq!(history.len(), 2);
+        service.delete_interaction(&check.interaction.id).unwrap();
+        assert_eq!(service.history("npc-1").unwrap().len(), 1);
+        assert!(service.delete_interaction(&check.interaction.id).is_err());
+    }
+
+    #[test]
+    fn test_check_validates_input() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = ReactionService::new(&mut conn);
+
+        assert!(service.check(input(ReactionDice::TwoD6, 1)).is_err());
+        assert!(service.check(input(ReactionDice::D20, 21)).is_err());
+        let mut pc = input(ReactionDice::TwoD6, 7);
+        pc.character_id = "pc-1".to_string();
+        assert!(service.check(pc).is_err());
+        let mut unknown_party = input(ReactionDice::TwoD6, 7);
+        unknown_party.party_id = Some("missing".to_string());
+        assert!(service.check(unknown_party).is_err());
+
+        assert!(service.set_npc_disposition("npc-1", Some(4)).is_err());
+        assert!(service
+            .set_faction_disposition("camp-1", "  ", Some(1))
+            .is_err());
+        assert!(service
+            .set_party_reputation("party-1", "Redbrands", Some(-4))
+            .is_err());
+        assert!(service.history("npc-1").unwrap().is_empty());
+    }
+}
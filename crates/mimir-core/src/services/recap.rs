@@ -0,0 +1,639 @@
+//! Recap Service
+//!
+//! Builds "previously on" recaps from a campaign's play history: the
+//! sessions in scope with what their module's play notes say happened, where
+//! each module (quest) stands, and the major events on the party timelines.
+//!
+//! Every recap is written twice. The DM version adds module descriptions,
+//! modules not started yet, and the dangling threads, DM notes, and
+//! consequences from the play notes. The player version leaves all of that
+//! out so it can be read aloud or shared as-is.
+//!
+//! Play notes are matched to sessions by their `# ... Session N` headings,
+//! as in the play notes template.
+
+use std::collections::HashSet;
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{Document, GameSession, Module, PartyTimelineEvent};
+use crate::services::{
+    CreateDocumentInput, DocumentService, ServiceError, ServiceResult, UpdateDocumentInput,
+};
+
+/// Document type of a recap's DM version.
+pub const RECAP_DOC_TYPE: &str = "recap";
+
+/// Document type of a recap's spoiler-safe player version.
+pub const PLAYER_RECAP_DOC_TYPE: &str = "player_recap";
+
+/// Play notes sections read out in both versions of a recap.
+const PLAYER_SECTIONS: &[(&str, &str)] = &[
+    ("What Happened", ""),
+    ("Cliffhanger/Ending", "How it ended"),
+];
+
+/// Play notes sections only the DM version includes.
+const DM_SECTIONS: &[(&str, &str)] = &[
+    ("Dangling Threads", "Dangling threads"),
+    ("Consequences to Implement", "Consequences"),
+    ("DM Notes", "DM notes"),
+];
+
+/// How much play history a recap covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RecapScope {
+    /// The most recently finished session
+    LastSession,
+    /// Every session of the module played most recently
+    LastModule,
+    /// Every session of the campaign
+    WholeCampaign,
+}
+
+/// The two documents a recap is written to.
+#[derive(Debug, Clone, Serialize)]
+pub struct Recap {
+    /// Full recap for the DM
+    pub dm_document: Document,
+    /// Spoiler-safe recap for the players
+    pub player_document: Document,
+}
+
+/// Source material gathered for a recap.
+struct RecapSource {
+    /// Name of what the recap covers, e.g. "Session 4"
+    subject: String,
+    /// Sessions in scope, in play order
+    sessions: Vec<GameSession>,
+    /// Modules in scope, in campaign order
+    modules: Vec<Module>,
+    /// Play notes of the modules in scope, by module ID
+    play_notes: Vec<(String, String)>,
+    /// Timeline events in scope with their party's name, in calendar order
+    events: Vec<(String, PartyTimelineEvent)>,
+    /// Sessions played per module over the whole campaign, by module ID
+    sessions_per_module: Vec<(String, usize)>,
+    /// Module of the most recent session
+    current_module_id: Option<String>,
+    /// Whether the campaign has more than one party
+    several_parties: bool,
+}
+
+/// Service for generating session recaps.
+pub struct RecapService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> RecapService<'a> {
+    /// Create a new recap service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Write a recap of the given scope as a DM document and a player
+    /// document. Generating the same recap again replaces their contents.
+    pub fn generate_recap(&mut self, campaign_id: &str, scope: RecapScope) -> ServiceResult<Recap> {
+        let source = self.gather(campaign_id, scope)?;
+
+        let dm_title = format!("Recap: {}", source.subject);
+        let player_title = format!("Player Recap: {}", source.subject);
+        let dm_document = self.write_document(
+            campaign_id,
+            RECAP_DOC_TYPE,
+            &dm_title,
+            &recap_markdown(&dm_title, &source, true),
+        )?;
+        let player_document = self.write_document(
+            campaign_id,
+            PLAYER_RECAP_DOC_TYPE,
+            &player_title,
+            &recap_markdown(&player_title, &source, false),
+        )?;
+
+        Ok(Recap {
+            dm_document,
+            player_document,
+        })
+    }
+
+    // ── Private helpers ────────────────────────────────────────────────────
+
+    /// Collect the sessions, modules, play notes, and timeline events a
+    /// recap of the given scope covers.
+    fn gather(&mut self, campaign_id: &str, scope: RecapScope) -> ServiceResult<RecapSource> {
+        let campaign = dal::get_campaign_optional(self.conn, campaign_id)?
+            .ok_or_else(|| ServiceError::not_found("Campaign", campaign_id))?;
+
+        // Newest first
+        let all_sessions = dal::list_game_sessions(self.conn, campaign_id)?;
+        if all_sessions.is_empty() {
+            return Err(ServiceError::validation("No sessions have been played yet"));
+        }
+        let all_modules = dal::list_modules(self.conn, campaign_id)?;
+        let module_name = |id: &str| {
+            all_modules
+                .iter()
+                .find(|m| m.id == id)
+                .map(|m| m.name.clone())
+                .unwrap_or_default()
+        };
+
+        let (subject, mut sessions) = match scope {
+            RecapScope::LastSession => {
+                let last = all_sessions
+                    .iter()
+                    .find(|s| !s.is_active())
+                    .unwrap_or(&all_sessions[0]);
+                (
+                    format!("Session {}", last.session_number),
+                    vec![last.clone()],
+                )
+            }
+            RecapScope::LastModule => {
+                let module_id = all_sessions
+                    .iter()
+                    .find_map(|s| s.module_id.clone())
+                    .ok_or_else(|| {
+                        ServiceError::validation("No sessions have been played in a module yet")
+                    })?;
+                let sessions = all_sessions
+                    .iter()
+                    .filter(|s| s.module_id.as_deref() == Some(module_id.as_str()))
+                    .cloned()
+                    .collect();
+                (module_name(&module_id), sessions)
+            }
+            RecapScope::WholeCampaign => (campaign.name.clone(), all_sessions.clone()),
+        };
+        sessions.sort_by_key(|s| s.session_number);
+
+        let module_ids: HashSet<&str> = sessions
+            .iter()
+            .filter_map(|s| s.module_id.as_deref())
+            .collect();
+        let modules: Vec<Module> = all_modules
+            .iter()
+            .filter(|m| scope == RecapScope::WholeCampaign || module_ids.contains(m.id.as_str()))
+            .cloned()
+            .collect();
+
+        let mut play_notes = Vec::new();
+        for module in &modules {
+            let notes = dal::list_module_documents(self.conn, &module.id)?
+                .into_iter()
+                .filter(|d| d.doc_type == "play_notes");
+            for doc in notes {
+                play_notes.push((module.id.clone(), doc.content));
+            }
+        }
+
+        let session_ids: HashSet<&str> = sessions.iter().map(|s| s.id.as_str()).collect();
+        let parties = dal::list_parties(self.conn, campaign_id)?;
+        let mut events = Vec::new();
+        for party in &parties {
+            for event in dal::list_party_timeline_events(self.conn, &party.id)? {
+                let in_scope = match event.session_id.as_deref() {
+                    Some(id) => session_ids.contains(id),
+                    None => scope == RecapScope::WholeCampaign,
+                };
+                if in_scope {
+                    events.push((party.name.clone(), event));
+                }
+            }
+        }
+        // Stable, so same-day events keep their party and entry order
+        events.sort_by_key(|(_, e)| e.day);
+
+        let sessions_per_module = all_modules
+            .iter()
+            .map(|m| {
+                let played = all_sessions
+                    .iter()
+                    .filter(|s| s.module_id.as_deref() == Some(m.id.as_str()))
+                    .count();
+                (m.id.clone(), played)
+            })
+            .collect();
+
+        Ok(RecapSource {
+            subject,
+            sessions,
+            modules,
+            play_notes,
+            events,
+            sessions_per_module,
+            current_module_id: all_sessions[0].module_id.clone(),
+            several_parties: parties.len() > 1,
+        })
+    }
+
+    /// Create a campaign document, or replace the contents of the one with
+    /// the same type and title.
+    fn write_document(
+        &mut self,
+        campaign_id: &str,
+        doc_type: &str,
+        title: &str,
+        content: &str,
+    ) -> ServiceResult<Document> {
+        let mut documents = DocumentService::new(self.conn);
+        let existing = documents
+            .list_by_type(campaign_id, doc_type)?
+            .into_iter()
+            .find(|d| d.title == title && d.module_id.is_none());
+        match existing {
+            Some(doc) => documents.update(&doc.id, UpdateDocumentInput::set_content(content)),
+            None => documents.create(
+                CreateDocumentInput::for_campaign(campaign_id, title)
+                    .with_type(doc_type)
+                    .with_content(content),
+            ),
+        }
+    }
+}
+
+/// Render one version of a recap.
+fn recap_markdown(title: &str, source: &RecapSource, dm: bool) -> String {
+    let mut md = format!("# {}\n\n", title);
+    md.push_str(if dm {
+        "*DM version. Includes notes the players haven't seen.*\n\n"
+    } else {
+        "*Previously, on our adventure...*\n\n"
+    });
+
+    md.push_str("## Sessions\n\n");
+    for session in &source.sessions {
+        md.push_str(&format!("### Session {}", session.session_number));
+        if let Some(session_title) = session.title.as_deref() {
+            md.push_str(&format!(": {}", session_title));
+        }
+        md.push_str("\n\n");
+
+        let mut details = vec![session
+            .started_at
+            .get(..10)
+            .unwrap_or(&session.started_at)
+            .to_string()];
+        if let Some(module) = session
+            .module_id
+            .as_deref()
+            .and_then(|id| source.modules.iter().find(|m| m.id == id))
+        {
+            details.push(module.name.clone());
+        }
+        md.push_str(&format!("*{}*\n\n", details.join(" · ")));
+
+        let notes: Vec<&str> = source
+            .play_notes
+            .iter()
+            .filter(|(module_id, _)| session.module_id.as_deref() == Some(module_id.as_str()))
+            .filter_map(|(_, content)| session_notes(content, session.session_number))
+            .collect();
+        let mut wrote_notes = false;
+        let sections = PLAYER_SECTIONS
+            .iter()
+            .chain(if dm { DM_SECTIONS } else { &[] });
+        for (heading, label) in sections {
+            let lines: Vec<&str> = notes
+                .iter()
+                .flat_map(|block| notes_section(block, heading))
+                .collect();
+            if lines.is_empty() {
+                continue;
+            }
+            wrote_notes = true;
+            if !label.is_empty() {
+                md.push_str(&format!("**{}:**\n\n", label));
+            }
+            md.push_str(&lines.join("\n"));
+            md.push_str("\n\n");
+        }
+        if !wrote_notes {
+            md.push_str("Nothing recorded in the play notes.\n\n");
+        }
+    }
+
+    let quests: Vec<(&Module, usize)> = source
+        .modules
+        .iter()
+        .map(|m| {
+            let played = source
+                .sessions_per_module
+                .iter()
+                .find(|(id, _)| *id == m.id)
+                .map_or(0, |(_, n)| *n);
+            (m, played)
+        })
+        // Modules nobody has played yet would spoil what's coming
+        .filter(|(_, played)| dm || *played > 0)
+        .collect();
+    if !quests.is_empty() {
+        md.push_str("## Quests\n\n");
+        for (module, played) in quests {
+            let state = if played == 0 {
+                "Not started".to_string()
+            } else {
+                let status = if source.current_module_id.as_deref() == Some(module.id.as_str()) {
+                    "In progress"
+                } else {
+                    "Played"
+                };
+                let plural = if played == 1 { "" } else { "s" };
+                format!("{} ({} session{})", status, played, plural)
+            };
+            md.push_str(&format!("- **{}**: {}\n", module.name, state));
+            if dm {
+                if let Some(description) = module
+                    .description
+                    .as_deref()
+                    .filter(|d| !d.trim().is_empty())
+                {
+                    md.push_str(&format!("  {}\n", description.trim()));
+                }
+            }
+        }
+        md.push('\n');
+    }
+
+    if !source.events.is_empty() {
+        md.push_str("## Major Events\n\n");
+        for (party_name, event) in &source.events {
+            md.push_str(&format!("- **Day {}", event.day));
+            if source.several_parties {
+                md.push_str(&format!(", {}", party_name));
+            }
+            md.push_str(&format!(":** {}", event.title));
+            if let Some(description) = event
+                .description
+                .as_deref()
+                .filter(|d| !d.trim().is_empty())
+            {
+                md.push_str(&format!(". {}", description.trim()));
+            }
+            md.push('\n');
+        }
+        md.push('\n');
+    }
+
+    md
+}
+
+/// The block of a play notes document written for a session: from the
+/// top-level heading naming the session up to the next top-level heading.
+fn session_notes(content: &str, session_number: i32) -> Option<&str> {
+    let mut start = None;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        if line.starts_with("# ") {
+            if let Some(start) = start {
+                return Some(&content[start..offset]);
+            }
+            if heading_session_number(line) == Some(session_number) {
+                start = Some(offset);
+            }
+        }
+        offset += line.len();
+    }
+    start.map(|start| &content[start..])
+}
+
+/// The session number a play notes heading names, as in
+/// "# Play Notes: Session 4".
+fn heading_session_number(heading: &str) -> Option<i32> {
+    let (_, rest) = heading.split_once("Session ")?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// The filled-in lines under a heading of a play notes block, skipping the
+/// template's italic hints and empty bullets.
+fn notes_section<'c>(block: &'c str, heading: &str) -> Vec<&'c str> {
+    let mut lines = Vec::new();
+    let mut inside = false;
+    for line in block.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            inside = trimmed.trim_start_matches('#').trim() == heading;
+            continue;
+        }
+        if trimmed == "---" {
+            inside = false;
+        }
+        if !inside || trimmed.is_empty() || trimmed == "-" {
+            continue;
+        }
+        if trimmed.len() > 1
+            && trimmed.starts_with('*')
+            && !trimmed.starts_with("**")
+            && trimmed.ends_with('*')
+        {
+            continue;
+        }
+        lines.push(line.trim_end());
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_document, insert_game_session, insert_module, insert_party,
+        insert_party_timeline_event,
+    };
+    use crate::models::campaign::{
+        NewCampaign, NewDocument, NewGameSession, NewModule, NewParty, NewPartyTimelineEvent,
+    };
+    use crate::test_utils::setup_test_db;
+
+    const PLAY_NOTES: &str = "# Play Notes: Session 1
+
+## What Happened
+*Quick bullet points of key events*
+- Met Gundren on the road
+-
+
+## DM Notes
+*What worked, what didn't, ideas for next time*
+- Goblins were too easy
+
+# Play Notes: Session 2
+
+## What Happened
+*Quick bullet points of key events*
+- Cleared the goblin hideout
+
+## Dangling Threads
+*Unresolved situations and loose ends*
+- Klarg's note mentions the Black Spider
+
+## Cliffhanger/Ending
+*How the session ended*
+Sildar asks them to find Gundren.
+";
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Lost Mine")).unwrap();
+        insert_module(
+            conn,
+            &NewModule::new("mod-1", "camp-1", "Goblin Arrows", 1)
+                .with_description("The Cragmaw goblins work for the Black Spider."),
+        )
+        .unwrap();
+        insert_module(
+            conn,
+            &NewModule::new("mod-2", "camp-1", "Wave Echo Cave", 2),
+        )
+        .unwrap();
+        insert_document(
+            conn,
+            &NewDocument::for_module("notes-1", "camp-1", "mod-1", "Play Notes", "play_notes")
+                .with_content(PLAY_NOTES),
+        )
+        .unwrap();
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Heroes")).unwrap();
+        for (id, number, title) in [
+            ("session-1", 1, "On the Road"),
+            ("session-2", 2, "Cragmaw Hideout"),
+        ] {
+            insert_game_session(
+                conn,
+                &NewGameSession::new(id, "camp-1", number)
+                    .with_module("mod-1")
+                    .with_title(title)
+                    .with_party("party-1"),
+            )
+            .unwrap();
+        }
+        insert_party_timeline_event(
+            conn,
+            &NewPartyTimelineEvent::new("event-1", "party-1", 1, "Ambushed by goblins")
+                .with_session("session-1"),
+        )
+        .unwrap();
+        insert_party_timeline_event(
+            conn,
+            &NewPartyTimelineEvent::new("event-2", "party-1", 3, "Rescued Sildar")
+                .with_session("session-2"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_notes_section_skips_template_hints() {
+        let block = session_notes(PLAY_NOTES, 1).unwrap();
+        assert_eq!(
+            notes_section(block, "What Happened"),
+            vec!["- Met Gundren on the road"]
+        );
+        assert_eq!(
+            notes_section(block, "DM Notes"),
+            vec!["- Goblins were too easy"]
+        );
+        assert!(notes_section(block, "Dangling Threads").is_empty());
+        assert!(session_notes(PLAY_NOTES, 3).is_none());
+        assert_eq!(
+            heading_session_number("# Play Notes: Session 12\n"),
+            Some(12)
+        );
+        assert_eq!(
+            heading_session_number("# Play Notes: Session [Number]"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_last_session_recap() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = RecapService::new(&mut conn);
+
+        let recap = service
+            .generate_recap("camp-1", RecapScope::LastSession)
+            .unwrap();
+        let dm = &recap.dm_document;
+        assert_eq!(dm.title, "Recap: Session 2");
+        assert_eq!(dm.doc_type, RECAP_DOC_TYPE);
+        assert!(dm.content.contains("### Session 2: Cragmaw Hideout"));
+        assert!(dm.content.contains("- Cleared the goblin hideout"));
+        assert!(dm
+            .content
+            .contains("Klarg's note mentions the Black Spider"));
+        assert!(dm.content.contains("Rescued Sildar"));
+        assert!(!dm.content.contains("Met Gundren"));
+        assert!(!dm.content.contains("Ambushed by goblins"));
+        assert!(dm
+            .content
+            .contains("**Goblin Arrows**: In progress (2 sessions)"));
+
+        let player = &recap.player_document;
+        assert_eq!(player.title, "Player Recap: Session 2");
+        assert_eq!(player.doc_type, PLAYER_RECAP_DOC_TYPE);
+        assert!(player.content.contains("Sildar asks them to find Gundren."));
+        assert!(!player.content.contains("Black Spider"));
+    }
+
+    #[test]
+    fn test_whole_campaign_hides_unstarted_modules_from_players() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = RecapService::new(&mut conn);
+
+        let recap = service
+            .generate_recap("camp-1", RecapScope::WholeCampaign)
+            .unwrap();
+        assert_eq!(recap.dm_document.title, "Recap: Lost Mine");
+        assert!(recap
+            .dm_document
+            .content
+            .contains("**Wave Echo Cave**: Not started"));
+        assert!(recap
+            .dm_document
+            .content
+            .contains("- Goblins were too easy"));
+        assert!(!recap.player_document.content.contains("Wave Echo Cave"));
+        assert!(!recap
+            .player_document
+            .content
+            .contains("Goblins were too easy"));
+        assert!(recap.player_document.content.contains("Met Gundren"));
+        assert!(recap
+            .player_document
+            .content
+            .contains("Ambushed by goblins"));
+
+        // Generating again replaces the same documents
+        let again = service
+            .generate_recap("camp-1", RecapScope::WholeCampaign)
+            .unwrap();
+        assert_eq!(again.dm_document.id, recap.dm_document.id);
+        assert_eq!(again.player_document.id, recap.player_document.id);
+    }
+
+    #[test]
+    fn test_recap_needs_sessions() {
+        let mut conn = setup_test_db();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Lost Mine")).unwrap();
+        insert_game_session(&mut conn, &NewGameSession::new("session-1", "camp-1", 1)).unwrap();
+        let mut service = RecapService::new(&mut conn);
+
+        assert!(service
+            .generate_recap("camp-1", RecapScope::LastModule)
+            .is_err());
+        assert!(service
+            .generate_recap("missing", RecapScope::LastSession)
+            .is_err());
+
+        let recap = service
+            .generate_recap("camp-1", RecapScope::LastSession)
+            .unwrap();
+        assert!(recap
+            .dm_document
+            .content
+            .contains("Nothing recorded in the play notes."));
+    }
+}
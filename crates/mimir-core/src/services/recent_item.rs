@@ -0,0 +1,233 @@
+//! Recent Item Service
+//!
+//! Records the catalog entries, documents, and maps the DM opens so the
+//! frontend can offer a "jump back in" panel.
+
+use diesel::SqliteConnection;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{ItemKind, NewRecentItem, RecentItem};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Number of history entries kept; older views are pruned.
+pub const RECENT_ITEMS_KEPT: i64 = 200;
+
+/// Reference to a catalog entry, document, or map.
+#[derive(Debug, Clone)]
+pub struct ItemRef {
+    pub kind: ItemKind,
+    /// Document/map ID, or catalog entry name
+    pub ref_id: String,
+    /// Catalog source code (catalog entries only)
+    pub ref_source: Option<String>,
+    /// Owning campaign (documents and maps only)
+    pub campaign_id: Option<String>,
+    /// Display title
+    pub title: String,
+}
+
+impl ItemRef {
+    /// Reference a catalog entry by name and source.
+    pub fn catalog(kind: ItemKind, name: impl Into<String>, source: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            kind,
+            title: name.clone(),
+            ref_id: name,
+            ref_source: Some(source.into()),
+            campaign_id: None,
+        }
+    }
+
+    /// Reference a campaign document or map by ID.
+    pub fn campaign_item(
+        kind: ItemKind,
+        campaign_id: impl Into<String>,
+        id: impl Into<String>,
+        title: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            ref_id: id.into(),
+            ref_source: None,
+            campaign_id: Some(campaign_id.into()),
+            title: title.into(),
+        }
+    }
+
+    /// Check the reference is well formed and, for documents and maps, that
+    /// the item exists in its campaign.
+    pub(crate) fn validate(&self, conn: &mut SqliteConnection) -> ServiceResult<()> {
+        if self.ref_id.trim().is_empty() {
+            return Err(ServiceError::validation("Item reference cannot be empty"));
+        }
+        if self.title.trim().is_empty() {
+            return Err(ServiceError::validation("Item title cannot be empty"));
+        }
+
+        if self.kind.is_catalog() {
+            if !matches!(self.ref_source.as_deref(), Some(s) if !s.trim().is_empty()) {
+                return Err(ServiceError::validation(format!(
+                    "A {} reference requires a source",
+                    self.kind.as_str()
+                )));
+            }
+            if self.campaign_id.is_some() {
+                return Err(ServiceError::validation(
+                    "Catalog references cannot belong to a campaign",
+                ));
+            }
+            return Ok(());
+        }
+
+        let campaign_id = self.campaign_id.as_deref().ok_or_else(|| {
+            ServiceError::validation(format!(
+                "A {} reference requires a campaign",
+                self.kind.as_str()
+            ))
+        })?;
+        let exists = match self.kind {
+            ItemKind::Document => dal::get_document_optional(conn, &self.ref_id)?
+                .is_some_and(|d| d.campaign_id == campaign_id),
+            _ => dal::get_map_optional(conn, &self.ref_id)?
+                .is_some_and(|m| m.campaign_id == campaign_id),
+        };
+        if !exists {
+            let entity = if self.kind == ItemKind::Document { "Document" } else { "Map" };
+            return Err(ServiceError::not_found(entity, &self.ref_id));
+        }
+        Ok(())
+    }
+}
+
+/// Service for recently viewed items.
+pub struct RecentItemService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> RecentItemService<'a> {
+    /// Create a new recent item service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Record that an item was opened, moving it to the top of the history.
+    pub fn record_view(&mut self, item: &ItemRef) -> ServiceResult<RecentItem> {
+        item.validate(self.conn)?;
+        let now = now_rfc3339();
+
+        let existing = dal::find_recent_item(
+            self.conn,
+            item.kind,
+            &item.ref_id,
+            item.ref_source.as_deref(),
+        )?;
+
+        match existing {
+            Some(existing) => {
+                dal::touch_recent_item(self.conn, &existing.id, &item.title, &now)?;
+            }
+            None => {
+                let id = Uuid::new_v4().to_string();
+                let mut new_item =
+                    NewRecentItem::new(&id, item.kind, &item.ref_id, &item.title, &now);
+                if let Some(ref source) = item.ref_source {
+                    new_item = new_item.with_source(source);
+                }
+                if let Some(ref campaign_id) = item.campaign_id {
+                    new_item = new_item.with_campaign(campaign_id);
+                }
+                dal::insert_recent_item(self.conn, &new_item)?;
+                dal::prune_recent_items(self.conn, RECENT_ITEMS_KEPT)?;
+            }
+        }
+
+        dal::find_recent_item(self.conn, item.kind, &item.ref_id, item.ref_source.as_deref())?
+            .ok_or_else(|| ServiceError::not_found("RecentItem", &item.ref_id))
+    }
+
+    /// Most recently opened items, optionally of one kind and limited to a
+    /// campaign's content plus the catalog.
+    pub fn get_recent(
+        &mut self,
+        kind: Option<ItemKind>,
+        campaign_id: Option<&str>,
+        limit: i64,
+    ) -> ServiceResult<Vec<RecentItem>> {
+        let limit = limit.clamp(1, RECENT_ITEMS_KEPT);
+        dal::list_recent_items(self.conn, kind, campaign_id, limit).map_err(ServiceError::from)
+    }
+
+    /// Remove one entry from the history.
+    pub fn remove(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_recent_item(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("RecentItem", id));
+        }
+        Ok(())
+    }
+
+    /// Clear the whole history.
+    pub fn clear(&mut self) -> ServiceResult<usize> {
+        dal::delete_all_recent_items(self.conn).map_err(ServiceError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_document};
+    use crate::models::campaign::{NewCampaign, NewDocument};
+    use crate::test_utils::setup_test_db;
+
+    #[test]
+    fn test_record_view_moves_to_top() {
+        let mut conn = setup_test_db();
+        let mut service = RecentItemService::new(&mut conn);
+
+        let goblin = ItemRef::catalog(ItemKind::Monster, "Goblin", "MM");
+        let fireball = ItemRef::catalog(ItemKind::Spell, "Fireball", "PHB");
+
+        service.record_view(&goblin).expect("Failed to record");
+        service.record_view(&fireball).expect("Failed to record");
+        let again = service.record_view(&goblin).expect("Failed to record");
+        assert_eq!(again.view_count, 2);
+
+        let recent = service.get_recent(None, None, 10).expect("Failed to list");
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].ref_id, "Goblin");
+
+        let spells = service
+            .get_recent(Some(ItemKind::Spell), None, 10)
+            .expect("Failed to list");
+        assert_eq!(spells.len(), 1);
+    }
+
+    #[test]
+    fn test_record_view_validates_references() {
+        let mut conn = setup_test_db();
+        insert_campaign(&mut conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        let doc = NewDocument::for_campaign("doc-1", "camp-1", "Heist Plan", "notes");
+        insert_document(&mut conn, &doc).unwrap();
+        let mut service = RecentItemService::new(&mut conn);
+
+        let mut no_source = ItemRef::catalog(ItemKind::Monster, "Goblin", "MM");
+        no_source.ref_source = None;
+        assert!(matches!(
+            service.record_view(&no_source),
+            Err(ServiceError::Validation(_))
+        ));
+
+        let missing = ItemRef::campaign_item(ItemKind::Document, "camp-1", "doc-2", "Missing");
+        assert!(matches!(
+            service.record_view(&missing),
+            Err(ServiceError::NotFound { .. })
+        ));
+
+        let doc = ItemRef::campaign_item(ItemKind::Document, "camp-1", "doc-1", "Heist Plan");
+        let recorded = service.record_view(&doc).expect("Failed to record");
+        assert_eq!(recorded.campaign_id.as_deref(), Some("camp-1"));
+    }
+}
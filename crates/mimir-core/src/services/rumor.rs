@@ -0,0 +1,499 @@
+//! Rumor Service
+//!
+//! Business logic for a campaign's rumors and adventure hooks. Each rumor
+//! records whether it is true, the NPC it comes from, and the module it leads
+//! into, along with which players have heard it and when. Tavern rumor tables
+//! for a session favor rumors the table hasn't heard yet, and the hooks
+//! nobody has heard go into a module's prep export.
+
+use std::collections::HashMap;
+
+use diesel::SqliteConnection;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{NewRumor, NewRumorHearing, Rumor, RumorHearing, UpdateRumor};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Entries on a tavern rumor table when no size is given (a d6 table).
+pub const TAVERN_TABLE_SIZE: usize = 6;
+
+/// Most entries a tavern rumor table can have (a d20 table).
+const MAX_TAVERN_TABLE_SIZE: usize = 20;
+
+/// Input for creating or replacing a rumor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RumorInput {
+    /// What is said
+    pub text: String,
+    /// Whether the rumor is true
+    #[serde(default = "default_true")]
+    pub is_true: bool,
+    /// NPC the rumor comes from
+    #[serde(default)]
+    pub source_npc_id: Option<String>,
+    /// Module (quest) the rumor leads into
+    #[serde(default)]
+    pub module_id: Option<String>,
+    /// DM notes
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// A rumor with the record of who has heard it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RumorWithHearings {
+    #[serde(flatten)]
+    pub rumor: Rumor,
+    /// Players who have heard the rumor, earliest first
+    pub hearings: Vec<RumorHearing>,
+}
+
+/// An entry on a tavern rumor table.
+#[derive(Debug, Clone, Serialize)]
+pub struct TavernRumor {
+    /// Die result that gives this entry
+    pub roll: usize,
+    pub rumor_id: String,
+    pub text: String,
+    pub is_true: bool,
+    /// Name of the NPC the rumor comes from
+    pub source_npc: Option<String>,
+    /// Number of players who have already heard it
+    pub heard_by: usize,
+}
+
+/// A tavern rumor table for a session.
+#[derive(Debug, Clone, Serialize)]
+pub struct TavernRumorTable {
+    /// Die to roll on the table (its number of entries)
+    pub die: usize,
+    pub entries: Vec<TavernRumor>,
+    /// Seed that reproduces this table
+    pub seed: u64,
+}
+
+/// Service for rumors and adventure hooks.
+pub struct RumorService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> RumorService<'a> {
+    /// Create a new rumor service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a campaign's rumors with who has heard each one.
+    pub fn list(&mut self, campaign_id: &str) -> ServiceResult<Vec<RumorWithHearings>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let rumors = dal::list_rumors(self.conn, campaign_id)?;
+        let mut hearings: HashMap<String, Vec<RumorHearing>> = HashMap::new();
+        for hearing in dal::list_campaign_rumor_hearings(self.conn, campaign_id)? {
+            hearings
+                .entry(hearing.rumor_id.clone())
+                .or_default()
+                .push(hearing);
+        }
+
+        Ok(rumors
+            .into_iter()
+            .map(|rumor| RumorWithHearings {
+                hearings: hearings.remove(&rumor.id).unwrap_or_default(),
+                rumor,
+            })
+            .collect())
+    }
+
+    /// Create a rumor in a campaign.
+    pub fn create(&mut self, campaign_id: &str, input: RumorInput) -> ServiceResult<Rumor> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let text = self.validate(campaign_id, &input)?;
+
+        let id = Uuid::new_v4().to_string();
+        let mut rumor = NewRumor::new(&id, campaign_id, text).with_truth(input.is_true);
+        if let Some(npc_id) = input.source_npc_id.as_deref() {
+            rumor = rumor.with_source_npc(npc_id);
+        }
+        if let Some(module_id) = input.module_id.as_deref() {
+            rumor = rumor.with_module(module_id);
+        }
+        if let Some(notes) = trimmed(&input.notes) {
+            rumor = rumor.with_notes(notes);
+        }
+        dal::insert_rumor(self.conn, &rumor)?;
+        dal::get_rumor(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Replace a rumor's text, truth, source, quest, and notes.
+    pub fn update(&mut self, id: &str, input: RumorInput) -> ServiceResult<Rumor> {
+        let rumor = dal::get_rumor_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Rumor", id))?;
+        let text = self.validate(&rumor.campaign_id, &input)?;
+
+        let now = now_rfc3339();
+        let update = UpdateRumor {
+            text: Some(text),
+            is_true: Some(input.is_true as i32),
+            source_npc_id: Some(input.source_npc_id.as_deref()),
+            module_id: Some(input.module_id.as_deref()),
+            notes: Some(trimmed(&input.notes)),
+            updated_at: Some(&now),
+        };
+        dal::update_rumor(self.conn, id, &update)?;
+        dal::get_rumor(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Delete a rumor and the record of who heard it.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_rumor(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("Rumor", id));
+        }
+        Ok(())
+    }
+
+    /// Record that players heard a rumor, optionally in a given session.
+    ///
+    /// Players who had already heard it get their record moved to now.
+    pub fn mark_heard(
+        &mut self,
+        rumor_id: &str,
+        player_ids: &[String],
+        session_id: Option<&str>,
+    ) -> ServiceResult<RumorWithHearings> {
+        let rumor = dal::get_rumor_optional(self.conn, rumor_id)?
+            .ok_or_else(|| ServiceError::not_found("Rumor", rumor_id))?;
+        if let Some(session_id) = session_id {
+            let session = dal::get_game_session_optional(self.conn, session_id)?
+                .ok_or_else(|| ServiceError::not_found("Session", session_id))?;
+            if session.campaign_id != rumor.campaign_id {
+                return Err(ServiceError::validation(
+                    "Session belongs to another campaign",
+                ));
+            }
+        }
+        for player_id in player_ids {
+            let player = dal::get_player_optional(self.conn, player_id)?
+                .ok_or_else(|| ServiceError::not_found("Player", player_id))?;
+            if player.campaign_id != rumor.campaign_id {
+                return Err(ServiceError::validation(format!(
+                    "{} is not in this rumor's campaign",
+                    player.name
+                )));
+            }
+        }
+
+        let now = now_rfc3339();
+        for player_id in player_ids {
+            let mut hearing = NewRumorHearing::new(rumor_id, player_id, &now);
+            if let Some(session_id) = session_id {
+                hearing = hearing.with_session(session_id);
+            }
+            dal::insert_rumor_hearing(self.conn, &hearing)?;
+        }
+        self.with_hearings(rumor)
+    }
+
+    /// Remove the record of a player hearing a rumor.
+    pub fn unmark_heard(
+        &mut self,
+        rumor_id: &str,
+        player_id: &str,
+    ) -> ServiceResult<RumorWithHearings> {
+        let rumor = dal::get_rumor_optional(self.conn, rumor_id)?
+            .ok_or_else(|| ServiceError::not_found("Rumor", rumor_id))?;
+        dal::delete_rumor_hearing(self.conn, rumor_id, player_id)?;
+        self.with_hearings(rumor)
+    }
+
+    /// Roll up a tavern rumor table for a session.
+    ///
+    /// Rumors nobody has heard come first, then those heard by the fewest
+    /// players, in random order within each group. Given a module, only its
+    /// rumors and those not tied to any module are used. The table is
+    /// shorter than asked if there aren't enough rumors.
+    pub fn tavern_table(
+        &mut self,
+        campaign_id: &str,
+        size: Option<usize>,
+        module_id: Option<&str>,
+        seed: Option<u64>,
+    ) -> ServiceResult<TavernRumorTable> {
+        let size = size.unwrap_or(TAVERN_TABLE_SIZE);
+        if !(1..=MAX_TAVERN_TABLE_SIZE).contains(&size) {
+            return Err(ServiceError::validation(format!(
+                "A rumor table has between 1 and {} entries",
+                MAX_TAVERN_TABLE_SIZE
+            )));
+        }
+
+        let mut candidates: Vec<RumorWithHearings> = self
+            .list(campaign_id)?
+            .into_iter()
+            .filter(|r| {
+                module_id.is_none()
+                    || r.rumor.module_id.as_deref() == module_id
+                    || r.rumor.module_id.is_none()
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Err(ServiceError::validation("There are no rumors to roll on"));
+        }
+
+        let seed = seed.unwrap_or_else(|| rand::thread_rng().gen::<u32>() as u64);
+        let mut rng = StdRng::seed_from_u64(seed);
+        candidates.shuffle(&mut rng);
+        candidates.sort_by_key(|r| r.hearings.len());
+        candidates.truncate(size);
+
+        let mut entries = Vec::with_capacity(candidates.len());
+        for (i, candidate) in candidates.into_iter().enumerate() {
+            let source_npc = match candidate.rumor.source_npc_id.as_deref() {
+                Some(npc_id) => dal::get_character_optional(self.conn, npc_id)?.map(|c| c.name),
+                None => None,
+            };
+            entries.push(TavernRumor {
+                roll: i + 1,
+                is_true: candidate.rumor.is_true(),
+                rumor_id: candidate.rumor.id,
+                text: candidate.rumor.text,
+                source_npc,
+                heard_by: candidate.hearings.len(),
+            });
+        }
+
+        Ok(TavernRumorTable {
+            die: entries.len(),
+            entries,
+            seed,
+        })
+    }
+
+    /// List the hooks into a module that no player has heard yet: its own
+    /// rumors and those not tied to any module.
+    pub fn unrevealed_hooks(&mut self, module_id: &str) -> ServiceResult<Vec<Rumor>> {
+        let module = dal::get_module_optional(self.conn, module_id)?
+            .ok_or_else(|| ServiceError::not_found("Module", module_id))?;
+        Ok(self
+            .list(&module.campaign_id)?
+            .into_iter()
+            .filter(|r| r.hearings.is_empty())
+            .map(|r| r.rumor)
+            .filter(|r| r.module_id.is_none() || r.module_id.as_deref() == Some(module_id))
+            .collect())
+    }
+
+    /// Check a rumor's input against its campaign, returning the trimmed
+    /// text.
+    fn validate<'i>(&mut self, campaign_id: &str, input: &'i RumorInput) -> ServiceResult<&'i str> {
+        let text = input.text.trim();
+        if text.is_empty() {
+            return Err(ServiceError::validation("Rumor text is required"));
+        }
+        if let Some(npc_id) = input.source_npc_id.as_deref() {
+            let npc = dal::get_character_optional(self.conn, npc_id)?
+                .ok_or_else(|| ServiceError::not_found("Character", npc_id))?;
+            if npc.campaign_id.as_deref() != Some(campaign_id) {
+                return Err(ServiceError::validation(format!(
+                    "{} belongs to another campaign",
+                    npc.name
+                )));
+            }
+        }
+        if let Some(module_id) = input.module_id.as_deref() {
+            let module = dal::get_module_optional(self.conn, module_id)?
+                .ok_or_else(|| ServiceError::not_found("Module", module_id))?;
+            if module.campaign_id != campaign_id {
+                return Err(ServiceError::validation(format!(
+                    "{} belongs to another campaign",
+                    module.name
+                )));
+            }
+        }
+        Ok(text)
+    }
+
+    fn with_hearings(&mut self, rumor: Rumor) -> ServiceResult<RumorWithHearings> {
+        let hearings = dal::list_rumor_hearings(self.conn, &rumor.id)?;
+        Ok(RumorWithHearings { rumor, hearings })
+    }
+}
+
+/// An optional text field, trimmed, with blank treated as unset.
+fn trimmed(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, insert_module, insert_player};
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewModule, NewPlayer};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_campaign(conn, &NewCampaign::new("camp-2", "Other Campaign")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "The Old Mine", 1)).unwrap();
+        insert_module(conn, &NewModule::new("mod-2", "camp-1", "Wolf Hills", 2)).unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_npc("npc-1", Some("camp-1"), "Marta"),
+        )
+        .unwrap();
+        insert_player(conn, &NewPlayer::new("player-1", "camp-1", "Alice")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-2", "camp-1", "Bob")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-3", "camp-2", "Carol")).unwrap();
+    }
+
+    fn input(text: &str) -> RumorInput {
+        RumorInput {
+            text: text.to_string(),
+            is_true: true,
+            source_npc_id: None,
+            module_id: None,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_create_validates() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = RumorService::new(&mut conn);
+
+        let mut gold = input(" The old mine is full of gold. ");
+        gold.is_true = false;
+        gold.source_npc_id = Some("npc-1".into());
+        gold.module_id = Some("mod-1".into());
+        gold.notes = Some("  ".into());
+        let rumor = service.create("camp-1", gold).unwrap();
+        assert_eq!(rumor.text, "The old mine is full of gold.");
+        assert!(!rumor.is_true());
+        assert_eq!(rumor.source_npc_id.as_deref(), Some("npc-1"));
+        assert!(rumor.notes.is_none());
+
+        assert!(service.create("camp-1", input("  ")).is_err());
+        assert!(service.create("camp-9", input("Wolves")).is_err());
+        let mut elsewhere = input("Wolves");
+        elsewhere.source_npc_id = Some("npc-1".into());
+        assert!(service.create("camp-2", elsewhere).is_err());
+
+        let mut change = input("The old mine is haunted.");
+        change.module_id = Some("mod-1".into());
+        let updated = service.update(&rumor.id, change).unwrap();
+        assert!(updated.is_true());
+        assert!(updated.source_npc_id.is_none());
+
+        service.delete(&rumor.id).unwrap();
+        assert!(service.delete(&rumor.id).is_err());
+    }
+
+    #[test]
+    fn test_mark_and_unmark_heard() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = RumorService::new(&mut conn);
+        let rumor = service.create("camp-1", input("Wolves")).unwrap();
+
+        let players = vec!["player-1".to_string(), "player-2".to_string()];
+        let heard = service.mark_heard(&rumor.id, &players, None).unwrap();
+        assert_eq!(heard.hearings.len(), 2);
+        assert!(service
+            .mark_heard(&rumor.id, &["player-3".to_string()], None)
+            .is_err());
+
+        let heard = service.unmark_heard(&rumor.id, "player-2").unwrap();
+        assert_eq!(heard.hearings.len(), 1);
+        assert_eq!(heard.hearings[0].player_id, "player-1");
+        assert_eq!(service.list("camp-1").unwrap()[0].hearings.len(), 1);
+    }
+
+    #[test]
+    fn test_tavern_table_prefers_unheard() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = RumorService::new(&mut conn);
+
+        let mut ids = Vec::new();
+        for text in ["Wolves", "Gold", "Ghosts", "Bandits"] {
+            ids.push(service.create("camp-1", input(text)).unwrap().id);
+        }
+        let everyone = vec!["player-1".to_string(), "player-2".to_string()];
+        service.mark_heard(&ids[0], &everyone, None).unwrap();
+        service.mark_heard(&ids[1], &everyone[..1], None).unwrap();
+
+        let table = service
+            .tavern_table("camp-1", Some(3), None, Some(5))
+            .unwrap();
+        assert_eq!(table.die, 3);
+        let rolls: Vec<usize> = table.entries.iter().map(|e| e.roll).collect();
+        assert_eq!(rolls, vec![1, 2, 3]);
+        assert!(table.entries.iter().all(|e| e.rumor_id != ids[0]));
+        assert_eq!(table.entries[2].rumor_id, ids[1]);
+
+        let again = service
+            .tavern_table("camp-1", Some(3), None, Some(5))
+            .unwrap();
+        let ids_of = |t: &TavernRumorTable| {
+            t.entries
+                .iter()
+                .map(|e| e.rumor_id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids_of(&table), ids_of(&again));
+
+        assert_eq!(
+            service
+                .tavern_table("camp-1", None, None, None)
+                .unwrap()
+                .die,
+            4
+        );
+        assert!(service.tavern_table("camp-1", Some(0), None, None).is_err());
+        assert!(service.tavern_table("camp-2", None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_unrevealed_hooks() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = RumorService::new(&mut conn);
+
+        let mut mine = input("Lights in the old mine");
+        mine.module_id = Some("mod-1".into());
+        let mine = service.create("camp-1", mine).unwrap();
+        let mut hills = input("Wolves in the hills");
+        hills.module_id = Some("mod-2".into());
+        service.create("camp-1", hills).unwrap();
+        let general = service.create("camp-1", input("The baron is ill")).unwrap();
+
+        let hooks = service.unrevealed_hooks("mod-1").unwrap();
+        assert_eq!(hooks.len(), 2);
+
+        service
+            .mark_heard(&mine.id, &["player-1".to_string()], None)
+            .unwrap();
+        let hooks = service.unrevealed_hooks("mod-1").unwrap();
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0].id, general.id);
+
+        let table = service
+            .tavern_table("camp-1", None, Some("mod-2"), Some(1))
+            .unwrap();
+        assert_eq!(table.die, 2);
+    }
+}
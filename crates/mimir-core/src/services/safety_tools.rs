@@ -0,0 +1,375 @@
+//! Safety Tools Service
+//!
+//! Business logic for a campaign's safety tools: the lines, veils, and table
+//! mechanics agreed at session zero. The configuration feeds the session zero
+//! "Safety Tools" document, the content constraints handed to LLM assistants,
+//! and the DM-only section of the campaign export.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{CampaignSafetyTools, Document, NewCampaignSafetyTools};
+use crate::services::{
+    CreateDocumentInput, DocumentService, ServiceError, ServiceResult, UpdateDocumentInput,
+};
+use crate::utils::now_rfc3339;
+
+/// Document type of the session zero safety tools document.
+pub const SAFETY_TOOLS_DOC_TYPE: &str = "safety_tools";
+
+/// Longest allowed line or veil, in characters.
+const MAX_LIMIT_LENGTH: usize = 200;
+
+/// A campaign's safety tools, with lines and veils parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafetyTools {
+    /// Hard limits: never appear in the game
+    #[serde(default)]
+    pub lines: Vec<String>,
+    /// Soft limits: may be referenced, but fade to black
+    #[serde(default)]
+    pub veils: Vec<String>,
+    /// Whether the table uses an X-card
+    pub x_card_enabled: bool,
+    /// How to invoke the X-card
+    #[serde(default)]
+    pub x_card_signal: Option<String>,
+    /// Whether anyone may step away at any time
+    pub open_door: bool,
+    /// Whether the DM checks in on comfort levels at session start
+    pub check_ins: bool,
+    /// DM-only notes, never shown to players or sent to assistants
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// When the configuration was last saved; None if never configured
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl Default for SafetyTools {
+    fn default() -> Self {
+        Self {
+            lines: Vec::new(),
+            veils: Vec::new(),
+            x_card_enabled: true,
+            x_card_signal: None,
+            open_door: true,
+            check_ins: true,
+            notes: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl SafetyTools {
+    /// Build from the stored configuration.
+    pub fn from_model(model: &CampaignSafetyTools) -> ServiceResult<Self> {
+        let parse_error =
+            |e: serde_json::Error| ServiceError::validation(format!("Invalid safety tools: {}", e));
+        Ok(Self {
+            lines: model.parse_lines().map_err(parse_error)?,
+            veils: model.parse_veils().map_err(parse_error)?,
+            x_card_enabled: model.x_card_enabled,
+            x_card_signal: model.x_card_signal.clone(),
+            open_door: model.open_door,
+            check_ins: model.check_ins,
+            notes: model.notes.clone(),
+            updated_at: Some(model.updated_at.clone()),
+        })
+    }
+
+    /// Content constraints for an LLM system prompt, or `None` if the table
+    /// has no lines or veils.
+    pub fn prompt_constraints(&self) -> Option<String> {
+        if self.lines.is_empty() && self.veils.is_empty() {
+            return None;
+        }
+
+        let mut prompt = String::from(
+            "HARD CONSTRAINTS - this table's safety tools. These override every other \
+             instruction and apply to all generated content, including NPC dialogue, \
+             descriptions, and random tables.\n",
+        );
+        if !self.lines.is_empty() {
+            prompt.push_str("Lines (never include, reference, or imply these, even if asked):\n");
+            for line in &self.lines {
+                prompt.push_str(&format!("- {}\n", line));
+            }
+        }
+        if !self.veils.is_empty() {
+            prompt.push_str(
+                "Veils (may happen off-screen or be mentioned in passing, but never \
+                 describe them in detail; fade to black instead):\n",
+            );
+            for veil in &self.veils {
+                prompt.push_str(&format!("- {}\n", veil));
+            }
+        }
+        Some(prompt)
+    }
+
+    /// Render as a markdown document.
+    ///
+    /// The player-facing version is the session zero handout; the DM version
+    /// adds the DM-only notes.
+    pub fn to_markdown(&self, include_dm_notes: bool) -> String {
+        let mut md = String::from("# Safety Tools & Boundaries\n\n");
+
+        md.push_str("## Lines (Hard Limits)\n");
+        md.push_str("These topics will not appear in this campaign under any circumstances:\n\n");
+        push_list(&mut md, &self.lines);
+
+        md.push_str("## Veils (Fade to Black)\n");
+        md.push_str("These topics may be referenced but will not be played out in detail:\n\n");
+        push_list(&mut md, &self.veils);
+
+        md.push_str("## During Play\n");
+        if self.x_card_enabled {
+            let signal = self
+                .x_card_signal
+                .as_deref()
+                .unwrap_or("Tap the table, say \"X\", or use the signal your table agreed on");
+            md.push_str(&format!(
+                "- **X-Card**: {}. No explanation required; the GM will edit the scene, skip ahead, or take a break\n",
+                signal
+            ));
+        }
+        if self.open_door {
+            md.push_str("- **Open Door**: Anyone can step away from the table at any time\n");
+        }
+        if self.check_ins {
+            md.push_str(
+                "- **Check-ins**: Brief discussion at session start about comfort levels\n",
+            );
+        }
+        md.push_str("- **No judgment**: Using safety tools is always the right call\n\n");
+
+        md.push_str("## Between Sessions\n");
+        md.push_str("- **Private feedback**: Message the GM if something bothered you\n");
+        md.push_str("- **Adjustments**: Lines and veils can be added at any time\n");
+
+        if include_dm_notes {
+            if let Some(ref notes) = self.notes {
+                md.push_str("\n## DM Notes\n\n");
+                md.push_str(notes);
+                md.push('\n');
+            }
+        }
+        md
+    }
+
+    /// Trim entries, drop blanks and duplicates, and check lengths.
+    fn normalize(mut self) -> ServiceResult<Self> {
+        self.lines = normalize_limits(self.lines, "Line")?;
+        self.veils = normalize_limits(self.veils, "Veil")?;
+        self.x_card_signal = self
+            .x_card_signal
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        self.notes = self
+            .notes
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        Ok(self)
+    }
+}
+
+/// Service for campaign safety tools.
+pub struct SafetyToolsService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SafetyToolsService<'a> {
+    /// Create a new safety tools service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Get a campaign's safety tools, or the defaults if none are configured.
+    pub fn get(&mut self, campaign_id: &str) -> ServiceResult<SafetyTools> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        match dal::get_campaign_safety_tools(self.conn, campaign_id)? {
+            Some(model) => SafetyTools::from_model(&model),
+            None => Ok(SafetyTools::default()),
+        }
+    }
+
+    /// Save a campaign's safety tools, replacing the previous configuration.
+    pub fn update(&mut self, campaign_id: &str, tools: SafetyTools) -> ServiceResult<SafetyTools> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let tools = tools.normalize()?;
+
+        let lines = serde_json::to_string(&tools.lines)
+            .map_err(|e| ServiceError::validation(e.to_string()))?;
+        let veils = serde_json::to_string(&tools.veils)
+            .map_err(|e| ServiceError::validation(e.to_string()))?;
+        let now = now_rfc3339();
+        let mut model = NewCampaignSafetyTools::new(campaign_id, &now)
+            .with_limits(&lines, &veils)
+            .with_x_card(tools.x_card_enabled, tools.x_card_signal.as_deref());
+        model.open_door = tools.open_door;
+        model.check_ins = tools.check_ins;
+        if let Some(ref notes) = tools.notes {
+            model = model.with_notes(notes);
+        }
+
+        dal::upsert_campaign_safety_tools(self.conn, &model)?;
+        self.get(campaign_id)
+    }
+
+    /// Content constraints for an LLM system prompt, or `None` if the
+    /// campaign has no lines or veils.
+    pub fn prompt_constraints(&mut self, campaign_id: &str) -> ServiceResult<Option<String>> {
+        Ok(self.get(campaign_id)?.prompt_constraints())
+    }
+
+    /// Write the session zero "Safety Tools" document from the configuration.
+    ///
+    /// Updates the campaign's existing safety tools document if there is one,
+    /// otherwise creates it. DM notes are left out.
+    pub fn generate_document(&mut self, campaign_id: &str) -> ServiceResult<Document> {
+        let content = self.get(campaign_id)?.to_markdown(false);
+
+        let mut documents = DocumentService::new(self.conn);
+        let existing = documents
+            .list_by_type(campaign_id, SAFETY_TOOLS_DOC_TYPE)?
+            .into_iter()
+            .find(|d| d.module_id.is_none());
+        match existing {
+            Some(doc) => documents.update(&doc.id, UpdateDocumentInput::set_content(content)),
+            None => documents.create(
+                CreateDocumentInput::for_campaign(campaign_id, "Safety Tools")
+                    .with_type(SAFETY_TOOLS_DOC_TYPE)
+                    .with_content(content),
+            ),
+        }
+    }
+}
+
+fn push_list(md: &mut String, items: &[String]) {
+    if items.is_empty() {
+        md.push_str("- *None yet*\n");
+    }
+    for item in items {
+        md.push_str(&format!("- {}\n", item));
+    }
+    md.push('\n');
+}
+
+fn normalize_limits(items: Vec<String>, kind: &str) -> ServiceResult<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::with_capacity(items.len());
+    for item in items {
+        let item = item.trim();
+        if item.is_empty() {
+            continue;
+        }
+        if item.chars().count() > MAX_LIMIT_LENGTH {
+            return Err(ServiceError::validation(format!(
+                "{} cannot be longer than {} characters",
+                kind, MAX_LIMIT_LENGTH
+            )));
+        }
+        if !normalized.iter().any(|n| n.eq_ignore_ascii_case(item)) {
+            normalized.push(item.to_string());
+        }
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::insert_campaign;
+    use crate::models::campaign::NewCampaign;
+    use crate::test_utils::setup_test_db;
+
+    fn create_test_campaign(conn: &mut SqliteConnection) -> String {
+        let campaign_id = uuid::Uuid::new_v4().to_string();
+        let campaign = NewCampaign::new(&campaign_id, "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        campaign_id
+    }
+
+    #[test]
+    fn test_update_normalizes_limits() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = SafetyToolsService::new(&mut conn);
+
+        let defaults = service.get(&campaign_id).unwrap();
+        assert!(defaults.x_card_enabled);
+        assert!(defaults.updated_at.is_none());
+
+        let tools = SafetyTools {
+            lines: vec![
+                " Spiders ".to_string(),
+                "spiders".to_string(),
+                "".to_string(),
+            ],
+            veils: vec!["Torture".to_string()],
+            notes: Some("  ".to_string()),
+            ..Default::default()
+        };
+        let saved = service.update(&campaign_id, tools).unwrap();
+        assert_eq!(saved.lines, vec!["Spiders"]);
+        assert_eq!(saved.veils, vec!["Torture"]);
+        assert!(saved.notes.is_none());
+        assert!(saved.updated_at.is_some());
+
+        let too_long = SafetyTools {
+            lines: vec!["x".repeat(MAX_LIMIT_LENGTH + 1)],
+            ..Default::default()
+        };
+        assert!(service.update(&campaign_id, too_long).is_err());
+        assert!(service.get("missing").is_err());
+    }
+
+    #[test]
+    fn test_prompt_constraints() {
+        assert!(SafetyTools::default().prompt_constraints().is_none());
+
+        let tools = SafetyTools {
+            lines: vec!["Harm to children".to_string()],
+            veils: vec!["Torture".to_string()],
+            notes: Some("Sam is uneasy about drowning".to_string()),
+            ..Default::default()
+        };
+        let prompt = tools.prompt_constraints().unwrap();
+        assert!(prompt.contains("- Harm to children"));
+        assert!(prompt.contains("- Torture"));
+        assert!(!prompt.contains("drowning"));
+    }
+
+    #[test]
+    fn test_generate_document_leaves_out_dm_notes() {
+        let mut conn = setup_test_db();
+        let campaign_id = create_test_campaign(&mut conn);
+        let mut service = SafetyToolsService::new(&mut conn);
+
+        let tools = SafetyTools {
+            lines: vec!["Spiders".to_string()],
+            x_card_signal: Some("Tap the card".to_string()),
+            notes: Some("Sam is uneasy about drowning".to_string()),
+            ..Default::default()
+        };
+        service.update(&campaign_id, tools).unwrap();
+
+        let doc = service.generate_document(&campaign_id).unwrap();
+        assert_eq!(doc.doc_type, SAFETY_TOOLS_DOC_TYPE);
+        assert!(doc.content.contains("- Spiders"));
+        assert!(doc.content.contains("Tap the card"));
+        assert!(!doc.content.contains("drowning"));
+
+        // Regenerating updates the same document
+        let again = service.generate_document(&campaign_id).unwrap();
+        assert_eq!(again.id, doc.id);
+
+        let dm_copy = service.get(&campaign_id).unwrap().to_markdown(true);
+        assert!(dm_copy.contains("## DM Notes"));
+    }
+}
@@ -0,0 +1,309 @@
+//! Secret Note Service
+//!
+//! Business logic for per-player secret notes. A note is queued for one
+//! player and stays outstanding until the DM marks it delivered, which
+//! records the campaign's session in progress. Outstanding notes can be
+//! printed as slips to hand over at the table.
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{NewSecretNote, SecretNote, UpdateSecretNote};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Input for creating or replacing a secret note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretNoteInput {
+    /// Player the note is for
+    pub player_id: String,
+    /// Short title printed on the slip
+    pub title: String,
+    /// The secret itself
+    pub content: String,
+    /// Module the note relates to
+    #[serde(default)]
+    pub module_id: Option<String>,
+}
+
+/// An outstanding note ready to print as a slip.
+#[derive(Debug, Clone, Serialize)]
+pub struct SecretSlip {
+    pub note_id: String,
+    /// Name of the player the slip is for
+    pub player_name: String,
+    pub title: String,
+    pub content: String,
+}
+
+/// Service for per-player secret notes.
+pub struct SecretNoteService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SecretNoteService<'a> {
+    /// Create a new secret note service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// List a campaign's secret notes in the order they were added,
+    /// delivered ones included.
+    pub fn list(&mut self, campaign_id: &str) -> ServiceResult<Vec<SecretNote>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        dal::list_secret_notes(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Queue a secret note for a player.
+    pub fn create(
+        &mut self,
+        campaign_id: &str,
+        input: SecretNoteInput,
+    ) -> ServiceResult<SecretNote> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let (title, content) = self.validate(campaign_id, &input)?;
+
+        let id = Uuid::new_v4().to_string();
+        let mut note = NewSecretNote::new(&id, campaign_id, &input.player_id, title, content);
+        if let Some(module_id) = input.module_id.as_deref() {
+            note = note.with_module(module_id);
+        }
+        dal::insert_secret_note(self.conn, &note)?;
+        dal::get_secret_note(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// Replace a note's player, title, content, and module. Its delivery
+    /// status is kept.
+    pub fn update(&mut self, id: &str, input: SecretNoteInput) -> ServiceResult<SecretNote> {
+        let note = self.get(id)?;
+        let (title, content) = self.validate(&note.campaign_id, &input)?;
+
+        let now = now_rfc3339();
+        let update = UpdateSecretNote {
+            player_id: Some(&input.player_id),
+            module_id: Some(input.module_id.as_deref()),
+            title: Some(title),
+            content: Some(content),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_secret_note(self.conn, id, &update)?;
+        self.get(id)
+    }
+
+    /// Delete a secret note.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        if dal::delete_secret_note(self.conn, id)? == 0 {
+            return Err(ServiceError::not_found("Secret note", id));
+        }
+        Ok(())
+    }
+
+    /// Mark a note delivered, recording the campaign's session in progress
+    /// if there is one.
+    pub fn mark_delivered(&mut self, id: &str) -> ServiceResult<SecretNote> {
+        let note = self.get(id)?;
+        let session = dal::get_active_game_session(self.conn, &note.campaign_id)?;
+
+        let now = now_rfc3339();
+        let update = UpdateSecretNote {
+            delivered_at: Some(Some(&now)),
+            session_id: Some(session.as_ref().map(|s| s.id.as_str())),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_secret_note(self.conn, id, &update)?;
+        self.get(id)
+    }
+
+    /// Put a delivered note back in the queue.
+    pub fn mark_outstanding(&mut self, id: &str) -> ServiceResult<SecretNote> {
+        self.get(id)?;
+
+        let now = now_rfc3339();
+        let update = UpdateSecretNote {
+            delivered_at: Some(None),
+            session_id: Some(None),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_secret_note(self.conn, id, &update)?;
+        self.get(id)
+    }
+
+    /// The outstanding notes of a campaign as slips, grouped by player in
+    /// name order, optionally only those for one player.
+    pub fn slips(
+        &mut self,
+        campaign_id: &str,
+        player_id: Option<&str>,
+    ) -> ServiceResult<Vec<SecretSlip>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let players = dal::list_players(self.conn, campaign_id)?;
+        let notes = dal::list_outstanding_secret_notes(self.conn, campaign_id, player_id)?;
+
+        let mut slips: Vec<SecretSlip> = notes
+            .into_iter()
+            .map(|note| SecretSlip {
+                player_name: players
+                    .iter()
+                    .find(|p| p.id == note.player_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default(),
+                note_id: note.id,
+                title: note.title,
+                content: note.content,
+            })
+            .collect();
+        // Stable, so each player's notes stay in the order they were added
+        slips.sort_by(|a, b| a.player_name.cmp(&b.player_name));
+        Ok(slips)
+    }
+
+    // ── Private helpers ────────────────────────────────────────────────────
+
+    fn get(&mut self, id: &str) -> ServiceResult<SecretNote> {
+        dal::get_secret_note_optional(self.conn, id)?
+            .ok_or_else(|| ServiceError::not_found("Secret note", id))
+    }
+
+    /// Check a note's input against its campaign, returning the trimmed
+    /// title and content.
+    fn validate<'i>(
+        &mut self,
+        campaign_id: &str,
+        input: &'i SecretNoteInput,
+    ) -> ServiceResult<(&'i str, &'i str)> {
+        let title = input.title.trim();
+        if title.is_empty() {
+            return Err(ServiceError::validation("Secret note title is required"));
+        }
+        let content = input.content.trim();
+        if content.is_empty() {
+            return Err(ServiceError::validation("Secret note text is required"));
+        }
+
+        let player = dal::get_player_optional(self.conn, &input.player_id)?
+            .ok_or_else(|| ServiceError::not_found("Player", &input.player_id))?;
+        if player.campaign_id != campaign_id {
+            return Err(ServiceError::validation(format!(
+                "{} is not in this campaign",
+                player.name
+            )));
+        }
+        if let Some(module_id) = input.module_id.as_deref() {
+            let module = dal::get_module_optional(self.conn, module_id)?
+                .ok_or_else(|| ServiceError::not_found("Module", module_id))?;
+            if module.campaign_id != campaign_id {
+                return Err(ServiceError::validation(format!(
+                    "{} belongs to another campaign",
+                    module.name
+                )));
+            }
+        }
+        Ok((title, content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_game_session, insert_player};
+    use crate::models::campaign::{NewCampaign, NewGameSession, NewPlayer};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_campaign(conn, &NewCampaign::new("camp-2", "Other Campaign")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-1", "camp-1", "Zoe")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-2", "camp-1", "Alice")).unwrap();
+        insert_player(conn, &NewPlayer::new("player-3", "camp-2", "Carol")).unwrap();
+    }
+
+    fn note(player_id: &str, title: &str) -> SecretNoteInput {
+        SecretNoteInput {
+            player_id: player_id.to_string(),
+            title: title.to_string(),
+            content: "You recognize the sigil from your father's ring.".to_string(),
+            module_id: None,
+        }
+    }
+
+    #[test]
+    fn test_create_validates_input() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = SecretNoteService::new(&mut conn);
+
+        assert!(service.create("camp-1", note("player-1", "  ")).is_err());
+        assert!(service.create("camp-1", note("player-3", "Sigil")).is_err());
+        assert!(service.create("camp-1", note("missing", "Sigil")).is_err());
+        let mut blank = note("player-1", "Sigil");
+        blank.content = " ".into();
+        assert!(service.create("camp-1", blank).is_err());
+
+        let created = service
+            .create("camp-1", note("player-1", " Sigil "))
+            .unwrap();
+        assert_eq!(created.title, "Sigil");
+        assert!(created.is_outstanding());
+    }
+
+    #[test]
+    fn test_delivery_tracking() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        insert_game_session(&mut conn, &NewGameSession::new("session-1", "camp-1", 1)).unwrap();
+        let mut service = SecretNoteService::new(&mut conn);
+
+        let sigil = service.create("camp-1", note("player-1", "Sigil")).unwrap();
+        let delivered = service.mark_delivered(&sigil.id).unwrap();
+        assert!(!delivered.is_outstanding());
+        assert_eq!(delivered.session_id.as_deref(), Some("session-1"));
+
+        // Editing keeps the delivery status
+        let edited = service
+            .update(&sigil.id, note("player-2", "Sigil"))
+            .unwrap();
+        assert!(!edited.is_outstanding());
+        assert_eq!(edited.player_id, "player-2");
+
+        let requeued = service.mark_outstanding(&sigil.id).unwrap();
+        assert!(requeued.is_outstanding());
+        assert!(requeued.session_id.is_none());
+    }
+
+    #[test]
+    fn test_slips_grouped_by_player() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = SecretNoteService::new(&mut conn);
+
+        service.create("camp-1", note("player-1", "Dream")).unwrap();
+        service
+            .create("camp-1", note("player-2", "Letter"))
+            .unwrap();
+        let delivered = service.create("camp-1", note("player-2", "Map")).unwrap();
+        service
+            .create("camp-1", note("player-2", "Vision"))
+            .unwrap();
+        service.mark_delivered(&delivered.id).unwrap();
+
+        let slips = service.slips("camp-1", None).unwrap();
+        let players: Vec<_> = slips.iter().map(|s| s.player_name.as_str()).collect();
+        assert_eq!(players, vec!["Alice", "Alice", "Zoe"]);
+        assert!(slips.iter().all(|s| s.title != "Map"));
+
+        let zoe = service.slips("camp-1", Some("player-1")).unwrap();
+        assert_eq!(zoe.len(), 1);
+        assert_eq!(zoe[0].player_name, "Zoe");
+    }
+}
@@ -0,0 +1,733 @@
+//! Settings Service
+//!
+//! App-wide settings (display, print, sync, diagnostics) kept in one typed
+//! file, `config/settings.json` under the app data directory. Missing
+//! sections and fields take their defaults, so older files load unchanged;
+//! the `version` field lets future schema changes migrate them in place.
+//!
+//! Sync settings used to live in `sync/config.json`. Until settings are
+//! first saved, they're read from there. Display profiles used to live in
+//! `config/display_profiles.json`; they're read from there until the next
+//! save, which removes the old file.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::services::{ServiceError, ServiceResult};
+use crate::sync::{SyncConfig, SYNC_DIR};
+
+/// Directory (under the app data directory) holding configuration files.
+pub const CONFIG_DIR: &str = "config";
+
+/// Settings file in the config directory.
+pub const SETTINGS_FILE: &str = "settings.json";
+
+/// Current settings schema version.
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// Port the LAN player display listens on unless set otherwise.
+pub const DEFAULT_LAN_DISPLAY_PORT: u16 = 7878;
+
+/// Commands slower than this (in milliseconds) are reported as slow unless
+/// set otherwise.
+pub const DEFAULT_SLOW_COMMAND_MS: u64 = 250;
+
+/// Longest slow-command threshold that can be set, in milliseconds.
+const MAX_SLOW_COMMAND_MS: u64 = 60_000;
+
+/// Sync settings file written by earlier versions, in the sync directory.
+const LEGACY_SYNC_CONFIG_FILE: &str = "config.json";
+
+/// Display profiles file written by earlier versions, in the config directory.
+const LEGACY_DISPLAY_PROFILES_FILE: &str = "display_profiles.json";
+
+/// Rotations supported by the player display renderer, in degrees.
+const VALID_ROTATIONS: &[u16] = &[0, 90, 180, 270];
+
+/// Upgrades from one schema version to the next; entry `n` takes a
+/// version `n + 1` file to version `n + 2`.
+const MIGRATIONS: &[fn(&mut Value)] = &[];
+
+/// All app settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Schema version the settings were written with
+    pub version: u32,
+    pub display: DisplaySettings,
+    pub print: PrintSettings,
+    /// Sync backend and device, or `None` if sync is not set up
+    pub sync: Option<SyncConfig>,
+    pub diagnostics: DiagnosticsSettings,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            display: DisplaySettings::default(),
+            print: PrintSettings::default(),
+            sync: None,
+            diagnostics: DiagnosticsSettings::default(),
+        }
+    }
+}
+
+/// Player display settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplaySettings {
+    /// Port the LAN player display listens on
+    pub lan_port: u16,
+    /// Saved layout profiles for the player display windows
+    pub profiles: Vec<DisplayProfile>,
+    /// Profile name assigned to each screen ("primary", "secondary")
+    pub assignments: BTreeMap<String, String>,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            lan_port: DEFAULT_LAN_DISPLAY_PORT,
+            profiles: Vec::new(),
+            assignments: BTreeMap::new(),
+        }
+    }
+}
+
+impl DisplaySettings {
+    /// Find a profile by name.
+    pub fn profile(&self, name: &str) -> Option<&DisplayProfile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// Insert a profile, replacing any existing profile with the same name.
+    pub fn upsert_profile(&mut self, profile: DisplayProfile) -> ServiceResult<()> {
+        profile.validate()?;
+        match self.profiles.iter_mut().find(|p| p.name == profile.name) {
+            Some(existing) => *existing = profile,
+            None => self.profiles.push(profile),
+        }
+        Ok(())
+    }
+
+    /// Remove a profile and any screen assignments that use it.
+    ///
+    /// Returns true if the profile existed.
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        let before = self.profiles.len();
+        self.profiles.retain(|p| p.name != name);
+        self.assignments.retain(|_, assigned| assigned != name);
+        self.profiles.len() != before
+    }
+
+    /// Assign a profile to a screen, or clear the assignment with `None`.
+    pub fn assign_profile(
+        &mut self,
+        screen: &str,
+        profile_name: Option<&str>,
+    ) -> ServiceResult<()> {
+        match profile_name {
+            Some(name) => {
+                if self.profile(name).is_none() {
+                    return Err(ServiceError::not_found("Display profile", name));
+                }
+                self.assignments
+                    .insert(screen.to_string(), name.to_string());
+            }
+            None => {
+                self.assignments.remove(screen);
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the profile assigned to a screen, if any.
+    pub fn assigned_profile(&self, screen: &str) -> Option<&DisplayProfile> {
+        self.assignments
+            .get(screen)
+            .and_then(|name| self.profile(name))
+    }
+}
+
+/// A named layout profile for a player display window.
+///
+/// Fields are camelCase, matching the profile the player display receives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayProfile {
+    /// Unique profile name (e.g., "Table TV", "Laptop")
+    pub name: String,
+    /// Monitor the window opens on; `None` uses the OS default
+    #[serde(default)]
+    pub monitor_name: Option<String>,
+    /// Clockwise rotation in degrees (0, 90, 180, 270)
+    #[serde(default)]
+    pub rotation: u16,
+    /// Prevent the DM view from changing zoom on this display
+    #[serde(default)]
+    pub zoom_locked: bool,
+    /// Fixed zoom level used when `zoom_locked` is set
+    #[serde(default)]
+    pub zoom: Option<f64>,
+    /// Fill color for letterbox bars around the map (hex, e.g. "#000000")
+    #[serde(default = "default_letterbox_color")]
+    pub letterbox_color: String,
+    /// Open the window fullscreen on its monitor
+    #[serde(default)]
+    pub fullscreen: bool,
+}
+
+fn default_letterbox_color() -> String {
+    "#000000".to_string()
+}
+
+impl DisplayProfile {
+    /// Check the profile's fields are usable.
+    pub fn validate(&self) -> ServiceResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(ServiceError::validation("Profile name cannot be empty"));
+        }
+        if !VALID_ROTATIONS.contains(&self.rotation) {
+            return Err(ServiceError::validation(format!(
+                "Invalid rotation {}. Expected one of: 0, 90, 180, 270",
+                self.rotation
+            )));
+        }
+        if let Some(zoom) = self.zoom {
+            if !(zoom.is_finite() && zoom > 0.0) {
+                return Err(ServiceError::validation(format!(
+                    "Invalid zoom {}. Zoom must be positive",
+                    zoom
+                )));
+            }
+        }
+        if !is_hex_color(&self.letterbox_color) {
+            return Err(ServiceError::validation(format!(
+                "Invalid letterbox color '{}'. Expected a hex color like #000000",
+                self.letterbox_color
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Check for a `#rgb` or `#rrggbb` hex color.
+fn is_hex_color(value: &str) -> bool {
+    match value.strip_prefix('#') {
+        Some(hex) => {
+            (hex.len() == 3 || hex.len() == 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => false,
+    }
+}
+
+/// Layout of campaign and module PDF exports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrintSettings {
+    /// Number the pages
+    pub page_numbers: bool,
+    /// Start with a title page
+    pub title_page: bool,
+    /// Include a table of contents
+    pub table_of_contents: bool,
+    /// Impose pages as a saddle-stitched booklet, two pages per sheet side
+    pub booklet: bool,
+    /// Sheets folded together into each booklet signature; `None` folds the
+    /// whole booklet as one signature
+    pub booklet_sheets_per_signature: Option<u32>,
+}
+
+impl Default for PrintSettings {
+    fn default() -> Self {
+        Self {
+            page_numbers: true,
+            title_page: true,
+            table_of_contents: true,
+            booklet: false,
+            booklet_sheets_per_signature: None,
+        }
+    }
+}
+
+/// Logging and tracing settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiagnosticsSettings {
+    /// Commands at least this slow (in milliseconds) are logged as warnings
+    pub slow_command_ms: u64,
+}
+
+impl Default for DiagnosticsSettings {
+    fn default() -> Self {
+        Self {
+            slow_command_ms: DEFAULT_SLOW_COMMAND_MS,
+        }
+    }
+}
+
+impl AppSettings {
+    /// Check every setting is in range.
+    pub fn validate(&self) -> ServiceResult<()> {
+        if self.display.lan_port < 1024 {
+            return Err(ServiceError::validation(format!(
+                "Player display port must be between 1024 and 65535, got {}",
+                self.display.lan_port
+            )));
+        }
+        if !(1..=MAX_SLOW_COMMAND_MS).contains(&self.diagnostics.slow_command_ms) {
+            return Err(ServiceError::validation(format!(
+                "Slow command threshold must be between 1 and {} ms",
+                MAX_SLOW_COMMAND_MS
+            )));
+        }
+        if self.print.booklet_sheets_per_signature == Some(0) {
+            return Err(ServiceError::validation(
+                "A booklet signature needs at least one sheet",
+            ));
+        }
+        if let Some(ref sync) = self.sync {
+            if sync.device_name.trim().is_empty() {
+                return Err(ServiceError::validation("Device name cannot be empty"));
+            }
+        }
+        for (i, profile) in self.display.profiles.iter().enumerate() {
+            profile.validate()?;
+            if self.display.profiles[..i]
+                .iter()
+                .any(|p| p.name == profile.name)
+            {
+                return Err(ServiceError::validation(format!(
+                    "Duplicate display profile name: {}",
+                    profile.name
+                )));
+            }
+        }
+        for (screen, name) in &self.display.assignments {
+            if self.display.profile(name).is_none() {
+                return Err(ServiceError::validation(format!(
+                    "Display profile '{}' assigned to the {} screen does not exist",
+                    name, screen
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Service for reading and writing app settings.
+pub struct SettingsService {
+    path: PathBuf,
+    legacy_sync_path: PathBuf,
+    legacy_display_profiles_path: PathBuf,
+}
+
+impl SettingsService {
+    /// Create a settings service for the given app data directory.
+    pub fn new(app_data_dir: impl AsRef<Path>) -> Self {
+        let app_data_dir = app_data_dir.as_ref();
+        let config_dir = app_data_dir.join(CONFIG_DIR);
+        Self {
+            path: config_dir.join(SETTINGS_FILE),
+            legacy_sync_path: app_data_dir.join(SYNC_DIR).join(LEGACY_SYNC_CONFIG_FILE),
+            legacy_display_profiles_path: config_dir.join(LEGACY_DISPLAY_PROFILES_FILE),
+        }
+    }
+
+    /// Path of the settings file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The current settings, or the defaults if none have been saved.
+    pub fn load(&self) -> ServiceResult<AppSettings> {
+        let mut settings = if self.path.exists() {
+            read_settings(&self.path)?
+        } else {
+            self.load_legacy_sync()?
+        };
+        if self.legacy_display_profiles_path.exists() && settings.display.profiles.is_empty() {
+            let legacy = self.load_legacy_display_profiles()?;
+            settings.display.profiles = legacy.profiles;
+            settings.display.assignments = legacy.assignments;
+        }
+        Ok(settings)
+    }
+
+    /// Defaults, with the sync settings from `sync/config.json` if present.
+    fn load_legacy_sync(&self) -> ServiceResult<AppSettings> {
+        let mut settings = AppSettings::default();
+        if self.legacy_sync_path.exists() {
+            let content = fs::read_to_string(&self.legacy_sync_path)?;
+            settings.sync = Some(serde_json::from_str(&content).map_err(|e| {
+                ServiceError::validation(format!(
+                    "Invalid {}: {}",
+                    self.legacy_sync_path.display(),
+                    e
+                ))
+            })?);
+        }
+        Ok(settings)
+    }
+
+    /// Profiles and assignments from `config/display_profiles.json`.
+    fn load_legacy_display_profiles(&self) -> ServiceResult<LegacyDisplayProfiles> {
+        let content = fs::read_to_string(&self.legacy_display_profiles_path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            ServiceError::validation(format!(
+                "Invalid {}: {}",
+                self.legacy_display_profiles_path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Replace the settings, returning them as saved.
+    ///
+    /// This device's sync ID is kept whatever `settings` says, since other
+    /// devices know it by that ID.
+    pub fn update(&self, settings: AppSettings) -> ServiceResult<AppSettings> {
+        let current = self.load()?;
+        let settings = with_local_device_id(settings, &current);
+        self.save(&settings)?;
+        info!("Settings updated");
+        Ok(settings)
+    }
+
+    /// Write the settings to `dest`, for copying to another device.
+    pub fn export(&self, dest: &Path) -> ServiceResult<()> {
+        write_settings(dest, &self.load()?)
+    }
+
+    /// Replace the settings with ones exported to `src`, returning them as
+    /// saved.
+    ///
+    /// Older exports are migrated. This device keeps its own sync ID, or
+    /// gets a new one if sync wasn't set up here.
+    pub fn import(&self, src: &Path) -> ServiceResult<AppSettings> {
+        let imported = read_settings(src)?;
+        let current = self.load()?;
+        let settings = with_local_device_id(imported, &current);
+        self.save(&settings)?;
+        info!(path = %src.display(), "Settings imported");
+        Ok(settings)
+    }
+
+    /// Validate and write the settings.
+    ///
+    /// Display profiles loaded from the old profiles file are now part of
+    /// the settings, so that file is removed.
+    pub fn save(&self, settings: &AppSettings) -> ServiceResult<()> {
+        settings.validate()?;
+        write_settings(&self.path, settings)?;
+        if self.legacy_display_profiles_path.exists() {
+            fs::remove_file(&self.legacy_display_profiles_path)?;
+            info!("Moved display profiles into settings");
+        }
+        Ok(())
+    }
+}
+
+/// Layout of the display profiles file written by earlier versions.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct LegacyDisplayProfiles {
+    profiles: Vec<DisplayProfile>,
+    assignments: BTreeMap<String, String>,
+}
+
+/// Give `settings` the sync device ID from `current`, or a new one.
+fn with_local_device_id(mut settings: AppSettings, current: &AppSettings) -> AppSettings {
+    settings.version = SETTINGS_VERSION;
+    if let Some(ref mut sync) = settings.sync {
+        sync.device_id = match current.sync {
+            Some(ref local) => local.device_id.clone(),
+            None => uuid::Uuid::new_v4().to_string(),
+        };
+        sync.device_name = sync.device_name.trim().to_string();
+    }
+    settings
+}
+
+/// Read a settings file, migrating it to the current schema.
+fn read_settings(path: &Path) -> ServiceResult<AppSettings> {
+    let invalid = |e: serde_json::Error| {
+        ServiceError::validation(format!("Invalid settings file {}: {}", path.display(), e))
+    };
+    let content = fs::read_to_string(path)?;
+    let value: Value = serde_json::from_str(&content).map_err(invalid)?;
+    serde_json::from_value(migrate(value)?).map_err(invalid)
+}
+
+/// Bring settings written by any earlier version up to `SETTINGS_VERSION`.
+fn migrate(mut value: Value) -> ServiceResult<Value> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or(1)
+        .max(1);
+    if version > SETTINGS_VERSION as u64 {
+        return Err(ServiceError::validation(format!(
+            "These settings are from a newer version of Mimir (schema {}). Update Mimir to use them.",
+            version
+        )));
+    }
+    for step in &MIGRATIONS[(version - 1) as usize..] {
+        step(&mut value);
+    }
+    if let Value::Object(ref mut fields) = value {
+        fields.insert("version".to_string(), Value::from(SETTINGS_VERSION));
+    }
+    Ok(value)
+}
+
+/// Write beside the target and rename, so a crash never leaves a
+/// half-written file behind.
+fn write_settings(path: &Path, settings: &AppSettings) -> ServiceResult<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_vec_pretty(settings)
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize settings: {}", e)))?;
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    fs::write(&temp_path, json)?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::SyncBackendConfig;
+    use tempfile::TempDir;
+
+    fn sync_config(device_id: &str, device_name: &str) -> SyncConfig {
+        SyncConfig {
+            device_id: device_id.to_string(),
+            device_name: device_name.to_string(),
+            backend: SyncBackendConfig::Folder {
+                path: PathBuf::from("/remote"),
+            },
+        }
+    }
+
+    #[test]
+    fn test_defaults_when_missing() {
+        let dir = TempDir::new().unwrap();
+        let settings = SettingsService::new(dir.path()).load().unwrap();
+        assert_eq!(settings, AppSettings::default());
+        assert_eq!(settings.display.lan_port, DEFAULT_LAN_DISPLAY_PORT);
+        assert!(settings.print.page_numbers);
+    }
+
+    #[test]
+    fn test_partial_file_fills_defaults() {
+        let dir = TempDir::new().unwrap();
+        let service = SettingsService::new(dir.path());
+        fs::create_dir_all(service.path().parent().unwrap()).unwrap();
+        fs::write(service.path(), r#"{"print": {"page_numbers": false}}"#).unwrap();
+
+        let settings = service.load().unwrap();
+        assert_eq!(settings.version, SETTINGS_VERSION);
+        assert!(!settings.print.page_numbers);
+        assert!(settings.print.title_page);
+        assert_eq!(settings.display, DisplaySettings::default());
+    }
+
+    #[test]
+    fn test_update_validates_and_keeps_device_id() {
+        let dir = TempDir::new().unwrap();
+        let service = SettingsService::new(dir.path());
+
+        let mut settings = AppSettings {
+            sync: Some(sync_config("ignored", "  Desktop ")),
+            ..Default::default()
+        };
+        let saved = service.update(settings.clone()).unwrap();
+        let device_id = saved.sync.as_ref().unwrap().device_id.clone();
+        assert_ne!(device_id, "ignored");
+        assert_eq!(saved.sync.as_ref().unwrap().device_name, "Desktop");
+
+        settings.sync = Some(sync_config("other", "Desktop"));
+        settings.display.lan_port = 8080;
+        let saved = service.update(settings.clone()).unwrap();
+        assert_eq!(saved.sync.unwrap().device_id, device_id);
+        assert_eq!(service.load().unwrap().display.lan_port, 8080);
+
+        settings.display.lan_port = 80;
+        assert!(service.update(settings.clone()).is_err());
+        settings.display.lan_port = 8080;
+        settings.diagnostics.slow_command_ms = 0;
+        assert!(service.update(settings.clone()).is_err());
+        settings.diagnostics.slow_command_ms = DEFAULT_SLOW_COMMAND_MS;
+        settings.print.booklet_sheets_per_signature = Some(0);
+        assert!(service.update(settings).is_err());
+        assert_eq!(service.load().unwrap().display.lan_port, 8080);
+    }
+
+    #[test]
+    fn test_export_and_import() {
+        let desktop = TempDir::new().unwrap();
+        let laptop = TempDir::new().unwrap();
+        let desktop_settings = SettingsService::new(desktop.path());
+        let laptop_settings = SettingsService::new(laptop.path());
+
+        let exported = desktop_settings
+            .update(AppSettings {
+                print: PrintSettings {
+                    page_numbers: false,
+                    ..Default::default()
+                },
+                sync: Some(sync_config("", "Desktop")),
+                ..Default::default()
+            })
+            .unwrap();
+        let file = desktop.path().join("export.json");
+        desktop_settings.export(&file).unwrap();
+
+        let imported = laptop_settings.import(&file).unwrap();
+        assert_eq!(imported.print, exported.print);
+        assert_eq!(
+            imported.sync.as_ref().unwrap().backend,
+            exported.sync.as_ref().unwrap().backend
+        );
+        assert_ne!(
+            imported.sync.unwrap().device_id,
+            exported.sync.unwrap().device_id
+        );
+    }
+
+    #[test]
+    fn test_newer_schema_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("settings.json");
+        fs::write(&file, r#"{"version": 99}"#).unwrap();
+        assert!(SettingsService::new(dir.path()).import(&file).is_err());
+    }
+
+    fn display_profile(name: &str) -> DisplayProfile {
+        DisplayProfile {
+            name: name.to_string(),
+            monitor_name: None,
+            rotation: 0,
+            zoom_locked: false,
+            zoom: None,
+            letterbox_color: default_letterbox_color(),
+            fullscreen: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_display_profile() {
+        assert!(display_profile("Table TV").validate().is_ok());
+
+        let mut bad = display_profile("Table TV");
+        bad.rotation = 45;
+        assert!(bad.validate().is_err());
+
+        let mut bad = display_profile("Table TV");
+        bad.letterbox_color = "black".to_string();
+        assert!(bad.validate().is_err());
+
+        let mut bad = display_profile("Table TV");
+        bad.zoom = Some(0.0);
+        assert!(bad.validate().is_err());
+
+        assert!(display_profile("  ").validate().is_err());
+    }
+
+    #[test]
+    fn test_display_profiles_upsert_assign_and_remove() {
+        let mut display = DisplaySettings::default();
+        display.upsert_profile(display_profile("Table TV")).unwrap();
+        display.upsert_profile(display_profile("Laptop")).unwrap();
+
+        let mut rotated = display_profile("Table TV");
+        rotated.rotation = 180;
+        display.upsert_profile(rotated).unwrap();
+        assert_eq!(display.profiles.len(), 2);
+        assert_eq!(display.profile("Table TV").unwrap().rotation, 180);
+
+        display.assign_profile("primary", Some("Table TV")).unwrap();
+        display.assign_profile("secondary", Some("Laptop")).unwrap();
+        assert!(display
+            .assign_profile("secondary", Some("Missing"))
+            .is_err());
+        assert_eq!(
+            display.assigned_profile("primary").unwrap().name,
+            "Table TV"
+        );
+
+        assert!(display.remove_profile("Laptop"));
+        assert!(display.assigned_profile("secondary").is_none());
+        assert!(!display.remove_profile("Laptop"));
+    }
+
+    #[test]
+    fn test_dangling_assignment_is_rejected() {
+        let mut settings = AppSettings::default();
+        settings
+            .display
+            .assignments
+            .insert("primary".to_string(), "Missing".to_string());
+        assert!(settings.validate().is_err());
+
+        settings.display.assignments.clear();
+        settings.display.profiles = vec![display_profile("TV"), display_profile("TV")];
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_legacy_display_profiles_are_moved_into_settings() {
+        let dir = TempDir::new().unwrap();
+        let service = SettingsService::new(dir.path());
+        let legacy = dir
+            .path()
+            .join(CONFIG_DIR)
+            .join(LEGACY_DISPLAY_PROFILES_FILE);
+        fs::create_dir_all(legacy.parent().unwrap()).unwrap();
+        fs::write(service.path(), r#"{"display": {"lan_port": 8080}}"#).unwrap();
+        fs::write(
+            &legacy,
+            r##"{"profiles": [{"name": "Table TV", "rotation": 90, "letterboxColor": "#111"}],
+                "assignments": {"primary": "Table TV"}}"##,
+        )
+        .unwrap();
+
+        let settings = service.load().unwrap();
+        assert_eq!(settings.display.lan_port, 8080);
+        assert_eq!(
+            settings
+                .display
+                .assigned_profile("primary")
+                .unwrap()
+                .rotation,
+            90
+        );
+
+        service.update(settings.clone()).unwrap();
+        assert!(!legacy.exists());
+        assert_eq!(service.load().unwrap().display, settings.display);
+    }
+
+    #[test]
+    fn test_legacy_sync_config_is_read() {
+        let dir = TempDir::new().unwrap();
+        let legacy = dir.path().join(SYNC_DIR).join(LEGACY_SYNC_CONFIG_FILE);
+        fs::create_dir_all(legacy.parent().unwrap()).unwrap();
+        fs::write(
+            &legacy,
+            serde_json::to_string(&sync_config("device-1", "Desktop")).unwrap(),
+        )
+        .unwrap();
+
+        let settings = SettingsService::new(dir.path()).load().unwrap();
+        assert_eq!(settings.sync.unwrap().device_id, "device-1");
+    }
+}
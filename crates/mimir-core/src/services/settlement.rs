@@ -0,0 +1,844 @@
+//! Settlement Generator
+//!
+//! Generates a town record: size and population after the DMG's settlement
+//! sizes, notable NPCs, shops with their proprietors, and a rumor table.
+//! The record is written as a gazetteer document for the campaign and can be
+//! pinned on a region map as a point of interest linked to that document.
+
+use diesel::SqliteConnection;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{Character, Document, MapPoi, NewMapPoi};
+use crate::services::{
+    CharacterService, CreateCharacterInput, CreateDocumentInput, DocumentService, ServiceError,
+    ServiceResult, UpdateCharacterInput,
+};
+
+/// Document type of a generated settlement's gazetteer entry.
+pub const SETTLEMENT_DOC_TYPE: &str = "settlement";
+
+/// How big a settlement is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementSize {
+    /// Up to about 1,000 people
+    Village,
+    /// 1,000 to 6,000 people
+    #[default]
+    Town,
+    /// 6,000 to 25,000 people
+    City,
+}
+
+impl SettlementSize {
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Village => "Village",
+            Self::Town => "Town",
+            Self::City => "City",
+        }
+    }
+
+    /// Population range.
+    fn population(self) -> (i32, i32) {
+        match self {
+            Self::Village => (100, 1_000),
+            Self::Town => (1_000, 6_000),
+            Self::City => (6_000, 25_000),
+        }
+    }
+
+    /// Title of the settlement's leader.
+    fn leader(self) -> &'static str {
+        match self {
+            Self::Village => "Village Elder",
+            Self::Town => "Mayor",
+            Self::City => "Lord Mayor",
+        }
+    }
+
+    /// Notable NPCs besides the leader and the shopkeepers.
+    fn notables(self) -> usize {
+        match self {
+            Self::Village => 2,
+            Self::Town => 4,
+            Self::City => 6,
+        }
+    }
+
+    /// Kinds of shop the settlement can support.
+    fn shop_kinds(self) -> &'static [&'static str] {
+        match self {
+            Self::Village => &VILLAGE_SHOPS,
+            Self::Town => &TOWN_SHOPS,
+            Self::City => &CITY_SHOPS,
+        }
+    }
+
+    /// Number of shops.
+    fn shops(self) -> usize {
+        match self {
+            Self::Village => 2,
+            Self::Town => 4,
+            Self::City => 7,
+        }
+    }
+
+    /// Die rolled on the rumor table.
+    fn rumor_die(self) -> usize {
+        match self {
+            Self::Village => 6,
+            Self::Town => 8,
+            Self::City => 10,
+        }
+    }
+}
+
+/// Who founded and mostly lives in a settlement. Sets its name, the names
+/// and race of its people.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementCulture {
+    #[default]
+    Human,
+    Dwarven,
+    Elven,
+    Halfling,
+    Gnomish,
+    Orcish,
+}
+
+impl SettlementCulture {
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Human => "Human",
+            Self::Dwarven => "Dwarven",
+            Self::Elven => "Elven",
+            Self::Halfling => "Halfling",
+            Self::Gnomish => "Gnomish",
+            Self::Orcish => "Orcish",
+        }
+    }
+
+    /// Catalog race of the settlement's people, from the PHB.
+    fn race(self) -> &'static str {
+        match self {
+            Self::Human => "Human",
+            Self::Dwarven => "Dwarf",
+            Self::Elven => "Elf",
+            Self::Halfling => "Halfling",
+            Self::Gnomish => "Gnome",
+            Self::Orcish => "Half-Orc",
+        }
+    }
+
+    fn given_names(self) -> &'static [&'static str] {
+        match self {
+            Self::Human => &[
+                "Aldric", "Brenna", "Cedric", "Dara", "Edwin", "Freya", "Gareth", "Helena", "Ivo",
+                "Jessa",
+            ],
+            Self::Dwarven => &[
+                "Bruenor", "Dagna", "Eberk", "Gunnloda", "Harbek", "Kathra", "Orsik", "Riswynn",
+                "Thorin", "Vistra",
+            ],
+            Self::Elven => &[
+                "Adran", "Birel", "Erevan", "Keyleth", "Laucian", "Naivara", "Quelenna",
+                "Soveliss", "Thamior", "Valanthe",
+            ],
+            Self::Halfling => &[
+                "Andry",
+                "Bree",
+                "Cade",
+                "Callie",
+                "Eldon",
+                "Kithri",
+                "Merric",
+                "Seraphina",
+                "Roscoe",
+                "Verna",
+            ],
+            Self::Gnomish => &[
+                "Alston",
+                "Bimpnottin",
+                "Boddynock",
+                "Caramip",
+                "Dimble",
+                "Ellywick",
+                "Fonkin",
+                "Nissa",
+                "Orryn",
+                "Zanna",
+            ],
+            Self::Orcish => &[
+                "Baggi", "Dench", "Emen", "Feng", "Gell", "Holg", "Ront", "Shump", "Thokk", "Volen",
+            ],
+        }
+    }
+
+    fn family_names(self) -> &'static [&'static str] {
+        match self {
+            Self::Human => &[
+                "Ashdown",
+                "Blackwood",
+                "Carrow",
+                "Dunmore",
+                "Fairfax",
+                "Hale",
+                "Marsh",
+                "Thorne",
+            ],
+            Self::Dwarven => &[
+                "Battlehammer",
+                "Fireforge",
+                "Gorunn",
+                "Holderhek",
+                "Ironfist",
+                "Loderr",
+                "Rumnaheim",
+                "Torunn",
+            ],
+            Self::Elven => &[
+                "Amakiir",
+                "Galanodel",
+                "Holimion",
+                "Ilphelkiir",
+                "Liadon",
+                "Meliamne",
+                "Siannodel",
+                "Xiloscient",
+            ],
+            Self::Halfling => &[
+                "Brushgather",
+                "Goodbarrel",
+                "Greenbottle",
+                "Highhill",
+                "Hilltopple",
+                "Leagallow",
+                "Tealeaf",
+                "Underbough",
+            ],
+            Self::Gnomish => &[
+                "Beren", "Daergel", "Folkor", "Garrick", "Nackle", "Murnig", "Ningel", "Scheppen",
+            ],
+            Self::Orcish => &[
+                "Bonecrusher",
+                "Grimtusk",
+                "Ironhide",
+                "Redfang",
+                "Skullsplitter",
+                "Stonejaw",
+                "Thundersong",
+                "Wolfrunner",
+            ],
+        }
+    }
+
+    /// Halves of settlement names, e.g. "Stone" + "ford".
+    fn place_parts(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            Self::Human => (
+                &[
+                    "Ash", "Bright", "Cold", "Elm", "Green", "Mill", "Oak", "Red", "Stone", "West",
+                ],
+                &[
+                    "bridge", "bury", "dale", "field", "ford", "haven", "ton", "wick",
+                ],
+            ),
+            Self::Dwarven => (
+                &[
+                    "Anvil", "Copper", "Deep", "Flint", "Granite", "Iron", "Khaz", "Mithril",
+                ],
+                &["delve", "forge", "hall", "hold", "mount", "spire", "vault"],
+            ),
+            Self::Elven => (
+                &["Ael", "Cael", "Eryn", "Lith", "Myth", "Sil", "Tel", "Yl"],
+                &[
+                    "andor", "anor", "ithil", "lorien", "quessir", "thalas", "varra",
+                ],
+            ),
+            Self::Halfling => (
+                &[
+                    "Bramble", "Butter", "Clover", "Honey", "Little", "Meadow", "Pipe", "Thistle",
+                ],
+                &["bottom", "burrow", "by", "dell", "hollow", "shire", "wold"],
+            ),
+            Self::Gnomish => (
+                &[
+                    "Bell", "Cog", "Gimble", "Nim", "Spark", "Tinker", "Whistle", "Wizzle",
+                ],
+                &[
+                    "burrow", "gear", "glen", "nook", "spring", "warren", "works",
+                ],
+            ),
+            Self::Orcish => (
+                &[
+                    "Blood", "Broken", "Gor", "Gruum", "Skull", "Thrak", "Ur", "War",
+                ],
+                &["gash", "gore", "hold", "kar", "maw", "rock", "tusk"],
+            ),
+        }
+    }
+}
+
+const VILLAGE_SHOPS: [&str; 4] = ["General Store", "Blacksmith", "Stables", "Tavern"];
+
+const TOWN_SHOPS: [&str; 8] = [
+    "General Store",
+    "Blacksmith",
+    "Stables",
+    "Tavern",
+    "Alchemist",
+    "Tailor",
+    "Fletcher",
+    "Bookseller",
+];
+
+const CITY_SHOPS: [&str; 12] = [
+    "General Store",
+    "Blacksmith",
+    "Stables",
+    "Tavern",
+    "Alchemist",
+    "Tailor",
+    "Fletcher",
+    "Bookseller",
+    "Armorer",
+    "Jeweler",
+    "Magic Shop",
+    "Shipwright",
+];
+
+const SHOP_ADJECTIVES: [&str; 12] = [
+    "Gilded",
+    "Rusty",
+    "Silver",
+    "Crooked",
+    "Jolly",
+    "Hidden",
+    "Golden",
+    "Wandering",
+    "Copper",
+    "Laughing",
+    "Broken",
+    "Lucky",
+];
+
+const SHOP_NOUNS: [&str; 12] = [
+    "Anvil",
+    "Lantern",
+    "Stag",
+    "Barrel",
+    "Griffon",
+    "Kettle",
+    "Needle",
+    "Quill",
+    "Boar",
+    "Horseshoe",
+    "Raven",
+    "Flagon",
+];
+
+const NOTABLE_ROLES: [&str; 10] = [
+    "Innkeeper",
+    "Priest",
+    "Captain of the Guard",
+    "Healer",
+    "Guildmaster",
+    "Sage",
+    "Crime Boss",
+    "Retired Adventurer",
+    "Merchant",
+    "Hermit",
+];
+
+/// Rumor templates. `{npc}` and `{shop}` are filled from the settlement.
+const RUMORS: [&str; 16] = [
+    "{npc} has been seen slipping out of town after dark.",
+    "The cellar beneath {shop} opens onto old tunnels.",
+    "Bandits are stopping wagons on the north road.",
+    "{npc} owes a great deal of money to someone dangerous.",
+    "Strange lights were seen over the old barrow last night.",
+    "Wolves have been taking sheep from the outlying farms.",
+    "{npc} is not who they claim to be.",
+    "A stranger paid for supplies at {shop} with ancient coins.",
+    "The well water has tasted of copper since the last storm.",
+    "Someone is quietly buying up all the silver in town.",
+    "{npc} is looking for capable hands and pays well.",
+    "The temple bell rang by itself at midnight.",
+    "A child went missing near the river, and no one is searching.",
+    "{shop} sells more than it admits to, if you know the word.",
+    "An old map to a buried vault was sold at market for a song.",
+    "{npc} has been meeting with agents of a rival lord.",
+];
+
+/// Optional pin for a settlement on a region map.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SettlementPin {
+    /// Region map to pin the settlement on
+    pub map_id: String,
+    /// Grid column
+    pub grid_x: i32,
+    /// Grid row
+    pub grid_y: i32,
+}
+
+/// Input for generating a settlement.
+#[derive(Debug, Clone)]
+pub struct GenerateSettlementInput {
+    /// Campaign the settlement belongs to
+    pub campaign_id: String,
+    /// Settlement size
+    pub size: SettlementSize,
+    /// Settlement culture
+    pub culture: SettlementCulture,
+    /// Name to use instead of a generated one
+    pub name: Option<String>,
+    /// Where to pin the settlement on a region map
+    pub pin: Option<SettlementPin>,
+    /// Seed for a repeatable settlement; random when not set
+    pub seed: Option<u64>,
+}
+
+impl GenerateSettlementInput {
+    /// Generate a settlement of a size and culture in a campaign.
+    pub fn new(
+        campaign_id: impl Into<String>,
+        size: SettlementSize,
+        culture: SettlementCulture,
+    ) -> Self {
+        Self {
+            campaign_id: campaign_id.into(),
+            size,
+            culture,
+            name: None,
+            pin: None,
+            seed: None,
+        }
+    }
+
+    /// Use a given name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Pin the settlement on a region map.
+    pub fn with_pin(mut self, map_id: impl Into<String>, grid_x: i32, grid_y: i32) -> Self {
+        self.pin = Some(SettlementPin {
+            map_id: map_id.into(),
+            grid_x,
+            grid_y,
+        });
+        self
+    }
+
+    /// Set the seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+/// A shop in a settlement.
+#[derive(Debug, Clone, Serialize)]
+pub struct SettlementShop {
+    /// Shop name, e.g. "The Gilded Anvil"
+    pub name: String,
+    /// What the shop is, e.g. "Blacksmith"
+    pub kind: String,
+    /// NPC character who runs the shop
+    pub proprietor_id: String,
+    /// Proprietor's name
+    pub proprietor: String,
+}
+
+/// One entry of a settlement's rumor table.
+#[derive(Debug, Clone, Serialize)]
+pub struct SettlementRumor {
+    /// Die result for this rumor
+    pub roll: i32,
+    /// What people are saying
+    pub text: String,
+    /// Whether the rumor is true
+    pub is_true: bool,
+}
+
+/// A generated settlement.
+#[derive(Debug, Clone, Serialize)]
+pub struct Settlement {
+    pub name: String,
+    pub size: SettlementSize,
+    pub culture: SettlementCulture,
+    pub population: i32,
+    /// Gazetteer entry describing the settlement
+    pub document: Document,
+    /// Notable NPCs, leader first, then the shopkeepers
+    pub npcs: Vec<Character>,
+    pub shops: Vec<SettlementShop>,
+    pub rumors: Vec<SettlementRumor>,
+    /// Pin on the region map, linked to the gazetteer entry
+    pub pin: Option<MapPoi>,
+    /// Seed that reproduces this settlement
+    pub seed: u64,
+}
+
+/// Service for generating settlements.
+pub struct SettlementService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> SettlementService<'a> {
+    /// Create a new settlement service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Generate a settlement in a campaign.
+    ///
+    /// Creates an NPC character for the leader, each notable, and each
+    /// shopkeeper, all located in the settlement. Writes the gazetteer
+    /// document and, if asked, pins the settlement on a region map.
+    pub fn generate(&mut self, input: GenerateSettlementInput) -> ServiceResult<Settlement> {
+        let campaign = dal::get_campaign_optional(self.conn, &input.campaign_id)?
+            .ok_or_else(|| ServiceError::not_found("Campaign", &input.campaign_id))?;
+        if let Some(pin) = &input.pin {
+            let map = dal::get_map_optional(self.conn, &pin.map_id)?
+                .ok_or_else(|| ServiceError::not_found("Map", &pin.map_id))?;
+            if map.campaign_id != campaign.id {
+                return Err(ServiceError::validation(format!(
+                    "{} belongs to another campaign",
+                    map.name
+                )));
+            }
+        }
+
+        let seed = input
+            .seed
+            .unwrap_or_else(|| rand::thread_rng().gen::<u32>() as u64);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (size, culture) = (input.size, input.culture);
+
+        let name = match input.name.as_deref().map(str::trim) {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => {
+                let (prefixes, suffixes) = culture.place_parts();
+                let (prefix, suffix) = (choose(prefixes, &mut rng), choose(suffixes, &mut rng));
+                format!("{}{}", prefix, suffix)
+            }
+        };
+        let (low, high) = size.population();
+        let population = rng.gen_range(low..=high);
+
+        // Every NPC gets a distinct name
+        let mut people: Vec<String> = culture
+            .given_names()
+            .iter()
+            .flat_map(|given| {
+                culture
+                    .family_names()
+                    .iter()
+                    .map(move |family| format!("{} {}", given, family))
+            })
+            .collect();
+        people.shuffle(&mut rng);
+        let mut people = people.into_iter();
+
+        let mut roles: Vec<&str> = NOTABLE_ROLES.to_vec();
+        roles.shuffle(&mut rng);
+        roles.truncate(size.notables());
+        roles.insert(0, size.leader());
+
+        let mut kinds: Vec<&str> = size.shop_kinds().to_vec();
+        kinds.shuffle(&mut rng);
+        kinds.truncate(size.shops());
+        let mut shop_names: Vec<String> = SHOP_ADJECTIVES
+            .iter()
+            .flat_map(|adj| {
+                SHOP_NOUNS
+                    .iter()
+                    .map(move |noun| format!("The {} {}", adj, noun))
+            })
+            .collect();
+        shop_names.shuffle(&mut rng);
+
+        let mut npcs = Vec::new();
+        for role in &roles {
+            let npc_name = people.next().unwrap_or_else(|| role.to_string());
+            npcs.push(self.create_npc(&campaign.id, culture, &npc_name, role, &name)?);
+        }
+        let mut shops = Vec::new();
+        for (kind, shop_name) in kinds.iter().zip(shop_names) {
+            let npc_name = people.next().unwrap_or_else(|| format!("{} Owner", kind));
+            let role = format!("Proprietor, {}", shop_name);
+            let npc = self.create_npc(&campaign.id, culture, &npc_name, &role, &name)?;
+            shops.push(SettlementShop {
+                name: shop_name,
+                kind: kind.to_string(),
+                proprietor_id: npc.id.clone(),
+                proprietor: npc.name.clone(),
+            });
+            npcs.push(npc);
+        }
+
+        let mut templates: Vec<&str> = RUMORS.to_vec();
+        templates.shuffle(&mut rng);
+        let rumors: Vec<SettlementRumor> = templates
+            .into_iter()
+            .take(size.rumor_die())
+            .enumerate()
+            .map(|(i, template)| {
+                let npc = choose(&npcs, &mut rng);
+                let shop = choose(&shops, &mut rng);
+                SettlementRumor {
+                    roll: i as i32 + 1,
+                    text: template
+                        .replace("{npc}", &npc.name)
+                        .replace("{shop}", &shop.name),
+                    is_true: rng.gen_bool(0.5),
+                }
+            })
+            .collect();
+
+        let summary = format!(
+            "{} {}, population {}.",
+            culture.label(),
+            size.label().to_lowercase(),
+            population
+        );
+        let content = gazetteer_entry(&summary, &roles, &npcs, &shops, &rumors);
+        let document = DocumentService::new(self.conn).create(
+            CreateDocumentInput::for_campaign(&campaign.id, &name)
+                .with_type(SETTLEMENT_DOC_TYPE)
+                .with_content(content),
+        )?;
+
+        let pin = match &input.pin {
+            Some(pin) => {
+                let id = Uuid::new_v4().to_string();
+                let poi = NewMapPoi::new(&id, &pin.map_id, &name, pin.grid_x, pin.grid_y)
+                    .with_description(&summary)
+                    .with_icon("star")
+                    .with_document_link(&document.id, None);
+                dal::insert_map_poi(self.conn, &poi)?;
+                Some(dal::get_map_poi(self.conn, &id)?)
+            }
+            None => None,
+        };
+
+        Ok(Settlement {
+            name,
+            size,
+            culture,
+            population,
+            document,
+            npcs,
+            shops,
+            rumors,
+            pin,
+            seed,
+        })
+    }
+
+    /// Create an NPC of the settlement's culture with a role there.
+    fn create_npc(
+        &mut self,
+        campaign_id: &str,
+        culture: SettlementCulture,
+        name: &str,
+        role: &str,
+        settlement: &str,
+    ) -> ServiceResult<Character> {
+        let mut characters = CharacterService::new(self.conn);
+        let input =
+            CreateCharacterInput::new_npc(Some(campaign_id), name).with_race(culture.race(), "PHB");
+        let npc = characters.create(input)?;
+        characters.update(
+            &npc.id,
+            UpdateCharacterInput::set_npc_info(
+                Some(role.to_string()),
+                Some(settlement.to_string()),
+                None,
+            ),
+        )
+    }
+}
+
+/// Pick one item; the lists here are never empty.
+fn choose<'t, T>(items: &'t [T], rng: &mut impl Rng) -> &'t T {
+    &items[rng.gen_range(0..items.len())]
+}
+
+/// Markdown for the settlement's gazetteer document.
+fn gazetteer_entry(
+    summary: &str,
+    roles: &[&str],
+    npcs: &[Character],
+    shops: &[SettlementShop],
+    rumors: &[SettlementRumor],
+) -> String {
+    let mut content = format!("*{}*\n\n## Notable NPCs\n\n", summary);
+    for (npc, role) in npcs.iter().zip(roles) {
+        let race = npc.race_name.as_deref().unwrap_or("Unknown");
+        content.push_str(&format!("- **{}** — {} ({})\n", npc.name, role, race));
+    }
+
+    content.push_str("\n## Shops\n\n| Shop | Kind | Proprietor |\n|------|------|------------|\n");
+    for shop in shops {
+        content.push_str(&format!(
+            "| {} | {} | {} |\n",
+            shop.name, shop.kind, shop.proprietor
+        ));
+    }
+
+    content.push_str(&format!(
+        "\n## Rumors\n\n| d{} | Rumor | True? |\n|----|-------|-------|\n",
+        rumors.len()
+    ));
+    for rumor in rumors {
+        let truth = if rumor.is_true { "Yes" } else { "No" };
+        content.push_str(&format!(
+            "| {} | {} | {} |\n",
+            rumor.roll, rumor.text, truth
+        ));
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_campaign_asset, insert_map};
+    use crate::models::campaign::{NewCampaign, NewCampaignAsset, NewMap};
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        insert_campaign(conn, &NewCampaign::new("camp-2", "Other Campaign")).unwrap();
+        for (asset_id, map_id, campaign_id) in [
+            ("asset-1", "map-1", "camp-1"),
+            ("asset-2", "map-2", "camp-2"),
+        ] {
+            let asset = NewCampaignAsset::for_campaign(
+                asset_id,
+                campaign_id,
+                "region.uvtt",
+                "application/octet-stream",
+                "/blobs/region.uvtt",
+            );
+            insert_campaign_asset(conn, &asset).unwrap();
+            insert_map(
+                conn,
+                &NewMap::for_campaign(map_id, campaign_id, "Region", asset_id),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_generate_town() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let input = GenerateSettlementInput::new(
+            "camp-1",
+            SettlementSize::Town,
+            SettlementCulture::Dwarven,
+        )
+        .with_seed(7);
+        let town = SettlementService::new(&mut conn).generate(input).unwrap();
+
+        assert!((1_000..=6_000).contains(&town.population));
+        assert_eq!(town.shops.len(), 4);
+        assert_eq!(town.npcs.len(), 1 + 4 + 4);
+        assert_eq!(town.rumors.len(), 8);
+        assert!(town.pin.is_none());
+
+        let mayor = &town.npcs[0];
+        assert!(mayor.is_npc());
+        assert_eq!(mayor.role.as_deref(), Some("Mayor"));
+        assert_eq!(mayor.location.as_deref(), Some(town.name.as_str()));
+        assert_eq!(mayor.race_name.as_deref(), Some("Dwarf"));
+        for shop in &town.shops {
+            assert!(town.npcs.iter().any(|npc| npc.id == shop.proprietor_id));
+        }
+
+        assert_eq!(town.document.title, town.name);
+        assert_eq!(town.document.doc_type, SETTLEMENT_DOC_TYPE);
+        let content = &town.document.content;
+        assert!(content.contains("## Notable NPCs"));
+        assert!(content.contains(&format!("**{}** — Mayor (Dwarf)", mayor.name)));
+        assert!(content.contains("| d8 | Rumor | True? |"));
+        assert!(!content.contains("{npc}") && !content.contains("{shop}"));
+    }
+
+    #[test]
+    fn test_generate_with_pin_and_name() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let input = GenerateSettlementInput::new(
+            "camp-1",
+            SettlementSize::Village,
+            SettlementCulture::Halfling,
+        )
+        .with_name("Bywater")
+        .with_pin("map-1", 12, 4);
+        let village = SettlementService::new(&mut conn).generate(input).unwrap();
+
+        assert_eq!(village.name, "Bywater");
+        assert_eq!(village.rumors.len(), 6);
+        let pin = village.pin.unwrap();
+        assert_eq!((pin.grid_x, pin.grid_y), (12, 4));
+        assert_eq!(pin.name, "Bywater");
+        assert_eq!(
+            pin.document_link(),
+            Some((village.document.id.as_str(), None))
+        );
+    }
+
+    #[test]
+    fn test_same_seed_same_settlement() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+
+        let input =
+            GenerateSettlementInput::new("camp-1", SettlementSize::City, SettlementCulture::Elven)
+                .with_seed(99);
+        let mut service = SettlementService::new(&mut conn);
+        let first = service.generate(input.clone()).unwrap();
+        let second = service.generate(input).unwrap();
+        assert_eq!(first.name, second.name);
+        assert_eq!(first.population, second.population);
+        assert_eq!(first.document.content, second.document.content);
+    }
+
+    #[test]
+    fn test_generate_rejects_bad_targets() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = SettlementService::new(&mut conn);
+
+        let missing =
+            GenerateSettlementInput::new("missing", SettlementSize::Town, SettlementCulture::Human);
+        assert!(matches!(
+            service.generate(missing),
+            Err(ServiceError::NotFound { .. })
+        ));
+
+        let foreign_map =
+            GenerateSettlementInput::new("camp-1", SettlementSize::Town, SettlementCulture::Human)
+                .with_pin("map-2", 0, 0);
+        assert!(matches!(
+            service.generate(foreign_map),
+            Err(ServiceError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,687 @@
+//! Stash Service
+//!
+//! Business logic for a campaign's shared stash: coins and items the party
+//! holds in common. Items are validated against the catalog (or campaign
+//! homebrew) and stack by name and source. Every movement is written to the
+//! stash ledger, and the loot splitter shares coins evenly and hands items
+//! out into characters' inventories.
+
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    CampaignStashItem, Character, CharacterInventory, LedgerAction, NewCampaignStashCurrency,
+    NewCampaignStashItem, NewCharacterInventory, NewStashLedgerEntry, StashLedgerEntry,
+    UpdateCampaignStashItem,
+};
+use crate::services::currency::set_purse;
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Item source used for campaign homebrew items.
+const HOMEBREW_SOURCE: &str = "HB";
+
+/// Ledger note for hand-outs made by the loot splitter.
+const SPLIT_NOTE: &str = "Loot split";
+
+/// A campaign's stash: coins and item stacks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignStash {
+    /// Campaign ID
+    pub campaign_id: String,
+    /// Coins as [CP, SP, EP, GP, PP]
+    pub currency: [i32; 5],
+    /// Item stacks, alphabetically
+    pub items: Vec<CampaignStashItem>,
+}
+
+/// Input for adding items to the stash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StashItemInput {
+    /// Item name from the catalog (or campaign homebrew)
+    pub item_name: String,
+    /// Item source (e.g., "PHB", or "HB" for campaign homebrew)
+    pub item_source: String,
+    /// Quantity (default 1)
+    pub quantity: Option<i32>,
+    /// Ledger note (e.g., "Dragon hoard")
+    pub note: Option<String>,
+}
+
+/// Hand a stash item to a character during a loot split.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootAssignment {
+    /// Stash item stack
+    pub stash_item_id: String,
+    /// Character receiving it
+    pub character_id: String,
+    /// Quantity (default: the whole stack)
+    pub quantity: Option<i32>,
+}
+
+/// Input for splitting the stash between characters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SplitLootInput {
+    /// Characters sharing the coins
+    pub character_ids: Vec<String>,
+    /// Share every coin in the stash evenly, by denomination
+    #[serde(default = "default_true")]
+    pub split_currency: bool,
+    /// Items to hand out
+    #[serde(default)]
+    pub assignments: Vec<LootAssignment>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// What one character received from a loot split.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootShare {
+    /// Character ID
+    pub character_id: String,
+    /// Character name
+    pub name: String,
+    /// Coins received as [CP, SP, EP, GP, PP]
+    pub currency: [i32; 5],
+    /// Inventory entries created for items received
+    pub items: Vec<CharacterInventory>,
+}
+
+/// Result of a loot split.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SplitLootResult {
+    /// Each character's share
+    pub shares: Vec<LootShare>,
+    /// Coins left in the stash that didn't divide evenly
+    pub remainder: [i32; 5],
+}
+
+/// Service for a campaign's shared stash.
+pub struct StashService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> StashService<'a> {
+    /// Create a new stash service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Get a campaign's stash.
+    pub fn get(&mut self, campaign_id: &str) -> ServiceResult<CampaignStash> {
+        require_campaign(self.conn, campaign_id)?;
+        Ok(CampaignStash {
+            campaign_id: campaign_id.to_string(),
+            currency: balance(self.conn, campaign_id)?,
+            items: dal::list_campaign_stash_items(self.conn, campaign_id)?,
+        })
+    }
+
+    /// The stash ledger, newest first.
+    pub fn ledger(&mut self, campaign_id: &str) -> ServiceResult<Vec<StashLedgerEntry>> {
+        require_campaign(self.conn, campaign_id)?;
+        dal::list_stash_ledger(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Add items to the stash, stacking onto an existing stack.
+    pub fn add_item(
+        &mut self,
+        campaign_id: &str,
+        input: StashItemInput,
+    ) -> ServiceResult<CampaignStashItem> {
+        require_campaign(self.conn, campaign_id)?;
+        let quantity = positive_quantity(input.quantity.unwrap_or(1))?;
+        let item_name = resolve_item(self.conn, campaign_id, &input.item_name, &input.item_source)?;
+
+        self.conn.transaction(|conn| {
+            let now = now_rfc3339();
+            let id = match dal::find_campaign_stash_item(
+                conn,
+                campaign_id,
+                &item_name,
+                &input.item_source,
+            )? {
+                Some(stack) => {
+                    let update = UpdateCampaignStashItem {
+                        quantity: Some(stack.quantity + quantity),
+                        updated_at: Some(&now),
+                        ..Default::default()
+                    };
+                    dal::update_campaign_stash_item(conn, &stack.id, &update)?;
+                    stack.id
+                }
+                None => {
+                    let id = Uuid::new_v4().to_string();
+                    let stack = NewCampaignStashItem::new(
+                        &id,
+                        campaign_id,
+                        &item_name,
+                        &input.item_source,
+                        quantity,
+                    );
+                    dal::insert_campaign_stash_item(conn, &stack)?;
+                    id
+                }
+            };
+
+            let entry_id = Uuid::new_v4().to_string();
+            let mut entry = NewStashLedgerEntry::new(&entry_id, campaign_id, LedgerAction::Deposit)
+                .with_item(&item_name, &input.item_source, quantity);
+            if let Some(ref note) = input.note {
+                entry = entry.with_note(note);
+            }
+            dal::insert_stash_ledger_entry(conn, &entry)?;
+
+            dal::get_campaign_stash_item(conn, &id).map_err(ServiceError::from)
+        })
+    }
+
+    /// Remove items from play (spent, sold, lost). Without a quantity the
+    /// whole stack goes.
+    pub fn remove_item(
+        &mut self,
+        stash_item_id: &str,
+        quantity: Option<i32>,
+        note: Option<&str>,
+    ) -> ServiceResult<()> {
+        self.conn.transaction(|conn| {
+            let (stack, quantity) = take_from_stack(conn, stash_item_id, quantity)?;
+            let entry_id = Uuid::new_v4().to_string();
+            let mut entry =
+                NewStashLedgerEntry::new(&entry_id, &stack.campaign_id, LedgerAction::Withdraw)
+                    .with_item(&stack.item_name, &stack.item_source, quantity);
+            if let Some(note) = note {
+                entry = entry.with_note(note);
+            }
+            dal::insert_stash_ledger_entry(conn, &entry)?;
+            Ok(())
+        })
+    }
+
+    /// Move items from the stash into a character's inventory.
+    pub fn take_item(
+        &mut self,
+        stash_item_id: &str,
+        character_id: &str,
+        quantity: Option<i32>,
+    ) -> ServiceResult<CharacterInventory> {
+        self.conn
+            .transaction(|conn| give_item(conn, stash_item_id, character_id, quantity, None))
+    }
+
+    /// Add coins (as [CP, SP, EP, GP, PP]) to the stash, returning the new
+    /// balance.
+    pub fn deposit_currency(
+        &mut self,
+        campaign_id: &str,
+        coins: [i32; 5],
+        note: Option<&str>,
+    ) -> ServiceResult<[i32; 5]> {
+        require_campaign(self.conn, campaign_id)?;
+        validate_coins(coins)?;
+        self.conn.transaction(|conn| {
+            let current = balance(conn, campaign_id)?;
+            let updated = std::array::from_fn(|i| current[i] + coins[i]);
+            set_balance(conn, campaign_id, updated)?;
+
+            let entry_id = Uuid::new_v4().to_string();
+            let mut entry = NewStashLedgerEntry::new(&entry_id, campaign_id, LedgerAction::Deposit)
+                .with_currency(coins);
+            if let Some(note) = note {
+                entry = entry.with_note(note);
+            }
+            dal::insert_stash_ledger_entry(conn, &entry)?;
+            Ok(updated)
+        })
+    }
+
+    /// Remove coins from play (spent on the party's behalf), returning the
+    /// new balance.
+    pub fn withdraw_currency(
+        &mut self,
+        campaign_id: &str,
+        coins: [i32; 5],
+        note: Option<&str>,
+    ) -> ServiceResult<[i32; 5]> {
+        require_campaign(self.conn, campaign_id)?;
+        validate_coins(coins)?;
+        self.conn.transaction(|conn| {
+            let updated = debit(conn, campaign_id, coins)?;
+            let entry_id = Uuid::new_v4().to_string();
+            let mut entry =
+                NewStashLedgerEntry::new(&entry_id, campaign_id, LedgerAction::Withdraw)
+                    .with_currency(coins);
+            if let Some(note) = note {
+                entry = entry.with_note(note);
+            }
+            dal::insert_stash_ledger_entry(conn, &entry)?;
+            Ok(updated)
+        })
+    }
+
+    /// Move coins from the stash into a character's purse, returning the
+    /// stash's new balance.
+    pub fn take_currency(
+        &mut self,
+        campaign_id: &str,
+        character_id: &str,
+        coins: [i32; 5],
+    ) -> ServiceResult<[i32; 5]> {
+        require_campaign(self.conn, campaign_id)?;
+        validate_coins(coins)?;
+        self.conn
+            .transaction(|conn| give_currency(conn, campaign_id, character_id, coins, None))
+    }
+
+    /// Split the stash: share coins evenly between characters by
+    /// denomination and hand out items into their inventories.
+    ///
+    /// Coins that don't divide evenly stay in the stash.
+    pub fn split_loot(
+        &mut self,
+        campaign_id: &str,
+        input: SplitLootInput,
+    ) -> ServiceResult<SplitLootResult> {
+        require_campaign(self.conn, campaign_id)?;
+        let mut recipients: Vec<&str> = Vec::new();
+        for id in &input.character_ids {
+            if recipients.contains(&id.as_str()) {
+                return Err(ServiceError::validation(format!(
+                    "Character {} is listed more than once",
+                    id
+                )));
+            }
+            recipients.push(id);
+        }
+        if input.split_currency && recipients.is_empty() {
+            return Err(ServiceError::validation(
+                "Choose at least one character to share the coins",
+            ));
+        }
+
+        self.conn.transaction(|conn| {
+            let mut shares: Vec<LootShare> = Vec::new();
+
+            if input.split_currency {
+                let total = balance(conn, campaign_id)?;
+                let count = recipients.len() as i32;
+                let each: [i32; 5] = std::array::from_fn(|i| total[i] / count);
+                for character_id in &recipients {
+                    let character = campaign_character(conn, campaign_id, character_id)?;
+                    if each.iter().any(|&c| c > 0) {
+                        give_currency(conn, campaign_id, character_id, each, Some(SPLIT_NOTE))?;
+                    }
+                    shares.push(LootShare {
+                        character_id: character.id,
+                        name: character.name,
+                        currency: each,
+                        items: Vec::new(),
+                    });
+                }
+            }
+
+            for assignment in &input.assignments {
+                let item = give_item(
+                    conn,
+                    &assignment.stash_item_id,
+                    &assignment.character_id,
+                    assignment.quantity,
+                    Some(SPLIT_NOTE),
+                )?;
+                match shares
+                    .iter_mut()
+                    .find(|s| s.character_id == assignment.character_id)
+                {
+                    Some(share) => share.items.push(item),
+                    None => {
+                        let character =
+                            campaign_character(conn, campaign_id, &assignment.character_id)?;
+                        shares.push(LootShare {
+                            character_id: character.id,
+                            name: character.name,
+                            currency: [0; 5],
+                            items: vec![item],
+                        });
+                    }
+                }
+            }
+
+            Ok(SplitLootResult {
+                shares,
+                remainder: balance(conn, campaign_id)?,
+            })
+        })
+    }
+}
+
+/// Move items from a stash stack into a character's inventory and record it.
+fn give_item(
+    conn: &mut SqliteConnection,
+    stash_item_id: &str,
+    character_id: &str,
+    quantity: Option<i32>,
+    note: Option<&str>,
+) -> ServiceResult<CharacterInventory> {
+    let stack = dal::get_campaign_stash_item_optional(conn, stash_item_id)?
+        .ok_or_else(|| ServiceError::not_found("CampaignStashItem", stash_item_id))?;
+    let character = campaign_character(conn, &stack.campaign_id, character_id)?;
+    let (stack, quantity) = take_from_stack(conn, stash_item_id, quantity)?;
+
+    let inventory_id = Uuid::new_v4().to_string();
+    let item = NewCharacterInventory::new(
+        &inventory_id,
+        character_id,
+        &stack.item_name,
+        &stack.item_source,
+    )
+    .with_quantity(quantity);
+    dal::insert_character_inventory(conn, &item)?;
+
+    let entry_id = Uuid::new_v4().to_string();
+    let mut entry = NewStashLedgerEntry::new(&entry_id, &stack.campaign_id, LedgerAction::Take)
+        .with_character(character_id, &character.name)
+        .with_item(&stack.item_name, &stack.item_source, quantity);
+    if let Some(note) = note {
+        entry = entry.with_note(note);
+    }
+    dal::insert_stash_ledger_entry(conn, &entry)?;
+
+    dal::get_character_inventory(conn, &inventory_id).map_err(ServiceError::from)
+}
+
+/// Move coins from the stash into a character's purse and record it.
+fn give_currency(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    character_id: &str,
+    coins: [i32; 5],
+    note: Option<&str>,
+) -> ServiceResult<[i32; 5]> {
+    let character = campaign_character(conn, campaign_id, character_id)?;
+    let updated = debit(conn, campaign_id, coins)?;
+
+    let purse = [
+        character.cp + coins[0],
+        character.sp + coins[1],
+        character.ep + coins[2],
+        character.gp + coins[3],
+        character.pp + coins[4],
+    ];
+    set_purse(
+        conn,
+        &character,
+        purse,
+        note.unwrap_or("Taken from the shared stash"),
+    )?;
+
+    let entry_id = Uuid::new_v4().to_string();
+    let mut entry = NewStashLedgerEntry::new(&entry_id, campaign_id, LedgerAction::Take)
+        .with_character(character_id, &character.name)
+        .with_currency(coins);
+    if let Some(note) = note {
+        entry = entry.with_note(note);
+    }
+    dal::insert_stash_ledger_entry(conn, &entry)?;
+    Ok(updated)
+}
+
+/// Take items off a stack, deleting it when it empties. Returns the stack as
+/// it was and the quantity taken.
+fn take_from_stack(
+    conn: &mut SqliteConnection,
+    stash_item_id: &str,
+    quantity: Option<i32>,
+) -> ServiceResult<(CampaignStashItem, i32)> {
+    let stack = dal::get_campaign_stash_item_optional(conn, stash_item_id)?
+        .ok_or_else(|| ServiceError::not_found("CampaignStashItem", stash_item_id))?;
+    let quantity = positive_quantity(quantity.unwrap_or(stack.quantity))?;
+    if quantity > stack.quantity {
+        return Err(ServiceError::validation(format!(
+            "The stash only holds {} {}",
+            stack.quantity, stack.item_name
+        )));
+    }
+
+    if quantity == stack.quantity {
+        dal::delete_campaign_stash_item(conn, &stack.id)?;
+    } else {
+        let now = now_rfc3339();
+        let update = UpdateCampaignStashItem {
+            quantity: Some(stack.quantity - quantity),
+            updated_at: Some(&now),
+            ..Default::default()
+        };
+        dal::update_campaign_stash_item(conn, &stack.id, &update)?;
+    }
+    Ok((stack, quantity))
+}
+
+/// Subtract coins from the stash, refusing to go below zero in any
+/// denomination. Returns the new balance.
+fn debit(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    coins: [i32; 5],
+) -> ServiceResult<[i32; 5]> {
+    const DENOMINATIONS: [&str; 5] = ["cp", "sp", "ep", "gp", "pp"];
+    let current = balance(conn, campaign_id)?;
+    for ((wanted, held), denomination) in coins.iter().zip(current).zip(DENOMINATIONS) {
+        if *wanted > held {
+            return Err(ServiceError::validation(format!(
+                "The stash only holds {} {}",
+                held, denomination
+            )));
+        }
+    }
+    let updated = std::array::from_fn(|i| current[i] - coins[i]);
+    set_balance(conn, campaign_id, updated)?;
+    Ok(updated)
+}
+
+/// The stash's coins as [CP, SP, EP, GP, PP].
+fn balance(conn: &mut SqliteConnection, campaign_id: &str) -> ServiceResult<[i32; 5]> {
+    Ok(dal::get_campaign_stash_currency(conn, campaign_id)?
+        .map(|c| c.as_array())
+        .unwrap_or_default())
+}
+
+fn set_balance(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    coins: [i32; 5],
+) -> ServiceResult<()> {
+    let now = now_rfc3339();
+    let currency = NewCampaignStashCurrency::new(campaign_id, coins, &now);
+    dal::upsert_campaign_stash_currency(conn, &currency)?;
+    Ok(())
+}
+
+/// Check an item exists in the catalog (or campaign homebrew for "HB"),
+/// returning its canonical name.
+pub(crate) fn resolve_item(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    name: &str,
+    source: &str,
+) -> ServiceResult<String> {
+    let found = if source == HOMEBREW_SOURCE {
+        dal::get_campaign_homebrew_item_by_name(conn, campaign_id, name)?.map(|i| i.name)
+    } else {
+        catalog_dal::get_item_by_name(conn, name, source)?.map(|i| i.name)
+    };
+    found.ok_or_else(|| ServiceError::not_found("Item", format!("{} ({})", name, source)))
+}
+
+fn require_campaign(conn: &mut SqliteConnection, campaign_id: &str) -> ServiceResult<()> {
+    dal::get_campaign_optional(conn, campaign_id)?
+        .map(|_| ())
+        .ok_or_else(|| ServiceError::not_found("Campaign", campaign_id))
+}
+
+/// Get a character, checking it belongs to the campaign.
+fn campaign_character(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    character_id: &str,
+) -> ServiceResult<Character> {
+    let character = dal::get_character_optional(conn, character_id)?
+        .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+    if character.campaign_id.as_deref() != Some(campaign_id) {
+        return Err(ServiceError::validation(format!(
+            "{} is not in this campaign",
+            character.name
+        )));
+    }
+    Ok(character)
+}
+
+fn positive_quantity(quantity: i32) -> ServiceResult<i32> {
+    if quantity < 1 {
+        return Err(ServiceError::validation(format!(
+            "Quantity must be at least 1, got {}",
+            quantity
+        )));
+    }
+    Ok(quantity)
+}
+
+fn validate_coins(coins: [i32; 5]) -> ServiceResult<()> {
+    if coins.iter().any(|&c| c < 0) {
+        return Err(ServiceError::validation("Coin amounts cannot be negative"));
+    }
+    if coins.iter().all(|&c| c == 0) {
+        return Err(ServiceError::validation("No coins given"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character};
+    use crate::dal::catalog::insert_item;
+    use crate::models::campaign::NewCampaign;
+    use crate::models::campaign::NewCharacter;
+    use crate::models::catalog::NewItem;
+    use crate::test_utils::setup_test_db_with_sources;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        for (id, name) in [("char-1", "Ada"), ("char-2", "Brom"), ("char-3", "Cora")] {
+            insert_character(
+                conn,
+                &NewCharacter::new_pc(id, Some("camp-1"), name, "Alice"),
+            )
+            .unwrap();
+        }
+        let bag = r#"{"name": "Bag of Holding", "source": "DMG"}"#;
+        insert_item(conn, &NewItem::new("Bag of Holding", "DMG", bag)).unwrap();
+    }
+
+    fn bag(quantity: i32) -> StashItemInput {
+        StashItemInput {
+            item_name: "bag of holding".to_string(),
+            item_source: "DMG".to_string(),
+            quantity: Some(quantity),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_add_item_validates_and_stacks() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = StashService::new(&mut conn);
+
+        service.add_item("camp-1", bag(1)).unwrap();
+        let stack = service.add_item("camp-1", bag(2)).unwrap();
+        assert_eq!(stack.item_name, "Bag of Holding");
+        assert_eq!(stack.quantity, 3);
+        assert_eq!(service.get("camp-1").unwrap().items.len(), 1);
+
+        let mut unknown = bag(1);
+        unknown.item_name = "Bag of Infinite Gold".to_string();
+        assert!(service.add_item("camp-1", unknown).is_err());
+    }
+
+    #[test]
+    fn test_take_item_records_who_took_it() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = StashService::new(&mut conn);
+        let stack = service.add_item("camp-1", bag(1)).unwrap();
+
+        let item = service.take_item(&stack.id, "char-2", None).unwrap();
+        assert_eq!(item.character_id, "char-2");
+        assert!(service.get("camp-1").unwrap().items.is_empty());
+
+        let ledger = service.ledger("camp-1").unwrap();
+        let take = ledger
+            .iter()
+            .find(|e| e.action_enum() == Some(LedgerAction::Take))
+            .unwrap();
+        assert_eq!(take.character_name.as_deref(), Some("Brom"));
+        assert_eq!(take.item_name.as_deref(), Some("Bag of Holding"));
+    }
+
+    #[test]
+    fn test_currency_cannot_go_negative() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = StashService::new(&mut conn);
+
+        service
+            .deposit_currency("camp-1", [0, 0, 0, 10, 0], None)
+            .unwrap();
+        assert!(service
+            .withdraw_currency("camp-1", [0, 0, 0, 11, 0], None)
+            .is_err());
+        let balance = service
+            .withdraw_currency("camp-1", [0, 0, 0, 4, 0], Some("Inn"))
+            .unwrap();
+        assert_eq!(balance, [0, 0, 0, 6, 0]);
+    }
+
+    #[test]
+    fn test_split_loot_shares_coins_and_items() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        let mut service = StashService::new(&mut conn);
+        service
+            .deposit_currency("camp-1", [5, 0, 0, 100, 0], None)
+            .unwrap();
+        let stack = service.add_item("camp-1", bag(1)).unwrap();
+
+        let result = service
+            .split_loot(
+                "camp-1",
+                SplitLootInput {
+                    character_ids: vec![
+                        "char-1".to_string(),
+                        "char-2".to_string(),
+                        "char-3".to_string(),
+                    ],
+                    split_currency: true,
+                    assignments: vec![LootAssignment {
+                        stash_item_id: stack.id,
+                        character_id: "char-3".to_string(),
+                        quantity: None,
+                    }],
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result.shares.len(), 3);
+        assert_eq!(result.shares[0].currency, [1, 0, 0, 33, 0]);
+        assert_eq!(result.shares[2].items.len(), 1);
+        assert_eq!(result.remainder, [2, 0, 0, 1, 0]);
+
+        let ada = dal::get_character(&mut conn, "char-1").unwrap();
+        assert_eq!(ada.gp, 33);
+    }
+}
@@ -0,0 +1,839 @@
+//! Character Stat Block Service
+//!
+//! Renders a PC or NPC as a compact monster-style stat block in 5etools
+//! monster JSON, the shape the monster card templates and module monster
+//! lists already read. Attacks come from equipped weapons and spell save DCs
+//! from the character's spellcasting classes.
+
+use std::collections::BTreeMap;
+
+use diesel::SqliteConnection;
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+use super::character::get_class_hit_die;
+use crate::dal::campaign as dal;
+use crate::dal::catalog as catalog_dal;
+use crate::models::campaign::{
+    Character, CharacterClass, CharacterInventory, CharacterProficiency, CharacterSpell,
+    ModuleMonster, NewCampaignHomebrewMonster, NewModuleMonster, UpdateCampaignHomebrewMonster,
+};
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Key in the stat block data linking it back to its character.
+pub const STATBLOCK_CHARACTER_KEY: &str = "characterId";
+
+/// Skills and the ability each one uses.
+const SKILLS: [(&str, &str); 18] = [
+    ("Acrobatics", "dex"),
+    ("Animal Handling", "wis"),
+    ("Arcana", "int"),
+    ("Athletics", "str"),
+    ("Deception", "cha"),
+    ("History", "int"),
+    ("Insight", "wis"),
+    ("Intimidation", "cha"),
+    ("Investigation", "int"),
+    ("Medicine", "wis"),
+    ("Nature", "int"),
+    ("Perception", "wis"),
+    ("Performance", "cha"),
+    ("Persuasion", "cha"),
+    ("Religion", "int"),
+    ("Sleight of Hand", "dex"),
+    ("Stealth", "dex"),
+    ("Survival", "wis"),
+];
+
+/// Ability abbreviations and full names, in stat block order.
+const ABILITIES: [(&str, &str); 6] = [
+    ("str", "Strength"),
+    ("dex", "Dexterity"),
+    ("con", "Constitution"),
+    ("int", "Intelligence"),
+    ("wis", "Wisdom"),
+    ("cha", "Charisma"),
+];
+
+/// Service for converting characters to stat blocks.
+pub struct StatblockService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> StatblockService<'a> {
+    /// Create a new stat block service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Render a character as a monster-style stat block.
+    pub fn convert(&mut self, character_id: &str) -> ServiceResult<Value> {
+        let character = dal::get_character_optional(self.conn, character_id)?
+            .ok_or_else(|| ServiceError::not_found("Character", character_id))?;
+        let classes = dal::list_character_classes(self.conn, character_id)?;
+        let proficiencies = dal::list_character_proficiencies(self.conn, character_id)?;
+        let equipped = dal::list_equipped_items(self.conn, character_id)?;
+        let spells = dal::list_character_spells(self.conn, character_id)?;
+
+        let level = classes.iter().map(|c| c.level).sum::<i32>().max(1);
+        let prof = proficiency_bonus(level);
+
+        let mut block = Map::new();
+        block.insert("name".to_string(), json!(character.name));
+        block.insert("source".to_string(), json!("Homebrew"));
+        block.insert(STATBLOCK_CHARACTER_KEY.to_string(), json!(character.id));
+        block.insert("size".to_string(), json!([self.race_size(&character)]));
+        block.insert("type".to_string(), json!({"type": "humanoid"}));
+        block.insert(
+            "ac".to_string(),
+            self.armor_class(&character, &classes, &equipped),
+        );
+        block.insert("hp".to_string(), self.hit_points(&character, &classes));
+        block.insert("speed".to_string(), json!({"walk": character.speed}));
+        for (abbrev, _) in ABILITIES {
+            block.insert(abbrev.to_string(), json!(score(&character, abbrev)));
+        }
+
+        let saves: Map<String, Value> = ABILITIES
+            .iter()
+            .filter(|(_, full)| has_proficiency(&proficiencies, "save", full))
+            .map(|(abbrev, _)| {
+                let bonus = modifier(&character, abbrev) + prof;
+                (abbrev.to_string(), json!(signed(bonus)))
+            })
+            .collect();
+        if !saves.is_empty() {
+            block.insert("save".to_string(), Value::Object(saves));
+        }
+
+        let skills: Map<String, Value> = SKILLS
+            .iter()
+            .filter_map(|(skill, ability)| {
+                skill_bonus(&character, &proficiencies, skill, ability, prof)
+                    .map(|bonus| (skill.to_lowercase(), json!(signed(bonus))))
+            })
+            .collect();
+        if !skills.is_empty() {
+            block.insert("skill".to_string(), Value::Object(skills));
+        }
+
+        if let Some(darkvision) = character.darkvision {
+            block.insert(
+                "senses".to_string(),
+                json!([format!("darkvision {} ft.", darkvision)]),
+            );
+        }
+        let perception = skill_bonus(&character, &proficiencies, "Perception", "wis", prof)
+            .unwrap_or_else(|| modifier(&character, "wis"));
+        block.insert("passive".to_string(), json!(10 + perception));
+
+        let resistances = character.parse_resistances().unwrap_or_default();
+        if !resistances.is_empty() {
+            let resist: Vec<String> = resistances.iter().map(|r| r.to_lowercase()).collect();
+            block.insert("resist".to_string(), json!(resist));
+        }
+
+        let languages: Vec<&str> = proficiencies
+            .iter()
+            .filter(|p| p.proficiency_type == "language")
+            .map(|p| p.name.as_str())
+            .collect();
+        if !languages.is_empty() {
+            block.insert("languages".to_string(), json!(languages));
+        }
+
+        block.insert("cr".to_string(), json!(challenge_rating(level)));
+
+        let traits = self.spellcasting_traits(&character, &classes, &spells, prof);
+        if !traits.is_empty() {
+            block.insert("trait".to_string(), Value::Array(traits));
+        }
+        block.insert(
+            "action".to_string(),
+            Value::Array(self.actions(&character, &classes, &equipped, prof)),
+        );
+
+        Ok(Value::Object(block))
+    }
+
+    /// Save a character's stat block as a homebrew monster in the module's
+    /// campaign and add it to the module's monsters.
+    ///
+    /// Converting the same character again refreshes its stat block instead
+    /// of adding a second copy.
+    pub fn attach_to_module(
+        &mut self,
+        character_id: &str,
+        module_id: &str,
+    ) -> ServiceResult<ModuleMonster> {
+        use diesel::Connection;
+
+        let module = dal::get_module_optional(self.conn, module_id)?
+            .ok_or_else(|| ServiceError::not_found("Module", module_id))?;
+        let statblock = self.convert(character_id)?;
+        let name = statblock["name"].as_str().unwrap_or_default().to_string();
+        let cr = statblock["cr"].as_str().unwrap_or("1").to_string();
+        let size = statblock["size"][0].as_str().unwrap_or("M").to_string();
+        let data = statblock.to_string();
+
+        self.conn.transaction(|conn| {
+            let existing = dal::list_campaign_homebrew_monsters(conn, &module.campaign_id)?
+                .into_iter()
+                .find(|monster| {
+                    serde_json::from_str::<Value>(&monster.data)
+                        .ok()
+                        .and_then(|d| d[STATBLOCK_CHARACTER_KEY].as_str().map(String::from))
+                        .is_some_and(|id| id == character_id)
+                });
+
+            let homebrew_id = match existing {
+                Some(monster) => {
+                    let now = now_rfc3339();
+                    let update = UpdateCampaignHomebrewMonster {
+                        name: Some(&name),
+                        cr: Some(Some(&cr)),
+                        creature_type: Some(Some("humanoid")),
+                        size: Some(Some(&size)),
+                        data: Some(&data),
+                        updated_at: Some(&now),
+                    };
+                    dal::update_campaign_homebrew_monster(conn, &monster.id, &update)?;
+                    monster.id
+                }
+                None => {
+                    let id = Uuid::new_v4().to_string();
+                    let monster =
+                        NewCampaignHomebrewMonster::new(&id, &module.campaign_id, &name, &data)
+                            .with_cr(&cr)
+                            .with_creature_type("humanoid")
+                            .with_size(&size);
+                    dal::insert_campaign_homebrew_monster(conn, &monster)?;
+                    id
+                }
+            };
+
+            let attached = dal::list_module_monsters(conn, module_id)?
+                .into_iter()
+                .find(|m| m.homebrew_monster_id.as_deref() == Some(homebrew_id.as_str()));
+            if let Some(monster) = attached {
+                return Ok(monster);
+            }
+
+            let id = Uuid::new_v4().to_string();
+            let monster = NewModuleMonster::from_homebrew(&id, module_id, &homebrew_id);
+            dal::insert_module_monster(conn, &monster)?;
+            dal::get_module_monster(conn, &id).map_err(ServiceError::from)
+        })
+    }
+
+    /// Size from the character's race, defaulting to Medium.
+    fn race_size(&mut self, character: &Character) -> String {
+        let (Some(name), Some(source)) = (&character.race_name, &character.race_source) else {
+            return "M".to_string();
+        };
+        catalog_dal::get_race_by_name(self.conn, name, source)
+            .ok()
+            .flatten()
+            .and_then(|race| race.parse_data().ok())
+            .and_then(|data| data["size"][0].as_str().map(String::from))
+            .unwrap_or_else(|| "M".to_string())
+    }
+
+    /// Armor class from equipped armor and shields, or unarmored defense.
+    fn armor_class(
+        &mut self,
+        character: &Character,
+        classes: &[CharacterClass],
+        equipped: &[CharacterInventory],
+    ) -> Value {
+        let dex = modifier(character, "dex");
+        let mut armor = None;
+        let mut shield = None;
+        for item in equipped {
+            let Some(data) = self.item_data(item) else {
+                continue;
+            };
+            let base = data["ac"].as_i64().unwrap_or(0) as i32 + magic_bonus(&data, "bonusAc");
+            match item_type(&data).as_str() {
+                "LA" => armor = Some((item.item_name.to_lowercase(), base + dex)),
+                "MA" => armor = Some((item.item_name.to_lowercase(), base + dex.min(2))),
+                "HA" => armor = Some((item.item_name.to_lowercase(), base)),
+                "S" => shield = Some((item.item_name.to_lowercase(), base)),
+                _ => {}
+            }
+        }
+
+        let has_class = |name: &str| {
+            classes
+                .iter()
+                .any(|c| c.class_name.eq_ignore_ascii_case(name))
+        };
+        let mut from = Vec::new();
+        let mut ac = match armor {
+            Some((name, ac)) => {
+                from.push(name);
+                ac
+            }
+            None if has_class("Barbarian") => {
+                from.push("unarmored defense".to_string());
+                10 + dex + modifier(character, "con")
+            }
+            None if has_class("Monk") && shield.is_none() => {
+                from.push("unarmored defense".to_string());
+                10 + dex + modifier(character, "wis")
+            }
+            None => 10 + dex,
+        };
+        if let Some((name, bonus)) = shield {
+            from.push(name);
+            ac += bonus;
+        }
+
+        if from.is_empty() {
+            json!([ac])
+        } else {
+            json!([{"ac": ac, "from": from}])
+        }
+    }
+
+    /// Average hit points: the starting class's full hit die at 1st level,
+    /// then the average roll for every level after.
+    fn hit_points(&mut self, character: &Character, classes: &[CharacterClass]) -> Value {
+        let con = modifier(character, "con");
+        if classes.is_empty() {
+            let average = (5 + con).max(1);
+            return json!({"average": average, "formula": dice_formula("1d8", con)});
+        }
+
+        let mut dice: BTreeMap<i32, i32> = BTreeMap::new();
+        let mut average = 0;
+        let mut levels = 0;
+        for class in classes {
+            let die = get_class_hit_die(self.conn, &class.class_name, &class.class_source);
+            *dice.entry(die).or_default() += class.level;
+            average += class.level * (die / 2 + 1);
+            if class.is_starting_class() {
+                average += die - (die / 2 + 1);
+            }
+            levels += class.level;
+        }
+        average = (average + con * levels).max(levels);
+
+        let formula = dice
+            .iter()
+            .rev()
+            .map(|(die, count)| format!("{}d{}", count, die))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        json!({"average": average, "formula": dice_formula(&formula, con * levels)})
+    }
+
+    /// Spellcasting traits: one per spellcasting class, plus any spells that
+    /// did not come from a class (e.g., racial innate spells).
+    fn spellcasting_traits(
+        &mut self,
+        character: &Character,
+        classes: &[CharacterClass],
+        spells: &[CharacterSpell],
+        prof: i32,
+    ) -> Vec<Value> {
+        let mut traits = Vec::new();
+        for class in classes {
+            let Some(ability) = self.spellcasting_ability(class) else {
+                continue;
+            };
+            let full = ABILITIES
+                .iter()
+                .find(|(abbrev, _)| *abbrev == ability)
+                .map(|(_, full)| *full)
+                .unwrap_or("Intelligence");
+            let bonus = modifier(character, &ability) + prof;
+            let mut entries = vec![json!(format!(
+                "{} is a {}-level {} spellcaster. Its spellcasting ability is {} (spell save {{@dc {}}}, {} to hit with spell attacks).",
+                character.name,
+                ordinal(class.level),
+                class.class_name.to_lowercase(),
+                full,
+                8 + bonus,
+                hit_tag(bonus)
+            ))];
+            let class_spells: Vec<&CharacterSpell> = spells
+                .iter()
+                .filter(|s| s.source_class.eq_ignore_ascii_case(&class.class_name))
+                .collect();
+            entries.extend(self.spell_lines(&class_spells));
+            traits.push(json!({"name": "Spellcasting", "entries": entries}));
+        }
+
+        let innate: Vec<&CharacterSpell> = spells
+            .iter()
+            .filter(|s| {
+                !classes
+                    .iter()
+                    .any(|c| s.source_class.eq_ignore_ascii_case(&c.class_name))
+            })
+            .collect();
+        if !innate.is_empty() {
+            let mut entries = vec![json!(format!(
+                "{} can innately cast the following spells:",
+                character.name
+            ))];
+            entries.extend(self.spell_lines(&innate));
+            traits.push(json!({"name": "Innate Spellcasting", "entries": entries}));
+        }
+
+        traits
+    }
+
+    /// Spell list lines grouped by spell level.
+    fn spell_lines(&mut self, spells: &[&CharacterSpell]) -> Vec<Value> {
+        let mut by_level: BTreeMap<i32, Vec<String>> = BTreeMap::new();
+        for spell in spells {
+            let level =
+                catalog_dal::get_spell_by_name(self.conn, &spell.spell_name, &spell.spell_source)
+                    .ok()
+                    .flatten()
+                    .map(|s| s.level)
+                    .unwrap_or(-1);
+            by_level
+                .entry(level)
+                .or_default()
+                .push(format!("{{@spell {}}}", spell.spell_name.to_lowercase()));
+        }
+
+        by_level
+            .into_iter()
+            .map(|(level, names)| {
+                let label = match level {
+                    -1 => "Other".to_string(),
+                    0 => "Cantrips (at will)".to_string(),
+                    n => format!("{} level", ordinal(n)),
+                };
+                json!(format!("{}: {}", label, names.join(", ")))
+            })
+            .collect()
+    }
+
+    /// Spellcasting ability for a class, from the catalog when available.
+    fn spellcasting_ability(&mut self, class: &CharacterClass) -> Option<String> {
+        let from_catalog =
+            catalog_dal::get_class_by_name(self.conn, &class.class_name, &class.class_source)
+                .ok()
+                .flatten()
+                .and_then(|c| c.parse_data().ok())
+                .and_then(|data| data["spellcastingAbility"].as_str().map(String::from));
+        if from_catalog.is_some() {
+            return from_catalog;
+        }
+
+        let ability = match class.class_name.to_lowercase().as_str() {
+            "artificer" | "wizard" => "int",
+            "cleric" | "druid" | "ranger" => "wis",
+            "bard" | "paladin" | "sorcerer" | "warlock" => "cha",
+            _ => match class
+                .subclass_name
+                .as_deref()
+                .map(str::to_lowercase)
+                .as_deref()
+            {
+                Some("eldritch knight") | Some("arcane trickster") => "int",
+                _ => return None,
+            },
+        };
+        Some(ability.to_string())
+    }
+
+    /// Attacks from equipped weapons, led by Multiattack when the character
+    /// has Extra Attack.
+    fn actions(
+        &mut self,
+        character: &Character,
+        classes: &[CharacterClass],
+        equipped: &[CharacterInventory],
+        prof: i32,
+    ) -> Vec<Value> {
+        let mut actions = Vec::new();
+
+        let attacks = extra_attacks(classes) + 1;
+        if attacks > 1 {
+            actions.push(json!({
+                "name": "Multiattack",
+                "entries": [format!("{} makes {} weapon attacks.", character.name, number_word(attacks))]
+            }));
+        }
+
+        let mut weapons = 0;
+        for item in equipped {
+            let Some(data) = self.item_data(item) else {
+                continue;
+            };
+            if let Some(action) = weapon_action(character, &item.item_name, &data, prof) {
+                actions.push(action);
+                weapons += 1;
+            }
+        }
+
+        if weapons == 0 {
+            let bonus = modifier(character, "str") + prof;
+            let damage = (1 + modifier(character, "str")).max(1);
+            actions.push(json!({
+                "name": "Unarmed Strike",
+                "entries": [format!(
+                    "{{@atk mw}} {} to hit, reach 5 ft., one target. {{@h}}{} bludgeoning damage.",
+                    hit_tag(bonus),
+                    damage
+                )]
+            }));
+        }
+
+        actions
+    }
+
+    /// Catalog data for an inventory item.
+    fn item_data(&mut self, item: &CharacterInventory) -> Option<Value> {
+        catalog_dal::get_item_by_name(self.conn, &item.item_name, &item.item_source)
+            .ok()
+            .flatten()
+            .and_then(|i| i.parse_data().ok())
+    }
+}
+
+/// Render a character as a monster-style stat block.
+pub fn convert_character_to_statblock(
+    conn: &mut SqliteConnection,
+    character_id: &str,
+) -> ServiceResult<Value> {
+    StatblockService::new(conn).convert(character_id)
+}
+
+/// Build a weapon's attack action, or None if the item is not a weapon.
+fn weapon_action(character: &Character, name: &str, data: &Value, prof: i32) -> Option<Value> {
+    let kind = item_type(data);
+    if kind != "M" && kind != "R" && data.get("weaponCategory").is_none() {
+        return None;
+    }
+    let dice = data["dmg1"].as_str()?;
+
+    let properties: Vec<String> = data["property"]
+        .as_array()
+        .map(|props| {
+            props
+                .iter()
+                .filter_map(|p| p.as_str().or_else(|| p["uid"].as_str()))
+                .map(|p| p.split('|').next().unwrap_or(p).to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    let has = |property: &str| properties.iter().any(|p| p == property);
+
+    let ranged = kind == "R";
+    let str_mod = modifier(character, "str");
+    let dex_mod = modifier(character, "dex");
+    let ability = if ranged {
+        dex_mod
+    } else if has("F") {
+        str_mod.max(dex_mod)
+    } else {
+        str_mod
+    };
+    let magic = magic_bonus(data, "bonusWeapon");
+    let to_hit = prof + ability + magic;
+    let damage_bonus = ability + magic;
+
+    let range = data["range"].as_str();
+    let reach = if has("R") { 10 } else { 5 };
+    let (attack, distance) = match (ranged, has("T"), range) {
+        (true, _, Some(range)) => ("{@atk rw}", format!("range {} ft.", range)),
+        (false, true, Some(range)) => (
+            "{@atk mw,rw}",
+            format!("reach {} ft. or range {} ft.", reach, range),
+        ),
+        _ => ("{@atk mw}", format!("reach {} ft.", reach)),
+    };
+
+    let damage_type = match data["dmgType"].as_str().unwrap_or("") {
+        "S" => "slashing",
+        "P" => "piercing",
+        "B" => "bludgeoning",
+        _ => "",
+    };
+    let mut text = format!(
+        "{} {} to hit, {}, one target. {{@h}}{} ({{@damage {}}}) {} damage",
+        attack,
+        hit_tag(to_hit),
+        distance,
+        (average_roll(dice) + damage_bonus).max(1),
+        dice_formula(dice, damage_bonus),
+        damage_type
+    );
+    if let (true, Some(two_handed)) = (has("V"), data["dmg2"].as_str()) {
+        text.push_str(&format!(
+            ", or {} ({{@damage {}}}) {} damage if used with two hands",
+            (average_roll(two_handed) + damage_bonus).max(1),
+            dice_formula(two_handed, damage_bonus),
+            damage_type
+        ));
+    }
+    text.push('.');
+
+    Some(json!({"name": name, "entries": [text]}))
+}
+
+/// Item type code without its source suffix (e.g., "M|XPHB" -> "M").
+fn item_type(data: &Value) -> String {
+    data["type"]
+        .as_str()
+        .and_then(|t| t.split('|').next())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Magic bonus from a field like `bonusWeapon: "+1"`.
+fn magic_bonus(data: &Value, field: &str) -> i32 {
+    data[field]
+        .as_str()
+        .and_then(|b| b.trim_start_matches('+').parse().ok())
+        .unwrap_or(0)
+}
+
+/// Additional attacks from Extra Attack.
+fn extra_attacks(classes: &[CharacterClass]) -> i32 {
+    classes
+        .iter()
+        .map(|c| match c.class_name.to_lowercase().as_str() {
+            "fighter" if c.level >= 20 => 3,
+            "fighter" if c.level >= 11 => 2,
+            "fighter" | "barbarian" | "monk" | "paladin" | "ranger" if c.level >= 5 => 1,
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Check for a proficiency of the given type, ignoring case.
+fn has_proficiency(proficiencies: &[CharacterProficiency], kind: &str, name: &str) -> bool {
+    proficiencies
+        .iter()
+        .any(|p| p.proficiency_type == kind && p.name.eq_ignore_ascii_case(name))
+}
+
+/// Skill bonus, or None if the character is not proficient.
+fn skill_bonus(
+    character: &Character,
+    proficiencies: &[CharacterProficiency],
+    skill: &str,
+    ability: &str,
+    prof: i32,
+) -> Option<i32> {
+    proficiencies
+        .iter()
+        .find(|p| p.proficiency_type == "skill" && p.name.eq_ignore_ascii_case(skill))
+        .map(|p| {
+            let multiplier = if p.expertise != 0 { 2 } else { 1 };
+            modifier(character, ability) + prof * multiplier
+        })
+}
+
+/// Ability score by abbreviation.
+fn score(character: &Character, ability: &str) -> i32 {
+    match ability {
+        "str" => character.strength,
+        "dex" => character.dexterity,
+        "con" => character.constitution,
+        "int" => character.intelligence,
+        "wis" => character.wisdom,
+        _ => character.charisma,
+    }
+}
+
+/// Ability modifier by abbreviation.
+fn modifier(character: &Character, ability: &str) -> i32 {
+    Character::ability_modifier(score(character, ability))
+}
+
+/// Proficiency bonus for a total character level.
+fn proficiency_bonus(level: i32) -> i32 {
+    2 + (level - 1) / 4
+}
+
+/// Rough challenge rating: half the character's level, at least 1/2.
+fn challenge_rating(level: i32) -> String {
+    if level < 2 {
+        "1/2".to_string()
+    } else {
+        (level / 2).to_string()
+    }
+}
+
+/// Average of a dice expression like "2d6".
+fn average_roll(dice: &str) -> i32 {
+    let Some((count, faces)) = dice.split_once('d') else {
+        return dice.parse().unwrap_or(0);
+    };
+    let count: i32 = count.parse().unwrap_or(1);
+    let faces: i32 = faces.parse().unwrap_or(0);
+    count * (faces + 1) / 2
+}
+
+/// Dice expression with a flat modifier (e.g., "1d8 + 3").
+fn dice_formula(dice: &str, bonus: i32) -> String {
+    match bonus {
+        0 => dice.to_string(),
+        b if b > 0 => format!("{} + {}", dice, b),
+        b => format!("{} - {}", dice, -b),
+    }
+}
+
+/// To-hit bonus as a 5etools tag, or plain text when negative.
+fn hit_tag(bonus: i32) -> String {
+    if bonus >= 0 {
+        format!("{{@hit {}}}", bonus)
+    } else {
+        bonus.to_string()
+    }
+}
+
+/// Signed modifier text (e.g., "+3").
+fn signed(value: i32) -> String {
+    format!("{:+}", value)
+}
+
+/// Ordinal number text (e.g., 1 -> "1st").
+fn ordinal(n: i32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Spelled-out count for Multiattack text.
+fn number_word(n: i32) -> String {
+    match n {
+        2 => "two".to_string(),
+        3 => "three".to_string(),
+        4 => "four".to_string(),
+        n => n.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_character_class, insert_character_inventory,
+        insert_character_proficiency, insert_module,
+    };
+    use crate::dal::catalog::insert_item;
+    use crate::models::campaign::{
+        NewCampaign, NewCharacter, NewCharacterClass, NewCharacterInventory,
+        NewCharacterProficiency, NewModule, ProficiencyType,
+    };
+    use crate::models::catalog::NewItem;
+    use crate::test_utils::setup_test_db_with_sources;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        let character = NewCharacter::new_pc("char-1", Some("camp-1"), "Brakka", "Alice")
+            .with_ability_scores(16, 14, 14, 10, 12, 8);
+        insert_character(conn, &character).unwrap();
+        insert_character_class(
+            conn,
+            &NewCharacterClass::starting("class-1", "char-1", "Fighter", "PHB").with_level(5),
+        )
+        .unwrap();
+        for (id, kind, name) in [
+            ("prof-1", ProficiencyType::Save, "Strength"),
+            ("prof-2", ProficiencyType::Skill, "Athletics"),
+            ("prof-3", ProficiencyType::Language, "Common"),
+        ] {
+            insert_character_proficiency(
+                conn,
+                &NewCharacterProficiency::new(id, "char-1", kind, name),
+            )
+            .unwrap();
+        }
+
+        let longsword = json!({
+            "name": "Longsword",
+            "type": "M",
+            "weaponCategory": "martial",
+            "dmg1": "1d8",
+            "dmgType": "S",
+            "dmg2": "1d10",
+            "property": ["V"]
+        });
+        let chain_mail = json!({"name": "Chain Mail", "type": "HA", "ac": 16});
+        for (name, data) in [("Longsword", longsword), ("Chain Mail", chain_mail)] {
+            insert_item(conn, &NewItem::new(name, "PHB", &data.to_string())).unwrap();
+        }
+        for (id, name) in [("inv-1", "Longsword"), ("inv-2", "Chain Mail")] {
+            let item = NewCharacterInventory::new(id, "char-1", name, "PHB").equipped();
+            insert_character_inventory(conn, &item).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_convert_character() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+
+        let block = convert_character_to_statblock(&mut conn, "char-1").unwrap();
+
+        assert_eq!(block["name"], "Brakka");
+        assert_eq!(block["ac"][0]["ac"], 16);
+        assert_eq!(block["ac"][0]["from"][0], "chain mail");
+        // 10 + 4 * 6 average, +2 Con per level
+        assert_eq!(block["hp"]["average"], 44);
+        assert_eq!(block["hp"]["formula"], "5d10 + 10");
+        assert_eq!(block["save"]["str"], "+6");
+        assert_eq!(block["skill"]["athletics"], "+6");
+        assert_eq!(block["languages"][0], "Common");
+        assert_eq!(block["passive"], 11);
+
+        let actions = block["action"].as_array().unwrap();
+        assert_eq!(actions[0]["name"], "Multiattack");
+        assert_eq!(actions[1]["name"], "Longsword");
+        assert_eq!(
+            actions[1]["entries"][0],
+            "{@atk mw} {@hit 6} to hit, reach 5 ft., one target. {@h}7 ({@damage 1d8 + 3}) slashing damage, or 8 ({@damage 1d10 + 3}) slashing damage if used with two hands."
+        );
+        assert!(block.get("trait").is_none());
+    }
+
+    #[test]
+    fn test_attach_to_module_refreshes_existing() {
+        let mut conn = setup_test_db_with_sources();
+        setup(&mut conn);
+        insert_module(&mut conn, &NewModule::new("mod-1", "camp-1", "Arena", 1)).unwrap();
+
+        let mut service = StatblockService::new(&mut conn);
+        let first = service.attach_to_module("char-1", "mod-1").unwrap();
+        let second = service.attach_to_module("char-1", "mod-1").unwrap();
+
+        assert_eq!(first.id, second.id);
+        assert!(first.is_homebrew());
+        let homebrew = dal::list_campaign_homebrew_monsters(&mut conn, "camp-1").unwrap();
+        assert_eq!(homebrew.len(), 1);
+        assert_eq!(homebrew[0].name, "Brakka");
+        assert_eq!(homebrew[0].cr.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(proficiency_bonus(1), 2);
+        assert_eq!(proficiency_bonus(5), 3);
+        assert_eq!(proficiency_bonus(17), 6);
+        assert_eq!(challenge_rating(1), "1/2");
+        assert_eq!(challenge_rating(9), "4");
+        assert_eq!(average_roll("2d6"), 7);
+        assert_eq!(dice_formula("1d4", -1), "1d4 - 1");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(22), "22nd");
+    }
+}
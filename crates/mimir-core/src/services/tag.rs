@@ -0,0 +1,345 @@
+//! Tag Service
+//!
+//! Business logic for campaign-wide tags: creating and renaming tags,
+//! attaching them to documents, NPCs, monsters, maps, and assets, and
+//! looking up what carries a tag.
+
+use std::collections::HashSet;
+
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+use uuid::Uuid;
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::{EntityTag, NewEntityTag, NewTag, Tag, TaggableType, UpdateTag};
+use crate::services::{ServiceError, ServiceResult};
+
+/// Service for tag management.
+pub struct TagService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> TagService<'a> {
+    /// Create a new tag service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Create a tag for a campaign.
+    pub fn create(
+        &mut self,
+        campaign_id: &str,
+        name: &str,
+        color: Option<&str>,
+    ) -> ServiceResult<Tag> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+        let name = validate_name(name)?;
+        if let Some(color) = color {
+            validate_color(color)?;
+        }
+        if dal::get_tag_by_name(self.conn, campaign_id, name)?.is_some() {
+            return Err(ServiceError::validation(format!(
+                "A tag named '{}' already exists",
+                name
+            )));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let mut new_tag = NewTag::new(&id, campaign_id, name);
+        if let Some(color) = color {
+            new_tag = new_tag.with_color(color);
+        }
+
+        dal::insert_tag(self.conn, &new_tag)?;
+        dal::get_tag(self.conn, &id).map_err(ServiceError::from)
+    }
+
+    /// List a campaign's tags alphabetically.
+    pub fn list_for_campaign(&mut self, campaign_id: &str) -> ServiceResult<Vec<Tag>> {
+        dal::list_tags(self.conn, campaign_id).map_err(ServiceError::from)
+    }
+
+    /// Rename or recolor a tag. `color: Some(None)` clears the color.
+    pub fn update(
+        &mut self,
+        id: &str,
+        name: Option<&str>,
+        color: Option<Option<&str>>,
+    ) -> ServiceResult<Tag> {
+        let tag = self.get_required(id)?;
+
+        let name = match name {
+            Some(name) => {
+                let name = validate_name(name)?;
+                if let Some(existing) = dal::get_tag_by_name(self.conn, &tag.campaign_id, name)? {
+                    if existing.id != tag.id {
+                        return Err(ServiceError::validation(format!(
+                            "A tag named '{}' already exists",
+                            name
+                        )));
+                    }
+                }
+                Some(name)
+            }
+            None => None,
+        };
+        if let Some(Some(color)) = color {
+            validate_color(color)?;
+        }
+
+        let update = UpdateTag { name, color };
+        dal::update_tag(self.conn, id, &update)?;
+        dal::get_tag(self.conn, id).map_err(ServiceError::from)
+    }
+
+    /// Delete a tag, detaching it from everything it was on.
+    pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
+        let rows = dal::delete_tag(self.conn, id)?;
+        if rows == 0 {
+            return Err(ServiceError::not_found("Tag", id));
+        }
+        Ok(())
+    }
+
+    /// Attach a tag to an entity by tag name, creating the tag if needed.
+    ///
+    /// The entity must belong to the campaign.
+    pub fn tag_entity(
+        &mut self,
+        campaign_id: &str,
+        entity_type: TaggableType,
+        entity_id: &str,
+        tag_name: &str,
+    ) -> ServiceResult<Tag> {
+        if !entity_in_campaign(self.conn, campaign_id, entity_type, entity_id)? {
+            return Err(ServiceError::not_found(entity_label(entity_type), entity_id));
+        }
+
+        let tag = match dal::get_tag_by_name(self.conn, campaign_id, validate_name(tag_name)?)? {
+            Some(tag) => tag,
+            None => self.create(campaign_id, tag_name, None)?,
+        };
+
+        dal::insert_entity_tag(self.conn, &NewEntityTag::new(&tag.id, entity_type, entity_id))?;
+        Ok(tag)
+    }
+
+    /// Detach a tag from an entity.
+    pub fn untag_entity(
+        &mut self,
+        tag_id: &str,
+        entity_type: TaggableType,
+        entity_id: &str,
+    ) -> ServiceResult<()> {
+        let rows = dal::delete_entity_tag(self.conn, tag_id, entity_type, entity_id)?;
+        if rows == 0 {
+            return Err(ServiceError::validation(format!(
+                "{} {} does not carry tag {}",
+                entity_label(entity_type),
+                entity_id,
+                tag_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Tags attached to an entity.
+    pub fn tags_for_entity(
+        &mut self,
+        entity_type: TaggableType,
+        entity_id: &str,
+    ) -> ServiceResult<Vec<Tag>> {
+        dal::list_tags_for_entity(self.conn, entity_type, entity_id).map_err(ServiceError::from)
+    }
+
+    /// Everything carrying a tag, across entity types.
+    pub fn tagged_entities(&mut self, tag_id: &str) -> ServiceResult<Vec<EntityTag>> {
+        self.get_required(tag_id)?;
+        dal::list_entity_tags_for_tag(self.conn, tag_id).map_err(ServiceError::from)
+    }
+
+    /// IDs of entities of one type carrying a tag, for filtering list results.
+    pub fn tagged_ids(
+        &mut self,
+        tag_id: &str,
+        entity_type: TaggableType,
+    ) -> ServiceResult<HashSet<String>> {
+        self.get_required(tag_id)?;
+        Ok(dal::list_tagged_entity_ids(self.conn, tag_id, entity_type)?
+            .into_iter()
+            .collect())
+    }
+
+    fn get_required(&mut self, id: &str) -> ServiceResult<Tag> {
+        dal::get_tag_optional(self.conn, id)?.ok_or_else(|| ServiceError::not_found("Tag", id))
+    }
+}
+
+fn validate_name(name: &str) -> ServiceResult<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServiceError::validation("Tag name cannot be empty"));
+    }
+    if name.chars().count() > 64 {
+        return Err(ServiceError::validation(
+            "Tag name cannot be longer than 64 characters",
+        ));
+    }
+    Ok(name)
+}
+
+/// Check a display color is a hex color like `#8b5cf6`.
+pub(super) fn validate_color(color: &str) -> ServiceResult<()> {
+    let valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err(ServiceError::validation(format!(
+            "Invalid color '{}'. Expected a hex color like #8b5cf6",
+            color
+        )));
+    }
+    Ok(())
+}
+
+fn entity_label(entity_type: TaggableType) -> &'static str {
+    match entity_type {
+        TaggableType::Document => "Document",
+        TaggableType::Npc => "NPC",
+        TaggableType::Monster => "ModuleMonster",
+        TaggableType::HomebrewMonster => "HomebrewMonster",
+        TaggableType::Map => "Map",
+        TaggableType::Asset => "Asset",
+    }
+}
+
+/// Whether an entity exists and belongs to the campaign.
+fn entity_in_campaign(
+    conn: &mut SqliteConnection,
+    campaign_id: &str,
+    entity_type: TaggableType,
+    entity_id: &str,
+) -> ServiceResult<bool> {
+    let module_in_campaign = |conn: &mut SqliteConnection, module_id: &str| -> ServiceResult<bool> {
+        Ok(dal::get_module_optional(conn, module_id)?
+            .is_some_and(|m| m.campaign_id == campaign_id))
+    };
+
+    let owned = match entity_type {
+        TaggableType::Document => dal::get_document_optional(conn, entity_id)?
+            .is_some_and(|d| d.campaign_id == campaign_id),
+        TaggableType::Npc => dal::get_character_optional(conn, entity_id)?.is_some_and(|c| {
+            c.is_npc != 0 && c.campaign_id.as_deref() == Some(campaign_id)
+        }),
+        TaggableType::Monster => match dal::get_module_monster_optional(conn, entity_id)? {
+            Some(monster) => module_in_campaign(conn, &monster.module_id)?,
+            None => false,
+        },
+        TaggableType::HomebrewMonster => dal::get_campaign_homebrew_monster(conn, entity_id)
+            .optional()?
+            .is_some_and(|m| m.campaign_id == campaign_id),
+        TaggableType::Map => {
+            dal::get_map_optional(conn, entity_id)?.is_some_and(|m| m.campaign_id == campaign_id)
+        }
+        TaggableType::Asset => match dal::get_campaign_asset_optional(conn, entity_id)? {
+            Some(asset) => match (asset.campaign_id.as_deref(), asset.module_id.as_deref()) {
+                (Some(owner), _) => owner == campaign_id,
+                (None, Some(module_id)) => module_in_campaign(conn, module_id)?,
+                (None, None) => false,
+            },
+            None => false,
+        },
+    };
+
+    Ok(owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_document};
+    use crate::models::campaign::{NewCampaign, NewDocument};
+    use crate::test_utils::setup_test_db;
+
+    fn setup_campaign(conn: &mut SqliteConnection, id: &str) {
+        let campaign = NewCampaign::new(id, "Test Campaign");
+        insert_campaign(conn, &campaign).expect("Failed to create campaign");
+        let doc_id = format!("{}-doc", id);
+        let doc = NewDocument::for_campaign(&doc_id, id, "Heist Plan", "notes");
+        insert_document(conn, &doc).expect("Failed to create document");
+    }
+
+    #[test]
+    fn test_tag_entity_creates_tag() {
+        let mut conn = setup_test_db();
+        setup_campaign(&mut conn, "camp-1");
+        let mut service = TagService::new(&mut conn);
+
+        let tag = service
+            .tag_entity("camp-1", TaggableType::Document, "camp-1-doc", " Act 2 ")
+            .expect("Failed to tag");
+        assert_eq!(tag.name, "Act 2");
+
+        // Reuses the existing tag regardless of case
+        let again = service
+            .tag_entity("camp-1", TaggableType::Document, "camp-1-doc", "act 2")
+            .expect("Failed to tag");
+        assert_eq!(again.id, tag.id);
+
+        let ids = service
+            .tagged_ids(&tag.id, TaggableType::Document)
+            .expect("Failed to list");
+        assert!(ids.contains("camp-1-doc"));
+        assert_eq!(service.list_for_campaign("camp-1").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_tag_entity_rejects_other_campaign() {
+        let mut conn = setup_test_db();
+        setup_campaign(&mut conn, "camp-1");
+        setup_campaign(&mut conn, "camp-2");
+        let mut service = TagService::new(&mut conn);
+
+        let result = service.tag_entity("camp-1", TaggableType::Document, "camp-2-doc", "Act 2");
+        assert!(matches!(result, Err(ServiceError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_create_validates() {
+        let mut conn = setup_test_db();
+        setup_campaign(&mut conn, "camp-1");
+        let mut service = TagService::new(&mut conn);
+
+        assert!(service.create("camp-1", "  ", None).is_err());
+        assert!(service.create("camp-1", "Underdark", Some("purple")).is_err());
+
+        service
+            .create("camp-1", "Underdark", Some("#4c1d95"))
+            .expect("Failed to create");
+        let duplicate = service.create("camp-1", "underdark", None);
+        assert!(matches!(duplicate, Err(ServiceError::Validation(_))));
+    }
+
+    #[test]
+    fn test_untag_entity() {
+        let mut conn = setup_test_db();
+        setup_campaign(&mut conn, "camp-1");
+        let mut service = TagService::new(&mut conn);
+
+        let tag = service
+            .tag_entity("camp-1", TaggableType::Document, "camp-1-doc", "Heist")
+            .expect("Failed to tag");
+        service
+            .untag_entity(&tag.id, TaggableType::Document, "camp-1-doc")
+            .expect("Failed to untag");
+        assert!(service
+            .tags_for_entity(TaggableType::Document, "camp-1-doc")
+            .unwrap()
+            .is_empty());
+        assert!(service
+            .untag_entity(&tag.id, TaggableType::Document, "camp-1-doc")
+            .is_err());
+    }
+}
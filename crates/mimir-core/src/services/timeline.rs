@@ -0,0 +1,284 @@
+//! Campaign Timeline Service
+//!
+//! Gathers every party's timeline events into one campaign history, sorted
+//! into swimlanes. Plotline lanes follow the module being played in the
+//! session an event happened in. Faction lanes follow the factions of the
+//! NPCs an event mentions by name, so an event involving two factions shows
+//! up in both lanes.
+
+use std::collections::HashMap;
+
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::dal::campaign as dal;
+use crate::services::{ServiceError, ServiceResult};
+
+/// Lane for plotline events from sessions not run in a module.
+pub const NO_PLOTLINE_LANE: &str = "Between Modules";
+
+/// Lane for faction events that mention no faction's NPCs.
+pub const NO_FACTION_LANE: &str = "Unaffiliated";
+
+/// How a campaign timeline is split into swimlanes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineGrouping {
+    /// One lane per module
+    #[default]
+    Plotline,
+    /// One lane per NPC faction
+    Faction,
+}
+
+/// A party timeline event placed on the campaign timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct CampaignTimelineEvent {
+    pub id: String,
+    /// Name of the party whose timeline the event is on
+    pub party_name: String,
+    /// In-world day the event happened on
+    pub day: i32,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// A swimlane of the campaign timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct CampaignTimelineLane {
+    /// Module or faction name
+    pub name: String,
+    /// Events in calendar order
+    pub events: Vec<CampaignTimelineEvent>,
+}
+
+/// Service for the campaign-wide timeline.
+pub struct CampaignTimelineService<'a> {
+    conn: &'a mut SqliteConnection,
+}
+
+impl<'a> CampaignTimelineService<'a> {
+    /// Create a new campaign timeline service.
+    pub fn new(conn: &'a mut SqliteConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Every party's timeline events split into lanes, leaving out lanes
+    /// with no events. Plotline lanes are in module order and faction lanes
+    /// in name order, with the catch-all lane last.
+    pub fn lanes(
+        &mut self,
+        campaign_id: &str,
+        grouping: TimelineGrouping,
+    ) -> ServiceResult<Vec<CampaignTimelineLane>> {
+        if !dal::campaign_exists(self.conn, campaign_id)? {
+            return Err(ServiceError::not_found("Campaign", campaign_id));
+        }
+
+        let mut events = Vec::new();
+        for party in dal::list_parties(self.conn, campaign_id)? {
+            for event in dal::list_party_timeline_events(self.conn, &party.id)? {
+                events.push((
+                    event.session_id.clone(),
+                    CampaignTimelineEvent {
+                        id: event.id,
+                        party_name: party.name.clone(),
+                        day: event.day,
+                        title: event.title,
+                        description: event.description,
+                    },
+                ));
+            }
+        }
+        // Stable, so same-day events keep their party and entry order
+        events.sort_by_key(|(_, e)| e.day);
+
+        let (mut lanes, catch_all) = match grouping {
+            TimelineGrouping::Plotline => {
+                let modules = dal::list_modules(self.conn, campaign_id)?;
+                let session_modules: HashMap<String, String> =
+                    dal::list_game_sessions(self.conn, campaign_id)?
+                        .into_iter()
+                        .filter_map(|s| s.module_id.map(|m| (s.id, m)))
+                        .collect();
+
+                let mut lanes: Vec<CampaignTimelineLane> =
+                    modules.iter().map(|m| lane(&m.name)).collect();
+                let mut catch_all = lane(NO_PLOTLINE_LANE);
+                for (session_id, event) in events {
+                    let index = session_id
+                        .and_then(|id| session_modules.get(&id))
+                        .and_then(|module_id| modules.iter().position(|m| &m.id == module_id));
+                    match index {
+                        Some(i) => lanes[i].events.push(event),
+                        None => catch_all.events.push(event),
+                    }
+                }
+                (lanes, catch_all)
+            }
+            TimelineGrouping::Faction => {
+                let npcs: Vec<(String, String)> = dal::list_npcs(self.conn, campaign_id)?
+                    .into_iter()
+                    .filter_map(|npc| {
+                        let faction = npc.faction?.trim().to_string();
+                        (!faction.is_empty()).then(|| (npc.name.to_lowercase(), faction))
+                    })
+                    .collect();
+
+                let mut lanes: Vec<CampaignTimelineLane> = Vec::new();
+                let mut catch_all = lane(NO_FACTION_LANE);
+                for (_, event) in events {
+                    let text = format!(
+                        "{}\n{}",
+                        event.title,
+                        event.description.as_deref().unwrap_or("")
+                    )
+                    .to_lowercase();
+                    let mut factions: Vec<&str> = npcs
+                        .iter()
+                        .filter(|(name, _)| text.contains(name.as_str()))
+                        .map(|(_, faction)| faction.as_str())
+                        .collect();
+                    factions.sort_unstable();
+                    factions.dedup();
+
+                    if factions.is_empty() {
+                        catch_all.events.push(event);
+                        continue;
+                    }
+                    for faction in factions {
+                        match lanes.iter_mut().find(|l| l.name == faction) {
+                            Some(l) => l.events.push(event.clone()),
+                            None => {
+                                let mut new_lane = lane(faction);
+                                new_lane.events.push(event.clone());
+                                lanes.push(new_lane);
+                            }
+                        }
+                    }
+                }
+                lanes.sort_by(|a, b| a.name.cmp(&b.name));
+                (lanes, catch_all)
+            }
+        };
+
+        lanes.push(catch_all);
+        lanes.retain(|l| !l.events.is_empty());
+        Ok(lanes)
+    }
+}
+
+/// An empty lane.
+fn lane(name: &str) -> CampaignTimelineLane {
+    CampaignTimelineLane {
+        name: name.to_string(),
+        events: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{
+        insert_campaign, insert_character, insert_game_session, insert_module, insert_party,
+        insert_party_timeline_event,
+    };
+    use crate::models::campaign::{
+        NewCampaign, NewCharacter, NewGameSession, NewModule, NewParty, NewPartyTimelineEvent,
+    };
+    use crate::test_utils::setup_test_db;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Lost Mine")).unwrap();
+        insert_module(conn, &NewModule::new("mod-1", "camp-1", "Goblin Arrows", 1)).unwrap();
+        insert_module(conn, &NewModule::new("mod-2", "camp-1", "Phandalin", 2)).unwrap();
+        insert_party(conn, &NewParty::new("party-1", "camp-1", "Heroes")).unwrap();
+        insert_game_session(
+            conn,
+            &NewGameSession::new("session-1", "camp-1", 1).with_module("mod-1"),
+        )
+        .unwrap();
+        insert_game_session(
+            conn,
+            &NewGameSession::new("session-2", "camp-1", 2).with_module("mod-2"),
+        )
+        .unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_npc("npc-1", Some("camp-1"), "Sildar Hallwinter").with_npc_info(
+                None,
+                None,
+                Some("Lords' Alliance"),
+            ),
+        )
+        .unwrap();
+        insert_character(
+            conn,
+            &NewCharacter::new_npc("npc-2", Some("camp-1"), "Glasstaff").with_npc_info(
+                None,
+                None,
+                Some("Redbrands"),
+            ),
+        )
+        .unwrap();
+
+        let events = [
+            (
+                "event-1",
+                4,
+                "Met Glasstaff and Sildar Hallwinter",
+                Some("session-2"),
+            ),
+            ("event-2", 1, "Ambushed on the road", Some("session-1")),
+            ("event-3", 2, "Camped in the woods", None),
+        ];
+        for (id, day, title, session_id) in events {
+            let mut event = NewPartyTimelineEvent::new(id, "party-1", day, title);
+            if let Some(session_id) = session_id {
+                event = event.with_session(session_id);
+            }
+            insert_party_timeline_event(conn, &event).unwrap();
+        }
+    }
+
+    fn lane_names(lanes: &[CampaignTimelineLane]) -> Vec<&str> {
+        lanes.iter().map(|l| l.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_plotline_lanes() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CampaignTimelineService::new(&mut conn);
+
+        let lanes = service.lanes("camp-1", TimelineGrouping::Plotline).unwrap();
+        assert_eq!(
+            lane_names(&lanes),
+            vec!["Goblin Arrows", "Phandalin", NO_PLOTLINE_LANE]
+        );
+        assert_eq!(lanes[0].events[0].title, "Ambushed on the road");
+        assert_eq!(lanes[0].events[0].party_name, "Heroes");
+        assert_eq!(lanes[2].events[0].day, 2);
+        assert!(service
+            .lanes("missing", TimelineGrouping::Plotline)
+            .is_err());
+    }
+
+    #[test]
+    fn test_faction_lanes() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let mut service = CampaignTimelineService::new(&mut conn);
+
+        let lanes = service.lanes("camp-1", TimelineGrouping::Faction).unwrap();
+        assert_eq!(
+            lane_names(&lanes),
+            vec!["Lords' Alliance", "Redbrands", NO_FACTION_LANE]
+        );
+        // Mentioning two factions' NPCs puts the event in both lanes
+        assert_eq!(lanes[0].events[0].id, "event-1");
+        assert_eq!(lanes[1].events[0].id, "event-1");
+        let unaffiliated: Vec<_> = lanes[2].events.iter().map(|e| e.day).collect();
+        assert_eq!(unaffiliated, vec![1, 2]);
+    }
+}
@@ -24,12 +24,16 @@ pub struct CreateTokenInput {
     pub grid_x: i32,
     /// Grid Y coordinate
     pub grid_y: i32,
-    /// Optional label (required for PC tokens)
+    /// Optional label (required for PC tokens not linked to a character)
     pub label: Option<String>,
     /// Faction color
     pub faction_color: Option<String>,
     /// Whether the token is hidden from players
     pub hidden: bool,
+    /// Character a PC token stands for
+    pub character_id: Option<String>,
+    /// Number of creatures a monster token stands for, under the mob rules
+    pub mob_size: Option<i32>,
 }
 
 impl CreateTokenInput {
@@ -49,6 +53,8 @@ impl CreateTokenInput {
             label: None,
             faction_color: None,
             hidden: false,
+            character_id: None,
+            mob_size: None,
         }
     }
 
@@ -68,6 +74,8 @@ impl CreateTokenInput {
             label: None,
             faction_color: None,
             hidden: false,
+            character_id: None,
+            mob_size: None,
         }
     }
 
@@ -82,6 +90,8 @@ impl CreateTokenInput {
             label: Some(label.into()),
             faction_color: None,
             hidden: false,
+            character_id: None,
+            mob_size: None,
         }
     }
 
@@ -102,6 +112,18 @@ impl CreateTokenInput {
         self.hidden = true;
         self
     }
+
+    /// Link a PC token to its character.
+    pub fn with_character(mut self, character_id: impl Into<String>) -> Self {
+        self.character_id = Some(character_id.into());
+        self
+    }
+
+    /// Make a monster token stand for a mob of `size` creatures.
+    pub fn as_mob(mut self, size: i32) -> Self {
+        self.mob_size = Some(size);
+        self
+    }
 }
 
 /// Input for updating a token placement.
@@ -125,6 +147,8 @@ pub struct UpdateTokenInput {
     pub vision_dark_ft: Option<i32>,
     /// Update light radius
     pub light_radius_ft: Option<i32>,
+    /// Update mob size (Some(None) to make it a single creature's token)
+    pub mob_size: Option<Option<i32>>,
 }
 
 impl UpdateTokenInput {
@@ -178,10 +202,15 @@ pub struct TokenResponse {
     pub color: Option<String>,
     pub monster_id: Option<String>,
     pub character_id: Option<String>,
+    /// Token image of the character a PC token stands for, relative to the
+    /// assets directory
+    pub image_path: Option<String>,
     pub vision_bright_ft: Option<i32>,
     pub vision_dim_ft: Option<i32>,
     pub vision_dark_ft: i32,
     pub light_radius_ft: i32,
+    /// Creatures in the mob the token stands for; None for a single creature
+    pub mob_size: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -241,16 +270,30 @@ impl<'a> TokenService<'a> {
             ));
         }
 
-        // Validate: PC tokens require a label
+        // Validate: PC tokens require a label or a character to take the name from
         if input.module_monster_id.is_none()
             && input.module_npc_id.is_none()
             && input.label.is_none()
+            && input.character_id.is_none()
         {
             return Err(ServiceError::validation(
                 "A label is required for PC tokens (when no monster_id or npc_id is provided)",
             ));
         }
 
+        if let Some(ref character_id) = input.character_id {
+            if dal::get_character_optional(self.conn, character_id)?.is_none() {
+                return Err(ServiceError::not_found("Character", character_id));
+            }
+        }
+
+        if input.mob_size.is_some() && input.module_monster_id.is_none() {
+            return Err(ServiceError::validation(
+                "Only monster tokens can stand for a mob",
+            ));
+        }
+        validate_mob_size(input.mob_size)?;
+
         let id = Uuid::new_v4().to_string();
         let label_ref = input.label.as_deref();
         let color_ref = input.faction_color.as_deref();
@@ -271,6 +314,8 @@ impl<'a> TokenService<'a> {
             vision_dim_ft: None,
             vision_dark_ft: 0,
             light_radius_ft: 0,
+            character_id: input.character_id.as_deref(),
+            mob_size: input.mob_size,
         };
 
         dal::insert_token_placement(self.conn, &placement)?;
@@ -284,6 +329,16 @@ impl<'a> TokenService<'a> {
     pub fn update(&mut self, id: &str, input: UpdateTokenInput) -> ServiceResult<TokenResponse> {
         let label: Option<Option<&str>> = input.label.as_ref().map(|o| o.as_deref());
         let faction_color: Option<Option<&str>> = input.faction_color.as_ref().map(|o| o.as_deref());
+        if let Some(mob_size) = input.mob_size {
+            validate_mob_size(mob_size)?;
+            let not_monster = dal::get_token_placement_optional(self.conn, id)?
+                .is_some_and(|token| !token.is_monster());
+            if mob_size.is_some() && not_monster {
+                return Err(ServiceError::validation(
+                    "Only monster tokens can stand for a mob",
+                ));
+            }
+        }
 
         let update = UpdateTokenPlacement {
             grid_x: input.grid_x,
@@ -295,6 +350,7 @@ impl<'a> TokenService<'a> {
             vision_dim_ft: input.vision_dim_ft,
             vision_dark_ft: input.vision_dark_ft,
             light_radius_ft: input.light_radius_ft,
+            mob_size: input.mob_size,
         };
 
         let rows = dal::update_token_placement(self.conn, id, &update)?;
@@ -361,6 +417,14 @@ impl<'a> TokenService<'a> {
         self.enrich(updated, grid_size_px)
     }
 
+    /// Shrink every mob token of a module monster to the mob's survivors.
+    ///
+    /// Returns the number of tokens updated.
+    pub fn resize_mob(&mut self, module_monster_id: &str, survivors: i32) -> ServiceResult<usize> {
+        validate_mob_size(Some(survivors))?;
+        dal::resize_mob_tokens(self.conn, module_monster_id, survivors).map_err(ServiceError::from)
+    }
+
     /// Delete a token.
     pub fn delete(&mut self, id: &str) -> ServiceResult<()> {
         let rows = dal::delete_token_placement(self.conn, id)?;
@@ -392,6 +456,10 @@ impl<'a> TokenService<'a> {
     /// Enrich a TokenPlacement with resolved names and computed coordinates.
     fn enrich(&mut self, placement: TokenPlacement, grid_size_px: i32) -> ServiceResult<TokenResponse> {
         let (token_type, name, size) = self.resolve_names(&placement);
+        let character = placement
+            .character_id
+            .as_deref()
+            .and_then(|id| dal::get_character_optional(self.conn, id).ok().flatten());
 
         // Convert grid coordinates to pixel coordinates (center of grid cell)
         let x = (placement.grid_x as f64 + 0.5) * grid_size_px as f64;
@@ -400,7 +468,11 @@ impl<'a> TokenService<'a> {
         Ok(TokenResponse {
             id: placement.id,
             map_id: placement.map_id,
-            name: placement.label.or(name).unwrap_or_else(|| "Unknown".to_string()),
+            name: placement
+                .label
+                .or(name)
+                .or_else(|| character.as_ref().map(|c| c.name.clone()))
+                .unwrap_or_else(|| "Unknown".to_string()),
             token_type,
             size,
             grid_x: placement.grid_x,
@@ -410,11 +482,13 @@ impl<'a> TokenService<'a> {
             visible_to_players: placement.hidden == 0,
             color: placement.faction_color,
             monster_id: placement.module_monster_id,
-            character_id: placement.module_npc_id,
+            character_id: placement.module_npc_id.or(placement.character_id),
+            image_path: character.and_then(|c| c.token_path),
             vision_bright_ft: placement.vision_bright_ft,
             vision_dim_ft: placement.vision_dim_ft,
             vision_dark_ft: placement.vision_dark_ft,
             light_radius_ft: placement.light_radius_ft,
+            mob_size: placement.mob_size,
             created_at: placement.created_at.clone(),
             updated_at: placement.created_at, // Use created_at as updated_at since we don't track it
         })
@@ -474,6 +548,16 @@ impl<'a> TokenService<'a> {
     }
 }
 
+/// Check that a mob token stands for at least one creature.
+fn validate_mob_size(mob_size: Option<i32>) -> ServiceResult<()> {
+    match mob_size {
+        Some(size) if size < 1 => Err(ServiceError::validation(
+            "A mob must have at least one creature",
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Normalize size codes (T, S, M, L, H, G) to full names.
 fn normalize_size_code(size: &str) -> String {
     match size.to_uppercase().as_str() {
@@ -491,11 +575,12 @@ fn normalize_size_code(size: &str) -> String {
 mod tests {
     use super::*;
     use crate::dal::campaign::{
-        insert_campaign, insert_campaign_asset, insert_map, insert_module,
-        insert_module_monster, insert_module_npc,
+        insert_campaign, insert_campaign_asset, insert_character, insert_map, insert_module,
+        insert_module_monster, insert_module_npc, update_character,
     };
     use crate::models::campaign::{
-        NewCampaign, NewCampaignAsset, NewMap, NewModule, NewModuleMonster, NewModuleNpc,
+        NewCampaign, NewCampaignAsset, NewCharacter, NewMap, NewModule, NewModuleMonster,
+        NewModuleNpc, UpdateCharacter,
     };
     use crate::test_utils::setup_test_db;
     use tempfile::TempDir;
@@ -577,6 +662,37 @@ mod tests {
         assert!(token.character_id.is_none());
     }
 
+    #[test]
+    fn test_create_character_token_uses_character_image() {
+        let (mut conn, temp_dir) = setup_test_env();
+        let (campaign_id, _module_id, map_id) = create_prerequisites(&mut conn);
+        let pc = NewCharacter::new_pc("char-1", Some(campaign_id.as_str()), "Ada", "Alice");
+        insert_character(&mut conn, &pc).unwrap();
+        let update = UpdateCharacter {
+            token_path: Some(Some("characters/char-1/token-abc.png")),
+            ..Default::default()
+        };
+        update_character(&mut conn, "char-1", &update).unwrap();
+
+        let mut service = TokenService::new(&mut conn, temp_dir.path());
+        let mut input = CreateTokenInput::for_pc(&map_id, "Ada", 0, 0).with_character("char-1");
+        input.label = None;
+        let token = service.create(input).expect("Failed to create PC token");
+
+        assert_eq!(token.name, "Ada");
+        assert_eq!(token.character_id.as_deref(), Some("char-1"));
+        assert_eq!(
+            token.image_path.as_deref(),
+            Some("characters/char-1/token-abc.png")
+        );
+
+        let missing = CreateTokenInput::for_pc(&map_id, "Bob", 0, 0).with_character("nobody");
+        assert!(matches!(
+            service.create(missing),
+            Err(ServiceError::NotFound { .. })
+        ));
+    }
+
     #[test]
     fn test_create_monster_token() {
         let (mut conn, temp_dir) = setup_test_env();
@@ -593,6 +709,33 @@ mod tests {
         assert_eq!(token.grid_y, 2);
     }
 
+    #[test]
+    fn test_mob_token_shrinks_with_casualties() {
+        let (mut conn, temp_dir) = setup_test_env();
+        let (_campaign_id, module_id, map_id) = create_prerequisites(&mut conn);
+        let monster_id = create_module_monster(&mut conn, &module_id);
+
+        let mut service = TokenService::new(&mut conn, temp_dir.path());
+        let mob = service
+            .create(CreateTokenInput::for_monster(&map_id, &monster_id, 0, 0).as_mob(30))
+            .expect("Failed to create mob token");
+        let single = service
+            .create(CreateTokenInput::for_monster(&map_id, &monster_id, 1, 0))
+            .expect("Failed to create monster token");
+        assert_eq!(mob.mob_size, Some(30));
+
+        assert_eq!(service.resize_mob(&monster_id, 22).unwrap(), 1);
+        assert_eq!(service.get(&mob.id).unwrap().unwrap().mob_size, Some(22));
+        assert_eq!(service.get(&single.id).unwrap().unwrap().mob_size, None);
+
+        assert!(service
+            .create(CreateTokenInput::for_pc(&map_id, "Fighter", 0, 0).as_mob(3))
+            .is_err());
+        assert!(service
+            .create(CreateTokenInput::for_monster(&map_id, &monster_id, 0, 0).as_mob(0))
+            .is_err());
+    }
+
     #[test]
     fn test_create_npc_token() {
         let (mut conn, temp_dir) = setup_test_env();
@@ -1012,6 +1155,8 @@ mod tests {
             label: None,
             faction_color: None,
             hidden: false,
+            character_id: None,
+            mob_size: None,
         };
         let result = service.create(input);
         assert!(matches!(result, Err(ServiceError::Validation(_))));
@@ -1035,6 +1180,8 @@ mod tests {
             label: None,
             faction_color: None,
             hidden: false,
+            character_id: None,
+            mob_size: None,
         };
         let result = service.create(input);
         assert!(matches!(result, Err(ServiceError::Validation(_))));
@@ -0,0 +1,418 @@
+//! Trash Service
+//!
+//! Soft-delete for documents, characters, and maps. Trashed items are hidden
+//! from every list but keep their data for `TRASH_RETENTION_DAYS`, and a
+//! map's files are moved under `.trash/` in the app data directory until the
+//! map is restored or purged.
+
+use chrono::{DateTime, Duration, Utc};
+use diesel::{Connection, SqliteConnection};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::dal::campaign as dal;
+use crate::models::campaign::Map;
+use crate::services::map::map_asset_files;
+use crate::services::{ServiceError, ServiceResult};
+use crate::utils::now_rfc3339;
+
+/// Days an item stays in the trash before it can be purged automatically.
+pub const TRASH_RETENTION_DAYS: i64 = 30;
+
+/// Directory (under the app data directory) holding quarantined files.
+pub const TRASH_DIR: &str = ".trash";
+
+/// Kind of entity that can be trashed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashKind {
+    Document,
+    Character,
+    Map,
+}
+
+impl TrashKind {
+    /// Convert to string for display and the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashKind::Document => "document",
+            TrashKind::Character => "character",
+            TrashKind::Map => "map",
+        }
+    }
+
+    /// Parse from string.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "document" => Some(TrashKind::Document),
+            "character" => Some(TrashKind::Character),
+            "map" => Some(TrashKind::Map),
+            _ => None,
+        }
+    }
+
+    fn entity_name(&self) -> &'static str {
+        match self {
+            TrashKind::Document => "Document",
+            TrashKind::Character => "Character",
+            TrashKind::Map => "Map",
+        }
+    }
+}
+
+/// An item in the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedItem {
+    pub kind: TrashKind,
+    pub id: String,
+    /// Owning campaign (None for characters not assigned to a campaign)
+    pub campaign_id: Option<String>,
+    /// Document title, character name, or map name
+    pub name: String,
+    /// ISO8601 timestamp when the item was trashed
+    pub deleted_at: String,
+    /// ISO8601 timestamp after which the item is purged automatically
+    pub expires_at: String,
+}
+
+impl TrashedItem {
+    fn new(
+        kind: TrashKind,
+        id: String,
+        campaign_id: Option<String>,
+        name: String,
+        deleted_at: Option<String>,
+    ) -> Self {
+        let deleted_at = deleted_at.unwrap_or_default();
+        let expires_at = expiry(&deleted_at)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_else(|| deleted_at.clone());
+        Self {
+            kind,
+            id,
+            campaign_id,
+            name,
+            deleted_at,
+            expires_at,
+        }
+    }
+
+    /// Check if the item has been in the trash longer than the retention period.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        expiry(&self.deleted_at).is_some_and(|t| t <= now)
+    }
+}
+
+/// When an item trashed at `deleted_at` expires.
+fn expiry(deleted_at: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(deleted_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc) + Duration::days(TRASH_RETENTION_DAYS))
+}
+
+/// Move a file, creating the destination directory. Missing sources are skipped.
+fn move_if_exists(from: &Path, to: &Path) -> std::io::Result<()> {
+    if !from.exists() {
+        return Ok(());
+    }
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::rename(from, to)
+}
+
+/// Service for trashing, restoring, and purging campaign entities.
+pub struct TrashService<'a> {
+    conn: &'a mut SqliteConnection,
+    app_data_dir: PathBuf,
+}
+
+impl<'a> TrashService<'a> {
+    /// Create a new trash service.
+    pub fn new(conn: &'a mut SqliteConnection, app_data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            conn,
+            app_data_dir: app_data_dir.into(),
+        }
+    }
+
+    /// Move an item to the trash.
+    pub fn trash(&mut self, kind: TrashKind, id: &str) -> ServiceResult<()> {
+        let now = now_rfc3339();
+        let rows = match kind {
+            TrashKind::Document => dal::trash_document(self.conn, id, &now)?,
+            TrashKind::Character => dal::trash_character(self.conn, id, &now)?,
+            TrashKind::Map => return self.trash_map(id, &now),
+        };
+        if rows == 0 {
+            return Err(ServiceError::not_found(kind.entity_name(), id));
+        }
+        Ok(())
+    }
+
+    /// Restore an item from the trash.
+    pub fn restore(&mut self, kind: TrashKind, id: &str) -> ServiceResult<()> {
+        let rows = match kind {
+            TrashKind::Document => dal::restore_document(self.conn, id)?,
+            TrashKind::Character => dal::restore_character(self.conn, id)?,
+            TrashKind::Map => return self.restore_map(id),
+        };
+        if rows == 0 {
+            return Err(ServiceError::not_found(kind.entity_name(), id));
+        }
+        Ok(())
+    }
+
+    /// List trashed items, most recently trashed first.
+    ///
+    /// With a campaign, only that campaign's items are listed.
+    pub fn list(&mut self, campaign_id: Option<&str>) -> ServiceResult<Vec<TrashedItem>> {
+        let mut items = Vec::new();
+
+        for doc in dal::list_trashed_documents(self.conn, campaign_id)? {
+            items.push(TrashedItem::new(
+                TrashKind::Document,
+                doc.id,
+                Some(doc.campaign_id),
+                doc.title,
+                doc.deleted_at,
+            ));
+        }
+        for character in dal::list_trashed_characters(self.conn, campaign_id)? {
+            items.push(TrashedItem::new(
+                TrashKind::Character,
+                character.id,
+                character.campaign_id,
+                character.name,
+                character.deleted_at,
+            ));
+        }
+        for map in dal::list_trashed_maps(self.conn, campaign_id)? {
+            items.push(TrashedItem::new(
+                TrashKind::Map,
+                map.id,
+                Some(map.campaign_id),
+                map.name,
+                map.deleted_at,
+            ));
+        }
+
+        items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+        Ok(items)
+    }
+
+    /// Permanently delete trashed items.
+    ///
+    /// With `expired_only`, only items older than the retention period are
+    /// removed. Returns the number of items purged.
+    pub fn purge(&mut self, campaign_id: Option<&str>, expired_only: bool) -> ServiceResult<usize> {
+        let now = Utc::now();
+        let items: Vec<TrashedItem> = self
+            .list(campaign_id)?
+            .into_iter()
+            .filter(|item| !expired_only || item.is_expired(now))
+            .collect();
+
+        if items.is_empty() {
+            return Ok(0);
+        }
+
+        // Delete all rows in one transaction, then remove quarantined files
+        let quarantined = self.conn.transaction(|conn| {
+            let mut files = Vec::new();
+            for item in &items {
+                match item.kind {
+                    TrashKind::Document => {
+                        dal::delete_document(conn, &item.id)?;
+                    }
+                    TrashKind::Character => {
+                        dal::delete_character(conn, &item.id)?;
+                    }
+                    TrashKind::Map => {
+                        let map = dal::get_map(conn, &item.id)?;
+                        let asset = dal::get_campaign_asset_optional(conn, &map.uvtt_asset_id)?;
+                        // Delete the map record first (removes FK constraint to asset)
+                        dal::delete_map(conn, &map.id)?;
+                        if let Some(asset) = asset {
+                            dal::delete_campaign_asset(conn, &asset.id)?;
+                            files.extend(map_asset_files(&asset.blob_path));
+                        }
+                    }
+                }
+            }
+            Ok::<_, ServiceError>(files)
+        })?;
+
+        for file in &quarantined {
+            let _ = std::fs::remove_file(self.trash_path(file));
+        }
+
+        Ok(items.len())
+    }
+
+    fn trash_map(&mut self, id: &str, now: &str) -> ServiceResult<()> {
+        let map = self.get_map(id, false)?;
+        let files = self.map_files(&map)?;
+        let moves: Vec<(PathBuf, PathBuf)> = files
+            .iter()
+            .map(|f| (self.app_data_dir.join(f), self.trash_path(f)))
+            .collect();
+
+        self.move_files(&moves)?;
+        if let Err(e) = dal::trash_map(self.conn, id, now) {
+            self.move_back(&moves);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    fn restore_map(&mut self, id: &str) -> ServiceResult<()> {
+        let map = self.get_map(id, true)?;
+        let files = self.map_files(&map)?;
+        let moves: Vec<(PathBuf, PathBuf)> = files
+            .iter()
+            .map(|f| (self.trash_path(f), self.app_data_dir.join(f)))
+            .collect();
+
+        self.move_files(&moves)?;
+        if let Err(e) = dal::restore_map(self.conn, id) {
+            self.move_back(&moves);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    /// Get a map that is (or is not) in the trash.
+    fn get_map(&mut self, id: &str, trashed: bool) -> ServiceResult<Map> {
+        dal::get_map_optional(self.conn, id)?
+            .filter(|m| m.is_trashed() == trashed)
+            .ok_or_else(|| ServiceError::not_found("Map", id))
+    }
+
+    /// Files stored for a map's UVTT asset, relative to the app data directory.
+    fn map_files(&mut self, map: &Map) -> ServiceResult<Vec<String>> {
+        let asset = dal::get_campaign_asset_optional(self.conn, &map.uvtt_asset_id)?;
+        Ok(asset
+            .map(|a| map_asset_files(&a.blob_path).to_vec())
+            .unwrap_or_default())
+    }
+
+    fn trash_path(&self, file: &str) -> PathBuf {
+        self.app_data_dir
+            .join(TRASH_DIR)
+            .join(file.trim_start_matches('/'))
+    }
+
+    /// Move files, undoing earlier moves if one fails.
+    fn move_files(&self, moves: &[(PathBuf, PathBuf)]) -> ServiceResult<()> {
+        for (i, (from, to)) in moves.iter().enumerate() {
+            if let Err(e) = move_if_exists(from, to) {
+                self.move_back(&moves[..i]);
+                return Err(e.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn move_back(&self, moves: &[(PathBuf, PathBuf)]) {
+        for (from, to) in moves {
+            let _ = move_if_exists(to, from);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::campaign::{insert_campaign, insert_character, insert_document};
+    use crate::models::campaign::{NewCampaign, NewCharacter, NewDocument};
+    use crate::services::{CreateMapInput, MapService};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    fn setup(conn: &mut SqliteConnection) {
+        insert_campaign(conn, &NewCampaign::new("camp-1", "Test Campaign")).unwrap();
+        let doc = NewDocument::for_campaign("doc-1", "camp-1", "Heist Plan", "note");
+        insert_document(conn, &doc).unwrap();
+        let npc = NewCharacter::new_npc("npc-1", Some("camp-1"), "Shopkeeper");
+        insert_character(conn, &npc).unwrap();
+    }
+
+    #[test]
+    fn test_trash_and_restore_document_and_character() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let temp_dir = TempDir::new().unwrap();
+        let mut service = TrashService::new(&mut conn, temp_dir.path());
+
+        service.trash(TrashKind::Document, "doc-1").expect("Failed to trash");
+        service.trash(TrashKind::Character, "npc-1").expect("Failed to trash");
+        assert!(service.trash(TrashKind::Document, "doc-1").is_err());
+
+        let trashed = service.list(Some("camp-1")).expect("Failed to list");
+        assert_eq!(trashed.len(), 2);
+        assert!(trashed.iter().all(|i| !i.is_expired(Utc::now())));
+
+        service.restore(TrashKind::Document, "doc-1").expect("Failed to restore");
+        assert!(service.restore(TrashKind::Document, "doc-1").is_err());
+        assert_eq!(service.list(None).unwrap().len(), 1);
+
+        drop(service);
+        assert_eq!(dal::list_campaign_documents(&mut conn, "camp-1").unwrap().len(), 1);
+        assert!(dal::list_npcs(&mut conn, "camp-1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_trash_map_quarantines_files() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        let temp_dir = TempDir::new().unwrap();
+
+        let input = CreateMapInput::for_campaign("camp-1", "Cave", "cave.uvtt", b"uvtt".to_vec());
+        let map = MapService::new(&mut conn, temp_dir.path())
+            .create(input)
+            .expect("Failed to create map");
+        let asset = dal::get_campaign_asset(&mut conn, &map.uvtt_asset_id).unwrap();
+        let original = temp_dir.path().join(&asset.blob_path);
+        let quarantined = temp_dir.path().join(TRASH_DIR).join(&asset.blob_path);
+        assert!(original.exists());
+
+        let mut service = TrashService::new(&mut conn, temp_dir.path());
+        service.trash(TrashKind::Map, &map.id).expect("Failed to trash");
+        assert!(!original.exists());
+        assert!(quarantined.exists());
+
+        service.restore(TrashKind::Map, &map.id).expect("Failed to restore");
+        assert!(original.exists());
+        assert!(!quarantined.exists());
+
+        service.trash(TrashKind::Map, &map.id).expect("Failed to trash");
+        assert_eq!(service.purge(None, false).expect("Failed to purge"), 1);
+        assert!(!quarantined.exists());
+
+        drop(service);
+        assert!(dal::get_map_optional(&mut conn, &map.id).unwrap().is_none());
+        assert!(dal::get_campaign_asset_optional(&mut conn, &asset.id)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_purge_expired_only() {
+        let mut conn = setup_test_db();
+        setup(&mut conn);
+        dal::trash_document(&mut conn, "doc-1", "2020-01-01T00:00:00+00:00").unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let mut service = TrashService::new(&mut conn, temp_dir.path());
+        service.trash(TrashKind::Character, "npc-1").unwrap();
+
+        assert_eq!(service.purge(None, true).expect("Failed to purge"), 1);
+        let remaining = service.list(None).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].kind, TrashKind::Character);
+
+        drop(service);
+        assert!(dal::get_document_optional(&mut conn, "doc-1").unwrap().is_none());
+    }
+}
@@ -0,0 +1,431 @@
+//! Overland Travel
+//!
+//! Travel planning from the PHB and DMG: travel pace, difficult terrain,
+//! forced marches, random encounter checks, and food and water. The pace
+//! table assumes a walking speed of 30 feet; a party moves at the speed of
+//! its slowest member, so other speeds scale the table.
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::{ServiceError, ServiceResult};
+
+/// Walking speed the travel pace table is written for.
+const BASE_SPEED: i32 = 30;
+
+/// Hours a party can travel in a day before it becomes a forced march.
+const MARCH_HOURS: i32 = 8;
+
+/// Default hours between random encounter checks.
+const DEFAULT_CHECK_HOURS: i32 = 8;
+
+/// Lowest d20 roll that causes a random encounter.
+const ENCOUNTER_ON: i32 = 18;
+
+/// How fast a party travels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TravelPace {
+    /// 4 miles per hour; -5 to passive Wisdom (Perception)
+    Fast,
+    /// 3 miles per hour
+    #[default]
+    Normal,
+    /// 2 miles per hour; the party can move stealthily
+    Slow,
+}
+
+impl TravelPace {
+    /// Miles per hour at a walking speed of 30 feet.
+    pub fn miles_per_hour(self) -> f64 {
+        match self {
+            TravelPace::Fast => 4.0,
+            TravelPace::Normal => 3.0,
+            TravelPace::Slow => 2.0,
+        }
+    }
+}
+
+/// The ground a party travels over.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Terrain {
+    Road,
+    /// Grassland, plains, and farmland
+    #[default]
+    Open,
+    Hills,
+    Desert,
+    /// Difficult terrain
+    Forest,
+    /// Difficult terrain
+    Jungle,
+    /// Difficult terrain
+    Swamp,
+    /// Difficult terrain
+    Mountains,
+    /// Difficult terrain
+    Arctic,
+}
+
+impl Terrain {
+    /// Whether the terrain halves travel distance.
+    pub fn is_difficult(self) -> bool {
+        matches!(
+            self,
+            Terrain::Forest
+                | Terrain::Jungle
+                | Terrain::Swamp
+                | Terrain::Mountains
+                | Terrain::Arctic
+        )
+    }
+}
+
+/// Weather during the journey.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    /// Heavy rain or snowfall; disadvantage on sight-based Perception
+    HeavyPrecipitation,
+    /// Deep snow, which is difficult terrain
+    HeavySnow,
+    /// Disadvantage on ranged attacks and hearing-based Perception
+    StrongWind,
+    /// 100°F or hotter; travelers need twice as much water
+    ExtremeHeat,
+    /// 0°F or colder
+    ExtremeCold,
+}
+
+/// A journey to plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TravelRequest {
+    /// Distance to travel, in miles
+    pub distance_miles: f64,
+    /// Walking speed of each traveler in feet; the slowest sets the pace.
+    /// Empty means everyone has a speed of 30.
+    #[serde(default)]
+    pub speeds: Vec<i32>,
+    /// Creatures eating and drinking (defaults to the number of speeds, or 1)
+    #[serde(default)]
+    pub party_size: Option<i32>,
+    #[serde(default)]
+    pub pace: TravelPace,
+    #[serde(default)]
+    pub terrain: Terrain,
+    #[serde(default)]
+    pub weather: Weather,
+    /// Hours traveled each day (defaults to 8; more is a forced march)
+    #[serde(default)]
+    pub hours_per_day: Option<i32>,
+    /// Hours between random encounter checks (defaults to 8)
+    #[serde(default)]
+    pub encounter_check_hours: Option<i32>,
+}
+
+/// When a random encounter check happens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TravelPhase {
+    Travel,
+    Rest,
+}
+
+/// A random encounter check on a day of travel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterCheck {
+    /// Day of the journey, starting at 1
+    pub day: i32,
+    /// Hours since the party set out that day
+    pub hour: i32,
+    pub phase: TravelPhase,
+}
+
+/// A planned journey.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TravelPlan {
+    pub distance_miles: f64,
+    pub pace: TravelPace,
+    pub terrain: Terrain,
+    pub weather: Weather,
+    /// Walking speed of the slowest traveler, in feet
+    pub speed: i32,
+    pub miles_per_hour: f64,
+    pub hours_per_day: i32,
+    pub miles_per_day: f64,
+    /// Total hours on the move
+    pub travel_hours: f64,
+    /// Days on the road, counting a partial last day as a full one
+    pub days: i32,
+    /// Constitution save DCs for each hour past 8 on a full day of travel;
+    /// a failed save costs a level of exhaustion
+    pub forced_march_dcs: Vec<i32>,
+    pub encounter_checks: Vec<EncounterCheck>,
+    /// Chance that a single check causes an encounter
+    pub encounter_chance: f64,
+    /// Average number of encounters over the journey
+    pub expected_encounters: f64,
+    pub party_size: i32,
+    /// Days of rations (one pound of food per creature per day)
+    pub rations: i32,
+    /// Gallons of water (one per creature per day, two in extreme heat)
+    pub water_gallons: i32,
+    /// Rules reminders for the pace, terrain, and weather
+    pub notes: Vec<String>,
+}
+
+/// Plan a journey.
+pub fn plan_travel(request: &TravelRequest) -> ServiceResult<TravelPlan> {
+    if !request.distance_miles.is_finite() || request.distance_miles <= 0.0 {
+        return Err(ServiceError::validation(format!(
+            "Distance must be more than 0 miles, got {}",
+            request.distance_miles
+        )));
+    }
+    if let Some(speed) = request.speeds.iter().find(|s| **s <= 0) {
+        return Err(ServiceError::validation(format!(
+            "Walking speed must be more than 0 feet, got {}",
+            speed
+        )));
+    }
+    let hours_per_day = request.hours_per_day.unwrap_or(MARCH_HOURS);
+    if !(1..=24).contains(&hours_per_day) {
+        return Err(ServiceError::validation(format!(
+            "Hours per day must be 1-24, got {}",
+            hours_per_day
+        )));
+    }
+    let check_hours = request.encounter_check_hours.unwrap_or(DEFAULT_CHECK_HOURS);
+    if !(1..=24).contains(&check_hours) {
+        return Err(ServiceError::validation(format!(
+            "Hours between encounter checks must be 1-24, got {}",
+            check_hours
+        )));
+    }
+    let party_size = request
+        .party_size
+        .unwrap_or(request.speeds.len().max(1) as i32);
+    if party_size < 1 {
+        return Err(ServiceError::validation(format!(
+            "Party size must be at least 1, got {}",
+            party_size
+        )));
+    }
+
+    let speed = request.speeds.iter().copied().min().unwrap_or(BASE_SPEED);
+    // Difficult terrain halves the distance; deep snow doesn't stack with it
+    let terrain_factor = if request.terrain.is_difficult() || request.weather == Weather::HeavySnow
+    {
+        0.5
+    } else {
+        1.0
+    };
+    let miles_per_hour =
+        request.pace.miles_per_hour() * speed as f64 / BASE_SPEED as f64 * terrain_factor;
+    let miles_per_day = miles_per_hour * hours_per_day as f64;
+    let travel_hours = request.distance_miles / miles_per_hour;
+    let days = (request.distance_miles / miles_per_day).ceil() as i32;
+
+    // Only full days run past 8 hours; a short last day may not
+    let forced_march_dcs = (MARCH_HOURS + 1..=hours_per_day)
+        .map(|hour| 10 + hour - MARCH_HOURS)
+        .collect();
+
+    let mut encounter_checks = Vec::new();
+    for day in 1..=days {
+        let hours_today = if day < days {
+            hours_per_day as f64
+        } else {
+            travel_hours - (days - 1) as f64 * hours_per_day as f64
+        };
+        // The journey ends on arrival, so the last day has no rest checks
+        let day_length = if day < days { 24.0 } else { hours_today };
+        let mut hour = check_hours;
+        while hour as f64 <= day_length + 1e-9 {
+            let phase = if hour as f64 <= hours_today + 1e-9 {
+                TravelPhase::Travel
+            } else {
+                TravelPhase::Rest
+            };
+            encounter_checks.push(EncounterCheck { day, hour, phase });
+            hour += check_hours;
+        }
+    }
+    let encounter_chance = (21 - ENCOUNTER_ON) as f64 / 20.0;
+    let expected_encounters = encounter_checks.len() as f64 * encounter_chance;
+
+    let water_per_day = if request.weather == Weather::ExtremeHeat {
+        2
+    } else {
+        1
+    };
+
+    Ok(TravelPlan {
+        distance_miles: request.distance_miles,
+        pace: request.pace,
+        terrain: request.terrain,
+        weather: request.weather,
+        speed,
+        miles_per_hour,
+        hours_per_day,
+        miles_per_day,
+        travel_hours,
+        days,
+        forced_march_dcs,
+        encounter_checks,
+        encounter_chance,
+        expected_encounters,
+        party_size,
+        rations: party_size * days,
+        water_gallons: party_size * days * water_per_day,
+        notes: travel_notes(request, hours_per_day),
+    })
+}
+
+fn travel_notes(request: &TravelRequest, hours_per_day: i32) -> Vec<String> {
+    let mut notes = Vec::new();
+    match request.pace {
+        TravelPace::Fast => {
+            notes.push("Fast pace: -5 to passive Wisdom (Perception) scores".to_string())
+        }
+        TravelPace::Normal => {}
+        TravelPace::Slow => notes.push("Slow pace: the party can use stealth".to_string()),
+    }
+    if hours_per_day > MARCH_HOURS {
+        notes.push(format!(
+            "Forced march: after hour {}, each traveler makes a Constitution save at the end \
+             of every hour or gains a level of exhaustion",
+            MARCH_HOURS
+        ));
+    }
+    if request.terrain.is_difficult() {
+        notes.push("Difficult terrain halves the distance covered".to_string());
+    }
+    let weather = match request.weather {
+        Weather::Clear | Weather::Rain => None,
+        Weather::HeavyPrecipitation => Some(
+            "Heavy precipitation: lightly obscured; disadvantage on sight-based Perception checks",
+        ),
+        Weather::HeavySnow => {
+            Some("Deep snow is difficult terrain and halves the distance covered")
+        }
+        Weather::StrongWind => Some(
+            "Strong wind: disadvantage on ranged weapon attacks and hearing-based Perception \
+             checks; open flames are extinguished",
+        ),
+        Weather::ExtremeHeat => Some(
+            "Extreme heat: without water, DC 5 Constitution save each hour (+1 per hour) or \
+             gain a level of exhaustion",
+        ),
+        Weather::ExtremeCold => Some(
+            "Extreme cold: without cold weather gear, DC 10 Constitution save each hour or \
+             gain a level of exhaustion",
+        ),
+    };
+    notes.extend(weather.map(String::from));
+    notes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(distance_miles: f64) -> TravelRequest {
+        TravelRequest {
+            distance_miles,
+            speeds: Vec::new(),
+            party_size: None,
+            pace: TravelPace::Normal,
+            terrain: Terrain::Open,
+            weather: Weather::Clear,
+            hours_per_day: None,
+            encounter_check_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_normal_pace_covers_24_miles_a_day() {
+        let plan = plan_travel(&request(60.0)).expect("Failed to plan");
+        assert_eq!(plan.miles_per_day, 24.0);
+        assert_eq!(plan.travel_hours, 20.0);
+        assert_eq!(plan.days, 3);
+        assert_eq!(plan.rations, 3);
+        assert!(plan.forced_march_dcs.is_empty());
+    }
+
+    #[test]
+    fn test_slowest_speed_and_terrain_slow_the_party() {
+        let plan = plan_travel(&TravelRequest {
+            speeds: vec![30, 25, 30, 30],
+            terrain: Terrain::Forest,
+            pace: TravelPace::Fast,
+            ..request(20.0)
+        })
+        .expect("Failed to plan");
+        assert_eq!(plan.speed, 25);
+        // 4 mph scaled by 25/30 and halved by difficult terrain
+        assert!((plan.miles_per_hour - 5.0 / 3.0).abs() < 1e-9);
+        assert_eq!(plan.days, 2);
+        assert_eq!(plan.party_size, 4);
+        assert_eq!(plan.rations, 8);
+        assert!(plan.notes.iter().any(|n| n.contains("passive")));
+    }
+
+    #[test]
+    fn test_forced_march_and_heat() {
+        let plan = plan_travel(&TravelRequest {
+            hours_per_day: Some(10),
+            weather: Weather::ExtremeHeat,
+            party_size: Some(3),
+            ..request(30.0)
+        })
+        .expect("Failed to plan");
+        assert_eq!(plan.forced_march_dcs, vec![11, 12]);
+        assert_eq!(plan.days, 1);
+        assert_eq!(plan.water_gallons, 6);
+    }
+
+    #[test]
+    fn test_encounter_check_schedule() {
+        // 36 miles at 24 a day: a full day, then 4 hours on the second
+        let plan = plan_travel(&TravelRequest {
+            encounter_check_hours: Some(4),
+            ..request(36.0)
+        })
+        .expect("Failed to plan");
+        let day_one: Vec<_> = plan
+            .encounter_checks
+            .iter()
+            .filter(|c| c.day == 1)
+            .collect();
+        assert_eq!(day_one.len(), 6);
+        assert_eq!(day_one[1].phase, TravelPhase::Travel);
+        assert_eq!(day_one[2].phase, TravelPhase::Rest);
+
+        let day_two: Vec<_> = plan
+            .encounter_checks
+            .iter()
+            .filter(|c| c.day == 2)
+            .collect();
+        assert_eq!(day_two.len(), 1);
+        assert!((plan.expected_encounters - 7.0 * 0.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert!(plan_travel(&request(0.0)).is_err());
+        assert!(plan_travel(&TravelRequest {
+            speeds: vec![30, 0],
+            ..request(10.0)
+        })
+        .is_err());
+        assert!(plan_travel(&TravelRequest {
+            hours_per_day: Some(25),
+            ..request(10.0)
+        })
+        .is_err());
+    }
+}
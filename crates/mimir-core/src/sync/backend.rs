@@ -0,0 +1,622 @@
+//! Sync Backends
+//!
+//! Where synced campaigns are stored: a folder (a network share or a folder
+//! another sync client already mirrors), a WebDAV server, or an
+//! S3-compatible bucket. Backends only store and fetch blobs by key; the
+//! sync logic lives in [`super::SyncService`].
+
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::services::{ServiceError, ServiceResult};
+
+/// A blob and the version it was read at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedBlob {
+    pub data: Vec<u8>,
+    /// The server's ETag, or a hash of the content where there is none
+    pub version: String,
+}
+
+/// Blob storage a campaign is synced through.
+#[async_trait]
+pub trait SyncBackend: Send + Sync {
+    /// Fetch a blob, or `None` if there is none under `key`.
+    async fn get(&self, key: &str) -> ServiceResult<Option<Vec<u8>>>;
+
+    /// Fetch a blob with its version, for a later [`SyncBackend::put_if`].
+    async fn get_versioned(&self, key: &str) -> ServiceResult<Option<VersionedBlob>>;
+
+    /// Store a blob, replacing any blob under `key`.
+    async fn put(&self, key: &str, data: Vec<u8>) -> ServiceResult<()>;
+
+    /// Store a blob only if the blob under `key` is still at `expected`, or
+    /// there is still none when `expected` is `None`. Returns `false`, storing
+    /// nothing, if another device replaced it in the meantime.
+    async fn put_if(&self, key: &str, data: Vec<u8>, expected: Option<&str>)
+        -> ServiceResult<bool>;
+
+    /// Whether a blob exists under `key`.
+    async fn exists(&self, key: &str) -> ServiceResult<bool>;
+}
+
+/// User-configured sync backend.
+///
+/// Passwords and secret keys are not part of the configuration; they are
+/// kept in the OS keychain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SyncBackendConfig {
+    /// A local or mounted folder
+    Folder { path: PathBuf },
+    /// A WebDAV collection, e.g. `https://cloud.example.com/remote.php/dav/files/me/mimir/`
+    WebDav { url: String, username: String },
+    /// An S3-compatible bucket, addressed path-style
+    S3 {
+        /// e.g. `https://s3.eu-west-1.amazonaws.com` or a MinIO server
+        endpoint: String,
+        region: String,
+        bucket: String,
+        access_key_id: String,
+        /// Key prefix inside the bucket, e.g. `mimir/`
+        #[serde(default)]
+        prefix: String,
+    },
+}
+
+impl SyncBackendConfig {
+    /// Whether the backend needs a password or secret key.
+    pub fn needs_secret(&self) -> bool {
+        !matches!(self, Self::Folder { .. })
+    }
+
+    /// Create the backend, using `secret` as the WebDAV password or S3
+    /// secret access key.
+    pub fn connect(&self, secret: Option<String>) -> ServiceResult<Box<dyn SyncBackend>> {
+        let require_secret = || {
+            secret
+                .clone()
+                .ok_or_else(|| ServiceError::validation("The sync backend needs a password"))
+        };
+        let backend: Box<dyn SyncBackend> = match self {
+            Self::Folder { path } => Box::new(FolderBackend::new(path.clone())),
+            Self::WebDav { url, username } => {
+                Box::new(WebDavBackend::new(url, username, &require_secret()?)?)
+            }
+            Self::S3 {
+                endpoint,
+                region,
+                bucket,
+                access_key_id,
+                prefix,
+            } => Box::new(S3Backend::new(
+                endpoint,
+                region,
+                bucket,
+                prefix,
+                access_key_id,
+                &require_secret()?,
+            )?),
+        };
+        Ok(backend)
+    }
+}
+
+// =============================================================================
+// Folder
+// =============================================================================
+
+/// Blobs as files under a root folder.
+pub struct FolderBackend {
+    root: PathBuf,
+}
+
+impl FolderBackend {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+}
+
+#[async_trait]
+impl SyncBackend for FolderBackend {
+    async fn get(&self, key: &str) -> ServiceResult<Option<Vec<u8>>> {
+        match tokio::fs::read(self.root.join(key)).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(ServiceError::Io(e)),
+        }
+    }
+
+    async fn get_versioned(&self, key: &str) -> ServiceResult<Option<VersionedBlob>> {
+        Ok(self.get(key).await?.map(content_version))
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> ServiceResult<()> {
+        let path = self.root.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        // Write beside the target and rename, so other devices never read a
+        // half-written blob
+        let mut temp_name = path.as_os_str().to_owned();
+        temp_name.push(".partial");
+        tokio::fs::write(&temp_name, data).await?;
+        tokio::fs::rename(&temp_name, &path).await?;
+        Ok(())
+    }
+
+    async fn put_if(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        expected: Option<&str>,
+    ) -> ServiceResult<bool> {
+        // Folders have no conditional writes; re-reading narrows the window
+        // to the write itself
+        let current = self.get_versioned(key).await?;
+        if current.as_ref().map(|blob| blob.version.as_str()) != expected {
+            return Ok(false);
+        }
+        self.put(key, data).await?;
+        Ok(true)
+    }
+
+    async fn exists(&self, key: &str) -> ServiceResult<bool> {
+        Ok(tokio::fs::try_exists(self.root.join(key)).await?)
+    }
+}
+
+// =============================================================================
+// WebDAV
+// =============================================================================
+
+/// Blobs as resources under a WebDAV collection.
+pub struct WebDavBackend {
+    client: Client,
+    base: Url,
+    username: String,
+    password: String,
+    /// Collections already created this session
+    collections: Mutex<HashSet<String>>,
+}
+
+impl WebDavBackend {
+    pub fn new(url: &str, username: &str, password: &str) -> ServiceResult<Self> {
+        let mut url = url.to_string();
+        if !url.ends_with('/') {
+            url.push('/');
+        }
+        Ok(Self {
+            client: Client::new(),
+            base: parse_url(&url)?,
+            username: username.to_string(),
+            password: password.to_string(),
+            collections: Mutex::new(HashSet::new()),
+        })
+    }
+
+    fn request(&self, method: Method, path: &str) -> ServiceResult<RequestBuilder> {
+        let url = self
+            .base
+            .join(path)
+            .map_err(|e| ServiceError::validation(format!("Invalid sync key '{}': {}", path, e)))?;
+        Ok(self
+            .client
+            .request(method, url)
+            .basic_auth(&self.username, Some(&self.password)))
+    }
+
+    /// Create the collections above `key` that don't exist yet.
+    async fn create_collections(&self, key: &str) -> ServiceResult<()> {
+        let mut path = String::new();
+        let Some((dirs, _)) = key.rsplit_once('/') else {
+            return Ok(());
+        };
+        for dir in dirs.split('/') {
+            path.push_str(dir);
+            path.push('/');
+            if self.collections.lock().unwrap().contains(&path) {
+                continue;
+            }
+            let mkcol = Method::from_bytes(b"MKCOL").expect("MKCOL is a valid method");
+            let response = send(self.request(mkcol, &path)?).await?;
+            // 405 means the collection already exists
+            if !response.status().is_success()
+                && response.status() != StatusCode::METHOD_NOT_ALLOWED
+            {
+                return Err(http_error("MKCOL", &path, response.status()));
+            }
+            self.collections.lock().unwrap().insert(path.clone());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SyncBackend for WebDavBackend {
+    async fn get(&self, key: &str) -> ServiceResult<Option<Vec<u8>>> {
+        read_body(send(self.request(Method::GET, key)?).await?, key).await
+    }
+
+    async fn get_versioned(&self, key: &str) -> ServiceResult<Option<VersionedBlob>> {
+        read_versioned_body(send(self.request(Method::GET, key)?).await?, key).await
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> ServiceResult<()> {
+        self.create_collections(key).await?;
+        let response = send(self.request(Method::PUT, key)?.body(data)).await?;
+        if !response.status().is_success() {
+            return Err(http_error("PUT", key, response.status()));
+        }
+        Ok(())
+    }
+
+    async fn put_if(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        expected: Option<&str>,
+    ) -> ServiceResult<bool> {
+        if !check_content_version(self, key, expected).await? {
+            return Ok(false);
+        }
+        self.create_collections(key).await?;
+        let request = with_precondition(self.request(Method::PUT, key)?, expected);
+        conditional_put_result(send(request.body(data)).await?, key)
+    }
+
+    async fn exists(&self, key: &str) -> ServiceResult<bool> {
+        head_exists(send(self.request(Method::HEAD, key)?).await?, key)
+    }
+}
+
+// =============================================================================
+// S3
+// =============================================================================
+
+/// Blobs as objects in an S3-compatible bucket, signed with AWS Signature
+/// Version 4.
+pub struct S3Backend {
+    client: Client,
+    endpoint: Url,
+    region: String,
+    bucket: String,
+    prefix: String,
+    access_key_id: String,
+    secret_access_key: String,
+}
+
+impl S3Backend {
+    pub fn new(
+        endpoint: &str,
+        region: &str,
+        bucket: &str,
+        prefix: &str,
+        access_key_id: &str,
+        secret_access_key: &str,
+    ) -> ServiceResult<Self> {
+        Ok(Self {
+            client: Client::new(),
+            endpoint: parse_url(endpoint)?,
+            region: region.to_string(),
+            bucket: bucket.to_string(),
+            prefix: prefix.to_string(),
+            access_key_id: access_key_id.to_string(),
+            secret_access_key: secret_access_key.to_string(),
+        })
+    }
+
+    fn request(&self, method: Method, key: &str, body: &[u8]) -> ServiceResult<RequestBuilder> {
+        let path = format!(
+            "/{}/{}",
+            uri_encode(&self.bucket),
+            uri_encode(&format!("{}{}", self.prefix, key))
+        );
+        let mut url = self.endpoint.clone();
+        url.set_path(&path);
+
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(ServiceError::validation("The S3 endpoint has no host")),
+        };
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex(&Sha256::digest(body));
+
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method, path, host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let mut key = hmac_sha256(
+            format!("AWS4{}", self.secret_access_key).as_bytes(),
+            date.as_bytes(),
+        );
+        for part in [self.region.as_str(), "s3", "aws4_request"] {
+            key = hmac_sha256(&key, part.as_bytes());
+        }
+        let signature = hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+
+        Ok(self
+            .client
+            .request(method, url)
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header(
+                "authorization",
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                    self.access_key_id, scope, signed_headers, signature
+                ),
+            ))
+    }
+}
+
+#[async_trait]
+impl SyncBackend for S3Backend {
+    async fn get(&self, key: &str) -> ServiceResult<Option<Vec<u8>>> {
+        read_body(send(self.request(Method::GET, key, &[])?).await?, key).await
+    }
+
+    async fn get_versioned(&self, key: &str) -> ServiceResult<Option<VersionedBlob>> {
+        read_versioned_body(send(self.request(Method::GET, key, &[])?).await?, key).await
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>) -> ServiceResult<()> {
+        let request = self.request(Method::PUT, key, &data)?.body(data);
+        let response = send(request).await?;
+        if !response.status().is_success() {
+            return Err(http_error("PUT", key, response.status()));
+        }
+        Ok(())
+    }
+
+    async fn put_if(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        expected: Option<&str>,
+    ) -> ServiceResult<bool> {
+        if !check_content_version(self, key, expected).await? {
+            return Ok(false);
+        }
+        let request = with_precondition(self.request(Method::PUT, key, &data)?, expected);
+        conditional_put_result(send(request.body(data)).await?, key)
+    }
+
+    async fn exists(&self, key: &str) -> ServiceResult<bool> {
+        head_exists(send(self.request(Method::HEAD, key, &[])?).await?, key)
+    }
+}
+
+/// Percent-encode a path for S3, leaving `/` between segments.
+fn uri_encode(path: &str) -> String {
+    let mut encoded = String::new();
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// HMAC-SHA256 (RFC 2104).
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// =============================================================================
+// Versions
+// =============================================================================
+
+/// Prefix of versions made from the content, for servers that send no ETag
+const CONTENT_VERSION_PREFIX: &str = "sha256:";
+
+/// Version a blob by its content.
+fn content_version(data: Vec<u8>) -> VersionedBlob {
+    let version = format!("{}{}", CONTENT_VERSION_PREFIX, hex(&Sha256::digest(&data)));
+    VersionedBlob { data, version }
+}
+
+/// Servers that sent no ETag can't check one, so the blob is re-read and
+/// compared instead. Blobs versioned by ETag are checked by the server.
+async fn check_content_version<B: SyncBackend + ?Sized>(
+    backend: &B,
+    key: &str,
+    expected: Option<&str>,
+) -> ServiceResult<bool> {
+    match expected {
+        Some(version) if version.starts_with(CONTENT_VERSION_PREFIX) => {
+            let current = backend.get(key).await?.map(content_version);
+            Ok(current.is_some_and(|blob| blob.version == version))
+        }
+        _ => Ok(true),
+    }
+}
+
+// =============================================================================
+// HTTP helpers
+// =============================================================================
+
+fn parse_url(url: &str) -> ServiceResult<Url> {
+    Url::parse(url).map_err(|e| ServiceError::validation(format!("Invalid URL '{}': {}", url, e)))
+}
+
+async fn send(request: RequestBuilder) -> ServiceResult<Response> {
+    request.send().await.map_err(|e| {
+        ServiceError::Io(std::io::Error::other(format!(
+            "Sync backend request failed: {}",
+            e
+        )))
+    })
+}
+
+async fn read_body(response: Response, key: &str) -> ServiceResult<Option<Vec<u8>>> {
+    match response.status() {
+        StatusCode::NOT_FOUND => Ok(None),
+        status if status.is_success() => {
+            let body = response.bytes().await.map_err(|e| {
+                ServiceError::Io(std::io::Error::other(format!(
+                    "Failed to download {}: {}",
+                    key, e
+                )))
+            })?;
+            Ok(Some(body.to_vec()))
+        }
+        status => Err(http_error("GET", key, status)),
+    }
+}
+
+async fn read_versioned_body(
+    response: Response,
+    key: &str,
+) -> ServiceResult<Option<VersionedBlob>> {
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    Ok(read_body(response, key).await?.map(|data| match etag {
+        Some(version) => VersionedBlob { data, version },
+        None => content_version(data),
+    }))
+}
+
+/// Make a PUT conditional on the blob's ETag, or on there being no blob.
+fn with_precondition(request: RequestBuilder, expected: Option<&str>) -> RequestBuilder {
+    match expected {
+        None => request.header(IF_NONE_MATCH, "*"),
+        Some(version) if version.starts_with(CONTENT_VERSION_PREFIX) => request,
+        Some(etag) => request.header(IF_MATCH, etag),
+    }
+}
+
+fn conditional_put_result(response: Response, key: &str) -> ServiceResult<bool> {
+    match response.status() {
+        // S3 answers 409 when a concurrent conditional write won
+        StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT => Ok(false),
+        status if status.is_success() => Ok(true),
+        status => Err(http_error("PUT", key, status)),
+    }
+}
+
+fn head_exists(response: Response, key: &str) -> ServiceResult<bool> {
+    match response.status() {
+        StatusCode::NOT_FOUND => Ok(false),
+        status if status.is_success() => Ok(true),
+        status => Err(http_error("HEAD", key, status)),
+    }
+}
+
+fn http_error(method: &str, key: &str, status: StatusCode) -> ServiceError {
+    ServiceError::Io(std::io::Error::other(format!(
+        "Sync backend {} {} failed: {}",
+        method, key, status
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hmac_sha256() {
+        // RFC 4231 test case 2
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex(&mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_uri_encode() {
+        assert_eq!(
+            uri_encode("mimir/campaigns/a b+c.json"),
+            "mimir/campaigns/a%20b%2Bc.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_folder_backend() {
+        let dir = TempDir::new().unwrap();
+        let backend = FolderBackend::new(dir.path().to_path_buf());
+
+        assert_eq!(
+            backend.get("campaigns/a/manifest.json").await.unwrap(),
+            None
+        );
+        assert!(!backend.exists("campaigns/a/manifest.json").await.unwrap());
+
+        backend
+            .put("campaigns/a/manifest.json", b"{}".to_vec())
+            .await
+            .unwrap();
+        assert!(backend.exists("campaigns/a/manifest.json").await.unwrap());
+        assert_eq!(
+            backend.get("campaigns/a/manifest.json").await.unwrap(),
+            Some(b"{}".to_vec())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_folder_backend_conditional_put() {
+        let dir = TempDir::new().unwrap();
+        let backend = FolderBackend::new(dir.path().to_path_buf());
+        let key = "campaigns/a/manifest.json";
+
+        assert!(backend.put_if(key, b"1".to_vec(), None).await.unwrap());
+        assert!(!backend.put_if(key, b"2".to_vec(), None).await.unwrap());
+
+        let first = backend.get_versioned(key).await.unwrap().unwrap();
+        assert!(backend
+            .put_if(key, b"2".to_vec(), Some(&first.version))
+            .await
+            .unwrap());
+        // Another device already replaced the version this one read
+        assert!(!backend
+            .put_if(key, b"3".to_vec(), Some(&first.version))
+            .await
+            .unwrap());
+        assert_eq!(backend.get(key).await.unwrap(), Some(b"2".to_vec()));
+    }
+}
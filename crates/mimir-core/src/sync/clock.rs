@@ -0,0 +1,91 @@
+//! Vector Clocks
+//!
+//! Each device counts the changes it has pushed for a campaign. Comparing the
+//! counts a device last saw with the counts on the remote tells whether the
+//! remote moved on, and whether it moved on concurrently with local edits.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How two vector clocks relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockOrdering {
+    Equal,
+    /// Every change in the first clock is in the second, plus more
+    Before,
+    /// Every change in the second clock is in the first, plus more
+    After,
+    /// Each clock has changes the other lacks
+    Concurrent,
+}
+
+/// Pushed change counts by device ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VectorClock(BTreeMap<String, u64>);
+
+impl VectorClock {
+    /// Changes pushed by a device.
+    pub fn get(&self, device_id: &str) -> u64 {
+        self.0.get(device_id).copied().unwrap_or(0)
+    }
+
+    /// Record a change pushed by a device.
+    pub fn increment(&mut self, device_id: &str) {
+        *self.0.entry(device_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Take the highest count of each device from both clocks.
+    pub fn merge(&mut self, other: &VectorClock) {
+        for (device_id, &count) in &other.0 {
+            let entry = self.0.entry(device_id.clone()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+    }
+
+    /// Whether every change in this clock is also in `other`.
+    pub fn is_covered_by(&self, other: &VectorClock) -> bool {
+        self.0
+            .iter()
+            .all(|(device_id, &count)| count <= other.get(device_id))
+    }
+
+    /// Compare this clock with another.
+    pub fn compare(&self, other: &VectorClock) -> ClockOrdering {
+        match (self.is_covered_by(other), other.is_covered_by(self)) {
+            (true, true) => ClockOrdering::Equal,
+            (true, false) => ClockOrdering::Before,
+            (false, true) => ClockOrdering::After,
+            (false, false) => ClockOrdering::Concurrent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let mut desktop = VectorClock::default();
+        let mut laptop = VectorClock::default();
+        assert_eq!(desktop.compare(&laptop), ClockOrdering::Equal);
+
+        desktop.increment("desktop");
+        assert_eq!(desktop.compare(&laptop), ClockOrdering::After);
+        assert_eq!(laptop.compare(&desktop), ClockOrdering::Before);
+
+        laptop.merge(&desktop);
+        assert_eq!(laptop.compare(&desktop), ClockOrdering::Equal);
+
+        // Both push without seeing the other's change
+        desktop.increment("desktop");
+        laptop.increment("laptop");
+        assert_eq!(desktop.compare(&laptop), ClockOrdering::Concurrent);
+
+        laptop.merge(&desktop);
+        assert_eq!(laptop.get("desktop"), 2);
+        assert_eq!(laptop.get("laptop"), 1);
+        assert_eq!(desktop.compare(&laptop), ClockOrdering::Before);
+    }
+}
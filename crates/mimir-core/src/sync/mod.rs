@@ -0,0 +1,30 @@
+//! Campaign Sync
+//!
+//! Keeps one campaign in step across devices, such as a desktop and a laptop,
+//! through a user-configured backend: a folder, a WebDAV server, or an
+//! S3-compatible bucket.
+//!
+//! # Architecture
+//!
+//! - `backend` - Blob storage the campaign is pushed to and pulled from
+//! - `clock` - Vector clocks telling whether the remote moved on since the
+//!   last sync, and whether it did so concurrently with local edits
+//! - `snapshot` - A campaign's database rows and asset files, read and
+//!   written generically per table
+//! - `service` - Push, pull, three-way merge, and conflict resolution
+//!
+//! Each sync compares this device's rows and files with the remote version
+//! and with the version both had at the last sync. Changes made on one side
+//! are taken as they are; rows or files changed differently on both sides
+//! are reported as conflicts and settled with
+//! [`SyncService::resolve_conflicts`].
+
+mod backend;
+mod clock;
+mod service;
+mod snapshot;
+
+pub use backend::*;
+pub use clock::*;
+pub use service::*;
+pub use snapshot::*;
@@ -0,0 +1,938 @@
+//! Sync Service
+//!
+//! Pushes and pulls campaigns through the configured backend and merges
+//! changes made on different devices.
+
+use chrono::{DateTime, Utc};
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use super::backend::{SyncBackend, SyncBackendConfig};
+use super::clock::{ClockOrdering, VectorClock};
+use super::snapshot::{apply_rows, asset_path, bytes_sha256, CampaignSnapshot, FILE_KEY_PREFIX};
+use crate::encryption::{self, keychain_error, KEYCHAIN_SERVICE};
+use crate::services::{ServiceError, ServiceResult, SettingsService};
+
+/// Directory under the app data directory holding sync state.
+pub const SYNC_DIR: &str = "sync";
+
+/// Campaigns on the remote, at the backend root
+const INDEX_KEY: &str = "campaigns.json";
+/// Keychain account of the backend password or secret key
+const KEYCHAIN_ACCOUNT: &str = "sync";
+/// Times to merge again when another device pushes mid-sync, before giving up
+const MAX_SYNC_ATTEMPTS: usize = 3;
+
+/// Sync settings for this device.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncConfig {
+    /// Random ID identifying this device in vector clocks
+    pub device_id: String,
+    /// Name shown to other devices, e.g. "Desktop"
+    pub device_name: String,
+    pub backend: SyncBackendConfig,
+}
+
+/// Which side of a conflict to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSide {
+    Local,
+    Remote,
+}
+
+/// A row or file changed differently on this device and on the remote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConflict {
+    /// Row key (`<table>/<primary key>`) or `file/<path>`
+    pub key: String,
+    /// Table name, or "file"
+    pub table: String,
+    /// Name or title of the row, when it has one
+    pub label: Option<String>,
+    /// This device's version; `None` if deleted here
+    pub local: Option<Value>,
+    /// The remote version; `None` if deleted on the remote
+    pub remote: Option<Value>,
+}
+
+/// How to settle one conflict.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictResolution {
+    pub key: String,
+    pub keep: ConflictSide,
+}
+
+/// What a sync did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncOutcome {
+    /// Nothing changed on either side
+    UpToDate,
+    /// Local changes were pushed
+    Pushed,
+    /// Remote changes were pulled
+    Pulled,
+    /// Remote changes were pulled and local changes pushed
+    Merged,
+    /// Nothing was changed; conflicts must be resolved first
+    Conflicts,
+}
+
+/// Result of syncing a campaign.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncReport {
+    pub campaign_id: String,
+    pub outcome: SyncOutcome,
+    /// Rows and files taken from the remote
+    pub pulled: usize,
+    /// Rows and files sent to the remote
+    pub pushed: usize,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+/// A campaign stored on the remote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteCampaign {
+    pub id: String,
+    pub name: String,
+    /// Name of the device that last pushed it
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Sync state of a campaign on this device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CampaignSyncState {
+    /// Remote clock as of the last sync
+    pub clock: VectorClock,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// Conflicts found by the last sync
+    pub conflicts: Vec<SyncConflict>,
+    /// Row and file hashes as of the last sync, the base both sides changed from
+    #[serde(default)]
+    base: BTreeMap<String, String>,
+}
+
+/// A campaign as pushed to the remote.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteManifest {
+    campaign_id: String,
+    campaign_name: String,
+    clock: VectorClock,
+    updated_by: String,
+    updated_at: DateTime<Utc>,
+    snapshot: CampaignSnapshot,
+}
+
+/// Service for syncing campaigns between devices.
+pub struct SyncService<'a> {
+    conn: &'a mut SqliteConnection,
+    settings: SettingsService,
+    sync_dir: PathBuf,
+    assets_dir: PathBuf,
+}
+
+impl<'a> SyncService<'a> {
+    /// Create a new sync service.
+    pub fn new(
+        conn: &'a mut SqliteConnection,
+        app_data_dir: impl AsRef<Path>,
+        assets_dir: impl AsRef<Path>,
+    ) -> Self {
+        Self {
+            conn,
+            settings: SettingsService::new(app_data_dir.as_ref()),
+            sync_dir: app_data_dir.as_ref().join(SYNC_DIR),
+            assets_dir: assets_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// The sync settings, or `None` if sync is not set up.
+    pub fn config(&self) -> ServiceResult<Option<SyncConfig>> {
+        Ok(self.settings.load()?.sync)
+    }
+
+    /// Set the backend campaigns are synced through.
+    ///
+    /// `secret` is the WebDAV password or S3 secret access key; it is stored
+    /// in the OS keychain. Leave it out to keep the stored one.
+    pub fn configure(
+        &self,
+        backend: SyncBackendConfig,
+        device_name: &str,
+        secret: Option<&str>,
+    ) -> ServiceResult<SyncConfig> {
+        if device_name.trim().is_empty() {
+            return Err(ServiceError::validation("Device name cannot be empty"));
+        }
+        match secret {
+            Some(secret) => keychain_entry()?
+                .set_password(secret)
+                .map_err(keychain_error)?,
+            None if backend.needs_secret() && read_secret()?.is_none() => {
+                return Err(ServiceError::validation(
+                    "The sync backend needs a password",
+                ))
+            }
+            None => {}
+        }
+
+        let mut settings = self.settings.load()?;
+        let config = SyncConfig {
+            device_id: match settings.sync {
+                Some(existing) => existing.device_id,
+                None => uuid::Uuid::new_v4().to_string(),
+            },
+            device_name: device_name.trim().to_string(),
+            backend,
+        };
+        settings.sync = Some(config.clone());
+        self.settings.save(&settings)?;
+        info!(device = %config.device_name, "Sync configured");
+        Ok(config)
+    }
+
+    /// Sync state of a campaign on this device.
+    pub fn campaign_state(&self, campaign_id: &str) -> ServiceResult<CampaignSyncState> {
+        Ok(read_json(&self.state_path(campaign_id))?.unwrap_or_default())
+    }
+
+    /// Campaigns stored on the remote, including ones not on this device yet.
+    pub async fn list_remote_campaigns(&self) -> ServiceResult<Vec<RemoteCampaign>> {
+        let (_, backend) = self.connect()?;
+        read_index(backend.as_ref()).await
+    }
+
+    /// Sync a campaign: pull remote changes and push local ones.
+    ///
+    /// A campaign only on the remote is created here. Rows or files changed
+    /// differently on both sides since the last sync are reported as
+    /// conflicts and nothing is changed until they are resolved.
+    pub async fn sync_campaign(&mut self, campaign_id: &str) -> ServiceResult<SyncReport> {
+        self.run(campaign_id, &BTreeMap::new()).await
+    }
+
+    /// Sync a campaign, settling conflicts from the last sync as given.
+    ///
+    /// Conflicts not mentioned, or new since the last sync, are reported
+    /// again.
+    pub async fn resolve_conflicts(
+        &mut self,
+        campaign_id: &str,
+        resolutions: &[ConflictResolution],
+    ) -> ServiceResult<SyncReport> {
+        let resolutions = resolutions
+            .iter()
+            .map(|r| (r.key.clone(), r.keep))
+            .collect();
+        self.run(campaign_id, &resolutions).await
+    }
+
+    async fn run(
+        &mut self,
+        campaign_id: &str,
+        resolutions: &BTreeMap<String, ConflictSide>,
+    ) -> ServiceResult<SyncReport> {
+        // Campaign IDs name files locally and on the remote
+        if campaign_id.is_empty()
+            || !campaign_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return Err(ServiceError::validation(format!(
+                "Invalid campaign ID '{}'",
+                campaign_id
+            )));
+        }
+        encryption::ensure_unencrypted(self.conn, "Sync")?;
+        let (config, backend) = self.connect()?;
+        let backend = backend.as_ref();
+
+        for attempt in 1..=MAX_SYNC_ATTEMPTS {
+            if let Some(report) = self
+                .sync_once(&config, backend, campaign_id, resolutions)
+                .await?
+            {
+                return Ok(report);
+            }
+            info!(
+                campaign_id = %campaign_id,
+                attempt,
+                "Remote campaign changed during sync, merging again"
+            );
+        }
+        Err(ServiceError::validation(
+            "The campaign kept changing on the remote while syncing. Try again",
+        ))
+    }
+
+    /// Pull, merge, and push once. Returns `None` if another device pushed
+    /// after the remote manifest was read, so the push was refused.
+    async fn sync_once(
+        &mut self,
+        config: &SyncConfig,
+        backend: &dyn SyncBackend,
+        campaign_id: &str,
+        resolutions: &BTreeMap<String, ConflictSide>,
+    ) -> ServiceResult<Option<SyncReport>> {
+        let mut state = self.campaign_state(campaign_id)?;
+
+        let (remote, remote_version) = match backend
+            .get_versioned(&manifest_key(campaign_id))
+            .await?
+        {
+            Some(blob) => {
+                let manifest: RemoteManifest = serde_json::from_slice(&blob.data).map_err(|e| {
+                    ServiceError::validation(format!("Invalid remote campaign manifest: {}", e))
+                })?;
+                (Some(manifest), Some(blob.version))
+            }
+            None => (None, None),
+        };
+        let local = CampaignSnapshot::read(self.conn, campaign_id, &self.assets_dir)?;
+        let local_hashes = local.hashes()?;
+
+        let Some(remote) = remote else {
+            if local.rows.is_empty() {
+                return Err(ServiceError::not_found("Campaign", campaign_id));
+            }
+            // Nothing on the remote yet, or it was cleared: push everything
+            let Some(pushed) = self
+                .push(config, backend, campaign_id, &local, None, None, state)
+                .await?
+            else {
+                return Ok(None);
+            };
+            return Ok(Some(report(
+                campaign_id,
+                SyncOutcome::Pushed,
+                0,
+                pushed,
+                Vec::new(),
+            )));
+        };
+        let remote_hashes = remote.snapshot.hashes()?;
+
+        let remote_moved_on = matches!(
+            remote.clock.compare(&state.clock),
+            ClockOrdering::After | ClockOrdering::Concurrent
+        );
+        if !remote_moved_on {
+            if local_hashes == state.base {
+                return Ok(Some(report(
+                    campaign_id,
+                    SyncOutcome::UpToDate,
+                    0,
+                    0,
+                    Vec::new(),
+                )));
+            }
+            let Some(pushed) = self
+                .push(
+                    config,
+                    backend,
+                    campaign_id,
+                    &local,
+                    Some(&remote),
+                    remote_version.as_deref(),
+                    state,
+                )
+                .await?
+            else {
+                return Ok(None);
+            };
+            return Ok(Some(report(
+                campaign_id,
+                SyncOutcome::Pushed,
+                0,
+                pushed,
+                Vec::new(),
+            )));
+        }
+
+        // Three-way merge against the state both sides started from
+        let keys: BTreeSet<&String> = local_hashes
+            .keys()
+            .chain(remote_hashes.keys())
+            .chain(state.base.keys())
+            .collect();
+        let mut take_remote = Vec::new();
+        let mut keep_local = 0;
+        let mut conflicts = Vec::new();
+        for key in keys {
+            let local_hash = local_hashes.get(key);
+            let remote_hash = remote_hashes.get(key);
+            let base_hash = state.base.get(key);
+            if local_hash == remote_hash {
+                continue;
+            }
+            if local_hash == base_hash {
+                take_remote.push(key.clone());
+            } else if remote_hash == base_hash {
+                keep_local += 1;
+            } else {
+                match resolutions.get(key) {
+                    Some(ConflictSide::Remote) => take_remote.push(key.clone()),
+                    Some(ConflictSide::Local) => keep_local += 1,
+                    None => conflicts.push(conflict(key, &local, &remote.snapshot)),
+                }
+            }
+        }
+
+        if !conflicts.is_empty() {
+            state.conflicts = conflicts.clone();
+            write_json(&self.state_path(campaign_id), &state)?;
+            info!(
+                campaign_id = %campaign_id,
+                conflicts = conflicts.len(),
+                "Sync found conflicts"
+            );
+            return Ok(Some(report(
+                campaign_id,
+                SyncOutcome::Conflicts,
+                0,
+                0,
+                conflicts,
+            )));
+        }
+
+        self.apply(backend, campaign_id, &remote.snapshot, &take_remote)
+            .await?;
+        let pulled = take_remote.len();
+        state.clock.merge(&remote.clock);
+
+        if keep_local > 0 {
+            // Both sides now agree on what was pulled; record that before
+            // pushing, so a refused push merges again from here
+            for key in &take_remote {
+                match remote_hashes.get(key) {
+                    Some(hash) => state.base.insert(key.clone(), hash.clone()),
+                    None => state.base.remove(key),
+                };
+            }
+            write_json(&self.state_path(campaign_id), &state)?;
+
+            let merged = CampaignSnapshot::read(self.conn, campaign_id, &self.assets_dir)?;
+            let Some(pushed) = self
+                .push(
+                    config,
+                    backend,
+                    campaign_id,
+                    &merged,
+                    Some(&remote),
+                    remote_version.as_deref(),
+                    state,
+                )
+                .await?
+            else {
+                return Ok(None);
+            };
+            return Ok(Some(report(
+                campaign_id,
+                SyncOutcome::Merged,
+                pulled,
+                pushed,
+                Vec::new(),
+            )));
+        }
+
+        state.base = remote_hashes;
+        state.conflicts.clear();
+        state.last_synced_at = Some(Utc::now());
+        write_json(&self.state_path(campaign_id), &state)?;
+        info!(campaign_id = %campaign_id, pulled, "Campaign pulled");
+        Ok(Some(report(
+            campaign_id,
+            SyncOutcome::Pulled,
+            pulled,
+            0,
+            Vec::new(),
+        )))
+    }
+
+    /// Upload a snapshot as the campaign's new remote version, returning
+    /// how many rows and files changed on the remote.
+    ///
+    /// The manifest is only replaced if it is still at `remote_version`, the
+    /// version `remote` was read at; `None` is returned if another device
+    /// pushed in the meantime.
+    #[allow(clippy::too_many_arguments)]
+    async fn push(
+        &self,
+        config: &SyncConfig,
+        backend: &dyn SyncBackend,
+        campaign_id: &str,
+        snapshot: &CampaignSnapshot,
+        remote: Option<&RemoteManifest>,
+        remote_version: Option<&str>,
+        mut state: CampaignSyncState,
+    ) -> ServiceResult<Option<usize>> {
+        let hashes = snapshot.hashes()?;
+        let remote_hashes = match remote {
+            Some(remote) => remote.snapshot.hashes()?,
+            None => BTreeMap::new(),
+        };
+        let pushed = hashes
+            .iter()
+            .filter(|(key, hash)| remote_hashes.get(*key) != Some(*hash))
+            .count()
+            + remote_hashes
+                .keys()
+                .filter(|key| !hashes.contains_key(*key))
+                .count();
+
+        // Files first, so the manifest never names a file that isn't there
+        for (path, sha256) in &snapshot.files {
+            let key = file_key(campaign_id, sha256);
+            if remote_hashes.get(&format!("{}{}", FILE_KEY_PREFIX, path)) == Some(sha256)
+                || backend.exists(&key).await?
+            {
+                continue;
+            }
+            let data = fs::read(asset_path(&self.assets_dir, path)?)?;
+            backend.put(&key, data).await?;
+        }
+
+        let mut clock = state.clock.clone();
+        if let Some(remote) = remote {
+            clock.merge(&remote.clock);
+        }
+        clock.increment(&config.device_id);
+
+        let now = Utc::now();
+        let campaign_name = snapshot
+            .rows
+            .iter()
+            .find(|(key, _)| key.starts_with("campaigns/"))
+            .and_then(|(_, row)| row.get("name")?.as_str())
+            .unwrap_or(campaign_id)
+            .to_string();
+        let manifest = RemoteManifest {
+            campaign_id: campaign_id.to_string(),
+            campaign_name: campaign_name.clone(),
+            clock: clock.clone(),
+            updated_by: config.device_name.clone(),
+            updated_at: now,
+            snapshot: snapshot.clone(),
+        };
+        let stored = backend
+            .put_if(
+                &manifest_key(campaign_id),
+                to_json(&manifest)?,
+                remote_version,
+            )
+            .await?;
+        if !stored {
+            return Ok(None);
+        }
+
+        update_index(
+            backend,
+            RemoteCampaign {
+                id: campaign_id.to_string(),
+                name: campaign_name,
+                updated_by: config.device_name.clone(),
+                updated_at: now,
+            },
+        )
+        .await?;
+
+        state.clock = clock;
+        state.base = hashes;
+        state.conflicts.clear();
+        state.last_synced_at = Some(now);
+        write_json(&self.state_path(campaign_id), &state)?;
+
+        info!(campaign_id = %campaign_id, pushed, "Campaign pushed");
+        Ok(Some(pushed))
+    }
+
+    /// Take the remote version of the given rows and files.
+    async fn apply(
+        &mut self,
+        backend: &dyn SyncBackend,
+        campaign_id: &str,
+        remote: &CampaignSnapshot,
+        keys: &[String],
+    ) -> ServiceResult<()> {
+        let mut upserts = Vec::new();
+        let mut deletes = Vec::new();
+        for key in keys {
+            let Some(path) = key.strip_prefix(FILE_KEY_PREFIX) else {
+                match remote.rows.get(key) {
+                    Some(row) => upserts.push((key.as_str(), row)),
+                    None => deletes.push(key.as_str()),
+                }
+                continue;
+            };
+
+            let dest = asset_path(&self.assets_dir, path)?;
+            match remote.files.get(path) {
+                Some(sha256) => {
+                    let data = backend
+                        .get(&file_key(campaign_id, sha256))
+                        .await?
+                        .ok_or_else(|| {
+                            ServiceError::validation(format!("Remote file {} is missing", path))
+                        })?;
+                    if &bytes_sha256(&data) != sha256 {
+                        return Err(ServiceError::validation(format!(
+                            "Remote file {} does not match its checksum",
+                            path
+                        )));
+                    }
+                    if let Some(parent) = dest.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&dest, data)?;
+                }
+                None => match fs::remove_file(&dest) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(e) => return Err(ServiceError::Io(e)),
+                },
+            }
+        }
+        apply_rows(self.conn, &upserts, &deletes)
+    }
+
+    fn connect(&self) -> ServiceResult<(SyncConfig, Box<dyn SyncBackend>)> {
+        let config = self
+            .config()?
+            .ok_or_else(|| ServiceError::validation("Sync is not set up"))?;
+        let secret = if config.backend.needs_secret() {
+            read_secret()?
+        } else {
+            None
+        };
+        let backend = config.backend.connect(secret)?;
+        Ok((config, backend))
+    }
+
+    fn state_path(&self, campaign_id: &str) -> PathBuf {
+        self.sync_dir
+            .join("campaigns")
+            .join(format!("{}.json", campaign_id))
+    }
+}
+
+fn report(
+    campaign_id: &str,
+    outcome: SyncOutcome,
+    pulled: usize,
+    pushed: usize,
+    conflicts: Vec<SyncConflict>,
+) -> SyncReport {
+    SyncReport {
+        campaign_id: campaign_id.to_string(),
+        outcome,
+        pulled,
+        pushed,
+        conflicts,
+    }
+}
+
+fn conflict(key: &str, local: &CampaignSnapshot, remote: &CampaignSnapshot) -> SyncConflict {
+    let local = local.entry(key);
+    let remote = remote.entry(key);
+    let table = key.split_once('/').map_or(key, |(table, _)| table);
+    let label = [&local, &remote].into_iter().flatten().find_map(|value| {
+        ["name", "title", "filename", "path"]
+            .iter()
+            .find_map(|field| value.get(*field)?.as_str())
+            .map(str::to_string)
+    });
+    SyncConflict {
+        key: key.to_string(),
+        table: table.to_string(),
+        label,
+        local,
+        remote,
+    }
+}
+
+fn manifest_key(campaign_id: &str) -> String {
+    format!("campaigns/{}/manifest.json", campaign_id)
+}
+
+/// Files are stored by content, so renamed or shared files upload once.
+fn file_key(campaign_id: &str, sha256: &str) -> String {
+    format!("campaigns/{}/files/{}", campaign_id, sha256)
+}
+
+async fn read_index(backend: &dyn SyncBackend) -> ServiceResult<Vec<RemoteCampaign>> {
+    match backend.get(INDEX_KEY).await? {
+        Some(bytes) => parse_index(&bytes),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn parse_index(bytes: &[u8]) -> ServiceResult<Vec<RemoteCampaign>> {
+    serde_json::from_slice(bytes)
+        .map_err(|e| ServiceError::validation(format!("Invalid remote {}: {}", INDEX_KEY, e)))
+}
+
+/// Add or replace a campaign in the remote index, re-reading it if another
+/// device changed it at the same time.
+async fn update_index(backend: &dyn SyncBackend, campaign: RemoteCampaign) -> ServiceResult<()> {
+    for _ in 0..MAX_SYNC_ATTEMPTS {
+        let current = backend.get_versioned(INDEX_KEY).await?;
+        let mut index = match &current {
+            Some(blob) => parse_index(&blob.data)?,
+            None => Vec::new(),
+        };
+        index.retain(|c| c.id != campaign.id);
+        index.push(campaign.clone());
+        index.sort_by(|a, b| a.name.cmp(&b.name));
+        let version = current.as_ref().map(|blob| blob.version.as_str());
+        if backend.put_if(INDEX_KEY, to_json(&index)?, version).await? {
+            return Ok(());
+        }
+    }
+    Err(ServiceError::validation(format!(
+        "The remote {} kept changing while syncing. Try again",
+        INDEX_KEY
+    )))
+}
+
+fn keychain_entry() -> ServiceResult<keyring::Entry> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT).map_err(keychain_error)
+}
+
+fn read_secret() -> ServiceResult<Option<String>> {
+    match keychain_entry()?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(keychain_error(e)),
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> ServiceResult<Vec<u8>> {
+    serde_json::to_vec_pretty(value)
+        .map_err(|e| ServiceError::validation(format!("Failed to serialize: {}", e)))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> ServiceResult<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path)?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| ServiceError::validation(format!("Invalid {}: {}", path.display(), e)))
+}
+
+/// Write beside the target and rename, so a crash never leaves a
+/// half-written file behind.
+fn write_json<T: Serialize>(path: &Path, value: &T) -> ServiceResult<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    fs::write(&temp_path, to_json(value)?)?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{CampaignService, CreateCampaignInput, UpdateCampaignInput};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    /// A device with its own database and data directory, syncing through
+    /// a shared folder.
+    struct Device {
+        conn: SqliteConnection,
+        data_dir: TempDir,
+    }
+
+    impl Device {
+        fn new(name: &str, remote: &Path) -> Self {
+            let mut device = Self {
+                conn: setup_test_db(),
+                data_dir: TempDir::new().unwrap(),
+            };
+            device
+                .service()
+                .configure(
+                    SyncBackendConfig::Folder {
+                        path: remote.to_path_buf(),
+                    },
+                    name,
+                    None,
+                )
+                .unwrap();
+            device
+        }
+
+        fn service(&mut self) -> SyncService<'_> {
+            let assets_dir = self.data_dir.path().join("assets");
+            SyncService::new(&mut self.conn, self.data_dir.path(), assets_dir)
+        }
+
+        fn rename(&mut self, campaign_id: &str, name: &str) {
+            CampaignService::new(&mut self.conn)
+                .update(campaign_id, UpdateCampaignInput::set_name(name))
+                .unwrap();
+        }
+
+        fn campaign_name(&mut self, campaign_id: &str) -> String {
+            CampaignService::new(&mut self.conn)
+                .get(campaign_id)
+                .unwrap()
+                .unwrap()
+                .name
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_between_devices() {
+        let remote = TempDir::new().unwrap();
+        let mut desktop = Device::new("Desktop", remote.path());
+        let mut laptop = Device::new("Laptop", remote.path());
+
+        let campaign = CampaignService::new(&mut desktop.conn)
+            .create(CreateCampaignInput::new("Phandelver"))
+            .unwrap();
+        let report = desktop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Pushed);
+
+        // The laptop finds the campaign on the remote and pulls it
+        let remote_campaigns = laptop.service().list_remote_campaigns().await.unwrap();
+        assert_eq!(remote_campaigns.len(), 1);
+        assert_eq!(remote_campaigns[0].updated_by, "Desktop");
+        let report = laptop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Pulled);
+        assert_eq!(laptop.campaign_name(&campaign.id), "Phandelver");
+
+        let report = laptop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::UpToDate);
+
+        // A change on the laptop reaches the desktop
+        laptop.rename(&campaign.id, "Lost Mine of Phandelver");
+        let report = laptop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Pushed);
+        let report = desktop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Pulled);
+        assert_eq!(
+            desktop.campaign_name(&campaign.id),
+            "Lost Mine of Phandelver"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_changes() {
+        let remote = TempDir::new().unwrap();
+        let mut desktop = Device::new("Desktop", remote.path());
+        let mut laptop = Device::new("Laptop", remote.path());
+
+        let campaign = CampaignService::new(&mut desktop.conn)
+            .create(CreateCampaignInput::new("Phandelver"))
+            .unwrap();
+        desktop.service().sync_campaign(&campaign.id).await.unwrap();
+        laptop.service().sync_campaign(&campaign.id).await.unwrap();
+
+        // Both devices rename the campaign before syncing
+        desktop.rename(&campaign.id, "Desktop Name");
+        laptop.rename(&campaign.id, "Laptop Name");
+        desktop.service().sync_campaign(&campaign.id).await.unwrap();
+
+        let report = laptop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Conflicts);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].table, "campaigns");
+        assert_eq!(laptop.campaign_name(&campaign.id), "Laptop Name");
+        assert_eq!(
+            laptop
+                .service()
+                .campaign_state(&campaign.id)
+                .unwrap()
+                .conflicts
+                .len(),
+            1
+        );
+
+        // Keeping the laptop's version pushes it
+        let resolution = ConflictResolution {
+            key: report.conflicts[0].key.clone(),
+            keep: ConflictSide::Local,
+        };
+        let report = laptop
+            .service()
+            .resolve_conflicts(&campaign.id, &[resolution])
+            .await
+            .unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Pushed);
+        assert!(laptop
+            .service()
+            .campaign_state(&campaign.id)
+            .unwrap()
+            .conflicts
+            .is_empty());
+
+        desktop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(desktop.campaign_name(&campaign.id), "Laptop Name");
+    }
+
+    #[tokio::test]
+    async fn test_stale_push_is_refused() {
+        let remote = TempDir::new().unwrap();
+        let mut desktop = Device::new("Desktop", remote.path());
+        let mut laptop = Device::new("Laptop", remote.path());
+
+        let campaign = CampaignService::new(&mut desktop.conn)
+            .create(CreateCampaignInput::new("Phandelver"))
+            .unwrap();
+        desktop.service().sync_campaign(&campaign.id).await.unwrap();
+        laptop.service().sync_campaign(&campaign.id).await.unwrap();
+
+        // The laptop reads the manifest, then the desktop pushes over it
+        let key = manifest_key(&campaign.id);
+        let (_, backend) = laptop.service().connect().unwrap();
+        let stale = backend.get_versioned(&key).await.unwrap().unwrap();
+        desktop.rename(&campaign.id, "Desktop Name");
+        desktop.service().sync_campaign(&campaign.id).await.unwrap();
+
+        let mut service = laptop.service();
+        let (config, backend) = service.connect().unwrap();
+        let manifest: RemoteManifest = serde_json::from_slice(&stale.data).unwrap();
+        let snapshot =
+            CampaignSnapshot::read(service.conn, &campaign.id, &service.assets_dir).unwrap();
+        let state = service.campaign_state(&campaign.id).unwrap();
+        let pushed = service
+            .push(
+                &config,
+                backend.as_ref(),
+                &campaign.id,
+                &snapshot,
+                Some(&manifest),
+                Some(&stale.version),
+                state,
+            )
+            .await
+            .unwrap();
+        assert!(pushed.is_none());
+
+        // The desktop's change survives and the next sync pulls it
+        let report = laptop.service().sync_campaign(&campaign.id).await.unwrap();
+        assert_eq!(report.outcome, SyncOutcome::Pulled);
+        assert_eq!(laptop.campaign_name(&campaign.id), "Desktop Name");
+    }
+
+    #[tokio::test]
+    async fn test_sync_requires_config() {
+        let mut conn = setup_test_db();
+        let dir = TempDir::new().unwrap();
+        let mut service = SyncService::new(&mut conn, dir.path(), dir.path());
+        assert!(service.sync_campaign("missing").await.is_err());
+    }
+}
@@ -0,0 +1,422 @@
+//! Campaign Snapshots
+//!
+//! A campaign's synced state: every row of the campaign's tables as JSON,
+//! keyed by table and primary key, plus the hashes of its asset files. Rows
+//! are read and written generically from the table definitions in SQLite, so
+//! new columns sync without changes here.
+
+use diesel::prelude::*;
+use diesel::sql_types::{Integer, Text};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+
+use crate::services::{ServiceError, ServiceResult};
+
+/// Key prefix of asset files in `CampaignSnapshot::hashes`.
+pub(super) const FILE_KEY_PREFIX: &str = "file/";
+
+const CAMPAIGN: &str = "campaign_id = ?";
+const CHARACTER: &str = "character_id IN (SELECT id FROM characters WHERE campaign_id = ?)";
+const HANDOUT: &str = "handout_id IN (SELECT id FROM handouts WHERE campaign_id = ?)";
+const MAP: &str = "map_id IN (SELECT id FROM maps WHERE campaign_id = ?)";
+const MODULE: &str = "module_id IN (SELECT id FROM modules WHERE campaign_id = ?)";
+const PARTY: &str = "party_id IN (SELECT id FROM parties WHERE campaign_id = ?)";
+const PLAYER: &str = "player_id IN (SELECT id FROM players WHERE campaign_id = ?)";
+const RUMOR: &str = "rumor_id IN (SELECT id FROM rumors WHERE campaign_id = ?)";
+const TAG: &str = "tag_id IN (SELECT id FROM tags WHERE campaign_id = ?)";
+
+/// Synced tables, parents before children, with the condition selecting a
+/// campaign's rows (`?` is the campaign ID).
+///
+/// Recent items and favorites are left out; they belong to the device.
+const SYNCED_TABLES: &[(&str, &str)] = &[
+    ("campaigns", "id = ?"),
+    ("campaign_sources", CAMPAIGN),
+    ("modules", CAMPAIGN),
+    ("module_links", CAMPAIGN),
+    ("campaign_assets", CAMPAIGN),
+    ("documents", CAMPAIGN),
+    ("campaign_homebrew_items", CAMPAIGN),
+    ("campaign_homebrew_monsters", CAMPAIGN),
+    ("campaign_homebrew_spells", CAMPAIGN),
+    ("campaign_safety_tools", CAMPAIGN),
+    ("campaign_custom_fields", CAMPAIGN),
+    ("campaign_stash_currency", CAMPAIGN),
+    ("campaign_memories", CAMPAIGN),
+    ("forage_ingredients", CAMPAIGN),
+    ("quick_actions", CAMPAIGN),
+    ("dm_reference_pins", CAMPAIGN),
+    ("tags", CAMPAIGN),
+    ("characters", CAMPAIGN),
+    ("character_classes", CHARACTER),
+    ("character_inventory", CHARACTER),
+    ("character_spells", CHARACTER),
+    ("character_proficiencies", CHARACTER),
+    ("character_features", CHARACTER),
+    ("character_feats", CHARACTER),
+    ("character_custom_fields", CHARACTER),
+    ("character_resources", CHARACTER),
+    ("character_sources", CHARACTER),
+    ("character_companions", CHARACTER),
+    ("crafting_projects", CHARACTER),
+    ("currency_transactions", CHARACTER),
+    ("parties", CAMPAIGN),
+    ("party_members", PARTY),
+    ("game_sessions", CAMPAIGN),
+    ("party_timeline_events", PARTY),
+    ("character_deaths", CHARACTER),
+    ("owned_vehicles", CAMPAIGN),
+    ("campaign_stash_items", CAMPAIGN),
+    ("campaign_stash_ledger", CAMPAIGN),
+    ("players", CAMPAIGN),
+    ("player_characters", PLAYER),
+    ("player_claims", CAMPAIGN),
+    ("rumors", CAMPAIGN),
+    ("rumor_hearings", RUMOR),
+    ("handouts", CAMPAIGN),
+    ("handout_reveals", HANDOUT),
+    ("secret_notes", CAMPAIGN),
+    ("dispositions", CAMPAIGN),
+    ("party_reputations", CAMPAIGN),
+    ("npc_interactions", CAMPAIGN),
+    ("maps", CAMPAIGN),
+    ("map_pois", MAP),
+    ("map_traps", MAP),
+    ("light_sources", MAP),
+    ("fog_revealed_areas", MAP),
+    ("map_regions", MAP),
+    ("module_monsters", MODULE),
+    ("module_npcs", MODULE),
+    ("encounter_effects", MODULE),
+    ("module_prep_checks", MODULE),
+    ("token_placements", MAP),
+    ("entity_tags", TAG),
+];
+
+/// A campaign's rows and asset files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CampaignSnapshot {
+    /// Rows by key (`<table>/<primary key as a JSON array>`)
+    pub rows: BTreeMap<String, Value>,
+    /// SHA-256 hashes of asset files, by path under the assets directory
+    pub files: BTreeMap<String, String>,
+}
+
+impl CampaignSnapshot {
+    /// Read a campaign's rows and hash its asset files.
+    ///
+    /// A campaign that doesn't exist gives an empty snapshot.
+    pub fn read(
+        conn: &mut SqliteConnection,
+        campaign_id: &str,
+        assets_dir: &Path,
+    ) -> ServiceResult<Self> {
+        let mut snapshot = Self::default();
+        for (table, condition) in SYNCED_TABLES {
+            let columns = table_columns(conn, table)?;
+            let fields = columns
+                .iter()
+                .map(|c| format!("'{}', \"{}\"", c.name, c.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let rows: Vec<JsonRow> = diesel::sql_query(format!(
+                "SELECT json_object({}) AS row FROM \"{}\" WHERE {}",
+                fields, table, condition
+            ))
+            .bind::<Text, _>(campaign_id)
+            .load(conn)?;
+
+            for row in rows {
+                let value: Value = serde_json::from_str(&row.row).map_err(|e| {
+                    ServiceError::validation(format!("Invalid row in {}: {}", table, e))
+                })?;
+                let primary_key = key_columns(&columns)
+                    .iter()
+                    .map(|c| value.get(&c.name).cloned().unwrap_or(Value::Null))
+                    .collect();
+                snapshot.rows.insert(row_key(table, primary_key), value);
+            }
+        }
+
+        let blob_paths: Vec<String> = snapshot
+            .rows
+            .iter()
+            .filter(|(key, _)| key.starts_with("campaign_assets/"))
+            .filter_map(|(_, row)| row.get("blob_path")?.as_str().map(str::to_string))
+            .collect();
+        for blob_path in blob_paths {
+            match file_sha256(&assets_dir.join(&blob_path)) {
+                Ok(sha256) => {
+                    snapshot.files.insert(blob_path, sha256);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(ServiceError::Io(e)),
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Content hash of every row and file. Rows are keyed as in `rows`, files
+    /// by their path after `file/`.
+    pub fn hashes(&self) -> ServiceResult<BTreeMap<String, String>> {
+        let mut hashes = BTreeMap::new();
+        for (key, row) in &self.rows {
+            let bytes = serde_json::to_vec(row)
+                .map_err(|e| ServiceError::validation(format!("Failed to serialize row: {}", e)))?;
+            hashes.insert(key.clone(), hex(&Sha256::digest(&bytes)));
+        }
+        for (path, sha256) in &self.files {
+            hashes.insert(format!("{}{}", FILE_KEY_PREFIX, path), sha256.clone());
+        }
+        Ok(hashes)
+    }
+
+    /// The row or file under a key from [`hashes`](Self::hashes).
+    pub fn entry(&self, key: &str) -> Option<Value> {
+        match key.strip_prefix(FILE_KEY_PREFIX) {
+            Some(path) => self
+                .files
+                .get(path)
+                .map(|sha256| serde_json::json!({ "path": path, "sha256": sha256 })),
+            None => self.rows.get(key).cloned(),
+        }
+    }
+}
+
+/// Write rows into the database in one transaction, deleting `deletes` and
+/// inserting or updating `upserts`.
+///
+/// Only tables the sync covers are written, so a remote snapshot cannot
+/// touch anything else.
+pub(super) fn apply_rows(
+    conn: &mut SqliteConnection,
+    upserts: &[(&str, &Value)],
+    deletes: &[&str],
+) -> ServiceResult<()> {
+    let mut upserts: Vec<(usize, &str, Vec<Value>, &Value)> = upserts
+        .iter()
+        .map(|(key, row)| {
+            let (index, table, primary_key) = parse_row_key(key)?;
+            Ok((index, table, primary_key, *row))
+        })
+        .collect::<ServiceResult<_>>()?;
+    let mut deletes: Vec<(usize, &str, Vec<Value>)> = deletes
+        .iter()
+        .map(|key| parse_row_key(key))
+        .collect::<ServiceResult<_>>()?;
+    // Children are deleted before their parents and inserted after them
+    upserts.sort_by_key(|(index, ..)| *index);
+    deletes.sort_by_key(|(index, ..)| std::cmp::Reverse(*index));
+
+    conn.transaction(|conn| {
+        diesel::sql_query("PRAGMA defer_foreign_keys = ON").execute(conn)?;
+
+        for (_, table, primary_key) in &deletes {
+            let columns = table_columns(conn, table)?;
+            let condition = primary_key_condition(&columns);
+            diesel::sql_query(format!("DELETE FROM \"{}\" WHERE {}", table, condition))
+                .bind::<Text, _>(Value::Array(primary_key.clone()).to_string())
+                .execute(conn)?;
+        }
+
+        for (_, table, primary_key, row) in &upserts {
+            let columns = table_columns(conn, table)?;
+            let names = columns
+                .iter()
+                .map(|c| format!("\"{}\"", c.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let values = columns
+                .iter()
+                .map(|c| format!("json_extract(?1, '$.\"{}\"')", c.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let conflict = key_columns(&columns)
+                .iter()
+                .map(|c| format!("\"{}\"", c.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let updates = columns
+                .iter()
+                .filter(|c| c.pk == 0)
+                .map(|c| format!("\"{0}\" = excluded.\"{0}\"", c.name))
+                .collect::<Vec<_>>();
+            let on_conflict = if updates.is_empty() {
+                "DO NOTHING".to_string()
+            } else {
+                format!("DO UPDATE SET {}", updates.join(", "))
+            };
+
+            // The key decides which row is written, whatever the row says
+            let mut row = (*row).clone();
+            for (column, value) in key_columns(&columns).iter().zip(primary_key.iter()) {
+                if let Some(fields) = row.as_object_mut() {
+                    fields.insert(column.name.clone(), value.clone());
+                }
+            }
+
+            diesel::sql_query(format!(
+                "INSERT INTO \"{}\" ({}) VALUES ({}) ON CONFLICT ({}) {}",
+                table, names, values, conflict, on_conflict
+            ))
+            .bind::<Text, _>(row.to_string())
+            .execute(conn)?;
+        }
+        Ok(())
+    })
+}
+
+/// Path of an asset file under the assets directory, rejecting paths that
+/// would leave it.
+pub(super) fn asset_path(assets_dir: &Path, blob_path: &str) -> ServiceResult<PathBuf> {
+    let relative = Path::new(blob_path);
+    if !relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return Err(ServiceError::validation(format!(
+            "Invalid asset path '{}'",
+            blob_path
+        )));
+    }
+    Ok(assets_dir.join(relative))
+}
+
+/// SHA-256 hash of a file, hex encoded.
+fn file_sha256(path: &Path) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(hex(&hasher.finalize()))
+}
+
+/// SHA-256 hash of bytes, hex encoded.
+pub(super) fn bytes_sha256(bytes: &[u8]) -> String {
+    hex(&Sha256::digest(bytes))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[derive(QueryableByName)]
+struct JsonRow {
+    #[diesel(sql_type = Text)]
+    row: String,
+}
+
+#[derive(QueryableByName)]
+struct TableColumn {
+    #[diesel(sql_type = Text)]
+    name: String,
+    /// Position in the primary key, starting at 1; 0 if not part of it
+    #[diesel(sql_type = Integer)]
+    pk: i32,
+}
+
+fn table_columns(conn: &mut SqliteConnection, table: &str) -> QueryResult<Vec<TableColumn>> {
+    diesel::sql_query("SELECT name, pk FROM pragma_table_info(?) ORDER BY cid")
+        .bind::<Text, _>(table)
+        .load(conn)
+}
+
+fn key_columns(columns: &[TableColumn]) -> Vec<&TableColumn> {
+    let mut key: Vec<&TableColumn> = columns.iter().filter(|c| c.pk > 0).collect();
+    key.sort_by_key(|c| c.pk);
+    key
+}
+
+/// `WHERE` condition matching a primary key given as a JSON array in `?1`.
+fn primary_key_condition(columns: &[TableColumn]) -> String {
+    key_columns(columns)
+        .iter()
+        .enumerate()
+        .map(|(i, c)| format!("\"{}\" = json_extract(?1, '$[{}]')", c.name, i))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+fn row_key(table: &str, primary_key: Vec<Value>) -> String {
+    format!("{}/{}", table, Value::Array(primary_key))
+}
+
+/// Table position in [`SYNCED_TABLES`], table name, and primary key of a row key.
+fn parse_row_key(key: &str) -> ServiceResult<(usize, &'static str, Vec<Value>)> {
+    let invalid = || ServiceError::validation(format!("Invalid sync row key '{}'", key));
+    let (table, primary_key) = key.split_once('/').ok_or_else(invalid)?;
+    let (index, (table, _)) = SYNCED_TABLES
+        .iter()
+        .enumerate()
+        .find(|(_, (name, _))| *name == table)
+        .ok_or_else(invalid)?;
+    let primary_key = serde_json::from_str(primary_key).map_err(|_| invalid())?;
+    Ok((index, *table, primary_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{CampaignService, CreateCampaignInput};
+    use crate::test_utils::setup_test_db;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let assets = TempDir::new().unwrap();
+        let mut desktop = setup_test_db();
+        let campaign = CampaignService::new(&mut desktop)
+            .create(CreateCampaignInput::new("Phandelver"))
+            .unwrap();
+        let snapshot = CampaignSnapshot::read(&mut desktop, &campaign.id, assets.path()).unwrap();
+        assert!(snapshot.rows.contains_key(&row_key(
+            "campaigns",
+            vec![Value::from(campaign.id.clone())]
+        )));
+        assert!(snapshot.rows.keys().any(|k| k.starts_with("documents/")));
+
+        // Writing the rows into another database reproduces the campaign
+        let mut laptop = setup_test_db();
+        let upserts: Vec<(&str, &Value)> = snapshot
+            .rows
+            .iter()
+            .map(|(key, row)| (key.as_str(), row))
+            .collect();
+        apply_rows(&mut laptop, &upserts, &[]).unwrap();
+        let copy = CampaignSnapshot::read(&mut laptop, &campaign.id, assets.path()).unwrap();
+        assert_eq!(copy.hashes().unwrap(), snapshot.hashes().unwrap());
+
+        // Deleting the campaign row removes it
+        let campaign_key = row_key("campaigns", vec![Value::from(campaign.id.clone())]);
+        let children: Vec<&str> = snapshot.rows.keys().map(String::as_str).collect();
+        apply_rows(&mut laptop, &[], &children).unwrap();
+        assert!(
+            CampaignSnapshot::read(&mut laptop, &campaign.id, assets.path())
+                .unwrap()
+                .rows
+                .get(&campaign_key)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_rejects_unsynced_tables() {
+        let mut conn = setup_test_db();
+        let row = serde_json::json!({ "id": "x" });
+        assert!(apply_rows(&mut conn, &[("catalog_sources/[\"x\"]", &row)], &[]).is_err());
+        assert!(apply_rows(&mut conn, &[], &["favorites/[\"x\"]"]).is_err());
+    }
+
+    #[test]
+    fn test_asset_path() {
+        let dir = Path::new("/data/assets");
+        assert_eq!(
+            asset_path(dir, "campaigns/a/map.png").unwrap(),
+            dir.join("campaigns/a/map.png")
+        );
+        assert!(asset_path(dir, "../secrets").is_err());
+        assert!(asset_path(dir, "/etc/passwd").is_err());
+    }
+}
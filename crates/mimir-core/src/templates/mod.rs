@@ -90,6 +90,10 @@ pub struct ModuleTemplateInfo {
     pub title: &'static str,
     /// The template content.
     pub content: &'static str,
+    /// Maps a module of this type usually needs prepared.
+    pub maps: u32,
+    /// Monster stat blocks a module of this type usually needs selected.
+    pub stat_blocks: u32,
 }
 
 /// All campaign templates with metadata.
@@ -157,31 +161,43 @@ pub const MODULE_TEMPLATES: &[ModuleTemplateInfo] = &[
         module_type: "general",
         title: "Module Overview",
         content: MODULE_OVERVIEW,
+        maps: 1,
+        stat_blocks: 3,
     },
     ModuleTemplateInfo {
         module_type: "mystery",
         title: "Mystery Module",
         content: MODULE_MYSTERY,
+        maps: 1,
+        stat_blocks: 2,
     },
     ModuleTemplateInfo {
         module_type: "dungeon",
         title: "Dungeon Crawl",
         content: MODULE_DUNGEON,
+        maps: 2,
+        stat_blocks: 6,
     },
     ModuleTemplateInfo {
         module_type: "heist",
         title: "Heist Module",
         content: MODULE_HEIST,
+        maps: 2,
+        stat_blocks: 3,
     },
     ModuleTemplateInfo {
         module_type: "horror",
         title: "Horror Module",
         content: MODULE_HORROR,
+        maps: 1,
+        stat_blocks: 3,
     },
     ModuleTemplateInfo {
         module_type: "political",
         title: "Political Intrigue",
         content: MODULE_POLITICAL,
+        maps: 0,
+        stat_blocks: 2,
     },
 ];
 
@@ -228,6 +244,14 @@ pub fn get_module_template(module_type: &str) -> Option<&'static str> {
     }
 }
 
+/// Get a module template with its metadata by module type.
+///
+/// # Returns
+/// The template info if found, or `None` if the module type is unknown.
+pub fn get_module_template_info(module_type: &str) -> Option<&'static ModuleTemplateInfo> {
+    MODULE_TEMPLATES.iter().find(|t| t.module_type == module_type)
+}
+
 /// Get the play notes template.
 ///
 /// Play notes are used for session-by-session DM notes within a module.
@@ -286,6 +310,14 @@ mod tests {
         assert!(get_module_template("nonexistent").is_none());
     }
 
+    #[test]
+    fn test_get_module_template_info() {
+        let info = get_module_template_info("dungeon").unwrap();
+        assert_eq!(info.content, MODULE_DUNGEON);
+        assert!(info.maps > 0);
+        assert!(get_module_template_info("nonexistent").is_none());
+    }
+
     #[test]
     fn test_play_notes_template() {
         let template = get_play_notes_template();
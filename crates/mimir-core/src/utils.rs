@@ -3,6 +3,7 @@
 //! Common helper functions used across services and DAL.
 
 use chrono::Utc;
+use similar::{capture_diff_slices, Algorithm, DiffOp};
 
 /// Returns the current UTC timestamp in RFC 3339 format.
 ///
@@ -20,6 +21,131 @@ pub fn now_rfc3339() -> String {
     Utc::now().to_rfc3339()
 }
 
+/// Result of a three-way text merge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeResult {
+    /// Both sides merged without overlapping edits.
+    Clean(String),
+    /// Both sides changed the same lines. The text contains git-style
+    /// conflict markers around each overlapping edit.
+    Conflict(String),
+}
+
+/// Merge two edited versions of `base` line by line.
+///
+/// Changes that touch different lines are combined. Where both sides changed
+/// the same lines differently, the result is a conflict with `ours` shown
+/// above `theirs` between `<<<<<<<`/`=======`/`>>>>>>>` markers.
+pub fn merge3(base: &str, ours: &str, theirs: &str) -> MergeResult {
+    let base_lines: Vec<&str> = base.split_inclusive('\n').collect();
+    let our_lines: Vec<&str> = ours.split_inclusive('\n').collect();
+    let their_lines: Vec<&str> = theirs.split_inclusive('\n').collect();
+
+    let ours_at = matching_lines(&base_lines, &our_lines);
+    let theirs_at = matching_lines(&base_lines, &their_lines);
+
+    let mut merged = String::new();
+    let mut conflicted = false;
+    let (mut i, mut o, mut t) = (0, 0, 0);
+    loop {
+        // Next base line kept unchanged by both sides, or the end of all three.
+        let next = (i..base_lines.len()).find_map(|j| Some((j, ours_at[j]?, theirs_at[j]?)));
+        let (j, oj, tj) = next.unwrap_or((base_lines.len(), our_lines.len(), their_lines.len()));
+
+        let base_chunk = &base_lines[i..j];
+        let our_chunk = &our_lines[o..oj];
+        let their_chunk = &their_lines[t..tj];
+        if our_chunk == base_chunk || our_chunk == their_chunk {
+            merged.extend(their_chunk.iter().copied());
+        } else if their_chunk == base_chunk {
+            merged.extend(our_chunk.iter().copied());
+        } else {
+            conflicted = true;
+            merged.push_str("<<<<<<< Your changes\n");
+            push_lines(&mut merged, our_chunk);
+            merged.push_str("=======\n");
+            push_lines(&mut merged, their_chunk);
+            merged.push_str(">>>>>>> External changes\n");
+        }
+
+        if j == base_lines.len() {
+            break;
+        }
+        merged.push_str(base_lines[j]);
+        (i, o, t) = (j + 1, oj + 1, tj + 1);
+    }
+
+    if conflicted {
+        MergeResult::Conflict(merged)
+    } else {
+        MergeResult::Clean(merged)
+    }
+}
+
+/// For each line of `base`, the index of the same line in `other` if the
+/// diff kept it unchanged.
+fn matching_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        if let DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = op
+        {
+            for k in 0..len {
+                matches[old_index + k] = Some(new_index + k);
+            }
+        }
+    }
+    matches
+}
+
+/// Append lines inside a conflict block, making sure the block ends with a newline.
+fn push_lines(out: &mut String, lines: &[&str]) {
+    out.extend(lines.iter().copied());
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// Extract the boxed read-aloud text blocks of a markdown document.
+///
+/// A block starts at a `:::read-aloud` line and ends at the next `:::` line,
+/// or at the end of the document if it is never closed. Fences inside code
+/// blocks are ignored. Each block's markdown is returned trimmed, in order.
+pub fn read_aloud_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<String> = None;
+    let mut in_code = false;
+
+    for line in markdown.lines() {
+        match current.as_mut() {
+            Some(text) if line.trim() == ":::" => {
+                blocks.push(text.trim().to_string());
+                current = None;
+            }
+            Some(text) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+            None if !in_code && line.trim() == ":::read-aloud" => {
+                current = Some(String::new());
+            }
+            None => {
+                if line.trim_start().starts_with("```") {
+                    in_code = !in_code;
+                }
+            }
+        }
+    }
+
+    if let Some(text) = current {
+        blocks.push(text.trim().to_string());
+    }
+    blocks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,4 +163,57 @@ mod tests {
         // UTC timestamps end with +00:00
         assert!(timestamp.ends_with("+00:00"));
     }
+
+    #[test]
+    fn test_merge3_combines_separate_edits() {
+        let base = "one\ntwo\nthree\n";
+        let ours = "ONE\ntwo\nthree\n";
+        let theirs = "one\ntwo\nTHREE\n";
+        assert_eq!(
+            merge3(base, ours, theirs),
+            MergeResult::Clean("ONE\ntwo\nTHREE\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_merge3_same_edit_on_both_sides_is_clean() {
+        let base = "one\ntwo\n";
+        let edited = "one\n2\n";
+        assert_eq!(
+            merge3(base, edited, edited),
+            MergeResult::Clean(edited.to_string())
+        );
+    }
+
+    #[test]
+    fn test_merge3_overlapping_edits_conflict() {
+        let base = "one\ntwo\nthree";
+        let ours = "one\nmine\nthree";
+        let theirs = "one\ntheirs\nthree";
+        assert_eq!(
+            merge3(base, ours, theirs),
+            MergeResult::Conflict(
+                "one\n<<<<<<< Your changes\nmine\n=======\ntheirs\n>>>>>>> External changes\nthree"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_aloud_blocks() {
+        let md = "# Scene\n\n:::read-aloud\nThe door creaks open.\n\nCold air spills out.\n:::\n\nDM note.\n\n:::read-aloud\nYou smell smoke.";
+        assert_eq!(
+            read_aloud_blocks(md),
+            vec![
+                "The door creaks open.\n\nCold air spills out.".to_string(),
+                "You smell smoke.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_aloud_blocks_ignores_code_blocks() {
+        let md = "```\n:::read-aloud\nnot boxed\n:::\n```";
+        assert!(read_aloud_blocks(md).is_empty());
+    }
 }
@@ -7,9 +7,10 @@
 
 use diesel::SqliteConnection;
 use mimir_core::db::{create_connection, init_database};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use crate::policy::ToolPolicies;
 use crate::McpError;
 
 /// Shared context for the MCP server.
@@ -23,6 +24,8 @@ pub struct McpContext {
     pub assets_dir: PathBuf,
     /// Currently active campaign ID
     pub active_campaign_id: Mutex<Option<String>>,
+    /// Confirmation policies for tool calls
+    pub tool_policies: ToolPolicies,
 }
 
 impl McpContext {
@@ -61,10 +64,13 @@ impl McpContext {
             ))
         })?;
 
+        let tool_policies = ToolPolicies::from_env()?;
+
         Ok(Self {
             db_url,
             assets_dir,
             active_campaign_id: Mutex::new(None),
+            tool_policies,
         })
     }
 
@@ -117,6 +123,24 @@ impl McpContext {
         }
     }
 
+    /// Get the app data directory (parent of `assets_dir`).
+    pub fn app_data_dir(&self) -> PathBuf {
+        self.assets_dir
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| self.assets_dir.clone())
+    }
+
+    /// Get the app's own directory: the parent of the `data/` folder holding
+    /// the database. The app keeps campaign history here.
+    pub fn app_dir(&self) -> PathBuf {
+        Path::new(&self.db_url)
+            .parent()
+            .and_then(Path::parent)
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| self.app_data_dir())
+    }
+
     /// Create a new database connection.
     ///
     /// Each connection is configured with WAL mode and foreign keys enabled.
@@ -137,7 +161,13 @@ impl McpContext {
         static COUNTER: AtomicU32 = AtomicU32::new(0);
 
         let id = COUNTER.fetch_add(1, Ordering::Relaxed);
-        let db_url = format!("/tmp/mimir-test-{}-{}.db", std::process::id(), id);
+        let data_dir = PathBuf::from(format!(
+            "/tmp/mimir-test-{}-{}/data",
+            std::process::id(),
+            id
+        ));
+        std::fs::create_dir_all(&data_dir).expect("Failed to create test data directory");
+        let db_url = data_dir.join("mimir.db").to_string_lossy().to_string();
 
         // Initialize with migrations
         init_database(&db_url).expect("Failed to initialize test database");
@@ -146,6 +176,7 @@ impl McpContext {
             db_url,
             assets_dir: PathBuf::from("/tmp/mimir-test-assets"),
             active_campaign_id: Mutex::new(None),
+            tool_policies: ToolPolicies::default(),
         }
     }
 }
@@ -1,5 +1,7 @@
 //! MCP Error Types
 
+use mimir_core::services::ErrorKind;
+use serde_json::{Map, Value};
 use thiserror::Error;
 
 /// Errors that can occur in the MCP server.
@@ -17,6 +19,10 @@ pub enum McpError {
     #[error("Tool not found: {0}")]
     ToolNotFound(String),
 
+    /// Tool disabled by the tool policy
+    #[error("Tool is disabled by MIMIR_TOOL_POLICY: {0}")]
+    ToolDenied(String),
+
     /// Invalid tool arguments
     #[error("Invalid arguments: {0}")]
     InvalidArguments(String),
@@ -34,6 +40,65 @@ pub enum McpError {
     Internal(String),
 }
 
+impl McpError {
+    /// The kind of failure, using the same taxonomy as the app's commands.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            McpError::Initialization(_) | McpError::Internal(_) => ErrorKind::Other,
+            McpError::Database(_) => ErrorKind::Database,
+            McpError::ToolNotFound(_) | McpError::NotFound(_, _) => ErrorKind::NotFound,
+            McpError::ToolDenied(_) => ErrorKind::PermissionDenied,
+            McpError::InvalidArguments(_) | McpError::NoActiveCampaign => ErrorKind::Validation,
+        }
+    }
+
+    /// The type of entity involved, when known.
+    pub fn entity(&self) -> Option<&str> {
+        match self {
+            McpError::ToolNotFound(_) | McpError::ToolDenied(_) => Some("Tool"),
+            McpError::NotFound(entity, _) => Some(entity),
+            McpError::NoActiveCampaign => Some("Campaign"),
+            _ => None,
+        }
+    }
+
+    /// What the caller can do about the error, if anything.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            McpError::NoActiveCampaign => {
+                Some("Call list_campaigns, then set_active_campaign with one of the ids.")
+            }
+            McpError::ToolNotFound(_) => Some("Call tools/list to see the available tools."),
+            McpError::ToolDenied(_) => Some("Ask the user to allow the tool in MIMIR_TOOL_POLICY."),
+            McpError::InvalidArguments(_) => Some("Check the tool's input schema and try again."),
+            _ => None,
+        }
+    }
+
+    /// Structured form of the error, matching the app's command errors:
+    /// `kind`, `entity`, `message`, `recoverable`, and `suggestion`.
+    pub fn details(&self) -> Map<String, Value> {
+        let kind = self.kind();
+        let mut details = Map::new();
+        details.insert(
+            "kind".to_string(),
+            serde_json::to_value(kind).unwrap_or_default(),
+        );
+        if let Some(entity) = self.entity() {
+            details.insert("entity".to_string(), Value::from(entity));
+        }
+        details.insert("message".to_string(), Value::from(self.to_string()));
+        details.insert(
+            "recoverable".to_string(),
+            Value::from(kind.is_recoverable()),
+        );
+        if let Some(suggestion) = self.suggestion() {
+            details.insert("suggestion".to_string(), Value::from(suggestion));
+        }
+        details
+    }
+}
+
 impl From<diesel::result::Error> for McpError {
     fn from(e: diesel::result::Error) -> Self {
         McpError::Database(e.to_string())
@@ -64,3 +129,26 @@ impl From<mimir_core::services::ServiceError> for McpError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_active_campaign_details() {
+        let details = McpError::NoActiveCampaign.details();
+        assert_eq!(details["kind"], "validation");
+        assert_eq!(details["entity"], "Campaign");
+        assert_eq!(details["recoverable"], true);
+        assert!(details.contains_key("suggestion"));
+    }
+
+    #[test]
+    fn test_database_error_details() {
+        let details = McpError::Database("disk I/O error".to_string()).details();
+        assert_eq!(details["kind"], "database");
+        assert_eq!(details["recoverable"], false);
+        assert!(!details.contains_key("entity"));
+        assert!(!details.contains_key("suggestion"));
+    }
+}
@@ -3,6 +3,7 @@
 //! Implements the ServerHandler trait to route tool calls to appropriate handlers.
 
 use async_trait::async_trait;
+use mimir_core::services::CampaignHistoryService;
 use rust_mcp_sdk::mcp_server::ServerHandler;
 use rust_mcp_sdk::schema::{
     CallToolRequestParams, CallToolResult, ContentBlock, ListToolsResult, PaginatedRequestParams,
@@ -11,9 +12,10 @@ use rust_mcp_sdk::schema::{
 use rust_mcp_sdk::McpServer;
 use serde_json::Value;
 use std::sync::Arc;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use crate::context::McpContext;
+use crate::policy::{ToolKind, ToolPolicy};
 use crate::tools;
 use crate::McpError;
 
@@ -52,6 +54,12 @@ impl MimirHandler {
             tools::campaign::export_campaign_tool(),
             tools::campaign::import_campaign_tool(),
             tools::campaign::preview_archive_tool(),
+            tools::campaign::get_session_transcript_tool(),
+            // Memory tools
+            tools::memory::remember_fact_tool(),
+            tools::memory::recall_facts_tool(),
+            tools::memory::list_memories_tool(),
+            tools::memory::forget_fact_tool(),
             // Module tools
             tools::module::create_module_tool(),
             tools::module::list_modules_tool(),
@@ -61,6 +69,8 @@ impl MimirHandler {
             tools::module::add_monster_to_module_tool(),
             tools::module::remove_monster_from_module_tool(),
             tools::module::add_item_to_module_tool(),
+            tools::module::link_modules_tool(),
+            tools::module::check_module_completion_tool(),
             // Document tools
             tools::document::list_documents_tool(),
             tools::document::read_document_tool(),
@@ -82,6 +92,16 @@ impl MimirHandler {
             tools::character::add_character_spell_tool(),
             tools::character::remove_character_spell_tool(),
             tools::character::list_character_spells_tool(),
+            tools::character::use_resource_tool(),
+            tools::character::restore_resource_tool(),
+            tools::character::take_rest_tool(),
+            tools::character::start_crafting_project_tool(),
+            tools::character::advance_crafting_tool(),
+            tools::character::adjust_currency_tool(),
+            tools::character::get_currency_history_tool(),
+            tools::character::mark_character_dead_tool(),
+            tools::character::resurrect_character_tool(),
+            tools::character::set_character_portrait_tool(),
             // Map tools
             tools::map::create_map_tool(),
             tools::map::list_maps_tool(),
@@ -101,13 +121,43 @@ impl MimirHandler {
             tools::mapgen::generate_map_tool(),
             tools::mapgen::list_map_presets_tool(),
             tools::mapgen::validate_map_config_tool(),
+            // Travel planning
+            tools::travel::plan_travel_tool(),
+            // Foraging (adds to the active campaign's stash)
+            tools::forage::forage_tool(),
             // Catalog search (all categories unified by category param)
             tools::catalog::search_catalog_tool(),
         ]
     }
 
-    /// Route a tool call to the appropriate handler.
+    /// Run a tool call. A tool that changed data is recorded in the active
+    /// campaign's history (when history is enabled), as the app does after
+    /// its own saves.
     async fn execute_tool(&self, name: &str, args: Value) -> Result<Value, McpError> {
+        let result = self.route_tool(name, args).await?;
+        if ToolKind::of(name) != ToolKind::Read {
+            self.record_history(name);
+        }
+        Ok(result)
+    }
+
+    fn record_history(&self, tool_name: &str) {
+        let Some(campaign_id) = self.context.get_active_campaign_id() else {
+            return;
+        };
+        let mut db = match self.context.connect() {
+            Ok(db) => db,
+            Err(e) => {
+                warn!(error = %e, "Failed to record campaign history");
+                return;
+            }
+        };
+        CampaignHistoryService::new(&mut db, self.context.app_dir())
+            .record_after_save(&campaign_id, &format!("{} (via MCP)", tool_name));
+    }
+
+    /// Route a tool call to the appropriate handler.
+    async fn route_tool(&self, name: &str, args: Value) -> Result<Value, McpError> {
         match name {
             // Campaign tools
             "list_campaigns" => tools::campaign::list_campaigns(&self.context, args).await,
@@ -126,6 +176,15 @@ impl MimirHandler {
             "create_campaign" => tools::campaign::create_campaign(&self.context, args).await,
             "update_campaign" => tools::campaign::update_campaign(&self.context, args).await,
             "delete_campaign" => tools::campaign::delete_campaign(&self.context, args).await,
+            "get_session_transcript" => {
+                tools::campaign::get_session_transcript(&self.context, args).await
+            }
+
+            // Memory tools
+            "remember_fact" => tools::memory::remember_fact(&self.context, args).await,
+            "recall_facts" => tools::memory::recall_facts(&self.context, args).await,
+            "list_memories" => tools::memory::list_memories(&self.context, args).await,
+            "forget_fact" => tools::memory::forget_fact(&self.context, args).await,
 
             // Module tools
             "create_module" => tools::module::create_module(&self.context, args).await,
@@ -140,6 +199,10 @@ impl MimirHandler {
                 tools::module::remove_monster_from_module(&self.context, args).await
             }
             "add_item_to_module" => tools::module::add_item_to_module(&self.context, args).await,
+            "link_modules" => tools::module::link_modules(&self.context, args).await,
+            "check_module_completion" => {
+                tools::module::check_module_completion(&self.context, args).await
+            }
 
             // Document tools
             "list_documents" => tools::document::list_documents(&self.context, args).await,
@@ -179,6 +242,28 @@ impl MimirHandler {
             "list_character_spells" => {
                 tools::character::list_character_spells(&self.context, args).await
             }
+            "use_resource" => tools::character::use_resource(&self.context, args).await,
+            "restore_resource" => {
+                tools::character::restore_resource(&self.context, args).await
+            }
+            "take_rest" => tools::character::take_rest(&self.context, args).await,
+            "start_crafting_project" => {
+                tools::character::start_crafting_project(&self.context, args).await
+            }
+            "advance_crafting" => tools::character::advance_crafting(&self.context, args).await,
+            "adjust_currency" => tools::character::adjust_currency(&self.context, args).await,
+            "get_currency_history" => {
+                tools::character::get_currency_history(&self.context, args).await
+            }
+            "mark_character_dead" => {
+                tools::character::mark_character_dead(&self.context, args).await
+            }
+            "resurrect_character" => {
+                tools::character::resurrect_character(&self.context, args).await
+            }
+            "set_character_portrait" => {
+                tools::character::set_character_portrait(&self.context, args).await
+            }
 
             // Map tools
             "create_map" => tools::map::create_map(&self.context, args).await,
@@ -202,6 +287,12 @@ impl MimirHandler {
             "list_map_presets" => tools::mapgen::list_map_presets(args).await,
             "validate_map_config" => tools::mapgen::validate_map_config(args).await,
 
+            // Travel planning (no campaign context needed)
+            "plan_travel" => tools::travel::plan_travel(args).await,
+
+            // Foraging
+            "forage" => tools::forage::forage(&self.context, args).await,
+
             // Catalog search (dispatched by category param)
             "search_catalog" => tools::catalog::search_catalog(&self.context, args).await,
 
@@ -219,7 +310,7 @@ impl ServerHandler for MimirHandler {
     ) -> Result<ListToolsResult, RpcError> {
         info!("Handling list_tools request");
         Ok(ListToolsResult {
-            tools: Self::get_tools(),
+            tools: self.context.tool_policies.apply(Self::get_tools()),
             meta: None,
             next_cursor: None,
         })
@@ -230,14 +321,21 @@ impl ServerHandler for MimirHandler {
         params: CallToolRequestParams,
         _runtime: Arc<dyn McpServer>,
     ) -> Result<CallToolResult, rust_mcp_sdk::schema::schema_utils::CallToolError> {
-        info!(tool = %params.name, "Handling call_tool request");
+        let policy = self.context.tool_policies.policy_for(&params.name);
+        info!(tool = %params.name, %policy, "Handling call_tool request");
 
         let args = params
             .arguments
             .map(|m| Value::Object(m))
             .unwrap_or(Value::Object(Default::default()));
 
-        match self.execute_tool(&params.name, args).await {
+        let result = if policy == ToolPolicy::Deny {
+            Err(McpError::ToolDenied(params.name.clone()))
+        } else {
+            self.execute_tool(&params.name, args).await
+        };
+
+        match result {
             Ok(result) => {
                 let content = vec![ContentBlock::text_content(
                     serde_json::to_string_pretty(&result).unwrap_or_default(),
@@ -256,7 +354,7 @@ impl ServerHandler for MimirHandler {
                     content,
                     is_error: Some(true),
                     meta: None,
-                    structured_content: None,
+                    structured_content: Some(e.details()),
                 })
             }
         }
@@ -267,6 +365,7 @@ impl ServerHandler for MimirHandler {
 mod tests {
     use super::*;
     use crate::context::McpContext;
+    use mimir_core::services::{CampaignHistoryService, TrashKind, TrashService};
     use serde_json::json;
 
     /// Expected tool names — every MCP tool the server should publish.
@@ -282,6 +381,12 @@ mod tests {
         "export_campaign",
         "import_campaign",
         "preview_archive",
+        "get_session_transcript",
+        // Memory
+        "remember_fact",
+        "recall_facts",
+        "list_memories",
+        "forget_fact",
         // Module
         "create_module",
         "list_modules",
@@ -291,6 +396,8 @@ mod tests {
         "add_monster_to_module",
         "remove_monster_from_module",
         "add_item_to_module",
+        "link_modules",
+        "check_module_completion",
         // Document
         "list_documents",
         "read_document",
@@ -312,6 +419,16 @@ mod tests {
         "add_character_spell",
         "remove_character_spell",
         "list_character_spells",
+        "use_resource",
+        "restore_resource",
+        "take_rest",
+        "start_crafting_project",
+        "advance_crafting",
+        "adjust_currency",
+        "get_currency_history",
+        "mark_character_dead",
+        "resurrect_character",
+        "set_character_portrait",
         // Map
         "create_map",
         "list_maps",
@@ -331,6 +448,10 @@ mod tests {
         "generate_map",
         "list_map_presets",
         "validate_map_config",
+        // Travel
+        "plan_travel",
+        // Foraging
+        "forage",
         // Catalog
         "search_catalog",
     ];
@@ -750,6 +871,82 @@ mod tests {
         assert_eq!(res["characters"].as_array().unwrap().len(), 0);
     }
 
+    #[tokio::test]
+    async fn deletes_move_to_trash() {
+        let ctx = test_ctx();
+        let handler = MimirHandler::with_context(ctx.clone());
+        setup_campaign(&handler).await;
+
+        let res = call_ok(
+            &handler,
+            "create_character",
+            serde_json::json!({"name": "Gandalf", "character_type": "npc"}),
+        )
+        .await;
+        let char_id = res["character"]["id"].as_str().unwrap().to_string();
+        let res = call_ok(
+            &handler,
+            "create_document",
+            serde_json::json!({"title": "Lore", "document_type": "dm_notes", "content": "Old tales."}),
+        )
+        .await;
+        let doc_id = res["document"]["id"].as_str().unwrap().to_string();
+
+        call_ok(
+            &handler,
+            "delete_character",
+            serde_json::json!({"character_id": char_id}),
+        )
+        .await;
+        call_ok(
+            &handler,
+            "delete_document",
+            serde_json::json!({"document_id": doc_id}),
+        )
+        .await;
+
+        // Both can be restored from the app's trash
+        let mut db = ctx.connect().unwrap();
+        let trashed = TrashService::new(&mut db, ctx.app_data_dir())
+            .list(None)
+            .unwrap();
+        assert!(trashed
+            .iter()
+            .any(|t| t.kind == TrashKind::Character && t.id == char_id));
+        assert!(trashed
+            .iter()
+            .any(|t| t.kind == TrashKind::Document && t.id == doc_id));
+    }
+
+    #[tokio::test]
+    async fn writes_are_recorded_in_campaign_history() {
+        let ctx = test_ctx();
+        let handler = MimirHandler::with_context(ctx.clone());
+        let campaign_id = setup_campaign(&handler).await;
+        {
+            let mut db = ctx.connect().unwrap();
+            CampaignHistoryService::new(&mut db, ctx.app_dir())
+                .enable(&campaign_id)
+                .unwrap();
+        }
+
+        call_ok(
+            &handler,
+            "create_document",
+            serde_json::json!({"title": "Lore", "document_type": "dm_notes", "content": "Old tales."}),
+        )
+        .await;
+        call_ok(&handler, "list_documents", serde_json::json!({})).await;
+
+        // Only the write is recorded, on top of the commit made when enabling
+        let mut db = ctx.connect().unwrap();
+        let history = CampaignHistoryService::new(&mut db, ctx.app_dir())
+            .history(&campaign_id, 10)
+            .unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].message, "create_document (via MCP)");
+    }
+
     #[tokio::test]
     async fn character_filter_by_type() {
         let handler = MimirHandler::with_context(test_ctx());
@@ -830,6 +1027,30 @@ mod tests {
         }
     }
 
+    // -- Travel planning ------------------------------------------------------
+
+    #[tokio::test]
+    async fn plan_travel_without_campaign() {
+        let handler = MimirHandler::with_context(test_ctx());
+
+        let res = call_ok(
+            &handler,
+            "plan_travel",
+            serde_json::json!({"distance_miles": 48, "speeds": [30, 25], "terrain": "forest"}),
+        )
+        .await;
+        assert_eq!(res["plan"]["speed"], 25);
+        assert_eq!(res["plan"]["rations"], 10);
+
+        let err = call_err(
+            &handler,
+            "plan_travel",
+            serde_json::json!({"distance_miles": 10, "pace": "sprint"}),
+        )
+        .await;
+        assert!(matches!(err, McpError::InvalidArguments(_)));
+    }
+
     // -- Error cases ----------------------------------------------------------
 
     #[tokio::test]
@@ -848,6 +1069,7 @@ mod tests {
             "list_modules",
             "list_characters",
             "list_documents",
+            "forage",
         ];
 
         for tool in tools_needing_campaign {
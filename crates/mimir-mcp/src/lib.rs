@@ -6,10 +6,12 @@
 pub mod context;
 pub mod error;
 pub mod handler;
+pub mod policy;
 pub mod response;
 pub mod tools;
 
 pub use context::McpContext;
 pub use error::McpError;
 pub use handler::MimirHandler;
+pub use policy::{ToolPolicies, ToolPolicy};
 pub use response::McpResponse;
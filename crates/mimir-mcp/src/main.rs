@@ -47,7 +47,10 @@ async fn main() -> anyhow::Result<()> {
         instructions: Some(
             "Mimir MCP Server for D&D 5e campaign management. \
              Use list_campaigns to see available campaigns, then set_active_campaign \
-             to select one before using other tools."
+             to select one before using other tools. When a campaign response includes \
+             safety_constraints, treat them as hard constraints on all content you \
+             generate for that campaign. Use recall_facts to check what happened \
+             earlier in the campaign and remember_fact to save what should carry over."
                 .to_string(),
         ),
         meta: None,
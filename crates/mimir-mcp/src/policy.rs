@@ -0,0 +1,288 @@
+//! Tool Policies
+//!
+//! Per-tool confirmation policies for MCP tool calls.
+//!
+//! Every tool is classed as a read, write, or delete from its name, and each
+//! class (or a single tool) can be set to `auto`, `confirm`, or `deny` through
+//! the `MIMIR_TOOL_POLICY` environment variable, e.g.
+//! `MIMIR_TOOL_POLICY="deletes=deny,create_map=auto"`.
+//!
+//! Only `deny` is enforced by the server: denied tools are left out of the
+//! tool list and rejected if called anyway. `confirm` is advisory, since the
+//! server cannot ask the user anything itself; confirm tools are published
+//! with a note asking the client to check with the user first. Every tool
+//! also carries read-only/destructive hints matching its kind.
+
+use rust_mcp_sdk::schema::{Tool, ToolAnnotations};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::McpError;
+
+/// Environment variable holding the policy overrides.
+pub const TOOL_POLICY_ENV: &str = "MIMIR_TOOL_POLICY";
+
+/// Added to the description of tools whose policy is `confirm`.
+const CONFIRM_NOTE: &str = "Ask the user for permission before calling this tool.";
+
+/// What a tool does to campaign data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolKind {
+    /// Only reads data
+    Read,
+    /// Creates or changes data
+    Write,
+    /// Deletes data
+    Delete,
+}
+
+impl ToolKind {
+    /// Class a tool by its name.
+    pub fn of(tool_name: &str) -> Self {
+        const READ_PREFIXES: &[&str] = &[
+            "list_",
+            "get_",
+            "search_",
+            "preview_",
+            "validate_",
+            "recall_",
+            "check_",
+            "plan_",
+            "read_",
+            "export_",
+        ];
+        const DELETE_PREFIXES: &[&str] = &["delete_", "remove_", "forget_"];
+
+        if tool_name == "set_active_campaign"
+            || READ_PREFIXES.iter().any(|p| tool_name.starts_with(p))
+        {
+            ToolKind::Read
+        } else if DELETE_PREFIXES.iter().any(|p| tool_name.starts_with(p)) {
+            ToolKind::Delete
+        } else {
+            ToolKind::Write
+        }
+    }
+}
+
+/// How calls to a tool are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolPolicy {
+    /// Run without asking
+    Auto,
+    /// Ask the user before running (advisory; left to the client)
+    Confirm,
+    /// Never run
+    Deny,
+}
+
+impl ToolPolicy {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "auto" => Some(ToolPolicy::Auto),
+            "confirm" => Some(ToolPolicy::Confirm),
+            "deny" => Some(ToolPolicy::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ToolPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToolPolicy::Auto => write!(f, "auto"),
+            ToolPolicy::Confirm => write!(f, "confirm"),
+            ToolPolicy::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// Policies for every tool: one per kind, with per-tool overrides.
+#[derive(Debug, Clone)]
+pub struct ToolPolicies {
+    reads: ToolPolicy,
+    writes: ToolPolicy,
+    deletes: ToolPolicy,
+    overrides: HashMap<String, ToolPolicy>,
+}
+
+impl Default for ToolPolicies {
+    /// Reads run without asking; writes and deletes are confirmed.
+    fn default() -> Self {
+        Self {
+            reads: ToolPolicy::Auto,
+            writes: ToolPolicy::Confirm,
+            deletes: ToolPolicy::Confirm,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl ToolPolicies {
+    /// Load policies from `MIMIR_TOOL_POLICY`, falling back to the defaults.
+    pub fn from_env() -> Result<Self, McpError> {
+        match std::env::var(TOOL_POLICY_ENV) {
+            Ok(spec) if !spec.trim().is_empty() && !spec.starts_with("${") => Self::parse(&spec),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Parse a comma-separated list of `target=policy` entries, where the
+    /// target is `reads`, `writes`, `deletes`, or a tool name.
+    pub fn parse(spec: &str) -> Result<Self, McpError> {
+        let mut policies = Self::default();
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (target, value) = entry.split_once('=').ok_or_else(|| {
+                McpError::Initialization(format!(
+                    "Invalid {} entry '{}': expected target=policy",
+                    TOOL_POLICY_ENV, entry
+                ))
+            })?;
+            let policy = ToolPolicy::parse(value).ok_or_else(|| {
+                McpError::Initialization(format!(
+                    "Invalid {} policy '{}': expected auto, confirm, or deny",
+                    TOOL_POLICY_ENV,
+                    value.trim()
+                ))
+            })?;
+
+            match target.trim() {
+                "reads" => policies.reads = policy,
+                "writes" => policies.writes = policy,
+                "deletes" => policies.deletes = policy,
+                tool => {
+                    policies.overrides.insert(tool.to_string(), policy);
+                }
+            }
+        }
+
+        Ok(policies)
+    }
+
+    /// The policy for a tool.
+    pub fn policy_for(&self, tool_name: &str) -> ToolPolicy {
+        if let Some(policy) = self.overrides.get(tool_name) {
+            return *policy;
+        }
+        match ToolKind::of(tool_name) {
+            ToolKind::Read => self.reads,
+            ToolKind::Write => self.writes,
+            ToolKind::Delete => self.deletes,
+        }
+    }
+
+    /// Drop denied tools, annotate the rest with hints matching their kind,
+    /// and ask the client to confirm calls to confirm tools.
+    pub fn apply(&self, tools: Vec<Tool>) -> Vec<Tool> {
+        tools
+            .into_iter()
+            .filter_map(|mut tool| {
+                let policy = self.policy_for(&tool.name);
+                if policy == ToolPolicy::Deny {
+                    return None;
+                }
+                let kind = ToolKind::of(&tool.name);
+                tool.annotations = Some(ToolAnnotations {
+                    destructive_hint: Some(kind == ToolKind::Delete),
+                    idempotent_hint: None,
+                    open_world_hint: Some(false),
+                    read_only_hint: Some(kind == ToolKind::Read),
+                    title: None,
+                });
+                if policy == ToolPolicy::Confirm {
+                    let description = tool.description.take().unwrap_or_default();
+                    tool.description = Some(
+                        format!("{} {}", description, CONFIRM_NOTE)
+                            .trim_start()
+                            .to_string(),
+                    );
+                }
+                Some(tool)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_kinds() {
+        assert_eq!(ToolKind::of("list_campaigns"), ToolKind::Read);
+        assert_eq!(ToolKind::of("get_character"), ToolKind::Read);
+        assert_eq!(ToolKind::of("set_active_campaign"), ToolKind::Read);
+        assert_eq!(ToolKind::of("create_map"), ToolKind::Write);
+        assert_eq!(ToolKind::of("delete_campaign"), ToolKind::Delete);
+        assert_eq!(ToolKind::of("remove_token"), ToolKind::Delete);
+        assert_eq!(ToolKind::of("recall_facts"), ToolKind::Read);
+        assert_eq!(ToolKind::of("forget_fact"), ToolKind::Delete);
+        assert_eq!(ToolKind::of("check_module_completion"), ToolKind::Read);
+        assert_eq!(ToolKind::of("plan_travel"), ToolKind::Read);
+        assert_eq!(ToolKind::of("read_document"), ToolKind::Read);
+        assert_eq!(ToolKind::of("export_campaign"), ToolKind::Read);
+    }
+
+    #[test]
+    fn test_default_policies() {
+        let policies = ToolPolicies::default();
+        assert_eq!(policies.policy_for("list_maps"), ToolPolicy::Auto);
+        assert_eq!(policies.policy_for("create_map"), ToolPolicy::Confirm);
+        assert_eq!(policies.policy_for("delete_map"), ToolPolicy::Confirm);
+    }
+
+    #[test]
+    fn test_apply_hints_follow_kind() {
+        use rust_mcp_sdk::schema::ToolInputSchema;
+
+        let tool = |name: &str| Tool {
+            name: name.to_string(),
+            description: Some("Does a thing.".to_string()),
+            input_schema: ToolInputSchema::new(vec![], None, None),
+            title: None,
+            annotations: None,
+            icons: vec![],
+            execution: None,
+            output_schema: None,
+            meta: None,
+        };
+        let policies = ToolPolicies::parse("writes=auto, delete_map=deny").unwrap();
+        let tools = policies.apply(vec![
+            tool("get_map"),
+            tool("create_map"),
+            tool("delete_token"),
+            tool("delete_map"),
+        ]);
+        assert_eq!(tools.len(), 3);
+
+        let hints = |tool: &Tool| {
+            let annotations = tool.annotations.as_ref().unwrap();
+            (annotations.read_only_hint, annotations.destructive_hint)
+        };
+        assert_eq!(hints(&tools[0]), (Some(true), Some(false)));
+        // Auto writes still aren't read-only
+        assert_eq!(hints(&tools[1]), (Some(false), Some(false)));
+        assert_eq!(hints(&tools[2]), (Some(false), Some(true)));
+
+        assert_eq!(tools[1].description.as_deref(), Some("Does a thing."));
+        assert!(tools[2]
+            .description
+            .as_deref()
+            .unwrap()
+            .ends_with(CONFIRM_NOTE));
+    }
+
+    #[test]
+    fn test_parse_overrides() {
+        let policies =
+            ToolPolicies::parse("deletes=deny, writes=auto, create_map=Confirm").unwrap();
+        assert_eq!(policies.policy_for("delete_map"), ToolPolicy::Deny);
+        assert_eq!(policies.policy_for("update_map"), ToolPolicy::Auto);
+        assert_eq!(policies.policy_for("create_map"), ToolPolicy::Confirm);
+        assert_eq!(policies.policy_for("get_map"), ToolPolicy::Auto);
+
+        assert!(ToolPolicies::parse("deletes").is_err());
+        assert!(ToolPolicies::parse("deletes=never").is_err());
+    }
+}
@@ -4,8 +4,8 @@
 
 use mimir_core::dal::campaign as dal;
 use mimir_core::services::{
-    ArchiveService, CampaignService, CharacterService, CreateCampaignInput, ModuleService,
-    UpdateCampaignInput,
+    ArchiveService, CampaignService, CharacterService, CreateCampaignInput, GameSessionService,
+    ModuleService, SafetyToolsService, UpdateCampaignInput,
 };
 use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
 use serde_json::{json, Value};
@@ -39,7 +39,9 @@ pub fn set_active_campaign_tool() -> Tool {
     Tool {
         name: "set_active_campaign".to_string(),
         description: Some(
-            "Set the active campaign. Most other tools require an active campaign.".to_string(),
+            "Set the active campaign. Most other tools require an active campaign. \
+             Returns the campaign's safety constraints, which must be followed."
+                .to_string(),
         ),
         input_schema: ToolInputSchema::new(
             vec!["campaign_id".to_string()],
@@ -63,7 +65,8 @@ pub fn get_campaign_details_tool() -> Tool {
     Tool {
         name: "get_campaign_details".to_string(),
         description: Some(
-            "Get detailed information about a campaign including modules and characters"
+            "Get detailed information about a campaign including modules, characters, \
+             and safety constraints"
                 .to_string(),
         ),
         input_schema: ToolInputSchema::new(
@@ -209,6 +212,10 @@ pub async fn set_active_campaign(ctx: &Arc<McpContext>, args: Value) -> Result<V
         .map_err(|e| McpError::Internal(e.to_string()))?
         .ok_or_else(|| McpError::InvalidArguments(format!("Campaign '{}' not found", campaign_id)))?;
 
+    let safety_constraints = SafetyToolsService::new(&mut db)
+        .prompt_constraints(campaign_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
     ctx.set_active_campaign_id(Some(campaign_id.to_string()));
 
     McpResponse::success(json!({
@@ -217,7 +224,8 @@ pub async fn set_active_campaign(ctx: &Arc<McpContext>, args: Value) -> Result<V
             "id": campaign.id,
             "name": campaign.name,
             "description": campaign.description
-        }
+        },
+        "safety_constraints": safety_constraints
     }))
 }
 
@@ -277,6 +285,11 @@ pub async fn get_campaign_details(ctx: &Arc<McpContext>, args: Value) -> Result<
         })
         .collect();
 
+    // Get safety tools - these are hard content constraints
+    let safety_constraints = SafetyToolsService::new(&mut db)
+        .prompt_constraints(&campaign_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
     McpResponse::ok(json!({
         "campaign": {
             "id": campaign.id,
@@ -284,6 +297,7 @@ pub async fn get_campaign_details(ctx: &Arc<McpContext>, args: Value) -> Result<
             "description": campaign.description,
             "created_at": campaign.created_at
         },
+        "safety_constraints": safety_constraints,
         "modules": module_data,
         "module_count": modules.len(),
         "characters": character_data,
@@ -564,3 +578,71 @@ pub async fn preview_archive(_ctx: &Arc<McpContext>, args: Value) -> Result<Valu
         }).collect::<Vec<_>>()
     }))
 }
+
+// =============================================================================
+// Session Tool Definitions
+// =============================================================================
+
+pub fn get_session_transcript_tool() -> Tool {
+    Tool {
+        name: "get_session_transcript".to_string(),
+        description: Some(
+            "Get the transcript of a game session in the active campaign, for writing a session summary"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec![],
+            create_properties(vec![
+                ("session_id", "string", "The ID of the session (default: the most recent session with a transcript)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+// =============================================================================
+// Session Tool Implementations
+// =============================================================================
+
+pub async fn get_session_transcript(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let campaign_id = ctx
+        .get_active_campaign_id()
+        .ok_or(McpError::NoActiveCampaign)?;
+
+    let mut db = ctx.connect()?;
+    let sessions = GameSessionService::new(&mut db)
+        .list_for_campaign(&campaign_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
+    let session = match args.get("session_id").and_then(|v| v.as_str()) {
+        Some(session_id) => sessions
+            .into_iter()
+            .find(|s| s.id == session_id)
+            .ok_or_else(|| {
+                McpError::InvalidArguments(format!("Session '{}' not found", session_id))
+            })?,
+        None => sessions
+            .into_iter()
+            .find(|s| s.transcript.is_some())
+            .ok_or_else(|| {
+                McpError::InvalidArguments(
+                    "No session in this campaign has a transcript".to_string(),
+                )
+            })?,
+    };
+
+    McpResponse::ok(json!({
+        "session_id": session.id,
+        "session_number": session.session_number,
+        "title": session.title,
+        "started_at": session.started_at,
+        "ended_at": session.ended_at,
+        "transcript": session.transcript
+    }))
+}
@@ -2,12 +2,12 @@
 //!
 //! Single `search_catalog` tool for searching the D&D 5e catalog across all categories.
 
-use mimir_core::dal::campaign as campaign_dal;
 use mimir_core::dal::catalog as catalog_dal;
 use mimir_core::models::catalog::{
     BackgroundFilter, ClassFilter, ConditionFilter, FeatFilter, ItemFilter, MonsterFilter,
     RaceFilter, SpellFilter,
 };
+use mimir_core::services::CampaignService;
 use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -40,7 +40,7 @@ pub fn search_catalog_tool() -> Tool {
     Tool {
         name: "search_catalog".to_string(),
         description: Some(
-            "Search the D&D 5e catalog by category. Supports monsters, items, spells, races, classes, backgrounds, feats, and conditions. Category-specific filters are available for monsters (cr_min, cr_max, monster_type), items (rarity, item_type), and spells (level, school, class_name). Monster searches also include homebrew monsters from the active campaign by default."
+            "Search the D&D 5e catalog by category. Supports monsters, items, spells, races, classes, backgrounds, feats, and conditions. Category-specific filters are available for monsters (cr_min, cr_max, monster_type), items (rarity, item_type), and spells (level, school, class_name). Monster searches also include homebrew monsters from the active campaign by default. Results are limited to the active campaign's allowed books and ruleset edition, and to SRD content if the campaign is SRD-only."
                 .to_string(),
         ),
         input_schema: ToolInputSchema::new(
@@ -102,20 +102,23 @@ pub async fn search_catalog(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
 
     let mut db = ctx.connect()?;
 
-    // Get campaign sources for filtering (shared across all categories)
-    let campaign_sources = if let Some(campaign_id) = ctx.get_active_campaign_id() {
-        let sources = campaign_dal::list_campaign_source_codes(&mut db, &campaign_id)
+    // Get campaign sources for filtering (shared across all categories),
+    // narrowed to the campaign's edition if it has one
+    let (campaign_sources, srd_only) = if let Some(campaign_id) = ctx.get_active_campaign_id() {
+        let mut service = CampaignService::new(&mut db);
+        let sources = service
+            .search_sources(&campaign_id)
             .map_err(|e| McpError::Internal(e.to_string()))?;
-        if sources.is_empty() {
-            None
-        } else {
-            Some(sources)
-        }
+        let srd_only = service
+            .get(&campaign_id)
+            .map_err(|e| McpError::Internal(e.to_string()))?
+            .is_some_and(|c| c.is_srd_only());
+        (sources, srd_only)
     } else {
-        None
+        (None, false)
     };
 
-    match category {
+    let response = match category {
         "monster" => search_monsters(ctx, &args, &mut db, limit, campaign_sources).await,
         "item" => search_items(&args, &mut db, limit, campaign_sources),
         "spell" => search_spells(&args, &mut db, limit, campaign_sources),
@@ -125,9 +128,55 @@ pub async fn search_catalog(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
         "feat" => search_feats(&args, &mut db, limit, campaign_sources),
         "condition" => search_conditions(&args, &mut db, limit, campaign_sources),
         _ => unreachable!(),
+    }?;
+
+    if srd_only {
+        retain_srd_results(&mut db, category, response)
+    } else {
+        Ok(response)
     }
 }
 
+/// Drop book content from search results, keeping SRD entries and homebrew.
+fn retain_srd_results(
+    db: &mut diesel::SqliteConnection,
+    category: &str,
+    mut response: Value,
+) -> Result<Value, McpError> {
+    let Some(obj) = response.as_object_mut() else {
+        return Ok(response);
+    };
+
+    let mut count = 0;
+    for results in obj.values_mut() {
+        let Some(results) = results.as_array_mut() else {
+            continue;
+        };
+        let mut kept = Vec::with_capacity(results.len());
+        for result in results.drain(..) {
+            let is_homebrew = result
+                .get("is_homebrew")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let name = result.get("name").and_then(|v| v.as_str()).unwrap_or("");
+            let source = result.get("source").and_then(|v| v.as_str()).unwrap_or("");
+            let licensed = is_homebrew
+                || catalog_dal::get_catalog_license(db, category, name, source)
+                    .map_err(|e| McpError::Internal(e.to_string()))?
+                    .is_some();
+            if licensed {
+                kept.push(result);
+            }
+        }
+        count = kept.len();
+        *results = kept;
+    }
+    obj.insert("count".to_string(), json!(count));
+    obj.insert("srd_only".to_string(), json!(true));
+
+    Ok(response)
+}
+
 // =============================================================================
 // Category-specific implementations
 // =============================================================================
@@ -3,9 +3,15 @@
 //! MCP tools for character (NPC and PC) management.
 
 use mimir_core::dal::campaign as dal;
-use mimir_core::models::campaign::NewCharacterSpell;
+use mimir_core::models::campaign::{
+    CharacterDeath, CharacterResource, CraftingProject, CurrencyTransaction, NewCharacterSpell,
+    RestType,
+};
 use mimir_core::services::{
-    AddInventoryInput, CharacterService, CreateCharacterInput, UpdateCharacterInput,
+    AddInventoryInput, AdvanceCraftingInput, CharacterImageService, CharacterService,
+    ClassResourceService, CraftingService, CreateCharacterInput, CurrencyAdjustment,
+    CurrencyService, CustomFieldService, DeathService, MarkDeadInput, ResurrectInput,
+    StartCraftingInput, TrashKind, TrashService, UpdateCharacterInput,
 };
 use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
 use serde_json::{json, Value};
@@ -47,7 +53,8 @@ pub fn get_character_tool() -> Tool {
     Tool {
         name: "get_character".to_string(),
         description: Some(
-            "Get detailed information about a character including classes and inventory"
+            "Get detailed information about a character including classes, inventory, \
+             and class resources (rage, ki, channel divinity) with uses remaining"
                 .to_string(),
         ),
         input_schema: ToolInputSchema::new(
@@ -74,8 +81,11 @@ pub fn create_character_tool() -> Tool {
                 ("name", "string", "Name of the character"),
                 ("character_type", "string", "Type: pc or npc"),
                 ("player_name", "string", "Player name (PCs only, defaults to 'Player')"),
-                ("race_name", "string", "Race name (e.g., 'Elf'). Populates race proficiencies."),
+                ("race_name", "string", "Race name (e.g., 'Elf'). Applies racial ability increases, speed, darkvision, resistances, proficiencies, and innate spells."),
                 ("race_source", "string", "Race source book (default: PHB)"),
+                ("subrace_name", "string", "Subrace name as listed in the catalog (e.g., 'High' for a High Elf)"),
+                ("subrace_source", "string", "Subrace source book (default: race source)"),
+                ("racial_ability_choices", "array", "Abilities picked for racial increases that offer a choice (e.g., ['dex', 'con'] for a Half-Elf)"),
                 ("class_name", "string", "Starting class (e.g., 'Fighter'). Populates saving throws, armor, weapon, and tool proficiencies."),
                 ("class_source", "string", "Class source book (default: PHB)"),
                 ("background_name", "string", "Background name (e.g., 'Acolyte'). Populates skill, tool, and language proficiencies."),
@@ -119,6 +129,7 @@ pub fn edit_character_tool() -> Tool {
                 ("ep", "integer", "Electrum pieces"),
                 ("gp", "integer", "Gold pieces"),
                 ("pp", "integer", "Platinum pieces"),
+                ("currency_reason", "string", "Why the coins changed, for the currency history"),
                 ("module_id", "string", "Assign to module (NPCs only)"),
                 ("npc_role", "string", "NPC's role in the module"),
                 ("npc_location", "string", "NPC's location"),
@@ -127,6 +138,11 @@ pub fn edit_character_tool() -> Tool {
                 ("ideals", "string", "Ideals"),
                 ("bonds", "string", "Bonds"),
                 ("flaws", "string", "Flaws"),
+                (
+                    "custom_fields",
+                    "object",
+                    "Campaign custom field values by field key, e.g. {\"sanity\": 45}; null resets a field to its default",
+                ),
             ]),
             None,
         ),
@@ -142,7 +158,10 @@ pub fn edit_character_tool() -> Tool {
 pub fn delete_character_tool() -> Tool {
     Tool {
         name: "delete_character".to_string(),
-        description: Some("Delete a character and all associated data".to_string()),
+        description: Some(
+            "Move a character to the trash. It can be restored from the app until the trash is emptied"
+                .to_string(),
+        ),
         input_schema: ToolInputSchema::new(
             vec!["character_id".to_string()],
             create_properties(vec![("character_id", "string", "The ID of the character to delete")]),
@@ -269,6 +288,7 @@ pub fn level_up_character_tool() -> Tool {
                 ("asi_increase2", "integer", "Amount for second ability (for ASI)"),
                 ("feat_name", "string", "Feat name (if choosing feat)"),
                 ("feat_source", "string", "Feat source (default: PHB)"),
+                ("feat_ability", "string", "Ability to increase when the feat offers a choice (e.g. con for Resilient)"),
             ]),
             None,
         ),
@@ -301,6 +321,7 @@ pub fn add_character_spell_tool() -> Tool {
                 ("spell_source", "string", "Source book abbreviation (e.g. PHB, XGE) or HB for homebrew"),
                 ("source_class", "string", "Class that grants this spell (e.g. Wizard, Cleric)"),
                 ("prepared", "boolean", "Whether the spell starts prepared (default: false)"),
+                ("scribed", "boolean", "Whether a wizard copied the spell into their spellbook from a scroll or another book (default: false)"),
             ]),
             None,
         ),
@@ -357,6 +378,265 @@ pub fn list_character_spells_tool() -> Tool {
     }
 }
 
+pub fn use_resource_tool() -> Tool {
+    Tool {
+        name: "use_resource".to_string(),
+        description: Some(
+            "Spend uses of a character's class resource (e.g. Rage, Ki Points, Channel Divinity)"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "resource_name".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the character"),
+                ("resource_name", "string", "Name of the resource, as listed by get_character"),
+                ("source_class", "string", "Class granting the resource, if several classes grant one by that name"),
+                ("amount", "integer", "Uses to spend (default: 1)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn restore_resource_tool() -> Tool {
+    Tool {
+        name: "restore_resource".to_string(),
+        description: Some(
+            "Restore uses of a character's class resource outside of a rest".to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "resource_name".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the character"),
+                ("resource_name", "string", "Name of the resource, as listed by get_character"),
+                ("source_class", "string", "Class granting the resource, if several classes grant one by that name"),
+                ("amount", "integer", "Uses to restore (default: all)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn take_rest_tool() -> Tool {
+    Tool {
+        name: "take_rest".to_string(),
+        description: Some(
+            "Have a character take a short or long rest, restoring the class resources it recharges"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "rest_type".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the character"),
+                ("rest_type", "string", "Kind of rest: short or long"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn start_crafting_project_tool() -> Tool {
+    Tool {
+        name: "start_crafting_project".to_string(),
+        description: Some(
+            "Start a downtime crafting project for a catalog item, using the XGE rules for gold, time, tools, and exotic ingredients"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec![
+                "character_id".to_string(),
+                "item_name".to_string(),
+                "item_source".to_string(),
+            ],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the crafting character"),
+                ("item_name", "string", "Name of the item to craft"),
+                ("item_source", "string", "Source book of the item (e.g., PHB, DMG), or HB for campaign homebrew"),
+                ("quantity", "integer", "Number of items (default: 1)"),
+                ("pay_from_purse", "boolean", "Pay the materials cost from the character's coins now (default: true)"),
+                ("ignore_requirements", "boolean", "Skip the level and tool proficiency checks (default: false)"),
+                ("notes", "string", "Notes about the project"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn advance_crafting_tool() -> Tool {
+    Tool {
+        name: "advance_crafting".to_string(),
+        description: Some(
+            "Log downtime days against a crafting project; the finished item goes into the crafter's inventory"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["project_id".to_string()],
+            create_properties(vec![
+                ("project_id", "string", "The ID of the crafting project"),
+                ("days", "integer", "Downtime days spent crafting (default: 0)"),
+                ("ingredient_acquired", "boolean", "Mark the exotic ingredient as found"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn adjust_currency_tool() -> Tool {
+    Tool {
+        name: "adjust_currency".to_string(),
+        description: Some(
+            "Add or spend a character's coins with a reason recorded in their currency history. Negative amounts are spent; with make_change, missing denominations are paid from other coins"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "reason".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the character"),
+                ("reason", "string", "Why the coins changed (e.g., 'Bought rations')"),
+                ("cp", "integer", "Copper pieces gained (negative to spend)"),
+                ("sp", "integer", "Silver pieces gained (negative to spend)"),
+                ("ep", "integer", "Electrum pieces gained (negative to spend)"),
+                ("gp", "integer", "Gold pieces gained (negative to spend)"),
+                ("pp", "integer", "Platinum pieces gained (negative to spend)"),
+                ("make_change", "boolean", "Pay from other denominations and take change (default: false)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn get_currency_history_tool() -> Tool {
+    Tool {
+        name: "get_currency_history".to_string(),
+        description: Some("List a character's currency transactions, newest first".to_string()),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string()],
+            create_properties(vec![("character_id", "string", "The ID of the character")]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn mark_character_dead_tool() -> Tool {
+    Tool {
+        name: "mark_character_dead".to_string(),
+        description: Some(
+            "Mark a character dead with a cause, dated on their party's calendar. Dead characters are left out of party XP and encounter budgets"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "cause".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the character"),
+                ("cause", "string", "How the character died"),
+                ("party_id", "string", "Party whose calendar dates the death (default: the character's only party)"),
+                ("day", "integer", "In-world day of the death (default: the party's current day)"),
+                ("notes", "string", "Notes (last words, where the body lies)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn resurrect_character_tool() -> Tool {
+    Tool {
+        name: "resurrect_character".to_string(),
+        description: Some(
+            "Bring a dead character back to life, recording the method and its cost".to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "method".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the dead character"),
+                ("method", "string", "How they came back (e.g., 'Raise Dead', 'Revivify')"),
+                ("cost_gp", "number", "Cost in gold (default: the spell's material component cost)"),
+                ("paid_by", "string", "ID of the character who pays from their purse"),
+                ("party_id", "string", "Party whose calendar dates the resurrection"),
+                ("day", "integer", "In-world day (default: the party's current day)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn set_character_portrait_tool() -> Tool {
+    Tool {
+        name: "set_character_portrait".to_string(),
+        description: Some(
+            "Set a character's portrait from a PNG or JPEG file on disk (e.g., an image you generated) and build their map token from it. Replaces any existing portrait and token"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["character_id".to_string(), "file_path".to_string()],
+            create_properties(vec![
+                ("character_id", "string", "The ID of the character"),
+                ("file_path", "string", "Absolute path to the PNG or JPEG image file"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
 // =============================================================================
 // Tool Implementations
 // =============================================================================
@@ -402,6 +682,7 @@ pub async fn list_characters(ctx: &Arc<McpContext>, args: Value) -> Result<Value
                 "id": c.id,
                 "name": c.name,
                 "is_npc": c.is_npc(),
+                "is_dead": c.is_dead,
                 "race_name": c.race_name,
                 "role": c.role,
                 "location": c.location,
@@ -443,6 +724,15 @@ pub async fn get_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
     let classes = dal::list_character_classes(&mut db, character_id)
         .map_err(|e| McpError::Internal(e.to_string()))?;
 
+    // Campaign custom fields (house-ruled stats)
+    let custom_fields = CustomFieldService::new(&mut db)
+        .character_values(character_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
+    let resources = ClassResourceService::new(&mut db)
+        .list_resources(character_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
     let class_data: Vec<Value> = classes
         .iter()
         .map(|c| {
@@ -474,9 +764,12 @@ pub async fn get_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
             "id": character.id,
             "name": character.name,
             "is_npc": character.is_npc(),
+            "is_dead": character.is_dead,
             "player_name": character.player_name,
             "race_name": character.race_name,
             "race_source": character.race_source,
+            "subrace_name": character.subrace_name,
+            "subrace_source": character.subrace_source,
             "background_name": character.background_name,
             "background_source": character.background_source,
             "strength": character.strength,
@@ -496,10 +789,15 @@ pub async fn get_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
             "flaws": character.flaws,
             "role": character.role,
             "location": character.location,
-            "faction": character.faction
+            "faction": character.faction,
+            "speed": character.speed,
+            "darkvision": character.darkvision,
+            "resistances": character.parse_resistances().unwrap_or_default()
         },
         "classes": class_data,
-        "inventory": inv_data
+        "inventory": inv_data,
+        "custom_fields": custom_fields,
+        "resources": resources.iter().map(resource_json).collect::<Vec<_>>()
     }))
 }
 
@@ -541,6 +839,28 @@ pub async fn create_character(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
             .and_then(|v| v.as_str())
             .unwrap_or("PHB");
         input = input.with_race(race, race_source);
+
+        if let Some(subrace) = args.get("subrace_name").and_then(|v| v.as_str()) {
+            let subrace_source = args
+                .get("subrace_source")
+                .and_then(|v| v.as_str())
+                .unwrap_or(race_source);
+            input = input.with_subrace(subrace, subrace_source);
+        }
+    }
+
+    // Set racial ability choices if provided
+    if let Some(choices) = args
+        .get("racial_ability_choices")
+        .and_then(|v| v.as_array())
+    {
+        let abilities: Vec<String> = choices
+            .iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect();
+        if !abilities.is_empty() {
+            input = input.with_racial_ability_choices(abilities);
+        }
     }
 
     // Set class if provided (enables proficiency auto-population)
@@ -580,7 +900,17 @@ pub async fn create_character(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
         "id": character.id,
         "name": character.name,
         "is_npc": character.is_npc(),
-        "race_name": character.race_name
+        "race_name": character.race_name,
+        "subrace_name": character.subrace_name,
+        "ability_scores": [
+            character.strength,
+            character.dexterity,
+            character.constitution,
+            character.intelligence,
+            character.wisdom,
+            character.charisma
+        ],
+        "speed": character.speed
     }))
 }
 
@@ -677,6 +1007,10 @@ pub async fn edit_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
             gp.unwrap_or(cur.gp as i64) as i32,
             pp.unwrap_or(cur.pp as i64) as i32,
         ]);
+        update.currency_reason = args
+            .get("currency_reason")
+            .and_then(|v| v.as_str())
+            .map(String::from);
     }
 
     // NPC-specific fields
@@ -708,6 +1042,24 @@ pub async fn edit_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
         .update(character_id, update)
         .map_err(|e| McpError::Internal(e.to_string()))?;
 
+    // Custom fields - values are validated against each field's type
+    let mut custom_fields = None;
+    if let Some(values) = args.get("custom_fields").and_then(|v| v.as_object()) {
+        let mut field_service = CustomFieldService::new(&mut db);
+        for (key, value) in values {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                Value::Null => String::new(),
+                other => other.to_string(),
+            };
+            custom_fields = Some(
+                field_service
+                    .set_value(character_id, key, &value)
+                    .map_err(|e| McpError::InvalidArguments(e.to_string()))?,
+            );
+        }
+    }
+
     McpResponse::updated("character", json!({
         "id": character.id,
         "name": character.name,
@@ -727,7 +1079,8 @@ pub async fn edit_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
         "pp": character.pp,
         "role": character.role,
         "location": character.location,
-        "faction": character.faction
+        "faction": character.faction,
+        "custom_fields": custom_fields
     }))
 }
 
@@ -738,10 +1091,10 @@ pub async fn delete_character(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
         .ok_or_else(|| McpError::InvalidArguments("character_id is required".to_string()))?;
 
     let mut db = ctx.connect()?;
-    let mut service = CharacterService::new(&mut db);
 
-    service
-        .delete(character_id)
+    // Trashed like in the app, so the character can be restored until it is purged
+    TrashService::new(&mut db, ctx.app_data_dir())
+        .trash(TrashKind::Character, character_id)
         .map_err(|e| McpError::Internal(e.to_string()))?;
 
     McpResponse::deleted(character_id)
@@ -967,9 +1320,11 @@ pub async fn level_up_character(ctx: &Arc<McpContext>, args: Value) -> Result<Va
             let name = args.get("feat_name").and_then(|v| v.as_str())
                 .ok_or_else(|| McpError::InvalidArguments("feat_name required for feat choice".to_string()))?;
             let source = args.get("feat_source").and_then(|v| v.as_str()).unwrap_or("PHB");
+            let ability = args.get("feat_ability").and_then(|v| v.as_str()).map(|s| s.to_string());
             Some(AsiOrFeat::Feat {
                 name: name.to_string(),
                 source: source.to_string(),
+                ability,
             })
         }
         _ => None,
@@ -1003,7 +1358,8 @@ pub async fn level_up_character(ctx: &Arc<McpContext>, args: Value) -> Result<Va
         },
         "hp_gained": result.hp_gained,
         "new_total_level": result.new_total_level,
-        "is_multiclass": result.is_multiclass
+        "is_multiclass": result.is_multiclass,
+        "feat": result.feat
     }))
 }
 
@@ -1025,6 +1381,7 @@ pub async fn add_character_spell(ctx: &Arc<McpContext>, args: Value) -> Result<V
         .and_then(|v| v.as_str())
         .ok_or_else(|| McpError::InvalidArguments("source_class required".to_string()))?;
     let prepared = args.get("prepared").and_then(|v| v.as_bool()).unwrap_or(false);
+    let scribed = args.get("scribed").and_then(|v| v.as_bool()).unwrap_or(false);
 
     let mut db = ctx.connect()?;
 
@@ -1041,6 +1398,7 @@ pub async fn add_character_spell(ctx: &Arc<McpContext>, args: Value) -> Result<V
     let id = uuid::Uuid::new_v4().to_string();
     let new_spell = NewCharacterSpell::new(&id, character_id, spell_name, spell_source, source_class);
     let new_spell = if prepared { new_spell.prepared() } else { new_spell };
+    let new_spell = if scribed { new_spell.scribed() } else { new_spell };
 
     dal::insert_character_spell(&mut db, &new_spell)
         .map_err(|e| McpError::Internal(e.to_string()))?;
@@ -1053,7 +1411,8 @@ pub async fn add_character_spell(ctx: &Arc<McpContext>, args: Value) -> Result<V
             "spell_name": spell_name,
             "spell_source": spell_source,
             "source_class": source_class,
-            "prepared": prepared
+            "prepared": prepared,
+            "scribed": scribed
         }
     }))
 }
@@ -1141,7 +1500,8 @@ pub async fn list_character_spells(ctx: &Arc<McpContext>, args: Value) -> Result
                 "spell_name": s.spell_name,
                 "spell_source": s.spell_source,
                 "source_class": s.source_class,
-                "prepared": s.is_prepared()
+                "prepared": s.is_prepared(),
+                "scribed": s.is_scribed()
             })
         })
         .collect();
@@ -1152,3 +1512,379 @@ pub async fn list_character_spells(ctx: &Arc<McpContext>, args: Value) -> Result
         "spells": spell_data
     }))
 }
+
+/// Summarize a class resource for tool responses.
+fn resource_json(resource: &CharacterResource) -> Value {
+    json!({
+        "resource_name": resource.resource_name,
+        "source_class": resource.source_class,
+        "current_uses": resource.current_uses,
+        "max_uses": resource.max_uses,
+        "recharge": resource.recharge
+    })
+}
+
+/// Find a character's class resource by name, and by class when given.
+fn find_resource(
+    service: &mut ClassResourceService,
+    character_id: &str,
+    args: &Value,
+) -> Result<CharacterResource, McpError> {
+    let resource_name = args
+        .get("resource_name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("resource_name required".to_string()))?;
+    let source_class = args.get("source_class").and_then(|v| v.as_str());
+
+    let resources = service
+        .list_resources(character_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+    resources
+        .into_iter()
+        .find(|r| {
+            r.resource_name.eq_ignore_ascii_case(resource_name)
+                && source_class.map_or(true, |c| r.source_class.eq_ignore_ascii_case(c))
+        })
+        .ok_or_else(|| {
+            McpError::InvalidArguments(format!(
+                "Character has no class resource named {}",
+                resource_name
+            ))
+        })
+}
+
+pub async fn use_resource(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let amount = args.get("amount").and_then(|v| v.as_i64()).unwrap_or(1) as i32;
+
+    let mut db = ctx.connect()?;
+    let mut service = ClassResourceService::new(&mut db);
+
+    let resource = find_resource(&mut service, character_id, &args)?;
+    let resource = service
+        .use_resource(&resource.id, amount)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "resource_used",
+        "character_id": character_id,
+        "resource": resource_json(&resource)
+    }))
+}
+
+pub async fn restore_resource(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let amount = args
+        .get("amount")
+        .and_then(|v| v.as_i64())
+        .map(|a| a as i32);
+
+    let mut db = ctx.connect()?;
+    let mut service = ClassResourceService::new(&mut db);
+
+    let resource = find_resource(&mut service, character_id, &args)?;
+    let resource = service
+        .restore_resource(&resource.id, amount)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "resource_restored",
+        "character_id": character_id,
+        "resource": resource_json(&resource)
+    }))
+}
+
+pub async fn take_rest(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let rest_type = args
+        .get("rest_type")
+        .and_then(|v| v.as_str())
+        .and_then(RestType::from_str)
+        .ok_or_else(|| McpError::InvalidArguments("rest_type must be short or long".to_string()))?;
+
+    let mut db = ctx.connect()?;
+    let result = ClassResourceService::new(&mut db)
+        .take_rest(character_id, rest_type)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "rest_taken",
+        "character_id": character_id,
+        "rest_type": rest_type.as_str(),
+        "restored": result.restored,
+        "resources": result.resources.iter().map(resource_json).collect::<Vec<_>>()
+    }))
+}
+
+fn crafting_project_json(project: &CraftingProject) -> Value {
+    json!({
+        "project_id": project.id,
+        "item_name": project.item_name,
+        "item_source": project.item_source,
+        "quantity": project.quantity,
+        "rarity": project.rarity,
+        "cost_gp": project.cost_cp as f64 / 100.0,
+        "days_required": project.days_required,
+        "days_spent": project.days_spent,
+        "days_remaining": project.days_remaining(),
+        "tool": project.tool,
+        "ingredient": project.ingredient,
+        "ingredient_acquired": project.ingredient_acquired,
+        "status": project.status
+    })
+}
+
+pub async fn start_crafting_project(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let item_name = args
+        .get("item_name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("item_name required".to_string()))?;
+    let item_source = args
+        .get("item_source")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("item_source required".to_string()))?;
+
+    let input = StartCraftingInput {
+        character_id: character_id.to_string(),
+        item_name: item_name.to_string(),
+        item_source: item_source.to_string(),
+        quantity: args
+            .get("quantity")
+            .and_then(|v| v.as_i64())
+            .map(|q| q as i32),
+        pay_from_purse: args
+            .get("pay_from_purse")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        ignore_requirements: args
+            .get("ignore_requirements")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        notes: args.get("notes").and_then(|v| v.as_str()).map(String::from),
+    };
+
+    let mut db = ctx.connect()?;
+    let project = CraftingService::new(&mut db)
+        .start(input)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "crafting_started",
+        "character_id": character_id,
+        "project": crafting_project_json(&project)
+    }))
+}
+
+pub async fn advance_crafting(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let project_id = args
+        .get("project_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("project_id required".to_string()))?;
+
+    let input = AdvanceCraftingInput {
+        days: args.get("days").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+        ingredient_acquired: args.get("ingredient_acquired").and_then(|v| v.as_bool()),
+    };
+
+    let mut db = ctx.connect()?;
+    let progress = CraftingService::new(&mut db)
+        .advance(project_id, input)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    let action = if progress.item.is_some() {
+        "crafting_completed"
+    } else {
+        "crafting_advanced"
+    };
+    McpResponse::success(json!({
+        "action": action,
+        "project": crafting_project_json(&progress.project),
+        "inventory_item_id": progress.item.as_ref().map(|i| i.id.clone())
+    }))
+}
+
+fn currency_transaction_json(transaction: &CurrencyTransaction) -> Value {
+    json!({
+        "transaction_id": transaction.id,
+        "reason": transaction.reason,
+        "cp": transaction.cp,
+        "sp": transaction.sp,
+        "ep": transaction.ep,
+        "gp": transaction.gp,
+        "pp": transaction.pp,
+        "created_at": transaction.created_at
+    })
+}
+
+pub async fn adjust_currency(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let reason = args
+        .get("reason")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("reason required".to_string()))?;
+
+    let coin = |key: &str| args.get(key).and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+    let adjustment = CurrencyAdjustment {
+        amounts: [coin("cp"), coin("sp"), coin("ep"), coin("gp"), coin("pp")],
+        reason: reason.to_string(),
+        make_change: args
+            .get("make_change")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    };
+
+    let mut db = ctx.connect()?;
+    let result = CurrencyService::new(&mut db)
+        .adjust(character_id, adjustment)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "currency_adjusted",
+        "character_id": character_id,
+        "currency": {
+            "cp": result.balance[0],
+            "sp": result.balance[1],
+            "ep": result.balance[2],
+            "gp": result.balance[3],
+            "pp": result.balance[4]
+        },
+        "transaction": result.transaction.as_ref().map(currency_transaction_json)
+    }))
+}
+
+pub async fn get_currency_history(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+
+    let mut db = ctx.connect()?;
+    let history = CurrencyService::new(&mut db)
+        .history(character_id)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "character_id": character_id,
+        "transactions": history.iter().map(currency_transaction_json).collect::<Vec<_>>(),
+        "count": history.len()
+    }))
+}
+
+fn character_death_json(death: &CharacterDeath) -> Value {
+    json!({
+        "death_id": death.id,
+        "character_id": death.character_id,
+        "cause": death.cause,
+        "day": death.day,
+        "notes": death.notes,
+        "died_at": death.died_at,
+        "resurrection_method": death.resurrection_method,
+        "resurrection_cost_gp": death.resurrection_cost_cp.map(|cp| cp as f64 / 100.0),
+        "resurrection_day": death.resurrection_day,
+        "resurrected_at": death.resurrected_at
+    })
+}
+
+pub async fn mark_character_dead(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let cause = args
+        .get("cause")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("cause required".to_string()))?;
+
+    let input = MarkDeadInput {
+        cause: cause.to_string(),
+        party_id: args.get("party_id").and_then(|v| v.as_str()).map(String::from),
+        day: args.get("day").and_then(|v| v.as_i64()).map(|d| d as i32),
+        notes: args.get("notes").and_then(|v| v.as_str()).map(String::from),
+    };
+
+    let mut db = ctx.connect()?;
+    let death = DeathService::new(&mut db)
+        .mark_dead(character_id, input)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "character_died",
+        "death": character_death_json(&death)
+    }))
+}
+
+pub async fn resurrect_character(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let method = args
+        .get("method")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("method required".to_string()))?;
+
+    let input = ResurrectInput {
+        method: method.to_string(),
+        cost_cp: args
+            .get("cost_gp")
+            .and_then(|v| v.as_f64())
+            .map(|gp| (gp * 100.0).round() as i32),
+        paid_by: args.get("paid_by").and_then(|v| v.as_str()).map(String::from),
+        party_id: args.get("party_id").and_then(|v| v.as_str()).map(String::from),
+        day: args.get("day").and_then(|v| v.as_i64()).map(|d| d as i32),
+    };
+
+    let mut db = ctx.connect()?;
+    let death = DeathService::new(&mut db)
+        .resurrect(character_id, input)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "character_resurrected",
+        "death": character_death_json(&death)
+    }))
+}
+
+pub async fn set_character_portrait(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let character_id = args
+        .get("character_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("character_id required".to_string()))?;
+    let file_path = args
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("file_path required".to_string()))?;
+
+    let data = std::fs::read(file_path).map_err(|e| {
+        McpError::InvalidArguments(format!("Failed to read file '{}': {}", file_path, e))
+    })?;
+
+    let mut db = ctx.connect()?;
+    let character = CharacterImageService::new(&mut db, &ctx.assets_dir)
+        .upload_portrait(character_id, &data)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "portrait_set",
+        "character_id": character.id,
+        "name": character.name,
+        "portrait_path": character.portrait_path,
+        "token_path": character.token_path
+    }))
+}
@@ -2,7 +2,9 @@
 //!
 //! MCP tools for managing documents (campaign-level and module-level narrative content).
 
-use mimir_core::services::{CreateDocumentInput, DocumentService, UpdateDocumentInput};
+use mimir_core::services::{
+    CreateDocumentInput, DocumentService, TrashKind, TrashService, UpdateDocumentInput,
+};
 use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -77,7 +79,11 @@ pub fn create_document_tool() -> Tool {
                     "string",
                     "Type: backstory, read_aloud, dm_notes, description, custom",
                 ),
-                ("content", "string", "Initial content of the document"),
+                (
+                    "content",
+                    "string",
+                    "Initial markdown content. Wrap text to be read to players in `:::read-aloud` and `:::` lines. Embed a stat block with `{{monster: Name|SOURCE}}` on its own line. Reference a map area with `{{map-region: Map Name | region}}`",
+                ),
             ]),
             None,
         ),
@@ -146,7 +152,10 @@ pub fn reorder_document_tool() -> Tool {
 pub fn delete_document_tool() -> Tool {
     Tool {
         name: "delete_document".to_string(),
-        description: Some("Delete a document".to_string()),
+        description: Some(
+            "Move a document to the trash. It can be restored from the app until the trash is emptied"
+                .to_string(),
+        ),
         input_schema: ToolInputSchema::new(
             vec!["document_id".to_string()],
             create_properties(vec![
@@ -366,10 +375,10 @@ pub async fn delete_document(ctx: &Arc<McpContext>, args: Value) -> Result<Value
         .ok_or_else(|| McpError::InvalidArguments("document_id is required".to_string()))?;
 
     let mut db = ctx.connect()?;
-    let mut service = DocumentService::new(&mut db);
 
-    service
-        .delete(document_id)
+    // Trashed like in the app, so the document can be restored until it is purged
+    TrashService::new(&mut db, ctx.app_data_dir())
+        .trash(TrashKind::Document, document_id)
         .map_err(|e| McpError::Internal(e.to_string()))?;
 
     McpResponse::deleted(document_id)
@@ -0,0 +1,71 @@
+//! Forage Tools
+//!
+//! MCP tool for gathering ingredients in the active campaign. A forage check
+//! rolls against the environment's gathering table and adds what's found to
+//! the party stash.
+
+use mimir_core::services::ForageService;
+use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::create_properties;
+use crate::context::McpContext;
+use crate::response::McpResponse;
+use crate::McpError;
+
+// =============================================================================
+// Tool Definitions
+// =============================================================================
+
+pub fn forage_tool() -> Tool {
+    Tool {
+        name: "forage".to_string(),
+        description: Some(
+            "Make a forage check in an environment of the active campaign: roll d20 + modifier against the environment's gathering table and add the ingredient found to the party stash"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["environment".to_string(), "modifier".to_string()],
+            create_properties(vec![
+                ("environment", "string", "Environment with a gathering table (e.g., 'forest'); unknown environments list the available ones"),
+                ("modifier", "integer", "The forager's check modifier (e.g., Wisdom (Survival) or herbalism kit bonus)"),
+                ("roll", "integer", "A d20 result rolled at the table (default: roll one)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+// =============================================================================
+// Tool Implementations
+// =============================================================================
+
+pub async fn forage(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let campaign_id = ctx
+        .get_active_campaign_id()
+        .ok_or(McpError::NoActiveCampaign)?;
+    let environment = args
+        .get("environment")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("environment required".to_string()))?;
+    let modifier = args
+        .get("modifier")
+        .and_then(|v| v.as_i64())
+        .ok_or_else(|| McpError::InvalidArguments("modifier required".to_string()))?
+        as i32;
+    let roll = args.get("roll").and_then(|v| v.as_i64()).map(|r| r as i32);
+
+    let mut db = ctx.connect()?;
+    let result = ForageService::new(&mut db)
+        .forage(&campaign_id, environment, modifier, roll)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!(result))
+}
@@ -3,7 +3,10 @@
 //! MCP tools for map and token placement management.
 
 use mimir_core::models::campaign::LightingMode;
-use mimir_core::services::{CreateMapInput, CreateTokenInput, MapService, TokenService, UpdateMapInput};
+use mimir_core::services::{
+    CreateMapInput, CreateTokenInput, MapService, TokenService, TrashKind, TrashService,
+    UpdateMapInput,
+};
 use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -112,7 +115,10 @@ pub fn update_map_tool() -> Tool {
 pub fn delete_map_tool() -> Tool {
     Tool {
         name: "delete_map".to_string(),
-        description: Some("Delete a map and its associated UVTT asset".to_string()),
+        description: Some(
+            "Move a map and its UVTT asset to the trash. It can be restored from the app until the trash is emptied"
+                .to_string(),
+        ),
         input_schema: ToolInputSchema::new(
             vec!["map_id".to_string()],
             create_properties(vec![("map_id", "string", "The ID of the map to delete")]),
@@ -131,7 +137,7 @@ pub fn add_token_to_map_tool() -> Tool {
     Tool {
         name: "add_token_to_map".to_string(),
         description: Some(
-            "Add a monster or NPC token to a map. Provide either module_monster_id or module_npc_id. Coordinates default to (0,0) - user can reposition in the UI."
+            "Add a monster, NPC, or PC token to a map. Provide either module_monster_id or module_npc_id, or character_id (or a label) for a PC token. Coordinates default to (0,0) - user can reposition in the UI."
                 .to_string(),
         ),
         input_schema: ToolInputSchema::new(
@@ -145,6 +151,8 @@ pub fn add_token_to_map_tool() -> Tool {
                 ("label", "string", "Optional override label for the token"),
                 ("faction_color", "string", "Faction color as hex (e.g. #FF0000)"),
                 ("hidden", "boolean", "Whether token is hidden from players (default: false)"),
+                ("character_id", "string", "Character a PC token stands for (shows the character's token image)"),
+                ("mob_size", "integer", "Number of identical creatures a monster token stands for, to run them as a mob"),
             ]),
             None,
         ),
@@ -200,14 +208,6 @@ pub fn remove_token_tool() -> Tool {
 // Tool Implementations
 // =============================================================================
 
-/// Get the app_data_dir from context (parent of assets_dir).
-fn app_data_dir(ctx: &Arc<McpContext>) -> std::path::PathBuf {
-    ctx.assets_dir
-        .parent()
-        .map(|p| p.to_path_buf())
-        .unwrap_or_else(|| ctx.assets_dir.clone())
-}
-
 fn parse_lighting_mode(s: &str) -> Result<LightingMode, McpError> {
     match s.to_lowercase().as_str() {
         "bright" => Ok(LightingMode::Bright),
@@ -256,7 +256,7 @@ pub async fn create_map(ctx: &Arc<McpContext>, args: Value) -> Result<Value, Mcp
         .to_string();
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
     let mut service = MapService::new(&mut db, &data_dir);
 
     let mut input = if let Some(mid) = module_id {
@@ -295,7 +295,7 @@ pub async fn list_maps(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpE
     let module_id = args.get("module_id").and_then(|v| v.as_str());
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
     let mut service = MapService::new(&mut db, &data_dir);
 
     let maps = if let Some(mid) = module_id {
@@ -332,7 +332,7 @@ pub async fn get_map(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpErr
         .ok_or_else(|| McpError::InvalidArguments("map_id is required".to_string()))?;
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
 
     let map = {
         let mut service = MapService::new(&mut db, &data_dir);
@@ -405,7 +405,7 @@ pub async fn update_map(ctx: &Arc<McpContext>, args: Value) -> Result<Value, Mcp
     }
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
     let mut service = MapService::new(&mut db, &data_dir);
 
     let map = service
@@ -430,11 +430,10 @@ pub async fn delete_map(ctx: &Arc<McpContext>, args: Value) -> Result<Value, Mcp
         .ok_or_else(|| McpError::InvalidArguments("map_id is required".to_string()))?;
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
-    let mut service = MapService::new(&mut db, &data_dir);
 
-    service
-        .delete(map_id)
+    // Trashed like in the app, so the map can be restored until it is purged
+    TrashService::new(&mut db, ctx.app_data_dir())
+        .trash(TrashKind::Map, map_id)
         .map_err(|e| McpError::Internal(e.to_string()))?;
 
     McpResponse::deleted(map_id)
@@ -449,11 +448,17 @@ pub async fn add_token_to_map(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
     let module_monster_id = args.get("module_monster_id").and_then(|v| v.as_str());
     let module_npc_id = args.get("module_npc_id").and_then(|v| v.as_str());
     let label = args.get("label").and_then(|v| v.as_str());
-
-    // Validation: need either monster, npc, or label (for PC tokens)
-    if module_monster_id.is_none() && module_npc_id.is_none() && label.is_none() {
+    let character_id = args.get("character_id").and_then(|v| v.as_str());
+
+    // Validation: need either monster, npc, or label/character (for PC tokens)
+    if module_monster_id.is_none()
+        && module_npc_id.is_none()
+        && label.is_none()
+        && character_id.is_none()
+    {
         return Err(McpError::InvalidArguments(
-            "Either module_monster_id, module_npc_id, or label is required".to_string(),
+            "Either module_monster_id, module_npc_id, character_id, or label is required"
+                .to_string(),
         ));
     }
     if module_monster_id.is_some() && module_npc_id.is_some() {
@@ -466,6 +471,7 @@ pub async fn add_token_to_map(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
     let grid_y = args.get("grid_y").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
     let faction_color = args.get("faction_color").and_then(|v| v.as_str());
     let hidden = args.get("hidden").and_then(|v| v.as_bool()).unwrap_or(false);
+    let mob_size = args.get("mob_size").and_then(|v| v.as_i64()).map(|n| n as i32);
 
     let input = CreateTokenInput {
         map_id: map_id.to_string(),
@@ -476,10 +482,12 @@ pub async fn add_token_to_map(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
         label: label.map(|s| s.to_string()),
         faction_color: faction_color.map(|s| s.to_string()),
         hidden,
+        character_id: character_id.map(|s| s.to_string()),
+        mob_size,
     };
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
 
     let token = TokenService::new(&mut db, &data_dir)
         .create(input)
@@ -494,7 +502,8 @@ pub async fn add_token_to_map(ctx: &Arc<McpContext>, args: Value) -> Result<Valu
         "grid_x": token.grid_x,
         "grid_y": token.grid_y,
         "visible_to_players": token.visible_to_players,
-        "color": token.color
+        "color": token.color,
+        "mob_size": token.mob_size
     }))
 }
 
@@ -510,7 +519,7 @@ pub async fn list_tokens_on_map(ctx: &Arc<McpContext>, args: Value) -> Result<Va
         .unwrap_or(false);
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
     let mut service = TokenService::new(&mut db, &data_dir);
 
     let tokens = if visible_only {
@@ -548,7 +557,7 @@ pub async fn remove_token(ctx: &Arc<McpContext>, args: Value) -> Result<Value, M
         .ok_or_else(|| McpError::InvalidArguments("token_id is required".to_string()))?;
 
     let mut db = ctx.connect()?;
-    let data_dir = app_data_dir(ctx);
+    let data_dir = ctx.app_data_dir();
 
     TokenService::new(&mut db, &data_dir)
         .delete(token_id)
@@ -0,0 +1,197 @@
+//! Memory Tools
+//!
+//! MCP tools for remembering facts about the active campaign between
+//! conversations.
+
+use mimir_core::models::campaign::CampaignMemory;
+use mimir_core::services::MemoryService;
+use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use super::create_properties;
+use crate::context::McpContext;
+use crate::response::McpResponse;
+use crate::McpError;
+
+/// Memories returned by `recall_facts` when no limit is given.
+const DEFAULT_RECALL_LIMIT: usize = 5;
+
+// =============================================================================
+// Tool Definitions
+// =============================================================================
+
+pub fn remember_fact_tool() -> Tool {
+    Tool {
+        name: "remember_fact".to_string(),
+        description: Some(
+            "Remember a fact about the active campaign for later conversations (e.g., 'The party insulted Duke Avery'). Remembering under an existing name replaces that fact"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["name".to_string(), "content".to_string()],
+            create_properties(vec![
+                ("name", "string", "Short name for the fact, unique in the campaign (e.g., 'Duke Avery insulted')"),
+                ("content", "string", "The fact to remember"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn recall_facts_tool() -> Tool {
+    Tool {
+        name: "recall_facts".to_string(),
+        description: Some(
+            "Recall remembered facts about the active campaign that match a question or keywords, best match first"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["query".to_string()],
+            create_properties(vec![
+                ("query", "string", "What to recall (e.g., 'what happened with the duke')"),
+                ("limit", "integer", "Maximum number of facts to return (default: 5)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn list_memories_tool() -> Tool {
+    Tool {
+        name: "list_memories".to_string(),
+        description: Some(
+            "List every fact remembered for the active campaign, most recently updated first"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(vec![], None, None),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn forget_fact_tool() -> Tool {
+    Tool {
+        name: "forget_fact".to_string(),
+        description: Some("Forget a remembered fact that is no longer true".to_string()),
+        input_schema: ToolInputSchema::new(
+            vec!["memory_id".to_string()],
+            create_properties(vec![("memory_id", "string", "The ID of the memory to forget")]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+// =============================================================================
+// Tool Implementations
+// =============================================================================
+
+pub async fn remember_fact(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let campaign_id = ctx
+        .get_active_campaign_id()
+        .ok_or(McpError::NoActiveCampaign)?;
+    let name = args
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("name required".to_string()))?;
+    let content = args
+        .get("content")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("content required".to_string()))?;
+
+    let mut db = ctx.connect()?;
+    let memory = MemoryService::new(&mut db)
+        .remember(&campaign_id, name, content)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::success(json!({
+        "action": "remembered",
+        "memory": memory_json(&memory)
+    }))
+}
+
+pub async fn recall_facts(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let campaign_id = ctx
+        .get_active_campaign_id()
+        .ok_or(McpError::NoActiveCampaign)?;
+    let query = args
+        .get("query")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("query required".to_string()))?;
+    let limit = args
+        .get("limit")
+        .and_then(|v| v.as_u64())
+        .map(|l| l as usize)
+        .unwrap_or(DEFAULT_RECALL_LIMIT);
+
+    let mut db = ctx.connect()?;
+    let memories = MemoryService::new(&mut db)
+        .recall(&campaign_id, query, limit)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::ok(json!({
+        "memories": memories.iter().map(memory_json).collect::<Vec<_>>(),
+        "count": memories.len()
+    }))
+}
+
+pub async fn list_memories(ctx: &Arc<McpContext>, _args: Value) -> Result<Value, McpError> {
+    let campaign_id = ctx
+        .get_active_campaign_id()
+        .ok_or(McpError::NoActiveCampaign)?;
+
+    let mut db = ctx.connect()?;
+    let memories = MemoryService::new(&mut db)
+        .list_for_campaign(&campaign_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
+    McpResponse::ok(json!({
+        "memories": memories.iter().map(memory_json).collect::<Vec<_>>(),
+        "count": memories.len()
+    }))
+}
+
+pub async fn forget_fact(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let memory_id = args
+        .get("memory_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("memory_id required".to_string()))?;
+
+    let mut db = ctx.connect()?;
+    MemoryService::new(&mut db)
+        .delete(memory_id)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::deleted(memory_id)
+}
+
+fn memory_json(memory: &CampaignMemory) -> Value {
+    json!({
+        "id": memory.id,
+        "name": memory.name,
+        "content": memory.content,
+        "updated_at": memory.updated_at
+    })
+}
@@ -9,10 +9,13 @@ pub mod campaign;
 pub mod catalog;
 pub mod character;
 pub mod document;
+pub mod forage;
 pub mod homebrew;
 pub mod map;
 pub mod mapgen;
+pub mod memory;
 pub mod module;
+pub mod travel;
 
 /// Create a properties map for tool input schema.
 ///
@@ -3,8 +3,11 @@
 //! MCP tools for module management.
 
 use mimir_core::dal::campaign as dal;
-use mimir_core::models::campaign::NewModuleMonster;
-use mimir_core::services::{CreateModuleInput, ModuleService, ModuleType, UpdateModuleInput};
+use mimir_core::models::campaign::{ModuleLinkType, NewModuleMonster};
+use mimir_core::services::{
+    ContinuityService, CreateModuleInput, CreateModuleLinkInput, ModulePrepService, ModuleService,
+    ModuleType, UpdateModuleInput,
+};
 use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -63,7 +66,7 @@ pub fn get_module_details_tool() -> Tool {
     Tool {
         name: "get_module_details".to_string(),
         description: Some(
-            "Get detailed information about a module including documents, monsters, and items"
+            "Get detailed information about a module including documents, monsters, items, and its prep checklist with estimated prep time remaining"
                 .to_string(),
         ),
         input_schema: ToolInputSchema::new(
@@ -194,6 +197,61 @@ pub fn delete_module_tool() -> Tool {
     }
 }
 
+pub fn link_modules_tool() -> Tool {
+    Tool {
+        name: "link_modules".to_string(),
+        description: Some(
+            "Link two modules: one follows from an earlier module, or foreshadows a hook that pays off in a later one"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec![
+                "from_module_id".to_string(),
+                "to_module_id".to_string(),
+                "link_type".to_string(),
+            ],
+            create_properties(vec![
+                ("from_module_id", "string", "The module the link starts from"),
+                ("to_module_id", "string", "The module the link points to"),
+                (
+                    "link_type",
+                    "string",
+                    "follows_from (from picks up after to) or foreshadows (from sets up a hook paid off in to)",
+                ),
+                ("note", "string", "What carries over, e.g. the hook being foreshadowed"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+pub fn check_module_completion_tool() -> Tool {
+    Tool {
+        name: "check_module_completion".to_string(),
+        description: Some(
+            "Continuity checklist for a module: foreshadowed hooks not yet paid off, NPCs introduced but never used, and items handed out while it was played"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["module_id".to_string()],
+            create_properties(vec![("module_id", "string", "The ID of the module")]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
 // =============================================================================
 // Tool Implementations
 // =============================================================================
@@ -308,15 +366,22 @@ pub async fn get_module_details(ctx: &Arc<McpContext>, args: Value) -> Result<Va
         })
         .collect();
 
+    // Prep checklist derived from the module's template
+    let prep = ModulePrepService::new(&mut db)
+        .checklist(module_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
     McpResponse::ok(json!({
         "module": {
             "id": module.id,
             "name": module.name,
             "description": module.description,
-            "module_number": module.module_number
+            "module_number": module.module_number,
+            "module_type": module.module_type
         },
         "documents": doc_data,
-        "monsters": monster_data
+        "monsters": monster_data,
+        "prep": prep
     }))
 }
 
@@ -496,3 +561,63 @@ pub async fn delete_module(ctx: &Arc<McpContext>, args: Value) -> Result<Value,
 
     McpResponse::deleted(module_id)
 }
+
+pub async fn link_modules(ctx: &Arc<McpContext>, args: Value) -> Result<Value, McpError> {
+    let from_module_id = args
+        .get("from_module_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("from_module_id is required".to_string()))?;
+    let to_module_id = args
+        .get("to_module_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("to_module_id is required".to_string()))?;
+    let link_type = args
+        .get("link_type")
+        .and_then(|v| v.as_str())
+        .and_then(ModuleLinkType::from_str)
+        .ok_or_else(|| {
+            McpError::InvalidArguments("link_type must be follows_from or foreshadows".to_string())
+        })?;
+
+    let mut input = CreateModuleLinkInput::new(from_module_id, to_module_id, link_type);
+    if let Some(note) = args.get("note").and_then(|v| v.as_str()) {
+        input = input.with_note(note);
+    }
+
+    let mut db = ctx.connect()?;
+    let link = ContinuityService::new(&mut db)
+        .link(input)
+        .map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::created("link", json!({
+        "id": link.id,
+        "from_module_id": link.from_module_id,
+        "to_module_id": link.to_module_id,
+        "link_type": link.link_type,
+        "note": link.note
+    }))
+}
+
+pub async fn check_module_completion(
+    ctx: &Arc<McpContext>,
+    args: Value,
+) -> Result<Value, McpError> {
+    let module_id = args
+        .get("module_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpError::InvalidArguments("module_id is required".to_string()))?;
+
+    let mut db = ctx.connect()?;
+    let completion = ContinuityService::new(&mut db)
+        .check_module_completion(module_id)
+        .map_err(|e| McpError::Internal(e.to_string()))?;
+
+    McpResponse::ok(json!({
+        "module_id": completion.module_id,
+        "module_name": completion.module_name,
+        "is_complete": completion.is_complete,
+        "unresolved_hooks": completion.unresolved_hooks,
+        "unused_npcs": completion.unused_npcs,
+        "items_handed_out": completion.items_handed_out
+    }))
+}
@@ -0,0 +1,58 @@
+//! Travel Tools
+//!
+//! MCP tool for planning overland journeys. Planning is pure rules math and
+//! needs no campaign context.
+
+use mimir_core::services::{plan_travel as plan, TravelRequest};
+use rust_mcp_sdk::schema::{Tool, ToolInputSchema};
+use serde_json::{json, Value};
+
+use super::create_properties;
+use crate::response::McpResponse;
+use crate::McpError;
+
+// =============================================================================
+// Tool Definitions
+// =============================================================================
+
+pub fn plan_travel_tool() -> Tool {
+    Tool {
+        name: "plan_travel".to_string(),
+        description: Some(
+            "Plan an overland journey: miles per day, travel days, forced march saves, a random encounter check schedule, and rations and water for the party"
+                .to_string(),
+        ),
+        input_schema: ToolInputSchema::new(
+            vec!["distance_miles".to_string()],
+            create_properties(vec![
+                ("distance_miles", "number", "Distance to travel, in miles"),
+                ("speeds", "array", "Walking speed of each traveler in feet (e.g., [30, 25, 30]); the slowest sets the pace. Default: 30"),
+                ("party_size", "integer", "Creatures that need food and water (default: the number of speeds)"),
+                ("pace", "string", "Travel pace: fast, normal, or slow (default: normal)"),
+                ("terrain", "string", "Terrain: road, open, hills, desert, forest, jungle, swamp, mountains, or arctic (default: open)"),
+                ("weather", "string", "Weather: clear, rain, heavy_precipitation, heavy_snow, strong_wind, extreme_heat, or extreme_cold (default: clear)"),
+                ("hours_per_day", "integer", "Hours traveled each day (default: 8; more is a forced march)"),
+                ("encounter_check_hours", "integer", "Hours between random encounter checks (default: 8)"),
+            ]),
+            None,
+        ),
+        title: None,
+        annotations: None,
+        icons: vec![],
+        execution: None,
+        output_schema: None,
+        meta: None,
+    }
+}
+
+// =============================================================================
+// Tool Implementations
+// =============================================================================
+
+pub async fn plan_travel(args: Value) -> Result<Value, McpError> {
+    let request: TravelRequest = serde_json::from_value(args)
+        .map_err(|e| McpError::InvalidArguments(format!("Invalid travel request: {e}")))?;
+    let plan = plan(&request).map_err(|e| McpError::InvalidArguments(e.to_string()))?;
+
+    McpResponse::get("plan", json!(plan))
+}
@@ -0,0 +1,296 @@
+//! Booklet imposition
+//!
+//! Reorders a rendered PDF's pages and places them two to a sheet side, so
+//! the printed stack folds into a saddle-stitched booklet. Long booklets can
+//! be split into signatures: small stacks of sheets that are folded
+//! separately and gathered, which keeps the spine flat.
+//!
+//! Each source page becomes a Form XObject, and every output sheet side is a
+//! landscape page (the source page turned on its side) with two of them
+//! scaled to fit, so letter pages print as a half-letter booklet.
+
+use lopdf::{dictionary, Dictionary, Object, ObjectId, Stream};
+
+use crate::error::{PrintError, Result};
+
+/// Pages printed on one sheet: two on the front, two on the back.
+const PAGES_PER_SHEET: usize = 4;
+
+/// How to fold the imposed booklet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BookletLayout {
+    /// Sheets folded together into each signature; `None` folds the whole
+    /// booklet as one signature
+    pub sheets_per_signature: Option<usize>,
+}
+
+/// Order source pages for booklet printing.
+///
+/// Returns the 0-based source page in each half of each sheet side, in
+/// printing order: front left, front right, back left, back right, then the
+/// next sheet. `None` marks a blank half where the page count doesn't fill
+/// the last signature.
+pub fn booklet_page_order(page_count: usize, layout: &BookletLayout) -> Vec<Option<usize>> {
+    let padded = page_count.div_ceil(PAGES_PER_SHEET) * PAGES_PER_SHEET;
+    let signature_pages = layout
+        .sheets_per_signature
+        .map_or(padded, |sheets| sheets.max(1) * PAGES_PER_SHEET);
+    let page = |index: usize| (index < page_count).then_some(index);
+
+    let mut order = Vec::with_capacity(padded);
+    let mut start = 0;
+    while start < padded {
+        // The last signature takes whatever pages are left
+        let last = (start + signature_pages).min(padded) - 1;
+        for sheet in 0..(last + 1 - start) / PAGES_PER_SHEET {
+            let outer = 2 * sheet;
+            order.extend([
+                page(last - outer),
+                page(start + outer),
+                page(start + outer + 1),
+                page(last - outer - 1),
+            ]);
+        }
+        start = last + 1;
+    }
+    order
+}
+
+/// Impose a rendered PDF as a booklet.
+///
+/// Bookmarks, links, and page labels point at the original pages, so they
+/// are dropped from the imposed document.
+pub fn impose_booklet(pdf: &[u8], layout: &BookletLayout) -> Result<Vec<u8>> {
+    let mut doc = lopdf::Document::load_mem(pdf).map_err(pdf_error)?;
+    let pages: Vec<ObjectId> = doc.get_pages().into_values().collect();
+    let Some(&first_page) = pages.first() else {
+        return Err(PrintError::InvalidData(
+            "Cannot impose an empty document".to_string(),
+        ));
+    };
+
+    // Every page becomes a Form XObject that the sheets draw
+    let mut forms = Vec::with_capacity(pages.len());
+    for &page_id in &pages {
+        let media_box = page_box(&doc, page_id)?;
+        let mut dict = dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Form",
+            "BBox" => media_box.iter().map(|&v| Object::Real(v as f32)).collect::<Vec<_>>(),
+        };
+        if let Some(resources) = inherited(&doc, page_id, b"Resources") {
+            dict.set("Resources", resources);
+        }
+        let content = doc.get_page_content(page_id).map_err(pdf_error)?;
+        let mut stream = Stream::new(dict, content);
+        // Compression only saves space; the stream is valid either way
+        let _ = stream.compress();
+        forms.push((doc.add_object(stream), media_box));
+    }
+
+    // Sheets are the first page turned on its side
+    let [x0, y0, x1, y1] = page_box(&doc, first_page)?;
+    let (sheet_width, sheet_height) = (y1 - y0, x1 - x0);
+    let half_width = sheet_width / 2.0;
+
+    let pages_id = doc.new_object_id();
+    let mut kids = Vec::new();
+    for side in booklet_page_order(pages.len(), layout).chunks(2) {
+        let mut content = String::new();
+        let mut xobjects = Dictionary::new();
+        for (slot, page) in side.iter().enumerate() {
+            let Some(index) = *page else { continue };
+            let (form_id, [bx0, by0, bx1, by1]) = forms[index];
+            let (width, height) = (bx1 - bx0, by1 - by0);
+            let scale = (half_width / width).min(sheet_height / height);
+            let x = slot as f64 * half_width + (half_width - width * scale) / 2.0;
+            let y = (sheet_height - height * scale) / 2.0;
+
+            let name = format!("P{}", slot);
+            content.push_str(&format!(
+                "q {s:.4} 0 0 {s:.4} {x:.2} {y:.2} cm /{name} Do Q\n",
+                s = scale,
+                x = x - bx0 * scale,
+                y = y - by0 * scale,
+                name = name,
+            ));
+            xobjects.set(name, form_id);
+        }
+
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.into_bytes()));
+        let sheet_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![
+                0.into(),
+                0.into(),
+                Object::Real(sheet_width as f32),
+                Object::Real(sheet_height as f32),
+            ],
+            "Resources" => dictionary! { "XObject" => xobjects },
+            "Contents" => content_id,
+        });
+        kids.push(Object::Reference(sheet_id));
+    }
+
+    let count = kids.len() as i64;
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => count,
+        }),
+    );
+
+    let catalog = doc.catalog_mut().map_err(pdf_error)?;
+    catalog.set("Pages", pages_id);
+    for key in [
+        b"Outlines".as_slice(),
+        b"Dests",
+        b"Names",
+        b"PageLabels",
+        b"OpenAction",
+    ] {
+        catalog.remove(key);
+    }
+    doc.prune_objects();
+
+    let mut out = Vec::new();
+    doc.save_to(&mut out).map_err(pdf_error)?;
+    Ok(out)
+}
+
+/// A page attribute, looked up through the page tree if the page inherits it.
+fn inherited(doc: &lopdf::Document, page_id: ObjectId, key: &[u8]) -> Option<Object> {
+    let mut node = doc.get_dictionary(page_id).ok()?;
+    loop {
+        if let Ok(value) = node.get(key) {
+            return Some(value.clone());
+        }
+        let parent = node.get(b"Parent").and_then(Object::as_reference).ok()?;
+        node = doc.get_dictionary(parent).ok()?;
+    }
+}
+
+/// A page's MediaBox as `[x0, y0, x1, y1]` in points.
+fn page_box(doc: &lopdf::Document, page_id: ObjectId) -> Result<[f64; 4]> {
+    let media_box = inherited(doc, page_id, b"MediaBox")
+        .ok_or_else(|| PrintError::PdfError("Page has no MediaBox".to_string()))?;
+    let media_box = match media_box {
+        Object::Reference(id) => doc.get_object(id).map_err(pdf_error)?.clone(),
+        other => other,
+    };
+    let values = media_box
+        .as_array()
+        .map_err(pdf_error)?
+        .iter()
+        .map(|v| v.as_float().map(f64::from))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(pdf_error)?;
+    values
+        .try_into()
+        .map_err(|_| PrintError::PdfError("Malformed page MediaBox".to_string()))
+}
+
+fn pdf_error(e: lopdf::Error) -> PrintError {
+    PrintError::PdfError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A letter-size PDF with `count` pages, each drawing its own number.
+    fn numbered_pdf(count: usize) -> Vec<u8> {
+        let mut doc = lopdf::Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let kids: Vec<Object> = (1..=count)
+            .map(|n| {
+                let content = format!("BT /F1 24 Tf 72 720 Td ({}) Tj ET", n);
+                let content_id = doc.add_object(Stream::new(dictionary! {}, content.into_bytes()));
+                doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "Contents" => content_id,
+                })
+                .into()
+            })
+            .collect();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => count as i64,
+                "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut out = Vec::new();
+        doc.save_to(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_single_signature_order() {
+        let order = booklet_page_order(8, &BookletLayout::default());
+        let expected = [7, 0, 1, 6, 5, 2, 3, 4];
+        assert_eq!(order, expected.map(Some).to_vec());
+    }
+
+    #[test]
+    fn test_short_booklet_pads_with_blanks() {
+        // Six pages fill two sheets; the back cover and the page before it are blank
+        let order = booklet_page_order(6, &BookletLayout::default());
+        assert_eq!(&order[..4], &[None, Some(0), Some(1), None]);
+        assert_eq!(&order[4..], &[5, 2, 3, 4].map(Some));
+    }
+
+    #[test]
+    fn test_signatures_fold_separately() {
+        let layout = BookletLayout {
+            sheets_per_signature: Some(1),
+        };
+        let order = booklet_page_order(8, &layout);
+        let expected = [3, 0, 1, 2, 7, 4, 5, 6];
+        assert_eq!(order, expected.map(Some).to_vec());
+
+        // A short last signature is folded on its own
+        let layout = BookletLayout {
+            sheets_per_signature: Some(2),
+        };
+        let order = booklet_page_order(12, &layout);
+        assert_eq!(&order[8..], &[11, 8, 9, 10].map(Some));
+    }
+
+    #[test]
+    fn test_impose_booklet() {
+        let pdf = impose_booklet(&numbered_pdf(6), &BookletLayout::default()).unwrap();
+
+        let doc = lopdf::Document::load_mem(&pdf).unwrap();
+        let pages = doc.get_pages();
+        assert_eq!(pages.len(), 4);
+
+        // Sheets are landscape letter
+        let sheet = doc.get_dictionary(pages[&1]).unwrap();
+        let media_box = sheet.get(b"MediaBox").unwrap().as_array().unwrap();
+        assert_eq!(media_box[2].as_float().unwrap(), 792.0);
+        assert_eq!(media_box[3].as_float().unwrap(), 612.0);
+
+        // The front of the outer sheet has a blank back cover and page 1
+        let content = String::from_utf8(doc.get_page_content(pages[&1]).unwrap()).unwrap();
+        assert!(!content.contains("/P0 Do"));
+        assert!(content.contains("/P1 Do"));
+    }
+
+    #[test]
+    fn test_impose_empty_document_fails() {
+        assert!(impose_booklet(&numbered_pdf(0), &BookletLayout::default()).is_err());
+    }
+}
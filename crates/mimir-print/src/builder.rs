@@ -20,7 +20,9 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
+use crate::booklet::{impose_booklet, BookletLayout};
 use crate::error::{PrintError, Result};
+use crate::profile::PrintProfile;
 
 /// Registry for virtual files that will be available to Typst
 ///
@@ -83,6 +85,31 @@ impl RenderContext {
         self.base_path = Some(path);
         self
     }
+
+    /// Register an image as `{stem}.png` or `{stem}.jpg`
+    ///
+    /// Formats Typst can't embed (e.g., WebP) are converted to PNG. Returns
+    /// the virtual path to use in Typst, or `None` if the bytes aren't an
+    /// image.
+    pub fn register_image(&self, stem: &str, bytes: &[u8]) -> Option<String> {
+        let (ext, data) = match image::guess_format(bytes) {
+            Ok(image::ImageFormat::Jpeg) => ("jpg", bytes.to_vec()),
+            Ok(image::ImageFormat::Png) => ("png", bytes.to_vec()),
+            _ => {
+                let mut png = Vec::new();
+                let converted = image::load_from_memory(bytes).and_then(|img| {
+                    img.write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                });
+                if let Err(e) = converted {
+                    tracing::warn!("Skipping image {}: {}", stem, e);
+                    return None;
+                }
+                ("png", png)
+            }
+        };
+        let filename = format!("{}.{}", stem, ext);
+        Some(self.virtual_files.register(&filename, data))
+    }
 }
 
 impl Default for RenderContext {
@@ -131,6 +158,25 @@ pub trait Renderable: Send + Sync {
     fn page_margin(&self) -> Option<f32> {
         None
     }
+
+    /// Whether this section is a chapter divider page
+    ///
+    /// Divider pages are left out when the document's config turns
+    /// chapter dividers off.
+    fn is_chapter_divider(&self) -> bool {
+        false
+    }
+}
+
+/// Details printed on the title page beneath the document title
+#[derive(Debug, Clone, Default)]
+pub struct TitlePage {
+    /// Logo image (PNG or JPEG) shown above the title
+    pub logo: Option<Vec<u8>>,
+    /// Dungeon Master credited on the title page
+    pub dm_name: Option<String>,
+    /// When the campaign ran (e.g., "March 2024 - June 2025")
+    pub date_range: Option<String>,
 }
 
 /// Configuration for document assembly
@@ -146,6 +192,16 @@ pub struct DocumentConfig {
     pub margin: f32,
     /// Base font size in points
     pub font_size: f32,
+    /// Full-page art (PNG or JPEG) printed before the title page
+    pub cover_art: Option<Vec<u8>>,
+    /// Logo, DM name, and dates for the title page
+    pub title_page: TitlePage,
+    /// Print chapter divider sections
+    pub chapter_dividers: bool,
+    /// Impose the rendered pages as a saddle-stitched booklet
+    pub booklet: Option<BookletLayout>,
+    /// Accessibility profile applied to the shared styles
+    pub profile: PrintProfile,
 }
 
 impl Default for DocumentConfig {
@@ -156,6 +212,11 @@ impl Default for DocumentConfig {
             page_numbers: true,
             margin: 0.5, // Reduced for more drawing area
             font_size: 10.0,
+            cover_art: None,
+            title_page: TitlePage::default(),
+            chapter_dividers: true,
+            booklet: None,
+            profile: PrintProfile::default(),
         }
     }
 }
@@ -228,6 +289,36 @@ impl DocumentBuilder {
         self
     }
 
+    /// Print full-page cover art (PNG or JPEG) before the title page
+    pub fn with_cover_art(mut self, image: Vec<u8>) -> Self {
+        self.config.cover_art = Some(image);
+        self
+    }
+
+    /// Set the logo, DM name, and dates shown on the title page
+    pub fn with_title_page_details(mut self, details: TitlePage) -> Self {
+        self.config.title_page = details;
+        self
+    }
+
+    /// Enable or disable chapter divider pages
+    pub fn with_chapter_dividers(mut self, include: bool) -> Self {
+        self.config.chapter_dividers = include;
+        self
+    }
+
+    /// Impose the PDF as a booklet for saddle-stitch printing, or not
+    pub fn with_booklet(mut self, layout: Option<BookletLayout>) -> Self {
+        self.config.booklet = layout;
+        self
+    }
+
+    /// Set the accessibility profile (text scale, contrast, font, layout)
+    pub fn with_profile(mut self, profile: PrintProfile) -> Self {
+        self.config.profile = profile;
+        self
+    }
+
     /// Set the render context (temp directory, base path, etc.)
     pub fn with_context(mut self, context: RenderContext) -> Self {
         self.context = context;
@@ -277,6 +368,9 @@ impl DocumentBuilder {
         // Document preamble with settings
         output.push_str(&self.build_preamble());
 
+        // Cover art (if supplied) is a page of its own
+        output.push_str(&self.build_cover());
+
         // Title page (if enabled)
         if self.config.include_title_page {
             output.push_str(&self.build_title_page());
@@ -293,8 +387,12 @@ impl DocumentBuilder {
         // Determine if we need a page break before the first section
         let has_preceding_content = self.config.include_title_page || self.config.include_toc;
 
-        // Render each section
-        for (i, section) in self.sections.iter().enumerate() {
+        // Render each section, leaving out divider pages if they're turned off
+        let sections = self
+            .sections
+            .iter()
+            .filter(|section| self.config.chapter_dividers || !section.is_chapter_divider());
+        for (i, section) in sections.enumerate() {
             // Page break before section (except first if no preceding content)
             if section.page_break_before() && (i > 0 || has_preceding_content) {
                 output.push_str("\n#pagebreak()\n\n");
@@ -348,7 +446,7 @@ impl DocumentBuilder {
 
         // Page numbers in footer (bottom right)
         if self.config.page_numbers {
-            preamble.push_str(", footer: context [#h(1fr) #text(size: 9pt, fill: luma(100))[#counter(page).display()]]");
+            preamble.push_str(", footer: context [#h(1fr) #text(size: sizes.base, fill: colors.text-secondary)[#counter(page).display()]]");
         }
 
         preamble.push_str(")\n");
@@ -380,15 +478,62 @@ impl DocumentBuilder {
         preamble
     }
 
+    /// Build the full-page cover, or nothing if there is no cover art
+    fn build_cover(&self) -> String {
+        let Some(path) = self
+            .config
+            .cover_art
+            .as_ref()
+            .and_then(|art| self.context.register_image("cover_art", art))
+        else {
+            return String::new();
+        };
+
+        format!(
+            "#page(margin: 0in, footer: none)[#image(\"{}\", width: 100%, height: 100%, fit: \"cover\")]\n",
+            path
+        )
+    }
+
     /// Build the title page
     fn build_title_page(&self) -> String {
+        let details = &self.config.title_page;
         let mut title_page = String::new();
 
         title_page.push_str("#align(center + horizon)[\n");
+        if let Some(path) = details
+            .logo
+            .as_ref()
+            .and_then(|logo| self.context.register_image("title_logo", logo))
+        {
+            title_page.push_str(&format!(
+                "  #image(\"{}\", width: 3in, height: 3in, fit: \"contain\")\n",
+                path
+            ));
+            title_page.push_str("  #v(spacing.xxl)\n");
+        }
         title_page.push_str(&format!(
             "  #title-text[{}]\n",
             escape_typst_string(&self.title)
         ));
+        if let Some(dm_name) = details.dm_name.as_deref().filter(|s| !s.trim().is_empty()) {
+            title_page.push_str("  #v(spacing.xl)\n");
+            title_page.push_str(&format!(
+                "  #subtitle-text[Dungeon Master: {}]\n",
+                escape_typst_string(dm_name.trim())
+            ));
+        }
+        if let Some(dates) = details
+            .date_range
+            .as_deref()
+            .filter(|s| !s.trim().is_empty())
+        {
+            title_page.push_str("  #v(spacing.sm)\n");
+            title_page.push_str(&format!(
+                "  #text(fill: colors.text-secondary)[{}]\n",
+                escape_typst_string(dates.trim())
+            ));
+        }
         title_page.push_str("]\n");
 
         title_page
@@ -408,9 +553,48 @@ impl DocumentBuilder {
 
     /// Render the document to PDF bytes
     ///
+    /// Booklet imposition, if enabled, is applied to the rendered pages.
+    ///
     /// # Returns
     /// PDF file contents as bytes
     pub fn to_pdf(self) -> Result<Vec<u8>> {
+        let booklet = self.config.booklet;
+        let document = self.compile()?;
+        let pdf_bytes = render_pdf(&document)?;
+        match booklet {
+            Some(layout) => impose_booklet(&pdf_bytes, &layout),
+            None => Ok(pdf_bytes),
+        }
+    }
+
+    /// Render the document to a fillable PDF
+    ///
+    /// Form fields emitted by sections (see [`crate::forms::form_field`]) become
+    /// AcroForm widgets at the same place on the page, so values can be typed
+    /// into the PDF.
+    ///
+    /// # Returns
+    /// PDF file contents as bytes
+    pub fn to_fillable_pdf(self) -> Result<Vec<u8>> {
+        let document = self.compile()?;
+        let pdf_bytes = render_pdf(&document)?;
+
+        let fields = crate::forms::collect_form_fields(&document);
+        tracing::debug!("Adding {} form fields", fields.len());
+        if fields.is_empty() {
+            return Ok(pdf_bytes);
+        }
+
+        let page_heights: Vec<f64> = document
+            .pages
+            .iter()
+            .map(|page| page.frame.height().to_pt())
+            .collect();
+        crate::forms::add_form_fields(&pdf_bytes, &fields, &page_heights)
+    }
+
+    /// Compile the sections into a laid-out Typst document
+    fn compile(self) -> Result<typst::model::Document> {
         if self.sections.is_empty() {
             return Err(PrintError::InvalidData(
                 "Cannot render empty document".to_string(),
@@ -434,13 +618,13 @@ impl DocumentBuilder {
 
         // Create world with in-memory content and virtual files
         use crate::world::MimirTypstWorld;
-        use typst::diag::Severity;
 
         let world = MimirTypstWorld::from_content_with_files(
             typst_content.clone(),
             self.templates_root.clone(),
             virtual_files,
-        );
+        )
+        .with_profile(self.config.profile);
 
         let warned = typst::compile(&world);
 
@@ -449,23 +633,7 @@ impl DocumentBuilder {
         }
 
         match warned.output {
-            Ok(document) => {
-                let pdf_result = typst_pdf::pdf(&document, &typst_pdf::PdfOptions::default());
-                match pdf_result {
-                    Ok(pdf_bytes) => Ok(pdf_bytes),
-                    Err(errors) => {
-                        let error_msg = errors
-                            .iter()
-                            .map(|d| format!("{}: {}",
-                                match d.severity { Severity::Error => "error", Severity::Warning => "warning" },
-                                d.message
-                            ))
-                            .collect::<Vec<_>>()
-                            .join("\n");
-                        Err(PrintError::PdfError(error_msg))
-                    }
-                }
-            }
+            Ok(document) => Ok(document),
             Err(errors) => {
                 // Write debug file on error
                 let debug_path = std::env::temp_dir().join("mimir_debug_error.typ");
@@ -475,20 +643,32 @@ impl DocumentBuilder {
                     tracing::error!("Typst compilation failed. Debug file: {}", debug_path.display());
                 }
 
-                let error_msg = errors
-                    .iter()
-                    .map(|d| format!("{}: {}",
-                        match d.severity { Severity::Error => "error", Severity::Warning => "warning" },
-                        d.message
-                    ))
-                    .collect::<Vec<_>>()
-                    .join("\n");
-                Err(PrintError::CompilationError(error_msg))
+                Err(PrintError::CompilationError(format_diagnostics(&errors)))
             }
         }
     }
 }
 
+/// Export a compiled document as PDF bytes
+fn render_pdf(document: &typst::model::Document) -> Result<Vec<u8>> {
+    typst_pdf::pdf(document, &typst_pdf::PdfOptions::default())
+        .map_err(|errors| PrintError::PdfError(format_diagnostics(&errors)))
+}
+
+/// Join Typst diagnostics into one error message
+fn format_diagnostics(diagnostics: &[typst::diag::SourceDiagnostic]) -> String {
+    use typst::diag::Severity;
+
+    diagnostics
+        .iter()
+        .map(|d| format!("{}: {}",
+            match d.severity { Severity::Error => "error", Severity::Warning => "warning" },
+            d.message
+        ))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Escape special characters for Typst strings
 pub fn escape_typst_string(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -556,12 +736,17 @@ mod tests {
             .with_toc(true)
             .with_page_numbers(false)
             .with_margin(1.0)
-            .with_font_size(12.0);
+            .with_font_size(12.0)
+            .with_profile(PrintProfile {
+                simplified: true,
+                ..Default::default()
+            });
 
         assert!(builder.config.include_toc);
         assert!(!builder.config.page_numbers);
         assert_eq!(builder.config.margin, 1.0);
         assert_eq!(builder.config.font_size, 12.0);
+        assert!(builder.config.profile.simplified);
     }
 
     #[test]
@@ -682,6 +867,71 @@ mod tests {
         assert!(typst.contains("sizes.base"));
     }
 
+    /// A 1x1 PNG image
+    fn tiny_png() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::RgbImage::new(1, 1)
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageFormat::Png,
+            )
+            .unwrap();
+        bytes
+    }
+
+    /// Test section that only prints when chapter dividers are on
+    struct TestDivider;
+
+    impl Renderable for TestDivider {
+        fn to_typst(&self, _ctx: &RenderContext) -> Result<String> {
+            Ok("Divider page".to_string())
+        }
+
+        fn is_chapter_divider(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_build_typst_cover_and_title_page_details() {
+        let builder = DocumentBuilder::new("Curse of Strahd")
+            .with_cover_art(tiny_png())
+            .with_title_page_details(TitlePage {
+                logo: Some(tiny_png()),
+                dm_name: Some("Sam".to_string()),
+                date_range: Some("March 2024 - June 2025".to_string()),
+            })
+            .append(TestSection::new("Body"));
+
+        let typst = builder.build_typst().unwrap();
+
+        let cover = typst.find("/_virtual/cover_art.png").unwrap();
+        let logo = typst.find("/_virtual/title_logo.png").unwrap();
+        let title = typst.find("Curse of Strahd").unwrap();
+        assert!(cover < logo && logo < title);
+        assert!(typst.contains("Dungeon Master: Sam"));
+        assert!(typst.contains("March 2024 - June 2025"));
+    }
+
+    #[test]
+    fn test_build_typst_chapter_dividers_toggle() {
+        let with_dividers = DocumentBuilder::new("Book")
+            .append(TestDivider)
+            .append(TestSection::new("Chapter body"));
+        let typst = with_dividers.build_typst().unwrap();
+        assert!(typst.contains("Divider page"));
+
+        let without_dividers = DocumentBuilder::new("Book")
+            .with_title_page(false)
+            .with_chapter_dividers(false)
+            .append(TestDivider)
+            .append(TestSection::new("Chapter body"));
+        let typst = without_dividers.build_typst().unwrap();
+        assert!(!typst.contains("Divider page"));
+        // The first printed section doesn't start with a blank page
+        assert!(!typst.contains("pagebreak"));
+    }
+
     #[test]
     fn test_build_typst_page_break_between_sections() {
         let builder = DocumentBuilder::new("Page Break Test")
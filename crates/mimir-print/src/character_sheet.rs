@@ -0,0 +1,189 @@
+//! Built-in character sheet layouts
+//!
+//! Each layout is an embedded Typst template under `character/` that renders
+//! the same serialized [`CharacterData`](crate::CharacterData) through the
+//! shared partials in `_shared/character.typ`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::PrintError;
+
+/// A built-in character sheet layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CharacterSheetTemplate {
+    /// Everything on a single page
+    Minimalist,
+    /// Three pages: statistics, equipment and personality, spells and notes
+    #[default]
+    Classic,
+    /// Half-page card for running an NPC
+    NpcCard,
+}
+
+impl CharacterSheetTemplate {
+    /// All built-in layouts, in display order
+    pub const ALL: [CharacterSheetTemplate; 3] = [
+        CharacterSheetTemplate::Minimalist,
+        CharacterSheetTemplate::Classic,
+        CharacterSheetTemplate::NpcCard,
+    ];
+
+    /// Short name used to select the layout ("minimalist", "classic", "npc-card")
+    pub fn name(self) -> &'static str {
+        match self {
+            CharacterSheetTemplate::Minimalist => "minimalist",
+            CharacterSheetTemplate::Classic => "classic",
+            CharacterSheetTemplate::NpcCard => "npc-card",
+        }
+    }
+
+    /// Template path relative to the templates root
+    pub fn template_path(self) -> &'static str {
+        match self {
+            CharacterSheetTemplate::Minimalist => "character/minimalist.typ",
+            CharacterSheetTemplate::Classic => "character/classic.typ",
+            CharacterSheetTemplate::NpcCard => "character/npc-card.typ",
+        }
+    }
+}
+
+impl fmt::Display for CharacterSheetTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CharacterSheetTemplate {
+    type Err = PrintError;
+
+    /// Accepts the short name or the template path, e.g. "npc", "npc-card",
+    /// or "character/npc-card.typ"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().trim_start_matches('/');
+        let name = name.strip_prefix("character/").unwrap_or(name);
+        let name = name.strip_suffix(".typ").unwrap_or(name);
+
+        match name.to_ascii_lowercase().as_str() {
+            "minimalist" => Ok(CharacterSheetTemplate::Minimalist),
+            "classic" => Ok(CharacterSheetTemplate::Classic),
+            "npc" | "npc-card" => Ok(CharacterSheetTemplate::NpcCard),
+            _ => Err(PrintError::TemplateNotFound(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embedded_templates::get_embedded_template;
+    use crate::PrintService;
+
+    fn sample_character() -> serde_json::Value {
+        serde_json::json!({
+            "name": "Mirela Voss",
+            "player_name": null,
+            "is_npc": true,
+            "race_name": "Half-Elf",
+            "background_name": "Acolyte",
+            "strength": 10, "dexterity": 14, "constitution": 12,
+            "intelligence": 11, "wisdom": 17, "charisma": 13,
+            "cp": 0, "sp": 12, "ep": 0, "gp": 40, "pp": 0,
+            "traits": "Quotes scripture at every turn",
+            "ideals": null,
+            "bonds": "The temple that raised her",
+            "flaws": null,
+            "role": "High Priestess",
+            "location": "Saltmarsh",
+            "faction": "Order of the Dawn",
+            "classes": [{
+                "class_name": "Cleric",
+                "class_source": "PHB",
+                "level": 5,
+                "subclass_name": "Life Domain",
+                "is_starting": true
+            }],
+            "inventory": [{
+                "name": "Mace", "quantity": 1, "equipped": true, "attuned": false,
+                "item_type": "M", "damage": "1d6", "damage_type": "B",
+                "armor_ac": null, "finesse": false
+            }],
+            "proficiencies": {
+                "skills": [{ "name": "Insight", "expertise": false },
+                           { "name": "Religion", "expertise": true }],
+                "saves": ["Wisdom", "Charisma"],
+                "languages": ["Common", "Elvish"],
+                "armor": ["Light armor", "Medium armor", "Shields"],
+                "weapons": ["Simple weapons"],
+                "tools": []
+            },
+            "speed": 30,
+            "ac": 16,
+            "hit_points_max": 38,
+            "hit_die": "5d8",
+            "spellcasting_ability": "WIS",
+            "spell_save_dc": 14,
+            "spell_attack_bonus": 6,
+            "spell_slots": [4, 3, 2, 0, 0, 0, 0, 0, 0],
+            "custom_fields": [{ "label": "Faith", "value": "12" }]
+        })
+    }
+
+    #[test]
+    fn test_parse_names_and_paths() {
+        assert_eq!(
+            "minimalist".parse::<CharacterSheetTemplate>().unwrap(),
+            CharacterSheetTemplate::Minimalist
+        );
+        assert_eq!(
+            "Classic".parse::<CharacterSheetTemplate>().unwrap(),
+            CharacterSheetTemplate::Classic
+        );
+        assert_eq!(
+            "npc".parse::<CharacterSheetTemplate>().unwrap(),
+            CharacterSheetTemplate::NpcCard
+        );
+        assert_eq!(
+            "character/npc-card.typ"
+                .parse::<CharacterSheetTemplate>()
+                .unwrap(),
+            CharacterSheetTemplate::NpcCard
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_template() {
+        let err = "character/sheet".parse::<CharacterSheetTemplate>();
+        assert!(matches!(err, Err(PrintError::TemplateNotFound(_))));
+    }
+
+    #[test]
+    fn test_every_layout_is_embedded() {
+        for template in CharacterSheetTemplate::ALL {
+            assert!(get_embedded_template(template.template_path()).is_some());
+            assert_eq!(
+                template.name().parse::<CharacterSheetTemplate>().unwrap(),
+                template
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_layout_renders() {
+        // Built-in layouts need no files in the templates root
+        let temp = tempfile::TempDir::new().unwrap();
+        let service = PrintService::new(temp.path().to_path_buf());
+
+        for (template, pages) in [
+            (CharacterSheetTemplate::Minimalist, 1),
+            (CharacterSheetTemplate::Classic, 3),
+            (CharacterSheetTemplate::NpcCard, 1),
+        ] {
+            let result = service.render_to_pdf(template.template_path(), sample_character());
+            assert!(result.is_ok(), "{} failed: {:?}", template, result.err());
+
+            let pdf = lopdf::Document::load_mem(&result.unwrap()).unwrap();
+            assert_eq!(pdf.get_pages().len(), pages, "{} page count", template);
+        }
+    }
+}
@@ -7,6 +7,19 @@
 pub const STYLES_TYP: &str = r##"// Mimir Print System - Shared Styles
 // Base typography, page settings, and visual constants for all templates
 
+// =============================================================================
+// PRINT PROFILE
+// =============================================================================
+// Accessibility options passed in by the renderer as sys.inputs (see
+// PrintProfile). Documents compiled without them get the standard look.
+
+#let profile = (
+  font-scale: sys.inputs.at("font-scale", default: 1.0),
+  high-contrast: sys.inputs.at("high-contrast", default: false),
+  dyslexia-font: sys.inputs.at("dyslexia-font", default: false),
+  simplified: sys.inputs.at("simplified", default: false),
+)
+
 // =============================================================================
 // COLOR PALETTE
 // =============================================================================
@@ -14,11 +27,11 @@ pub const STYLES_TYP: &str = r##"// Mimir Print System - Shared Styles
 
 #let colors = (
   text: black,
-  text-secondary: luma(60),
-  border: luma(40),
-  border-light: luma(80),
-  background-alt: luma(245),
-  accent: luma(30),
+  text-secondary: if profile.high-contrast { black } else { luma(60) },
+  border: if profile.high-contrast { black } else { luma(40) },
+  border-light: if profile.high-contrast { black } else { luma(80) },
+  background-alt: if profile.high-contrast or profile.simplified { white } else { luma(245) },
+  accent: if profile.high-contrast { black } else { luma(30) },
 )
 
 // =============================================================================
@@ -26,20 +39,22 @@ pub const STYLES_TYP: &str = r##"// Mimir Print System - Shared Styles
 // =============================================================================
 
 // Font stacks (with fallbacks)
-#let font-heading = ("Inter", "Helvetica", "Arial", "sans-serif")
-#let font-body = ("Inter", "Helvetica", "Arial", "sans-serif")
+#let font-standard = ("Inter", "Helvetica", "Arial", "sans-serif")
+#let font-dyslexia = ("OpenDyslexic", "Atkinson Hyperlegible", "Lexend", "Verdana", "sans-serif")
+#let font-heading = if profile.dyslexia-font { font-dyslexia } else { font-standard }
+#let font-body = if profile.dyslexia-font { font-dyslexia } else { font-standard }
 #let font-mono = ("JetBrains Mono", "Consolas", "monospace")
 
 // Text size scale
 #let sizes = (
-  xs: 7pt,
-  sm: 8pt,
-  base: 9pt,
-  md: 10pt,
-  lg: 12pt,
-  xl: 14pt,
-  xxl: 18pt,
-  title: 24pt,
+  xs: 7pt * profile.font-scale,
+  sm: 8pt * profile.font-scale,
+  base: 9pt * profile.font-scale,
+  md: 10pt * profile.font-scale,
+  lg: 12pt * profile.font-scale,
+  xl: 14pt * profile.font-scale,
+  xxl: 18pt * profile.font-scale,
+  title: 24pt * profile.font-scale,
 )
 
 // =============================================================================
@@ -167,6 +182,10 @@ pub const STYLES_TYP: &str = r##"// Mimir Print System - Shared Styles
   xxl: 24pt,
 )
 
+/// Equal columns for a page layout, or a single column in the simplified
+/// profile so the cells stack
+#let layout-columns(count) = if profile.simplified { (1fr,) } else { (1fr,) * count }
+
 // =============================================================================
 // HORIZONTAL RULES
 // =============================================================================
@@ -305,23 +324,31 @@ pub const COMPONENTS_TYP: &str = r##"// Mimir Print System - Reusable Components
 // LAYOUT HELPERS
 // =============================================================================
 
-/// Two-column layout
+/// Two-column layout (stacked in the simplified profile)
 #let two-columns(left, right, gutter: spacing.md) = {
-  grid(
-    columns: (1fr, 1fr),
-    column-gutter: gutter,
-    left,
-    right,
-  )
+  if profile.simplified {
+    stack(dir: ttb, spacing: gutter, left, right)
+  } else {
+    grid(
+      columns: (1fr, 1fr),
+      column-gutter: gutter,
+      left,
+      right,
+    )
+  }
 }
 
-/// Three-column layout
+/// Three-column layout (stacked in the simplified profile)
 #let three-columns(a, b, c, gutter: spacing.md) = {
-  grid(
-    columns: (1fr, 1fr, 1fr),
-    column-gutter: gutter,
-    a, b, c,
-  )
+  if profile.simplified {
+    stack(dir: ttb, spacing: gutter, a, b, c)
+  } else {
+    grid(
+      columns: (1fr, 1fr, 1fr),
+      column-gutter: gutter,
+      a, b, c,
+    )
+  }
 }
 
 /// Labeled value display (label above value)
@@ -367,6 +394,22 @@ pub const COMPONENTS_TYP: &str = r##"// Mimir Print System - Reusable Components
     body
   )
 }
+
+/// Boxed read-aloud text, set apart from DM notes
+#let read-aloud-box(body) = {
+  block(
+    width: 100%,
+    fill: colors.background-alt,
+    stroke: (left: 2pt + colors.accent),
+    inset: spacing.md,
+    radius: 2pt,
+    {
+      label-text("Read Aloud")
+      v(spacing.xs)
+      emph(body)
+    }
+  )
+}
 "##;
 
 /// Icons template (class icons, spell school icons, damage type icons)
@@ -567,6 +610,443 @@ pub const ICONS_TYP: &str = r##"// Mimir Print System - Icons
 }
 "##;
 
+/// Character sheet partials shared by the character layouts
+pub const CHARACTER_TYP: &str = r##"// Mimir Print System - Character Sheet Partials
+// Building blocks shared by the character sheet templates. Every partial
+// takes the injected character data, which is the same for all layouts.
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+
+// =============================================================================
+// DERIVED VALUES
+// =============================================================================
+
+/// Abbreviation, full name, and data key for each ability
+#let char-abilities = (
+  ("STR", "Strength", "strength"),
+  ("DEX", "Dexterity", "dexterity"),
+  ("CON", "Constitution", "constitution"),
+  ("INT", "Intelligence", "intelligence"),
+  ("WIS", "Wisdom", "wisdom"),
+  ("CHA", "Charisma", "charisma"),
+)
+
+/// The 18 skills and the ability each uses
+#let char-skill-list = (
+  ("Acrobatics", "dexterity"),
+  ("Animal Handling", "wisdom"),
+  ("Arcana", "intelligence"),
+  ("Athletics", "strength"),
+  ("Deception", "charisma"),
+  ("History", "intelligence"),
+  ("Insight", "wisdom"),
+  ("Intimidation", "charisma"),
+  ("Investigation", "intelligence"),
+  ("Medicine", "wisdom"),
+  ("Nature", "intelligence"),
+  ("Perception", "wisdom"),
+  ("Performance", "charisma"),
+  ("Persuasion", "charisma"),
+  ("Religion", "intelligence"),
+  ("Sleight of Hand", "dexterity"),
+  ("Stealth", "dexterity"),
+  ("Survival", "wisdom"),
+)
+
+/// Total level across all classes
+#let char-level(data) = data.classes.map(c => c.level).sum(default: 0)
+
+/// Proficiency bonus for the character's total level
+#let char-prof(data) = 2 + calc.floor((calc.max(char-level(data), 1) - 1) / 4)
+
+/// Ability modifier for a score
+#let char-mod(score) = calc.floor((score - 10) / 2)
+
+/// A bonus with its sign ("+3", "-1")
+#let char-signed(n) = if n >= 0 { "+" + str(n) } else { str(n) }
+
+/// Classes with subclasses and levels ("Fighter (Champion) 5 / Rogue 3")
+#let char-classes(data) = {
+  if data.classes.len() == 0 { return "No Class" }
+  data.classes.map(c => {
+    let name = if c.subclass_name != none { c.class_name + " (" + c.subclass_name + ")" } else { c.class_name }
+    name + " " + str(c.level)
+  }).join(" / ")
+}
+
+/// Saving throw bonus for one of `char-abilities`
+#let char-save(data, ability) = {
+  let (_, name, key) = ability
+  let proficient = name in data.proficiencies.saves
+  char-mod(data.at(key)) + if proficient { char-prof(data) } else { 0 }
+}
+
+/// Bonus and proficiency marker for one of `char-skill-list`
+#let char-skill(data, skill) = {
+  let (name, key) = skill
+  let entry = data.proficiencies.skills.find(p => p.name == name)
+  let prof = char-prof(data)
+  let bonus = char-mod(data.at(key)) + if entry == none { 0 } else if entry.expertise { 2 * prof } else { prof }
+  let marker = if entry == none { "○" } else if entry.expertise { "◆" } else { "●" }
+  (name: name, bonus: bonus, marker: marker, proficient: entry != none)
+}
+
+/// Passive Perception
+#let char-passive-perception(data) = 10 + char-skill(data, ("Perception", "wisdom")).bonus
+
+// =============================================================================
+// PARTIALS
+// =============================================================================
+
+/// Titled block that every layout groups its content in
+#let char-block(title, body) = block(width: 100%, breakable: true, below: spacing.md)[
+  #text(weight: "bold", size: sizes.sm, tracking: 0.5pt, fill: colors.text-secondary)[#upper(title)]
+  #v(spacing.xs, weak: true)
+  #line(length: 100%, stroke: 0.5pt + colors.border-light)
+  #v(spacing.xs, weak: true)
+  #body
+]
+
+/// Name, classes, race, background, and player
+#let char-header(data, size: sizes.title) = {
+  text(size: size, weight: "bold", font: font-heading)[#data.name]
+  linebreak()
+  let details = (char-classes(data), data.race_name, data.background_name).filter(d => d != none)
+  small-text(details.join(" · "))
+  if data.player_name != none {
+    h(1fr)
+    small-text[Player: #data.player_name]
+  }
+}
+
+/// Ability scores and modifiers in a row of boxes
+#let char-ability-row(data) = ability-scores(
+  str: data.strength,
+  dex: data.dexterity,
+  con: data.constitution,
+  int: data.intelligence,
+  wis: data.wisdom,
+  cha: data.charisma,
+  layout: "row",
+)
+
+/// AC, hit points, hit dice, speed, initiative, and proficiency bonus
+#let char-combat(data) = {
+  let stats = (
+    ("AC", str(data.ac)),
+    ("HP", str(data.hit_points_max)),
+    ("Hit Dice", data.hit_die),
+    ("Speed", str(data.speed) + " ft"),
+    ("Init", char-signed(char-mod(data.dexterity))),
+    ("Prof", char-signed(char-prof(data))),
+  )
+  grid(
+    columns: (1fr,) * stats.len(),
+    column-gutter: spacing.sm,
+    ..stats.map(((title, value)) => box(
+      width: 100%,
+      stroke: 0.5pt + colors.border,
+      inset: spacing.sm,
+      radius: 2pt,
+      align(center, labeled-value(title, value)),
+    ))
+  )
+}
+
+/// Saving throws; ● marks proficiency
+#let char-saves(data) = {
+  for ability in char-abilities {
+    let entry = [#if ability.at(1) in data.proficiencies.saves [●] else [○] #ability.at(1) #h(1fr) #char-signed(char-save(data, ability))]
+    if ability.at(1) in data.proficiencies.saves { strong(entry) } else { entry }
+    linebreak()
+  }
+}
+
+/// Skills with bonuses; ● marks proficiency and ◆ expertise
+#let char-skills(data, columns: 1, proficient-only: false) = {
+  let skills = char-skill-list.map(skill => char-skill(data, skill))
+  if proficient-only { skills = skills.filter(s => s.proficient) }
+  grid(
+    columns: layout-columns(columns),
+    column-gutter: spacing.md,
+    row-gutter: spacing.xs,
+    ..skills.map(s => {
+      let entry = [#s.marker #s.name #h(1fr) #char-signed(s.bonus)]
+      if s.proficient { strong(entry) } else { entry }
+    })
+  )
+}
+
+/// Attack and damage for each equipped weapon
+#let char-attacks(data) = {
+  let weapons = data.inventory.filter(i => i.equipped and i.damage != none)
+  if weapons.len() == 0 { return small-text[No weapons equipped] }
+
+  let prof = char-prof(data)
+  let str-mod = char-mod(data.strength)
+  let dex-mod = char-mod(data.dexterity)
+  table(
+    columns: (1fr, auto, auto),
+    stroke: none,
+    inset: (x: 0pt, y: spacing.xs),
+    column-gutter: spacing.md,
+    label-text[Weapon], label-text[Hit], label-text[Damage],
+    ..weapons.map(w => {
+      let ranged = w.item_type != none and w.item_type.starts-with("R")
+      let ability = if ranged { dex-mod } else if w.finesse { calc.max(str-mod, dex-mod) } else { str-mod }
+      let kind = if w.damage_type != none { " " + w.damage_type } else { "" }
+      (w.name, char-signed(ability + prof), w.damage + " " + char-signed(ability) + kind)
+    }).flatten()
+  )
+}
+
+/// Spellcasting ability, save DC, attack bonus, and slot boxes per level
+#let char-spellcasting(data) = {
+  if data.spellcasting_ability == none { return none }
+  [*#data.spellcasting_ability*]
+  if data.spell_save_dc != none [ #h(spacing.md) Save DC #data.spell_save_dc]
+  if data.spell_attack_bonus != none [ #h(spacing.md) Attack #char-signed(data.spell_attack_bonus)]
+  let slots = data.spell_slots.enumerate().filter(((_, count)) => count > 0)
+  if slots.len() > 0 {
+    linebreak()
+    slots.map(((level, count)) => [Level #(level + 1) #("☐" * count)]).join(h(spacing.md))
+  }
+}
+
+/// Languages, armor, weapons, and tools
+#let char-proficiencies(data) = {
+  let groups = (
+    ("Languages", data.proficiencies.languages),
+    ("Armor", data.proficiencies.armor),
+    ("Weapons", data.proficiencies.weapons),
+    ("Tools", data.proficiencies.tools),
+  ).filter(((_, items)) => items.len() > 0)
+  for (title, items) in groups [
+    *#title:* #items.join(", ") \
+  ]
+}
+
+/// Equipment; equipped items in bold, attuned items marked ✦
+#let char-inventory(data) = {
+  if data.inventory.len() == 0 { return small-text[No equipment] }
+  for item in data.inventory {
+    let name = if item.quantity > 1 { item.name + " ×" + str(item.quantity) } else { item.name }
+    let entry = if item.attuned { name + " ✦" } else { name }
+    if item.equipped { strong(entry) } else { entry }
+    linebreak()
+  }
+}
+
+/// Coins by denomination
+#let char-currency(data) = {
+  let coins = (("CP", data.cp), ("SP", data.sp), ("EP", data.ep), ("GP", data.gp), ("PP", data.pp))
+  grid(
+    columns: (1fr,) * coins.len(),
+    ..coins.map(((title, count)) => align(center, labeled-value(title, str(count))))
+  )
+}
+
+/// Personality traits, ideals, bonds, and flaws that are filled in
+#let char-personality(data) = {
+  let entries = (
+    ("Traits", data.traits),
+    ("Ideals", data.ideals),
+    ("Bonds", data.bonds),
+    ("Flaws", data.flaws),
+  ).filter(((_, value)) => value != none and value.trim() != "")
+  for (title, value) in entries [
+    #label-text(title) \
+    #value
+    #v(spacing.sm, weak: true)
+  ]
+}
+
+/// Role, location, and faction for NPCs
+#let char-npc-info(data) = {
+  let entries = (
+    ("Role", data.role),
+    ("Location", data.location),
+    ("Faction", data.faction),
+  ).filter(((_, value)) => value != none)
+  for (title, value) in entries [*#title:* #value \ ]
+}
+
+/// Campaign custom fields (house-ruled stats)
+#let char-custom-fields(data) = {
+  for entry in data.custom_fields [*#entry.label:* #entry.value \ ]
+}
+
+/// Blank ruled lines for handwritten notes
+#let char-ruled-lines(count) = {
+  for _ in range(count) {
+    v(sizes.base * 1.6)
+    line(length: 100%, stroke: 0.5pt + colors.border-light)
+  }
+}
+"##;
+
+/// One-page minimalist character sheet
+pub const CHARACTER_MINIMALIST_TYP: &str = r##"// Mimir Character Sheet - Minimalist
+// Everything needed at the table on a single page
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+#import "/_shared/character.typ": *
+
+#show: mimir-doc.with(margin: 0.5in)
+
+#char-header(data)
+#v(spacing.md)
+#char-ability-row(data)
+#v(spacing.sm)
+#char-combat(data)
+#v(spacing.md)
+
+#grid(
+  columns: layout-columns(2),
+  column-gutter: spacing.lg,
+  [
+    #char-block("Saving Throws", char-saves(data))
+    #char-block("Skills", char-skills(data))
+  ],
+  [
+    #char-block("Attacks", char-attacks(data))
+    #if data.spellcasting_ability != none {
+      char-block("Spellcasting", char-spellcasting(data))
+    }
+    #char-block("Proficiencies", char-proficiencies(data))
+    #char-block("Equipment", char-inventory(data))
+    #char-block("Passive Perception")[#char-passive-perception(data)]
+  ],
+)
+"##;
+
+/// Three-page classic character sheet
+pub const CHARACTER_CLASSIC_TYP: &str = r##"// Mimir Character Sheet - Classic
+// Three pages: statistics, equipment and personality, spellcasting and notes
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+#import "/_shared/character.typ": *
+
+#show: mimir-doc.with(margin: 0.5in)
+
+// =============================================================================
+// PAGE 1: STATISTICS
+// =============================================================================
+
+#char-header(data)
+#v(spacing.md)
+#char-combat(data)
+#v(spacing.md)
+
+#grid(
+  columns: if profile.simplified { (1fr,) } else { (0.9in, 1fr, 1fr) },
+  column-gutter: spacing.lg,
+  row-gutter: spacing.md,
+  stack(
+    dir: ttb,
+    spacing: spacing.sm,
+    ..char-abilities.map(a => ability-box(a.at(0), data.at(a.at(2)))),
+  ),
+  [
+    #char-block("Saving Throws", char-saves(data))
+    #char-block("Skills", char-skills(data))
+  ],
+  [
+    #char-block("Attacks", char-attacks(data))
+    #char-block("Proficiencies", char-proficiencies(data))
+    #char-block("Passive Perception")[#char-passive-perception(data)]
+  ],
+)
+
+// =============================================================================
+// PAGE 2: EQUIPMENT AND PERSONALITY
+// =============================================================================
+
+#pagebreak()
+
+#char-header(data, size: sizes.xl)
+#v(spacing.md)
+
+#grid(
+  columns: layout-columns(2),
+  column-gutter: spacing.lg,
+  [
+    #char-block("Currency", char-currency(data))
+    #char-block("Equipment", char-inventory(data))
+  ],
+  [
+    #char-block("Personality", char-personality(data))
+    #if data.is_npc {
+      char-block("NPC", char-npc-info(data))
+    }
+    #if data.custom_fields.len() > 0 {
+      char-block("Campaign Fields", char-custom-fields(data))
+    }
+  ],
+)
+
+// =============================================================================
+// PAGE 3: SPELLCASTING AND NOTES
+// =============================================================================
+
+#pagebreak()
+
+#char-header(data, size: sizes.xl)
+#v(spacing.md)
+
+#if data.spellcasting_ability != none {
+  char-block("Spellcasting", char-spellcasting(data))
+  char-block("Spells Known")[#char-ruled-lines(12)]
+}
+
+#char-block("Notes")[#char-ruled-lines(if data.spellcasting_ability != none { 12 } else { 28 })]
+"##;
+
+/// Half-page NPC card
+pub const CHARACTER_NPC_CARD_TYP: &str = r##"// Mimir Character Sheet - NPC Card
+// Half-page card for running an NPC: stats, proficient skills, and roleplay
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+#import "/_shared/character.typ": *
+
+#show: mimir-doc.with(margin: 0.4in)
+#set page(width: 8.5in, height: 5.5in)
+
+#char-header(data, size: sizes.xxl)
+#let npc-details = (data.role, data.location, data.faction).filter(v => v != none)
+#if npc-details.len() > 0 [
+  #v(spacing.xs)
+  #small-text(npc-details.join(" · "))
+]
+#v(spacing.sm)
+#char-ability-row(data)
+#v(spacing.sm)
+#char-combat(data)
+#v(spacing.sm)
+
+#grid(
+  columns: layout-columns(2),
+  column-gutter: spacing.lg,
+  [
+    #char-block("Skills", char-skills(data, columns: 2, proficient-only: true))
+    #char-block("Attacks", char-attacks(data))
+    #if data.spellcasting_ability != none {
+      char-block("Spellcasting", char-spellcasting(data))
+    }
+  ],
+  [
+    #char-block("Personality", char-personality(data))
+    #if data.proficiencies.languages.len() > 0 {
+      char-block("Languages", data.proficiencies.languages.join(", "))
+    }
+  ],
+)
+"##;
+
 /// Get embedded template content by path
 /// Returns None if path is not a known embedded template
 pub fn get_embedded_template(path: &str) -> Option<&'static str> {
@@ -577,6 +1057,10 @@ pub fn get_embedded_template(path: &str) -> Option<&'static str> {
         "_shared/styles.typ" => Some(STYLES_TYP),
         "_shared/components.typ" => Some(COMPONENTS_TYP),
         "_shared/icons.typ" => Some(ICONS_TYP),
+        "_shared/character.typ" => Some(CHARACTER_TYP),
+        "character/minimalist.typ" => Some(CHARACTER_MINIMALIST_TYP),
+        "character/classic.typ" => Some(CHARACTER_CLASSIC_TYP),
+        "character/npc-card.typ" => Some(CHARACTER_NPC_CARD_TYP),
         _ => None,
     }
 }
@@ -591,6 +1075,8 @@ mod tests {
         assert!(get_embedded_template("_shared/styles.typ").is_some());
         assert!(get_embedded_template("/_shared/components.typ").is_some());
         assert!(get_embedded_template("/_shared/icons.typ").is_some());
+        assert!(get_embedded_template("/_shared/character.typ").is_some());
+        assert!(get_embedded_template("character/classic.typ").is_some());
         assert!(get_embedded_template("nonexistent.typ").is_none());
     }
 
@@ -601,13 +1087,38 @@ mod tests {
         assert!(STYLES_TYP.contains("#let mimir-doc"));
     }
 
+    #[test]
+    fn test_styles_reads_print_profile() {
+        // PrintProfile::to_inputs sets these keys
+        for key in ["font-scale", "high-contrast", "dyslexia-font", "simplified"] {
+            assert!(STYLES_TYP.contains(&format!("sys.inputs.at(\"{}\"", key)));
+        }
+    }
+
     #[test]
     fn test_components_imports_styles() {
         assert!(COMPONENTS_TYP.contains("#import \"/_shared/styles.typ\": *"));
     }
 
+    #[test]
+    fn test_components_defines_read_aloud_box() {
+        // markdown_to_typst emits this for `:::read-aloud` blocks
+        assert!(COMPONENTS_TYP.contains("#let read-aloud-box(body)"));
+    }
+
     #[test]
     fn test_icons_imports_styles() {
         assert!(ICONS_TYP.contains("#import \"/_shared/styles.typ\": *"));
     }
+
+    #[test]
+    fn test_character_layouts_import_shared_partials() {
+        for layout in [
+            CHARACTER_MINIMALIST_TYP,
+            CHARACTER_CLASSIC_TYP,
+            CHARACTER_NPC_CARD_TYP,
+        ] {
+            assert!(layout.contains("#import \"/_shared/character.typ\": *"));
+        }
+    }
 }
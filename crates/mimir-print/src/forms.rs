@@ -0,0 +1,397 @@
+//! Fillable PDF form fields
+//!
+//! Sections mark where a form field goes by emitting [`form_field`] markup: a
+//! bordered box carrying a `metadata` element that names the field. After
+//! compilation, [`collect_form_fields`] finds those elements and their
+//! positions through Typst's introspector, and [`add_form_fields`] adds
+//! matching AcroForm widgets to the rendered PDF.
+
+use lopdf::{dictionary, Dictionary, Object, ObjectId, Stream};
+use typst::foundations::{NativeElement, Selector, Value};
+use typst::introspection::MetadataElem;
+use typst::model::Document;
+
+use crate::error::{PrintError, Result};
+
+/// Metadata key that identifies a form field marker.
+const FIELD_KEY: &str = "mimir_form_field";
+
+/// Font size for text fields, in points.
+const TEXT_FONT_SIZE: f64 = 10.0;
+
+/// Kind of form field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormFieldKind {
+    /// Single-line text input
+    Text,
+    /// Checkbox that toggles between checked and unchecked
+    Checkbox,
+}
+
+impl FormFieldKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            FormFieldKind::Text => "text",
+            FormFieldKind::Checkbox => "checkbox",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(FormFieldKind::Text),
+            "checkbox" => Some(FormFieldKind::Checkbox),
+            _ => None,
+        }
+    }
+}
+
+/// A form field placed on a rendered page.
+///
+/// Coordinates are in points from the top-left corner of the page, as Typst
+/// lays them out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormField {
+    pub name: String,
+    pub kind: FormFieldKind,
+    /// 1-based page number
+    pub page: usize,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Typst markup for a form field box of the given size in points.
+///
+/// Returns a code expression; prefix it with `#` when used in markup.
+pub fn form_field(name: &str, kind: FormFieldKind, width: f64, height: f64) -> String {
+    format!(
+        "box(width: {w}pt, height: {h}pt, stroke: 0.5pt + colors.border-light, radius: 2pt)[#metadata(({key}: \"{name}\", kind: \"{kind}\", width: {w:.1}, height: {h:.1}))]",
+        w = width,
+        h = height,
+        key = FIELD_KEY,
+        name = name,
+        kind = kind.as_str(),
+    )
+}
+
+/// Find the form field markers in a compiled document.
+pub fn collect_form_fields(document: &Document) -> Vec<FormField> {
+    let introspector = &document.introspector;
+    let selector = Selector::Elem(MetadataElem::elem(), None);
+
+    introspector
+        .query(&selector)
+        .iter()
+        .filter_map(|content| {
+            let elem = content.to_packed::<MetadataElem>()?;
+            let Value::Dict(dict) = &elem.value else {
+                return None;
+            };
+            let name = match dict.get(FIELD_KEY).ok()? {
+                Value::Str(name) => name.to_string(),
+                _ => return None,
+            };
+            let kind = match dict.get("kind").ok()? {
+                Value::Str(kind) => FormFieldKind::parse(kind.as_str())?,
+                _ => return None,
+            };
+            let position = introspector.position(content.location()?);
+
+            Some(FormField {
+                name,
+                kind,
+                page: position.page.get(),
+                x: position.point.x.to_pt(),
+                y: position.point.y.to_pt(),
+                width: number(dict.get("width").ok()?)?,
+                height: number(dict.get("height").ok()?)?,
+            })
+        })
+        .collect()
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int(n) => Some(*n as f64),
+        Value::Float(n) => Some(*n),
+        _ => None,
+    }
+}
+
+/// Add AcroForm widgets for `fields` to a rendered PDF.
+///
+/// `page_heights` gives each page's height in points, used to flip Typst's
+/// top-down coordinates into PDF space.
+pub fn add_form_fields(pdf: &[u8], fields: &[FormField], page_heights: &[f64]) -> Result<Vec<u8>> {
+    let mut doc = lopdf::Document::load_mem(pdf).map_err(pdf_error)?;
+    let pages = doc.get_pages();
+
+    let helvetica = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+        "Encoding" => "WinAnsiEncoding",
+    });
+    let zapf = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "ZapfDingbats",
+    });
+
+    let mut field_ids = Vec::with_capacity(fields.len());
+    for field in fields {
+        let (Some(&page_id), Some(&page_height)) = (
+            pages.get(&(field.page as u32)),
+            field.page.checked_sub(1).and_then(|i| page_heights.get(i)),
+        ) else {
+            return Err(PrintError::PdfError(format!(
+                "Form field '{}' is on missing page {}",
+                field.name, field.page
+            )));
+        };
+
+        let bottom = page_height - field.y - field.height;
+        let mut widget = dictionary! {
+            "Type" => "Annot",
+            "Subtype" => "Widget",
+            "T" => Object::string_literal(field.name.as_str()),
+            "Rect" => vec![
+                field.x.into(),
+                bottom.into(),
+                (field.x + field.width).into(),
+                (bottom + field.height).into(),
+            ],
+            "F" => 4,
+            "P" => page_id,
+        };
+        match field.kind {
+            FormFieldKind::Text => {
+                widget.set("FT", "Tx");
+                widget.set("V", Object::string_literal(""));
+                widget.set(
+                    "DA",
+                    Object::string_literal(format!("/Helv {} Tf 0 g", TEXT_FONT_SIZE)),
+                );
+            }
+            FormFieldKind::Checkbox => {
+                let on = checkmark_appearance(&mut doc, zapf, field.width, field.height);
+                let off = doc.add_object(appearance_stream(
+                    field.width,
+                    field.height,
+                    None,
+                    Vec::new(),
+                ));
+                widget.set("FT", "Btn");
+                widget.set("V", "Off");
+                widget.set("AS", "Off");
+                widget.set("DA", Object::string_literal("/ZaDb 0 Tf 0 g"));
+                widget.set("MK", dictionary! { "CA" => Object::string_literal("4") });
+                widget.set(
+                    "AP",
+                    dictionary! { "N" => dictionary! { "Yes" => on, "Off" => off } },
+                );
+            }
+        }
+
+        let widget_id = doc.add_object(widget);
+        add_page_annotation(&mut doc, page_id, widget_id)?;
+        field_ids.push(Object::Reference(widget_id));
+    }
+
+    let acro_form = dictionary! {
+        "Fields" => field_ids,
+        "NeedAppearances" => true,
+        "DA" => Object::string_literal(format!("/Helv {} Tf 0 g", TEXT_FONT_SIZE)),
+        "DR" => dictionary! {
+            "Font" => dictionary! { "Helv" => helvetica, "ZaDb" => zapf },
+        },
+    };
+    doc.catalog_mut()
+        .map_err(pdf_error)?
+        .set("AcroForm", acro_form);
+
+    let mut out = Vec::new();
+    doc.save_to(&mut out).map_err(pdf_error)?;
+    Ok(out)
+}
+
+/// Appearance of a checked checkbox: a ZapfDingbats check mark.
+fn checkmark_appearance(
+    doc: &mut lopdf::Document,
+    font: ObjectId,
+    width: f64,
+    height: f64,
+) -> ObjectId {
+    let size = height * 0.8;
+    let content = format!(
+        "q BT /ZaDb {size:.2} Tf 0 g {x:.2} {y:.2} Td (4) Tj ET Q",
+        size = size,
+        x = (width - size * 0.85) / 2.0,
+        y = (height - size * 0.7) / 2.0,
+    );
+    let resources = dictionary! { "Font" => dictionary! { "ZaDb" => font } };
+    doc.add_object(appearance_stream(
+        width,
+        height,
+        Some(resources),
+        content.into_bytes(),
+    ))
+}
+
+fn appearance_stream(
+    width: f64,
+    height: f64,
+    resources: Option<Dictionary>,
+    content: Vec<u8>,
+) -> Stream {
+    let mut dict = dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Form",
+        "BBox" => vec![0.into(), 0.into(), width.into(), height.into()],
+    };
+    if let Some(resources) = resources {
+        dict.set("Resources", resources);
+    }
+    Stream::new(dict, content)
+}
+
+/// Append an annotation to a page's `Annots` array, which may be inline or
+/// an indirect object.
+fn add_page_annotation(
+    doc: &mut lopdf::Document,
+    page_id: ObjectId,
+    annot: ObjectId,
+) -> Result<()> {
+    let annots = doc
+        .get_object(page_id)
+        .and_then(Object::as_dict)
+        .map_err(pdf_error)?
+        .get(b"Annots")
+        .ok()
+        .cloned();
+
+    match annots {
+        Some(Object::Reference(array_id)) => {
+            doc.get_object_mut(array_id)
+                .and_then(Object::as_array_mut)
+                .map_err(pdf_error)?
+                .push(Object::Reference(annot));
+        }
+        Some(Object::Array(mut array)) => {
+            array.push(Object::Reference(annot));
+            page_dict_mut(doc, page_id)?.set("Annots", array);
+        }
+        _ => {
+            page_dict_mut(doc, page_id)?.set("Annots", vec![Object::Reference(annot)]);
+        }
+    }
+    Ok(())
+}
+
+fn page_dict_mut(doc: &mut lopdf::Document, page_id: ObjectId) -> Result<&mut Dictionary> {
+    doc.get_object_mut(page_id)
+        .and_then(Object::as_dict_mut)
+        .map_err(pdf_error)
+}
+
+fn pdf_error(e: lopdf::Error) -> PrintError {
+    PrintError::PdfError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A blank single-page letter-size PDF.
+    fn blank_pdf() -> Vec<u8> {
+        let mut doc = lopdf::Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let content_id = doc.add_object(Stream::new(dictionary! {}, Vec::new()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+                "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut out = Vec::new();
+        doc.save_to(&mut out).unwrap();
+        out
+    }
+
+    fn field(name: &str, kind: FormFieldKind) -> FormField {
+        FormField {
+            name: name.to_string(),
+            kind,
+            page: 1,
+            x: 72.0,
+            y: 100.0,
+            width: 50.0,
+            height: 14.0,
+        }
+    }
+
+    #[test]
+    fn test_form_field_markup() {
+        let markup = form_field("hp_current", FormFieldKind::Text, 40.0, 14.0);
+        assert!(markup.starts_with("box(width: 40pt, height: 14pt"));
+        assert!(markup.contains("mimir_form_field: \"hp_current\""));
+        assert!(markup.contains("kind: \"text\""));
+        assert!(markup.contains("width: 40.0, height: 14.0"));
+    }
+
+    #[test]
+    fn test_add_form_fields() {
+        let fields = vec![
+            field("hp_current", FormFieldKind::Text),
+            field("inspiration", FormFieldKind::Checkbox),
+        ];
+        let pdf = add_form_fields(&blank_pdf(), &fields, &[792.0]).unwrap();
+
+        let doc = lopdf::Document::load_mem(&pdf).unwrap();
+        let acro_form = doc
+            .catalog()
+            .unwrap()
+            .get(b"AcroForm")
+            .unwrap()
+            .as_dict()
+            .unwrap();
+        let field_refs = acro_form.get(b"Fields").unwrap().as_array().unwrap();
+        assert_eq!(field_refs.len(), 2);
+
+        let text = doc
+            .get_object(field_refs[0].as_reference().unwrap())
+            .unwrap()
+            .as_dict()
+            .unwrap();
+        assert_eq!(text.get(b"FT").unwrap().as_name_str().unwrap(), "Tx");
+        let rect = text.get(b"Rect").unwrap().as_array().unwrap();
+        // y is flipped: 792 - 100 - 14
+        assert_eq!(rect[1].as_float().unwrap(), 678.0);
+
+        let page_id = doc.get_pages()[&1];
+        let page = doc.get_object(page_id).unwrap().as_dict().unwrap();
+        assert_eq!(page.get(b"Annots").unwrap().as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_field_on_missing_page_fails() {
+        let mut f = field("hp_current", FormFieldKind::Text);
+        f.page = 2;
+        assert!(add_form_fields(&blank_pdf(), &[f], &[792.0]).is_err());
+    }
+}
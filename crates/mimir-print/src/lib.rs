@@ -13,6 +13,10 @@
 //! - **markdown**: Markdown to Typst conversion with frontmatter support
 //! - **sections**: Renderable document sections (markdown, monsters, maps, etc.)
 //! - **map_renderer**: Map image rendering with grid, LOS walls, and tokens
+//! - **forms**: AcroForm fields for fillable PDFs
+//! - **booklet**: Booklet imposition for saddle-stitch printing
+//! - **profile**: Accessibility print profiles (text scale, contrast, fonts)
+//! - **character_sheet**: Built-in character sheet layouts
 //!
 //! # Usage
 //!
@@ -22,7 +26,7 @@
 //! use mimir_print::PrintService;
 //!
 //! let service = PrintService::new(templates_path);
-//! let pdf = service.render_to_pdf("character/sheet.typ", data)?;
+//! let pdf = service.render_to_pdf("character/classic.typ", data)?;
 //! ```
 //!
 //! ## Multi-Section Document Assembly
@@ -47,20 +51,39 @@ pub mod markdown;
 pub mod map_renderer;
 pub mod sections;
 pub mod embedded_templates;
+pub mod forms;
+pub mod booklet;
+pub mod profile;
+pub mod character_sheet;
 
 pub use error::{PrintError, Result};
 pub use world::MimirTypstWorld;
 pub use service::{PrintService, TemplateInfo};
-pub use builder::{DocumentBuilder, DocumentConfig, Renderable, RenderContext, VirtualFileRegistry, escape_typst_string};
+pub use builder::{DocumentBuilder, DocumentConfig, Renderable, RenderContext, TitlePage, VirtualFileRegistry, escape_typst_string};
 pub use markdown::{ParsedDocument, parse_campaign_document, markdown_to_typst};
+pub use markdown::{
+    find_monster_embeds, markdown_to_typst_with_monsters, parse_campaign_document_with_monsters,
+    MonsterEmbed, MonsterEmbeds,
+};
+pub use markdown::{
+    find_map_region_refs, markdown_to_typst_with_embeds, parse_campaign_document_with_embeds,
+    DocumentEmbeds, EncounterRegion, EncounterTrap, MapRegionEmbeds, MapRegionRef,
+};
 pub use sections::MarkdownSection;
-pub use sections::{CharacterData, CharacterSection, ClassInfo, InventoryItem};
+pub use sections::ChapterDivider;
+pub use sections::{CharacterData, CharacterSection, ClassInfo, CustomFieldEntry, InventoryItem};
 pub use sections::CharacterBattleCardSection;
+pub use sections::ConditionMarkerSection;
 pub use sections::{is_card_worthy, EquipmentCardsSection};
 pub use sections::{MapPreview, TileData, TiledMapSection};
 pub use sections::{MonsterCardSection, TrapCardSection};
 pub use sections::{SpellCardsSection};
+pub use sections::{GrimoireSection, GrimoireSpell};
 pub use sections::{CutoutToken, TokenCutoutSection};
+pub use forms::{FormField, FormFieldKind};
+pub use booklet::{booklet_page_order, impose_booklet, BookletLayout};
+pub use profile::PrintProfile;
+pub use character_sheet::CharacterSheetTemplate;
 pub use map_renderer::{MapPrintOptions, RenderMap, RenderToken, RenderedMapForPrint};
 
 /// State for print functionality, managed by Tauri.
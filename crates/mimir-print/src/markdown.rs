@@ -3,12 +3,17 @@
 //! This module handles parsing campaign markdown documents (with YAML frontmatter)
 //! and converting them to Typst markup for PDF rendering.
 
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 use gray_matter::{engine::YAML, Matter, ParsedEntity};
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use regex::Regex;
 use serde_json::Value;
 use serde_yaml::Value as YamlValue;
 
 use crate::error::{PrintError, Result};
+use crate::sections::monster_cards::render_stat_block;
 
 /// A parsed campaign document with frontmatter and converted content.
 #[derive(Debug, Clone)]
@@ -27,6 +32,32 @@ pub struct ParsedDocument {
 /// # Returns
 /// A `ParsedDocument` with the frontmatter as JSON and content as Typst markup
 pub fn parse_campaign_document(markdown: &str) -> Result<ParsedDocument> {
+    parse_campaign_document_with_monsters(markdown, &MonsterEmbeds::new())
+}
+
+/// Parse a campaign document, rendering `{{monster: ...}}` embeds from the
+/// given resolved monsters.
+pub fn parse_campaign_document_with_monsters(
+    markdown: &str,
+    monsters: &MonsterEmbeds,
+) -> Result<ParsedDocument> {
+    parse_document(markdown, monsters, &MapRegionEmbeds::new())
+}
+
+/// Parse a campaign document, rendering monster embeds and map region
+/// references from the given resolved embeds.
+pub fn parse_campaign_document_with_embeds(
+    markdown: &str,
+    embeds: &DocumentEmbeds,
+) -> Result<ParsedDocument> {
+    parse_document(markdown, &embeds.monsters, &embeds.map_regions)
+}
+
+fn parse_document(
+    markdown: &str,
+    monsters: &MonsterEmbeds,
+    regions: &MapRegionEmbeds,
+) -> Result<ParsedDocument> {
     // Parse frontmatter using gray_matter
     let matter = Matter::<YAML>::new();
     let parsed: ParsedEntity<YamlValue> = matter
@@ -45,7 +76,7 @@ pub fn parse_campaign_document(markdown: &str) -> Result<ParsedDocument> {
     };
 
     // Convert markdown content to Typst
-    let typst_content = markdown_to_typst(&parsed.content);
+    let typst_content = convert_document(&parsed.content, monsters, regions);
 
     Ok(ParsedDocument {
         frontmatter,
@@ -63,7 +94,409 @@ pub fn parse_campaign_document(markdown: &str) -> Result<ParsedDocument> {
 /// - Links (`[text](url)` -> `#link("url")[text]`)
 /// - Code blocks (converted to raw blocks)
 /// - Tables (converted to Typst table syntax)
+/// - Boxed read-aloud text (`:::read-aloud` ... `:::` -> `#read-aloud-box[...]`)
+/// - Monster embeds (`{{monster: Goblin|MM}}`), shown as the bold monster name
+/// - Map region references (`{{map-region: Crypt Level 1 | area-3}}`), shown
+///   as the bold region name
 pub fn markdown_to_typst(markdown: &str) -> String {
+    markdown_to_typst_with_monsters(markdown, &MonsterEmbeds::new())
+}
+
+/// Convert markdown text to Typst markup, rendering monster embeds.
+///
+/// An embed on a line of its own becomes a full stat block when its monster
+/// is in `monsters`, or a fallback box explaining why it is missing. Embeds
+/// inside other text become the bold monster name.
+pub fn markdown_to_typst_with_monsters(markdown: &str, monsters: &MonsterEmbeds) -> String {
+    convert_document(markdown, monsters, &MapRegionEmbeds::new())
+}
+
+/// Convert markdown text to Typst markup, rendering monster embeds and map
+/// region references.
+///
+/// A region reference on a line of its own becomes an encounter box listing
+/// the tokens placed in the region, or a fallback box when the region is not
+/// in `embeds`. References inside other text become the bold region name.
+pub fn markdown_to_typst_with_embeds(markdown: &str, embeds: &DocumentEmbeds) -> String {
+    convert_document(markdown, &embeds.monsters, &embeds.map_regions)
+}
+
+fn convert_document(markdown: &str, monsters: &MonsterEmbeds, regions: &MapRegionEmbeds) -> String {
+    let mut output = String::new();
+    let mut plain = String::new();
+    let mut boxed: Option<String> = None;
+    let mut in_code = false;
+
+    for line in markdown.lines() {
+        match boxed.as_mut() {
+            Some(text) if line.trim() == READ_ALOUD_CLOSE => {
+                push_read_aloud_box(&mut output, text);
+                boxed = None;
+            }
+            Some(text) => {
+                text.push_str(&inline_embeds(line));
+                text.push('\n');
+            }
+            None if !in_code && line.trim() == READ_ALOUD_OPEN => {
+                output.push_str(&convert_markdown(&plain));
+                plain.clear();
+                boxed = Some(String::new());
+            }
+            None if !in_code && standalone_monster_embed(line).is_some() => {
+                output.push_str(&convert_markdown(&plain));
+                plain.clear();
+                if let Some(embed) = standalone_monster_embed(line) {
+                    push_monster_embed(&mut output, &embed, monsters.get(&embed));
+                }
+            }
+            None if !in_code && standalone_map_region(line).is_some() => {
+                output.push_str(&convert_markdown(&plain));
+                plain.clear();
+                if let Some(reference) = standalone_map_region(line) {
+                    push_map_region(&mut output, &reference, regions.get(&reference));
+                }
+            }
+            None => {
+                // Fences and embeds inside code blocks are left as written
+                if line.trim_start().starts_with("```") {
+                    in_code = !in_code;
+                }
+                if in_code {
+                    plain.push_str(line);
+                } else {
+                    plain.push_str(&inline_embeds(line));
+                }
+                plain.push('\n');
+            }
+        }
+    }
+
+    output.push_str(&convert_markdown(&plain));
+    // An unclosed fence runs to the end of the document
+    if let Some(text) = boxed {
+        push_read_aloud_box(&mut output, &text);
+    }
+
+    // Clean up extra newlines
+    let cleaned = output
+        .lines()
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+
+    cleaned + "\n"
+}
+
+/// Opening line of a boxed read-aloud block.
+const READ_ALOUD_OPEN: &str = ":::read-aloud";
+
+/// Closing line of a boxed read-aloud block.
+const READ_ALOUD_CLOSE: &str = ":::";
+
+/// Wrap converted read-aloud text in the shared callout box component.
+fn push_read_aloud_box(output: &mut String, markdown: &str) {
+    output.push_str("#read-aloud-box[\n");
+    output.push_str(convert_markdown(markdown).trim());
+    output.push_str("\n]\n\n");
+}
+
+/// A `{{monster: Name|SOURCE}}` embed found in a document.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MonsterEmbed {
+    /// Monster name as written
+    pub name: String,
+    /// Source code, when the embed names one
+    pub source: Option<String>,
+}
+
+/// Resolved monster data keyed by embed. An `Err` holds the reason the
+/// monster could not be shown, e.g. its source being disabled.
+pub type MonsterEmbeds = HashMap<MonsterEmbed, std::result::Result<Value, String>>;
+
+fn monster_embed_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\{\{\s*monster:\s*([^|}]+?)\s*(?:\|\s*([^}]*?)\s*)?\}\}")
+            .expect("valid monster embed regex")
+    })
+}
+
+fn monster_embed_from_captures(caps: &regex::Captures) -> MonsterEmbed {
+    MonsterEmbed {
+        name: caps[1].to_string(),
+        source: caps
+            .get(2)
+            .map(|m| m.as_str().to_string())
+            .filter(|s| !s.is_empty()),
+    }
+}
+
+/// Find the distinct monster embeds in a document, in order of appearance.
+/// Embeds inside fenced code blocks are skipped.
+pub fn find_monster_embeds(markdown: &str) -> Vec<MonsterEmbed> {
+    let mut embeds: Vec<MonsterEmbed> = Vec::new();
+    let mut in_code = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+        if in_code {
+            continue;
+        }
+        for caps in monster_embed_regex().captures_iter(line) {
+            let embed = monster_embed_from_captures(&caps);
+            if !embeds.contains(&embed) {
+                embeds.push(embed);
+            }
+        }
+    }
+
+    embeds
+}
+
+/// The embed on a line that holds nothing else.
+fn standalone_monster_embed(line: &str) -> Option<MonsterEmbed> {
+    let line = line.trim();
+    let caps = monster_embed_regex().captures(line)?;
+    let whole = caps.get(0)?;
+    (whole.start() == 0 && whole.end() == line.len()).then(|| monster_embed_from_captures(&caps))
+}
+
+/// Replace embeds within running text by the bold monster name.
+fn inline_monster_embeds(line: &str) -> String {
+    monster_embed_regex()
+        .replace_all(line, |caps: &regex::Captures| {
+            format!("**{}**", monster_embed_from_captures(caps).name)
+        })
+        .into_owned()
+}
+
+/// Render a standalone embed as a stat block, or as a fallback box when the
+/// monster was not resolved.
+fn push_monster_embed(
+    output: &mut String,
+    embed: &MonsterEmbed,
+    resolved: Option<&std::result::Result<Value, String>>,
+) {
+    match resolved {
+        Some(Ok(monster)) => output.push_str(&render_stat_block(monster)),
+        other => {
+            let reason = match other {
+                Some(Err(reason)) => reason.as_str(),
+                _ => "Stat block unavailable",
+            };
+            output.push_str(&format!(
+                "#info-box(title: \"{}\")[#\"{}\"]",
+                escape_for_typst_string(&embed.name),
+                escape_for_typst_string(reason)
+            ));
+        }
+    }
+    output.push_str("\n\n");
+}
+
+/// Replace embeds and region references within running text.
+fn inline_embeds(line: &str) -> String {
+    inline_map_regions(&inline_monster_embeds(line))
+}
+
+/// Resolved embeds a document can render.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentEmbeds {
+    pub monsters: MonsterEmbeds,
+    pub map_regions: MapRegionEmbeds,
+}
+
+/// A `{{map-region: Map Name | region}}` reference found in a document.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MapRegionRef {
+    /// Map name as written
+    pub map: String,
+    /// Region name as written
+    pub region: String,
+}
+
+/// A map region resolved for an encounter box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncounterRegion {
+    /// Map name as stored
+    pub map: String,
+    /// Region name as stored
+    pub region: String,
+    /// Bounding box width in grid squares
+    pub width: f64,
+    /// Bounding box height in grid squares
+    pub height: f64,
+    /// Names of the tokens placed inside the region
+    pub tokens: Vec<String>,
+    /// Traps placed inside the region
+    pub traps: Vec<EncounterTrap>,
+}
+
+/// A trap listed in an encounter box with its full text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EncounterTrap {
+    /// Trap name as shown on the map
+    pub name: String,
+    /// Save or check DC
+    pub dc: Option<i32>,
+    /// How the trap is triggered
+    pub trigger: Option<String>,
+    /// What the trap does
+    pub effect: Option<String>,
+    /// Flavor text and countermeasures
+    pub description: Option<String>,
+}
+
+/// Resolved regions keyed by reference. An `Err` holds the reason the
+/// region could not be shown, e.g. it having been deleted.
+pub type MapRegionEmbeds = HashMap<MapRegionRef, std::result::Result<EncounterRegion, String>>;
+
+fn map_region_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"\{\{\s*map-region:\s*([^|}]+?)\s*\|\s*([^}]+?)\s*\}\}")
+            .expect("valid map region regex")
+    })
+}
+
+fn map_region_from_captures(caps: &regex::Captures) -> MapRegionRef {
+    MapRegionRef {
+        map: caps[1].to_string(),
+        region: caps[2].to_string(),
+    }
+}
+
+/// Find the distinct map region references in a document, in order of
+/// appearance. References inside fenced code blocks are skipped.
+pub fn find_map_region_refs(markdown: &str) -> Vec<MapRegionRef> {
+    let mut refs: Vec<MapRegionRef> = Vec::new();
+    let mut in_code = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+        if in_code {
+            continue;
+        }
+        for caps in map_region_regex().captures_iter(line) {
+            let reference = map_region_from_captures(&caps);
+            if !refs.contains(&reference) {
+                refs.push(reference);
+            }
+        }
+    }
+
+    refs
+}
+
+/// The region reference on a line that holds nothing else.
+fn standalone_map_region(line: &str) -> Option<MapRegionRef> {
+    let line = line.trim();
+    let caps = map_region_regex().captures(line)?;
+    let whole = caps.get(0)?;
+    (whole.start() == 0 && whole.end() == line.len()).then(|| map_region_from_captures(&caps))
+}
+
+/// Replace region references within running text by the bold region name.
+fn inline_map_regions(line: &str) -> String {
+    map_region_regex()
+        .replace_all(line, |caps: &regex::Captures| {
+            let reference = map_region_from_captures(caps);
+            format!("**{} ({})**", reference.region, reference.map)
+        })
+        .into_owned()
+}
+
+/// Render a standalone region reference as an encounter box listing the
+/// region's tokens and traps, or as a fallback box when it was not resolved.
+fn push_map_region(
+    output: &mut String,
+    reference: &MapRegionRef,
+    resolved: Option<&std::result::Result<EncounterRegion, String>>,
+) {
+    match resolved {
+        Some(Ok(region)) => {
+            output.push_str(&format!(
+                "#info-box(title: \"Encounter: {}\")[\n#\"{} · {} × {} squares\"\n\n",
+                escape_for_typst_string(&region.region),
+                escape_for_typst_string(&region.map),
+                region.width,
+                region.height
+            ));
+            if region.tokens.is_empty() {
+                output.push_str("#emph[No tokens placed in this area]\n");
+            }
+            // Group repeated tokens, e.g. "Goblin ×3", keeping first-seen order
+            let mut counts: Vec<(&str, usize)> = Vec::new();
+            for token in &region.tokens {
+                match counts.iter_mut().find(|(name, _)| *name == token) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((token, 1)),
+                }
+            }
+            for (name, count) in counts {
+                let label = if count > 1 {
+                    format!("{} ×{}", name, count)
+                } else {
+                    name.to_string()
+                };
+                output.push_str(&format!("- #\"{}\"\n", escape_for_typst_string(&label)));
+            }
+            for trap in &region.traps {
+                push_encounter_trap(output, trap);
+            }
+            output.push(']');
+        }
+        other => {
+            let reason = match other {
+                Some(Err(reason)) => reason.as_str(),
+                _ => "Map region unavailable",
+            };
+            output.push_str(&format!(
+                "#info-box(title: \"{} ({})\")[#\"{}\"]",
+                escape_for_typst_string(&reference.region),
+                escape_for_typst_string(&reference.map),
+                escape_for_typst_string(reason)
+            ));
+        }
+    }
+    output.push_str("\n\n");
+}
+
+/// Render a trap inside an encounter box: name and DC, then the trigger,
+/// effect, and description paragraphs.
+fn push_encounter_trap(output: &mut String, trap: &EncounterTrap) {
+    let title = match trap.dc {
+        Some(dc) => format!("Trap: {} (DC {})", trap.name, dc),
+        None => format!("Trap: {}", trap.name),
+    };
+    output.push_str(&format!("\n*#\"{}\"*\n\n", escape_for_typst_string(&title)));
+
+    let sections = [
+        (Some("Trigger"), &trap.trigger),
+        (Some("Effect"), &trap.effect),
+        (None, &trap.description),
+    ];
+    for (label, text) in sections {
+        let Some(text) = text else { continue };
+        for (i, paragraph) in text.split("\n\n").enumerate() {
+            if let (0, Some(label)) = (i, label) {
+                output.push_str(&format!("*{}.* ", label));
+            }
+            output.push_str(&format!(
+                "#\"{}\"\n\n",
+                escape_for_typst_string(paragraph.trim())
+            ));
+        }
+    }
+}
+
+/// Convert a run of plain markdown (no read-aloud fences) to Typst markup.
+fn convert_markdown(markdown: &str) -> String {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_STRIKETHROUGH);
@@ -392,15 +825,7 @@ pub fn markdown_to_typst(markdown: &str) -> String {
         }
     }
 
-    // Clean up extra newlines
-    let cleaned = output
-        .lines()
-        .collect::<Vec<_>>()
-        .join("\n")
-        .trim()
-        .to_string();
-
-    cleaned + "\n"
+    output
 }
 
 /// Escape text for use inside a Typst string literal.
@@ -536,6 +961,183 @@ This is a test."#;
         assert!(typst.contains("#quote["));
     }
 
+    #[test]
+    fn test_markdown_read_aloud_box() {
+        let md = "Before\n\n:::read-aloud\nThe door creaks *open*.\n:::\n\nAfter";
+        let typst = markdown_to_typst(md);
+        assert!(typst.contains("#read-aloud-box[\n#\"The door creaks \"_#\"open\"_"));
+        assert!(typst.find("Before").unwrap() < typst.find("#read-aloud-box").unwrap());
+        assert!(typst.find("#read-aloud-box").unwrap() < typst.find("After").unwrap());
+        assert!(!typst.contains(":::"));
+    }
+
+    #[test]
+    fn test_markdown_read_aloud_unclosed_runs_to_end() {
+        let md = ":::read-aloud\n\nYou smell smoke.";
+        let typst = markdown_to_typst(md);
+        assert!(typst.starts_with("#read-aloud-box["));
+        assert!(typst.contains("You smell smoke."));
+        assert!(typst.trim_end().ends_with(']'));
+    }
+
+    #[test]
+    fn test_markdown_read_aloud_fence_in_code_block() {
+        let md = "```\n:::read-aloud\n:::\n```";
+        let typst = markdown_to_typst(md);
+        assert!(!typst.contains("#read-aloud-box"));
+        assert!(typst.contains(":::read-aloud"));
+    }
+
+    #[test]
+    fn test_find_monster_embeds() {
+        let md = "{{monster: Goblin|MM}}\nTwo {{ monster: Goblin | MM }} and {{monster: Owlbear}}\n```\n{{monster: Kraken}}\n```";
+        let embeds = find_monster_embeds(md);
+        assert_eq!(
+            embeds,
+            vec![
+                MonsterEmbed {
+                    name: "Goblin".to_string(),
+                    source: Some("MM".to_string()),
+                },
+                MonsterEmbed {
+                    name: "Owlbear".to_string(),
+                    source: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markdown_monster_embed_stat_block() {
+        let embed = MonsterEmbed {
+            name: "Goblin".to_string(),
+            source: Some("MM".to_string()),
+        };
+        let mut monsters = MonsterEmbeds::new();
+        monsters.insert(
+            embed,
+            Ok(serde_json::json!({ "name": "Goblin", "source": "MM", "cr": "1/4" })),
+        );
+        let typst =
+            markdown_to_typst_with_monsters("Intro\n\n{{monster: Goblin|MM}}\n\nOutro", &monsters);
+        assert!(typst.contains("CR 1/4"));
+        assert!(typst.find("Intro").unwrap() < typst.find("CR 1/4").unwrap());
+        assert!(typst.find("CR 1/4").unwrap() < typst.find("Outro").unwrap());
+        assert!(!typst.contains("{{"));
+    }
+
+    #[test]
+    fn test_markdown_monster_embed_fallback() {
+        let mut monsters = MonsterEmbeds::new();
+        monsters.insert(
+            MonsterEmbed {
+                name: "Goblin".to_string(),
+                source: Some("MM".to_string()),
+            },
+            Err("MM is not enabled".to_string()),
+        );
+        let typst = markdown_to_typst_with_monsters("{{monster: Goblin|MM}}", &monsters);
+        assert!(typst.contains("#info-box(title: \"Goblin\")"));
+        assert!(typst.contains("MM is not enabled"));
+
+        let unresolved = markdown_to_typst("{{monster: Owlbear}}");
+        assert!(unresolved.contains("#info-box(title: \"Owlbear\")"));
+    }
+
+    #[test]
+    fn test_markdown_monster_embed_inline() {
+        let typst = markdown_to_typst("Three {{monster: Goblin|MM}} guards.");
+        assert!(typst.contains("*#\"Goblin\"*"));
+        assert!(!typst.contains("#info-box"));
+    }
+
+    #[test]
+    fn test_find_map_region_refs() {
+        let md = "{{map-region: Crypt Level 1 | area-3}}\nSee {{ map-region: Crypt Level 1|area-3 }}\n```\n{{map-region: Tower | roof}}\n```";
+        assert_eq!(
+            find_map_region_refs(md),
+            vec![MapRegionRef {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_markdown_map_region_encounter_box() {
+        let mut embeds = DocumentEmbeds::default();
+        embeds.map_regions.insert(
+            MapRegionRef {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+            },
+            Ok(EncounterRegion {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+                width: 8.0,
+                height: 6.0,
+                tokens: vec![
+                    "Goblin".to_string(),
+                    "Goblin Boss".to_string(),
+                    "Goblin".to_string(),
+                ],
+                traps: Vec::new(),
+            }),
+        );
+        let typst = markdown_to_typst_with_embeds(
+            "Intro\n\n{{map-region: Crypt Level 1 | area-3}}\n\nOutro",
+            &embeds,
+        );
+        assert!(typst.contains("#info-box(title: \"Encounter: area-3\")"));
+        assert!(typst.contains("8 × 6 squares"));
+        assert!(typst.contains("- #\"Goblin ×2\""));
+        assert!(typst.contains("- #\"Goblin Boss\""));
+        assert!(typst.find("Intro").unwrap() < typst.find("Encounter").unwrap());
+        assert!(!typst.contains("{{"));
+    }
+
+    #[test]
+    fn test_markdown_map_region_lists_traps() {
+        let mut embeds = DocumentEmbeds::default();
+        embeds.map_regions.insert(
+            MapRegionRef {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+            },
+            Ok(EncounterRegion {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+                width: 8.0,
+                height: 6.0,
+                tokens: Vec::new(),
+                traps: vec![EncounterTrap {
+                    name: "Poison Darts".to_string(),
+                    dc: Some(15),
+                    trigger: Some("A creature steps on the plate.".to_string()),
+                    effect: Some("Darts fly.\n\nActive Elements. More darts.".to_string()),
+                    description: None,
+                }],
+            }),
+        );
+        let typst =
+            markdown_to_typst_with_embeds("{{map-region: Crypt Level 1 | area-3}}", &embeds);
+        assert!(typst.contains("*#\"Trap: Poison Darts (DC 15)\"*"));
+        assert!(typst.contains("*Trigger.* #\"A creature steps on the plate.\""));
+        assert!(typst.contains("*Effect.* #\"Darts fly.\""));
+        assert!(typst.contains("#\"Active Elements. More darts.\""));
+    }
+
+    #[test]
+    fn test_markdown_map_region_fallback_and_inline() {
+        let typst = markdown_to_typst("{{map-region: Crypt Level 1 | area-9}}");
+        assert!(typst.contains("#info-box(title: \"area-9 (Crypt Level 1)\")"));
+        assert!(typst.contains("Map region unavailable"));
+
+        let inline = markdown_to_typst("Fight in {{map-region: Crypt Level 1 | area-3}}.");
+        assert!(inline.contains("*#\"area-3 (Crypt Level 1)\"*"));
+        assert!(!inline.contains("#info-box"));
+    }
+
     #[test]
     fn test_markdown_horizontal_rule() {
         let md = "Before\n\n---\n\nAfter";
@@ -0,0 +1,107 @@
+//! Accessibility print profiles
+//!
+//! A profile adjusts the shared styles every template imports, so one setting
+//! changes text size, contrast, fonts, and layout across a whole export. The
+//! values reach Typst as `sys.inputs`, which `_shared/styles.typ` reads when
+//! it defines its size scale, colors, and font stacks.
+
+use serde::{Deserialize, Serialize};
+use typst::foundations::{Dict, IntoValue};
+
+/// Smallest and largest text scale a profile can ask for
+const FONT_SCALE_RANGE: (f64, f64) = (0.75, 2.5);
+
+/// Accessibility options for a printed document.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrintProfile {
+    /// Multiplier for every text size (1.0 is the standard scale)
+    pub font_scale: f64,
+    /// Black text and borders with no gray shading
+    pub high_contrast: bool,
+    /// Use a dyslexia-friendly font when one is installed
+    pub dyslexia_font: bool,
+    /// Single-column layouts without shaded boxes
+    pub simplified: bool,
+}
+
+impl Default for PrintProfile {
+    fn default() -> Self {
+        Self {
+            font_scale: 1.0,
+            high_contrast: false,
+            dyslexia_font: false,
+            simplified: false,
+        }
+    }
+}
+
+impl PrintProfile {
+    /// The `sys.inputs` entries the shared styles read
+    pub(crate) fn to_inputs(self) -> Dict {
+        let (min_scale, max_scale) = FONT_SCALE_RANGE;
+        let font_scale = if self.font_scale.is_finite() {
+            self.font_scale.clamp(min_scale, max_scale)
+        } else {
+            1.0
+        };
+
+        let mut inputs = Dict::new();
+        inputs.insert("font-scale".into(), font_scale.into_value());
+        inputs.insert("high-contrast".into(), self.high_contrast.into_value());
+        inputs.insert("dyslexia-font".into(), self.dyslexia_font.into_value());
+        inputs.insert("simplified".into(), self.simplified.into_value());
+        inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use typst::foundations::Value;
+
+    #[test]
+    fn test_inputs_carry_profile() {
+        let inputs = PrintProfile {
+            font_scale: 1.5,
+            high_contrast: true,
+            dyslexia_font: false,
+            simplified: true,
+        }
+        .to_inputs();
+
+        assert_eq!(inputs.get("font-scale").unwrap(), &Value::Float(1.5));
+        assert_eq!(inputs.get("high-contrast").unwrap(), &Value::Bool(true));
+        assert_eq!(inputs.get("dyslexia-font").unwrap(), &Value::Bool(false));
+        assert_eq!(inputs.get("simplified").unwrap(), &Value::Bool(true));
+    }
+
+    #[test]
+    fn test_font_scale_is_clamped() {
+        let huge = PrintProfile {
+            font_scale: 10.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            huge.to_inputs().get("font-scale").unwrap(),
+            &Value::Float(2.5)
+        );
+
+        let broken = PrintProfile {
+            font_scale: f64::NAN,
+            ..Default::default()
+        };
+        assert_eq!(
+            broken.to_inputs().get("font-scale").unwrap(),
+            &Value::Float(1.0)
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_use_standard_values() {
+        let profile: PrintProfile = serde_json::from_str(r#"{"high_contrast": true}"#).unwrap();
+        assert_eq!(profile.font_scale, 1.0);
+        assert!(profile.high_contrast);
+        assert!(!profile.simplified);
+    }
+}
@@ -0,0 +1,125 @@
+//! Chapter divider section
+//!
+//! A full page that opens a chapter of a campaign book (usually one module),
+//! with optional art and a short summary of what the chapter covers.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::builder::{escape_typst_string, RenderContext, Renderable};
+use crate::error::Result;
+
+/// Counter for unique divider art filenames
+static DIVIDER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Chapter divider page - title, optional art, and a summary blurb
+pub struct ChapterDivider {
+    /// Chapter title (shown in the table of contents)
+    title: String,
+    /// Short summary printed under the title
+    summary: Option<String>,
+    /// Art image (PNG or JPEG)
+    art: Option<Vec<u8>>,
+}
+
+impl ChapterDivider {
+    /// Create a divider with just a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            summary: None,
+            art: None,
+        }
+    }
+
+    /// Set the summary blurb (blank summaries are ignored)
+    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
+        let summary = summary.into();
+        if !summary.trim().is_empty() {
+            self.summary = Some(summary);
+        }
+        self
+    }
+
+    /// Set the divider art
+    pub fn with_art(mut self, image: Vec<u8>) -> Self {
+        self.art = Some(image);
+        self
+    }
+}
+
+impl Renderable for ChapterDivider {
+    fn to_typst(&self, ctx: &RenderContext) -> Result<String> {
+        let mut typst = String::from("#align(center + horizon)[\n");
+
+        let art_path = self.art.as_ref().and_then(|art| {
+            let counter = DIVIDER_COUNTER.fetch_add(1, Ordering::SeqCst);
+            ctx.register_image(&format!("divider_{}", counter), art)
+        });
+        if let Some(path) = art_path {
+            typst.push_str(&format!(
+                "  #image(\"{}\", width: 100%, height: 5in, fit: \"contain\")\n",
+                path
+            ));
+            typst.push_str("  #v(spacing.xxl)\n");
+        }
+
+        // Outlined heading so the chapter appears in the table of contents
+        typst.push_str(&format!(
+            "  #heading(level: 1, outlined: true)[#title-text[{}]]\n",
+            escape_typst_string(&self.title)
+        ));
+
+        if let Some(ref summary) = self.summary {
+            typst.push_str("  #v(spacing.lg)\n");
+            typst.push_str("  #block(width: 75%)[\n");
+            typst.push_str("    #set par(justify: false)\n");
+            for paragraph in summary.split("\n\n").map(str::trim) {
+                if paragraph.is_empty() {
+                    continue;
+                }
+                typst.push_str(&format!(
+                    "    #subtitle-text[{}]\n\n",
+                    escape_typst_string(paragraph)
+                ));
+            }
+            typst.push_str("  ]\n");
+        }
+
+        typst.push_str("]\n");
+        Ok(typst)
+    }
+
+    fn is_chapter_divider(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_divider_renders_title_and_summary() {
+        let divider = ChapterDivider::new("The Sunless Citadel")
+            .with_summary("A fortress swallowed by the earth.\n\nBeware [the] dragon cult.");
+        let typst = divider.to_typst(&RenderContext::default()).unwrap();
+
+        assert!(typst.contains("outlined: true"));
+        assert!(typst.contains("The Sunless Citadel"));
+        assert!(typst.contains("A fortress swallowed by the earth."));
+        assert!(typst.contains("Beware \\[the\\] dragon cult."));
+        assert!(!typst.contains("#image"));
+        assert!(divider.is_chapter_divider());
+    }
+
+    #[test]
+    fn test_divider_skips_blank_summary_and_unknown_art() {
+        let divider = ChapterDivider::new("Interlude")
+            .with_summary("   ")
+            .with_art(b"not an image".to_vec());
+        let typst = divider.to_typst(&RenderContext::default()).unwrap();
+
+        assert!(!typst.contains("subtitle-text"));
+        assert!(!typst.contains("#image"));
+    }
+}
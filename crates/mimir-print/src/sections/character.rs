@@ -3,10 +3,16 @@
 //! Generates character sheets from the simplified mimir Character model.
 //! Shows basic info, ability scores, classes, and roleplay elements.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use serde::{Deserialize, Serialize};
 
 use crate::builder::{escape_typst_string, RenderContext, Renderable};
 use crate::error::Result;
+use crate::forms::{form_field, FormFieldKind};
+
+/// Counter for unique portrait image filenames
+static PORTRAIT_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 /// Character data for PDF rendering
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +75,14 @@ pub struct CharacterData {
     pub spell_save_dc: Option<i32>,
     pub spell_attack_bonus: Option<i32>,
     pub spell_slots: Vec<i32>, // 9 entries for spell levels 1-9
+
+    // Campaign custom fields (house-ruled stats), in sheet order
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldEntry>,
+
+    // Portrait image bytes (PNG or JPEG), shown beside the name
+    #[serde(skip)]
+    pub portrait: Option<Vec<u8>>,
 }
 
 /// Class level information
@@ -105,6 +119,13 @@ pub struct InventoryItem {
     pub finesse: bool,
 }
 
+/// A campaign custom field value for display (e.g., Sanity: 45)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomFieldEntry {
+    pub label: String,
+    pub value: String,
+}
+
 /// Proficiencies grouped by type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Proficiencies {
@@ -126,12 +147,25 @@ pub struct ProficiencyEntry {
 /// Character sheet section
 pub struct CharacterSection {
     character: CharacterData,
+    fillable: bool,
 }
 
 impl CharacterSection {
     /// Create a new character section
     pub fn new(character: CharacterData) -> Self {
-        Self { character }
+        Self {
+            character,
+            fillable: false,
+        }
+    }
+
+    /// Add form fields for HP, death saves, inspiration, and spell slots
+    ///
+    /// The fields only become fillable when the document is rendered with
+    /// `DocumentBuilder::to_fillable_pdf`.
+    pub fn with_fillable(mut self, fillable: bool) -> Self {
+        self.fillable = fillable;
+        self
     }
 
     /// Calculate total character level
@@ -193,10 +227,26 @@ impl CharacterSection {
             + (self.character.pp as f64 * 10.0)
     }
 
+    /// Register the portrait with the document and return its Typst image call
+    fn portrait_image(&self, ctx: &RenderContext) -> Option<String> {
+        let bytes = self.character.portrait.as_ref()?;
+        let ext = match image::guess_format(bytes) {
+            Ok(image::ImageFormat::Jpeg) => "jpg",
+            Ok(image::ImageFormat::Png) => "png",
+            _ => return None,
+        };
+        let counter = PORTRAIT_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let filename = format!("portrait_{}.{}", counter, ext);
+        let virtual_path = ctx.virtual_files.register(&filename, bytes.clone());
+        Some(format!(
+            r#"box(width: 0.9in, height: 0.9in, radius: 2pt, clip: true, stroke: 0.5pt + colors.border)[#image("{}", width: 100%, height: 100%, fit: "cover")]"#,
+            virtual_path
+        ))
+    }
 }
 
 impl Renderable for CharacterSection {
-    fn to_typst(&self, _ctx: &RenderContext) -> Result<String> {
+    fn to_typst(&self, ctx: &RenderContext) -> Result<String> {
         let mut typst = String::new();
 
         let char = &self.character;
@@ -350,10 +400,22 @@ impl Renderable for CharacterSection {
         let bg = char.background_name.as_deref().unwrap_or("");
         let player_line = char.player_name.as_deref().unwrap_or("");
 
+        // With a portrait, the header text sits in a grid beside it
+        let (portrait_open, portrait_close) = match self.portrait_image(ctx) {
+            Some(image) => (
+                format!(
+                    "#grid(columns: (auto, 1fr), column-gutter: spacing.sm, align: horizon,\n  {},\n  [\n",
+                    image
+                ),
+                "  ]\n)\n",
+            ),
+            None => (String::new(), ""),
+        };
+
         typst.push_str(&format!(
             r#"// Character: {name}
 #block(width: 100%, inset: (x: spacing.sm, y: spacing.sm), stroke: (bottom: 2pt + colors.accent))[
-  #grid(columns: (1fr, auto), column-gutter: spacing.sm,
+{portrait_open}  #grid(columns: (1fr, auto), column-gutter: spacing.sm,
     text(size: sizes.title, weight: "bold", font: font-heading, tracking: -0.5pt)[{name_upper}],
     {npc_badge}
   )
@@ -361,11 +423,13 @@ impl Renderable for CharacterSection {
   #text(size: sizes.base)[{race} · {classes} · Level {level}]
   #h(spacing.md)
   #text(size: sizes.sm, fill: colors.text-secondary)[{bg}{player_sep}{player}]
-]
+{portrait_close}]
 
 #v(spacing.sm)
 
 "#,
+            portrait_open = portrait_open,
+            portrait_close = portrait_close,
             name = escape_typst_string(&char.name),
             name_upper = escape_typst_string(&char.name.to_uppercase()),
             npc_badge = if char.is_npc {
@@ -410,6 +474,50 @@ impl Renderable for CharacterSection {
             10 + perc_bonus
         };
 
+        // Death saves and the between-session trackers are form fields on a
+        // fillable sheet and blank boxes to pencil in otherwise
+        let (death_saves, play_fields) = if self.fillable {
+            let checkboxes = |prefix: &str| {
+                (1..=3)
+                    .map(|n| {
+                        let name = format!("{}_{}", prefix, n);
+                        form_field(&name, FormFieldKind::Checkbox, 9.0, 9.0)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let death_saves = format!(
+                r#"        #grid(columns: (auto, auto, auto, auto), column-gutter: 3pt, row-gutter: 2pt, align: horizon,
+          text(size: sizes.xs)[S], {successes},
+          text(size: sizes.xs)[F], {failures},
+        )
+"#,
+                successes = checkboxes("death_save_success"),
+                failures = checkboxes("death_save_failure"),
+            );
+            let play_fields = format!(
+                r#"    #v(spacing.sm)
+    #grid(columns: (auto, 1fr, auto, 1fr, auto, auto), column-gutter: spacing.sm, align: horizon,
+      label-text("CURRENT HP"), {current},
+      label-text("TEMP HP"), {temp},
+      label-text("INSPIRATION"), {inspiration},
+    )
+"#,
+                current = form_field("hp_current", FormFieldKind::Text, 60.0, 16.0),
+                temp = form_field("hp_temp", FormFieldKind::Text, 60.0, 16.0),
+                inspiration = form_field("inspiration", FormFieldKind::Checkbox, 12.0, 12.0),
+            );
+            (death_saves, play_fields)
+        } else {
+            let death_saves = r#"        #grid(columns: (1fr, 1fr, 1fr), column-gutter: 2pt,
+          box(width: 100%, stroke: 0.5pt + colors.border-light, radius: 2pt, inset: spacing.xs)[#align(center)[#text(size: sizes.xs, fill: luma(200))[S]]],
+          box(width: 100%, stroke: 0.5pt + colors.border-light, radius: 2pt, inset: spacing.xs)[#align(center)[#text(size: sizes.xs, fill: luma(200))[S]]],
+          box(width: 100%, stroke: 0.5pt + colors.border-light, radius: 2pt, inset: spacing.xs)[#align(center)[#text(size: sizes.xs, fill: luma(200))[F]]],
+        )
+"#;
+            (death_saves.to_string(), String::new())
+        };
+
         let combat_content = format!(
             r#"    #grid(columns: (1fr, 1fr, 1fr, 1fr), column-gutter: spacing.sm, row-gutter: spacing.sm,
       [#align(center)[#text(size: sizes.xxl, weight: "bold")[{ac}] #linebreak() #label-text("ARMOR CLASS")]],
@@ -420,15 +528,10 @@ impl Renderable for CharacterSection {
       [#align(center)[#text(size: sizes.lg, weight: "bold")[{hit_die}] #linebreak() #label-text("HIT DICE")]],
       [#align(center)[#text(size: sizes.lg, weight: "bold")[{pp}] #linebreak() #label-text("PASSIVE")]],
       [#align(center)[
-        #grid(columns: (1fr, 1fr, 1fr), column-gutter: 2pt,
-          box(width: 100%, stroke: 0.5pt + colors.border-light, radius: 2pt, inset: spacing.xs)[#align(center)[#text(size: sizes.xs, fill: luma(200))[S]]],
-          box(width: 100%, stroke: 0.5pt + colors.border-light, radius: 2pt, inset: spacing.xs)[#align(center)[#text(size: sizes.xs, fill: luma(200))[S]]],
-          box(width: 100%, stroke: 0.5pt + colors.border-light, radius: 2pt, inset: spacing.xs)[#align(center)[#text(size: sizes.xs, fill: luma(200))[F]]],
-        )
-        #label-text("DEATH SAVES")
+{death_saves}        #label-text("DEATH SAVES")
       ]],
     )
-"#,
+{play_fields}"#,
             ac = char.ac,
             hp = char.hit_points_max,
             init = Self::modifier_str(char.dexterity),
@@ -436,6 +539,8 @@ impl Renderable for CharacterSection {
             prof = prof,
             hit_die = escape_typst_string(&char.hit_die),
             pp = passive_perception,
+            death_saves = death_saves,
+            play_fields = play_fields,
         );
         typst.push_str(&primary_box("COMBAT", &combat_content, true));
         typst.push_str("#v(spacing.sm)\n\n");
@@ -631,7 +736,13 @@ impl Renderable for CharacterSection {
             for row in 0..max_slots {
                 for i in 0..9 {
                     let count = char.spell_slots.get(i).copied().unwrap_or(0);
-                    if row < count {
+                    if row < count && self.fillable {
+                        let name = format!("spell_slot_{}_{}", i + 1, row + 1);
+                        spell_content.push_str(&format!(
+                            "      align(center, block(width: 100%, inset: spacing.xs)[#{}]),\n",
+                            form_field(&name, FormFieldKind::Checkbox, 9.0, 9.0)
+                        ));
+                    } else if row < count {
                         spell_content.push_str(
                             "      align(center, block(width: 100%, inset: spacing.xs)[#text(size: sizes.sm)[○]]),\n",
                         );
@@ -696,7 +807,7 @@ impl Renderable for CharacterSection {
         typst.push_str("#v(spacing.sm)\n\n");
 
         // --- Two-column: Spellcasting+NPC (left) | Currency+Inventory (right) ---
-        typst.push_str("#grid(\n  columns: (1fr, 1fr),\n  column-gutter: spacing.md,\n\n");
+        typst.push_str("#grid(\n  columns: layout-columns(2),\n  column-gutter: spacing.md,\n\n");
 
         // ===== LEFT COLUMN =====
         typst.push_str("  // Left column\n  [\n");
@@ -726,6 +837,22 @@ impl Renderable for CharacterSection {
             typst.push_str("#v(spacing.sm)\n\n");
         }
 
+        // Custom fields (campaign house rules)
+        if !char.custom_fields.is_empty() {
+            let mut custom_content = String::new();
+            custom_content.push_str("    #grid(columns: (1fr, auto), column-gutter: spacing.md, row-gutter: spacing.xs,\n");
+            for field in &char.custom_fields {
+                custom_content.push_str(&format!(
+                    "      text(size: sizes.xs, weight: \"bold\", tracking: 0.5pt, fill: colors.text-secondary)[{}], text(size: sizes.sm)[{}],\n",
+                    escape_typst_string(&field.label.to_uppercase()),
+                    escape_typst_string(&field.value)
+                ));
+            }
+            custom_content.push_str("    )\n");
+            typst.push_str(&secondary_box("CUSTOM FIELDS", &custom_content, true));
+            typst.push_str("#v(spacing.sm)\n\n");
+        }
+
         // Notes (fills remaining left column)
         let notes_content = "    #v(1fr)\n";
         typst.push_str(&secondary_box("NOTES", notes_content, true));
@@ -878,6 +1005,8 @@ mod tests {
             spell_save_dc: None,
             spell_attack_bonus: None,
             spell_slots: vec![0; 9],
+            custom_fields: vec![],
+            portrait: None,
         }
     }
 
@@ -959,6 +1088,8 @@ mod tests {
             spell_save_dc: Some(17),
             spell_attack_bonus: Some(9),
             spell_slots: vec![4, 3, 3, 3, 1, 0, 0, 0, 0],
+            custom_fields: vec![],
+            portrait: None,
         }
     }
 
@@ -1018,6 +1149,67 @@ mod tests {
         assert!(typst.contains("pagebreak()"));
     }
 
+    #[test]
+    fn test_fillable_sheet_renders_form_fields() {
+        let char = test_spellcaster();
+        let slots_total: i32 = char.spell_slots.iter().sum();
+        let section = CharacterSection::new(char).with_fillable(true);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("\"hp_current\""));
+        assert!(typst.contains("\"hp_temp\""));
+        assert!(typst.contains("\"inspiration\""));
+        assert!(typst.contains("\"death_save_success_3\""));
+        assert!(typst.contains("\"death_save_failure_3\""));
+        assert!(typst.contains("\"spell_slot_1_1\""));
+        assert_eq!(
+            typst.matches("mimir_form_field: \"spell_slot_").count() as i32,
+            slots_total
+        );
+    }
+
+    #[test]
+    fn test_static_sheet_has_no_form_fields() {
+        let section = CharacterSection::new(test_spellcaster());
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(!typst.contains("mimir_form_field"));
+        assert!(typst.contains("DEATH SAVES"));
+    }
+
+    #[test]
+    fn test_custom_fields_rendered_when_present() {
+        let typst = CharacterSection::new(test_character())
+            .to_typst(&RenderContext::default())
+            .unwrap();
+        assert!(!typst.contains("CUSTOM FIELDS"));
+
+        let mut char = test_character();
+        char.custom_fields = vec![CustomFieldEntry {
+            label: "Sanity".to_string(),
+            value: "45".to_string(),
+        }];
+        let typst = CharacterSection::new(char)
+            .to_typst(&RenderContext::default())
+            .unwrap();
+        assert!(typst.contains("CUSTOM FIELDS"));
+        assert!(typst.contains("[SANITY]"));
+        assert!(typst.contains("[45]"));
+    }
+
+    #[test]
+    fn test_portrait_rendered_when_present() {
+        let typst = CharacterSection::new(test_character())
+            .to_typst(&RenderContext::default())
+            .unwrap();
+        assert!(!typst.contains("#image("));
+
+        let mut char = test_character();
+        char.portrait = Some(b"\x89PNG\r\n\x1a\n".to_vec());
+        let ctx = RenderContext::default();
+        let typst = CharacterSection::new(char).to_typst(&ctx).unwrap();
+        assert!(typst.contains("#image(\"/_virtual/portrait_"));
+        assert!(typst.contains("[ARAGORN]"));
+    }
+
     #[test]
     fn test_non_caster_no_spell_section() {
         let char = test_character();
@@ -2,10 +2,14 @@
 //!
 //! Generates half-page (4" x 5.5") character cards for combat reference.
 //! Works for both PCs and NPCs - shows AC, HP, speed, attacks, saves, skills, etc.
+//!
+//! With tracking enabled, each character also gets a tracking card with
+//! checkbox tracks for hit dice, spell slots, class resources, and ammunition,
+//! sized for laminating and marking with a dry-erase pen.
 
 use crate::builder::{escape_typst_string, RenderContext, Renderable};
 use crate::error::Result;
-use crate::sections::character::CharacterData;
+use crate::sections::character::{CharacterData, InventoryItem};
 
 /// Character battle cards - half-page combat reference cards (2x2 layout)
 /// Works for both player characters and NPCs
@@ -14,14 +18,23 @@ pub struct CharacterBattleCardSection {
     characters: Vec<CharacterData>,
     /// Show cut lines between cards
     show_cut_lines: bool,
+    /// Add a tracking card after each character's battle card
+    show_tracking: bool,
 }
 
+/// Size of a tracking checkbox, large enough to mark with a dry-erase pen
+const TRACK_BOX: &str = "10pt";
+
+/// Longest track drawn as individual boxes; larger pools get a write-in box
+const MAX_TRACK_BOXES: i32 = 20;
+
 impl CharacterBattleCardSection {
     /// Create a new character battle cards section
     pub fn new(characters: Vec<CharacterData>) -> Self {
         Self {
             characters,
             show_cut_lines: true,
+            show_tracking: false,
         }
     }
 
@@ -36,6 +49,12 @@ impl CharacterBattleCardSection {
         self
     }
 
+    /// Set whether to add a tracking card for each character
+    pub fn with_tracking(mut self, show: bool) -> Self {
+        self.show_tracking = show;
+        self
+    }
+
     /// Calculate ability modifier
     fn modifier(score: i32) -> i32 {
         (score - 10).div_euclid(2)
@@ -287,6 +306,184 @@ impl CharacterBattleCardSection {
         )
     }
 
+    /// Render a tracking card: write-in HP plus checkbox tracks for hit dice,
+    /// spell slots, class resources, and ammunition
+    fn render_tracking_card(character: &CharacterData) -> String {
+        let name = escape_typst_string(&character.name);
+        let accent_color = if character.is_npc {
+            "rgb(\"#2563eb\")"
+        } else {
+            "rgb(\"#16a34a\")"
+        };
+
+        let mut rows: Vec<(String, String)> = Vec::new();
+
+        for (count, die) in parse_hit_dice(&character.hit_die) {
+            rows.push((format!("Hit Dice ({})", die), track(count)));
+        }
+
+        for (i, &slots) in character.spell_slots.iter().enumerate() {
+            if slots > 0 {
+                let label = format!("{} Level Slots", ordinal(i as i32 + 1));
+                rows.push((label, track(slots)));
+            }
+        }
+
+        for (resource, uses) in Self::class_resources(character) {
+            rows.push((resource, track(uses)));
+        }
+
+        for item in character.inventory.iter().filter(|i| is_ammunition(i)) {
+            rows.push((escape_typst_string(&item.name), track(item.quantity)));
+        }
+
+        let tracks = if rows.is_empty() {
+            "#text(size: 7pt, fill: colors.text-secondary)[Nothing to track]".to_string()
+        } else {
+            let cells = rows
+                .iter()
+                .map(|(label, boxes)| format!("[*{}*], [{}]", label, boxes))
+                .collect::<Vec<_>>()
+                .join(",\n      ");
+            format!(
+                r#"#grid(
+      columns: (auto, 1fr),
+      column-gutter: 6pt,
+      row-gutter: 6pt,
+      align: left + horizon,
+      {}
+    )"#,
+                cells
+            )
+        };
+
+        format!(
+            r##"box(
+  width: 3.875in,
+  height: 5.125in,
+  stroke: (
+    top: 3pt + {accent_color},
+    bottom: 3pt + {accent_color},
+    left: 0.5pt + colors.border,
+    right: 0.5pt + colors.border,
+  ),
+  radius: 3pt,
+  clip: true,
+  inset: 0pt,
+)[
+  // Header
+  #block(
+    width: 100%,
+    inset: (x: 6pt, y: 4pt),
+    stroke: (bottom: 0.5pt + colors.border-light),
+  )[
+    #text(size: 10pt, weight: "bold")[{name}]
+    #h(1fr)
+    #text(size: 7pt, style: "italic", fill: {accent_color})[Tracking]
+  ]
+
+  // Hit points write-in
+  #block(
+    width: 100%,
+    inset: 6pt,
+    stroke: (bottom: 0.5pt + colors.border-light),
+  )[
+    #set text(size: 7pt)
+    #grid(
+      columns: (1fr, 1fr, 1fr),
+      column-gutter: 6pt,
+      [*HP Max* {hp}],
+      [*Current* #box(width: 100%, height: 18pt, stroke: 0.75pt + black, radius: 2pt)],
+      [*Temp* #box(width: 100%, height: 18pt, stroke: 0.75pt + black, radius: 2pt)],
+    )
+  ]
+
+  // Tracks
+  #block(
+    width: 100%,
+    inset: 6pt,
+  )[
+    #set text(size: 7pt)
+    {tracks}
+  ]
+]"##,
+            accent_color = accent_color,
+            name = name,
+            hp = character.hit_points_max,
+            tracks = tracks,
+        )
+    }
+
+    /// Limited-use class features and their uses per rest
+    fn class_resources(character: &CharacterData) -> Vec<(String, i32)> {
+        let cha_mod = Self::modifier(character.charisma);
+        let mut resources = Vec::new();
+
+        for class in &character.classes {
+            let level = class.level;
+            match class.class_name.to_lowercase().as_str() {
+                "barbarian" if level < 20 => {
+                    let rages = match level {
+                        1..=2 => 2,
+                        3..=5 => 3,
+                        6..=11 => 4,
+                        12..=16 => 5,
+                        _ => 6,
+                    };
+                    resources.push(("Rage".to_string(), rages));
+                }
+                "bard" => {
+                    resources.push(("Bardic Inspiration".to_string(), cha_mod.max(1)));
+                }
+                "cleric" if level >= 2 => {
+                    let uses = match level {
+                        2..=5 => 1,
+                        6..=17 => 2,
+                        _ => 3,
+                    };
+                    resources.push(("Channel Divinity".to_string(), uses));
+                }
+                "druid" if level >= 2 => {
+                    resources.push(("Wild Shape".to_string(), 2));
+                }
+                "fighter" => {
+                    resources.push(("Second Wind".to_string(), 1));
+                    if level >= 2 {
+                        let surges = if level >= 17 { 2 } else { 1 };
+                        resources.push(("Action Surge".to_string(), surges));
+                    }
+                }
+                "monk" if level >= 2 => {
+                    resources.push(("Ki Points".to_string(), level));
+                }
+                "paladin" => {
+                    resources.push(("Lay on Hands (HP)".to_string(), level * 5));
+                    if level >= 3 {
+                        resources.push(("Channel Divinity".to_string(), 1));
+                    }
+                }
+                "sorcerer" if level >= 2 => {
+                    resources.push(("Sorcery Points".to_string(), level));
+                }
+                "warlock" => {
+                    let slots = match level {
+                        1 => 1,
+                        2..=10 => 2,
+                        11..=16 => 3,
+                        _ => 4,
+                    };
+                    resources.push(("Pact Slots".to_string(), slots));
+                }
+                "wizard" => {
+                    resources.push(("Arcane Recovery".to_string(), 1));
+                }
+                _ => {}
+            }
+        }
+
+        resources
+    }
+
     /// Get attacks from equipped weapons
     fn get_attacks(character: &CharacterData) -> String {
         let prof_bonus = Self::prof_bonus(character);
@@ -355,17 +552,26 @@ impl Renderable for CharacterBattleCardSection {
             return Ok(String::new());
         }
 
+        // Tracking cards sit right after their character's battle card
+        let mut cards = Vec::new();
+        for character in &self.characters {
+            cards.push(Self::render_card(character));
+            if self.show_tracking {
+                cards.push(Self::render_tracking_card(character));
+            }
+        }
+
         let mut typst = String::new();
         let cards_per_page = 4; // 2x2 grid of half-page cards
-        let total_pages = (self.characters.len() + cards_per_page - 1) / cards_per_page;
+        let total_pages = (cards.len() + cards_per_page - 1) / cards_per_page;
 
         // Set page margins for half-page cards (centered with gutters for cutting)
         typst.push_str("#set page(paper: \"us-letter\", margin: 0.25in)\n");
 
         for page_num in 0..total_pages {
             let start_idx = page_num * cards_per_page;
-            let end_idx = std::cmp::min(start_idx + cards_per_page, self.characters.len());
-            let page_chars = &self.characters[start_idx..end_idx];
+            let end_idx = std::cmp::min(start_idx + cards_per_page, cards.len());
+            let page_cards = &cards[start_idx..end_idx];
 
             if page_num > 0 {
                 typst.push_str("\n#pagebreak()\n");
@@ -379,24 +585,24 @@ impl Renderable for CharacterBattleCardSection {
             typst.push_str("    row-gutter: 0.25in,\n\n");
 
             // Render each card
-            for (i, character) in page_chars.iter().enumerate() {
+            for (i, card) in page_cards.iter().enumerate() {
                 typst.push_str("    ");
-                typst.push_str(&Self::render_card(character));
-                if i < page_chars.len() - 1 || page_chars.len() < 4 {
+                typst.push_str(card);
+                if i < page_cards.len() - 1 || page_cards.len() < 4 {
                     typst.push(',');
                 }
                 typst.push('\n');
             }
 
             // Fill remaining slots with empty boxes
-            for _ in page_chars.len()..4 {
+            for _ in page_cards.len()..4 {
                 typst.push_str("    box(width: 3.875in, height: 5.125in),\n");
             }
 
             typst.push_str(")\n");
 
             // Cut lines indicator
-            if self.show_cut_lines && !page_chars.is_empty() {
+            if self.show_cut_lines && !page_cards.is_empty() {
                 typst.push_str("#place(\n  bottom + center,\n  dy: 0.1in,\n");
                 typst.push_str(
                     "  text(size: 6pt, fill: colors.text-secondary)[Cut along card borders]\n)\n",
@@ -422,6 +628,55 @@ impl Renderable for CharacterBattleCardSection {
     }
 }
 
+/// Render a row of tracking checkboxes, or a write-in box for large pools
+fn track(count: i32) -> String {
+    if count > MAX_TRACK_BOXES {
+        return format!(
+            "#box(width: 36pt, height: 14pt, stroke: 0.75pt + black, radius: 2pt) / {}",
+            count
+        );
+    }
+    let tick = format!(
+        "#box(width: {size}, height: {size}, stroke: 0.75pt + black, radius: 1pt)",
+        size = TRACK_BOX
+    );
+    vec![tick; count.max(0) as usize].join("#h(2pt)")
+}
+
+/// Split a hit dice string like "5d10 + 3d8" into (count, die) pairs
+fn parse_hit_dice(hit_die: &str) -> Vec<(i32, String)> {
+    hit_die
+        .split('+')
+        .filter_map(|term| {
+            let (count, sides) = term.trim().split_once('d')?;
+            let count = count.trim().parse::<i32>().ok()?;
+            let sides = sides.trim().parse::<i32>().ok()?;
+            Some((count, format!("d{}", sides)))
+        })
+        .collect()
+}
+
+/// Whether an inventory item is ammunition worth tracking
+fn is_ammunition(item: &InventoryItem) -> bool {
+    let is_ammo_type = matches!(item.item_type.as_deref(), Some("A") | Some("AF"));
+    let lower = item.name.to_lowercase();
+    let is_ammo_name = ["arrow", "bolt", "bullet", "needle"]
+        .iter()
+        .any(|ammo| lower.contains(ammo));
+    item.quantity > 0 && (is_ammo_type || is_ammo_name)
+}
+
+/// "1st", "2nd", "3rd", "4th", ...
+fn ordinal(n: i32) -> String {
+    let suffix = match n {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
 /// Render HP tracker based on HP value
 /// - 1-20 HP: Individual boxes with styled 10th box
 /// - 21-100 HP: 5s and 1s grouping
@@ -594,6 +849,8 @@ mod tests {
             spell_save_dc: None,
             spell_attack_bonus: None,
             spell_slots: vec![0; 9],
+            custom_fields: vec![],
+            portrait: None,
         }
     }
 
@@ -726,4 +983,55 @@ mod tests {
         let typst = section.to_typst(&ctx).unwrap();
         assert!(typst.contains("NPC"));
     }
+    #[test]
+    fn test_tracking_cards_follow_battle_cards() {
+        let section = CharacterBattleCardSection::from_single(test_character()).with_tracking(true);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+
+        assert!(typst.contains("Tracking"));
+        assert!(typst.contains("Hit Dice (d10)"));
+        assert!(typst.contains("Second Wind"));
+        assert!(typst.contains("Action Surge"));
+        assert!(!typst.contains("Level Slots"));
+
+        let untracked = CharacterBattleCardSection::from_single(test_character());
+        let typst = untracked.to_typst(&RenderContext::default()).unwrap();
+        assert!(!typst.contains("Hit Dice ("));
+    }
+
+    #[test]
+    fn test_tracking_card_spell_slots_and_ammo() {
+        let mut char = test_character();
+        char.spell_slots = vec![4, 2, 0, 0, 0, 0, 0, 0, 0];
+        char.inventory.push(InventoryItem {
+            name: "Arrows".to_string(),
+            quantity: 40,
+            item_type: Some("A".to_string()),
+            ..Default::default()
+        });
+        let section = CharacterBattleCardSection::from_single(char).with_tracking(true);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+
+        assert!(typst.contains("1st Level Slots"));
+        assert!(typst.contains("2nd Level Slots"));
+        assert!(!typst.contains("3rd Level Slots"));
+        // 40 arrows is too many boxes, so it gets a write-in
+        assert!(typst.contains("[*Arrows*], [#box(width: 36pt"));
+    }
+
+    #[test]
+    fn test_parse_hit_dice() {
+        assert_eq!(
+            parse_hit_dice("5d10 + 3d8"),
+            vec![(5, "d10".to_string()), (3, "d8".to_string())]
+        );
+        assert!(parse_hit_dice("").is_empty());
+    }
+
+    #[test]
+    fn test_track_boxes() {
+        assert_eq!(track(3).matches("#box").count(), 3);
+        assert_eq!(track(0), "");
+        assert!(track(MAX_TRACK_BOXES + 1).contains("/ 21"));
+    }
 }
@@ -0,0 +1,141 @@
+//! Condition marker sheet section
+//!
+//! Renders small cut-out markers, one per condition, styled like the monster
+//! cards. Each marker has a blank circle for the number of the creature card
+//! it is clipped to, so one sheet serves a whole initiative deck.
+
+use super::card_utils::escape_typst;
+use crate::builder::{RenderContext, Renderable};
+use crate::error::Result;
+
+/// Conditions that get markers, in the order they are printed
+pub const CONDITIONS: [&str; 17] = [
+    "Blinded",
+    "Charmed",
+    "Deafened",
+    "Frightened",
+    "Grappled",
+    "Incapacitated",
+    "Invisible",
+    "Paralyzed",
+    "Petrified",
+    "Poisoned",
+    "Prone",
+    "Restrained",
+    "Stunned",
+    "Unconscious",
+    "Exhaustion",
+    "Concentrating",
+    "Bloodied",
+];
+
+/// Markers per row of the sheet
+const MARKERS_PER_ROW: usize = 5;
+
+/// Condition marker sheet - cut-out markers for every condition
+pub struct ConditionMarkerSection {
+    /// Heading printed above the markers (usually the encounter name)
+    title: String,
+    /// Markers printed for each condition
+    copies: usize,
+}
+
+impl ConditionMarkerSection {
+    /// Create a marker sheet with one marker per condition
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            copies: 1,
+        }
+    }
+
+    /// Set how many markers to print for each condition
+    pub fn with_copies(mut self, copies: usize) -> Self {
+        self.copies = copies.max(1);
+        self
+    }
+
+    /// Render a single marker
+    fn render_marker(condition: &str) -> String {
+        format!(
+            r#"box(
+    width: 1.3in,
+    height: 0.5in,
+    stroke: (top: 2pt + colors.accent, rest: (dash: "dashed", paint: colors.border)),
+    radius: 2pt,
+    inset: (x: 4pt, y: 3pt),
+  )[
+    #grid(
+      columns: (1fr, auto),
+      align: horizon,
+      text(size: 7pt, weight: "bold")[{}],
+      circle(radius: 7pt, stroke: 0.5pt + colors.border),
+    )
+  ]"#,
+            escape_typst(condition)
+        )
+    }
+}
+
+impl Renderable for ConditionMarkerSection {
+    fn to_typst(&self, _ctx: &RenderContext) -> Result<String> {
+        let mut typst = String::new();
+
+        typst.push_str("#pagebreak(weak: true)\n");
+        typst.push_str(&format!(
+            "#align(center)[#text(size: 14pt, weight: \"bold\")[Condition Markers: {}]]\n",
+            escape_typst(&self.title)
+        ));
+        typst.push_str("#v(0.3em)\n");
+        typst.push_str("#text(size: 9pt)[Cut along dashed lines. Write the creature's card number in the circle and clip the marker to its card.]\n");
+        typst.push_str("#v(0.5em)\n\n");
+
+        typst.push_str(&format!(
+            "#grid(\n  columns: ({}),\n  gutter: 6pt,\n",
+            vec!["1.3in"; MARKERS_PER_ROW].join(", ")
+        ));
+        for condition in CONDITIONS {
+            for _ in 0..self.copies {
+                typst.push_str("  ");
+                typst.push_str(&Self::render_marker(condition));
+                typst.push_str(",\n");
+            }
+        }
+        typst.push_str(")\n");
+
+        Ok(typst)
+    }
+
+    fn toc_title(&self) -> Option<String> {
+        Some("Condition Markers".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_marker_per_condition() {
+        let section = ConditionMarkerSection::new("Goblin Ambush");
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("Condition Markers: Goblin Ambush"));
+        for condition in CONDITIONS {
+            assert_eq!(typst.matches(&format!("[{}]", condition)).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_copies_per_condition() {
+        let section = ConditionMarkerSection::new("Goblin Ambush").with_copies(3);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert_eq!(typst.matches("[Prone]").count(), 3);
+    }
+
+    #[test]
+    fn test_copies_at_least_one() {
+        let section = ConditionMarkerSection::new("Empty").with_copies(0);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert_eq!(typst.matches("[Stunned]").count(), 1);
+    }
+}
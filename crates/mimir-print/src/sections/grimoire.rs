@@ -0,0 +1,413 @@
+//! Grimoire section
+//!
+//! A wizard's spellbook written out as a book: one chapter per school of
+//! magic, a ledger of what it cost to scribe spells copied in from scrolls
+//! and other books, and blank pages for spells still to be learned.
+
+use serde_json::Value;
+
+use super::card_utils::{escape_typst, flatten_entries};
+use super::monster_cards::strip_5etools_tags;
+use super::spell_cards::SpellCardsSection;
+use crate::builder::{RenderContext, Renderable};
+use crate::error::Result;
+
+/// Gold cost to scribe a spell, per spell level
+pub const SCRIBE_GP_PER_LEVEL: i64 = 50;
+
+/// Hours it takes to scribe a spell, per spell level
+pub const SCRIBE_HOURS_PER_LEVEL: i64 = 2;
+
+/// Schools in chapter order, as 5etools codes
+const SCHOOL_ORDER: [&str; 8] = ["A", "C", "D", "E", "V", "I", "N", "T"];
+
+/// A spell written in the grimoire
+#[derive(Debug, Clone)]
+pub struct GrimoireSpell {
+    /// Catalog or homebrew spell data
+    pub data: Value,
+    /// Copied in from a scroll or another book, rather than learned on level up
+    pub scribed: bool,
+}
+
+impl GrimoireSpell {
+    fn name(&self) -> &str {
+        self.data
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown Spell")
+    }
+
+    fn level(&self) -> i64 {
+        self.data.get("level").and_then(|v| v.as_i64()).unwrap_or(0)
+    }
+
+    fn school(&self) -> String {
+        let school = self
+            .data
+            .get("school")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        SCHOOL_ORDER
+            .iter()
+            .find(|code| {
+                SpellCardsSection::school_name(code) == SpellCardsSection::school_name(school)
+            })
+            .map(|code| code.to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Grimoire section - spells by school, scribing ledger, and blank pages
+pub struct GrimoireSection {
+    /// Spells in the book (cantrips are left out when rendering)
+    spells: Vec<GrimoireSpell>,
+    /// Blank spell pages at the back of the book
+    blank_pages: usize,
+}
+
+impl GrimoireSection {
+    /// Create a grimoire from the spells in a spellbook
+    pub fn new(spells: Vec<GrimoireSpell>) -> Self {
+        Self {
+            spells,
+            blank_pages: 4,
+        }
+    }
+
+    /// Set how many blank spell pages to add at the back
+    pub fn with_blank_pages(mut self, pages: usize) -> Self {
+        self.blank_pages = pages;
+        self
+    }
+
+    /// Spells that belong in the book, sorted by level then name.
+    /// Cantrips are memorized rather than written down, so they're skipped.
+    fn book_spells(&self) -> Vec<&GrimoireSpell> {
+        let mut spells: Vec<&GrimoireSpell> =
+            self.spells.iter().filter(|s| s.level() > 0).collect();
+        spells.sort_by(|a, b| {
+            a.level()
+                .cmp(&b.level())
+                .then_with(|| a.name().cmp(b.name()))
+        });
+        spells
+    }
+
+    /// Gold and hours it costs to scribe a spell of the given level
+    pub fn scribing_cost(level: i64) -> (i64, i64) {
+        (level * SCRIBE_GP_PER_LEVEL, level * SCRIBE_HOURS_PER_LEVEL)
+    }
+
+    /// Render one spell as a book entry
+    fn render_spell(spell: &GrimoireSpell) -> String {
+        let f = SpellCardsSection::extract_fields(&spell.data);
+        let level = spell.level();
+
+        let mut kind = format!("{} {}", f.level_text, f.school_full.to_lowercase());
+        if spell.data.get("ritual").and_then(|v| v.as_bool()) == Some(true) {
+            kind.push_str(" (ritual)");
+        }
+
+        let mut components = f.components.clone();
+        if let Some(material) = material_text(&spell.data) {
+            components = components.replacen('M', &format!("M ({})", material), 1);
+        }
+
+        let duration = if f.conc_marker.is_empty() {
+            f.duration.clone()
+        } else {
+            format!("Concentration, up to {}", f.duration)
+        };
+
+        let mut typst = String::from("#block(width: 100%, breakable: true, below: spacing.xl)[\n");
+        typst.push_str(&format!(
+            "  #heading(level: 2, outlined: false)[{}]\n",
+            escape_typst(&f.name)
+        ));
+        typst.push_str(&format!("  #subtitle-text[{}]", escape_typst(&kind)));
+        if spell.scribed {
+            let (gp, hours) = Self::scribing_cost(level);
+            typst.push_str(&format!(
+                " #h(1fr) #small-text[Scribed: {} gp, {} hours]",
+                gp, hours
+            ));
+        }
+        typst.push_str("\n  #v(spacing.sm)\n");
+        typst.push_str(
+            "  #grid(columns: (auto, 1fr), column-gutter: spacing.md, row-gutter: spacing.xs,\n",
+        );
+        for (label, value) in [
+            ("Casting Time", &f.casting_time),
+            ("Range", &f.range),
+            ("Components", &components),
+            ("Duration", &duration),
+        ] {
+            typst.push_str(&format!(
+                "    [*{}:*], [{}],\n",
+                label,
+                escape_typst(&strip_5etools_tags(value))
+            ));
+        }
+        typst.push_str("  )\n  #v(spacing.sm)\n");
+
+        for paragraph in description_paragraphs(&spell.data) {
+            typst.push_str(&format!(
+                "  {}\n\n",
+                escape_typst(&strip_5etools_tags(&paragraph))
+            ));
+        }
+
+        typst.push_str("]\n");
+        typst
+    }
+
+    /// Render the ledger of scribed spells and their costs
+    fn render_ledger(spells: &[&GrimoireSpell]) -> String {
+        let scribed: Vec<&&GrimoireSpell> = spells.iter().filter(|s| s.scribed).collect();
+        if scribed.is_empty() {
+            return String::new();
+        }
+
+        let mut typst = String::from("#pagebreak()\n");
+        typst.push_str("#heading(level: 1, outlined: true)[Scribing Ledger]\n");
+        typst.push_str(&format!(
+            "#small-text[Copying a spell into the book takes {} hours and {} gp of inks per spell level.]\n",
+            SCRIBE_HOURS_PER_LEVEL, SCRIBE_GP_PER_LEVEL
+        ));
+        typst.push_str("#v(spacing.md)\n");
+        typst.push_str("#mimir-table(columns: (1fr, auto, auto, auto),\n");
+        typst.push_str("  [*Spell*], [*Level*], [*Cost*], [*Time*],\n");
+
+        let (mut total_gp, mut total_hours) = (0, 0);
+        for spell in &scribed {
+            let (gp, hours) = Self::scribing_cost(spell.level());
+            total_gp += gp;
+            total_hours += hours;
+            typst.push_str(&format!(
+                "  [{}], [{}], [{} gp], [{} h],\n",
+                escape_typst(spell.name()),
+                spell.level(),
+                gp,
+                hours
+            ));
+        }
+        typst.push_str(&format!(
+            "  [*Total*], [], [*{} gp*], [*{} h*],\n",
+            total_gp, total_hours
+        ));
+        typst.push_str(")\n");
+        typst
+    }
+
+    /// Render a blank page for writing in a new spell
+    fn render_blank_page() -> String {
+        let mut typst = String::from("#pagebreak()\n");
+        typst.push_str(
+            "#grid(columns: (auto, 1fr), column-gutter: spacing.md, row-gutter: spacing.lg,\n",
+        );
+        for label in [
+            "Spell",
+            "Level & School",
+            "Casting Time",
+            "Range",
+            "Components",
+            "Duration",
+        ] {
+            typst.push_str(&format!(
+                "  label-text[{}], line(length: 100%, stroke: 0.5pt + colors.border-light),\n",
+                label
+            ));
+        }
+        typst.push_str(")\n");
+        typst.push_str("#v(spacing.lg)\n");
+        typst.push_str("#for _ in range(22) { v(sizes.base * 1.6); divider() }\n");
+        typst
+    }
+}
+
+/// Material component text, if the spell has one
+fn material_text(spell: &Value) -> Option<String> {
+    let material = spell.get("components")?.get("m")?;
+    material
+        .as_str()
+        .or_else(|| material.get("text").and_then(|v| v.as_str()))
+        .map(String::from)
+}
+
+/// Description as paragraphs, with the higher-level text last
+fn description_paragraphs(spell: &Value) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    if let Some(desc) = spell.get("description").and_then(|v| v.as_str()) {
+        paragraphs.extend(desc.split("\n\n").map(String::from));
+    } else if let Some(entries) = spell.get("entries").and_then(|v| v.as_array()) {
+        paragraphs.extend(
+            entries
+                .iter()
+                .map(|e| flatten_entries(std::slice::from_ref(e))),
+        );
+    }
+    if let Some(higher) = spell.get("entriesHigherLevel").and_then(|v| v.as_array()) {
+        paragraphs.push(flatten_entries(higher));
+    }
+    paragraphs.retain(|p| !p.trim().is_empty());
+    paragraphs
+}
+
+impl Renderable for GrimoireSection {
+    fn to_typst(&self, _ctx: &RenderContext) -> Result<String> {
+        let spells = self.book_spells();
+        let mut typst = String::new();
+
+        // One chapter per school, each starting on its own page
+        let mut first = true;
+        for code in SCHOOL_ORDER {
+            let chapter: Vec<&&GrimoireSpell> =
+                spells.iter().filter(|s| s.school() == code).collect();
+            if chapter.is_empty() {
+                continue;
+            }
+            if !first {
+                typst.push_str("#pagebreak()\n");
+            }
+            first = false;
+
+            typst.push_str(&format!(
+                "#heading(level: 1, outlined: true)[{}]\n",
+                SpellCardsSection::school_name(code)
+            ));
+            for spell in chapter {
+                typst.push_str(&Self::render_spell(spell));
+            }
+        }
+
+        // Spells with an unrecognized school get a chapter of their own
+        let other: Vec<&&GrimoireSpell> = spells.iter().filter(|s| s.school().is_empty()).collect();
+        if !other.is_empty() {
+            if !first {
+                typst.push_str("#pagebreak()\n");
+            }
+            first = false;
+            typst.push_str("#heading(level: 1, outlined: true)[Other Spells]\n");
+            for spell in other {
+                typst.push_str(&Self::render_spell(spell));
+            }
+        }
+
+        if first {
+            typst.push_str("#subtitle-text[This spellbook has no spells yet.]\n");
+        }
+
+        typst.push_str(&Self::render_ledger(&spells));
+
+        if self.blank_pages > 0 {
+            typst.push_str("#pagebreak()\n");
+            typst.push_str("#heading(level: 1, outlined: true)[New Spells]\n");
+            typst.push_str("#small-text[Blank pages for spells learned later.]\n");
+            for _ in 0..self.blank_pages {
+                typst.push_str(&Self::render_blank_page());
+            }
+        }
+
+        Ok(typst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn spell(name: &str, level: i64, school: &str, scribed: bool) -> GrimoireSpell {
+        GrimoireSpell {
+            data: json!({
+                "name": name,
+                "level": level,
+                "school": school,
+                "source": "PHB",
+                "components": { "v": true, "s": true, "m": "a tiny ball of bat guano and sulfur" },
+                "entries": ["A bright streak flashes.", "Each creature makes a {@dc 15} save."],
+                "entriesHigherLevel": [{ "type": "entries", "name": "At Higher Levels", "entries": ["More damage."] }]
+            }),
+            scribed,
+        }
+    }
+
+    #[test]
+    fn test_chapters_follow_school_order() {
+        let section = GrimoireSection::new(vec![
+            spell("Fireball", 3, "V", false),
+            spell("Shield", 1, "A", false),
+            spell("Magic Missile", 1, "V", false),
+        ]);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+
+        let abjuration = typst.find("[Abjuration]").unwrap();
+        let evocation = typst.find("[Evocation]").unwrap();
+        assert!(abjuration < evocation);
+        assert!(!typst.contains("[Conjuration]"));
+
+        // Sorted by level within a chapter
+        assert!(typst.find("Magic Missile").unwrap() < typst.find("Fireball").unwrap());
+    }
+
+    #[test]
+    fn test_cantrips_are_left_out() {
+        let section = GrimoireSection::new(vec![
+            spell("Fire Bolt", 0, "V", false),
+            spell("Shield", 1, "A", false),
+        ]);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(!typst.contains("Fire Bolt"));
+        assert!(typst.contains("Shield"));
+    }
+
+    #[test]
+    fn test_spell_entry_details() {
+        let typst = GrimoireSection::render_spell(&spell("Fireball", 3, "V", false));
+        assert!(typst.contains("3rd-level evocation"));
+        assert!(typst.contains("M (a tiny ball of bat guano and sulfur)"));
+        assert!(typst.contains("DC 15 save"));
+        assert!(typst.contains("At Higher Levels. More damage."));
+        assert!(!typst.contains("Scribed:"));
+    }
+
+    #[test]
+    fn test_ledger_lists_scribed_spells_with_totals() {
+        let section = GrimoireSection::new(vec![
+            spell("Fireball", 3, "V", true),
+            spell("Shield", 1, "A", false),
+            spell("Counterspell", 3, "A", true),
+        ])
+        .with_blank_pages(0);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+
+        assert!(typst.contains("[Scribing Ledger]"));
+        assert!(typst.contains("[Fireball], [3], [150 gp], [6 h]"));
+        assert!(typst.contains("[*Total*], [], [*300 gp*], [*12 h*]"));
+        assert!(!typst.contains("[Shield], [1]"));
+        assert!(!typst.contains("[New Spells]"));
+    }
+
+    #[test]
+    fn test_no_ledger_without_scribed_spells() {
+        let section = GrimoireSection::new(vec![spell("Shield", 1, "A", false)]);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(!typst.contains("Scribing Ledger"));
+    }
+
+    #[test]
+    fn test_blank_pages() {
+        let section = GrimoireSection::new(vec![]).with_blank_pages(3);
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("no spells yet"));
+        assert!(typst.contains("[New Spells]"));
+        assert_eq!(typst.matches("label-text[Spell]").count(), 3);
+    }
+
+    #[test]
+    fn test_scribing_cost() {
+        assert_eq!(GrimoireSection::scribing_cost(1), (50, 2));
+        assert_eq!(GrimoireSection::scribing_cost(5), (250, 10));
+    }
+}
@@ -14,10 +14,23 @@ use crate::map_renderer::{
     load_image_from_file, render_map_for_print, MapPrintOptions, RenderMap, RenderToken,
 };
 
+/// A label drawn over a map preview, e.g. a location key number
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLabel {
+    /// Text inside the label
+    pub text: String,
+    /// Horizontal center as a fraction of the map width (0.0 is the left edge)
+    pub x: f64,
+    /// Vertical center as a fraction of the map height (0.0 is the top edge)
+    pub y: f64,
+}
+
 /// Map preview section - renders a map fit to a single page
 pub struct MapPreview {
     name: String,
     source: MapPreviewSource,
+    /// Labels drawn over the image
+    labels: Vec<MapLabel>,
 }
 
 enum MapPreviewSource {
@@ -47,6 +60,7 @@ impl MapPreview {
                 },
                 base_path,
             },
+            labels: Vec::new(),
         }
     }
 
@@ -55,7 +69,39 @@ impl MapPreview {
         Self {
             name,
             source: MapPreviewSource::PreRendered { image_bytes },
+            labels: Vec::new(),
+        }
+    }
+
+    /// Draw numbered circles over the map, as on a keyed dungeon map
+    pub fn with_labels(mut self, labels: Vec<MapLabel>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Image scaled to fit the page with the labels placed over it
+    fn render_labeled_image(&self, virtual_path: &str) -> String {
+        let mut typst = format!(
+            r#"#layout(size => {{
+    let natural = measure(image("{path}", width: size.width))
+    let scale = calc.min(1.0, size.height * 0.9 / natural.height)
+    let w = size.width * scale
+    let h = natural.height * scale
+    align(center, box(width: w, height: h, {{
+      image("{path}", width: 100%, height: 100%)
+"#,
+            path = virtual_path
+        );
+        for label in &self.labels {
+            typst.push_str(&format!(
+                "      place(top + left, dx: w * {:.4} - 8pt, dy: h * {:.4} - 8pt, circle(radius: 8pt, fill: white, stroke: 1.5pt + black, align(center + horizon, text(size: 8pt, weight: \"bold\")[{}])))\n",
+                label.x.clamp(0.0, 1.0),
+                label.y.clamp(0.0, 1.0),
+                escape_typst_string(&label.text)
+            ));
         }
+        typst.push_str("    }))\n  })");
+        typst
     }
 
     /// Set rendering options (only applies to FromMap source)
@@ -103,6 +149,14 @@ impl Renderable for MapPreview {
         let virtual_path = ctx.virtual_files.register(&image_filename, image_bytes);
         tracing::debug!("Registered map preview as: {}", virtual_path);
 
+        if !self.labels.is_empty() {
+            return Ok(format!(
+                "#block(breakable: false)[\n  #heading(level: 1, outlined: true)[Map: {}]\n  {}\n]\n",
+                escape_typst_string(&self.name),
+                self.render_labeled_image(&virtual_path)
+            ));
+        }
+
         // Use heading(outlined: true) so it appears in TOC, wrapped in block to keep together
         let typst = format!(
             r#"#block(breakable: false)[
@@ -643,6 +697,31 @@ mod tests {
         assert_eq!(preview.toc_title(), None); // Renders own heading
     }
 
+    #[test]
+    fn test_map_preview_labels() {
+        let preview = MapPreview::from_rendered("Crypt".to_string(), create_test_tile_image())
+            .with_labels(vec![
+                MapLabel {
+                    text: "1".to_string(),
+                    x: 0.25,
+                    y: 0.5,
+                },
+                MapLabel {
+                    text: "2".to_string(),
+                    x: 1.5,
+                    y: 0.0,
+                },
+            ]);
+        let ctx = RenderContext::new(std::env::temp_dir().join("mimir-test-map-labels"));
+        let typst = preview.to_typst(&ctx).unwrap();
+
+        assert!(typst.contains("Map: Crypt"));
+        assert!(typst.contains("dx: w * 0.2500 - 8pt, dy: h * 0.5000 - 8pt"));
+        assert!(typst.contains("[1]"));
+        // Positions are kept on the map
+        assert!(typst.contains("dx: w * 1.0000 - 8pt"));
+    }
+
     #[test]
     fn test_tiled_map_from_rendered() {
         // Create a valid small PNG image
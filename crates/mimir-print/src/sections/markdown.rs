@@ -4,7 +4,10 @@ use std::path::Path;
 
 use crate::builder::{RenderContext, Renderable};
 use crate::error::{PrintError, Result};
-use crate::markdown::{markdown_to_typst, parse_campaign_document};
+use crate::markdown::{
+    markdown_to_typst, parse_campaign_document_with_embeds, parse_campaign_document_with_monsters,
+    DocumentEmbeds, MonsterEmbeds, ParsedDocument,
+};
 
 /// A markdown document section with optional YAML frontmatter
 pub struct MarkdownSection {
@@ -19,8 +22,22 @@ pub struct MarkdownSection {
 impl MarkdownSection {
     /// Create from raw markdown string (with optional YAML frontmatter)
     pub fn from_markdown(markdown: &str) -> Result<Self> {
-        let parsed = parse_campaign_document(markdown)?;
+        Self::from_markdown_with_monsters(markdown, &MonsterEmbeds::new())
+    }
+
+    /// Create from raw markdown, rendering `{{monster: ...}}` embeds as stat
+    /// blocks from the given resolved monsters
+    pub fn from_markdown_with_monsters(markdown: &str, monsters: &MonsterEmbeds) -> Result<Self> {
+        Self::from_parsed(parse_campaign_document_with_monsters(markdown, monsters)?)
+    }
 
+    /// Create from raw markdown, rendering monster embeds and
+    /// `{{map-region: ...}}` encounter boxes from the given resolved embeds
+    pub fn from_markdown_with_embeds(markdown: &str, embeds: &DocumentEmbeds) -> Result<Self> {
+        Self::from_parsed(parse_campaign_document_with_embeds(markdown, embeds)?)
+    }
+
+    fn from_parsed(parsed: ParsedDocument) -> Result<Self> {
         let title = parsed
             .frontmatter
             .get("title")
@@ -173,6 +190,51 @@ This is a test.
         assert!(typst.contains("Item C"));
     }
 
+    #[test]
+    fn test_from_markdown_with_monsters() {
+        use crate::markdown::MonsterEmbed;
+        use serde_json::json;
+
+        let mut monsters = MonsterEmbeds::new();
+        monsters.insert(
+            MonsterEmbed {
+                name: "Goblin".to_string(),
+                source: Some("MM".to_string()),
+            },
+            Ok(json!({ "name": "Goblin", "source": "MM", "cr": "1/4" })),
+        );
+        let md = "---\ntitle: Ambush\n---\n\n{{monster: Goblin|MM}}";
+        let section = MarkdownSection::from_markdown_with_monsters(md, &monsters).unwrap();
+        assert_eq!(section.toc_title(), Some("Ambush".to_string()));
+        assert!(section.typst_content.contains("CR 1/4"));
+    }
+
+    #[test]
+    fn test_from_markdown_with_embeds() {
+        use crate::markdown::{EncounterRegion, MapRegionRef};
+
+        let mut embeds = DocumentEmbeds::default();
+        embeds.map_regions.insert(
+            MapRegionRef {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+            },
+            Ok(EncounterRegion {
+                map: "Crypt Level 1".to_string(),
+                region: "area-3".to_string(),
+                width: 4.0,
+                height: 4.0,
+                tokens: vec!["Ghoul".to_string()],
+                traps: Vec::new(),
+            }),
+        );
+        let md = "---\ntitle: Crypt\n---\n\n{{map-region: Crypt Level 1 | area-3}}";
+        let section = MarkdownSection::from_markdown_with_embeds(md, &embeds).unwrap();
+        assert_eq!(section.toc_title(), Some("Crypt".to_string()));
+        assert!(section.typst_content.contains("Encounter: area-3"));
+        assert!(section.typst_content.contains("Ghoul"));
+    }
+
     #[test]
     fn test_page_break_before_default() {
         let section = MarkdownSection::from_content("Content", None);
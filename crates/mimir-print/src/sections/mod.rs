@@ -4,22 +4,35 @@
 //! that can be assembled into a complete PDF document.
 
 pub mod card_utils;
+pub mod chapter_divider;
 pub mod character;
 pub mod character_battle_card;
+pub mod condition_markers;
 pub mod equipment_cards;
+pub mod grimoire;
 pub mod map;
 pub mod markdown;
 pub mod monster_cards;
+pub mod secret_slips;
 pub mod spell_cards;
+pub mod timeline_chart;
 pub mod token_cutouts;
 pub mod trap_cards;
 
-pub use character::{CharacterData, CharacterSection, ClassInfo, InventoryItem, Proficiencies, ProficiencyEntry};
+pub use chapter_divider::ChapterDivider;
+pub use character::{
+    CharacterData, CharacterSection, ClassInfo, CustomFieldEntry, InventoryItem, Proficiencies,
+    ProficiencyEntry,
+};
 pub use character_battle_card::CharacterBattleCardSection;
+pub use condition_markers::ConditionMarkerSection;
 pub use equipment_cards::{is_card_worthy, EquipmentCardsSection};
-pub use map::{MapPreview, TileData, TiledMapSection};
+pub use grimoire::{GrimoireSection, GrimoireSpell};
+pub use map::{MapLabel, MapPreview, TileData, TiledMapSection};
 pub use markdown::MarkdownSection;
 pub use monster_cards::MonsterCardSection;
+pub use secret_slips::{SecretSlip, SecretSlipSection};
 pub use spell_cards::SpellCardsSection;
+pub use timeline_chart::{TimelineChartSection, TimelineEvent, TimelineLane};
 pub use token_cutouts::{CutoutToken, TokenCutoutSection};
 pub use trap_cards::TrapCardSection;
@@ -52,21 +52,7 @@ impl MonsterCardSection {
             .unwrap_or("");
 
         // Size
-        let size = monster
-            .get("size")
-            .and_then(|v| v.as_array())
-            .and_then(|a| a.first())
-            .and_then(|v| v.as_str())
-            .unwrap_or("M");
-        let size_name = match size.to_uppercase().as_str() {
-            "T" => "Tiny",
-            "S" => "Small",
-            "M" => "Medium",
-            "L" => "Large",
-            "H" => "Huge",
-            "G" => "Gargantuan",
-            _ => "Medium",
-        };
+        let size_name = extract_size_name(monster);
 
         // Creature type
         let creature_type = extract_creature_type(monster);
@@ -120,6 +106,9 @@ impl MonsterCardSection {
         // Plan card layout (single or foldable)
         let layout = plan_card_layout(monster);
 
+        // Initiative deck label, e.g. "Goblin Ambush · 3 of 7 · Init +2"
+        let deck_label = render_deck_label(monster);
+
         // Build front sections content
         let front_sections_content = render_sections(&layout.front_sections);
 
@@ -227,7 +216,7 @@ impl MonsterCardSection {
       fill: colors.background-alt,
       inset: (x: 6pt, y: 2pt),
     )[
-      #text(size: 5pt, fill: colors.text-secondary)[{source}{fold_indicator}]
+      #text(size: 5pt, fill: colors.text-secondary)[{source}{fold_indicator}{deck_label}]
     ]
   )
 ]"##,
@@ -272,6 +261,7 @@ impl MonsterCardSection {
             front_sections_content = front_sections_content,
             source = escape_typst(source),
             fold_indicator = if layout.is_foldable { " ▶ continued" } else { "" },
+            deck_label = deck_label,
         )
     }
 
@@ -330,13 +320,14 @@ impl MonsterCardSection {
       fill: colors.background-alt,
       inset: (x: 6pt, y: 2pt),
     )[
-      #text(size: 5pt, fill: colors.text-secondary)[◀ fold \| {source}]
+      #text(size: 5pt, fill: colors.text-secondary)[◀ fold \| {source}{deck_label}]
     ]
   )
 ]"##,
             name = escape_typst(name),
             back_sections_content = back_sections_content,
             source = escape_typst(source),
+            deck_label = render_deck_label(monster),
         ))
     }
 }
@@ -448,8 +439,171 @@ impl Renderable for MonsterCardSection {
     }
 }
 
+/// Render the initiative deck label for a card footer, if the monster has one.
+fn render_deck_label(monster: &Value) -> String {
+    match monster.get("deck_label").and_then(|v| v.as_str()) {
+        Some(label) => format!(" #h(1fr) #text(weight: \"bold\")[{}]", escape_typst(label)),
+        None => String::new(),
+    }
+}
+
+/// Render a full-width stat block for embedding in a document.
+///
+/// Shows the same stats as a monster card, but flows with the surrounding
+/// text instead of being sized to cut out.
+pub(crate) fn render_stat_block(monster: &Value) -> String {
+    let name = monster
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Unknown");
+    let source = monster.get("source").and_then(|v| v.as_str()).unwrap_or("");
+    let score = |key: &str| monster.get(key).and_then(|v| v.as_i64()).unwrap_or(10);
+    let abilities = ["str", "dex", "con", "int", "wis", "cha"]
+        .iter()
+        .map(|key| {
+            format!(
+                "align(center)[*{}*\\ {} ({})]",
+                key.to_uppercase(),
+                score(key),
+                modifier(score(key))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n      ");
+
+    let mut details = Vec::new();
+    for (label, value) in [
+        ("Saves", extract_saves(monster)),
+        ("Senses", extract_senses(monster)),
+        ("Languages", extract_languages(monster)),
+    ] {
+        if !value.is_empty() {
+            details.push(format!("*{}* {}", label, escape_typst(&value)));
+        }
+    }
+    let details_block = if details.is_empty() {
+        String::new()
+    } else {
+        format!(
+            r#"#block(
+    width: 100%,
+    inset: (x: 6pt, y: 3pt),
+    stroke: (bottom: 0.5pt + colors.border-light),
+  )[
+    #set text(size: 6pt)
+    {}
+  ]"#,
+            details.join(" \\\n    ")
+        )
+    };
+
+    format!(
+        r##"#block(
+  width: 100%,
+  breakable: false,
+  stroke: (
+    top: 3pt + colors.accent,
+    bottom: 3pt + colors.accent,
+    left: 0.5pt + colors.border,
+    right: 0.5pt + colors.border,
+  ),
+  radius: 3pt,
+  clip: true,
+  inset: 0pt,
+)[
+  #block(
+    width: 100%,
+    fill: colors.background-alt,
+    inset: (x: 6pt, y: 4pt),
+  )[
+    #grid(
+      columns: (1fr, auto),
+      [
+        #text(size: 10pt, weight: "bold")[{name}]
+        #linebreak()
+        #text(size: 7pt, style: "italic")[{size_name} {creature_type}, {alignment}]
+      ],
+      align(right + horizon)[
+        #text(size: 12pt, weight: "bold", fill: colors.accent)[CR {cr}]
+        #linebreak()
+        #text(size: 5pt, fill: colors.text-secondary)[{source}]
+      ]
+    )
+  ]
+
+  #block(
+    width: 100%,
+    inset: 6pt,
+    stroke: (bottom: 0.5pt + colors.border-light),
+  )[
+    #set text(size: 7pt)
+    #grid(
+      columns: (1fr, 1fr, 1fr),
+      [*AC* {ac}],
+      [*HP* {hp}],
+      [*Speed* {speed}],
+    )
+  ]
+
+  #block(
+    width: 100%,
+    inset: (x: 6pt, y: 4pt),
+    stroke: (bottom: 0.5pt + colors.border-light),
+  )[
+    #set text(size: 6pt)
+    #grid(
+      columns: (1fr,) * 6,
+      {abilities},
+    )
+  ]
+
+  {details_block}
+
+  {resistances_block}
+
+  {sections}
+]"##,
+        name = escape_typst(name),
+        size_name = extract_size_name(monster),
+        creature_type = escape_typst(&extract_creature_type(monster)),
+        alignment = escape_typst(&extract_alignment(monster)),
+        cr = escape_typst(&extract_cr(monster)),
+        source = escape_typst(source),
+        ac = escape_typst(&extract_ac_full(monster)),
+        hp = escape_typst(&extract_hp_full(monster)),
+        speed = escape_typst(&extract_speed_full(monster)),
+        abilities = abilities,
+        details_block = details_block,
+        resistances_block = render_resistances_block(
+            &extract_damage_list(monster, "damage_vulnerabilities", "damageVulnerabilities"),
+            &extract_damage_list(monster, "damage_resistances", "damageResistances"),
+            &extract_damage_list(monster, "damage_immunities", "damageImmunities"),
+            &extract_condition_immunities(monster),
+        ),
+        sections = render_sections(&extract_sections(monster)),
+    )
+}
+
 // === Helper Functions ===
 
+fn extract_size_name(monster: &Value) -> &'static str {
+    let size = monster
+        .get("size")
+        .and_then(|v| v.as_array())
+        .and_then(|a| a.first())
+        .and_then(|v| v.as_str())
+        .unwrap_or("M");
+    match size.to_uppercase().as_str() {
+        "T" => "Tiny",
+        "S" => "Small",
+        "M" => "Medium",
+        "L" => "Large",
+        "H" => "Huge",
+        "G" => "Gargantuan",
+        _ => "Medium",
+    }
+}
+
 fn extract_creature_type(monster: &Value) -> String {
     monster
         .get("type")
@@ -848,6 +1002,44 @@ fn plan_card_layout(monster: &Value) -> CardLayout {
     // Single card budget for ability sections (after header, stats, abilities row)
     const SINGLE_CARD_BUDGET: usize = 1200;
 
+    let sections = extract_sections(monster);
+
+    // Calculate total content size
+    let total_size: usize = sections.iter().map(|s| s.char_count).sum();
+
+    // If everything fits, single card
+    if total_size <= SINGLE_CARD_BUDGET {
+        return CardLayout {
+            front_sections: sections,
+            back_sections: Vec::new(),
+            is_foldable: false,
+        };
+    }
+
+    // Need foldable card - find break point at section boundary
+    let mut front_sections = Vec::new();
+    let mut back_sections = Vec::new();
+    let mut front_used = 0;
+
+    for section in sections {
+        if front_used + section.char_count <= SINGLE_CARD_BUDGET {
+            front_used += section.char_count;
+            front_sections.push(section);
+        } else {
+            back_sections.push(section);
+        }
+    }
+
+    let is_foldable = !back_sections.is_empty();
+    CardLayout {
+        front_sections,
+        back_sections,
+        is_foldable,
+    }
+}
+
+/// Extract traits, actions, reactions, legendary and lair actions
+fn extract_sections(monster: &Value) -> Vec<SectionContent> {
     let mut sections = Vec::new();
 
     // Extract traits
@@ -910,38 +1102,7 @@ fn plan_card_layout(monster: &Value) -> CardLayout {
         });
     }
 
-    // Calculate total content size
-    let total_size: usize = sections.iter().map(|s| s.char_count).sum();
-
-    // If everything fits, single card
-    if total_size <= SINGLE_CARD_BUDGET {
-        return CardLayout {
-            front_sections: sections,
-            back_sections: Vec::new(),
-            is_foldable: false,
-        };
-    }
-
-    // Need foldable card - find break point at section boundary
-    let mut front_sections = Vec::new();
-    let mut back_sections = Vec::new();
-    let mut front_used = 0;
-
-    for section in sections {
-        if front_used + section.char_count <= SINGLE_CARD_BUDGET {
-            front_used += section.char_count;
-            front_sections.push(section);
-        } else {
-            back_sections.push(section);
-        }
-    }
-
-    let is_foldable = !back_sections.is_empty();
-    CardLayout {
-        front_sections,
-        back_sections,
-        is_foldable,
-    }
+    sections
 }
 
 /// Extract ability list from monster data
@@ -1054,7 +1215,7 @@ fn extract_entries_text(entries: Option<&Value>) -> String {
 }
 
 /// Strip 5etools formatting tags and convert to plain text
-fn strip_5etools_tags(text: &str) -> String {
+pub(super) fn strip_5etools_tags(text: &str) -> String {
     let mut result = text.to_string();
 
     // {@atk mw} -> Melee Weapon Attack:
@@ -1402,6 +1563,16 @@ mod tests {
         assert!(card.contains("fly 80 ft."));
     }
 
+    #[test]
+    fn test_render_card_deck_label() {
+        let mut goblin = test_goblin();
+        assert!(!MonsterCardSection::render_card(&goblin).contains("Init +2"));
+
+        goblin["deck_label"] = json!("Goblin Ambush · 2 of 5 · Init +2");
+        let card = MonsterCardSection::render_card(&goblin);
+        assert!(card.contains("[Goblin Ambush · 2 of 5 · Init +2]"));
+    }
+
     #[test]
     fn test_foldable_card_with_lots_of_content() {
         // Create a monster with enough content to exceed SINGLE_CARD_BUDGET (1200 chars)
@@ -1474,6 +1645,15 @@ mod tests {
         assert!(!card.contains("continued"));
     }
 
+    #[test]
+    fn test_render_stat_block() {
+        let block = render_stat_block(&test_dragon());
+        assert!(block.starts_with("#block("));
+        assert!(block.contains("Adult Red Dragon"));
+        assert!(block.contains("Legendary Actions"));
+        assert!(!block.contains("5.125in"));
+    }
+
     #[test]
     fn test_to_typst_grid_layout() {
         let monsters = vec![test_goblin()];
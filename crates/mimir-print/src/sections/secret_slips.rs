@@ -0,0 +1,151 @@
+//! Secret slips section
+//!
+//! Renders small cut-out cards, one per secret note, to hand to players at
+//! the table. The player's name sits above a fold line so the slip can be
+//! folded shut with only the name showing.
+
+use super::card_utils::escape_typst;
+use crate::builder::{RenderContext, Renderable};
+use crate::error::Result;
+
+/// Slips per row of the sheet
+const SLIPS_PER_ROW: usize = 2;
+
+/// A secret note printed on a slip
+#[derive(Debug, Clone)]
+pub struct SecretSlip {
+    /// Player the slip is for
+    pub player_name: String,
+    /// Short title of the secret
+    pub title: String,
+    /// The secret itself
+    pub content: String,
+}
+
+/// Secret slips sheet - cut-out cards, one per secret note
+pub struct SecretSlipSection {
+    /// Heading printed above the slips (usually the campaign name)
+    title: String,
+    slips: Vec<SecretSlip>,
+}
+
+impl SecretSlipSection {
+    /// Create a sheet of slips
+    pub fn new(title: impl Into<String>, slips: Vec<SecretSlip>) -> Self {
+        Self {
+            title: title.into(),
+            slips,
+        }
+    }
+
+    /// Render a single slip
+    fn render_slip(slip: &SecretSlip) -> String {
+        let paragraphs: Vec<String> = slip
+            .content
+            .split("\n\n")
+            .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|p| !p.is_empty())
+            .map(|p| escape_typst(&p))
+            .collect();
+
+        format!(
+            r#"box(
+    width: 3.4in,
+    height: 2.6in,
+    stroke: (dash: "dashed", paint: colors.border),
+    radius: 2pt,
+    inset: 6pt,
+    clip: true,
+  )[
+    #align(center)[#text(size: 7pt, fill: colors.border)[For]]
+    #align(center)[#text(size: 12pt, weight: "bold")[{player}]]
+    #v(2pt)
+    #line(length: 100%, stroke: (dash: "dotted", paint: colors.border))
+    #align(center)[#text(size: 5pt, fill: colors.border)[fold here]]
+    #text(size: 9pt, weight: "bold", fill: colors.accent)[{title}]
+    #v(2pt)
+    #set par(justify: false, leading: 0.5em)
+    #text(size: 8pt)[{content}]
+  ]"#,
+            player = escape_typst(&slip.player_name),
+            title = escape_typst(&slip.title),
+            content = paragraphs.join("\n\n    "),
+        )
+    }
+}
+
+impl Renderable for SecretSlipSection {
+    fn to_typst(&self, _ctx: &RenderContext) -> Result<String> {
+        let mut typst = String::new();
+
+        typst.push_str("#pagebreak(weak: true)\n");
+        typst.push_str(&format!(
+            "#align(center)[#text(size: 14pt, weight: \"bold\")[Secret Notes: {}]]\n",
+            escape_typst(&self.title)
+        ));
+        typst.push_str("#v(0.3em)\n");
+        typst.push_str("#text(size: 9pt)[Cut along dashed lines and fold each slip along the dotted line so only the name shows.]\n");
+        typst.push_str("#v(0.5em)\n\n");
+
+        typst.push_str(&format!(
+            "#grid(\n  columns: ({}),\n  gutter: 8pt,\n",
+            vec!["3.4in"; SLIPS_PER_ROW].join(", ")
+        ));
+        for slip in &self.slips {
+            typst.push_str("  ");
+            typst.push_str(&Self::render_slip(slip));
+            typst.push_str(",\n");
+        }
+        typst.push_str(")\n");
+
+        Ok(typst)
+    }
+
+    fn toc_title(&self) -> Option<String> {
+        Some("Secret Notes".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slip(player_name: &str, title: &str, content: &str) -> SecretSlip {
+        SecretSlip {
+            player_name: player_name.to_string(),
+            title: title.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_one_slip_per_note() {
+        let section = SecretSlipSection::new(
+            "Curse of the Crimson King",
+            vec![
+                slip("Alice", "The Sigil", "You recognize it."),
+                slip("Bob", "A Dream", "The voice calls your name."),
+            ],
+        );
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("Secret Notes: Curse of the Crimson King"));
+        assert_eq!(typst.matches("fold here").count(), 2);
+        assert!(typst.contains("[Alice]"));
+        assert!(typst.contains("[The voice calls your name.]"));
+    }
+
+    #[test]
+    fn test_content_is_escaped() {
+        let section = SecretSlipSection::new(
+            "Test",
+            vec![slip(
+                "Alice",
+                "Note #1",
+                "Meet at [the] docks\nat midnight.",
+            )],
+        );
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("Note \\#1"));
+        assert!(typst.contains("Meet at \\[the\\] docks at midnight."));
+    }
+}
@@ -69,7 +69,7 @@ impl SpellCardsSection {
     }
 
     /// Get school full name
-    fn school_name(school: &str) -> &'static str {
+    pub(super) fn school_name(school: &str) -> &'static str {
         match school.to_uppercase().as_str() {
             "A" | "ABJURATION" => "Abjuration",
             "C" | "CONJURATION" => "Conjuration",
@@ -84,7 +84,7 @@ impl SpellCardsSection {
     }
 
     /// Extract common spell fields for card rendering
-    fn extract_fields(spell: &Value) -> SpellFields {
+    pub(super) fn extract_fields(spell: &Value) -> SpellFields {
         let name = spell
             .get("name")
             .and_then(|v| v.as_str())
@@ -538,20 +538,20 @@ impl Renderable for SpellCardsSection {
 }
 
 /// Extracted spell fields for card rendering
-struct SpellFields {
-    name: String,
-    source: String,
-    icon: &'static str,
-    school_full: &'static str,
-    level_text: String,
-    ritual_marker: &'static str,
-    conc_marker: &'static str,
-    casting_time: String,
-    range: String,
-    components: String,
-    duration: String,
-    classes: String,
-    desc_text: String,
+pub(super) struct SpellFields {
+    pub(super) name: String,
+    pub(super) source: String,
+    pub(super) icon: &'static str,
+    pub(super) school_full: &'static str,
+    pub(super) level_text: String,
+    pub(super) ritual_marker: &'static str,
+    pub(super) conc_marker: &'static str,
+    pub(super) casting_time: String,
+    pub(super) range: String,
+    pub(super) components: String,
+    pub(super) duration: String,
+    pub(super) classes: String,
+    pub(super) desc_text: String,
 }
 
 #[cfg(test)]
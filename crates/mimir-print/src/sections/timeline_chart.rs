@@ -0,0 +1,292 @@
+//! Timeline chart section
+//!
+//! Plots a campaign's history across in-game days, one swimlane per
+//! plotline or faction. Events are drawn as numbered markers along each
+//! lane, with a key beneath the chart giving each number's day and title,
+//! the same way keyed maps label their rooms.
+
+use super::card_utils::escape_typst;
+use crate::builder::{RenderContext, Renderable};
+use crate::error::Result;
+
+/// Height of one stacked row of markers within a lane, in points
+const MARKER_ROW_PT: u32 = 16;
+
+/// Day labels printed along the axis
+const AXIS_TICKS: i32 = 5;
+
+/// An event plotted on the chart
+#[derive(Debug, Clone)]
+pub struct TimelineEvent {
+    /// In-game day the event happened on
+    pub day: i32,
+    /// Short event title
+    pub title: String,
+    /// Optional details, printed in the key
+    pub description: Option<String>,
+}
+
+/// A swimlane of events sharing a plotline or faction
+#[derive(Debug, Clone)]
+pub struct TimelineLane {
+    /// Lane label, e.g. a module or faction name
+    pub name: String,
+    pub events: Vec<TimelineEvent>,
+}
+
+/// Timeline chart - events across in-game days in swimlanes, with a key
+pub struct TimelineChartSection {
+    /// Heading printed above the chart (usually the campaign name)
+    title: String,
+    lanes: Vec<TimelineLane>,
+}
+
+impl TimelineChartSection {
+    /// Create a chart of the given lanes (empty lanes are left out)
+    pub fn new(title: impl Into<String>, lanes: Vec<TimelineLane>) -> Self {
+        Self {
+            title: title.into(),
+            lanes: lanes.into_iter().filter(|l| !l.events.is_empty()).collect(),
+        }
+    }
+
+    /// The first and last day on the chart
+    fn day_range(&self) -> Option<(i32, i32)> {
+        let days = self
+            .lanes
+            .iter()
+            .flat_map(|l| l.events.iter().map(|e| e.day));
+        let first = days.clone().min()?;
+        let last = days.max()?;
+        Some((first, last))
+    }
+
+    /// Key numbers for the events, in day order. An event shown in more
+    /// than one lane keeps a single number.
+    fn key(&self) -> Vec<(&TimelineEvent, Vec<&str>)> {
+        let mut key: Vec<(&TimelineEvent, Vec<&str>)> = Vec::new();
+        for lane in &self.lanes {
+            for event in &lane.events {
+                match key.iter_mut().find(|(e, _)| same_event(e, event)) {
+                    Some((_, lanes)) => lanes.push(&lane.name),
+                    None => key.push((event, vec![&lane.name])),
+                }
+            }
+        }
+        // Stable, so same-day events keep their lane order
+        key.sort_by_key(|(e, _)| e.day);
+        key
+    }
+
+    /// Render one lane: its label and its markers along the day axis
+    fn render_lane(
+        &self,
+        lane: &TimelineLane,
+        index: usize,
+        key: &[(&TimelineEvent, Vec<&str>)],
+        (first, last): (i32, i32),
+    ) -> String {
+        // Events on the same day stack downwards
+        let mut placed: Vec<(i32, u32)> = Vec::new();
+        let mut markers = String::new();
+        for event in &lane.events {
+            let row = placed.iter().filter(|(day, _)| *day == event.day).count() as u32;
+            placed.push((event.day, row));
+            let number = key
+                .iter()
+                .position(|(e, _)| same_event(e, event))
+                .map_or(0, |i| i + 1);
+            markers.push_str(&format!(
+                "      place(top + left, dx: {:.2}% - 7pt, dy: {}pt, circle(radius: 7pt, fill: white, stroke: 1.2pt + colors.accent, align(center + horizon, text(size: 7pt, weight: \"bold\")[{}])))\n",
+                axis_percent(event.day, first, last),
+                row * MARKER_ROW_PT + 2,
+                number
+            ));
+        }
+        let rows = placed.iter().map(|(_, row)| row + 1).max().unwrap_or(1);
+        let height = rows * MARKER_ROW_PT + 4;
+
+        let fill = if index % 2 == 0 {
+            "colors.background-alt"
+        } else {
+            "none"
+        };
+        format!(
+            r#"  align(horizon, text(size: 8pt, weight: "bold")[{name}]),
+  box(width: 100%, height: {height}pt, fill: {fill}, {{
+      place(top + left, dy: {line}pt, line(length: 100%, stroke: 0.5pt + colors.border-light))
+{markers}  }}),
+"#,
+            name = escape_typst(&lane.name),
+            height = height,
+            fill = fill,
+            line = MARKER_ROW_PT / 2 + 2,
+            markers = markers,
+        )
+    }
+
+    /// Render the day labels under the lanes
+    fn render_axis((first, last): (i32, i32)) -> String {
+        let mut days: Vec<i32> = (0..AXIS_TICKS)
+            .map(|i| first + (last - first) * i / (AXIS_TICKS - 1))
+            .collect();
+        days.dedup();
+
+        let mut typst = String::from("  [],\n  box(width: 100%, height: 12pt, {\n");
+        for day in days {
+            typst.push_str(&format!(
+                "      place(top + left, dx: {:.2}% - 16pt, box(width: 32pt, align(center, text(size: 7pt, fill: colors.text-secondary)[Day {}])))\n",
+                axis_percent(day, first, last),
+                day
+            ));
+        }
+        typst.push_str("  }),\n");
+        typst
+    }
+}
+
+impl Renderable for TimelineChartSection {
+    fn to_typst(&self, _ctx: &RenderContext) -> Result<String> {
+        let mut typst = String::new();
+
+        typst.push_str("#pagebreak(weak: true)\n");
+        typst.push_str(&format!(
+            "#align(center)[#text(size: 14pt, weight: \"bold\")[Timeline: {}]]\n",
+            escape_typst(&self.title)
+        ));
+        typst.push_str("#v(0.5em)\n\n");
+
+        let Some(range) = self.day_range() else {
+            typst.push_str("#text(size: 9pt)[No events recorded.]\n");
+            return Ok(typst);
+        };
+        let key = self.key();
+
+        typst.push_str(
+            "#grid(\n  columns: (1.3in, 1fr),\n  column-gutter: 10pt,\n  row-gutter: 4pt,\n",
+        );
+        for (index, lane) in self.lanes.iter().enumerate() {
+            typst.push_str(&self.render_lane(lane, index, &key, range));
+        }
+        typst.push_str(&Self::render_axis(range));
+        typst.push_str(")\n\n");
+
+        typst.push_str("#v(1em)\n");
+        typst.push_str(
+            "#grid(\n  columns: (16pt, 0.6in, 1fr),\n  column-gutter: 6pt,\n  row-gutter: 5pt,\n",
+        );
+        for (number, (event, lanes)) in key.iter().enumerate() {
+            let mut entry = format!("*{}*", escape_typst(&event.title));
+            if let Some(description) = event.description.as_deref().map(str::trim) {
+                if !description.is_empty() {
+                    entry.push_str(&format!(" -- {}", escape_typst(description)));
+                }
+            }
+            typst.push_str(&format!(
+                "  text(size: 8pt, weight: \"bold\")[{}], text(size: 8pt)[Day {}], text(size: 8pt)[{} #text(fill: colors.text-secondary)[({})]],\n",
+                number + 1,
+                event.day,
+                entry,
+                escape_typst(&lanes.join(", "))
+            ));
+        }
+        typst.push_str(")\n");
+
+        Ok(typst)
+    }
+
+    fn toc_title(&self) -> Option<String> {
+        Some("Timeline".to_string())
+    }
+}
+
+/// Two events are the same if they share a day, title, and description
+fn same_event(a: &TimelineEvent, b: &TimelineEvent) -> bool {
+    a.day == b.day && a.title == b.title && a.description == b.description
+}
+
+/// Where a day falls along the axis, as a percentage of the lane width,
+/// keeping the end markers inside the lane
+fn axis_percent(day: i32, first: i32, last: i32) -> f64 {
+    let fraction = if last > first {
+        (day - first) as f64 / (last - first) as f64
+    } else {
+        0.5
+    };
+    3.0 + fraction * 94.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(day: i32, title: &str) -> TimelineEvent {
+        TimelineEvent {
+            day,
+            title: title.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_markers_numbered_in_day_order() {
+        let section = TimelineChartSection::new(
+            "Lost Mine",
+            vec![
+                TimelineLane {
+                    name: "Goblin Arrows".to_string(),
+                    events: vec![event(1, "Ambush"), event(5, "Rescued Sildar")],
+                },
+                TimelineLane {
+                    name: "Empty".to_string(),
+                    events: Vec::new(),
+                },
+                TimelineLane {
+                    name: "Phandalin".to_string(),
+                    events: vec![event(3, "Arrived in town")],
+                },
+            ],
+        );
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("Timeline: Lost Mine"));
+        assert!(!typst.contains("[Empty]"));
+        assert_eq!(typst.matches("circle(").count(), 3);
+        // Day 3 is the second event, halfway along the axis
+        assert!(typst.contains("dx: 50.00% - 7pt, dy: 2pt"));
+        assert!(typst.contains("[2], text(size: 8pt)[Day 3]"));
+        assert!(typst.contains("[Day 1]"));
+        assert!(typst.contains("[Day 5]"));
+    }
+
+    #[test]
+    fn test_shared_events_keep_one_number() {
+        let mut shared = event(2, "Treaty [signed]");
+        shared.description = Some("The Zhentarim and the Harpers agree to a truce.".to_string());
+        let section = TimelineChartSection::new(
+            "Test",
+            vec![
+                TimelineLane {
+                    name: "Zhentarim".to_string(),
+                    events: vec![shared.clone(), event(2, "Second")],
+                },
+                TimelineLane {
+                    name: "Harpers".to_string(),
+                    events: vec![shared],
+                },
+            ],
+        );
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("*Treaty \\[signed\\]* -- The Zhentarim"));
+        assert!(typst.contains("(Zhentarim, Harpers)"));
+        assert_eq!(typst.matches("[Day 2], text").count(), 2);
+        // The second same-day event stacks under the first
+        assert!(typst.contains("dy: 18pt"));
+    }
+
+    #[test]
+    fn test_no_events() {
+        let section = TimelineChartSection::new("Test", Vec::new());
+        let typst = section.to_typst(&RenderContext::default()).unwrap();
+        assert!(typst.contains("No events recorded."));
+    }
+}
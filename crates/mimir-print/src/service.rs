@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info, instrument};
 use typst::diag::{SourceDiagnostic, Severity};
 
+use crate::embedded_templates::get_embedded_template;
 use crate::error::{PrintError, Result};
+use crate::profile::PrintProfile;
 use crate::world::MimirTypstWorld;
 
 /// Information about an available template
@@ -26,6 +28,8 @@ pub struct TemplateInfo {
 pub struct PrintService {
     /// Root directory containing templates
     templates_root: PathBuf,
+    /// Accessibility profile applied to every render
+    profile: PrintProfile,
 }
 
 impl PrintService {
@@ -34,7 +38,16 @@ impl PrintService {
     /// # Arguments
     /// * `templates_root` - Root directory containing Typst templates
     pub fn new(templates_root: PathBuf) -> Self {
-        Self { templates_root }
+        Self {
+            templates_root,
+            profile: PrintProfile::default(),
+        }
+    }
+
+    /// Render with an accessibility profile
+    pub fn with_profile(mut self, profile: PrintProfile) -> Self {
+        self.profile = profile;
+        self
     }
 
     /// Get the templates root directory
@@ -63,7 +76,8 @@ impl PrintService {
             self.templates_root.clone(),
             template_path,
             data,
-        )?;
+        )?
+        .with_profile(self.profile);
 
         // Compile the document
         debug!("Compiling Typst document");
@@ -164,9 +178,10 @@ impl PrintService {
         Ok(templates)
     }
 
-    /// Check if a template exists
+    /// Check if a template exists, either built in or under the templates root
     pub fn template_exists(&self, template_path: &str) -> bool {
-        self.templates_root.join(template_path).exists()
+        get_embedded_template(template_path).is_some()
+            || self.templates_root.join(template_path).exists()
     }
 }
 
@@ -266,6 +281,7 @@ This is a test document.
         let service = PrintService::new(temp.path().to_path_buf());
         assert!(service.template_exists("test/hello.typ"));
         assert!(!service.template_exists("nonexistent.typ"));
+        assert!(service.template_exists("character/classic.typ"));
     }
 
     #[test]
@@ -20,6 +20,7 @@ use typst::Library;
 
 use crate::embedded_templates::get_embedded_template;
 use crate::error::{PrintError, Result};
+use crate::profile::PrintProfile;
 
 /// Static font database loaded once
 static FONT_DB: OnceLock<(LazyHash<FontBook>, Vec<Font>)> = OnceLock::new();
@@ -117,6 +118,15 @@ impl MimirTypstWorld {
         }
     }
 
+    /// Apply an accessibility profile
+    ///
+    /// The profile is passed to Typst as `sys.inputs`, where the shared styles
+    /// pick it up.
+    pub fn with_profile(mut self, profile: PrintProfile) -> Self {
+        self.library = LazyHash::new(Library::builder().with_inputs(profile.to_inputs()).build());
+        self
+    }
+
     /// Register an in-memory file that can be accessed via a virtual path
     ///
     /// The path should be a simple name like "map_preview.png" which will be
@@ -137,14 +147,18 @@ impl MimirTypstWorld {
         template_path: &str,
         data: serde_json::Value,
     ) -> Result<Self> {
-        let full_path = templates_root.join(template_path);
-        if !full_path.exists() {
-            return Err(PrintError::TemplateNotFound(template_path.to_string()));
-        }
-
-        // Read template and inject data
-        let template_content = std::fs::read_to_string(&full_path)
-            .map_err(|_| PrintError::TemplateReadError(template_path.to_string()))?;
+        // Built-in templates ship in the binary; anything else is read from disk
+        let template_content = match get_embedded_template(template_path) {
+            Some(embedded) => embedded.to_string(),
+            None => {
+                let full_path = templates_root.join(template_path);
+                if !full_path.exists() {
+                    return Err(PrintError::TemplateNotFound(template_path.to_string()));
+                }
+                std::fs::read_to_string(&full_path)
+                    .map_err(|_| PrintError::TemplateReadError(template_path.to_string()))?
+            }
+        };
 
         let data_json = serde_json::to_string(&data)?;
         let content = format!(
@@ -303,6 +317,25 @@ mod tests {
         assert_eq!(world.main_content.unwrap(), "= Hello World");
     }
 
+    #[test]
+    fn test_profile_reaches_shared_styles() {
+        let temp = TempDir::new().unwrap();
+        let content = "#import \"/_shared/styles.typ\": *\n\
+                       #assert.eq(sizes.base, 13.5pt)\n\
+                       #assert.eq(colors.border, black)\n";
+        let world = MimirTypstWorld::from_content(content.to_string(), temp.path().to_path_buf())
+            .with_profile(PrintProfile {
+                font_scale: 1.5,
+                high_contrast: true,
+                ..Default::default()
+            });
+        assert!(typst::compile(&world).output.is_ok());
+
+        // Without a profile the standard sizes apply
+        let world = MimirTypstWorld::from_content(content.to_string(), temp.path().to_path_buf());
+        assert!(typst::compile(&world).output.is_err());
+    }
+
     #[test]
     fn test_from_template() {
         let temp = TempDir::new().unwrap();
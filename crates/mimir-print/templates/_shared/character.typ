@@ -0,0 +1,273 @@
+// Mimir Print System - Character Sheet Partials
+// Building blocks shared by the character sheet templates. Every partial
+// takes the injected character data, which is the same for all layouts.
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+
+// =============================================================================
+// DERIVED VALUES
+// =============================================================================
+
+/// Abbreviation, full name, and data key for each ability
+#let char-abilities = (
+  ("STR", "Strength", "strength"),
+  ("DEX", "Dexterity", "dexterity"),
+  ("CON", "Constitution", "constitution"),
+  ("INT", "Intelligence", "intelligence"),
+  ("WIS", "Wisdom", "wisdom"),
+  ("CHA", "Charisma", "charisma"),
+)
+
+/// The 18 skills and the ability each uses
+#let char-skill-list = (
+  ("Acrobatics", "dexterity"),
+  ("Animal Handling", "wisdom"),
+  ("Arcana", "intelligence"),
+  ("Athletics", "strength"),
+  ("Deception", "charisma"),
+  ("History", "intelligence"),
+  ("Insight", "wisdom"),
+  ("Intimidation", "charisma"),
+  ("Investigation", "intelligence"),
+  ("Medicine", "wisdom"),
+  ("Nature", "intelligence"),
+  ("Perception", "wisdom"),
+  ("Performance", "charisma"),
+  ("Persuasion", "charisma"),
+  ("Religion", "intelligence"),
+  ("Sleight of Hand", "dexterity"),
+  ("Stealth", "dexterity"),
+  ("Survival", "wisdom"),
+)
+
+/// Total level across all classes
+#let char-level(data) = data.classes.map(c => c.level).sum(default: 0)
+
+/// Proficiency bonus for the character's total level
+#let char-prof(data) = 2 + calc.floor((calc.max(char-level(data), 1) - 1) / 4)
+
+/// Ability modifier for a score
+#let char-mod(score) = calc.floor((score - 10) / 2)
+
+/// A bonus with its sign ("+3", "-1")
+#let char-signed(n) = if n >= 0 { "+" + str(n) } else { str(n) }
+
+/// Classes with subclasses and levels ("Fighter (Champion) 5 / Rogue 3")
+#let char-classes(data) = {
+  if data.classes.len() == 0 { return "No Class" }
+  data.classes.map(c => {
+    let name = if c.subclass_name != none { c.class_name + " (" + c.subclass_name + ")" } else { c.class_name }
+    name + " " + str(c.level)
+  }).join(" / ")
+}
+
+/// Saving throw bonus for one of `char-abilities`
+#let char-save(data, ability) = {
+  let (_, name, key) = ability
+  let proficient = name in data.proficiencies.saves
+  char-mod(data.at(key)) + if proficient { char-prof(data) } else { 0 }
+}
+
+/// Bonus and proficiency marker for one of `char-skill-list`
+#let char-skill(data, skill) = {
+  let (name, key) = skill
+  let entry = data.proficiencies.skills.find(p => p.name == name)
+  let prof = char-prof(data)
+  let bonus = char-mod(data.at(key)) + if entry == none { 0 } else if entry.expertise { 2 * prof } else { prof }
+  let marker = if entry == none { "○" } else if entry.expertise { "◆" } else { "●" }
+  (name: name, bonus: bonus, marker: marker, proficient: entry != none)
+}
+
+/// Passive Perception
+#let char-passive-perception(data) = 10 + char-skill(data, ("Perception", "wisdom")).bonus
+
+// =============================================================================
+// PARTIALS
+// =============================================================================
+
+/// Titled block that every layout groups its content in
+#let char-block(title, body) = block(width: 100%, breakable: true, below: spacing.md)[
+  #text(weight: "bold", size: sizes.sm, tracking: 0.5pt, fill: colors.text-secondary)[#upper(title)]
+  #v(spacing.xs, weak: true)
+  #line(length: 100%, stroke: 0.5pt + colors.border-light)
+  #v(spacing.xs, weak: true)
+  #body
+]
+
+/// Name, classes, race, background, and player
+#let char-header(data, size: sizes.title) = {
+  text(size: size, weight: "bold", font: font-heading)[#data.name]
+  linebreak()
+  let details = (char-classes(data), data.race_name, data.background_name).filter(d => d != none)
+  small-text(details.join(" · "))
+  if data.player_name != none {
+    h(1fr)
+    small-text[Player: #data.player_name]
+  }
+}
+
+/// Ability scores and modifiers in a row of boxes
+#let char-ability-row(data) = ability-scores(
+  str: data.strength,
+  dex: data.dexterity,
+  con: data.constitution,
+  int: data.intelligence,
+  wis: data.wisdom,
+  cha: data.charisma,
+  layout: "row",
+)
+
+/// AC, hit points, hit dice, speed, initiative, and proficiency bonus
+#let char-combat(data) = {
+  let stats = (
+    ("AC", str(data.ac)),
+    ("HP", str(data.hit_points_max)),
+    ("Hit Dice", data.hit_die),
+    ("Speed", str(data.speed) + " ft"),
+    ("Init", char-signed(char-mod(data.dexterity))),
+    ("Prof", char-signed(char-prof(data))),
+  )
+  grid(
+    columns: (1fr,) * stats.len(),
+    column-gutter: spacing.sm,
+    ..stats.map(((title, value)) => box(
+      width: 100%,
+      stroke: 0.5pt + colors.border,
+      inset: spacing.sm,
+      radius: 2pt,
+      align(center, labeled-value(title, value)),
+    ))
+  )
+}
+
+/// Saving throws; ● marks proficiency
+#let char-saves(data) = {
+  for ability in char-abilities {
+    let entry = [#if ability.at(1) in data.proficiencies.saves [●] else [○] #ability.at(1) #h(1fr) #char-signed(char-save(data, ability))]
+    if ability.at(1) in data.proficiencies.saves { strong(entry) } else { entry }
+    linebreak()
+  }
+}
+
+/// Skills with bonuses; ● marks proficiency and ◆ expertise
+#let char-skills(data, columns: 1, proficient-only: false) = {
+  let skills = char-skill-list.map(skill => char-skill(data, skill))
+  if proficient-only { skills = skills.filter(s => s.proficient) }
+  grid(
+    columns: layout-columns(columns),
+    column-gutter: spacing.md,
+    row-gutter: spacing.xs,
+    ..skills.map(s => {
+      let entry = [#s.marker #s.name #h(1fr) #char-signed(s.bonus)]
+      if s.proficient { strong(entry) } else { entry }
+    })
+  )
+}
+
+/// Attack and damage for each equipped weapon
+#let char-attacks(data) = {
+  let weapons = data.inventory.filter(i => i.equipped and i.damage != none)
+  if weapons.len() == 0 { return small-text[No weapons equipped] }
+
+  let prof = char-prof(data)
+  let str-mod = char-mod(data.strength)
+  let dex-mod = char-mod(data.dexterity)
+  table(
+    columns: (1fr, auto, auto),
+    stroke: none,
+    inset: (x: 0pt, y: spacing.xs),
+    column-gutter: spacing.md,
+    label-text[Weapon], label-text[Hit], label-text[Damage],
+    ..weapons.map(w => {
+      let ranged = w.item_type != none and w.item_type.starts-with("R")
+      let ability = if ranged { dex-mod } else if w.finesse { calc.max(str-mod, dex-mod) } else { str-mod }
+      let kind = if w.damage_type != none { " " + w.damage_type } else { "" }
+      (w.name, char-signed(ability + prof), w.damage + " " + char-signed(ability) + kind)
+    }).flatten()
+  )
+}
+
+/// Spellcasting ability, save DC, attack bonus, and slot boxes per level
+#let char-spellcasting(data) = {
+  if data.spellcasting_ability == none { return none }
+  [*#data.spellcasting_ability*]
+  if data.spell_save_dc != none [ #h(spacing.md) Save DC #data.spell_save_dc]
+  if data.spell_attack_bonus != none [ #h(spacing.md) Attack #char-signed(data.spell_attack_bonus)]
+  let slots = data.spell_slots.enumerate().filter(((_, count)) => count > 0)
+  if slots.len() > 0 {
+    linebreak()
+    slots.map(((level, count)) => [Level #(level + 1) #("☐" * count)]).join(h(spacing.md))
+  }
+}
+
+/// Languages, armor, weapons, and tools
+#let char-proficiencies(data) = {
+  let groups = (
+    ("Languages", data.proficiencies.languages),
+    ("Armor", data.proficiencies.armor),
+    ("Weapons", data.proficiencies.weapons),
+    ("Tools", data.proficiencies.tools),
+  ).filter(((_, items)) => items.len() > 0)
+  for (title, items) in groups [
+    *#title:* #items.join(", ") \
+  ]
+}
+
+/// Equipment; equipped items in bold, attuned items marked ✦
+#let char-inventory(data) = {
+  if data.inventory.len() == 0 { return small-text[No equipment] }
+  for item in data.inventory {
+    let name = if item.quantity > 1 { item.name + " ×" + str(item.quantity) } else { item.name }
+    let entry = if item.attuned { name + " ✦" } else { name }
+    if item.equipped { strong(entry) } else { entry }
+    linebreak()
+  }
+}
+
+/// Coins by denomination
+#let char-currency(data) = {
+  let coins = (("CP", data.cp), ("SP", data.sp), ("EP", data.ep), ("GP", data.gp), ("PP", data.pp))
+  grid(
+    columns: (1fr,) * coins.len(),
+    ..coins.map(((title, count)) => align(center, labeled-value(title, str(count))))
+  )
+}
+
+/// Personality traits, ideals, bonds, and flaws that are filled in
+#let char-personality(data) = {
+  let entries = (
+    ("Traits", data.traits),
+    ("Ideals", data.ideals),
+    ("Bonds", data.bonds),
+    ("Flaws", data.flaws),
+  ).filter(((_, value)) => value != none and value.trim() != "")
+  for (title, value) in entries [
+    #label-text(title) \
+    #value
+    #v(spacing.sm, weak: true)
+  ]
+}
+
+/// Role, location, and faction for NPCs
+#let char-npc-info(data) = {
+  let entries = (
+    ("Role", data.role),
+    ("Location", data.location),
+    ("Faction", data.faction),
+  ).filter(((_, value)) => value != none)
+  for (title, value) in entries [*#title:* #value \ ]
+}
+
+/// Campaign custom fields (house-ruled stats)
+#let char-custom-fields(data) = {
+  for entry in data.custom_fields [*#entry.label:* #entry.value \ ]
+}
+
+/// Blank ruled lines for handwritten notes
+#let char-ruled-lines(count) = {
+  for _ in range(count) {
+    v(sizes.base * 1.6)
+    line(length: 100%, stroke: 0.5pt + colors.border-light)
+  }
+}
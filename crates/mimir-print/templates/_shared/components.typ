@@ -311,23 +311,31 @@
 // LAYOUT HELPERS
 // =============================================================================
 
-/// Two-column layout
+/// Two-column layout (stacked in the simplified profile)
 #let two-columns(left, right, gutter: spacing.md) = {
-  grid(
-    columns: (1fr, 1fr),
-    column-gutter: gutter,
-    left,
-    right,
-  )
+  if profile.simplified {
+    stack(dir: ttb, spacing: gutter, left, right)
+  } else {
+    grid(
+      columns: (1fr, 1fr),
+      column-gutter: gutter,
+      left,
+      right,
+    )
+  }
 }
 
-/// Three-column layout
+/// Three-column layout (stacked in the simplified profile)
 #let three-columns(a, b, c, gutter: spacing.md) = {
-  grid(
-    columns: (1fr, 1fr, 1fr),
-    column-gutter: gutter,
-    a, b, c,
-  )
+  if profile.simplified {
+    stack(dir: ttb, spacing: gutter, a, b, c)
+  } else {
+    grid(
+      columns: (1fr, 1fr, 1fr),
+      column-gutter: gutter,
+      a, b, c,
+    )
+  }
 }
 
 /// Labeled value display (label above value)
@@ -374,6 +382,22 @@
   )
 }
 
+/// Boxed read-aloud text, set apart from DM notes
+#let read-aloud-box(body) = {
+  block(
+    width: 100%,
+    fill: colors.background-alt,
+    stroke: (left: 2pt + colors.accent),
+    inset: spacing.md,
+    radius: 2pt,
+    {
+      label-text("Read Aloud")
+      v(spacing.xs)
+      emph(body)
+    }
+  )
+}
+
 // =============================================================================
 // COMPACT MONSTER CARD (Inline format for documents)
 // =============================================================================
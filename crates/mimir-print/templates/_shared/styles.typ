@@ -1,6 +1,19 @@
 // Mimir Print System - Shared Styles
 // Base typography, page settings, and visual constants for all templates
 
+// =============================================================================
+// PRINT PROFILE
+// =============================================================================
+// Accessibility options passed in by the renderer as sys.inputs (see
+// PrintProfile). Documents compiled without them get the standard look.
+
+#let profile = (
+  font-scale: sys.inputs.at("font-scale", default: 1.0),
+  high-contrast: sys.inputs.at("high-contrast", default: false),
+  dyslexia-font: sys.inputs.at("dyslexia-font", default: false),
+  simplified: sys.inputs.at("simplified", default: false),
+)
+
 // =============================================================================
 // COLOR PALETTE
 // =============================================================================
@@ -8,11 +21,11 @@
 
 #let colors = (
   text: black,
-  text-secondary: luma(60),
-  border: luma(40),
-  border-light: luma(80),
-  background-alt: luma(245),
-  accent: luma(30),
+  text-secondary: if profile.high-contrast { black } else { luma(60) },
+  border: if profile.high-contrast { black } else { luma(40) },
+  border-light: if profile.high-contrast { black } else { luma(80) },
+  background-alt: if profile.high-contrast or profile.simplified { white } else { luma(245) },
+  accent: if profile.high-contrast { black } else { luma(30) },
 )
 
 // =============================================================================
@@ -20,20 +33,22 @@
 // =============================================================================
 
 // Font stacks (with fallbacks)
-#let font-heading = ("Inter", "Helvetica", "Arial", "sans-serif")
-#let font-body = ("Inter", "Helvetica", "Arial", "sans-serif")
+#let font-standard = ("Inter", "Helvetica", "Arial", "sans-serif")
+#let font-dyslexia = ("OpenDyslexic", "Atkinson Hyperlegible", "Lexend", "Verdana", "sans-serif")
+#let font-heading = if profile.dyslexia-font { font-dyslexia } else { font-standard }
+#let font-body = if profile.dyslexia-font { font-dyslexia } else { font-standard }
 #let font-mono = ("JetBrains Mono", "Consolas", "monospace")
 
 // Text size scale
 #let sizes = (
-  xs: 7pt,
-  sm: 8pt,
-  base: 9pt,
-  md: 10pt,
-  lg: 12pt,
-  xl: 14pt,
-  xxl: 18pt,
-  title: 24pt,
+  xs: 7pt * profile.font-scale,
+  sm: 8pt * profile.font-scale,
+  base: 9pt * profile.font-scale,
+  md: 10pt * profile.font-scale,
+  lg: 12pt * profile.font-scale,
+  xl: 14pt * profile.font-scale,
+  xxl: 18pt * profile.font-scale,
+  title: 24pt * profile.font-scale,
 )
 
 // =============================================================================
@@ -165,6 +180,10 @@
   xxl: 24pt,
 )
 
+/// Equal columns for a page layout, or a single column in the simplified
+/// profile so the cells stack
+#let layout-columns(count) = if profile.simplified { (1fr,) } else { (1fr,) * count }
+
 // =============================================================================
 // HORIZONTAL RULES
 // =============================================================================
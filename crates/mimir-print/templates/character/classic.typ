@@ -0,0 +1,80 @@
+// Mimir Character Sheet - Classic
+// Three pages: statistics, equipment and personality, spellcasting and notes
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+#import "/_shared/character.typ": *
+
+#show: mimir-doc.with(margin: 0.5in)
+
+// =============================================================================
+// PAGE 1: STATISTICS
+// =============================================================================
+
+#char-header(data)
+#v(spacing.md)
+#char-combat(data)
+#v(spacing.md)
+
+#grid(
+  columns: if profile.simplified { (1fr,) } else { (0.9in, 1fr, 1fr) },
+  column-gutter: spacing.lg,
+  row-gutter: spacing.md,
+  stack(
+    dir: ttb,
+    spacing: spacing.sm,
+    ..char-abilities.map(a => ability-box(a.at(0), data.at(a.at(2)))),
+  ),
+  [
+    #char-block("Saving Throws", char-saves(data))
+    #char-block("Skills", char-skills(data))
+  ],
+  [
+    #char-block("Attacks", char-attacks(data))
+    #char-block("Proficiencies", char-proficiencies(data))
+    #char-block("Passive Perception")[#char-passive-perception(data)]
+  ],
+)
+
+// =============================================================================
+// PAGE 2: EQUIPMENT AND PERSONALITY
+// =============================================================================
+
+#pagebreak()
+
+#char-header(data, size: sizes.xl)
+#v(spacing.md)
+
+#grid(
+  columns: layout-columns(2),
+  column-gutter: spacing.lg,
+  [
+    #char-block("Currency", char-currency(data))
+    #char-block("Equipment", char-inventory(data))
+  ],
+  [
+    #char-block("Personality", char-personality(data))
+    #if data.is_npc {
+      char-block("NPC", char-npc-info(data))
+    }
+    #if data.custom_fields.len() > 0 {
+      char-block("Campaign Fields", char-custom-fields(data))
+    }
+  ],
+)
+
+// =============================================================================
+// PAGE 3: SPELLCASTING AND NOTES
+// =============================================================================
+
+#pagebreak()
+
+#char-header(data, size: sizes.xl)
+#v(spacing.md)
+
+#if data.spellcasting_ability != none {
+  char-block("Spellcasting", char-spellcasting(data))
+  char-block("Spells Known")[#char-ruled-lines(12)]
+}
+
+#char-block("Notes")[#char-ruled-lines(if data.spellcasting_ability != none { 12 } else { 28 })]
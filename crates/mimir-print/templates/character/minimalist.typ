@@ -0,0 +1,33 @@
+// Mimir Character Sheet - Minimalist
+// Everything needed at the table on a single page
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+#import "/_shared/character.typ": *
+
+#show: mimir-doc.with(margin: 0.5in)
+
+#char-header(data)
+#v(spacing.md)
+#char-ability-row(data)
+#v(spacing.sm)
+#char-combat(data)
+#v(spacing.md)
+
+#grid(
+  columns: layout-columns(2),
+  column-gutter: spacing.lg,
+  [
+    #char-block("Saving Throws", char-saves(data))
+    #char-block("Skills", char-skills(data))
+  ],
+  [
+    #char-block("Attacks", char-attacks(data))
+    #if data.spellcasting_ability != none {
+      char-block("Spellcasting", char-spellcasting(data))
+    }
+    #char-block("Proficiencies", char-proficiencies(data))
+    #char-block("Equipment", char-inventory(data))
+    #char-block("Passive Perception")[#char-passive-perception(data)]
+  ],
+)
@@ -0,0 +1,39 @@
+// Mimir Character Sheet - NPC Card
+// Half-page card for running an NPC: stats, proficient skills, and roleplay
+
+#import "/_shared/styles.typ": *
+#import "/_shared/components.typ": *
+#import "/_shared/character.typ": *
+
+#show: mimir-doc.with(margin: 0.4in)
+#set page(width: 8.5in, height: 5.5in)
+
+#char-header(data, size: sizes.xxl)
+#let npc-details = (data.role, data.location, data.faction).filter(v => v != none)
+#if npc-details.len() > 0 [
+  #v(spacing.xs)
+  #small-text(npc-details.join(" · "))
+]
+#v(spacing.sm)
+#char-ability-row(data)
+#v(spacing.sm)
+#char-combat(data)
+#v(spacing.sm)
+
+#grid(
+  columns: layout-columns(2),
+  column-gutter: spacing.lg,
+  [
+    #char-block("Skills", char-skills(data, columns: 2, proficient-only: true))
+    #char-block("Attacks", char-attacks(data))
+    #if data.spellcasting_ability != none {
+      char-block("Spellcasting", char-spellcasting(data))
+    }
+  ],
+  [
+    #char-block("Personality", char-personality(data))
+    #if data.proficiencies.languages.len() > 0 {
+      char-block("Languages", data.proficiencies.languages.join(", "))
+    }
+  ],
+)
@@ -53,11 +53,31 @@ const routes: RouteRecordRaw[] = [
         name: 'dashboard-pcs',
         component: () => import('../../features/campaigns/components/dashboard/PCsTab.vue')
       },
+      {
+        path: 'rumors',
+        name: 'dashboard-rumors',
+        component: () => import('../../features/campaigns/components/dashboard/RumorsTab.vue')
+      },
+      {
+        path: 'handouts',
+        name: 'dashboard-handouts',
+        component: () => import('../../features/campaigns/components/dashboard/HandoutsTab.vue')
+      },
+      {
+        path: 'secrets',
+        name: 'dashboard-secrets',
+        component: () => import('../../features/campaigns/components/dashboard/SecretsTab.vue')
+      },
       {
         path: 'homebrew',
         name: 'dashboard-homebrew',
         component: () => import('../../features/campaigns/components/dashboard/HomebrewTab.vue')
       },
+      {
+        path: 'stats',
+        name: 'dashboard-stats',
+        component: () => import('../../features/campaigns/components/dashboard/StatsTab.vue')
+      },
       {
         path: 'modules/:moduleId/play',
         name: 'dashboard-play',
@@ -101,6 +121,13 @@ const routes: RouteRecordRaw[] = [
     name: 'player-display',
     component: () => import('../../components/PlayerDisplayWindow.vue'),
     meta: { hideNav: true, isPlayerDisplay: true }
+  },
+  // DM Reference Window (pinned panels beside the play view)
+  {
+    path: '/dm-reference',
+    name: 'dm-reference',
+    component: () => import('../../components/DmReferenceWindow.vue'),
+    meta: { hideNav: true }
   }
 ]
 
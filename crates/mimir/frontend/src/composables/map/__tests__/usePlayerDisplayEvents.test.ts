@@ -32,9 +32,15 @@ import type {
   FogUpdatePayload,
   LightSourcesUpdatePayload,
   MarkersUpdatePayload,
+  ReadAloudPayload,
+  MonsterDisplayPayload,
+  HandoutPayload,
+  InitiativeDisplayPayload,
+  DisplayProfilePayload,
   PlayerDisplayEventHandlers,
 } from '../usePlayerDisplayEvents'
 import { usePlayerDisplayEvents } from '../usePlayerDisplayEvents'
+import type { TimersSnapshot } from '@/types/api'
 
 function createHandlerSpies(): PlayerDisplayEventHandlers {
   return {
@@ -44,6 +50,13 @@ function createHandlerSpies(): PlayerDisplayEventHandlers {
     onFogUpdate: vi.fn(),
     onLightSourcesUpdate: vi.fn(),
     onMarkersUpdate: vi.fn(),
+    onReadAloud: vi.fn(),
+    onMonster: vi.fn(),
+    onHandout: vi.fn(),
+    onHandoutClear: vi.fn(),
+    onInitiative: vi.fn(),
+    onTimers: vi.fn(),
+    onProfile: vi.fn(),
   }
 }
 
@@ -68,7 +81,7 @@ describe('usePlayerDisplayEvents', () => {
   })
 
   describe('event registration', () => {
-    it('registers all 6 event listeners on mount', async () => {
+    it('registers all 13 event listeners on mount', async () => {
       const handlers = createHandlerSpies()
       mountWithHandlers(handlers)
       await flushPromises()
@@ -79,14 +92,21 @@ describe('usePlayerDisplayEvents', () => {
       expect(mockListeners.has('player-display:fog-update')).toBe(true)
       expect(mockListeners.has('player-display:light-sources-update')).toBe(true)
       expect(mockListeners.has('player-display:markers-update')).toBe(true)
+      expect(mockListeners.has('player-display:read-aloud')).toBe(true)
+      expect(mockListeners.has('player-display:monster')).toBe(true)
+      expect(mockListeners.has('player-display:handout')).toBe(true)
+      expect(mockListeners.has('player-display:handout-clear')).toBe(true)
+      expect(mockListeners.has('player-display:initiative')).toBe(true)
+      expect(mockListeners.has('player-display:profile')).toBe(true)
+      expect(mockListeners.has('timers:update')).toBe(true)
     })
 
-    it('creates 6 unlisten functions', async () => {
+    it('creates 13 unlisten functions', async () => {
       const handlers = createHandlerSpies()
       mountWithHandlers(handlers)
       await flushPromises()
 
-      expect(mockUnlistenFns.length).toBe(6)
+      expect(mockUnlistenFns.length).toBe(13)
     })
   })
 
@@ -192,6 +212,111 @@ describe('usePlayerDisplayEvents', () => {
 
       expect(handlers.onMarkersUpdate).toHaveBeenCalledWith(payload)
     })
+
+    it('dispatches read-aloud text and its clear to handlers', async () => {
+      const handlers = createHandlerSpies()
+      mountWithHandlers(handlers)
+      await flushPromises()
+
+      const payload: ReadAloudPayload = {
+        title: 'The Sunken Crypt',
+        paragraphs: ['The door creaks open.'],
+      }
+      mockListeners.get('player-display:read-aloud')!({ payload })
+      expect(handlers.onReadAloud).toHaveBeenCalledWith(payload)
+
+      mockListeners.get('player-display:handout-clear')!({ payload: null })
+      expect(handlers.onHandoutClear).toHaveBeenCalled()
+    })
+
+    it('dispatches monster cards to handler', async () => {
+      const handlers = createHandlerSpies()
+      mountWithHandlers(handlers)
+      await flushPromises()
+
+      const payload: MonsterDisplayPayload = {
+        name: 'Owlbear',
+        size: 'Large',
+        imageUrl: null,
+      }
+      mockListeners.get('player-display:monster')!({ payload })
+      expect(handlers.onMonster).toHaveBeenCalledWith(payload)
+    })
+
+    it('dispatches image handouts to handler', async () => {
+      const handlers = createHandlerSpies()
+      mountWithHandlers(handlers)
+      await flushPromises()
+
+      const payload: HandoutPayload = {
+        kind: 'handout',
+        title: "The Baron's Letter",
+        imageUrl: 'data:image/png;base64,AAAA',
+      }
+      mockListeners.get('player-display:handout')!({ payload })
+      expect(handlers.onHandout).toHaveBeenCalledWith(payload)
+    })
+
+    it('dispatches initiative order to handler', async () => {
+      const handlers = createHandlerSpies()
+      mountWithHandlers(handlers)
+      await flushPromises()
+
+      const payload: InitiativeDisplayPayload = {
+        combatants: [
+          { name: 'Aria', portraitUrl: 'data:image/png;base64,AAAA' },
+          { name: '???', portraitUrl: null },
+          { name: 'Bram', portraitUrl: null },
+        ],
+        current: 1,
+        round: 2,
+      }
+      mockListeners.get('player-display:initiative')!({ payload })
+      expect(handlers.onInitiative).toHaveBeenCalledWith(payload)
+    })
+
+    it('dispatches table timers to handler', async () => {
+      const handlers = createHandlerSpies()
+      mountWithHandlers(handlers)
+      await flushPromises()
+
+      const stopped = {
+        running: false,
+        elapsedSecs: 0,
+        limitSecs: null,
+        remainingSecs: null,
+        expired: false,
+        label: null,
+        runningSince: null,
+      }
+      const payload: TimersSnapshot = {
+        turn: { ...stopped, running: true, elapsedSecs: 12, limitSecs: 60, remainingSecs: 48, label: 'Aria' },
+        break: stopped,
+        session: { ...stopped, running: true, elapsedSecs: 3600 },
+        sessionId: 'session-1',
+        totals: { play_seconds: 3600, break_seconds: 0, combat_turns: 4, combat_turn_seconds: 180, longest_turn_seconds: 75 },
+      }
+      mockListeners.get('timers:update')!({ payload })
+      expect(handlers.onTimers).toHaveBeenCalledWith(payload)
+    })
+
+    it('dispatches the assigned display profile to handler', async () => {
+      const handlers = createHandlerSpies()
+      mountWithHandlers(handlers)
+      await flushPromises()
+
+      const payload: DisplayProfilePayload = {
+        name: 'Table TV',
+        monitorName: 'HDMI-1',
+        rotation: 180,
+        zoomLocked: true,
+        zoom: 1.5,
+        letterboxColor: '#112233',
+        fullscreen: true,
+      }
+      mockListeners.get('player-display:profile')!({ payload })
+      expect(handlers.onProfile).toHaveBeenCalledWith(payload)
+    })
   })
 
   describe('cleanup', () => {
@@ -200,7 +325,7 @@ describe('usePlayerDisplayEvents', () => {
       const wrapper = mountWithHandlers(handlers)
       await flushPromises()
 
-      expect(mockUnlistenFns.length).toBe(6)
+      expect(mockUnlistenFns.length).toBe(13)
 
       wrapper.unmount()
 
@@ -5,7 +5,7 @@
  * mouse interaction handlers, zoom clamping, and reset behavior.
  */
 
-import { describe, it, expect } from 'vitest'
+import { describe, it, expect, vi } from 'vitest'
 import { usePlayerViewport } from '../usePlayerViewport'
 
 describe('usePlayerViewport', () => {
@@ -251,6 +251,21 @@ describe('usePlayerViewport', () => {
       expect(viewport.imageNaturalWidth.value).toBe(1920)
       expect(viewport.imageNaturalHeight.value).toBe(1080)
     })
+
+    it('swaps window dimensions for a quarter-turned display', () => {
+      vi.spyOn(window, 'innerWidth', 'get').mockReturnValue(1000)
+      vi.spyOn(window, 'innerHeight', 'get').mockReturnValue(500)
+      const img = { naturalWidth: 2000, naturalHeight: 500 } as HTMLImageElement
+
+      const viewport = usePlayerViewport()
+      viewport.updateDisplayScale(img)
+      expect(viewport.displayScale.value).toBe(1)
+
+      viewport.updateDisplayScale(img, true)
+      expect(viewport.displayScale.value).toBe(2)
+
+      vi.restoreAllMocks()
+    })
   })
 
   describe('custom options', () => {
@@ -16,6 +16,9 @@ export interface MapTrap {
   effect_description: string | null
   dc: number | null
   visible: number
+  /** Catalog trap the text was copied from */
+  catalog_name?: string | null
+  catalog_source?: string | null
   created_at: string
   updated_at: string
 }
@@ -35,6 +38,10 @@ export interface MapPoi {
   visible: number
   created_at: string
   updated_at: string
+  /** Document the POI links to, e.g. its location key */
+  document_id: string | null
+  /** Heading within the document the link opens at */
+  document_section: string | null
 }
 
 interface UseMapMarkersOptions {
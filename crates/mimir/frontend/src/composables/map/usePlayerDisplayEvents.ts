@@ -1,6 +1,6 @@
 import { onMounted, onUnmounted } from 'vue'
 import { listen, emit, type UnlistenFn } from '@tauri-apps/api/event'
-import type { Token } from '@/types/api'
+import type { TimersSnapshot, Token } from '@/types/api'
 import type { LightSourceSummary } from '@/composables/map/useLightSources'
 import type { Light, Wall, Portal } from '@/composables/map/useVisibilityPolygon'
 import type { AmbientLight } from '@/composables/map/useVisionCalculation'
@@ -62,6 +62,68 @@ export interface MarkersUpdatePayload {
   gridSizePx: number
 }
 
+/**
+ * Boxed read-aloud passage sent from a document
+ */
+export interface ReadAloudPayload {
+  title: string | null
+  paragraphs: string[]
+}
+
+/**
+ * Player-safe monster card: art, name, and size, never statistics
+ */
+export interface MonsterDisplayPayload {
+  name: string
+  size: string
+  /** Monster art as a data URL, if any was imported */
+  imageUrl: string | null
+}
+
+/**
+ * Image handout (NPC portrait, item art, or a queued handout) shown to players
+ */
+export interface HandoutPayload {
+  kind: string
+  title: string | null
+  /** Image as a data URL */
+  imageUrl: string
+}
+
+/**
+ * Turn order sent from the initiative tracker; an empty list hides it
+ */
+export interface InitiativeDisplayPayload {
+  combatants: InitiativeDisplayCombatant[]
+  current: number | null
+  round: number
+}
+
+/**
+ * A combatant as players see them; hidden monsters are "???" with no portrait
+ */
+export interface InitiativeDisplayCombatant {
+  name: string
+  /** Token art as a data URL */
+  portraitUrl: string | null
+}
+
+/**
+ * Layout profile assigned to this display screen (see display_profile.rs)
+ */
+export interface DisplayProfilePayload {
+  name: string
+  monitorName: string | null
+  /** Clockwise rotation in degrees (0, 90, 180, 270) */
+  rotation: number
+  zoomLocked: boolean
+  /** Fixed zoom level used while zoom is locked */
+  zoom: number | null
+  /** Fill color for the bars around the map (hex) */
+  letterboxColor: string
+  fullscreen: boolean
+}
+
 /**
  * Event handlers for player display IPC events
  */
@@ -72,12 +134,37 @@ export interface PlayerDisplayEventHandlers {
   onFogUpdate: (payload: FogUpdatePayload) => void
   onLightSourcesUpdate: (payload: LightSourcesUpdatePayload) => void
   onMarkersUpdate: (payload: MarkersUpdatePayload) => void
+  onReadAloud: (payload: ReadAloudPayload) => void
+  onMonster: (payload: MonsterDisplayPayload) => void
+  onHandout: (payload: HandoutPayload) => void
+  onHandoutClear: () => void
+  onInitiative: (payload: InitiativeDisplayPayload) => void
+  onTimers: (payload: TimersSnapshot) => void
+  onProfile: (payload: DisplayProfilePayload) => void
+}
+
+/**
+ * Event namespace for the current display window.
+ *
+ * The backend prefixes events with the target window label, so the secondary
+ * display only receives what was sent to it.
+ */
+function displayEventPrefix(): string {
+  return displayScreen() === 'secondary' ? 'player-display-secondary' : 'player-display'
+}
+
+/**
+ * Screen this display window was opened for, from its `screen` query parameter
+ */
+export function displayScreen(): 'primary' | 'secondary' {
+  const screen = new URLSearchParams(window.location.search).get('screen')
+  return screen === 'secondary' ? 'secondary' : 'primary'
 }
 
 /**
  * Composable for managing player display IPC event listeners.
  *
- * Consolidates 6 IPC event listeners into a single setup/cleanup pattern.
+ * Consolidates 13 IPC event listeners into a single setup/cleanup pattern.
  * Use in conjunction with onMounted to register handlers and auto-cleanup on unmount.
  *
  * @example
@@ -99,10 +186,11 @@ export function usePlayerDisplayEvents(handlers: PlayerDisplayEventHandlers): vo
 
   onMounted(async () => {
     console.log('usePlayerDisplayEvents: Setting up event listeners')
+    const prefix = displayEventPrefix()
 
     // Map update listener
     unlisteners.push(
-      await listen<MapUpdatePayload>('player-display:map-update', async (event) => {
+      await listen<MapUpdatePayload>(`${prefix}:map-update`, async (event) => {
         console.log('usePlayerDisplayEvents: Received map-update', event.payload.mapId)
         await handlers.onMapUpdate(event.payload)
         // Request current state from DM window
@@ -112,14 +200,14 @@ export function usePlayerDisplayEvents(handlers: PlayerDisplayEventHandlers): vo
 
     // Blackout listener
     unlisteners.push(
-      await listen<{ isBlackout: boolean }>('player-display:blackout', (event) => {
+      await listen<{ isBlackout: boolean }>(`${prefix}:blackout`, (event) => {
         handlers.onBlackout(event.payload.isBlackout)
       })
     )
 
     // Tokens update listener
     unlisteners.push(
-      await listen<TokensUpdatePayload>('player-display:tokens-update', async (event) => {
+      await listen<TokensUpdatePayload>(`${prefix}:tokens-update`, async (event) => {
         console.log('usePlayerDisplayEvents: Received tokens-update', event.payload.tokens.length, 'tokens')
         await handlers.onTokensUpdate(event.payload)
       })
@@ -127,24 +215,73 @@ export function usePlayerDisplayEvents(handlers: PlayerDisplayEventHandlers): vo
 
     // Fog/LOS update listener
     unlisteners.push(
-      await listen<FogUpdatePayload>('player-display:fog-update', (event) => {
+      await listen<FogUpdatePayload>(`${prefix}:fog-update`, (event) => {
         handlers.onFogUpdate(event.payload)
       })
     )
 
     // Light sources update listener
     unlisteners.push(
-      await listen<LightSourcesUpdatePayload>('player-display:light-sources-update', (event) => {
+      await listen<LightSourcesUpdatePayload>(`${prefix}:light-sources-update`, (event) => {
         handlers.onLightSourcesUpdate(event.payload)
       })
     )
 
     // Markers update listener
     unlisteners.push(
-      await listen<MarkersUpdatePayload>('player-display:markers-update', (event) => {
+      await listen<MarkersUpdatePayload>(`${prefix}:markers-update`, (event) => {
         handlers.onMarkersUpdate(event.payload)
       })
     )
+
+    // Read-aloud text listener
+    unlisteners.push(
+      await listen<ReadAloudPayload>(`${prefix}:read-aloud`, (event) => {
+        handlers.onReadAloud(event.payload)
+      })
+    )
+
+    // Monster card listener
+    unlisteners.push(
+      await listen<MonsterDisplayPayload>(`${prefix}:monster`, (event) => {
+        handlers.onMonster(event.payload)
+      })
+    )
+
+    // Image handout listener
+    unlisteners.push(
+      await listen<HandoutPayload>(`${prefix}:handout`, (event) => {
+        handlers.onHandout(event.payload)
+      })
+    )
+
+    // Handout clear dismisses handouts and read-aloud text
+    unlisteners.push(
+      await listen(`${prefix}:handout-clear`, () => {
+        handlers.onHandoutClear()
+      })
+    )
+
+    // Initiative order listener
+    unlisteners.push(
+      await listen<InitiativeDisplayPayload>(`${prefix}:initiative`, (event) => {
+        handlers.onInitiative(event.payload)
+      })
+    )
+
+    // Layout profile listener (sent when a profile is assigned or edited)
+    unlisteners.push(
+      await listen<DisplayProfilePayload>(`${prefix}:profile`, (event) => {
+        handlers.onProfile(event.payload)
+      })
+    )
+
+    // Table timers are shared by every window, so the event is not prefixed
+    unlisteners.push(
+      await listen<TimersSnapshot>('timers:update', (event) => {
+        handlers.onTimers(event.payload)
+      })
+    )
   })
 
   onUnmounted(() => {
@@ -35,7 +35,7 @@ export interface PlayerViewportReturn {
   /** Handle wheel for zooming */
   handleWheel: (event: WheelEvent) => void
   /** Update display scale based on image and viewport dimensions */
-  updateDisplayScale: (imageEl: HTMLImageElement | null) => void
+  updateDisplayScale: (imageEl: HTMLImageElement | null, quarterTurn?: boolean) => void
   /** Reset viewport to default (fit to screen) */
   reset: () => void
 }
@@ -143,9 +143,12 @@ export function usePlayerViewport(options: PlayerViewportOptions = {}): PlayerVi
   }
 
   /**
-   * Update display scale to fit image in viewport
+   * Update display scale to fit image in viewport.
+   *
+   * A display rotated by 90 or 270 degrees (`quarterTurn`) lays the map out
+   * against the swapped window dimensions.
    */
-  function updateDisplayScale(imageEl: HTMLImageElement | null): void {
+  function updateDisplayScale(imageEl: HTMLImageElement | null, quarterTurn = false): void {
     if (!imageEl) return
 
     const naturalWidth = imageEl.naturalWidth
@@ -158,8 +161,8 @@ export function usePlayerViewport(options: PlayerViewportOptions = {}): PlayerVi
     imageNaturalHeight.value = naturalHeight
 
     // Get viewport dimensions
-    const viewportWidth = window.innerWidth
-    const viewportHeight = window.innerHeight
+    const viewportWidth = quarterTurn ? window.innerHeight : window.innerWidth
+    const viewportHeight = quarterTurn ? window.innerWidth : window.innerHeight
 
     // Calculate scale to fill (cover) - use larger scale for no black bars
     const scaleX = viewportWidth / naturalWidth
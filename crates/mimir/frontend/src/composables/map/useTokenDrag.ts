@@ -27,6 +27,7 @@ interface BackendToken {
   vision_dim_ft: number | null
   vision_dark_ft: number
   light_radius_ft: number
+  mob_size: number | null
   created_at: string
   updated_at: string
 }
@@ -55,6 +56,7 @@ function transformToken(backendToken: BackendToken): Token {
     vision_dim_ft: backendToken.vision_dim_ft,
     vision_dark_ft: backendToken.vision_dark_ft,
     light_radius_ft: backendToken.light_radius_ft,
+    mob_size: backendToken.mob_size ?? null,
     created_at: backendToken.created_at,
     updated_at: backendToken.updated_at
   }
@@ -217,9 +217,10 @@ export function useTokens(mapId: string) {
     }
   }
 
-  // Load images for all monster tokens (convention-based paths on backend)
+  // Load images for monster tokens (convention-based paths on backend) and
+  // PC tokens whose character has a token image
   async function loadAllTokenImages(): Promise<void> {
-    const tokensWithImages = tokens.value.filter(t => t.token_type === 'monster')
+    const tokensWithImages = tokens.value.filter(t => t.token_type === 'monster' || t.image_path)
     await Promise.all(tokensWithImages.map(t => loadTokenImage(t.id)))
   }
 
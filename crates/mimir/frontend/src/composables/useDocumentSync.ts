@@ -0,0 +1,51 @@
+/**
+ * Composable forwarding watch-mode syncs to the data event bus.
+ *
+ * While a campaign folder is watched, the backend applies edits made in
+ * external editors and emits `documents:synced`. This re-emits each change
+ * as a `documents:synced` data event so open document lists refresh.
+ */
+
+import { onMounted, onUnmounted } from 'vue'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import { dataEvents, type DocumentSyncKind } from '@/utils/dataEvents'
+
+/** A document change applied from a watched folder (mirrors `SyncedChange`) */
+interface SyncedChange {
+  kind: DocumentSyncKind
+  campaign_id: string
+  document_id: string
+  /** File path relative to the export root */
+  path: string
+}
+
+export function useDocumentSync() {
+  let unlisten: UnlistenFn | null = null
+
+  onMounted(async () => {
+    try {
+      unlisten = await listen<SyncedChange>('documents:synced', (event) => {
+        const change = event.payload
+        if (change.kind === 'conflicted') {
+          console.warn(
+            `${change.path} and its document both changed since the export; the file was not applied`
+          )
+        }
+        dataEvents.emit('documents:synced', {
+          campaignId: change.campaign_id,
+          documentId: change.document_id,
+          kind: change.kind
+        })
+      })
+    } catch (e) {
+      console.error('Failed to set up document sync listener:', e)
+    }
+  })
+
+  onUnmounted(() => {
+    if (unlisten) {
+      unlisten()
+      unlisten = null
+    }
+  })
+}
@@ -242,4 +242,19 @@ describe('usePlayerDisplay', () => {
       await expect(pd.toggleFullscreen()).rejects.toThrow('not supported')
     })
   })
+
+  describe('read-aloud', () => {
+    it('sends a document block by index', async () => {
+      const { invoke: freshInvoke } = await import('@tauri-apps/api/core')
+      vi.mocked(freshInvoke).mockResolvedValueOnce(undefined)
+
+      const pd = await getUsePlayerDisplay()
+      await pd.sendReadAloudToDisplay('doc-1', 2)
+
+      expect(freshInvoke).toHaveBeenCalledWith('send_read_aloud_to_display', {
+        documentId: 'doc-1',
+        blockIndex: 2,
+      })
+    })
+  })
 })
@@ -138,6 +138,58 @@ async function setBlackout(blackout: boolean): Promise<void> {
   }
 }
 
+/**
+ * Send a player-safe monster card (art, name, size) to the player display
+ */
+async function sendMonsterToDisplay(monsterId: number): Promise<void> {
+  try {
+    await invoke('send_monster_to_display', { monsterId })
+  } catch (err) {
+    console.error('Failed to send monster to display:', err)
+    throw err
+  }
+}
+
+/**
+ * Send an NPC portrait or item art handout to the player display
+ */
+async function sendHandoutToDisplay(
+  kind: 'npc_portrait' | 'item_art',
+  imagePath: string,
+  title: string | null = null
+): Promise<void> {
+  try {
+    await invoke('send_handout_to_display', { kind, imagePath, title })
+  } catch (err) {
+    console.error('Failed to send handout to display:', err)
+    throw err
+  }
+}
+
+/**
+ * Send one boxed read-aloud block of a document to the player display
+ */
+async function sendReadAloudToDisplay(documentId: string, blockIndex: number): Promise<void> {
+  try {
+    await invoke('send_read_aloud_to_display', { documentId, blockIndex })
+  } catch (err) {
+    console.error('Failed to send read-aloud text to display:', err)
+    throw err
+  }
+}
+
+/**
+ * Clear any monster card, handout, or read-aloud text shown on the player display
+ */
+async function clearHandout(): Promise<void> {
+  try {
+    await invoke('clear_display_handout')
+  } catch (err) {
+    console.error('Failed to clear handout:', err)
+    throw err
+  }
+}
+
 /**
  * Composable for player display control
  */
@@ -156,7 +208,11 @@ export function usePlayerDisplay() {
     toggleFullscreen,
     sendMapToDisplay,
     toggleBlackout,
-    setBlackout
+    setBlackout,
+    sendMonsterToDisplay,
+    sendHandoutToDisplay,
+    sendReadAloudToDisplay,
+    clearHandout
   }
 }
 
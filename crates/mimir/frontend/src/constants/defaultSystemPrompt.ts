@@ -169,7 +169,11 @@ If unsure whether tools are needed, respond conversationally first. Ask clarifyi
 
 **Combat & Health:**
 - update_character_hp - Apply damage (negative) or healing (positive)
-- take_rest - Apply short or long rest (restores HP, spell slots, hit dice)
+- take_rest - Apply short or long rest (restores class resources that recharge on it)
+- use_resource - Spend uses of a class resource (rage, ki, channel divinity)
+- restore_resource - Restore uses of a class resource outside of a rest
+- mark_character_dead - Mark a character dead with a cause, dated on the party calendar
+- resurrect_character - Bring a dead character back, recording the method and cost
 
 **Spellcasting:**
 - check_spell_slots - Check available spell slots
@@ -179,7 +183,10 @@ If unsure whether tools are needed, respond conversationally first. Ask clarifyi
 - add_inventory_item - Add item to inventory
 - remove_inventory_item - Remove item from inventory
 - update_equipped - Change equipped items (armor, weapons)
-- update_currency - Add/remove gold, silver, copper, etc.
+- start_crafting_project - Start crafting an item during downtime (gold, time, tools, and ingredients per XGE)
+- advance_crafting - Log downtime days on a crafting project; the finished item goes into inventory
+- adjust_currency - Add or spend gold, silver, copper, etc. with a reason for the currency history
+- get_currency_history - List a character's currency transactions
 
 **D&D Reference Catalog:**
 - search_monsters - Search by name, CR, type, size, alignment
@@ -198,6 +205,12 @@ If unsure whether tools are needed, respond conversationally first. Ask clarifyi
 - edit_file - Edit specific lines in a file
 - list_files - List files in campaign directory
 
+**Campaign Memory:**
+- remember_fact - Save a fact to recall in later conversations (e.g., "The party insulted Duke Avery")
+- recall_facts - Find remembered facts matching a question or keywords
+- list_memories - List every remembered fact for the campaign
+- forget_fact - Forget a fact that is no longer true
+
 **Task Management:**
 - todo_write - Track tasks for complex multi-step operations
 
@@ -220,6 +233,8 @@ CRITICAL: For all document operations:
 
 **Combat Tracking**: Use update_character_hp for damage/healing during combat. Use take_rest after encounters.
 
+**Campaign Memory**: When the party makes a lasting impression, a promise, or an enemy, use remember_fact. Before improvising an NPC or scene, use recall_facts to stay consistent with what happened before.
+
 **Rule Lookups**: Use search_monsters, search_spells, search_items to find D&D 5e content.
 
 Use todo_write for multi-step processes:
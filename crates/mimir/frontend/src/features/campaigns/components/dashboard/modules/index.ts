@@ -2,6 +2,7 @@ export { default as ModuleList } from './ModuleList.vue'
 export { default as ModuleDocumentsPanel } from './ModuleDocumentsPanel.vue'
 export { default as ModuleMapsPanel } from './ModuleMapsPanel.vue'
 export { default as ModuleNpcsPanel } from './ModuleNpcsPanel.vue'
+export { default as ModuleEffectsPanel } from './ModuleEffectsPanel.vue'
 
 // Re-export types
 export type { ModuleNpc } from './ModuleNpcsPanel.vue'
@@ -1,7 +1,7 @@
 import { ref, computed, watch } from 'vue'
 import { useRoute, useRouter } from 'vue-router'
 
-export type DashboardTab = 'campaign' | 'modules' | 'npcs' | 'pcs' | 'homebrew'
+export type DashboardTab = 'campaign' | 'modules' | 'npcs' | 'pcs' | 'rumors' | 'handouts' | 'secrets' | 'homebrew' | 'stats'
 
 export interface DashboardTabConfig {
   id: DashboardTab
@@ -15,7 +15,11 @@ export const dashboardTabs: DashboardTabConfig[] = [
   { id: 'modules', label: 'Modules', icon: 'folder', route: 'modules' },
   { id: 'npcs', label: 'NPCs', icon: 'users', route: 'npcs' },
   { id: 'pcs', label: 'PCs', icon: 'user', route: 'pcs' },
-  { id: 'homebrew', label: 'Homebrew', icon: 'flask', route: 'homebrew' }
+  { id: 'rumors', label: 'Rumors', icon: 'rumor', route: 'rumors' },
+  { id: 'handouts', label: 'Handouts', icon: 'handout', route: 'handouts' },
+  { id: 'secrets', label: 'Secrets', icon: 'secret', route: 'secrets' },
+  { id: 'homebrew', label: 'Homebrew', icon: 'flask', route: 'homebrew' },
+  { id: 'stats', label: 'Stats', icon: 'chart', route: 'stats' }
 ]
 
 const STORAGE_KEY_PREFIX = 'mimir-dashboard-tab-'
@@ -8,6 +8,7 @@ export type ModalName =
   | 'createModal'      // Create module modal
   | 'deleteModule'     // Delete module confirmation
   | 'mapUpload'        // Map upload modal
+  | 'dungeon'          // Dungeon generator modal
   | 'tokenSetup'       // Token setup modal
   | 'createDoc'        // Create document modal
   | 'deleteDoc'        // Delete document confirmation
@@ -25,6 +26,7 @@ export function useModalsState() {
   const showCreateModal = ref(false)
   const showDeleteModuleModal = ref(false)
   const showMapUploadModal = ref(false)
+  const showDungeonModal = ref(false)
   const showTokenSetupModal = ref(false)
   const showCreateDocModal = ref(false)
   const showDeleteDocModal = ref(false)
@@ -38,6 +40,7 @@ export function useModalsState() {
     createModal: showCreateModal,
     deleteModule: showDeleteModuleModal,
     mapUpload: showMapUploadModal,
+    dungeon: showDungeonModal,
     tokenSetup: showTokenSetupModal,
     createDoc: showCreateDocModal,
     deleteDoc: showDeleteDocModal,
@@ -89,6 +92,7 @@ export function useModalsState() {
     showCreateModal,
     showDeleteModuleModal,
     showMapUploadModal,
+    showDungeonModal,
     showTokenSetupModal,
     showCreateDocModal,
     showDeleteDocModal,
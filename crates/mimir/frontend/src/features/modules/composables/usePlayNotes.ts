@@ -1,6 +1,7 @@
 import { ref, onBeforeUnmount } from 'vue'
 import { invoke } from '@tauri-apps/api/core'
 import type { Document } from '@/types/api'
+import { DocumentService } from '@/services/DocumentService'
 
 interface ApiResponse<T> {
   success: boolean
@@ -19,7 +20,11 @@ export function usePlayNotes() {
   const notesDocumentId = ref<string | null>(null)
   const notesSaving = ref(false)
   const notesLastSaved = ref(false)
+  // True while the notes show both versions of overlapping edits
+  const notesConflict = ref(false)
   let saveTimeout: ReturnType<typeof setTimeout> | null = null
+  // Notes as last loaded or saved, so saves merge with edits made elsewhere
+  let notesBaseContent = ''
 
   // Toggle notes panel visibility
   function toggleNotes() {
@@ -45,11 +50,14 @@ export function usePlayNotes() {
           notesDocumentId.value = null
           notesContent.value = ''
         }
+        notesBaseContent = notesContent.value
+        notesConflict.value = false
       }
     } catch (error) {
       console.error('Failed to load play notes:', error)
       notesDocumentId.value = null
       notesContent.value = ''
+      notesBaseContent = ''
     }
   }
 
@@ -64,12 +72,24 @@ export function usePlayNotes() {
     notesLastSaved.value = false
 
     try {
-      await invoke<ApiResponse<Document>>('update_document', {
-        id: notesDocumentId.value,
-        request: {
-          content: notesContent.value
+      const content = notesContent.value
+      const outcome = await DocumentService.saveContent(notesDocumentId.value, notesBaseContent, content)
+      // Nothing to show if the DM typed more while saving; the next save merges again
+      const unchanged = notesContent.value === content
+      if (outcome.status === 'conflict') {
+        // Both versions go between conflict markers; the next save keeps what the DM leaves
+        if (unchanged) {
+          notesContent.value = outcome.merged_content
+          notesBaseContent = outcome.document.content
+          notesConflict.value = true
         }
-      })
+        return
+      }
+      notesBaseContent = outcome.document.content
+      notesConflict.value = false
+      if (outcome.merged && unchanged) {
+        notesContent.value = outcome.document.content
+      }
       notesLastSaved.value = true
       // Clear the "Saved" indicator after 2 seconds
       setTimeout(() => {
@@ -115,6 +135,7 @@ export function usePlayNotes() {
     notesDocumentId,
     notesSaving,
     notesLastSaved,
+    notesConflict,
     // Actions
     toggleNotes,
     loadNotesForModule,
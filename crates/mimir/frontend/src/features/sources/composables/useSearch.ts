@@ -1,5 +1,6 @@
 import { ref, computed, watch, toRef, isRef, type Ref, type MaybeRef } from 'vue'
 import { SearchService, type SearchFilters } from '../services/SearchService'
+import { RecentItemService } from '@/services/RecentItemService'
 import type {
   SpellSummary,
   ItemSummary,
@@ -128,7 +129,13 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
     filters.value.monsters = { ...filters.value.monsters, ...newFilters }
   }
   
+  // Remember an opened entry for the "jump back in" panel
+  function recordOpened(kind: string, entry: { name: string; source: string }) {
+    RecentItemService.recordQuietly({ kind, refId: entry.name, refSource: entry.source })
+  }
+
   async function selectSpell(spell: SpellSummary) {
+    recordOpened('spell', spell)
     const fullSpell = await SearchService.getDetails({
       name: spell.name,
       source: spell.source,
@@ -143,6 +150,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectItem(item: ItemSummary) {
+    recordOpened('item', item)
     const fullItem = await SearchService.getDetails({
       name: item.name,
       source: item.source,
@@ -158,6 +166,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectMonster(monster: MonsterSummary) {
+    recordOpened('monster', monster)
     const fullMonster = await SearchService.getDetails({
       name: monster.name,
       source: monster.source,
@@ -173,6 +182,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectClass(classItem: ClassSummary) {
+    recordOpened('class', classItem)
     // Check if this row has a valid subclass (not '—' which indicates no subclass)
     const hasValidSubclass = classItem.subclassName &&
                              classItem.subclassName !== '—' &&
@@ -214,6 +224,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectFeat(feat: FeatSummary) {
+    recordOpened('feat', feat)
     const fullFeat = await SearchService.getDetails({
       name: feat.name,
       source: feat.source,
@@ -229,6 +240,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectRace(race: RaceSummary) {
+    recordOpened('race', race)
     const fullRaceResult = await SearchService.getDetails({
       name: race.name,
       source: race.source,
@@ -273,6 +285,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectBackground(background: BackgroundSummary) {
+    recordOpened('background', background)
     const fullBackground = await SearchService.getDetails({
       name: background.name,
       source: background.source,
@@ -288,6 +301,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectAction(action: ActionSummary) {
+    recordOpened('action', action)
     const fullAction = await SearchService.getDetails({
       name: action.name,
       source: action.source,
@@ -303,6 +317,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectCondition(condition: ConditionSummary) {
+    recordOpened('condition', condition)
     const fullCondition = await SearchService.getDetails({
       name: condition.name,
       source: condition.source,
@@ -319,6 +334,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectOption(option: OptionalFeatureSummary) {
+    recordOpened('optional_feature', option)
     const fullOption = await SearchService.getDetails({
       name: option.name,
       source: option.source,
@@ -335,6 +351,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectDeity(deity: DeitySummary) {
+    recordOpened('deity', deity)
     const fullDeity = await SearchService.getDetails({
       name: deity.name,
       source: deity.source,
@@ -351,6 +368,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectObject(obj: ObjectSummary) {
+    recordOpened('object', obj)
     const fullObject = await SearchService.getDetails({
       name: obj.name,
       source: obj.source,
@@ -367,6 +385,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectTrap(trap: TrapSummary) {
+    recordOpened('trap', trap)
     const fullTrap = await SearchService.getDetails({
       name: trap.name,
       source: trap.source,
@@ -383,6 +402,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectLanguage(lang: LanguageSummary) {
+    recordOpened('language', lang)
     const fullLang = await SearchService.getDetails({
       name: lang.name,
       source: lang.source,
@@ -399,6 +419,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectReward(reward: RewardSummary) {
+    recordOpened('reward', reward)
     const fullReward = await SearchService.getDetails({
       name: reward.name,
       source: reward.source,
@@ -415,6 +436,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectTable(table: TableSummary) {
+    recordOpened('table', table)
     const fullTable = await SearchService.getDetails({
       name: table.name,
       source: table.source,
@@ -431,6 +453,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectVariantRule(rule: any) {
+    recordOpened('variant_rule', rule)
     const fullRule = await SearchService.getDetails({
       name: rule.name,
       source: rule.source,
@@ -447,6 +470,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectVehicle(vehicle: any) {
+    recordOpened('vehicle', vehicle)
     const fullVehicle = await SearchService.getDetails({
       name: vehicle.name,
       source: vehicle.source,
@@ -467,6 +491,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
     let formattedContent: string
     
     if (item.item_type === 'cult') {
+      recordOpened('cult', item)
       details = await SearchService.getDetails({
         name: item.name,
         source: item.source,
@@ -492,6 +517,7 @@ export function useSearch(initialCategory: string, initialSources: MaybeRef<stri
   }
   
   async function selectPsionic(psionic: PsionicSummary) {
+    recordOpened('psionic', psionic)
     const fullPsionic = await SearchService.getDetails({
       name: psionic.name,
       source: psionic.source,
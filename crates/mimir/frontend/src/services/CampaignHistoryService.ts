@@ -0,0 +1,90 @@
+/**
+ * Campaign History Service
+ *
+ * Turns a campaign's git-backed history on and off, browses its commits,
+ * and restores documents from them via Tauri commands.
+ * Types match mimir-core HistoryCommit and ExportedDocument.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, Document, HistoryCommit, HistoryDocumentEntry } from '@/types/api'
+
+/** Hidden file in each snapshot listing every file it contains */
+const FILES_MANIFEST = '.mimir-files.json'
+
+/** Hidden file in each snapshot mapping document files to document IDs */
+const DOCUMENT_MANIFEST = '.mimir-documents.json'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Campaign History Service
+// =============================================================================
+
+class CampaignHistoryServiceClass {
+  /**
+   * Check whether history is enabled for a campaign
+   */
+  isEnabled(campaignId: string): Promise<boolean> {
+    return call('is_campaign_history_enabled', { campaignId }, 'Failed to check campaign history')
+  }
+
+  /**
+   * Turn on history, committing the campaign's current state
+   */
+  enable(campaignId: string): Promise<void> {
+    return call('enable_campaign_history', { campaignId }, 'Failed to enable campaign history')
+  }
+
+  /**
+   * Turn off history. This deletes the recorded history.
+   */
+  disable(campaignId: string): Promise<void> {
+    return call('disable_campaign_history', { campaignId }, 'Failed to disable campaign history')
+  }
+
+  /**
+   * List commits, newest first
+   */
+  list(campaignId: string, limit?: number): Promise<HistoryCommit[]> {
+    return call('list_campaign_history', { campaignId, limit }, 'Failed to load campaign history')
+  }
+
+  /**
+   * Read a file as it was at a commit
+   */
+  readFile(campaignId: string, commit: string, path: string): Promise<string> {
+    return call('read_campaign_history_file', { campaignId, commit, path }, 'Failed to read file from history')
+  }
+
+  /**
+   * List the files in a commit's snapshot, sorted by path
+   */
+  async listFiles(campaignId: string, commit: string): Promise<string[]> {
+    return JSON.parse(await this.readFile(campaignId, commit, FILES_MANIFEST))
+  }
+
+  /**
+   * List the document files in a commit's snapshot; only these can be restored
+   */
+  async listDocuments(campaignId: string, commit: string): Promise<HistoryDocumentEntry[]> {
+    return JSON.parse(await this.readFile(campaignId, commit, DOCUMENT_MANIFEST))
+  }
+
+  /**
+   * Restore a document to its content at a commit
+   */
+  restoreFile(campaignId: string, commit: string, path: string): Promise<Document> {
+    return call('restore_campaign_file', { campaignId, commit, path }, 'Failed to restore file')
+  }
+}
+
+export const CampaignHistoryService = new CampaignHistoryServiceClass()
@@ -0,0 +1,92 @@
+/**
+ * Character Image Service
+ *
+ * Provides access to character portraits and map tokens via Tauri commands.
+ * Uploading a portrait also generates a circular token bordered in the
+ * owning player's color.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse } from '@/types/api'
+import type { Character } from '@/types/character'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+function fileToBase64(file: File): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const reader = new FileReader()
+    reader.onload = () => {
+      const result = reader.result as string
+      resolve(result.split(',')[1])
+    }
+    reader.onerror = reject
+    reader.readAsDataURL(file)
+  })
+}
+
+// =============================================================================
+// Character Image Service
+// =============================================================================
+
+class CharacterImageServiceClass {
+  /**
+   * Set a character's portrait (PNG or JPEG) and generate its token
+   */
+  async uploadPortrait(characterId: string, file: File): Promise<Character> {
+    const dataBase64 = await fileToBase64(file)
+    return call('upload_character_portrait', { characterId, dataBase64 }, 'Failed to upload portrait')
+  }
+
+  /**
+   * Set a character's token from its own image instead of the portrait
+   */
+  async uploadToken(characterId: string, file: File): Promise<Character> {
+    const dataBase64 = await fileToBase64(file)
+    return call('upload_character_token', { characterId, dataBase64 }, 'Failed to upload token')
+  }
+
+  /**
+   * Generate a character's token again from its portrait
+   */
+  regenerateToken(characterId: string): Promise<Character> {
+    return call('regenerate_character_token', { characterId }, 'Failed to regenerate token')
+  }
+
+  /**
+   * Remove a character's portrait
+   */
+  removePortrait(characterId: string): Promise<Character> {
+    return call('remove_character_portrait', { characterId }, 'Failed to remove portrait')
+  }
+
+  /**
+   * Remove a character's token
+   */
+  removeToken(characterId: string): Promise<Character> {
+    return call('remove_character_token', { characterId }, 'Failed to remove token')
+  }
+
+  /**
+   * Get a character's portrait as a data URL (null if it has none)
+   */
+  getPortraitUrl(characterId: string): Promise<string | null> {
+    return call('serve_character_portrait', { characterId }, 'Failed to load portrait')
+  }
+
+  /**
+   * Get a character's token as a data URL (null if it has none)
+   */
+  getTokenUrl(characterId: string): Promise<string | null> {
+    return call('serve_character_token', { characterId }, 'Failed to load token')
+  }
+}
+
+export const CharacterImageService = new CharacterImageServiceClass()
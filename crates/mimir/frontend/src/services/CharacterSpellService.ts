@@ -0,0 +1,42 @@
+/**
+ * Character Spell Service
+ *
+ * Provides access to the spells a character knows and the entries in a
+ * wizard's spellbook via Tauri commands.
+ * Types match mimir-core CharacterSpell models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, CharacterSpell } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Character Spell Service
+// =============================================================================
+
+class CharacterSpellServiceClass {
+  /**
+   * List the spells a character knows
+   */
+  list(characterId: string): Promise<CharacterSpell[]> {
+    return call('list_character_spells', { characterId }, 'Failed to list character spells')
+  }
+
+  /**
+   * Mark a known spell as scribed into the spellbook, or clear the mark
+   */
+  toggleScribed(spellId: string): Promise<CharacterSpell> {
+    return call('toggle_spell_scribed', { spellId }, 'Failed to update spellbook')
+  }
+}
+
+export const CharacterSpellService = new CharacterSpellServiceClass()
@@ -0,0 +1,56 @@
+/**
+ * Class Resource Service
+ *
+ * Provides access to characters' limited-use class features (Rage, Ki,
+ * Channel Divinity) and rests via Tauri commands.
+ * Types match mimir-core CharacterResource models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, CharacterResource, RestResult, RestType } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Class Resource Service
+// =============================================================================
+
+class ClassResourceServiceClass {
+  /**
+   * List a character's class resources, derived from their class levels
+   */
+  list(characterId: string): Promise<CharacterResource[]> {
+    return call('list_character_resources', { characterId }, 'Failed to list class resources')
+  }
+
+  /**
+   * Spend uses of a class resource (one by default)
+   */
+  use(id: string, amount?: number): Promise<CharacterResource> {
+    return call('use_resource', { id, amount: amount ?? null }, 'Failed to use resource')
+  }
+
+  /**
+   * Restore uses of a class resource; every use when no amount is given
+   */
+  restore(id: string, amount?: number): Promise<CharacterResource> {
+    return call('restore_resource', { id, amount: amount ?? null }, 'Failed to restore resource')
+  }
+
+  /**
+   * Take a short or long rest, restoring the resources it recharges
+   */
+  takeRest(characterId: string, restType: RestType): Promise<RestResult> {
+    return call('take_rest', { characterId, restType }, 'Failed to take rest')
+  }
+}
+
+export const ClassResourceService = new ClassResourceServiceClass()
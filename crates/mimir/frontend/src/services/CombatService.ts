@@ -0,0 +1,49 @@
+/**
+ * Combat Service
+ *
+ * Reads one-click combat actions out of monster and spell data and rolls
+ * them via Tauri commands.
+ * Types match mimir-core action hint models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ActionHint, ApiResponse, HintRoll } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Combat Service
+// =============================================================================
+
+class CombatServiceClass {
+  /**
+   * Read the rollable traits and actions out of a monster's 5etools data
+   */
+  monsterHints(monsterData: unknown): Promise<ActionHint[]> {
+    return call('get_monster_action_hints', { monsterData }, 'Failed to read monster actions')
+  }
+
+  /**
+   * Read what a catalog spell does, using the caster's save DC and attack bonus
+   */
+  spellHint(name: string, source: string, saveDc?: number, attackBonus?: number): Promise<ActionHint> {
+    return call('get_spell_action_hint', { name, source, saveDc, attackBonus }, 'Failed to read spell')
+  }
+
+  /**
+   * Roll an action's attack and damage
+   */
+  roll(hint: ActionHint): Promise<HintRoll> {
+    return call('roll_action_hint', { hint }, 'Failed to roll action')
+  }
+}
+
+export const CombatService = new CombatServiceClass()
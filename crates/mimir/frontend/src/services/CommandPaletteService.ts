@@ -0,0 +1,97 @@
+/**
+ * Command Palette Service
+ *
+ * Lists the actions registered for the command palette and runs them by ID
+ * via Tauri commands. Types match the command registry in the Tauri crate.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse } from '@/types/api'
+
+export type CommandParamKind =
+  | 'text'
+  | 'integer'
+  | 'boolean'
+  | 'choice'
+  | 'campaign'
+  | 'module'
+  | 'map'
+  | 'quick_action'
+
+export type CommandContextRequirement = 'campaign' | 'module' | 'map'
+
+export interface CommandParam {
+  name: string
+  label: string
+  kind: CommandParamKind
+  required: boolean
+  /** Allowed values of a `choice` parameter */
+  options?: string[]
+}
+
+export interface PaletteCommand {
+  id: string
+  label: string
+  category: string
+  params: CommandParam[]
+  requires: CommandContextRequirement[]
+  /** Whether the given context has everything the command requires */
+  available: boolean
+}
+
+/** What the user has open; fills `campaign`, `module`, and `map` parameters */
+export interface CommandContext {
+  campaignId?: string | null
+  moduleId?: string | null
+  mapId?: string | null
+}
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Command Palette Service
+// =============================================================================
+
+class CommandPaletteServiceClass {
+  /**
+   * List every registered command, marking which ones the context allows
+   */
+  list(context: CommandContext = {}): Promise<PaletteCommand[]> {
+    return call('list_registered_commands', { context }, 'Failed to list commands')
+  }
+
+  /**
+   * Run a registered command. Context parameters the caller leaves out are
+   * filled from `context`.
+   */
+  execute<T = unknown>(
+    command: PaletteCommand,
+    args: Record<string, unknown>,
+    context: CommandContext = {}
+  ): Promise<T> {
+    const filled: Record<string, unknown> = { ...args }
+    for (const param of command.params) {
+      if (filled[param.name] !== undefined) continue
+      const fromContext =
+        param.kind === 'campaign'
+          ? context.campaignId
+          : param.kind === 'module'
+            ? context.moduleId
+            : param.kind === 'map'
+              ? context.mapId
+              : undefined
+      if (fromContext) filled[param.name] = fromContext
+    }
+    return call('execute_registered_command', { id: command.id, args: filled }, `Failed to run ${command.label}`)
+  }
+}
+
+export const CommandPaletteService = new CommandPaletteServiceClass()
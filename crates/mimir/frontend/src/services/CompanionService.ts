@@ -0,0 +1,82 @@
+/**
+ * Companion Service
+ *
+ * Provides access to characters' companion creatures (beast companions,
+ * familiars, sidekicks) via Tauri commands.
+ * Types match mimir-core CharacterCompanion models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  AddCompanionInput,
+  ApiResponse,
+  CharacterCompanion,
+  UpdateCompanionInput,
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Companion Service
+// =============================================================================
+
+class CompanionServiceClass {
+  /**
+   * List a character's companions
+   */
+  list(characterId: string): Promise<CharacterCompanion[]> {
+    return call('list_character_companions', { characterId }, 'Failed to list companions')
+  }
+
+  /**
+   * Link a companion to a character
+   */
+  add(characterId: string, input: AddCompanionInput): Promise<CharacterCompanion> {
+    return call('add_companion', { characterId, input }, 'Failed to add companion')
+  }
+
+  /**
+   * Update a companion's name, hit point maximum, or notes
+   */
+  update(id: string, input: UpdateCompanionInput): Promise<CharacterCompanion> {
+    return call('update_companion', { id, input }, 'Failed to update companion')
+  }
+
+  /**
+   * Apply damage (negative) or healing (positive)
+   */
+  adjustHp(id: string, delta: number): Promise<CharacterCompanion> {
+    return call('adjust_companion_hp', { id, delta }, 'Failed to adjust companion HP')
+  }
+
+  /**
+   * Give a sidekick one level
+   */
+  levelUp(id: string): Promise<CharacterCompanion> {
+    return call('level_up_companion', { id }, 'Failed to level up companion')
+  }
+
+  /**
+   * Remove a companion from its owner
+   */
+  remove(id: string): Promise<void> {
+    return call('remove_companion', { id }, 'Failed to remove companion')
+  }
+
+  /**
+   * Get a companion's stat block (5etools monster JSON)
+   */
+  getStatblock(id: string): Promise<Record<string, unknown>> {
+    return call('get_companion_statblock', { id }, 'Failed to load companion stat block')
+  }
+}
+
+export const CompanionService = new CompanionServiceClass()
@@ -0,0 +1,76 @@
+/**
+ * Continuity Service
+ *
+ * Provides access to links between modules (follows-from, foreshadows) and
+ * the continuity checklist built from them via Tauri commands.
+ * Types match mimir-core ModuleLink models and ContinuityService results.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, ModuleCompletion, ModuleLink, ModuleLinkType } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Continuity Service
+// =============================================================================
+
+class ContinuityServiceClass {
+  /**
+   * List a campaign's module links
+   */
+  listLinks(campaignId: string): Promise<ModuleLink[]> {
+    return call('list_module_links', { campaignId }, 'Failed to list module links')
+  }
+
+  /**
+   * Link one module to another
+   */
+  link(
+    fromModuleId: string,
+    toModuleId: string,
+    linkType: ModuleLinkType,
+    note?: string
+  ): Promise<ModuleLink> {
+    return call(
+      'link_modules',
+      { fromModuleId, toModuleId, linkType, note: note ?? null },
+      'Failed to link modules'
+    )
+  }
+
+  /**
+   * Change a link's note (an empty note clears it) or mark its hook resolved
+   */
+  updateLink(linkId: string, changes: { note?: string; resolved?: boolean }): Promise<ModuleLink> {
+    return call(
+      'update_module_link',
+      { linkId, note: changes.note ?? null, resolved: changes.resolved ?? null },
+      'Failed to update module link'
+    )
+  }
+
+  /**
+   * Remove a module link
+   */
+  unlink(linkId: string): Promise<void> {
+    return call('delete_module_link', { linkId }, 'Failed to remove module link')
+  }
+
+  /**
+   * Build a module's continuity checklist
+   */
+  checkCompletion(moduleId: string): Promise<ModuleCompletion> {
+    return call('check_module_completion', { moduleId }, 'Failed to check module continuity')
+  }
+}
+
+export const ContinuityService = new ContinuityServiceClass()
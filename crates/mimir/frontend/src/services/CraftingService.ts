@@ -0,0 +1,79 @@
+/**
+ * Crafting Service
+ *
+ * Provides access to downtime crafting projects and recipes via Tauri
+ * commands.
+ * Types match mimir-core CraftingProject models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  AdvanceCraftingInput,
+  ApiResponse,
+  CraftingProgress,
+  CraftingProject,
+  CraftingRecipe,
+  StartCraftingInput,
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Crafting Service
+// =============================================================================
+
+class CraftingServiceClass {
+  /**
+   * List a character's crafting projects
+   */
+  list(characterId: string): Promise<CraftingProject[]> {
+    return call('list_crafting_projects', { characterId }, 'Failed to list crafting projects')
+  }
+
+  /**
+   * Work out the gold, time, tool, and ingredient needed to craft an item
+   */
+  getRecipe(
+    characterId: string,
+    itemName: string,
+    itemSource: string,
+    quantity?: number
+  ): Promise<CraftingRecipe> {
+    return call(
+      'get_crafting_recipe',
+      { characterId, itemName, itemSource, quantity: quantity ?? null },
+      'Failed to load crafting recipe'
+    )
+  }
+
+  /**
+   * Start crafting an item
+   */
+  start(input: StartCraftingInput): Promise<CraftingProject> {
+    return call('start_crafting_project', { input }, 'Failed to start crafting')
+  }
+
+  /**
+   * Log downtime days against a project
+   */
+  advance(id: string, input: AdvanceCraftingInput): Promise<CraftingProgress> {
+    return call('advance_crafting', { id, input }, 'Failed to log crafting progress')
+  }
+
+  /**
+   * Abandon a project
+   */
+  abandon(id: string): Promise<CraftingProject> {
+    return call('abandon_crafting_project', { id }, 'Failed to abandon crafting project')
+  }
+}
+
+export const CraftingService = new CraftingServiceClass()
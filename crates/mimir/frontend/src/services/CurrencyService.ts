@@ -0,0 +1,69 @@
+/**
+ * Currency Service
+ *
+ * Provides access to characters' coins and currency history via Tauri
+ * commands.
+ * Types match mimir-core CurrencyTransaction models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  CurrencyAdjustment,
+  CurrencyResult,
+  CurrencyTransaction,
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Currency Service
+// =============================================================================
+
+class CurrencyServiceClass {
+  /**
+   * List a character's currency transactions, newest first
+   */
+  getHistory(characterId: string): Promise<CurrencyTransaction[]> {
+    return call('get_currency_history', { characterId }, 'Failed to load currency history')
+  }
+
+  /**
+   * Add or spend coins with a reason
+   */
+  adjust(characterId: string, input: CurrencyAdjustment): Promise<CurrencyResult> {
+    return call('adjust_character_currency', { characterId, input }, 'Failed to adjust currency')
+  }
+
+  /**
+   * Pay a cost in copper, making change from the character's coins
+   */
+  spend(characterId: string, costCp: number, reason: string): Promise<CurrencyResult> {
+    return call(
+      'spend_character_currency',
+      { characterId, costCp, reason },
+      'Failed to spend currency'
+    )
+  }
+
+  /**
+   * Convert a character's coins to gold
+   */
+  consolidate(characterId: string): Promise<CurrencyResult> {
+    return call(
+      'consolidate_character_currency',
+      { characterId },
+      'Failed to consolidate currency'
+    )
+  }
+}
+
+export const CurrencyService = new CurrencyServiceClass()
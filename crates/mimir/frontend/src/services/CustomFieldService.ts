@@ -0,0 +1,107 @@
+/**
+ * Custom Field Service
+ *
+ * Provides access to a campaign's custom character sheet fields and each
+ * character's values for them via Tauri commands.
+ * Types match mimir-core CustomField models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  CampaignCustomField,
+  CreateCustomFieldInput,
+  CustomFieldValue,
+  UpdateCustomFieldRequest
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+/**
+ * Format a custom field value for display, e.g. "Yes" for a true boolean
+ */
+export function formatCustomFieldValue(field: CustomFieldValue): string {
+  if (field.value === null) return '—'
+  if (field.field_type === 'boolean') return field.value === 'true' ? 'Yes' : 'No'
+  return field.value
+}
+
+// =============================================================================
+// Custom Field Service
+// =============================================================================
+
+class CustomFieldServiceClass {
+  /**
+   * List a campaign's custom fields in sheet order
+   */
+  list(campaignId: string): Promise<CampaignCustomField[]> {
+    return call('list_custom_fields', { campaignId }, 'Failed to list custom fields')
+  }
+
+  /**
+   * Define a custom field for a campaign
+   */
+  create(campaignId: string, input: CreateCustomFieldInput): Promise<CampaignCustomField> {
+    return call('create_custom_field', { campaignId, input }, 'Failed to create custom field')
+  }
+
+  /**
+   * Update a custom field
+   */
+  update(id: string, request: UpdateCustomFieldRequest): Promise<CampaignCustomField> {
+    return call('update_custom_field', { id, request }, 'Failed to update custom field')
+  }
+
+  /**
+   * Reorder a campaign's custom fields
+   */
+  reorder(campaignId: string, fieldIds: string[]): Promise<CampaignCustomField[]> {
+    return call('reorder_custom_fields', { campaignId, fieldIds }, 'Failed to reorder custom fields')
+  }
+
+  /**
+   * Delete a custom field and every character's value for it
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_custom_field', { id }, 'Failed to delete custom field')
+  }
+
+  /**
+   * Get a character's custom field values, with defaults filled in
+   */
+  getValues(characterId: string): Promise<CustomFieldValue[]> {
+    return call('get_character_custom_fields', { characterId }, 'Failed to get custom fields')
+  }
+
+  /**
+   * Set a character's value for a custom field
+   */
+  setValue(characterId: string, fieldKey: string, value: string): Promise<CustomFieldValue[]> {
+    return call(
+      'set_character_custom_field',
+      { characterId, fieldKey, value },
+      'Failed to set custom field'
+    )
+  }
+
+  /**
+   * Reset a character's value for a custom field to the field default
+   */
+  clearValue(characterId: string, fieldKey: string): Promise<CustomFieldValue[]> {
+    return call(
+      'clear_character_custom_field',
+      { characterId, fieldKey },
+      'Failed to clear custom field'
+    )
+  }
+}
+
+export const CustomFieldService = new CustomFieldServiceClass()
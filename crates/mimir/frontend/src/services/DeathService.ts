@@ -0,0 +1,62 @@
+/**
+ * Death Service
+ *
+ * Provides access to character death, resurrection, and the campaign
+ * memorial via Tauri commands.
+ * Types match mimir-core CharacterDeath models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  CharacterDeath,
+  MarkDeadInput,
+  MemorialEntry,
+  ResurrectInput,
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Death Service
+// =============================================================================
+
+class DeathServiceClass {
+  /**
+   * List a character's deaths, oldest first
+   */
+  listForCharacter(characterId: string): Promise<CharacterDeath[]> {
+    return call('list_character_deaths', { characterId }, 'Failed to list deaths')
+  }
+
+  /**
+   * List a campaign's dead characters
+   */
+  getMemorial(campaignId: string): Promise<MemorialEntry[]> {
+    return call('get_campaign_memorial', { campaignId }, 'Failed to load memorial')
+  }
+
+  /**
+   * Mark a character dead
+   */
+  markDead(characterId: string, input: MarkDeadInput): Promise<CharacterDeath> {
+    return call('mark_character_dead', { characterId, input }, 'Failed to mark character dead')
+  }
+
+  /**
+   * Bring a dead character back to life
+   */
+  resurrect(characterId: string, input: ResurrectInput): Promise<CharacterDeath> {
+    return call('resurrect_character', { characterId, input }, 'Failed to resurrect character')
+  }
+}
+
+export const DeathService = new DeathServiceClass()
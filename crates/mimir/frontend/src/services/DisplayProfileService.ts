@@ -0,0 +1,87 @@
+/**
+ * Display Profile Service
+ *
+ * Manages named layout profiles for the player display windows and their
+ * assignment to the primary and secondary screens via Tauri commands.
+ * Profiles are stored in the display section of the app settings.
+ * Types match the mimir-core DisplayProfile and mimir MonitorInfo structs.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse } from '@/types/api'
+import type { DisplayProfilePayload } from '@/composables/map/usePlayerDisplayEvents'
+
+export type DisplayProfile = DisplayProfilePayload
+
+export type DisplayScreen = 'primary' | 'secondary'
+
+/** Saved profiles and the profile name assigned to each screen, from the display settings */
+export interface DisplayProfileStore {
+  profiles: DisplayProfile[]
+  assignments: Partial<Record<DisplayScreen, string>>
+}
+
+/** A monitor connected to this machine */
+export interface MonitorInfo {
+  name: string | null
+  width: number
+  height: number
+  x: number
+  y: number
+  scaleFactor: number
+  isPrimary: boolean
+}
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Display Profile Service
+// =============================================================================
+
+class DisplayProfileServiceClass {
+  /**
+   * List the monitors connected to this machine
+   */
+  listMonitors(): Promise<MonitorInfo[]> {
+    return call('list_display_monitors', {}, 'Failed to list monitors')
+  }
+
+  /**
+   * List saved profiles and screen assignments
+   */
+  list(): Promise<DisplayProfileStore> {
+    return call('list_display_profiles', {}, 'Failed to load display profiles')
+  }
+
+  /**
+   * Create a profile, or replace the profile with the same name
+   */
+  save(profile: DisplayProfile): Promise<DisplayProfile> {
+    return call('save_display_profile', { profile }, 'Failed to save display profile')
+  }
+
+  /**
+   * Delete a profile and clear any screen assignments using it
+   */
+  delete(name: string): Promise<void> {
+    return call('delete_display_profile', { name }, 'Failed to delete display profile')
+  }
+
+  /**
+   * Assign a profile to a screen, or clear the assignment with null. An open
+   * display window picks up the new profile immediately.
+   */
+  assign(screen: DisplayScreen, profileName: string | null): Promise<DisplayProfile | null> {
+    return call('assign_display_profile', { screen, profileName }, 'Failed to assign display profile')
+  }
+}
+
+export const DisplayProfileService = new DisplayProfileServiceClass()
@@ -0,0 +1,63 @@
+/**
+ * DM Reference Service
+ *
+ * Manages the panels pinned to the DM reference window and opens the
+ * window itself via Tauri commands.
+ * Types match mimir-core DmReferencePin model.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, DmReferencePin, PinDmReferenceRequest } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// DM Reference Service
+// =============================================================================
+
+class DmReferenceServiceClass {
+  /**
+   * List a campaign's pinned panels in display order
+   */
+  list(campaignId: string): Promise<DmReferencePin[]> {
+    return call('list_dm_reference_pins', { campaignId }, 'Failed to load pinned panels')
+  }
+
+  /**
+   * Pin a panel at the end of the list
+   */
+  pin(request: PinDmReferenceRequest): Promise<DmReferencePin> {
+    return call('pin_dm_reference', { request }, 'Failed to pin panel')
+  }
+
+  /**
+   * Remove a pinned panel
+   */
+  unpin(id: string): Promise<void> {
+    return call('unpin_dm_reference', { id }, 'Failed to unpin panel')
+  }
+
+  /**
+   * Reorder the panels, listing the campaign's pins in their new order
+   */
+  reorder(campaignId: string, pinIds: string[]): Promise<DmReferencePin[]> {
+    return call('reorder_dm_reference_pins', { campaignId, pinIds }, 'Failed to reorder panels')
+  }
+
+  /**
+   * Open the DM reference window for a campaign, or focus it if already open
+   */
+  openWindow(campaignId: string): Promise<void> {
+    return invoke('open_dm_reference_window', { campaignId })
+  }
+}
+
+export const DmReferenceService = new DmReferenceServiceClass()
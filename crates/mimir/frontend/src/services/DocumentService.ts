@@ -21,12 +21,58 @@ export interface CreateDocumentRequest {
   content?: string
 }
 
+/** Content is saved through `saveContent`, which merges instead of overwriting */
 export interface UpdateDocumentRequest {
   title?: string
-  content?: string
   doc_type?: string
 }
 
+// =============================================================================
+// Save Outcome Type
+// =============================================================================
+
+/**
+ * Result of saving against the content the editor started from. On a
+ * conflict nothing was written; `merged_content` holds both versions between
+ * conflict markers.
+ */
+export type DocumentSaveOutcome =
+  | { status: 'saved'; document: Document; merged: boolean }
+  | { status: 'conflict'; document: Document; merged_content: string }
+
+/** Whether a document changed elsewhere since an editor loaded it */
+export interface DocumentExternalChange {
+  changed: boolean
+  /** The document as currently stored */
+  document: Document
+}
+
+// =============================================================================
+// Bulk Operation Types
+// =============================================================================
+
+/** Changes applied to every selected document; tags are given by name */
+export interface BulkDocumentMetadata {
+  doc_type?: string
+  add_tags?: string[]
+  remove_tags?: string[]
+}
+
+export interface BulkItemResult {
+  id: string
+  success: boolean
+  error: string | null
+}
+
+/**
+ * Bulk operations are all-or-nothing: when any document fails, `applied` is
+ * false, nothing changed, and `results` shows which documents failed.
+ */
+export interface BulkResult {
+  applied: boolean
+  results: BulkItemResult[]
+}
+
 // =============================================================================
 // Search Result Type
 // =============================================================================
@@ -42,17 +88,32 @@ export interface DocumentSearchResult {
   updated_at: string
 }
 
+// =============================================================================
+// Monster Embed Types
+// =============================================================================
+
+export type MonsterEmbedStatus = 'resolved' | 'source_disabled' | 'not_found'
+
+export interface ResolvedMonsterEmbed {
+  name: string
+  source: string | null
+  status: MonsterEmbedStatus
+  data: Record<string, any> | null
+}
+
 // =============================================================================
 // Document Service
 // =============================================================================
 
 class DocumentServiceClass {
   /**
-   * List campaign-level documents (not in any module)
+   * List campaign-level documents (not in any module); with `tagId`, only
+   * documents carrying that tag
    */
-  async listForCampaign(campaignId: string): Promise<Document[]> {
+  async listForCampaign(campaignId: string, tagId?: string): Promise<Document[]> {
     const response = await invoke<ApiResponse<Document[]>>('list_campaign_documents', {
-      campaignId
+      campaignId,
+      tagId
     })
 
     if (response.success && response.data) {
@@ -115,7 +176,7 @@ class DocumentServiceClass {
   }
 
   /**
-   * Update a document
+   * Update a document's title or type
    */
   async update(id: string, request: UpdateDocumentRequest): Promise<Document> {
     const response = await invoke<ApiResponse<Document>>('update_document', {
@@ -147,6 +208,62 @@ class DocumentServiceClass {
     throw new Error(response.error || `Failed to delete document ${id}`)
   }
 
+  /**
+   * Change the type and tags of several documents at once
+   */
+  async bulkUpdateMetadata(documentIds: string[], metadata: BulkDocumentMetadata): Promise<BulkResult> {
+    const response = await invoke<ApiResponse<BulkResult>>('bulk_update_document_metadata', {
+      documentIds,
+      request: metadata
+    })
+
+    if (response.success && response.data) {
+      if (response.data.applied) {
+        documentIds.forEach(documentId => dataEvents.emit('document:updated', { documentId }))
+      }
+      return response.data
+    }
+
+    throw new Error(response.error || 'Failed to update documents')
+  }
+
+  /**
+   * Move several documents to the trash at once
+   */
+  async bulkDelete(documentIds: string[]): Promise<BulkResult> {
+    const response = await invoke<ApiResponse<BulkResult>>('bulk_delete_documents', {
+      documentIds
+    })
+
+    if (response.success && response.data) {
+      if (response.data.applied) {
+        documentIds.forEach(documentId => dataEvents.emit('document:deleted', { documentId }))
+      }
+      return response.data
+    }
+
+    throw new Error(response.error || 'Failed to delete documents')
+  }
+
+  /**
+   * Move several documents into a module, or out to campaign level with null
+   */
+  async moveToModule(documentIds: string[], moduleId: string | null): Promise<BulkResult> {
+    const response = await invoke<ApiResponse<BulkResult>>('move_documents_to_module', {
+      documentIds,
+      moduleId
+    })
+
+    if (response.success && response.data) {
+      if (response.data.applied && moduleId) {
+        dataEvents.emit('module:updated', { moduleId })
+      }
+      return response.data
+    }
+
+    throw new Error(response.error || 'Failed to move documents')
+  }
+
   /**
    * Search documents in a campaign
    */
@@ -179,6 +296,28 @@ class DocumentServiceClass {
     throw new Error(response.error || 'Failed to search module documents')
   }
 
+  /**
+   * Resolve a `{{monster: Name|SOURCE}}` embed against the campaign's
+   * enabled sources and homebrew
+   */
+  async resolveMonsterEmbed(
+    campaignId: string,
+    name: string,
+    source: string | null
+  ): Promise<ResolvedMonsterEmbed> {
+    const response = await invoke<ApiResponse<ResolvedMonsterEmbed>>('resolve_monster_embed', {
+      campaignId,
+      name,
+      source
+    })
+
+    if (response.success && response.data) {
+      return response.data
+    }
+
+    throw new Error(response.error || `Failed to resolve monster ${name}`)
+  }
+
   /**
    * Swap sort order between two documents
    */
@@ -197,10 +336,41 @@ class DocumentServiceClass {
   }
 
   /**
-   * Update only the content of a document
+   * Save a document's content, merging with changes made elsewhere since
+   * the editor loaded `baseContent`
    */
-  async updateContent(id: string, content: string): Promise<Document> {
-    return this.update(id, { content })
+  async saveContent(id: string, baseContent: string, content: string): Promise<DocumentSaveOutcome> {
+    const response = await invoke<ApiResponse<DocumentSaveOutcome>>('save_document_merging', {
+      id,
+      baseContent,
+      request: { content }
+    })
+
+    if (response.success && response.data) {
+      if (response.data.status === 'saved') {
+        dataEvents.emit('document:updated', { documentId: id })
+      }
+      return response.data
+    }
+
+    throw new Error(response.error || `Failed to save document ${id}`)
+  }
+
+  /**
+   * Check whether a document was changed elsewhere (another window, the MCP
+   * server) since the editor loaded the version stamped `baseUpdatedAt`
+   */
+  async checkExternalChanges(id: string, baseUpdatedAt: string): Promise<DocumentExternalChange> {
+    const response = await invoke<ApiResponse<DocumentExternalChange>>('check_document_external_changes', {
+      id,
+      baseUpdatedAt
+    })
+
+    if (response.success && response.data) {
+      return response.data
+    }
+
+    throw new Error(response.error || `Failed to check document ${id} for changes`)
   }
 
   /**
@@ -0,0 +1,56 @@
+/**
+ * Encounter Effect Service
+ *
+ * Manages the lair actions, hazards, and scheduled events in a module's
+ * encounter via Tauri commands.
+ * Types match mimir-core EncounterEffect models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, EncounterEffect, EncounterEffectInput } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Encounter Effect Service
+// =============================================================================
+
+class EncounterEffectServiceClass {
+  /**
+   * List a module's encounter effects
+   */
+  list(moduleId: string): Promise<EncounterEffect[]> {
+    return call('list_encounter_effects', { moduleId }, 'Failed to load encounter effects')
+  }
+
+  /**
+   * Add an effect to a module's encounter
+   */
+  add(moduleId: string, input: EncounterEffectInput): Promise<EncounterEffect> {
+    return call('add_encounter_effect', { moduleId, input }, 'Failed to add encounter effect')
+  }
+
+  /**
+   * Replace an effect's name, description, and timing
+   */
+  update(id: string, input: EncounterEffectInput): Promise<EncounterEffect> {
+    return call('update_encounter_effect', { id, input }, 'Failed to update encounter effect')
+  }
+
+  /**
+   * Remove an effect from its module's encounter
+   */
+  remove(id: string): Promise<void> {
+    return call('remove_encounter_effect', { id }, 'Failed to remove encounter effect')
+  }
+}
+
+export const EncounterEffectService = new EncounterEffectServiceClass()
@@ -0,0 +1,87 @@
+/**
+ * Forage Service
+ *
+ * Provides access to a campaign's gathering tables and forage checks via
+ * Tauri commands. Found ingredients go into the shared stash.
+ * Types match mimir-core ForageIngredient models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  AddForageIngredientInput,
+  ApiResponse,
+  ForageImportResult,
+  ForageIngredient,
+  ForageResult,
+  UpdateForageIngredientInput,
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Forage Service
+// =============================================================================
+
+class ForageServiceClass {
+  /**
+   * List a campaign's forage ingredients, optionally for one environment
+   */
+  list(campaignId: string, environment?: string): Promise<ForageIngredient[]> {
+    return call('list_forage_ingredients', { campaignId, environment }, 'Failed to load gathering tables')
+  }
+
+  /**
+   * Add a catalog or homebrew item to an environment's gathering table
+   */
+  addIngredient(campaignId: string, input: AddForageIngredientInput): Promise<ForageIngredient> {
+    return call('add_forage_ingredient', { campaignId, input }, 'Failed to add ingredient')
+  }
+
+  /**
+   * Change an ingredient's DC, quantity or weight
+   */
+  updateIngredient(id: string, input: UpdateForageIngredientInput): Promise<ForageIngredient> {
+    return call('update_forage_ingredient', { id, input }, 'Failed to update ingredient')
+  }
+
+  /**
+   * Remove an ingredient from its gathering table
+   */
+  removeIngredient(id: string): Promise<void> {
+    return call('remove_forage_ingredient', { id }, 'Failed to remove ingredient')
+  }
+
+  /**
+   * Import the items in a catalog table into an environment's gathering table
+   */
+  importTable(
+    campaignId: string,
+    environment: string,
+    tableName: string,
+    tableSource: string,
+    dc?: number
+  ): Promise<ForageImportResult> {
+    return call(
+      'import_forage_table',
+      { campaignId, environment, tableName, tableSource, dc },
+      'Failed to import table'
+    )
+  }
+
+  /**
+   * Make a forage check; pass roll to use a physical d20
+   */
+  forage(campaignId: string, environment: string, modifier: number, roll?: number): Promise<ForageResult> {
+    return call('forage', { campaignId, environment, modifier, roll }, 'Failed to forage')
+  }
+}
+
+export const ForageService = new ForageServiceClass()
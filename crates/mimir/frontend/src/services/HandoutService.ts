@@ -0,0 +1,92 @@
+/**
+ * Handout Service
+ *
+ * Manages a campaign's handout queue and reveals handouts to the player
+ * display one at a time via Tauri commands.
+ * Types match mimir-core Handout models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, Handout, HandoutInput, RevealedHandout } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Handout Service
+// =============================================================================
+
+class HandoutServiceClass {
+  /**
+   * List a campaign's handouts in queue order, revealed ones included
+   */
+  list(campaignId: string): Promise<Handout[]> {
+    return call('list_handouts', { campaignId }, 'Failed to load handouts')
+  }
+
+  /**
+   * Stage a handout at the end of the queue
+   */
+  create(campaignId: string, input: HandoutInput): Promise<Handout> {
+    return call('create_handout', { campaignId, input }, 'Failed to create handout')
+  }
+
+  /**
+   * Delete a handout
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_handout', { id }, 'Failed to delete handout')
+  }
+
+  /**
+   * Reorder the queue, listing the campaign's handouts in their new order
+   */
+  reorder(campaignId: string, handoutIds: string[]): Promise<Handout[]> {
+    return call('reorder_handouts', { campaignId, handoutIds }, 'Failed to reorder handouts')
+  }
+
+  /**
+   * Put a revealed handout back in the queue
+   */
+  requeue(id: string): Promise<Handout> {
+    return call('requeue_handout', { id }, 'Failed to requeue handout')
+  }
+
+  /**
+   * What players have been shown, optionally only in one session
+   */
+  history(campaignId: string, sessionId?: string): Promise<RevealedHandout[]> {
+    return call(
+      'list_handout_history',
+      { campaignId, sessionId: sessionId ?? null },
+      'Failed to load handout history'
+    )
+  }
+
+  /**
+   * Reveal the next queued handout; resolves to null when the queue is empty
+   */
+  revealNext(campaignId: string, screen?: string): Promise<Handout | null> {
+    return call(
+      'reveal_next_handout',
+      { campaignId, screen: screen ?? null },
+      'Failed to reveal handout'
+    )
+  }
+
+  /**
+   * Reveal a particular handout, queued or not
+   */
+  reveal(id: string, screen?: string): Promise<Handout> {
+    return call('reveal_handout', { id, screen: screen ?? null }, 'Failed to reveal handout')
+  }
+}
+
+export const HandoutService = new HandoutServiceClass()
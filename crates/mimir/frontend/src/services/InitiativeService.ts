@@ -0,0 +1,163 @@
+/**
+ * Initiative Service
+ *
+ * Manages the encounter's turn order via Tauri commands: players' rolls
+ * typed in or pasted, monsters rolled by Mimir.
+ * Types match the initiative tracker snapshot.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, CombatantPortrait, InitiativeSnapshot, MobAttack, MobCasualties } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Initiative Service
+// =============================================================================
+
+class InitiativeServiceClass {
+  /**
+   * Get the current turn order
+   */
+  get(): Promise<InitiativeSnapshot> {
+    return call('get_initiative', {}, 'Failed to load initiative')
+  }
+
+  /**
+   * Enter an initiative rolled at the table; re-entering a name replaces it
+   */
+  set(name: string, initiative: number, modifier?: number, portrait?: CombatantPortrait): Promise<InitiativeSnapshot> {
+    return call('set_initiative', { name, initiative, modifier, portrait }, 'Failed to set initiative')
+  }
+
+  /**
+   * Roll d20 plus the modifier for a creature; hidden creatures are "???" to players
+   */
+  roll(name: string, modifier: number, portrait?: CombatantPortrait, hidden?: boolean): Promise<InitiativeSnapshot> {
+    return call('roll_initiative', { name, modifier, portrait, hidden }, 'Failed to roll initiative')
+  }
+
+  /**
+   * Add a mob of identical creatures as one combatant with pooled hit points
+   */
+  addMob(
+    name: string,
+    size: number,
+    hpEach: number,
+    modifier: number,
+    monsterId?: string,
+    portrait?: CombatantPortrait,
+    hidden?: boolean
+  ): Promise<InitiativeSnapshot> {
+    return call('add_mob', { name, size, hpEach, modifier, monsterId, portrait, hidden }, 'Failed to add mob')
+  }
+
+  /**
+   * Reveal a hidden combatant to players, or hide them again
+   */
+  reveal(name: string, revealed: boolean): Promise<InitiativeSnapshot> {
+    return call('reveal_combatant', { name, revealed }, 'Failed to reveal combatant')
+  }
+
+  /**
+   * Deal damage to a mob; its mob tokens shrink to the survivors
+   */
+  damageMob(name: string, amount: number): Promise<MobCasualties> {
+    return call('damage_mob', { name, amount }, 'Failed to damage mob')
+  }
+
+  /**
+   * Work out how many of a mob's attacks hit a target
+   */
+  mobAttack(name: string, attackBonus: number, targetAc: number, attackers?: number): Promise<MobAttack> {
+    return call('resolve_mob_attack', { name, attackers, attackBonus, targetAc }, 'Failed to resolve mob attack')
+  }
+
+  /**
+   * Enter initiative from a pasted list, one "Name 17" per line
+   */
+  import(text: string): Promise<InitiativeSnapshot> {
+    return call('import_initiative', { text }, 'Failed to import initiative')
+  }
+
+  /**
+   * Get the turn order as "Name: 17" lines
+   */
+  export(): Promise<string> {
+    return call('export_initiative', {}, 'Failed to export initiative')
+  }
+
+  /**
+   * Remove a combatant from the turn order
+   */
+  remove(name: string): Promise<InitiativeSnapshot> {
+    return call('remove_combatant', { name }, 'Failed to remove combatant')
+  }
+
+  /**
+   * Move a combatant ahead of the combatant they are tied with
+   */
+  moveUp(name: string): Promise<InitiativeSnapshot> {
+    return call('move_combatant_up', { name }, 'Failed to reorder tie')
+  }
+
+  /**
+   * Delay the current combatant's turn
+   */
+  delay(): Promise<InitiativeSnapshot> {
+    return call('delay_turn', {}, 'Failed to delay turn')
+  }
+
+  /**
+   * Bring a delayed combatant back in to act now
+   */
+  resume(name: string): Promise<InitiativeSnapshot> {
+    return call('resume_turn', { name }, 'Failed to resume turn')
+  }
+
+  /**
+   * Ready an action for a combatant; an empty trigger clears it
+   */
+  ready(name: string, trigger: string | null): Promise<InitiativeSnapshot> {
+    return call('ready_action', { name, trigger }, 'Failed to ready action')
+  }
+
+  /**
+   * Advance to the next turn, starting combat on the first call
+   */
+  nextTurn(): Promise<InitiativeSnapshot> {
+    return call('next_initiative_turn', {}, 'Failed to advance turn')
+  }
+
+  /**
+   * Schedule a module's encounter effects, replacing those of the last module
+   */
+  loadEffects(moduleId: string): Promise<InitiativeSnapshot> {
+    return call('load_encounter_effects', { moduleId }, 'Failed to load encounter effects')
+  }
+
+  /**
+   * End the encounter, removing every creature; encounter effects stay
+   */
+  clear(): Promise<InitiativeSnapshot> {
+    return call('clear_initiative', {}, 'Failed to clear initiative')
+  }
+
+  /**
+   * Show the turn order on the player display, following it as turns
+   * advance, or hide it
+   */
+  async sendToDisplay(hide = false, screen?: string): Promise<void> {
+    await invoke('send_initiative_to_display', { hide, screen })
+  }
+}
+
+export const InitiativeService = new InitiativeServiceClass()
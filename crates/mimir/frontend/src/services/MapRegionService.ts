@@ -0,0 +1,107 @@
+/**
+ * Map Region Service
+ *
+ * Named encounter regions on maps, which documents reference with
+ * `{{map-region: Map Name | region}}`, and focusing the DM map window on one.
+ * Types match mimir-core MapRegion models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse } from '@/types/api'
+
+/** A named rectangle or polygon on a map, in grid units */
+export interface MapRegion {
+  id: string
+  map_id: string
+  name: string
+  /** Bounding box, in grid units */
+  x: number
+  y: number
+  width: number
+  height: number
+  /** Polygon vertices as a JSON array of [x, y] pairs; null for a rectangle */
+  points: string | null
+  created_at: string
+  updated_at: string
+}
+
+export type RegionShape =
+  | { kind: 'rect'; x: number; y: number; width: number; height: number }
+  | { kind: 'polygon'; points: [number, number][] }
+
+/** A document reference resolved to its map, region, tokens, and traps */
+export interface ResolvedMapRegion {
+  map_id: string
+  map_name: string
+  module_id: string | null
+  region: MapRegion
+  /** Tokens whose cell center lies inside the region */
+  token_ids: string[]
+  /** Traps whose cell center lies inside the region */
+  trap_ids: string[]
+}
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+class MapRegionServiceClass {
+  /**
+   * List a map's regions alphabetically
+   */
+  list(mapId: string): Promise<MapRegion[]> {
+    return call('list_map_regions', { mapId }, 'Failed to list map regions')
+  }
+
+  /**
+   * Create a named region on a map
+   */
+  create(mapId: string, name: string, shape: RegionShape): Promise<MapRegion> {
+    return call('create_map_region', { request: { mapId, name, shape } }, 'Failed to create map region')
+  }
+
+  /**
+   * Rename a region and/or replace its shape
+   */
+  update(id: string, request: { name?: string; shape?: RegionShape }): Promise<MapRegion> {
+    return call('update_map_region', { id, request }, `Failed to update map region ${id}`)
+  }
+
+  /**
+   * Delete a region
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_map_region', { id }, `Failed to delete map region ${id}`)
+  }
+
+  /**
+   * Resolve a document reference by map and region name
+   */
+  resolve(campaignId: string, mapName: string, regionName: string): Promise<ResolvedMapRegion> {
+    return call(
+      'resolve_map_region',
+      { campaignId, mapName, regionName },
+      `Map region not found: ${regionName}`
+    )
+  }
+
+  /**
+   * Show a resolved region in the DM map window, opening it if needed
+   */
+  async focus(campaignId: string, resolved: ResolvedMapRegion, moduleId?: string | null): Promise<void> {
+    await invoke('focus_dm_map_region', {
+      campaignId,
+      moduleId: resolved.module_id ?? moduleId ?? null,
+      mapId: resolved.map_id,
+      regionId: resolved.region.id
+    })
+  }
+}
+
+export const MapRegionService = new MapRegionServiceClass()
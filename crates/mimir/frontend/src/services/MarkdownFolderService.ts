@@ -0,0 +1,66 @@
+/**
+ * Markdown Folder Service
+ *
+ * Exports a campaign as a folder of markdown files and watches that folder
+ * for edits made in external editors via Tauri commands.
+ * Types match the mimir WatchedFolder struct.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, MarkdownExportResult, WatchedFolder } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Markdown Folder Service
+// =============================================================================
+
+class MarkdownFolderServiceClass {
+  /**
+   * Export a campaign to `<dest>/<campaign-slug>/` once, without watching it;
+   * exporting into the same folder again updates it in place
+   */
+  export(campaignId: string, dest: string): Promise<MarkdownExportResult> {
+    return call('export_campaign_markdown', { campaignId, dest }, 'Failed to export campaign')
+  }
+
+  /**
+   * Export a campaign to `<dest>/<campaign-slug>/` and watch that folder,
+   * replacing any folder already watched for the campaign
+   */
+  startWatch(campaignId: string, dest: string): Promise<WatchedFolder> {
+    return call('start_document_watch', { campaignId, dest }, 'Failed to watch folder')
+  }
+
+  /**
+   * Stop watching a campaign's folder; false if it wasn't watched
+   */
+  stopWatch(campaignId: string): Promise<boolean> {
+    return call('stop_document_watch', { campaignId }, 'Failed to stop watching folder')
+  }
+
+  /**
+   * List the folders currently being watched
+   */
+  listWatches(): Promise<WatchedFolder[]> {
+    return call('list_document_watches', {}, 'Failed to load watched folders')
+  }
+
+  /**
+   * The folder watched for a campaign, if any
+   */
+  async watchFor(campaignId: string): Promise<WatchedFolder | null> {
+    const watches = await this.listWatches()
+    return watches.find(w => w.campaign_id === campaignId) ?? null
+  }
+}
+
+export const MarkdownFolderService = new MarkdownFolderServiceClass()
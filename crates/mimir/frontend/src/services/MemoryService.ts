@@ -0,0 +1,60 @@
+/**
+ * Memory Service
+ *
+ * Provides access to the facts the AI assistant has remembered about a
+ * campaign via Tauri commands, so the DM can review and correct them.
+ * Types match mimir-core CampaignMemory models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, CampaignMemory } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Memory Service
+// =============================================================================
+
+class MemoryServiceClass {
+  /**
+   * List a campaign's memories, most recently updated first
+   */
+  list(campaignId: string): Promise<CampaignMemory[]> {
+    return call('list_campaign_memories', { campaignId }, 'Failed to list memories')
+  }
+
+  /**
+   * Add a memory, or replace the one with the same name
+   */
+  remember(campaignId: string, name: string, content: string): Promise<CampaignMemory> {
+    return call('remember_campaign_memory', { campaignId, name, content }, 'Failed to save memory')
+  }
+
+  /**
+   * Rename a memory or change what it says
+   */
+  update(memoryId: string, changes: { name?: string; content?: string }): Promise<CampaignMemory> {
+    return call(
+      'update_campaign_memory',
+      { memoryId, name: changes.name ?? null, content: changes.content ?? null },
+      'Failed to update memory'
+    )
+  }
+
+  /**
+   * Forget a memory
+   */
+  delete(memoryId: string): Promise<void> {
+    return call('delete_campaign_memory', { memoryId }, 'Failed to delete memory')
+  }
+}
+
+export const MemoryService = new MemoryServiceClass()
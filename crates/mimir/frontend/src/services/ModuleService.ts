@@ -6,7 +6,7 @@
  */
 
 import { invoke } from '@tauri-apps/api/core'
-import type { ApiResponse, Module } from '@/types/api'
+import type { ApiResponse, Module, ModulePrep, ModuleWithPrep } from '@/types/api'
 import { dataEvents } from '@/utils/dataEvents'
 
 // =============================================================================
@@ -46,10 +46,10 @@ class ModuleServiceClass {
   }
 
   /**
-   * Get a module by ID
+   * Get a module by ID, with its prep checklist
    */
-  async get(id: string): Promise<Module> {
-    const response = await invoke<ApiResponse<Module>>('get_module', { id })
+  async get(id: string): Promise<ModuleWithPrep> {
+    const response = await invoke<ApiResponse<ModuleWithPrep>>('get_module', { id })
 
     if (response.success && response.data) {
       return response.data
@@ -142,6 +142,24 @@ class ModuleServiceClass {
     throw new Error(response.error || `Failed to reorder module ${moduleId}`)
   }
 
+  /**
+   * Tick a prep checklist task off by hand, or untick it.
+   * Returns the updated checklist.
+   */
+  async setPrepTask(moduleId: string, taskKey: string, checked: boolean): Promise<ModulePrep> {
+    const response = await invoke<ApiResponse<ModulePrep>>('set_module_prep_task', {
+      moduleId,
+      taskKey,
+      checked
+    })
+
+    if (response.success && response.data) {
+      return response.data
+    }
+
+    throw new Error(response.error || `Failed to update prep task ${taskKey}`)
+  }
+
   /**
    * Update module status/stage (stub - backend doesn't have stages)
    * @deprecated The new backend doesn't support module stages
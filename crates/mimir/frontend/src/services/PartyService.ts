@@ -0,0 +1,148 @@
+/**
+ * Party Service
+ *
+ * Provides access to parties, party membership, party XP, encounter
+ * difficulty, and party timelines via Tauri commands.
+ * Types match mimir-core Party models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  EncounterBudget,
+  EncounterEvaluation,
+  EncounterMonster,
+  Party,
+  PartyTimelineEvent,
+  PartyXpSummary,
+  TimelineEventInput,
+  UpdatePartyRequest
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Party Service
+// =============================================================================
+
+class PartyServiceClass {
+  /**
+   * List all parties for a campaign
+   */
+  list(campaignId: string): Promise<Party[]> {
+    return call('list_parties', { campaignId }, 'Failed to list parties')
+  }
+
+  /**
+   * Create a party
+   */
+  create(campaignId: string, name: string, description?: string, color?: string): Promise<Party> {
+    return call('create_party', { campaignId, name, description, color }, 'Failed to create party')
+  }
+
+  /**
+   * Update a party
+   */
+  update(id: string, request: UpdatePartyRequest): Promise<Party> {
+    return call('update_party', { id, request }, `Failed to update party ${id}`)
+  }
+
+  /**
+   * Delete a party; its sessions are kept with no party
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_party', { id }, `Failed to delete party ${id}`)
+  }
+
+  /**
+   * Add a player character to a party
+   */
+  addMember(partyId: string, characterId: string): Promise<void> {
+    return call('add_party_member', { partyId, characterId }, 'Failed to add party member')
+  }
+
+  /**
+   * Remove a character from a party
+   */
+  removeMember(partyId: string, characterId: string): Promise<void> {
+    return call('remove_party_member', { partyId, characterId }, 'Failed to remove party member')
+  }
+
+  /**
+   * List the parties a character belongs to
+   */
+  listForCharacter(characterId: string): Promise<Party[]> {
+    return call('list_character_parties', { characterId }, 'Failed to list character parties')
+  }
+
+  /**
+   * XP totals for a party and its members (living members unless includeDead)
+   */
+  getXp(partyId: string, includeDead = false): Promise<PartyXpSummary> {
+    return call('get_party_xp', { partyId, includeDead }, 'Failed to load party XP')
+  }
+
+  /**
+   * Encounter XP thresholds for a party (living members unless includeDead)
+   */
+  getEncounterBudget(partyId: string, includeDead = false): Promise<EncounterBudget> {
+    return call(
+      'get_party_encounter_budget',
+      { partyId, includeDead },
+      'Failed to load encounter budget'
+    )
+  }
+
+  /**
+   * Rate a group of monsters against a party (living members unless includeDead)
+   */
+  evaluateEncounter(
+    partyId: string,
+    monsters: EncounterMonster[],
+    includeDead = false
+  ): Promise<EncounterEvaluation> {
+    return call(
+      'evaluate_party_encounter',
+      { partyId, monsters, includeDead },
+      'Failed to evaluate encounter'
+    )
+  }
+
+  /**
+   * Set a party's current in-world day
+   */
+  setDay(partyId: string, day: number): Promise<Party> {
+    return call('set_party_day', { partyId, day }, 'Failed to set party day')
+  }
+
+  /**
+   * A party's timeline in calendar order
+   */
+  timeline(partyId: string): Promise<PartyTimelineEvent[]> {
+    return call('list_party_timeline', { partyId }, 'Failed to load party timeline')
+  }
+
+  /**
+   * Add an event to a party's timeline
+   */
+  addTimelineEvent(partyId: string, event: TimelineEventInput): Promise<PartyTimelineEvent> {
+    return call('add_party_timeline_event', { partyId, event }, 'Failed to add timeline event')
+  }
+
+  /**
+   * Delete a timeline event
+   */
+  deleteTimelineEvent(id: string): Promise<void> {
+    return call('delete_party_timeline_event', { id }, 'Failed to delete timeline event')
+  }
+}
+
+export const PartyService = new PartyServiceClass()
@@ -0,0 +1,130 @@
+/**
+ * Player Service
+ *
+ * Provides access to a campaign's player roster, character ownership,
+ * attendance, and character claim codes via Tauri commands.
+ * Types match mimir-core Player models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  CharacterSubmission,
+  Player,
+  PlayerAttendance,
+  PlayerClaim,
+  PlayerContact,
+  UpdatePlayerRequest
+} from '@/types/api'
+import type { Character } from '@/types/generated/Character'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+/**
+ * Format a claim code for sharing, e.g. "ABCD-2345"
+ */
+export function formatClaimCode(code: string): string {
+  const half = Math.floor(code.length / 2)
+  return `${code.slice(0, half)}-${code.slice(half)}`
+}
+
+// =============================================================================
+// Player Service
+// =============================================================================
+
+class PlayerServiceClass {
+  /**
+   * List all players for a campaign
+   */
+  list(campaignId: string): Promise<Player[]> {
+    return call('list_players', { campaignId }, 'Failed to list players')
+  }
+
+  /**
+   * Add a player to a campaign's roster
+   */
+  create(campaignId: string, name: string, contact?: PlayerContact): Promise<Player> {
+    return call('create_player', { campaignId, name, contact }, 'Failed to create player')
+  }
+
+  /**
+   * Update a player
+   */
+  update(id: string, request: UpdatePlayerRequest): Promise<Player> {
+    return call('update_player', { id, request }, `Failed to update player ${id}`)
+  }
+
+  /**
+   * Remove a player from the roster; their characters are kept
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_player', { id }, `Failed to delete player ${id}`)
+  }
+
+  /**
+   * Give a player character to a player
+   */
+  assignCharacter(playerId: string, characterId: string): Promise<void> {
+    return call('assign_player_character', { playerId, characterId }, 'Failed to assign character')
+  }
+
+  /**
+   * Take a character away from its player
+   */
+  unassignCharacter(characterId: string): Promise<void> {
+    return call('unassign_player_character', { characterId }, 'Failed to unassign character')
+  }
+
+  /**
+   * List a player's characters
+   */
+  listCharacters(playerId: string): Promise<Character[]> {
+    return call('list_player_characters', { playerId }, 'Failed to list player characters')
+  }
+
+  /**
+   * Sessions a player attended
+   */
+  getAttendance(playerId: string): Promise<PlayerAttendance> {
+    return call('get_player_attendance', { playerId }, 'Failed to load attendance')
+  }
+
+  /**
+   * Issue a claim code, optionally offering an existing character
+   */
+  createClaim(playerId: string, characterId?: string, expiresInDays?: number): Promise<PlayerClaim> {
+    return call('create_player_claim', { playerId, characterId, expiresInDays }, 'Failed to create claim code')
+  }
+
+  /**
+   * List a campaign's claim codes
+   */
+  listClaims(campaignId: string): Promise<PlayerClaim[]> {
+    return call('list_player_claims', { campaignId }, 'Failed to list claim codes')
+  }
+
+  /**
+   * Revoke a claim code
+   */
+  revokeClaim(id: string): Promise<void> {
+    return call('revoke_player_claim', { id }, 'Failed to revoke claim code')
+  }
+
+  /**
+   * Redeem a claim code, optionally submitting a new character
+   */
+  redeemClaim(code: string, character?: CharacterSubmission | string): Promise<Character> {
+    const characterJson = typeof character === 'string' ? character : character && JSON.stringify(character)
+    return call('redeem_player_claim', { code, characterJson }, 'Failed to redeem claim code')
+  }
+}
+
+export const PlayerService = new PlayerServiceClass()
@@ -66,6 +66,8 @@ export interface ModuleExportOptions {
   include_npcs?: boolean
   /** Include play notes (play-notes.md) */
   include_play_notes?: boolean
+  /** Include rumors leading into the module that no player has heard */
+  include_hooks?: boolean
   // Map Preview section
   /** Include map previews (fit to single page) */
   include_preview?: boolean
@@ -96,6 +98,27 @@ export interface CharacterExportOptions {
   include_spell_cards?: boolean
   /** Include equipment cards (weapons, magic items, special ammo) */
   include_equipment_cards?: boolean
+  /** Include a companion addendum page (silently no-op if no companions) */
+  include_companions?: boolean
+  /** Add fillable form fields to the compact sheet */
+  fillable_sheet?: boolean
+}
+
+/** Built-in character sheet layouts rendered by generate_character_sheet */
+export type CharacterSheetTemplate = 'minimalist' | 'classic' | 'npc-card'
+
+/** Options for exporting a wizard's spellbook as a grimoire booklet */
+export interface GrimoireExportOptions {
+  /** Blank spell pages at the back of the book (default 4) */
+  blank_pages?: number
+}
+
+/** Options for exporting a party reference to PDF */
+export interface PartyReferenceOptions {
+  /** Include battle cards for each party member */
+  include_battle_cards?: boolean
+  /** Include mount and vehicle stat blocks with the party's carrying capacity */
+  include_vehicles?: boolean
 }
 
 /** Options for exporting a campaign to PDF */
@@ -107,6 +130,8 @@ export interface CampaignExportOptions {
   include_module_content?: boolean
   /** Include campaign NPC sheets */
   include_npcs?: boolean
+  /** Include the safety tools, with DM-only notes */
+  include_safety_tools?: boolean
   // Module Maps options (sub-options for module content)
   /** Include module map previews scaled to fit one page */
   include_module_map_previews?: boolean
@@ -119,6 +144,71 @@ export interface CampaignExportOptions {
   include_campaign_map_previews?: boolean
   /** Include campaign maps at 1"=5ft scale for tabletop play */
   include_campaign_tiled_maps?: boolean
+  // Presentation options
+  /** Campaign image asset printed as full-page cover art */
+  cover_art_asset_id?: string
+  /** Campaign image asset shown as the logo on the title page */
+  logo_asset_id?: string
+  /** DM credited on the title page */
+  dm_name?: string
+  /** When the campaign ran, shown on the title page */
+  date_range?: string
+  /** Open each module's content with a divider page */
+  include_chapter_dividers?: boolean
+  /** Divider art by module ID (campaign image asset IDs) */
+  divider_art?: Record<string, string>
+  /** Include a chart of every party's timeline events */
+  include_timeline?: boolean
+  /** Swimlanes of the timeline chart (plotlines by default) */
+  timeline_grouping?: TimelineGrouping
+}
+
+/**
+ * How a campaign timeline chart is split into swimlanes: one per module,
+ * or one per faction of the NPCs each event mentions
+ */
+export type TimelineGrouping = 'plotline' | 'faction'
+
+/**
+ * Accessibility options applied to every template in an export
+ */
+export interface PrintProfile {
+  /** Multiplier for every text size (1 is the standard scale) */
+  font_scale: number
+  /** Black text and borders with no gray shading */
+  high_contrast: boolean
+  /** Use a dyslexia-friendly font when one is installed */
+  dyslexia_font: boolean
+  /** Single-column layouts without shaded boxes */
+  simplified: boolean
+}
+
+export const STANDARD_PRINT_PROFILE: PrintProfile = {
+  font_scale: 1,
+  high_contrast: false,
+  dyslexia_font: false,
+  simplified: false
+}
+
+/** Named starting points for the profile picker */
+export const PRINT_PROFILE_PRESETS: Record<string, { label: string; profile: PrintProfile }> = {
+  standard: { label: 'Standard', profile: STANDARD_PRINT_PROFILE },
+  large: {
+    label: 'Large Print',
+    profile: { ...STANDARD_PRINT_PROFILE, font_scale: 1.5, simplified: true }
+  },
+  contrast: {
+    label: 'High Contrast',
+    profile: { ...STANDARD_PRINT_PROFILE, high_contrast: true }
+  },
+  dyslexia: {
+    label: 'Dyslexia-Friendly',
+    profile: { ...STANDARD_PRINT_PROFILE, font_scale: 1.25, dyslexia_font: true, simplified: true }
+  },
+  simplified: {
+    label: 'Simplified',
+    profile: { ...STANDARD_PRINT_PROFILE, simplified: true }
+  }
 }
 
 class PrintServiceClass {
@@ -136,21 +226,20 @@ class PrintServiceClass {
   }
 
   /**
-   * Generate a character sheet PDF
+   * Generate a character sheet PDF from a built-in layout
    * @param characterId - The ID of the character
-   * @param template - Template variant to use ('sheet' or 'summary')
-   * @param includeSpellCards - Whether to include spell cards in the PDF (default: true)
-   * @deprecated Use generateCharacterExport instead for composable sections
+   * @param template - Layout to use: one-page 'minimalist', three-page 'classic', or half-page 'npc-card'
+   * @param profile - Accessibility print profile (standard if omitted)
    */
   async generateCharacterSheet(
     characterId: string,
-    template: 'sheet' | 'summary' = 'sheet',
-    includeSpellCards: boolean = true
+    template: CharacterSheetTemplate = 'classic',
+    profile?: PrintProfile
   ): Promise<PrintResult> {
     const response = await invoke<ApiResponse<PrintResult>>('generate_character_sheet', {
       characterId,
-      template: includeSpellCards ? undefined : `character/${template}`,
-      includeSpellCards
+      template,
+      profile
     })
 
     if (!response.success || !response.data) {
@@ -164,11 +253,17 @@ class PrintServiceClass {
    * Export character to PDF with composable sections
    * @param characterId - The ID of the character
    * @param options - Export options for section selection
+   * @param profile - Accessibility print profile (standard if omitted)
    */
-  async generateCharacterExport(characterId: string, options?: CharacterExportOptions): Promise<PrintResult> {
+  async generateCharacterExport(
+    characterId: string,
+    options?: CharacterExportOptions,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
     const response = await invoke<ApiResponse<PrintResult>>('export_character', {
       characterId,
-      options
+      options,
+      profile
     })
 
     if (!response.success || !response.data) {
@@ -178,13 +273,84 @@ class PrintServiceClass {
     return response.data
   }
 
+  /**
+   * Export a wizard's spellbook as a grimoire booklet
+   * @param characterId - The ID of the character
+   * @param options - Grimoire options (blank pages for future spells)
+   * @param profile - Accessibility print profile (standard if omitted)
+   */
+  async exportGrimoire(
+    characterId: string,
+    options?: GrimoireExportOptions,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_grimoire', {
+      characterId,
+      options,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export grimoire')
+    }
+
+    return response.data
+  }
+
+  /**
+   * Export battle cards with tracking cards for every PC in a campaign
+   * @param campaignId - The campaign ID
+   * @param profile - Accessibility print profile (standard if omitted)
+   * @returns PDF result with base64 data
+   */
+  async exportBattleCards(campaignId: string, profile?: PrintProfile): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_battle_cards', {
+      campaignId,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export battle cards')
+    }
+
+    return response.data
+  }
+
+  /**
+   * Export a party reference: member battle cards plus mount and vehicle
+   * stat blocks with the party's carrying capacity
+   * @param partyId - The party ID
+   * @param options - Sections to include
+   * @param profile - Accessibility print profile (standard if omitted)
+   * @returns PDF result with base64 data
+   */
+  async exportPartyReference(
+    partyId: string,
+    options?: PartyReferenceOptions,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_party_reference', {
+      partyId,
+      options,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export party reference')
+    }
+
+    return response.data
+  }
+
   /**
    * Export a single campaign document to PDF
    * @param documentId - The ID of the campaign document
+   * @param profile - Accessibility print profile (standard if omitted)
    */
-  async exportCampaignDocument(documentId: string): Promise<PrintResult> {
+  async exportCampaignDocument(documentId: string, profile?: PrintProfile): Promise<PrintResult> {
     const response = await invoke<ApiResponse<PrintResult>>('export_campaign_document', {
-      documentId
+      documentId,
+      profile
     })
 
     if (!response.success || !response.data) {
@@ -198,11 +364,17 @@ class PrintServiceClass {
    * Export all campaign documents as a combined PDF
    * @param campaignId - The ID of the campaign
    * @param options - Export options for content selection
+   * @param profile - Accessibility print profile (standard if omitted)
    */
-  async exportCampaignDocuments(campaignId: string, options?: CampaignExportOptions): Promise<PrintResult> {
+  async exportCampaignDocuments(
+    campaignId: string,
+    options?: CampaignExportOptions,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
     const response = await invoke<ApiResponse<PrintResult>>('export_campaign_documents', {
       campaignId,
-      options
+      options,
+      profile
     })
 
     if (!response.success || !response.data) {
@@ -212,15 +384,89 @@ class PrintServiceClass {
     return response.data
   }
 
+  /**
+   * Export a campaign retrospective: session log, combat pacing, prep per
+   * week, and the most-used monsters and spells
+   * @param campaignId - The ID of the campaign
+   * @param profile - Accessibility print profile (standard if omitted)
+   */
+  async exportCampaignRetrospective(campaignId: string, profile?: PrintProfile): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_campaign_retrospective', {
+      campaignId,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export campaign retrospective')
+    }
+
+    return response.data
+  }
+
+  /**
+   * Export a campaign's outstanding secret notes as fold-shut slips.
+   * Printing doesn't mark anything delivered.
+   * @param campaignId - The ID of the campaign
+   * @param playerId - Only print this player's notes
+   * @param profile - Accessibility print profile (standard if omitted)
+   */
+  async exportSecretSlips(
+    campaignId: string,
+    playerId?: string,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_secret_slips', {
+      campaignId,
+      playerId: playerId ?? null,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export secret slips')
+    }
+
+    return response.data
+  }
+
+  /**
+   * Export every party's timeline events as a chart across in-game days
+   * @param campaignId - The ID of the campaign
+   * @param grouping - Swimlanes to split the events into (plotlines if omitted)
+   * @param profile - Accessibility print profile (standard if omitted)
+   */
+  async exportCampaignTimeline(
+    campaignId: string,
+    grouping?: TimelineGrouping,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_campaign_timeline', {
+      campaignId,
+      grouping: grouping ?? null,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export campaign timeline')
+    }
+
+    return response.data
+  }
+
   /**
    * Export a single module's documents and monsters as PDF
    * @param moduleId - The ID of the module
    * @param options - Export options for content selection
+   * @param profile - Accessibility print profile (standard if omitted)
    */
-  async exportModuleDocuments(moduleId: string, options?: ModuleExportOptions): Promise<PrintResult> {
+  async exportModuleDocuments(
+    moduleId: string,
+    options?: ModuleExportOptions,
+    profile?: PrintProfile
+  ): Promise<PrintResult> {
     const response = await invoke<ApiResponse<PrintResult>>('export_module_documents', {
       moduleId,
-      options
+      options,
+      profile
     })
 
     if (!response.success || !response.data) {
@@ -234,11 +480,13 @@ class PrintServiceClass {
    * Print a map to PDF with configurable options
    * @param mapId - The ID of the map
    * @param options - Print options (mode, overlays, etc.)
+   * @param profile - Accessibility print profile (standard if omitted)
    */
-  async printMap(mapId: string, options?: MapPrintOptions): Promise<PrintResult> {
+  async printMap(mapId: string, options?: MapPrintOptions, profile?: PrintProfile): Promise<PrintResult> {
     const response = await invoke<ApiResponse<PrintResult>>('print_map', {
       mapId,
-      options
+      options,
+      profile
     })
 
     if (!response.success || !response.data) {
@@ -248,6 +496,25 @@ class PrintServiceClass {
     return response.data
   }
 
+  /**
+   * Export a map's location key: the map with numbered POIs and the key
+   * document with a section per location. Regenerates the key first.
+   * @param mapId - The ID of the map
+   * @param profile - Accessibility print profile (standard if omitted)
+   */
+  async exportLocationKey(mapId: string, profile?: PrintProfile): Promise<PrintResult> {
+    const response = await invoke<ApiResponse<PrintResult>>('export_location_key', {
+      mapId,
+      profile
+    })
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to export location key')
+    }
+
+    return response.data
+  }
+
   /**
    * Convert base64 PDF to Blob for display/download
    */
@@ -0,0 +1,117 @@
+/**
+ * Quick Action Service
+ *
+ * Lists, edits, reorders, and runs a campaign's quick actions (the DM
+ * soundboard) via Tauri commands. Types match mimir-core QuickActionResponse.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  QuickAction,
+  QuickActionStep,
+  QuickActionStepType,
+  TriggerQuickActionResult
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+export interface QuickActionInput {
+  name: string
+  description: string | null
+  steps: QuickActionStep[]
+}
+
+/**
+ * A new step of the given type with empty targets and default settings
+ */
+export function newQuickActionStep(type: QuickActionStepType): QuickActionStep {
+  switch (type) {
+    case 'reveal_rect':
+      return { type, map_id: '', x: 0, y: 0, width: 100, height: 100 }
+    case 'reveal_circle':
+      return { type, map_id: '', x: 0, y: 0, radius: 50 }
+    case 'set_light':
+      return { type, light_id: '', active: true }
+    case 'set_token_hidden':
+      return { type, token_id: '', hidden: false }
+    case 'set_poi_visible':
+      return { type, poi_id: '', visible: true }
+    case 'set_trap_visible':
+      return { type, trap_id: '', visible: true }
+    case 'send_handout':
+      return { type, kind: 'npc_portrait', image_path: '', title: null, screen: null }
+    case 'blackout':
+      return { type, enabled: true, screen: null }
+    case 'play_ambience':
+      return { type, track: '' }
+    case 'start_timer':
+      return { type, timer: 'turn', limit_secs: null, label: null }
+  }
+}
+
+// =============================================================================
+// Quick Action Service
+// =============================================================================
+
+class QuickActionServiceClass {
+  /**
+   * List a campaign's quick actions in soundboard order
+   */
+  list(campaignId: string): Promise<QuickAction[]> {
+    return call('list_quick_actions', { campaignId }, 'Failed to load quick actions')
+  }
+
+  /**
+   * Create a quick action at the end of the soundboard
+   */
+  create(campaignId: string, input: QuickActionInput): Promise<QuickAction> {
+    return call(
+      'create_quick_action',
+      { request: { campaignId, ...input } },
+      'Failed to create quick action'
+    )
+  }
+
+  /**
+   * Replace a quick action's name, description, and steps
+   */
+  update(id: string, input: QuickActionInput): Promise<QuickAction> {
+    return call('update_quick_action', { id, request: input }, 'Failed to update quick action')
+  }
+
+  /**
+   * Put a campaign's quick actions in the given order
+   */
+  reorder(campaignId: string, actionIds: string[]): Promise<QuickAction[]> {
+    return call(
+      'reorder_quick_actions',
+      { campaignId, actionIds },
+      'Failed to reorder quick actions'
+    )
+  }
+
+  /**
+   * Delete a quick action
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_quick_action', { id }, 'Failed to delete quick action')
+  }
+
+  /**
+   * Run every step of a quick action. Nothing changes if any step can't run.
+   */
+  trigger(id: string): Promise<TriggerQuickActionResult> {
+    return call('trigger_quick_action', { id }, 'Failed to run quick action')
+  }
+}
+
+export const QuickActionService = new QuickActionServiceClass()
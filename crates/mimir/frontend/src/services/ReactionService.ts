@@ -0,0 +1,101 @@
+/**
+ * Reaction Service
+ *
+ * Makes NPC reaction checks and manages the dispositions, party
+ * reputations, and relationship history behind them via Tauri commands.
+ * Types match mimir-core relationship models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  Disposition,
+  NpcInteraction,
+  PartyReputation,
+  ReactionCheck,
+  ReactionCheckInput,
+  RelationshipStandings
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Reaction Service
+// =============================================================================
+
+class ReactionServiceClass {
+  /**
+   * Get a campaign's dispositions and party reputations
+   */
+  standings(campaignId: string): Promise<RelationshipStandings> {
+    return call('get_relationship_standings', { campaignId }, 'Failed to load standings')
+  }
+
+  /**
+   * Set an NPC's own disposition, or clear it with null
+   */
+  setNpcDisposition(characterId: string, disposition: number | null): Promise<Disposition | null> {
+    return call('set_npc_disposition', { characterId, disposition }, 'Failed to save disposition')
+  }
+
+  /**
+   * Set a faction's disposition, or clear it with null
+   */
+  setFactionDisposition(
+    campaignId: string,
+    faction: string,
+    disposition: number | null
+  ): Promise<Disposition | null> {
+    return call(
+      'set_faction_disposition',
+      { campaignId, faction, disposition },
+      'Failed to save disposition'
+    )
+  }
+
+  /**
+   * Set a party's reputation with a faction, or clear it with null
+   */
+  setPartyReputation(
+    partyId: string,
+    faction: string,
+    reputation: number | null
+  ): Promise<PartyReputation | null> {
+    return call(
+      'set_party_reputation',
+      { partyId, faction, reputation },
+      'Failed to save reputation'
+    )
+  }
+
+  /**
+   * Make a reaction check and record it in the NPC's history
+   */
+  roll(input: ReactionCheckInput): Promise<ReactionCheck> {
+    return call('roll_npc_reaction', { input }, 'Failed to roll reaction')
+  }
+
+  /**
+   * Get an NPC's relationship history, most recent first
+   */
+  history(characterId: string): Promise<NpcInteraction[]> {
+    return call('list_npc_interactions', { characterId }, 'Failed to load relationship history')
+  }
+
+  /**
+   * Remove a check from an NPC's relationship history
+   */
+  deleteInteraction(id: string): Promise<void> {
+    return call('delete_npc_interaction', { id }, 'Failed to delete interaction')
+  }
+}
+
+export const ReactionService = new ReactionServiceClass()
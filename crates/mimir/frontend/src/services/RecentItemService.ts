@@ -0,0 +1,86 @@
+/**
+ * Recent Item Service
+ *
+ * Records recently opened catalog entries, documents, and maps, and manages
+ * pinned favorites, for the "jump back in" panel via Tauri commands.
+ * Types match mimir-core RecentItem and Favorite models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, Favorite, ItemRefRequest, RecentItem } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Recent Item Service
+// =============================================================================
+
+class RecentItemServiceClass {
+  /**
+   * Record that the DM opened an item
+   */
+  record(item: ItemRefRequest): Promise<RecentItem> {
+    return call('record_recent_item', { item }, 'Failed to record recent item')
+  }
+
+  /**
+   * Record an opened item without letting a failure interrupt what opened it
+   */
+  recordQuietly(item: ItemRefRequest): void {
+    this.record(item).catch(e => console.error('Failed to record recent item:', e))
+  }
+
+  /**
+   * List recently opened items, most recent first. `campaignId` hides
+   * documents and maps from other campaigns.
+   */
+  list(options: { kind?: string; limit?: number; campaignId?: string } = {}): Promise<RecentItem[]> {
+    return call('get_recent', options, 'Failed to load recent items')
+  }
+
+  /**
+   * Remove one item from the recent history
+   */
+  remove(id: string): Promise<void> {
+    return call('remove_recent_item', { id }, 'Failed to remove recent item')
+  }
+
+  /**
+   * Clear the recent history, returning how many items were removed
+   */
+  clear(): Promise<number> {
+    return call('clear_recent_items', {}, 'Failed to clear recent items')
+  }
+
+  /**
+   * Pin an item to favorites
+   */
+  pinFavorite(item: ItemRefRequest): Promise<Favorite> {
+    return call('pin_favorite', { item }, 'Failed to pin favorite')
+  }
+
+  /**
+   * Unpin a favorite
+   */
+  unpinFavorite(id: string): Promise<void> {
+    return call('unpin_favorite', { id }, 'Failed to unpin favorite')
+  }
+
+  /**
+   * List favorites in display order. `campaignId` hides documents and maps
+   * from other campaigns.
+   */
+  listFavorites(options: { kind?: string; campaignId?: string } = {}): Promise<Favorite[]> {
+    return call('list_favorites', options, 'Failed to load favorites')
+  }
+}
+
+export const RecentItemService = new RecentItemServiceClass()
@@ -0,0 +1,99 @@
+/**
+ * Rumor Service
+ *
+ * Manages a campaign's rumors and adventure hooks, who has heard them, and
+ * tavern rumor tables via Tauri commands.
+ * Types match mimir-core Rumor models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  Rumor,
+  RumorInput,
+  RumorWithHearings,
+  TavernRumorTable
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Rumor Service
+// =============================================================================
+
+class RumorServiceClass {
+  /**
+   * List a campaign's rumors with who has heard each one
+   */
+  list(campaignId: string): Promise<RumorWithHearings[]> {
+    return call('list_rumors', { campaignId }, 'Failed to load rumors')
+  }
+
+  /**
+   * Create a rumor in a campaign
+   */
+  create(campaignId: string, input: RumorInput): Promise<Rumor> {
+    return call('create_rumor', { campaignId, input }, 'Failed to create rumor')
+  }
+
+  /**
+   * Replace a rumor's text, truth, source, quest, and notes
+   */
+  update(id: string, input: RumorInput): Promise<Rumor> {
+    return call('update_rumor', { id, input }, 'Failed to update rumor')
+  }
+
+  /**
+   * Delete a rumor
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_rumor', { id }, 'Failed to delete rumor')
+  }
+
+  /**
+   * Record that players heard a rumor, optionally in a given session
+   */
+  markHeard(rumorId: string, playerIds: string[], sessionId?: string): Promise<RumorWithHearings> {
+    return call(
+      'mark_rumor_heard',
+      { rumorId, playerIds, sessionId: sessionId ?? null },
+      'Failed to mark rumor heard'
+    )
+  }
+
+  /**
+   * Remove the record of a player hearing a rumor
+   */
+  unmarkHeard(rumorId: string, playerId: string): Promise<RumorWithHearings> {
+    return call('unmark_rumor_heard', { rumorId, playerId }, 'Failed to unmark rumor heard')
+  }
+
+  /**
+   * Roll up a tavern rumor table, favoring rumors the players haven't heard
+   */
+  tavernTable(
+    campaignId: string,
+    options: { size?: number; moduleId?: string; seed?: number } = {}
+  ): Promise<TavernRumorTable> {
+    return call(
+      'generate_tavern_rumor_table',
+      {
+        campaignId,
+        size: options.size ?? null,
+        moduleId: options.moduleId ?? null,
+        seed: options.seed ?? null
+      },
+      'Failed to generate rumor table'
+    )
+  }
+}
+
+export const RumorService = new RumorServiceClass()
@@ -0,0 +1,56 @@
+/**
+ * Safety Tools Service
+ *
+ * Provides access to a campaign's safety tools (lines, veils, and table
+ * mechanics) via Tauri commands.
+ * Types match the mimir-core SafetyTools service type.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, Document, SafetyTools } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+class SafetyToolsServiceClass {
+  /**
+   * Get a campaign's safety tools (defaults if never configured)
+   */
+  get(campaignId: string): Promise<SafetyTools> {
+    return call('get_safety_tools', { campaignId }, 'Failed to get safety tools')
+  }
+
+  /**
+   * Save a campaign's safety tools
+   */
+  update(campaignId: string, tools: SafetyTools): Promise<SafetyTools> {
+    return call('update_safety_tools', { campaignId, tools }, 'Failed to save safety tools')
+  }
+
+  /**
+   * Write the session zero "Safety Tools" document
+   */
+  generateDocument(campaignId: string): Promise<Document> {
+    return call(
+      'generate_safety_tools_document',
+      { campaignId },
+      'Failed to generate safety tools document'
+    )
+  }
+
+  /**
+   * Get the content constraints for an assistant's system prompt
+   */
+  getPrompt(campaignId: string): Promise<string | null> {
+    return call('get_safety_prompt', { campaignId }, 'Failed to get safety prompt')
+  }
+}
+
+export const SafetyToolsService = new SafetyToolsServiceClass()
@@ -0,0 +1,70 @@
+/**
+ * Secret Note Service
+ *
+ * Manages a campaign's per-player secret notes and their delivery status
+ * via Tauri commands.
+ * Types match mimir-core SecretNote models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, SecretNote, SecretNoteInput } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Secret Note Service
+// =============================================================================
+
+class SecretNoteServiceClass {
+  /**
+   * List a campaign's secret notes, delivered ones included
+   */
+  list(campaignId: string): Promise<SecretNote[]> {
+    return call('list_secret_notes', { campaignId }, 'Failed to load secret notes')
+  }
+
+  /**
+   * Queue a secret note for a player
+   */
+  create(campaignId: string, input: SecretNoteInput): Promise<SecretNote> {
+    return call('create_secret_note', { campaignId, input }, 'Failed to create secret note')
+  }
+
+  /**
+   * Replace a secret note's player, title, content, and module
+   */
+  update(id: string, input: SecretNoteInput): Promise<SecretNote> {
+    return call('update_secret_note', { id, input }, 'Failed to update secret note')
+  }
+
+  /**
+   * Delete a secret note
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_secret_note', { id }, 'Failed to delete secret note')
+  }
+
+  /**
+   * Mark a secret note delivered in the session in progress
+   */
+  markDelivered(id: string): Promise<SecretNote> {
+    return call('mark_secret_note_delivered', { id }, 'Failed to mark secret note delivered')
+  }
+
+  /**
+   * Put a delivered secret note back in the queue
+   */
+  markOutstanding(id: string): Promise<SecretNote> {
+    return call('mark_secret_note_outstanding', { id }, 'Failed to mark secret note outstanding')
+  }
+}
+
+export const SecretNoteService = new SecretNoteServiceClass()
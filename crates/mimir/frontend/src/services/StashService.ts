@@ -0,0 +1,101 @@
+/**
+ * Stash Service
+ *
+ * Provides access to a campaign's shared stash, loot splitting, and the
+ * stash ledger via Tauri commands.
+ * Types match mimir-core CampaignStash models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  CampaignStash,
+  CampaignStashItem,
+  Coins,
+  SplitLootInput,
+  SplitLootResult,
+  StashItemInput,
+  StashLedgerEntry,
+} from '@/types/api'
+import type { CharacterInventory } from '@/types/generated/CharacterInventory'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Stash Service
+// =============================================================================
+
+class StashServiceClass {
+  /**
+   * Get a campaign's stash
+   */
+  get(campaignId: string): Promise<CampaignStash> {
+    return call('get_campaign_stash', { campaignId }, 'Failed to load stash')
+  }
+
+  /**
+   * Get the stash ledger, newest first
+   */
+  getLedger(campaignId: string): Promise<StashLedgerEntry[]> {
+    return call('get_stash_ledger', { campaignId }, 'Failed to load stash ledger')
+  }
+
+  /**
+   * Add catalog or homebrew items to the stash
+   */
+  addItem(campaignId: string, input: StashItemInput): Promise<CampaignStashItem> {
+    return call('add_stash_item', { campaignId, input }, 'Failed to add item to stash')
+  }
+
+  /**
+   * Remove items from play; without a quantity the whole stack goes
+   */
+  removeItem(id: string, quantity?: number, note?: string): Promise<void> {
+    return call('remove_stash_item', { id, quantity, note }, 'Failed to remove item from stash')
+  }
+
+  /**
+   * Move items from the stash into a character's inventory
+   */
+  takeItem(id: string, characterId: string, quantity?: number): Promise<CharacterInventory> {
+    return call('take_stash_item', { id, characterId, quantity }, 'Failed to take item from stash')
+  }
+
+  /**
+   * Add coins to the stash, returning the new balance
+   */
+  depositCurrency(campaignId: string, coins: Coins, note?: string): Promise<Coins> {
+    return call('deposit_stash_currency', { campaignId, coins, note }, 'Failed to deposit coins')
+  }
+
+  /**
+   * Remove coins from the stash, returning the new balance
+   */
+  withdrawCurrency(campaignId: string, coins: Coins, note?: string): Promise<Coins> {
+    return call('withdraw_stash_currency', { campaignId, coins, note }, 'Failed to withdraw coins')
+  }
+
+  /**
+   * Move coins into a character's purse, returning the stash's new balance
+   */
+  takeCurrency(campaignId: string, characterId: string, coins: Coins): Promise<Coins> {
+    return call('take_stash_currency', { campaignId, characterId, coins }, 'Failed to take coins')
+  }
+
+  /**
+   * Split coins evenly and hand out items to characters
+   */
+  splitLoot(campaignId: string, input: SplitLootInput): Promise<SplitLootResult> {
+    return call('split_stash_loot', { campaignId, input }, 'Failed to split loot')
+  }
+}
+
+export const StashService = new StashServiceClass()
@@ -0,0 +1,62 @@
+/**
+ * Stat Block Service
+ *
+ * Renders characters (usually NPCs) as monster-style stat blocks and adds
+ * them to modules as encounter participants via Tauri commands.
+ * Types match the mimir-core ModuleMonster model.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse } from '@/types/api'
+
+/** A monster entry in a module's encounter list */
+export interface ModuleMonster {
+  id: string
+  module_id: string
+  monster_name: string | null
+  monster_source: string | null
+  /** Set for stat blocks saved as campaign homebrew, as attached characters are */
+  homebrew_monster_id: string | null
+  display_name: string | null
+  notes: string | null
+  quantity: number
+  created_at: string
+  updated_at: string
+}
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Stat Block Service
+// =============================================================================
+
+class StatblockServiceClass {
+  /**
+   * Render a character as a stat block (5etools monster JSON)
+   */
+  convert(characterId: string): Promise<Record<string, any>> {
+    return call('convert_character_to_statblock', { characterId }, 'Failed to build stat block')
+  }
+
+  /**
+   * Add a character's stat block to a module's monsters; attaching the same
+   * character again refreshes the saved stat block
+   */
+  attachToModule(characterId: string, moduleId: string): Promise<ModuleMonster> {
+    return call(
+      'attach_character_statblock_to_module',
+      { characterId, moduleId },
+      'Failed to add stat block to module'
+    )
+  }
+}
+
+export const StatblockService = new StatblockServiceClass()
@@ -0,0 +1,88 @@
+/**
+ * Tag Service
+ *
+ * Creates, edits, and attaches campaign-wide tags via Tauri commands. Tags
+ * can go on documents, NPCs, module and homebrew monsters, maps, and assets.
+ * Types match mimir-core Tag and EntityTag.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, EntityTag, Tag, TaggableType } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Tag Service
+// =============================================================================
+
+class TagServiceClass {
+  /**
+   * List a campaign's tags alphabetically
+   */
+  list(campaignId: string): Promise<Tag[]> {
+    return call('list_tags', { campaignId }, 'Failed to load tags')
+  }
+
+  /**
+   * Create a tag
+   */
+  create(campaignId: string, name: string, color: string | null = null): Promise<Tag> {
+    return call('create_tag', { campaignId, name, color }, 'Failed to create tag')
+  }
+
+  /**
+   * Rename or recolor a tag; an empty color clears it
+   */
+  update(id: string, changes: { name?: string; color?: string }): Promise<Tag> {
+    return call('update_tag', { id, request: changes }, 'Failed to update tag')
+  }
+
+  /**
+   * Delete a tag, removing it from everything it was on
+   */
+  delete(id: string): Promise<void> {
+    return call('delete_tag', { id }, 'Failed to delete tag')
+  }
+
+  /**
+   * Attach a tag by name, creating the tag if the campaign doesn't have it
+   */
+  tag(campaignId: string, entityType: TaggableType, entityId: string, tagName: string): Promise<Tag> {
+    return call(
+      'tag_entity',
+      { campaignId, entityType, entityId, tagName },
+      'Failed to add tag'
+    )
+  }
+
+  /**
+   * Remove a tag from one piece of content
+   */
+  untag(tagId: string, entityType: TaggableType, entityId: string): Promise<void> {
+    return call('untag_entity', { tagId, entityType, entityId }, 'Failed to remove tag')
+  }
+
+  /**
+   * List the tags on one piece of content
+   */
+  listForEntity(entityType: TaggableType, entityId: string): Promise<Tag[]> {
+    return call('list_entity_tags', { entityType, entityId }, 'Failed to load tags')
+  }
+
+  /**
+   * List everything carrying a tag
+   */
+  listTagged(tagId: string): Promise<EntityTag[]> {
+    return call('list_tagged_entities', { tagId }, 'Failed to load tagged items')
+  }
+}
+
+export const TagService = new TagServiceClass()
@@ -0,0 +1,105 @@
+/**
+ * Timer Service
+ *
+ * Drives the table timers and game sessions via Tauri commands.
+ * Every change is also broadcast as `timers:update`.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, GameSession, TimerKind, TimersSnapshot } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Timer Service
+// =============================================================================
+
+class TimerServiceClass {
+  /**
+   * Get the current state of all table timers
+   */
+  get(): Promise<TimersSnapshot> {
+    return call('get_timers', {}, 'Failed to load timers')
+  }
+
+  /**
+   * Start or resume a timer, optionally as a countdown
+   */
+  start(kind: TimerKind, durationSecs?: number, label?: string): Promise<TimersSnapshot> {
+    return call('start_timer', { kind, durationSecs, label }, 'Failed to start timer')
+  }
+
+  /**
+   * Pause a timer; pausing the session clock saves the session totals
+   */
+  pause(kind: TimerKind): Promise<TimersSnapshot> {
+    return call('pause_timer', { kind }, 'Failed to pause timer')
+  }
+
+  /**
+   * Reset a timer to zero and stop it
+   */
+  reset(kind: TimerKind): Promise<TimersSnapshot> {
+    return call('reset_timer', { kind }, 'Failed to reset timer')
+  }
+
+  /**
+   * End the current combat turn and start timing the next
+   */
+  nextTurn(label?: string): Promise<TimersSnapshot> {
+    return call('next_turn_timer', { label }, 'Failed to start next turn')
+  }
+
+  /**
+   * Start a game session and its session clock
+   */
+  startSession(campaignId: string, moduleId?: string, title?: string, partyId?: string): Promise<GameSession> {
+    return call('start_game_session', { campaignId, moduleId, title, partyId }, 'Failed to start session')
+  }
+
+  /**
+   * End the session being timed, saving its totals
+   */
+  endSession(): Promise<GameSession> {
+    return call('end_game_session', {}, 'Failed to end session')
+  }
+
+  /**
+   * End a session left open, e.g. in another campaign
+   */
+  abandonSession(sessionId: string): Promise<GameSession> {
+    return call('abandon_game_session', { sessionId }, 'Failed to abandon session')
+  }
+
+  /**
+   * List a campaign's game sessions, most recent first
+   */
+  listSessions(campaignId: string, partyId?: string): Promise<GameSession[]> {
+    return call('list_game_sessions', { campaignId, partyId }, 'Failed to load sessions')
+  }
+
+  /**
+   * Set the XP each party member earned in a session
+   */
+  awardXp(sessionId: string, xp: number): Promise<GameSession> {
+    return call('award_session_xp', { sessionId, xp }, 'Failed to award XP')
+  }
+
+  /**
+   * Attach a transcript (plain text, WebVTT, or SRT) to a session; an empty
+   * string clears it
+   */
+  setTranscript(sessionId: string, transcript: string): Promise<GameSession> {
+    return call('set_session_transcript', { sessionId, transcript }, 'Failed to save transcript')
+  }
+}
+
+export const TimerService = new TimerServiceClass()
@@ -0,0 +1,50 @@
+/**
+ * Trash Service
+ *
+ * Lists, restores, and purges trashed documents, characters, and maps via
+ * Tauri commands. Types match mimir-core TrashedItem.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, TrashKind, TrashedItem } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Trash Service
+// =============================================================================
+
+class TrashServiceClass {
+  /**
+   * List trashed items, most recently trashed first; with `campaignId`, only
+   * that campaign's items
+   */
+  list(campaignId?: string): Promise<TrashedItem[]> {
+    return call('list_trashed_items', { campaignId }, 'Failed to load trash')
+  }
+
+  /**
+   * Restore a trashed item
+   */
+  restore(kind: TrashKind, id: string): Promise<void> {
+    return call('restore_item', { kind, id }, 'Failed to restore item')
+  }
+
+  /**
+   * Permanently delete trashed items, returning how many were purged. With
+   * `expiredOnly`, only items past their 30 days in the trash are removed.
+   */
+  purge(campaignId?: string, expiredOnly = false): Promise<number> {
+    return call('purge_trash', { campaignId, expiredOnly }, 'Failed to empty trash')
+  }
+}
+
+export const TrashService = new TrashServiceClass()
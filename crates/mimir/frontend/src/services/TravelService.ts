@@ -0,0 +1,36 @@
+/**
+ * Travel Service
+ *
+ * Plans overland journeys (days on the road, forced march saves, random
+ * encounter checks, food and water) via Tauri commands.
+ * Types match mimir-core TravelRequest and TravelPlan.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type { ApiResponse, TravelPlan, TravelRequest } from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Travel Service
+// =============================================================================
+
+class TravelServiceClass {
+  /**
+   * Plan a journey over a distance for a party's speeds, pace, terrain, and
+   * weather
+   */
+  plan(request: TravelRequest): Promise<TravelPlan> {
+    return call('plan_travel', { request }, 'Failed to plan travel')
+  }
+}
+
+export const TravelService = new TravelServiceClass()
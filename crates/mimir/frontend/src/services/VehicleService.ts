@@ -0,0 +1,90 @@
+/**
+ * Vehicle Service
+ *
+ * Provides access to mounts and vehicles owned by characters and parties,
+ * and a party's carrying capacity, via Tauri commands.
+ * Types match mimir-core OwnedVehicle models.
+ */
+
+import { invoke } from '@tauri-apps/api/core'
+import type {
+  ApiResponse,
+  AssignVehicleInput,
+  OwnedVehicle,
+  PartyCarryingCapacity,
+  UpdateOwnedVehicleInput,
+} from '@/types/api'
+
+async function call<T>(command: string, args: Record<string, unknown>, failure: string): Promise<T> {
+  const response = await invoke<ApiResponse<T>>(command, args)
+
+  if (response.success) {
+    return response.data as T
+  }
+
+  throw new Error(response.error || failure)
+}
+
+// =============================================================================
+// Vehicle Service
+// =============================================================================
+
+class VehicleServiceClass {
+  /**
+   * List every mount and vehicle owned in a campaign
+   */
+  listForCampaign(campaignId: string): Promise<OwnedVehicle[]> {
+    return call('list_campaign_vehicles', { campaignId }, 'Failed to list vehicles')
+  }
+
+  /**
+   * List a character's own mounts and vehicles
+   */
+  listForCharacter(characterId: string): Promise<OwnedVehicle[]> {
+    return call('list_character_vehicles', { characterId }, 'Failed to list vehicles')
+  }
+
+  /**
+   * List a party's mounts and vehicles, including its members'
+   */
+  listForParty(partyId: string): Promise<OwnedVehicle[]> {
+    return call('list_party_vehicles', { partyId }, 'Failed to list vehicles')
+  }
+
+  /**
+   * Assign a catalog mount or vehicle to a character or party
+   */
+  assign(input: AssignVehicleInput): Promise<OwnedVehicle> {
+    return call('assign_vehicle', { input }, 'Failed to assign vehicle')
+  }
+
+  /**
+   * Rename, annotate, or transfer an owned vehicle
+   */
+  update(id: string, input: UpdateOwnedVehicleInput): Promise<OwnedVehicle> {
+    return call('update_owned_vehicle', { id, input }, 'Failed to update vehicle')
+  }
+
+  /**
+   * Remove a mount or vehicle from its owner
+   */
+  remove(id: string): Promise<void> {
+    return call('remove_owned_vehicle', { id }, 'Failed to remove vehicle')
+  }
+
+  /**
+   * Get an owned vehicle's catalog stat block
+   */
+  getStatblock(id: string): Promise<Record<string, unknown>> {
+    return call('get_owned_vehicle_statblock', { id }, 'Failed to load vehicle stat block')
+  }
+
+  /**
+   * Get a party's cargo and passenger capacity against its inventory weight
+   */
+  getPartyCapacity(partyId: string): Promise<PartyCarryingCapacity> {
+    return call('get_party_capacity', { partyId }, 'Failed to load party capacity')
+  }
+}
+
+export const VehicleService = new VehicleServiceClass()
@@ -1,7 +1,12 @@
 import { describe, it, expect, beforeEach, vi, afterEach } from 'vitest'
 import { invoke } from '@tauri-apps/api/core'
 import { save } from '@tauri-apps/plugin-dialog'
-import { PrintService, type PrintResult, type ApiResponse } from '../PrintService'
+import {
+  PrintService,
+  PRINT_PROFILE_PRESETS,
+  type PrintResult,
+  type ApiResponse
+} from '../PrintService'
 
 // Mock Tauri APIs
 vi.mock('@tauri-apps/api/core', () => ({
@@ -90,7 +95,7 @@ describe('PrintService', () => {
   })
 
   describe('generateCharacterSheet', () => {
-    it('generates character sheet with default options', async () => {
+    it('generates the classic sheet by default', async () => {
       const mockResult = createMockPrintResult()
       mockInvoke.mockResolvedValueOnce({ success: true, data: mockResult })
 
@@ -98,8 +103,8 @@ describe('PrintService', () => {
 
       expect(mockInvoke).toHaveBeenCalledWith('generate_character_sheet', {
         characterId: 1,
-        template: undefined,
-        includeSpellCards: true
+        template: 'classic',
+        profile: undefined
       })
       expect(result).toEqual(mockResult)
     })
@@ -108,26 +113,26 @@ describe('PrintService', () => {
       const mockResult = createMockPrintResult()
       mockInvoke.mockResolvedValueOnce({ success: true, data: mockResult })
 
-      const result = await PrintService.generateCharacterSheet(1, 'summary')
+      const result = await PrintService.generateCharacterSheet(1, 'npc-card')
 
       expect(mockInvoke).toHaveBeenCalledWith('generate_character_sheet', {
         characterId: 1,
-        template: undefined,
-        includeSpellCards: true
+        template: 'npc-card',
+        profile: undefined
       })
       expect(result).toEqual(mockResult)
     })
 
-    it('generates character sheet without spell cards', async () => {
-      const mockResult = createMockPrintResult()
-      mockInvoke.mockResolvedValueOnce({ success: true, data: mockResult })
+    it('passes the accessibility profile', async () => {
+      mockInvoke.mockResolvedValueOnce({ success: true, data: createMockPrintResult() })
+      const profile = PRINT_PROFILE_PRESETS.dyslexia.profile
 
-      await PrintService.generateCharacterSheet(1, 'sheet', false)
+      await PrintService.generateCharacterSheet(1, 'minimalist', profile)
 
       expect(mockInvoke).toHaveBeenCalledWith('generate_character_sheet', {
         characterId: 1,
-        template: 'character/sheet',
-        includeSpellCards: false
+        template: 'minimalist',
+        profile
       })
     })
 
@@ -178,6 +183,32 @@ describe('PrintService', () => {
     })
   })
 
+  describe('exportGrimoire', () => {
+    it('exports the grimoire with blank pages', async () => {
+      const mockResult = createMockPrintResult()
+      mockInvoke.mockResolvedValueOnce({ success: true, data: mockResult })
+
+      const result = await PrintService.exportGrimoire('char-1', { blank_pages: 8 })
+
+      expect(mockInvoke).toHaveBeenCalledWith('export_grimoire', {
+        characterId: 'char-1',
+        options: { blank_pages: 8 },
+        profile: undefined
+      })
+      expect(result).toEqual(mockResult)
+    })
+
+    it('throws error on failure', async () => {
+      mockInvoke.mockResolvedValueOnce({
+        success: false,
+        error: 'Character has no spells in their spellbook'
+      })
+
+      await expect(PrintService.exportGrimoire('char-1'))
+        .rejects.toThrow('Character has no spells in their spellbook')
+    })
+  })
+
   describe('exportCampaignDocument', () => {
     it('exports single campaign document', async () => {
       const mockResult = createMockPrintResult()
@@ -310,6 +341,19 @@ describe('PrintService', () => {
       })
     })
 
+    it('passes the accessibility profile', async () => {
+      mockInvoke.mockResolvedValueOnce({ success: true, data: createMockPrintResult() })
+
+      const profile = PRINT_PROFILE_PRESETS.large.profile
+      await PrintService.printMap(10, undefined, profile)
+
+      expect(mockInvoke).toHaveBeenCalledWith('print_map', {
+        mapId: 10,
+        options: undefined,
+        profile: { font_scale: 1.5, high_contrast: false, dyslexia_font: false, simplified: true }
+      })
+    })
+
     it('throws error on failure', async () => {
       mockInvoke.mockResolvedValueOnce({ success: false, error: 'Map print failed' })
 
@@ -318,6 +362,30 @@ describe('PrintService', () => {
     })
   })
 
+  describe('exportLocationKey', () => {
+    it('exports the keyed map', async () => {
+      const mockResult = createMockPrintResult()
+      mockInvoke.mockResolvedValueOnce({ success: true, data: mockResult })
+
+      const result = await PrintService.exportLocationKey('map-1')
+
+      expect(mockInvoke).toHaveBeenCalledWith('export_location_key', {
+        mapId: 'map-1'
+      })
+      expect(result).toEqual(mockResult)
+    })
+
+    it('throws error on failure', async () => {
+      mockInvoke.mockResolvedValueOnce({
+        success: false,
+        error: 'Crypt has no points of interest to key'
+      })
+
+      await expect(PrintService.exportLocationKey('map-1'))
+        .rejects.toThrow('Crypt has no points of interest to key')
+    })
+  })
+
   describe('pdfToBlob', () => {
     it('converts base64 PDF to Blob', () => {
       const printResult = createMockPrintResult()
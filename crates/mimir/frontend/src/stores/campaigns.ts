@@ -1,7 +1,7 @@
 import { defineStore } from 'pinia'
 import { ref } from 'vue'
 import { invoke } from '@tauri-apps/api/core'
-import type { ApiResponse, ArchiveCounts, ArchivePreview, Campaign, CreateCampaignRequest, UpdateCampaignRequest } from '../types/api'
+import type { ApiResponse, ArchiveCounts, ArchivePreview, BackupEntry, Campaign, CloneCampaignOptions, CreateCampaignRequest, UpdateCampaignRequest } from '../types/api'
 import { dataEvents } from '@/utils/dataEvents'
 
 export const useCampaignStore = defineStore('campaigns', () => {
@@ -82,6 +82,33 @@ export const useCampaignStore = defineStore('campaigns', () => {
     }
   }
 
+  // Clone campaign under a new name
+  const cloneCampaign = async (campaignId: string, newName: string, options?: CloneCampaignOptions) => {
+    loading.value = true
+    error.value = null
+
+    try {
+      const response = await invoke<ApiResponse<Campaign>>('clone_campaign', {
+        campaignId,
+        newName,
+        options: options || null
+      })
+      if (response.success && response.data) {
+        campaigns.value.push(response.data)
+        dataEvents.emit('campaign:created', { campaignId: response.data.id })
+        return response.data
+      } else {
+        error.value = response.error || 'Failed to clone campaign'
+        return null
+      }
+    } catch (e) {
+      error.value = e instanceof Error ? e.message : 'Unknown error occurred'
+      return null
+    } finally {
+      loading.value = false
+    }
+  }
+
   // Update campaign
   const updateCampaign = async (id: string, request: UpdateCampaignRequest) => {
     loading.value = true
@@ -218,10 +245,11 @@ export const useCampaignStore = defineStore('campaigns', () => {
     }
   }
 
-  // Fetch campaign sources
+  // Fetch the sources catalog searches are limited to (allowed books narrowed
+  // to the campaign's edition)
   const fetchCampaignSources = async (campaignId: string) => {
     try {
-      const response = await invoke<ApiResponse<string[]>>('list_campaign_sources', { campaignId })
+      const response = await invoke<ApiResponse<string[] | null>>('list_campaign_search_sources', { campaignId })
       if (response.success && response.data) {
         currentCampaignSources.value = response.data
         return response.data
@@ -333,6 +361,75 @@ export const useCampaignStore = defineStore('campaigns', () => {
     }
   }
 
+  // Back up a campaign (differential backups store only changes since the last backup)
+  const createBackup = async (campaignId: string, differential: boolean): Promise<BackupEntry | null> => {
+    loading.value = true
+    error.value = null
+
+    try {
+      const response = await invoke<ApiResponse<BackupEntry>>('create_campaign_backup', {
+        campaignId,
+        differential
+      })
+      if (response.success && response.data) {
+        return response.data
+      } else {
+        error.value = response.error || 'Failed to back up campaign'
+        return null
+      }
+    } catch (e) {
+      error.value = e instanceof Error ? e.message : 'Unknown error occurred'
+      return null
+    } finally {
+      loading.value = false
+    }
+  }
+
+  // List a campaign's backups, oldest first
+  const listBackups = async (campaignId: string): Promise<BackupEntry[]> => {
+    try {
+      const response = await invoke<ApiResponse<BackupEntry[]>>('list_campaign_backups', {
+        campaignId
+      })
+      if (response.success && response.data) {
+        return response.data
+      } else {
+        error.value = response.error || 'Failed to list backups'
+        return []
+      }
+    } catch (e) {
+      error.value = e instanceof Error ? e.message : 'Unknown error occurred'
+      return []
+    }
+  }
+
+  // Restore a backup as a new campaign
+  const restoreBackup = async (campaignId: string, backupId: string, newName?: string): Promise<Campaign | null> => {
+    loading.value = true
+    error.value = null
+
+    try {
+      const response = await invoke<ApiResponse<{ campaign_id: string; campaign_name: string; counts: ArchiveCounts }>>('restore_campaign_backup', {
+        campaignId,
+        backupId,
+        newName: newName || null
+      })
+      if (response.success && response.data) {
+        await fetchCampaigns()
+        const restored = campaigns.value.find(c => c.id === response.data!.campaign_id)
+        return restored || null
+      } else {
+        error.value = response.error || 'Failed to restore backup'
+        return null
+      }
+    } catch (e) {
+      error.value = e instanceof Error ? e.message : 'Unknown error occurred'
+      return null
+    } finally {
+      loading.value = false
+    }
+  }
+
   // Get archive file extension
   const getArchiveExtension = (): string => {
     return 'mimir-campaign.tar.gz'
@@ -349,6 +446,7 @@ export const useCampaignStore = defineStore('campaigns', () => {
     fetchArchivedCampaigns,
     getCampaign,
     createCampaign,
+    cloneCampaign,
     updateCampaign,
     archiveCampaign,
     unarchiveCampaign,
@@ -360,6 +458,9 @@ export const useCampaignStore = defineStore('campaigns', () => {
     exportCampaign,
     importCampaign,
     previewArchive,
+    createBackup,
+    listBackups,
+    restoreBackup,
     getArchiveExtension
   }
 })
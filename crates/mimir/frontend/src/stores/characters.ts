@@ -63,15 +63,16 @@ export const useCharacterStore = defineStore('characters', () => {
   }
 
   /**
-   * Fetch only player characters for a campaign
+   * Fetch only player characters for a campaign, optionally limited to one party
    */
-  const fetchPcs = async (campaignId: string) => {
+  const fetchPcs = async (campaignId: string, partyId?: string) => {
     loading.value = true
     error.value = null
 
     try {
       const response = await invoke<ApiResponse<Character[]>>('list_pcs', {
-        campaignId
+        campaignId,
+        partyId
       })
       if (response.success && response.data) {
         // Update the characters list with PCs
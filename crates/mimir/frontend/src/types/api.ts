@@ -1,8 +1,31 @@
+import type { CharacterInventory } from './generated/CharacterInventory'
+import type { Document } from './generated/Document'
+import type { Module } from './generated/Module'
+
 // API Response types
 export interface ApiResponse<T> {
   success: boolean
   data?: T
   error?: string
+  error_details?: CommandError
+}
+
+// Structured command errors
+export type ErrorKind =
+  | 'not_found'
+  | 'validation'
+  | 'permission_denied'
+  | 'database'
+  | 'io'
+  | 'print'
+  | 'other'
+
+export interface CommandError {
+  kind: ErrorKind
+  entity?: string
+  message: string
+  recoverable: boolean
+  suggestion?: string
 }
 
 // Theme types
@@ -23,6 +46,14 @@ export interface CreateCampaignRequest {
   description?: string
 }
 
+/** Which characters to copy when cloning a campaign */
+export interface CloneCampaignOptions {
+  /** Copy player characters (default: false) */
+  include_player_characters?: boolean
+  /** Copy NPCs (default: true) */
+  include_npcs?: boolean
+}
+
 /** Request for updating a campaign */
 export interface UpdateCampaignRequest {
   name?: string
@@ -45,6 +76,30 @@ export interface ArchiveCounts {
   assets: number
 }
 
+/** Progress of a campaign archive export, sent after each file is written */
+export interface ArchiveProgress {
+  files_written: number
+  total_files: number
+  bytes_written: number
+  total_bytes: number
+  current_file: string
+}
+
+/** A campaign backup; differential backups build on their parent */
+export interface BackupEntry {
+  id: string
+  kind: 'full' | 'differential'
+  parent: string | null
+  created_at: string
+  file: string
+  size_bytes: number
+  sha256: string
+  changed_entities: number
+  removed_entities: number
+  changed_assets: number
+  removed_assets: number
+}
+
 /** A reference to a catalog item found in campaign content */
 export interface CatalogReference {
   type: string
@@ -82,6 +137,1571 @@ export interface UpdateModuleRequest {
   module_number?: number
 }
 
+/** `document`: fill in an overview section. `map`/`stat_block`: prepare maps, select monsters */
+export type PrepTaskKind = 'document' | 'map' | 'stat_block'
+
+/** One item of a module's prep checklist */
+export interface PrepTask {
+  /** Stable key, e.g. `document:dungeon-background` or `maps` */
+  key: string
+  kind: PrepTaskKind
+  title: string
+  /** Placeholders filled in, maps added, or stat blocks selected */
+  done: number
+  total: number
+  /** Ticked off by hand */
+  checked: boolean
+  complete: boolean
+  estimated_minutes: number
+  remaining_minutes: number
+}
+
+/** A module's prep checklist, derived from its type's template */
+export interface ModulePrep {
+  module_type: string
+  tasks: PrepTask[]
+  /** Share of the estimated prep time already done, 0-100 */
+  completion_percent: number
+  estimated_minutes_remaining: number
+}
+
+/** A module as returned by `get_module`, with its prep checklist */
+export interface ModuleWithPrep extends Module {
+  prep: ModulePrep
+}
+
+// =============================================================================
+// Party types
+// =============================================================================
+
+/** A group of player characters adventuring together within a campaign */
+export interface Party {
+  id: string
+  campaign_id: string
+  name: string
+  description: string | null
+  color: string | null
+  current_day: number
+  created_at: string
+  updated_at: string
+}
+
+/** Request for updating a party; empty strings clear optional fields */
+export interface UpdatePartyRequest {
+  name?: string
+  description?: string
+  color?: string
+}
+
+/** An event on a party's calendar timeline */
+export interface PartyTimelineEvent {
+  id: string
+  party_id: string
+  session_id: string | null
+  day: number
+  title: string
+  description: string | null
+  created_at: string
+}
+
+/** Input for a new timeline event; day defaults to the party's current day */
+export interface TimelineEventInput {
+  day?: number
+  title: string
+  description?: string
+  session_id?: string
+}
+
+/** XP totals for a party and each current member */
+export interface PartyXpSummary {
+  party_id: string
+  sessions_played: number
+  total_xp: number
+  members: { character_id: string; name: string; xp: number; companions: MemberCompanion[] }[]
+}
+
+/** A party member's companion, as shown in party summaries */
+export interface MemberCompanion {
+  id: string
+  name: string
+  companion_type: CompanionType
+  /** Sidekick level; counts toward encounter budgets */
+  level: number | null
+  current_hp: number
+  max_hp: number
+}
+
+/** Kind of owned conveyance: vehicle catalog entry or mount item (type MNT) */
+export type VehicleKind = 'vehicle' | 'mount'
+
+/** A mount or vehicle owned by a character or a whole party */
+export interface OwnedVehicle {
+  id: string
+  campaign_id: string
+  /** Exactly one of character_id and party_id is set */
+  character_id: string | null
+  party_id: string | null
+  name: string
+  vehicle_kind: VehicleKind
+  catalog_name: string
+  catalog_source: string
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** Who owns a mount or vehicle */
+export type VehicleOwner =
+  | { type: 'character'; character_id: string }
+  | { type: 'party'; party_id: string }
+
+/** Input for assigning a catalog mount or vehicle */
+export interface AssignVehicleInput {
+  owner: VehicleOwner
+  vehicle_kind: VehicleKind
+  catalog_name: string
+  catalog_source: string
+  /** Defaults to the catalog name */
+  name?: string
+  notes?: string
+}
+
+/** Input for renaming, annotating, or transferring an owned vehicle */
+export interface UpdateOwnedVehicleInput {
+  name?: string
+  notes?: string | null
+  owner?: VehicleOwner
+}
+
+/** Cargo and passenger capacity of one mount or vehicle */
+export interface VehicleCapacity {
+  id: string
+  name: string
+  vehicle_kind: VehicleKind
+  /** Owning character's name; null for party-owned vehicles */
+  owner_name: string | null
+  cargo_lb: number
+  passengers: number
+}
+
+/** A party's carrying capacity against what its members carry */
+export interface PartyCarryingCapacity {
+  party_id: string
+  vehicles: VehicleCapacity[]
+  cargo_capacity_lb: number
+  passenger_capacity: number
+  inventory_weight_lb: number
+  members: number
+  overloaded: boolean
+  short_of_seats: boolean
+}
+
+/** Coins as [CP, SP, EP, GP, PP] */
+export type Coins = [number, number, number, number, number]
+
+/** A stack of items in a campaign's shared stash */
+export interface CampaignStashItem {
+  id: string
+  campaign_id: string
+  item_name: string
+  item_source: string
+  quantity: number
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** A campaign's shared stash */
+export interface CampaignStash {
+  campaign_id: string
+  currency: Coins
+  items: CampaignStashItem[]
+}
+
+export type LedgerAction = 'deposit' | 'withdraw' | 'take'
+
+/** One movement of coins or items in or out of the stash */
+export interface StashLedgerEntry {
+  id: string
+  campaign_id: string
+  action: LedgerAction
+  /** Null once the character is deleted */
+  character_id: string | null
+  character_name: string | null
+  item_name: string | null
+  item_source: string | null
+  quantity: number
+  cp: number
+  sp: number
+  ep: number
+  gp: number
+  pp: number
+  note: string | null
+  created_at: string
+}
+
+/** Input for adding catalog (or "HB" homebrew) items to the stash */
+export interface StashItemInput {
+  item_name: string
+  item_source: string
+  quantity?: number
+  note?: string
+}
+
+/** Hand a stash item to a character during a loot split */
+export interface LootAssignment {
+  stash_item_id: string
+  character_id: string
+  /** Defaults to the whole stack */
+  quantity?: number
+}
+
+/** Input for splitting the stash between characters */
+export interface SplitLootInput {
+  character_ids: string[]
+  /** Share every coin evenly by denomination (default true) */
+  split_currency?: boolean
+  assignments?: LootAssignment[]
+}
+
+/** What one character received from a loot split */
+export interface LootShare {
+  character_id: string
+  name: string
+  currency: Coins
+  items: CharacterInventory[]
+}
+
+/** Result of a loot split; the remainder stays in the stash */
+export interface SplitLootResult {
+  shares: LootShare[]
+  remainder: Coins
+}
+
+/** An ingredient in one of a campaign's gathering tables */
+export interface ForageIngredient {
+  id: string
+  campaign_id: string
+  /** Environment or biome, matched case-insensitively */
+  environment: string
+  item_name: string
+  item_source: string
+  /** Lowest check total that finds it */
+  dc: number
+  /** Number or dice expression, e.g. "1d4" */
+  quantity: string
+  /** Relative chance among the ingredients a check finds */
+  weight: number
+  created_at: string
+  updated_at: string
+}
+
+/** Input for adding an ingredient to a gathering table */
+export interface AddForageIngredientInput {
+  environment: string
+  item_name: string
+  item_source: string
+  /** Default 10 */
+  dc?: number
+  /** Default "1" */
+  quantity?: string
+  /** Default 1 */
+  weight?: number
+}
+
+/** Input for changing an ingredient's DC, quantity or weight */
+export interface UpdateForageIngredientInput {
+  dc?: number
+  quantity?: string
+  weight?: number
+}
+
+/** Result of importing a catalog table into a gathering table */
+export interface ForageImportResult {
+  added: ForageIngredient[]
+  /** Rows skipped, with why */
+  skipped: string[]
+}
+
+/** Result of a forage check; ingredient is null when the total beats no DC */
+export interface ForageResult {
+  environment: string
+  roll: number
+  modifier: number
+  total: number
+  lowest_dc: number
+  ingredient: ForageIngredient | null
+  quantity: number
+  stash_item: CampaignStashItem | null
+}
+
+export type EncounterDifficulty = 'trivial' | 'easy' | 'medium' | 'hard' | 'deadly'
+
+/** A monster entry in an encounter */
+export interface EncounterMonster {
+  cr: string
+  quantity?: number
+}
+
+/** Encounter XP thresholds for a party */
+export interface EncounterBudget {
+  party_size: number
+  levels: number[]
+  easy: number
+  medium: number
+  hard: number
+  deadly: number
+}
+
+/** An encounter rated against a party */
+export interface EncounterEvaluation {
+  budget: EncounterBudget
+  monster_count: number
+  base_xp: number
+  multiplier: number
+  adjusted_xp: number
+  difficulty: EncounterDifficulty
+}
+
+// =============================================================================
+// Player types
+// =============================================================================
+
+/** A player on a campaign's roster */
+export interface Player {
+  id: string
+  campaign_id: string
+  name: string
+  email: string | null
+  discord: string | null
+  phone: string | null
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** Contact details for a new player */
+export interface PlayerContact {
+  email?: string
+  discord?: string
+  phone?: string
+  notes?: string
+}
+
+/** Request for updating a player; empty strings clear optional fields */
+export interface UpdatePlayerRequest extends PlayerContact {
+  name?: string
+}
+
+/** Sessions a player attended and the character they played in each */
+export interface PlayerAttendance {
+  player_id: string
+  sessions: {
+    session_id: string
+    session_number: number
+    title: string | null
+    started_at: string
+    character_id: string
+    character_name: string
+  }[]
+  campaign_sessions: number
+  rate: number
+}
+
+/** A claim code issued to a player */
+export interface PlayerClaim {
+  id: string
+  campaign_id: string
+  player_id: string
+  code: string
+  character_id: string | null
+  expires_at: string | null
+  redeemed_at: string | null
+  redeemed_character_id: string | null
+  created_at: string
+}
+
+/** A character submitted with a claim code */
+export interface CharacterSubmission {
+  name: string
+  race_name?: string
+  race_source?: string
+  background_name?: string
+  background_source?: string
+  ability_scores?: [number, number, number, number, number, number]
+  class_name?: string
+  class_source?: string
+  selected_skills?: string[]
+}
+
+// =============================================================================
+// Custom Field types
+// =============================================================================
+
+/** Type of value a custom character sheet field holds */
+export type CustomFieldType = 'number' | 'text' | 'boolean' | 'dice'
+
+/** A custom character sheet field defined for a campaign */
+export interface CampaignCustomField {
+  id: string
+  campaign_id: string
+  field_key: string
+  label: string
+  field_type: CustomFieldType
+  default_value: string | null
+  /** Lowest allowed value (number fields) */
+  min_value: number | null
+  /** Highest allowed value (number fields) */
+  max_value: number | null
+  description: string | null
+  sort_order: number
+  created_at: string
+  updated_at: string
+}
+
+/** Input for defining a custom field */
+export interface CreateCustomFieldInput {
+  label: string
+  field_type: CustomFieldType
+  /** Storage key; derived from the label when omitted */
+  field_key?: string
+  default_value?: string
+  min_value?: number
+  max_value?: number
+  description?: string
+}
+
+/** Request for updating a custom field (empty strings clear values) */
+export interface UpdateCustomFieldRequest {
+  label?: string
+  default_value?: string
+  /** Replaces both bounds; null removes a bound */
+  range?: { min: number | null; max: number | null }
+  description?: string
+}
+
+/** A character's value for one of their campaign's custom fields */
+export interface CustomFieldValue {
+  field_key: string
+  label: string
+  field_type: CustomFieldType
+  /** The character's value, or the field default when unset */
+  value: string | null
+  is_default: boolean
+}
+
+// =============================================================================
+// Class Resource types
+// =============================================================================
+
+/** Rest that restores a class resource */
+export type RechargeType = 'short_rest' | 'long_rest'
+
+/** Kind of rest a character takes */
+export type RestType = 'short' | 'long'
+
+/** A limited-use class feature (Rage, Ki, Channel Divinity) */
+export interface CharacterResource {
+  id: string
+  character_id: string
+  resource_name: string
+  /** Class that grants the resource */
+  source_class: string
+  max_uses: number
+  current_uses: number
+  recharge: RechargeType
+  created_at: string
+  updated_at: string
+}
+
+/** Outcome of a character taking a rest */
+export interface RestResult {
+  rest_type: RestType
+  /** Names of the resources that regained uses */
+  restored: string[]
+  /** All of the character's resources after the rest */
+  resources: CharacterResource[]
+}
+
+// =============================================================================
+// Character Spell types
+// =============================================================================
+
+/** A spell a character knows, from one of their classes */
+export interface CharacterSpell {
+  id: string
+  character_id: string
+  spell_name: string
+  spell_source: string
+  /** Class that grants the spell */
+  source_class: string
+  /** 1 when prepared */
+  prepared: number
+  /** 1 when copied into the spellbook from a scroll or another book */
+  scribed: number
+}
+
+// =============================================================================
+// Companion types
+// =============================================================================
+
+/** Kind of companion creature */
+export type CompanionType = 'beast' | 'familiar' | 'sidekick' | 'other'
+
+/** Sidekick class from Tasha's Cauldron of Everything */
+export type SidekickClass = 'expert' | 'spellcaster' | 'warrior'
+
+/** A companion creature linked to a character */
+export interface CharacterCompanion {
+  id: string
+  character_id: string
+  name: string
+  companion_type: CompanionType
+  /** Catalog monster used for the stat block */
+  monster_name: string | null
+  monster_source: string | null
+  /** Campaign homebrew monster used for the stat block */
+  homebrew_monster_id: string | null
+  sidekick_class: SidekickClass | null
+  /** Sidekick level (null for companions without levels) */
+  level: number | null
+  max_hp: number
+  current_hp: number
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** Input for linking a companion to a character */
+export interface AddCompanionInput {
+  name: string
+  companion_type: CompanionType
+  monster_name?: string | null
+  monster_source?: string | null
+  homebrew_monster_id?: string | null
+  /** Required for sidekicks */
+  sidekick_class?: SidekickClass | null
+  /** Starting sidekick level (defaults to 1) */
+  level?: number | null
+  /** Defaults to the stat block's average HP */
+  max_hp?: number | null
+  notes?: string | null
+}
+
+/** Input for updating a companion */
+export interface UpdateCompanionInput {
+  name?: string
+  max_hp?: number
+  notes?: string | null
+}
+
+// =============================================================================
+// Crafting types
+// =============================================================================
+
+export type CraftingStatus = 'in_progress' | 'completed' | 'abandoned'
+
+/** An item a character is crafting during downtime */
+export interface CraftingProject {
+  id: string
+  character_id: string
+  item_name: string
+  item_source: string
+  quantity: number
+  /** Rarity for magic items (null for mundane items) */
+  rarity: string | null
+  /** Materials cost in copper pieces */
+  cost_cp: number
+  days_required: number
+  days_spent: number
+  tool: string | null
+  /** Exotic material a magic item needs */
+  ingredient: string | null
+  ingredient_acquired: boolean
+  status: CraftingStatus
+  notes: string | null
+  created_at: string
+  updated_at: string
+  completed_at: string | null
+}
+
+/** What it takes to craft an item (XGE downtime rules) */
+export interface CraftingRecipe {
+  item_name: string
+  item_source: string
+  quantity: number
+  rarity: string | null
+  /** Potions and scrolls take half the time and gold */
+  consumable: boolean
+  cost_cp: number
+  days: number
+  min_level: number | null
+  tool: string | null
+  ingredient: string | null
+}
+
+/** Input for starting a crafting project */
+export interface StartCraftingInput {
+  character_id: string
+  item_name: string
+  /** Source book, or "HB" for campaign homebrew */
+  item_source: string
+  quantity?: number | null
+  /** Pay for materials from the character's purse (default true) */
+  pay_from_purse?: boolean
+  /** Skip the level and tool proficiency checks */
+  ignore_requirements?: boolean
+  notes?: string | null
+}
+
+/** Input for logging downtime against a crafting project */
+export interface AdvanceCraftingInput {
+  days?: number
+  ingredient_acquired?: boolean | null
+}
+
+/** A crafting project after logging downtime */
+export interface CraftingProgress {
+  project: CraftingProject
+  /** Inventory entry for the finished item */
+  item: CharacterInventory | null
+}
+
+// =============================================================================
+// Currency types
+// =============================================================================
+
+/** One change to a character's coins (negative amounts were spent) */
+export interface CurrencyTransaction {
+  id: string
+  character_id: string
+  reason: string
+  cp: number
+  sp: number
+  ep: number
+  gp: number
+  pp: number
+  created_at: string
+}
+
+/** Input for adding or spending a character's coins */
+export interface CurrencyAdjustment {
+  /** Change as [CP, SP, EP, GP, PP]; negative amounts are spent */
+  amounts: Coins
+  reason: string
+  /** Pay missing denominations from other coins and take change */
+  make_change?: boolean
+}
+
+/** A character's coins after a change */
+export interface CurrencyResult {
+  balance: Coins
+  /** Ledger entry for the change (null when nothing changed) */
+  transaction: CurrencyTransaction | null
+}
+
+// =============================================================================
+// Death types
+// =============================================================================
+
+/** A character's death, and the resurrection that ended it, if any */
+export interface CharacterDeath {
+  id: string
+  character_id: string
+  cause: string
+  /** Party whose calendar dates the death */
+  party_id: string | null
+  /** In-world day on the party's calendar */
+  day: number | null
+  notes: string | null
+  died_at: string
+  /** null while the character is still dead */
+  resurrection_method: string | null
+  resurrection_cost_cp: number | null
+  resurrection_day: number | null
+  resurrected_at: string | null
+}
+
+/** Input for marking a character dead */
+export interface MarkDeadInput {
+  cause: string
+  /** Defaults to the character's party when they belong to exactly one */
+  party_id?: string | null
+  /** Defaults to the party's current day */
+  day?: number | null
+  notes?: string | null
+}
+
+/** Input for bringing a dead character back */
+export interface ResurrectInput {
+  method: string
+  /** Defaults to the spell's material component cost */
+  cost_cp?: number | null
+  /** Character who pays the cost from their purse */
+  paid_by?: string | null
+  party_id?: string | null
+  day?: number | null
+}
+
+/** A fallen character on the campaign memorial */
+export interface MemorialEntry {
+  character_id: string
+  name: string
+  player_name: string | null
+  is_npc: boolean
+  level: number
+  death: CharacterDeath | null
+}
+
+// =============================================================================
+// Campaign Memory types
+// =============================================================================
+
+/** A fact the AI assistant remembered about a campaign */
+export interface CampaignMemory {
+  id: string
+  campaign_id: string
+  /** Short name, unique within the campaign */
+  name: string
+  content: string
+  created_at: string
+  updated_at: string
+}
+
+// =============================================================================
+// Module continuity types
+// =============================================================================
+
+/** `follows_from`: from picks up after to. `foreshadows`: from sets up a hook paid off in to */
+export type ModuleLinkType = 'follows_from' | 'foreshadows'
+
+/** A link from one module to another */
+export interface ModuleLink {
+  id: string
+  campaign_id: string
+  from_module_id: string
+  to_module_id: string
+  link_type: ModuleLinkType
+  note: string | null
+  /** Whether a foreshadowed hook has paid off (0 or 1) */
+  resolved: number
+  created_at: string
+}
+
+/** A foreshadowed hook that hasn't paid off yet */
+export interface ContinuityHook {
+  link_id: string
+  from_module_id: string
+  from_module_name: string
+  to_module_id: string
+  to_module_name: string
+  note: string | null
+  /** True if planted in the checked module, false if it pays off there */
+  planted_here: boolean
+}
+
+/** An NPC a module introduced that no document mentions */
+export interface UnusedNpc {
+  id: string
+  name: string
+  /** False for module NPCs, true for NPC characters brought in by a tag */
+  is_character: boolean
+}
+
+/** An item handed to a character from the stash while a module was played */
+export interface HandedOutItem {
+  ledger_entry_id: string
+  item_name: string
+  item_source: string | null
+  quantity: number
+  character_id: string | null
+  character_name: string | null
+  session_number: number
+  handed_out_at: string
+}
+
+/** Continuity checklist for a module */
+export interface ModuleCompletion {
+  module_id: string
+  module_name: string
+  links: ModuleLink[]
+  unresolved_hooks: ContinuityHook[]
+  unused_npcs: UnusedNpc[]
+  items_handed_out: HandedOutItem[]
+  is_complete: boolean
+}
+
+// =============================================================================
+// Combat action hint types
+// =============================================================================
+
+export type HintKind = 'trait' | 'action' | 'bonus' | 'reaction' | 'legendary' | 'spell'
+
+/** Damage an action deals */
+export interface DamageHint {
+  /** Dice expression, e.g. "2d8 + 3" */
+  dice: string
+  damage_type: string | null
+}
+
+/** A saving throw an action calls for */
+export interface SaveHint {
+  /** e.g. "Constitution" */
+  ability: string
+  /** Null for a spell whose caster DC wasn't given */
+  dc: number | null
+}
+
+/** What a monster action or spell does, read from its catalog data */
+export interface ActionHint {
+  name: string
+  kind: HintKind
+  attack_bonus: number | null
+  damage: DamageHint[]
+  save: SaveHint | null
+  /** Lowercase condition names */
+  conditions: string[]
+  /** e.g. "5-6" */
+  recharge: string | null
+}
+
+export interface AttackRoll {
+  d20: number
+  bonus: number
+  total: number
+  critical: boolean
+  fumble: boolean
+}
+
+export interface DamageRoll {
+  /** Expression rolled; dice are doubled on a critical hit */
+  dice: string
+  damage_type: string | null
+  rolls: number[]
+  total: number
+}
+
+/** The result of rolling an action hint */
+export interface HintRoll {
+  name: string
+  attack: AttackRoll | null
+  damage: DamageRoll[]
+  save: SaveHint | null
+  conditions: string[]
+  /** One-line summary for the combat log */
+  summary: string
+}
+
+// =============================================================================
+// Initiative types
+// =============================================================================
+
+export type TurnStatus = 'waiting' | 'delayed' | 'readied'
+
+/** One creature in the turn order */
+export interface Combatant {
+  name: string
+  initiative: number
+  /** Initiative modifier, used to break ties */
+  modifier: number
+  /** Initiative was rolled at the table rather than by Mimir */
+  isPlayer: boolean
+  status: TurnStatus
+  /** Trigger and action of a readied action */
+  readiedAction: string | null
+  /** What happens on this count, when the entry is an encounter effect */
+  effect: string | null
+  /** Pooled hit points, when the entry is a mob of identical creatures */
+  mob: Mob | null
+  /** Token art shown beside the name on the player display */
+  portrait: CombatantPortrait | null
+  /** Players see the combatant as "???" until revealed */
+  hidden: boolean
+}
+
+/** Where a combatant's token art comes from */
+export type CombatantPortrait =
+  | { kind: 'character'; id: string }
+  | { kind: 'monster'; name: string; source: string }
+
+/** A mob's pooled hit points (DMG mob rules) */
+export interface Mob {
+  /** Module monster whose mob tokens shrink with casualties */
+  monsterId: string | null
+  /** Creatures in the mob when it formed */
+  size: number
+  hpEach: number
+  /** Damage the mob has taken in total */
+  damage: number
+  survivors: number
+}
+
+/** What damage dealt to a mob did, also broadcast as `mob:casualties` */
+export interface MobCasualties {
+  monsterId: string | null
+  casualties: number
+  survivors: number
+}
+
+/** A mob's attacks against one target, resolved with the DMG mob table */
+export interface MobAttack {
+  attackers: number
+  /** d20 roll an attacker needs to hit */
+  roll_needed: number
+  /** Attackers needed for one of them to hit */
+  attackers_per_hit: number
+  hits: number
+}
+
+/** An encounter effect that happened as the current turn began */
+export interface TurnEvent {
+  name: string
+  description: string
+}
+
+/** The encounter's turn order, also broadcast as `initiative:update` */
+export interface InitiativeSnapshot {
+  combatants: Combatant[]
+  /** Index of the combatant whose turn it is, once combat has started */
+  current: number | null
+  /** Combat round, zero before the first turn */
+  round: number
+  /** Effects that happened as the current turn began */
+  events: TurnEvent[]
+}
+
+/**
+ * When an encounter effect acts: on an initiative count every round, at the
+ * start of every round, or at the start of one round
+ */
+export type EffectTiming = 'initiative' | 'each_round' | 'round'
+
+/** A lair action, hazard, or scheduled event in a module's encounter */
+export interface EncounterEffect {
+  id: string
+  module_id: string
+  name: string
+  description: string
+  timing: EffectTiming
+  /** Initiative count for 'initiative', round number for 'round' */
+  timing_value: number | null
+  created_at: string
+  updated_at: string
+}
+
+/** Input for adding or replacing an encounter effect */
+export interface EncounterEffectInput {
+  name: string
+  description?: string | null
+  timing: EffectTiming
+  /** Initiative count (default 20) or round number */
+  timing_value?: number | null
+}
+
+// =============================================================================
+// Table Timer types
+// =============================================================================
+
+export type TimerKind = 'turn' | 'break' | 'session'
+
+/** One table timer */
+export interface TimerSnapshot {
+  running: boolean
+  elapsedSecs: number
+  /** Countdown length, if the timer was started with one */
+  limitSecs: number | null
+  remainingSecs: number | null
+  expired: boolean
+  /** Whose turn it is, or what the break is for */
+  label: string | null
+  /** When the current run started, so displays can tick locally */
+  runningSince: string | null
+}
+
+/** Pacing totals recorded from the table timers */
+export interface SessionTotals {
+  play_seconds: number
+  break_seconds: number
+  combat_turns: number
+  combat_turn_seconds: number
+  longest_turn_seconds: number
+}
+
+/** All table timers, also broadcast as `timers:update` */
+export interface TimersSnapshot {
+  turn: TimerSnapshot
+  break: TimerSnapshot
+  session: TimerSnapshot
+  /** Game session the session clock is recording into, if any */
+  sessionId: string | null
+  totals: SessionTotals
+}
+
+/** A game session played at the table */
+export interface GameSession extends SessionTotals {
+  id: string
+  campaign_id: string
+  module_id: string | null
+  session_number: number
+  title: string | null
+  started_at: string
+  /** Null while the session is in progress */
+  ended_at: string | null
+  party_id: string | null
+  xp_awarded: number
+  transcript: string | null
+  created_at: string
+  updated_at: string
+}
+
+// =============================================================================
+// Rumor types
+// =============================================================================
+
+/** A rumor or adventure hook in a campaign */
+export interface Rumor {
+  id: string
+  campaign_id: string
+  text: string
+  /** 1 if the rumor is true, 0 if false */
+  is_true: number
+  /** NPC the rumor comes from */
+  source_npc_id: string | null
+  /** Module (quest) the rumor leads into */
+  module_id: string | null
+  notes: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** A record of a player having heard a rumor */
+export interface RumorHearing {
+  rumor_id: string
+  player_id: string
+  session_id: string | null
+  heard_at: string
+}
+
+/** A rumor with the record of who has heard it */
+export interface RumorWithHearings extends Rumor {
+  hearings: RumorHearing[]
+}
+
+/** Input for creating or replacing a rumor */
+export interface RumorInput {
+  text: string
+  is_true: boolean
+  source_npc_id?: string | null
+  module_id?: string | null
+  notes?: string | null
+}
+
+/** An entry on a tavern rumor table */
+export interface TavernRumor {
+  roll: number
+  rumor_id: string
+  text: string
+  is_true: boolean
+  /** Name of the NPC the rumor comes from */
+  source_npc: string | null
+  /** Number of players who have already heard it */
+  heard_by: number
+}
+
+/** A tavern rumor table for a session */
+export interface TavernRumorTable {
+  /** Die to roll on the table (its number of entries) */
+  die: number
+  entries: TavernRumor[]
+  /** Seed that reproduces this table */
+  seed: number
+}
+
+// =============================================================================
+// Handout types
+// =============================================================================
+
+/** A handout in a campaign's reveal queue; exactly one source is set */
+export interface Handout {
+  id: string
+  campaign_id: string
+  title: string
+  /** Catalog image, relative to the catalog images directory */
+  image_path: string | null
+  /** Uploaded campaign image */
+  asset_id: string | null
+  /** Document shown as read-aloud text */
+  document_id: string | null
+  sort_order: number
+  /** Time of the last reveal; null while queued */
+  revealed_at: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** Input for staging a handout; give exactly one source */
+export interface HandoutInput {
+  title: string
+  image_path?: string | null
+  asset_id?: string | null
+  document_id?: string | null
+}
+
+/** A record of players being shown a handout */
+export interface RevealedHandout {
+  id: string
+  handout_id: string
+  session_id: string | null
+  revealed_at: string
+  title: string
+  /** Number of the session it was shown in */
+  session_number: number | null
+}
+
+// =============================================================================
+// DM Reference types
+// =============================================================================
+
+export type DmReferencePanelType = 'monster' | 'document_section' | 'npc' | 'rules'
+
+/** A content panel pinned to the DM reference window */
+export interface DmReferencePin {
+  id: string
+  campaign_id: string
+  panel_type: DmReferencePanelType
+  title: string
+  /** Document/character ID, or catalog entry name for monster and rules panels */
+  ref_id: string | null
+  /** Catalog source code for monster and rules panels */
+  ref_source: string | null
+  /** Document heading, or catalog kind for rules panels */
+  section: string | null
+  /** Free-form excerpt text */
+  content: string | null
+  sort_order: number
+  created_at: string
+  updated_at: string
+}
+
+/** Input for pinning a panel; monster, npc, and document panels need refId */
+export interface PinDmReferenceRequest {
+  campaignId: string
+  panelType: DmReferencePanelType
+  title: string
+  refId?: string | null
+  refSource?: string | null
+  section?: string | null
+  content?: string | null
+}
+
+// =============================================================================
+// Secret Note types
+// =============================================================================
+
+/** A secret note for one player */
+export interface SecretNote {
+  id: string
+  campaign_id: string
+  player_id: string
+  module_id: string | null
+  /** Short title printed on the slip */
+  title: string
+  content: string
+  /** Time of delivery; null while outstanding */
+  delivered_at: string | null
+  /** Session the note was delivered in */
+  session_id: string | null
+  created_at: string
+  updated_at: string
+}
+
+/** Input for creating or replacing a secret note */
+export interface SecretNoteInput {
+  player_id: string
+  title: string
+  content: string
+  module_id?: string | null
+}
+
+// =============================================================================
+// Recap types
+// =============================================================================
+
+/** How much play history a recap covers */
+export type RecapScope = 'last-session' | 'last-module' | 'whole-campaign'
+
+/** The two documents a recap is written to */
+export interface Recap {
+  /** Full recap for the DM */
+  dm_document: Document
+  /** Spoiler-safe recap for the players */
+  player_document: Document
+}
+
+// =============================================================================
+// NPC Reaction types
+// =============================================================================
+
+/** Dice a reaction check is rolled with */
+export type ReactionDice = '2d6' | 'd20'
+
+/** An NPC's suggested attitude toward the party */
+export type Attitude = 'hostile' | 'unfriendly' | 'indifferent' | 'friendly' | 'helpful'
+
+/** How an NPC, or everyone in a faction, regards outsiders (-3 to +3) */
+export interface Disposition {
+  id: string
+  campaign_id: string
+  /** Set for an NPC's own disposition */
+  character_id: string | null
+  /** Set for a faction's disposition */
+  faction: string | null
+  disposition: number
+  created_at: string
+  updated_at: string
+}
+
+/** A party's standing with a faction (-3 to +3) */
+export interface PartyReputation {
+  id: string
+  campaign_id: string
+  party_id: string
+  faction: string
+  reputation: number
+  created_at: string
+  updated_at: string
+}
+
+/** A campaign's dispositions and party reputations */
+export interface RelationshipStandings {
+  dispositions: Disposition[]
+  reputations: PartyReputation[]
+}
+
+/** A reaction check kept in an NPC's relationship history */
+export interface NpcInteraction {
+  id: string
+  campaign_id: string
+  character_id: string
+  party_id: string | null
+  session_id: string | null
+  dice: ReactionDice
+  roll: number
+  /** Disposition, reputation, and situational modifiers combined */
+  modifier: number
+  total: number
+  attitude: Attitude
+  notes: string | null
+  created_at: string
+}
+
+/** Input for a reaction check */
+export interface ReactionCheckInput {
+  character_id: string
+  /** Defaults to the party of the session in progress, or the only party */
+  party_id?: string | null
+  dice?: ReactionDice
+  /** Situational modifier */
+  modifier?: number
+  /** Physical dice result; rolled when omitted */
+  roll?: number | null
+  notes?: string | null
+}
+
+/** Where the disposition in a reaction check came from */
+export type DispositionSource =
+  | { kind: 'npc' }
+  | { kind: 'faction'; faction: string }
+  | { kind: 'none' }
+
+/** Result of a reaction check */
+export interface ReactionCheck {
+  interaction: NpcInteraction
+  disposition: number
+  disposition_source: DispositionSource
+  reputation: number
+  situational: number
+  attitude: Attitude
+}
+
+// =============================================================================
+// Safety Tools types
+// =============================================================================
+
+/** A campaign's safety tools, agreed at session zero */
+export interface SafetyTools {
+  /** Hard limits that never appear in the game */
+  lines: string[]
+  /** Topics that may be referenced but happen off-screen */
+  veils: string[]
+  x_card_enabled: boolean
+  /** How to invoke the X-card */
+  x_card_signal: string | null
+  open_door: boolean
+  check_ins: boolean
+  /** DM-only notes, left out of the session zero document */
+  notes: string | null
+  /** Null until the tools have been saved */
+  updated_at: string | null
+}
+
+// =============================================================================
+// Campaign History types
+// =============================================================================
+
+/** A commit in a campaign's history */
+export interface HistoryCommit {
+  id: string
+  short_id: string
+  message: string
+  /** RFC 3339 commit time */
+  timestamp: string
+}
+
+/** A document file in a history snapshot, from its document manifest */
+export interface HistoryDocumentEntry {
+  /** Path relative to the campaign folder */
+  path: string
+  document_id: string
+  title_added: boolean
+}
+
+// =============================================================================
+// Recent Items and Favorites types
+// =============================================================================
+
+/** An item the DM opened recently */
+export interface RecentItem {
+  id: string
+  /** 'document', 'map', or a catalog kind such as 'monster' */
+  kind: string
+  /** Document/map ID, or catalog entry name */
+  ref_id: string
+  /** Catalog source code */
+  ref_source: string | null
+  /** Campaign the document or map belongs to */
+  campaign_id: string | null
+  title: string
+  view_count: number
+  last_viewed_at: string
+}
+
+/** An item pinned to favorites */
+export interface Favorite {
+  id: string
+  kind: string
+  ref_id: string
+  ref_source: string | null
+  campaign_id: string | null
+  title: string
+  sort_order: number
+  created_at: string
+}
+
+/** Reference to a catalog entry, document, or map */
+export interface ItemRefRequest {
+  kind: string
+  refId: string
+  /** Catalog entries only */
+  refSource?: string | null
+  /** Documents and maps only */
+  campaignId?: string | null
+  /** Required for documents and maps; defaults to the entry name otherwise */
+  title?: string
+}
+
+// =============================================================================
+// Trash types
+// =============================================================================
+
+export type TrashKind = 'document' | 'character' | 'map'
+
+/** A soft-deleted item waiting in the trash */
+export interface TrashedItem {
+  kind: TrashKind
+  id: string
+  /** Null for characters not assigned to a campaign */
+  campaign_id: string | null
+  /** Document title, character name, or map name */
+  name: string
+  deleted_at: string
+  /** When the item is purged automatically */
+  expires_at: string
+}
+
+// =============================================================================
+// Markdown Folder types
+// =============================================================================
+
+/** A campaign's exported markdown folder being watched for external edits */
+export interface WatchedFolder {
+  campaign_id: string
+  root: string
+}
+
+/** A document written by a markdown export */
+export interface ExportedDocument {
+  /** Path relative to the export root */
+  path: string
+  document_id: string
+  title_added: boolean
+  base_hash?: string
+}
+
+/** Result of a one-off markdown export */
+export interface MarkdownExportResult {
+  root: string
+  files_written: number
+  /** Files an earlier export wrote that are no longer needed */
+  files_removed: number
+  documents: ExportedDocument[]
+}
+
+// =============================================================================
+// Travel types
+// =============================================================================
+
+export type TravelPace = 'fast' | 'normal' | 'slow'
+
+export type Terrain =
+  | 'road'
+  | 'open'
+  | 'hills'
+  | 'desert'
+  | 'forest'
+  | 'jungle'
+  | 'swamp'
+  | 'mountains'
+  | 'arctic'
+
+export type Weather =
+  | 'clear'
+  | 'rain'
+  | 'heavy_precipitation'
+  | 'heavy_snow'
+  | 'strong_wind'
+  | 'extreme_heat'
+  | 'extreme_cold'
+
+/** A journey to plan; left-out values use the rules defaults */
+export interface TravelRequest {
+  distance_miles: number
+  /** Walking speed of each traveler in feet; the slowest sets the pace */
+  speeds?: number[]
+  /** Creatures eating and drinking (defaults to the number of speeds, or 1) */
+  party_size?: number | null
+  pace?: TravelPace
+  terrain?: Terrain
+  weather?: Weather
+  /** Hours traveled each day (defaults to 8; more is a forced march) */
+  hours_per_day?: number | null
+  /** Hours between random encounter checks (defaults to 8) */
+  encounter_check_hours?: number | null
+}
+
+/** A random encounter check on a day of travel */
+export interface EncounterCheck {
+  day: number
+  /** Hours since the party set out that day */
+  hour: number
+  phase: 'travel' | 'rest'
+}
+
+/** A planned journey */
+export interface TravelPlan {
+  distance_miles: number
+  pace: TravelPace
+  terrain: Terrain
+  weather: Weather
+  /** Walking speed of the slowest traveler, in feet */
+  speed: number
+  miles_per_hour: number
+  hours_per_day: number
+  miles_per_day: number
+  travel_hours: number
+  /** Days on the road, counting a partial last day as a full one */
+  days: number
+  /** Constitution save DCs for each hour past 8 on a full day of travel */
+  forced_march_dcs: number[]
+  encounter_checks: EncounterCheck[]
+  /** Chance that a single check causes an encounter */
+  encounter_chance: number
+  expected_encounters: number
+  party_size: number
+  /** Days of rations */
+  rations: number
+  water_gallons: number
+  /** Rules reminders for the pace, terrain, and weather */
+  notes: string[]
+}
+
+// =============================================================================
+// Tag types
+// =============================================================================
+
+/** Kinds of campaign content that can carry tags */
+export type TaggableType = 'document' | 'npc' | 'monster' | 'homebrew_monster' | 'map' | 'asset'
+
+/** A campaign-wide tag, e.g. an arc ("Act 2"), location, or theme */
+export interface Tag {
+  id: string
+  campaign_id: string
+  /** Unique per campaign, ignoring case */
+  name: string
+  /** Hex color for display */
+  color: string | null
+  created_at: string
+}
+
+/** A tag attached to one piece of content */
+export interface EntityTag {
+  tag_id: string
+  entity_type: TaggableType
+  entity_id: string
+  created_at: string
+}
+
+// =============================================================================
+// Quick Action types
+// =============================================================================
+
+/** One scene change in a quick action; map steps run together in one transaction */
+export type QuickActionStep =
+  | { type: 'reveal_rect'; map_id: string; x: number; y: number; width: number; height: number }
+  | { type: 'reveal_circle'; map_id: string; x: number; y: number; radius: number }
+  | { type: 'set_light'; light_id: string; active: boolean }
+  | { type: 'set_token_hidden'; token_id: string; hidden: boolean }
+  | { type: 'set_poi_visible'; poi_id: string; visible: boolean }
+  | { type: 'set_trap_visible'; trap_id: string; visible: boolean }
+  | { type: 'send_handout'; kind: string; image_path: string; title: string | null; screen: string | null }
+  | { type: 'blackout'; enabled: boolean; screen: string | null }
+  /** Saved, but not played until the app has an audio player */
+  | { type: 'play_ambience'; track: string }
+  | { type: 'start_timer'; timer: string; limit_secs: number | null; label: string | null }
+
+export type QuickActionStepType = QuickActionStep['type']
+
+/** A named bundle of scene changes run from the campaign soundboard */
+export interface QuickAction {
+  id: string
+  campaign_id: string
+  name: string
+  description: string | null
+  steps: QuickActionStep[]
+  sort_order: number
+  created_at: string
+  updated_at: string
+}
+
+/** Result of running a quick action */
+export interface TriggerQuickActionResult {
+  actionId: string
+  /** Maps whose fog, lights, tokens, or markers changed */
+  mapIds: string[]
+  /** Steps carried out; ambience steps are skipped */
+  stepsRun: number
+}
+
 // =============================================================================
 // Document types - auto-generated from Rust via ts-rs
 // =============================================================================
@@ -133,6 +1753,8 @@ export interface Token {
   vision_dim_ft: number | null     // Vision range in dim light (null = unlimited)
   vision_dark_ft: number           // Vision range in darkness (0 = blind, 60 = darkvision)
   light_radius_ft: number          // Light source dim radius (bright = half)
+  /** Creatures in the mob a monster token stands for; null for one creature */
+  mob_size: number | null
   created_at: string
   updated_at: string
 }
@@ -162,10 +1784,13 @@ export interface CreateTokenRequest {
 /**
  * Extended token config used by frontend for tracking monster info.
  * The monster_name/monster_source fields are used to auto-add to module_monsters.
+ * The trap_name/trap_source fields place a catalog trap on the map.
  */
 export interface TokenConfigWithMonster extends CreateTokenRequest {
   monster_name?: string
   monster_source?: string
+  trap_name?: string
+  trap_source?: string
 }
 
 export interface UpdateTokenRequest {
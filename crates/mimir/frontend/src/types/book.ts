@@ -9,6 +9,8 @@ export interface BookInfo {
   enabled: boolean
   /** ISO 8601 timestamp of when imported */
   imported_at: string
+  /** Ruleset edition the source is tagged with ("2014" or "2024") */
+  edition?: string
   /** Number of images (optional, for book archives) */
   image_count?: number
 }
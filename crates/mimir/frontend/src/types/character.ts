@@ -14,6 +14,7 @@ import type { CharacterProficiency } from './generated/CharacterProficiency'
 import type { CharacterClass } from './generated/CharacterClass'
 import type { CharacterInventory } from './generated/CharacterInventory'
 import type { CharacterResponse } from './generated/CharacterResponse'
+import type { CharacterCompanion } from './api'
 
 // Re-export generated types
 export type { CharacterProficiency, CharacterClass, CharacterInventory, CharacterResponse }
@@ -35,16 +36,63 @@ export interface CreatePcRequest {
   player_name: string
   race_name?: string
   race_source?: string
+  /** Subrace as listed in the catalog (e.g., "High" for a High Elf) */
+  subrace_name?: string
+  subrace_source?: string
   background_name?: string
   background_source?: string
-  /** Ability scores: [str, dex, con, int, wis, cha] */
+  /** Base ability scores before racial increases: [str, dex, con, int, wis, cha] */
   ability_scores?: [number, number, number, number, number, number]
+  /** Abilities picked for racial increases that offer a choice (e.g., ["dex", "con"]) */
+  racial_ability_choices?: string[]
   /** Starting class name (e.g., "Fighter") */
   class_name?: string
   /** Starting class source (e.g., "PHB") */
   class_source?: string
   /** Selected skill proficiencies from class */
   selected_skills?: string[]
+  /** Personality traits, ideals, bonds, and flaws from the background tables */
+  traits?: string
+  ideals?: string
+  bonds?: string
+  flaws?: string
+}
+
+/**
+ * A background's feature (e.g., Shelter of the Faithful).
+ */
+export interface BackgroundFeature {
+  name: string
+  text: string
+}
+
+/**
+ * Characteristics rolled from a background's tables.
+ */
+export interface RolledCharacteristics {
+  personality_traits: string[]
+  ideal: string | null
+  bond: string | null
+  flaw: string | null
+}
+
+/**
+ * What a background offers a character at creation, from get_background_options.
+ */
+export interface BackgroundOptions {
+  name: string
+  source: string
+  feature: BackgroundFeature | null
+  skill_proficiencies: string[]
+  tool_proficiencies: string[]
+  language_proficiencies: string[]
+  /** Proficiency picks left to the player (e.g., "Choose 2 languages") */
+  proficiency_choices: string[]
+  personality_traits: string[]
+  ideals: string[]
+  bonds: string[]
+  flaws: string[]
+  rolled: RolledCharacteristics | null
 }
 
 /**
@@ -74,6 +122,8 @@ export interface UpdateCharacterRequest {
   ability_scores?: [number, number, number, number, number, number]
   /** Currency: [cp, sp, ep, gp, pp] */
   currency?: [number, number, number, number, number]
+  /** Why the currency changed, for the transaction history */
+  currency_reason?: string | null
   traits?: string | null
   ideals?: string | null
   bonds?: string | null
@@ -302,6 +352,8 @@ export type AsiOrFeat =
       type: 'Feat'
       name: string
       source: string
+      /** Ability to increase when the feat offers a choice */
+      ability?: string
     }
 
 /**
@@ -393,5 +445,66 @@ export interface LevelUpResult {
   hp_gained: number
   new_total_level: number
   is_multiclass: boolean
+  /** Effects applied by a feat taken this level */
+  feat: FeatApplication | null
+  /** Sidekicks who gained a level alongside the character */
+  sidekicks: CharacterCompanion[]
+}
+
+/**
+ * Effects a feat applied on level up, and anything left to adjust by hand.
+ */
+export interface FeatApplication {
+  feat_name: string
+  applied: string[]
+  manual_adjustments: string[]
 }
 
+
+/**
+ * A class in a rebuilt character.
+ */
+export interface RespecClass {
+  class_name: string
+  class_source: string
+  /** Levels in this class */
+  level: number
+  subclass?: SubclassChoice
+}
+
+/**
+ * Request to rebuild a character at its current level with different choices.
+ */
+export interface RespecRequest {
+  /** Classes in the order taken; levels must add up to the current level */
+  classes: RespecClass[]
+  /** Ability scores before improvements and feats */
+  ability_scores: [number, number, number, number, number, number]
+  /** One ability score improvement or feat per improvement the classes grant */
+  asi_or_feats: AsiOrFeat[]
+  /** Why the character is being rebuilt, recorded in campaign history */
+  reason?: string
+}
+
+/**
+ * A feat recorded on a character.
+ */
+export interface CharacterFeat {
+  id: string
+  character_id: string
+  feat_name: string
+  feat_source: string
+  source_type: string
+}
+
+/**
+ * Response from respec_character.
+ */
+export interface RespecResult {
+  character: Character
+  classes: CharacterClass[]
+  previous_classes: CharacterClass[]
+  previous_feats: CharacterFeat[]
+  feats: FeatApplication[]
+  reason: string | null
+}
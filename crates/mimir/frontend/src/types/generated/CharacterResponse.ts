@@ -5,7 +5,23 @@ import type { CharacterProficiency } from "./CharacterProficiency";
 /**
  * Character with classes included - used for API responses.
  */
-export type CharacterResponse = { id: string, campaign_id: string | null, name: string, is_npc: number, player_name: string | null, race_name: string | null, race_source: string | null, background_name: string | null, background_source: string | null, strength: number, dexterity: number, constitution: number, intelligence: number, wisdom: number, charisma: number, cp: number, sp: number, ep: number, gp: number, pp: number, traits: string | null, ideals: string | null, bonds: string | null, flaws: string | null, role: string | null, location: string | null, faction: string | null, created_at: string, updated_at: string, 
+export type CharacterResponse = { id: string, campaign_id: string | null, name: string, is_npc: number, player_name: string | null, race_name: string | null, race_source: string | null, background_name: string | null, background_source: string | null, strength: number, dexterity: number, constitution: number, intelligence: number, wisdom: number, charisma: number, cp: number, sp: number, ep: number, gp: number, pp: number, traits: string | null, ideals: string | null, bonds: string | null, flaws: string | null, role: string | null, location: string | null, faction: string | null, created_at: string, updated_at: string, subrace_name: string | null, subrace_source: string | null, speed: number, darkvision: number | null, 
+/**
+ * Damage types resisted (parsed from the character's JSON array)
+ */
+resistances: Array<string>, 
+/**
+ * Whether the character is dead
+ */
+is_dead: boolean, 
+/**
+ * Portrait image, relative to the assets directory
+ */
+portrait_path: string | null, 
+/**
+ * Circular token image, relative to the assets directory
+ */
+token_path: string | null, 
 /**
  * Character classes (populated from character_classes table)
  */
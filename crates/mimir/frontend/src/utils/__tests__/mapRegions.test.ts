@@ -0,0 +1,70 @@
+import { describe, it, expect } from 'vitest'
+import { findMapRegionRefs, mapRegionRefAt, regionContains, regionFocusView } from '../mapRegions'
+import type { MapRegion } from '@/services/MapRegionService'
+
+function region(overrides: Partial<MapRegion> = {}): MapRegion {
+  return {
+    id: 'region-1',
+    map_id: 'map-1',
+    name: 'area-3',
+    x: 0,
+    y: 0,
+    width: 4,
+    height: 4,
+    points: null,
+    created_at: '',
+    updated_at: '',
+    ...overrides,
+  }
+}
+
+describe('findMapRegionRefs', () => {
+  it('parses map and region names with offsets', () => {
+    const text = 'Go to {{map-region: Crypt Level 1 | area-3}}'
+
+    expect(findMapRegionRefs(text)).toEqual([
+      { map: 'Crypt Level 1', region: 'area-3', start: 6, end: 44 },
+    ])
+  })
+
+  it('requires a region name', () => {
+    expect(findMapRegionRefs('{{map-region: Crypt Level 1}}')).toEqual([])
+  })
+})
+
+describe('mapRegionRefAt', () => {
+  it('finds the reference under an offset', () => {
+    const text = 'See {{map-region: Crypt | area-3}} now'
+
+    expect(mapRegionRefAt(text, 10)?.region).toBe('area-3')
+    expect(mapRegionRefAt(text, 2)).toBeNull()
+  })
+})
+
+describe('regionContains', () => {
+  it('tests rectangles and polygons', () => {
+    expect(regionContains(region(), 3.5, 0.5)).toBe(true)
+    expect(regionContains(region(), 4.5, 0.5)).toBe(false)
+
+    const triangle = region({ points: '[[0,0],[0,4],[4,4]]' })
+    expect(regionContains(triangle, 0.5, 3.5)).toBe(true)
+    expect(regionContains(triangle, 3.5, 0.5)).toBe(false)
+  })
+})
+
+describe('regionFocusView', () => {
+  it('centers the region and fits it to the viewport', () => {
+    const view = regionFocusView(
+      region({ x: 10, y: 10, width: 5, height: 5 }),
+      100,
+      { width: 2000, height: 2000 },
+      { width: 1000, height: 500 }
+    )
+
+    // 500px tall region filling 80% of a 500px viewport
+    expect(view.zoom).toBeCloseTo(0.8)
+    // Region center (1250, 1250) is 250px right/down of the map center
+    expect(view.panX).toBeCloseTo(-200)
+    expect(view.panY).toBeCloseTo(-200)
+  })
+})
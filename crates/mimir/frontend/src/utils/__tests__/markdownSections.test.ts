@@ -0,0 +1,36 @@
+import { describe, it, expect } from 'vitest'
+import { markdownSection } from '../markdownSections'
+
+describe('markdownSection', () => {
+  const md = [
+    '# Cragmaw Hideout',
+    '',
+    '## 1. Cave Mouth',
+    'Two goblins watch the trail.',
+    '',
+    '### Development',
+    'They flee to area 2.',
+    '',
+    '```',
+    '# not a heading',
+    '```',
+    '## 2. Goblin Blind',
+    'A screen of brush.',
+  ].join('\n')
+
+  it('runs to the next heading of the same level', () => {
+    const section = markdownSection(md, '1. cave mouth')
+    expect(section).toContain('Two goblins watch the trail.')
+    expect(section).toContain('### Development')
+    expect(section).toContain('# not a heading')
+    expect(section).not.toContain('Goblin Blind')
+  })
+
+  it('stops at a higher level heading', () => {
+    expect(markdownSection(md, 'Development')).toBe('They flee to area 2.\n\n```\n# not a heading\n```')
+  })
+
+  it('returns null for a missing heading', () => {
+    expect(markdownSection(md, 'Area 9')).toBeNull()
+  })
+})
@@ -0,0 +1,40 @@
+import { describe, it, expect } from 'vitest'
+import { findMonsterEmbeds, monsterEmbedAt, monsterEmbedSummary } from '../monsterEmbeds'
+
+describe('findMonsterEmbeds', () => {
+  it('parses names, optional sources, and offsets', () => {
+    const text = 'Two {{monster: Goblin|MM}} and {{ monster: Owlbear }}'
+
+    expect(findMonsterEmbeds(text)).toEqual([
+      { name: 'Goblin', source: 'MM', start: 4, end: 26 },
+      { name: 'Owlbear', source: null, start: 31, end: 53 },
+    ])
+  })
+})
+
+describe('monsterEmbedAt', () => {
+  it('finds the embed under an offset', () => {
+    const text = 'See {{monster: Goblin|MM}} here'
+
+    expect(monsterEmbedAt(text, 10)?.name).toBe('Goblin')
+    expect(monsterEmbedAt(text, 2)).toBeNull()
+    expect(monsterEmbedAt(text, 26)).toBeNull()
+  })
+})
+
+describe('monsterEmbedSummary', () => {
+  it('reads 5etools object and scalar fields', () => {
+    const summary = monsterEmbedSummary({
+      type: { type: 'humanoid', tags: ['goblinoid'] },
+      ac: [{ ac: 15, from: ['leather armor', 'shield'] }],
+      hp: { average: 7, formula: '2d6' },
+      cr: '1/4',
+    })
+
+    expect(summary).toEqual({ type: 'humanoid', ac: '15', hp: '7 (2d6)', cr: '1/4' })
+  })
+
+  it('falls back to dashes for missing stats', () => {
+    expect(monsterEmbedSummary({ type: 'beast' })).toEqual({ type: 'beast', ac: '—', hp: '—', cr: '—' })
+  })
+})
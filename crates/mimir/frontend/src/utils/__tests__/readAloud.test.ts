@@ -0,0 +1,30 @@
+import { describe, it, expect } from 'vitest'
+import { readAloudBlocks } from '../readAloud'
+
+describe('readAloudBlocks', () => {
+  it('extracts closed and unclosed blocks in order', () => {
+    const md = [
+      '# Scene',
+      '',
+      ':::read-aloud',
+      'The door creaks open.',
+      '',
+      'Cold air spills out.',
+      ':::',
+      '',
+      'DM note.',
+      '',
+      ':::read-aloud',
+      'You smell smoke.',
+    ].join('\n')
+
+    expect(readAloudBlocks(md)).toEqual([
+      'The door creaks open.\n\nCold air spills out.',
+      'You smell smoke.',
+    ])
+  })
+
+  it('ignores fences inside code blocks', () => {
+    expect(readAloudBlocks('```\n:::read-aloud\nnot boxed\n:::\n```')).toEqual([])
+  })
+})
@@ -0,0 +1,38 @@
+import { describe, it, expect } from 'vitest'
+import { formatClock, timerElapsed, timerRemaining } from '../timers'
+import type { TimerSnapshot } from '@/types/api'
+
+function timer(overrides: Partial<TimerSnapshot>): TimerSnapshot {
+  return {
+    running: false,
+    elapsedSecs: 0,
+    limitSecs: null,
+    remainingSecs: null,
+    expired: false,
+    label: null,
+    runningSince: null,
+    ...overrides,
+  }
+}
+
+describe('timerElapsed', () => {
+  it('ticks running timers from when the snapshot arrived', () => {
+    const running = timer({ running: true, elapsedSecs: 10 })
+    expect(timerElapsed(running, 1_000, 6_500)).toBe(15)
+    expect(timerElapsed({ ...running, running: false }, 1_000, 6_500)).toBe(10)
+  })
+
+  it('counts countdowns down to zero', () => {
+    const countdown = timer({ running: true, elapsedSecs: 50, limitSecs: 60 })
+    expect(timerRemaining(countdown, 0, 5_000)).toBe(5)
+    expect(timerRemaining(countdown, 0, 30_000)).toBe(0)
+    expect(timerRemaining(timer({}), 0, 0)).toBeNull()
+  })
+})
+
+describe('formatClock', () => {
+  it('formats minutes and hours', () => {
+    expect(formatClock(245)).toBe('4:05')
+    expect(formatClock(3729)).toBe('1:02:09')
+  })
+})
@@ -21,6 +21,9 @@
  * })
  */
 
+/** What a watch-mode sync did to a document */
+export type DocumentSyncKind = 'created' | 'updated' | 'deleted' | 'conflicted'
+
 // Event payload types for type safety
 // Note: IDs are strings (UUIDs) matching the mimir-core backend
 export interface DataEventPayloads {
@@ -54,6 +57,8 @@ export interface DataEventPayloads {
   'document:updated': { documentId: string }
   'document:deleted': { documentId: string; moduleId?: string }
   'document:reordered': { documentId: string }
+  // A document file in a watched export folder was applied (or conflicted)
+  'documents:synced': { campaignId: string; documentId: string; kind: DocumentSyncKind }
 
   // Character events
   // Note: campaignId can be null for characters not attached to a campaign
@@ -66,6 +71,10 @@ export interface DataEventPayloads {
   'campaign:updated': { campaignId: string }
   'campaign:deleted': { campaignId: string }
 
+  // Tag events
+  // A campaign's tags were created, renamed, deleted, attached, or removed
+  'tags:changed': { campaignId: string }
+
   // Homebrew item events
   'homebrew-item:created': unknown
   'homebrew-item:updated': unknown
@@ -0,0 +1,116 @@
+/**
+ * Map region references in markdown documents, and region geometry.
+ *
+ * A reference is written `{{map-region: Crypt Level 1 | area-3}}`. Matches
+ * `find_map_region_refs` in mimir-print. Regions are stored in grid units;
+ * a polygon region also keeps its vertices, with x/y/width/height holding
+ * its bounding box.
+ */
+
+import type { MapRegion } from '@/services/MapRegionService'
+
+export interface MapRegionRef {
+  map: string
+  region: string
+  /** Offset of the opening braces in the text */
+  start: number
+  /** Offset just past the closing braces */
+  end: number
+}
+
+export interface RegionView {
+  zoom: number
+  panX: number
+  panY: number
+}
+
+const REF_PATTERN = /\{\{\s*map-region:\s*([^|}]+?)\s*\|\s*([^}]+?)\s*\}\}/g
+
+/** Viewer zoom limits, matching the DM map viewer */
+const MIN_ZOOM = 0.1
+const MAX_ZOOM = 5
+
+/** Fraction of the viewport a focused region fills */
+const FOCUS_FILL = 0.8
+
+/**
+ * Find every region reference in a run of text
+ */
+export function findMapRegionRefs(text: string): MapRegionRef[] {
+  return Array.from(text.matchAll(REF_PATTERN), match => ({
+    map: match[1],
+    region: match[2],
+    start: match.index ?? 0,
+    end: (match.index ?? 0) + match[0].length,
+  }))
+}
+
+/**
+ * The region reference covering a text offset, if any
+ */
+export function mapRegionRefAt(text: string, offset: number): MapRegionRef | null {
+  return findMapRegionRefs(text).find(ref => offset >= ref.start && offset < ref.end) ?? null
+}
+
+/**
+ * A region's polygon vertices in grid units; rectangles give their corners
+ */
+export function regionPoints(region: MapRegion): [number, number][] {
+  if (region.points) {
+    try {
+      return JSON.parse(region.points)
+    } catch {
+      // Fall back to the bounding box
+    }
+  }
+  const { x, y, width, height } = region
+  return [[x, y], [x + width, y], [x + width, y + height], [x, y + height]]
+}
+
+/**
+ * Whether a point in grid units lies inside a region
+ */
+export function regionContains(region: MapRegion, x: number, y: number): boolean {
+  if (x < region.x || x > region.x + region.width || y < region.y || y > region.y + region.height) {
+    return false
+  }
+  if (!region.points) return true
+
+  // Even-odd ray casting, as in mimir-core
+  const points = regionPoints(region)
+  let inside = false
+  for (let i = 0, j = points.length - 1; i < points.length; j = i++) {
+    const [xi, yi] = points[i]
+    const [xj, yj] = points[j]
+    if ((yi > y) !== (yj > y) && x < ((xj - xi) * (y - yi)) / (yj - yi) + xi) {
+      inside = !inside
+    }
+  }
+  return inside
+}
+
+/**
+ * Zoom and pan that frame a region in a viewer whose map is centered in the
+ * viewport and scaled about its center.
+ */
+export function regionFocusView(
+  region: MapRegion,
+  gridSize: number,
+  map: { width: number; height: number },
+  viewport: { width: number; height: number }
+): RegionView {
+  const width = Math.max(region.width * gridSize, 1)
+  const height = Math.max(region.height * gridSize, 1)
+  const zoom = Math.min(
+    MAX_ZOOM,
+    Math.max(MIN_ZOOM, Math.min((viewport.width * FOCUS_FILL) / width, (viewport.height * FOCUS_FILL) / height))
+  )
+
+  const centerX = (region.x + region.width / 2) * gridSize
+  const centerY = (region.y + region.height / 2) * gridSize
+  return {
+    zoom,
+    panX: -(centerX - map.width / 2) * zoom,
+    panY: -(centerY - map.height / 2) * zoom,
+  }
+}
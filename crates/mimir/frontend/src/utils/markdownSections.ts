@@ -0,0 +1,35 @@
+/**
+ * Sections of markdown documents.
+ *
+ * A section runs from its heading to the next heading of the same or a
+ * higher level. Headings inside code blocks are ignored.
+ */
+
+const HEADING_PATTERN = /^(#{1,6})\s+(.*?)\s*#*\s*$/
+
+/**
+ * The markdown under a heading, without the heading itself. Headings match
+ * case-insensitively; returns null if no heading matches.
+ */
+export function markdownSection(markdown: string, heading: string): string | null {
+  const wanted = heading.trim().toLowerCase()
+  let section: string[] | null = null
+  let level = 0
+  let inCode = false
+
+  for (const line of markdown.split(/\r?\n/)) {
+    if (line.trimStart().startsWith('```')) {
+      inCode = !inCode
+    }
+    const match = inCode ? null : HEADING_PATTERN.exec(line)
+    if (section) {
+      if (match && match[1].length <= level) break
+      section.push(line)
+    } else if (match && match[2].trim().toLowerCase() === wanted) {
+      section = []
+      level = match[1].length
+    }
+  }
+
+  return section ? section.join('\n').trim() : null
+}
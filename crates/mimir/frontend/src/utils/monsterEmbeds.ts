@@ -0,0 +1,67 @@
+/**
+ * Monster stat block embeds in markdown documents.
+ *
+ * An embed is written `{{monster: Goblin|MM}}`, or `{{monster: Goblin}}` to
+ * take the first match from homebrew and the campaign's enabled sources.
+ * Matches `find_monster_embeds` in mimir-print.
+ */
+
+export interface MonsterEmbed {
+  name: string
+  source: string | null
+  /** Offset of the opening braces in the text */
+  start: number
+  /** Offset just past the closing braces */
+  end: number
+}
+
+export interface MonsterEmbedSummary {
+  type: string
+  ac: string
+  hp: string
+  cr: string
+}
+
+const EMBED_PATTERN = /\{\{\s*monster:\s*([^|}]+?)\s*(?:\|\s*([^}]*?)\s*)?\}\}/g
+
+/**
+ * Find every embed in a run of text
+ */
+export function findMonsterEmbeds(text: string): MonsterEmbed[] {
+  return Array.from(text.matchAll(EMBED_PATTERN), match => ({
+    name: match[1],
+    source: match[2] || null,
+    start: match.index ?? 0,
+    end: (match.index ?? 0) + match[0].length,
+  }))
+}
+
+/**
+ * The embed covering a text offset, if any
+ */
+export function monsterEmbedAt(text: string, offset: number): MonsterEmbed | null {
+  return findMonsterEmbeds(text).find(embed => offset >= embed.start && offset < embed.end) ?? null
+}
+
+/**
+ * Pull the hover card fields out of 5etools monster JSON
+ */
+export function monsterEmbedSummary(data: Record<string, any>): MonsterEmbedSummary {
+  const type = typeof data.type === 'string' ? data.type : data.type?.type ?? ''
+
+  const firstAc = Array.isArray(data.ac) ? data.ac[0] : data.ac
+  const ac = typeof firstAc === 'object' && firstAc !== null ? firstAc.ac : firstAc
+
+  const hp = typeof data.hp === 'object' && data.hp !== null
+    ? [data.hp.average, data.hp.formula && `(${data.hp.formula})`].filter(Boolean).join(' ')
+    : data.hp
+
+  const cr = typeof data.cr === 'object' && data.cr !== null ? data.cr.cr : data.cr
+
+  return {
+    type: String(type),
+    ac: ac === undefined ? '—' : String(ac),
+    hp: hp === undefined || hp === '' ? '—' : String(hp),
+    cr: cr === undefined ? '—' : String(cr),
+  }
+}
@@ -0,0 +1,40 @@
+/**
+ * Boxed read-aloud text in markdown documents.
+ *
+ * A block starts at a `:::read-aloud` line and ends at the next `:::` line,
+ * or at the end of the document if it is never closed. Fences inside code
+ * blocks are ignored. Matches `read_aloud_blocks` in mimir-core, so block
+ * indexes line up with `send_read_aloud_to_display`.
+ */
+
+const OPEN_FENCE = ':::read-aloud'
+const CLOSE_FENCE = ':::'
+
+/**
+ * Extract each read-aloud block's markdown, trimmed, in document order
+ */
+export function readAloudBlocks(markdown: string): string[] {
+  const blocks: string[] = []
+  let current: string[] | null = null
+  let inCode = false
+
+  for (const line of markdown.split(/\r?\n/)) {
+    if (current) {
+      if (line.trim() === CLOSE_FENCE) {
+        blocks.push(current.join('\n').trim())
+        current = null
+      } else {
+        current.push(line)
+      }
+    } else if (!inCode && line.trim() === OPEN_FENCE) {
+      current = []
+    } else if (line.trimStart().startsWith('```')) {
+      inCode = !inCode
+    }
+  }
+
+  if (current) {
+    blocks.push(current.join('\n').trim())
+  }
+  return blocks
+}
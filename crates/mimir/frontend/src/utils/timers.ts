@@ -0,0 +1,35 @@
+/**
+ * Table timer display helpers.
+ *
+ * `timers:update` snapshots are sent when a timer changes, not every second,
+ * so windows tick running timers locally from when the snapshot arrived.
+ */
+
+import type { TimerSnapshot } from '@/types/api'
+
+/**
+ * Seconds a timer has run, counting time since its snapshot was received
+ */
+export function timerElapsed(timer: TimerSnapshot, receivedAt: number, now: number): number {
+  if (!timer.running) return timer.elapsedSecs
+  return timer.elapsedSecs + Math.max(Math.floor((now - receivedAt) / 1000), 0)
+}
+
+/**
+ * Seconds left on a countdown timer, or null if it has no limit
+ */
+export function timerRemaining(timer: TimerSnapshot, receivedAt: number, now: number): number | null {
+  if (timer.limitSecs === null) return null
+  return Math.max(timer.limitSecs - timerElapsed(timer, receivedAt, now), 0)
+}
+
+/**
+ * Format seconds as a clock, e.g. "4:05" or "1:02:09"
+ */
+export function formatClock(seconds: number): string {
+  const hours = Math.floor(seconds / 3600)
+  const minutes = Math.floor((seconds % 3600) / 60)
+  const secs = String(seconds % 60).padStart(2, '0')
+  if (hours > 0) return `${hours}:${String(minutes).padStart(2, '0')}:${secs}`
+  return `${minutes}:${secs}`
+}
@@ -0,0 +1,453 @@
+//! Command Registry
+//!
+//! The actions offered in the command palette: an ID, a human label, the
+//! parameters each one takes, and the context (campaign, module, map) it
+//! needs. The frontend lists them with `list_registered_commands` and runs
+//! them by ID with `execute_registered_command`, which checks arguments
+//! against the parameters here before dispatching.
+//!
+//! Arguments are passed by parameter name. Parameters of a context kind
+//! (`campaign`, `module`, `map`) are filled by the frontend from whatever
+//! the user has open.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::ipc::InvokeBody;
+
+use crate::command_trace::summarize_args;
+use crate::error::{CommandError, CommandResult};
+use mimir_core::services::ErrorKind;
+
+/// What a command parameter holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamKind {
+    Text,
+    Integer,
+    Boolean,
+    /// One of the parameter's `options`
+    Choice,
+    /// ID of the open campaign
+    Campaign,
+    /// ID of the open module
+    Module,
+    /// ID of the open map
+    Map,
+    /// ID of one of the campaign's quick actions
+    QuickAction,
+}
+
+/// What has to be open for a command to make sense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextRequirement {
+    Campaign,
+    Module,
+    Map,
+}
+
+/// A parameter of a registered command.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandParam {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub kind: ParamKind,
+    pub required: bool,
+    /// Allowed values of a `choice` parameter
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub options: &'static [&'static str],
+}
+
+/// An action the command palette can run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegisteredCommand {
+    /// Stable ID, e.g. `module.create`
+    pub id: &'static str,
+    pub label: &'static str,
+    /// Palette group, e.g. "Display"
+    pub category: &'static str,
+    pub params: &'static [CommandParam],
+    pub requires: &'static [ContextRequirement],
+}
+
+/// A registered command as listed in the palette.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaletteCommand {
+    #[serde(flatten)]
+    pub command: &'static RegisteredCommand,
+    /// Whether the context has everything the command requires
+    pub available: bool,
+}
+
+/// What the user has open, for deciding which commands are available.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandContext {
+    pub campaign_id: Option<String>,
+    pub module_id: Option<String>,
+    pub map_id: Option<String>,
+}
+
+impl CommandContext {
+    /// Whether every requirement is met.
+    pub fn satisfies(&self, requires: &[ContextRequirement]) -> bool {
+        requires.iter().all(|r| match r {
+            ContextRequirement::Campaign => self.campaign_id.is_some(),
+            ContextRequirement::Module => self.module_id.is_some(),
+            ContextRequirement::Map => self.map_id.is_some(),
+        })
+    }
+}
+
+const fn param(
+    name: &'static str,
+    label: &'static str,
+    kind: ParamKind,
+    required: bool,
+) -> CommandParam {
+    CommandParam {
+        name,
+        label,
+        kind,
+        required,
+        options: &[],
+    }
+}
+
+const fn choice(
+    name: &'static str,
+    label: &'static str,
+    required: bool,
+    options: &'static [&'static str],
+) -> CommandParam {
+    CommandParam {
+        name,
+        label,
+        kind: ParamKind::Choice,
+        required,
+        options,
+    }
+}
+
+const CAMPAIGN: CommandParam = param("campaignId", "Campaign", ParamKind::Campaign, true);
+const SCREEN: CommandParam = choice("screen", "Screen", false, &["primary", "secondary"]);
+
+/// Every command the palette can run, in palette order.
+pub const REGISTERED_COMMANDS: &[RegisteredCommand] = &[
+    RegisteredCommand {
+        id: "module.create",
+        label: "Create Module",
+        category: "Campaign",
+        params: &[
+            CAMPAIGN,
+            param("name", "Name", ParamKind::Text, true),
+            choice(
+                "moduleType",
+                "Type",
+                false,
+                &[
+                    "general",
+                    "mystery",
+                    "dungeon",
+                    "heist",
+                    "horror",
+                    "political",
+                ],
+            ),
+        ],
+        requires: &[ContextRequirement::Campaign],
+    },
+    RegisteredCommand {
+        id: "campaign.backup",
+        label: "Back Up Campaign",
+        category: "Campaign",
+        params: &[
+            CAMPAIGN,
+            param(
+                "differential",
+                "Only changes since the last full backup",
+                ParamKind::Boolean,
+                false,
+            ),
+        ],
+        requires: &[ContextRequirement::Campaign],
+    },
+    RegisteredCommand {
+        id: "quick_action.trigger",
+        label: "Trigger Quick Action",
+        category: "Table",
+        params: &[
+            CAMPAIGN,
+            param(
+                "quickActionId",
+                "Quick action",
+                ParamKind::QuickAction,
+                true,
+            ),
+        ],
+        requires: &[ContextRequirement::Campaign],
+    },
+    RegisteredCommand {
+        id: "session.start",
+        label: "Start Game Session",
+        category: "Table",
+        params: &[
+            CAMPAIGN,
+            param("moduleId", "Module", ParamKind::Module, false),
+            param("title", "Title", ParamKind::Text, false),
+        ],
+        requires: &[ContextRequirement::Campaign],
+    },
+    RegisteredCommand {
+        id: "session.end",
+        label: "End Game Session",
+        category: "Table",
+        params: &[],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "timer.start",
+        label: "Start Timer",
+        category: "Table",
+        params: &[
+            choice("kind", "Timer", true, &["turn", "break", "session"]),
+            param(
+                "durationSecs",
+                "Countdown (seconds)",
+                ParamKind::Integer,
+                false,
+            ),
+            param("label", "Label", ParamKind::Text, false),
+        ],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "timer.next_turn",
+        label: "Next Turn",
+        category: "Table",
+        params: &[param("label", "Whose turn", ParamKind::Text, false)],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "display.open",
+        label: "Open Player Display",
+        category: "Display",
+        params: &[SCREEN],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "display.blackout",
+        label: "Black Out Player Display",
+        category: "Display",
+        params: &[
+            param("isBlackout", "Blackout", ParamKind::Boolean, true),
+            SCREEN,
+        ],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "display.reveal_next_handout",
+        label: "Reveal Next Handout",
+        category: "Display",
+        params: &[CAMPAIGN, SCREEN],
+        requires: &[ContextRequirement::Campaign],
+    },
+    RegisteredCommand {
+        id: "display.clear_handout",
+        label: "Clear Handout",
+        category: "Display",
+        params: &[SCREEN],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "lan_display.start",
+        label: "Share Display on LAN",
+        category: "Display",
+        params: &[param("port", "Port", ParamKind::Integer, false)],
+        requires: &[],
+    },
+    RegisteredCommand {
+        id: "lan_display.stop",
+        label: "Stop Sharing Display on LAN",
+        category: "Display",
+        params: &[],
+        requires: &[],
+    },
+];
+
+/// List every registered command, marking which ones `context` allows.
+pub fn palette(context: &CommandContext) -> Vec<PaletteCommand> {
+    REGISTERED_COMMANDS
+        .iter()
+        .map(|command| PaletteCommand {
+            command,
+            available: context.satisfies(command.requires),
+        })
+        .collect()
+}
+
+/// Look up a registered command by ID.
+pub fn find(id: &str) -> CommandResult<&'static RegisteredCommand> {
+    REGISTERED_COMMANDS
+        .iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| {
+            CommandError::new(ErrorKind::NotFound, format!("Unknown command '{}'", id))
+                .with_entity("Command")
+        })
+}
+
+/// Check arguments against a command's parameters: required ones are
+/// present, values have the right type, and nothing unknown is passed.
+pub fn validate_args(command: &RegisteredCommand, args: &Map<String, Value>) -> CommandResult<()> {
+    for name in args.keys() {
+        if !command.params.iter().any(|p| p.name == name) {
+            return Err(invalid(format!(
+                "'{}' doesn't take an argument '{}'",
+                command.id, name
+            )));
+        }
+    }
+
+    for param in command.params {
+        let value = match args.get(param.name) {
+            None | Some(Value::Null) if param.required => {
+                return Err(invalid(format!(
+                    "'{}' requires '{}'",
+                    command.id, param.name
+                )))
+            }
+            None | Some(Value::Null) => continue,
+            Some(value) => value,
+        };
+        let valid = match param.kind {
+            ParamKind::Integer => value.is_i64(),
+            ParamKind::Boolean => value.is_boolean(),
+            ParamKind::Choice => value.as_str().is_some_and(|v| param.options.contains(&v)),
+            _ => value.is_string(),
+        };
+        if !valid {
+            return Err(invalid(format!(
+                "Invalid value for '{}': {}",
+                param.name, value
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Write an audit record of a registered command run to the log, with
+/// secret-looking arguments redacted.
+pub fn log_execution(
+    command_id: &str,
+    args: &Map<String, Value>,
+    result: &CommandResult<Value>,
+    duration_ms: u64,
+) {
+    let args = summarize_args(&InvokeBody::Json(Value::Object(args.clone())));
+    match result {
+        Ok(_) => tracing::info!(command_id, args = %args, duration_ms, "Registered command run"),
+        Err(e) => tracing::warn!(
+            command_id,
+            args = %args,
+            duration_ms,
+            error = %e,
+            "Registered command failed"
+        ),
+    }
+}
+
+fn invalid(message: String) -> CommandError {
+    CommandError::new(ErrorKind::Validation, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[test]
+    fn test_ids_are_unique() {
+        for (i, command) in REGISTERED_COMMANDS.iter().enumerate() {
+            assert!(
+                REGISTERED_COMMANDS[i + 1..]
+                    .iter()
+                    .all(|c| c.id != command.id),
+                "duplicate command id {}",
+                command.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_unknown_command() {
+        assert!(find("module.create").is_ok());
+        let err = find("module.explode").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_validate_args() {
+        let command = find("module.create").unwrap();
+        assert!(validate_args(
+            command,
+            &args(json!({"campaignId": "c1", "name": "Caves", "moduleType": "dungeon"}))
+        )
+        .is_ok());
+        // Optional parameters can be left out or null
+        assert!(validate_args(
+            command,
+            &args(json!({"campaignId": "c1", "name": "Caves", "moduleType": null}))
+        )
+        .is_ok());
+
+        for bad in [
+            json!({"campaignId": "c1"}),
+            json!({"campaignId": "c1", "name": 3}),
+            json!({"campaignId": "c1", "name": "Caves", "moduleType": "picnic"}),
+            json!({"campaignId": "c1", "name": "Caves", "colour": "red"}),
+        ] {
+            let err = validate_args(command, &args(bad)).unwrap_err();
+            assert_eq!(err.kind, ErrorKind::Validation);
+        }
+
+        let timer = find("timer.start").unwrap();
+        assert!(validate_args(timer, &args(json!({"kind": "turn", "durationSecs": 60}))).is_ok());
+        assert!(
+            validate_args(timer, &args(json!({"kind": "turn", "durationSecs": "60"}))).is_err()
+        );
+    }
+
+    #[test]
+    fn test_palette_marks_available_commands() {
+        let palette = palette(&CommandContext::default());
+        assert_eq!(palette.len(), REGISTERED_COMMANDS.len());
+        let available = |id: &str| {
+            palette
+                .iter()
+                .find(|p| p.command.id == id)
+                .unwrap()
+                .available
+        };
+        assert!(!available("module.create"));
+        assert!(available("timer.start"));
+    }
+
+    #[test]
+    fn test_context_requirements() {
+        let context = CommandContext::default();
+        assert!(context.satisfies(&[]));
+        assert!(!context.satisfies(&[ContextRequirement::Campaign]));
+
+        let context = CommandContext {
+            campaign_id: Some("c1".to_string()),
+            ..Default::default()
+        };
+        assert!(context.satisfies(&[ContextRequirement::Campaign]));
+        assert!(!context.satisfies(&[ContextRequirement::Campaign, ContextRequirement::Map]));
+    }
+}
@@ -0,0 +1,265 @@
+//! Command Tracing
+//!
+//! Wraps every Tauri command with a request ID, a duration, and a short
+//! summary of its arguments. Each call runs inside a `command` span (so
+//! anything the command logs carries its request ID) and is written to the
+//! log files under the `mimir_lib::command_trace` target. The most recent
+//! calls are also kept in memory so the log viewer can list slow commands.
+//!
+//! Synchronous commands are timed end to end. Async commands return to the
+//! dispatcher immediately, so their duration covers dispatch only.
+
+use chrono::Utc;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::{Manager, Runtime};
+
+use crate::state::AppState;
+
+pub use mimir_core::services::DEFAULT_SLOW_COMMAND_MS;
+
+/// Number of recent command timings kept in memory.
+pub const RECENT_COMMANDS_KEPT: usize = 1000;
+
+/// Longest string argument shown in an argument summary before truncating.
+const MAX_SUMMARY_STRING: usize = 32;
+
+/// Argument names whose values are never written to the log.
+const REDACTED_ARGS: &[&str] = &[
+    "password",
+    "passphrase",
+    "secret",
+    "credential",
+    "apikey",
+    "accesskey",
+];
+
+/// One traced command call.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandTiming {
+    pub request_id: u64,
+    pub command: String,
+    /// Short summary of the arguments, with long values truncated
+    pub args: String,
+    /// When the command started (RFC 3339)
+    pub started_at: String,
+    pub duration_ms: u64,
+}
+
+/// Request ID counter, slow-command threshold, and recent command timings.
+#[derive(Debug)]
+pub struct CommandTrace {
+    next_request_id: AtomicU64,
+    slow_command_ms: AtomicU64,
+    recent: Mutex<VecDeque<CommandTiming>>,
+}
+
+impl Default for CommandTrace {
+    fn default() -> Self {
+        Self {
+            next_request_id: AtomicU64::new(0),
+            slow_command_ms: AtomicU64::new(DEFAULT_SLOW_COMMAND_MS),
+            recent: Mutex::new(VecDeque::new()),
+        }
+    }
+}
+
+impl CommandTrace {
+    /// Commands at least this slow (in milliseconds) are logged as warnings.
+    pub fn slow_command_ms(&self) -> u64 {
+        self.slow_command_ms.load(Ordering::Relaxed)
+    }
+
+    /// Change the slow-command threshold, e.g. when settings change.
+    pub fn set_slow_command_ms(&self, threshold_ms: u64) {
+        self.slow_command_ms.store(threshold_ms, Ordering::Relaxed);
+    }
+
+    /// Allocate the next request ID.
+    pub fn next_request_id(&self) -> u64 {
+        self.next_request_id.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Remember a finished command, dropping the oldest beyond the limit.
+    pub fn record(&self, timing: CommandTiming) {
+        if let Ok(mut recent) = self.recent.lock() {
+            if recent.len() == RECENT_COMMANDS_KEPT {
+                recent.pop_front();
+            }
+            recent.push_back(timing);
+        }
+    }
+
+    /// Recent commands that took at least `threshold_ms`, slowest first.
+    pub fn slow_commands(&self, threshold_ms: u64) -> Vec<CommandTiming> {
+        let mut slow: Vec<CommandTiming> = match self.recent.lock() {
+            Ok(recent) => recent
+                .iter()
+                .filter(|t| t.duration_ms >= threshold_ms)
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        slow.sort_by(|a, b| b.duration_ms.cmp(&a.duration_ms));
+        slow
+    }
+}
+
+/// Wrap a command handler (from `tauri::generate_handler!`) so every call
+/// is traced.
+pub fn traced<R: Runtime>(
+    handler: impl Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    move |invoke: Invoke<R>| {
+        let app = invoke.message.webview().app_handle().clone();
+        let command = invoke.message.command().to_string();
+        let args = summarize_args(invoke.message.payload());
+        let (request_id, slow_command_ms) = app
+            .try_state::<AppState>()
+            .map(|state| {
+                (
+                    state.command_trace.next_request_id(),
+                    state.command_trace.slow_command_ms(),
+                )
+            })
+            .unwrap_or((0, DEFAULT_SLOW_COMMAND_MS));
+
+        let span = tracing::info_span!("command", request_id, command = %command);
+        let _entered = span.enter();
+        let started_at = Utc::now().to_rfc3339();
+        let start = Instant::now();
+
+        let handled = handler(invoke);
+
+        let duration_ms = start.elapsed().as_millis() as u64;
+        if duration_ms >= slow_command_ms {
+            tracing::warn!(duration_ms, args = %args, "Slow command");
+        } else {
+            tracing::info!(duration_ms, args = %args, "Command finished");
+        }
+
+        if let Some(state) = app.try_state::<AppState>() {
+            state.command_trace.record(CommandTiming {
+                request_id,
+                command,
+                args,
+                started_at,
+                duration_ms,
+            });
+        }
+        handled
+    }
+}
+
+/// Summarize command arguments as `name=value` pairs.
+///
+/// Strings are truncated, nested objects and arrays are reduced to their
+/// size, and arguments that look like secrets are redacted.
+pub fn summarize_args(body: &InvokeBody) -> String {
+    match body {
+        InvokeBody::Json(Value::Object(args)) => args
+            .iter()
+            .map(|(name, value)| {
+                let lower = name.to_lowercase().replace('_', "");
+                if REDACTED_ARGS.iter().any(|r| lower.contains(r)) {
+                    format!("{}=<redacted>", name)
+                } else {
+                    format!("{}={}", name, summarize_value(value))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        InvokeBody::Json(Value::Null) => String::new(),
+        InvokeBody::Json(value) => summarize_value(value),
+        InvokeBody::Raw(bytes) => format!("<{} bytes>", bytes.len()),
+    }
+}
+
+fn summarize_value(value: &Value) -> String {
+    match value {
+        Value::String(s) if s.chars().count() > MAX_SUMMARY_STRING => {
+            let truncated: String = s.chars().take(MAX_SUMMARY_STRING).collect();
+            format!("{:?}", format!("{}...", truncated))
+        }
+        Value::String(s) => format!("{:?}", s),
+        Value::Array(items) => format!("[{} items]", items.len()),
+        Value::Object(fields) => format!("{{{} fields}}", fields.len()),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn timing(request_id: u64, duration_ms: u64) -> CommandTiming {
+        CommandTiming {
+            request_id,
+            command: "list_campaigns".to_string(),
+            args: String::new(),
+            started_at: "2024-03-04T10:00:00Z".to_string(),
+            duration_ms,
+        }
+    }
+
+    #[test]
+    fn test_slow_commands_sorted_slowest_first() {
+        let trace = CommandTrace::default();
+        trace.record(timing(1, 300));
+        trace.record(timing(2, 10));
+        trace.record(timing(3, 900));
+
+        let ids: Vec<_> = trace
+            .slow_commands(250)
+            .iter()
+            .map(|t| t.request_id)
+            .collect();
+        assert_eq!(ids, vec![3, 1]);
+    }
+
+    #[test]
+    fn test_recent_commands_are_bounded() {
+        let trace = CommandTrace::default();
+        for id in 0..(RECENT_COMMANDS_KEPT as u64 + 5) {
+            trace.record(timing(id, 1000));
+        }
+        let slow = trace.slow_commands(0);
+        assert_eq!(slow.len(), RECENT_COMMANDS_KEPT);
+        assert!(slow.iter().all(|t| t.request_id >= 5));
+    }
+
+    #[test]
+    fn test_request_ids_increase() {
+        let trace = CommandTrace::default();
+        assert_eq!(trace.next_request_id(), 1);
+        assert_eq!(trace.next_request_id(), 2);
+    }
+
+    #[test]
+    fn test_summarize_args() {
+        let body = InvokeBody::Json(json!({
+            "campaignId": "camp-1",
+            "limit": 10,
+            "content": "x".repeat(100),
+            "tags": ["a", "b"],
+            "config": { "bucket": "b", "region": "r" },
+            "secretKey": "hunter2",
+        }));
+        let summary = summarize_args(&body);
+        assert!(summary.contains("campaignId=\"camp-1\""));
+        assert!(summary.contains("limit=10"));
+        assert!(summary.contains(&format!("content=\"{}...\"", "x".repeat(32))));
+        assert!(summary.contains("tags=[2 items]"));
+        assert!(summary.contains("config={2 fields}"));
+        assert!(summary.contains("secretKey=<redacted>"));
+        assert!(!summary.contains("hunter2"));
+
+        assert_eq!(summarize_args(&InvokeBody::Raw(vec![0; 4])), "<4 bytes>");
+    }
+}
@@ -0,0 +1,24 @@
+//! Campaign Analytics Commands
+//!
+//! Tauri commands for the local campaign statistics dashboard.
+
+use mimir_core::services::{AnalyticsService, CampaignAnalytics};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Get statistics for a campaign: sessions run, prep per week, most-used
+/// monsters and spells, and combat pacing.
+#[tauri::command]
+pub fn get_campaign_analytics(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<CampaignAnalytics> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(AnalyticsService::new(&mut db).campaign_analytics(&campaign_id))
+}
@@ -2,14 +2,21 @@
 //!
 //! Tauri commands for campaign export/import operations.
 
-use mimir_core::services::{ArchivePreview, ArchiveService, ImportResult};
+use mimir_core::services::{
+    ArchivePreview, ArchiveService, BackupEntry, BackupKind, BackupService, ImportResult,
+    MarkdownExportResult, MarkdownExportService,
+};
 use serde::Serialize;
 use std::path::Path;
-use tauri::State;
+use tauri::{AppHandle, Emitter, Manager, State};
 
 use super::{to_api_response, ApiResponse};
 use crate::state::AppState;
 
+/// Event emitted with an `ArchiveProgress` as each file is written to an
+/// exported archive.
+pub const ARCHIVE_EXPORT_PROGRESS_EVENT: &str = "archive:export-progress";
+
 /// Result of exporting a campaign.
 #[derive(Debug, Serialize)]
 pub struct ExportResult {
@@ -22,13 +29,16 @@ pub struct ExportResult {
 /// Export a campaign to an archive file.
 ///
 /// Creates a `.mimir-campaign.tar.gz` archive containing all campaign data
-/// and assets, suitable for sharing with other DMs.
-#[tauri::command]
+/// and assets, suitable for sharing with other DMs. Runs off the main thread
+/// and emits `archive:export-progress` events while files are written; the
+/// archive is verified against its checksums before this returns.
+#[tauri::command(async)]
 pub fn export_campaign(
-    state: State<'_, AppState>,
+    app: AppHandle,
     campaign_id: String,
     output_dir: String,
 ) -> ApiResponse<ExportResult> {
+    let state = app.state::<AppState>();
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
@@ -37,7 +47,18 @@ pub fn export_campaign(
     let output_path = Path::new(&output_dir);
     let assets_path = &state.paths.assets_dir;
 
-    match ArchiveService::new(&mut db).export_campaign(&campaign_id, output_path, assets_path) {
+    let result = ArchiveService::new(&mut db).export_campaign_with_progress(
+        &campaign_id,
+        output_path,
+        assets_path,
+        |progress| {
+            if let Err(e) = app.emit(ARCHIVE_EXPORT_PROGRESS_EVENT, progress) {
+                tracing::warn!("Failed to emit archive export progress: {}", e);
+            }
+        },
+    );
+
+    match result {
         Ok(archive_path) => {
             // Get file size
             let size_bytes = std::fs::metadata(&archive_path)
@@ -53,6 +74,27 @@ pub fn export_campaign(
     }
 }
 
+/// Export a campaign as a folder of markdown files.
+///
+/// Writes documents, character sheets, NPC notes, and session logs to
+/// `<dest>/<campaign-slug>/` with an `index.md`. Exporting again into the
+/// same folder updates it in place, so it can be kept under version control.
+#[tauri::command]
+pub fn export_campaign_markdown(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    dest: String,
+) -> ApiResponse<MarkdownExportResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result =
+        MarkdownExportService::new(&mut db).export_campaign(&campaign_id, Path::new(&dest));
+    to_api_response(result)
+}
+
 /// Preview an archive without importing it.
 ///
 /// Returns information about the archive contents including campaign name,
@@ -89,3 +131,71 @@ pub fn import_campaign(
     );
     to_api_response(result)
 }
+
+/// Back up a campaign into the app's backup directory.
+///
+/// A differential backup (`differential: true`) only stores what changed
+/// since the campaign's last backup, making it cheap enough to run before
+/// every session. The first backup of a campaign is always full.
+#[tauri::command(async)]
+pub fn create_campaign_backup(
+    app: AppHandle,
+    campaign_id: String,
+    differential: Option<bool>,
+) -> ApiResponse<BackupEntry> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let kind = if differential.unwrap_or(false) {
+        BackupKind::Differential
+    } else {
+        BackupKind::Full
+    };
+    to_api_response(BackupService::new(&mut db, &state.paths.app_dir).create(
+        &campaign_id,
+        kind,
+        &state.paths.assets_dir,
+    ))
+}
+
+/// List a campaign's backups, oldest first.
+#[tauri::command]
+pub fn list_campaign_backups(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<BackupEntry>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(BackupService::new(&mut db, &state.paths.app_dir).list(&campaign_id))
+}
+
+/// Restore a campaign backup as a new campaign.
+///
+/// Rebuilds the campaign from its last full backup and the differential
+/// backups after it, up to `backup_id`.
+#[tauri::command(async)]
+pub fn restore_campaign_backup(
+    app: AppHandle,
+    campaign_id: String,
+    backup_id: String,
+    new_name: Option<String>,
+) -> ApiResponse<ImportResult> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(BackupService::new(&mut db, &state.paths.app_dir).restore(
+        &campaign_id,
+        &backup_id,
+        &state.paths.assets_dir,
+        new_name.as_deref(),
+    ))
+}
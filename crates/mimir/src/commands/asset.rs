@@ -2,10 +2,11 @@
 //!
 //! Tauri commands for binary asset management (images, files).
 
-use mimir_core::models::campaign::CampaignAsset;
+use mimir_core::models::campaign::{CampaignAsset, TaggableType};
 use mimir_core::services::{AssetService, UploadAssetInput};
 use tauri::State;
 
+use super::tag::filter_by_tag;
 use super::{to_api_response, ApiResponse};
 use crate::state::AppState;
 
@@ -14,18 +15,24 @@ use crate::state::AppState;
 // =============================================================================
 
 /// List all assets for a campaign.
+///
+/// `tag_id` limits the list to assets carrying that tag.
 #[tauri::command]
 pub fn list_campaign_assets(
     state: State<'_, AppState>,
     campaign_id: String,
+    tag_id: Option<String>,
 ) -> ApiResponse<Vec<CampaignAsset>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = AssetService::new(&mut db, &state.paths.app_dir).list_for_campaign(&campaign_id);
-    to_api_response(result)
+    let assets = match AssetService::new(&mut db, &state.paths.app_dir).list_for_campaign(&campaign_id) {
+        Ok(assets) => assets,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    to_api_response(filter_by_tag(&mut db, tag_id.as_deref(), TaggableType::Asset, assets, |a| &a.id))
 }
 
 /// List all assets for a module.
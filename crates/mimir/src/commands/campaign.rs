@@ -7,7 +7,9 @@ use mimir_core::dal::campaign::{
     insert_campaign_source, list_campaign_source_codes,
 };
 use mimir_core::models::campaign::{Campaign, CampaignSource, NewCampaignSource};
-use mimir_core::services::{CampaignService, CreateCampaignInput, UpdateCampaignInput};
+use mimir_core::services::{
+    ArchiveService, CampaignService, CloneCampaignOptions, CreateCampaignInput, UpdateCampaignInput,
+};
 use tauri::State;
 use uuid::Uuid;
 
@@ -115,12 +117,82 @@ pub fn update_campaign(
     let input = UpdateCampaignInput {
         name: request.name,
         description: request.description,
+        ..Default::default()
     };
 
     let result = CampaignService::new(&mut db).update(&id, input);
     to_api_response(result)
 }
 
+/// Set a campaign's ruleset edition ("2014" or "2024"), or `None` for both.
+#[tauri::command]
+pub fn set_campaign_edition(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    edition: Option<String>,
+) -> ApiResponse<Campaign> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CampaignService::new(&mut db)
+        .update(&campaign_id, UpdateCampaignInput::set_edition(edition));
+    to_api_response(result)
+}
+
+/// Limit a campaign's AI searches and exports to SRD content, or lift the limit.
+#[tauri::command]
+pub fn set_campaign_srd_only(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    srd_only: bool,
+) -> ApiResponse<Campaign> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CampaignService::new(&mut db)
+        .update(&campaign_id, UpdateCampaignInput::set_srd_only(srd_only));
+    to_api_response(result)
+}
+
+/// Clone a campaign under a new name.
+///
+/// Deep-copies modules, documents, maps, homebrew, and assets with new IDs so
+/// a prepared campaign can be reused for another table. NPCs are copied and
+/// player characters are not unless `options` says otherwise.
+#[tauri::command]
+pub fn clone_campaign(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    new_name: String,
+    options: Option<CloneCampaignOptions>,
+) -> ApiResponse<Campaign> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let options = options.unwrap_or_default();
+    let cloned = match ArchiveService::new(&mut db).clone_campaign(
+        &campaign_id,
+        &new_name,
+        &options,
+        &state.paths.assets_dir,
+    ) {
+        Ok(result) => result,
+        Err(e) => return ApiResponse::err(format!("Clone failed: {}", e)),
+    };
+
+    match CampaignService::new(&mut db).get(&cloned.campaign_id) {
+        Ok(Some(campaign)) => ApiResponse::ok(campaign),
+        Ok(None) => ApiResponse::err(format!("Campaign not found: {}", cloned.campaign_id)),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
 /// Archive a campaign (soft delete).
 #[tauri::command]
 pub fn archive_campaign(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Campaign> {
@@ -210,6 +282,23 @@ pub fn list_campaign_sources(
     to_api_response(result)
 }
 
+/// List the source codes catalog searches should be limited to for a campaign.
+///
+/// Combines the campaign's allowed sources with its edition. Returns `None`
+/// when searches are unrestricted.
+#[tauri::command]
+pub fn list_campaign_search_sources(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Option<Vec<String>>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CampaignService::new(&mut db).search_sources(&campaign_id))
+}
+
 /// Add a source to a campaign's allowed sources.
 #[tauri::command]
 pub fn add_campaign_source(
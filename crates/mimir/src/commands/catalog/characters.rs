@@ -7,8 +7,9 @@ use mimir_core::models::catalog::{
     RaceFilter, Subclass,
 };
 use mimir_core::services::{
-    BackgroundService, CatalogEntityService, ClassFeatureService, ClassService, FeatService,
-    RaceService, SubclassFeatureService, SubclassService, DEFAULT_QUERY_LIMIT,
+    get_background_options as load_background_options, BackgroundOptions, BackgroundService,
+    CatalogEntityService, ClassFeatureService, ClassService, FeatService, RaceService,
+    SubclassFeatureService, SubclassService, DEFAULT_QUERY_LIMIT,
 };
 use serde_json::Value;
 use tauri::State;
@@ -170,6 +171,30 @@ pub fn get_background_by_name(
     }
 }
 
+/// Get a background's feature, proficiencies, and characteristics tables.
+///
+/// With `roll`, also rolls personality traits, an ideal, a bond, and a flaw.
+#[tauri::command]
+pub fn get_background_options(
+    state: State<'_, AppState>,
+    name: String,
+    source: String,
+    roll: Option<bool>,
+) -> ApiResponse<BackgroundOptions> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = load_background_options(&mut db, &name, &source).map(|mut options| {
+        if roll.unwrap_or(false) {
+            options.rolled = Some(options.roll_characteristics());
+        }
+        options
+    });
+    to_api_response(result)
+}
+
 /// List all background sources.
 #[tauri::command]
 pub fn list_background_sources(state: State<'_, AppState>) -> ApiResponse<Vec<String>> {
@@ -4,14 +4,18 @@
 
 use mimir_core::dal::campaign as dal;
 use mimir_core::models::campaign::{
-    CharacterInventory, CharacterResponse, CharacterSpell, NewCharacterSpell, UpdateCharacterSpell,
+    CharacterInventory, CharacterResponse, CharacterSpell, ModuleMonster, NewCharacterSpell,
+    TaggableType, UpdateCharacterSpell,
 };
 use mimir_core::services::{
     AddInventoryInput, CharacterService, CreateCharacterInput, LevelUpRequest, LevelUpResult,
+    PartyService, RespecRequest, RespecResult, StatblockService, TrashKind, TrashService,
     UpdateCharacterInput,
 };
 use tauri::State;
 
+use super::history::record_history;
+use super::tag::filter_by_tag;
 use super::{to_api_response, ApiResponse};
 use crate::state::AppState;
 
@@ -43,40 +47,62 @@ pub fn list_characters(
 }
 
 /// List only player characters for a campaign (with classes and proficiencies).
+///
+/// `party_id` limits the list to members of that party.
 #[tauri::command]
-pub fn list_pcs(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<CharacterResponse>> {
+pub fn list_pcs(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    party_id: Option<String>,
+) -> ApiResponse<Vec<CharacterResponse>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    let mut service = CharacterService::new(&mut db);
-    let characters = match service.list_pcs(&campaign_id) {
+    let characters = match party_id {
+        Some(party_id) => PartyService::new(&mut db).list_pcs(&party_id).map(|pcs| {
+            pcs.into_iter()
+                .filter(|c| c.campaign_id.as_deref() == Some(campaign_id.as_str()))
+                .collect()
+        }),
+        None => CharacterService::new(&mut db).list_pcs(&campaign_id),
+    };
+    let characters = match characters {
         Ok(chars) => chars,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
 
-    match service.enrich_many(characters) {
+    match CharacterService::new(&mut db).enrich_many(characters) {
         Ok(result) => ApiResponse::ok(result),
         Err(e) => ApiResponse::err(e.to_string()),
     }
 }
 
 /// List only NPCs for a campaign (with classes and proficiencies).
+///
+/// `tag_id` limits the list to NPCs carrying that tag.
 #[tauri::command]
-pub fn list_npcs(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<CharacterResponse>> {
+pub fn list_npcs(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    tag_id: Option<String>,
+) -> ApiResponse<Vec<CharacterResponse>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    let mut service = CharacterService::new(&mut db);
-    let characters = match service.list_npcs(&campaign_id) {
+    let characters = match CharacterService::new(&mut db).list_npcs(&campaign_id) {
         Ok(chars) => chars,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
+    let characters = match filter_by_tag(&mut db, tag_id.as_deref(), TaggableType::Npc, characters, |c| &c.id) {
+        Ok(chars) => chars,
+        Err(e) => return ApiResponse::err(e),
+    };
 
-    match service.enrich_many(characters) {
+    match CharacterService::new(&mut db).enrich_many(characters) {
         Ok(result) => ApiResponse::ok(result),
         Err(e) => ApiResponse::err(e.to_string()),
     }
@@ -128,12 +154,22 @@ pub struct CreatePcRequest {
     pub player_name: String,
     pub race_name: Option<String>,
     pub race_source: Option<String>,
+    pub subrace_name: Option<String>,
+    pub subrace_source: Option<String>,
     pub background_name: Option<String>,
     pub background_source: Option<String>,
+    /// Base ability scores, before racial increases
     pub ability_scores: Option<[i32; 6]>,
+    /// Abilities picked for racial increases that offer a choice
+    pub racial_ability_choices: Option<Vec<String>>,
     pub class_name: Option<String>,
     pub class_source: Option<String>,
     pub selected_skills: Option<Vec<String>>,
+    /// Personality traits, ideals, bonds, and flaws from the background tables
+    pub traits: Option<String>,
+    pub ideals: Option<String>,
+    pub bonds: Option<String>,
+    pub flaws: Option<String>,
 }
 
 /// Create a new player character.
@@ -152,6 +188,11 @@ pub fn create_pc(state: State<'_, AppState>, request: CreatePcRequest) -> ApiRes
         input = input.with_race(name, source);
     }
 
+    // Set subrace if both name and source provided
+    if let (Some(name), Some(source)) = (&request.subrace_name, &request.subrace_source) {
+        input = input.with_subrace(name, source);
+    }
+
     // Set background if both name and source provided
     if let (Some(name), Some(source)) = (&request.background_name, &request.background_source) {
         input = input.with_background(name, source);
@@ -162,6 +203,11 @@ pub fn create_pc(state: State<'_, AppState>, request: CreatePcRequest) -> ApiRes
         input = input.with_ability_scores(scores);
     }
 
+    // Set racial ability choices if provided
+    if let Some(choices) = request.racial_ability_choices {
+        input = input.with_racial_ability_choices(choices);
+    }
+
     // Set class if both name and source provided
     if let (Some(name), Some(source)) = (&request.class_name, &request.class_source) {
         input = input.with_class(name, source);
@@ -172,8 +218,13 @@ pub fn create_pc(state: State<'_, AppState>, request: CreatePcRequest) -> ApiRes
         input = input.with_skills(skills);
     }
 
+    input = input.with_roleplay(request.traits, request.ideals, request.bonds, request.flaws);
+
     match CharacterService::new(&mut db).create(input) {
         Ok(character) => {
+            let message = format!("Create character '{}'", character.name);
+            record_history(&state, &mut db, character.campaign_id.as_deref(), &message);
+
             // Enrich response with proficiencies
             match CharacterService::new(&mut db).enrich(character) {
                 Ok(response) => ApiResponse::ok(response),
@@ -212,7 +263,7 @@ pub fn create_npc(state: State<'_, AppState>, request: CreateNpcRequest) -> ApiR
     }
 
     // Create the NPC first
-    let character = match CharacterService::new(&mut db).create(input) {
+    let mut character = match CharacterService::new(&mut db).create(input) {
         Ok(c) => c,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
@@ -224,14 +275,14 @@ pub fn create_npc(state: State<'_, AppState>, request: CreateNpcRequest) -> ApiR
             request.location,
             request.faction,
         );
-        match CharacterService::new(&mut db).update(&character.id, update) {
-            Ok(updated) => {
-                return ApiResponse::ok(CharacterResponse::from_character(updated, vec![], vec![]))
-            }
+        character = match CharacterService::new(&mut db).update(&character.id, update) {
+            Ok(updated) => updated,
             Err(e) => return ApiResponse::err(e.to_string()),
-        }
+        };
     }
 
+    let message = format!("Create NPC '{}'", character.name);
+    record_history(&state, &mut db, character.campaign_id.as_deref(), &message);
     ApiResponse::ok(CharacterResponse::from_character(character, vec![], vec![]))
 }
 
@@ -246,6 +297,7 @@ pub struct UpdateCharacterRequest {
     pub background_source: Option<Option<String>>,
     pub ability_scores: Option<[i32; 6]>,
     pub currency: Option<[i32; 5]>,
+    pub currency_reason: Option<String>,
     pub traits: Option<Option<String>>,
     pub ideals: Option<Option<String>>,
     pub bonds: Option<Option<String>>,
@@ -276,6 +328,7 @@ pub fn update_character(
         background_source: request.background_source,
         ability_scores: request.ability_scores,
         currency: request.currency,
+        currency_reason: request.currency_reason,
         traits: request.traits,
         ideals: request.ideals,
         bonds: request.bonds,
@@ -285,17 +338,21 @@ pub fn update_character(
         faction: request.faction,
     };
 
-    let mut service = CharacterService::new(&mut db);
-    match service.update(&id, input) {
-        Ok(character) => match service.enrich(character) {
-            Ok(response) => ApiResponse::ok(response),
-            Err(e) => ApiResponse::err(e.to_string()),
-        },
+    let character = match CharacterService::new(&mut db).update(&id, input) {
+        Ok(character) => character,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let message = format!("Update character '{}'", character.name);
+    record_history(&state, &mut db, character.campaign_id.as_deref(), &message);
+
+    match CharacterService::new(&mut db).enrich(character) {
+        Ok(response) => ApiResponse::ok(response),
         Err(e) => ApiResponse::err(e.to_string()),
     }
 }
 
-/// Delete a character permanently.
+/// Delete a character by moving it to the trash.
 #[tauri::command]
 pub fn delete_character(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
     let mut db = match state.connect() {
@@ -303,7 +360,14 @@ pub fn delete_character(state: State<'_, AppState>, id: String) -> ApiResponse<(
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = CharacterService::new(&mut db).delete(&id);
+    let result =
+        TrashService::new(&mut db, &state.paths.app_dir).trash(TrashKind::Character, &id);
+    if result.is_ok() {
+        if let Ok(character) = dal::get_character(&mut db, &id) {
+            let message = format!("Delete character '{}'", character.name);
+            record_history(&state, &mut db, character.campaign_id.as_deref(), &message);
+        }
+    }
     to_api_response(result)
 }
 
@@ -344,6 +408,82 @@ pub fn level_up_character(
     };
 
     let result = CharacterService::new(&mut db).level_up(&character_id, request);
+    if let Ok(ref level_up) = result {
+        let message = format!(
+            "Level up '{}' to level {}",
+            level_up.character.name, level_up.new_total_level
+        );
+        record_history(&state, &mut db, level_up.character.campaign_id.as_deref(), &message);
+    }
+    to_api_response(result)
+}
+
+/// Rebuild a character at its current level with different class, subclass, and feat choices.
+///
+/// The rebuild is recorded in campaign history with the respec reason.
+#[tauri::command]
+pub fn respec_character(
+    state: State<'_, AppState>,
+    character_id: String,
+    request: RespecRequest,
+) -> ApiResponse<RespecResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CharacterService::new(&mut db).respec(&character_id, request);
+    if let Ok(ref respec) = result {
+        let message = match respec.reason.as_deref() {
+            Some(reason) => format!("Respec '{}': {}", respec.character.name, reason),
+            None => format!("Respec '{}'", respec.character.name),
+        };
+        record_history(&state, &mut db, respec.character.campaign_id.as_deref(), &message);
+    }
+    to_api_response(result)
+}
+
+/// Render a character as a monster-style stat block (5etools monster JSON).
+#[tauri::command]
+pub fn convert_character_to_statblock(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<serde_json::Value> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(StatblockService::new(&mut db).convert(&character_id))
+}
+
+/// Attach a character's stat block to a module as an encounter participant.
+///
+/// The stat block is saved as a campaign homebrew monster; attaching the same
+/// character again refreshes it.
+#[tauri::command]
+pub fn attach_character_statblock_to_module(
+    state: State<'_, AppState>,
+    character_id: String,
+    module_id: String,
+) -> ApiResponse<ModuleMonster> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = StatblockService::new(&mut db).attach_to_module(&character_id, &module_id);
+    if result.is_ok() {
+        if let Ok(Some(module)) = dal::get_module_optional(&mut db, &module_id) {
+            let name = dal::get_character_optional(&mut db, &character_id)
+                .ok()
+                .flatten()
+                .map(|c| c.name)
+                .unwrap_or_default();
+            let message = format!("Add '{}' stat block to module '{}'", name, module.name);
+            record_history(&state, &mut db, Some(&module.campaign_id), &message);
+        }
+    }
     to_api_response(result)
 }
 
@@ -608,6 +748,7 @@ pub fn add_character_spell(
     spell_source: String,
     source_class: String,
     prepared: Option<bool>,
+    scribed: Option<bool>,
 ) -> ApiResponse<CharacterSpell> {
     let mut db = match state.connect() {
         Ok(db) => db,
@@ -629,6 +770,9 @@ pub fn add_character_spell(
     if prepared.unwrap_or(false) {
         spell = spell.prepared();
     }
+    if scribed.unwrap_or(false) {
+        spell = spell.scribed();
+    }
 
     match dal::insert_character_spell(&mut db, &spell) {
         Ok(_) => ApiResponse::ok(CharacterSpell {
@@ -638,6 +782,7 @@ pub fn add_character_spell(
             spell_source,
             source_class,
             prepared: if prepared.unwrap_or(false) { 1 } else { 0 },
+            scribed: if scribed.unwrap_or(false) { 1 } else { 0 },
         }),
         Err(e) => ApiResponse::err(e.to_string()),
     }
@@ -720,3 +865,33 @@ pub fn toggle_spell_prepared(
         Err(e) => ApiResponse::err(e.to_string()),
     }
 }
+
+/// Toggle whether a spell was scribed into the character's spellbook.
+#[tauri::command]
+pub fn toggle_spell_scribed(
+    state: State<'_, AppState>,
+    spell_id: String,
+) -> ApiResponse<CharacterSpell> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    // Get current state
+    let spell = match dal::get_character_spell_optional(&mut db, &spell_id) {
+        Ok(Some(s)) => s,
+        Ok(None) => return ApiResponse::err(format!("Spell entry not found: {}", spell_id)),
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let new_scribed = !spell.is_scribed();
+    let update = UpdateCharacterSpell::set_scribed(new_scribed);
+
+    match dal::update_character_spell(&mut db, &spell_id, &update) {
+        Ok(_) => ApiResponse::ok(CharacterSpell {
+            scribed: if new_scribed { 1 } else { 0 },
+            ..spell
+        }),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
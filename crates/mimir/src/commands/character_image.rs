@@ -0,0 +1,165 @@
+//! Character Image Commands
+//!
+//! Tauri commands for character portraits and map tokens. Uploads arrive as
+//! base64; images are served back as data URLs.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::{Character, CharacterResponse};
+use mimir_core::services::{CharacterImageService, CharacterService, ServiceResult};
+use tauri::State;
+
+use super::history::record_history;
+use super::player_display::read_image_data_url;
+use super::ApiResponse;
+use crate::state::AppState;
+
+/// Set a character's portrait (PNG or JPEG) and generate its token from it.
+#[tauri::command]
+pub fn upload_character_portrait(
+    state: State<'_, AppState>,
+    character_id: String,
+    data_base64: String,
+) -> ApiResponse<CharacterResponse> {
+    let data = match BASE64.decode(&data_base64) {
+        Ok(data) => data,
+        Err(e) => return ApiResponse::err(format!("Invalid base64 data: {}", e)),
+    };
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CharacterImageService::new(&mut db, &state.paths.assets_dir)
+        .upload_portrait(&character_id, &data);
+    respond(&state, &mut db, result, "Set portrait of")
+}
+
+/// Set a character's token from its own image instead of the portrait.
+#[tauri::command]
+pub fn upload_character_token(
+    state: State<'_, AppState>,
+    character_id: String,
+    data_base64: String,
+) -> ApiResponse<CharacterResponse> {
+    let data = match BASE64.decode(&data_base64) {
+        Ok(data) => data,
+        Err(e) => return ApiResponse::err(format!("Invalid base64 data: {}", e)),
+    };
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CharacterImageService::new(&mut db, &state.paths.assets_dir)
+        .upload_token(&character_id, &data);
+    respond(&state, &mut db, result, "Set token of")
+}
+
+/// Generate a character's token again from its portrait.
+#[tauri::command]
+pub fn regenerate_character_token(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<CharacterResponse> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CharacterImageService::new(&mut db, &state.paths.assets_dir)
+        .regenerate_token(&character_id);
+    respond(&state, &mut db, result, "Regenerate token of")
+}
+
+/// Remove a character's portrait.
+#[tauri::command]
+pub fn remove_character_portrait(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<CharacterResponse> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result =
+        CharacterImageService::new(&mut db, &state.paths.assets_dir).remove_portrait(&character_id);
+    respond(&state, &mut db, result, "Remove portrait of")
+}
+
+/// Remove a character's token.
+#[tauri::command]
+pub fn remove_character_token(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<CharacterResponse> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result =
+        CharacterImageService::new(&mut db, &state.paths.assets_dir).remove_token(&character_id);
+    respond(&state, &mut db, result, "Remove token of")
+}
+
+/// Serve a character's portrait as a base64 data URL.
+#[tauri::command]
+pub fn serve_character_portrait(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Option<String>> {
+    serve_image(&state, &character_id, |c| c.portrait_path)
+}
+
+/// Serve a character's token as a base64 data URL.
+#[tauri::command]
+pub fn serve_character_token(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Option<String>> {
+    serve_image(&state, &character_id, |c| c.token_path)
+}
+
+/// Read one of a character's images as a data URL.
+fn serve_image(
+    state: &AppState,
+    character_id: &str,
+    path: impl FnOnce(Character) -> Option<String>,
+) -> ApiResponse<Option<String>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    match dal::get_character_optional(&mut db, character_id) {
+        Ok(Some(character)) => ApiResponse::ok(
+            path(character).and_then(|p| read_image_data_url(&state.paths.assets_dir, &p)),
+        ),
+        Ok(None) => ApiResponse::err(format!("Character not found: {}", character_id)),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Record an image change in the campaign history and return the updated character.
+fn respond(
+    state: &AppState,
+    db: &mut SqliteConnection,
+    result: ServiceResult<Character>,
+    action: &str,
+) -> ApiResponse<CharacterResponse> {
+    let character = match result {
+        Ok(character) => character,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let message = format!("{} '{}'", action, character.name);
+    record_history(state, db, character.campaign_id.as_deref(), &message);
+
+    match CharacterService::new(db).enrich(character) {
+        Ok(response) => ApiResponse::ok(response),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
@@ -0,0 +1,71 @@
+//! Class Resource Commands
+//!
+//! Tauri commands for limited-use class features (Rage, Ki, Channel Divinity):
+//! spending and restoring uses, and taking rests.
+
+use mimir_core::models::campaign::{CharacterResource, RestType};
+use mimir_core::services::{ClassResourceService, RestResult};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a character's class resources, derived from their class levels.
+#[tauri::command]
+pub fn list_character_resources(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<CharacterResource>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ClassResourceService::new(&mut db).list_resources(&character_id))
+}
+
+/// Spend uses of a class resource (one by default).
+#[tauri::command]
+pub fn use_resource(
+    state: State<'_, AppState>,
+    id: String,
+    amount: Option<i32>,
+) -> ApiResponse<CharacterResource> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ClassResourceService::new(&mut db).use_resource(&id, amount.unwrap_or(1)))
+}
+
+/// Restore uses of a class resource outside of a rest. Without an amount
+/// every use is restored.
+#[tauri::command]
+pub fn restore_resource(
+    state: State<'_, AppState>,
+    id: String,
+    amount: Option<i32>,
+) -> ApiResponse<CharacterResource> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ClassResourceService::new(&mut db).restore_resource(&id, amount))
+}
+
+/// Take a short or long rest, restoring the resources it recharges.
+#[tauri::command]
+pub fn take_rest(
+    state: State<'_, AppState>,
+    character_id: String,
+    rest_type: RestType,
+) -> ApiResponse<RestResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ClassResourceService::new(&mut db).take_rest(&character_id, rest_type))
+}
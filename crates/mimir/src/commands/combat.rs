@@ -0,0 +1,60 @@
+//! Combat Commands
+//!
+//! Tauri commands for one-click combat actions: reading attack bonuses,
+//! damage, saves, and conditions out of monster and spell data, and rolling
+//! them for the combat log.
+
+use mimir_core::services::{
+    monster_action_hints, roll_action_hint as roll, spell_action_hint, ActionHint,
+    CatalogEntityService, HintRoll, SpellCaster, SpellService,
+};
+use serde_json::Value;
+use tauri::State;
+
+use super::ApiResponse;
+use crate::state::AppState;
+
+/// Read the rollable traits and actions out of a monster's 5etools data.
+#[tauri::command]
+pub fn get_monster_action_hints(monster_data: Value) -> ApiResponse<Vec<ActionHint>> {
+    ApiResponse::ok(monster_action_hints(&monster_data))
+}
+
+/// Read what a catalog spell does, using the caster's save DC and spell
+/// attack bonus.
+#[tauri::command]
+pub fn get_spell_action_hint(
+    state: State<'_, AppState>,
+    name: String,
+    source: String,
+    save_dc: Option<i32>,
+    attack_bonus: Option<i32>,
+) -> ApiResponse<ActionHint> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let spell = match SpellService::new(&mut db).get_by_name_and_source(&name, &source) {
+        Ok(Some(spell)) => spell,
+        Ok(None) => return ApiResponse::err(format!("Spell not found: {} ({})", name, source)),
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let data: Value = match serde_json::from_str(&spell.data) {
+        Ok(data) => data,
+        Err(e) => return ApiResponse::err(format!("Invalid spell data: {}", e)),
+    };
+    let caster = SpellCaster {
+        save_dc,
+        attack_bonus,
+    };
+    ApiResponse::ok(spell_action_hint(&data, caster))
+}
+
+/// Roll an action hint's attack and damage.
+#[tauri::command]
+pub fn roll_action_hint(hint: ActionHint) -> ApiResponse<HintRoll> {
+    let result = roll(&hint);
+    tracing::info!(summary = %result.summary, "Rolled action");
+    ApiResponse::ok(result)
+}
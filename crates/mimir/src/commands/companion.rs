@@ -0,0 +1,129 @@
+//! Companion Commands
+//!
+//! Tauri commands for companion creatures linked to characters: beast
+//! companions, familiars, and sidekicks.
+
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::CharacterCompanion;
+use mimir_core::services::{AddCompanionInput, CompanionService, UpdateCompanionInput};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a character's companions.
+#[tauri::command]
+pub fn list_character_companions(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<CharacterCompanion>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CompanionService::new(&mut db).list(&character_id))
+}
+
+/// Link a companion to a character.
+#[tauri::command]
+pub fn add_companion(
+    state: State<'_, AppState>,
+    character_id: String,
+    input: AddCompanionInput,
+) -> ApiResponse<CharacterCompanion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CompanionService::new(&mut db).add(&character_id, input);
+    if let Ok(ref companion) = result {
+        if let Ok(Some(owner)) = dal::get_character_optional(&mut db, &character_id) {
+            let message = format!("Add companion '{}' to '{}'", companion.name, owner.name);
+            record_history(&state, &mut db, owner.campaign_id.as_deref(), &message);
+        }
+    }
+    to_api_response(result)
+}
+
+/// Update a companion's name, hit point maximum, or notes.
+#[tauri::command]
+pub fn update_companion(
+    state: State<'_, AppState>,
+    id: String,
+    input: UpdateCompanionInput,
+) -> ApiResponse<CharacterCompanion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CompanionService::new(&mut db).update(&id, input))
+}
+
+/// Apply damage (negative) or healing (positive) to a companion.
+#[tauri::command]
+pub fn adjust_companion_hp(
+    state: State<'_, AppState>,
+    id: String,
+    delta: i32,
+) -> ApiResponse<CharacterCompanion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CompanionService::new(&mut db).adjust_hp(&id, delta))
+}
+
+/// Give a sidekick one level.
+#[tauri::command]
+pub fn level_up_companion(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<CharacterCompanion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CompanionService::new(&mut db).level_up(&id))
+}
+
+/// Remove a companion from its owner.
+#[tauri::command]
+pub fn remove_companion(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let companion = CompanionService::new(&mut db).get(&id).ok();
+    let result = CompanionService::new(&mut db).remove(&id);
+    if let (Ok(()), Some(companion)) = (&result, companion) {
+        if let Ok(Some(owner)) = dal::get_character_optional(&mut db, &companion.character_id) {
+            let message = format!(
+                "Remove companion '{}' from '{}'",
+                companion.name, owner.name
+            );
+            record_history(&state, &mut db, owner.campaign_id.as_deref(), &message);
+        }
+    }
+    to_api_response(result)
+}
+
+/// Get a companion's stat block (5etools monster JSON) for display or cards.
+#[tauri::command]
+pub fn get_companion_statblock(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<serde_json::Value> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CompanionService::new(&mut db).statblock(&id))
+}
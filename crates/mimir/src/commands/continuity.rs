@@ -0,0 +1,92 @@
+//! Module Continuity Commands
+//!
+//! Tauri commands for linking modules (follows-from, foreshadows) and for
+//! the continuity checklist shown before a module is wrapped up.
+
+use mimir_core::models::campaign::{ModuleLink, ModuleLinkType};
+use mimir_core::services::{
+    ContinuityService, CreateModuleLinkInput, ModuleCompletion, UpdateModuleLinkInput,
+};
+use tauri::State;
+
+use crate::commands::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a campaign's module links.
+#[tauri::command]
+pub fn list_module_links(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<ModuleLink>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ContinuityService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// Link one module to another.
+#[tauri::command]
+pub fn link_modules(
+    state: State<'_, AppState>,
+    from_module_id: String,
+    to_module_id: String,
+    link_type: ModuleLinkType,
+    note: Option<String>,
+) -> ApiResponse<ModuleLink> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut input = CreateModuleLinkInput::new(from_module_id, to_module_id, link_type);
+    input.note = note;
+    to_api_response(ContinuityService::new(&mut db).link(input))
+}
+
+/// Change a link's note or mark its hook resolved. An empty note clears it.
+#[tauri::command]
+pub fn update_module_link(
+    state: State<'_, AppState>,
+    link_id: String,
+    note: Option<String>,
+    resolved: Option<bool>,
+) -> ApiResponse<ModuleLink> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = UpdateModuleLinkInput {
+        note: note.map(|n| Some(n).filter(|n| !n.trim().is_empty())),
+        resolved,
+    };
+    to_api_response(ContinuityService::new(&mut db).update_link(&link_id, input))
+}
+
+/// Remove a module link.
+#[tauri::command]
+pub fn delete_module_link(state: State<'_, AppState>, link_id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ContinuityService::new(&mut db).unlink(&link_id))
+}
+
+/// Build a module's continuity checklist: open hooks, unused NPCs, and the
+/// items handed out while it was played.
+#[tauri::command]
+pub fn check_module_completion(
+    state: State<'_, AppState>,
+    module_id: String,
+) -> ApiResponse<ModuleCompletion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ContinuityService::new(&mut db).check_module_completion(&module_id))
+}
@@ -0,0 +1,125 @@
+//! Crafting Commands
+//!
+//! Tauri commands for downtime crafting: recipes from the catalog, crafting
+//! projects, and logging downtime days until the item is finished.
+
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::CraftingProject;
+use mimir_core::services::{
+    AdvanceCraftingInput, CraftingProgress, CraftingRecipe, CraftingService, StartCraftingInput,
+};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a character's crafting projects.
+#[tauri::command]
+pub fn list_crafting_projects(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<CraftingProject>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CraftingService::new(&mut db).list_for_character(&character_id))
+}
+
+/// Work out the gold, time, tool, and ingredient needed to craft an item.
+#[tauri::command]
+pub fn get_crafting_recipe(
+    state: State<'_, AppState>,
+    character_id: String,
+    item_name: String,
+    item_source: String,
+    quantity: Option<i32>,
+) -> ApiResponse<CraftingRecipe> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CraftingService::new(&mut db).recipe(
+        &character_id,
+        &item_name,
+        &item_source,
+        quantity,
+    ))
+}
+
+/// Start crafting an item, paying for materials from the character's purse.
+#[tauri::command]
+pub fn start_crafting_project(
+    state: State<'_, AppState>,
+    input: StartCraftingInput,
+) -> ApiResponse<CraftingProject> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CraftingService::new(&mut db).start(input);
+    if let Ok(ref project) = result {
+        record_crafting_history(&state, &mut db, project, "Start crafting");
+    }
+    to_api_response(result)
+}
+
+/// Log downtime days against a crafting project, finishing it when done.
+#[tauri::command]
+pub fn advance_crafting(
+    state: State<'_, AppState>,
+    id: String,
+    input: AdvanceCraftingInput,
+) -> ApiResponse<CraftingProgress> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CraftingService::new(&mut db).advance(&id, input);
+    if let Ok(ref progress) = result {
+        let action = if progress.item.is_some() {
+            "Finish crafting"
+        } else {
+            "Craft"
+        };
+        record_crafting_history(&state, &mut db, &progress.project, action);
+    }
+    to_api_response(result)
+}
+
+/// Abandon a crafting project.
+#[tauri::command]
+pub fn abandon_crafting_project(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<CraftingProject> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CraftingService::new(&mut db).abandon(&id);
+    if let Ok(ref project) = result {
+        record_crafting_history(&state, &mut db, project, "Abandon crafting");
+    }
+    to_api_response(result)
+}
+
+/// Record a crafting change in the crafter's campaign history.
+fn record_crafting_history(
+    state: &AppState,
+    db: &mut SqliteConnection,
+    project: &CraftingProject,
+    action: &str,
+) {
+    if let Ok(Some(crafter)) = dal::get_character_optional(db, &project.character_id) {
+        let message = format!("{} '{}' for '{}'", action, project.item_name, crafter.name);
+        record_history(state, db, crafter.campaign_id.as_deref(), &message);
+    }
+}
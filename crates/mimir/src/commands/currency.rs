@@ -0,0 +1,99 @@
+//! Currency Commands
+//!
+//! Tauri commands for characters' coins: adjustments with a reason, paying
+//! costs with change, consolidating to gold, and the transaction history.
+
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::CurrencyTransaction;
+use mimir_core::services::{CurrencyAdjustment, CurrencyResult, CurrencyService};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a character's currency transactions, newest first.
+#[tauri::command]
+pub fn get_currency_history(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<CurrencyTransaction>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CurrencyService::new(&mut db).history(&character_id))
+}
+
+/// Add or spend coins, recording the reason.
+#[tauri::command]
+pub fn adjust_character_currency(
+    state: State<'_, AppState>,
+    character_id: String,
+    input: CurrencyAdjustment,
+) -> ApiResponse<CurrencyResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CurrencyService::new(&mut db).adjust(&character_id, input);
+    record_currency_history(&state, &mut db, &character_id, &result);
+    to_api_response(result)
+}
+
+/// Pay a cost given in copper, making change from the character's coins.
+#[tauri::command]
+pub fn spend_character_currency(
+    state: State<'_, AppState>,
+    character_id: String,
+    cost_cp: i64,
+    reason: String,
+) -> ApiResponse<CurrencyResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CurrencyService::new(&mut db).spend(&character_id, cost_cp, &reason);
+    record_currency_history(&state, &mut db, &character_id, &result);
+    to_api_response(result)
+}
+
+/// Convert a character's coins to gold.
+#[tauri::command]
+pub fn consolidate_character_currency(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<CurrencyResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = CurrencyService::new(&mut db).consolidate(&character_id);
+    record_currency_history(&state, &mut db, &character_id, &result);
+    to_api_response(result)
+}
+
+/// Record a currency change in the character's campaign history.
+fn record_currency_history<E>(
+    state: &AppState,
+    db: &mut SqliteConnection,
+    character_id: &str,
+    result: &Result<CurrencyResult, E>,
+) {
+    let Ok(CurrencyResult {
+        transaction: Some(transaction),
+        ..
+    }) = result
+    else {
+        return;
+    };
+    if let Ok(Some(character)) = dal::get_character_optional(db, character_id) {
+        let message = format!("{}: {}", character.name, transaction.reason);
+        record_history(state, db, character.campaign_id.as_deref(), &message);
+    }
+}
@@ -0,0 +1,169 @@
+//! Custom Field Commands
+//!
+//! Tauri commands for custom character sheet fields: the per-campaign field
+//! definitions and each character's values for them.
+
+use mimir_core::models::campaign::CampaignCustomField;
+use mimir_core::services::{
+    CreateCustomFieldInput, CustomFieldService, CustomFieldValue, UpdateCustomFieldInput,
+};
+use serde::Deserialize;
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Map an optional field from a request: an empty string clears it.
+fn clearable(value: Option<String>) -> Option<Option<String>> {
+    value.map(|v| if v.is_empty() { None } else { Some(v) })
+}
+
+// =============================================================================
+// Field Definition Commands
+// =============================================================================
+
+/// List a campaign's custom fields in sheet order.
+#[tauri::command]
+pub fn list_custom_fields(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<CampaignCustomField>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).list_fields(&campaign_id))
+}
+
+/// Define a custom field for a campaign.
+#[tauri::command]
+pub fn create_custom_field(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: CreateCustomFieldInput,
+) -> ApiResponse<CampaignCustomField> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).create_field(&campaign_id, input))
+}
+
+/// Allowed range for a number field; a null bound removes it.
+#[derive(Debug, Deserialize)]
+pub struct CustomFieldRange {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+}
+
+/// Request for updating a custom field.
+#[derive(Debug, Deserialize)]
+pub struct UpdateCustomFieldRequest {
+    pub label: Option<String>,
+    /// New default value; an empty string clears it
+    pub default_value: Option<String>,
+    /// New range, replacing both bounds
+    pub range: Option<CustomFieldRange>,
+    /// New description; an empty string clears it
+    pub description: Option<String>,
+}
+
+/// Update a custom field's label, default, range, or description.
+#[tauri::command]
+pub fn update_custom_field(
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdateCustomFieldRequest,
+) -> ApiResponse<CampaignCustomField> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = UpdateCustomFieldInput {
+        label: request.label,
+        default_value: clearable(request.default_value),
+        min_value: request.range.as_ref().map(|r| r.min),
+        max_value: request.range.as_ref().map(|r| r.max),
+        description: clearable(request.description),
+    };
+    to_api_response(CustomFieldService::new(&mut db).update_field(&id, input))
+}
+
+/// Reorder a campaign's custom fields.
+#[tauri::command]
+pub fn reorder_custom_fields(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    field_ids: Vec<String>,
+) -> ApiResponse<Vec<CampaignCustomField>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).reorder_fields(&campaign_id, &field_ids))
+}
+
+/// Delete a custom field and every character's value for it.
+#[tauri::command]
+pub fn delete_custom_field(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).delete_field(&id))
+}
+
+// =============================================================================
+// Character Value Commands
+// =============================================================================
+
+/// Get a character's custom field values, with defaults filled in.
+#[tauri::command]
+pub fn get_character_custom_fields(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<CustomFieldValue>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).character_values(&character_id))
+}
+
+/// Set a character's value for a custom field. An empty value resets it to
+/// the field default.
+#[tauri::command]
+pub fn set_character_custom_field(
+    state: State<'_, AppState>,
+    character_id: String,
+    field_key: String,
+    value: String,
+) -> ApiResponse<Vec<CustomFieldValue>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).set_value(&character_id, &field_key, &value))
+}
+
+/// Reset a character's value for a custom field to the field default.
+#[tauri::command]
+pub fn clear_character_custom_field(
+    state: State<'_, AppState>,
+    character_id: String,
+    field_key: String,
+) -> ApiResponse<Vec<CustomFieldValue>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CustomFieldService::new(&mut db).clear_value(&character_id, &field_key))
+}
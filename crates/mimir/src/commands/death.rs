@@ -0,0 +1,93 @@
+//! Death Commands
+//!
+//! Tauri commands for character death and resurrection, and the campaign
+//! memorial of fallen characters.
+
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::CharacterDeath;
+use mimir_core::services::{DeathService, MarkDeadInput, MemorialEntry, ResurrectInput};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a character's deaths, oldest first.
+#[tauri::command]
+pub fn list_character_deaths(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<CharacterDeath>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(DeathService::new(&mut db).history(&character_id))
+}
+
+/// List a campaign's dead characters.
+#[tauri::command]
+pub fn get_campaign_memorial(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<MemorialEntry>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(DeathService::new(&mut db).memorial(&campaign_id))
+}
+
+/// Mark a character dead.
+#[tauri::command]
+pub fn mark_character_dead(
+    state: State<'_, AppState>,
+    character_id: String,
+    input: MarkDeadInput,
+) -> ApiResponse<CharacterDeath> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = DeathService::new(&mut db).mark_dead(&character_id, input);
+    if result.is_ok() {
+        record_death_history(&state, &mut db, &character_id, "Mark dead");
+    }
+    to_api_response(result)
+}
+
+/// Bring a dead character back to life.
+#[tauri::command]
+pub fn resurrect_character(
+    state: State<'_, AppState>,
+    character_id: String,
+    input: ResurrectInput,
+) -> ApiResponse<CharacterDeath> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = DeathService::new(&mut db).resurrect(&character_id, input);
+    if result.is_ok() {
+        record_death_history(&state, &mut db, &character_id, "Resurrect");
+    }
+    to_api_response(result)
+}
+
+/// Record a death or resurrection in the character's campaign history.
+fn record_death_history(
+    state: &AppState,
+    db: &mut SqliteConnection,
+    character_id: &str,
+    action: &str,
+) {
+    if let Ok(Some(character)) = dal::get_character_optional(db, character_id) {
+        let message = format!("{} '{}'", action, character.name);
+        record_history(state, db, character.campaign_id.as_deref(), &message);
+    }
+}
@@ -0,0 +1,210 @@
+//! Player Display Profile Commands
+//!
+//! Named layout profiles for the player display windows (monitor selection,
+//! rotation, zoom lock, letterboxing) and their assignment to the primary and
+//! secondary display screens.
+//!
+//! Profiles are per-user settings rather than campaign data, so they are kept
+//! in the app settings (`AppSettings.display`) instead of the database.
+
+use mimir_core::services::{
+    AppSettings, DisplayProfile, DisplaySettings, ServiceError, ServiceResult,
+};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::player_display::DisplayScreen;
+use super::settings::settings_changed;
+use super::{to_api_response, ApiResponse};
+use crate::error::CommandResult;
+use crate::state::AppState;
+
+/// A monitor available for the player display.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+/// List the monitors connected to this machine.
+#[tauri::command]
+pub fn list_display_monitors(app: AppHandle) -> ApiResponse<Vec<MonitorInfo>> {
+    let monitors = match app.available_monitors() {
+        Ok(m) => m,
+        Err(e) => return ApiResponse::err(format!("Failed to list monitors: {}", e)),
+    };
+    let primary_name = app
+        .primary_monitor()
+        .ok()
+        .flatten()
+        .and_then(|m| m.name().cloned());
+
+    let infos = monitors
+        .iter()
+        .map(|m| MonitorInfo {
+            name: m.name().cloned(),
+            width: m.size().width,
+            height: m.size().height,
+            x: m.position().x,
+            y: m.position().y,
+            scale_factor: m.scale_factor(),
+            is_primary: primary_name.is_some() && m.name().cloned() == primary_name,
+        })
+        .collect();
+
+    ApiResponse::ok(infos)
+}
+
+/// List all saved display profiles and screen assignments.
+#[tauri::command]
+pub fn list_display_profiles(state: State<'_, AppState>) -> ApiResponse<DisplaySettings> {
+    to_api_response(state.settings().load().map(|settings| settings.display))
+}
+
+/// Create or replace a display profile.
+#[tauri::command]
+pub fn save_display_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    profile: DisplayProfile,
+) -> ApiResponse<DisplayProfile> {
+    let result = update_display(&app, &state, |display| {
+        display.upsert_profile(profile.clone())
+    });
+    to_api_response(result.map(|_| profile))
+}
+
+/// Delete a display profile by name.
+#[tauri::command]
+pub fn delete_display_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> ApiResponse<()> {
+    let result = update_display(&app, &state, |display| {
+        if display.remove_profile(&name) {
+            Ok(())
+        } else {
+            Err(ServiceError::not_found("Display profile", &name))
+        }
+    });
+    to_api_response(result.map(|_| ()))
+}
+
+/// Assign a profile to a display screen ("primary" or "secondary").
+///
+/// Passing no profile name clears the assignment. If the screen's window is
+/// open, the new profile is applied immediately.
+#[tauri::command]
+pub fn assign_display_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    screen: String,
+    profile_name: Option<String>,
+) -> ApiResponse<Option<DisplayProfile>> {
+    to_api_response(assign(&app, &state, &screen, profile_name.as_deref()))
+}
+
+fn assign(
+    app: &AppHandle,
+    state: &AppState,
+    screen: &str,
+    profile_name: Option<&str>,
+) -> CommandResult<Option<DisplayProfile>> {
+    let screen = DisplayScreen::parse(screen)?;
+    let settings = update_display(app, state, |display| {
+        display.assign_profile(screen.as_str(), profile_name)
+    })?;
+
+    let profile = settings.display.assigned_profile(screen.as_str()).cloned();
+    if let Some(ref profile) = profile {
+        apply_display_profile(app, screen, profile)?;
+    }
+    Ok(profile)
+}
+
+/// Get the profile assigned to a display screen.
+///
+/// Called by the player display window on load to pick up its layout settings.
+#[tauri::command]
+pub fn get_assigned_display_profile(
+    state: State<'_, AppState>,
+    screen: String,
+) -> ApiResponse<Option<DisplayProfile>> {
+    let screen = match DisplayScreen::parse(&screen) {
+        Ok(s) => s,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        state
+            .settings()
+            .load()
+            .map(|settings| settings.display.assigned_profile(screen.as_str()).cloned()),
+    )
+}
+
+/// Change the display settings and save them, telling open windows.
+fn update_display(
+    app: &AppHandle,
+    state: &AppState,
+    change: impl FnOnce(&mut DisplaySettings) -> ServiceResult<()>,
+) -> ServiceResult<AppSettings> {
+    let service = state.settings();
+    let mut settings = service.load()?;
+    change(&mut settings.display)?;
+    settings_changed(app, state, service.update(settings))
+}
+
+/// Apply a profile to an open display window.
+///
+/// Moves the window onto the profile's monitor, sets fullscreen, and emits
+/// the profile so the frontend can apply rotation, zoom lock and letterboxing.
+/// Does nothing if the window is not open.
+pub(crate) fn apply_display_profile(
+    app: &AppHandle,
+    screen: DisplayScreen,
+    profile: &DisplayProfile,
+) -> Result<(), String> {
+    let Some(window) = app.get_webview_window(screen.label()) else {
+        return Ok(());
+    };
+
+    if let Some(ref monitor_name) = profile.monitor_name {
+        let monitors = app
+            .available_monitors()
+            .map_err(|e| format!("Failed to list monitors: {}", e))?;
+        match monitors.iter().find(|m| m.name() == Some(monitor_name)) {
+            Some(monitor) => {
+                // Leave fullscreen before moving, otherwise some platforms ignore the move
+                window.set_fullscreen(false).ok();
+                window
+                    .set_position(*monitor.position())
+                    .map_err(|e| format!("Failed to move display window: {}", e))?;
+            }
+            None => {
+                tracing::warn!(
+                    "Monitor '{}' for display profile '{}' not connected; using current monitor",
+                    monitor_name,
+                    profile.name
+                );
+            }
+        }
+    }
+
+    window
+        .set_fullscreen(profile.fullscreen)
+        .map_err(|e| format!("Failed to set fullscreen: {}", e))?;
+
+    window
+        .emit(&screen.event("profile"), profile)
+        .map_err(|e| format!("Failed to emit display profile: {}", e))?;
+
+    Ok(())
+}
@@ -4,10 +4,22 @@
 //! This is a separate window from the main app that shows the battle map
 //! while the main window stays on the module dashboard.
 
-use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
 
 const DM_MAP_LABEL: &str = "dm-map";
 
+/// Event asking an open DM map window to show a map region.
+const FOCUS_REGION_EVENT: &str = "dm-map:focus-region";
+
+/// Payload of [`FOCUS_REGION_EVENT`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FocusRegionPayload {
+    map_id: String,
+    region_id: String,
+}
+
 /// Check if the DM map window is currently open.
 #[tauri::command]
 pub fn is_dm_map_open(app: AppHandle) -> bool {
@@ -33,9 +45,46 @@ pub fn open_dm_map_window(
 
     // Build URL with query parameters
     let url = format!("/dm-map?moduleId={}&campaignId={}", module_id, campaign_id);
+    build_dm_map_window(&app, url)
+}
+
+/// Show a map region in the DM map window, opening the window if needed.
+///
+/// An open window is told to switch maps and frame the region; a new window
+/// receives the map and region in its query parameters. `module_id` is the
+/// module whose maps the window lists when it has to be opened.
+#[tauri::command]
+pub fn focus_dm_map_region(
+    app: AppHandle,
+    campaign_id: String,
+    module_id: Option<String>,
+    map_id: String,
+    region_id: String,
+) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(DM_MAP_LABEL) {
+        app.emit_to(
+            DM_MAP_LABEL,
+            FOCUS_REGION_EVENT,
+            FocusRegionPayload { map_id, region_id },
+        )
+        .map_err(|e| format!("Failed to focus map region: {}", e))?;
+        window.set_focus().ok();
+        return Ok(());
+    }
+
+    let url = format!(
+        "/dm-map?moduleId={}&campaignId={}&mapId={}&regionId={}",
+        module_id.unwrap_or_default(),
+        campaign_id,
+        map_id,
+        region_id
+    );
+    build_dm_map_window(&app, url)
+}
 
-    // Create the window
-    WebviewWindowBuilder::new(&app, DM_MAP_LABEL, WebviewUrl::App(url.into()))
+/// Create the DM map window at the given app URL.
+fn build_dm_map_window(app: &AppHandle, url: String) -> Result<(), String> {
+    WebviewWindowBuilder::new(app, DM_MAP_LABEL, WebviewUrl::App(url.into()))
         .title("DM Map")
         .inner_size(1280.0, 900.0)
         .resizable(true)
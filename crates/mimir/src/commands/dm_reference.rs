@@ -0,0 +1,335 @@
+//! DM Reference Window Commands
+//!
+//! Tauri commands for the DM reference window, a sibling of the DM map window
+//! that holds pinned content panels (monster stat blocks, document sections,
+//! NPC cards, rules excerpts) for running the rules side of the table.
+//! Pins are stored per campaign so the screen is rebuilt on the next session.
+
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::{
+    DmReferencePin, NewDmReferencePin, PanelType, UpdateDmReferencePin,
+};
+use mimir_core::utils::now_rfc3339;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
+use uuid::Uuid;
+
+use crate::commands::ApiResponse;
+use crate::state::AppState;
+
+const DM_REFERENCE_LABEL: &str = "dm-reference";
+
+/// Payload emitted to the DM reference window when its pins change.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinsUpdatedPayload {
+    pub campaign_id: String,
+}
+
+/// Notify the DM reference window (if open) that a campaign's pins changed.
+fn notify_pins_updated(app: &AppHandle, campaign_id: &str) {
+    if let Some(window) = app.get_webview_window(DM_REFERENCE_LABEL) {
+        let payload = PinsUpdatedPayload {
+            campaign_id: campaign_id.to_string(),
+        };
+        if let Err(e) = window.emit("dm-reference:pins-updated", payload) {
+            tracing::warn!("Failed to emit pins update: {}", e);
+        }
+    }
+}
+
+// =============================================================================
+// Window Commands
+// =============================================================================
+
+/// Check if the DM reference window is currently open.
+#[tauri::command]
+pub fn is_dm_reference_open(app: AppHandle) -> bool {
+    app.get_webview_window(DM_REFERENCE_LABEL).is_some()
+}
+
+/// Open the DM reference window for a campaign.
+///
+/// The window loads with a campaignId query parameter so it can fetch the
+/// campaign's pinned panels.
+#[tauri::command]
+pub fn open_dm_reference_window(app: AppHandle, campaign_id: String) -> Result<(), String> {
+    // Check if window already exists
+    if let Some(window) = app.get_webview_window(DM_REFERENCE_LABEL) {
+        // Focus existing window
+        window.set_focus().ok();
+        return Ok(());
+    }
+
+    let url = format!("/dm-reference?campaignId={}", campaign_id);
+
+    WebviewWindowBuilder::new(&app, DM_REFERENCE_LABEL, WebviewUrl::App(url.into()))
+        .title("DM Reference")
+        .inner_size(900.0, 900.0)
+        .resizable(true)
+        .visible(true)
+        .build()
+        .map_err(|e| format!("Failed to create DM reference window: {}", e))?;
+
+    Ok(())
+}
+
+/// Close the DM reference window.
+#[tauri::command]
+pub fn close_dm_reference_window(app: AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(DM_REFERENCE_LABEL) {
+        window
+            .close()
+            .map_err(|e| format!("Failed to close DM reference window: {}", e))?;
+    }
+    Ok(())
+}
+
+// =============================================================================
+// Pin Commands
+// =============================================================================
+
+/// List all pinned panels for a campaign in display order.
+#[tauri::command]
+pub fn list_dm_reference_pins(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<DmReferencePin>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    match dal::list_dm_reference_pins(&mut db, &campaign_id) {
+        Ok(pins) => ApiResponse::ok(pins),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Request for pinning a panel to the DM reference window.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinDmReferenceRequest {
+    pub campaign_id: String,
+    /// One of: monster, document_section, npc, rules
+    pub panel_type: String,
+    pub title: String,
+    /// Document/character ID, or catalog entry name for monster and rules panels
+    pub ref_id: Option<String>,
+    /// Catalog source code for monster and rules panels
+    pub ref_source: Option<String>,
+    /// Document heading, or catalog kind for rules panels
+    pub section: Option<String>,
+    /// Free-form excerpt text
+    pub content: Option<String>,
+}
+
+impl PinDmReferenceRequest {
+    /// Check that the request carries what its panel type needs.
+    fn validate(&self) -> Result<PanelType, String> {
+        let panel_type = PanelType::from_str(&self.panel_type).ok_or_else(|| {
+            format!(
+                "Unknown panel type '{}'. Expected monster, document_section, npc, or rules",
+                self.panel_type
+            )
+        })?;
+
+        if self.title.trim().is_empty() {
+            return Err("Panel title cannot be empty".to_string());
+        }
+
+        match panel_type {
+            PanelType::Monster | PanelType::Npc | PanelType::DocumentSection
+                if self.ref_id.is_none() =>
+            {
+                Err(format!("A {} panel requires refId", panel_type.as_str()))
+            }
+            PanelType::Rules if self.ref_id.is_none() && self.content.is_none() => {
+                Err("A rules panel requires refId or content".to_string())
+            }
+            _ => Ok(panel_type),
+        }
+    }
+}
+
+/// Pin a content panel to the DM reference window.
+#[tauri::command]
+pub fn pin_dm_reference(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    request: PinDmReferenceRequest,
+) -> ApiResponse<DmReferencePin> {
+    let panel_type = match request.validate() {
+        Ok(t) => t,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    // Campaign-owned references must exist and belong to this campaign
+    match (panel_type, request.ref_id.as_deref()) {
+        (PanelType::DocumentSection, Some(doc_id)) => {
+            match dal::get_document_optional(&mut db, doc_id) {
+                Ok(Some(doc)) if doc.campaign_id == request.campaign_id => {}
+                Ok(_) => return ApiResponse::err(format!("Document not found: {}", doc_id)),
+                Err(e) => return ApiResponse::err(e.to_string()),
+            }
+        }
+        (PanelType::Npc, Some(character_id)) => {
+            match dal::get_character_optional(&mut db, character_id) {
+                Ok(Some(c)) if c.campaign_id.as_deref() == Some(request.campaign_id.as_str()) => {}
+                Ok(_) => return ApiResponse::err(format!("Character not found: {}", character_id)),
+                Err(e) => return ApiResponse::err(e.to_string()),
+            }
+        }
+        _ => {}
+    }
+
+    let sort_order = match dal::next_dm_reference_pin_sort_order(&mut db, &request.campaign_id) {
+        Ok(n) => n,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let id = Uuid::new_v4().to_string();
+    let mut pin = NewDmReferencePin::new(&id, &request.campaign_id, panel_type, &request.title)
+        .with_sort_order(sort_order);
+
+    if let Some(ref_id) = &request.ref_id {
+        pin = pin.with_ref(ref_id, request.ref_source.as_deref());
+    }
+    if let Some(section) = &request.section {
+        pin = pin.with_section(section);
+    }
+    if let Some(content) = &request.content {
+        pin = pin.with_content(content);
+    }
+
+    if let Err(e) = dal::insert_dm_reference_pin(&mut db, &pin) {
+        return ApiResponse::err(e.to_string());
+    }
+
+    match dal::get_dm_reference_pin(&mut db, &id) {
+        Ok(pin) => {
+            notify_pins_updated(&app, &pin.campaign_id);
+            ApiResponse::ok(pin)
+        }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Request for updating a pinned panel.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDmReferencePinRequest {
+    pub title: Option<String>,
+    pub section: Option<String>,
+    pub content: Option<String>,
+}
+
+/// Update a pinned panel's title, section, or excerpt text.
+#[tauri::command]
+pub fn update_dm_reference_pin(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdateDmReferencePinRequest,
+) -> ApiResponse<DmReferencePin> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let now = now_rfc3339();
+    let update = UpdateDmReferencePin {
+        title: request.title.as_deref(),
+        section: request.section.as_ref().map(|s| Some(s.as_str())),
+        content: request.content.as_ref().map(|s| Some(s.as_str())),
+        sort_order: None,
+        updated_at: Some(&now),
+    };
+
+    if let Err(e) = dal::update_dm_reference_pin(&mut db, &id, &update) {
+        return ApiResponse::err(e.to_string());
+    }
+
+    match dal::get_dm_reference_pin(&mut db, &id) {
+        Ok(pin) => {
+            notify_pins_updated(&app, &pin.campaign_id);
+            ApiResponse::ok(pin)
+        }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Reorder a campaign's pinned panels.
+///
+/// `pin_ids` lists the campaign's pins in their new order.
+#[tauri::command]
+pub fn reorder_dm_reference_pins(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    campaign_id: String,
+    pin_ids: Vec<String>,
+) -> ApiResponse<Vec<DmReferencePin>> {
+    use diesel::Connection;
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = db.transaction(|conn| {
+        let existing = dal::list_dm_reference_pins(conn, &campaign_id)?;
+        let now = now_rfc3339();
+
+        for (index, pin_id) in pin_ids.iter().enumerate() {
+            if !existing.iter().any(|p| &p.id == pin_id) {
+                return Err(diesel::result::Error::NotFound);
+            }
+            let update = UpdateDmReferencePin::set_sort_order(index as i32 + 1, &now);
+            dal::update_dm_reference_pin(conn, pin_id, &update)?;
+        }
+
+        dal::list_dm_reference_pins(conn, &campaign_id)
+    });
+
+    match result {
+        Ok(pins) => {
+            notify_pins_updated(&app, &campaign_id);
+            ApiResponse::ok(pins)
+        }
+        Err(diesel::result::Error::NotFound) => {
+            ApiResponse::err("Pin list contains a pin that is not in this campaign")
+        }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Remove a pinned panel.
+#[tauri::command]
+pub fn unpin_dm_reference(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let pin = match dal::get_dm_reference_pin(&mut db, &id) {
+        Ok(p) => p,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    match dal::delete_dm_reference_pin(&mut db, &id) {
+        Ok(_) => {
+            notify_pins_updated(&app, &pin.campaign_id);
+            ApiResponse::ok(())
+        }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
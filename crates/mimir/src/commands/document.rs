@@ -2,11 +2,17 @@
 //!
 //! Tauri commands for document management (campaign and module markdown content).
 
-use mimir_core::dal::campaign::DocumentSearchResult;
-use mimir_core::models::campaign::Document;
-use mimir_core::services::{CreateDocumentInput, DocumentService, UpdateDocumentInput};
+use mimir_core::dal::campaign::{self as dal, DocumentSearchResult};
+use mimir_core::models::campaign::{Document, TaggableType};
+use mimir_core::services::{
+    BulkDocumentMetadata, BulkResult, CreateDocumentInput, DocumentService, ExternalChangeStatus,
+    MonsterEmbedService, ResolvedMonsterEmbed, SaveOutcome, TrashKind, TrashService,
+    UpdateDocumentInput,
+};
 use tauri::State;
 
+use super::history::record_history;
+use super::tag::filter_by_tag;
 use super::{to_api_response, ApiResponse};
 use crate::state::AppState;
 
@@ -15,33 +21,45 @@ use crate::state::AppState;
 // =============================================================================
 
 /// List campaign-level documents (not in any module).
+///
+/// `tag_id` limits the list to documents carrying that tag.
 #[tauri::command]
 pub fn list_campaign_documents(
     state: State<'_, AppState>,
     campaign_id: String,
+    tag_id: Option<String>,
 ) -> ApiResponse<Vec<Document>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = DocumentService::new(&mut db).list_for_campaign(&campaign_id);
-    to_api_response(result)
+    let docs = match DocumentService::new(&mut db).list_for_campaign(&campaign_id) {
+        Ok(docs) => docs,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    to_api_response(filter_by_tag(&mut db, tag_id.as_deref(), TaggableType::Document, docs, |d| &d.id))
 }
 
 /// List all documents for a specific module.
+///
+/// `tag_id` limits the list to documents carrying that tag.
 #[tauri::command]
 pub fn list_module_documents(
     state: State<'_, AppState>,
     module_id: String,
+    tag_id: Option<String>,
 ) -> ApiResponse<Vec<Document>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = DocumentService::new(&mut db).list_for_module(&module_id);
-    to_api_response(result)
+    let docs = match DocumentService::new(&mut db).list_for_module(&module_id) {
+        Ok(docs) => docs,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    to_api_response(filter_by_tag(&mut db, tag_id.as_deref(), TaggableType::Document, docs, |d| &d.id))
 }
 
 // =============================================================================
@@ -100,6 +118,10 @@ pub fn create_document(
     }
 
     let result = DocumentService::new(&mut db).create(input);
+    if let Ok(ref document) = result {
+        let message = format!("Create document '{}'", document.title);
+        record_history(&state, &mut db, Some(&document.campaign_id), &message);
+    }
     to_api_response(result)
 }
 
@@ -130,10 +152,63 @@ pub fn update_document(
     };
 
     let result = DocumentService::new(&mut db).update(&id, input);
+    if let Ok(ref document) = result {
+        let message = format!("Update document '{}'", document.title);
+        record_history(&state, &mut db, Some(&document.campaign_id), &message);
+    }
+    to_api_response(result)
+}
+
+/// Check whether a document was changed elsewhere since the editor loaded it.
+///
+/// `base_updated_at` is the `updated_at` of the copy the editor holds.
+#[tauri::command]
+pub fn check_document_external_changes(
+    state: State<'_, AppState>,
+    id: String,
+    base_updated_at: String,
+) -> ApiResponse<ExternalChangeStatus> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = DocumentService::new(&mut db).check_external_changes(&id, &base_updated_at);
+    to_api_response(result)
+}
+
+/// Save a document, merging with changes made elsewhere since the editor
+/// loaded `base_content`.
+///
+/// Returns a conflict (and writes nothing) when both sides changed the same
+/// lines.
+#[tauri::command]
+pub fn save_document_merging(
+    state: State<'_, AppState>,
+    id: String,
+    base_content: String,
+    request: UpdateDocumentRequest,
+) -> ApiResponse<SaveOutcome> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = UpdateDocumentInput {
+        title: request.title,
+        content: request.content,
+        doc_type: request.doc_type,
+    };
+
+    let result = DocumentService::new(&mut db).save_merging(&id, &base_content, input);
+    if let Ok(SaveOutcome::Saved { ref document, .. }) = result {
+        let message = format!("Update document '{}'", document.title);
+        record_history(&state, &mut db, Some(&document.campaign_id), &message);
+    }
     to_api_response(result)
 }
 
-/// Delete a document permanently.
+/// Delete a document by moving it to the trash.
 #[tauri::command]
 pub fn delete_document(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
     let mut db = match state.connect() {
@@ -141,7 +216,14 @@ pub fn delete_document(state: State<'_, AppState>, id: String) -> ApiResponse<()
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = DocumentService::new(&mut db).delete(&id);
+    let result =
+        TrashService::new(&mut db, &state.paths.app_dir).trash(TrashKind::Document, &id);
+    if result.is_ok() {
+        if let Ok(document) = dal::get_document(&mut db, &id) {
+            let message = format!("Delete document '{}'", document.title);
+            record_history(&state, &mut db, Some(&document.campaign_id), &message);
+        }
+    }
     to_api_response(result)
 }
 
@@ -165,6 +247,79 @@ pub fn reorder_document(
     to_api_response(result)
 }
 
+// =============================================================================
+// Bulk Commands
+// =============================================================================
+
+/// Move documents into a module, or out to campaign level when `module_id` is None.
+///
+/// Runs in a single transaction: if any document fails, none are moved.
+#[tauri::command]
+pub fn move_documents_to_module(
+    state: State<'_, AppState>,
+    document_ids: Vec<String>,
+    module_id: Option<String>,
+) -> ApiResponse<BulkResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = DocumentService::new(&mut db).bulk_move(&document_ids, module_id.as_deref());
+    to_api_response(result)
+}
+
+/// Request for a bulk metadata update.
+#[derive(Debug, serde::Deserialize)]
+pub struct BulkUpdateDocumentMetadataRequest {
+    pub doc_type: Option<String>,
+    #[serde(default)]
+    pub add_tags: Vec<String>,
+    #[serde(default)]
+    pub remove_tags: Vec<String>,
+}
+
+/// Change the type and tags of several documents.
+///
+/// Runs in a single transaction: if any document fails, none are changed.
+#[tauri::command]
+pub fn bulk_update_document_metadata(
+    state: State<'_, AppState>,
+    document_ids: Vec<String>,
+    request: BulkUpdateDocumentMetadataRequest,
+) -> ApiResponse<BulkResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let metadata = BulkDocumentMetadata {
+        doc_type: request.doc_type,
+        add_tags: request.add_tags,
+        remove_tags: request.remove_tags,
+    };
+
+    let result = DocumentService::new(&mut db).bulk_update_metadata(&document_ids, &metadata);
+    to_api_response(result)
+}
+
+/// Move several documents to the trash.
+///
+/// Runs in a single transaction: if any document fails, none are trashed.
+#[tauri::command]
+pub fn bulk_delete_documents(
+    state: State<'_, AppState>,
+    document_ids: Vec<String>,
+) -> ApiResponse<BulkResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = DocumentService::new(&mut db).bulk_trash(&document_ids);
+    to_api_response(result)
+}
+
 // =============================================================================
 // Search Commands
 // =============================================================================
@@ -200,3 +355,27 @@ pub fn search_module_documents(
     let result = DocumentService::new(&mut db).search_in_module(&module_id, &query);
     to_api_response(result)
 }
+
+// =============================================================================
+// Embed Commands
+// =============================================================================
+
+/// Resolve a `{{monster: Name|SOURCE}}` embed for the hover card.
+///
+/// Monsters whose source is disabled for the campaign come back with a
+/// fallback status rather than an error.
+#[tauri::command]
+pub fn resolve_monster_embed(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    name: String,
+    source: Option<String>,
+) -> ApiResponse<ResolvedMonsterEmbed> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = MonsterEmbedService::new(&mut db).resolve(&campaign_id, &name, source.as_deref());
+    to_api_response(result)
+}
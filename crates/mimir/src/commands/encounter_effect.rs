@@ -0,0 +1,66 @@
+//! Encounter Effect Commands
+//!
+//! Tauri commands for the lair actions, hazards, and scheduled events in a
+//! module's encounter.
+
+use mimir_core::models::campaign::EncounterEffect;
+use mimir_core::services::{EncounterEffectInput, EncounterEffectService};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a module's encounter effects.
+#[tauri::command]
+pub fn list_encounter_effects(
+    state: State<'_, AppState>,
+    module_id: String,
+) -> ApiResponse<Vec<EncounterEffect>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(EncounterEffectService::new(&mut db).list(&module_id))
+}
+
+/// Add an effect to a module's encounter.
+#[tauri::command]
+pub fn add_encounter_effect(
+    state: State<'_, AppState>,
+    module_id: String,
+    input: EncounterEffectInput,
+) -> ApiResponse<EncounterEffect> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(EncounterEffectService::new(&mut db).add(&module_id, input))
+}
+
+/// Replace an encounter effect's name, description, and timing.
+#[tauri::command]
+pub fn update_encounter_effect(
+    state: State<'_, AppState>,
+    id: String,
+    input: EncounterEffectInput,
+) -> ApiResponse<EncounterEffect> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(EncounterEffectService::new(&mut db).update(&id, input))
+}
+
+/// Remove an effect from its module's encounter.
+#[tauri::command]
+pub fn remove_encounter_effect(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(EncounterEffectService::new(&mut db).remove(&id))
+}
@@ -0,0 +1,39 @@
+//! Database Encryption Commands
+//!
+//! Tauri commands for turning encryption at rest on and off for the Mimir
+//! database. The key lives in the OS keychain.
+
+use mimir_core::encryption::{self, EncryptionStatus};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Report whether the database is encrypted and whether its key is available.
+#[tauri::command]
+pub fn get_encryption_status(state: State<'_, AppState>) -> ApiResponse<EncryptionStatus> {
+    to_api_response(encryption::encryption_status(&state.paths.database_path))
+}
+
+/// Encrypt the database with a new key stored in the OS keychain.
+///
+/// The MCP server must not have the database open while this runs.
+#[tauri::command]
+pub fn enable_encryption(state: State<'_, AppState>) -> ApiResponse<EncryptionStatus> {
+    let db_path = &state.paths.database_path;
+    to_api_response(
+        encryption::enable_encryption(db_path).and_then(|_| encryption::encryption_status(db_path)),
+    )
+}
+
+/// Decrypt the database and remove its key from the OS keychain.
+///
+/// The MCP server must not have the database open while this runs.
+#[tauri::command]
+pub fn disable_encryption(state: State<'_, AppState>) -> ApiResponse<EncryptionStatus> {
+    let db_path = &state.paths.database_path;
+    to_api_response(
+        encryption::disable_encryption(db_path)
+            .and_then(|_| encryption::encryption_status(db_path)),
+    )
+}
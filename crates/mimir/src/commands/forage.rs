@@ -0,0 +1,122 @@
+//! Forage Commands
+//!
+//! Tauri commands for a campaign's gathering tables and for forage checks
+//! that add what's found to the shared stash.
+
+use mimir_core::models::campaign::ForageIngredient;
+use mimir_core::services::{
+    AddForageIngredientInput, ForageImportResult, ForageResult, ForageService,
+    UpdateForageIngredientInput,
+};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a campaign's forage ingredients, optionally for one environment.
+#[tauri::command]
+pub fn list_forage_ingredients(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    environment: Option<String>,
+) -> ApiResponse<Vec<ForageIngredient>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ForageService::new(&mut db).list(&campaign_id, environment.as_deref()))
+}
+
+/// Add a catalog or homebrew item to an environment's gathering table.
+#[tauri::command]
+pub fn add_forage_ingredient(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: AddForageIngredientInput,
+) -> ApiResponse<ForageIngredient> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ForageService::new(&mut db).add_ingredient(&campaign_id, input))
+}
+
+/// Change a forage ingredient's DC, quantity or weight.
+#[tauri::command]
+pub fn update_forage_ingredient(
+    state: State<'_, AppState>,
+    id: String,
+    input: UpdateForageIngredientInput,
+) -> ApiResponse<ForageIngredient> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ForageService::new(&mut db).update_ingredient(&id, input))
+}
+
+/// Remove an ingredient from its gathering table.
+#[tauri::command]
+pub fn remove_forage_ingredient(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ForageService::new(&mut db).remove_ingredient(&id))
+}
+
+/// Import the items in a catalog table into an environment's gathering table.
+#[tauri::command]
+pub fn import_forage_table(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    environment: String,
+    table_name: String,
+    table_source: String,
+    dc: Option<i32>,
+) -> ApiResponse<ForageImportResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ForageService::new(&mut db).import_catalog_table(
+        &campaign_id,
+        &environment,
+        &table_name,
+        &table_source,
+        dc,
+    ))
+}
+
+/// Make a forage check and add what's found to the stash.
+#[tauri::command]
+pub fn forage(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    environment: String,
+    modifier: i32,
+    roll: Option<i32>,
+) -> ApiResponse<ForageResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = ForageService::new(&mut db).forage(&campaign_id, &environment, modifier, roll);
+    if let Ok(ForageResult {
+        ingredient: Some(ref ingredient),
+        quantity,
+        ..
+    }) = result
+    {
+        let message = format!("Forage {} x {} to stash", quantity, ingredient.item_name);
+        record_history(&state, &mut db, Some(&campaign_id), &message);
+    }
+    to_api_response(result)
+}
@@ -0,0 +1,205 @@
+//! Handout Commands
+//!
+//! Tauri commands for the handout queue: images and documents staged during
+//! prep and revealed to the player display one at a time. Images are shown
+//! as handout cards and documents as read-aloud text.
+
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::Handout;
+use mimir_core::services::{HandoutInput, HandoutService, RevealedHandout};
+use tauri::{AppHandle, Emitter, State};
+
+use super::player_display::{
+    display_screen, read_aloud_paragraphs, read_image_data_url, DisplayScreen, HandoutPayload,
+    ReadAloudPayload,
+};
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Handout kind sent to the display for queued images.
+const QUEUED_HANDOUT_KIND: &str = "handout";
+
+/// List a campaign's handouts in queue order, revealed ones included.
+#[tauri::command]
+pub fn list_handouts(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<Handout>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(HandoutService::new(&mut db).list(&campaign_id))
+}
+
+/// Stage a handout at the end of a campaign's queue.
+#[tauri::command]
+pub fn create_handout(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: HandoutInput,
+) -> ApiResponse<Handout> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(HandoutService::new(&mut db).create(&campaign_id, input))
+}
+
+/// Delete a handout.
+#[tauri::command]
+pub fn delete_handout(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(HandoutService::new(&mut db).delete(&id))
+}
+
+/// Reorder a campaign's handout queue.
+#[tauri::command]
+pub fn reorder_handouts(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    handout_ids: Vec<String>,
+) -> ApiResponse<Vec<Handout>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(HandoutService::new(&mut db).reorder(&campaign_id, &handout_ids))
+}
+
+/// Put a revealed handout back in the queue.
+#[tauri::command]
+pub fn requeue_handout(state: State<'_, AppState>, id: String) -> ApiResponse<Handout> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(HandoutService::new(&mut db).requeue(&id))
+}
+
+/// List what players have been shown, optionally only in one session.
+#[tauri::command]
+pub fn list_handout_history(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    session_id: Option<String>,
+) -> ApiResponse<Vec<RevealedHandout>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(HandoutService::new(&mut db).history(&campaign_id, session_id.as_deref()))
+}
+
+/// Reveal the next handout in a campaign's queue on the player display.
+///
+/// Returns the handout shown, or `None` when the queue is empty.
+#[tauri::command]
+pub fn reveal_next_handout(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    campaign_id: String,
+    screen: Option<String>,
+) -> ApiResponse<Option<Handout>> {
+    let screen = match display_screen(&app, screen.as_deref()) {
+        Ok(screen) => screen,
+        Err(e) => return ApiResponse::err(e),
+    };
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let next = match HandoutService::new(&mut db).next_queued(&campaign_id) {
+        Ok(Some(next)) => next,
+        Ok(None) => return ApiResponse::ok(None),
+        Err(e) => return ApiResponse::err(e),
+    };
+    if let Err(e) = show_handout(&app, &state, &mut db, screen, &next) {
+        return ApiResponse::err(e);
+    }
+    to_api_response(
+        HandoutService::new(&mut db)
+            .record_reveal(&next.id)
+            .map(Some),
+    )
+}
+
+/// Reveal a particular handout on the player display, queued or not.
+#[tauri::command]
+pub fn reveal_handout(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    id: String,
+    screen: Option<String>,
+) -> ApiResponse<Handout> {
+    let screen = match display_screen(&app, screen.as_deref()) {
+        Ok(screen) => screen,
+        Err(e) => return ApiResponse::err(e),
+    };
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let handout = match HandoutService::new(&mut db).get(&id) {
+        Ok(handout) => handout,
+        Err(e) => return ApiResponse::err(e),
+    };
+    if let Err(e) = show_handout(&app, &state, &mut db, screen, &handout) {
+        return ApiResponse::err(e);
+    }
+    to_api_response(HandoutService::new(&mut db).record_reveal(&handout.id))
+}
+
+/// Send a handout to a display screen.
+///
+/// Nothing is recorded here, so a handout whose image or document is gone
+/// stays in the queue.
+fn show_handout(
+    app: &AppHandle,
+    state: &AppState,
+    db: &mut SqliteConnection,
+    screen: DisplayScreen,
+    handout: &Handout,
+) -> Result<(), String> {
+    if let Some(document_id) = handout.document_id.as_deref() {
+        let document =
+            dal::get_document(db, document_id).map_err(|e| format!("Document not found: {}", e))?;
+        let payload = ReadAloudPayload {
+            title: Some(handout.title.clone()),
+            paragraphs: read_aloud_paragraphs(&document.content),
+        };
+        return app
+            .emit(&screen.event("read-aloud"), payload)
+            .map_err(|e| format!("Failed to emit handout: {}", e));
+    }
+
+    let image_url = match (handout.asset_id.as_deref(), handout.image_path.as_deref()) {
+        (Some(asset_id), _) => {
+            let asset = dal::get_campaign_asset(db, asset_id)
+                .map_err(|e| format!("Asset not found: {}", e))?;
+            read_image_data_url(&state.paths.app_dir, &asset.blob_path)
+        }
+        (None, Some(image_path)) => {
+            read_image_data_url(&state.paths.assets_dir.join("catalog"), image_path)
+        }
+        (None, None) => None,
+    }
+    .ok_or_else(|| format!("Image for handout '{}' not found", handout.title))?;
+
+    let payload = HandoutPayload {
+        kind: QUEUED_HANDOUT_KIND.to_string(),
+        title: Some(handout.title.clone()),
+        image_url,
+    };
+    app.emit(&screen.event("handout"), payload)
+        .map_err(|e| format!("Failed to emit handout: {}", e))
+}
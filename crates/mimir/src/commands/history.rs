@@ -0,0 +1,135 @@
+//! Campaign History Commands
+//!
+//! Tauri commands for the optional git-backed campaign history: turning it
+//! on and off, browsing commits, and restoring documents from a commit.
+
+use diesel::SqliteConnection;
+use mimir_core::models::campaign::Document;
+use mimir_core::services::{CampaignHistoryService, HistoryCommit};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Default number of commits returned by `list_campaign_history`.
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// Commit the campaign's current state to its history, if history is enabled.
+///
+/// Called after saves; failures are logged rather than failing the save.
+pub(crate) fn record_history(
+    state: &AppState,
+    db: &mut SqliteConnection,
+    campaign_id: Option<&str>,
+    message: &str,
+) {
+    if let Some(campaign_id) = campaign_id {
+        CampaignHistoryService::new(db, &state.paths.app_dir)
+            .record_after_save(campaign_id, message);
+    }
+}
+
+/// Check whether history is enabled for a campaign.
+#[tauri::command]
+pub fn is_campaign_history_enabled(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<bool> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    ApiResponse::ok(
+        CampaignHistoryService::new(&mut db, &state.paths.app_dir).is_enabled(&campaign_id),
+    )
+}
+
+/// Turn on history for a campaign, committing its current state.
+#[tauri::command]
+pub fn enable_campaign_history(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(CampaignHistoryService::new(&mut db, &state.paths.app_dir).enable(&campaign_id))
+}
+
+/// Turn off history for a campaign. This deletes the recorded history.
+#[tauri::command]
+pub fn disable_campaign_history(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        CampaignHistoryService::new(&mut db, &state.paths.app_dir).disable(&campaign_id),
+    )
+}
+
+/// List a campaign's history, newest commit first.
+#[tauri::command]
+pub fn list_campaign_history(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    limit: Option<usize>,
+) -> ApiResponse<Vec<HistoryCommit>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        CampaignHistoryService::new(&mut db, &state.paths.app_dir)
+            .history(&campaign_id, limit.unwrap_or(DEFAULT_HISTORY_LIMIT)),
+    )
+}
+
+/// Read a file from the campaign's history as it was at `commit`.
+#[tauri::command]
+pub fn read_campaign_history_file(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    commit: String,
+    path: String,
+) -> ApiResponse<String> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        CampaignHistoryService::new(&mut db, &state.paths.app_dir).read_file(
+            &campaign_id,
+            &commit,
+            &path,
+        ),
+    )
+}
+
+/// Restore a document file to its content at `commit`.
+#[tauri::command]
+pub fn restore_campaign_file(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    commit: String,
+    path: String,
+) -> ApiResponse<Document> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        CampaignHistoryService::new(&mut db, &state.paths.app_dir).restore_file(
+            &campaign_id,
+            &commit,
+            &path,
+        ),
+    )
+}
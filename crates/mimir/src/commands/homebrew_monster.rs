@@ -2,11 +2,12 @@
 //!
 //! Tauri commands for managing campaign homebrew monsters.
 
-use mimir_core::models::campaign::CampaignHomebrewMonster;
+use mimir_core::models::campaign::{CampaignHomebrewMonster, TaggableType};
 use mimir_core::services::{CreateHomebrewMonsterInput, HomebrewService, UpdateHomebrewMonsterInput};
 use serde::Deserialize;
 use tauri::State;
 
+use super::tag::filter_by_tag;
 use super::{to_api_response, ApiResponse};
 use crate::state::AppState;
 
@@ -34,17 +35,30 @@ pub struct TauriUpdateHomebrewMonsterInput {
 }
 
 /// List all homebrew monsters for a campaign.
+///
+/// `tag_id` limits the list to monsters carrying that tag.
 #[tauri::command]
 pub fn list_homebrew_monsters(
     state: State<'_, AppState>,
     campaign_id: String,
+    tag_id: Option<String>,
 ) -> ApiResponse<Vec<CampaignHomebrewMonster>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    to_api_response(HomebrewService::new(&mut db).list_monsters(&campaign_id))
+    let monsters = match HomebrewService::new(&mut db).list_monsters(&campaign_id) {
+        Ok(monsters) => monsters,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    to_api_response(filter_by_tag(
+        &mut db,
+        tag_id.as_deref(),
+        TaggableType::HomebrewMonster,
+        monsters,
+        |m| &m.id,
+    ))
 }
 
 /// Get a homebrew monster by ID.
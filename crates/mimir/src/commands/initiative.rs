@@ -0,0 +1,469 @@
+//! Initiative Commands
+//!
+//! Tauri commands for the encounter's turn order. Players' rolls are typed in
+//! or pasted from a list while monsters are rolled here, so physical dice and
+//! auto-rolls share one order. Every change is broadcast as an
+//! `initiative:update` event. The order reaches the player display once the
+//! DM sends it, and from then on follows every change until it is hidden.
+//! Mobs of identical monsters share one entry and one pool of hit points.
+
+use mimir_core::dal::campaign as dal;
+use mimir_core::services::{EncounterEffectService, MobAttack, TokenService};
+use rand::Rng;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, State};
+
+use super::player_display::{display_screen, monster_token_candidates, read_image_data_url};
+use crate::commands::{to_api_response, ApiResponse};
+use crate::initiative::{InitiativeSnapshot, InitiativeTracker, MobCasualties, Portrait};
+use crate::state::AppState;
+
+/// Payload for the turn order shown to players.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeDisplayPayload {
+    /// Combatants in turn order; empty hides the list
+    pub combatants: Vec<InitiativeDisplayCombatant>,
+    pub current: Option<usize>,
+    pub round: i32,
+}
+
+/// A combatant as shown to players.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeDisplayCombatant {
+    /// Name, or "???" for a hidden combatant
+    pub name: String,
+    /// Token art as a data URL
+    pub portrait_url: Option<String>,
+}
+
+/// Lock the tracker, apply `f`, broadcast the new order, and return the snapshot.
+fn with_initiative<F>(app: &AppHandle, state: &AppState, f: F) -> Result<InitiativeSnapshot, String>
+where
+    F: FnOnce(&mut InitiativeTracker) -> Result<(), String>,
+{
+    let snapshot = {
+        let mut tracker = state
+            .initiative
+            .lock()
+            .map_err(|_| "Initiative state is poisoned".to_string())?;
+        f(&mut tracker)?;
+        tracker.snapshot()
+    };
+
+    if let Err(e) = app.emit("initiative:update", &snapshot) {
+        tracing::warn!("Failed to emit initiative update: {}", e);
+    }
+    refresh_display(app, state);
+
+    Ok(snapshot)
+}
+
+/// Re-send the turn order to the player display showing it, if any.
+fn refresh_display(app: &AppHandle, state: &AppState) {
+    let screen = match state.initiative_display.lock() {
+        Ok(screen) => *screen,
+        Err(_) => return,
+    };
+    let Some(screen) = screen else {
+        return;
+    };
+
+    let result = display_payload(state).and_then(|payload| {
+        app.emit(&screen.event("initiative"), payload)
+            .map_err(|e| e.to_string())
+    });
+    if let Err(e) = result {
+        tracing::warn!("Failed to update displayed initiative: {}", e);
+    }
+}
+
+/// Build the turn order as players see it, with each combatant's token art.
+fn display_payload(state: &AppState) -> Result<InitiativeDisplayPayload, String> {
+    let (view, current, round) = {
+        let tracker = state
+            .initiative
+            .lock()
+            .map_err(|_| "Initiative state is poisoned".to_string())?;
+        let view: Vec<(String, Option<Portrait>)> = tracker
+            .player_view()
+            .into_iter()
+            .map(|c| (c.name.to_string(), c.portrait.cloned()))
+            .collect();
+        let snapshot = tracker.snapshot();
+        (view, snapshot.current, snapshot.round)
+    };
+
+    let combatants = view
+        .into_iter()
+        .map(|(name, portrait)| InitiativeDisplayCombatant {
+            name,
+            portrait_url: portrait.and_then(|p| portrait_url(state, &p)),
+        })
+        .collect();
+
+    Ok(InitiativeDisplayPayload {
+        combatants,
+        current,
+        round,
+    })
+}
+
+/// Read a combatant's token art as a data URL, if it can be found.
+fn portrait_url(state: &AppState, portrait: &Portrait) -> Option<String> {
+    match portrait {
+        Portrait::Character { id } => {
+            let mut db = state.connect().ok()?;
+            let character = dal::get_character_optional(&mut db, id).ok()??;
+            read_image_data_url(&state.paths.assets_dir, character.token_path.as_deref()?)
+        }
+        Portrait::Monster { name, source } => {
+            let catalog_dir = state.paths.assets_dir.join("catalog");
+            monster_token_candidates(source, name)
+                .iter()
+                .find_map(|path| read_image_data_url(&catalog_dir, path))
+        }
+    }
+}
+
+/// Get the current turn order.
+#[tauri::command]
+pub fn get_initiative(state: State<'_, AppState>) -> ApiResponse<InitiativeSnapshot> {
+    match state.initiative.lock() {
+        Ok(tracker) => ApiResponse::ok(tracker.snapshot()),
+        Err(_) => ApiResponse::err("Initiative state is poisoned"),
+    }
+}
+
+/// Set a combatant's token art and whether players see them, leaving either
+/// unchanged when not given.
+fn present(
+    tracker: &mut InitiativeTracker,
+    name: &str,
+    portrait: Option<Portrait>,
+    hidden: Option<bool>,
+) -> Result<(), String> {
+    if portrait.is_some() {
+        tracker.set_portrait(name, portrait)?;
+    }
+    if let Some(hidden) = hidden {
+        tracker.set_hidden(name, hidden)?;
+    }
+    Ok(())
+}
+
+/// Enter an initiative rolled at the table.
+///
+/// Re-entering a name replaces their initiative. `modifier` breaks ties.
+/// `portrait` is the token art players see beside the name.
+#[tauri::command]
+pub fn set_initiative(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    initiative: i32,
+    modifier: Option<i32>,
+    portrait: Option<Portrait>,
+) -> ApiResponse<InitiativeSnapshot> {
+    if name.trim().is_empty() {
+        return ApiResponse::err("Combatant name is required");
+    }
+
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.set(&name, initiative, modifier, true);
+        present(tracker, &name, portrait, None)
+    }))
+}
+
+/// Roll initiative for a creature: d20 plus its initiative modifier.
+///
+/// A `hidden` creature is shown to players as "???" until revealed.
+#[tauri::command]
+pub fn roll_initiative(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    modifier: i32,
+    portrait: Option<Portrait>,
+    hidden: Option<bool>,
+) -> ApiResponse<InitiativeSnapshot> {
+    if name.trim().is_empty() {
+        return ApiResponse::err("Combatant name is required");
+    }
+
+    let d20 = rand::thread_rng().gen_range(1..=20);
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        let initiative = tracker.roll(&name, modifier, d20);
+        tracing::info!(name = %name, d20, initiative, "Rolled initiative");
+        present(tracker, &name, portrait, hidden)
+    }))
+}
+
+/// Add a mob of identical creatures as one combatant with pooled hit points.
+///
+/// `monster_id` is the module monster whose mob tokens shrink as the mob
+/// takes casualties.
+#[tauri::command]
+pub fn add_mob(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    size: i32,
+    hp_each: i32,
+    modifier: i32,
+    monster_id: Option<String>,
+    portrait: Option<Portrait>,
+    hidden: Option<bool>,
+) -> ApiResponse<InitiativeSnapshot> {
+    if name.trim().is_empty() {
+        return ApiResponse::err("Mob name is required");
+    }
+
+    let d20 = rand::thread_rng().gen_range(1..=20);
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        let initiative = tracker.add_mob(&name, monster_id, size, hp_each, modifier, d20)?;
+        tracing::info!(name = %name, size, initiative, "Formed mob");
+        present(tracker, &name, portrait, hidden)
+    }))
+}
+
+/// Reveal a hidden combatant to players, or hide them again.
+#[tauri::command]
+pub fn reveal_combatant(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    revealed: bool,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.set_hidden(&name, !revealed)
+    }))
+}
+
+/// Deal damage to a mob and shrink its tokens to the survivors.
+///
+/// Emits `mob:casualties` so open maps can update their mob tokens.
+#[tauri::command]
+pub fn damage_mob(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    amount: i32,
+) -> ApiResponse<MobCasualties> {
+    let mut result = None;
+    if let Err(e) = with_initiative(&app, &state, |tracker| {
+        result = Some(tracker.damage_mob(&name, amount)?);
+        Ok(())
+    }) {
+        return ApiResponse::err(e);
+    }
+    let Some(result) = result else {
+        return ApiResponse::err("Failed to damage mob");
+    };
+
+    // A routed mob's tokens keep their last size for the DM to clear away
+    if let Some(monster_id) = &result.monster_id {
+        if result.survivors > 0 {
+            let mut db = match state.connect() {
+                Ok(db) => db,
+                Err(e) => return ApiResponse::err(e),
+            };
+            if let Err(e) = TokenService::new(&mut db, &state.paths.app_dir)
+                .resize_mob(monster_id, result.survivors)
+            {
+                return ApiResponse::err(e.to_string());
+            }
+        }
+    }
+
+    if let Err(e) = app.emit("mob:casualties", &result) {
+        tracing::warn!("Failed to emit mob casualties: {}", e);
+    }
+    ApiResponse::ok(result)
+}
+
+/// Resolve a mob's attacks against one target with the DMG mob table.
+#[tauri::command]
+pub fn resolve_mob_attack(
+    state: State<'_, AppState>,
+    name: String,
+    attackers: Option<i32>,
+    attack_bonus: i32,
+    target_ac: i32,
+) -> ApiResponse<MobAttack> {
+    match state.initiative.lock() {
+        Ok(tracker) => match tracker.mob_attack(&name, attackers, attack_bonus, target_ac) {
+            Ok(attack) => ApiResponse::ok(attack),
+            Err(e) => ApiResponse::err(e),
+        },
+        Err(_) => ApiResponse::err("Initiative state is poisoned"),
+    }
+}
+
+/// Enter players' initiative from a pasted list, one "Name 17" per line.
+#[tauri::command]
+pub fn import_initiative(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    text: String,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.import(&text).map(|_| ())
+    }))
+}
+
+/// Get the turn order as "Name: 17" lines, ready to paste back in.
+#[tauri::command]
+pub fn export_initiative(state: State<'_, AppState>) -> ApiResponse<String> {
+    match state.initiative.lock() {
+        Ok(tracker) => ApiResponse::ok(tracker.export()),
+        Err(_) => ApiResponse::err("Initiative state is poisoned"),
+    }
+}
+
+/// Remove a combatant from the turn order.
+#[tauri::command]
+pub fn remove_combatant(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.remove(&name)
+    }))
+}
+
+/// Move a combatant ahead of the combatant they are tied with.
+#[tauri::command]
+pub fn move_combatant_up(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.move_up(&name)
+    }))
+}
+
+/// Delay the current combatant's turn and move on to the next.
+#[tauri::command]
+pub fn delay_turn(app: AppHandle, state: State<'_, AppState>) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| tracker.delay()))
+}
+
+/// Bring a delayed combatant back in to act now.
+#[tauri::command]
+pub fn resume_turn(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.resume(&name)
+    }))
+}
+
+/// Ready an action for a combatant; an empty trigger clears it.
+#[tauri::command]
+pub fn ready_action(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    trigger: Option<String>,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.ready(&name, trigger)
+    }))
+}
+
+/// Advance to the next combatant's turn, starting combat on the first call.
+#[tauri::command]
+pub fn next_initiative_turn(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.next_turn().map(|_| ())
+    }))
+}
+
+/// Schedule a module's encounter effects, replacing those of the last module.
+#[tauri::command]
+pub fn load_encounter_effects(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    module_id: String,
+) -> ApiResponse<InitiativeSnapshot> {
+    let effects = {
+        let mut db = match state.connect() {
+            Ok(db) => db,
+            Err(e) => return ApiResponse::err(e),
+        };
+        match EncounterEffectService::new(&mut db).list(&module_id) {
+            Ok(effects) => effects,
+            Err(e) => return ApiResponse::err(e.to_string()),
+        }
+    };
+
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.schedule_effects(&effects);
+        Ok(())
+    }))
+}
+
+/// End the encounter, removing every creature from the turn order.
+#[tauri::command]
+pub fn clear_initiative(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> ApiResponse<InitiativeSnapshot> {
+    to_api_response(with_initiative(&app, &state, |tracker| {
+        tracker.clear();
+        Ok(())
+    }))
+}
+
+/// Show the turn order on the player display as names and token art.
+///
+/// Initiative values and statuses stay with the DM, and hidden combatants
+/// are shown as "???". The display follows the order as turns advance until
+/// `hide` takes the list off it.
+#[tauri::command]
+pub fn send_initiative_to_display(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    screen: Option<String>,
+    hide: Option<bool>,
+) -> Result<(), String> {
+    let screen = display_screen(&app, screen.as_deref())?;
+    let hide = hide.unwrap_or(false);
+
+    {
+        let mut shown_on = state
+            .initiative_display
+            .lock()
+            .map_err(|_| "Initiative display state is poisoned".to_string())?;
+        // Hiding the list on one screen leaves the other following it
+        if !hide {
+            *shown_on = Some(screen);
+        } else if *shown_on == Some(screen) {
+            *shown_on = None;
+        }
+    }
+
+    let payload = if hide {
+        InitiativeDisplayPayload {
+            combatants: Vec::new(),
+            current: None,
+            round: 0,
+        }
+    } else {
+        display_payload(&state)?
+    };
+
+    app.emit(&screen.event("initiative"), payload)
+        .map_err(|e| format!("Failed to emit initiative: {}", e))?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! LAN Display Commands
+//!
+//! Tauri commands for the LAN player display: an optional web server that
+//! lets other devices on the network show the player display.
+
+use tauri::{AppHandle, State};
+
+use super::ApiResponse;
+use crate::lan_display::LanDisplayStatus;
+use crate::state::AppState;
+
+/// Get whether the LAN display is running and the URLs to open on other devices.
+#[tauri::command]
+pub fn get_lan_display_status(state: State<'_, AppState>) -> ApiResponse<LanDisplayStatus> {
+    match state.lan_display.lock() {
+        Ok(lan) => ApiResponse::ok(lan.status()),
+        Err(_) => ApiResponse::err("LAN display state is poisoned".to_string()),
+    }
+}
+
+/// Start the LAN display server on `port` (default: the port in settings).
+///
+/// A new access token is generated each time the server starts, so earlier
+/// viewer URLs stop working.
+#[tauri::command]
+pub fn start_lan_display(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    port: Option<u16>,
+) -> ApiResponse<LanDisplayStatus> {
+    let port = match port {
+        Some(port) => port,
+        None => match state.settings().load() {
+            Ok(settings) => settings.display.lan_port,
+            Err(e) => return ApiResponse::err(e),
+        },
+    };
+    let mut lan = match state.lan_display.lock() {
+        Ok(lan) => lan,
+        Err(_) => return ApiResponse::err("LAN display state is poisoned".to_string()),
+    };
+    match lan.start(app, port) {
+        Ok(status) => ApiResponse::ok(status),
+        Err(e) => ApiResponse::err(e),
+    }
+}
+
+/// Stop the LAN display server. Returns false if it wasn't running.
+#[tauri::command]
+pub fn stop_lan_display(app: AppHandle, state: State<'_, AppState>) -> ApiResponse<bool> {
+    match state.lan_display.lock() {
+        Ok(mut lan) => ApiResponse::ok(lan.stop(&app)),
+        Err(_) => ApiResponse::err("LAN display state is poisoned".to_string()),
+    }
+}
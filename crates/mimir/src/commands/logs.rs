@@ -0,0 +1,31 @@
+//! Log Viewer Commands
+//!
+//! Tauri commands backing the log viewer in Settings.
+
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::command_trace::CommandTiming;
+use crate::logging::{self, LogPage, LogQuery};
+use crate::state::AppState;
+
+/// List recent commands that took at least `threshold_ms` milliseconds
+/// (default: the slow-command threshold in settings), slowest first.
+#[tauri::command]
+pub fn get_slow_commands(
+    state: State<'_, AppState>,
+    threshold_ms: Option<u64>,
+) -> ApiResponse<Vec<CommandTiming>> {
+    ApiResponse::ok(
+        state
+            .command_trace
+            .slow_commands(threshold_ms.unwrap_or(state.command_trace.slow_command_ms())),
+    )
+}
+
+/// Search the log files by minimum level, module prefix, time range, and
+/// text, newest first, one page at a time.
+#[tauri::command]
+pub fn query_logs(query: LogQuery) -> ApiResponse<LogPage> {
+    to_api_response(logging::query_logs(&logging::log_dir(), &query))
+}
@@ -2,11 +2,12 @@
 //!
 //! List, get, create, update, and delete map operations.
 
-use mimir_core::models::campaign::{LightingMode, Map};
-use mimir_core::services::{CreateMapInput, MapService, UpdateMapInput};
+use mimir_core::models::campaign::{LightingMode, Map, TaggableType};
+use mimir_core::services::{CreateMapInput, MapService, TrashKind, TrashService, UpdateMapInput};
 use tauri::State;
 
 use super::{base64_decode, enrich_map_with_uvtt, enrich_maps_with_uvtt, MapResponse};
+use crate::commands::tag::filter_by_tag;
 use crate::commands::{to_api_response, ApiResponse};
 use crate::state::AppState;
 
@@ -15,18 +16,30 @@ use crate::state::AppState;
 // =============================================================================
 
 /// List all maps for a campaign (including module maps).
+///
+/// `tag_id` limits the list to maps carrying that tag.
 #[tauri::command]
-pub fn list_campaign_maps(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<MapResponse>> {
+pub fn list_campaign_maps(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    tag_id: Option<String>,
+) -> ApiResponse<Vec<MapResponse>> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
+    let maps = match MapService::new(&mut db, &state.paths.app_dir).list_for_campaign(&campaign_id) {
+        Ok(maps) => maps,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let maps = match filter_by_tag(&mut db, tag_id.as_deref(), TaggableType::Map, maps, |m| &m.id) {
+        Ok(maps) => maps,
+        Err(e) => return ApiResponse::err(e),
+    };
+
     let mut service = MapService::new(&mut db, &state.paths.app_dir);
-    match service.list_for_campaign(&campaign_id) {
-        Ok(maps) => ApiResponse::ok(enrich_maps_with_uvtt(maps, &mut service, &state.paths.app_dir)),
-        Err(e) => ApiResponse::err(e.to_string()),
-    }
+    ApiResponse::ok(enrich_maps_with_uvtt(maps, &mut service, &state.paths.app_dir))
 }
 
 /// List only campaign-level maps (not in any module).
@@ -174,7 +187,9 @@ pub fn update_map(
     to_api_response(result)
 }
 
-/// Delete a map and its associated UVTT asset.
+/// Delete a map by moving it to the trash.
+///
+/// The UVTT asset files are quarantined until the map is restored or purged.
 #[tauri::command]
 pub fn delete_map(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
     let mut db = match state.connect() {
@@ -182,6 +197,6 @@ pub fn delete_map(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = MapService::new(&mut db, &state.paths.app_dir).delete(&id);
+    let result = TrashService::new(&mut db, &state.paths.app_dir).trash(TrashKind::Map, &id);
     to_api_response(result)
 }
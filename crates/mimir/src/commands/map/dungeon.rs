@@ -0,0 +1,73 @@
+//! Dungeon Generator Commands
+//!
+//! Commands for generating random dungeon maps with monsters, traps, and a
+//! location key.
+
+use mimir_core::services::{DungeonService, GenerateDungeonInput, GeneratedDungeon};
+use serde::Deserialize;
+use tauri::State;
+use tracing::info;
+
+use crate::commands::ApiResponse;
+use crate::state::AppState;
+
+/// Request for generating a dungeon.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateDungeonRequest {
+    pub module_id: String,
+    pub name: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub rooms: Option<usize>,
+    pub environment: Option<String>,
+    pub min_cr: Option<String>,
+    pub max_cr: Option<String>,
+    pub trap_chance: Option<f64>,
+    pub seed: Option<u64>,
+}
+
+/// Generate a random dungeon map in a module.
+#[tauri::command]
+pub fn generate_dungeon(
+    state: State<'_, AppState>,
+    request: GenerateDungeonRequest,
+) -> ApiResponse<GeneratedDungeon> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut input = GenerateDungeonInput::new(request.module_id, request.name);
+    if let Some(width) = request.width {
+        input.width = width;
+    }
+    if let Some(height) = request.height {
+        input.height = height;
+    }
+    if let Some(rooms) = request.rooms {
+        input.rooms = rooms;
+    }
+    if let Some(trap_chance) = request.trap_chance {
+        input.trap_chance = trap_chance;
+    }
+    if request.max_cr.is_some() {
+        input.max_cr = request.max_cr;
+    }
+    input.min_cr = request.min_cr;
+    input.environment = request.environment;
+    input.seed = request.seed;
+
+    match DungeonService::new(&mut db, &state.paths.app_dir).generate(input) {
+        Ok(dungeon) => {
+            info!(
+                map_id = %dungeon.map.id,
+                seed = dungeon.seed,
+                rooms = dungeon.key.pois.len(),
+                "Generated dungeon"
+            );
+            ApiResponse::ok(dungeon)
+        }
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
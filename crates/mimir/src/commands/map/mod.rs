@@ -4,19 +4,23 @@
 //! Split into sub-modules by functionality.
 
 pub mod crud;
+pub mod dungeon;
 pub mod uvtt;
 pub mod light;
 pub mod fog;
 pub mod traps;
 pub mod pois;
+pub mod regions;
 
 // Re-export all public items for backwards compatibility
 pub use crud::*;
+pub use dungeon::*;
 pub use uvtt::*;
 pub use light::*;
 pub use fog::*;
 pub use traps::*;
 pub use pois::*;
+pub use regions::*;
 
 use mimir_core::models::campaign::{LightSource, Map};
 use mimir_core::services::MapService;
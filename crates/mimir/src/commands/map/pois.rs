@@ -4,6 +4,7 @@
 
 use mimir_core::dal::campaign as dal;
 use mimir_core::models::campaign::{MapPoi, NewMapPoi, UpdateMapPoi};
+use mimir_core::services::{LocationKey, LocationKeyService};
 use mimir_core::utils::now_rfc3339;
 use serde::Deserialize;
 use tauri::State;
@@ -195,6 +196,56 @@ pub fn toggle_map_poi_visibility(state: State<'_, AppState>, id: String) -> ApiR
     }
 }
 
+/// Link a map POI to a document, optionally to one of its sections.
+///
+/// `section` is the heading text the link opens at. Passing no document
+/// clears the link.
+#[tauri::command]
+pub fn link_map_poi_document(
+    state: State<'_, AppState>,
+    id: String,
+    document_id: Option<String>,
+    section: Option<String>,
+) -> ApiResponse<MapPoi> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let now = now_rfc3339();
+    let update = UpdateMapPoi::set_document_link(
+        document_id.as_deref(),
+        section.as_deref().filter(|s| !s.trim().is_empty()),
+        &now,
+    );
+
+    if let Err(e) = dal::update_map_poi(&mut db, &id, &update) {
+        return ApiResponse::err(e.to_string());
+    }
+
+    match dal::get_map_poi(&mut db, &id) {
+        Ok(poi) => ApiResponse::ok(poi),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Generate the numbered location key document for a map's POIs.
+#[tauri::command]
+pub fn generate_location_key(
+    state: State<'_, AppState>,
+    map_id: String,
+) -> ApiResponse<LocationKey> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    match LocationKeyService::new(&mut db).generate(&map_id) {
+        Ok(key) => ApiResponse::ok(key),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
 /// Delete a map POI.
 #[tauri::command]
 pub fn delete_map_poi(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
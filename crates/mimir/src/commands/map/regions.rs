@@ -0,0 +1,109 @@
+//! Map Region Commands
+//!
+//! Commands for named encounter regions on maps, which documents link to
+//! with `{{map-region: Map Name | region}}`.
+
+use mimir_core::models::campaign::MapRegion;
+use mimir_core::services::{MapRegionService, RegionShape, ResolvedMapRegion};
+use serde::Deserialize;
+use tauri::State;
+
+use crate::commands::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+// =============================================================================
+// Map Region Commands
+// =============================================================================
+
+/// List all regions for a map.
+#[tauri::command]
+pub fn list_map_regions(state: State<'_, AppState>, map_id: String) -> ApiResponse<Vec<MapRegion>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MapRegionService::new(&mut db).list_for_map(&map_id))
+}
+
+/// Request for creating a new map region.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMapRegionRequest {
+    pub map_id: String,
+    pub name: String,
+    pub shape: RegionShape,
+}
+
+/// Create a new map region.
+#[tauri::command]
+pub fn create_map_region(
+    state: State<'_, AppState>,
+    request: CreateMapRegionRequest,
+) -> ApiResponse<MapRegion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MapRegionService::new(&mut db).create(
+        &request.map_id,
+        &request.name,
+        &request.shape,
+    ))
+}
+
+/// Request for updating a map region.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMapRegionRequest {
+    pub name: Option<String>,
+    pub shape: Option<RegionShape>,
+}
+
+/// Rename a map region and/or replace its shape.
+#[tauri::command]
+pub fn update_map_region(
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdateMapRegionRequest,
+) -> ApiResponse<MapRegion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MapRegionService::new(&mut db).update(
+        &id,
+        request.name.as_deref(),
+        request.shape.as_ref(),
+    ))
+}
+
+/// Delete a map region.
+#[tauri::command]
+pub fn delete_map_region(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MapRegionService::new(&mut db).delete(&id))
+}
+
+/// Resolve a `{{map-region: Map Name | region}}` reference to its map,
+/// region, and the tokens inside it.
+#[tauri::command]
+pub fn resolve_map_region(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    map_name: String,
+    region_name: String,
+) -> ApiResponse<ResolvedMapRegion> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MapRegionService::new(&mut db).resolve(&campaign_id, &map_name, &region_name))
+}
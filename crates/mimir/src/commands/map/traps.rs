@@ -3,6 +3,7 @@
 //! Commands for managing traps placed on maps.
 
 use mimir_core::dal::campaign as dal;
+use mimir_core::dal::catalog as catalog_dal;
 use mimir_core::models::campaign::{MapTrap, NewMapTrap, UpdateMapTrap};
 use mimir_core::utils::now_rfc3339;
 use serde::Deserialize;
@@ -99,6 +100,146 @@ pub fn create_map_trap(
     }
 }
 
+/// Reference to a trap in the catalog.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrapRef {
+    pub name: String,
+    pub source: String,
+}
+
+/// Request for placing a catalog trap on a map.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMapTrapFromCatalogRequest {
+    pub map_id: String,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub trap_ref: TrapRef,
+    /// Name shown on the map; defaults to the catalog name
+    pub name: Option<String>,
+    pub visible: Option<bool>,
+}
+
+/// Place a catalog trap on a map.
+///
+/// Copies the trigger, effect, description, and DC onto the map trap and
+/// keeps a reference to the catalog entry so the text can be refreshed later.
+#[tauri::command]
+pub fn create_map_trap_from_catalog(
+    state: State<'_, AppState>,
+    request: CreateMapTrapFromCatalogRequest,
+) -> ApiResponse<MapTrap> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let catalog_trap = match catalog_dal::get_trap_by_name(
+        &mut db,
+        &request.trap_ref.name,
+        &request.trap_ref.source,
+    ) {
+        Ok(Some(trap)) => trap,
+        Ok(None) => {
+            return ApiResponse::err(format!(
+                "Trap not found: {} ({})",
+                request.trap_ref.name, request.trap_ref.source
+            ))
+        }
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let text = match catalog_trap.placement_text() {
+        Ok(text) => text,
+        Err(e) => return ApiResponse::err(format!("Failed to parse trap data: {}", e)),
+    };
+
+    let id = Uuid::new_v4().to_string();
+    let name = request.name.as_deref().unwrap_or(&catalog_trap.name);
+    let mut trap = NewMapTrap::new(&id, &request.map_id, name, request.grid_x, request.grid_y)
+        .with_catalog_ref(&catalog_trap.name, &catalog_trap.source);
+
+    if let Some(desc) = &text.description {
+        trap = trap.with_description(desc);
+    }
+    if let Some(trigger) = &text.trigger {
+        trap = trap.with_trigger(trigger);
+    }
+    if let Some(effect) = &text.effect {
+        trap = trap.with_effect(effect);
+    }
+    if let Some(dc) = text.dc {
+        trap = trap.with_dc(dc);
+    }
+    if request.visible == Some(true) {
+        trap = trap.visible();
+    }
+
+    if let Err(e) = dal::insert_map_trap(&mut db, &trap) {
+        return ApiResponse::err(e.to_string());
+    }
+
+    match dal::get_map_trap(&mut db, &id) {
+        Ok(trap) => ApiResponse::ok(trap),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
+/// Re-copy a map trap's text from the catalog trap it was placed from.
+///
+/// Overwrites the description, trigger, effect, and DC; the name, position,
+/// and state are kept.
+#[tauri::command]
+pub fn refresh_map_trap_from_catalog(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<MapTrap> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let trap = match dal::get_map_trap(&mut db, &id) {
+        Ok(t) => t,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let Some((name, source)) = trap.catalog_ref() else {
+        return ApiResponse::err(format!(
+            "Trap {} was not placed from the catalog",
+            trap.name
+        ));
+    };
+
+    let catalog_trap = match catalog_dal::get_trap_by_name(&mut db, name, source) {
+        Ok(Some(trap)) => trap,
+        Ok(None) => return ApiResponse::err(format!("Trap not found: {} ({})", name, source)),
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+    let text = match catalog_trap.placement_text() {
+        Ok(text) => text,
+        Err(e) => return ApiResponse::err(format!("Failed to parse trap data: {}", e)),
+    };
+
+    let now = now_rfc3339();
+    let update = UpdateMapTrap {
+        description: Some(text.description.as_deref()),
+        trigger_description: Some(text.trigger.as_deref()),
+        effect_description: Some(text.effect.as_deref()),
+        dc: Some(text.dc),
+        updated_at: Some(&now),
+        ..Default::default()
+    };
+
+    if let Err(e) = dal::update_map_trap(&mut db, &id, &update) {
+        return ApiResponse::err(e.to_string());
+    }
+
+    match dal::get_map_trap(&mut db, &id) {
+        Ok(trap) => ApiResponse::ok(trap),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
 /// Request for updating a map trap.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,72 @@
+//! Campaign Memory Commands
+//!
+//! Tauri commands for reviewing and correcting the facts the AI assistant
+//! has remembered about a campaign.
+
+use mimir_core::models::campaign::CampaignMemory;
+use mimir_core::services::MemoryService;
+use tauri::State;
+
+use crate::commands::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a campaign's memories, most recently updated first.
+#[tauri::command]
+pub fn list_campaign_memories(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<CampaignMemory>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MemoryService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// Add a memory, or replace the one with the same name.
+#[tauri::command]
+pub fn remember_campaign_memory(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    name: String,
+    content: String,
+) -> ApiResponse<CampaignMemory> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MemoryService::new(&mut db).remember(&campaign_id, &name, &content))
+}
+
+/// Rename a memory or change what it says.
+#[tauri::command]
+pub fn update_campaign_memory(
+    state: State<'_, AppState>,
+    memory_id: String,
+    name: Option<String>,
+    content: Option<String>,
+) -> ApiResponse<CampaignMemory> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MemoryService::new(&mut db).update(
+        &memory_id,
+        name.as_deref(),
+        content.as_deref(),
+    ))
+}
+
+/// Forget a memory.
+#[tauri::command]
+pub fn delete_campaign_memory(state: State<'_, AppState>, memory_id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(MemoryService::new(&mut db).delete(&memory_id))
+}
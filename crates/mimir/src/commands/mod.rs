@@ -3,29 +3,75 @@
 //! This module contains all Tauri commands that bridge the Vue.js frontend
 //! with the mimir-core service layer.
 
+pub mod analytics;
 pub mod archive;
 pub mod asset;
 pub mod campaign;
 pub mod catalog;
 pub mod character;
+pub mod character_image;
+pub mod class_resource;
+pub mod combat;
+pub mod companion;
+pub mod continuity;
+pub mod crafting;
+pub mod currency;
+pub mod custom_field;
+pub mod death;
 pub mod dev;
+pub mod display_profile;
 pub mod dm_map;
+pub mod dm_reference;
+pub mod history;
 pub mod homebrew;
 pub mod homebrew_monster;
 pub mod homebrew_spell;
+pub mod initiative;
 pub mod document;
+pub mod encounter_effect;
+pub mod encryption;
+pub mod forage;
+pub mod handout;
+pub mod lan_display;
+pub mod logs;
 pub mod map;
+pub mod memory;
 pub mod module;
+pub mod onboarding;
+pub mod palette;
+pub mod party;
+pub mod player;
 pub mod player_display;
 pub mod print;
+pub mod quick_action;
+pub mod reaction;
+pub mod recap;
+pub mod recent;
+pub mod rumor;
+pub mod safety_tools;
+pub mod secret_note;
+pub mod settings;
+pub mod settlement;
 pub mod source;
+pub mod stash;
+pub mod sync;
+pub mod tag;
+pub mod timer;
+pub mod trash;
+pub mod travel;
+pub mod vehicle;
+pub mod watch;
 
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::error::CommandError;
+
 /// API Response wrapper for frontend compatibility.
 ///
 /// The frontend expects responses in this format with success/error handling.
+/// Failures carry the message in `error` and the structured
+/// [`CommandError`] in `error_details`.
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T: Serialize> {
     pub success: bool,
@@ -33,6 +79,8 @@ pub struct ApiResponse<T: Serialize> {
     pub data: Option<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_details: Option<CommandError>,
 }
 
 impl<T: Serialize> ApiResponse<T> {
@@ -42,26 +90,32 @@ impl<T: Serialize> ApiResponse<T> {
             success: true,
             data: Some(data),
             error: None,
+            error_details: None,
         }
     }
 
     /// Create an error response.
-    pub fn err(message: impl Into<String>) -> Self {
+    ///
+    /// Plain messages are reported with [`ErrorKind::Other`](mimir_core::services::ErrorKind);
+    /// pass a [`CommandError`] (or a service/print error) to classify the failure.
+    pub fn err(error: impl Into<CommandError>) -> Self {
+        let error = error.into();
         Self {
             success: false,
             data: None,
-            error: Some(message.into()),
+            error: Some(error.message.clone()),
+            error_details: Some(error),
         }
     }
 }
 
 /// Helper to convert service results to API responses.
-pub fn to_api_response<T: Serialize, E: std::fmt::Display>(
+pub fn to_api_response<T: Serialize, E: Into<CommandError>>(
     result: Result<T, E>,
 ) -> ApiResponse<T> {
     match result {
         Ok(data) => ApiResponse::ok(data),
-        Err(e) => ApiResponse::err(e.to_string()),
+        Err(e) => ApiResponse::err(e),
     }
 }
 
@@ -4,11 +4,13 @@
 
 use mimir_core::dal::campaign as dal;
 use mimir_core::dal::catalog::get_monster_by_name;
-use mimir_core::models::campaign::{Module, ModuleMonster, ModuleNpc, NewModuleMonster, UpdateModuleMonster};
+use mimir_core::models::campaign::{
+    Module, ModuleMonster, ModuleNpc, NewModuleMonster, TaggableType, UpdateModuleMonster,
+};
 use mimir_core::models::catalog::Monster;
 use mimir_core::services::{
-    CreateModuleInput, CreateTokenInput, ModuleService, ModuleType, TokenResponse, TokenService,
-    UpdateModuleInput, UpdateTokenInput,
+    CreateModuleInput, CreateTokenInput, ModulePrep, ModulePrepService, ModuleService, ModuleType,
+    ModuleWithPrep, TokenResponse, TokenService, UpdateModuleInput, UpdateTokenInput,
 };
 use mimir_core::utils::now_rfc3339;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,8 @@ use tauri::State;
 use uuid::Uuid;
 
 use crate::state::AppState;
+use super::player_display::read_image_data_url;
+use super::tag::filter_by_tag;
 use super::{to_api_response, ApiResponse};
 
 /// List all modules for a campaign.
@@ -30,15 +34,15 @@ pub fn list_modules(state: State<'_, AppState>, campaign_id: String) -> ApiRespo
     to_api_response(result)
 }
 
-/// Get a module by ID.
+/// Get a module by ID, with its prep checklist.
 #[tauri::command]
-pub fn get_module(state: State<'_, AppState>, id: String) -> ApiResponse<Module> {
+pub fn get_module(state: State<'_, AppState>, id: String) -> ApiResponse<ModuleWithPrep> {
     let mut db = match state.connect() {
         Ok(db) => db,
         Err(e) => return ApiResponse::err(e),
     };
 
-    let result = ModuleService::new(&mut db).get(&id);
+    let result = ModulePrepService::new(&mut db).get_module_with_prep(&id);
     match result {
         Ok(Some(module)) => ApiResponse::ok(module),
         Ok(None) => ApiResponse::err(format!("Module not found: {}", id)),
@@ -142,6 +146,23 @@ pub fn get_module_by_number(
     }
 }
 
+/// Tick a module prep task off by hand, or untick it.
+#[tauri::command]
+pub fn set_module_prep_task(
+    state: State<'_, AppState>,
+    module_id: String,
+    task_key: String,
+    checked: bool,
+) -> ApiResponse<ModulePrep> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = ModulePrepService::new(&mut db).set_task_checked(&module_id, &task_key, checked);
+    to_api_response(result)
+}
+
 /// Reorder a module by moving it to a new position (1-indexed).
 #[tauri::command]
 pub fn reorder_module(
@@ -171,10 +192,13 @@ pub struct MonsterWithData {
 }
 
 /// List all monsters for a module with catalog data.
+///
+/// `tag_id` limits the list to monster entries carrying that tag.
 #[tauri::command]
 pub fn list_module_monsters_with_data(
     state: State<'_, AppState>,
     module_id: String,
+    tag_id: Option<String>,
 ) -> ApiResponse<Vec<MonsterWithData>> {
     let mut db = match state.connect() {
         Ok(db) => db,
@@ -186,6 +210,10 @@ pub fn list_module_monsters_with_data(
         Ok(m) => m,
         Err(e) => return ApiResponse::err(e.to_string()),
     };
+    let monsters = match filter_by_tag(&mut db, tag_id.as_deref(), TaggableType::Monster, monsters, |m| &m.id) {
+        Ok(m) => m,
+        Err(e) => return ApiResponse::err(e),
+    };
 
     // Convert to MonsterWithData with catalog or homebrew data lookup
     let result: Vec<MonsterWithData> = monsters
@@ -463,6 +491,9 @@ pub struct CreateTokenRequest {
     pub label: Option<String>,
     pub faction_color: Option<String>,
     pub hidden: bool,
+    pub character_id: Option<String>,
+    #[serde(default)]
+    pub mob_size: Option<i32>,
 }
 
 /// Create a new token placement.
@@ -485,6 +516,8 @@ pub fn create_token(
         label: request.label,
         faction_color: request.faction_color,
         hidden: request.hidden,
+        character_id: request.character_id,
+        mob_size: request.mob_size,
     };
 
     match TokenService::new(&mut db, &state.paths.app_dir).create(input) {
@@ -576,6 +609,30 @@ pub fn update_token_vision(
     }
 }
 
+/// Make a monster token stand for a mob of identical creatures, or back into
+/// a single creature's token when `mob_size` is None.
+#[tauri::command]
+pub fn set_token_mob_size(
+    state: State<'_, AppState>,
+    id: String,
+    mob_size: Option<i32>,
+) -> ApiResponse<TokenResponse> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = UpdateTokenInput {
+        mob_size: Some(mob_size),
+        ..Default::default()
+    };
+
+    match TokenService::new(&mut db, &state.paths.app_dir).update(&id, input) {
+        Ok(token) => ApiResponse::ok(token),
+        Err(e) => ApiResponse::err(e.to_string()),
+    }
+}
+
 /// Toggle a token's visibility (hidden from players).
 #[tauri::command]
 pub fn toggle_token_visibility(
@@ -614,7 +671,8 @@ pub fn delete_token(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
 
 /// Serve a token's image as a base64 data URL.
 ///
-/// Uses convention-based paths: `bestiary/tokens/{source}/{name}.{ext}`
+/// PC tokens linked to a character use the character's token image. Monster
+/// tokens use convention-based paths: `bestiary/tokens/{source}/{name}.{ext}`
 /// The token_image_path field in the catalog is reserved for custom overrides.
 #[tauri::command]
 pub fn serve_token_image(
@@ -637,6 +695,15 @@ pub fn serve_token_image(
         }
     };
 
+    if let Some(ref character_id) = token.character_id {
+        let image_url = dal::get_character_optional(&mut db, character_id)
+            .ok()
+            .flatten()
+            .and_then(|c| c.token_path)
+            .and_then(|path| read_image_data_url(&state.paths.assets_dir, &path));
+        return ApiResponse::ok(image_url);
+    }
+
     // Only monster tokens have images from the catalog
     let Some(ref monster_id) = token.module_monster_id else {
         tracing::debug!("Token {} has no module_monster_id, skipping image", token_id);
@@ -0,0 +1,102 @@
+//! Onboarding Commands
+//!
+//! Tauri commands for the first-run setup: checking whether the library and
+//! campaign list are empty, filling the library with SRD content, and creating
+//! the sample campaign. Each step emits `onboarding:progress` events.
+
+use mimir_core::models::campaign::Campaign;
+use mimir_core::services::{
+    download_library_archive, LibraryImportSummary, OnboardingProgress, OnboardingService,
+    OnboardingStatus,
+};
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Event emitted with an `OnboardingProgress` as each setup step advances.
+pub const ONBOARDING_PROGRESS_EVENT: &str = "onboarding:progress";
+
+/// File name a downloaded library archive is saved under until imported.
+const DOWNLOADED_ARCHIVE: &str = "srd-library.tar.gz";
+
+/// Whether this install still needs first-run setup.
+#[tauri::command]
+pub fn get_onboarding_status(state: State<'_, AppState>) -> ApiResponse<OnboardingStatus> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OnboardingService::new(&mut db, &state.paths.app_dir).status())
+}
+
+/// Import the SRD content of a 5etools archive picked from disk.
+#[tauri::command(async)]
+pub fn import_srd_library(
+    app: AppHandle,
+    archive_path: String,
+) -> ApiResponse<LibraryImportSummary> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        OnboardingService::new(&mut db, &state.paths.app_dir)
+            .import_srd_library(Path::new(&archive_path), |p| emit_progress(&app, p)),
+    )
+}
+
+/// Download a 5etools archive from `url` and import its SRD content.
+///
+/// The archive is removed once imported.
+#[tauri::command]
+pub async fn download_srd_library(
+    app: AppHandle,
+    url: String,
+) -> ApiResponse<LibraryImportSummary> {
+    let state = app.state::<AppState>();
+    let archive_path = state.paths.data_dir.join(DOWNLOADED_ARCHIVE);
+
+    if let Err(e) = download_library_archive(&url, &archive_path, |p| emit_progress(&app, p)).await
+    {
+        let _ = std::fs::remove_file(&archive_path);
+        return ApiResponse::err(e.to_string());
+    }
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+    let result = OnboardingService::new(&mut db, &state.paths.app_dir)
+        .import_srd_library(&archive_path, |p| emit_progress(&app, p));
+
+    if let Err(e) = std::fs::remove_file(&archive_path) {
+        tracing::warn!("Failed to remove downloaded library archive: {}", e);
+    }
+    to_api_response(result)
+}
+
+/// Create the sample campaign, or return it if it already exists.
+#[tauri::command(async)]
+pub fn create_sample_campaign(app: AppHandle) -> ApiResponse<Campaign> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        OnboardingService::new(&mut db, &state.paths.app_dir)
+            .create_sample_campaign(|p| emit_progress(&app, p)),
+    )
+}
+
+fn emit_progress(app: &AppHandle, progress: OnboardingProgress) {
+    if let Err(e) = app.emit(ONBOARDING_PROGRESS_EVENT, progress) {
+        tracing::warn!("Failed to emit onboarding progress: {}", e);
+    }
+}
@@ -0,0 +1,152 @@
+//! Command Palette Commands
+//!
+//! Tauri commands that list the registered palette actions and run one by
+//! ID. Each action is dispatched to the command that backs it, and every run
+//! is written to the log as an audit record.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::time::Instant;
+use tauri::{AppHandle, Manager};
+
+use super::module::CreateModuleRequest;
+use super::{
+    archive, handout, lan_display, module, player_display, quick_action, timer, ApiResponse,
+};
+use crate::command_registry::{self, CommandContext, PaletteCommand};
+use crate::error::{CommandError, CommandResult};
+use crate::state::AppState;
+
+/// List the command palette's actions, marking which ones can run in
+/// `context`.
+#[tauri::command]
+pub fn list_registered_commands(
+    context: Option<CommandContext>,
+) -> ApiResponse<Vec<PaletteCommand>> {
+    ApiResponse::ok(command_registry::palette(&context.unwrap_or_default()))
+}
+
+/// Run a registered palette action with arguments keyed by parameter name.
+///
+/// Returns whatever the backing command returns.
+#[tauri::command(async)]
+pub fn execute_registered_command(
+    app: AppHandle,
+    id: String,
+    args: Option<Map<String, Value>>,
+) -> ApiResponse<Value> {
+    let args = args.unwrap_or_default();
+    let start = Instant::now();
+    let result = command_registry::find(&id)
+        .and_then(|command| command_registry::validate_args(command, &args))
+        .and_then(|_| dispatch(&app, &id, &args));
+    command_registry::log_execution(&id, &args, &result, start.elapsed().as_millis() as u64);
+
+    match result {
+        Ok(value) => ApiResponse::ok(value),
+        Err(e) => ApiResponse::err(e),
+    }
+}
+
+/// Run the command backing a registered action. Arguments have already been
+/// validated against its parameters.
+fn dispatch(app: &AppHandle, id: &str, args: &Map<String, Value>) -> CommandResult<Value> {
+    let state = app.state::<AppState>();
+    match id {
+        "module.create" => from_response(module::create_module(
+            state,
+            CreateModuleRequest {
+                campaign_id: arg(args, "campaignId")?,
+                name: arg(args, "name")?,
+                description: None,
+                module_type: arg(args, "moduleType")?,
+            },
+        )),
+        "campaign.backup" => from_response(archive::create_campaign_backup(
+            app.clone(),
+            arg(args, "campaignId")?,
+            arg(args, "differential")?,
+        )),
+        "quick_action.trigger" => from_response(quick_action::trigger_quick_action(
+            app.clone(),
+            state,
+            arg(args, "quickActionId")?,
+        )),
+        "session.start" => from_response(timer::start_game_session(
+            app.clone(),
+            state,
+            arg(args, "campaignId")?,
+            arg(args, "moduleId")?,
+            arg(args, "title")?,
+            None,
+        )),
+        "session.end" => from_response(timer::end_game_session(app.clone(), state)),
+        "timer.start" => from_response(timer::start_timer(
+            app.clone(),
+            state,
+            arg(args, "kind")?,
+            arg(args, "durationSecs")?,
+            arg(args, "label")?,
+        )),
+        "timer.next_turn" => from_response(timer::next_turn_timer(
+            app.clone(),
+            state,
+            arg(args, "label")?,
+        )),
+        "display.open" => from_result(player_display::open_player_display_window(
+            app.clone(),
+            state,
+            arg(args, "screen")?,
+        )),
+        "display.blackout" => from_result(player_display::toggle_display_blackout(
+            app.clone(),
+            arg(args, "isBlackout")?,
+            arg(args, "screen")?,
+        )),
+        "display.reveal_next_handout" => from_response(handout::reveal_next_handout(
+            app.clone(),
+            state,
+            arg(args, "campaignId")?,
+            arg(args, "screen")?,
+        )),
+        "display.clear_handout" => from_result(player_display::clear_display_handout(
+            app.clone(),
+            arg(args, "screen")?,
+        )),
+        "lan_display.start" => from_response(lan_display::start_lan_display(
+            app.clone(),
+            state,
+            arg(args, "port")?,
+        )),
+        "lan_display.stop" => from_response(lan_display::stop_lan_display(app.clone(), state)),
+        other => Err(CommandError::from(format!(
+            "Command '{}' is registered but has no handler",
+            other
+        ))),
+    }
+}
+
+/// Read an argument as the type the backing command takes. Missing
+/// arguments read as null, so optional parameters come out as `None`.
+fn arg<T: DeserializeOwned>(args: &Map<String, Value>, name: &str) -> CommandResult<T> {
+    serde_json::from_value(args.get(name).cloned().unwrap_or(Value::Null))
+        .map_err(|e| CommandError::from(format!("Invalid value for '{}': {}", name, e)))
+}
+
+fn from_response<T: Serialize>(response: ApiResponse<T>) -> CommandResult<Value> {
+    if !response.success {
+        return Err(response
+            .error_details
+            .unwrap_or_else(|| CommandError::from(response.error.unwrap_or_default())));
+    }
+    to_value(response.data)
+}
+
+fn from_result<T: Serialize>(result: Result<T, String>) -> CommandResult<Value> {
+    to_value(result?)
+}
+
+fn to_value<T: Serialize>(data: T) -> CommandResult<Value> {
+    serde_json::to_value(data).map_err(|e| CommandError::from(e.to_string()))
+}
@@ -0,0 +1,261 @@
+//! Party Commands
+//!
+//! Tauri commands for parties in multi-table and West Marches campaigns:
+//! membership, party XP, encounter difficulty against a party, and each
+//! party's calendar timeline. `list_pcs` and `list_game_sessions` accept an
+//! optional `party_id` filter.
+
+use mimir_core::models::campaign::{Party, PartyTimelineEvent};
+use mimir_core::services::{
+    EncounterBudget, EncounterEvaluation, EncounterMonster, PartyService, PartyXpSummary,
+    TimelineEventInput,
+};
+use serde::Deserialize;
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Map an optional field from a request: an empty string clears it.
+fn clearable(value: Option<&str>) -> Option<Option<&str>> {
+    value.map(|v| if v.is_empty() { None } else { Some(v) })
+}
+
+// =============================================================================
+// Party Commands
+// =============================================================================
+
+/// List all parties for a campaign, alphabetically.
+#[tauri::command]
+pub fn list_parties(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<Party>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// Create a party for a campaign.
+#[tauri::command]
+pub fn create_party(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    name: String,
+    description: Option<String>,
+    color: Option<String>,
+) -> ApiResponse<Party> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).create(
+        &campaign_id,
+        &name,
+        description.as_deref(),
+        color.as_deref(),
+    ))
+}
+
+/// Request for updating a party.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePartyRequest {
+    pub name: Option<String>,
+    /// New description; an empty string clears it
+    pub description: Option<String>,
+    /// New color; an empty string clears it
+    pub color: Option<String>,
+}
+
+/// Rename, redescribe, or recolor a party.
+#[tauri::command]
+pub fn update_party(
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdatePartyRequest,
+) -> ApiResponse<Party> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).update(
+        &id,
+        request.name.as_deref(),
+        clearable(request.description.as_deref()),
+        clearable(request.color.as_deref()),
+    ))
+}
+
+/// Delete a party. Its sessions are kept with no party.
+#[tauri::command]
+pub fn delete_party(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).delete(&id))
+}
+
+// =============================================================================
+// Membership Commands
+// =============================================================================
+
+/// Add a player character to a party.
+#[tauri::command]
+pub fn add_party_member(
+    state: State<'_, AppState>,
+    party_id: String,
+    character_id: String,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).add_member(&party_id, &character_id))
+}
+
+/// Remove a character from a party.
+#[tauri::command]
+pub fn remove_party_member(
+    state: State<'_, AppState>,
+    party_id: String,
+    character_id: String,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).remove_member(&party_id, &character_id))
+}
+
+/// List the parties a character belongs to.
+#[tauri::command]
+pub fn list_character_parties(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<Party>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).list_for_character(&character_id))
+}
+
+// =============================================================================
+// XP and Encounter Commands
+// =============================================================================
+
+/// XP totals for a party and each of its members (living members unless
+/// `include_dead`).
+#[tauri::command]
+pub fn get_party_xp(
+    state: State<'_, AppState>,
+    party_id: String,
+    include_dead: Option<bool>,
+) -> ApiResponse<PartyXpSummary> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).xp_summary(&party_id, include_dead.unwrap_or(false)))
+}
+
+/// Easy, medium, hard, and deadly XP thresholds for a party (living members
+/// unless `include_dead`).
+#[tauri::command]
+pub fn get_party_encounter_budget(
+    state: State<'_, AppState>,
+    party_id: String,
+    include_dead: Option<bool>,
+) -> ApiResponse<EncounterBudget> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        PartyService::new(&mut db).encounter_budget(&party_id, include_dead.unwrap_or(false)),
+    )
+}
+
+/// Rate a group of monsters against a party.
+#[tauri::command]
+pub fn evaluate_party_encounter(
+    state: State<'_, AppState>,
+    party_id: String,
+    monsters: Vec<EncounterMonster>,
+    include_dead: Option<bool>,
+) -> ApiResponse<EncounterEvaluation> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).evaluate_encounter(
+        &party_id,
+        &monsters,
+        include_dead.unwrap_or(false),
+    ))
+}
+
+// =============================================================================
+// Timeline Commands
+// =============================================================================
+
+/// Set a party's current in-world day.
+#[tauri::command]
+pub fn set_party_day(state: State<'_, AppState>, party_id: String, day: i32) -> ApiResponse<Party> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).set_current_day(&party_id, day))
+}
+
+/// A party's timeline in calendar order.
+#[tauri::command]
+pub fn list_party_timeline(
+    state: State<'_, AppState>,
+    party_id: String,
+) -> ApiResponse<Vec<PartyTimelineEvent>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).timeline(&party_id))
+}
+
+/// Add an event to a party's timeline. The day defaults to the party's current day.
+#[tauri::command]
+pub fn add_party_timeline_event(
+    state: State<'_, AppState>,
+    party_id: String,
+    event: TimelineEventInput,
+) -> ApiResponse<PartyTimelineEvent> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).add_timeline_event(&party_id, event))
+}
+
+/// Delete a timeline event.
+#[tauri::command]
+pub fn delete_party_timeline_event(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PartyService::new(&mut db).delete_timeline_event(&id))
+}
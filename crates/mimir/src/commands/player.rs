@@ -0,0 +1,225 @@
+//! Player Commands
+//!
+//! Tauri commands for a campaign's player roster: contact details, character
+//! ownership, attendance, and claim codes that let a player take over or
+//! submit a character.
+
+use mimir_core::models::campaign::{Character, Player, PlayerClaim};
+use mimir_core::services::{PlayerAttendance, PlayerContact, PlayerService, UpdatePlayerInput};
+use serde::Deserialize;
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Map an optional field from a request: an empty string clears it.
+fn clearable(value: Option<String>) -> Option<Option<String>> {
+    value.map(|v| if v.is_empty() { None } else { Some(v) })
+}
+
+// =============================================================================
+// Roster Commands
+// =============================================================================
+
+/// List a campaign's players, alphabetically.
+#[tauri::command]
+pub fn list_players(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<Player>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// Add a player to a campaign's roster.
+#[tauri::command]
+pub fn create_player(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    name: String,
+    contact: Option<PlayerContact>,
+) -> ApiResponse<Player> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).create(
+        &campaign_id,
+        &name,
+        &contact.unwrap_or_default(),
+    ))
+}
+
+/// Request for updating a player.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePlayerRequest {
+    pub name: Option<String>,
+    /// New email; an empty string clears it
+    pub email: Option<String>,
+    /// New Discord handle; an empty string clears it
+    pub discord: Option<String>,
+    /// New phone number; an empty string clears it
+    pub phone: Option<String>,
+    /// New notes; an empty string clears them
+    pub notes: Option<String>,
+}
+
+/// Rename a player or change their contact details.
+#[tauri::command]
+pub fn update_player(
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdatePlayerRequest,
+) -> ApiResponse<Player> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = UpdatePlayerInput {
+        name: request.name,
+        email: clearable(request.email),
+        discord: clearable(request.discord),
+        phone: clearable(request.phone),
+        notes: clearable(request.notes),
+    };
+    to_api_response(PlayerService::new(&mut db).update(&id, input))
+}
+
+/// Remove a player from the roster. Their characters are kept.
+#[tauri::command]
+pub fn delete_player(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).delete(&id))
+}
+
+// =============================================================================
+// Character Commands
+// =============================================================================
+
+/// Give a player character to a player.
+#[tauri::command]
+pub fn assign_player_character(
+    state: State<'_, AppState>,
+    player_id: String,
+    character_id: String,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).assign_character(&player_id, &character_id))
+}
+
+/// Take a character away from its player.
+#[tauri::command]
+pub fn unassign_player_character(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).unassign_character(&character_id))
+}
+
+/// List a player's characters, alphabetically.
+#[tauri::command]
+pub fn list_player_characters(
+    state: State<'_, AppState>,
+    player_id: String,
+) -> ApiResponse<Vec<Character>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).list_characters(&player_id))
+}
+
+/// The sessions a player attended, derived from party membership.
+#[tauri::command]
+pub fn get_player_attendance(
+    state: State<'_, AppState>,
+    player_id: String,
+) -> ApiResponse<PlayerAttendance> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).attendance(&player_id))
+}
+
+// =============================================================================
+// Claim Commands
+// =============================================================================
+
+/// Issue a claim code to a player, optionally offering an existing character.
+#[tauri::command]
+pub fn create_player_claim(
+    state: State<'_, AppState>,
+    player_id: String,
+    character_id: Option<String>,
+    expires_in_days: Option<i64>,
+) -> ApiResponse<PlayerClaim> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).create_claim(
+        &player_id,
+        character_id.as_deref(),
+        expires_in_days,
+    ))
+}
+
+/// List a campaign's claim codes, newest first.
+#[tauri::command]
+pub fn list_player_claims(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<PlayerClaim>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).list_claims(&campaign_id))
+}
+
+/// Revoke a claim code.
+#[tauri::command]
+pub fn revoke_player_claim(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).revoke_claim(&id))
+}
+
+/// Redeem a claim code, optionally submitting a new character as JSON.
+#[tauri::command]
+pub fn redeem_player_claim(
+    state: State<'_, AppState>,
+    code: String,
+    character_json: Option<String>,
+) -> ApiResponse<Character> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(PlayerService::new(&mut db).redeem_claim(&code, character_json.as_deref()))
+}
@@ -1,10 +1,26 @@
 //! Player Display Window Commands
 //!
-//! Tauri commands for managing the player display window.
+//! Tauri commands for managing the player display windows.
 //! These commands handle window creation, destruction, fullscreen, and IPC events.
+//!
+//! Two display screens are supported so a table can run, for example, the map
+//! on a TV and handouts on a laptop. Commands take an optional `screen`
+//! ("primary" or "secondary") and default to the primary screen.
+//!
+//! While the LAN display server is running, display events are also relayed to
+//! remote screens, so events can be sent without a local display window.
 
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use mimir_core::dal::catalog as catalog_dal;
+use mimir_core::models::catalog::Monster;
+use mimir_core::services::DocumentService;
+use mimir_core::utils::read_aloud_blocks;
 use serde::Serialize;
-use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
+use std::path::{Component, Path};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+
+use super::display_profile::apply_display_profile;
+use crate::state::AppState;
 
 /// Payload for map updates sent to the player display
 #[derive(Debug, Clone, Serialize)]
@@ -35,38 +51,193 @@ pub struct BlackoutPayload {
     pub is_blackout: bool,
 }
 
+/// Payload for a player-safe monster card.
+///
+/// Deliberately carries no statistics - only what the players could see
+/// when the creature steps into view.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterDisplayPayload {
+    pub name: String,
+    pub size: String,
+    /// Monster art as a base64 data URL, if any was imported.
+    pub image_url: Option<String>,
+}
+
+/// Payload for an image handout (NPC portrait, item art) shown to players.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandoutPayload {
+    pub kind: String,
+    pub title: Option<String>,
+    pub image_url: String,
+}
+
+/// Payload for a boxed read-aloud passage shown to players.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadAloudPayload {
+    pub title: Option<String>,
+    /// Paragraphs of the passage, with markdown emphasis markers removed.
+    pub paragraphs: Vec<String>,
+}
+
+/// Handout kinds accepted by `send_handout_to_display`.
+pub(crate) const HANDOUT_KINDS: &[&str] = &["npc_portrait", "item_art"];
+
 const PLAYER_DISPLAY_LABEL: &str = "player-display";
+const SECONDARY_DISPLAY_LABEL: &str = "player-display-secondary";
 
-/// Check if the player display window is currently open.
+/// A player display screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayScreen {
+    Primary,
+    Secondary,
+}
+
+impl DisplayScreen {
+    /// Parse a screen name ("primary" or "secondary").
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "primary" => Ok(Self::Primary),
+            "secondary" => Ok(Self::Secondary),
+            other => Err(format!(
+                "Unknown display screen '{}'. Expected 'primary' or 'secondary'",
+                other
+            )),
+        }
+    }
+
+    /// Resolve an optional screen argument, defaulting to the primary screen.
+    pub fn from_option(value: Option<&str>) -> Result<Self, String> {
+        value.map_or(Ok(Self::Primary), Self::parse)
+    }
+
+    /// Screen name as used in commands and persisted settings.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+        }
+    }
+
+    /// Tauri window label for this screen.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Primary => PLAYER_DISPLAY_LABEL,
+            Self::Secondary => SECONDARY_DISPLAY_LABEL,
+        }
+    }
+
+    /// Event name for this screen.
+    ///
+    /// Events are namespaced by window label (`player-display:map-update`,
+    /// `player-display-secondary:map-update`) because Tauri delivers
+    /// window emits to every global listener.
+    pub fn event(&self, name: &str) -> String {
+        format!("{}:{}", self.label(), name)
+    }
+
+    pub(crate) fn title(&self) -> &'static str {
+        match self {
+            Self::Primary => "Player Display",
+            Self::Secondary => "Player Display (Secondary)",
+        }
+    }
+}
+
+/// Get the open window for a display screen.
+pub(crate) fn display_window(
+    app: &AppHandle,
+    screen: Option<&str>,
+) -> Result<(WebviewWindow, DisplayScreen), String> {
+    let screen = DisplayScreen::from_option(screen)?;
+    let window = app
+        .get_webview_window(screen.label())
+        .ok_or_else(|| format!("{} window not open", screen.title()))?;
+    Ok((window, screen))
+}
+
+/// Resolve a screen that can receive display events.
+///
+/// A screen can receive events when its window is open or when the LAN display
+/// server is relaying events to remote screens.
+pub(crate) fn display_screen(
+    app: &AppHandle,
+    screen: Option<&str>,
+) -> Result<DisplayScreen, String> {
+    let screen = DisplayScreen::from_option(screen)?;
+    if screen_available(app, screen) {
+        Ok(screen)
+    } else {
+        Err(format!("{} window not open", screen.title()))
+    }
+}
+
+fn screen_available(app: &AppHandle, screen: DisplayScreen) -> bool {
+    app.get_webview_window(screen.label()).is_some()
+        || app
+            .state::<AppState>()
+            .lan_display
+            .lock()
+            .map(|lan| lan.is_running())
+            .unwrap_or(false)
+}
+
+/// Check if a player display is available, either as a window on this
+/// machine or through the LAN display server.
 #[tauri::command]
-pub fn is_player_display_open(app: AppHandle) -> bool {
-    app.get_webview_window(PLAYER_DISPLAY_LABEL).is_some()
+pub fn is_player_display_open(app: AppHandle, screen: Option<String>) -> bool {
+    DisplayScreen::from_option(screen.as_deref())
+        .map(|s| screen_available(&app, s))
+        .unwrap_or(false)
 }
 
-/// Open the player display window.
+/// Open a player display window.
+///
+/// If a display profile is assigned to the screen, the window is placed and
+/// configured according to that profile.
 #[tauri::command]
-pub fn open_player_display_window(app: AppHandle) -> Result<(), String> {
+pub fn open_player_display_window(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    screen: Option<String>,
+) -> Result<(), String> {
+    let screen = DisplayScreen::from_option(screen.as_deref())?;
+
     // Check if window already exists
-    if app.get_webview_window(PLAYER_DISPLAY_LABEL).is_some() {
+    if app.get_webview_window(screen.label()).is_some() {
         return Ok(());
     }
 
+    let url = match screen {
+        DisplayScreen::Primary => "/player-display".to_string(),
+        DisplayScreen::Secondary => "/player-display?screen=secondary".to_string(),
+    };
+
     // Create the window
-    WebviewWindowBuilder::new(&app, PLAYER_DISPLAY_LABEL, WebviewUrl::App("/player-display".into()))
-        .title("Player Display")
+    WebviewWindowBuilder::new(&app, screen.label(), WebviewUrl::App(url.into()))
+        .title(screen.title())
         .inner_size(1280.0, 720.0)
         .resizable(true)
         .visible(true)
         .build()
         .map_err(|e| format!("Failed to create player display window: {}", e))?;
 
+    // Apply the assigned layout profile, if any
+    let settings = state.settings().load().map_err(|e| e.to_string())?;
+    if let Some(profile) = settings.display.assigned_profile(screen.as_str()) {
+        apply_display_profile(&app, screen, profile)?;
+    }
+
     Ok(())
 }
 
-/// Close the player display window.
+/// Close a player display window.
 #[tauri::command]
-pub fn close_player_display_window(app: AppHandle) -> Result<(), String> {
-    if let Some(window) = app.get_webview_window(PLAYER_DISPLAY_LABEL) {
+pub fn close_player_display_window(app: AppHandle, screen: Option<String>) -> Result<(), String> {
+    let screen = DisplayScreen::from_option(screen.as_deref())?;
+    if let Some(window) = app.get_webview_window(screen.label()) {
         window
             .close()
             .map_err(|e| format!("Failed to close player display window: {}", e))?;
@@ -74,13 +245,14 @@ pub fn close_player_display_window(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
-/// Toggle fullscreen mode on the player display window.
+/// Toggle fullscreen mode on a player display window.
 /// Returns the new fullscreen state.
 #[tauri::command]
-pub fn toggle_player_display_fullscreen(app: AppHandle) -> Result<bool, String> {
-    let window = app
-        .get_webview_window(PLAYER_DISPLAY_LABEL)
-        .ok_or_else(|| "Player display window not open".to_string())?;
+pub fn toggle_player_display_fullscreen(
+    app: AppHandle,
+    screen: Option<String>,
+) -> Result<bool, String> {
+    let (window, _) = display_window(&app, screen.as_deref())?;
 
     let is_fullscreen = window
         .is_fullscreen()
@@ -105,10 +277,9 @@ pub fn send_map_to_display(
     ambient_light: Option<String>,
     map_width: Option<i32>,
     map_height: Option<i32>,
+    screen: Option<String>,
 ) -> Result<(), String> {
-    let window = app
-        .get_webview_window(PLAYER_DISPLAY_LABEL)
-        .ok_or_else(|| "Player display window not open".to_string())?;
+    let screen = display_screen(&app, screen.as_deref())?;
 
     let payload = MapUpdatePayload {
         map_id,
@@ -121,8 +292,7 @@ pub fn send_map_to_display(
         map_height,
     };
 
-    window
-        .emit("player-display:map-update", payload)
+    app.emit(&screen.event("map-update"), payload)
         .map_err(|e| format!("Failed to emit map update: {}", e))?;
 
     Ok(())
@@ -130,15 +300,18 @@ pub fn send_map_to_display(
 
 /// Update the viewport on the player display window (pan/zoom).
 #[tauri::command]
-pub fn update_display_viewport(app: AppHandle, x: f64, y: f64, zoom: f64) -> Result<(), String> {
-    let window = app
-        .get_webview_window(PLAYER_DISPLAY_LABEL)
-        .ok_or_else(|| "Player display window not open".to_string())?;
+pub fn update_display_viewport(
+    app: AppHandle,
+    x: f64,
+    y: f64,
+    zoom: f64,
+    screen: Option<String>,
+) -> Result<(), String> {
+    let screen = display_screen(&app, screen.as_deref())?;
 
     let payload = ViewportPayload { x, y, zoom };
 
-    window
-        .emit("player-display:viewport-update", payload)
+    app.emit(&screen.event("viewport-update"), payload)
         .map_err(|e| format!("Failed to emit viewport update: {}", e))?;
 
     Ok(())
@@ -146,16 +319,299 @@ pub fn update_display_viewport(app: AppHandle, x: f64, y: f64, zoom: f64) -> Res
 
 /// Toggle or set blackout mode on the player display.
 #[tauri::command]
-pub fn toggle_display_blackout(app: AppHandle, is_blackout: bool) -> Result<(), String> {
-    let window = app
-        .get_webview_window(PLAYER_DISPLAY_LABEL)
-        .ok_or_else(|| "Player display window not open".to_string())?;
+pub fn toggle_display_blackout(
+    app: AppHandle,
+    is_blackout: bool,
+    screen: Option<String>,
+) -> Result<(), String> {
+    let screen = display_screen(&app, screen.as_deref())?;
 
     let payload = BlackoutPayload { is_blackout };
 
-    window
-        .emit("player-display:blackout", payload)
+    app.emit(&screen.event("blackout"), payload)
         .map_err(|e| format!("Failed to emit blackout update: {}", e))?;
 
     Ok(())
 }
+
+/// Send a player-safe monster card (art, name, size) to the player display.
+///
+/// Uses the first fluff image for the monster, falling back to its token art.
+#[tauri::command]
+pub fn send_monster_to_display(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    monster_id: i32,
+    screen: Option<String>,
+) -> Result<(), String> {
+    let screen = display_screen(&app, screen.as_deref())?;
+
+    let mut db = state.connect()?;
+    let monster = catalog_dal::get_monster(&mut db, monster_id)
+        .map_err(|e| format!("Monster not found: {}", e))?;
+
+    let catalog_dir = state.paths.assets_dir.join("catalog");
+    let image_url = monster_image_candidates(&monster)
+        .iter()
+        .find_map(|path| read_image_data_url(&catalog_dir, path));
+
+    let payload = MonsterDisplayPayload {
+        size: monster.size_name().to_string(),
+        name: monster.name,
+        image_url,
+    };
+
+    app.emit(&screen.event("monster"), payload)
+        .map_err(|e| format!("Failed to emit monster card: {}", e))?;
+
+    Ok(())
+}
+
+/// Send an image handout (NPC portrait or item art) to the player display.
+///
+/// `image_path` is relative to the imported catalog images directory,
+/// e.g. `book/PHB/001-intro.webp` or `items/DMG/Bag of Holding.webp`.
+#[tauri::command]
+pub fn send_handout_to_display(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    kind: String,
+    image_path: String,
+    title: Option<String>,
+    screen: Option<String>,
+) -> Result<(), String> {
+    if !HANDOUT_KINDS.contains(&kind.as_str()) {
+        return Err(format!(
+            "Unknown handout kind '{}'. Expected one of: {}",
+            kind,
+            HANDOUT_KINDS.join(", ")
+        ));
+    }
+
+    let screen = display_screen(&app, screen.as_deref())?;
+
+    let catalog_dir = state.paths.assets_dir.join("catalog");
+    let image_url = read_image_data_url(&catalog_dir, &image_path)
+        .ok_or_else(|| format!("Image not found: {}", image_path))?;
+
+    let payload = HandoutPayload {
+        kind,
+        title,
+        image_url,
+    };
+
+    app.emit(&screen.event("handout"), payload)
+        .map_err(|e| format!("Failed to emit handout: {}", e))?;
+
+    Ok(())
+}
+
+/// Send one boxed read-aloud block of a document to the player display.
+///
+/// `block_index` counts the document's `:::read-aloud` blocks from zero.
+#[tauri::command]
+pub fn send_read_aloud_to_display(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    document_id: String,
+    block_index: usize,
+    screen: Option<String>,
+) -> Result<(), String> {
+    let screen = display_screen(&app, screen.as_deref())?;
+
+    let mut db = state.connect()?;
+    let document = DocumentService::new(&mut db)
+        .get(&document_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Document not found: {}", document_id))?;
+
+    let blocks = read_aloud_blocks(&document.content);
+    let block = blocks.get(block_index).ok_or_else(|| {
+        format!(
+            "Document has {} read-aloud block(s), no block {}",
+            blocks.len(),
+            block_index
+        )
+    })?;
+
+    let payload = ReadAloudPayload {
+        title: Some(document.title),
+        paragraphs: read_aloud_paragraphs(block),
+    };
+
+    app.emit(&screen.event("read-aloud"), payload)
+        .map_err(|e| format!("Failed to emit read-aloud text: {}", e))?;
+
+    Ok(())
+}
+
+/// Clear any monster card, handout, or read-aloud text currently shown on the
+/// player display.
+#[tauri::command]
+pub fn clear_display_handout(app: AppHandle, screen: Option<String>) -> Result<(), String> {
+    let screen = display_screen(&app, screen.as_deref())?;
+
+    app.emit(&screen.event("handout-clear"), ())
+        .map_err(|e| format!("Failed to emit handout clear: {}", e))?;
+
+    Ok(())
+}
+
+/// Split read-aloud markdown into display paragraphs.
+///
+/// Blank lines separate paragraphs and wrapped lines are joined. Emphasis
+/// markers are dropped since the display shows the passage as plain text.
+pub(crate) fn read_aloud_paragraphs(markdown: &str) -> Vec<String> {
+    markdown
+        .split("\n\n")
+        .map(|paragraph| {
+            paragraph
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .replace('*', "")
+        })
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect()
+}
+
+/// Candidate image paths for a monster, in order of preference.
+///
+/// Fluff art comes first since it is the full illustration; token art
+/// (`bestiary/tokens/{source}/{name}.{ext}`) is the fallback.
+fn monster_image_candidates(monster: &Monster) -> Vec<String> {
+    let mut candidates: Vec<String> = monster
+        .fluff
+        .as_deref()
+        .and_then(|f| serde_json::from_str::<serde_json::Value>(f).ok())
+        .and_then(|fluff| {
+            fluff.get("images").and_then(|v| v.as_array()).map(|images| {
+                images
+                    .iter()
+                    .filter_map(|img| img.pointer("/href/path").and_then(|p| p.as_str()))
+                    .map(String::from)
+                    .collect()
+            })
+        })
+        .unwrap_or_default();
+
+    candidates.extend(monster_token_candidates(&monster.source, &monster.name));
+    candidates
+}
+
+/// Candidate token art paths for a catalog monster, relative to the catalog
+/// images directory.
+pub(crate) fn monster_token_candidates(source: &str, name: &str) -> Vec<String> {
+    ["webp", "png", "jpg", "jpeg"]
+        .iter()
+        .map(|ext| format!("bestiary/tokens/{}/{}.{}", source, name, ext))
+        .collect()
+}
+
+/// Read an image under `base_dir` and encode it as a base64 data URL.
+///
+/// Returns `None` if the path escapes `base_dir`, does not exist, or cannot be read.
+pub(crate) fn read_image_data_url(base_dir: &Path, rel_path: &str) -> Option<String> {
+    let rel = Path::new(rel_path);
+    if rel
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return None;
+    }
+
+    let full_path = base_dir.join(rel);
+    let bytes = std::fs::read(&full_path).ok()?;
+
+    Some(format!(
+        "data:{};base64,{}",
+        image_mime_type(&full_path),
+        BASE64.encode(&bytes)
+    ))
+}
+
+/// MIME type of an image file, judged by its extension.
+pub(crate) fn image_mime_type(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .as_deref()
+    {
+        Some("webp") => "image/webp",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monster(fluff: Option<&str>) -> Monster {
+        Monster {
+            id: Some(1),
+            name: "Goblin".to_string(),
+            source: "MM".to_string(),
+            cr: Some("1/4".to_string()),
+            creature_type: Some("humanoid".to_string()),
+            size: Some("S".to_string()),
+            token_image_path: None,
+            data: "{}".to_string(),
+            fluff: fluff.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_monster_image_candidates_prefers_fluff() {
+        let m = monster(Some(
+            r#"{"images":[{"type":"image","href":{"type":"internal","path":"bestiary/MM/Goblin.webp"}}]}"#,
+        ));
+        let candidates = monster_image_candidates(&m);
+        assert_eq!(candidates[0], "bestiary/MM/Goblin.webp");
+        assert_eq!(candidates[1], "bestiary/tokens/MM/Goblin.webp");
+    }
+
+    #[test]
+    fn test_monster_image_candidates_without_fluff() {
+        let candidates = monster_image_candidates(&monster(None));
+        assert_eq!(candidates.len(), 4);
+        assert!(candidates.iter().all(|c| c.starts_with("bestiary/tokens/MM/Goblin.")));
+    }
+
+    #[test]
+    fn test_read_aloud_paragraphs() {
+        let paragraphs =
+            read_aloud_paragraphs("The door creaks\n*open*.\n\n\nCold air spills out.");
+        assert_eq!(
+            paragraphs,
+            vec!["The door creaks open.", "Cold air spills out."]
+        );
+    }
+
+    #[test]
+    fn test_display_screen_parse() {
+        assert_eq!(DisplayScreen::from_option(None).unwrap(), DisplayScreen::Primary);
+        assert_eq!(
+            DisplayScreen::from_option(Some("secondary")).unwrap(),
+            DisplayScreen::Secondary
+        );
+        assert!(DisplayScreen::parse("tertiary").is_err());
+        assert_eq!(
+            DisplayScreen::Secondary.event("map-update"),
+            "player-display-secondary:map-update"
+        );
+        assert_eq!(DisplayScreen::Primary.event("map-update"), "player-display:map-update");
+    }
+
+    #[test]
+    fn test_read_image_data_url_rejects_traversal() {
+        let dir = std::env::temp_dir();
+        assert!(read_image_data_url(&dir, "../etc/passwd").is_none());
+        assert!(read_image_data_url(&dir, "/etc/passwd").is_none());
+    }
+}
@@ -3,15 +3,24 @@
 //! Tauri commands for exporting character sheets to PDF.
 
 use base64::Engine;
+use diesel::SqliteConnection;
 use mimir_core::dal::campaign as dal;
 use mimir_core::dal::catalog as catalog_dal;
-use mimir_core::services::CharacterService;
+use mimir_core::models::campaign::Character;
+use mimir_core::services::{
+    companion_statblock, feat_speed_bonus, read_character_image, CharacterService,
+    CustomFieldService,
+};
 use mimir_print::sections::{
-    CharacterBattleCardSection, CharacterData, CharacterSection, ClassInfo, EquipmentCardsSection,
-    Proficiencies, ProficiencyEntry, SpellCardsSection, is_card_worthy,
+    CharacterBattleCardSection, CharacterData, CharacterSection, ClassInfo, CustomFieldEntry,
+    EquipmentCardsSection, GrimoireSection, GrimoireSpell, MonsterCardSection, Proficiencies,
+    ProficiencyEntry, SpellCardsSection, is_card_worthy,
+};
+use mimir_print::{
+    BookletLayout, CharacterSheetTemplate, DocumentBuilder, PrintProfile, PrintService, PrintState,
 };
-use mimir_print::{DocumentBuilder, PrintState};
 use serde_json::Value;
+use std::path::Path;
 use tauri::State;
 use tracing::{error, info};
 
@@ -22,7 +31,7 @@ use super::helpers::{
     enrich_inventory_item, max_spell_level_for_class, spell_slots_for_caster_level,
     spellcasting_ability_for_class,
 };
-use super::{ApiResponse, CharacterExportOptions, PrintResult};
+use super::{ApiResponse, CharacterExportOptions, GrimoireExportOptions, PrintResult};
 
 /// Map human-readable homebrew item_type (from frontend UI) to 5etools type codes.
 /// For weapons, inspects the JSON data to determine melee (M) vs ranged (R).
@@ -61,31 +70,52 @@ pub(super) fn homebrew_item_type_to_code(item_type: &str, data: &serde_json::Map
     }
 }
 
-/// Export a character to PDF
-#[tauri::command]
-pub fn export_character(
-    app_state: State<'_, AppState>,
-    print_state: State<'_, PrintState>,
-    character_id: String,
-    options: Option<CharacterExportOptions>,
-) -> ApiResponse<PrintResult> {
-    info!("Exporting character {} to PDF", character_id);
+/// Get a character's campaign custom fields for printing, skipping fields
+/// with no value.
+pub(super) fn custom_field_entries(db: &mut SqliteConnection, character_id: &str) -> Vec<CustomFieldEntry> {
+    match CustomFieldService::new(db).character_values(character_id) {
+        Ok(values) => values
+            .into_iter()
+            .filter_map(|v| {
+                v.display_value().map(|value| CustomFieldEntry {
+                    label: v.label,
+                    value,
+                })
+            })
+            .collect(),
+        Err(e) => {
+            error!("Failed to get character custom fields: {}", e);
+            vec![]
+        }
+    }
+}
 
-    // Get database connection
-    let mut db = match app_state.connect() {
-        Ok(db) => db,
-        Err(e) => return ApiResponse::err(e),
-    };
+/// Get a character's walking speed: their racial speed plus increases from
+/// feats such as Mobile.
+pub(super) fn character_speed(db: &mut SqliteConnection, character: &Character) -> i32 {
+    match feat_speed_bonus(db, &character.id) {
+        Ok(bonus) => character.speed + bonus,
+        Err(e) => {
+            error!("Failed to get feat speed bonus: {}", e);
+            character.speed
+        }
+    }
+}
 
-    // Get the character
-    let character = match CharacterService::new(&mut db).get(&character_id) {
-        Ok(Some(c)) => c,
-        Ok(None) => return ApiResponse::err(format!("Character not found: {}", character_id)),
-        Err(e) => return ApiResponse::err(format!("Failed to get character: {}", e)),
-    };
+/// Load a character's portrait from the assets directory, if it has one.
+pub(super) fn character_portrait(assets_dir: &Path, character: &Character) -> Option<Vec<u8>> {
+    read_character_image(assets_dir, character.portrait_path.as_deref()?)
+}
 
+/// Build the print data for a character: classes, inventory, proficiencies,
+/// portrait, and derived combat and spellcasting numbers.
+pub(super) fn build_character_data(
+    db: &mut SqliteConnection,
+    assets_dir: &Path,
+    character: &Character,
+) -> CharacterData {
     // Get character classes
-    let classes = match dal::list_character_classes(&mut db, &character_id) {
+    let classes = match dal::list_character_classes(db, &character.id) {
         Ok(c) => c,
         Err(e) => {
             error!("Failed to get character classes: {}", e);
@@ -94,7 +124,7 @@ pub fn export_character(
     };
 
     // Get character inventory
-    let inventory = match CharacterService::new(&mut db).get_inventory(&character_id) {
+    let inventory = match CharacterService::new(db).get_inventory(&character.id) {
         Ok(inv) => inv,
         Err(e) => {
             error!("Failed to get character inventory: {}", e);
@@ -103,7 +133,7 @@ pub fn export_character(
     };
 
     // Get character proficiencies
-    let proficiencies_raw = match dal::list_character_proficiencies(&mut db, &character_id) {
+    let proficiencies_raw = match dal::list_character_proficiencies(db, &character.id) {
         Ok(p) => p,
         Err(e) => {
             error!("Failed to get character proficiencies: {}", e);
@@ -179,11 +209,11 @@ pub fn export_character(
 
         inventory: inventory
             .iter()
-            .map(|i| enrich_inventory_item(&mut db, i, character.campaign_id.as_deref()))
+            .map(|i| enrich_inventory_item(db, i, character.campaign_id.as_deref()))
             .collect(),
 
         proficiencies,
-        speed: 30, // Default speed - could be looked up from race catalog
+        speed: character_speed(db, character),
         ac: 10,    // computed below
 
         hit_points_max: 0,   // computed below
@@ -192,6 +222,8 @@ pub fn export_character(
         spell_save_dc: None,
         spell_attack_bonus: None,
         spell_slots: vec![0; 9],
+        custom_fields: custom_field_entries(db, &character.id),
+        portrait: character_portrait(assets_dir, character),
     };
 
     // Compute AC from equipped armor
@@ -248,7 +280,34 @@ pub fn export_character(
         }
     }
 
-    let char_data = char_data;
+    char_data
+}
+
+/// Export a character to PDF
+#[tauri::command]
+pub fn export_character(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    character_id: String,
+    options: Option<CharacterExportOptions>,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting character {} to PDF", character_id);
+
+    // Get database connection
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    // Get the character
+    let character = match CharacterService::new(&mut db).get(&character_id) {
+        Ok(Some(c)) => c,
+        Ok(None) => return ApiResponse::err(format!("Character not found: {}", character_id)),
+        Err(e) => return ApiResponse::err(format!("Failed to get character: {}", e)),
+    };
+
+    let char_data = build_character_data(&mut db, &app_state.paths.assets_dir, &character);
 
     // Get export options with defaults
     let opts = options.unwrap_or_default();
@@ -256,6 +315,8 @@ pub fn export_character(
     let include_battle_card = opts.include_battle_card.unwrap_or(false);
     let include_spell_cards = opts.include_spell_cards.unwrap_or(false);
     let include_equipment_cards = opts.include_equipment_cards.unwrap_or(false);
+    let include_companions = opts.include_companions.unwrap_or(true);
+    let fillable_sheet = opts.fillable_sheet.unwrap_or(false);
 
     // Log received options
     info!("=== Character Export Options ===");
@@ -263,11 +324,14 @@ pub fn export_character(
     info!("  include_battle_card: {}", include_battle_card);
     info!("  include_spell_cards: {}", include_spell_cards);
     info!("  include_equipment_cards: {}", include_equipment_cards);
+    info!("  include_companions: {}", include_companions);
+    info!("  fillable_sheet: {}", fillable_sheet);
     info!("================================");
 
     // Build PDF with selected sections
     let mut builder = DocumentBuilder::new(&character.name)
         .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
         .with_title_page(false)
         .with_toc(false);
 
@@ -276,10 +340,41 @@ pub fn export_character(
     // Add compact sheet (default)
     if include_compact {
         info!("[SECTION] Adding CharacterSection (compact sheet)");
-        builder = builder.append(CharacterSection::new(char_data.clone()));
+        builder = builder
+            .append(CharacterSection::new(char_data.clone()).with_fillable(fillable_sheet));
         has_content = true;
     }
 
+    // Add companion addendum - stat blocks for beast companions, familiars, and sidekicks
+    if include_companions {
+        let companions =
+            dal::list_character_companions(&mut db, &character_id).unwrap_or_else(|e| {
+                error!("  Failed to load companions: {}", e);
+                vec![]
+            });
+        let statblocks: Vec<Value> = companions
+            .iter()
+            .filter_map(|companion| match companion_statblock(&mut db, companion) {
+                Ok(statblock) => Some(statblock),
+                Err(e) => {
+                    error!(
+                        "  Failed to build stat block for '{}': {}",
+                        companion.name, e
+                    );
+                    None
+                }
+            })
+            .collect();
+        if !statblocks.is_empty() {
+            info!(
+                "[SECTION] Adding companion addendum with {} stat blocks",
+                statblocks.len()
+            );
+            builder = builder.append(MonsterCardSection::new(statblocks));
+            has_content = true;
+        }
+    }
+
     // Add battle card
     if include_battle_card {
         info!("[SECTION] Adding CharacterBattleCardSection");
@@ -615,7 +710,11 @@ pub fn export_character(
     // Build PDF
     info!("=== Building PDF ===");
     info!("  has_content: {}", has_content);
-    let pdf_result = builder.to_pdf();
+    let pdf_result = if fillable_sheet && include_compact {
+        builder.to_fillable_pdf()
+    } else {
+        builder.to_pdf()
+    };
 
     match pdf_result {
         Ok(pdf_bytes) => {
@@ -639,6 +738,221 @@ pub fn export_character(
     }
 }
 
+/// Export battle cards for every player character in a campaign
+///
+/// Each character gets a half-page battle card followed by a tracking card
+/// with checkbox tracks for spell slots, hit dice, ammunition, and class
+/// resources, for laminating and dry-erase use at the table.
+#[tauri::command]
+pub fn export_battle_cards(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    campaign_id: String,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting battle cards for campaign {}", campaign_id);
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let characters = match CharacterService::new(&mut db).list_pcs(&campaign_id) {
+        Ok(c) => c,
+        Err(e) => return ApiResponse::err(format!("Failed to list characters: {}", e)),
+    };
+    if characters.is_empty() {
+        return ApiResponse::err("Campaign has no player characters");
+    }
+
+    let char_data: Vec<CharacterData> = characters
+        .iter()
+        .map(|c| build_character_data(&mut db, &app_state.paths.assets_dir, c))
+        .collect();
+    info!("  Rendering battle cards for {} characters", char_data.len());
+
+    let builder = DocumentBuilder::new("Battle Cards")
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false)
+        .append(CharacterBattleCardSection::new(char_data).with_tracking(true));
+
+    match builder.to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!("Battle cards PDF generated successfully ({} bytes)", size_bytes);
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate battle cards PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
+
+/// Export a wizard's spellbook as a grimoire booklet
+///
+/// The spellbook holds every Wizard spell on the character plus any spell
+/// marked as scribed. Spells are grouped into per-school chapters, followed
+/// by a scribing cost ledger and blank pages for future spells. The grimoire
+/// is always imposed as a booklet for folding and saddle-stitching.
+#[tauri::command]
+pub fn export_grimoire(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    character_id: String,
+    options: Option<GrimoireExportOptions>,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting grimoire for character {}", character_id);
+
+    let opts = options.unwrap_or_default();
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let character = match CharacterService::new(&mut db).get(&character_id) {
+        Ok(c) => c,
+        Err(e) => return ApiResponse::err(format!("Failed to get character: {}", e)),
+    };
+
+    let character_spells = match dal::list_character_spells(&mut db, &character_id) {
+        Ok(spells) => spells,
+        Err(e) => return ApiResponse::err(format!("Failed to get character spells: {}", e)),
+    };
+
+    let spellbook: Vec<_> = character_spells
+        .into_iter()
+        .filter(|s| s.source_class.eq_ignore_ascii_case("wizard") || s.is_scribed())
+        .collect();
+    if spellbook.is_empty() {
+        return ApiResponse::err("Character has no spells in their spellbook");
+    }
+
+    let mut spells = Vec::with_capacity(spellbook.len());
+    for entry in &spellbook {
+        let data = if entry.spell_source == "HB" {
+            let Some(ref campaign_id) = character.campaign_id else {
+                error!(
+                    "  Homebrew spell '{}' on a character without a campaign",
+                    entry.spell_name
+                );
+                continue;
+            };
+            match dal::get_campaign_homebrew_spell_by_name(&mut db, campaign_id, &entry.spell_name)
+            {
+                Ok(Some(hb_spell)) => match serde_json::from_str::<Value>(&hb_spell.data) {
+                    Ok(mut data) => {
+                        if let Some(obj) = data.as_object_mut() {
+                            obj.insert("name".to_string(), Value::String(hb_spell.name.clone()));
+                            obj.insert("source".to_string(), Value::String("HB".to_string()));
+                            obj.insert(
+                                "level".to_string(),
+                                Value::Number(hb_spell.level.unwrap_or(0).into()),
+                            );
+                            if let Some(ref school) = hb_spell.school {
+                                obj.insert("school".to_string(), Value::String(school.clone()));
+                            }
+                            obj.insert("homebrew".to_string(), Value::Bool(true));
+                        }
+                        data
+                    }
+                    Err(e) => {
+                        error!(
+                            "  Failed to parse homebrew spell '{}': {}",
+                            hb_spell.name, e
+                        );
+                        continue;
+                    }
+                },
+                Ok(None) => {
+                    error!("  Homebrew spell '{}' not found", entry.spell_name);
+                    continue;
+                }
+                Err(e) => {
+                    error!(
+                        "  Error looking up homebrew spell '{}': {}",
+                        entry.spell_name, e
+                    );
+                    continue;
+                }
+            }
+        } else {
+            match catalog_dal::get_spell_by_name(&mut db, &entry.spell_name, &entry.spell_source) {
+                Ok(Some(spell)) => match spell.parse_data() {
+                    Ok(data) => data,
+                    Err(e) => {
+                        error!("  Failed to parse spell '{}': {}", spell.name, e);
+                        continue;
+                    }
+                },
+                Ok(None) => {
+                    error!(
+                        "  Spell '{}' ({}) not found in catalog",
+                        entry.spell_name, entry.spell_source
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    error!("  Error looking up spell '{}': {}", entry.spell_name, e);
+                    continue;
+                }
+            }
+        };
+
+        spells.push(GrimoireSpell {
+            data,
+            scribed: entry.is_scribed(),
+        });
+    }
+    info!(
+        "  Rendering grimoire with {} of {} spellbook spells",
+        spells.len(),
+        spellbook.len()
+    );
+
+    let print_settings = app_state.settings().load().unwrap_or_default().print;
+    let builder = DocumentBuilder::new(format!("The Grimoire of {}", character.name))
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(print_settings.title_page)
+        .with_toc(true)
+        .with_page_numbers(print_settings.page_numbers)
+        .with_booklet(Some(BookletLayout {
+            sheets_per_signature: print_settings
+                .booklet_sheets_per_signature
+                .map(|sheets| sheets as usize),
+        }))
+        .append(GrimoireSection::new(spells).with_blank_pages(opts.blank_pages.unwrap_or(4)));
+
+    match builder.to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!("Grimoire PDF generated successfully ({} bytes)", size_bytes);
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate grimoire PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -769,17 +1083,59 @@ mod tests {
     }
 }
 
-/// Generate character sheet (legacy API)
+/// Generate a character sheet from one of the built-in layouts
+///
+/// `template` is "minimalist", "classic" (the default), or "npc-card".
 #[tauri::command]
 pub fn generate_character_sheet(
-    _app_state: State<'_, AppState>,
-    _print_state: State<'_, PrintState>,
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
     character_id: String,
     template: Option<String>,
-    _include_spell_cards: Option<bool>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
-    ApiResponse::err(format!(
-        "Character sheet generation not yet implemented (character_id: {}, template: {:?})",
-        character_id, template
-    ))
+    let layout = match template.as_deref().map(str::parse) {
+        None => CharacterSheetTemplate::default(),
+        Some(Ok(layout)) => layout,
+        Some(Err(e)) => return ApiResponse::err(e.to_string()),
+    };
+    info!("Generating {} character sheet for {}", layout, character_id);
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let character = match CharacterService::new(&mut db).get(&character_id) {
+        Ok(Some(c)) => c,
+        Ok(None) => return ApiResponse::err(format!("Character not found: {}", character_id)),
+        Err(e) => return ApiResponse::err(format!("Failed to get character: {}", e)),
+    };
+
+    let char_data = build_character_data(&mut db, &app_state.paths.assets_dir, &character);
+    let data = match serde_json::to_value(&char_data) {
+        Ok(data) => data,
+        Err(e) => return ApiResponse::err(format!("Failed to serialize character: {}", e)),
+    };
+
+    let service = PrintService::new(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default());
+
+    match service.render_to_pdf(layout.template_path(), data) {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!("Character sheet PDF generated ({} bytes)", size_bytes);
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate character sheet PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
 }
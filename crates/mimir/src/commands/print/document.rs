@@ -5,19 +5,34 @@
 use base64::Engine;
 use mimir_core::dal::campaign as dal;
 use mimir_core::dal::catalog as catalog_dal;
-use mimir_core::services::{CampaignService, CharacterService, DocumentService, MapService};
+use mimir_core::models::campaign::{Map, MapPoi, MapTrap, Module, ModuleMonster};
+use mimir_core::models::CharacterInventory;
+use mimir_core::services::{
+    AssetService, CampaignService, CharacterService, DocumentService, LocationKeyService,
+    MapRegionService, MapService, MonsterEmbedService, PrintSettings, RumorService, SafetyTools,
+    TokenService,
+};
 use mimir_print::sections::{
-    CharacterData, CharacterSection, ClassInfo, CutoutToken, MapPreview, MonsterCardSection,
-    Proficiencies, ProficiencyEntry, TiledMapSection, TokenCutoutSection,
+    ChapterDivider, CharacterData, CharacterSection, ClassInfo, CutoutToken, MapLabel, MapPreview,
+    MonsterCardSection, Proficiencies, ProficiencyEntry, TiledMapSection, TokenCutoutSection,
+};
+use mimir_print::{
+    find_map_region_refs, find_monster_embeds, BookletLayout, DocumentBuilder, DocumentEmbeds,
+    EncounterRegion, EncounterTrap, MarkdownSection, PrintProfile, PrintState, TitlePage,
 };
-use mimir_print::{DocumentBuilder, MarkdownSection, PrintState};
 use serde_json::Value;
+use std::path::Path;
 use tauri::State;
 use tracing::{error, info};
 
 use crate::state::AppState;
 
-use super::helpers::{compute_ac, compute_hit_die_string, compute_hp_max, enrich_inventory_item};
+use super::character::{character_portrait, character_speed, custom_field_entries};
+use super::helpers::{compute_ac, compute_hit_die_string, compute_hp_max};
+use super::lookups::{
+    parse_homebrew_monster_data, size_name, ExportLookups, ExportReferences, TRAP_SOURCES,
+};
+use super::timeline::timeline_section;
 use super::{ApiResponse, CampaignExportOptions, ModuleExportOptions, PrintResult, PrintTemplateInfo};
 
 /// Look up monster data from catalog or homebrew, returning parsed JSON.
@@ -65,22 +80,6 @@ fn lookup_homebrew_monster_by_id(
     }
 }
 
-fn parse_homebrew_monster_data(hb_monster: mimir_core::models::campaign::CampaignHomebrewMonster) -> Option<Value> {
-    match serde_json::from_str::<Value>(&hb_monster.data) {
-        Ok(mut data) => {
-            if let Some(obj) = data.as_object_mut() {
-                obj.insert("name".to_string(), Value::String(hb_monster.name));
-                obj.insert("source".to_string(), Value::String("Homebrew".to_string()));
-            }
-            Some(data)
-        }
-        Err(e) => {
-            error!("Failed to parse homebrew monster data: {}", e);
-            None
-        }
-    }
-}
-
 fn lookup_catalog_monster(
     db: &mut diesel::SqliteConnection,
     monster_name: &str,
@@ -107,6 +106,75 @@ fn lookup_catalog_monster(
     }
 }
 
+/// Build a document section, resolving its `{{monster: ...}}` embeds against
+/// the campaign's enabled sources and homebrew, and its `{{map-region: ...}}`
+/// references to encounter boxes listing the tokens and traps in each region.
+fn document_section(
+    db: &mut diesel::SqliteConnection,
+    app_dir: &Path,
+    campaign_id: &str,
+    content: &str,
+) -> mimir_print::Result<MarkdownSection> {
+    let mut embeds = DocumentEmbeds::default();
+    let mut service = MonsterEmbedService::new(db);
+    for embed in find_monster_embeds(content) {
+        let resolved = match service.resolve(campaign_id, &embed.name, embed.source.as_deref()) {
+            Ok(resolved) => {
+                let message = resolved.fallback_message();
+                resolved.data.ok_or_else(|| message.unwrap_or_default())
+            }
+            Err(e) => {
+                error!("Failed to resolve monster embed {}: {}", embed.name, e);
+                Err(format!("Stat block unavailable: {}", e))
+            }
+        };
+        embeds.monsters.insert(embed, resolved);
+    }
+
+    for reference in find_map_region_refs(content) {
+        let resolved =
+            MapRegionService::new(db).resolve(campaign_id, &reference.map, &reference.region);
+        let resolved = resolved
+            .and_then(|resolved| {
+                let tokens = TokenService::new(db, app_dir)
+                    .list(&resolved.map_id)?
+                    .into_iter()
+                    .filter(|t| resolved.token_ids.contains(&t.id))
+                    .map(|t| t.name)
+                    .collect();
+                let traps = dal::list_map_traps(db, &resolved.map_id)?
+                    .into_iter()
+                    .filter(|t| resolved.trap_ids.contains(&t.id))
+                    .map(|t| EncounterTrap {
+                        name: t.name,
+                        dc: t.dc,
+                        trigger: t.trigger_description,
+                        effect: t.effect_description,
+                        description: t.description,
+                    })
+                    .collect();
+                Ok(EncounterRegion {
+                    map: resolved.map_name,
+                    region: resolved.region.name,
+                    width: resolved.region.width,
+                    height: resolved.region.height,
+                    tokens,
+                    traps,
+                })
+            })
+            .map_err(|e| {
+                error!(
+                    "Failed to resolve map region {} | {}: {}",
+                    reference.map, reference.region, e
+                );
+                format!("Map region unavailable: {}", e)
+            });
+        embeds.map_regions.insert(reference, resolved);
+    }
+
+    MarkdownSection::from_markdown_with_embeds(content, &embeds)
+}
+
 /// Resolve monster data for a module monster, handling both catalog and homebrew paths.
 fn resolve_module_monster_data(
     db: &mut diesel::SqliteConnection,
@@ -134,11 +202,7 @@ fn resolve_monster_size(
         match dal::get_campaign_homebrew_monster(db, hb_id) {
             Ok(hb) => {
                 serde_json::from_str::<Value>(&hb.data).ok()
-                    .and_then(|d| d.get("size").and_then(|s| s.as_str()).map(|s| match s {
-                        "T" => "Tiny", "S" => "Small", "M" => "Medium",
-                        "L" => "Large", "H" => "Huge", "G" => "Gargantuan",
-                        other => other,
-                    }.to_string()))
+                    .and_then(|d| d.get("size").and_then(|s| s.as_str()).map(size_name))
                     .unwrap_or_else(|| "Medium".to_string())
             }
             _ => "Medium".to_string(),
@@ -149,11 +213,7 @@ fn resolve_monster_size(
             match dal::get_campaign_homebrew_monster_by_name(db, campaign_id, name) {
                 Ok(Some(hb)) => {
                     serde_json::from_str::<Value>(&hb.data).ok()
-                        .and_then(|d| d.get("size").and_then(|s| s.as_str()).map(|s| match s {
-                            "T" => "Tiny", "S" => "Small", "M" => "Medium",
-                            "L" => "Large", "H" => "Huge", "G" => "Gargantuan",
-                            other => other,
-                        }.to_string()))
+                        .and_then(|d| d.get("size").and_then(|s| s.as_str()).map(size_name))
                         .unwrap_or_else(|| "Medium".to_string())
                 }
                 _ => "Medium".to_string(),
@@ -202,6 +262,46 @@ fn load_monster_token_image(
     None
 }
 
+/// Booklet imposition from the print settings, if booklet mode is on.
+fn booklet_layout(settings: &PrintSettings) -> Option<BookletLayout> {
+    settings.booklet.then(|| BookletLayout {
+        sheets_per_signature: settings
+            .booklet_sheets_per_signature
+            .map(|sheets| sheets as usize),
+    })
+}
+
+/// Read an uploaded image asset for use as print art.
+fn load_asset_image(
+    db: &mut diesel::SqliteConnection,
+    app_dir: &Path,
+    asset_id: &str,
+) -> Option<Vec<u8>> {
+    let mut service = AssetService::new(db, app_dir);
+    let asset = match service.get(asset_id) {
+        Ok(Some(asset)) => asset,
+        Ok(None) => {
+            error!("Print art asset not found: {}", asset_id);
+            return None;
+        }
+        Err(e) => {
+            error!("Failed to look up print art asset {}: {}", asset_id, e);
+            return None;
+        }
+    };
+    if !asset.mime_type.starts_with("image/") {
+        error!("Print art asset {} is not an image", asset_id);
+        return None;
+    }
+    match service.read_file(&asset) {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            error!("Failed to read print art asset {}: {}", asset_id, e);
+            None
+        }
+    }
+}
+
 /// List available print templates
 #[tauri::command]
 pub fn list_print_templates(
@@ -232,6 +332,7 @@ pub fn export_campaign_document(
     app_state: State<'_, AppState>,
     print_state: State<'_, PrintState>,
     document_id: String,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("Exporting campaign document {} to PDF", document_id);
 
@@ -258,13 +359,19 @@ pub fn export_campaign_document(
     };
 
     // Build the PDF using markdown section
-    let section = match MarkdownSection::from_markdown(&document.content) {
+    let section = match document_section(
+        &mut db,
+        &app_state.paths.app_dir,
+        &document.campaign_id,
+        &document.content,
+    ) {
         Ok(s) => s.with_title(&document.title),
         Err(e) => return ApiResponse::err(format!("Failed to parse markdown: {}", e)),
     };
 
     let pdf_result = DocumentBuilder::new(&campaign.name)
         .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
         .with_title_page(false)
         .with_toc(false)
         .append(section)
@@ -292,6 +399,120 @@ pub fn export_campaign_document(
     }
 }
 
+/// Export a map's location key to PDF
+///
+/// Regenerates the key from the map's POIs, then prints the map with each
+/// POI's number circled over it, followed by the numbered descriptions.
+#[tauri::command]
+pub fn export_location_key(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    map_id: String,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting location key for map {} to PDF", map_id);
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let key = match LocationKeyService::new(&mut db).generate(&map_id) {
+        Ok(key) => key,
+        Err(e) => return ApiResponse::err(format!("Failed to generate location key: {}", e)),
+    };
+
+    let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
+    let map = match map_service.get(&map_id) {
+        Ok(Some(m)) => m,
+        Ok(None) => return ApiResponse::err(format!("Map not found: {}", map_id)),
+        Err(e) => return ApiResponse::err(format!("Failed to get map: {}", e)),
+    };
+
+    // The keyed map image, when the UVTT file carries one
+    let mut preview = None;
+    if let Ok(uvtt_bytes) = map_service.read_uvtt_file(&map) {
+        if let Ok(uvtt_json) = serde_json::from_slice::<Value>(&uvtt_bytes) {
+            let map_size = &uvtt_json["resolution"]["map_size"];
+            let size_x = map_size["x"].as_f64().filter(|x| *x > 0.0).unwrap_or(25.0);
+            let size_y = map_size["y"].as_f64().filter(|y| *y > 0.0).unwrap_or(25.0);
+
+            if let Some(image_base64) = uvtt_json.get("image").and_then(|v| v.as_str()) {
+                match base64::engine::general_purpose::STANDARD.decode(image_base64) {
+                    Ok(image_bytes) => {
+                        let labels = key
+                            .pois
+                            .iter()
+                            .enumerate()
+                            .map(|(i, poi)| MapLabel {
+                                text: (i + 1).to_string(),
+                                x: (poi.grid_x as f64 + 0.5) / size_x,
+                                y: (poi.grid_y as f64 + 0.5) / size_y,
+                            })
+                            .collect();
+                        preview = Some(
+                            MapPreview::from_rendered(map.name.clone(), image_bytes)
+                                .with_labels(labels),
+                        );
+                    }
+                    Err(e) => error!("Failed to decode map image for {}: {}", map.name, e),
+                }
+            }
+        }
+    }
+
+    let section = match document_section(
+        &mut db,
+        &app_state.paths.app_dir,
+        &key.document.campaign_id,
+        &key.document.content,
+    ) {
+        Ok(s) => s.with_title(&key.document.title),
+        Err(e) => return ApiResponse::err(format!("Failed to parse markdown: {}", e)),
+    };
+
+    let mut builder = DocumentBuilder::new(&key.document.title)
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false);
+    if let Some(preview) = preview {
+        builder = builder.append(preview);
+    }
+
+    match builder.append(section).to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!(
+                "Location key PDF generated successfully ({} bytes)",
+                size_bytes
+            );
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate location key PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
+
+/// A module's content gathered up front for the campaign export.
+struct ModuleContent {
+    module: Module,
+    monsters: Vec<ModuleMonster>,
+    maps: Vec<Map>,
+    /// Traps on every map in the module
+    traps: Vec<MapTrap>,
+    /// Points of interest by map name, for maps that have any
+    pois: Vec<(String, Vec<MapPoi>)>,
+}
+
 /// Export all campaign documents to PDF
 #[tauri::command]
 pub fn export_campaign_documents(
@@ -299,6 +520,7 @@ pub fn export_campaign_documents(
     print_state: State<'_, PrintState>,
     campaign_id: String,
     options: Option<CampaignExportOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     use mimir_core::services::ModuleService;
 
@@ -311,6 +533,7 @@ pub fn export_campaign_documents(
     info!("  include_campaign_docs: {:?}", opts.include_campaign_docs);
     info!("  include_module_content: {:?}", opts.include_module_content);
     info!("  include_npcs: {:?}", opts.include_npcs);
+    info!("  include_safety_tools: {:?}", opts.include_safety_tools);
     info!(
         "  include_module_map_previews: {:?}",
         opts.include_module_map_previews
@@ -328,6 +551,13 @@ pub fn export_campaign_documents(
         "  include_campaign_tiled_maps: {:?}",
         opts.include_campaign_tiled_maps
     );
+    info!("  cover_art_asset_id: {:?}", opts.cover_art_asset_id);
+    info!("  logo_asset_id: {:?}", opts.logo_asset_id);
+    info!(
+        "  include_chapter_dividers: {:?}",
+        opts.include_chapter_dividers
+    );
+    info!("  include_timeline: {:?}", opts.include_timeline);
     info!("================================");
 
     // Get database connection
@@ -344,13 +574,147 @@ pub fn export_campaign_documents(
     };
 
     // Build combined PDF
+    let print_settings = app_state.settings().load().unwrap_or_default().print;
+    let app_dir = &app_state.paths.app_dir;
+    let title_page = TitlePage {
+        logo: opts
+            .logo_asset_id
+            .as_deref()
+            .and_then(|id| load_asset_image(&mut db, app_dir, id)),
+        dm_name: opts.dm_name.clone(),
+        date_range: opts.date_range.clone(),
+    };
     let mut builder = DocumentBuilder::new(&campaign.name)
         .with_templates_root(print_state.templates_dir.clone())
-        .with_title_page(true)
-        .with_toc(true);
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(print_settings.title_page)
+        .with_title_page_details(title_page)
+        .with_toc(print_settings.table_of_contents)
+        .with_page_numbers(print_settings.page_numbers)
+        .with_booklet(booklet_layout(&print_settings))
+        .with_chapter_dividers(opts.include_chapter_dividers.unwrap_or(false));
+    if let Some(ref asset_id) = opts.cover_art_asset_id {
+        if let Some(art) = load_asset_image(&mut db, app_dir, asset_id) {
+            builder = builder.with_cover_art(art);
+        }
+    }
 
     let mut has_content = false;
 
+    let include_module_content = opts.include_module_content.unwrap_or(false);
+    let include_npcs = opts.include_npcs.unwrap_or(false);
+    let include_module_map_previews = opts.include_module_map_previews.unwrap_or(false);
+    let include_module_tiled_maps = opts.include_module_tiled_maps.unwrap_or(false);
+    let include_token_cutouts = opts.include_token_cutouts.unwrap_or(false);
+
+    // Gather modules, their maps and monsters, and NPCs once up front. The
+    // sections below share this data, and it tells us which catalog entries
+    // to batch-load.
+    let needs_modules = include_module_content
+        || include_module_map_previews
+        || include_module_tiled_maps
+        || include_token_cutouts;
+    let modules = if needs_modules {
+        match ModuleService::new(&mut db).list_for_campaign(&campaign_id) {
+            Ok(m) => {
+                info!("  Found {} modules", m.len());
+                m
+            }
+            Err(e) => {
+                error!("  Failed to list modules: {}", e);
+                vec![]
+            }
+        }
+    } else {
+        vec![]
+    };
+
+    let mut module_contents: Vec<ModuleContent> = Vec::new();
+    for module in modules {
+        let monsters = if include_module_content || include_token_cutouts {
+            dal::list_module_monsters(&mut db, &module.id).unwrap_or_else(|e| {
+                error!("  Failed to list module {} monsters: {}", module.id, e);
+                vec![]
+            })
+        } else {
+            vec![]
+        };
+
+        let maps = if include_module_content
+            || include_module_map_previews
+            || include_module_tiled_maps
+        {
+            MapService::new(&mut db, &app_state.paths.app_dir)
+                .list_for_module(&module.id)
+                .unwrap_or_else(|e| {
+                    error!("  Failed to list module {} maps: {}", module.id, e);
+                    vec![]
+                })
+        } else {
+            vec![]
+        };
+
+        let mut traps = Vec::new();
+        let mut pois = Vec::new();
+        if include_module_content {
+            for map in &maps {
+                if let Ok(map_traps) = dal::list_map_traps(&mut db, &map.id) {
+                    traps.extend(map_traps);
+                }
+                if let Ok(map_pois) = dal::list_map_pois(&mut db, &map.id) {
+                    if !map_pois.is_empty() {
+                        pois.push((map.name.clone(), map_pois));
+                    }
+                }
+            }
+        }
+
+        module_contents.push(ModuleContent {
+            module,
+            monsters,
+            maps,
+            traps,
+            pois,
+        });
+    }
+
+    let npcs = if include_npcs {
+        match CharacterService::new(&mut db).list_npcs(&campaign_id) {
+            Ok(n) => {
+                info!("  Found {} NPCs", n.len());
+                n
+            }
+            Err(e) => {
+                error!("  Failed to list NPCs: {}", e);
+                vec![]
+            }
+        }
+    } else {
+        vec![]
+    };
+    let npc_inventories: Vec<Vec<CharacterInventory>> = npcs
+        .iter()
+        .map(|npc| {
+            CharacterService::new(&mut db)
+                .get_inventory(&npc.id)
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut refs = ExportReferences::default();
+    for content in &module_contents {
+        for mm in &content.monsters {
+            refs.add_monster(mm);
+        }
+        for trap in &content.traps {
+            refs.add_map_trap(trap);
+        }
+    }
+    for item in npc_inventories.iter().flatten() {
+        refs.add_item(item);
+    }
+    let lookups = ExportLookups::load(&mut db, &campaign_id, &refs);
+
     // 1. Campaign-level documents
     if opts.include_campaign_docs.unwrap_or(true) {
         info!("[SECTION] Campaign documents requested");
@@ -366,7 +730,12 @@ pub fn export_campaign_documents(
         };
 
         for doc in documents {
-            match MarkdownSection::from_markdown(&doc.content) {
+            match document_section(
+                &mut db,
+                &app_state.paths.app_dir,
+                &doc.campaign_id,
+                &doc.content,
+            ) {
                 Ok(section) => {
                     info!("  Adding document: {}", doc.title);
                     builder = builder.append(section.with_title(&doc.title));
@@ -381,23 +750,59 @@ pub fn export_campaign_documents(
         info!("[SECTION] Campaign documents NOT requested");
     }
 
-    // 2. Module content (documents + monsters)
-    if opts.include_module_content.unwrap_or(false) {
-        info!("[SECTION] Module content requested");
-        let modules = match ModuleService::new(&mut db).list_for_campaign(&campaign_id) {
-            Ok(m) => {
-                info!("  Found {} modules", m.len());
-                m
-            }
-            Err(e) => {
-                error!("  Failed to list modules: {}", e);
-                vec![]
+    // 2. Safety tools (DM only - includes the DM's private notes)
+    if opts.include_safety_tools.unwrap_or(false) {
+        info!("[SECTION] Safety tools requested");
+        match dal::get_campaign_safety_tools(&mut db, &campaign_id) {
+            Ok(Some(model)) => match SafetyTools::from_model(&model) {
+                Ok(tools) => match MarkdownSection::from_markdown(&tools.to_markdown(true)) {
+                    Ok(section) => {
+                        builder = builder.append(section.with_title("Safety Tools (DM Only)"));
+                        has_content = true;
+                    }
+                    Err(e) => error!("  Failed to render safety tools: {}", e),
+                },
+                Err(e) => error!("  Failed to read safety tools: {}", e),
+            },
+            Ok(None) => info!("  No safety tools configured"),
+            Err(e) => error!("  Failed to load safety tools: {}", e),
+        }
+    }
+
+    // 2b. Timeline chart (every party's timeline events in swimlanes)
+    if opts.include_timeline.unwrap_or(false) {
+        info!("[SECTION] Timeline requested");
+        let grouping = opts.timeline_grouping.unwrap_or_default();
+        match timeline_section(&mut db, &campaign_id, &campaign.name, grouping) {
+            Ok(Some(section)) => {
+                builder = builder.append(section);
+                has_content = true;
             }
-        };
+            Ok(None) => info!("  No timeline events recorded"),
+            Err(e) => error!("  {}", e),
+        }
+    }
+
+    // 3. Module content (documents + monsters)
+    if include_module_content {
+        info!("[SECTION] Module content requested");
 
-        for module in modules {
+        for content in &module_contents {
+            let module = &content.module;
             info!("  Processing module: {}", module.name);
 
+            // Chapter divider (printed only if dividers are turned on)
+            let mut divider = ChapterDivider::new(&module.name)
+                .with_summary(module.description.as_deref().unwrap_or(""));
+            let art_id = opts
+                .divider_art
+                .as_ref()
+                .and_then(|art| art.get(&module.id));
+            if let Some(art) = art_id.and_then(|id| load_asset_image(&mut db, app_dir, id)) {
+                divider = divider.with_art(art);
+            }
+            builder = builder.append(divider);
+
             // Module documents
             let module_docs = match DocumentService::new(&mut db).list_for_module(&module.id) {
                 Ok(docs) => {
@@ -411,7 +816,12 @@ pub fn export_campaign_documents(
             };
 
             for doc in module_docs {
-                match MarkdownSection::from_markdown(&doc.content) {
+                match document_section(
+                    &mut db,
+                    &app_state.paths.app_dir,
+                    &doc.campaign_id,
+                    &doc.content,
+                ) {
                     Ok(section) => {
                         let title = format!("{}: {}", module.name, doc.title);
                         info!("    Adding document: {}", title);
@@ -425,46 +835,29 @@ pub fn export_campaign_documents(
             }
 
             // Module monsters as cards
-            let module_monsters = match dal::list_module_monsters(&mut db, &module.id) {
-                Ok(monsters) => {
-                    info!("    Found {} monsters", monsters.len());
-                    monsters
-                }
-                Err(e) => {
-                    error!("    Failed to list module {} monsters: {}", module.id, e);
-                    vec![]
-                }
-            };
-
-            if !module_monsters.is_empty() {
-                let mut monster_data: Vec<Value> = Vec::new();
-                for mm in &module_monsters {
-                    if let Some(mut data) = resolve_module_monster_data(
-                        &mut db, mm, &campaign_id,
-                    ) {
-                        if let Some(ref display_name) = mm.display_name {
-                            if let Some(obj) = data.as_object_mut() {
-                                obj.insert(
-                                    "name".to_string(),
-                                    Value::String(display_name.clone()),
-                                );
-                            }
-                        }
-                        for _ in 0..mm.quantity {
-                            monster_data.push(data.clone());
+            info!("    Found {} monsters", content.monsters.len());
+            let mut monster_data: Vec<Value> = Vec::new();
+            for mm in &content.monsters {
+                if let Some(mut data) = lookups.module_monster_data(mm) {
+                    if let Some(ref display_name) = mm.display_name {
+                        if let Some(obj) = data.as_object_mut() {
+                            obj.insert("name".to_string(), Value::String(display_name.clone()));
                         }
                     }
+                    for _ in 0..mm.quantity {
+                        monster_data.push(data.clone());
+                    }
                 }
+            }
 
-                if !monster_data.is_empty() {
-                    info!(
-                        "    Adding MonsterCardSection with {} monsters",
-                        monster_data.len()
-                    );
-                    let section = MonsterCardSection::new(monster_data);
-                    builder = builder.append(section);
-                    has_content = true;
-                }
+            if !monster_data.is_empty() {
+                info!(
+                    "    Adding MonsterCardSection with {} monsters",
+                    monster_data.len()
+                );
+                let section = MonsterCardSection::new(monster_data);
+                builder = builder.append(section);
+                has_content = true;
             }
 
             // Module traps as cards (from map_traps table)
@@ -472,66 +865,36 @@ pub fn export_campaign_documents(
                 use mimir_print::sections::TrapCardSection;
                 use std::collections::HashSet;
 
-                let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
-                let maps = match map_service.list_for_module(&module.id) {
-                    Ok(m) => m,
-                    Err(e) => {
-                        error!("    Failed to list module maps for traps: {}", e);
-                        vec![]
-                    }
-                };
-
                 let mut seen_traps: HashSet<String> = HashSet::new();
                 let mut trap_data: Vec<Value> = Vec::new();
 
-                for map in &maps {
-                    if let Ok(map_traps) = dal::list_map_traps(&mut db, &map.id) {
-                        for trap in map_traps {
-                            if seen_traps.contains(&trap.name) {
-                                continue;
-                            }
-                            seen_traps.insert(trap.name.clone());
-
-                            // Try catalog lookup
-                            let sources_to_try = ["DMG", "XGE", "TCE", "PHB"];
-                            let mut found_catalog = false;
+                for trap in &content.traps {
+                    if !seen_traps.insert(trap.name.clone()) {
+                        continue;
+                    }
 
-                            for source in sources_to_try {
-                                if let Ok(Some(catalog_trap)) =
-                                    catalog_dal::get_trap_by_name(&mut db, &trap.name, source)
+                    // Use the catalog trap, or create a custom trap card if not in catalog
+                    let data = lookups.map_trap_data(trap).unwrap_or_else(|| {
+                        serde_json::json!({
+                            "name": trap.name,
+                            "trapHazType": "TRAP",
+                            "effect": [
                                 {
-                                    if let Ok(data) = catalog_trap.parse_data() {
-                                        trap_data.push(data);
-                                        found_catalog = true;
-                                        break;
-                                    }
+                                    "type": "entries",
+                                    "name": "Trigger",
+                                    "entries": [trap.trigger_description.as_deref().unwrap_or("Unknown trigger")]
+                                },
+                                {
+                                    "type": "entries",
+                                    "name": "Effect",
+                                    "entries": [trap.effect_description.as_deref().unwrap_or("Unknown effect")]
                                 }
-                            }
-
-                            // Create custom trap card if not in catalog
-                            if !found_catalog {
-                                let custom_trap = serde_json::json!({
-                                    "name": trap.name,
-                                    "trapHazType": "TRAP",
-                                    "effect": [
-                                        {
-                                            "type": "entries",
-                                            "name": "Trigger",
-                                            "entries": [trap.trigger_description.as_deref().unwrap_or("Unknown trigger")]
-                                        },
-                                        {
-                                            "type": "entries",
-                                            "name": "Effect",
-                                            "entries": [trap.effect_description.as_deref().unwrap_or("Unknown effect")]
-                                        }
-                                    ],
-                                    "countermeasures": [trap.description.as_deref().unwrap_or("No countermeasures listed")],
-                                    "dc": trap.dc
-                                });
-                                trap_data.push(custom_trap);
-                            }
-                        }
-                    }
+                            ],
+                            "countermeasures": [trap.description.as_deref().unwrap_or("No countermeasures listed")],
+                            "dc": trap.dc
+                        })
+                    });
+                    trap_data.push(data);
                 }
 
                 if !trap_data.is_empty() {
@@ -544,35 +907,21 @@ pub fn export_campaign_documents(
 
             // Module POIs (from map_pois table)
             {
-                let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
-                let maps = match map_service.list_for_module(&module.id) {
-                    Ok(m) => m,
-                    Err(e) => {
-                        error!("    Failed to list module maps for POIs: {}", e);
-                        vec![]
-                    }
-                };
-
                 let mut poi_content = String::new();
                 let mut total_pois = 0;
 
-                for map in &maps {
-                    if let Ok(map_pois) = dal::list_map_pois(&mut db, &map.id) {
-                        if !map_pois.is_empty() {
-                            poi_content.push_str(&format!("### {}\n\n", map.name));
-                            for poi in &map_pois {
-                                total_pois += 1;
-                                poi_content.push_str(&format!(
-                                    "**{}** ({})\n",
-                                    poi.name,
-                                    format!("{},{}", poi.grid_x, poi.grid_y)
-                                ));
-                                if let Some(ref desc) = poi.description {
-                                    poi_content.push_str(&format!("{}\n", desc));
-                                }
-                                poi_content.push('\n');
-                            }
+                for (map_name, map_pois) in &content.pois {
+                    poi_content.push_str(&format!("### {}\n\n", map_name));
+                    for poi in map_pois {
+                        total_pois += 1;
+                        poi_content.push_str(&format!(
+                            "**{}** ({},{})\n",
+                            poi.name, poi.grid_x, poi.grid_y
+                        ));
+                        if let Some(ref desc) = poi.description {
+                            poi_content.push_str(&format!("{}\n", desc));
                         }
+                        poi_content.push('\n');
                     }
                 }
 
@@ -592,26 +941,13 @@ pub fn export_campaign_documents(
         info!("[SECTION] Module content NOT requested");
     }
 
-    // 3. NPCs (characters marked as NPCs)
-    if opts.include_npcs.unwrap_or(false) {
+    // 4. NPCs (characters marked as NPCs)
+    if include_npcs {
         info!("[SECTION] NPCs requested");
-        let npcs = match CharacterService::new(&mut db).list_npcs(&campaign_id) {
-            Ok(n) => {
-                info!("  Found {} NPCs", n.len());
-                n
-            }
-            Err(e) => {
-                error!("  Failed to list NPCs: {}", e);
-                vec![]
-            }
-        };
 
-        for npc in npcs {
+        for (npc, inventory) in npcs.iter().zip(&npc_inventories) {
             info!("  Processing NPC: {}", npc.name);
             let classes = dal::list_character_classes(&mut db, &npc.id).unwrap_or_default();
-            let inventory = CharacterService::new(&mut db)
-                .get_inventory(&npc.id)
-                .unwrap_or_default();
             let proficiencies_raw =
                 dal::list_character_proficiencies(&mut db, &npc.id).unwrap_or_default();
 
@@ -675,10 +1011,10 @@ pub fn export_campaign_documents(
                     .collect(),
                 inventory: inventory
                     .iter()
-                    .map(|i| enrich_inventory_item(&mut db, i, Some(&campaign_id)))
+                    .map(|i| lookups.inventory_item(i))
                     .collect(),
                 proficiencies,
-                speed: 30,
+                speed: character_speed(&mut db, npc),
                 ac: 10, // computed below
                 hit_points_max: 0,
                 hit_die: String::new(),
@@ -686,6 +1022,8 @@ pub fn export_campaign_documents(
                 spell_save_dc: None,
                 spell_attack_bonus: None,
                 spell_slots: vec![0; 9],
+                custom_fields: custom_field_entries(&mut db, &npc.id),
+                portrait: character_portrait(&app_state.paths.assets_dir, npc),
             };
 
             // Compute AC from equipped armor
@@ -706,7 +1044,7 @@ pub fn export_campaign_documents(
         info!("[SECTION] NPCs NOT requested");
     }
 
-    // 4. Campaign-level map previews
+    // 5. Campaign-level map previews
     if opts.include_campaign_map_previews.unwrap_or(false) {
         info!("[SECTION] Campaign map previews requested");
         let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
@@ -746,35 +1084,17 @@ pub fn export_campaign_documents(
         info!("[SECTION] Campaign map previews NOT requested");
     }
 
-    // 5. Module map previews
-    if opts.include_module_map_previews.unwrap_or(false) {
+    // 6. Module map previews
+    if include_module_map_previews {
         info!("[SECTION] Module map previews requested");
-        let modules = match ModuleService::new(&mut db).list_for_campaign(&campaign_id) {
-            Ok(m) => {
-                info!("  Found {} modules to check for maps", m.len());
-                m
-            }
-            Err(e) => {
-                error!("  Failed to list modules: {}", e);
-                vec![]
-            }
-        };
+        let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
 
-        for module in modules {
-            let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
-            let maps = match map_service.list_for_module(&module.id) {
-                Ok(m) => {
-                    info!("  Module '{}' has {} maps", module.name, m.len());
-                    m
-                }
-                Err(e) => {
-                    error!("  Failed to list module {} maps: {}", module.id, e);
-                    vec![]
-                }
-            };
+        for content in &module_contents {
+            let module = &content.module;
+            info!("  Module '{}' has {} maps", module.name, content.maps.len());
 
-            for map in maps {
-                if let Ok(uvtt_bytes) = map_service.read_uvtt_file(&map) {
+            for map in &content.maps {
+                if let Ok(uvtt_bytes) = map_service.read_uvtt_file(map) {
                     if let Ok(uvtt_json) = serde_json::from_slice::<Value>(&uvtt_bytes) {
                         // Extract base64 image from UVTT and decode it
                         if let Some(image_base64) = uvtt_json.get("image").and_then(|v| v.as_str())
@@ -803,7 +1123,7 @@ pub fn export_campaign_documents(
         info!("[SECTION] Module map previews NOT requested");
     }
 
-    // 6. Campaign tiled maps (for tabletop play at 1"=5ft scale)
+    // 7. Campaign tiled maps (for tabletop play at 1"=5ft scale)
     if opts.include_campaign_tiled_maps.unwrap_or(false) {
         info!("[SECTION] Campaign tiled maps requested");
         let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
@@ -852,42 +1172,21 @@ pub fn export_campaign_documents(
         }
     }
 
-    // 7. Module tiled maps (for tabletop play at 1"=5ft scale)
-    if opts.include_module_tiled_maps.unwrap_or(false) {
+    // 8. Module tiled maps (for tabletop play at 1"=5ft scale)
+    if include_module_tiled_maps {
         info!("[SECTION] Module tiled maps requested");
-        let modules = match ModuleService::new(&mut db).list_for_campaign(&campaign_id) {
-            Ok(m) => {
-                info!("  Found {} modules for tiled maps", m.len());
-                m
-            }
-            Err(e) => {
-                error!("  Failed to list modules for tiled maps: {}", e);
-                vec![]
-            }
-        };
+        let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
 
-        for module in modules {
-            let mut map_service = MapService::new(&mut db, &app_state.paths.app_dir);
-            let maps = match map_service.list_for_module(&module.id) {
-                Ok(m) => {
-                    info!(
-                        "  Module '{}' has {} maps for tiled export",
-                        module.name,
-                        m.len()
-                    );
-                    m
-                }
-                Err(e) => {
-                    error!(
-                        "  Failed to list module {} maps for tiled export: {}",
-                        module.id, e
-                    );
-                    vec![]
-                }
-            };
+        for content in &module_contents {
+            let module = &content.module;
+            info!(
+                "  Module '{}' has {} maps for tiled export",
+                module.name,
+                content.maps.len()
+            );
 
-            for map in maps {
-                if let Ok(uvtt_bytes) = map_service.read_uvtt_file(&map) {
+            for map in &content.maps {
+                if let Ok(uvtt_bytes) = map_service.read_uvtt_file(map) {
                     if let Ok(uvtt_json) = serde_json::from_slice::<Value>(&uvtt_bytes) {
                         let resolution = uvtt_json.get("resolution");
                         let pixels_per_grid = resolution
@@ -923,53 +1222,28 @@ pub fn export_campaign_documents(
         }
     }
 
-    // 8. Token cutouts (paper standees for tabletop play)
-    if opts.include_token_cutouts.unwrap_or(false) {
+    // 9. Token cutouts (paper standees for tabletop play)
+    if include_token_cutouts {
         info!("[SECTION] Token cutouts requested");
-        use mimir_core::services::ModuleService;
-
-        let modules = match ModuleService::new(&mut db).list_for_campaign(&campaign_id) {
-            Ok(m) => {
-                info!("  Found {} modules for token cutouts", m.len());
-                m
-            }
-            Err(e) => {
-                error!("  Failed to list modules for token cutouts: {}", e);
-                vec![]
-            }
-        };
 
         let mut cutout_tokens: Vec<CutoutToken> = Vec::new();
 
-        for module in modules {
-            // Get module monsters
-            let module_monsters = match dal::list_module_monsters(&mut db, &module.id) {
-                Ok(monsters) => {
-                    info!(
-                        "  Module '{}' has {} monsters for cutouts",
-                        module.name,
-                        monsters.len()
-                    );
-                    monsters
-                }
-                Err(e) => {
-                    error!(
-                        "  Failed to list module {} monsters for cutouts: {}",
-                        module.id, e
-                    );
-                    continue;
-                }
-            };
+        for content in &module_contents {
+            info!(
+                "  Module '{}' has {} monsters for cutouts",
+                content.module.name,
+                content.monsters.len()
+            );
 
-            for mm in module_monsters {
+            for mm in &content.monsters {
                 // Look up monster size from catalog or homebrew
-                let size = resolve_monster_size(&mut db, &mm, &campaign_id);
+                let size = lookups.monster_size(mm);
 
                 // Try to load token image from assets (catalog monsters only)
-                let image_bytes = load_monster_token_image(&mm, &app_state.paths.assets_dir);
+                let image_bytes = load_monster_token_image(mm, &app_state.paths.assets_dir);
 
                 // Use display name if set, otherwise monster name
-                let display_name = mm.display_name
+                let display_name = mm.display_name.clone()
                     .or(mm.monster_name.clone())
                     .unwrap_or_else(|| "Unknown Monster".to_string());
 
@@ -1031,6 +1305,7 @@ pub fn export_module_documents(
     print_state: State<'_, PrintState>,
     module_id: String,
     options: Option<ModuleExportOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("Exporting module {} to PDF", module_id);
 
@@ -1044,6 +1319,7 @@ pub fn export_module_documents(
     info!("  include_pois: {:?}", opts.include_pois);
     info!("  include_npcs: {:?}", opts.include_npcs);
     info!("  include_play_notes: {:?}", opts.include_play_notes);
+    info!("  include_hooks: {:?}", opts.include_hooks);
     info!("  include_preview: {:?}", opts.include_preview);
     info!("  preview_grid: {:?}", opts.preview_grid);
     info!("  preview_los_walls: {:?}", opts.preview_los_walls);
@@ -1069,10 +1345,14 @@ pub fn export_module_documents(
     };
 
     // Build combined PDF
+    let print_settings = app_state.settings().load().unwrap_or_default().print;
     let mut builder = DocumentBuilder::new(&module.name)
         .with_templates_root(print_state.templates_dir.clone())
-        .with_title_page(true)
-        .with_toc(true);
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(print_settings.title_page)
+        .with_toc(print_settings.table_of_contents)
+        .with_page_numbers(print_settings.page_numbers)
+        .with_booklet(booklet_layout(&print_settings));
 
     let mut has_content = false;
 
@@ -1091,7 +1371,12 @@ pub fn export_module_documents(
         };
 
         for doc in documents {
-            match MarkdownSection::from_markdown(&doc.content) {
+            match document_section(
+                &mut db,
+                &app_state.paths.app_dir,
+                &doc.campaign_id,
+                &doc.content,
+            ) {
                 Ok(section) => {
                     info!("  Adding document: {}", doc.title);
                     builder = builder.append(section.with_title(&doc.title));
@@ -1184,16 +1469,22 @@ pub fn export_module_documents(
                         }
                         seen_traps.insert(trap.name.clone());
 
-                        // Try to look up trap in catalog (default to DMG source)
-                        let sources_to_try = ["DMG", "XGE", "TCE", "PHB"];
+                        // Look up the catalog entry the trap was placed from, then
+                        // fall back to searching the catalog by name (DMG first)
                         let mut found_catalog = false;
+                        let candidates = trap.catalog_ref().into_iter().chain(
+                            TRAP_SOURCES
+                                .iter()
+                                .map(|source| (trap.name.as_str(), *source)),
+                        );
 
-                        for source in sources_to_try {
+                        for (name, source) in candidates {
                             if let Ok(Some(catalog_trap)) =
-                                catalog_dal::get_trap_by_name(&mut db, &trap.name, source)
+                                catalog_dal::get_trap_by_name(&mut db, name, source)
                             {
-                                if let Ok(data) = catalog_trap.parse_data() {
-                                    info!("  Found trap in catalog: {} ({})", trap.name, source);
+                                if let Ok(mut data) = catalog_trap.parse_data() {
+                                    info!("  Found trap in catalog: {} ({})", name, source);
+                                    data["name"] = Value::String(trap.name.clone());
                                     trap_data.push(data);
                                     found_catalog = true;
                                     break;
@@ -1302,6 +1593,45 @@ pub fn export_module_documents(
         info!("[SECTION] POIs NOT requested");
     }
 
+    // 2d. Unrevealed hooks (rumors no player has heard yet)
+    if opts.include_hooks.unwrap_or(false) {
+        info!("[SECTION] Unrevealed hooks requested");
+        let hooks = match RumorService::new(&mut db).unrevealed_hooks(&module_id) {
+            Ok(h) => h,
+            Err(e) => {
+                error!("  Failed to list unrevealed hooks: {}", e);
+                vec![]
+            }
+        };
+
+        if !hooks.is_empty() {
+            info!("  Adding hooks section with {} rumors", hooks.len());
+            let mut hook_content = String::new();
+            for hook in &hooks {
+                let mut details = vec![if hook.is_true() { "True" } else { "False" }.to_string()];
+                if let Some(npc_id) = hook.source_npc_id.as_deref() {
+                    if let Ok(Some(npc)) = dal::get_character_optional(&mut db, npc_id) {
+                        details.push(format!("from {}", npc.name));
+                    }
+                }
+                if hook.module_id.is_none() {
+                    details.push("not tied to a module".to_string());
+                }
+                hook_content.push_str(&format!("- {} *({})*\n", hook.text, details.join(", ")));
+                if let Some(ref notes) = hook.notes {
+                    hook_content.push_str(&format!("  {}\n", notes));
+                }
+            }
+            let section = MarkdownSection::from_content(&hook_content, Some("Unrevealed Hooks"));
+            builder = builder.append(section);
+            has_content = true;
+        } else {
+            info!("  No unrevealed hooks for module");
+        }
+    } else {
+        info!("[SECTION] Unrevealed hooks NOT requested");
+    }
+
     // 3. Map previews
     if opts.include_preview.unwrap_or(true) {
         info!("[SECTION] Map previews requested");
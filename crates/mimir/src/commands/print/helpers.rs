@@ -111,6 +111,32 @@ pub fn enrich_inventory_item(
     db: &mut diesel::SqliteConnection,
     inv_item: &mimir_core::models::CharacterInventory,
     campaign_id: Option<&str>,
+) -> InventoryItem {
+    // Try homebrew first if source is "HB", then fall back to catalog
+    if inv_item.item_source == "HB" {
+        let homebrew = campaign_id.and_then(|cid| {
+            mimir_core::dal::campaign::get_campaign_homebrew_item_by_name(
+                db, cid, &inv_item.item_name,
+            )
+            .ok()
+            .flatten()
+        });
+        inventory_item_from(inv_item, None, homebrew.as_ref())
+    } else {
+        let catalog_item =
+            catalog_dal::get_item_by_name(db, &inv_item.item_name, &inv_item.item_source)
+                .ok()
+                .flatten();
+        inventory_item_from(inv_item, catalog_item.as_ref(), None)
+    }
+}
+
+/// Build a printable inventory item from its already-fetched catalog or
+/// homebrew entry.
+pub fn inventory_item_from(
+    inv_item: &mimir_core::models::CharacterInventory,
+    catalog_item: Option<&mimir_core::models::catalog::Item>,
+    homebrew_item: Option<&mimir_core::models::campaign::CampaignHomebrewItem>,
 ) -> InventoryItem {
     let equipped = inv_item.is_equipped();
     let attuned = inv_item.is_attuned();
@@ -133,46 +159,31 @@ pub fn enrich_inventory_item(
         (it, dmg, dt, ac, fin)
     };
 
-    // Try homebrew first if source is "HB", then fall back to catalog
     let (item_type, damage, damage_type, armor_ac, finesse) =
-        if inv_item.item_source == "HB" {
-            if let Some(cid) = campaign_id {
-                match mimir_core::dal::campaign::get_campaign_homebrew_item_by_name(
-                    db, cid, &inv_item.item_name,
-                ) {
-                    Ok(Some(hb_item)) => {
-                        if let Ok(data) = serde_json::from_str::<serde_json::Value>(&hb_item.data) {
-                            let (it, dmg, dt, ac, fin) = parse_item_data(&data);
-                            // Fall back to DB item_type if JSON data has no type field
-                            let item_type = it.or_else(|| {
-                                hb_item.item_type.as_deref().and_then(|t| {
-                                    super::character::homebrew_item_type_to_code(
-                                        t,
-                                        data.as_object().unwrap_or(&serde_json::Map::new()),
-                                    )
-                                })
-                            });
-                            (item_type, dmg, dt, ac, fin)
-                        } else {
-                            (None, None, None, None, false)
-                        }
-                    }
-                    _ => (None, None, None, None, false),
-                }
+        if let Some(hb_item) = homebrew_item {
+            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&hb_item.data) {
+                let (it, dmg, dt, ac, fin) = parse_item_data(&data);
+                // Fall back to DB item_type if JSON data has no type field
+                let item_type = it.or_else(|| {
+                    hb_item.item_type.as_deref().and_then(|t| {
+                        super::character::homebrew_item_type_to_code(
+                            t,
+                            data.as_object().unwrap_or(&serde_json::Map::new()),
+                        )
+                    })
+                });
+                (item_type, dmg, dt, ac, fin)
             } else {
                 (None, None, None, None, false)
             }
-        } else {
-            match catalog_dal::get_item_by_name(db, &inv_item.item_name, &inv_item.item_source) {
-                Ok(Some(catalog_item)) => {
-                    if let Ok(data) = catalog_item.parse_data() {
-                        parse_item_data(&data)
-                    } else {
-                        (None, None, None, None, false)
-                    }
-                }
-                _ => (None, None, None, None, false),
+        } else if let Some(catalog_item) = catalog_item {
+            if let Ok(data) = catalog_item.parse_data() {
+                parse_item_data(&data)
+            } else {
+                (None, None, None, None, false)
             }
+        } else {
+            (None, None, None, None, false)
         };
 
     InventoryItem {
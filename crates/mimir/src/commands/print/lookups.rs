@@ -0,0 +1,350 @@
+//! Batched Catalog Lookups
+//!
+//! Large exports reference the same catalog monsters, traps, and items many
+//! times. Exports first collect every reference into `ExportReferences`, then
+//! `ExportLookups::load` fetches each kind with a few batched queries on one
+//! connection, and later lookups are answered from memory.
+
+use std::collections::HashMap;
+
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::dal::catalog as catalog_dal;
+use mimir_core::models::campaign::{
+    CampaignHomebrewItem, CampaignHomebrewMonster, MapTrap, ModuleMonster,
+};
+use mimir_core::models::catalog::{Item, Monster, Trap};
+use mimir_core::models::CharacterInventory;
+use mimir_print::sections::InventoryItem;
+use serde_json::Value;
+use tracing::{error, info};
+
+use super::helpers::inventory_item_from;
+
+/// Catalog sources searched, in order, for a trap placed on a map.
+pub(super) const TRAP_SOURCES: [&str; 4] = ["DMG", "XGE", "TCE", "PHB"];
+
+/// Catalog key: lowercased name and source.
+type CatalogKey = (String, String);
+
+fn catalog_key(name: &str, source: &str) -> CatalogKey {
+    (name.to_lowercase(), source.to_string())
+}
+
+/// Catalog and homebrew names an export will look up.
+#[derive(Debug, Default)]
+pub(super) struct ExportReferences {
+    monster_names: Vec<String>,
+    trap_names: Vec<String>,
+    item_names: Vec<String>,
+    homebrew: bool,
+}
+
+impl ExportReferences {
+    /// Reference a module monster's catalog or homebrew entry.
+    pub(super) fn add_monster(&mut self, mm: &ModuleMonster) {
+        match (
+            &mm.homebrew_monster_id,
+            &mm.monster_name,
+            &mm.monster_source,
+        ) {
+            (Some(_), _, _) => self.homebrew = true,
+            (None, Some(_), Some(source)) if source == "HB" => self.homebrew = true,
+            (None, Some(name), Some(_)) => self.monster_names.push(name.clone()),
+            _ => {}
+        }
+    }
+
+    /// Reference a trap by name.
+    pub(super) fn add_trap(&mut self, name: &str) {
+        self.trap_names.push(name.to_string());
+    }
+
+    /// Reference a map trap's catalog entry, or its name if it was not
+    /// placed from the catalog.
+    pub(super) fn add_map_trap(&mut self, trap: &MapTrap) {
+        match trap.catalog_ref() {
+            Some((name, _)) => self.add_trap(name),
+            None => self.add_trap(&trap.name),
+        }
+    }
+
+    /// Reference an inventory item's catalog or homebrew entry.
+    pub(super) fn add_item(&mut self, item: &CharacterInventory) {
+        if item.item_source == "HB" {
+            self.homebrew = true;
+        } else {
+            self.item_names.push(item.item_name.clone());
+        }
+    }
+}
+
+/// Catalog and homebrew entries fetched for an export.
+#[derive(Debug, Default)]
+pub(super) struct ExportLookups {
+    monsters: HashMap<CatalogKey, Monster>,
+    traps: HashMap<CatalogKey, Trap>,
+    items: HashMap<CatalogKey, Item>,
+    homebrew_monsters: Vec<CampaignHomebrewMonster>,
+    homebrew_items: Vec<CampaignHomebrewItem>,
+}
+
+impl ExportLookups {
+    /// Fetch everything in `refs` for a campaign.
+    ///
+    /// Failed queries are logged and leave their lookups empty, so the export
+    /// continues with whatever could be loaded.
+    pub(super) fn load(
+        db: &mut SqliteConnection,
+        campaign_id: &str,
+        refs: &ExportReferences,
+    ) -> Self {
+        let mut lookups = Self::default();
+
+        if !refs.monster_names.is_empty() {
+            match catalog_dal::list_monsters_by_names(db, &refs.monster_names) {
+                Ok(monsters) => {
+                    lookups.monsters = monsters
+                        .into_iter()
+                        .map(|m| (catalog_key(&m.name, &m.source), m))
+                        .collect();
+                }
+                Err(e) => error!("Failed to batch-load monsters: {}", e),
+            }
+        }
+
+        if !refs.trap_names.is_empty() {
+            match catalog_dal::list_traps_by_names(db, &refs.trap_names) {
+                Ok(traps) => {
+                    lookups.traps = traps
+                        .into_iter()
+                        .map(|t| (catalog_key(&t.name, &t.source), t))
+                        .collect();
+                }
+                Err(e) => error!("Failed to batch-load traps: {}", e),
+            }
+        }
+
+        if !refs.item_names.is_empty() {
+            match catalog_dal::list_items_by_names(db, &refs.item_names) {
+                Ok(items) => {
+                    lookups.items = items
+                        .into_iter()
+                        .map(|i| (catalog_key(&i.name, &i.source), i))
+                        .collect();
+                }
+                Err(e) => error!("Failed to batch-load items: {}", e),
+            }
+        }
+
+        if refs.homebrew {
+            lookups.homebrew_monsters = dal::list_campaign_homebrew_monsters(db, campaign_id)
+                .unwrap_or_else(|e| {
+                    error!("Failed to load homebrew monsters: {}", e);
+                    vec![]
+                });
+            lookups.homebrew_items = dal::list_campaign_homebrew_items(db, campaign_id)
+                .unwrap_or_else(|e| {
+                    error!("Failed to load homebrew items: {}", e);
+                    vec![]
+                });
+        }
+
+        info!(
+            "Loaded export lookups: {} monsters, {} traps, {} items, {} homebrew monsters, {} homebrew items",
+            lookups.monsters.len(),
+            lookups.traps.len(),
+            lookups.items.len(),
+            lookups.homebrew_monsters.len(),
+            lookups.homebrew_items.len()
+        );
+        lookups
+    }
+
+    fn homebrew_monster(&self, mm: &ModuleMonster) -> Option<&CampaignHomebrewMonster> {
+        if let Some(ref hb_id) = mm.homebrew_monster_id {
+            self.homebrew_monsters.iter().find(|hb| &hb.id == hb_id)
+        } else {
+            // Legacy: homebrew referenced by name with source "HB"
+            let name = mm.monster_name.as_ref()?;
+            self.homebrew_monsters.iter().find(|hb| &hb.name == name)
+        }
+    }
+
+    fn is_homebrew(mm: &ModuleMonster) -> bool {
+        mm.homebrew_monster_id.is_some() || mm.monster_source.as_deref() == Some("HB")
+    }
+
+    /// Monster stat block data for a module monster.
+    pub(super) fn module_monster_data(&self, mm: &ModuleMonster) -> Option<Value> {
+        if Self::is_homebrew(mm) {
+            let Some(hb) = self.homebrew_monster(mm) else {
+                error!("Homebrew monster not found for module monster {}", mm.id);
+                return None;
+            };
+            return parse_homebrew_monster_data(hb.clone());
+        }
+
+        let (Some(name), Some(source)) = (&mm.monster_name, &mm.monster_source) else {
+            error!(
+                "Module monster {} has no catalog or homebrew reference",
+                mm.id
+            );
+            return None;
+        };
+        let Some(monster) = self.monsters.get(&catalog_key(name, source)) else {
+            error!("Catalog monster not found: {} ({})", name, source);
+            return None;
+        };
+        match monster.parse_data() {
+            Ok(data) => Some(data),
+            Err(e) => {
+                error!("Failed to parse monster data for {}: {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Size name ("Medium", "Large", ...) for a module monster's token.
+    pub(super) fn monster_size(&self, mm: &ModuleMonster) -> String {
+        if Self::is_homebrew(mm) {
+            return self
+                .homebrew_monster(mm)
+                .and_then(|hb| serde_json::from_str::<Value>(&hb.data).ok())
+                .and_then(|d| d.get("size").and_then(|s| s.as_str()).map(size_name))
+                .unwrap_or_else(|| "Medium".to_string());
+        }
+
+        match (&mm.monster_name, &mm.monster_source) {
+            (Some(name), Some(source)) => self
+                .monsters
+                .get(&catalog_key(name, source))
+                .and_then(|m| m.size.clone())
+                .unwrap_or_else(|| "Medium".to_string()),
+            _ => "Medium".to_string(),
+        }
+    }
+
+    /// Trap card data from the first catalog source that has the trap.
+    pub(super) fn trap_data(&self, name: &str) -> Option<Value> {
+        TRAP_SOURCES.iter().find_map(|source| {
+            self.traps
+                .get(&catalog_key(name, source))
+                .and_then(|trap| trap.parse_data().ok())
+        })
+    }
+
+    /// Trap card data for a map trap: the catalog entry it was placed from,
+    /// else the first catalog source with a trap of the same name. The card
+    /// keeps the name the trap has on the map.
+    pub(super) fn map_trap_data(&self, trap: &MapTrap) -> Option<Value> {
+        let mut data = match trap.catalog_ref() {
+            Some((name, source)) => self
+                .traps
+                .get(&catalog_key(name, source))
+                .and_then(|t| t.parse_data().ok())
+                .or_else(|| self.trap_data(&trap.name)),
+            None => self.trap_data(&trap.name),
+        }?;
+        data["name"] = Value::String(trap.name.clone());
+        Some(data)
+    }
+
+    /// Printable inventory item with weapon and armor stats filled in.
+    pub(super) fn inventory_item(&self, inv_item: &CharacterInventory) -> InventoryItem {
+        if inv_item.item_source == "HB" {
+            let homebrew = self
+                .homebrew_items
+                .iter()
+                .find(|hb| hb.name == inv_item.item_name);
+            inventory_item_from(inv_item, None, homebrew)
+        } else {
+            let catalog_item = self
+                .items
+                .get(&catalog_key(&inv_item.item_name, &inv_item.item_source));
+            inventory_item_from(inv_item, catalog_item, None)
+        }
+    }
+}
+
+/// Parse a homebrew monster's stat block, labelling it as homebrew.
+pub(super) fn parse_homebrew_monster_data(hb_monster: CampaignHomebrewMonster) -> Option<Value> {
+    match serde_json::from_str::<Value>(&hb_monster.data) {
+        Ok(mut data) => {
+            if let Some(obj) = data.as_object_mut() {
+                obj.insert("name".to_string(), Value::String(hb_monster.name));
+                obj.insert("source".to_string(), Value::String("Homebrew".to_string()));
+            }
+            Some(data)
+        }
+        Err(e) => {
+            error!("Failed to parse homebrew monster data: {}", e);
+            None
+        }
+    }
+}
+
+/// Full size name for a 5etools size code.
+pub(super) fn size_name(code: &str) -> String {
+    match code {
+        "T" => "Tiny",
+        "S" => "Small",
+        "M" => "Medium",
+        "L" => "Large",
+        "H" => "Huge",
+        "G" => "Gargantuan",
+        other => other,
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module_monster(
+        name: Option<&str>,
+        source: Option<&str>,
+        hb_id: Option<&str>,
+    ) -> ModuleMonster {
+        ModuleMonster {
+            id: "mm-1".to_string(),
+            module_id: "mod-1".to_string(),
+            monster_name: name.map(String::from),
+            monster_source: source.map(String::from),
+            homebrew_monster_id: hb_id.map(String::from),
+            display_name: None,
+            notes: None,
+            quantity: 1,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_references_split_catalog_and_homebrew() {
+        let mut refs = ExportReferences::default();
+        refs.add_monster(&module_monster(Some("Goblin"), Some("MM"), None));
+        assert_eq!(refs.monster_names, vec!["Goblin".to_string()]);
+        assert!(!refs.homebrew);
+
+        refs.add_monster(&module_monster(Some("Bog Hag"), Some("HB"), None));
+        assert_eq!(refs.monster_names.len(), 1);
+        assert!(refs.homebrew);
+    }
+
+    #[test]
+    fn test_missing_lookups_fall_back() {
+        let lookups = ExportLookups::default();
+        let goblin = module_monster(Some("Goblin"), Some("MM"), None);
+        assert!(lookups.module_monster_data(&goblin).is_none());
+        assert_eq!(lookups.monster_size(&goblin), "Medium");
+        assert!(lookups.trap_data("Pit Trap").is_none());
+    }
+
+    #[test]
+    fn test_size_name() {
+        assert_eq!(size_name("L"), "Large");
+        assert_eq!(size_name("Huge"), "Huge");
+    }
+}
@@ -6,7 +6,7 @@ use base64::Engine;
 use mimir_core::services::MapService;
 use mimir_print::map_renderer::{MapPrintOptions as RenderMapPrintOptions, RenderMap};
 use mimir_print::sections::{MapPreview, TiledMapSection};
-use mimir_print::{DocumentBuilder, PrintState};
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
 use serde_json::Value;
 use std::path::PathBuf;
 use tauri::State;
@@ -23,6 +23,7 @@ pub fn print_map(
     print_state: State<'_, PrintState>,
     map_id: String,
     options: Option<MapPrintOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("=== print_map called ===");
     info!("  map_id: {}", map_id);
@@ -152,6 +153,7 @@ pub fn print_map(
 
     let mut builder = DocumentBuilder::new(&map.name)
         .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
         .with_title_page(false)
         .with_toc(false);
 
@@ -6,8 +6,13 @@
 mod character;
 mod document;
 mod helpers;
+mod lookups;
 mod map;
 mod monster;
+mod party;
+mod retrospective;
+mod secret;
+mod timeline;
 mod trap;
 
 // Re-export all commands for use in main.rs invoke_handler
@@ -15,9 +20,15 @@ pub use character::*;
 pub use document::*;
 pub use map::*;
 pub use monster::*;
+pub use party::*;
+pub use retrospective::*;
+pub use secret::*;
+pub use timeline::*;
 pub use trap::*;
 
+use mimir_core::services::TimelineGrouping;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // =============================================================================
 // Response Types
@@ -68,6 +79,17 @@ pub struct CharacterExportOptions {
     pub include_battle_card: Option<bool>,
     pub include_spell_cards: Option<bool>,
     pub include_equipment_cards: Option<bool>,
+    /// Add a companion addendum page (companion stat blocks) after the sheet
+    pub include_companions: Option<bool>,
+    /// Add fillable form fields (HP, spell slots, death saves, inspiration)
+    /// to the compact sheet
+    pub fillable_sheet: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct GrimoireExportOptions {
+    /// Blank spell pages at the back of the book for future spells
+    pub blank_pages: Option<usize>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -78,6 +100,8 @@ pub struct ModuleExportOptions {
     pub include_pois: Option<bool>,
     pub include_npcs: Option<bool>,
     pub include_play_notes: Option<bool>,
+    /// Rumors leading into the module that no player has heard yet
+    pub include_hooks: Option<bool>,
     pub include_preview: Option<bool>,
     pub preview_grid: Option<bool>,
     pub preview_los_walls: Option<bool>,
@@ -93,11 +117,37 @@ pub struct CampaignExportOptions {
     pub include_campaign_docs: Option<bool>,
     pub include_module_content: Option<bool>,
     pub include_npcs: Option<bool>,
+    /// DM-only safety tools section, including the DM's private notes
+    pub include_safety_tools: Option<bool>,
     pub include_module_map_previews: Option<bool>,
     pub include_module_tiled_maps: Option<bool>,
     pub include_token_cutouts: Option<bool>,
     pub include_campaign_map_previews: Option<bool>,
     pub include_campaign_tiled_maps: Option<bool>,
+    /// Campaign image asset printed as full-page cover art
+    pub cover_art_asset_id: Option<String>,
+    /// Campaign image asset shown as the logo on the title page
+    pub logo_asset_id: Option<String>,
+    /// DM credited on the title page
+    pub dm_name: Option<String>,
+    /// When the campaign ran, shown on the title page
+    pub date_range: Option<String>,
+    /// Open each module's content with a divider page
+    pub include_chapter_dividers: Option<bool>,
+    /// Divider art by module ID (campaign image asset IDs)
+    pub divider_art: Option<HashMap<String, String>>,
+    /// Chart of every party's timeline events
+    pub include_timeline: Option<bool>,
+    /// Swimlanes of the timeline chart (plotlines by default)
+    pub timeline_grouping: Option<TimelineGrouping>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PartyReferenceOptions {
+    /// Battle cards for each party member
+    pub include_battle_cards: Option<bool>,
+    /// Mount and vehicle stat blocks with the party's carrying capacity
+    pub include_vehicles: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Default)]
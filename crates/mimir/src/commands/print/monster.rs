@@ -2,11 +2,16 @@
 //!
 //! Tauri commands for exporting monster cards to PDF.
 
+use std::collections::HashMap;
+
 use base64::Engine;
 use mimir_core::dal::campaign as dal;
 use mimir_core::dal::catalog as catalog_dal;
-use mimir_print::sections::MonsterCardSection;
-use mimir_print::{DocumentBuilder, PrintState};
+use mimir_core::models::campaign::TaggableType;
+use mimir_core::models::catalog::license_notice;
+use mimir_core::services::TagService;
+use mimir_print::sections::{ConditionMarkerSection, MarkdownSection, MonsterCardSection};
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
 use serde::Deserialize;
 use serde_json::Value;
 use tauri::State;
@@ -20,15 +25,24 @@ use super::{ApiResponse, PrintResult};
 #[derive(Debug, Deserialize, Default)]
 pub struct MonsterExportOptions {
     pub show_cut_lines: Option<bool>,
+    /// Print one initiative deck per encounter tag instead of every module monster
+    pub encounter_tag_ids: Option<Vec<String>>,
+    /// Add a condition-marker sheet after each initiative deck
+    pub include_condition_markers: Option<bool>,
 }
 
 /// Export module monsters as cards to PDF
+///
+/// With `encounter_tag_ids`, each tag becomes an initiative deck: one card
+/// per creature, numbered duplicates, ordered by initiative modifier, and
+/// labeled with the encounter name and card position.
 #[tauri::command]
 pub fn export_module_monsters(
     app_state: State<'_, AppState>,
     print_state: State<'_, PrintState>,
     module_id: String,
     options: Option<MonsterExportOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("=== export_module_monsters called ===");
     info!("  module_id: {}", module_id);
@@ -38,6 +52,8 @@ pub fn export_module_monsters(
     // Log options
     info!("=== Options ===");
     info!("  show_cut_lines: {:?}", opts.show_cut_lines);
+    info!("  encounter_tag_ids: {:?}", opts.encounter_tag_ids);
+    info!("  include_condition_markers: {:?}", opts.include_condition_markers);
 
     // Get database connection
     let mut db = match app_state.connect() {
@@ -77,8 +93,15 @@ pub fn export_module_monsters(
         );
     }
 
+    // SRD-only campaigns leave out book monsters and credit the SRD instead
+    let srd_only = match dal::get_campaign(&mut db, &module.campaign_id) {
+        Ok(campaign) => campaign.is_srd_only(),
+        Err(e) => return ApiResponse::err(format!("Failed to get campaign: {}", e)),
+    };
+    let mut licenses: Vec<String> = Vec::new();
+
     // Look up each monster's full data from catalog or homebrew
-    let mut monster_data: Vec<Value> = Vec::new();
+    let mut resolved: Vec<(&str, Value, i32)> = Vec::new();
     for mm in &module_monsters {
         let parsed: Option<Value> = if let Some(ref hb_id) = mm.homebrew_monster_id {
             // Look up from homebrew monsters by ID
@@ -131,6 +154,12 @@ pub fn export_module_monsters(
                         None
                     }
                 }
+            } else if srd_only && !record_srd_license(&mut db, name, source, &mut licenses) {
+                info!(
+                    "Skipping non-SRD monster in SRD-only campaign: {} ({})",
+                    name, source
+                );
+                None
             } else {
                 // Look up from catalog
                 match catalog_dal::get_monster_by_name(&mut db, name, source) {
@@ -165,39 +194,100 @@ pub fn export_module_monsters(
                     obj.insert("name".to_string(), Value::String(display_name.clone()));
                 }
             }
-            // Add quantity copies
-            for _ in 0..mm.quantity {
-                monster_data.push(data.clone());
-            }
+            resolved.push((mm.id.as_str(), data, mm.quantity));
         }
     }
 
-    if monster_data.is_empty() {
+    if resolved.is_empty() {
         error!("No valid monster data found for this module");
         return ApiResponse::err("No valid monster data found for this module");
     }
 
+    let title = format!("{} - Monster Cards", module.name);
+    let mut builder = DocumentBuilder::new(&title)
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false);
+
     info!("=== Section ===");
-    info!(
-        "[SECTION] Adding MonsterCardSection with {} monsters",
-        monster_data.len()
-    );
+    let encounter_tag_ids = opts.encounter_tag_ids.unwrap_or_default();
+    if encounter_tag_ids.is_empty() {
+        // Add quantity copies
+        let monster_data: Vec<Value> = resolved
+            .iter()
+            .flat_map(|(_, data, quantity)| (0..*quantity).map(move |_| data.clone()))
+            .collect();
+        info!(
+            "[SECTION] Adding MonsterCardSection with {} monsters",
+            monster_data.len()
+        );
 
-    // Create monster cards section
-    let mut section = MonsterCardSection::new(monster_data);
-    if let Some(show_cut) = opts.show_cut_lines {
-        section = section.with_cut_lines(show_cut);
+        let mut section = MonsterCardSection::new(monster_data);
+        if let Some(show_cut) = opts.show_cut_lines {
+            section = section.with_cut_lines(show_cut);
+        }
+        builder = builder.append(section);
+    } else {
+        let tags = match TagService::new(&mut db).list_for_campaign(&module.campaign_id) {
+            Ok(tags) => tags,
+            Err(e) => return ApiResponse::err(format!("Failed to list tags: {}", e)),
+        };
+
+        let mut has_deck = false;
+        for tag_id in &encounter_tag_ids {
+            let Some(tag) = tags.iter().find(|t| &t.id == tag_id) else {
+                return ApiResponse::err(format!("Encounter tag not found: {}", tag_id));
+            };
+            let tagged = match TagService::new(&mut db).tagged_ids(tag_id, TaggableType::Monster) {
+                Ok(ids) => ids,
+                Err(e) => return ApiResponse::err(format!("Failed to get tagged monsters: {}", e)),
+            };
+
+            let encounter: Vec<(Value, i32)> = resolved
+                .iter()
+                .filter(|(id, _, _)| tagged.contains(*id))
+                .map(|(_, data, quantity)| (data.clone(), *quantity))
+                .collect();
+            let deck = initiative_deck(&tag.name, encounter);
+            if deck.is_empty() {
+                info!("  Encounter '{}' has no monsters - skipping", tag.name);
+                continue;
+            }
+            info!(
+                "[SECTION] Adding initiative deck '{}' with {} cards",
+                tag.name,
+                deck.len()
+            );
+
+            let deck_size = deck.len();
+            let mut section = MonsterCardSection::new(deck);
+            if let Some(show_cut) = opts.show_cut_lines {
+                section = section.with_cut_lines(show_cut);
+            }
+            builder = builder.append(section);
+            if opts.include_condition_markers.unwrap_or(false) {
+                builder = builder.append(
+                    ConditionMarkerSection::new(&tag.name)
+                        .with_copies(deck_size.min(MAX_MARKER_COPIES)),
+                );
+            }
+            has_deck = true;
+        }
+
+        if !has_deck {
+            error!("No monsters carry the selected encounter tags");
+            return ApiResponse::err("No monsters carry the selected encounter tags");
+        }
     }
 
     // Build PDF
     info!("=== Building PDF ===");
-    let title = format!("{} - Monster Cards", module.name);
-    let pdf_result = DocumentBuilder::new(&title)
-        .with_templates_root(print_state.templates_dir.clone())
-        .with_title_page(false)
-        .with_toc(false)
-        .append(section)
-        .to_pdf();
+    let license_refs: Vec<&str> = licenses.iter().map(String::as_str).collect();
+    if let Some(notice) = license_notice(&license_refs) {
+        builder = builder.append(MarkdownSection::from_content(&notice, None));
+    }
+    let pdf_result = builder.to_pdf();
 
     match pdf_result {
         Ok(pdf_bytes) => {
@@ -221,6 +311,89 @@ pub fn export_module_monsters(
     }
 }
 
+/// Most markers printed per condition on a condition-marker sheet
+const MAX_MARKER_COPIES: usize = 4;
+
+/// Expand an encounter's monsters into an initiative deck.
+///
+/// Each creature gets its own card. Repeated names are numbered ("Goblin #2"),
+/// cards are ordered by initiative modifier (highest first, then by name),
+/// and each card is labeled with the encounter and its place in the deck.
+fn initiative_deck(encounter: &str, monsters: Vec<(Value, i32)>) -> Vec<Value> {
+    let name_of = |data: &Value| {
+        data.get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown")
+            .to_string()
+    };
+
+    let mut totals: HashMap<String, i32> = HashMap::new();
+    for (data, quantity) in &monsters {
+        *totals.entry(name_of(data)).or_default() += (*quantity).max(0);
+    }
+
+    let mut numbered: HashMap<String, i32> = HashMap::new();
+    let mut cards: Vec<(i64, String, i32, Value)> = Vec::new();
+    for (data, quantity) in monsters {
+        let name = name_of(&data);
+        let dex = data.get("dex").and_then(|v| v.as_i64()).unwrap_or(10);
+        let init = (dex - 10).div_euclid(2);
+        for _ in 0..quantity {
+            let number = numbered.entry(name.clone()).or_default();
+            *number += 1;
+            let mut card = data.clone();
+            if totals[&name] > 1 {
+                card["name"] = Value::String(format!("{} #{}", name, number));
+            }
+            cards.push((init, name.clone(), *number, card));
+        }
+    }
+
+    cards.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| a.1.cmp(&b.1))
+            .then_with(|| a.2.cmp(&b.2))
+    });
+
+    let total = cards.len();
+    cards
+        .into_iter()
+        .enumerate()
+        .map(|(i, (init, _, _, mut card))| {
+            card["deck_label"] = Value::String(format!(
+                "{} · {} of {} · Init {:+}",
+                encounter,
+                i + 1,
+                total,
+                init
+            ));
+            card
+        })
+        .collect()
+}
+
+/// Note the SRD license of a catalog monster, returning false for book content.
+fn record_srd_license(
+    db: &mut diesel::SqliteConnection,
+    name: &str,
+    source: &str,
+    licenses: &mut Vec<String>,
+) -> bool {
+    match catalog_dal::get_catalog_license(db, "monster", name, source) {
+        Ok(Some(license)) => {
+            if !licenses.contains(&license) {
+                licenses.push(license);
+            }
+            true
+        }
+        Ok(None) => false,
+        Err(e) => {
+            error!("Failed to look up license for {} ({}): {}", name, source, e);
+            false
+        }
+    }
+}
+
 /// Export a single monster as a card to PDF
 #[tauri::command]
 pub fn export_monster_card(
@@ -229,6 +402,7 @@ pub fn export_monster_card(
     monster_name: String,
     monster_source: String,
     options: Option<MonsterExportOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("=== export_monster_card called ===");
     info!("  monster_name: {}", monster_name);
@@ -282,6 +456,7 @@ pub fn export_monster_card(
     let title = format!("{} - Monster Card", monster_name);
     let pdf_result = DocumentBuilder::new(&title)
         .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
         .with_title_page(false)
         .with_toc(false)
         .append(section)
@@ -308,3 +483,36 @@ pub fn export_monster_card(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_initiative_deck_numbers_and_orders_cards() {
+        let goblin = json!({"name": "Goblin", "dex": 14});
+        let ogre = json!({"name": "Ogre", "dex": 8});
+        let deck = initiative_deck("Ambush", vec![(ogre, 1), (goblin, 2)]);
+
+        let names: Vec<&str> = deck.iter().map(|c| c["name"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["Goblin #1", "Goblin #2", "Ogre"]);
+        assert_eq!(deck[0]["deck_label"], "Ambush · 1 of 3 · Init +2");
+        assert_eq!(deck[2]["deck_label"], "Ambush · 3 of 3 · Init -1");
+    }
+
+    #[test]
+    fn test_initiative_deck_numbers_across_entries() {
+        // Two entries for the same creature share one numbering
+        let wolf = json!({"name": "Wolf", "dex": 15});
+        let deck = initiative_deck("Pack", vec![(wolf.clone(), 1), (wolf, 2)]);
+
+        let names: Vec<&str> = deck.iter().map(|c| c["name"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["Wolf #1", "Wolf #2", "Wolf #3"]);
+    }
+
+    #[test]
+    fn test_initiative_deck_empty_encounter() {
+        assert!(initiative_deck("Empty", vec![]).is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+//! Party reference PDF export
+//!
+//! Battle cards for a party's members followed by the stat blocks and
+//! carrying capacity of the mounts and vehicles the party travels with.
+
+use base64::Engine;
+use mimir_core::services::{
+    vehicle_statblock, OwnedVehicleService, PartyCarryingCapacity, PartyService,
+};
+use mimir_print::sections::card_utils::flatten_entries;
+use mimir_print::sections::{CharacterBattleCardSection, CharacterData, MarkdownSection};
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
+use serde_json::Value;
+use tauri::State;
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+use super::character::build_character_data;
+use super::{ApiResponse, PartyReferenceOptions, PrintResult};
+
+/// Export a party reference: member battle cards plus vehicle stat blocks
+#[tauri::command]
+pub fn export_party_reference(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    party_id: String,
+    options: Option<PartyReferenceOptions>,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting party reference for party {}", party_id);
+
+    let opts = options.unwrap_or_default();
+    let include_battle_cards = opts.include_battle_cards.unwrap_or(true);
+    let include_vehicles = opts.include_vehicles.unwrap_or(true);
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let party = match PartyService::new(&mut db).get(&party_id) {
+        Ok(Some(p)) => p,
+        Ok(None) => return ApiResponse::err(format!("Party not found: {}", party_id)),
+        Err(e) => return ApiResponse::err(format!("Failed to get party: {}", e)),
+    };
+
+    let mut builder = DocumentBuilder::new(&party.name)
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false);
+    let mut has_content = false;
+
+    if include_battle_cards {
+        let members = match PartyService::new(&mut db).list_pcs(&party_id) {
+            Ok(m) => m,
+            Err(e) => return ApiResponse::err(format!("Failed to list party members: {}", e)),
+        };
+        if !members.is_empty() {
+            let char_data: Vec<CharacterData> = members
+                .iter()
+                .map(|c| build_character_data(&mut db, &app_state.paths.assets_dir, c))
+                .collect();
+            info!("  Rendering battle cards for {} members", char_data.len());
+            builder = builder.append(CharacterBattleCardSection::new(char_data));
+            has_content = true;
+        }
+    }
+
+    if include_vehicles {
+        let vehicles = match OwnedVehicleService::new(&mut db).list_for_party(&party_id) {
+            Ok(v) => v,
+            Err(e) => return ApiResponse::err(format!("Failed to list vehicles: {}", e)),
+        };
+        if !vehicles.is_empty() {
+            let capacity = match OwnedVehicleService::new(&mut db).party_capacity(&party_id) {
+                Ok(c) => c,
+                Err(e) => return ApiResponse::err(format!("Failed to total capacity: {}", e)),
+            };
+
+            let mut markdown = capacity_markdown(&capacity);
+            for vehicle in &vehicles {
+                match vehicle_statblock(&mut db, vehicle) {
+                    Ok(statblock) => markdown.push_str(&vehicle_markdown(&statblock)),
+                    Err(e) => error!("  Failed to load stat block for {}: {}", vehicle.name, e),
+                }
+            }
+            info!("  Adding {} vehicle stat blocks", vehicles.len());
+            builder = builder.append(MarkdownSection::from_content(
+                &markdown,
+                Some("Mounts & Vehicles"),
+            ));
+            has_content = true;
+        }
+    }
+
+    if !has_content {
+        return ApiResponse::err("Party has no members or vehicles to print");
+    }
+
+    match builder.to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!(
+                "Party reference PDF generated successfully ({} bytes)",
+                size_bytes
+            );
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate party reference PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
+
+/// Summary table of cargo and seats against what the party carries.
+fn capacity_markdown(capacity: &PartyCarryingCapacity) -> String {
+    let mut md = String::from("# Mounts & Vehicles\n\n");
+    md.push_str("| Name | Owner | Cargo (lb) | Seats |\n|---|---|---|---|\n");
+    for vehicle in &capacity.vehicles {
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            vehicle.name,
+            vehicle.owner_name.as_deref().unwrap_or("Party"),
+            format_amount(vehicle.cargo_lb),
+            vehicle.passengers
+        ));
+    }
+    md.push_str(&format!(
+        "\n**Carried:** {} lb of {} lb cargo capacity{}  \n",
+        format_amount(capacity.inventory_weight_lb),
+        format_amount(capacity.cargo_capacity_lb),
+        if capacity.overloaded {
+            " (overloaded)"
+        } else {
+            ""
+        }
+    ));
+    md.push_str(&format!(
+        "**Seats:** {} members, {} seats{}\n\n",
+        capacity.members,
+        capacity.passenger_capacity,
+        if capacity.short_of_seats {
+            " (not enough seats)"
+        } else {
+            ""
+        }
+    ));
+    md
+}
+
+/// Render a mount or vehicle stat block (5etools vehicle or mount item JSON).
+fn vehicle_markdown(statblock: &Value) -> String {
+    let name = statblock["name"].as_str().unwrap_or("Vehicle");
+    let mut md = format!("## {}\n\n", name);
+
+    let mut subtitle = Vec::new();
+    if let Some(catalog_name) = statblock["catalogName"].as_str() {
+        subtitle.push(catalog_name.to_string());
+    }
+    if let Some(source) = statblock["source"].as_str() {
+        subtitle.push(source.to_string());
+    }
+    if !subtitle.is_empty() {
+        md.push_str(&format!("*{}*\n\n", subtitle.join(", ")));
+    }
+
+    let hull = &statblock["hull"];
+    let ac = statblock["ac"].as_i64().or_else(|| hull["ac"].as_i64());
+    let hp = statblock["hp"]
+        .as_i64()
+        .or_else(|| statblock["hp"]["hp"].as_i64())
+        .or_else(|| hull["hp"].as_i64());
+    let mut lines = Vec::new();
+    if let Some(ac) = ac {
+        lines.push(format!("**Armor Class** {}", ac));
+    }
+    if let Some(hp) = hp {
+        let threshold = hull["dt"]
+            .as_i64()
+            .map(|dt| format!("; damage threshold {}", dt))
+            .unwrap_or_default();
+        lines.push(format!("**Hit Points** {}{}", hp, threshold));
+    }
+    if let Some(speed) = speed_text(&statblock["speed"]) {
+        lines.push(format!("**Speed** {}", speed));
+    }
+    if let Some(pace) = statblock["pace"].as_i64() {
+        lines.push(format!("**Travel Pace** {} miles per hour", pace));
+    }
+
+    let mut capacity = Vec::new();
+    if let Some(crew) = statblock["capCrew"].as_i64() {
+        capacity.push(format!("{} crew", crew));
+    }
+    if let Some(passengers) = statblock["capPassenger"].as_i64() {
+        capacity.push(format!("{} passengers", passengers));
+    }
+    if let Some(cargo) = statblock["capCargo"].as_f64() {
+        capacity.push(format!("{} tons cargo", format_amount(cargo)));
+    }
+    if let Some(carrying) = statblock["carryingCapacity"].as_f64() {
+        capacity.push(format!("{} lb", format_amount(carrying)));
+    }
+    if !capacity.is_empty() {
+        lines.push(format!("**Capacity** {}", capacity.join(", ")));
+    }
+    if let Some(immune) = string_list(&statblock["immune"]) {
+        lines.push(format!("**Damage Immunities** {}", immune));
+    }
+    if let Some(weapons) = statblock["weapon"].as_array() {
+        let names: Vec<String> = weapons
+            .iter()
+            .filter_map(|w| {
+                let name = w["name"].as_str()?;
+                Some(match w["count"].as_i64() {
+                    Some(count) if count > 1 => format!("{} ({})", name, count),
+                    _ => name.to_string(),
+                })
+            })
+            .collect();
+        if !names.is_empty() {
+            lines.push(format!("**Weapons** {}", names.join(", ")));
+        }
+    }
+    if !lines.is_empty() {
+        md.push_str(&lines.join("  \n"));
+        md.push_str("\n\n");
+    }
+
+    if let Some(entries) = statblock["entries"].as_array() {
+        let text = flatten_entries(entries);
+        if !text.is_empty() {
+            md.push_str(&text);
+            md.push_str("\n\n");
+        }
+    }
+    md
+}
+
+/// Speed as "30 ft., swim 60 ft." from a number or a speed object.
+fn speed_text(speed: &Value) -> Option<String> {
+    if let Some(walk) = speed.as_i64() {
+        return Some(format!("{} ft.", walk));
+    }
+    let obj = speed.as_object()?;
+    let parts: Vec<String> = ["walk", "swim", "fly", "burrow", "climb"]
+        .iter()
+        .filter_map(|mode| {
+            let feet = obj.get(*mode)?.as_i64()?;
+            Some(if *mode == "walk" {
+                format!("{} ft.", feet)
+            } else {
+                format!("{} {} ft.", mode, feet)
+            })
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Join a list of plain strings (e.g., damage types).
+fn string_list(value: &Value) -> Option<String> {
+    let items: Vec<&str> = value
+        .as_array()?
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    (!items.is_empty()).then(|| items.join(", "))
+}
+
+/// Format a weight or tonnage without a trailing ".0".
+fn format_amount(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ship_statblock_markdown() {
+        let statblock = json!({
+            "name": "Salty Gull",
+            "catalogName": "Keelboat",
+            "source": "GoS",
+            "hull": {"ac": 15, "hp": 100, "dt": 10},
+            "capCrew": 1,
+            "capPassenger": 6,
+            "capCargo": 0.5,
+            "pace": 1,
+            "entries": ["A shallow-draft river boat."]
+        });
+        let md = vehicle_markdown(&statblock);
+        assert!(md.starts_with("## Salty Gull"));
+        assert!(md.contains("*Keelboat, GoS*"));
+        assert!(md.contains("**Hit Points** 100; damage threshold 10"));
+        assert!(md.contains("1 crew, 6 passengers, 0.5 tons cargo"));
+        assert!(md.contains("A shallow-draft river boat."));
+    }
+
+    #[test]
+    fn test_mount_statblock_markdown() {
+        let statblock = json!({
+            "name": "Clover",
+            "source": "PHB",
+            "speed": 60,
+            "carryingCapacity": 480
+        });
+        let md = vehicle_markdown(&statblock);
+        assert!(md.contains("**Speed** 60 ft."));
+        assert!(md.contains("**Capacity** 480 lb"));
+    }
+}
@@ -0,0 +1,235 @@
+//! Campaign retrospective PDF export
+//!
+//! A printable summary of a campaign's analytics: the session log, pacing,
+//! prep cadence, and the monsters and spells that came up most.
+
+use base64::Engine;
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::GameSession;
+use mimir_core::services::{AnalyticsService, CampaignAnalytics, UsageCount};
+use mimir_print::sections::MarkdownSection;
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
+use tauri::State;
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+use super::{ApiResponse, PrintResult};
+
+/// Export a campaign retrospective report as PDF
+#[tauri::command]
+pub fn export_campaign_retrospective(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    campaign_id: String,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!(
+        "Exporting campaign retrospective for campaign {}",
+        campaign_id
+    );
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let analytics = match AnalyticsService::new(&mut db).campaign_analytics(&campaign_id) {
+        Ok(a) => a,
+        Err(e) => return ApiResponse::err(format!("Failed to compute analytics: {}", e)),
+    };
+    let mut sessions = match dal::list_game_sessions(&mut db, &campaign_id) {
+        Ok(s) => s,
+        Err(e) => return ApiResponse::err(format!("Failed to list sessions: {}", e)),
+    };
+    sessions.retain(|s| !s.is_active());
+    sessions.sort_by_key(|s| s.session_number);
+
+    let builder = DocumentBuilder::new(format!("{} Retrospective", analytics.campaign_name))
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false)
+        .append(MarkdownSection::from_content(
+            &retrospective_markdown(&analytics, &sessions),
+            Some("Campaign Retrospective"),
+        ));
+
+    match builder.to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!(
+                "Campaign retrospective PDF generated successfully ({} bytes)",
+                size_bytes
+            );
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate campaign retrospective PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
+
+/// Render the retrospective report.
+fn retrospective_markdown(analytics: &CampaignAnalytics, sessions: &[GameSession]) -> String {
+    let mut md = format!("# {}: Retrospective\n\n", analytics.campaign_name);
+
+    let stats = &analytics.sessions;
+    md.push_str("## At the Table\n\n");
+    md.push_str(&format!(
+        "**Sessions run:** {}  \n**Total play time:** {}  \n**Average session:** {}  \n**XP awarded per character:** {}\n\n",
+        stats.sessions_run,
+        format_duration(stats.total_play_seconds),
+        format_duration(stats.average_play_seconds),
+        stats.total_xp_awarded
+    ));
+
+    if !sessions.is_empty() {
+        md.push_str("| # | Session | Date | Play Time | Combat Turns |\n|---|---|---|---|---|\n");
+        for session in sessions {
+            md.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                session.session_number,
+                session.title.as_deref().unwrap_or("-"),
+                session.started_at.get(..10).unwrap_or(&session.started_at),
+                format_duration(session.play_seconds as i64),
+                session.combat_turns
+            ));
+        }
+        md.push('\n');
+    }
+
+    let combat = &analytics.combat;
+    md.push_str("## Combat\n\n");
+    if combat.combat_turns == 0 {
+        md.push_str("No combat turns were timed.\n\n");
+    } else {
+        md.push_str(&format!(
+            "**Sessions with combat:** {}  \n**Average combat per session:** {}  \n**Average turn:** {}  \n**Longest turn:** {}\n\n",
+            combat.sessions_with_combat,
+            format_duration(combat.average_combat_seconds),
+            format_duration(combat.average_turn_seconds),
+            format_duration(combat.longest_turn_seconds)
+        ));
+    }
+
+    md.push_str("## Prep\n\n");
+    let active_weeks: Vec<_> = analytics
+        .prep_per_week
+        .iter()
+        .filter(|w| w.count > 0)
+        .collect();
+    if active_weeks.is_empty() {
+        md.push_str("No prep documents written.\n\n");
+    } else {
+        md.push_str("| Week of | Documents |\n|---|---|\n");
+        for week in active_weeks {
+            md.push_str(&format!("| {} | {} |\n", week.week_start, week.count));
+        }
+        md.push('\n');
+    }
+
+    md.push_str(&usage_table(
+        "Most-Used Monsters",
+        "Appearances",
+        &analytics.top_monsters,
+    ));
+    md.push_str(&usage_table(
+        "Most-Known Spells",
+        "Characters",
+        &analytics.top_spells,
+    ));
+    md
+}
+
+/// A ranked table of monsters or spells.
+fn usage_table(title: &str, count_label: &str, usage: &[UsageCount]) -> String {
+    let mut md = format!("## {}\n\n", title);
+    if usage.is_empty() {
+        md.push_str("None recorded.\n\n");
+        return md;
+    }
+    md.push_str(&format!(
+        "| Name | Source | {} |\n|---|---|---|\n",
+        count_label
+    ));
+    for entry in usage {
+        md.push_str(&format!(
+            "| {} | {} | {} |\n",
+            entry.name,
+            entry.source.as_deref().unwrap_or("Homebrew"),
+            entry.count
+        ));
+    }
+    md.push('\n');
+    md
+}
+
+/// Format seconds as "2h 05m", "12m 30s", or "45s".
+fn format_duration(seconds: i64) -> String {
+    let (hours, minutes, secs) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mimir_core::services::{CombatStats, SessionStats, WeeklyCount};
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(750), "12m 30s");
+        assert_eq!(format_duration(7500), "2h 05m");
+    }
+
+    #[test]
+    fn test_retrospective_markdown() {
+        let analytics = CampaignAnalytics {
+            campaign_id: "camp-1".to_string(),
+            campaign_name: "Lost Mine".to_string(),
+            sessions: SessionStats {
+                sessions_run: 3,
+                ..Default::default()
+            },
+            combat: CombatStats::default(),
+            prep_per_week: vec![
+                WeeklyCount {
+                    week_start: "2024-03-04".to_string(),
+                    count: 2,
+                },
+                WeeklyCount {
+                    week_start: "2024-03-11".to_string(),
+                    count: 0,
+                },
+            ],
+            top_monsters: vec![UsageCount {
+                name: "Goblin".to_string(),
+                source: Some("MM".to_string()),
+                count: 12,
+            }],
+            top_spells: Vec::new(),
+        };
+        let md = retrospective_markdown(&analytics, &[]);
+        assert!(md.starts_with("# Lost Mine: Retrospective"));
+        assert!(md.contains("**Sessions run:** 3"));
+        assert!(md.contains("No combat turns were timed."));
+        assert!(md.contains("| 2024-03-04 | 2 |"));
+        assert!(!md.contains("2024-03-11"));
+        assert!(md.contains("| Goblin | MM | 12 |"));
+        assert!(md.contains("## Most-Known Spells\n\nNone recorded."));
+    }
+}
@@ -0,0 +1,85 @@
+//! Secret slips PDF export
+//!
+//! Outstanding secret notes printed as small fold-shut slips to hand to
+//! players at the table.
+
+use base64::Engine;
+use mimir_core::dal::campaign as dal;
+use mimir_core::services::SecretNoteService;
+use mimir_print::sections::{SecretSlip, SecretSlipSection};
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
+use tauri::State;
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+use super::{ApiResponse, PrintResult};
+
+/// Export a campaign's outstanding secret notes as slips, optionally only
+/// those for one player
+///
+/// Printing doesn't mark anything delivered.
+#[tauri::command]
+pub fn export_secret_slips(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    campaign_id: String,
+    player_id: Option<String>,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting secret slips for campaign {}", campaign_id);
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let campaign = match dal::get_campaign(&mut db, &campaign_id) {
+        Ok(c) => c,
+        Err(e) => return ApiResponse::err(format!("Campaign not found: {}", e)),
+    };
+    let slips = match SecretNoteService::new(&mut db).slips(&campaign_id, player_id.as_deref()) {
+        Ok(s) => s,
+        Err(e) => return ApiResponse::err(format!("Failed to list secret notes: {}", e)),
+    };
+    if slips.is_empty() {
+        return ApiResponse::err("There are no outstanding secret notes to print");
+    }
+    info!("  Rendering {} slips", slips.len());
+
+    let slips = slips
+        .into_iter()
+        .map(|s| SecretSlip {
+            player_name: s.player_name,
+            title: s.title,
+            content: s.content,
+        })
+        .collect();
+    let builder = DocumentBuilder::new(format!("{} Secret Notes", campaign.name))
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false)
+        .append(SecretSlipSection::new(&campaign.name, slips));
+
+    match builder.to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!(
+                "Secret slips PDF generated successfully ({} bytes)",
+                size_bytes
+            );
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate secret slips PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
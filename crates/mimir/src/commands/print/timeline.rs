@@ -0,0 +1,127 @@
+//! Campaign timeline PDF export
+//!
+//! Every party's timeline events plotted across in-game days, one swimlane
+//! per plotline or faction, for the campaign bible.
+
+use base64::Engine;
+use diesel::SqliteConnection;
+use mimir_core::dal::campaign as dal;
+use mimir_core::services::{CampaignTimelineService, TimelineGrouping};
+use mimir_print::sections::{TimelineChartSection, TimelineEvent, TimelineLane};
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
+use tauri::State;
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+use super::{ApiResponse, PrintResult};
+
+/// Export a campaign's timeline chart as PDF
+#[tauri::command]
+pub fn export_campaign_timeline(
+    app_state: State<'_, AppState>,
+    print_state: State<'_, PrintState>,
+    campaign_id: String,
+    grouping: Option<TimelineGrouping>,
+    profile: Option<PrintProfile>,
+) -> ApiResponse<PrintResult> {
+    info!("Exporting campaign timeline for campaign {}", campaign_id);
+
+    let mut db = match app_state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let campaign = match dal::get_campaign(&mut db, &campaign_id) {
+        Ok(c) => c,
+        Err(e) => return ApiResponse::err(format!("Campaign not found: {}", e)),
+    };
+    let section = match timeline_section(
+        &mut db,
+        &campaign_id,
+        &campaign.name,
+        grouping.unwrap_or_default(),
+    ) {
+        Ok(Some(section)) => section,
+        Ok(None) => return ApiResponse::err("There are no timeline events to chart"),
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let builder = DocumentBuilder::new(format!("{} Timeline", campaign.name))
+        .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
+        .with_title_page(false)
+        .with_toc(false)
+        .append(section);
+
+    match builder.to_pdf() {
+        Ok(pdf_bytes) => {
+            let size_bytes = pdf_bytes.len();
+            let pdf_base64 = base64::engine::general_purpose::STANDARD.encode(&pdf_bytes);
+
+            info!(
+                "Campaign timeline PDF generated successfully ({} bytes)",
+                size_bytes
+            );
+
+            ApiResponse::ok(PrintResult {
+                pdf_base64,
+                size_bytes,
+            })
+        }
+        Err(e) => {
+            error!("Failed to generate campaign timeline PDF: {:?}", e);
+            ApiResponse::err(format!("Failed to generate PDF: {}", e))
+        }
+    }
+}
+
+/// Build the timeline chart of a campaign, or `None` if no party has any
+/// timeline events.
+///
+/// When the campaign has more than one party, each event is labeled with
+/// the party it happened to.
+pub(super) fn timeline_section(
+    db: &mut SqliteConnection,
+    campaign_id: &str,
+    campaign_name: &str,
+    grouping: TimelineGrouping,
+) -> Result<Option<TimelineChartSection>, String> {
+    let lanes = CampaignTimelineService::new(db)
+        .lanes(campaign_id, grouping)
+        .map_err(|e| format!("Failed to load timeline: {}", e))?;
+    if lanes.is_empty() {
+        return Ok(None);
+    }
+
+    let mut parties: Vec<&str> = lanes
+        .iter()
+        .flat_map(|l| l.events.iter().map(|e| e.party_name.as_str()))
+        .collect();
+    parties.sort_unstable();
+    parties.dedup();
+    let label_parties = parties.len() > 1;
+
+    let lanes = lanes
+        .iter()
+        .map(|lane| TimelineLane {
+            name: lane.name.clone(),
+            events: lane
+                .events
+                .iter()
+                .map(|e| TimelineEvent {
+                    day: e.day,
+                    title: if label_parties {
+                        format!("{}: {}", e.party_name, e.title)
+                    } else {
+                        e.title.clone()
+                    },
+                    description: e.description.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+    info!("  Charting timeline with {} parties", parties.len());
+
+    Ok(Some(TimelineChartSection::new(campaign_name, lanes)))
+}
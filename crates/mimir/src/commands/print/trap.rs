@@ -5,7 +5,7 @@
 use base64::Engine;
 use mimir_core::dal::catalog as catalog_dal;
 use mimir_print::sections::TrapCardSection;
-use mimir_print::{DocumentBuilder, PrintState};
+use mimir_print::{DocumentBuilder, PrintProfile, PrintState};
 use serde::Deserialize;
 use serde_json::Value;
 use tauri::State;
@@ -29,6 +29,7 @@ pub fn export_trap_card(
     trap_name: String,
     trap_source: String,
     options: Option<TrapExportOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("=== export_trap_card called ===");
     info!("  trap_name: {}", trap_name);
@@ -81,6 +82,7 @@ pub fn export_trap_card(
     let title = format!("{} - Trap Card", trap_name);
     let pdf_result = DocumentBuilder::new(&title)
         .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
         .with_title_page(false)
         .with_toc(false)
         .append(section)
@@ -115,6 +117,7 @@ pub fn export_trap_cards(
     print_state: State<'_, PrintState>,
     traps: Vec<(String, String)>, // Vec of (name, source) tuples
     options: Option<TrapExportOptions>,
+    profile: Option<PrintProfile>,
 ) -> ApiResponse<PrintResult> {
     info!("=== export_trap_cards called ===");
     info!("  traps_count: {}", traps.len());
@@ -184,6 +187,7 @@ pub fn export_trap_cards(
     info!("=== Building PDF ===");
     let pdf_result = DocumentBuilder::new("Trap Cards")
         .with_templates_root(print_state.templates_dir.clone())
+        .with_profile(profile.unwrap_or_default())
         .with_title_page(false)
         .with_toc(false)
         .append(section)
@@ -0,0 +1,289 @@
+//! Quick Action Commands
+//!
+//! Tauri commands for the DM soundboard: per-campaign quick actions that bundle
+//! several scene changes (fog reveals, lights, token and marker visibility,
+//! handouts, blackout, ambience, timers) behind one button.
+//!
+//! Ambience steps are kept with the action but not played yet, since the app
+//! has no audio player.
+//!
+//! Triggering an action is all-or-nothing. Display steps are checked first
+//! (the target display must be open, handout images must exist), then map
+//! steps are applied in one transaction, and only after that commits are the
+//! display events sent.
+
+use chrono::Utc;
+use mimir_core::models::campaign::{QuickActionResponse, QuickActionStep};
+use mimir_core::services::{
+    CreateQuickActionInput, QuickActionService, UpdateQuickActionInput,
+};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, State};
+
+use super::player_display::{
+    display_screen, read_image_data_url, BlackoutPayload, DisplayScreen, HandoutPayload,
+    HANDOUT_KINDS,
+};
+use super::timer::with_timers;
+use crate::commands::{to_api_response, ApiResponse};
+use crate::state::AppState;
+use crate::timers::TimerKind;
+
+/// Payload emitted as `quick-action:maps-updated` so the DM map window
+/// reloads the changed maps and pushes them to the player display.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapsUpdatedPayload {
+    pub map_ids: Vec<String>,
+}
+
+/// Result of triggering a quick action.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerQuickActionResult {
+    pub action_id: String,
+    /// Maps whose fog, lights, tokens, or markers changed
+    pub map_ids: Vec<String>,
+    /// Number of steps carried out. Ambience steps are skipped because the
+    /// app has no audio player.
+    pub steps_run: usize,
+}
+
+/// A display step that has been checked and is ready to send.
+enum PreparedEffect {
+    Handout(DisplayScreen, HandoutPayload),
+    Blackout(DisplayScreen, BlackoutPayload),
+    Timer(TimerKind, Option<i64>, Option<String>),
+}
+
+/// Check every display step of an action and build its payload.
+///
+/// Runs before any map step so a missing display or image aborts the whole action.
+fn prepare_effects(
+    app: &AppHandle,
+    state: &AppState,
+    steps: &[QuickActionStep],
+) -> Result<Vec<PreparedEffect>, String> {
+    let catalog_dir = state.paths.assets_dir.join("catalog");
+    let mut effects = Vec::new();
+
+    for step in steps {
+        match step {
+            QuickActionStep::SendHandout {
+                kind,
+                image_path,
+                title,
+                screen,
+            } => {
+                if !HANDOUT_KINDS.contains(&kind.as_str()) {
+                    return Err(format!("Unknown handout kind '{}'", kind));
+                }
+                let screen = display_screen(app, screen.as_deref())?;
+                let image_url = read_image_data_url(&catalog_dir, image_path)
+                    .ok_or_else(|| format!("Image not found: {}", image_path))?;
+                effects.push(PreparedEffect::Handout(
+                    screen,
+                    HandoutPayload {
+                        kind: kind.clone(),
+                        title: title.clone(),
+                        image_url,
+                    },
+                ));
+            }
+            QuickActionStep::Blackout { enabled, screen } => {
+                let screen = display_screen(app, screen.as_deref())?;
+                effects.push(PreparedEffect::Blackout(
+                    screen,
+                    BlackoutPayload {
+                        is_blackout: *enabled,
+                    },
+                ));
+            }
+            QuickActionStep::StartTimer {
+                timer,
+                limit_secs,
+                label,
+            } => {
+                let kind = TimerKind::parse(timer)?;
+                effects.push(PreparedEffect::Timer(kind, *limit_secs, label.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(effects)
+}
+
+/// List a campaign's quick actions in soundboard order.
+#[tauri::command]
+pub fn list_quick_actions(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<QuickActionResponse>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(QuickActionService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// Request for creating a quick action.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateQuickActionRequest {
+    pub campaign_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub steps: Vec<QuickActionStep>,
+}
+
+/// Create a quick action at the end of the campaign's soundboard.
+#[tauri::command]
+pub fn create_quick_action(
+    state: State<'_, AppState>,
+    request: CreateQuickActionRequest,
+) -> ApiResponse<QuickActionResponse> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut input = CreateQuickActionInput::new(request.campaign_id, request.name, request.steps);
+    if let Some(description) = request.description {
+        input = input.with_description(description);
+    }
+
+    to_api_response(QuickActionService::new(&mut db).create(input))
+}
+
+/// Request for updating a quick action.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateQuickActionRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub steps: Option<Vec<QuickActionStep>>,
+}
+
+/// Update a quick action's name, description, or steps.
+#[tauri::command]
+pub fn update_quick_action(
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdateQuickActionRequest,
+) -> ApiResponse<QuickActionResponse> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = UpdateQuickActionInput {
+        name: request.name,
+        description: request.description.map(Some),
+        steps: request.steps,
+    };
+
+    to_api_response(QuickActionService::new(&mut db).update(&id, input))
+}
+
+/// Reorder a campaign's quick actions.
+///
+/// `action_ids` lists the campaign's actions in their new order.
+#[tauri::command]
+pub fn reorder_quick_actions(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    action_ids: Vec<String>,
+) -> ApiResponse<Vec<QuickActionResponse>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(QuickActionService::new(&mut db).reorder(&campaign_id, &action_ids))
+}
+
+/// Delete a quick action.
+#[tauri::command]
+pub fn delete_quick_action(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(QuickActionService::new(&mut db).delete(&id))
+}
+
+/// Run every step of a quick action.
+///
+/// Nothing is changed if any step would fail. Map changes are announced as
+/// `quick-action:maps-updated` so the DM map window can refresh and forward
+/// them to the player display.
+#[tauri::command]
+pub fn trigger_quick_action(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<TriggerQuickActionResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+    let mut service = QuickActionService::new(&mut db);
+
+    let action = match service.get(&id) {
+        Ok(Some(action)) => action,
+        Ok(None) => return ApiResponse::err(format!("Quick action not found: {}", id)),
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let effects = match prepare_effects(&app, &state, &action.steps) {
+        Ok(effects) => effects,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let map_ids = match service.apply_map_steps(&action) {
+        Ok(ids) => ids,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    if !map_ids.is_empty() {
+        let payload = MapsUpdatedPayload {
+            map_ids: map_ids.clone(),
+        };
+        if let Err(e) = app.emit("quick-action:maps-updated", payload) {
+            tracing::warn!("Failed to emit quick action map update: {}", e);
+        }
+    }
+
+    for effect in effects {
+        let result = match effect {
+            PreparedEffect::Handout(screen, payload) => app
+                .emit(&screen.event("handout"), payload)
+                .map_err(|e| e.to_string()),
+            PreparedEffect::Blackout(screen, payload) => app
+                .emit(&screen.event("blackout"), payload)
+                .map_err(|e| e.to_string()),
+            PreparedEffect::Timer(kind, limit_secs, label) => with_timers(&app, &state, |timers| {
+                timers.start(kind, Utc::now(), limit_secs, label)
+            })
+            .map(|_| ()),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Quick action {} display step failed: {}", id, e);
+        }
+    }
+
+    let steps_run = action
+        .steps
+        .iter()
+        .filter(|step| !matches!(step, QuickActionStep::PlayAmbience { .. }))
+        .count();
+
+    ApiResponse::ok(TriggerQuickActionResult {
+        action_id: action.id,
+        map_ids,
+        steps_run,
+    })
+}
@@ -0,0 +1,119 @@
+//! NPC Reaction Commands
+//!
+//! Tauri commands for NPC reaction checks, the dispositions and party
+//! reputations they draw on, and each NPC's relationship history.
+
+use mimir_core::models::campaign::{Disposition, NpcInteraction, PartyReputation};
+use mimir_core::services::{
+    ReactionCheck, ReactionCheckInput, ReactionService, RelationshipStandings,
+};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Get a campaign's dispositions and party reputations.
+#[tauri::command]
+pub fn get_relationship_standings(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<RelationshipStandings> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ReactionService::new(&mut db).standings(&campaign_id))
+}
+
+/// Set or clear an NPC's own disposition.
+#[tauri::command]
+pub fn set_npc_disposition(
+    state: State<'_, AppState>,
+    character_id: String,
+    disposition: Option<i32>,
+) -> ApiResponse<Option<Disposition>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ReactionService::new(&mut db).set_npc_disposition(&character_id, disposition))
+}
+
+/// Set or clear a faction's disposition.
+#[tauri::command]
+pub fn set_faction_disposition(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    faction: String,
+    disposition: Option<i32>,
+) -> ApiResponse<Option<Disposition>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ReactionService::new(&mut db).set_faction_disposition(
+        &campaign_id,
+        &faction,
+        disposition,
+    ))
+}
+
+/// Set or clear a party's reputation with a faction.
+#[tauri::command]
+pub fn set_party_reputation(
+    state: State<'_, AppState>,
+    party_id: String,
+    faction: String,
+    reputation: Option<i32>,
+) -> ApiResponse<Option<PartyReputation>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        ReactionService::new(&mut db).set_party_reputation(&party_id, &faction, reputation),
+    )
+}
+
+/// Make a reaction check against an NPC and record it in their history.
+#[tauri::command]
+pub fn roll_npc_reaction(
+    state: State<'_, AppState>,
+    input: ReactionCheckInput,
+) -> ApiResponse<ReactionCheck> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ReactionService::new(&mut db).check(input))
+}
+
+/// Get an NPC's relationship history, most recent first.
+#[tauri::command]
+pub fn list_npc_interactions(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<NpcInteraction>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ReactionService::new(&mut db).history(&character_id))
+}
+
+/// Remove a check from an NPC's relationship history.
+#[tauri::command]
+pub fn delete_npc_interaction(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(ReactionService::new(&mut db).delete_interaction(&id))
+}
@@ -0,0 +1,26 @@
+//! Recap Commands
+//!
+//! Tauri command for writing "previously on" recaps of a campaign's play
+//! history as a DM document and a spoiler-safe player document.
+
+use mimir_core::services::{Recap, RecapScope, RecapService};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Generate a recap of the last session, the last module, or the whole
+/// campaign.
+#[tauri::command]
+pub fn generate_recap(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    scope: RecapScope,
+) -> ApiResponse<Recap> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RecapService::new(&mut db).generate_recap(&campaign_id, scope))
+}
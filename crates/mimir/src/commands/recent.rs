@@ -0,0 +1,181 @@
+//! Recent Items and Favorites Commands
+//!
+//! Tauri commands for the "jump back in" panel: a history of recently opened
+//! catalog entries, documents, and maps, plus pinned favorites.
+
+use mimir_core::models::campaign::{Favorite, ItemKind, RecentItem};
+use mimir_core::services::{FavoriteService, ItemRef, RecentItemService};
+use serde::Deserialize;
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Default number of items returned by `get_recent`.
+const DEFAULT_RECENT_LIMIT: i64 = 20;
+
+/// Parse an item kind ("document", "map", or a catalog kind such as "monster").
+fn parse_kind(value: &str) -> Result<ItemKind, String> {
+    ItemKind::from_str(value).ok_or_else(|| format!("Unknown item kind '{}'", value))
+}
+
+/// Reference to a catalog entry, document, or map.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemRefRequest {
+    /// "document", "map", or a catalog kind such as "monster"
+    pub kind: String,
+    /// Document/map ID, or catalog entry name
+    pub ref_id: String,
+    /// Catalog source code (catalog entries only)
+    pub ref_source: Option<String>,
+    /// Owning campaign (documents and maps only)
+    pub campaign_id: Option<String>,
+    /// Display title; defaults to `ref_id` for catalog entries
+    pub title: Option<String>,
+}
+
+impl ItemRefRequest {
+    fn into_item_ref(self) -> Result<ItemRef, String> {
+        let kind = parse_kind(&self.kind)?;
+        let title = match self.title {
+            Some(title) => title,
+            None if kind.is_catalog() => self.ref_id.clone(),
+            None => return Err(format!("A {} reference requires a title", kind.as_str())),
+        };
+        Ok(ItemRef {
+            kind,
+            ref_id: self.ref_id,
+            ref_source: self.ref_source,
+            campaign_id: self.campaign_id,
+            title,
+        })
+    }
+}
+
+// =============================================================================
+// Recent Items
+// =============================================================================
+
+/// Record that the DM opened an item.
+#[tauri::command]
+pub fn record_recent_item(
+    state: State<'_, AppState>,
+    item: ItemRefRequest,
+) -> ApiResponse<RecentItem> {
+    let item = match item.into_item_ref() {
+        Ok(item) => item,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RecentItemService::new(&mut db).record_view(&item))
+}
+
+/// Get recently opened items, most recent first.
+///
+/// `kind` limits the list to one kind of item; `campaign_id` hides documents
+/// and maps from other campaigns.
+#[tauri::command]
+pub fn get_recent(
+    state: State<'_, AppState>,
+    kind: Option<String>,
+    limit: Option<i64>,
+    campaign_id: Option<String>,
+) -> ApiResponse<Vec<RecentItem>> {
+    let kind = match kind.as_deref().map(parse_kind).transpose() {
+        Ok(kind) => kind,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RecentItemService::new(&mut db).get_recent(
+        kind,
+        campaign_id.as_deref(),
+        limit.unwrap_or(DEFAULT_RECENT_LIMIT),
+    ))
+}
+
+/// Remove one item from the recent history.
+#[tauri::command]
+pub fn remove_recent_item(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RecentItemService::new(&mut db).remove(&id))
+}
+
+/// Clear the recent history.
+#[tauri::command]
+pub fn clear_recent_items(state: State<'_, AppState>) -> ApiResponse<usize> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RecentItemService::new(&mut db).clear())
+}
+
+// =============================================================================
+// Favorites
+// =============================================================================
+
+/// Pin an item to favorites.
+#[tauri::command]
+pub fn pin_favorite(state: State<'_, AppState>, item: ItemRefRequest) -> ApiResponse<Favorite> {
+    let item = match item.into_item_ref() {
+        Ok(item) => item,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(FavoriteService::new(&mut db).pin(&item))
+}
+
+/// Unpin a favorite.
+#[tauri::command]
+pub fn unpin_favorite(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(FavoriteService::new(&mut db).unpin(&id))
+}
+
+/// List favorites in pinned order.
+///
+/// `kind` limits the list to one kind of item; `campaign_id` hides documents
+/// and maps from other campaigns.
+#[tauri::command]
+pub fn list_favorites(
+    state: State<'_, AppState>,
+    kind: Option<String>,
+    campaign_id: Option<String>,
+) -> ApiResponse<Vec<Favorite>> {
+    let kind = match kind.as_deref().map(parse_kind).transpose() {
+        Ok(kind) => kind,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(FavoriteService::new(&mut db).list(kind, campaign_id.as_deref()))
+}
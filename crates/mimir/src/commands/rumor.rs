@@ -0,0 +1,123 @@
+//! Rumor Commands
+//!
+//! Tauri commands for a campaign's rumors and adventure hooks, tracking which
+//! players have heard them, and rolling up tavern rumor tables.
+
+use mimir_core::models::campaign::Rumor;
+use mimir_core::services::{RumorInput, RumorService, RumorWithHearings, TavernRumorTable};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a campaign's rumors with who has heard each one.
+#[tauri::command]
+pub fn list_rumors(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<RumorWithHearings>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).list(&campaign_id))
+}
+
+/// Create a rumor in a campaign.
+#[tauri::command]
+pub fn create_rumor(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: RumorInput,
+) -> ApiResponse<Rumor> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).create(&campaign_id, input))
+}
+
+/// Replace a rumor's text, truth, source, quest, and notes.
+#[tauri::command]
+pub fn update_rumor(
+    state: State<'_, AppState>,
+    id: String,
+    input: RumorInput,
+) -> ApiResponse<Rumor> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).update(&id, input))
+}
+
+/// Delete a rumor.
+#[tauri::command]
+pub fn delete_rumor(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).delete(&id))
+}
+
+/// Record that players heard a rumor, optionally in a given session.
+#[tauri::command]
+pub fn mark_rumor_heard(
+    state: State<'_, AppState>,
+    rumor_id: String,
+    player_ids: Vec<String>,
+    session_id: Option<String>,
+) -> ApiResponse<RumorWithHearings> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).mark_heard(
+        &rumor_id,
+        &player_ids,
+        session_id.as_deref(),
+    ))
+}
+
+/// Remove the record of a player hearing a rumor.
+#[tauri::command]
+pub fn unmark_rumor_heard(
+    state: State<'_, AppState>,
+    rumor_id: String,
+    player_id: String,
+) -> ApiResponse<RumorWithHearings> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).unmark_heard(&rumor_id, &player_id))
+}
+
+/// Roll up a tavern rumor table, favoring rumors the players haven't heard.
+#[tauri::command]
+pub fn generate_tavern_rumor_table(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    size: Option<usize>,
+    module_id: Option<String>,
+    seed: Option<u64>,
+) -> ApiResponse<TavernRumorTable> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(RumorService::new(&mut db).tavern_table(
+        &campaign_id,
+        size,
+        module_id.as_deref(),
+        seed,
+    ))
+}
@@ -0,0 +1,69 @@
+//! Safety Tools Commands
+//!
+//! Tauri commands for a campaign's safety tools: lines, veils, and the table
+//! mechanics agreed at session zero.
+
+use mimir_core::models::campaign::Document;
+use mimir_core::services::{SafetyTools, SafetyToolsService};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Get a campaign's safety tools. Unconfigured campaigns get the defaults.
+#[tauri::command]
+pub fn get_safety_tools(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<SafetyTools> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SafetyToolsService::new(&mut db).get(&campaign_id))
+}
+
+/// Save a campaign's safety tools.
+#[tauri::command]
+pub fn update_safety_tools(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    tools: SafetyTools,
+) -> ApiResponse<SafetyTools> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SafetyToolsService::new(&mut db).update(&campaign_id, tools))
+}
+
+/// Write the session zero "Safety Tools" document from the configuration.
+#[tauri::command]
+pub fn generate_safety_tools_document(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Document> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SafetyToolsService::new(&mut db).generate_document(&campaign_id))
+}
+
+/// Content constraints to add to an assistant's system prompt, or `None` if
+/// the campaign has no lines or veils.
+#[tauri::command]
+pub fn get_safety_prompt(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Option<String>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SafetyToolsService::new(&mut db).prompt_constraints(&campaign_id))
+}
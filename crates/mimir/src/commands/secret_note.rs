@@ -0,0 +1,94 @@
+//! Secret Note Commands
+//!
+//! Tauri commands for per-player secret notes and tracking which have been
+//! delivered.
+
+use mimir_core::models::campaign::SecretNote;
+use mimir_core::services::{SecretNoteInput, SecretNoteService};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List a campaign's secret notes, delivered ones included.
+#[tauri::command]
+pub fn list_secret_notes(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<SecretNote>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SecretNoteService::new(&mut db).list(&campaign_id))
+}
+
+/// Queue a secret note for a player.
+#[tauri::command]
+pub fn create_secret_note(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: SecretNoteInput,
+) -> ApiResponse<SecretNote> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SecretNoteService::new(&mut db).create(&campaign_id, input))
+}
+
+/// Replace a secret note's player, title, content, and module.
+#[tauri::command]
+pub fn update_secret_note(
+    state: State<'_, AppState>,
+    id: String,
+    input: SecretNoteInput,
+) -> ApiResponse<SecretNote> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SecretNoteService::new(&mut db).update(&id, input))
+}
+
+/// Delete a secret note.
+#[tauri::command]
+pub fn delete_secret_note(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SecretNoteService::new(&mut db).delete(&id))
+}
+
+/// Mark a secret note delivered in the session in progress.
+#[tauri::command]
+pub fn mark_secret_note_delivered(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<SecretNote> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SecretNoteService::new(&mut db).mark_delivered(&id))
+}
+
+/// Put a delivered secret note back in the queue.
+#[tauri::command]
+pub fn mark_secret_note_outstanding(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<SecretNote> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(SecretNoteService::new(&mut db).mark_outstanding(&id))
+}
@@ -0,0 +1,66 @@
+//! Settings Commands
+//!
+//! Tauri commands for reading, changing, importing, and exporting the app
+//! settings. Every change is broadcast as a `settings:changed` event so open
+//! windows pick it up without reloading.
+
+use mimir_core::services::{AppSettings, ServiceResult};
+use std::path::Path;
+use tauri::{AppHandle, Emitter, State};
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Event emitted with the new `AppSettings` whenever they change.
+pub const SETTINGS_CHANGED_EVENT: &str = "settings:changed";
+
+/// Get the app settings, with defaults for anything not set.
+#[tauri::command]
+pub fn get_settings(state: State<'_, AppState>) -> ApiResponse<AppSettings> {
+    to_api_response(state.settings().load())
+}
+
+/// Replace the app settings.
+#[tauri::command]
+pub fn update_settings(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    settings: AppSettings,
+) -> ApiResponse<AppSettings> {
+    let result = state.settings().update(settings);
+    to_api_response(settings_changed(&app, &state, result))
+}
+
+/// Replace the app settings with ones exported from another device.
+#[tauri::command]
+pub fn import_settings(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> ApiResponse<AppSettings> {
+    let result = state.settings().import(Path::new(&path));
+    to_api_response(settings_changed(&app, &state, result))
+}
+
+/// Write the app settings to a file, for importing on another device.
+#[tauri::command]
+pub fn export_settings(state: State<'_, AppState>, path: String) -> ApiResponse<()> {
+    to_api_response(state.settings().export(Path::new(&path)))
+}
+
+/// Apply newly saved settings to running services and tell the frontend.
+pub(super) fn settings_changed(
+    app: &AppHandle,
+    state: &AppState,
+    result: ServiceResult<AppSettings>,
+) -> ServiceResult<AppSettings> {
+    if let Ok(ref settings) = result {
+        state
+            .command_trace
+            .set_slow_command_ms(settings.diagnostics.slow_command_ms);
+        if let Err(e) = app.emit(SETTINGS_CHANGED_EVENT, settings) {
+            tracing::warn!("Failed to emit settings change: {}", e);
+        }
+    }
+    result
+}
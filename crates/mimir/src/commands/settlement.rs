@@ -0,0 +1,38 @@
+//! Settlement Commands
+//!
+//! Tauri command for generating settlements: NPCs, shops, rumors, and a
+//! gazetteer entry, optionally pinned on a region map.
+
+use mimir_core::services::{
+    GenerateSettlementInput, Settlement, SettlementCulture, SettlementPin, SettlementService,
+    SettlementSize,
+};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Generate a settlement of a size and culture in a campaign.
+#[tauri::command]
+pub fn generate_settlement(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    size: SettlementSize,
+    culture: SettlementCulture,
+    name: Option<String>,
+    pin: Option<SettlementPin>,
+    seed: Option<u64>,
+) -> ApiResponse<Settlement> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let input = GenerateSettlementInput {
+        name,
+        pin,
+        seed,
+        ..GenerateSettlementInput::new(campaign_id, size, culture)
+    };
+    to_api_response(SettlementService::new(&mut db).generate(input))
+}
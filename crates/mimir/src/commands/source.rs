@@ -4,7 +4,7 @@
 
 use mimir_core::dal::catalog::{self as catalog_dal};
 use mimir_core::import::CatalogImportService;
-use mimir_core::models::catalog::{BookContent, CatalogSource};
+use mimir_core::models::catalog::{is_valid_edition, BookContent, CatalogSource};
 use mimir_core::utils::now_rfc3339;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use flate2::read::GzDecoder;
@@ -30,6 +30,8 @@ pub struct SourceInfo {
     pub enabled: bool,
     /// When imported
     pub imported_at: String,
+    /// Ruleset edition ("2014" or "2024")
+    pub edition: String,
 }
 
 impl From<CatalogSource> for SourceInfo {
@@ -39,6 +41,7 @@ impl From<CatalogSource> for SourceInfo {
             name: source.name,
             enabled: source.enabled != 0,
             imported_at: source.imported_at,
+            edition: source.edition,
         }
     }
 }
@@ -148,6 +151,31 @@ pub fn set_source_enabled(
     }
 }
 
+/// Set the ruleset edition a catalog source is tagged with.
+#[tauri::command]
+pub fn set_source_edition(
+    state: State<'_, AppState>,
+    source_code: String,
+    edition: String,
+) -> ApiResponse<()> {
+    if !is_valid_edition(&edition) {
+        return ApiResponse::err(format!(
+            "Unknown edition '{}'. Must be 2014 or 2024",
+            edition
+        ));
+    }
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    match catalog_dal::set_edition(&mut db, &source_code, &edition) {
+        Ok(_) => ApiResponse::ok(()),
+        Err(e) => ApiResponse::err(format!("Failed to update source: {}", e)),
+    }
+}
+
 /// Delete a catalog source and all its associated entities.
 ///
 /// This cascade deletes all entities (monsters, spells, items, etc.)
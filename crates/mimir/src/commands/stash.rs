@@ -0,0 +1,203 @@
+//! Stash Commands
+//!
+//! Tauri commands for a campaign's shared stash: items and coins held in
+//! common, loot splitting, and the ledger of who took what.
+
+use mimir_core::dal::campaign as dal;
+use mimir_core::models::campaign::{CampaignStashItem, CharacterInventory, StashLedgerEntry};
+use mimir_core::services::{
+    CampaignStash, SplitLootInput, SplitLootResult, StashItemInput, StashService,
+};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Get a campaign's stash.
+#[tauri::command]
+pub fn get_campaign_stash(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<CampaignStash> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(StashService::new(&mut db).get(&campaign_id))
+}
+
+/// Get a campaign's stash ledger, newest first.
+#[tauri::command]
+pub fn get_stash_ledger(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<StashLedgerEntry>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(StashService::new(&mut db).ledger(&campaign_id))
+}
+
+/// Add catalog or homebrew items to the stash.
+#[tauri::command]
+pub fn add_stash_item(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: StashItemInput,
+) -> ApiResponse<CampaignStashItem> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let quantity = input.quantity.unwrap_or(1);
+    let result = StashService::new(&mut db).add_item(&campaign_id, input);
+    if let Ok(ref item) = result {
+        let message = format!("Add {} x {} to stash", quantity, item.item_name);
+        record_history(&state, &mut db, Some(&campaign_id), &message);
+    }
+    to_api_response(result)
+}
+
+/// Remove items from the stash (spent, sold, or lost).
+#[tauri::command]
+pub fn remove_stash_item(
+    state: State<'_, AppState>,
+    id: String,
+    quantity: Option<i32>,
+    note: Option<String>,
+) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let item = dal::get_campaign_stash_item_optional(&mut db, &id)
+        .ok()
+        .flatten();
+    let result = StashService::new(&mut db).remove_item(&id, quantity, note.as_deref());
+    if let (Ok(()), Some(item)) = (&result, item) {
+        let message = format!("Remove {} from stash", item.item_name);
+        record_history(&state, &mut db, Some(&item.campaign_id), &message);
+    }
+    to_api_response(result)
+}
+
+/// Move items from the stash into a character's inventory.
+#[tauri::command]
+pub fn take_stash_item(
+    state: State<'_, AppState>,
+    id: String,
+    character_id: String,
+    quantity: Option<i32>,
+) -> ApiResponse<CharacterInventory> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let item = dal::get_campaign_stash_item_optional(&mut db, &id)
+        .ok()
+        .flatten();
+    let result = StashService::new(&mut db).take_item(&id, &character_id, quantity);
+    if let (Ok(_), Some(item)) = (&result, item) {
+        let message = format!("Take {} from stash", item.item_name);
+        record_history(&state, &mut db, Some(&item.campaign_id), &message);
+    }
+    to_api_response(result)
+}
+
+/// Add coins ([CP, SP, EP, GP, PP]) to the stash, returning the new balance.
+#[tauri::command]
+pub fn deposit_stash_currency(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    coins: [i32; 5],
+    note: Option<String>,
+) -> ApiResponse<[i32; 5]> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = StashService::new(&mut db).deposit_currency(&campaign_id, coins, note.as_deref());
+    if result.is_ok() {
+        record_history(
+            &state,
+            &mut db,
+            Some(&campaign_id),
+            "Deposit coins in stash",
+        );
+    }
+    to_api_response(result)
+}
+
+/// Remove coins from the stash, returning the new balance.
+#[tauri::command]
+pub fn withdraw_stash_currency(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    coins: [i32; 5],
+    note: Option<String>,
+) -> ApiResponse<[i32; 5]> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = StashService::new(&mut db).withdraw_currency(&campaign_id, coins, note.as_deref());
+    if result.is_ok() {
+        record_history(
+            &state,
+            &mut db,
+            Some(&campaign_id),
+            "Withdraw coins from stash",
+        );
+    }
+    to_api_response(result)
+}
+
+/// Move coins from the stash into a character's purse, returning the
+/// stash's new balance.
+#[tauri::command]
+pub fn take_stash_currency(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    character_id: String,
+    coins: [i32; 5],
+) -> ApiResponse<[i32; 5]> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = StashService::new(&mut db).take_currency(&campaign_id, &character_id, coins);
+    if result.is_ok() {
+        record_history(&state, &mut db, Some(&campaign_id), "Take coins from stash");
+    }
+    to_api_response(result)
+}
+
+/// Split the stash's coins evenly and hand out items to characters.
+#[tauri::command]
+pub fn split_stash_loot(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    input: SplitLootInput,
+) -> ApiResponse<SplitLootResult> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = StashService::new(&mut db).split_loot(&campaign_id, input);
+    if let Ok(ref split) = result {
+        let message = format!("Split loot between {} characters", split.shares.len());
+        record_history(&state, &mut db, Some(&campaign_id), &message);
+    }
+    to_api_response(result)
+}
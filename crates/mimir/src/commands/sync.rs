@@ -0,0 +1,121 @@
+//! Sync Commands
+//!
+//! Tauri commands for syncing campaigns between devices through a folder,
+//! WebDAV server, or S3-compatible bucket.
+
+use mimir_core::sync::{
+    CampaignSyncState, ConflictResolution, RemoteCampaign, SyncBackendConfig, SyncConfig,
+    SyncReport, SyncService,
+};
+use tauri::{AppHandle, Manager, State};
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Get the sync settings, or `None` if sync is not set up.
+#[tauri::command]
+pub fn get_sync_config(state: State<'_, AppState>) -> ApiResponse<Option<SyncConfig>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        SyncService::new(&mut db, &state.paths.app_dir, &state.paths.assets_dir).config(),
+    )
+}
+
+/// Set the backend campaigns are synced through.
+///
+/// `secret` is the WebDAV password or S3 secret access key, stored in the OS
+/// keychain. Leave it out to keep the stored one.
+#[tauri::command]
+pub fn configure_sync(
+    state: State<'_, AppState>,
+    backend: SyncBackendConfig,
+    device_name: String,
+    secret: Option<String>,
+) -> ApiResponse<SyncConfig> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        SyncService::new(&mut db, &state.paths.app_dir, &state.paths.assets_dir).configure(
+            backend,
+            &device_name,
+            secret.as_deref(),
+        ),
+    )
+}
+
+/// Get a campaign's sync state, including unresolved conflicts.
+#[tauri::command]
+pub fn get_campaign_sync_state(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<CampaignSyncState> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        SyncService::new(&mut db, &state.paths.app_dir, &state.paths.assets_dir)
+            .campaign_state(&campaign_id),
+    )
+}
+
+/// List the campaigns on the remote, including ones not on this device yet.
+#[tauri::command]
+pub async fn list_remote_campaigns(app: AppHandle) -> ApiResponse<Vec<RemoteCampaign>> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        SyncService::new(&mut db, &state.paths.app_dir, &state.paths.assets_dir)
+            .list_remote_campaigns()
+            .await,
+    )
+}
+
+/// Sync a campaign with the remote, pulling it if it isn't on this device.
+#[tauri::command]
+pub async fn sync_campaign(app: AppHandle, campaign_id: String) -> ApiResponse<SyncReport> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        SyncService::new(&mut db, &state.paths.app_dir, &state.paths.assets_dir)
+            .sync_campaign(&campaign_id)
+            .await,
+    )
+}
+
+/// Settle a campaign's sync conflicts by keeping the local or remote side of
+/// each, then sync again.
+#[tauri::command]
+pub async fn resolve_sync_conflicts(
+    app: AppHandle,
+    campaign_id: String,
+    resolutions: Vec<ConflictResolution>,
+) -> ApiResponse<SyncReport> {
+    let state = app.state::<AppState>();
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(
+        SyncService::new(&mut db, &state.paths.app_dir, &state.paths.assets_dir)
+            .resolve_conflicts(&campaign_id, &resolutions)
+            .await,
+    )
+}
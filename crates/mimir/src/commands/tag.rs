@@ -0,0 +1,201 @@
+//! Tag Commands
+//!
+//! Tauri commands for campaign-wide tags. Tags can be attached to documents,
+//! NPCs, module monsters, homebrew monsters, maps, and assets; list commands
+//! for those entities accept an optional `tag_id` filter.
+
+use diesel::SqliteConnection;
+use mimir_core::models::campaign::{EntityTag, Tag, TaggableType};
+use mimir_core::services::TagService;
+use serde::Deserialize;
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// Parse an entity type ("document", "npc", "monster", "homebrew_monster", "map", "asset").
+fn parse_entity_type(value: &str) -> Result<TaggableType, String> {
+    TaggableType::from_str(value).ok_or_else(|| {
+        format!(
+            "Unknown entity type '{}'. Expected document, npc, monster, homebrew_monster, map, or asset",
+            value
+        )
+    })
+}
+
+/// Keep only the items carrying a tag. With no tag, items are returned unchanged.
+pub(crate) fn filter_by_tag<T>(
+    conn: &mut SqliteConnection,
+    tag_id: Option<&str>,
+    entity_type: TaggableType,
+    items: Vec<T>,
+    id_of: impl Fn(&T) -> &str,
+) -> Result<Vec<T>, String> {
+    let Some(tag_id) = tag_id else {
+        return Ok(items);
+    };
+
+    let ids = TagService::new(conn)
+        .tagged_ids(tag_id, entity_type)
+        .map_err(|e| e.to_string())?;
+    Ok(items
+        .into_iter()
+        .filter(|item| ids.contains(id_of(item)))
+        .collect())
+}
+
+// =============================================================================
+// Tag Commands
+// =============================================================================
+
+/// List all tags for a campaign, alphabetically.
+#[tauri::command]
+pub fn list_tags(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<Vec<Tag>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// Create a tag for a campaign.
+#[tauri::command]
+pub fn create_tag(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    name: String,
+    color: Option<String>,
+) -> ApiResponse<Tag> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).create(&campaign_id, &name, color.as_deref()))
+}
+
+/// Request for updating a tag.
+#[derive(Debug, Deserialize)]
+pub struct UpdateTagRequest {
+    pub name: Option<String>,
+    /// New color; an empty string clears it
+    pub color: Option<String>,
+}
+
+/// Rename or recolor a tag.
+#[tauri::command]
+pub fn update_tag(
+    state: State<'_, AppState>,
+    id: String,
+    request: UpdateTagRequest,
+) -> ApiResponse<Tag> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let color = request
+        .color
+        .as_deref()
+        .map(|c| if c.is_empty() { None } else { Some(c) });
+
+    to_api_response(TagService::new(&mut db).update(&id, request.name.as_deref(), color))
+}
+
+/// Delete a tag, removing it from everything it was on.
+#[tauri::command]
+pub fn delete_tag(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).delete(&id))
+}
+
+// =============================================================================
+// Tagging Commands
+// =============================================================================
+
+/// Attach a tag to an entity by name, creating the tag if it doesn't exist yet.
+#[tauri::command]
+pub fn tag_entity(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    entity_type: String,
+    entity_id: String,
+    tag_name: String,
+) -> ApiResponse<Tag> {
+    let entity_type = match parse_entity_type(&entity_type) {
+        Ok(t) => t,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).tag_entity(
+        &campaign_id,
+        entity_type,
+        &entity_id,
+        &tag_name,
+    ))
+}
+
+/// Detach a tag from an entity.
+#[tauri::command]
+pub fn untag_entity(
+    state: State<'_, AppState>,
+    tag_id: String,
+    entity_type: String,
+    entity_id: String,
+) -> ApiResponse<()> {
+    let entity_type = match parse_entity_type(&entity_type) {
+        Ok(t) => t,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).untag_entity(&tag_id, entity_type, &entity_id))
+}
+
+/// List the tags attached to an entity.
+#[tauri::command]
+pub fn list_entity_tags(
+    state: State<'_, AppState>,
+    entity_type: String,
+    entity_id: String,
+) -> ApiResponse<Vec<Tag>> {
+    let entity_type = match parse_entity_type(&entity_type) {
+        Ok(t) => t,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).tags_for_entity(entity_type, &entity_id))
+}
+
+/// List everything carrying a tag, across entity types.
+#[tauri::command]
+pub fn list_tagged_entities(
+    state: State<'_, AppState>,
+    tag_id: String,
+) -> ApiResponse<Vec<EntityTag>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(TagService::new(&mut db).tagged_entities(&tag_id))
+}
@@ -0,0 +1,340 @@
+//! Table Timer Commands
+//!
+//! Tauri commands for the turn timer, break timer, and session clock.
+//! Every change is broadcast as a `timers:update` event so the DM windows and
+//! player displays stay in sync. Session clock totals are written to the
+//! active game session record whenever the clock is paused or the session ends.
+//! Only one game session is timed at a time; a session still open when the app
+//! closed is picked up again on the next start.
+
+use chrono::Utc;
+use mimir_core::models::campaign::GameSession;
+use mimir_core::services::{GameSessionService, SessionTotals, StartSessionInput};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::commands::{to_api_response, ApiResponse};
+use crate::state::AppState;
+use crate::timers::{TableTimers, TimerKind, TimersSnapshot};
+
+/// Lock the timers, apply `f`, broadcast the new state, and return the snapshot.
+pub(crate) fn with_timers<F>(app: &AppHandle, state: &AppState, f: F) -> Result<TimersSnapshot, String>
+where
+    F: FnOnce(&mut TableTimers),
+{
+    let snapshot = {
+        let mut timers = state
+            .timers
+            .lock()
+            .map_err(|_| "Timer state is poisoned".to_string())?;
+        f(&mut timers);
+        timers.snapshot(Utc::now())
+    };
+
+    if let Err(e) = app.emit("timers:update", &snapshot) {
+        tracing::warn!("Failed to emit timers update: {}", e);
+    }
+
+    Ok(snapshot)
+}
+
+/// Write the current session clock totals to the active game session, if any.
+fn persist_session_totals(state: &AppState, snapshot: &TimersSnapshot) -> Result<(), String> {
+    let Some(ref session_id) = snapshot.session_id else {
+        return Ok(());
+    };
+    let mut db = state.connect()?;
+    GameSessionService::new(&mut db)
+        .record_totals(session_id, &snapshot.totals)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Reattach the timers to the game session left in progress when the app last
+/// closed, so it can be resumed or ended. Older sessions still marked in
+/// progress can no longer be timed and are ended with their recorded totals.
+pub fn restore_game_session(state: &AppState) {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::warn!("Failed to restore game session: {}", e);
+            return;
+        }
+    };
+    let mut service = GameSessionService::new(&mut db);
+    let active = match service.list_active() {
+        Ok(active) => active,
+        Err(e) => {
+            tracing::warn!("Failed to restore game session: {}", e);
+            return;
+        }
+    };
+
+    let mut sessions = active.into_iter();
+    if let Some(latest) = sessions.next() {
+        if let Ok(mut timers) = state.timers.lock() {
+            timers.resume_session(latest.id.clone(), &SessionTotals::recorded(&latest));
+        }
+        tracing::info!(session_id = %latest.id, "Restored game session in progress");
+    }
+    for stale in sessions {
+        if let Err(e) = service.abandon(&stale.id) {
+            tracing::warn!(session_id = %stale.id, "Failed to end stale game session: {}", e);
+        }
+    }
+}
+
+/// Get the current state of all table timers.
+#[tauri::command]
+pub fn get_timers(state: State<'_, AppState>) -> ApiResponse<TimersSnapshot> {
+    match state.timers.lock() {
+        Ok(timers) => ApiResponse::ok(timers.snapshot(Utc::now())),
+        Err(_) => ApiResponse::err("Timer state is poisoned"),
+    }
+}
+
+/// Start or resume a timer ("turn", "break", or "session").
+///
+/// `duration_secs` makes the timer count down; `label` names whose turn it is
+/// or what the break is for.
+#[tauri::command]
+pub fn start_timer(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    kind: String,
+    duration_secs: Option<i64>,
+    label: Option<String>,
+) -> ApiResponse<TimersSnapshot> {
+    let kind = match TimerKind::parse(&kind) {
+        Ok(k) => k,
+        Err(e) => return ApiResponse::err(e),
+    };
+    if duration_secs.is_some_and(|d| d <= 0) {
+        return ApiResponse::err("Timer duration must be positive");
+    }
+
+    to_api_response(with_timers(&app, &state, |timers| {
+        timers.start(kind, Utc::now(), duration_secs, label)
+    }))
+}
+
+/// Pause a timer.
+///
+/// Pausing the session clock saves the session totals so far.
+#[tauri::command]
+pub fn pause_timer(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    kind: String,
+) -> ApiResponse<TimersSnapshot> {
+    let kind = match TimerKind::parse(&kind) {
+        Ok(k) => k,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let snapshot = match with_timers(&app, &state, |timers| timers.pause(kind, Utc::now())) {
+        Ok(s) => s,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    if kind == TimerKind::Session {
+        if let Err(e) = persist_session_totals(&state, &snapshot) {
+            return ApiResponse::err(e);
+        }
+    }
+
+    ApiResponse::ok(snapshot)
+}
+
+/// Reset a timer to zero and stop it.
+///
+/// Resetting the break timer adds the finished break to the session's break total.
+#[tauri::command]
+pub fn reset_timer(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    kind: String,
+) -> ApiResponse<TimersSnapshot> {
+    let kind = match TimerKind::parse(&kind) {
+        Ok(k) => k,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(with_timers(&app, &state, |timers| {
+        timers.reset(kind, Utc::now())
+    }))
+}
+
+/// End the current combat turn and start timing the next.
+#[tauri::command]
+pub fn next_turn_timer(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    label: Option<String>,
+) -> ApiResponse<TimersSnapshot> {
+    to_api_response(with_timers(&app, &state, |timers| {
+        timers.next_turn(Utc::now(), label)
+    }))
+}
+
+/// Start a game session and its session clock.
+///
+/// Creates the session record; all timers are reset. Fails while another
+/// session is being timed, which must be ended or abandoned first.
+#[tauri::command]
+pub fn start_game_session(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    campaign_id: String,
+    module_id: Option<String>,
+    title: Option<String>,
+    party_id: Option<String>,
+) -> ApiResponse<GameSession> {
+    match state.timers.lock() {
+        Ok(timers) if timers.active_session_id().is_some() => {
+            return ApiResponse::err(
+                "Another game session is still in progress. End or abandon it first",
+            )
+        }
+        Ok(_) => {}
+        Err(_) => return ApiResponse::err("Timer state is poisoned"),
+    }
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut input = StartSessionInput::new(campaign_id);
+    if let Some(module_id) = module_id {
+        input = input.with_module(module_id);
+    }
+    if let Some(title) = title {
+        input = input.with_title(title);
+    }
+    if let Some(party_id) = party_id {
+        input = input.with_party(party_id);
+    }
+
+    let session = match GameSessionService::new(&mut db).start(input) {
+        Ok(s) => s,
+        Err(e) => return ApiResponse::err(e.to_string()),
+    };
+
+    let session_id = session.id.clone();
+    match with_timers(&app, &state, |timers| timers.begin_session(session_id, Utc::now())) {
+        Ok(_) => ApiResponse::ok(session),
+        Err(e) => ApiResponse::err(e),
+    }
+}
+
+/// End the active game session, saving its final pacing totals.
+#[tauri::command]
+pub fn end_game_session(app: AppHandle, state: State<'_, AppState>) -> ApiResponse<GameSession> {
+    let mut ended = None;
+    if let Err(e) = with_timers(&app, &state, |timers| ended = timers.end_session(Utc::now())) {
+        return ApiResponse::err(e);
+    }
+
+    let Some((session_id, totals)) = ended else {
+        return ApiResponse::err("No game session in progress");
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(GameSessionService::new(&mut db).end(&session_id, &totals))
+}
+
+/// End a game session without the table timers, e.g. one left open in
+/// another campaign.
+///
+/// If it is the session being timed, the timers stop and their totals are
+/// saved; otherwise the totals last recorded on the session are kept.
+#[tauri::command]
+pub fn abandon_game_session(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+) -> ApiResponse<GameSession> {
+    let mut ended = None;
+    let result = with_timers(&app, &state, |timers| {
+        if timers.active_session_id() == Some(session_id.as_str()) {
+            ended = timers.end_session(Utc::now());
+        }
+    });
+    if let Err(e) = result {
+        return ApiResponse::err(e);
+    }
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut service = GameSessionService::new(&mut db);
+    to_api_response(match ended {
+        Some((session_id, totals)) => service.end(&session_id, &totals),
+        None => service.abandon(&session_id),
+    })
+}
+
+/// List all game sessions for a campaign, most recent first.
+///
+/// `party_id` limits the list to sessions played by that party.
+#[tauri::command]
+pub fn list_game_sessions(
+    state: State<'_, AppState>,
+    campaign_id: String,
+    party_id: Option<String>,
+) -> ApiResponse<Vec<GameSession>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let mut service = GameSessionService::new(&mut db);
+    to_api_response(match party_id {
+        Some(party_id) => service.list_for_party(&party_id).map(|sessions| {
+            sessions
+                .into_iter()
+                .filter(|s| s.campaign_id == campaign_id)
+                .collect()
+        }),
+        None => service.list_for_campaign(&campaign_id),
+    })
+}
+
+/// Set the XP each party member earned in a session.
+#[tauri::command]
+pub fn award_session_xp(
+    state: State<'_, AppState>,
+    session_id: String,
+    xp: i32,
+) -> ApiResponse<GameSession> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(GameSessionService::new(&mut db).award_xp(&session_id, xp))
+}
+
+/// Attach a transcript to a game session.
+///
+/// `transcript` is the contents of a speech-to-text export (plain text, WebVTT,
+/// or SRT); an empty string clears it.
+#[tauri::command]
+pub fn set_session_transcript(
+    state: State<'_, AppState>,
+    session_id: String,
+    transcript: String,
+) -> ApiResponse<GameSession> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(GameSessionService::new(&mut db).set_transcript(&session_id, &transcript))
+}
@@ -0,0 +1,66 @@
+//! Trash Commands
+//!
+//! Tauri commands for listing, restoring, and purging trashed documents,
+//! characters, and maps.
+
+use mimir_core::services::{TrashKind, TrashService, TrashedItem};
+use tauri::State;
+
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List trashed items, most recently trashed first.
+///
+/// With `campaign_id`, only that campaign's items are listed.
+#[tauri::command]
+pub fn list_trashed_items(
+    state: State<'_, AppState>,
+    campaign_id: Option<String>,
+) -> ApiResponse<Vec<TrashedItem>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = TrashService::new(&mut db, &state.paths.app_dir).list(campaign_id.as_deref());
+    to_api_response(result)
+}
+
+/// Restore a trashed item.
+///
+/// `kind` is "document", "character", or "map".
+#[tauri::command]
+pub fn restore_item(state: State<'_, AppState>, kind: String, id: String) -> ApiResponse<()> {
+    let kind = match TrashKind::from_str(&kind) {
+        Some(kind) => kind,
+        None => return ApiResponse::err(format!("Unknown item kind '{}'", kind)),
+    };
+
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = TrashService::new(&mut db, &state.paths.app_dir).restore(kind, &id);
+    to_api_response(result)
+}
+
+/// Permanently delete trashed items, returning how many were purged.
+///
+/// With `expired_only`, only items trashed more than 30 days ago are removed;
+/// otherwise the whole trash (or the campaign's part of it) is emptied.
+#[tauri::command]
+pub fn purge_trash(
+    state: State<'_, AppState>,
+    campaign_id: Option<String>,
+    expired_only: Option<bool>,
+) -> ApiResponse<usize> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = TrashService::new(&mut db, &state.paths.app_dir)
+        .purge(campaign_id.as_deref(), expired_only.unwrap_or(false));
+    to_api_response(result)
+}
@@ -0,0 +1,15 @@
+//! Travel Commands
+//!
+//! Tauri command for planning overland journeys: days on the road, forced
+//! march saves, random encounter checks, and food and water.
+
+use mimir_core::services::{plan_travel as plan, TravelPlan, TravelRequest};
+
+use super::{to_api_response, ApiResponse};
+
+/// Plan a journey over a distance for a party's speeds, pace, terrain, and
+/// weather.
+#[tauri::command]
+pub fn plan_travel(request: TravelRequest) -> ApiResponse<TravelPlan> {
+    to_api_response(plan(&request))
+}
@@ -0,0 +1,137 @@
+//! Owned Vehicle Commands
+//!
+//! Tauri commands for mounts and vehicles owned by characters and parties,
+//! and a party's cargo and passenger capacity.
+
+use mimir_core::models::campaign::OwnedVehicle;
+use mimir_core::services::{
+    AssignVehicleInput, OwnedVehicleService, PartyCarryingCapacity, UpdateOwnedVehicleInput,
+};
+use tauri::State;
+
+use super::history::record_history;
+use super::{to_api_response, ApiResponse};
+use crate::state::AppState;
+
+/// List every mount and vehicle owned in a campaign.
+#[tauri::command]
+pub fn list_campaign_vehicles(
+    state: State<'_, AppState>,
+    campaign_id: String,
+) -> ApiResponse<Vec<OwnedVehicle>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OwnedVehicleService::new(&mut db).list_for_campaign(&campaign_id))
+}
+
+/// List a character's own mounts and vehicles.
+#[tauri::command]
+pub fn list_character_vehicles(
+    state: State<'_, AppState>,
+    character_id: String,
+) -> ApiResponse<Vec<OwnedVehicle>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OwnedVehicleService::new(&mut db).list_for_character(&character_id))
+}
+
+/// List the mounts and vehicles available to a party, including those its
+/// members own.
+#[tauri::command]
+pub fn list_party_vehicles(
+    state: State<'_, AppState>,
+    party_id: String,
+) -> ApiResponse<Vec<OwnedVehicle>> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OwnedVehicleService::new(&mut db).list_for_party(&party_id))
+}
+
+/// Assign a catalog mount or vehicle to a character or party.
+#[tauri::command]
+pub fn assign_vehicle(
+    state: State<'_, AppState>,
+    input: AssignVehicleInput,
+) -> ApiResponse<OwnedVehicle> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let result = OwnedVehicleService::new(&mut db).assign(input);
+    if let Ok(ref vehicle) = result {
+        let message = format!("Add {} '{}'", vehicle.vehicle_kind, vehicle.name);
+        record_history(&state, &mut db, Some(&vehicle.campaign_id), &message);
+    }
+    to_api_response(result)
+}
+
+/// Rename an owned vehicle, edit its notes, or hand it to another owner.
+#[tauri::command]
+pub fn update_owned_vehicle(
+    state: State<'_, AppState>,
+    id: String,
+    input: UpdateOwnedVehicleInput,
+) -> ApiResponse<OwnedVehicle> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OwnedVehicleService::new(&mut db).update(&id, input))
+}
+
+/// Remove a mount or vehicle from its owner.
+#[tauri::command]
+pub fn remove_owned_vehicle(state: State<'_, AppState>, id: String) -> ApiResponse<()> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let vehicle = OwnedVehicleService::new(&mut db).get(&id).ok();
+    let result = OwnedVehicleService::new(&mut db).remove(&id);
+    if let (Ok(()), Some(vehicle)) = (&result, vehicle) {
+        let message = format!("Remove {} '{}'", vehicle.vehicle_kind, vehicle.name);
+        record_history(&state, &mut db, Some(&vehicle.campaign_id), &message);
+    }
+    to_api_response(result)
+}
+
+/// Get an owned vehicle's catalog stat block, named for the vehicle.
+#[tauri::command]
+pub fn get_owned_vehicle_statblock(
+    state: State<'_, AppState>,
+    id: String,
+) -> ApiResponse<serde_json::Value> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OwnedVehicleService::new(&mut db).statblock(&id))
+}
+
+/// Get a party's cargo and passenger capacity against what its members
+/// carry.
+#[tauri::command]
+pub fn get_party_capacity(
+    state: State<'_, AppState>,
+    party_id: String,
+) -> ApiResponse<PartyCarryingCapacity> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    to_api_response(OwnedVehicleService::new(&mut db).party_capacity(&party_id))
+}
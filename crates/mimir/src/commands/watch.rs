@@ -0,0 +1,63 @@
+//! Document Watch Commands
+//!
+//! Tauri commands for watch mode: export a campaign to a folder and keep
+//! syncing edits made to its document files back into the app.
+
+use mimir_core::services::MarkdownExportService;
+use std::path::Path;
+use tauri::{AppHandle, State};
+
+use super::ApiResponse;
+use crate::state::AppState;
+use crate::watchers::WatchedFolder;
+
+/// Export a campaign as markdown to `<dest>/<campaign-slug>/` and watch that
+/// folder for changes made in external editors.
+///
+/// Edited, new, and deleted document files are applied to the campaign's
+/// documents and announced with a `documents:synced` event.
+#[tauri::command]
+pub fn start_document_watch(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    campaign_id: String,
+    dest: String,
+) -> ApiResponse<WatchedFolder> {
+    let mut db = match state.connect() {
+        Ok(db) => db,
+        Err(e) => return ApiResponse::err(e),
+    };
+
+    let export =
+        match MarkdownExportService::new(&mut db).export_campaign(&campaign_id, Path::new(&dest)) {
+            Ok(export) => export,
+            Err(e) => return ApiResponse::err(e.to_string()),
+        };
+
+    let mut watchers = match state.watchers.lock() {
+        Ok(watchers) => watchers,
+        Err(_) => return ApiResponse::err("Watcher state is poisoned".to_string()),
+    };
+    match watchers.start(app, state.paths.database_url(), &campaign_id, &export.root) {
+        Ok(folder) => ApiResponse::ok(folder),
+        Err(e) => ApiResponse::err(e),
+    }
+}
+
+/// Stop watching a campaign's folder. Returns false if it wasn't watched.
+#[tauri::command]
+pub fn stop_document_watch(state: State<'_, AppState>, campaign_id: String) -> ApiResponse<bool> {
+    match state.watchers.lock() {
+        Ok(mut watchers) => ApiResponse::ok(watchers.stop(&campaign_id)),
+        Err(_) => ApiResponse::err("Watcher state is poisoned".to_string()),
+    }
+}
+
+/// List the folders currently being watched.
+#[tauri::command]
+pub fn list_document_watches(state: State<'_, AppState>) -> ApiResponse<Vec<WatchedFolder>> {
+    match state.watchers.lock() {
+        Ok(watchers) => ApiResponse::ok(watchers.list()),
+        Err(_) => ApiResponse::err("Watcher state is poisoned".to_string()),
+    }
+}
@@ -2,51 +2,190 @@
 //!
 //! Error handling for Tauri commands, converting service errors to serializable responses.
 
-use mimir_core::services::ServiceError;
+use mimir_core::services::{ErrorKind, ServiceError};
+use mimir_print::PrintError;
 use serde::Serialize;
+use std::fmt;
 
 /// Error type returned from Tauri commands.
 ///
-/// This wraps service errors and makes them serializable for the frontend.
-#[derive(Debug, Serialize)]
+/// Every command serializes failures in this shape (as `error_details` on
+/// [`ApiResponse`](crate::commands::ApiResponse)) so the frontend can branch
+/// on `kind` and offer `suggestion` instead of parsing messages.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CommandError {
+    /// What kind of failure this is.
+    pub kind: ErrorKind,
+    /// The type of entity involved (e.g., "Campaign"), when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
     /// Human-readable error message.
     pub message: String,
-    /// Error code for programmatic handling.
-    pub code: String,
+    /// Whether the user can fix the problem and retry.
+    pub recoverable: bool,
+    /// What the user can do about it, when there's something to suggest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
 }
 
+impl CommandError {
+    /// Create an error of the given kind, recoverable if the kind usually is.
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            entity: None,
+            message: message.into(),
+            recoverable: kind.is_recoverable(),
+            suggestion: None,
+        }
+    }
+
+    /// Set the type of entity involved.
+    pub fn with_entity(mut self, entity: impl Into<String>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    /// Set what the user can do about the error.
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
 impl From<ServiceError> for CommandError {
     fn from(err: ServiceError) -> Self {
-        let (code, message) = match &err {
+        let kind = err.kind();
+        match err {
             ServiceError::NotFound { entity_type, id } => {
-                ("NOT_FOUND".to_string(), format!("{} with id '{}' not found", entity_type, id))
+                Self::new(kind, format!("{} with id '{}' not found", entity_type, id))
+                    .with_entity(entity_type)
+                    .with_suggestion("It may have been deleted. Refresh and try again.")
             }
-            ServiceError::Validation(msg) => ("VALIDATION_ERROR".to_string(), msg.clone()),
-            ServiceError::Database(e) => ("DATABASE_ERROR".to_string(), e.to_string()),
-            ServiceError::Io(e) => ("IO_ERROR".to_string(), e.to_string()),
-        };
-        Self { message, code }
+            ServiceError::Validation(msg) => Self::new(kind, msg),
+            ServiceError::Database(e) => Self::from(e),
+            ServiceError::Io(e) => Self::from(e),
+        }
     }
 }
 
 impl From<diesel::result::Error> for CommandError {
     fn from(err: diesel::result::Error) -> Self {
-        Self {
-            message: err.to_string(),
-            code: "DATABASE_ERROR".to_string(),
-        }
+        let kind = match err {
+            diesel::result::Error::NotFound => ErrorKind::NotFound,
+            _ => ErrorKind::Database,
+        };
+        Self::new(kind, err.to_string())
     }
 }
 
 impl From<std::io::Error> for CommandError {
     fn from(err: std::io::Error) -> Self {
-        Self {
-            message: err.to_string(),
-            code: "IO_ERROR".to_string(),
+        let suggestion = match err.kind() {
+            std::io::ErrorKind::NotFound => Some("Check that the file or folder still exists."),
+            std::io::ErrorKind::PermissionDenied => {
+                Some("Check that Mimir has permission to access the file or folder.")
+            }
+            _ => None,
+        };
+        let error = Self::new(ErrorKind::Io, err.to_string());
+        match suggestion {
+            Some(suggestion) => error.with_suggestion(suggestion),
+            None => error,
         }
     }
 }
 
+impl From<PrintError> for CommandError {
+    fn from(err: PrintError) -> Self {
+        let message = err.to_string();
+        match err {
+            PrintError::IoError(e) => Self::from(e),
+            PrintError::InvalidData(_) => {
+                Self::new(ErrorKind::Validation, message).with_entity("Document")
+            }
+            PrintError::TemplateNotFound(_) | PrintError::FontError(_) => {
+                Self::new(ErrorKind::Print, message)
+                    .with_suggestion("Reinstall Mimir to restore the print templates and fonts.")
+            }
+            _ => Self::new(ErrorKind::Print, message),
+        }
+    }
+}
+
+/// Plain messages from commands that don't classify their failures.
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::new(ErrorKind::Other, message)
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        Self::new(ErrorKind::Other, message)
+    }
+}
+
+/// Lets commands that return `Result<_, String>` use `?` on command errors.
+impl From<CommandError> for String {
+    fn from(err: CommandError) -> Self {
+        err.message
+    }
+}
+
 /// Result type for Tauri commands.
 pub type CommandResult<T> = Result<T, CommandError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found_carries_entity() {
+        let err = CommandError::from(ServiceError::not_found("Campaign", "42"));
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        assert_eq!(err.entity.as_deref(), Some("Campaign"));
+        assert!(err.recoverable);
+        assert!(err.suggestion.is_some());
+    }
+
+    #[test]
+    fn test_database_error_is_not_recoverable() {
+        let err = CommandError::from(ServiceError::Database(
+            diesel::result::Error::RollbackTransaction,
+        ));
+        assert_eq!(err.kind, ErrorKind::Database);
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_print_error_conversion() {
+        let err = CommandError::from(PrintError::TemplateNotFound("sheet.typ".to_string()));
+        assert_eq!(err.kind, ErrorKind::Print);
+        assert!(err.suggestion.is_some());
+
+        let err = CommandError::from(PrintError::InvalidData("missing name".to_string()));
+        assert_eq!(err.kind, ErrorKind::Validation);
+    }
+
+    #[test]
+    fn test_serialized_shape() {
+        let json = serde_json::to_value(CommandError::from("Something broke")).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "other",
+                "message": "Something broke",
+                "recoverable": false,
+            })
+        );
+    }
+}
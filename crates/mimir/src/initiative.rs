@@ -0,0 +1,911 @@
+//! Initiative Tracker
+//!
+//! Turn order for an encounter. Players' initiative is usually rolled at the
+//! table and typed in (one at a time or pasted as a list); monsters are
+//! rolled by Mimir. The tracker is in-memory application state, like the
+//! table timers.
+//!
+//! Combatants are kept sorted by initiative, then initiative modifier. Ties
+//! beyond that keep the order they were entered in and can be swapped by the
+//! DM. Dice results are passed in so the ordering logic can be tested
+//! without randomness.
+//!
+//! A module's encounter effects are scheduled alongside the creatures.
+//! Effects on an initiative count take a place in the order and lose ties;
+//! effects at the start of a round are announced as turn events when the
+//! round begins.
+//!
+//! A mob of identical monsters can stand in the order as one combatant with
+//! pooled hit points, run with the DMG mob rules.
+//!
+//! Players see the order with each creature's token art. Monsters can be
+//! kept hidden, shown as "???" until the DM reveals them.
+
+use mimir_core::models::campaign::{EffectTiming, EncounterEffect};
+use mimir_core::services::{mob_attack, MobAttack, MobPool, LAIR_INITIATIVE};
+use serde::{Deserialize, Serialize};
+
+/// Name players see in place of a hidden combatant.
+pub const HIDDEN_NAME: &str = "???";
+
+/// Where a combatant stands in the turn order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TurnStatus {
+    /// Acts on their initiative count
+    Waiting,
+    /// Held their turn and is skipped until they step back in
+    Delayed,
+    /// Has a readied action waiting on a trigger
+    Readied,
+}
+
+/// One creature in the turn order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Combatant {
+    pub name: String,
+    pub initiative: i32,
+    /// Initiative modifier, used to break ties
+    pub modifier: i32,
+    /// Whether the initiative was rolled at the table rather than by Mimir
+    pub is_player: bool,
+    pub status: TurnStatus,
+    /// Trigger and action of a readied action
+    pub readied_action: Option<String>,
+    /// What happens on this count, when the entry is an encounter effect
+    /// rather than a creature
+    pub effect: Option<String>,
+    /// Pooled hit points, when the entry is a mob of identical creatures
+    pub mob: Option<Mob>,
+    /// Token art shown beside the name on the player display
+    pub portrait: Option<Portrait>,
+    /// Whether players see the combatant as "???"
+    pub hidden: bool,
+}
+
+/// Where a combatant's token art comes from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Portrait {
+    /// A campaign character's token
+    Character { id: String },
+    /// A catalog monster's token art
+    Monster { name: String, source: String },
+}
+
+/// A combatant as players see them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerCombatant<'a> {
+    pub name: &'a str,
+    pub portrait: Option<&'a Portrait>,
+}
+
+/// A mob's pooled hit points.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mob {
+    /// Module monster whose mob tokens shrink with casualties
+    pub monster_id: Option<String>,
+    /// Creatures in the mob when it formed
+    pub size: i32,
+    /// Hit points of each creature
+    pub hp_each: i32,
+    /// Damage the mob has taken in total
+    pub damage: i32,
+    /// Creatures still standing
+    pub survivors: i32,
+}
+
+/// What damage dealt to a mob did.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MobCasualties {
+    pub monster_id: Option<String>,
+    /// Creatures the damage felled
+    pub casualties: i32,
+    pub survivors: i32,
+}
+
+/// An encounter effect that happened at the start of the current turn.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnEvent {
+    pub name: String,
+    pub description: String,
+}
+
+/// An effect acting at the start of every round, or of one round.
+#[derive(Debug, Clone)]
+struct RoundEffect {
+    name: String,
+    description: String,
+    /// The round it happens on; `None` for every round
+    round: Option<i32>,
+}
+
+/// Serializable view of the tracker, emitted as `initiative:update`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeSnapshot {
+    pub combatants: Vec<Combatant>,
+    /// Index of the combatant whose turn it is, once combat has started
+    pub current: Option<usize>,
+    /// Combat round, zero before the first turn
+    pub round: i32,
+    /// Effects that happened as the current turn began
+    pub events: Vec<TurnEvent>,
+}
+
+/// The turn order of the current encounter.
+#[derive(Debug, Clone, Default)]
+pub struct InitiativeTracker {
+    combatants: Vec<Combatant>,
+    current: Option<usize>,
+    round: i32,
+    round_effects: Vec<RoundEffect>,
+    /// Round whose start-of-round effects have been announced
+    announced_round: i32,
+    events: Vec<TurnEvent>,
+}
+
+impl InitiativeTracker {
+    fn position(&self, name: &str) -> Result<usize, String> {
+        self.combatants
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("No combatant named '{}' in initiative", name))
+    }
+
+    /// Take a combatant out of the order, keeping the current turn pointed at
+    /// the same creature.
+    fn take(&mut self, index: usize) -> Combatant {
+        let combatant = self.combatants.remove(index);
+        if let Some(current) = self.current {
+            if index < current {
+                self.current = Some(current - 1);
+            }
+        }
+        combatant
+    }
+
+    /// Insert a combatant after everyone who beats or ties them. Effects
+    /// lose ties with creatures.
+    fn insert_sorted(&mut self, combatant: Combatant) {
+        let sort_key = |c: &Combatant| (c.initiative, c.effect.is_none(), c.modifier);
+        let key = sort_key(&combatant);
+        let index = self
+            .combatants
+            .iter()
+            .position(|c| sort_key(c) < key)
+            .unwrap_or(self.combatants.len());
+        self.combatants.insert(index, combatant);
+        if let Some(current) = self.current {
+            if index <= current {
+                self.current = Some(current + 1);
+            }
+        }
+    }
+
+    /// Start a combatant's turn. A readied action they didn't use expires.
+    ///
+    /// The first turn of a round announces the round's effects, and an
+    /// effect's turn announces the effect.
+    fn begin_turn(&mut self, index: usize) {
+        let combatant = &mut self.combatants[index];
+        if combatant.status == TurnStatus::Readied {
+            combatant.status = TurnStatus::Waiting;
+            combatant.readied_action = None;
+        }
+        self.current = Some(index);
+
+        self.events.clear();
+        if self.announced_round != self.round {
+            self.announced_round = self.round;
+            let round = self.round;
+            self.events.extend(
+                self.round_effects
+                    .iter()
+                    .filter(|e| e.round.unwrap_or(round) == round)
+                    .map(|e| TurnEvent {
+                        name: e.name.clone(),
+                        description: e.description.clone(),
+                    }),
+            );
+        }
+        let combatant = &self.combatants[index];
+        if let Some(description) = &combatant.effect {
+            self.events.push(TurnEvent {
+                name: combatant.name.clone(),
+                description: description.clone(),
+            });
+        }
+    }
+
+    /// Error unless the combatant at `index` is a creature.
+    fn creature(&self, index: usize) -> Result<(), String> {
+        match self.combatants[index].effect {
+            Some(_) => Err(format!(
+                "{} is an encounter effect, not a creature",
+                self.combatants[index].name
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Set a combatant's initiative, adding them if they aren't in the order.
+    ///
+    /// Names match case-insensitively, so re-entering a player's roll
+    /// replaces it. `modifier` breaks ties and defaults to the combatant's
+    /// previous modifier, or zero.
+    pub fn set(&mut self, name: &str, initiative: i32, modifier: Option<i32>, is_player: bool) {
+        let name = name.trim();
+        let existing = self.position(name).ok().map(|index| self.take(index));
+        let combatant = match existing {
+            Some(previous) => Combatant {
+                initiative,
+                modifier: modifier.unwrap_or(previous.modifier),
+                is_player,
+                ..previous
+            },
+            None => Combatant {
+                name: name.to_string(),
+                initiative,
+                modifier: modifier.unwrap_or(0),
+                is_player,
+                status: TurnStatus::Waiting,
+                readied_action: None,
+                effect: None,
+                mob: None,
+                portrait: None,
+                hidden: false,
+            },
+        };
+        self.insert_sorted(combatant);
+    }
+
+    /// Add a combatant with a rolled d20 plus their initiative modifier.
+    ///
+    /// Returns the initiative they got.
+    pub fn roll(&mut self, name: &str, modifier: i32, d20: i32) -> i32 {
+        let initiative = d20 + modifier;
+        self.set(name, initiative, Some(modifier), false);
+        initiative
+    }
+
+    /// Add a mob of `size` identical creatures as one combatant, rolling its
+    /// initiative once for all of them.
+    ///
+    /// Returns the initiative it got.
+    pub fn add_mob(
+        &mut self,
+        name: &str,
+        monster_id: Option<String>,
+        size: i32,
+        hp_each: i32,
+        modifier: i32,
+        d20: i32,
+    ) -> Result<i32, String> {
+        let pool = MobPool::new(size, hp_each).map_err(|e| e.to_string())?;
+        let initiative = self.roll(name, modifier, d20);
+        let index = self.position(name.trim())?;
+        self.combatants[index].mob = Some(Mob {
+            monster_id,
+            size,
+            hp_each,
+            damage: pool.damage,
+            survivors: pool.survivors(),
+        });
+        Ok(initiative)
+    }
+
+    /// Deal damage to a mob's pooled hit points.
+    ///
+    /// Creatures fall one at a time as the pool drains; a mob with no one
+    /// left standing leaves the order.
+    pub fn damage_mob(&mut self, name: &str, amount: i32) -> Result<MobCasualties, String> {
+        let index = self.position(name)?;
+        let combatant = &mut self.combatants[index];
+        let mob = combatant
+            .mob
+            .as_mut()
+            .ok_or_else(|| format!("{} is not a mob", combatant.name))?;
+
+        let mut pool = MobPool {
+            size: mob.size,
+            hp_each: mob.hp_each,
+            damage: mob.damage,
+        };
+        let casualties = pool.apply_damage(amount);
+        mob.damage = pool.damage;
+        mob.survivors = pool.survivors();
+        let result = MobCasualties {
+            monster_id: mob.monster_id.clone(),
+            casualties,
+            survivors: mob.survivors,
+        };
+
+        if result.survivors == 0 {
+            let name = combatant.name.clone();
+            self.remove(&name)?;
+        }
+        Ok(result)
+    }
+
+    /// Resolve a mob's attacks against one target as a batch.
+    ///
+    /// `attackers` is how many can reach the target, defaulting to (and at
+    /// most) everyone still standing.
+    pub fn mob_attack(
+        &self,
+        name: &str,
+        attackers: Option<i32>,
+        attack_bonus: i32,
+        target_ac: i32,
+    ) -> Result<MobAttack, String> {
+        let combatant = &self.combatants[self.position(name)?];
+        let mob = combatant
+            .mob
+            .as_ref()
+            .ok_or_else(|| format!("{} is not a mob", combatant.name))?;
+        let attackers = attackers.map_or(mob.survivors, |a| a.min(mob.survivors));
+        Ok(mob_attack(attackers, attack_bonus, target_ac))
+    }
+
+    /// Add players' initiative from a pasted list, one "Name 17" per line.
+    ///
+    /// The name and value may be separated by spaces, a colon, a comma, or an
+    /// equals sign. Nothing is added unless every line parses. Returns the
+    /// number of combatants entered.
+    pub fn import(&mut self, text: &str) -> Result<usize, String> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_entry(line).ok_or_else(|| {
+                    format!(
+                        "Line {}: expected a name followed by an initiative value, got '{}'",
+                        i + 1,
+                        line.trim()
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (name, initiative) in &entries {
+            self.set(name, *initiative, None, true);
+        }
+        Ok(entries.len())
+    }
+
+    /// The turn order as "Name: 17" lines, in the format `import` reads.
+    pub fn export(&self) -> String {
+        self.combatants
+            .iter()
+            .map(|c| format!("{}: {}", c.name, c.initiative))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Combatant names in turn order.
+    pub fn names(&self) -> Vec<String> {
+        self.combatants.iter().map(|c| c.name.clone()).collect()
+    }
+
+    /// Set or clear the token art shown for a combatant.
+    pub fn set_portrait(&mut self, name: &str, portrait: Option<Portrait>) -> Result<(), String> {
+        let index = self.position(name)?;
+        self.combatants[index].portrait = portrait;
+        Ok(())
+    }
+
+    /// Hide a combatant from players, or reveal them.
+    pub fn set_hidden(&mut self, name: &str, hidden: bool) -> Result<(), String> {
+        let index = self.position(name)?;
+        self.creature(index)?;
+        self.combatants[index].hidden = hidden;
+        Ok(())
+    }
+
+    /// The turn order as players see it: hidden combatants are "???" with
+    /// no token art.
+    pub fn player_view(&self) -> Vec<PlayerCombatant<'_>> {
+        self.combatants
+            .iter()
+            .map(|c| {
+                if c.hidden {
+                    PlayerCombatant {
+                        name: HIDDEN_NAME,
+                        portrait: None,
+                    }
+                } else {
+                    PlayerCombatant {
+                        name: &c.name,
+                        portrait: c.portrait.as_ref(),
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Remove a combatant from the order.
+    ///
+    /// Removing the combatant whose turn it is passes the turn to the next.
+    pub fn remove(&mut self, name: &str) -> Result<(), String> {
+        let index = self.position(name)?;
+        let was_current = self.current == Some(index);
+        self.take(index);
+
+        if was_current {
+            self.current = None;
+            if !self.everyone_delaying() {
+                self.advance_from(index);
+            }
+        }
+        Ok(())
+    }
+
+    /// Move a combatant ahead of the one before them to settle a tie.
+    pub fn move_up(&mut self, name: &str) -> Result<(), String> {
+        let index = self.position(name)?;
+        if index == 0 || self.combatants[index - 1].initiative != self.combatants[index].initiative
+        {
+            return Err(format!(
+                "{} is not tied with the combatant before them",
+                self.combatants[index].name
+            ));
+        }
+
+        self.combatants.swap(index - 1, index);
+        self.current = match self.current {
+            Some(c) if c == index => Some(index - 1),
+            Some(c) if c == index - 1 => Some(index),
+            other => other,
+        };
+        Ok(())
+    }
+
+    /// Delay the current combatant's turn and move on to the next.
+    ///
+    /// Delayed combatants are skipped until they step back in with `resume`.
+    pub fn delay(&mut self) -> Result<(), String> {
+        let index = self
+            .current
+            .ok_or_else(|| "Combat has not started".to_string())?;
+        self.creature(index)?;
+        if self
+            .combatants
+            .iter()
+            .enumerate()
+            .all(|(i, c)| i == index || c.status == TurnStatus::Delayed)
+        {
+            return Err("No one else can act".to_string());
+        }
+        self.combatants[index].status = TurnStatus::Delayed;
+        self.combatants[index].readied_action = None;
+        self.next_turn().map(|_| ())
+    }
+
+    /// Bring a delayed combatant back in to act now.
+    ///
+    /// They take the current initiative count and go before the creature
+    /// whose turn it was, keeping that place in later rounds.
+    pub fn resume(&mut self, name: &str) -> Result<(), String> {
+        let index = self.position(name)?;
+        if self.combatants[index].status != TurnStatus::Delayed {
+            return Err(format!("{} is not delaying", self.combatants[index].name));
+        }
+        let current = self
+            .current
+            .ok_or_else(|| "Combat has not started".to_string())?;
+
+        let initiative = self.combatants[current].initiative;
+        let mut combatant = self.take(index);
+        combatant.status = TurnStatus::Waiting;
+        combatant.initiative = initiative;
+
+        let at = self.current.unwrap_or(0);
+        self.combatants.insert(at, combatant);
+        self.begin_turn(at);
+        Ok(())
+    }
+
+    /// Ready an action for a combatant, or clear it when the trigger is `None`.
+    ///
+    /// An unused readied action expires at the start of their next turn.
+    pub fn ready(&mut self, name: &str, trigger: Option<String>) -> Result<(), String> {
+        let index = self.position(name)?;
+        self.creature(index)?;
+        let combatant = &mut self.combatants[index];
+        match trigger
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+        {
+            Some(trigger) => {
+                combatant.status = TurnStatus::Readied;
+                combatant.readied_action = Some(trigger);
+            }
+            None if combatant.status == TurnStatus::Readied => {
+                combatant.status = TurnStatus::Waiting;
+                combatant.readied_action = None;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Advance to the next combatant who isn't delaying.
+    ///
+    /// The first call starts round 1; passing the bottom of the order starts
+    /// a new round. Returns the combatant whose turn it now is.
+    pub fn next_turn(&mut self) -> Result<&Combatant, String> {
+        if self.combatants.is_empty() {
+            return Err("No combatants in initiative".to_string());
+        }
+        if self.everyone_delaying() {
+            return Err("Every combatant is delaying".to_string());
+        }
+
+        let start = match self.current {
+            Some(current) => current + 1,
+            None => {
+                self.round = 1;
+                0
+            }
+        };
+        let index = self.advance_from(start);
+        Ok(&self.combatants[index])
+    }
+
+    fn everyone_delaying(&self) -> bool {
+        self.combatants
+            .iter()
+            .all(|c| c.status == TurnStatus::Delayed)
+    }
+
+    /// Begin the turn of the first combatant from `index` on who isn't
+    /// delaying, wrapping into the next round past the bottom of the order.
+    fn advance_from(&mut self, mut index: usize) -> usize {
+        loop {
+            if index >= self.combatants.len() {
+                index = 0;
+                self.round += 1;
+            }
+            if self.combatants[index].status != TurnStatus::Delayed {
+                break;
+            }
+            index += 1;
+        }
+        self.begin_turn(index);
+        index
+    }
+
+    /// Schedule a module's encounter effects, replacing any scheduled before.
+    pub fn schedule_effects(&mut self, effects: &[EncounterEffect]) {
+        let scheduled: Vec<String> = self
+            .combatants
+            .iter()
+            .filter(|c| c.effect.is_some())
+            .map(|c| c.name.clone())
+            .collect();
+        for name in scheduled {
+            let _ = self.remove(&name);
+        }
+        self.round_effects.clear();
+
+        for effect in effects {
+            let round = match effect.timing_enum() {
+                Some(EffectTiming::Initiative) => {
+                    self.insert_sorted(Combatant {
+                        name: effect.name.clone(),
+                        initiative: effect.timing_value.unwrap_or(LAIR_INITIATIVE),
+                        modifier: 0,
+                        is_player: false,
+                        status: TurnStatus::Waiting,
+                        readied_action: None,
+                        effect: Some(effect.description.clone()),
+                        mob: None,
+                        portrait: None,
+                        hidden: false,
+                    });
+                    continue;
+                }
+                Some(EffectTiming::EachRound) => None,
+                Some(EffectTiming::Round) => effect.timing_value,
+                None => continue,
+            };
+            self.round_effects.push(RoundEffect {
+                name: effect.name.clone(),
+                description: effect.description.clone(),
+                round,
+            });
+        }
+    }
+
+    /// End the encounter, removing every creature from the order.
+    ///
+    /// Scheduled encounter effects stay for the next fight.
+    pub fn clear(&mut self) {
+        let mut effects = std::mem::take(&mut self.combatants);
+        effects.retain(|c| c.effect.is_some());
+        *self = Self {
+            combatants: effects,
+            round_effects: std::mem::take(&mut self.round_effects),
+            ..Self::default()
+        };
+    }
+
+    /// Snapshot of the turn order.
+    pub fn snapshot(&self) -> InitiativeSnapshot {
+        InitiativeSnapshot {
+            combatants: self.combatants.clone(),
+            current: self.current,
+            round: self.round,
+            events: self.events.clone(),
+        }
+    }
+}
+
+/// Split a "Name 17" line into its name and initiative.
+fn parse_entry(line: &str) -> Option<(String, i32)> {
+    let line = line.trim();
+    let name_end = line.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let initiative = line[name_end..].parse().ok()?;
+    let name = line[..name_end]
+        .trim_end_matches(|c: char| c.is_whitespace() || matches!(c, ':' | ',' | '='))
+        .trim();
+    (!name.is_empty()).then(|| (name.to_string(), initiative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(tracker: &InitiativeTracker) -> Vec<String> {
+        tracker.names()
+    }
+
+    fn effect(name: &str, timing: EffectTiming, value: Option<i32>) -> EncounterEffect {
+        EncounterEffect {
+            id: name.to_lowercase(),
+            module_id: "mod-1".to_string(),
+            name: name.to_string(),
+            description: format!("{} happens", name),
+            timing: timing.as_str().to_string(),
+            timing_value: value,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn event_names(tracker: &InitiativeTracker) -> Vec<String> {
+        tracker
+            .snapshot()
+            .events
+            .into_iter()
+            .map(|e| e.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_sorted_by_initiative_then_modifier() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.set("Aria", 15, None, true);
+        assert_eq!(tracker.roll("Goblin 1", 2, 11), 13);
+        tracker.roll("Ogre", -1, 20);
+        tracker.set("Bram", 18, None, true);
+
+        assert_eq!(order(&tracker), vec!["Ogre", "Bram", "Aria", "Goblin 1"]);
+        assert_eq!(tracker.snapshot().combatants[3].modifier, 2);
+
+        // Re-entering a roll replaces it
+        tracker.set("aria", 20, None, true);
+        assert_eq!(order(&tracker), vec!["Aria", "Ogre", "Bram", "Goblin 1"]);
+    }
+
+    #[test]
+    fn test_import_and_export() {
+        let mut tracker = InitiativeTracker::default();
+        let count = tracker
+            .import("Aria: 17\n\nBram 12\nCorin, 20\nDun Elf = 3\n")
+            .unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(
+            tracker.export(),
+            "Corin: 20\nAria: 17\nBram: 12\nDun Elf: 3"
+        );
+        assert!(tracker.snapshot().combatants.iter().all(|c| c.is_player));
+
+        let err = tracker.import("Eve 9\nFinn\n").unwrap_err();
+        assert!(err.starts_with("Line 2"));
+        assert_eq!(tracker.names().len(), 4);
+    }
+
+    #[test]
+    fn test_ties_can_be_reordered() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.set("Aria", 14, None, true);
+        tracker.set("Goblin", 14, None, false);
+        tracker.set("Bram", 10, None, true);
+
+        tracker.move_up("Goblin").unwrap();
+        assert_eq!(order(&tracker), vec!["Goblin", "Aria", "Bram"]);
+        assert!(tracker.move_up("Bram").is_err());
+        assert!(tracker.move_up("Goblin").is_err());
+    }
+
+    #[test]
+    fn test_turns_wrap_into_new_rounds() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 18\nBram 12").unwrap();
+        assert_eq!(tracker.next_turn().unwrap().name, "Aria");
+        assert_eq!(tracker.next_turn().unwrap().name, "Bram");
+        assert_eq!(tracker.snapshot().round, 1);
+        assert_eq!(tracker.next_turn().unwrap().name, "Aria");
+        assert_eq!(tracker.snapshot().round, 2);
+
+        // A combatant joining ahead of the current turn doesn't steal it
+        tracker.set("Corin", 20, None, true);
+        assert_eq!(tracker.snapshot().current, Some(1));
+    }
+
+    #[test]
+    fn test_delay_and_resume() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 18\nBram 12\nCorin 5").unwrap();
+        tracker.next_turn().unwrap();
+        tracker.delay().unwrap();
+
+        let snap = tracker.snapshot();
+        assert_eq!(snap.combatants[snap.current.unwrap()].name, "Bram");
+        assert_eq!(snap.combatants[0].status, TurnStatus::Delayed);
+
+        tracker.resume("Aria").unwrap();
+        let snap = tracker.snapshot();
+        assert_eq!(order(&tracker), vec!["Aria", "Bram", "Corin"]);
+        assert_eq!(snap.current, Some(0));
+        assert_eq!(snap.combatants[0].initiative, 12);
+        assert_eq!(tracker.next_turn().unwrap().name, "Bram");
+        assert!(tracker.resume("Bram").is_err());
+    }
+
+    #[test]
+    fn test_delayed_combatants_are_skipped() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 18\nBram 12").unwrap();
+        tracker.next_turn().unwrap();
+        tracker.delay().unwrap();
+        assert_eq!(tracker.next_turn().unwrap().name, "Bram");
+        assert_eq!(tracker.snapshot().round, 2);
+    }
+
+    #[test]
+    fn test_readied_action_expires_on_next_turn() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 18\nBram 12").unwrap();
+        tracker.next_turn().unwrap();
+        tracker
+            .ready(
+                "Aria",
+                Some("Shoot the first orc through the door".to_string()),
+            )
+            .unwrap();
+        tracker.next_turn().unwrap();
+        assert_eq!(tracker.snapshot().combatants[0].status, TurnStatus::Readied);
+
+        let aria = tracker.next_turn().unwrap();
+        assert_eq!(aria.status, TurnStatus::Waiting);
+        assert!(aria.readied_action.is_none());
+    }
+
+    #[test]
+    fn test_remove_current_passes_the_turn() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 18\nBram 12").unwrap();
+        tracker.next_turn().unwrap();
+        tracker.next_turn().unwrap();
+        tracker.remove("Bram").unwrap();
+
+        let snap = tracker.snapshot();
+        assert_eq!(snap.current, Some(0));
+        assert_eq!(snap.round, 2);
+        assert!(tracker.remove("Bram").is_err());
+    }
+
+    #[test]
+    fn test_lair_action_loses_ties() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 20\nBram 12").unwrap();
+        tracker.schedule_effects(&[effect("Lair Action", EffectTiming::Initiative, None)]);
+        assert_eq!(order(&tracker), vec!["Aria", "Lair Action", "Bram"]);
+
+        tracker.next_turn().unwrap();
+        assert!(event_names(&tracker).is_empty());
+        tracker.next_turn().unwrap();
+        assert_eq!(event_names(&tracker), vec!["Lair Action"]);
+        assert!(tracker.delay().is_err());
+        assert!(tracker.ready("Lair Action", Some("x".to_string())).is_err());
+
+        // Rescheduling replaces the effects; clearing keeps them
+        tracker.schedule_effects(&[effect("Tremor", EffectTiming::Initiative, Some(10))]);
+        tracker.clear();
+        assert_eq!(order(&tracker), vec!["Tremor"]);
+        assert_eq!(tracker.snapshot().round, 0);
+    }
+
+    #[test]
+    fn test_round_effects_fire_at_round_start() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.import("Aria 18\nBram 12").unwrap();
+        tracker.schedule_effects(&[
+            effect("Smoke", EffectTiming::EachRound, None),
+            effect("Collapse", EffectTiming::Round, Some(2)),
+        ]);
+
+        tracker.next_turn().unwrap();
+        assert_eq!(event_names(&tracker), vec!["Smoke"]);
+        tracker.next_turn().unwrap();
+        assert!(event_names(&tracker).is_empty());
+        tracker.next_turn().unwrap();
+        assert_eq!(event_names(&tracker), vec!["Smoke", "Collapse"]);
+        tracker.next_turn().unwrap();
+        tracker.next_turn().unwrap();
+        assert_eq!(event_names(&tracker), vec!["Smoke"]);
+    }
+
+    #[test]
+    fn test_mob_takes_casualties_until_routed() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.set("Aria", 15, None, true);
+        let initiative = tracker
+            .add_mob("Goblins", Some("mm-1".to_string()), 30, 7, 2, 10)
+            .unwrap();
+        assert_eq!(initiative, 12);
+        assert!(tracker.add_mob("Rats", None, 0, 1, 0, 10).is_err());
+
+        let result = tracker.damage_mob("goblins", 20).unwrap();
+        assert_eq!(result.casualties, 2);
+        assert_eq!(result.survivors, 28);
+        assert_eq!(result.monster_id.as_deref(), Some("mm-1"));
+
+        // Only the survivors that can reach the target attack
+        let attack = tracker.mob_attack("Goblins", Some(40), 4, 16).unwrap();
+        assert_eq!(attack.attackers, 28);
+        assert_eq!(attack.hits, 14);
+        assert!(tracker.mob_attack("Aria", None, 4, 16).is_err());
+
+        assert_eq!(tracker.damage_mob("Goblins", 500).unwrap().survivors, 0);
+        assert_eq!(order(&tracker), vec!["Aria"]);
+    }
+
+    #[test]
+    fn test_player_view_hides_unrevealed_monsters() {
+        let mut tracker = InitiativeTracker::default();
+        tracker.set("Aria", 15, None, true);
+        tracker.roll("Goblin Boss", 1, 11);
+        let art = Portrait::Monster {
+            name: "Goblin Boss".to_string(),
+            source: "MM".to_string(),
+        };
+        tracker
+            .set_portrait("goblin boss", Some(art.clone()))
+            .unwrap();
+        tracker.set_hidden("Goblin Boss", true).unwrap();
+        assert!(tracker.set_hidden("Nobody", true).is_err());
+
+        let view = tracker.player_view();
+        assert_eq!(view[0].name, "Aria");
+        assert_eq!(view[1].name, HIDDEN_NAME);
+        assert!(view[1].portrait.is_none());
+
+        // Re-rolling keeps the art and the secret
+        tracker.roll("Goblin Boss", 1, 20);
+        assert_eq!(tracker.player_view()[0].name, HIDDEN_NAME);
+
+        tracker.set_hidden("Goblin Boss", false).unwrap();
+        let view = tracker.player_view();
+        assert_eq!(view[0].name, "Goblin Boss");
+        assert_eq!(view[0].portrait, Some(&art));
+    }
+}
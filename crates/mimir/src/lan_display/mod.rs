@@ -0,0 +1,554 @@
+//! LAN Player Display
+//!
+//! Optional embedded web server that lets a tablet or second laptop on the
+//! local network act as a player screen. It relays the player display event
+//! stream over a websocket and serves the images of whatever map and tokens
+//! are currently on display. Nothing it exposes can change campaign data.
+//!
+//! Every route requires the `token` query parameter generated when the server
+//! starts, and takes an optional `screen` ("primary" or "secondary"):
+//! - `GET /` - a self-contained viewer page
+//! - `GET /ws` - display events as JSON `{ screen, event, payload }` messages
+//! - `GET /api/state` - the latest event of each kind, in replay order
+//! - `GET /api/maps/{id}/image` - image of a map currently on display
+//! - `GET /api/tokens/{id}/image` - image of a token currently on display
+//!
+//! Events are picked up from the Tauri event bus, so anything sent to the
+//! local display windows (by commands or by the DM map window) reaches LAN
+//! clients as well.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::{IpAddr, TcpListener, UdpSocket};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, EventId, Listener, Manager};
+use tokio::sync::{broadcast, watch};
+
+use crate::commands::map::serve_map_image;
+use crate::commands::module::serve_token_image;
+use crate::commands::player_display::{image_mime_type, DisplayScreen};
+use crate::state::AppState;
+
+/// Player display events relayed to LAN clients, in replay order.
+pub const RELAYED_EVENTS: &[&str] = &[
+    "map-update",
+    "viewport-update",
+    "blackout",
+    "tokens-update",
+    "fog-update",
+    "light-sources-update",
+    "markers-update",
+    "monster",
+    "handout",
+    "read-aloud",
+    "handout-clear",
+    "initiative",
+];
+
+/// Events that describe the map on display and are dropped when a new map is sent.
+const MAP_SCOPED_EVENTS: &[&str] = &[
+    "viewport-update",
+    "tokens-update",
+    "fog-update",
+    "light-sources-update",
+    "markers-update",
+];
+
+/// Event the DM map window answers by resending tokens, fog, lights, and markers.
+const REQUEST_STATE_EVENT: &str = "player-display:request-state";
+
+const VIEWER_HTML: &str = include_str!("viewer.html");
+
+/// A display event as sent to LAN clients.
+#[derive(Debug, Clone, Serialize)]
+pub struct DisplayMessage {
+    pub screen: &'static str,
+    pub event: &'static str,
+    pub payload: Value,
+}
+
+/// Whether the LAN display is running and how to reach it.
+#[derive(Debug, Clone, Serialize)]
+pub struct LanDisplayStatus {
+    pub running: bool,
+    pub port: Option<u16>,
+    /// Viewer URLs, including the access token, for each screen
+    pub urls: Vec<String>,
+    /// Connected websocket clients
+    pub clients: usize,
+}
+
+/// The latest display events, shared by the event relay and the web server.
+struct DisplayFeed {
+    latest: Mutex<HashMap<(DisplayScreen, &'static str), Value>>,
+    sender: broadcast::Sender<DisplayMessage>,
+}
+
+impl DisplayFeed {
+    fn new() -> Self {
+        Self {
+            latest: Mutex::new(HashMap::new()),
+            sender: broadcast::channel(64).0,
+        }
+    }
+
+    /// Record an event and pass it on to connected clients.
+    fn publish(&self, screen: DisplayScreen, event: &'static str, payload: Value) {
+        if let Ok(mut latest) = self.latest.lock() {
+            let replaced: &[&str] = match event {
+                "map-update" => MAP_SCOPED_EVENTS,
+                "monster" => &["handout", "read-aloud"],
+                "handout" => &["monster", "read-aloud"],
+                "read-aloud" => &["monster", "handout"],
+                "handout-clear" => &["monster", "handout", "read-aloud"],
+                _ => &[],
+            };
+            latest.retain(|(s, e), _| *s != screen || !replaced.contains(e));
+            if event != "handout-clear" {
+                latest.insert((screen, event), payload.clone());
+            }
+        }
+
+        // Sending only fails when no client is connected
+        let _ = self.sender.send(DisplayMessage {
+            screen: screen.as_str(),
+            event,
+            payload,
+        });
+    }
+
+    /// The latest event of each kind for a screen, in replay order.
+    fn snapshot(&self, screen: DisplayScreen) -> Vec<DisplayMessage> {
+        let Ok(latest) = self.latest.lock() else {
+            return Vec::new();
+        };
+        RELAYED_EVENTS
+            .iter()
+            .filter_map(|&event| {
+                latest.get(&(screen, event)).map(|payload| DisplayMessage {
+                    screen: screen.as_str(),
+                    event,
+                    payload: payload.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Whether a map is on display on any screen.
+    fn shows_map(&self, map_id: &str) -> bool {
+        self.latest.lock().is_ok_and(|latest| {
+            latest.iter().any(|((_, event), payload)| {
+                *event == "map-update" && payload["mapId"].as_str() == Some(map_id)
+            })
+        })
+    }
+
+    /// Whether a token is visible on any screen.
+    fn shows_token(&self, token_id: &str) -> bool {
+        self.latest.lock().is_ok_and(|latest| {
+            latest.iter().any(|((_, event), payload)| {
+                *event == "tokens-update"
+                    && payload["tokens"].as_array().is_some_and(|tokens| {
+                        tokens.iter().any(|t| t["id"].as_str() == Some(token_id))
+                    })
+            })
+        })
+    }
+}
+
+struct RunningServer {
+    port: u16,
+    access_token: String,
+    feed: Arc<DisplayFeed>,
+    shutdown: watch::Sender<bool>,
+    listeners: Vec<EventId>,
+}
+
+/// The LAN display server, if running.
+#[derive(Default)]
+pub struct LanDisplay {
+    server: Option<RunningServer>,
+}
+
+impl LanDisplay {
+    /// Whether the server is running.
+    pub fn is_running(&self) -> bool {
+        self.server.is_some()
+    }
+
+    /// Start serving on all interfaces. Does nothing if already running.
+    pub fn start(&mut self, app: AppHandle, port: u16) -> Result<LanDisplayStatus, String> {
+        if self.server.is_some() {
+            return Ok(self.status());
+        }
+
+        let listener = TcpListener::bind(("0.0.0.0", port))
+            .and_then(|listener| listener.set_nonblocking(true).map(|_| listener))
+            .map_err(|e| format!("Cannot listen on port {}: {}", port, e))?;
+
+        let access_token = uuid::Uuid::new_v4().simple().to_string()[..12].to_string();
+        let feed = Arc::new(DisplayFeed::new());
+        let (shutdown, shutdown_rx) = watch::channel(false);
+
+        let mut listeners = Vec::new();
+        for screen in [DisplayScreen::Primary, DisplayScreen::Secondary] {
+            for &event in RELAYED_EVENTS {
+                let feed = feed.clone();
+                listeners.push(app.listen_any(screen.event(event), move |e| {
+                    match serde_json::from_str(e.payload()) {
+                        Ok(payload) => feed.publish(screen, event, payload),
+                        Err(err) => tracing::warn!("Unreadable {} event: {}", event, err),
+                    }
+                }));
+            }
+        }
+
+        let router = Router::new()
+            .route("/", get(viewer))
+            .route("/ws", get(websocket))
+            .route("/api/state", get(state))
+            .route("/api/maps/{id}/image", get(map_image))
+            .route("/api/tokens/{id}/image", get(token_image))
+            .with_state(Server {
+                app: app.clone(),
+                access_token: access_token.clone(),
+                feed: feed.clone(),
+                shutdown: shutdown_rx.clone(),
+            });
+
+        tauri::async_runtime::spawn(async move {
+            let listener = match tokio::net::TcpListener::from_std(listener) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    tracing::error!("LAN display failed to start: {}", e);
+                    return;
+                }
+            };
+            let mut shutdown_rx = shutdown_rx;
+            let result = axum::serve(listener, router)
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_rx.wait_for(|stop| *stop).await;
+                })
+                .await;
+            if let Err(e) = result {
+                tracing::error!("LAN display server stopped: {}", e);
+            }
+        });
+
+        tracing::info!("LAN display listening on port {}", port);
+        self.server = Some(RunningServer {
+            port,
+            access_token,
+            feed,
+            shutdown,
+            listeners,
+        });
+        Ok(self.status())
+    }
+
+    /// Stop the server and disconnect clients. Returns false if it wasn't running.
+    pub fn stop(&mut self, app: &AppHandle) -> bool {
+        let Some(server) = self.server.take() else {
+            return false;
+        };
+        for id in server.listeners {
+            app.unlisten(id);
+        }
+        let _ = server.shutdown.send(true);
+        tracing::info!("LAN display stopped");
+        true
+    }
+
+    /// Current status, including viewer URLs for this machine's LAN address.
+    pub fn status(&self) -> LanDisplayStatus {
+        let Some(server) = &self.server else {
+            return LanDisplayStatus {
+                running: false,
+                port: None,
+                urls: Vec::new(),
+                clients: 0,
+            };
+        };
+
+        let host = lan_address().map_or_else(|| "localhost".to_string(), |ip| ip.to_string());
+        let urls = [DisplayScreen::Primary, DisplayScreen::Secondary]
+            .iter()
+            .map(|screen| {
+                format!(
+                    "http://{}:{}/?token={}&screen={}",
+                    host,
+                    server.port,
+                    server.access_token,
+                    screen.as_str()
+                )
+            })
+            .collect();
+
+        LanDisplayStatus {
+            running: true,
+            port: Some(server.port),
+            urls,
+            clients: server.feed.sender.receiver_count(),
+        }
+    }
+}
+
+/// This machine's address on the local network.
+fn lan_address() -> Option<IpAddr> {
+    // Connecting a UDP socket sends nothing; it only picks the outbound interface.
+    let socket = UdpSocket::bind(("0.0.0.0", 0)).ok()?;
+    socket.connect(("192.0.2.1", 80)).ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+#[derive(Clone)]
+struct Server {
+    app: AppHandle,
+    access_token: String,
+    feed: Arc<DisplayFeed>,
+    shutdown: watch::Receiver<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClientQuery {
+    token: Option<String>,
+    screen: Option<String>,
+}
+
+/// A message from a LAN client. Only state requests are accepted.
+#[derive(Debug, Deserialize)]
+struct ClientMessage {
+    event: String,
+    #[serde(default)]
+    payload: Value,
+}
+
+impl Server {
+    /// Check the access token and resolve the requested screen.
+    fn authorize(&self, query: &ClientQuery) -> Result<DisplayScreen, Response> {
+        if query.token.as_deref() != Some(self.access_token.as_str()) {
+            return Err(
+                (StatusCode::UNAUTHORIZED, "Missing or wrong display token").into_response()
+            );
+        }
+        DisplayScreen::from_option(query.screen.as_deref())
+            .map_err(|e| (StatusCode::BAD_REQUEST, e).into_response())
+    }
+}
+
+async fn viewer(State(server): State<Server>, Query(query): Query<ClientQuery>) -> Response {
+    match server.authorize(&query) {
+        Ok(_) => Html(VIEWER_HTML).into_response(),
+        Err(response) => response,
+    }
+}
+
+async fn state(State(server): State<Server>, Query(query): Query<ClientQuery>) -> Response {
+    match server.authorize(&query) {
+        Ok(screen) => Json(server.feed.snapshot(screen)).into_response(),
+        Err(response) => response,
+    }
+}
+
+async fn websocket(
+    State(server): State<Server>,
+    Query(query): Query<ClientQuery>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    match server.authorize(&query) {
+        Ok(screen) => ws.on_upgrade(move |socket| relay(socket, server, screen)),
+        Err(response) => response,
+    }
+}
+
+/// Stream display events for one screen to a websocket client.
+async fn relay(mut socket: WebSocket, server: Server, screen: DisplayScreen) {
+    // Subscribe before the snapshot so nothing published in between is missed
+    let mut events = server.feed.sender.subscribe();
+    let mut shutdown = server.shutdown.clone();
+
+    if send_all(&mut socket, server.feed.snapshot(screen))
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(message) if message.screen == screen.as_str() => {
+                    if send_all(&mut socket, vec![message]).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                // A slow client skipped events; catch it up with the latest state
+                Err(broadcast::error::RecvError::Lagged(_)) => {
+                    if send_all(&mut socket, server.feed.snapshot(screen)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => handle_client_message(&server.app, text.as_str()),
+                Some(Ok(_)) => {}
+                Some(Err(_)) | None => break,
+            },
+            _ = shutdown.changed() => break,
+        }
+    }
+}
+
+async fn send_all(socket: &mut WebSocket, messages: Vec<DisplayMessage>) -> Result<(), ()> {
+    for message in messages {
+        let text = serde_json::to_string(&message).map_err(|_| ())?;
+        socket
+            .send(Message::Text(text.into()))
+            .await
+            .map_err(|_| ())?;
+    }
+    Ok(())
+}
+
+fn handle_client_message(app: &AppHandle, text: &str) {
+    match serde_json::from_str::<ClientMessage>(text) {
+        Ok(message) if message.event == "request-state" => {
+            if let Err(e) = app.emit(REQUEST_STATE_EVENT, message.payload) {
+                tracing::warn!("Failed to forward LAN display state request: {}", e);
+            }
+        }
+        Ok(message) => tracing::debug!("Ignoring LAN display event '{}'", message.event),
+        Err(e) => tracing::debug!("Ignoring malformed LAN display message: {}", e),
+    }
+}
+
+async fn map_image(
+    State(server): State<Server>,
+    Path(id): Path<String>,
+    Query(query): Query<ClientQuery>,
+) -> Response {
+    if let Err(response) = server.authorize(&query) {
+        return response;
+    }
+    if !server.feed.shows_map(&id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let app = server.app.clone();
+    image_response(
+        tokio::task::spawn_blocking(move || serve_map_image(app.state::<AppState>(), id).data)
+            .await
+            .ok()
+            .flatten(),
+    )
+}
+
+async fn token_image(
+    State(server): State<Server>,
+    Path(id): Path<String>,
+    Query(query): Query<ClientQuery>,
+) -> Response {
+    if let Err(response) = server.authorize(&query) {
+        return response;
+    }
+    if !server.feed.shows_token(&id) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let app = server.app.clone();
+    image_response(
+        tokio::task::spawn_blocking(move || {
+            serve_token_image(app.state::<AppState>(), id)
+                .data
+                .flatten()
+        })
+        .await
+        .ok()
+        .flatten(),
+    )
+}
+
+/// Serve an image given as a file path or a base64 data URL.
+fn image_response(source: Option<String>) -> Response {
+    match source.as_deref().and_then(load_image) {
+        Some((mime_type, bytes)) => ([(header::CONTENT_TYPE, mime_type)], bytes).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+fn load_image(source: &str) -> Option<(String, Vec<u8>)> {
+    if let Some(data_url) = source.strip_prefix("data:") {
+        let (mime_type, data) = data_url.split_once(";base64,")?;
+        let bytes = BASE64.decode(data).ok()?;
+        return Some((mime_type.to_string(), bytes));
+    }
+    let path = std::path::Path::new(source);
+    let bytes = std::fs::read(path).ok()?;
+    Some((image_mime_type(path).to_string(), bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_feed_keeps_latest_state_per_map() {
+        let feed = DisplayFeed::new();
+        let primary = DisplayScreen::Primary;
+        feed.publish(primary, "map-update", json!({ "mapId": "cave" }));
+        feed.publish(
+            primary,
+            "tokens-update",
+            json!({ "mapId": "cave", "tokens": [{ "id": "t1" }] }),
+        );
+        feed.publish(primary, "handout", json!({ "kind": "item_art" }));
+
+        assert!(feed.shows_map("cave"));
+        assert!(feed.shows_token("t1"));
+        assert!(!feed.shows_token("t2"));
+
+        let events: Vec<_> = feed.snapshot(primary).iter().map(|m| m.event).collect();
+        assert_eq!(events, vec!["map-update", "tokens-update", "handout"]);
+        assert!(feed.snapshot(DisplayScreen::Secondary).is_empty());
+
+        // A new map drops the old map's tokens but keeps the handout
+        feed.publish(primary, "map-update", json!({ "mapId": "forest" }));
+        assert!(!feed.shows_map("cave"));
+        assert!(!feed.shows_token("t1"));
+
+        feed.publish(primary, "handout-clear", Value::Null);
+        let events: Vec<_> = feed.snapshot(primary).iter().map(|m| m.event).collect();
+        assert_eq!(events, vec!["map-update"]);
+    }
+
+    #[test]
+    fn test_feed_overlays_replace_each_other() {
+        let feed = DisplayFeed::new();
+        let primary = DisplayScreen::Primary;
+        feed.publish(primary, "handout", json!({ "kind": "npc_portrait" }));
+        feed.publish(primary, "read-aloud", json!({ "paragraphs": ["Hello"] }));
+
+        let events: Vec<_> = feed.snapshot(primary).iter().map(|m| m.event).collect();
+        assert_eq!(events, vec!["read-aloud"]);
+
+        feed.publish(primary, "handout-clear", Value::Null);
+        assert!(feed.snapshot(primary).is_empty());
+    }
+
+    #[test]
+    fn test_load_image_from_data_url() {
+        let (mime_type, bytes) = load_image("data:image/png;base64,aGVsbG8=").unwrap();
+        assert_eq!(mime_type, "image/png");
+        assert_eq!(bytes, b"hello");
+        assert!(load_image("data:image/png,plain").is_none());
+    }
+}
@@ -5,9 +5,16 @@
 //! This crate provides the Tauri command handlers that bridge the Vue.js frontend
 //! with the mimir-core service layer.
 
+pub mod command_registry;
+pub mod command_trace;
 pub mod commands;
 pub mod error;
+pub mod initiative;
+pub mod lan_display;
+pub mod logging;
 pub mod state;
+pub mod timers;
+pub mod watchers;
 
 pub use error::{CommandError, CommandResult};
 pub use state::{is_dev_mode, AppPaths, AppState};
@@ -0,0 +1,403 @@
+//! Application Logging
+//!
+//! Log files are written as JSON lines (one tracing event per line) to a
+//! daily rolling file in the platform log directory, so the log viewer can
+//! filter them by level, module, time, and text instead of reading raw files.
+//!
+//! Lines that aren't JSON, such as those written by older versions, are
+//! skipped when querying.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// Prefix of the daily log files (`mimir.log.YYYY-MM-DD`).
+pub const LOG_FILE_PREFIX: &str = "mimir.log";
+
+/// Entries returned per page when a query doesn't set a limit.
+pub const DEFAULT_LOG_PAGE_SIZE: usize = 200;
+
+/// Largest page a query can request.
+pub const MAX_LOG_PAGE_SIZE: usize = 1000;
+
+/// Directory the log files are written to.
+pub fn log_dir() -> PathBuf {
+    #[cfg(target_os = "macos")]
+    let (base_var, relative) = ("HOME", "Library/Application Support/com.mimir.app/logs");
+    #[cfg(target_os = "windows")]
+    let (base_var, relative) = ("APPDATA", "com.mimir.app/logs");
+    #[cfg(target_os = "linux")]
+    let (base_var, relative) = ("HOME", ".local/share/com.mimir.app/logs");
+    std::env::var(base_var)
+        .map(|base| PathBuf::from(base).join(relative))
+        .unwrap_or_else(|_| PathBuf::from("logs"))
+}
+
+/// Filters and paging for a log query. Every filter is optional.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LogQuery {
+    /// Minimum level ("trace", "debug", "info", "warn", "error")
+    pub level: Option<String>,
+    /// Module path prefix, e.g. "mimir_core::services"
+    pub module: Option<String>,
+    /// Only entries at or after this time (RFC 3339)
+    pub since: Option<String>,
+    /// Only entries at or before this time (RFC 3339)
+    pub until: Option<String>,
+    /// Case-insensitive text to find in the message or fields
+    pub text: Option<String>,
+    /// Entries to skip, for paging
+    #[serde(default)]
+    pub offset: usize,
+    /// Entries to return (default 200, at most 1000)
+    pub limit: Option<usize>,
+}
+
+/// One parsed log line.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: String,
+    /// Module path the event was logged from
+    pub target: String,
+    pub message: String,
+    /// Structured fields other than the message
+    pub fields: Map<String, Value>,
+    /// Fields of the innermost span, e.g. a command's request ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Value>,
+}
+
+/// A page of matching log entries, newest first.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogPage {
+    pub entries: Vec<LogEntry>,
+    /// Total entries matching the filters
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Query the log files in `dir`, newest entries first.
+pub fn query_logs(dir: &Path, query: &LogQuery) -> Result<LogPage, String> {
+    let filter = LogFilter::new(query)?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_LOG_PAGE_SIZE)
+        .clamp(1, MAX_LOG_PAGE_SIZE);
+
+    let mut entries = Vec::new();
+    let mut total = 0;
+    for path in log_files(dir)? {
+        let file = fs::File::open(&path)
+            .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        let mut matching: Vec<LogEntry> = BufReader::new(file)
+            .lines()
+            .map_while(Result::ok)
+            .filter_map(|line| parse_line(&line))
+            .filter(|entry| filter.matches(entry))
+            .collect();
+        matching.reverse();
+
+        for entry in matching {
+            if total >= query.offset && entries.len() < limit {
+                entries.push(entry);
+            }
+            total += 1;
+        }
+    }
+
+    Ok(LogPage {
+        entries,
+        total,
+        offset: query.offset,
+        limit,
+    })
+}
+
+/// Log files in `dir`, newest first.
+fn log_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read log directory: {}", e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with(LOG_FILE_PREFIX))
+        })
+        .collect();
+    // The date suffix sorts chronologically
+    files.sort();
+    files.reverse();
+    Ok(files)
+}
+
+/// Parse a JSON log line written by the file layer.
+fn parse_line(line: &str) -> Option<LogEntry> {
+    let Value::Object(mut event) = serde_json::from_str::<Value>(line).ok()? else {
+        return None;
+    };
+    let mut fields = match event.remove("fields") {
+        Some(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    };
+    let message = match fields.remove("message") {
+        Some(Value::String(message)) => message,
+        Some(other) => other.to_string(),
+        None => String::new(),
+    };
+    let string = |value: Option<Value>| match value {
+        Some(Value::String(s)) => s,
+        _ => String::new(),
+    };
+
+    Some(LogEntry {
+        timestamp: string(event.remove("timestamp")),
+        level: string(event.remove("level")),
+        target: string(event.remove("target")),
+        message,
+        fields,
+        span: event.remove("span"),
+    })
+}
+
+/// Numeric severity of a level name; higher is more severe.
+fn severity(level: &str) -> Option<u8> {
+    match level.to_ascii_lowercase().as_str() {
+        "trace" => Some(0),
+        "debug" => Some(1),
+        "info" => Some(2),
+        "warn" | "warning" => Some(3),
+        "error" => Some(4),
+        _ => None,
+    }
+}
+
+/// A [`LogQuery`]'s filters, parsed once per query.
+struct LogFilter {
+    min_severity: Option<u8>,
+    module: Option<String>,
+    since: Option<DateTime<FixedOffset>>,
+    until: Option<DateTime<FixedOffset>>,
+    text: Option<String>,
+}
+
+impl LogFilter {
+    fn new(query: &LogQuery) -> Result<Self, String> {
+        let non_empty = |value: &Option<String>| value.clone().filter(|v| !v.trim().is_empty());
+        let time = |value: &Option<String>| {
+            non_empty(value)
+                .map(|v| {
+                    DateTime::parse_from_rfc3339(&v)
+                        .map_err(|_| format!("Invalid time '{}'. Expected RFC 3339", v))
+                })
+                .transpose()
+        };
+
+        let min_severity = match non_empty(&query.level) {
+            Some(level) => Some(severity(&level).ok_or_else(|| {
+                format!(
+                    "Unknown log level '{}'. Expected trace, debug, info, warn, or error",
+                    level
+                )
+            })?),
+            None => None,
+        };
+
+        Ok(Self {
+            min_severity,
+            module: non_empty(&query.module),
+            since: time(&query.since)?,
+            until: time(&query.until)?,
+            text: non_empty(&query.text).map(|t| t.to_lowercase()),
+        })
+    }
+
+    fn matches(&self, entry: &LogEntry) -> bool {
+        if let Some(min) = self.min_severity {
+            if severity(&entry.level).is_some_and(|s| s < min) {
+                return false;
+            }
+        }
+        if let Some(ref module) = self.module {
+            if !entry.target.starts_with(module.as_str()) {
+                return false;
+            }
+        }
+        if self.since.is_some() || self.until.is_some() {
+            let Ok(time) = DateTime::parse_from_rfc3339(&entry.timestamp) else {
+                return false;
+            };
+            if self.since.is_some_and(|since| time < since)
+                || self.until.is_some_and(|until| time > until)
+            {
+                return false;
+            }
+        }
+        if let Some(ref text) = self.text {
+            let in_message = entry.message.to_lowercase().contains(text);
+            let in_fields = Value::Object(entry.fields.clone())
+                .to_string()
+                .to_lowercase()
+                .contains(text);
+            if !in_message && !in_fields {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(timestamp: &str, level: &str, target: &str, message: &str) -> String {
+        serde_json::json!({
+            "timestamp": timestamp,
+            "level": level,
+            "target": target,
+            "fields": { "message": message, "duration_ms": 12 },
+            "span": { "name": "command", "request_id": 7 },
+        })
+        .to_string()
+    }
+
+    fn write_logs(files: &[(&str, Vec<String>)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mimir-logs-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        for (name, lines) in files {
+            fs::write(dir.join(name), lines.join("\n")).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let entry = parse_line(&line(
+            "2024-03-04T10:00:00.000000Z",
+            "INFO",
+            "mimir_lib::command_trace",
+            "Command finished",
+        ))
+        .unwrap();
+        assert_eq!(entry.level, "INFO");
+        assert_eq!(entry.message, "Command finished");
+        assert_eq!(entry.fields["duration_ms"], 12);
+        assert!(!entry.fields.contains_key("message"));
+        assert_eq!(entry.span.unwrap()["request_id"], 7);
+
+        assert!(parse_line("2024-03-04T10:00:00Z  INFO plain text line").is_none());
+    }
+
+    #[test]
+    fn test_query_filters_and_pages_newest_first() {
+        let dir = write_logs(&[
+            (
+                "mimir.log.2024-03-04",
+                vec![
+                    line(
+                        "2024-03-04T10:00:00Z",
+                        "INFO",
+                        "mimir_core::db",
+                        "Opened database",
+                    ),
+                    line(
+                        "2024-03-04T11:00:00Z",
+                        "WARN",
+                        "mimir_lib::command_trace",
+                        "Slow command",
+                    ),
+                    "not json".to_string(),
+                ],
+            ),
+            (
+                "mimir.log.2024-03-05",
+                vec![
+                    line(
+                        "2024-03-05T09:00:00Z",
+                        "ERROR",
+                        "mimir_core::services",
+                        "Save failed",
+                    ),
+                    line(
+                        "2024-03-05T09:30:00Z",
+                        "DEBUG",
+                        "mimir_core::services",
+                        "Saving",
+                    ),
+                ],
+            ),
+        ]);
+
+        let all = query_logs(&dir, &LogQuery::default()).unwrap();
+        assert_eq!(all.total, 4);
+        let messages: Vec<_> = all.entries.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec!["Saving", "Save failed", "Slow command", "Opened database"]
+        );
+
+        let warnings = query_logs(
+            &dir,
+            &LogQuery {
+                level: Some("warn".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(warnings.total, 2);
+
+        let core = query_logs(
+            &dir,
+            &LogQuery {
+                module: Some("mimir_core".to_string()),
+                since: Some("2024-03-05T00:00:00Z".to_string()),
+                text: Some("FAILED".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(core.total, 1);
+        assert_eq!(core.entries[0].message, "Save failed");
+
+        let page = query_logs(
+            &dir,
+            &LogQuery {
+                offset: 1,
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(page.total, 4);
+        let messages: Vec<_> = page.entries.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["Save failed", "Slow command"]);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_invalid_filters_are_rejected() {
+        let dir = std::env::temp_dir();
+        let query = LogQuery {
+            level: Some("loud".to_string()),
+            ..Default::default()
+        };
+        assert!(query_logs(&dir, &query).is_err());
+
+        let query = LogQuery {
+            since: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        assert!(query_logs(&dir, &query).is_err());
+    }
+}
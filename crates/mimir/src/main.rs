@@ -8,41 +8,32 @@
 )]
 
 use mimir_core::db::init_database;
-use mimir_lib::commands::{archive, asset, campaign, catalog, character, dev, dm_map, document, homebrew, homebrew_monster, homebrew_spell, map, module, player_display, print, source};
+use mimir_core::services::TrashService;
+use mimir_lib::commands::{analytics, archive, asset, campaign, catalog, character, character_image, class_resource, combat, companion, continuity, crafting, currency, custom_field, death, dev, display_profile, dm_map, dm_reference, document, encounter_effect, encryption, forage, handout, history, homebrew, homebrew_monster, homebrew_spell, initiative, lan_display, logs, map, memory, module, onboarding, palette, party, player, player_display, print, quick_action, reaction, recap, recent, rumor, safety_tools, secret_note, settings, settlement, source, stash, sync, tag, timer, trash, travel, vehicle, watch};
+use mimir_lib::{command_trace, logging};
 use mimir_lib::{AppPaths, AppState};
 use mimir_print::PrintState;
 use tauri::Manager;
-use tracing_subscriber::fmt::writer::MakeWriterExt;
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
 
 fn main() {
-    // Set up file-based logging in the app's logs directory
-    #[cfg(target_os = "macos")]
-    let log_dir = std::env::var("HOME")
-        .map(|h| std::path::PathBuf::from(h).join("Library/Application Support/com.mimir.app/logs"))
-        .unwrap_or_else(|_| std::path::PathBuf::from("logs"));
-    #[cfg(target_os = "windows")]
-    let log_dir = std::env::var("APPDATA")
-        .map(|h| std::path::PathBuf::from(h).join("com.mimir.app/logs"))
-        .unwrap_or_else(|_| std::path::PathBuf::from("logs"));
-    #[cfg(target_os = "linux")]
-    let log_dir = std::env::var("HOME")
-        .map(|h| std::path::PathBuf::from(h).join(".local/share/com.mimir.app/logs"))
-        .unwrap_or_else(|_| std::path::PathBuf::from("logs"));
+    // Set up file-based logging in the app's logs directory. Files are JSON
+    // lines so the log viewer can filter them; stderr stays human-readable.
+    let log_dir = logging::log_dir();
     std::fs::create_dir_all(&log_dir).ok();
 
-    let file_appender = tracing_appender::rolling::daily(&log_dir, "mimir.log");
+    let file_appender = tracing_appender::rolling::daily(&log_dir, logging::LOG_FILE_PREFIX);
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
 
-    // Log to both stderr and file
-    let writer = std::io::stderr.and(non_blocking);
-
-    tracing_subscriber::fmt()
-        .with_env_filter(
+    tracing_subscriber::registry()
+        .with(
             EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| EnvFilter::new("mimir=info,mimir_core=info"))
         )
-        .with_writer(writer)
+        .with(fmt::layer().with_writer(std::io::stderr))
+        .with(fmt::layer().json().with_ansi(false).with_writer(non_blocking))
         .init();
 
     tauri::Builder::default()
@@ -60,12 +51,19 @@ fn main() {
                 .expect("Failed to initialize application paths");
 
             // Initialize database with migrations (this creates the DB and runs migrations)
-            let _conn = init_database(&paths.database_url())
+            let mut conn = init_database(&paths.database_url())
                 .expect("Failed to initialize database");
+
+            // Permanently remove items that have outlived the trash retention period
+            if let Err(e) = TrashService::new(&mut conn, &paths.app_dir).purge(None, true) {
+                tracing::warn!("Failed to purge expired trash: {}", e);
+            }
             // Connection is dropped here - we'll create on-demand connections
 
             // Create and manage app state (stores DB path for on-demand connections)
             let state = AppState::new(paths.clone());
+            // Pick the table timers back up for a session left in progress
+            timer::restore_game_session(&state);
             app.manage(state);
 
             // Create and manage print state
@@ -77,24 +75,32 @@ fn main() {
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
+        .invoke_handler(command_trace::traced(tauri::generate_handler![
             // Campaign commands
             campaign::list_campaigns,
             campaign::list_archived_campaigns,
             campaign::get_campaign,
             campaign::create_campaign,
             campaign::update_campaign,
+            campaign::set_campaign_edition,
+            campaign::set_campaign_srd_only,
+            campaign::clone_campaign,
             campaign::archive_campaign,
             campaign::unarchive_campaign,
             campaign::delete_campaign,
             campaign::list_campaign_sources,
+            campaign::list_campaign_search_sources,
             campaign::add_campaign_source,
             campaign::remove_campaign_source,
             campaign::set_campaign_sources,
             // Archive commands (campaign export/import)
             archive::export_campaign,
+            archive::export_campaign_markdown,
             archive::preview_archive,
             archive::import_campaign,
+            archive::create_campaign_backup,
+            archive::list_campaign_backups,
+            archive::restore_campaign_backup,
             // Homebrew item commands
             homebrew::list_homebrew_items,
             homebrew::get_homebrew_item,
@@ -122,6 +128,7 @@ fn main() {
             module::update_module,
             module::delete_module,
             module::reorder_module,
+            module::set_module_prep_task,
             // Module monster commands
             module::list_module_monsters_with_data,
             module::add_module_monster,
@@ -136,6 +143,7 @@ fn main() {
             module::update_token,
             module::update_token_position,
             module::update_token_vision,
+            module::set_token_mob_size,
             module::toggle_token_visibility,
             module::delete_token,
             module::serve_token_image,
@@ -153,6 +161,9 @@ fn main() {
             character::assign_character_to_campaign,
             // Character commands - level up
             character::level_up_character,
+            character::respec_character,
+            character::convert_character_to_statblock,
+            character::attach_character_statblock_to_module,
             // Character commands - inventory
             character::get_character_inventory,
             character::get_equipped_items,
@@ -165,11 +176,58 @@ fn main() {
             character::add_character_spell,
             character::remove_character_spell,
             character::toggle_spell_prepared,
+            character::toggle_spell_scribed,
             // Character commands - sources
             character::list_character_sources,
             character::add_character_source,
             character::remove_character_source,
             character::set_character_sources,
+            // Character commands - custom fields
+            custom_field::list_custom_fields,
+            custom_field::create_custom_field,
+            custom_field::update_custom_field,
+            custom_field::reorder_custom_fields,
+            custom_field::delete_custom_field,
+            custom_field::get_character_custom_fields,
+            custom_field::set_character_custom_field,
+            custom_field::clear_character_custom_field,
+            // Character commands - class resources
+            class_resource::list_character_resources,
+            class_resource::use_resource,
+            class_resource::restore_resource,
+            class_resource::take_rest,
+            // Character commands - companions
+            companion::list_character_companions,
+            companion::add_companion,
+            companion::update_companion,
+            companion::adjust_companion_hp,
+            companion::level_up_companion,
+            companion::remove_companion,
+            companion::get_companion_statblock,
+            // Character commands - crafting
+            crafting::list_crafting_projects,
+            crafting::get_crafting_recipe,
+            crafting::start_crafting_project,
+            crafting::advance_crafting,
+            crafting::abandon_crafting_project,
+            // Character commands - currency
+            currency::get_currency_history,
+            currency::adjust_character_currency,
+            currency::spend_character_currency,
+            currency::consolidate_character_currency,
+            // Character commands - death and resurrection
+            death::list_character_deaths,
+            death::get_campaign_memorial,
+            death::mark_character_dead,
+            death::resurrect_character,
+            // Character commands - portraits and tokens
+            character_image::upload_character_portrait,
+            character_image::upload_character_token,
+            character_image::regenerate_character_token,
+            character_image::remove_character_portrait,
+            character_image::remove_character_token,
+            character_image::serve_character_portrait,
+            character_image::serve_character_token,
             // Document commands - list
             document::list_campaign_documents,
             document::list_module_documents,
@@ -177,11 +235,19 @@ fn main() {
             document::get_document,
             document::create_document,
             document::update_document,
+            document::check_document_external_changes,
+            document::save_document_merging,
             document::delete_document,
             document::reorder_document,
+            // Document commands - bulk
+            document::move_documents_to_module,
+            document::bulk_update_document_metadata,
+            document::bulk_delete_documents,
             // Document commands - search
             document::search_documents,
             document::search_module_documents,
+            // Document commands - embeds
+            document::resolve_monster_embed,
             // Map commands - list
             map::list_campaign_maps,
             map::list_campaign_level_maps,
@@ -219,6 +285,8 @@ fn main() {
             map::list_map_traps,
             map::get_map_trap,
             map::create_map_trap,
+            map::create_map_trap_from_catalog,
+            map::refresh_map_trap_from_catalog,
             map::update_map_trap,
             map::move_map_trap,
             map::toggle_map_trap_visibility,
@@ -232,7 +300,16 @@ fn main() {
             map::update_map_poi,
             map::move_map_poi,
             map::toggle_map_poi_visibility,
+            map::link_map_poi_document,
+            map::generate_location_key,
+            map::generate_dungeon,
             map::delete_map_poi,
+            // Map commands - regions
+            map::list_map_regions,
+            map::create_map_region,
+            map::update_map_region,
+            map::delete_map_region,
+            map::resolve_map_region,
             // Asset commands - list
             asset::list_campaign_assets,
             asset::list_module_assets,
@@ -272,6 +349,7 @@ fn main() {
             catalog::search_backgrounds,
             catalog::get_background,
             catalog::get_background_by_name,
+            catalog::get_background_options,
             catalog::list_background_sources,
             catalog::count_backgrounds,
             // Catalog commands - classes
@@ -394,6 +472,7 @@ fn main() {
             source::import_catalog_from_zip,
             source::import_catalog_images,
             source::set_source_enabled,
+            source::set_source_edition,
             source::delete_catalog_source,
             // Book content commands (Reading mode)
             source::list_library_books,
@@ -407,13 +486,255 @@ fn main() {
             player_display::send_map_to_display,
             player_display::update_display_viewport,
             player_display::toggle_display_blackout,
+            player_display::send_monster_to_display,
+            player_display::send_handout_to_display,
+            player_display::send_read_aloud_to_display,
+            player_display::clear_display_handout,
+            // Player display commands - handout queue
+            handout::list_handouts,
+            handout::create_handout,
+            handout::delete_handout,
+            handout::reorder_handouts,
+            handout::requeue_handout,
+            handout::list_handout_history,
+            handout::reveal_next_handout,
+            handout::reveal_handout,
+            // LAN player display commands
+            lan_display::get_lan_display_status,
+            lan_display::start_lan_display,
+            lan_display::stop_lan_display,
+            // Player display profile commands
+            display_profile::list_display_monitors,
+            display_profile::list_display_profiles,
+            display_profile::save_display_profile,
+            display_profile::delete_display_profile,
+            display_profile::assign_display_profile,
+            display_profile::get_assigned_display_profile,
             // DM map window commands
             dm_map::is_dm_map_open,
             dm_map::open_dm_map_window,
             dm_map::close_dm_map_window,
             dm_map::toggle_dm_map_fullscreen,
+            dm_map::focus_dm_map_region,
+            // DM reference window commands
+            dm_reference::is_dm_reference_open,
+            dm_reference::open_dm_reference_window,
+            dm_reference::close_dm_reference_window,
+            dm_reference::list_dm_reference_pins,
+            dm_reference::pin_dm_reference,
+            dm_reference::update_dm_reference_pin,
+            dm_reference::reorder_dm_reference_pins,
+            dm_reference::unpin_dm_reference,
+            // Table timer commands
+            timer::get_timers,
+            timer::start_timer,
+            timer::pause_timer,
+            timer::reset_timer,
+            timer::next_turn_timer,
+            // Game session commands
+            timer::start_game_session,
+            timer::end_game_session,
+            timer::abandon_game_session,
+            timer::list_game_sessions,
+            timer::award_session_xp,
+            timer::set_session_transcript,
+            // Campaign analytics commands
+            analytics::get_campaign_analytics,
+            // Party commands
+            party::list_parties,
+            party::create_party,
+            party::update_party,
+            party::delete_party,
+            party::add_party_member,
+            party::remove_party_member,
+            party::list_character_parties,
+            party::get_party_xp,
+            party::get_party_encounter_budget,
+            party::evaluate_party_encounter,
+            party::set_party_day,
+            party::list_party_timeline,
+            party::add_party_timeline_event,
+            party::delete_party_timeline_event,
+            // Party commands - mounts and vehicles
+            vehicle::list_campaign_vehicles,
+            vehicle::list_character_vehicles,
+            vehicle::list_party_vehicles,
+            vehicle::assign_vehicle,
+            vehicle::update_owned_vehicle,
+            vehicle::remove_owned_vehicle,
+            vehicle::get_owned_vehicle_statblock,
+            vehicle::get_party_capacity,
+            // Party commands - travel
+            travel::plan_travel,
+            // World commands - settlements
+            settlement::generate_settlement,
+            // World commands - rumors and hooks
+            rumor::list_rumors,
+            rumor::create_rumor,
+            rumor::update_rumor,
+            rumor::delete_rumor,
+            rumor::mark_rumor_heard,
+            rumor::unmark_rumor_heard,
+            rumor::generate_tavern_rumor_table,
+            // World commands - secret notes
+            secret_note::list_secret_notes,
+            secret_note::create_secret_note,
+            secret_note::update_secret_note,
+            secret_note::delete_secret_note,
+            secret_note::mark_secret_note_delivered,
+            secret_note::mark_secret_note_outstanding,
+            // World commands - NPC reactions
+            reaction::get_relationship_standings,
+            reaction::set_npc_disposition,
+            reaction::set_faction_disposition,
+            reaction::set_party_reputation,
+            reaction::roll_npc_reaction,
+            reaction::list_npc_interactions,
+            reaction::delete_npc_interaction,
+            // World commands - recaps
+            recap::generate_recap,
+            // Combat commands - action hints
+            combat::get_monster_action_hints,
+            combat::get_spell_action_hint,
+            combat::roll_action_hint,
+            // Combat commands - initiative
+            initiative::get_initiative,
+            initiative::set_initiative,
+            initiative::roll_initiative,
+            initiative::add_mob,
+            initiative::damage_mob,
+            initiative::resolve_mob_attack,
+            initiative::import_initiative,
+            initiative::export_initiative,
+            initiative::reveal_combatant,
+            initiative::remove_combatant,
+            initiative::move_combatant_up,
+            initiative::delay_turn,
+            initiative::resume_turn,
+            initiative::ready_action,
+            initiative::next_initiative_turn,
+            initiative::load_encounter_effects,
+            initiative::clear_initiative,
+            initiative::send_initiative_to_display,
+            // Combat commands - encounter effects
+            encounter_effect::list_encounter_effects,
+            encounter_effect::add_encounter_effect,
+            encounter_effect::update_encounter_effect,
+            encounter_effect::remove_encounter_effect,
+            // Player commands
+            player::list_players,
+            player::create_player,
+            player::update_player,
+            player::delete_player,
+            player::assign_player_character,
+            player::unassign_player_character,
+            player::list_player_characters,
+            player::get_player_attendance,
+            player::create_player_claim,
+            player::list_player_claims,
+            player::revoke_player_claim,
+            player::redeem_player_claim,
+            // Safety tools commands
+            safety_tools::get_safety_tools,
+            safety_tools::update_safety_tools,
+            safety_tools::generate_safety_tools_document,
+            safety_tools::get_safety_prompt,
+            // Campaign commands - assistant memories
+            memory::list_campaign_memories,
+            memory::remember_campaign_memory,
+            memory::update_campaign_memory,
+            memory::delete_campaign_memory,
+            // Campaign commands - shared stash
+            stash::get_campaign_stash,
+            stash::get_stash_ledger,
+            stash::add_stash_item,
+            stash::remove_stash_item,
+            stash::take_stash_item,
+            stash::deposit_stash_currency,
+            stash::withdraw_stash_currency,
+            stash::take_stash_currency,
+            stash::split_stash_loot,
+            // Campaign commands - foraging
+            forage::list_forage_ingredients,
+            forage::add_forage_ingredient,
+            forage::update_forage_ingredient,
+            forage::remove_forage_ingredient,
+            forage::import_forage_table,
+            forage::forage,
+            // Quick action commands
+            quick_action::list_quick_actions,
+            quick_action::create_quick_action,
+            quick_action::update_quick_action,
+            quick_action::reorder_quick_actions,
+            quick_action::delete_quick_action,
+            quick_action::trigger_quick_action,
+            // Tag commands
+            tag::list_tags,
+            tag::create_tag,
+            tag::update_tag,
+            tag::delete_tag,
+            tag::tag_entity,
+            tag::untag_entity,
+            tag::list_entity_tags,
+            tag::list_tagged_entities,
+            // Recent items and favorites commands
+            recent::record_recent_item,
+            recent::get_recent,
+            recent::remove_recent_item,
+            recent::clear_recent_items,
+            recent::pin_favorite,
+            recent::unpin_favorite,
+            recent::list_favorites,
+            // Trash commands
+            trash::list_trashed_items,
+            trash::restore_item,
+            trash::purge_trash,
+            // Campaign history commands
+            history::is_campaign_history_enabled,
+            history::enable_campaign_history,
+            history::disable_campaign_history,
+            history::list_campaign_history,
+            history::read_campaign_history_file,
+            history::restore_campaign_file,
+            // Document watch commands
+            watch::start_document_watch,
+            watch::stop_document_watch,
+            watch::list_document_watches,
+            // Database encryption commands
+            encryption::get_encryption_status,
+            encryption::enable_encryption,
+            encryption::disable_encryption,
+            // Campaign sync commands
+            sync::get_sync_config,
+            sync::configure_sync,
+            sync::get_campaign_sync_state,
+            sync::list_remote_campaigns,
+            sync::sync_campaign,
+            sync::resolve_sync_conflicts,
+            // Settings commands
+            settings::get_settings,
+            settings::update_settings,
+            settings::import_settings,
+            settings::export_settings,
+            // Onboarding commands
+            onboarding::get_onboarding_status,
+            onboarding::import_srd_library,
+            onboarding::download_srd_library,
+            onboarding::create_sample_campaign,
+            // Command palette commands
+            palette::list_registered_commands,
+            palette::execute_registered_command,
+            // Module continuity commands
+            continuity::list_module_links,
+            continuity::link_modules,
+            continuity::update_module_link,
+            continuity::delete_module_link,
+            continuity::check_module_completion,
             // App info commands
             dev::get_app_info,
+            // Log viewer commands
+            logs::get_slow_commands,
+            logs::query_logs,
             // Dev tools commands (dev mode only)
             dev::is_dev_mode,
             dev::is_dev_seeded,
@@ -423,17 +744,24 @@ fn main() {
             // Print/PDF export commands
             print::list_print_templates,
             print::export_character,
+            print::export_battle_cards,
+            print::export_grimoire,
+            print::export_party_reference,
             print::export_campaign_document,
             print::export_campaign_documents,
+            print::export_campaign_retrospective,
+            print::export_secret_slips,
+            print::export_campaign_timeline,
             print::export_module_documents,
             print::print_map,
+            print::export_location_key,
             print::generate_character_sheet,
             print::save_pdf,
             print::export_module_monsters,
             print::export_monster_card,
             print::export_trap_card,
             print::export_trap_cards,
-        ])
+        ]))
         .run(tauri::generate_context!())
         .expect("Error running Mimir application");
 }
@@ -8,10 +8,19 @@
 
 use diesel::SqliteConnection;
 use mimir_core::db::create_connection;
+use mimir_core::services::{ErrorKind, SettingsService, CONFIG_DIR};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
+use crate::command_trace::CommandTrace;
+use crate::commands::player_display::DisplayScreen;
+use crate::error::{CommandError, CommandResult};
+use crate::initiative::InitiativeTracker;
+use crate::lan_display::LanDisplay;
+use crate::timers::TableTimers;
+use crate::watchers::DocumentWatchers;
+
 /// Check if running in development mode.
 ///
 /// Returns true if either:
@@ -57,7 +66,7 @@ impl AppPaths {
             tauri_app_data_dir
         };
 
-        let config_dir = app_dir.join("config");
+        let config_dir = app_dir.join(CONFIG_DIR);
         let data_dir = app_dir.join("data");
         let logs_dir = app_dir.join("logs");
         let assets_dir = app_dir.join("assets");
@@ -123,6 +132,18 @@ pub struct AppState {
     pub paths: AppPaths,
     /// Active campaign ID (for commands that need it).
     pub active_campaign_id: Mutex<Option<String>>,
+    /// Turn, break, and session timers for the table.
+    pub timers: Mutex<TableTimers>,
+    /// Turn order of the encounter being run.
+    pub initiative: Mutex<InitiativeTracker>,
+    /// Player display screen showing the turn order, kept up to date as it changes.
+    pub initiative_display: Mutex<Option<DisplayScreen>>,
+    /// Filesystem watchers over exported campaign folders.
+    pub watchers: Mutex<DocumentWatchers>,
+    /// Web server relaying the player display to LAN devices.
+    pub lan_display: Mutex<LanDisplay>,
+    /// Request IDs and recent timings of traced commands.
+    pub command_trace: CommandTrace,
 }
 
 impl AppState {
@@ -130,19 +151,43 @@ impl AppState {
     ///
     /// The database should already be initialized (migrations run) before creating AppState.
     pub fn new(paths: AppPaths) -> Self {
-        Self {
+        let state = Self {
             db_url: paths.database_url(),
             paths,
             active_campaign_id: Mutex::new(None),
+            timers: Mutex::new(TableTimers::default()),
+            initiative: Mutex::new(InitiativeTracker::default()),
+            initiative_display: Mutex::new(None),
+            watchers: Mutex::new(DocumentWatchers::default()),
+            lan_display: Mutex::new(LanDisplay::default()),
+            command_trace: CommandTrace::default(),
+        };
+        match state.settings().load() {
+            Ok(settings) => state
+                .command_trace
+                .set_slow_command_ms(settings.diagnostics.slow_command_ms),
+            Err(e) => tracing::warn!("Failed to load settings, using defaults: {}", e),
         }
+        state
     }
 
     /// Create a new database connection.
     ///
     /// Each connection is configured with WAL mode and foreign keys enabled.
     /// Returns an error if the connection cannot be established.
-    pub fn connect(&self) -> Result<SqliteConnection, String> {
-        create_connection(&self.db_url).map_err(|e| format!("Database connection error: {}", e))
+    pub fn connect(&self) -> CommandResult<SqliteConnection> {
+        create_connection(&self.db_url).map_err(|e| {
+            CommandError::new(
+                ErrorKind::Database,
+                format!("Database connection error: {}", e),
+            )
+            .with_suggestion("Restart Mimir and try again.")
+        })
+    }
+
+    /// Service for the app settings file.
+    pub fn settings(&self) -> SettingsService {
+        SettingsService::new(&self.paths.app_dir)
     }
 
     /// Check if running in development mode.
@@ -0,0 +1,367 @@
+//! Table Timers
+//!
+//! Pacing timers for running a session: a per-turn combat timer, a break
+//! timer, and the session clock. Timers are in-memory application state;
+//! session totals are written to the active game session record by the
+//! timer commands, and restored from it when the app starts again.
+//!
+//! All operations take the current time explicitly so the timer logic can be
+//! tested without sleeping.
+
+use chrono::{DateTime, Utc};
+use mimir_core::services::SessionTotals;
+use serde::Serialize;
+
+/// Which table timer an operation applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimerKind {
+    /// Per-turn combat timer
+    Turn,
+    /// Break timer
+    Break,
+    /// Session clock
+    Session,
+}
+
+impl TimerKind {
+    /// Parse a timer kind ("turn", "break", "session").
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "turn" => Ok(Self::Turn),
+            "break" => Ok(Self::Break),
+            "session" => Ok(Self::Session),
+            other => Err(format!(
+                "Unknown timer '{}'. Expected turn, break, or session",
+                other
+            )),
+        }
+    }
+}
+
+/// A pausable stopwatch with an optional countdown limit.
+#[derive(Debug, Clone, Default)]
+pub struct Stopwatch {
+    accumulated_ms: i64,
+    running_since: Option<DateTime<Utc>>,
+    limit_secs: Option<i64>,
+    label: Option<String>,
+}
+
+impl Stopwatch {
+    /// Start or resume the stopwatch. Does nothing if already running.
+    pub fn start(&mut self, now: DateTime<Utc>) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Pause the stopwatch, banking the elapsed time.
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated_ms += (now - since).num_milliseconds().max(0);
+        }
+    }
+
+    /// Stop and clear elapsed time. The countdown limit and label are kept.
+    pub fn reset(&mut self) {
+        self.accumulated_ms = 0;
+        self.running_since = None;
+    }
+
+    /// Whether the stopwatch is running.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Elapsed time in milliseconds.
+    pub fn elapsed_ms(&self, now: DateTime<Utc>) -> i64 {
+        let running = self
+            .running_since
+            .map(|since| (now - since).num_milliseconds().max(0))
+            .unwrap_or(0);
+        self.accumulated_ms + running
+    }
+
+    /// Snapshot of the stopwatch for the frontend.
+    pub fn snapshot(&self, now: DateTime<Utc>) -> TimerSnapshot {
+        let elapsed_secs = self.elapsed_ms(now) / 1000;
+        TimerSnapshot {
+            running: self.is_running(),
+            elapsed_secs,
+            limit_secs: self.limit_secs,
+            remaining_secs: self.limit_secs.map(|limit| (limit - elapsed_secs).max(0)),
+            expired: self.limit_secs.is_some_and(|limit| elapsed_secs >= limit),
+            label: self.label.clone(),
+            running_since: self.running_since.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+/// Serializable view of a single timer.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerSnapshot {
+    pub running: bool,
+    pub elapsed_secs: i64,
+    /// Countdown length, if the timer was started with one
+    pub limit_secs: Option<i64>,
+    pub remaining_secs: Option<i64>,
+    pub expired: bool,
+    /// Whose turn it is, or what the break is for
+    pub label: Option<String>,
+    /// When the current run started, so displays can tick locally
+    pub running_since: Option<String>,
+}
+
+/// Serializable view of all table timers, emitted as `timers:update`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimersSnapshot {
+    pub turn: TimerSnapshot,
+    #[serde(rename = "break")]
+    pub break_timer: TimerSnapshot,
+    pub session: TimerSnapshot,
+    /// Game session the session clock is recording into, if any
+    pub session_id: Option<String>,
+    pub totals: SessionTotals,
+}
+
+/// The set of table timers plus running turn and break statistics.
+#[derive(Debug, Clone, Default)]
+pub struct TableTimers {
+    turn: Stopwatch,
+    break_timer: Stopwatch,
+    session: Stopwatch,
+    /// Break time banked from completed breaks
+    break_total_ms: i64,
+    turn_count: i64,
+    turn_total_ms: i64,
+    longest_turn_ms: i64,
+    active_session_id: Option<String>,
+}
+
+impl TableTimers {
+    fn timer_mut(&mut self, kind: TimerKind) -> &mut Stopwatch {
+        match kind {
+            TimerKind::Turn => &mut self.turn,
+            TimerKind::Break => &mut self.break_timer,
+            TimerKind::Session => &mut self.session,
+        }
+    }
+
+    /// Start or resume a timer.
+    ///
+    /// `limit_secs` turns the timer into a countdown; `label` names whose turn
+    /// it is or what the break is for. Both replace any previous values when given.
+    pub fn start(
+        &mut self,
+        kind: TimerKind,
+        now: DateTime<Utc>,
+        limit_secs: Option<i64>,
+        label: Option<String>,
+    ) {
+        let timer = self.timer_mut(kind);
+        if limit_secs.is_some() {
+            timer.limit_secs = limit_secs;
+        }
+        if label.is_some() {
+            timer.label = label;
+        }
+        timer.start(now);
+    }
+
+    /// Pause a timer.
+    pub fn pause(&mut self, kind: TimerKind, now: DateTime<Utc>) {
+        self.timer_mut(kind).pause(now);
+    }
+
+    /// Reset a timer to zero and stop it.
+    ///
+    /// Resetting the break timer banks the finished break into the session's
+    /// break total. Resetting the turn timer discards the turn.
+    pub fn reset(&mut self, kind: TimerKind, now: DateTime<Utc>) {
+        if kind == TimerKind::Break {
+            self.break_total_ms += self.break_timer.elapsed_ms(now);
+        }
+        self.timer_mut(kind).reset();
+    }
+
+    /// End the current combat turn and start timing the next one.
+    ///
+    /// The finished turn is counted toward the session's turn statistics.
+    pub fn next_turn(&mut self, now: DateTime<Utc>, label: Option<String>) {
+        let elapsed = self.turn.elapsed_ms(now);
+        if elapsed > 0 {
+            self.turn_count += 1;
+            self.turn_total_ms += elapsed;
+            self.longest_turn_ms = self.longest_turn_ms.max(elapsed);
+        }
+        self.turn.reset();
+        self.turn.label = label;
+        self.turn.start(now);
+    }
+
+    /// Reset all timers and statistics and start the session clock for a game session.
+    pub fn begin_session(&mut self, session_id: String, now: DateTime<Utc>) {
+        *self = Self::default();
+        self.active_session_id = Some(session_id);
+        self.session.start(now);
+    }
+
+    /// Reattach to a game session that was still in progress when the app
+    /// last closed, restoring its recorded totals with every timer stopped.
+    pub fn resume_session(&mut self, session_id: String, totals: &SessionTotals) {
+        *self = Self::default();
+        self.active_session_id = Some(session_id);
+        self.session.accumulated_ms = i64::from(totals.play_seconds) * 1000;
+        self.break_total_ms = i64::from(totals.break_seconds) * 1000;
+        self.turn_count = i64::from(totals.combat_turns);
+        self.turn_total_ms = i64::from(totals.combat_turn_seconds) * 1000;
+        self.longest_turn_ms = i64::from(totals.longest_turn_seconds) * 1000;
+    }
+
+    /// Stop all timers and detach from the game session.
+    ///
+    /// Returns the session ID and its final totals, if a session was active.
+    pub fn end_session(&mut self, now: DateTime<Utc>) -> Option<(String, SessionTotals)> {
+        let totals = self.totals(now);
+        let session_id = self.active_session_id.take()?;
+        self.turn.pause(now);
+        self.break_timer.pause(now);
+        self.session.pause(now);
+        Some((session_id, totals))
+    }
+
+    /// Game session the session clock is recording into, if any.
+    pub fn active_session_id(&self) -> Option<&str> {
+        self.active_session_id.as_deref()
+    }
+
+    /// Pacing totals so far, including any break in progress.
+    pub fn totals(&self, now: DateTime<Utc>) -> SessionTotals {
+        let secs = |ms: i64| i32::try_from(ms / 1000).unwrap_or(i32::MAX);
+        SessionTotals {
+            play_seconds: secs(self.session.elapsed_ms(now)),
+            break_seconds: secs(self.break_total_ms + self.break_timer.elapsed_ms(now)),
+            combat_turns: i32::try_from(self.turn_count).unwrap_or(i32::MAX),
+            combat_turn_seconds: secs(self.turn_total_ms),
+            longest_turn_seconds: secs(self.longest_turn_ms),
+        }
+    }
+
+    /// Snapshot of all timers.
+    pub fn snapshot(&self, now: DateTime<Utc>) -> TimersSnapshot {
+        TimersSnapshot {
+            turn: self.turn.snapshot(now),
+            break_timer: self.break_timer.snapshot(now),
+            session: self.session.snapshot(now),
+            session_id: self.active_session_id.clone(),
+            totals: self.totals(now),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn t0() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-20T19:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_stopwatch_pause_and_resume() {
+        let mut sw = Stopwatch::default();
+        sw.start(t0());
+        sw.pause(t0() + Duration::seconds(30));
+        assert_eq!(sw.elapsed_ms(t0() + Duration::seconds(100)), 30_000);
+
+        sw.start(t0() + Duration::seconds(100));
+        assert_eq!(sw.elapsed_ms(t0() + Duration::seconds(110)), 40_000);
+        assert!(sw.is_running());
+    }
+
+    #[test]
+    fn test_countdown_snapshot() {
+        let mut timers = TableTimers::default();
+        timers.start(TimerKind::Break, t0(), Some(600), Some("Pizza".to_string()));
+
+        let snap = timers.snapshot(t0() + Duration::seconds(120));
+        assert_eq!(snap.break_timer.remaining_secs, Some(480));
+        assert!(!snap.break_timer.expired);
+        assert_eq!(snap.break_timer.label.as_deref(), Some("Pizza"));
+
+        let snap = timers.snapshot(t0() + Duration::seconds(700));
+        assert_eq!(snap.break_timer.remaining_secs, Some(0));
+        assert!(snap.break_timer.expired);
+    }
+
+    #[test]
+    fn test_next_turn_records_stats() {
+        let mut timers = TableTimers::default();
+        timers.next_turn(t0(), Some("Fighter".to_string()));
+        timers.next_turn(t0() + Duration::seconds(45), Some("Goblin".to_string()));
+        timers.next_turn(t0() + Duration::seconds(60), Some("Wizard".to_string()));
+
+        let totals = timers.totals(t0() + Duration::seconds(90));
+        assert_eq!(totals.combat_turns, 2);
+        assert_eq!(totals.combat_turn_seconds, 60);
+        assert_eq!(totals.longest_turn_seconds, 45);
+
+        let snap = timers.snapshot(t0() + Duration::seconds(90));
+        assert_eq!(snap.turn.label.as_deref(), Some("Wizard"));
+        assert_eq!(snap.turn.elapsed_secs, 30);
+    }
+
+    #[test]
+    fn test_session_totals_include_breaks() {
+        let mut timers = TableTimers::default();
+        timers.begin_session("sess-1".to_string(), t0());
+
+        timers.start(TimerKind::Break, t0() + Duration::seconds(3600), None, None);
+        timers.reset(TimerKind::Break, t0() + Duration::seconds(4200));
+        timers.start(TimerKind::Break, t0() + Duration::seconds(5000), None, None);
+
+        let (session_id, totals) = timers
+            .end_session(t0() + Duration::seconds(5300))
+            .expect("session should be active");
+        assert_eq!(session_id, "sess-1");
+        assert_eq!(totals.play_seconds, 5300);
+        assert_eq!(totals.break_seconds, 900);
+        assert!(timers.active_session_id().is_none());
+        assert!(timers.end_session(t0()).is_none());
+    }
+
+    #[test]
+    fn test_resume_session_restores_totals() {
+        let totals = SessionTotals {
+            play_seconds: 5400,
+            break_seconds: 600,
+            combat_turns: 12,
+            combat_turn_seconds: 720,
+            longest_turn_seconds: 150,
+        };
+        let mut timers = TableTimers::default();
+        timers.resume_session("sess-1".to_string(), &totals);
+
+        assert_eq!(timers.active_session_id(), Some("sess-1"));
+        let snap = timers.snapshot(t0());
+        assert!(!snap.session.running);
+        assert_eq!(snap.totals, totals);
+
+        timers.start(TimerKind::Session, t0(), None, None);
+        let totals = timers.totals(t0() + Duration::seconds(60));
+        assert_eq!(totals.play_seconds, 5460);
+    }
+
+    #[test]
+    fn test_parse_timer_kind() {
+        assert_eq!(TimerKind::parse("turn").unwrap(), TimerKind::Turn);
+        assert_eq!(TimerKind::parse("break").unwrap(), TimerKind::Break);
+        assert!(TimerKind::parse("round").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! Document Folder Watchers
+//!
+//! Filesystem watchers over exported campaign folders, so a DM can edit notes
+//! in an external editor while Mimir is open. Each watcher applies changed
+//! markdown files to the database through `MarkdownSyncService` and emits a
+//! `documents:synced` event, which the frontend forwards to its data event bus
+//! so open document lists refresh.
+
+use mimir_core::db::create_connection;
+use mimir_core::services::MarkdownSyncService;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+/// Event emitted with a `SyncedChange` after a document is synced from a
+/// watched folder.
+pub const DOCUMENTS_SYNCED_EVENT: &str = "documents:synced";
+
+/// A campaign folder being watched.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchedFolder {
+    pub campaign_id: String,
+    pub root: PathBuf,
+}
+
+struct ActiveWatch {
+    folder: WatchedFolder,
+    // Watching stops when the watcher is dropped.
+    _watcher: RecommendedWatcher,
+}
+
+/// Active document folder watchers, at most one per campaign.
+#[derive(Default)]
+pub struct DocumentWatchers {
+    active: HashMap<String, ActiveWatch>,
+}
+
+impl DocumentWatchers {
+    /// Start watching `root` for a campaign, replacing any existing watch
+    /// for that campaign.
+    pub fn start(
+        &mut self,
+        app: AppHandle,
+        db_url: String,
+        campaign_id: &str,
+        root: &Path,
+    ) -> Result<WatchedFolder, String> {
+        // Events carry canonical paths, so match them against a canonical root.
+        let root = fs::canonicalize(root)
+            .map_err(|e| format!("Cannot watch {}: {}", root.display(), e))?;
+        let folder = WatchedFolder {
+            campaign_id: campaign_id.to_string(),
+            root: root.clone(),
+        };
+
+        let handler_folder = folder.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |result: notify::Result<Event>| match result {
+                Ok(event) => handle_event(&app, &db_url, &handler_folder, event),
+                Err(e) => tracing::warn!("Document watcher error: {}", e),
+            })
+            .map_err(|e| format!("Failed to start watcher: {}", e))?;
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .map_err(|e| format!("Failed to watch {}: {}", root.display(), e))?;
+
+        tracing::info!(campaign_id = %campaign_id, path = %root.display(), "Watching document folder");
+        self.active.insert(
+            campaign_id.to_string(),
+            ActiveWatch {
+                folder: folder.clone(),
+                _watcher: watcher,
+            },
+        );
+        Ok(folder)
+    }
+
+    /// Stop watching a campaign's folder. Returns false if it wasn't watched.
+    pub fn stop(&mut self, campaign_id: &str) -> bool {
+        self.active.remove(campaign_id).is_some()
+    }
+
+    /// List watched folders, ordered by campaign ID.
+    pub fn list(&self) -> Vec<WatchedFolder> {
+        let mut folders: Vec<WatchedFolder> =
+            self.active.values().map(|w| w.folder.clone()).collect();
+        folders.sort_by(|a, b| a.campaign_id.cmp(&b.campaign_id));
+        folders
+    }
+}
+
+/// Sync the markdown files touched by a filesystem event.
+fn handle_event(app: &AppHandle, db_url: &str, folder: &WatchedFolder, event: Event) {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+    let paths: Vec<&PathBuf> = event
+        .paths
+        .iter()
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    if paths.is_empty() {
+        return;
+    }
+
+    let mut conn = match create_connection(db_url) {
+        Ok(conn) => conn,
+        Err(e) => {
+            tracing::warn!("Document watcher could not connect to the database: {}", e);
+            return;
+        }
+    };
+
+    for path in paths {
+        match MarkdownSyncService::new(&mut conn).sync_file(&folder.campaign_id, &folder.root, path)
+        {
+            Ok(Some(change)) => {
+                if let Err(e) = app.emit(DOCUMENTS_SYNCED_EVENT, &change) {
+                    tracing::warn!("Failed to emit document sync event: {}", e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to sync document file")
+            }
+        }
+    }
+}